@@ -0,0 +1,424 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Compares the JSON descriptions of the ABI written by `--lang json`.
+//!
+//! Removing an item, or changing anything the C header declares about it, is
+//! breaking. Adding items, and adding variants to an enum without changing
+//! its layout, isn't. Documentation is ignored.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde_json::Value;
+
+/// A change to an item between two versions of the bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiChange {
+    /// The kind and name of the item, like `struct Foo`.
+    pub item: String,
+    pub description: String,
+    /// Whether code built against the old bindings can break.
+    pub breaking: bool,
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {}",
+            if self.breaking {
+                "breaking"
+            } else {
+                "compatible"
+            },
+            self.item,
+            self.description
+        )
+    }
+}
+
+/// The categories of items in a description, and the kind of their items
+/// when they don't have one.
+const CATEGORIES: &[(&str, &str)] = &[
+    ("constants", "constant"),
+    ("types", ""),
+    ("globals", "global"),
+    ("functions", "function"),
+];
+
+fn str_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+fn array_field<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    match value.get(key).and_then(Value::as_array) {
+        Some(array) => array,
+        None => &[],
+    }
+}
+
+/// Returns the C spelling of a JSON type.
+fn type_name(ty: &Value) -> String {
+    match str_field(ty, "kind") {
+        "primitive" | "named" => str_field(ty, "name").to_owned(),
+        "pointer" => {
+            let pointee = type_name(&ty["pointee"]);
+            if ty["const"].as_bool() == Some(true) {
+                format!("const {}*", pointee)
+            } else {
+                format!("{}*", pointee)
+            }
+        }
+        "array" => format!("{}[{}]", type_name(&ty["element"]), str_field(ty, "length")),
+        "function" => {
+            let args: Vec<_> = array_field(ty, "args").iter().map(type_name).collect();
+            format!("{} (*)({})", type_name(&ty["ret"]), args.join(", "))
+        }
+        _ => ty.to_string(),
+    }
+}
+
+/// Describes a change of a layout, if the ABIs of the target or either data
+/// model changed.
+fn layout_change(old: &Value, new: &Value) -> Option<String> {
+    let describe = |layout: &Value| match *layout {
+        Value::Object(_) => format!("size {}, align {}", layout["size"], layout["align"]),
+        _ => "unknown".to_owned(),
+    };
+    let models = [
+        ("target", "target"),
+        ("bits64", "64 bit"),
+        ("bits32", "32 bit"),
+    ];
+    for &(model, name) in &models {
+        let (old, new) = (&old["layout"][model], &new["layout"][model]);
+        if old["size"] != new["size"] || old["align"] != new["align"] {
+            return Some(format!(
+                "{} layout changed from {} to {}",
+                name,
+                describe(old),
+                describe(new)
+            ));
+        }
+    }
+    None
+}
+
+/// Collects the changes of an item that is in both descriptions.
+struct ItemDiff {
+    item: String,
+    changes: Vec<AbiChange>,
+}
+
+impl ItemDiff {
+    fn push(&mut self, description: String, breaking: bool) {
+        self.changes.push(AbiChange {
+            item: self.item.clone(),
+            description,
+            breaking,
+        });
+    }
+
+    /// Pushes a breaking change if `key` differs.
+    fn compare(&mut self, old: &Value, new: &Value, key: &str, describe: fn(&Value) -> String) {
+        if old[key] != new[key] {
+            let description = format!(
+                "{} changed from {} to {}",
+                key.replace('_', " "),
+                describe(&old[key]),
+                describe(&new[key])
+            );
+            self.push(description, true);
+        }
+    }
+
+    fn fields(&mut self, old: &Value, new: &Value) {
+        let old_fields = array_field(old, "fields");
+        let new_fields = array_field(new, "fields");
+        for (i, old_field) in old_fields.iter().enumerate() {
+            let name = str_field(old_field, "name");
+            let (j, new_field) = match new_fields
+                .iter()
+                .enumerate()
+                .find(|&(_, x)| str_field(x, "name") == name)
+            {
+                Some(x) => x,
+                None => {
+                    self.push(format!("field `{}` removed", name), true);
+                    continue;
+                }
+            };
+            if i != j {
+                self.push(format!("field `{}` moved from {} to {}", name, i, j), true);
+            }
+            if old_field["type"] != new_field["type"] {
+                let description = format!(
+                    "field `{}` changed from {} to {}",
+                    name,
+                    type_name(&old_field["type"]),
+                    type_name(&new_field["type"])
+                );
+                self.push(description, true);
+            }
+            if old_field["bits"] != new_field["bits"] {
+                let description = format!(
+                    "width of field `{}` changed from {} to {}",
+                    name, old_field["bits"], new_field["bits"]
+                );
+                self.push(description, true);
+            }
+        }
+        for new_field in new_fields {
+            let name = str_field(new_field, "name");
+            if !old_fields.iter().any(|x| str_field(x, "name") == name) {
+                self.push(format!("field `{}` added", name), true);
+            }
+        }
+    }
+
+    fn variants(&mut self, old: &Value, new: &Value) {
+        let old_variants = array_field(old, "variants");
+        let new_variants = array_field(new, "variants");
+        for old_variant in old_variants {
+            let name = str_field(old_variant, "name");
+            match new_variants.iter().find(|x| str_field(x, "name") == name) {
+                None => self.push(format!("variant `{}` removed", name), true),
+                Some(new_variant) => {
+                    if old_variant["discriminant"] != new_variant["discriminant"] {
+                        let description = format!(
+                            "value of variant `{}` changed from {} to {}",
+                            name, old_variant["discriminant"], new_variant["discriminant"]
+                        );
+                        self.push(description, true);
+                    }
+                    if old_variant["body"] != new_variant["body"] {
+                        let description = format!("body of variant `{}` changed", name);
+                        self.push(description, true);
+                    }
+                }
+            }
+        }
+        // Code built against the old bindings never sees new variants, unless
+        // the library returns them.
+        for new_variant in new_variants {
+            let name = str_field(new_variant, "name");
+            if !old_variants.iter().any(|x| str_field(x, "name") == name) {
+                self.push(format!("variant `{}` added", name), false);
+            }
+        }
+    }
+
+    fn function(&mut self, old: &Value, new: &Value) {
+        let old_args = array_field(old, "args");
+        let new_args = array_field(new, "args");
+        if old_args.len() != new_args.len() {
+            let description = format!(
+                "number of arguments changed from {} to {}",
+                old_args.len(),
+                new_args.len()
+            );
+            self.push(description, true);
+        }
+        for (i, (old_arg, new_arg)) in old_args.iter().zip(new_args).enumerate() {
+            if old_arg["type"] != new_arg["type"] {
+                let description = format!(
+                    "argument {} changed from {} to {}",
+                    i + 1,
+                    type_name(&old_arg["type"]),
+                    type_name(&new_arg["type"])
+                );
+                self.push(description, true);
+            }
+        }
+        self.compare(old, new, "ret", |x| type_name(x));
+    }
+}
+
+/// Returns the items of a description, keyed by their kind and name.
+fn items(description: &Value) -> BTreeMap<(String, String), Value> {
+    let mut items = BTreeMap::new();
+    for &(category, kind) in CATEGORIES {
+        for item in array_field(description, category) {
+            let kind = match str_field(item, "kind") {
+                "" => kind,
+                kind => kind,
+            };
+            let mut item = item.clone();
+            if let Value::Object(ref mut map) = item {
+                map.remove("documentation");
+            }
+            let name = str_field(&item, "name").to_owned();
+            items.insert((kind.replace('_', " "), name), item);
+        }
+    }
+    items
+}
+
+/// Returns the changes from the `old` description to the `new` one.
+pub(crate) fn diff(old: &Value, new: &Value) -> Vec<AbiChange> {
+    let old_items = items(old);
+    let new_items = items(new);
+    let mut changes = Vec::new();
+
+    for (&(ref kind, ref name), old) in &old_items {
+        let item = format!("{} {}", kind, name);
+        let new = match new_items.get(&(kind.clone(), name.clone())) {
+            Some(new) => new,
+            None => {
+                changes.push(AbiChange {
+                    item,
+                    description: "removed".to_owned(),
+                    breaking: true,
+                });
+                continue;
+            }
+        };
+        if old == new {
+            continue;
+        }
+
+        let mut diff = ItemDiff {
+            item,
+            changes: Vec::new(),
+        };
+        match kind.as_str() {
+            "struct" | "union" => {
+                diff.fields(old, new);
+                diff.compare(old, new, "packed", Value::to_string);
+                diff.compare(old, new, "align", Value::to_string);
+            }
+            "enum" => {
+                diff.compare(old, new, "type", Value::to_string);
+                diff.variants(old, new);
+            }
+            "tagged enum" => {
+                diff.compare(old, new, "tag", Value::to_string);
+                diff.compare(old, new, "separate_tag", Value::to_string);
+            }
+            "typedef" => diff.compare(old, new, "aliased", type_name),
+            "constant" => {
+                diff.compare(old, new, "type", type_name);
+                diff.compare(old, new, "value", Value::to_string);
+            }
+            "global" => {
+                diff.compare(old, new, "type", type_name);
+                diff.compare(old, new, "mutable", Value::to_string);
+            }
+            "function" => diff.function(old, new),
+            _ => {}
+        }
+        if let Some(description) = layout_change(old, new) {
+            diff.push(description, true);
+        }
+        diff.compare(old, new, "cfg", Value::to_string);
+
+        // Anything else that changed, like argument names, doesn't matter to
+        // the ABI.
+        changes.extend(diff.changes);
+    }
+
+    for &(ref kind, ref name) in new_items.keys() {
+        if !old_items.contains_key(&(kind.clone(), name.clone())) {
+            changes.push(AbiChange {
+                item: format!("{} {}", kind, name),
+                description: "added".to_owned(),
+                breaking: false,
+            });
+        }
+    }
+
+    changes
+}
+
+/// Returns the description in `old`, which has to be an object.
+pub(crate) fn parse(old: &str) -> Result<Value, String> {
+    match ::serde_json::from_str(old) {
+        Ok(value @ Value::Object(_)) => Ok(value),
+        Ok(_) => Err("The ABI description isn't a JSON object.".to_owned()),
+        Err(e) => Err(format!("Couldn't parse the ABI description: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use bindgen::{Builder, Language};
+
+    /// Returns the changes between the bindings of two sources.
+    fn changes(test: &str, old: &str, new: &str) -> Vec<String> {
+        let dir = env::temp_dir().join(format!(
+            "cbindgen-abi-diff-{}-{}",
+            test,
+            ::std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let describe = |src: &str, name: &str| {
+            let path = dir.join(name);
+            File::create(&path)
+                .unwrap()
+                .write_all(src.as_bytes())
+                .unwrap();
+            Builder::new()
+                .with_language(Language::Json)
+                .with_src(&path)
+                .generate()
+                .unwrap()
+                .abi_description()
+        };
+        let old = describe(old, "old.rs");
+        let new = describe(new, "new.rs");
+        fs::remove_dir_all(&dir).unwrap();
+        diff(&old, &new).iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn reports_reordered_fields() {
+        let old = "#[repr(C)] pub struct Foo { a: u32, b: u8 }
+                   #[no_mangle] pub extern \"C\" fn root(x: Foo) {}";
+        let new = "#[repr(C)] pub struct Foo { b: u8, a: u32 }
+                   #[no_mangle] pub extern \"C\" fn root(x: Foo) {}";
+        assert_eq!(
+            changes("fields", old, new),
+            [
+                "breaking: struct Foo: field `a` moved from 0 to 1",
+                "breaking: struct Foo: field `b` moved from 1 to 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_signature_changes() {
+        let old = "#[no_mangle] pub extern \"C\" fn root(x: u32) -> u8 { 0 }";
+        let new = "#[no_mangle] pub extern \"C\" fn root(y: u64) -> u8 { 0 }
+                   #[no_mangle] pub extern \"C\" fn other() {}";
+        assert_eq!(
+            changes("signature", old, new),
+            [
+                "breaking: function root: argument 1 changed from uint32_t to uint64_t",
+                "compatible: function other: added",
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_new_enum_variants() {
+        let old = "#[repr(u8)] pub enum E { A, B = 4 }
+                   #[no_mangle] pub extern \"C\" fn root(x: E) {}";
+        let new = "#[repr(u8)] pub enum E { A, B = 5, C }
+                   #[no_mangle] pub extern \"C\" fn root(x: E) {}";
+        assert_eq!(
+            changes("variants", old, new),
+            [
+                "breaking: enum E: value of variant `B` changed from 4 to 5",
+                "compatible: enum E: variant `C` added",
+            ]
+        );
+    }
+}
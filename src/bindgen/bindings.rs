@@ -2,16 +2,43 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path;
 
-use bindgen::config::{Config, Language};
+use serde_json::Value;
+
+extern crate tempfile;
+
+use bindgen::abidiff::{self, AbiChange};
+use bindgen::config::{
+    encode_api_version, Config, FieldOffsetStyle, Injection, Language, LanguageVersion,
+    SectionStyle,
+};
+use bindgen::cython::CythonWriter;
+use bindgen::depgraph::{DependencyGraph, DependencyGraphFormat};
+use bindgen::diagnostic::Diagnostic;
+use bindgen::dlang::DWriter;
+use bindgen::error::Error;
+use bindgen::golang::GoWriter;
+use bindgen::ir::layout::{DataLayout, StructLayout, StructLayouts, TypeLayout};
 use bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct,
+    AnnotationSet, CallingConvention, ConditionWrite, Constant, Function, Item, ItemContainer,
+    ItemMap, Path as BindgenPath, Static, StrongTypedef, Struct, ToCondition, Type,
 };
+use bindgen::java::JavaWriter;
+use bindgen::json::JsonWriter;
+use bindgen::node::NodeWriter;
+use bindgen::python::PythonWriter;
+use bindgen::rename::{IdentifierType, RenameRule};
+use bindgen::symbols::{self, Symbol, SymbolListFormat};
+use bindgen::template;
+use bindgen::verify;
+use bindgen::wasm::WasmWriter;
 use bindgen::writer::{Source, SourceWriter};
+use bindgen::zig::ZigWriter;
 
 /// A bindings header that can be written.
 pub struct Bindings {
@@ -19,12 +46,161 @@ pub struct Bindings {
     /// The map from path to struct, used to lookup whether a given type is a
     /// transparent struct. This is needed to generate code for constants.
     struct_map: ItemMap<Struct>,
-    globals: Vec<Static>,
-    constants: Vec<Constant>,
-    items: Vec<ItemContainer>,
-    functions: Vec<Function>,
+    pub(crate) globals: Vec<Static>,
+    pub(crate) constants: Vec<Constant>,
+    pub(crate) items: Vec<ItemContainer>,
+    pub(crate) functions: Vec<Function>,
+    /// The `#[inline]` functions written as `static inline` functions after
+    /// the last type they use.
+    pub(crate) inline_functions: Vec<Function>,
+    /// The modules that each module depends on, used to write the includes
+    /// between headers when `header_per_module` is set.
+    module_dependencies: BTreeMap<Option<String>, BTreeSet<Option<String>>>,
+    /// The secondary headers that each header depends on, `None` being the
+    /// main header, used to route items into secondary headers.
+    output_dependencies: BTreeMap<Option<String>, BTreeSet<Option<String>>>,
+    /// The headers to include before the include guard, which are the headers
+    /// a secondary header depends on.
+    dependency_includes: Vec<String>,
+    /// The headers to include at the end, inside the include guard, which are
+    /// the secondary headers the main header includes.
+    output_includes: Vec<String>,
+    /// The layouts of the structs to assert when `layout_asserts` is set.
+    struct_layouts: HashMap<BindgenPath, StructLayouts>,
+    /// The data layout of the target the bindings are generated for, if
+    /// there's a single one and it's known.
+    pub(crate) data_layout: Option<DataLayout>,
+    /// The items that each item uses directly.
+    dependency_graph: DependencyGraph,
+    /// The source files the bindings were generated from.
+    pub(crate) source_files: Vec<path::PathBuf>,
+    /// The diagnostics about the items, which are reported once the bindings
+    /// are generated.
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    /// Whether the bindings are written as a C++20 module interface unit
+    /// instead of a header.
+    pub(crate) module_interface: bool,
+    /// The bindings rendered with the template of their language, if it has
+    /// one.
+    rendered: Option<String>,
 }
 
+const INTEGER_TYPES: &[&str] = &[
+    "int8_t",
+    "int16_t",
+    "int32_t",
+    "int64_t",
+    "uint8_t",
+    "uint16_t",
+    "uint32_t",
+    "uint64_t",
+    "intptr_t",
+    "uintptr_t",
+];
+
+/// The C system headers, and the identifiers they declare that bindings can
+/// use, in the order they're included.
+const C_SYSTEM_HEADERS: &[(&str, &[&str])] = &[
+    ("stdarg.h", &["va_list"]),
+    ("stdbool.h", &["bool", "true", "false"]),
+    ("stdint.h", INTEGER_TYPES),
+    (
+        "stddef.h",
+        &["size_t", "ptrdiff_t", "wchar_t", "offsetof", "NULL"],
+    ),
+    ("sys/types.h", &["ssize_t"]),
+    ("uchar.h", &["char16_t", "char32_t"]),
+    ("math.h", &["NAN", "INFINITY"]),
+    ("assert.h", &["assert"]),
+];
+
+/// The C++ system headers, and the identifiers they declare that bindings can
+/// use, in the order they're included.
+const CXX_SYSTEM_HEADERS: &[(&str, &[&str])] = &[
+    ("cstdarg", &["va_list"]),
+    ("cstdint", INTEGER_TYPES),
+    ("cstddef", &["size_t", "ptrdiff_t", "offsetof", "NULL"]),
+    ("sys/types.h", &["ssize_t"]),
+    ("new", &["new"]),
+    ("atomic", &["atomic"]),
+    ("cmath", &["NAN", "INFINITY"]),
+    ("cassert", &["assert"]),
+];
+
+const INTEGER_DEFINITIONS: &[(&str, &str)] = &[
+    ("int8_t", "typedef __INT8_TYPE__ int8_t;"),
+    ("int16_t", "typedef __INT16_TYPE__ int16_t;"),
+    ("int32_t", "typedef __INT32_TYPE__ int32_t;"),
+    ("int64_t", "typedef __INT64_TYPE__ int64_t;"),
+    ("uint8_t", "typedef __UINT8_TYPE__ uint8_t;"),
+    ("uint16_t", "typedef __UINT16_TYPE__ uint16_t;"),
+    ("uint32_t", "typedef __UINT32_TYPE__ uint32_t;"),
+    ("uint64_t", "typedef __UINT64_TYPE__ uint64_t;"),
+    ("intptr_t", "typedef __INTPTR_TYPE__ intptr_t;"),
+    ("uintptr_t", "typedef __UINTPTR_TYPE__ uintptr_t;"),
+];
+
+/// The C definitions of the system types and macros that bindings can use,
+/// with the types and builtins predefined by GCC and Clang, grouped under the
+/// condition that the system header they come from isn't included. Headers
+/// compiled as C++ get the C++ definitions.
+const C_FREESTANDING_DEFINITIONS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "!defined(__cplusplus) && !defined(__bool_true_false_are_defined)",
+        &[
+            ("bool", "#define bool _Bool"),
+            ("true", "#define true 1"),
+            ("false", "#define false 0"),
+        ],
+    ),
+    ("!defined(INT8_MAX)", INTEGER_DEFINITIONS),
+    ("!defined(offsetof)", STDDEF_DEFINITIONS),
+    (
+        "!defined(__cplusplus) && !defined(WCHAR_MIN)",
+        &[("wchar_t", "typedef __WCHAR_TYPE__ wchar_t;")],
+    ),
+    ("!defined(NULL)", &[("NULL", "#define NULL 0")]),
+    (
+        "!defined(SSIZE_MAX)",
+        &[("ssize_t", "typedef __PTRDIFF_TYPE__ ssize_t;")],
+    ),
+    (
+        "!defined(va_start)",
+        &[("va_list", "typedef __builtin_va_list va_list;")],
+    ),
+    ("!defined(NAN)", MATH_DEFINITIONS),
+];
+
+/// The C++ definitions of the system types and macros that bindings can use,
+/// like `C_FREESTANDING_DEFINITIONS`.
+const CXX_FREESTANDING_DEFINITIONS: &[(&str, &[(&str, &str)])] = &[
+    ("!defined(INT8_MAX)", INTEGER_DEFINITIONS),
+    ("!defined(offsetof)", STDDEF_DEFINITIONS),
+    (
+        "!defined(SSIZE_MAX)",
+        &[("ssize_t", "typedef __PTRDIFF_TYPE__ ssize_t;")],
+    ),
+    (
+        "!defined(va_start)",
+        &[("va_list", "typedef __builtin_va_list va_list;")],
+    ),
+    ("!defined(NAN)", MATH_DEFINITIONS),
+];
+
+const STDDEF_DEFINITIONS: &[(&str, &str)] = &[
+    ("size_t", "typedef __SIZE_TYPE__ size_t;"),
+    ("ptrdiff_t", "typedef __PTRDIFF_TYPE__ ptrdiff_t;"),
+    (
+        "offsetof",
+        "#define offsetof(type, member) __builtin_offsetof(type, member)",
+    ),
+];
+
+const MATH_DEFINITIONS: &[(&str, &str)] = &[
+    ("NAN", "#define NAN __builtin_nanf(\"\")"),
+    ("INFINITY", "#define INFINITY __builtin_inff()"),
+];
+
 impl Bindings {
     pub(crate) fn new(
         config: Config,
@@ -33,6 +209,13 @@ impl Bindings {
         globals: Vec<Static>,
         items: Vec<ItemContainer>,
         functions: Vec<Function>,
+        inline_functions: Vec<Function>,
+        module_dependencies: BTreeMap<Option<String>, BTreeSet<Option<String>>>,
+        output_dependencies: BTreeMap<Option<String>, BTreeSet<Option<String>>>,
+        struct_layouts: HashMap<BindgenPath, StructLayouts>,
+        data_layout: Option<DataLayout>,
+        dependency_graph: DependencyGraph,
+        diagnostics: Vec<Diagnostic>,
     ) -> Bindings {
         Bindings {
             config,
@@ -41,9 +224,35 @@ impl Bindings {
             constants,
             items,
             functions,
+            inline_functions,
+            module_dependencies,
+            output_dependencies,
+            dependency_includes: Vec::new(),
+            output_includes: Vec::new(),
+            struct_layouts,
+            data_layout,
+            dependency_graph,
+            source_files: Vec::new(),
+            diagnostics,
+            module_interface: false,
+            rendered: None,
         }
     }
 
+    /// Renders the bindings with the template of their language, which
+    /// they're written as from then on.
+    pub(crate) fn render_template(&mut self) -> Result<(), Error> {
+        let template = match self.config.template.files.get(&self.config.language) {
+            Some(template) => template.clone(),
+            None => return Ok(()),
+        };
+        let mut default = Vec::new();
+        self.write(&mut default);
+        let default = String::from_utf8(default).unwrap();
+        self.rendered = Some(template::render(self, &template, default).map_err(Error::Template)?);
+        Ok(())
+    }
+
     // FIXME(emilio): What to do when the configuration doesn't match?
     pub fn struct_is_transparent(&self, path: &BindgenPath) -> bool {
         let mut any = false;
@@ -51,6 +260,20 @@ impl Bindings {
         any
     }
 
+    /// How the typedef or transparent struct at `path` is written as a strong
+    /// typedef in C++, if it's one.
+    pub fn strong_typedef(&self, path: &BindgenPath) -> Option<StrongTypedef> {
+        self.items.iter().find_map(|item| match *item {
+            ItemContainer::Typedef(ref x) if x.path() == path => {
+                StrongTypedef::of(&x.annotations, &x.generic_params, &x.aliased)
+            }
+            ItemContainer::Struct(ref x) if x.is_transparent && x.path() == path => {
+                StrongTypedef::of(&x.annotations, &x.generic_params, &x.fields[0].1)
+            }
+            _ => None,
+        })
+    }
+
     pub fn struct_exists(&self, path: &BindgenPath) -> bool {
         let mut any = false;
         self.struct_map.for_items(path, |_| any = true);
@@ -58,31 +281,481 @@ impl Bindings {
     }
 
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
-        // Don't compare files if we've never written this file before
-        if !path.as_ref().is_file() {
-            if let Some(parent) = path::Path::new(path.as_ref()).parent() {
-                fs::create_dir_all(parent).unwrap();
+        let path = path.as_ref();
+        if self.rendered.is_some() {
+            return self.write_to_single_file(path);
+        }
+        match self.module_interface_path(path) {
+            Some(ref module_path) if module_path == path => {
+                self.module_interface_bindings().write_to_single_file(path)
+            }
+            Some(ref module_path) => {
+                let changed = self.write_headers_to_file(path);
+                self.module_interface_bindings()
+                    .write_to_single_file(module_path)
+                    || changed
             }
-            self.write(File::create(path).unwrap());
-            return true;
+            None => self.write_headers_to_file(path),
         }
+    }
 
-        let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
+    fn write_headers_to_file(&self, path: &path::Path) -> bool {
+        if self.has_outputs() {
+            let mut changed = false;
+            for output in self.output_dependencies.keys() {
+                changed |= self
+                    .output_bindings(output, path)
+                    .write_to_single_file(output_header_path(path, output));
+            }
+            return changed;
+        }
 
-        let mut old_file_contents = Vec::new();
+        self.write_modules_to_file(path)
+    }
+
+    /// Returns the path to write the module interface unit to with
+    /// `module_interface`, which is `path` itself if it names one.
+    fn module_interface_path(&self, path: &path::Path) -> Option<path::PathBuf> {
+        if self.config.language != Language::Cxx || self.config.module_interface.is_none() {
+            return None;
+        }
+        match path.extension().and_then(|x| x.to_str()) {
+            Some("cppm") | Some("ixx") => Some(path.to_owned()),
+            _ => Some(path.with_extension("cppm")),
+        }
+    }
+
+    /// Returns the bindings for the module interface unit, which exports all
+    /// the items.
+    fn module_interface_bindings(&self) -> Bindings {
+        let mut config = self.config.clone();
+        config.include_guard = None;
+        let mut bindings = Bindings::new(
+            config,
+            self.struct_map.clone(),
+            self.constants.clone(),
+            self.globals.clone(),
+            self.items.clone(),
+            self.functions.clone(),
+            self.inline_functions.clone(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            self.struct_layouts.clone(),
+            self.data_layout,
+            self.dependency_graph.clone(),
+            Vec::new(),
+        );
+        bindings.module_interface = true;
+        bindings
+    }
+
+    fn write_modules_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        if !self.config.header_per_module
+            || (self.config.language != Language::C && self.config.language != Language::Cxx)
         {
-            let mut old_file = File::open(&path).unwrap();
-            old_file.read_to_end(&mut old_file_contents).unwrap();
+            return self.write_to_single_file(path);
+        }
+
+        let path = path.as_ref();
+        let mut changed = self.module_bindings(&None, path).write_to_single_file(path);
+        for module in self.module_dependencies.keys().filter(|x| x.is_some()) {
+            changed |= self
+                .module_bindings(module, path)
+                .write_to_single_file(module_header_path(path, module));
+        }
+        changed
+    }
+
+    /// Returns the bindings for the items of `module`, which include the
+    /// headers of the modules they depend on.
+    fn module_bindings(&self, module: &Option<String>, path: &path::Path) -> Bindings {
+        let mut config = self.config.clone();
+        if let Some(ref module) = *module {
+            if let Some(ref mut include_guard) = config.include_guard {
+                include_guard.push('_');
+                include_guard.push_str(&module.to_uppercase());
+            }
+        }
+        if let Some(dependencies) = self.module_dependencies.get(module) {
+            for dependency in dependencies {
+                let header = module_header_path(path, dependency);
+                let name = header.file_name().unwrap().to_string_lossy();
+                config.includes.push(name.into_owned());
+            }
+        }
+
+        let in_module = |annotations: &AnnotationSet| annotations.module == *module;
+        Bindings::new(
+            config,
+            self.struct_map.clone(),
+            self.constants
+                .iter()
+                .filter(|x| in_module(&x.annotations))
+                .cloned()
+                .collect(),
+            self.globals
+                .iter()
+                .filter(|x| in_module(&x.annotations))
+                .cloned()
+                .collect(),
+            self.items
+                .iter()
+                .filter(|x| in_module(x.deref().annotations()))
+                .cloned()
+                .collect(),
+            self.functions
+                .iter()
+                .filter(|x| in_module(&x.annotations))
+                .cloned()
+                .collect(),
+            self.inline_functions
+                .iter()
+                .filter(|x| in_module(&x.annotations))
+                .cloned()
+                .collect(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            self.struct_layouts.clone(),
+            self.data_layout,
+            self.dependency_graph.clone(),
+            Vec::new(),
+        )
+    }
+
+    /// Whether some constants are non-finite floats, which need `<math.h>`.
+    fn uses_math(&self) -> bool {
+        self.constants.iter().any(|c| c.value.uses_math())
+            || self.items.iter().any(|item| match *item {
+                ItemContainer::Struct(ref s) => {
+                    s.associated_constants.iter().any(|c| c.value.uses_math())
+                }
+                _ => false,
+            })
+    }
+
+    /// Whether the items use `std::atomic`.
+    fn uses_atomics(&self) -> bool {
+        self.config.has_atomics() && self.used_identifiers().contains("atomic")
+    }
+
+    /// Whether some items are routed into secondary headers.
+    fn has_outputs(&self) -> bool {
+        self.output_dependencies.keys().any(|x| x.is_some())
+    }
+
+    /// Returns the bindings for the items routed to `output`, which include
+    /// the headers they depend on before their include guard, so that the main
+    /// header can include the secondary ones after its own items with
+    /// `output.include_in_main`.
+    fn output_bindings(&self, output: &Option<String>, path: &path::Path) -> Bindings {
+        let mut config = self.config.clone();
+        let include_name = |output: &Option<String>| {
+            let header = output_header_path(path, output);
+            header.file_name().unwrap().to_string_lossy().into_owned()
+        };
+        if let Some(ref output) = *output {
+            if let Some(ref mut include_guard) = config.include_guard {
+                let stem = path::Path::new(output).file_stem().unwrap();
+                include_guard.push('_');
+                include_guard.extend(stem.to_string_lossy().chars().map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                }));
+            }
+        }
+
+        let self_config = &self.config;
+        let in_output = |name: &str, annotations: &AnnotationSet| {
+            self_config.output_of(name, annotations) == *output
+        };
+        let mut bindings = Bindings::new(
+            config,
+            self.struct_map.clone(),
+            self.constants
+                .iter()
+                .filter(|x| in_output(x.path.name(), &x.annotations))
+                .cloned()
+                .collect(),
+            self.globals
+                .iter()
+                .filter(|x| in_output(x.path.name(), &x.annotations))
+                .cloned()
+                .collect(),
+            self.items
+                .iter()
+                .filter(|x| in_output(x.deref().path().name(), x.deref().annotations()))
+                .cloned()
+                .collect(),
+            self.functions
+                .iter()
+                .filter(|x| in_output(x.path.name(), &x.annotations))
+                .cloned()
+                .collect(),
+            self.inline_functions
+                .iter()
+                .filter(|x| in_output(x.path.name(), &x.annotations))
+                .cloned()
+                .collect(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            self.struct_layouts.clone(),
+            self.data_layout,
+            self.dependency_graph.clone(),
+            Vec::new(),
+        );
+        if let Some(dependencies) = self.output_dependencies.get(output) {
+            bindings.dependency_includes = dependencies.iter().map(&include_name).collect();
+        }
+        if output.is_none() && self.config.output.include_in_main {
+            if self.config.include_guard.is_none() {
+                warn!("The main header can only include the secondary ones with an include guard.");
+                return bindings;
+            }
+            bindings.output_includes = self
+                .output_dependencies
+                .keys()
+                .filter(|x| x.is_some())
+                .map(&include_name)
+                .collect();
+        }
+        bindings
+    }
+
+    /// Writes the dependency graph of the bindings to `path`, returning
+    /// whether it changed.
+    pub fn write_dependency_graph<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        format: DependencyGraphFormat,
+    ) -> bool {
+        write_if_changed(path.as_ref(), |file| {
+            self.dependency_graph.write(format, file).unwrap();
+        })
+    }
+
+    /// The functions and globals the bindings declare, which the library
+    /// exports, sorted by name.
+    pub fn symbols(&self) -> Vec<Symbol> {
+        let functions = self
+            .functions
+            .iter()
+            .filter(|x| !x.extern_decl)
+            .map(|x| Symbol {
+                name: x.path().name().to_owned(),
+                data: false,
+            });
+        let globals = self.globals.iter().map(|x| Symbol {
+            name: x.export_name().to_owned(),
+            data: true,
+        });
+        let mut symbols: Vec<_> = functions.chain(globals).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Writes the list of symbols the bindings declare to `path`, for the
+    /// linker to export, returning whether it changed.
+    pub fn write_symbol_list<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        format: SymbolListFormat,
+    ) -> bool {
+        let symbols = self.symbols();
+        write_if_changed(path.as_ref(), |file| {
+            symbols::write_symbol_list(&symbols, format, file).unwrap();
+        })
+    }
+
+    /// The source files, config and injected files the bindings were
+    /// generated from.
+    pub fn input_files(&self) -> Vec<&path::Path> {
+        let mut files: Vec<_> = self.source_files.iter().map(|x| x.as_path()).collect();
+        files.extend(self.config.config_path.as_ref().map(|x| x.as_path()));
+        files.extend(self.config.inject.files());
+        files.extend(
+            self.config
+                .template
+                .files
+                .get(&self.config.language)
+                .map(|x| x.as_path()),
+        );
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// The headers written when the bindings are written to `path`, which
+    /// are a header per module with `header_per_module`.
+    fn output_paths(&self, path: &path::Path) -> Vec<path::PathBuf> {
+        let module_path = self.module_interface_path(path);
+        if module_path.as_ref().map_or(false, |x| x == path) {
+            return vec![path.to_owned()];
         }
 
-        if old_file_contents != new_file_contents {
-            let mut new_file = File::create(&path).unwrap();
-            new_file.write_all(&new_file_contents).unwrap();
-            true
+        let mut paths = if self.has_outputs() {
+            self.output_dependencies
+                .keys()
+                .map(|x| output_header_path(path, x))
+                .collect()
         } else {
-            false
+            vec![path.to_owned()]
+        };
+        if !self.has_outputs()
+            && self.config.header_per_module
+            && (self.config.language == Language::C || self.config.language == Language::Cxx)
+        {
+            for module in self.module_dependencies.keys().filter(|x| x.is_some()) {
+                paths.push(module_header_path(path, module));
+            }
+        }
+        paths.extend(module_path);
+        paths
+    }
+
+    /// Compares the bindings with the files they were written to at `path`,
+    /// by their declarations, or by their bytes if `strict`, returning how the
+    /// first file that differs does.
+    pub fn verify<P: AsRef<path::Path>>(&self, path: P, strict: bool) -> Result<(), String> {
+        let path = path.as_ref();
+        let dir = self::tempfile::tempdir().map_err(|e| e.to_string())?;
+        let file_name = match path.file_name() {
+            Some(file_name) => file_name,
+            None => return Err(format!("{} isn't a file.", path.display())),
+        };
+        let generated = dir.path().join(file_name);
+        self.write_to_file(&generated);
+
+        for generated in self.output_paths(&generated) {
+            let existing = path.with_file_name(generated.strip_prefix(dir.path()).unwrap());
+            let old = fs::read_to_string(&existing)
+                .map_err(|e| format!("Couldn't read {}: {}", existing.display(), e))?;
+            let new = fs::read_to_string(&generated).unwrap();
+            if strict {
+                if old != new {
+                    return Err(format!("{} differs from the bindings.", existing.display()));
+                }
+            } else if let Some((old, new)) =
+                verify::first_difference(self.config.language, &old, &new)
+            {
+                return Err(format!(
+                    "{} differs from the bindings, which have `{}` instead of `{}`.",
+                    existing.display(),
+                    new,
+                    old
+                ));
+            }
         }
+        Ok(())
+    }
+
+    /// Writes a Makefile rule to `depfile` making the headers written to
+    /// `path` depend on the source files and config they were generated from,
+    /// returning whether it changed.
+    pub fn write_depfile<P: AsRef<path::Path>, Q: AsRef<path::Path>>(
+        &self,
+        depfile: P,
+        path: Q,
+    ) -> bool {
+        let targets = self.output_paths(path.as_ref());
+        let sources = self.input_files();
+        write_if_changed(depfile.as_ref(), |file| {
+            let targets: Vec<_> = targets.iter().map(|x| escape_make_path(x)).collect();
+            write!(file, "{}:", targets.join(" ")).unwrap();
+            for source in sources {
+                write!(file, " \\\n  {}", escape_make_path(source)).unwrap();
+            }
+            writeln!(file).unwrap();
+        })
+    }
+
+    /// Returns the JSON description of the ABI that `--lang json` writes.
+    pub(crate) fn abi_description(&self) -> Value {
+        JsonWriter::new(self).value()
+    }
+
+    /// Compares the ABI of the bindings to the one described by the file
+    /// `old`, written by `--lang json`, returning the changes.
+    pub fn abi_changes<P: AsRef<path::Path>>(&self, old: P) -> Result<Vec<AbiChange>, String> {
+        let old = old.as_ref();
+        let old = fs::read_to_string(old)
+            .map_err(|e| format!("Couldn't read {}: {}", old.display(), e))?;
+        Ok(abidiff::diff(
+            &abidiff::parse(&old)?,
+            &self.abi_description(),
+        ))
+    }
+
+    /// Writes a Kotlin/Native cinterop definition file to `def` for the
+    /// headers written to `path`, returning whether it changed.
+    pub fn write_kotlin_def<P: AsRef<path::Path>, Q: AsRef<path::Path>>(
+        &self,
+        def: P,
+        path: Q,
+    ) -> bool {
+        if self.config.language != Language::C {
+            warn!("Kotlin/Native cinterop only reads C headers.");
+        }
+
+        let headers: Vec<_> = self
+            .output_paths(path.as_ref())
+            .iter()
+            .filter_map(|x| x.file_name())
+            .map(|x| x.to_string_lossy().into_owned())
+            .collect();
+        let strict_enums: Vec<_> = self
+            .items
+            .iter()
+            .filter_map(|x| match *x {
+                ItemContainer::Enum(ref x) if x.tag.is_none() => Some(x.export_name()),
+                _ => None,
+            })
+            .collect();
+
+        let kotlin = &self.config.kotlin;
+        write_if_changed(def.as_ref(), |file| {
+            writeln!(file, "headers = {}", headers.join(" ")).unwrap();
+            writeln!(file, "headerFilter = {}", headers.join(" ")).unwrap();
+            if let Some(ref package) = kotlin.package {
+                writeln!(file, "package = {}", package).unwrap();
+            }
+            if !kotlin.compiler_opts.is_empty() {
+                writeln!(file, "compilerOpts = {}", kotlin.compiler_opts.join(" ")).unwrap();
+            }
+            if !kotlin.linker_opts.is_empty() {
+                writeln!(file, "linkerOpts = {}", kotlin.linker_opts.join(" ")).unwrap();
+            }
+            if !strict_enums.is_empty() {
+                writeln!(file, "strictEnums = {}", strict_enums.join(" ")).unwrap();
+            }
+        })
+    }
+
+    fn write_to_single_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        let mut changed = write_if_changed(path.as_ref(), |file| self.write(file));
+        if self.config.language == Language::Node {
+            // The TypeScript declarations of the module go next to it.
+            let declarations = path.as_ref().with_extension("d.ts");
+            changed |= write_if_changed(&declarations, |file| {
+                NodeWriter::new(self).write_declarations(&mut SourceWriter::new(file, self))
+            });
+        }
+        if self.config.language == Language::Wasm {
+            // TypeScript looks for the declarations of `.mjs` files in
+            // `.d.mts` files.
+            let extension = match path.as_ref().extension() {
+                Some(extension) if extension == "mjs" => "d.mts",
+                _ => "d.ts",
+            };
+            let declarations = path.as_ref().with_extension(extension);
+            changed |= write_if_changed(&declarations, |file| {
+                WasmWriter::new(self).write_declarations(&mut SourceWriter::new(file, self))
+            });
+        }
+        changed
     }
 
     pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
@@ -91,6 +764,13 @@ impl Bindings {
             write!(out, "{}", f);
             out.new_line();
         }
+        if !self.dependency_includes.is_empty() {
+            out.new_line_if_not_start();
+            for include in &self.dependency_includes {
+                write!(out, "#include \"{}\"", include);
+                out.new_line();
+            }
+        }
         if let Some(ref f) = self.config.include_guard {
             out.new_line_if_not_start();
             write!(out, "#ifndef {}", f);
@@ -112,9 +792,22 @@ impl Bindings {
             write!(out, "{}", f);
             out.new_line();
         }
+        if self.module_interface {
+            // The includes go in the global module fragment.
+            out.new_line_if_not_start();
+            out.write("module;");
+            out.new_line();
+        }
 
         out.new_line_if_not_start();
-        if !self.config.no_includes {
+        if !self.config.no_includes && self.config.no_std_headers {
+            self.write_freestanding_definitions(out);
+        } else if !self.config.no_includes && self.config.infer_includes {
+            for include in self.inferred_includes() {
+                write!(out, "#include <{}>", include);
+                out.new_line();
+            }
+        } else if !self.config.no_includes {
             if self.config.language == Language::C {
                 out.write("#include <stdarg.h>");
                 out.new_line();
@@ -124,15 +817,35 @@ impl Bindings {
                 out.new_line();
                 out.write("#include <stdlib.h>");
                 out.new_line();
+                if self.config.layout_asserts {
+                    out.write("#include <stddef.h>");
+                    out.new_line();
+                }
+                if self.uses_math() {
+                    out.write("#include <math.h>");
+                    out.new_line();
+                }
             } else {
                 out.write("#include <cstdarg>");
                 out.new_line();
-                out.write("#include <cstdint>");
+                write!(out, "#include <{}>", self.cxx_header("cstdint"));
                 out.new_line();
                 out.write("#include <cstdlib>");
                 out.new_line();
                 out.write("#include <new>");
                 out.new_line();
+                if self.uses_atomics() {
+                    out.write("#include <atomic>");
+                    out.new_line();
+                }
+                if self.config.layout_asserts {
+                    out.write("#include <cstddef>");
+                    out.new_line();
+                }
+                if self.uses_math() {
+                    out.write("#include <cmath>");
+                    out.new_line();
+                }
                 if self.config.enumeration.cast_assert_name.is_none()
                     && (self.config.enumeration.derive_mut_casts
                         || self.config.enumeration.derive_const_casts)
@@ -152,30 +865,642 @@ impl Bindings {
             write!(out, "#include \"{}\"", include);
             out.new_line();
         }
+
+        self.write_injection(out, &self.config.inject.after_includes);
     }
 
-    pub fn write<F: Write>(&self, file: F) {
+    /// Writes the code injected at a point of the bindings.
+    fn write_injection<F: Write>(&self, out: &mut SourceWriter<F>, injection: &Option<Injection>) {
+        if let Some(ref injection) = *injection {
+            out.new_line_if_not_start();
+            write!(out, "{}", injection.text());
+            out.new_line();
+        }
+    }
+
+    /// Writes the snippet that the `before` or `after` annotation of an item
+    /// names.
+    fn write_snippet<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        annotations: &AnnotationSet,
+        position: &str,
+    ) {
+        if let Some(snippet) = self.config.inject.snippet(annotations, position) {
+            out.new_line_if_not_start();
+            write!(out, "{}", snippet);
+            out.new_line();
+        }
+    }
+
+    /// Defines the C++ member functions of the items that derive methods or
+    /// operators, which need the functions they call to be declared first.
+    fn write_member_definitions<F: Write>(&self, out: &mut SourceWriter<F>) {
+        for item in &self.items {
+            let (class, methods, operators) = match *item {
+                ItemContainer::Struct(ref x) => (x.export_name(), &x.methods, &x.operators),
+                ItemContainer::OpaqueItem(ref x) => (x.export_name(), &x.methods, &x.operators),
+                _ => continue,
+            };
+            for method in methods {
+                if let Some(member) = method.member() {
+                    out.new_line_if_not_start();
+                    method.write_member_definition(class, &member, false, &self.config, out);
+                    out.new_line();
+                }
+            }
+            for &(ref operator, ref function) in operators {
+                out.new_line_if_not_start();
+                function.write_member_definition(class, operator, true, &self.config, out);
+                out.new_line();
+            }
+        }
+    }
+
+    /// Defines `export.dll_export_macro`, unless it's defined already.
+    fn write_dll_export_macro<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let name = match self.config.export.dll_export_macro {
+            Some(ref name) => name,
+            None => return,
+        };
+        let (build, static_) = self.config.export.dll_defines().unwrap();
+
+        out.new_line_if_not_start();
+        write!(out, "#ifndef {}", name);
+        out.new_line();
+        write!(out, "#  if defined({})", static_);
+        out.new_line();
+        write!(out, "#    define {}", name);
+        out.new_line();
+        out.write("#  elif defined(_WIN32) || defined(__CYGWIN__)");
+        out.new_line();
+        write!(out, "#    if defined({})", build);
+        out.new_line();
+        write!(out, "#      define {} __declspec(dllexport)", name);
+        out.new_line();
+        out.write("#    else");
+        out.new_line();
+        write!(out, "#      define {} __declspec(dllimport)", name);
+        out.new_line();
+        out.write("#    endif");
+        out.new_line();
+        out.write("#  elif defined(__GNUC__) && __GNUC__ >= 4");
+        out.new_line();
+        write!(
+            out,
+            "#    define {} __attribute__((visibility(\"default\")))",
+            name
+        );
+        out.new_line();
+        out.write("#  else");
+        out.new_line();
+        write!(out, "#    define {}", name);
+        out.new_line();
+        out.write("#  endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Returns the calling conventions other than `C` of the functions and
+    /// function pointers of the bindings.
+    pub(crate) fn calling_conventions(&self) -> BTreeSet<CallingConvention> {
+        let mut types = Vec::new();
+        for item in &self.items {
+            match *item {
+                ItemContainer::Struct(ref s) => types.extend(s.fields.iter().map(|x| &x.1)),
+                ItemContainer::Union(ref u) => types.extend(u.fields.iter().map(|x| &x.1)),
+                ItemContainer::Enum(ref e) => {
+                    for variant in &e.variants {
+                        if let Some((_, ref body)) = variant.body {
+                            types.extend(body.fields.iter().map(|x| &x.1));
+                        }
+                    }
+                }
+                ItemContainer::Typedef(ref t) => types.push(&t.aliased),
+                _ => {}
+            }
+        }
+        types.extend(self.globals.iter().map(|x| &x.ty));
+
+        let mut conventions = BTreeSet::new();
+        for function in &self.functions {
+            conventions.insert(function.calling_convention);
+            types.push(&function.ret);
+            types.extend(function.args.iter().map(|x| &x.1));
+        }
+        while let Some(ty) = types.pop() {
+            match *ty {
+                Type::ConstPtr(ref ty, _)
+                | Type::Ptr(ref ty, _)
+                | Type::Ref(ref ty)
+                | Type::MutRef(ref ty)
+                | Type::Array(ref ty, _) => types.push(ty),
+                Type::FuncPtr(ref ret, ref args, _, calling_convention, _) => {
+                    conventions.insert(calling_convention);
+                    types.push(ret);
+                    types.extend(args.iter().map(|x| &x.1));
+                }
+                _ => {}
+            }
+        }
+        conventions.remove(&CallingConvention::C);
+        conventions
+    }
+
+    /// Writes the definitions of the macros giving functions their calling
+    /// convention, which only has an effect on 32-bit x86.
+    fn write_calling_convention_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let conventions = self.calling_conventions();
+        let mut macros = Vec::new();
+        if conventions.contains(&CallingConvention::Stdcall)
+            || conventions.contains(&CallingConvention::System)
+        {
+            macros.push(("CBINDGEN_STDCALL", "__stdcall", "stdcall"));
+        }
+        if conventions.contains(&CallingConvention::Fastcall) {
+            macros.push(("CBINDGEN_FASTCALL", "__fastcall", "fastcall"));
+        }
+        for (name, keyword, attribute) in macros {
+            out.new_line_if_not_start();
+            write!(out, "#ifndef {}", name);
+            out.new_line();
+            out.write("#  if defined(_MSC_VER)");
+            out.new_line();
+            write!(out, "#    define {} {}", name, keyword);
+            out.new_line();
+            out.write("#  elif defined(__i386__)");
+            out.new_line();
+            write!(out, "#    define {} __attribute__(({}))", name, attribute);
+            out.new_line();
+            out.write("#  else");
+            out.new_line();
+            write!(out, "#    define {}", name);
+            out.new_line();
+            out.write("#  endif");
+            out.new_line();
+            out.write("#endif");
+            out.new_line();
+        }
+        if conventions.contains(&CallingConvention::System) {
+            out.new_line_if_not_start();
+            out.write("#ifndef CBINDGEN_SYSTEM");
+            out.new_line();
+            out.write("#  if defined(_WIN32)");
+            out.new_line();
+            out.write("#    define CBINDGEN_SYSTEM CBINDGEN_STDCALL");
+            out.new_line();
+            out.write("#  else");
+            out.new_line();
+            out.write("#    define CBINDGEN_SYSTEM");
+            out.new_line();
+            out.write("#  endif");
+            out.new_line();
+            out.write("#endif");
+            out.new_line();
+        }
+    }
+
+    /// Writes the definition of the macro marking functions returning `!` in
+    /// C, with the attribute of the standard or compiler.
+    fn write_no_return_macro<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let uses_macro = self
+            .config
+            .function_no_return()
+            .map_or(false, |x| x == "CBINDGEN_NORETURN");
+        if self.config.function.no_return.is_some()
+            || !uses_macro
+            || !self.functions.iter().any(|x| x.no_return)
+        {
+            return;
+        }
+        let definitions = [
+            ("#  if defined(__cplusplus)", "[[noreturn]]"),
+            (
+                "#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L",
+                "[[noreturn]]",
+            ),
+            (
+                "#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L",
+                "_Noreturn",
+            ),
+            ("#  elif defined(__GNUC__)", "__attribute__((noreturn))"),
+            ("#  elif defined(_MSC_VER)", "__declspec(noreturn)"),
+        ];
+        out.new_line_if_not_start();
+        out.write("#ifndef CBINDGEN_NORETURN");
+        out.new_line();
+        for &(condition, attribute) in &definitions {
+            out.write(condition);
+            out.new_line();
+            write!(out, "#    define CBINDGEN_NORETURN {}", attribute);
+            out.new_line();
+        }
+        out.write("#  else");
+        out.new_line();
+        out.write("#    define CBINDGEN_NORETURN");
+        out.new_line();
+        out.write("#  endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Defines the nullability qualifiers away for compilers other than clang,
+    /// and `NS_ASSUME_NONNULL_BEGIN` and `NS_ASSUME_NONNULL_END` without
+    /// Foundation, for `objc_compat`.
+    fn write_objc_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if !self.config.objc_compat() {
+            return;
+        }
+        let lines = [
+            "#if !defined(__clang__)",
+            "#  define _Nonnull",
+            "#  define _Nullable",
+            "#endif",
+            "#ifndef NS_ASSUME_NONNULL_BEGIN",
+            "#  if defined(__clang__)",
+            "#    define NS_ASSUME_NONNULL_BEGIN _Pragma(\"clang assume_nonnull begin\")",
+            "#    define NS_ASSUME_NONNULL_END _Pragma(\"clang assume_nonnull end\")",
+            "#  else",
+            "#    define NS_ASSUME_NONNULL_BEGIN",
+            "#    define NS_ASSUME_NONNULL_END",
+            "#  endif",
+            "#endif",
+        ];
+        out.new_line_if_not_start();
+        for line in &lines {
+            out.write(line);
+            out.new_line();
+        }
+    }
+
+    /// Defines the latest version of the API, and the version to compile
+    /// against as it unless it's defined already.
+    fn write_api_version_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let api_version = &self.config.api_version;
+        let define = match api_version.define {
+            Some(ref define) => define,
+            None => return,
+        };
+        let latest = match api_version.current {
+            Some(ref current) => encode_api_version(current),
+            None => {
+                let annotations = self
+                    .constants
+                    .iter()
+                    .map(|x| x.annotations())
+                    .chain(self.items.iter().map(|x| x.deref().annotations()))
+                    .chain(self.globals.iter().map(|x| x.annotations()))
+                    .chain(self.functions.iter().map(|x| &x.annotations));
+                annotations
+                    .filter_map(|x| match x.atom("since") {
+                        Some(Some(since)) => encode_api_version(&since),
+                        _ => None,
+                    })
+                    .max()
+            }
+        };
+        let latest = match latest {
+            Some(latest) => latest,
+            None => return,
+        };
+
+        out.new_line_if_not_start();
+        write!(out, "#define {}_LATEST {}", define, latest);
+        out.new_line();
+        write!(out, "#ifndef {}", define);
+        out.new_line();
+        write!(out, "#  define {} {}_LATEST", define, define);
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Writes the `static inline` functions whose last type used is the item at
+    /// `index`, or that use none of the items when it's `None`.
+    fn write_inline_functions<F: Write>(&self, out: &mut SourceWriter<F>, index: Option<usize>) {
+        for function in &self.inline_functions {
+            let last_used = self.items.iter().rposition(|item| {
+                let path = item.deref().path();
+                !item
+                    .deref()
+                    .annotations()
+                    .bool("no-export")
+                    .unwrap_or(false)
+                    && (function.ret.mentions(path)
+                        || function.args.iter().any(|x| x.1.mentions(path)))
+            });
+            if last_used != index {
+                continue;
+            }
+            out.new_line_if_not_start();
+            let guarded = self.open_api_version_guard(out, &function.annotations);
+            function.write_inline_definition(&self.config, out);
+            out.new_line();
+            self.close_api_version_guard(out, guarded);
+        }
+    }
+
+    /// Opens the `#if` guarding an item with the `since` annotation, returning
+    /// whether it did.
+    fn open_api_version_guard<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        annotations: &AnnotationSet,
+    ) -> bool {
+        match self.config.api_version.guard(annotations) {
+            Some(guard) => {
+                write!(out, "#if {}", guard);
+                out.new_line();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn close_api_version_guard<F: Write>(&self, out: &mut SourceWriter<F>, opened: bool) {
+        if opened {
+            out.write("#endif");
+            out.new_line();
+        }
+    }
+
+    /// Defines the thread safety macros that the bindings use, unless they're
+    /// defined already, as Clang's attributes and as nothing for other
+    /// compilers.
+    fn write_thread_safety_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let guarded = self.items.iter().any(|x| match *x {
+            ItemContainer::Struct(ref x) => x.guarded_by.is_some(),
+            _ => false,
+        });
+        let requires = self.functions.iter().any(|x| x.required_locks().is_some());
+
+        let thread_safety = &self.config.thread_safety;
+        let macros = [
+            (guarded, &thread_safety.guarded_by, "x", "guarded_by(x)"),
+            (
+                requires,
+                &thread_safety.requires,
+                "...",
+                "requires_capability(__VA_ARGS__)",
+            ),
+        ];
+        for &(used, name, params, attribute) in &macros {
+            if !used {
+                continue;
+            }
+            out.new_line_if_not_start();
+            write!(out, "#ifndef {}", name);
+            out.new_line();
+            out.write("#  if defined(__clang__)");
+            out.new_line();
+            write!(
+                out,
+                "#    define {}({}) __attribute__(({}))",
+                name, params, attribute
+            );
+            out.new_line();
+            out.write("#  else");
+            out.new_line();
+            write!(out, "#    define {}({})", name, params);
+            out.new_line();
+            out.write("#  endif");
+            out.new_line();
+            out.write("#endif");
+            out.new_line();
+        }
+    }
+
+    pub fn write<F: Write>(&self, mut file: F) {
+        if let Some(ref rendered) = self.rendered {
+            file.write_all(rendered.as_bytes()).unwrap();
+            return;
+        }
+        if self.config.language == Language::Json {
+            JsonWriter::new(self).write(file).unwrap();
+            return;
+        }
+
         let mut out = SourceWriter::new(file, self);
 
+        if self.config.language == Language::Python {
+            PythonWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Cython {
+            CythonWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Java {
+            JavaWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Node {
+            NodeWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::D {
+            DWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Zig {
+            ZigWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Go {
+            GoWriter::new(self).write(&mut out);
+            return;
+        }
+        if self.config.language == Language::Wasm {
+            WasmWriter::new(self).write(&mut out);
+            return;
+        }
+
         if !self.config.no_includes
             || !self.config.includes.is_empty()
             || !self.config.sys_includes.is_empty()
+            || !self.dependency_includes.is_empty()
         {
             self.write_headers(&mut out);
         }
 
+        if self.module_interface {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "export module {};",
+                self.config.module_interface.as_ref().unwrap()
+            );
+            out.new_line();
+            out.new_line();
+            out.write("export {");
+            out.new_line();
+        }
+
+        self.write_body(&mut out);
+
+        if self.module_interface {
+            out.new_line_if_not_start();
+            out.write("} // export");
+            out.new_line();
+        }
+
+        if !self.output_includes.is_empty() {
+            out.new_line_if_not_start();
+            for include in &self.output_includes {
+                write!(out, "#include \"{}\"", include);
+                out.new_line();
+            }
+        }
+
+        if let Some(ref f) = self.config.include_guard {
+            out.new_line_if_not_start();
+            if self.config.language == Language::C {
+                write!(out, "#endif /* {} */", f);
+            } else {
+                write!(out, "#endif // {}", f);
+            }
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    /// Returns the identifiers that the items of the bindings use.
+    fn used_identifiers(&self) -> HashSet<String> {
+        let mut body = Vec::new();
+        self.write_body(&mut SourceWriter::new(&mut body, self));
+        String::from_utf8_lossy(&body)
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .map(|x| x.to_owned())
+            .collect()
+    }
+
+    /// Writes the definitions of the system types and macros that the items
+    /// group guarded by the header it comes from not being included.
+    /// group guarded by a macro of the header it comes from.
+    fn write_freestanding_definitions<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let used = self.used_identifiers();
+        let (groups, headers) = if self.config.language == Language::C {
+            (C_FREESTANDING_DEFINITIONS, &["assert.h"][..])
+        } else {
+            (
+                CXX_FREESTANDING_DEFINITIONS,
+                &["new", "atomic", "cassert"][..],
+            )
+        };
+
+        for &(condition, definitions) in groups {
+            let definitions: Vec<_> = definitions
+                .iter()
+                .filter(|&&(identifier, _)| used.contains(identifier))
+                .collect();
+            if definitions.is_empty() {
+                continue;
+            }
+            out.new_line_if_not_start();
+            write!(out, "#if {}", condition);
+            out.new_line();
+            for &&(_, definition) in &definitions {
+                out.write(definition);
+                out.new_line();
+            }
+            out.write("#endif");
+            out.new_line();
+        }
+
+        // The headers without libc equivalents are still included.
+        let includes: Vec<_> = self
+            .inferred_includes()
+            .into_iter()
+            .filter(|x| headers.contains(x))
+            .collect();
+        if !includes.is_empty() {
+            out.new_line_if_not_start();
+            for include in includes {
+                write!(out, "#include <{}>", include);
+                out.new_line();
+            }
+        }
+    }
+
+    /// Returns the system headers declaring the identifiers that the items of
+    /// the bindings use.
+    fn inferred_includes(&self) -> Vec<&'static str> {
+        let used = self.used_identifiers();
+        let headers = if self.config.language == Language::C {
+            C_SYSTEM_HEADERS
+        } else {
+            CXX_SYSTEM_HEADERS
+        };
+        headers
+            .iter()
+            .filter(|&&(_, identifiers)| identifiers.iter().any(|x| used.contains(*x)))
+            .map(|&(header, _)| self.cxx_header(header))
+            .collect()
+    }
+
+    /// Returns the header to include for `header`, which is the C header for
+    /// `cstdint` before C++11 added it.
+    fn cxx_header(&self, header: &'static str) -> &'static str {
+        if header == "cstdint"
+            && self.config.language == Language::Cxx
+            && !self.config.has_version(LanguageVersion::Cxx11)
+        {
+            "stdint.h"
+        } else {
+            header
+        }
+    }
+
+    /// Writes the items of the bindings, between the includes and the end of
+    /// the include guard.
+    fn write_body<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.export.dll_export_macro.is_some() {
+            self.write_dll_export_macro(out);
+        }
+
+        self.write_api_version_macros(out);
+        self.write_thread_safety_macros(out);
+        self.write_calling_convention_macros(out);
+        self.write_no_return_macro(out);
+        self.write_objc_macros(out);
+
+        if self.config.objc_compat() {
+            out.new_line_if_not_start();
+            out.write("NS_ASSUME_NONNULL_BEGIN");
+            out.new_line();
+        }
+
         if self.config.language == Language::Cxx {
-            self.open_namespaces(&mut out);
+            self.open_namespaces(out);
         }
 
+        self.write_injection(out, &self.config.inject.before_constants);
+
+        let mut sections = Sections::new(&self.config);
         for constant in &self.constants {
             if constant.ty.is_primitive_or_ptr_primitive() {
+                sections.enter(out, constant.annotations(), "Constants");
+                self.write_snippet(out, constant.annotations(), "before");
                 out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
+                let guarded = self.open_api_version_guard(out, constant.annotations());
+                constant.write(&self.config, out, None);
                 out.new_line();
+                self.close_api_version_guard(out, guarded);
+                self.write_snippet(out, constant.annotations(), "after");
             }
         }
+        sections.close(out);
+
+        self.write_injection(out, &self.config.inject.before_types);
 
+        // The forward declarations go before all the items.
+        let mut declared = false;
         for item in &self.items {
             if item
                 .deref()
@@ -185,25 +1510,89 @@ impl Bindings {
             {
                 continue;
             }
+            match *item {
+                ItemContainer::Struct(ref x) if x.forward_declared => {
+                    if !declared {
+                        out.new_line_if_not_start();
+                    }
+                    x.write_forward_declaration(&self.config, out);
+                }
+                ItemContainer::Union(ref x) if x.forward_declared => {
+                    if !declared {
+                        out.new_line_if_not_start();
+                    }
+                    x.write_forward_declaration(&self.config, out);
+                }
+                _ => continue,
+            }
+            out.new_line();
+            declared = true;
+        }
 
+        self.write_inline_functions(out, None);
+        let last_enum = self.items.iter().rposition(|x| match *x {
+            ItemContainer::Enum(ref x) => !x.annotations.bool("no-export").unwrap_or(false),
+            _ => false,
+        });
+        for (i, item) in self.items.iter().enumerate() {
+            let annotations = item.deref().annotations();
+            if annotations.bool("no-export").unwrap_or(false) {
+                continue;
+            }
+
+            sections.enter(out, annotations, item.standard_section());
+            self.write_snippet(out, annotations, "before");
             out.new_line_if_not_start();
+            let guarded = self.open_api_version_guard(out, annotations);
             match *item {
                 ItemContainer::Constant(..) => unreachable!(),
                 ItemContainer::Static(..) => unreachable!(),
-                ItemContainer::Enum(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Struct(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Union(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::OpaqueItem(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Typedef(ref x) => x.write(&self.config, &mut out),
+                ItemContainer::Enum(ref x) => x.write(&self.config, out),
+                ItemContainer::Struct(ref x) => {
+                    x.write(&self.config, out);
+                    self.write_field_offsets(x, out);
+                    self.write_struct_default(x, out);
+                }
+                ItemContainer::Union(ref x) => x.write(&self.config, out),
+                ItemContainer::OpaqueItem(ref x) => x.write(&self.config, out),
+                ItemContainer::Typedef(ref x) => x.write(&self.config, out),
             }
             out.new_line();
+            self.close_api_version_guard(out, guarded);
+            self.write_snippet(out, annotations, "after");
+            self.write_inline_functions(out, Some(i));
+            if Some(i) == last_enum {
+                self.write_injection(out, &self.config.inject.after_enums);
+            }
         }
+        sections.close(out);
+
+        self.write_injection(out, &self.config.inject.after_types);
 
         for constant in &self.constants {
             if !constant.ty.is_primitive_or_ptr_primitive() {
+                sections.enter(out, constant.annotations(), "Constants");
+                self.write_snippet(out, constant.annotations(), "before");
                 out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
+                let guarded = self.open_api_version_guard(out, constant.annotations());
+                constant.write(&self.config, out, None);
                 out.new_line();
+                self.close_api_version_guard(out, guarded);
+                self.write_snippet(out, constant.annotations(), "after");
+            }
+        }
+        sections.close(out);
+
+        self.write_layout_asserts(out);
+
+        for item in &self.items {
+            if let ItemContainer::Enum(ref x) = *item {
+                if x.has_companions(&self.config)
+                    && !x.annotations.bool("no-export").unwrap_or(false)
+                {
+                    out.new_line_if_not_start();
+                    x.write_companions(&self.config, out);
+                }
             }
         }
 
@@ -224,17 +1613,32 @@ impl Bindings {
                 out.new_line();
             }
 
+            self.write_injection(out, &self.config.inject.before_functions);
+
             for global in &self.globals {
+                sections.enter(out, global.annotations(), "Globals");
+                self.write_snippet(out, global.annotations(), "before");
                 out.new_line_if_not_start();
-                global.write(&self.config, &mut out);
+                let guarded = self.open_api_version_guard(out, global.annotations());
+                global.write(&self.config, out);
                 out.new_line();
+                self.close_api_version_guard(out, guarded);
+                self.write_snippet(out, global.annotations(), "after");
             }
 
             for function in &self.functions {
+                sections.enter(out, &function.annotations, "Functions");
+                self.write_snippet(out, &function.annotations, "before");
                 out.new_line_if_not_start();
-                function.write(&self.config, &mut out);
+                let guarded = self.open_api_version_guard(out, &function.annotations);
+                function.write(&self.config, out);
                 out.new_line();
+                self.close_api_version_guard(out, guarded);
+                self.write_snippet(out, &function.annotations, "after");
             }
+            sections.close(out);
+
+            self.write_injection(out, &self.config.inject.after_functions);
 
             if self.config.language == Language::C && self.config.cpp_compat {
                 out.new_line();
@@ -254,25 +1658,315 @@ impl Bindings {
         }
 
         if self.config.language == Language::Cxx {
-            self.close_namespaces(&mut out);
+            self.write_member_definitions(out);
         }
 
-        if let Some(ref f) = self.config.include_guard {
+        if self.config.language == Language::Cxx {
+            self.close_namespaces(out);
+        }
+
+        if self.config.objc_compat() {
             out.new_line_if_not_start();
-            if self.config.language == Language::C {
-                write!(out, "#endif /* {} */", f);
-            } else {
-                write!(out, "#endif // {}", f);
-            }
+            out.write("NS_ASSUME_NONNULL_END");
             out.new_line();
         }
-        if let Some(ref f) = self.config.trailer {
+    }
+
+    /// Writes the static assertions checking the size of the platform-dependent
+    /// integer types mapped with `[types]`, the size and field offsets of each
+    /// struct whose layout is known with `layout_asserts`, and the size of the
+    /// opaque items defined with storage.
+    fn write_layout_asserts<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let mapped_types = self.config.types.mapped_platform_types();
+        let structs: Vec<_> = self
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                ItemContainer::Struct(ref s)
+                    if !s.is_transparent && !s.annotations.bool("no-export").unwrap_or(false) =>
+                {
+                    Some((s, self.struct_layouts.get(&s.path)?))
+                }
+                _ => None,
+            })
+            .filter(|_| self.config.layout_asserts)
+            .collect();
+        let opaque_items: Vec<_> = self
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                ItemContainer::OpaqueItem(ref o)
+                    if !o.annotations.bool("no-export").unwrap_or(false) =>
+                {
+                    Some((o, o.storage?))
+                }
+                _ => None,
+            })
+            .collect();
+        if mapped_types.is_empty() && structs.is_empty() && opaque_items.is_empty() {
+            return;
+        }
+
+        // Without a version, C picks the assertion of the standard it's
+        // compiled with, and C++03 has none.
+        let language = self.config.language;
+        let fallback_only =
+            language == Language::Cxx && !self.config.has_version(LanguageVersion::Cxx11);
+        let assert = if language == Language::Cxx && !fallback_only {
+            "static_assert"
+        } else if language == Language::C
+            && self.config.language_version().is_some()
+            && self.config.has_version(LanguageVersion::C11)
+            && !self.config.cpp_compat
+        {
+            "_Static_assert"
+        } else {
             out.new_line_if_not_start();
-            write!(out, "{}", f);
+            out.write("#ifndef CBINDGEN_STATIC_ASSERT");
+            out.new_line();
+            if !fallback_only {
+                out.write("#if defined(__cplusplus)");
+                out.new_line();
+                out.write("#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)");
+                out.new_line();
+                out.write("#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L");
+                out.new_line();
+                out.write("#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)");
+                out.new_line();
+                out.write("#else");
+                out.new_line();
+            }
+            out.write("#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line");
+            out.new_line();
+            out.write(
+                "#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)",
+            );
+            out.new_line();
+            out.write(
+                "#define CBINDGEN_STATIC_ASSERT(cond, msg) \
+                 typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]",
+            );
+            out.new_line();
+            if !fallback_only {
+                out.write("#endif");
+                out.new_line();
+            }
+            out.write("#endif");
             out.new_line();
+            "CBINDGEN_STATIC_ASSERT"
+        };
+
+        if !mapped_types.is_empty() {
+            out.new_line_if_not_start();
+            for (primitive, name) in mapped_types {
+                write!(
+                    out,
+                    "{}(sizeof({}) == sizeof({}), \"unexpected size of {}\");",
+                    assert,
+                    name,
+                    primitive.to_repr_c(),
+                    primitive.to_repr_rust()
+                );
+                out.new_line();
+            }
+        }
+
+        for (s, layouts) in structs {
+            let name =
+                if self.config.language == Language::C && !self.config.style.generate_typedef() {
+                    format!("struct {}", s.export_name())
+                } else {
+                    s.export_name().to_owned()
+                };
+
+            out.new_line_if_not_start();
+            let condition = (&s.cfg).to_condition(&self.config);
+            condition.write_before(&self.config, out);
+
+            let write_layout = |layout: &StructLayout, out: &mut SourceWriter<F>| {
+                write!(
+                    out,
+                    "{}(sizeof({}) == {}, \"unexpected size of {}\");",
+                    assert,
+                    name,
+                    layout.layout.size,
+                    s.export_name()
+                );
+                out.new_line();
+                for (&(ref field, _, _), offset) in s.fields.iter().zip(&layout.offsets) {
+                    write!(
+                        out,
+                        "{}(offsetof({}, {}) == {}, \"unexpected offset of {}::{}\");",
+                        assert,
+                        name,
+                        field,
+                        offset,
+                        s.export_name(),
+                        field
+                    );
+                    out.new_line();
+                }
+            };
+
+            write_conditions(&layouts.conditions(), out, write_layout);
+
+            condition.write_after(&self.config, out);
+        }
+
+        for (o, storage) in opaque_items {
+            let name =
+                if self.config.language == Language::C && !self.config.style.generate_typedef() {
+                    format!("struct {}", o.export_name())
+                } else {
+                    o.export_name().to_owned()
+                };
+
+            out.new_line_if_not_start();
+            let condition = (&o.cfg).to_condition(&self.config);
+            condition.write_before(&self.config, out);
+
+            let write_size = |layout: &TypeLayout, out: &mut SourceWriter<F>| {
+                write!(
+                    out,
+                    "{}(sizeof({}) == {}, \"unexpected size of {}\");",
+                    assert,
+                    name,
+                    layout.size,
+                    o.export_name()
+                );
+                out.new_line();
+            };
+
+            write_conditions(&storage.conditions(), out, write_size);
+
+            condition.write_after(&self.config, out);
         }
     }
 
+    /// Defines `STRUCT_OFFSETOF_FIELD` as the offset of each field of the
+    /// struct, if it has the `field-offsets` annotation, either as
+    /// `offsetof(Struct, field)` or as the offset computed from its layout.
+    fn write_field_offsets<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        let style = match self.config.structure.field_offsets(&s.annotations) {
+            Some(style) => style,
+            None => return,
+        };
+        if s.is_transparent || s.bitfield_widths.is_some() {
+            warn!(
+                "Skip the field offsets of {} - (only structs without bitfields have them).",
+                s.export_name()
+            );
+            return;
+        }
+        let layouts = match style {
+            FieldOffsetStyle::Computed => {
+                let layouts = self.struct_layouts.get(&s.path);
+                if layouts.is_none() {
+                    warn!(
+                        "Writing the field offsets of {} with offsetof, since its layout isn't \
+                         known.",
+                        s.export_name()
+                    );
+                }
+                layouts
+            }
+            FieldOffsetStyle::Offsetof => None,
+        };
+
+        let name = if self.config.language == Language::C && !self.config.style.generate_typedef() {
+            format!("struct {}", s.export_name())
+        } else {
+            s.export_name().to_owned()
+        };
+        let prefix = s.export_name().to_uppercase();
+
+        let define = |field: &str, offset: &str| {
+            format!(
+                "#define {}_OFFSETOF_{} {}",
+                prefix,
+                field.to_uppercase(),
+                offset
+            )
+        };
+        let mut lines = Vec::new();
+        match layouts {
+            Some(layouts) => {
+                // Like `write_conditions`, for the data models the offsets are
+                // known for.
+                let mut directive = "#if";
+                for (condition, layout) in layouts.conditions() {
+                    if let Some(condition) = condition {
+                        lines.push(format!("{} {}", directive, condition));
+                        directive = "#elif";
+                    }
+                    for (&(ref field, _, _), offset) in s.fields.iter().zip(&layout.offsets) {
+                        lines.push(define(field, &offset.to_string()));
+                    }
+                }
+                if directive == "#elif" {
+                    lines.push("#endif".to_owned());
+                }
+            }
+            None => {
+                for &(ref field, _, _) in &s.fields {
+                    lines.push(define(field, &format!("offsetof({}, {})", name, field)));
+                }
+            }
+        }
+
+        out.new_line();
+        out.new_line();
+        let condition = (&s.cfg).to_condition(&self.config);
+        condition.write_before(&self.config, out);
+        for (i, line) in lines.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "{}", line);
+        }
+        condition.write_after(&self.config, out);
+    }
+
+    /// Defines `struct_default()` returning the struct with the fields
+    /// annotated with `default` set to their default values, and the others
+    /// zeroed, unless C++11 default member initializers set them.
+    fn write_struct_default<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        let defaults = match s.field_defaults {
+            Some(ref defaults) => defaults,
+            None => return,
+        };
+        if self.config.language == Language::Cxx && self.config.has_version(LanguageVersion::Cxx11)
+        {
+            return;
+        }
+
+        let name = if self.config.language == Language::C && !self.config.style.generate_typedef() {
+            format!("struct {}", s.export_name())
+        } else {
+            s.export_name().to_owned()
+        };
+        let function =
+            RenameRule::SnakeCase.apply_to_pascal_case(s.export_name(), IdentifierType::Type);
+
+        out.new_line();
+        out.new_line();
+        let condition = (&s.cfg).to_condition(&self.config);
+        condition.write_before(&self.config, out);
+        write!(out, "static inline {} {}_default(void)", name, function);
+        out.open_brace();
+        write!(out, "{} value = {{0}};", name);
+        for (&(ref field, _, _), default) in s.fields.iter().zip(defaults) {
+            if let Some(ref default) = *default {
+                out.new_line();
+                write!(out, "value.{} = {};", field, default);
+            }
+        }
+        out.new_line();
+        write!(out, "return value;");
+        out.close_brace(false);
+        condition.write_after(&self.config, out);
+    }
+
     pub(crate) fn open_namespaces<F: Write>(&self, out: &mut SourceWriter<F>) {
         let mut wrote_namespace: bool = false;
         if let Some(ref namespace) = self.config.namespace {
@@ -314,3 +2008,174 @@ impl Bindings {
         }
     }
 }
+
+/// Returns the path of the header for `module`, next to the header of the
+/// crate root at `path`.
+fn module_header_path(path: &path::Path, module: &Option<String>) -> path::PathBuf {
+    let module = match *module {
+        Some(ref module) => module,
+        None => return path.to_path_buf(),
+    };
+    let mut name = path.file_stem().unwrap().to_os_string();
+    name.push("_");
+    name.push(module);
+    if let Some(extension) = path.extension() {
+        name.push(".");
+        name.push(extension);
+    }
+    path.with_file_name(name)
+}
+
+/// Returns the path of the secondary header `output`, next to the main header
+/// at `path`. Names without an extension are appended to the name of the main
+/// header, like the headers of modules.
+fn output_header_path(path: &path::Path, output: &Option<String>) -> path::PathBuf {
+    match *output {
+        Some(ref output) if path::Path::new(output).extension().is_some() => {
+            path.with_file_name(output)
+        }
+        _ => module_header_path(path, output),
+    }
+}
+
+/// Escapes the characters that are special in the paths of Makefile rules.
+fn escape_make_path(path: &path::Path) -> String {
+    let path = path.to_string_lossy();
+    let mut result = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            ' ' | '#' => {
+                result.push('\\');
+                result.push(c);
+            }
+            '$' => result.push_str("$$"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Writes the output of `write` to `path`, unless the file already has that
+/// content. Returns whether the file changed.
+fn write_if_changed<F: FnOnce(&mut Vec<u8>)>(path: &path::Path, write: F) -> bool {
+    let mut new_file_contents = Vec::new();
+    write(&mut new_file_contents);
+
+    // Don't compare files if we've never written this file before
+    if !path.is_file() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        File::create(path)
+            .unwrap()
+            .write_all(&new_file_contents)
+            .unwrap();
+        return true;
+    }
+
+    let mut old_file_contents = Vec::new();
+    {
+        let mut old_file = File::open(path).unwrap();
+        old_file.read_to_end(&mut old_file_contents).unwrap();
+    }
+
+    if old_file_contents != new_file_contents {
+        let mut new_file = File::create(path).unwrap();
+        new_file.write_all(&new_file_contents).unwrap();
+        true
+    } else {
+        false
+    }
+}
+
+/// Writes each of the layouts under the preprocessor condition that selects
+/// it, if any.
+fn write_conditions<T, F, W>(conditions: &[(Option<&str>, &T)], out: &mut SourceWriter<F>, write: W)
+where
+    F: Write,
+    W: Fn(&T, &mut SourceWriter<F>),
+{
+    let mut directive = "#if";
+    for &(condition, layout) in conditions {
+        if let Some(condition) = condition {
+            write!(out, "{} {}", directive, condition);
+            out.new_line();
+            directive = "#elif";
+        }
+        write(layout, out);
+    }
+    if directive == "#elif" {
+        out.write("#endif");
+        out.new_line();
+    }
+}
+
+/// Marks where the sections that items are grouped into start and end, as they
+/// are written.
+struct Sections<'a> {
+    config: &'a Config,
+    current: Option<String>,
+}
+
+impl<'a> Sections<'a> {
+    fn new(config: &'a Config) -> Sections<'a> {
+        Sections {
+            config: config,
+            current: None,
+        }
+    }
+
+    /// Starts the section of the next item, unless it's in the current one.
+    fn enter<F: Write>(
+        &mut self,
+        out: &mut SourceWriter<F>,
+        annotations: &AnnotationSet,
+        standard: &'static str,
+    ) {
+        let section = match self.config.section_of(annotations, standard) {
+            Some(section) => section,
+            None => return,
+        };
+        if self.current.as_ref() == Some(&section) {
+            return;
+        }
+        self.close(out);
+
+        out.new_line_if_not_start();
+        match self.config.sections.style {
+            Some(SectionStyle::Banner) => write!(
+                out,
+                "{}",
+                self.config.sections.banner.replace("{}", &section)
+            ),
+            Some(SectionStyle::Region) => write!(out, "#pragma region {}", section),
+            None => unreachable!(),
+        }
+        out.new_line();
+        self.current = Some(section);
+    }
+
+    /// Ends the current section.
+    fn close<F: Write>(&mut self, out: &mut SourceWriter<F>) {
+        if self.current.take().is_some() && self.config.sections.style == Some(SectionStyle::Region)
+        {
+            out.new_line_if_not_start();
+            out.write("#pragma endregion");
+            out.new_line();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_make_paths() {
+        let path = path::Path::new("/my lib/#include/$HOME/bindings.h");
+        assert_eq!(
+            escape_make_path(path),
+            "/my\\ lib/\\#include/$$HOME/bindings.h"
+        );
+    }
+}
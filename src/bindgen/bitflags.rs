@@ -13,11 +13,17 @@ use syn::parse::{Parse, ParseStream, Parser, Result as ParseResult};
 //         const $Flag:ident = $value:expr;
 //     )+
 // }
+//
+// or, for the flags of a struct declared separately, like
+// `pub struct Flags(u32);` with bitflags 2:
+//
+// impl $BitFlags:ident: $T:ty { ... }
 #[derive(Debug)]
 pub struct Bitflags {
     attrs: Vec<syn::Attribute>,
     vis: syn::Visibility,
-    struct_token: Token![struct],
+    impl_token: Option<Token![impl]>,
+    struct_token: Option<Token![struct]>,
     name: syn::Ident,
     colon_token: Token![:],
     repr: syn::Type,
@@ -25,25 +31,31 @@ pub struct Bitflags {
 }
 
 impl Bitflags {
-    pub fn expand(&self) -> (syn::ItemStruct, syn::ItemImpl) {
+    /// Expands to the struct holding the bits and the impl block of the
+    /// flags, which are the only ones of the struct declared separately.
+    pub fn expand(&self) -> (Option<syn::ItemStruct>, syn::ItemImpl) {
         let Bitflags {
             ref attrs,
             ref vis,
+            ref impl_token,
             ref name,
             ref repr,
             ref flags,
             ..
         } = *self;
 
-        let struct_ = parse_quote! {
-            /// cbindgen:internal-derive-bitflags=true
-            #(#attrs)*
-            #vis struct #name {
-                bits: #repr,
-            }
+        let (struct_, consts) = if impl_token.is_some() {
+            (None, flags.expand(name, &quote! { 0 }))
+        } else {
+            let struct_ = parse_quote! {
+                /// cbindgen:internal-derive-bitflags=true
+                #(#attrs)*
+                #vis struct #name {
+                    bits: #repr,
+                }
+            };
+            (Some(struct_), flags.expand(name, &quote! { bits }))
         };
-
-        let consts = flags.expand(name);
         let impl_ = parse_quote! {
             impl #name {
                 #consts
@@ -59,6 +71,7 @@ impl Parse for Bitflags {
         Ok(Self {
             attrs: input.call(syn::Attribute::parse_outer)?,
             vis: input.parse()?,
+            impl_token: input.parse()?,
             struct_token: input.parse()?,
             name: input.parse()?,
             colon_token: input.parse()?,
@@ -81,7 +94,7 @@ struct Flag {
 }
 
 impl Flag {
-    fn expand(&self, struct_name: &syn::Ident) -> TokenStream {
+    fn expand(&self, struct_name: &syn::Ident, field: &TokenStream) -> TokenStream {
         let Flag {
             ref attrs,
             ref name,
@@ -90,7 +103,7 @@ impl Flag {
         } = *self;
         quote! {
             #(#attrs)*
-            pub const #name : #struct_name = #struct_name { bits: #value };
+            pub const #name : #struct_name = #struct_name { #field: #value };
         }
     }
 }
@@ -124,10 +137,10 @@ impl Parse for Flags {
 }
 
 impl Flags {
-    fn expand(&self, struct_name: &syn::Ident) -> TokenStream {
+    fn expand(&self, struct_name: &syn::Ident, field: &TokenStream) -> TokenStream {
         let mut ts = quote! {};
         for flag in &self.0 {
-            ts.extend(flag.expand(struct_name));
+            ts.extend(flag.expand(struct_name, field));
         }
         ts
     }
@@ -3,11 +3,15 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::path;
+use std::sync::Arc;
 
 use bindgen::bindings::Bindings;
+use bindgen::cargo::cargo_expand::Expander;
 use bindgen::cargo::Cargo;
 use bindgen::config::{Braces, Config, Language};
+use bindgen::diagnostic;
 use bindgen::error::Error;
+use bindgen::ir::KnownCfgs;
 use bindgen::library::Library;
 use bindgen::parser::{self, Parse};
 
@@ -20,6 +24,7 @@ pub struct Builder {
     lib_cargo: Option<Cargo>,
     std_types: bool,
     lockfile: Option<path::PathBuf>,
+    expander: Option<Arc<dyn Expander>>,
 }
 
 impl Builder {
@@ -31,6 +36,7 @@ impl Builder {
             lib_cargo: None,
             std_types: true,
             lockfile: None,
+            expander: None,
         }
     }
 
@@ -46,6 +52,18 @@ impl Builder {
         self
     }
 
+    #[allow(unused)]
+    pub fn with_infer_includes(mut self) -> Builder {
+        self.config.infer_includes = true;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_no_std_headers(mut self) -> Builder {
+        self.config.no_std_headers = true;
+        self
+    }
+
     #[allow(unused)]
     pub fn with_include<S: AsRef<str>>(mut self, include: S) -> Builder {
         self.config.includes.push(String::from(include.as_ref()));
@@ -125,6 +143,20 @@ impl Builder {
         self
     }
 
+    /// Expands the crates in `parse.expand` with `expander` instead of the
+    /// configured backend, e.g. to supply sources expanded beforehand.
+    #[allow(unused)]
+    pub fn with_expander<E: Expander + 'static>(mut self, expander: E) -> Builder {
+        self.expander = Some(Arc::new(expander));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_target<S: AsRef<str>>(mut self, target: S) -> Builder {
+        self.config.target = Some(String::from(target.as_ref()));
+        self
+    }
+
     #[allow(unused)]
     pub fn include_item<S: AsRef<str>>(mut self, item_name: S) -> Builder {
         self.config
@@ -287,7 +319,43 @@ impl Builder {
         self
     }
 
+    fn expander(&self) -> Arc<dyn Expander> {
+        match self.expander {
+            Some(ref expander) => expander.clone(),
+            None => self.config.parse.expand.expander(),
+        }
+    }
+
+    /// Sets the features of the binding crate that `#[cfg(feature = "...")]`s
+    /// are resolved with, which are the ones it's expanded with.
+    fn set_features(&self, targets: &mut Vec<KnownCfgs>, cargo: &Cargo) {
+        if targets.is_empty() {
+            if !self.config.parse.resolve_features {
+                return;
+            }
+            targets.push(KnownCfgs::default());
+        }
+        let expand = &self.config.parse.expand;
+        let features = cargo.binding_crate_features(
+            expand.all_features,
+            expand.default_features,
+            &expand.features,
+        );
+        for target in targets {
+            target.set_features(cargo.binding_crate_name(), features.clone());
+        }
+    }
+
     pub fn generate(self) -> Result<Bindings, Error> {
+        if let Some(version) = self.config.language_version {
+            if version.language() != self.config.language {
+                warn!(
+                    "Ignoring language_version {}, which isn't a version of the target language.",
+                    version.as_str()
+                );
+            }
+        }
+
         let mut result = Parse::new();
 
         if self.std_types {
@@ -295,13 +363,32 @@ impl Builder {
         }
 
         for x in &self.srcs {
-            result.extend_with(&parser::parse_src(x, &self.config.macro_expansion)?);
+            result.extend_with(&parser::parse_src(
+                x,
+                &self.config.macro_expansion,
+                &self.config.parse.cache_dir,
+            )?);
         }
 
+        let mut targets = Vec::new();
+        for target in self.config.targets() {
+            targets.push(
+                KnownCfgs::for_target(target).map_err(|x| Error::TargetCfgs(target.clone(), x))?,
+            );
+        }
+
+        let members: Vec<_> = self
+            .config
+            .workspace
+            .members
+            .iter()
+            .map(|x| x.name.clone())
+            .collect();
+
         if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
             let lockfile = self.lockfile.as_ref().and_then(|p| p.to_str());
 
-            let cargo = if let Some(binding_lib_name) = binding_lib_name {
+            let mut cargo = if let Some(binding_lib_name) = binding_lib_name {
                 Cargo::load(
                     &lib_dir,
                     lockfile,
@@ -318,6 +405,8 @@ impl Builder {
                     self.config.parse.clean,
                 )?
             };
+            cargo.set_expander(self.expander());
+            self.set_features(&mut targets, &cargo);
 
             result.extend_with(&parser::parse_lib(
                 cargo,
@@ -329,8 +418,13 @@ impl Builder {
                 self.config.parse.expand.all_features,
                 self.config.parse.expand.default_features,
                 &self.config.parse.expand.features,
+                &self.config.parse.cache_dir,
+                self.config.parse.jobs,
+                &members,
             )?);
-        } else if let Some(cargo) = self.lib_cargo.clone() {
+        } else if let Some(mut cargo) = self.lib_cargo.clone() {
+            cargo.set_expander(self.expander());
+            self.set_features(&mut targets, &cargo);
             result.extend_with(&parser::parse_lib(
                 cargo,
                 &self.config.macro_expansion,
@@ -341,10 +435,14 @@ impl Builder {
                 self.config.parse.expand.all_features,
                 self.config.parse.expand.default_features,
                 &self.config.parse.expand.features,
+                &self.config.parse.cache_dir,
+                self.config.parse.jobs,
+                &members,
             )?);
         }
 
-        Library::new(
+        let diagnostics = self.config.diagnostics.clone();
+        let generated = Library::new(
             self.config,
             result.constants,
             result.globals,
@@ -354,7 +452,20 @@ impl Builder {
             result.opaque_items,
             result.typedefs,
             result.functions,
+            result.inline_functions,
+            result.trait_impls,
+            result.source_order,
+            targets,
+            result.diagnostics,
         )
-        .generate()
+        .generate();
+        let mut bindings = generated?;
+        let denied = diagnostic::report(&diagnostics, &bindings.diagnostics);
+        if denied > 0 {
+            return Err(Error::Diagnostics(denied));
+        }
+        bindings.source_files = result.source_files;
+        bindings.render_template()?;
+        Ok(bindings)
     }
 }
@@ -0,0 +1,144 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use serde_json;
+
+use bindgen::config::VERSION;
+use bindgen::parser::ParsedMod;
+
+/// A cached module, which is only valid for the same source parsed in the
+/// same context by the same version of cbindgen.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    version: String,
+    src_path: PathBuf,
+    context: String,
+    src_hash: u64,
+    src_len: usize,
+    module: ParsedMod,
+}
+
+/// An on-disk cache of the modules parsed from source files, so that runs
+/// only reparse the files that changed since the previous ones.
+///
+/// Each source file parsed in a context has one entry, which is replaced
+/// when the file changes.
+#[derive(Debug, Clone)]
+pub struct ParseCache {
+    dir: PathBuf,
+}
+
+impl ParseCache {
+    pub fn new<P: AsRef<Path>>(dir: P) -> ParseCache {
+        ParseCache {
+            dir: dir.as_ref().to_owned(),
+        }
+    }
+
+    /// Returns the module parsed from `src` at `src_path` in `context`, if it
+    /// was cached.
+    pub fn get(&self, src_path: &Path, context: &str, src: &str) -> Option<ParsedMod> {
+        let file = File::open(self.entry_path(src_path, context)).ok()?;
+        let entry: Entry = match serde_json::from_reader(BufReader::new(file)) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("Ignoring the parse cache of {}: {}.", src_path.display(), e);
+                return None;
+            }
+        };
+
+        if entry.version != VERSION
+            || entry.src_path != src_path
+            || entry.context != context
+            || entry.src_hash != hash(src)
+            || entry.src_len != src.len()
+        {
+            return None;
+        }
+        Some(entry.module)
+    }
+
+    /// Caches the module parsed from `src` at `src_path` in `context`.
+    pub fn insert(&self, src_path: &Path, context: &str, src: &str, module: &ParsedMod) {
+        let entry = Entry {
+            version: VERSION.to_owned(),
+            src_path: src_path.to_owned(),
+            context: context.to_owned(),
+            src_hash: hash(src),
+            src_len: src.len(),
+            module: module.clone(),
+        };
+
+        let result = fs::create_dir_all(&self.dir)
+            .and_then(|_| File::create(self.entry_path(src_path, context)))
+            .and_then(|file| {
+                serde_json::to_writer(BufWriter::new(file), &entry).map_err(|e| e.into())
+            });
+        if let Err(e) = result {
+            warn!("Couldn't cache the parse of {}: {}.", src_path.display(), e);
+        }
+    }
+
+    fn entry_path(&self, src_path: &Path, context: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        src_path.hash(&mut hasher);
+        context.hash(&mut hasher);
+        self.dir.join(format!("{:016x}.json", hasher.finish()))
+    }
+}
+
+fn hash(src: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    src.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::io::Write;
+
+    use bindgen::config::MacroExpansionConfig;
+    use bindgen::ir::Item;
+    use bindgen::parser;
+
+    fn parse_structs(src_path: &Path, cache_dir: &Path) -> Vec<String> {
+        let cache_dir = Some(cache_dir.to_string_lossy().into_owned());
+        let parse =
+            parser::parse_src(src_path, &MacroExpansionConfig::default(), &cache_dir).unwrap();
+        let mut names = Vec::new();
+        parse
+            .structs
+            .for_all_items(|x| names.push(x.export_name().to_owned()));
+        names
+    }
+
+    #[test]
+    fn reparses_changed_files() {
+        let dir = env::temp_dir().join(format!("cbindgen-parse-cache-{}", std::process::id()));
+        let src_path = dir.join("lib.rs");
+        let cache_dir = dir.join("cache");
+        fs::create_dir_all(&dir).unwrap();
+
+        let write_src = |src: &str| File::create(&src_path).unwrap().write_all(src.as_bytes());
+        write_src("#[repr(C)] pub struct Foo { a: i32 }").unwrap();
+        assert_eq!(parse_structs(&src_path, &cache_dir), ["Foo"]);
+        assert_eq!(parse_structs(&src_path, &cache_dir), ["Foo"]);
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+        write_src("#[repr(C)] pub struct Bar { a: i32 }").unwrap();
+        assert_eq!(parse_structs(&src_path, &cache_dir), ["Bar"]);
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,9 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use bindgen::cargo::cargo_expand;
+use bindgen::cargo::cargo_expand::{self, ExpandRequest, Expander, RustcExpander};
 use bindgen::cargo::cargo_lock::{self, Lock};
 pub(crate) use bindgen::cargo::cargo_metadata::PackageRef;
 use bindgen::cargo::cargo_metadata::{self, Metadata};
@@ -12,11 +14,12 @@ use bindgen::cargo::cargo_toml;
 use bindgen::error::Error;
 use bindgen::ir::Cfg;
 
-/// Parse a dependency string used in Cargo.lock
-fn parse_dep_string(dep_string: &str) -> (&str, &str) {
+/// Parse a dependency string used in Cargo.lock, which newer lock files
+/// only give the name of when a single version of the crate is locked.
+fn parse_dep_string(dep_string: &str) -> (&str, Option<&str>) {
     let split: Vec<&str> = dep_string.split_whitespace().collect();
 
-    (split[0], split[1])
+    (split[0], split.get(1).cloned())
 }
 
 /// A collection of metadata for a library from cargo.
@@ -27,6 +30,7 @@ pub(crate) struct Cargo {
     lock: Option<Lock>,
     metadata: Metadata,
     clean: bool,
+    expander: Arc<dyn Expander>,
 }
 
 impl Cargo {
@@ -63,8 +67,16 @@ impl Cargo {
         let manifest = cargo_toml::manifest(&toml_path)
             .map_err(|x| Error::CargoToml(toml_path.to_str().unwrap().to_owned(), x))?;
 
-        let binding_crate_name =
-            binding_crate_name.map_or(manifest.package.name.clone(), |x| x.to_owned());
+        let binding_crate_name = match (binding_crate_name, manifest.package) {
+            (Some(name), _) => name.to_owned(),
+            (None, Some(package)) => package.name,
+            (None, None) => {
+                return Err(Error::CargoToml(
+                    toml_path.to_str().unwrap().to_owned(),
+                    cargo_toml::Error::VirtualManifest,
+                ))
+            }
+        };
 
         Ok(Cargo {
             manifest_path: toml_path,
@@ -72,9 +84,15 @@ impl Cargo {
             lock,
             metadata,
             clean,
+            expander: Arc::new(RustcExpander::default()),
         })
     }
 
+    /// Sets the expander to expand the crates in `parse.expand` with.
+    pub(crate) fn set_expander(&mut self, expander: Arc<dyn Expander>) {
+        self.expander = expander;
+    }
+
     pub(crate) fn binding_crate_name(&self) -> &str {
         &self.binding_crate_name
     }
@@ -117,6 +135,13 @@ impl Cargo {
             .iter()
             .map(|dep| {
                 let (dep_name, dep_version) = parse_dep_string(dep);
+                let dep_version = dep_version.or_else(|| {
+                    lock.package
+                        .iter()
+                        .flat_map(|x| x.iter())
+                        .find(|x| x.name == dep_name)
+                        .map(|x| x.version.as_str())
+                });
 
                 // Try to find the cfgs in the Cargo.toml
                 let cfg = self
@@ -128,7 +153,7 @@ impl Cargo {
 
                 let package_ref = PackageRef {
                     name: dep_name.to_owned(),
-                    version: dep_version.to_owned(),
+                    version: dep_version.unwrap_or_default().to_owned(),
                 };
 
                 (package_ref, cfg)
@@ -138,7 +163,7 @@ impl Cargo {
 
     /// Finds the package reference in `cargo metadata` that has `package_name`
     /// ignoring the version.
-    fn find_pkg_ref(&self, package_name: &str) -> Option<PackageRef> {
+    pub(crate) fn find_pkg_ref(&self, package_name: &str) -> Option<PackageRef> {
         for package in &self.metadata.packages {
             if package.name_and_version.name == package_name {
                 return Some(package.name_and_version.clone());
@@ -147,6 +172,44 @@ impl Cargo {
         None
     }
 
+    /// Finds the features of the binding crate that are enabled when it's
+    /// built with the given features, including the ones they enable.
+    pub(crate) fn binding_crate_features(
+        &self,
+        all_features: bool,
+        default_features: bool,
+        features: &Option<Vec<String>>,
+    ) -> HashSet<String> {
+        let mut enabled = HashSet::new();
+        let package = match self.metadata.packages.get(&self.binding_crate_ref()) {
+            Some(package) => package,
+            None => return enabled,
+        };
+
+        let mut pending: Vec<String> = if all_features {
+            package.features.keys().cloned().collect()
+        } else {
+            features.clone().unwrap_or_default()
+        };
+        if default_features && package.features.contains_key("default") {
+            pending.push("default".to_owned());
+        }
+
+        while let Some(feature) = pending.pop() {
+            // Features of dependencies don't enable any of the crate's own.
+            if feature.starts_with("dep:") || feature.contains('/') {
+                continue;
+            }
+            if !enabled.insert(feature.clone()) {
+                continue;
+            }
+            if let Some(implied) = package.features.get(&feature) {
+                pending.extend(implied.iter().cloned());
+            }
+        }
+        enabled
+    }
+
     /// Finds the directory for a specified package reference.
     #[allow(unused)]
     pub(crate) fn find_crate_dir(&self, package: &PackageRef) -> Option<PathBuf> {
@@ -193,14 +256,14 @@ impl Cargo {
         expand_default_features: bool,
         expand_features: &Option<Vec<String>>,
     ) -> Result<String, cargo_expand::Error> {
-        cargo_expand::expand(
-            &self.manifest_path,
-            &package.name,
-            &package.version,
-            self.clean,
-            expand_all_features,
-            expand_default_features,
-            expand_features,
-        )
+        self.expander.expand(&ExpandRequest {
+            manifest_path: &self.manifest_path,
+            crate_name: &package.name,
+            version: &package.version,
+            use_tempdir: self.clean,
+            all_features: expand_all_features,
+            default_features: expand_default_features,
+            features: expand_features,
+        })
     }
 }
@@ -5,8 +5,9 @@
 use std::env;
 use std::error;
 use std::fmt;
+use std::fs;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::{from_utf8, Utf8Error};
 
@@ -22,6 +23,8 @@ pub enum Error {
     Utf8(Utf8Error),
     /// Error during execution of `cargo rustc --pretty=expanded`
     Compile(String),
+    /// Error reading a pre-expanded source file
+    Read(PathBuf, io::Error),
 }
 
 impl From<io::Error> for Error {
@@ -41,6 +44,9 @@ impl fmt::Display for Error {
             Error::Io(ref err) => err.fmt(f),
             Error::Utf8(ref err) => err.fmt(f),
             Error::Compile(ref err) => write!(f, "{}", err),
+            Error::Read(ref path, ref err) => {
+                write!(f, "couldn't read expanded source {:?}: {}", path, err)
+            }
         }
     }
 }
@@ -51,37 +57,85 @@ impl error::Error for Error {
             Error::Io(ref err) => Some(err),
             Error::Utf8(ref err) => Some(err),
             Error::Compile(..) => None,
+            Error::Read(_, ref err) => Some(err),
         }
     }
 }
 
+/// A crate to expand, and the features to expand it with.
+#[derive(Debug)]
+pub struct ExpandRequest<'a> {
+    /// The manifest of the crate being bound, which `crate_name` is the crate
+    /// itself or one of its dependencies of.
+    pub manifest_path: &'a Path,
+    pub crate_name: &'a str,
+    pub version: &'a str,
+    /// Whether to expand in a new temporary target directory.
+    pub use_tempdir: bool,
+    pub all_features: bool,
+    pub default_features: bool,
+    pub features: &'a Option<Vec<String>>,
+}
+
+/// Expands the macros of a crate into a single source file to parse.
+pub trait Expander: fmt::Debug + Send + Sync {
+    fn expand(&self, request: &ExpandRequest) -> Result<String, Error>;
+}
+
+/// Expands crates with `cargo rustc`, either with nightly's
+/// `--pretty=expanded`, or with `-Zunpretty=expanded`, which stable rustc
+/// accepts with `RUSTC_BOOTSTRAP=1`.
+#[derive(Debug, Clone, Default)]
+pub struct RustcExpander {
+    pub stable: bool,
+}
+
+impl Expander for RustcExpander {
+    fn expand(&self, request: &ExpandRequest) -> Result<String, Error> {
+        expand(request, self.stable)
+    }
+}
+
+/// Reads crates that were expanded beforehand from `<dir>/<crate>.rs`, with
+/// a relative `dir` being relative to the manifest of the crate being bound.
+#[derive(Debug, Clone)]
+pub struct ExpandedDirExpander {
+    pub dir: PathBuf,
+}
+
+impl Expander for ExpandedDirExpander {
+    fn expand(&self, request: &ExpandRequest) -> Result<String, Error> {
+        let dir = match request.manifest_path.parent() {
+            Some(manifest_dir) => manifest_dir.join(&self.dir),
+            None => self.dir.clone(),
+        };
+        let path = dir.join(format!("{}.rs", request.crate_name));
+        fs::read_to_string(&path).map_err(|err| Error::Read(path, err))
+    }
+}
+
 /// Use rustc to expand and pretty print the crate into a single file,
 /// removing any macros in the process.
-pub fn expand(
-    manifest_path: &Path,
-    crate_name: &str,
-    version: &str,
-    use_tempdir: bool,
-    expand_all_features: bool,
-    expand_default_features: bool,
-    expand_features: &Option<Vec<String>>,
-) -> Result<String, Error> {
+fn expand(request: &ExpandRequest, stable: bool) -> Result<String, Error> {
     let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
     let mut cmd = Command::new(cargo);
 
     let mut _temp_dir = None; // drop guard
-    if use_tempdir {
+    if request.use_tempdir {
         _temp_dir = Some(Builder::new().prefix("cbindgen-expand").tempdir()?);
         cmd.env("CARGO_TARGET_DIR", _temp_dir.unwrap().path());
     } else if let Ok(ref path) = env::var("CARGO_EXPAND_TARGET_DIR") {
         cmd.env("CARGO_TARGET_DIR", path);
     }
+    if stable {
+        cmd.env("RUSTC_BOOTSTRAP", "1");
+    }
 
     cmd.arg("rustc");
     cmd.arg("--lib");
     cmd.arg("--manifest-path");
-    cmd.arg(manifest_path);
-    if let Some(features) = expand_features {
+    cmd.arg(request.manifest_path);
+    if let Some(features) = request.features {
         cmd.arg("--features");
         let mut features_str = String::new();
         for (index, feature) in features.iter().enumerate() {
@@ -92,18 +146,22 @@ pub fn expand(
         }
         cmd.arg(features_str);
     }
-    if expand_all_features {
+    if request.all_features {
         cmd.arg("--all-features");
     }
-    if !expand_default_features {
+    if !request.default_features {
         cmd.arg("--no-default-features");
     }
     cmd.arg("-p");
-    cmd.arg(&format!("{}:{}", crate_name, version));
+    cmd.arg(&format!("{}:{}", request.crate_name, request.version));
     cmd.arg("--");
-    cmd.arg("-Z");
-    cmd.arg("unstable-options");
-    cmd.arg("--pretty=expanded");
+    if stable {
+        cmd.arg("-Zunpretty=expanded");
+    } else {
+        cmd.arg("-Z");
+        cmd.arg("unstable-options");
+        cmd.arg("--pretty=expanded");
+    }
     let output = cmd.output()?;
 
     let src = from_utf8(&output.stdout)?.to_owned();
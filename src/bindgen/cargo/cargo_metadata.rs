@@ -50,7 +50,8 @@ pub struct Package {
     pub dependencies: HashSet<Dependency>,
     /// Targets provided by the crate (lib, bin, example, test, ...)
     pub targets: Vec<Target>,
-    features: HashMap<String, Vec<String>>,
+    /// The features of the crate with the features they enable
+    pub features: HashMap<String, Vec<String>>,
     /// path containing the `Cargo.toml`
     pub manifest_path: String,
 }
@@ -18,6 +18,9 @@ pub enum Error {
     Io(io::Error),
     /// Deserialization error
     Toml(toml::de::Error),
+    /// The manifest of a workspace without a package, when no binding crate
+    /// is given
+    VirtualManifest,
 }
 
 impl From<io::Error> for Error {
@@ -36,6 +39,10 @@ impl fmt::Display for Error {
         match self {
             Error::Io(ref err) => err.fmt(f),
             Error::Toml(ref err) => err.fmt(f),
+            Error::VirtualManifest => write!(
+                f,
+                "the workspace has no package, a binding crate has to be given"
+            ),
         }
     }
 }
@@ -45,13 +52,15 @@ impl error::Error for Error {
         match self {
             Error::Io(ref err) => Some(err),
             Error::Toml(ref err) => Some(err),
+            Error::VirtualManifest => None,
         }
     }
 }
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct Manifest {
-    pub package: Package,
+    /// The package, which the root of a workspace may not have
+    pub package: Option<Package>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -4,8 +4,9 @@
 
 use std::io::Write;
 
+use bindgen::config::{Config, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationType;
-use bindgen::ir::{Function, Type};
+use bindgen::ir::{CallingConvention, Function, PrimitiveType, Type};
 use bindgen::writer::{ListType, SourceWriter};
 
 // This code is for translating Rust types into C declarations.
@@ -13,10 +14,13 @@ use bindgen::writer::{ListType, SourceWriter};
 // http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 
 enum CDeclarator {
-    Ptr(bool),
+    /// A pointer, which may be const, may be null and may be atomic.
+    Ptr(bool, bool, bool),
     Ref,
     Array(String),
-    Func(Vec<(Option<String>, CDecl)>, bool),
+    /// A function, with whether its arguments are laid out vertically, its
+    /// calling convention and whether it's variadic.
+    Func(Vec<(Option<String>, CDecl)>, bool, CallingConvention, bool),
 }
 
 impl CDeclarator {
@@ -49,29 +53,35 @@ impl CDecl {
         }
     }
 
-    fn from_type(t: &Type) -> CDecl {
+    fn from_type(t: &Type, config: &Config) -> CDecl {
         let mut cdecl = CDecl::new();
-        cdecl.build_type(t, false);
+        cdecl.build_type(t, false, config);
         cdecl
     }
-    fn from_func(f: &Function, layout_vertical: bool) -> CDecl {
+    fn from_func(f: &Function, layout_vertical: bool, config: &Config) -> CDecl {
         let mut cdecl = CDecl::new();
-        cdecl.build_func(f, layout_vertical);
+        cdecl.build_func(f, layout_vertical, config);
         cdecl
     }
 
-    fn build_func(&mut self, f: &Function, layout_vertical: bool) {
+    fn build_func(&mut self, f: &Function, layout_vertical: bool, config: &Config) {
         let args = f
             .args
             .iter()
-            .map(|&(ref arg_name, ref arg_ty)| (Some(arg_name.clone()), CDecl::from_type(arg_ty)))
+            .map(|&(ref arg_name, ref arg_ty)| {
+                (Some(arg_name.clone()), CDecl::from_type(arg_ty, config))
+            })
             .collect();
-        self.declarators
-            .push(CDeclarator::Func(args, layout_vertical));
-        self.build_type(&f.ret, false);
+        self.declarators.push(CDeclarator::Func(
+            args,
+            layout_vertical,
+            f.calling_convention,
+            f.variadic,
+        ));
+        self.build_type(&f.ret, false, config);
     }
 
-    fn build_type(&mut self, t: &Type, is_const: bool) {
+    fn build_type(&mut self, t: &Type, is_const: bool, config: &Config) {
         match t {
             &Type::Path(ref generic) => {
                 if is_const {
@@ -112,38 +122,78 @@ impl CDecl {
                     "error generating cdecl for {:?}",
                     t
                 );
-                self.type_name = p.to_string();
+                self.type_name = match config.types.primitive(p) {
+                    Some(name) => name.to_owned(),
+                    None => p.to_string(),
+                };
             }
 
-            &Type::ConstPtr(ref t) => {
-                self.declarators.push(CDeclarator::Ptr(is_const));
-                self.build_type(t, true);
+            &Type::ConstPtr(ref inner, is_nullable) => {
+                // C strings may be mapped to a type of their own.
+                if let &Type::Primitive(PrimitiveType::Char) = &**inner {
+                    if let Some(ref c_string) = config.types.c_string {
+                        if is_const {
+                            self.type_qualifers = "const".to_owned();
+                        }
+                        self.type_name = c_string.clone();
+                        return;
+                    }
+                }
+                self.declarators
+                    .push(CDeclarator::Ptr(is_const, is_nullable, false));
+                self.build_type(inner, true, config);
             }
-            &Type::Ptr(ref t) => {
-                self.declarators.push(CDeclarator::Ptr(is_const));
-                self.build_type(t, false);
+            &Type::Ptr(ref t, is_nullable) => {
+                self.declarators
+                    .push(CDeclarator::Ptr(is_const, is_nullable, false));
+                self.build_type(t, false, config);
             }
             &Type::Ref(ref t) => {
                 self.declarators.push(CDeclarator::Ref);
-                self.build_type(t, true);
+                self.build_type(t, true, config);
             }
             &Type::MutRef(ref t) => {
                 self.declarators.push(CDeclarator::Ref);
-                self.build_type(t, false);
+                self.build_type(t, false, config);
             }
             &Type::Array(ref t, ref constant) => {
                 let len = constant.as_str().to_owned();
                 self.declarators.push(CDeclarator::Array(len));
-                self.build_type(t, is_const);
+                self.build_type(t, is_const, config);
             }
-            &Type::FuncPtr(ref ret, ref args) => {
+            &Type::FuncPtr(ref ret, ref args, is_nullable, calling_convention, variadic) => {
                 let args = args
                     .iter()
-                    .map(|(ref name, ref ty)| (name.clone(), CDecl::from_type(ty)))
+                    .map(|(ref name, ref ty)| (name.clone(), CDecl::from_type(ty, config)))
                     .collect();
-                self.declarators.push(CDeclarator::Ptr(false));
-                self.declarators.push(CDeclarator::Func(args, false));
-                self.build_type(ret, false);
+                self.declarators
+                    .push(CDeclarator::Ptr(false, is_nullable, false));
+                self.declarators
+                    .push(CDeclarator::Func(args, false, calling_convention, variadic));
+                self.build_type(ret, false, config);
+            }
+            &Type::Atomic(ref t) if config.language == Language::Cxx => {
+                if is_const {
+                    self.type_qualifers = "const".to_owned();
+                }
+                self.type_name = "std::atomic".to_owned();
+                self.type_generic_args = vec![t.as_ref().clone()];
+            }
+            &Type::Atomic(ref t) => {
+                // Atomic pointers are qualified like const ones, other types
+                // like `_Atomic uint32_t`.
+                let declarators = self.declarators.len();
+                self.build_type(t, is_const, config);
+                match self.declarators.get_mut(declarators) {
+                    Some(&mut CDeclarator::Ptr(_, _, ref mut is_atomic)) => *is_atomic = true,
+                    _ if self.type_qualifers.is_empty() => {
+                        self.type_qualifers = "_Atomic".to_owned();
+                    }
+                    _ => self.type_qualifers.push_str(" _Atomic"),
+                }
+            }
+            &Type::ConstSlice(..) | &Type::Slice(..) | &Type::Tuple(..) => {
+                unreachable!("{:?} should have been lowered to a struct", t);
             }
         }
     }
@@ -168,6 +218,14 @@ impl CDecl {
         if !self.type_generic_args.is_empty() {
             out.write("<");
             out.write_horizontal_source_list(&self.type_generic_args, ListType::Join(", "));
+            // C++03 lexes `>>` as a shift operator.
+            let nested = match self.type_generic_args.last() {
+                Some(&Type::Path(ref generic)) => !generic.generics().is_empty(),
+                _ => false,
+            };
+            if nested && !out.bindings().config.has_version(LanguageVersion::Cxx11) {
+                out.write(" ");
+            }
             out.write(">");
         }
 
@@ -176,6 +234,13 @@ impl CDecl {
             out.write(" ");
         }
 
+        let language = out.bindings().config.language;
+        let nullability = {
+            let config = &out.bindings().config;
+            (config.swift.nullability || config.objc_compat)
+                && (config.language == Language::C || config.language == Language::Cxx)
+        };
+
         // Write the left part of declarators before the identifier
         let mut iter_rev = self.declarators.iter().rev().peekable();
 
@@ -183,11 +248,23 @@ impl CDecl {
             let next_is_pointer = iter_rev.peek().map_or(false, |x| x.is_ptr());
 
             match declarator {
-                &CDeclarator::Ptr(ref is_const) => {
-                    if *is_const {
-                        out.write("*const ");
-                    } else {
-                        out.write("*");
+                &CDeclarator::Ptr(is_const, is_nullable, is_atomic) => {
+                    out.write("*");
+                    if is_atomic {
+                        out.write("_Atomic ");
+                    }
+                    if is_const {
+                        out.write("const ");
+                    }
+                    if nullability {
+                        if is_nullable {
+                            out.write("_Nullable");
+                        } else {
+                            out.write("_Nonnull");
+                        }
+                        if ident.is_some() || iter_rev.peek().is_some() {
+                            out.write(" ");
+                        }
                     }
                 }
                 &CDeclarator::Ref => {
@@ -198,10 +275,13 @@ impl CDecl {
                         out.write("(");
                     }
                 }
-                &CDeclarator::Func(..) => {
+                &CDeclarator::Func(_, _, calling_convention, _) => {
                     if next_is_pointer {
                         out.write("(");
                     }
+                    if let Some(keyword) = calling_convention.keyword(language) {
+                        write!(out, "{} ", keyword);
+                    }
                 }
             }
         }
@@ -231,13 +311,13 @@ impl CDecl {
 
                     last_was_pointer = false;
                 }
-                &CDeclarator::Func(ref args, layout_vertical) => {
+                &CDeclarator::Func(ref args, layout_vertical, _, variadic) => {
                     if last_was_pointer {
                         out.write(")");
                     }
 
                     out.write("(");
-                    if args.is_empty() && void_prototype {
+                    if args.is_empty() && void_prototype && !variadic {
                         out.write("void");
                     }
                     if layout_vertical {
@@ -254,6 +334,13 @@ impl CDecl {
 
                             arg_ty.write(out, arg_ident, void_prototype);
                         }
+                        if variadic {
+                            if !args.is_empty() {
+                                out.write(",");
+                                out.new_line();
+                            }
+                            out.write("...");
+                        }
                         out.pop_tab();
                     } else {
                         for (i, &(ref arg_ident, ref arg_ty)) in args.iter().enumerate() {
@@ -266,6 +353,12 @@ impl CDecl {
 
                             arg_ty.write(out, arg_ident, void_prototype);
                         }
+                        if variadic {
+                            if !args.is_empty() {
+                                out.write(", ");
+                            }
+                            out.write("...");
+                        }
                     }
                     out.write(")");
 
@@ -282,13 +375,16 @@ pub fn write_func<F: Write>(
     layout_vertical: bool,
     void_prototype: bool,
 ) {
-    &CDecl::from_func(f, layout_vertical).write(out, Some(f.path().name()), void_prototype);
+    let cdecl = CDecl::from_func(f, layout_vertical, &out.bindings().config);
+    cdecl.write(out, Some(f.path().name()), void_prototype);
 }
 
 pub fn write_field<F: Write>(out: &mut SourceWriter<F>, t: &Type, ident: &str) {
-    &CDecl::from_type(t).write(out, Some(ident), false);
+    let cdecl = CDecl::from_type(t, &out.bindings().config);
+    cdecl.write(out, Some(ident), false);
 }
 
 pub fn write_type<F: Write>(out: &mut SourceWriter<F>, t: &Type) {
-    &CDecl::from_type(t).write(out, None, false);
+    let cdecl = CDecl::from_type(t, &out.bindings().config);
+    cdecl.write(out, None, false);
 }
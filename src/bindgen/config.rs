@@ -2,31 +2,48 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::default::Default;
 use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{self, BufReader};
-use std::path::Path as StdPath;
+use std::path::{Path as StdPath, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
 
+use regex::Regex;
 use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
-use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::{Deserialize, Deserializer, Error as _, MapAccess, SeqAccess, Visitor};
 
 use toml;
 
+use bindgen::cargo::cargo_expand::{ExpandedDirExpander, Expander, RustcExpander};
+use bindgen::diagnostic::{Lint, Severity};
 use bindgen::ir::annotation::AnnotationSet;
 use bindgen::ir::path::Path;
+use bindgen::ir::repr::ReprAlign;
+use bindgen::ir::PrimitiveType;
+use bindgen::rename::IdentifierType;
 pub use bindgen::rename::RenameRule;
+use bindgen::reserved;
 
 pub const VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
 /// A language type to generate bindings for.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Language {
     Cxx,
     C,
+    Python,
+    Cython,
+    Java,
+    Node,
+    D,
+    Zig,
+    Go,
+    Json,
+    Wasm,
 }
 
 impl FromStr for Language {
@@ -44,6 +61,27 @@ impl FromStr for Language {
             "C++" => Ok(Language::Cxx),
             "c" => Ok(Language::C),
             "C" => Ok(Language::C),
+            "python" => Ok(Language::Python),
+            "Python" => Ok(Language::Python),
+            "py" => Ok(Language::Python),
+            "cython" => Ok(Language::Cython),
+            "Cython" => Ok(Language::Cython),
+            "java" => Ok(Language::Java),
+            "Java" => Ok(Language::Java),
+            "node" => Ok(Language::Node),
+            "Node" => Ok(Language::Node),
+            "nodejs" => Ok(Language::Node),
+            "d" => Ok(Language::D),
+            "D" => Ok(Language::D),
+            "zig" => Ok(Language::Zig),
+            "Zig" => Ok(Language::Zig),
+            "go" => Ok(Language::Go),
+            "Go" => Ok(Language::Go),
+            "json" => Ok(Language::Json),
+            "Json" => Ok(Language::Json),
+            "JSON" => Ok(Language::Json),
+            "wasm" => Ok(Language::Wasm),
+            "Wasm" => Ok(Language::Wasm),
             _ => Err(format!("Unrecognized Language: '{}'.", s)),
         }
     }
@@ -51,6 +89,77 @@ impl FromStr for Language {
 
 deserialize_enum_str!(Language);
 
+impl Language {
+    /// The name of the language, as configs spell it.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match *self {
+            Language::Cxx => "C++",
+            Language::C => "C",
+            Language::Python => "Python",
+            Language::Cython => "Cython",
+            Language::Java => "Java",
+            Language::Node => "Node",
+            Language::D => "D",
+            Language::Zig => "Zig",
+            Language::Go => "Go",
+            Language::Json => "JSON",
+            Language::Wasm => "Wasm",
+        }
+    }
+}
+
+/// A standard of C or C++ to restrict the constructs of the bindings to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LanguageVersion {
+    C99,
+    C11,
+    Cxx03,
+    Cxx11,
+    Cxx14,
+    Cxx17,
+    Cxx20,
+}
+
+impl LanguageVersion {
+    pub fn language(self) -> Language {
+        match self {
+            LanguageVersion::C99 | LanguageVersion::C11 => Language::C,
+            _ => Language::Cxx,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LanguageVersion::C99 => "c99",
+            LanguageVersion::C11 => "c11",
+            LanguageVersion::Cxx03 => "c++03",
+            LanguageVersion::Cxx11 => "c++11",
+            LanguageVersion::Cxx14 => "c++14",
+            LanguageVersion::Cxx17 => "c++17",
+            LanguageVersion::Cxx20 => "c++20",
+        }
+    }
+}
+
+impl FromStr for LanguageVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<LanguageVersion, Self::Err> {
+        match s {
+            "c99" | "C99" => Ok(LanguageVersion::C99),
+            "c11" | "C11" => Ok(LanguageVersion::C11),
+            "c++03" | "C++03" | "cxx03" => Ok(LanguageVersion::Cxx03),
+            "c++11" | "C++11" | "cxx11" => Ok(LanguageVersion::Cxx11),
+            "c++14" | "C++14" | "cxx14" => Ok(LanguageVersion::Cxx14),
+            "c++17" | "C++17" | "cxx17" => Ok(LanguageVersion::Cxx17),
+            "c++20" | "C++20" | "cxx20" => Ok(LanguageVersion::Cxx20),
+            _ => Err(format!("Unrecognized LanguageVersion: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(LanguageVersion);
+
 /// A style of braces to use for generating code.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Braces {
@@ -100,6 +209,177 @@ impl FromStr for Layout {
 
 deserialize_enum_str!(Layout);
 
+/// How to lower functions returning `Result<T, E>`. `Result` is
+/// `repr(Rust)`, so the other styles assume a layout Rust doesn't guarantee,
+/// and the functions have to be written to match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultStyle {
+    /// Leave them as they are, so that they return an opaque `Result`.
+    None,
+    /// Return a generated tagged union of `T` and `E`.
+    TaggedUnion,
+    /// Return `E`, and write `T` through an extra `out` pointer argument.
+    OutParam,
+}
+
+impl FromStr for ResultStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ResultStyle, Self::Err> {
+        match s {
+            "None" => Ok(ResultStyle::None),
+            "none" => Ok(ResultStyle::None),
+            "TaggedUnion" => Ok(ResultStyle::TaggedUnion),
+            "tagged_union" => Ok(ResultStyle::TaggedUnion),
+            "OutParam" => Ok(ResultStyle::OutParam),
+            "out_param" => Ok(ResultStyle::OutParam),
+            _ => Err(format!("Unrecognized ResultStyle: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(ResultStyle);
+
+/// How to write `#[repr(transparent)]` structs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransparentStyle {
+    /// Replace them with the type they wrap wherever they're used.
+    Erase,
+    /// Write a typedef of the type they wrap.
+    Typedef,
+    /// Write a struct with their field, so that they're distinct types.
+    Struct,
+}
+
+impl Default for TransparentStyle {
+    fn default() -> TransparentStyle {
+        TransparentStyle::Typedef
+    }
+}
+
+impl FromStr for TransparentStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<TransparentStyle, Self::Err> {
+        match s {
+            "Erase" => Ok(TransparentStyle::Erase),
+            "erase" => Ok(TransparentStyle::Erase),
+            "Typedef" => Ok(TransparentStyle::Typedef),
+            "typedef" => Ok(TransparentStyle::Typedef),
+            "Struct" => Ok(TransparentStyle::Struct),
+            "struct" => Ok(TransparentStyle::Struct),
+            _ => Err(format!("Unrecognized TransparentStyle: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(TransparentStyle);
+
+/// How to write the `#define`s of the offsets of the fields of a struct.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldOffsetStyle {
+    /// Expand to `offsetof(Struct, field)`.
+    Offsetof,
+    /// Expand to the offsets computed from the layout of the struct.
+    Computed,
+}
+
+impl Default for FieldOffsetStyle {
+    fn default() -> FieldOffsetStyle {
+        FieldOffsetStyle::Offsetof
+    }
+}
+
+impl FromStr for FieldOffsetStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<FieldOffsetStyle, Self::Err> {
+        match s {
+            "Offsetof" => Ok(FieldOffsetStyle::Offsetof),
+            "offsetof" => Ok(FieldOffsetStyle::Offsetof),
+            "Computed" => Ok(FieldOffsetStyle::Computed),
+            "computed" => Ok(FieldOffsetStyle::Computed),
+            _ => Err(format!("Unrecognized FieldOffsetStyle: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(FieldOffsetStyle);
+
+/// How to write the structs generated by `bitflags!` or annotated with
+/// `bitflags`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitflagsStyle {
+    /// A struct wrapping the bits, with C++ operators.
+    Struct,
+    /// A typedef of the integer type of the bits, the flags being integer
+    /// constants.
+    Integer,
+}
+
+impl Default for BitflagsStyle {
+    fn default() -> BitflagsStyle {
+        BitflagsStyle::Struct
+    }
+}
+
+impl FromStr for BitflagsStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BitflagsStyle, Self::Err> {
+        match s {
+            "Struct" => Ok(BitflagsStyle::Struct),
+            "struct" => Ok(BitflagsStyle::Struct),
+            "Integer" => Ok(BitflagsStyle::Integer),
+            "integer" => Ok(BitflagsStyle::Integer),
+            _ => Err(format!("Unrecognized BitflagsStyle: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(BitflagsStyle);
+
+/// How to write the discriminants of enum variants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiscriminantFormat {
+    /// Like `10`.
+    Decimal,
+    /// Like `0xA`.
+    Hex,
+}
+
+impl Default for DiscriminantFormat {
+    fn default() -> DiscriminantFormat {
+        DiscriminantFormat::Decimal
+    }
+}
+
+impl FromStr for DiscriminantFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DiscriminantFormat, Self::Err> {
+        match s {
+            "Decimal" => Ok(DiscriminantFormat::Decimal),
+            "decimal" => Ok(DiscriminantFormat::Decimal),
+            "Hex" => Ok(DiscriminantFormat::Hex),
+            "hex" => Ok(DiscriminantFormat::Hex),
+            _ => Err(format!("Unrecognized DiscriminantFormat: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(DiscriminantFormat);
+
+impl DiscriminantFormat {
+    pub(crate) fn format(self, value: i64) -> String {
+        match self {
+            DiscriminantFormat::Decimal => value.to_string(),
+            DiscriminantFormat::Hex if value < 0 => format!("-0x{:X}", -(value as i128)),
+            DiscriminantFormat::Hex => format!("0x{:X}", value),
+        }
+    }
+}
+
 /// How the comments containing documentation should be styled.
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum DocumentationStyle {
@@ -107,6 +387,9 @@ pub enum DocumentationStyle {
     C99,
     Doxy,
     Cxx,
+    Doxygen,
+    Javadoc,
+    Qt,
     Auto,
 }
 
@@ -120,6 +403,9 @@ impl FromStr for DocumentationStyle {
             "cxx" => Ok(DocumentationStyle::Cxx),
             "c++" => Ok(DocumentationStyle::Cxx),
             "doxy" => Ok(DocumentationStyle::Doxy),
+            "doxygen" => Ok(DocumentationStyle::Doxygen),
+            "javadoc" => Ok(DocumentationStyle::Javadoc),
+            "qt" => Ok(DocumentationStyle::Qt),
             "auto" => Ok(DocumentationStyle::Auto),
             _ => Err(format!("Unrecognized documentation style: '{}'.", s)),
         }
@@ -172,6 +458,81 @@ impl FromStr for Style {
 
 deserialize_enum_str!(Style);
 
+/// Which structs and unions to forward declare, once items are sorted so that
+/// they're declared before they're used.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ForwardDeclarations {
+    /// Only the ones that pointers refer to before they're defined, which
+    /// happens with cycles.
+    Minimal,
+    /// All of them.
+    All,
+}
+
+impl FromStr for ForwardDeclarations {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ForwardDeclarations, Self::Err> {
+        match s {
+            "Minimal" | "minimal" => Ok(ForwardDeclarations::Minimal),
+            "All" | "all" => Ok(ForwardDeclarations::All),
+            _ => Err(format!("Unrecognized ForwardDeclarations: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(ForwardDeclarations);
+
+/// The order to write items of a kind in.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SortKey {
+    /// By their names.
+    Name,
+    /// In the order they're declared in the sources.
+    SourceOrder,
+    /// In the order they're found from the functions, globals and constants
+    /// that use them.
+    Dependency,
+}
+
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SortKey, Self::Err> {
+        match s {
+            "Name" | "name" => Ok(SortKey::Name),
+            "SourceOrder" | "source-order" | "source_order" => Ok(SortKey::SourceOrder),
+            "Dependency" | "dependency" => Ok(SortKey::Dependency),
+            _ => Err(format!("Unrecognized SortKey: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(SortKey);
+
+/// How to mark the sections that items are grouped into.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SectionStyle {
+    /// With a banner comment before each section.
+    Banner,
+    /// With `#pragma region` and `#pragma endregion` around each section.
+    Region,
+}
+
+impl FromStr for SectionStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SectionStyle, Self::Err> {
+        match s {
+            "Banner" | "banner" => Ok(SectionStyle::Banner),
+            "Region" | "region" | "pragma" => Ok(SectionStyle::Region),
+            _ => Err(format!("Unrecognized SectionStyle: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(SectionStyle);
+
 /// Different item types that we can generate and filter.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ItemType {
@@ -206,6 +567,54 @@ impl FromStr for ItemType {
 
 deserialize_enum_str!(ItemType);
 
+/// An ordered list of regexes and their replacements, of which the first
+/// matching one renames an item. Replacements can refer to the groups of the
+/// regex, like `$1`.
+#[derive(Debug, Clone, Default)]
+pub struct RegexRenames(Vec<(Regex, String)>);
+
+impl RegexRenames {
+    /// Renames `name` with the first matching rule, returning whether one
+    /// matched.
+    pub(crate) fn apply(&self, name: &mut String) -> bool {
+        for &(ref regex, ref replacement) in &self.0 {
+            if regex.is_match(name) {
+                *name = regex.replace(name, replacement.as_str()).into_owned();
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<'de> Deserialize<'de> for RegexRenames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RegexRenames, D::Error> {
+        let rules = Vec::<(String, String)>::deserialize(deserializer)?;
+        let mut out = Vec::new();
+        for (regex, replacement) in rules {
+            match Regex::new(&regex) {
+                Ok(regex) => out.push((regex, replacement)),
+                Err(e) => return Err(D::Error::custom(format!("Invalid rename rule: {}", e))),
+            }
+        }
+        Ok(RegexRenames(out))
+    }
+}
+
+/// Regex rename rules for each kind of item.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct RenameRulesConfig {
+    /// The rules renaming structs, unions, enums, typedefs and opaque items
+    pub types: RegexRenames,
+    /// The rules renaming constants
+    pub constants: RegexRenames,
+    /// The rules renaming enum variants, after `rename_variants`
+    pub enum_variants: RegexRenames,
+}
+
 /// Settings to apply when exporting items.
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -219,6 +628,14 @@ pub struct ExportConfig {
     pub exclude: Vec<String>,
     /// Table of name conversions to apply to item names
     pub rename: HashMap<String, String>,
+    /// Regex rules renaming the items `rename` doesn't
+    pub rename_rules: RenameRulesConfig,
+    /// Whether to mark `#[must_use]` items with the standard attributes when
+    /// their kind has no `must_use` spelling
+    pub must_use_defaults: bool,
+    /// Whether to mark `#[deprecated]` items with the standard attributes when
+    /// their kind has no `deprecated` spelling
+    pub deprecated_defaults: bool,
     /// Table of raw strings to append to the body of items.
     pub body: HashMap<String, String>,
     /// A prefix to add before the name of every item
@@ -227,28 +644,141 @@ pub struct ExportConfig {
     pub item_types: Vec<ItemType>,
     /// Whether renaming overrides or extends prefixing.
     pub renaming_overrides_prefixing: bool,
+    /// Settings for the instantiation of generic types.
+    pub instantiations: InstantiationsConfig,
+    /// A macro to define at the top of the bindings and put before every
+    /// exported function and global, marking them as exported from or
+    /// imported into a shared library
+    pub dll_export_macro: Option<String>,
+    /// The define that selects exporting over importing with
+    /// `dll_export_macro`, which defaults to the macro name with `_API`
+    /// replaced by `_BUILD`
+    pub dll_build_define: Option<String>,
+    /// The define that makes `dll_export_macro` empty for static builds, which
+    /// defaults to the macro name with `_API` replaced by `_STATIC`
+    pub dll_static_define: Option<String>,
+    /// The order to write types in, which overrides `sort_by`
+    pub sort_by: Option<SortKey>,
+    /// Table of the types declared by existing headers, like
+    /// `"othercrate::Foo" = "other_lib.h"`, which are included instead of
+    /// declaring the types
+    pub external: HashMap<String, String>,
+    /// Whether to hoist the function pointer types of function arguments,
+    /// return values and fields into typedefs named after where they're used,
+    /// or after their `callback-name` annotation
+    pub hoist_callbacks: bool,
+    /// The generic types to write as the type they wrap, which have the same
+    /// layout, like `MaybeUninit<T>`. Defaults to `STANDARD_WRAPPERS`
+    pub transparent_wrappers: Option<Vec<String>>,
+    /// The generic types to write as a non-null pointer to the type they
+    /// wrap, like `NonNull<T>`. Defaults to `STANDARD_POINTER_WRAPPERS`
+    pub pointer_wrappers: Option<Vec<String>>,
+    /// Whether to write `Box<T>` as an owning `T*`, and `Option<Box<T>>` as
+    /// a nullable one
+    pub lower_box: bool,
+    /// Whether to note the wrappers of `transparent_wrappers` and
+    /// `pointer_wrappers` in the documentation of what they're the type of
+    pub wrapper_comments: bool,
 }
 
+/// The standard wrappers that have the layout of the type they wrap.
+const STANDARD_WRAPPERS: &[&str] = &["MaybeUninit", "ManuallyDrop", "UnsafeCell", "Cell", "Pin"];
+
+/// The standard wrappers that have the layout of a non-null pointer to the
+/// type they wrap.
+const STANDARD_POINTER_WRAPPERS: &[&str] = &["NonNull"];
+
 impl ExportConfig {
     pub(crate) fn should_generate(&self, item_type: ItemType) -> bool {
         self.item_types.is_empty() || self.item_types.contains(&item_type)
     }
 
+    /// Whether the generic type `name` is written as the type it wraps.
+    pub(crate) fn is_transparent_wrapper(&self, name: &str) -> bool {
+        match self.transparent_wrappers {
+            Some(ref wrappers) => wrappers.iter().any(|x| x == name),
+            None => STANDARD_WRAPPERS.contains(&name),
+        }
+    }
+
+    /// Whether the generic type `name` is written as a pointer to the type it
+    /// wraps.
+    pub(crate) fn is_pointer_wrapper(&self, name: &str) -> bool {
+        match self.pointer_wrappers {
+            Some(ref wrappers) => wrappers.iter().any(|x| x == name),
+            None => STANDARD_POINTER_WRAPPERS.contains(&name),
+        }
+    }
+
+    /// The header declaring the type `name` of `crate_name`, if `external`
+    /// maps it or its unqualified name to one.
+    pub(crate) fn external_header(&self, crate_name: Option<&str>, name: &str) -> Option<&str> {
+        let qualified = crate_name.map(|x| format!("{}::{}", x.replace('-', "_"), name));
+        self.external
+            .iter()
+            .find(|&(key, _)| key == name || Some(key.replace('-', "_")) == qualified)
+            .map(|(_, header)| header.as_str())
+    }
+
     pub(crate) fn extra_body(&self, path: &Path) -> Option<&str> {
         self.body.get(path.name()).map(|s| s.trim_matches('\n'))
     }
 
     pub(crate) fn rename(&self, item_name: &mut String) {
-        if let Some(name) = self.rename.get(item_name) {
-            *item_name = name.clone();
-            if self.renaming_overrides_prefixing {
-                return;
+        self.rename_with(&self.rename_rules.types, item_name);
+    }
+
+    pub(crate) fn rename_constant(&self, item_name: &mut String) {
+        self.rename_with(&self.rename_rules.constants, item_name);
+    }
+
+    fn rename_with(&self, rules: &RegexRenames, item_name: &mut String) {
+        let renamed = match self.rename.get(item_name) {
+            Some(name) => {
+                *item_name = name.clone();
+                true
             }
+            None => rules.apply(item_name),
+        };
+        if renamed && self.renaming_overrides_prefixing {
+            return;
         }
         if let Some(ref prefix) = self.prefix {
             item_name.insert_str(0, &prefix);
         }
     }
+
+    /// Returns the build and static defines of `dll_export_macro`.
+    pub(crate) fn dll_defines(&self) -> Option<(String, String)> {
+        let name = self.dll_export_macro.as_ref()?;
+        let base = if name.ends_with("_API") {
+            &name[..name.len() - "_API".len()]
+        } else {
+            &name[..]
+        };
+        let build = self
+            .dll_build_define
+            .clone()
+            .unwrap_or_else(|| format!("{}_BUILD", base));
+        let static_ = self
+            .dll_static_define
+            .clone()
+            .unwrap_or_else(|| format!("{}_STATIC", base));
+        Some((build, static_))
+    }
+}
+
+/// Settings for the instantiation of generic types.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct InstantiationsConfig {
+    /// A list of generic types to instantiate even if no exported item uses
+    /// them, such as `Vec2<f32>`
+    pub types: Vec<String>,
+    /// An optional file to write the list of every instantiated generic type to
+    pub report: Option<String>,
 }
 
 /// Settings to apply to generated functions.
@@ -263,10 +793,36 @@ pub struct FunctionConfig {
     pub postfix: Option<String>,
     /// The way to annotation this function as #[must_use].
     pub must_use: Option<String>,
+    /// The way to annotation this function as #[deprecated] without a note.
+    pub deprecated: Option<String>,
+    /// The way to annotation this function as #[deprecated] with a note, `{}`
+    /// being replaced by the quoted note.
+    pub deprecated_with_note: Option<String>,
+    /// The way to annotate functions returning `!`, instead of the standard
+    /// attribute of the language.
+    pub no_return: Option<String>,
     /// The style to layout the args
     pub args: Layout,
     /// The rename rule to apply to function args
     pub rename_args: Option<RenameRule>,
+    /// How to lower functions returning `Result<T, E>`, which they're left
+    /// as by default since Rust doesn't guarantee the layout of `Result`
+    pub results: ResultStyle,
+    /// The order to write functions in, which overrides `sort_by`
+    pub sort_by: Option<SortKey>,
+    /// Globs selecting the functions and globals to export, which are all of
+    /// them if empty. Only the types these use are exported
+    pub include: Vec<String>,
+    /// Globs of the functions and globals not to export, even if `include`
+    /// selects them
+    pub exclude: Vec<String>,
+    /// Whether to declare the functions panics can't unwind out of `noexcept`
+    /// in C++, leaving out the `extern "C-unwind"` ones
+    pub cpp_noexcept: bool,
+    /// The bodies of the `static inline` functions to write for `#[inline]`
+    /// functions, by their names, which the `inline-body` annotation
+    /// overrides
+    pub inline_bodies: HashMap<String, String>,
 }
 
 impl Default for FunctionConfig {
@@ -275,13 +831,28 @@ impl Default for FunctionConfig {
             prefix: None,
             postfix: None,
             must_use: None,
+            deprecated: None,
+            deprecated_with_note: None,
+            no_return: None,
             args: Layout::Auto,
             rename_args: None,
+            results: ResultStyle::None,
+            sort_by: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            cpp_noexcept: false,
+            inline_bodies: HashMap::new(),
         }
     }
 }
 
 impl FunctionConfig {
+    /// Whether `include` and `exclude` select the function or global `name`.
+    pub(crate) fn selects(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|x| glob_matches(x, name)))
+            && !self.exclude.iter().any(|x| glob_matches(x, name))
+    }
+
     pub(crate) fn prefix(&self, annotations: &AnnotationSet) -> Option<String> {
         if let Some(x) = annotations.atom("prefix") {
             return x;
@@ -325,6 +896,33 @@ pub struct StructConfig {
     pub associated_constants_in_body: bool,
     /// The way to annotation this struct as #[must_use].
     pub must_use: Option<String>,
+    /// The way to annotation this struct as #[deprecated] without a note.
+    pub deprecated: Option<String>,
+    /// The way to annotation this struct as #[deprecated] with a note, `{}`
+    /// being replaced by the quoted note.
+    pub deprecated_with_note: Option<String>,
+    /// Whether to lower tuples into generated `Tuple_*` structs. Items that
+    /// use tuples are skipped otherwise. Tuples are `repr(Rust)`, so the order
+    /// of their fields isn't guaranteed to match the struct's.
+    pub tuples: bool,
+    /// Whether to generate C++ member functions calling the `extern "C"`
+    /// methods of structs and opaque items. Not applicable to transparent
+    /// structs, which are typedefs.
+    pub derive_methods: bool,
+    /// Whether to generate C++ operators calling the `extern "C"` functions
+    /// named after a struct or opaque item and a method of an operator trait
+    /// it implements, such as `point_add` for `Add`. Not applicable to
+    /// transparent structs, which are typedefs.
+    pub derive_operators: bool,
+    /// How to write `#[repr(transparent)]` structs, unless their
+    /// `transparent` annotation says otherwise
+    pub transparent: TransparentStyle,
+    /// How to write the offsets of the fields of structs with the
+    /// `field-offsets` annotation, unless it says otherwise
+    pub field_offsets: FieldOffsetStyle,
+    /// How to write the structs generated by `bitflags!` or annotated with
+    /// `bitflags`, unless the annotation says otherwise
+    pub bitflags: BitflagsStyle,
 }
 
 impl StructConfig {
@@ -370,6 +968,47 @@ impl StructConfig {
         }
         self.derive_gte
     }
+    pub(crate) fn derive_methods(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-methods") {
+            return x;
+        }
+        self.derive_methods
+    }
+    pub(crate) fn derive_operators(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-operators") {
+            return x;
+        }
+        self.derive_operators
+    }
+    /// How to write the offsets of the fields of the struct, if it has the
+    /// `field-offsets` annotation.
+    pub(crate) fn field_offsets(&self, annotations: &AnnotationSet) -> Option<FieldOffsetStyle> {
+        match annotations.bool("field-offsets") {
+            Some(true) => Some(self.field_offsets),
+            Some(false) => None,
+            None => annotations.parse_atom("field-offsets"),
+        }
+    }
+
+    /// How to write the struct, if it's generated by `bitflags!` or has the
+    /// `bitflags` annotation.
+    pub(crate) fn bitflags(&self, annotations: &AnnotationSet) -> Option<BitflagsStyle> {
+        match annotations.bool("bitflags") {
+            Some(true) => return Some(self.bitflags),
+            Some(false) => return None,
+            None => {}
+        }
+        if let Some(style) = annotations.parse_atom("bitflags") {
+            return Some(style);
+        }
+        if annotations
+            .bool("internal-derive-bitflags")
+            .unwrap_or(false)
+        {
+            return Some(self.bitflags);
+        }
+        None
+    }
 }
 
 /// Settings to apply to generated enums.
@@ -398,10 +1037,38 @@ pub struct EnumConfig {
     pub cast_assert_name: Option<String>,
     /// The way to annotation this enum as #[must_use].
     pub must_use: Option<String>,
+    /// The way to annotation this enum as #[deprecated] without a note.
+    pub deprecated: Option<String>,
+    /// The way to annotation this enum as #[deprecated] with a note, `{}`
+    /// being replaced by the quoted note.
+    pub deprecated_with_note: Option<String>,
     /// Whether to generate destructors of tagged enums.
     pub derive_tagged_enum_destructor: bool,
     /// Whether to generate copy-constructors of tagged enums.
     pub derive_tagged_enum_copy_constructor: bool,
+    /// Whether to generate a `NAME_FOR_EACH(V)` X-macro listing the variants
+    /// of fieldless enums.
+    pub derive_for_each_macro: bool,
+    /// Whether to generate a `Name_to_string()` function returning the name
+    /// of a variant of fieldless enums.
+    pub derive_to_string: bool,
+    /// Whether to define the `name()` functions of enums with the
+    /// `error-enum` annotation as `static inline` functions, instead of only
+    /// declaring them. Can be set per enum with the `define-error-name`
+    /// annotation.
+    pub define_error_names: bool,
+    /// How to write the discriminants of the variants. Can be set per enum
+    /// with the `discriminant-format` annotation.
+    pub discriminant_format: DiscriminantFormat,
+    /// Whether to write the discriminant of every variant, counting on from
+    /// the previous one for those that don't have one in Rust. Can be set per
+    /// enum with the `explicit-discriminants` annotation.
+    pub explicit_discriminants: bool,
+    /// The integer type to use as the underlying type of enums without a
+    /// `#[repr(prim)]`, such as `uint8_t`, as if they had it. Can be set per
+    /// enum with the `enum-underlying` annotation, which takes precedence
+    /// over the `#[repr(prim)]`.
+    pub underlying_type: Option<String>,
 }
 
 impl EnumConfig {
@@ -441,6 +1108,35 @@ impl EnumConfig {
         }
         self.derive_tagged_enum_copy_constructor
     }
+    pub(crate) fn derive_for_each_macro(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-for-each-macro") {
+            return x;
+        }
+        self.derive_for_each_macro
+    }
+    pub(crate) fn derive_to_string(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-to-string") {
+            return x;
+        }
+        self.derive_to_string
+    }
+    pub(crate) fn define_error_names(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("define-error-name") {
+            return x;
+        }
+        self.define_error_names
+    }
+    pub(crate) fn discriminant_format(&self, annotations: &AnnotationSet) -> DiscriminantFormat {
+        annotations
+            .parse_atom("discriminant-format")
+            .unwrap_or(self.discriminant_format)
+    }
+    pub(crate) fn explicit_discriminants(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("explicit-discriminants") {
+            return x;
+        }
+        self.explicit_discriminants
+    }
 }
 
 /// Settings to apply to generated constants.
@@ -451,81 +1147,1170 @@ impl EnumConfig {
 pub struct ConstantConfig {
     /// Whether a generated constant can be a static const in C++ mode.
     pub allow_static_const: bool,
+    /// Optional text to output before each static const
+    pub prefix: Option<String>,
+    /// Optional text to output after the name of each static const
+    pub postfix: Option<String>,
+    /// How to write the associated constants that aren't written in the body
+    /// of their type. By default they're written like other constants
+    pub associated_style: Option<ConstantStyle>,
+    /// The rename rule to apply to the name of the type that associated
+    /// constants are prefixed with
+    pub rename_associated_type: Option<RenameRule>,
+    /// The text between the name of the type and the name of an associated
+    /// constant
+    pub associated_separator: String,
+    /// The order to write constants and globals in, which overrides `sort_by`
+    pub sort_by: Option<SortKey>,
 }
 
 impl Default for ConstantConfig {
     fn default() -> ConstantConfig {
         ConstantConfig {
             allow_static_const: true,
+            prefix: None,
+            postfix: None,
+            associated_style: None,
+            rename_associated_type: None,
+            associated_separator: "_".to_owned(),
+            sort_by: None,
         }
     }
 }
 
-/// Settings for custom macro expansion.
-#[derive(Debug, Clone, Deserialize, Default)]
-#[serde(rename_all = "snake_case")]
-#[serde(deny_unknown_fields)]
-#[serde(default)]
-pub struct MacroExpansionConfig {
-    /// Whether the `bitflags` macro should be expanded.
-    pub bitflags: bool,
+/// How a constant is written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstantStyle {
+    /// As a `#define`.
+    Define,
+    /// As a `static const`.
+    StaticConst,
 }
 
-/// Settings to apply when running `rustc --pretty=expanded`
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "snake_case")]
-#[serde(deny_unknown_fields)]
-#[serde(default)]
-pub struct ParseExpandConfig {
-    /// The names of crates to parse with `rustc --pretty=expanded`
-    pub crates: Vec<String>,
-    /// Whether to enable all the features when expanding.
-    pub all_features: bool,
-    /// Whether to use the default feature set when expanding.
-    pub default_features: bool,
-    /// List of features to use when expanding. Combines with `default_features` like in
-    /// `Cargo.toml`.
-    pub features: Option<Vec<String>>,
-}
+impl FromStr for ConstantStyle {
+    type Err = String;
 
-impl Default for ParseExpandConfig {
-    fn default() -> ParseExpandConfig {
-        ParseExpandConfig {
-            crates: Vec::new(),
-            all_features: false,
-            default_features: true,
-            features: None,
+    fn from_str(s: &str) -> Result<ConstantStyle, Self::Err> {
+        match s {
+            "define" | "Define" => Ok(ConstantStyle::Define),
+            "static_const" | "StaticConst" => Ok(ConstantStyle::StaticConst),
+            _ => Err(format!("Unrecognized constant style: '{}'.", s)),
         }
     }
 }
 
-// Backwards-compatibility deserializer for ParseExpandConfig. This allows accepting both the
-// simple `expand = ["crate"]` and the more complex `expand = {"crates": ["crate"],
-// "default_features": false}` format for the `expand` key.
-//
-// Note that one (major) difference between the two forms is that, for backwards-compatibility
-// reasons, the `expand = ["crate"]` form will enable the `--all-features` flag by default while
-// the `expand = {"crates": ["crate"]}` form will use the default feature set by default.
-fn retrocomp_parse_expand_config_deserialize<'de, D: Deserializer<'de>>(
-    deserializer: D,
-) -> Result<ParseExpandConfig, D::Error> {
-    struct ParseExpandVisitor;
+deserialize_enum_str!(ConstantStyle);
 
-    impl<'de> Visitor<'de> for ParseExpandVisitor {
-        type Value = ParseExpandConfig;
+impl ConstantConfig {
+    /// The name an associated constant is written with outside the body of
+    /// its type, like `Foo_BAR`.
+    pub(crate) fn associated_name(&self, type_name: &str, name: &str) -> String {
+        let type_name = match self.rename_associated_type {
+            Some(rule) => rule.apply_to_pascal_case(type_name, IdentifierType::Type),
+            None => type_name.to_owned(),
+        };
+        format!("{}{}{}", type_name, self.associated_separator, name)
+    }
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a map or sequence of string")
+    pub(crate) fn prefix(&self, annotations: &AnnotationSet) -> Option<String> {
+        if let Some(x) = annotations.atom("prefix") {
+            return x;
         }
+        self.prefix.clone()
+    }
 
-        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
-            let crates =
+    pub(crate) fn postfix(&self, annotations: &AnnotationSet) -> Option<String> {
+        if let Some(x) = annotations.atom("postfix") {
+            return x;
+        }
+        self.postfix.clone()
+    }
+}
+
+/// Settings to apply to generated globals.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct GlobalConfig {
+    /// Optional text to output before each global declaration
+    pub prefix: Option<String>,
+    /// Optional text to output after the name of each global declaration
+    pub postfix: Option<String>,
+}
+
+impl GlobalConfig {
+    pub(crate) fn prefix(&self, annotations: &AnnotationSet) -> Option<String> {
+        if let Some(x) = annotations.atom("prefix") {
+            return x;
+        }
+        self.prefix.clone()
+    }
+
+    pub(crate) fn postfix(&self, annotations: &AnnotationSet) -> Option<String> {
+        if let Some(x) = annotations.atom("postfix") {
+            return x;
+        }
+        self.postfix.clone()
+    }
+}
+
+/// Settings to apply to slices.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SliceConfig {
+    /// Whether to lower slices into generated view structs holding a pointer
+    /// and a length. Items that use slices are skipped otherwise.
+    pub views: bool,
+    /// The name of views into constant slices, which is suffixed with the
+    /// element type
+    pub name: String,
+    /// The name of views into mutable slices, which is suffixed with the
+    /// element type
+    pub mut_name: String,
+}
+
+impl Default for SliceConfig {
+    fn default() -> SliceConfig {
+        SliceConfig {
+            views: false,
+            name: "Slice".to_owned(),
+            mut_name: "MutSlice".to_owned(),
+        }
+    }
+}
+
+/// How to write `i128` and `u128`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Int128Style {
+    /// As `__int128` and `unsigned __int128`, which GCC and Clang support on
+    /// 64-bit targets.
+    Native,
+    /// As generated structs holding the low and high 64 bits.
+    Struct,
+}
+
+impl Default for Int128Style {
+    fn default() -> Int128Style {
+        Int128Style::Native
+    }
+}
+
+impl FromStr for Int128Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Int128Style, Self::Err> {
+        match s {
+            "Native" => Ok(Int128Style::Native),
+            "native" => Ok(Int128Style::Native),
+            "Struct" => Ok(Int128Style::Struct),
+            "struct" => Ok(Int128Style::Struct),
+            _ => Err(format!("Unrecognized Int128Style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(Int128Style);
+
+/// Settings to apply to 128-bit integers.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct Int128Config {
+    /// How to write 128-bit integers in C and C++, which Go follows since it
+    /// goes through the C header. The languages without native 128-bit
+    /// integers always use structs, except for WebAssembly, which splits them
+    /// into two `i64`s
+    pub style: Int128Style,
+    /// The name of the struct `i128` is lowered into
+    pub name: String,
+    /// The name of the struct `u128` is lowered into
+    pub unsigned_name: String,
+}
+
+impl Default for Int128Config {
+    fn default() -> Int128Config {
+        Int128Config {
+            style: Int128Style::default(),
+            name: "Int128".to_owned(),
+            unsigned_name: "UInt128".to_owned(),
+        }
+    }
+}
+
+impl Int128Config {
+    /// Whether 128-bit integers are lowered into structs for `language`.
+    pub(crate) fn lowers(&self, language: Language) -> bool {
+        match language {
+            Language::C | Language::Cxx | Language::Go => self.style == Int128Style::Struct,
+            Language::Zig | Language::Json | Language::Wasm => false,
+            _ => true,
+        }
+    }
+}
+
+/// What to do about generated identifiers that collide with C or C++
+/// keywords or macros.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KeywordCollisions {
+    /// Append an underscore to them, and warn about the functions and globals,
+    /// whose symbols can't be renamed.
+    Rename,
+    /// Fail with an error listing all of them.
+    Error,
+}
+
+impl Default for KeywordCollisions {
+    fn default() -> KeywordCollisions {
+        KeywordCollisions::Rename
+    }
+}
+
+impl FromStr for KeywordCollisions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<KeywordCollisions, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "rename" => Ok(KeywordCollisions::Rename),
+            "error" => Ok(KeywordCollisions::Error),
+            _ => Err(format!("Unrecognized KeywordCollisions: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(KeywordCollisions);
+
+/// Settings for identifiers that collide with keywords and macros.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct KeywordsConfig {
+    /// What to do about the collisions
+    pub collisions: KeywordCollisions,
+    /// Identifiers to treat as reserved besides the C and C++ keywords and
+    /// the common macros, like those of other headers
+    pub reserved: Vec<String>,
+}
+
+impl KeywordsConfig {
+    /// Whether `name` collides with a keyword or macro.
+    pub(crate) fn is_reserved(&self, name: &str) -> bool {
+        reserved::is_reserved(name) || self.reserved.iter().any(|x| x == name)
+    }
+
+    /// Appends an underscore to `name` if it collides with a keyword or
+    /// macro and collisions are renamed.
+    pub(crate) fn escape(&self, name: &mut String) {
+        if self.collisions == KeywordCollisions::Rename && self.is_reserved(name) {
+            name.push('_');
+        }
+    }
+}
+
+/// How diagnostics are written.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MessageFormat {
+    /// As log messages.
+    Human,
+    /// As newline-delimited JSON objects on stderr.
+    Json,
+}
+
+impl Default for MessageFormat {
+    fn default() -> MessageFormat {
+        MessageFormat::Human
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<MessageFormat, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!("Unrecognized MessageFormat: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(MessageFormat);
+
+/// Settings for the severity of diagnostics.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DiagnosticsConfig {
+    /// The lints whose diagnostics make generating the bindings fail
+    pub deny: Vec<Lint>,
+    /// The lints whose diagnostics aren't reported
+    pub allow: Vec<Lint>,
+    /// Whether the diagnostics of the other lints fail too
+    pub warnings_as_errors: bool,
+    /// How the diagnostics are written
+    pub message_format: MessageFormat,
+}
+
+impl DiagnosticsConfig {
+    pub(crate) fn severity(&self, lint: Lint) -> Severity {
+        if self.deny.contains(&lint) {
+            Severity::Deny
+        } else if self.allow.contains(&lint) {
+            Severity::Allow
+        } else if self.warnings_as_errors {
+            Severity::Deny
+        } else {
+            lint.default_severity()
+        }
+    }
+}
+
+/// Settings for custom macro expansion.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct MacroExpansionConfig {
+    /// Whether the `bitflags` macro should be expanded.
+    pub bitflags: bool,
+    /// Whether the `macro_rules!` macros of a crate should be expanded where
+    /// the crate invokes them on items.
+    pub macro_rules: bool,
+}
+
+/// Settings specific to Cython bindings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CythonConfig {
+    /// The header the `cdef extern` block declares items from. Without it the
+    /// block is declared `from *`, and the header must be included elsewhere.
+    pub header: Option<String>,
+}
+
+impl Default for CythonConfig {
+    fn default() -> CythonConfig {
+        CythonConfig { header: None }
+    }
+}
+
+/// How Java bindings call into the library.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JavaInterface {
+    /// A JNA `Library` interface, which calls the exported functions directly.
+    Jna,
+    /// A class with JNI `native` methods, which the library has to implement.
+    Jni,
+}
+
+impl FromStr for JavaInterface {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<JavaInterface, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "jna" => Ok(JavaInterface::Jna),
+            "jni" => Ok(JavaInterface::Jni),
+            _ => Err(format!("Unrecognized Java interface: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(JavaInterface);
+
+/// Settings to apply when generating Java bindings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct JavaConfig {
+    /// The package of the generated class
+    pub package: Option<String>,
+    /// The name of the generated interface or class
+    pub class_name: String,
+    /// The name of the library to load, such as `foo` for `libfoo.so`.
+    /// Without it the library has to be loaded by the user of the bindings.
+    pub library: Option<String>,
+    /// Whether to generate a JNA interface or JNI native methods
+    pub interface: JavaInterface,
+}
+
+impl Default for JavaConfig {
+    fn default() -> JavaConfig {
+        JavaConfig {
+            package: None,
+            class_name: "Bindings".to_owned(),
+            library: None,
+            interface: JavaInterface::Jna,
+        }
+    }
+}
+
+/// Settings to apply when generating D bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DConfig {
+    /// The name of the generated module, such as `mylib.bindings`. Without
+    /// it, the module is named after the file.
+    pub module: Option<String>,
+}
+
+/// Settings to apply when generating Go bindings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct GoConfig {
+    /// The package of the generated file
+    pub package: String,
+    /// The C header to include in the cgo preamble. Without it, the header is
+    /// named after the package.
+    pub header: Option<String>,
+    /// The `#cgo CFLAGS` of the preamble, such as include paths
+    pub cflags: Vec<String>,
+    /// The `#cgo LDFLAGS` of the preamble, such as the library to link
+    pub ldflags: Vec<String>,
+}
+
+impl Default for GoConfig {
+    fn default() -> GoConfig {
+        GoConfig {
+            package: "bindings".to_owned(),
+            header: None,
+            cflags: Vec::new(),
+            ldflags: Vec::new(),
+        }
+    }
+}
+
+/// Settings to group the items of C and C++ bindings into labeled sections.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SectionConfig {
+    /// How to mark the sections, which items are only grouped into when set
+    pub style: Option<SectionStyle>,
+    /// The comment to write before each section with the banner style, with
+    /// `{}` replaced by the name of the section
+    pub banner: String,
+    /// The sections to write first, in order. The standard sections come
+    /// after them, and then the other ones by name
+    pub order: Vec<String>,
+}
+
+impl Default for SectionConfig {
+    fn default() -> SectionConfig {
+        SectionConfig {
+            style: None,
+            banner: "/* {} */".to_owned(),
+            order: Vec::new(),
+        }
+    }
+}
+
+/// The sections of constants, types, globals and functions, in the order
+/// they're written in unless `order` changes it.
+pub const STANDARD_SECTIONS: [&'static str; 7] = [
+    "Constants",
+    "Enums",
+    "Structs",
+    "Typedefs",
+    "Callbacks",
+    "Globals",
+    "Functions",
+];
+
+impl SectionConfig {
+    /// The key to sort sections by.
+    pub(crate) fn rank(&self, section: &Option<String>) -> (usize, String) {
+        let name = match *section {
+            Some(ref name) => name,
+            None => return (0, String::new()),
+        };
+        if let Some(i) = self.order.iter().position(|x| x == name) {
+            return (i, String::new());
+        }
+        match STANDARD_SECTIONS.iter().position(|x| x == name) {
+            Some(i) => (self.order.len() + i, String::new()),
+            None => (self.order.len() + STANDARD_SECTIONS.len(), name.clone()),
+        }
+    }
+}
+
+/// Settings to render bindings with templates instead of the built-in
+/// writers.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct TemplateConfig {
+    /// The Handlebars template to render the bindings of each language with,
+    /// relative to the config file. The other languages are written by their
+    /// built-in writers
+    pub files: HashMap<Language, PathBuf>,
+    /// Whether rendering values missing from the bindings is an error
+    pub strict: bool,
+}
+
+impl TemplateConfig {
+    /// Makes the templates relative to `dir`.
+    pub(crate) fn resolve_files(&mut self, dir: &StdPath) {
+        for file in self.files.values_mut() {
+            *file = dir.join(&*file);
+        }
+    }
+}
+
+/// Code to inject into the bindings, given inline or as a file to read.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Injection {
+    /// The code itself
+    Text(String),
+    /// The file to read the code from, relative to the config file
+    File {
+        file: PathBuf,
+        #[serde(skip)]
+        text: Option<String>,
+    },
+}
+
+impl Injection {
+    /// The code to inject, which files have been read into when the bindings
+    /// were generated.
+    pub(crate) fn text(&self) -> &str {
+        match *self {
+            Injection::Text(ref text)
+            | Injection::File {
+                text: Some(ref text),
+                ..
+            } => text.trim_matches('\n'),
+            Injection::File { ref file, .. } => unreachable!("{:?} wasn't read", file),
+        }
+    }
+}
+
+/// Settings to inject raw code at points of C and C++ bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct InjectConfig {
+    /// The code to write after the includes
+    pub after_includes: Option<Injection>,
+    /// The code to write before the constants
+    pub before_constants: Option<Injection>,
+    /// The code to write before the types
+    pub before_types: Option<Injection>,
+    /// The code to write after the last enum
+    pub after_enums: Option<Injection>,
+    /// The code to write after the types
+    pub after_types: Option<Injection>,
+    /// The code to write before the globals and functions
+    pub before_functions: Option<Injection>,
+    /// The code to write after the globals and functions
+    pub after_functions: Option<Injection>,
+    /// The code that `before` and `after` annotations write before or after
+    /// items, by name
+    pub snippets: HashMap<String, Injection>,
+}
+
+impl InjectConfig {
+    fn injections(&self) -> Vec<&Injection> {
+        let mut out: Vec<&Injection> = vec![
+            &self.after_includes,
+            &self.before_constants,
+            &self.before_types,
+            &self.after_enums,
+            &self.after_types,
+            &self.before_functions,
+            &self.after_functions,
+        ]
+        .into_iter()
+        .filter_map(|x| x.as_ref())
+        .collect();
+        out.extend(self.snippets.values());
+        out
+    }
+
+    fn injections_mut(&mut self) -> Vec<&mut Injection> {
+        let mut out: Vec<&mut Injection> = vec![
+            &mut self.after_includes,
+            &mut self.before_constants,
+            &mut self.before_types,
+            &mut self.after_enums,
+            &mut self.after_types,
+            &mut self.before_functions,
+            &mut self.after_functions,
+        ]
+        .into_iter()
+        .filter_map(|x| x.as_mut())
+        .collect();
+        out.extend(self.snippets.values_mut());
+        out
+    }
+
+    /// Makes the files to read relative to `dir`.
+    pub(crate) fn resolve_files(&mut self, dir: &StdPath) {
+        for injection in self.injections_mut() {
+            if let Injection::File { ref mut file, .. } = *injection {
+                *file = dir.join(&*file);
+            }
+        }
+    }
+
+    /// Reads the files to inject, returning the first that can't be read and
+    /// why.
+    pub(crate) fn read_files(&mut self) -> Result<(), (PathBuf, io::Error)> {
+        for injection in self.injections_mut() {
+            if let Injection::File {
+                ref file,
+                ref mut text,
+            } = *injection
+            {
+                let mut contents = String::new();
+                File::open(file)
+                    .and_then(|mut x| x.read_to_string(&mut contents))
+                    .map_err(|e| (file.clone(), e))?;
+                *text = Some(contents);
+            }
+        }
+        Ok(())
+    }
+
+    /// The files the injected code is read from.
+    pub(crate) fn files(&self) -> Vec<&StdPath> {
+        self.injections()
+            .into_iter()
+            .filter_map(|x| match *x {
+                Injection::File { ref file, .. } => Some(file.as_path()),
+                Injection::Text(..) => None,
+            })
+            .collect()
+    }
+
+    /// The snippet named by a `before` or `after` annotation.
+    pub(crate) fn snippet(&self, annotations: &AnnotationSet, name: &str) -> Option<&str> {
+        let snippet = match annotations.atom(name) {
+            Some(Some(snippet)) => snippet,
+            _ => return None,
+        };
+        match self.snippets.get(&snippet) {
+            Some(injection) => Some(injection.text()),
+            None => {
+                warn!("Unknown snippet `{}` to inject.", snippet);
+                None
+            }
+        }
+    }
+}
+
+/// Settings to apply when generating WebAssembly descriptors.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct WasmConfig {
+    /// The module the functions of `extern` blocks are imported from
+    pub import_module: String,
+}
+
+impl Default for WasmConfig {
+    fn default() -> WasmConfig {
+        WasmConfig {
+            import_module: "env".to_owned(),
+        }
+    }
+}
+
+/// Settings for the Kotlin/Native cinterop definition file written with
+/// `--kotlin-def`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct KotlinConfig {
+    /// The package of the generated Kotlin bindings
+    pub package: Option<String>,
+    /// The options to pass to the C compiler, such as include paths
+    pub compiler_opts: Vec<String>,
+    /// The options to pass to the linker, such as the library to link
+    pub linker_opts: Vec<String>,
+}
+
+/// Quotes a deprecation note as a C string literal.
+fn quote_note(note: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in note.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Settings routing items into secondary headers next to the main one.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct OutputConfig {
+    /// The secondary headers, with the globs of the names of the items they
+    /// get
+    pub files: BTreeMap<String, Vec<String>>,
+    /// Whether the main header includes the secondary headers at its end
+    pub include_in_main: bool,
+}
+
+impl OutputConfig {
+    /// The secondary header an item goes to, given by the `output` annotation
+    /// or the first header with a glob matching its name, or `None` for the
+    /// main header.
+    pub(crate) fn output_of(&self, name: &str, annotations: &AnnotationSet) -> Option<String> {
+        if let Some(output) = annotations.atom("output") {
+            return output;
+        }
+        self.files
+            .iter()
+            .find(|&(_, globs)| globs.iter().any(|glob| glob_matches(glob, name)))
+            .map(|(file, _)| file.clone())
+    }
+}
+
+/// A crate of the workspace whose items go to their own header.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceMember {
+    /// The name of the crate
+    pub name: String,
+    /// The header its items go to, like the ones of `[output]`, or none for
+    /// the main header
+    pub output: Option<String>,
+}
+
+/// Settings generating the headers of several crates of a workspace at once.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct WorkspaceConfig {
+    /// The crates to parse along with the binding crate, each with its own
+    /// header
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl WorkspaceConfig {
+    /// The header of the items of `crate_name`, if it's a member with one.
+    pub(crate) fn output_of(&self, crate_name: &str) -> Option<&str> {
+        self.members
+            .iter()
+            .find(|x| x.name == crate_name)
+            .and_then(|x| x.output.as_ref())
+            .map(|x| x.as_str())
+    }
+}
+
+/// Whether `name` matches `glob`, in which `*` matches any characters and `?`
+/// a single one.
+fn glob_matches(glob: &str, name: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // The glob and name positions to retry from when a `*` has to match more.
+    let mut retry = None;
+    let (mut g, mut n) = (0, 0);
+    while n < name.len() {
+        match glob.get(g) {
+            Some(&'*') => {
+                retry = Some((g, n));
+                g += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                g += 1;
+                n += 1;
+            }
+            _ => match retry {
+                Some((star, start)) => {
+                    retry = Some((star, start + 1));
+                    g = star + 1;
+                    n = start + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|&c| c == '*')
+}
+
+/// Settings for Clang's thread safety analysis.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ThreadSafetyConfig {
+    /// The macro marking fields with the `guarded-by` annotation
+    pub guarded_by: String,
+    /// The macro marking functions with the `requires-lock` annotation
+    pub requires: String,
+}
+
+impl Default for ThreadSafetyConfig {
+    fn default() -> ThreadSafetyConfig {
+        ThreadSafetyConfig {
+            guarded_by: "CBINDGEN_GUARDED_BY".to_owned(),
+            requires: "CBINDGEN_REQUIRES".to_owned(),
+        }
+    }
+}
+
+/// Settings to map the character and string types to other C types.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct TypesConfig {
+    /// The type of `c_char`, instead of `char`
+    pub c_char: Option<String>,
+    /// The type of Rust's `char`, instead of `wchar_t`, such as `char32_t` or
+    /// `uint32_t`
+    #[serde(rename = "char")]
+    pub rust_char: Option<String>,
+    /// The type of `*const c_char`, such as a `typedef` for C strings
+    pub c_string: Option<String>,
+    /// The type of `c_long`, instead of `long`, such as `int64_t`
+    pub c_long: Option<String>,
+    /// The type of `c_ulong`, instead of `unsigned long`, such as `uint64_t`
+    pub c_ulong: Option<String>,
+    /// The type of `usize`, instead of `uintptr_t`, such as `size_t`
+    pub usize: Option<String>,
+    /// The type of `isize`, instead of `intptr_t`, such as `ptrdiff_t`
+    pub isize: Option<String>,
+}
+
+impl TypesConfig {
+    /// The type to write a primitive type as, if it's mapped.
+    pub(crate) fn primitive(&self, primitive: &PrimitiveType) -> Option<&str> {
+        let mapped = match *primitive {
+            PrimitiveType::Char => &self.c_char,
+            PrimitiveType::WChar => &self.rust_char,
+            PrimitiveType::Long => &self.c_long,
+            PrimitiveType::ULong => &self.c_ulong,
+            PrimitiveType::USize => &self.usize,
+            PrimitiveType::ISize => &self.isize,
+            _ => return None,
+        };
+        mapped.as_ref().map(|x| x.as_str())
+    }
+
+    /// The platform-dependent integer types that are mapped to other types,
+    /// whose sizes are asserted to match.
+    pub(crate) fn mapped_platform_types(&self) -> Vec<(PrimitiveType, &str)> {
+        [
+            PrimitiveType::Long,
+            PrimitiveType::ULong,
+            PrimitiveType::USize,
+            PrimitiveType::ISize,
+        ]
+        .iter()
+        .filter_map(|primitive| Some((primitive.clone(), self.primitive(primitive)?)))
+        .collect()
+    }
+}
+
+/// Settings to guard the items introduced in later versions of the API.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ApiVersionConfig {
+    /// The macro holding the API version to compile against, such as
+    /// `MYLIB_API_VERSION`. Items with the `since` annotation are only guarded
+    /// when set
+    pub define: Option<String>,
+    /// The latest version of the API, which the macro defaults to. Without it
+    /// the latest version any item is annotated with is used
+    pub current: Option<String>,
+}
+
+impl ApiVersionConfig {
+    /// The `#if` condition guarding an item with the `since` annotation, if
+    /// any.
+    pub(crate) fn guard(&self, annotations: &AnnotationSet) -> Option<String> {
+        let define = self.define.as_ref()?;
+        let since = match annotations.atom("since") {
+            Some(Some(since)) => since,
+            _ => return None,
+        };
+        match encode_api_version(&since) {
+            Some(version) => Some(format!("{} >= {}", define, version)),
+            None => {
+                warn!(
+                    "Ignoring `since={}`, which isn't a `major.minor` version.",
+                    since
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Encodes a `major.minor` version as a `0xMMmm` literal, like `0x0102` for
+/// `1.2`.
+pub(crate) fn encode_api_version(version: &str) -> Option<String> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse::<u8>().ok()?;
+    let minor = match parts.next() {
+        Some(minor) => minor.parse::<u8>().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(format!("0x{:02X}{:02X}", major, minor))
+}
+
+/// Settings to make the generated header import cleanly into Swift.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SwiftConfig {
+    /// Whether to annotate pointers with `_Nonnull` and `_Nullable`
+    pub nullability: bool,
+    /// The macro to use for Swift names, such as `CF_SWIFT_NAME`. Without it
+    /// `__attribute__((swift_name))` is used.
+    pub swift_name_macro: Option<String>,
+    /// The rename rule used to derive the Swift name of functions
+    pub rename_functions: Option<RenameRule>,
+}
+
+impl Default for SwiftConfig {
+    fn default() -> SwiftConfig {
+        SwiftConfig {
+            nullability: false,
+            swift_name_macro: None,
+            rename_functions: None,
+        }
+    }
+}
+
+impl SwiftConfig {
+    /// Returns the attribute giving a type the Swift name from its
+    /// `swift-name` annotation, if any.
+    pub(crate) fn type_name_attribute(&self, annotations: &AnnotationSet) -> Option<String> {
+        match annotations.atom("swift-name") {
+            Some(Some(name)) => Some(self.name_attribute(&name)),
+            _ => None,
+        }
+    }
+
+    /// Returns the attribute giving an item the Swift name `name`.
+    pub(crate) fn name_attribute(&self, name: &str) -> String {
+        match self.swift_name_macro {
+            Some(ref swift_name_macro) => format!("{}({})", swift_name_macro, name),
+            None => format!("__attribute__((swift_name(\"{}\")))", name),
+        }
+    }
+}
+
+/// A family of compilers, whose syntax is used for packed and aligned structs.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Compiler {
+    /// GCC and Clang, which use `__attribute__`s.
+    Gnu,
+    /// MSVC, which uses `#pragma pack` and `__declspec`.
+    Msvc,
+}
+
+impl FromStr for Compiler {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Compiler, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "gnu" | "gcc" | "clang" => Ok(Compiler::Gnu),
+            "msvc" => Ok(Compiler::Msvc),
+            _ => Err(format!("Unrecognized Compiler: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(Compiler);
+
+/// Settings to apply to `#[repr(packed)]` and `#[repr(align(N))]` structs.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// The compiler family to write packed and aligned structs for
+    pub compiler: Compiler,
+    /// The attribute to mark `#[repr(packed)]` structs with, such as a macro
+    /// defined for each compiler. Without it the syntax of `compiler` is used.
+    pub packed: Option<String>,
+    /// The function-like macro to mark `#[repr(align(N))]` structs with, which
+    /// is given `N`. Without it the syntax of `compiler` is used.
+    pub aligned_n: Option<String>,
+    /// Whether to define opaque structs whose layout is known with storage of
+    /// their size and alignment, so that they can be allocated by callers
+    pub opaque_storage: bool,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> LayoutConfig {
+        LayoutConfig {
+            compiler: Compiler::Gnu,
+            packed: None,
+            aligned_n: None,
+            opaque_storage: false,
+        }
+    }
+}
+
+impl LayoutConfig {
+    /// Returns the attribute to write after the `struct` keyword of a struct
+    /// with `align`, if any.
+    pub(crate) fn attribute(&self, align: ReprAlign) -> Option<String> {
+        match align {
+            ReprAlign::Packed(1) => match (&self.packed, self.compiler) {
+                (&Some(ref packed), _) => Some(packed.clone()),
+                (&None, Compiler::Gnu) => Some("__attribute__((packed))".to_owned()),
+                (&None, Compiler::Msvc) => None,
+            },
+            ReprAlign::Packed(_) => None,
+            ReprAlign::Align(n) => Some(match (&self.aligned_n, self.compiler) {
+                (&Some(ref aligned_n), _) => format!("{}({})", aligned_n, n),
+                (&None, Compiler::Gnu) => format!("__attribute__((aligned({})))", n),
+                (&None, Compiler::Msvc) => format!("__declspec(align({}))", n),
+            }),
+        }
+    }
+
+    /// Returns the value of the `#pragma pack` to write around a struct with
+    /// `align`, if it's packed through pragmas.
+    pub(crate) fn pack_pragma(&self, align: ReprAlign) -> Option<u64> {
+        match align {
+            ReprAlign::Packed(n) if self.attribute(align).is_none() => Some(n),
+            _ => None,
+        }
+    }
+}
+
+/// Settings to apply when running `rustc --pretty=expanded`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ParseExpandConfig {
+    /// The names of crates to parse with `rustc --pretty=expanded`
+    pub crates: Vec<String>,
+    /// Whether to enable all the features when expanding.
+    pub all_features: bool,
+    /// Whether to use the default feature set when expanding.
+    pub default_features: bool,
+    /// List of features to use when expanding. Combines with `default_features` like in
+    /// `Cargo.toml`.
+    pub features: Option<Vec<String>>,
+    /// How to expand the crates.
+    pub backend: ExpandBackend,
+    /// The directory of the `<crate>.rs` files expanded beforehand, for the
+    /// `expanded` backend. Relative to the directory of `Cargo.toml`.
+    pub expanded_dir: Option<PathBuf>,
+}
+
+impl Default for ParseExpandConfig {
+    fn default() -> ParseExpandConfig {
+        ParseExpandConfig {
+            crates: Vec::new(),
+            all_features: false,
+            default_features: true,
+            features: None,
+            backend: ExpandBackend::Nightly,
+            expanded_dir: None,
+        }
+    }
+}
+
+impl ParseExpandConfig {
+    pub(crate) fn expander(&self) -> Arc<dyn Expander> {
+        match self.backend {
+            ExpandBackend::Nightly => Arc::new(RustcExpander { stable: false }),
+            ExpandBackend::Stable => Arc::new(RustcExpander { stable: true }),
+            ExpandBackend::Expanded => Arc::new(ExpandedDirExpander {
+                dir: self
+                    .expanded_dir
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from("expanded")),
+            }),
+        }
+    }
+}
+
+/// How the crates in `parse.expand` are expanded.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ExpandBackend {
+    /// With `rustc -Z unstable-options --pretty=expanded`, which needs nightly.
+    Nightly,
+    /// With `rustc -Zunpretty=expanded`, allowed on stable with `RUSTC_BOOTSTRAP=1`.
+    Stable,
+    /// By reading `<crate>.rs` files expanded beforehand from `expanded_dir`.
+    Expanded,
+}
+
+impl FromStr for ExpandBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ExpandBackend, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "nightly" => Ok(ExpandBackend::Nightly),
+            "stable" => Ok(ExpandBackend::Stable),
+            "expanded" => Ok(ExpandBackend::Expanded),
+            _ => Err(format!("Unrecognized expand backend: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(ExpandBackend);
+
+// Backwards-compatibility deserializer for ParseExpandConfig. This allows accepting both the
+// simple `expand = ["crate"]` and the more complex `expand = {"crates": ["crate"],
+// "default_features": false}` format for the `expand` key.
+//
+// Note that one (major) difference between the two forms is that, for backwards-compatibility
+// reasons, the `expand = ["crate"]` form will enable the `--all-features` flag by default while
+// the `expand = {"crates": ["crate"]}` form will use the default feature set by default.
+fn retrocomp_parse_expand_config_deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ParseExpandConfig, D::Error> {
+    struct ParseExpandVisitor;
+
+    impl<'de> Visitor<'de> for ParseExpandVisitor {
+        type Value = ParseExpandConfig;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map or sequence of string")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            let crates =
                 <Vec<String> as Deserialize>::deserialize(SeqAccessDeserializer::new(seq))?;
             Ok(ParseExpandConfig {
                 crates,
                 all_features: true,
-                default_features: true,
-                features: None,
+                ..ParseExpandConfig::default()
             })
         }
 
@@ -562,6 +2347,14 @@ pub struct ParseConfig {
     /// Whether to use a new temporary target directory when running `rustc --pretty=expanded`.
     /// This may be required for some build processes.
     pub clean: bool,
+    /// An optional directory to cache the parsed source files in, so that
+    /// later runs only reparse the files that changed.
+    pub cache_dir: Option<String>,
+    /// The number of crates to parse at once
+    pub jobs: usize,
+    /// Whether to resolve the `#[cfg(feature = "...")]`s of the binding crate
+    /// with the features that `expand` enables, even without a `target`
+    pub resolve_features: bool,
 }
 
 impl Default for ParseConfig {
@@ -572,6 +2365,9 @@ impl Default for ParseConfig {
             exclude: Vec::new(),
             expand: ParseExpandConfig::default(),
             clean: false,
+            cache_dir: None,
+            jobs: 4,
+            resolve_features: false,
         }
     }
 }
@@ -597,14 +2393,35 @@ pub struct Config {
     /// This option is useful when using cbindgen with tools such as python's cffi which
     /// doesn't understand include directives
     pub no_includes: bool,
+    /// Whether to only include the system headers declaring what the bindings
+    /// use, instead of a fixed list
+    pub infer_includes: bool,
+    /// Whether to define the system types and macros that the bindings use,
+    /// instead of including the system headers, for freestanding environments
+    pub no_std_headers: bool,
     /// Optional text to output at major sections to deter manual editing
     pub autogen_warning: Option<String>,
     /// Include a comment with the version of cbindgen used to generate the file
     pub include_version: bool,
+    /// Write the items of each top-level module of the binding crate to a
+    /// separate header next to the output file. Only applicable when
+    /// language="C" or language="C++"
+    pub header_per_module: bool,
+    /// The configuration options for routing items into secondary headers.
+    /// Only applicable when language="C" or language="C++"
+    pub output: OutputConfig,
+    /// The configuration options for generating the headers of several crates
+    /// of a workspace at once. Only applicable when language="C" or
+    /// language="C++"
+    pub workspace: WorkspaceConfig,
     /// An optional name for the root namespace. Only applicable when language="C++"
     pub namespace: Option<String>,
     /// An optional list of namespaces. Only applicable when language="C++"
     pub namespaces: Option<Vec<String>>,
+    /// The name of a C++20 module to write an interface unit of, next to the
+    /// header, or instead of it when the output is a `.cppm` or `.ixx` file.
+    /// Only applicable when language="C++"
+    pub module_interface: Option<String>,
     /// The style to use for braces
     pub braces: Braces,
     /// The preferred length of a line, used for auto breaking function arguments
@@ -613,10 +2430,23 @@ pub struct Config {
     pub tab_width: usize,
     /// The language to output bindings for
     pub language: Language,
+    /// The standard of the language to restrict the bindings to
+    pub language_version: Option<LanguageVersion>,
     /// Include preprocessor defines in C bindings to ensure C++ compatibility
     pub cpp_compat: bool,
+    /// Whether to make C and C++ bindings importable from Objective-C, by
+    /// escaping the identifiers it predefines, annotating the nullability of
+    /// pointers and assuming they're non-null
+    pub objc_compat: bool,
     /// The style to declare structs, enums and unions in for C
     pub style: Style,
+    /// Whether to sort the items so that they're declared before they're
+    /// used, and which structs and unions to forward declare for that. Items
+    /// are written in the order they're found otherwise
+    pub forward_declarations: Option<ForwardDeclarations>,
+    /// The order to write functions, types and constants in, unless their
+    /// sections override it
+    pub sort_by: Option<SortKey>,
     /// The configuration options for parsing
     pub parse: ParseConfig,
     /// The configuration options for exporting
@@ -635,12 +2465,72 @@ pub struct Config {
     /// The configuration options for constants
     #[serde(rename = "const")]
     pub constant: ConstantConfig,
+    /// The configuration options for globals
+    #[serde(rename = "static")]
+    pub global: GlobalConfig,
+    /// The configuration options for slices
+    pub slice: SliceConfig,
+    /// The configuration options for 128-bit integers
+    pub int128: Int128Config,
+    /// The configuration options for identifiers colliding with keywords
+    pub keywords: KeywordsConfig,
+    /// The configuration options for the severity of diagnostics
+    pub diagnostics: DiagnosticsConfig,
+    /// The configuration options for grouping items into sections
+    pub sections: SectionConfig,
+    /// The configuration options for injecting code
+    pub inject: InjectConfig,
+    /// The configuration options for rendering templates
+    pub template: TemplateConfig,
+    /// The configuration options for Cython
+    pub cython: CythonConfig,
+    /// The configuration options for Java
+    pub java: JavaConfig,
+    /// The configuration options for D
+    pub d: DConfig,
+    /// The configuration options for Go
+    pub go: GoConfig,
+    /// The configuration options for WebAssembly
+    pub wasm: WasmConfig,
+    /// The configuration options for Kotlin/Native cinterop
+    pub kotlin: KotlinConfig,
+    /// The configuration options for Swift annotations
+    pub swift: SwiftConfig,
+    /// The configuration options for thread safety annotations
+    pub thread_safety: ThreadSafetyConfig,
+    /// The configuration options for packed and aligned structs
+    pub layout: LayoutConfig,
+    /// The configuration options for guarding items by API version
+    pub api_version: ApiVersionConfig,
+    /// The configuration options for mapping character and string types
+    pub types: TypesConfig,
     /// Preprocessor defines to use when generating #ifdef's for #[cfg]
     pub defines: HashMap<String, String>,
+    /// The define to guard the items under a `#[cfg(feature = "...")]` that
+    /// `defines` has no entry for with, where `{}` is replaced by the feature
+    /// name in screaming snake case, like `MYLIB_FEATURE_{}`
+    pub feature_define: Option<String>,
+    /// The target triple to resolve #[cfg]'s for, like `x86_64-pc-windows-msvc`.
+    /// The cfgs of the target and the enabled features of the binding crate are
+    /// resolved, and only the other ones are left to the preprocessor
+    pub target: Option<String>,
+    /// The target triples to generate a single header for, resolving #[cfg]'s
+    /// like `target` for each of them. The items that differ between them are
+    /// written under the conditions that `defines` gives for their
+    /// `target = triple` cfgs, like `"target = x86_64-pc-windows-msvc" = "_WIN32"`,
+    /// which default to the define of the OS of the ones without another
+    /// target of that OS. Overrides `target`
+    pub targets: Vec<String>,
     /// Include doc comments from rust as documentation
     pub documentation: bool,
     /// How documentation comments should be styled.
     pub documentation_style: DocumentationStyle,
+    /// Whether to assert the size of structs and the offsets of their fields
+    /// at compile time. Only applicable when language="C" or language="C++"
+    pub layout_asserts: bool,
+    /// The file this config was loaded from, which bindings depend on.
+    #[serde(skip)]
+    pub config_path: Option<PathBuf>,
 }
 
 impl Default for Config {
@@ -653,15 +2543,25 @@ impl Default for Config {
             include_guard: None,
             autogen_warning: None,
             include_version: false,
+            header_per_module: false,
+            output: OutputConfig::default(),
+            workspace: WorkspaceConfig::default(),
             no_includes: false,
+            infer_includes: false,
+            no_std_headers: false,
             namespace: None,
             namespaces: None,
+            module_interface: None,
             braces: Braces::SameLine,
             line_length: 100,
             tab_width: 2,
             language: Language::Cxx,
+            language_version: None,
             cpp_compat: false,
+            objc_compat: false,
             style: Style::Type,
+            forward_declarations: None,
+            sort_by: None,
             macro_expansion: Default::default(),
             parse: ParseConfig::default(),
             export: ExportConfig::default(),
@@ -669,14 +2569,91 @@ impl Default for Config {
             structure: StructConfig::default(),
             enumeration: EnumConfig::default(),
             constant: ConstantConfig::default(),
+            global: GlobalConfig::default(),
+            slice: SliceConfig::default(),
+            int128: Int128Config::default(),
+            keywords: KeywordsConfig::default(),
+            diagnostics: DiagnosticsConfig::default(),
+            sections: SectionConfig::default(),
+            inject: InjectConfig::default(),
+            template: TemplateConfig::default(),
+            cython: CythonConfig::default(),
+            java: JavaConfig::default(),
+            d: DConfig::default(),
+            go: GoConfig::default(),
+            wasm: WasmConfig::default(),
+            kotlin: KotlinConfig::default(),
+            swift: SwiftConfig::default(),
+            thread_safety: ThreadSafetyConfig::default(),
+            layout: LayoutConfig::default(),
+            api_version: ApiVersionConfig::default(),
+            types: TypesConfig::default(),
             defines: HashMap::new(),
+            feature_define: None,
+            target: None,
+            targets: Vec::new(),
             documentation: true,
             documentation_style: DocumentationStyle::Auto,
+            layout_asserts: false,
+            config_path: None,
         }
     }
 }
 
 impl Config {
+    /// The order to write functions in, by name unless set.
+    pub(crate) fn function_sort_by(&self) -> SortKey {
+        self.function
+            .sort_by
+            .or(self.sort_by)
+            .unwrap_or(SortKey::Name)
+    }
+
+    /// The order to write constants and globals in, by name unless set.
+    pub(crate) fn constant_sort_by(&self) -> SortKey {
+        self.constant
+            .sort_by
+            .or(self.sort_by)
+            .unwrap_or(SortKey::Name)
+    }
+
+    /// The order to write types in, if set.
+    pub(crate) fn type_sort_by(&self) -> Option<SortKey> {
+        self.export.sort_by.or(self.sort_by)
+    }
+
+    /// Whether to group the items into sections, which only C and C++
+    /// bindings are.
+    pub(crate) fn has_sections(&self) -> bool {
+        self.sections.style.is_some()
+            && (self.language == Language::C || self.language == Language::Cxx)
+    }
+
+    /// The section of an item, which a `section` annotation overrides, if
+    /// they're grouped into sections.
+    pub(crate) fn section_of(
+        &self,
+        annotations: &AnnotationSet,
+        standard: &'static str,
+    ) -> Option<String> {
+        if !self.has_sections() {
+            return None;
+        }
+        match annotations.atom("section") {
+            Some(Some(name)) => Some(name),
+            _ => Some(standard.to_owned()),
+        }
+    }
+
+    /// The target triples to resolve #[cfg]'s for.
+    pub(crate) fn targets(&self) -> Vec<&String> {
+        if !self.targets.is_empty() {
+            self.targets.iter().collect()
+        } else {
+            self.target.iter().collect()
+        }
+    }
+
     pub fn from_file<P: AsRef<StdPath>>(file_name: P) -> Result<Config, String> {
         fn read(file_name: &StdPath) -> io::Result<String> {
             let file = File::open(file_name)?;
@@ -689,7 +2666,16 @@ impl Config {
         let config_text = read(file_name.as_ref()).unwrap();
 
         match toml::from_str::<Config>(&config_text) {
-            Ok(x) => Ok(x),
+            Ok(mut x) => {
+                if let Some(dir) = file_name.as_ref().parent() {
+                    x.inject.resolve_files(dir);
+                    x.template.resolve_files(dir);
+                }
+                Ok(Config {
+                    config_path: Some(file_name.as_ref().to_owned()),
+                    ..x
+                })
+            }
             Err(e) => Err(format!("Couldn't parse config file: {}.", e)),
         }
     }
@@ -703,4 +2689,132 @@ impl Config {
             Config::default()
         }
     }
+
+    /// The secondary header an item goes to, given by `[output]` or by the
+    /// `[workspace]` member it's declared in, or `None` for the main header.
+    pub(crate) fn output_of(&self, name: &str, annotations: &AnnotationSet) -> Option<String> {
+        self.output.output_of(name, annotations).or_else(|| {
+            let crate_name = annotations.crate_name.as_ref()?;
+            self.workspace.output_of(crate_name).map(|x| x.to_owned())
+        })
+    }
+
+    /// Whether `objc_compat` applies to the language of the bindings.
+    pub(crate) fn objc_compat(&self) -> bool {
+        self.objc_compat && (self.language == Language::C || self.language == Language::Cxx)
+    }
+
+    /// The targeted version, if it's a version of the bindings' language.
+    pub(crate) fn language_version(&self) -> Option<LanguageVersion> {
+        self.language_version
+            .filter(|version| version.language() == self.language)
+    }
+
+    /// Whether the bindings can use the constructs `version` of their
+    /// language added, which they all can without a `language_version`.
+    pub(crate) fn has_version(&self, version: LanguageVersion) -> bool {
+        self.language_version().map_or(true, |x| x >= version)
+    }
+
+    /// Whether the bindings can use atomic types, which C11 and C++11 added.
+    /// C++ doesn't have `_Atomic`, so C bindings compatible with it can't.
+    pub(crate) fn has_atomics(&self) -> bool {
+        match self.language {
+            Language::C => self.has_version(LanguageVersion::C11) && !self.cpp_compat,
+            Language::Cxx => self.has_version(LanguageVersion::Cxx11),
+            _ => false,
+        }
+    }
+
+    /// The attribute marking `#[must_use]` functions.
+    pub(crate) fn function_must_use(&self) -> Option<String> {
+        self.function
+            .must_use
+            .clone()
+            .or_else(|| self.default_must_use(true))
+    }
+
+    /// The attribute marking functions returning `!`, which is a macro the
+    /// bindings define in C, where it depends on the standard.
+    pub(crate) fn function_no_return(&self) -> Option<String> {
+        if let Some(ref no_return) = self.function.no_return {
+            return Some(no_return.clone());
+        }
+        match self.language {
+            Language::Cxx if self.has_version(LanguageVersion::Cxx11) => {
+                Some("[[noreturn]]".to_owned())
+            }
+            Language::Cxx => Some("__attribute__((noreturn))".to_owned()),
+            // Without a version, the macro picks the attribute of the standard.
+            Language::C if self.language_version().is_some() && !self.cpp_compat => {
+                if self.has_version(LanguageVersion::C11) {
+                    Some("_Noreturn".to_owned())
+                } else {
+                    Some("__attribute__((noreturn))".to_owned())
+                }
+            }
+            Language::C => Some("CBINDGEN_NORETURN".to_owned()),
+            _ => None,
+        }
+    }
+
+    /// The attribute marking `#[must_use]` types, given the one configured
+    /// for their kind.
+    pub(crate) fn type_must_use(&self, configured: &Option<String>) -> Option<String> {
+        configured.clone().or_else(|| self.default_must_use(false))
+    }
+
+    /// The attribute marking an item deprecated with `note`, which is empty
+    /// when it has none, given the spellings configured for its kind. The
+    /// note is left out when only the spelling without one is configured.
+    pub(crate) fn deprecated(
+        &self,
+        note: &str,
+        configured: &Option<String>,
+        configured_with_note: &Option<String>,
+    ) -> Option<String> {
+        if configured.is_none() && configured_with_note.is_none() {
+            if !self.export.deprecated_defaults {
+                return None;
+            }
+            let (plain, with_note) = match self.language {
+                Language::Cxx if self.has_version(LanguageVersion::Cxx14) => {
+                    ("[[deprecated]]", "[[deprecated({})]]")
+                }
+                Language::C | Language::Cxx => (
+                    "__attribute__((deprecated))",
+                    "__attribute__((deprecated({})))",
+                ),
+                _ => return None,
+            };
+            return Some(if note.is_empty() {
+                plain.to_owned()
+            } else {
+                with_note.replace("{}", &quote_note(note))
+            });
+        }
+        match *configured_with_note {
+            Some(ref with_note) if !note.is_empty() => {
+                Some(with_note.replace("{}", &quote_note(note)))
+            }
+            _ => configured.clone(),
+        }
+    }
+
+    /// The standard attributes for `#[must_use]` items with
+    /// `export.must_use_defaults`, which C only has for functions.
+    fn default_must_use(&self, function: bool) -> Option<String> {
+        if !self.export.must_use_defaults {
+            return None;
+        }
+        match self.language {
+            Language::Cxx if self.has_version(LanguageVersion::Cxx17) => {
+                Some("[[nodiscard]]".to_owned())
+            }
+            Language::C | Language::Cxx if function => {
+                Some("__attribute__((warn_unused_result))".to_owned())
+            }
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,357 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a Cython `.pxd` file, declaring the items of the C
+//! header in a `cdef extern` block.
+//!
+//! Cython declarations use C declarators, so types are written with `cdecl`
+//! exactly like in the header.
+
+use std::io::Write;
+
+use bindgen::cdecl;
+use bindgen::config::Config;
+use bindgen::ir::{
+    Constant, Documentation, Enum, Function, Item, ItemContainer, OpaqueItem, ReprStyle, ReprType,
+    Static, Struct, Type, Typedef, Union,
+};
+use bindgen::python::{is_exported, write_cfg, write_documentation};
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Writes a Cython `.pxd` file with the declarations of `bindings`.
+pub(crate) struct CythonWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+}
+
+impl<'a> CythonWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        CythonWriter {
+            bindings,
+            config: &bindings.config,
+        }
+    }
+
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        out.new_line_if_not_start();
+        match self.config.cython.header {
+            Some(ref header) => write!(out, "cdef extern from \"{}\":", header),
+            None => out.write("cdef extern from *:"),
+        }
+        out.push_tab();
+
+        let mut empty = true;
+
+        for constant in &self.bindings.constants {
+            if constant.ty.is_primitive_or_ptr_primitive() {
+                out.new_line();
+                self.write_constant(constant, None, out);
+                empty = false;
+            }
+        }
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            out.new_line();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => self.write_struct(x, out),
+                ItemContainer::Union(ref x) => self.write_union(x, out),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque(x, out),
+                ItemContainer::Typedef(ref x) => self.write_typedef(x, out),
+            }
+            empty = false;
+        }
+
+        for constant in &self.bindings.constants {
+            if !constant.ty.is_primitive_or_ptr_primitive() {
+                out.new_line();
+                self.write_constant(constant, None, out);
+                empty = false;
+            }
+        }
+
+        for global in &self.bindings.globals {
+            out.new_line();
+            self.write_static(global, out);
+            empty = false;
+        }
+
+        for function in &self.bindings.functions {
+            out.new_line();
+            self.write_function(function, out);
+            empty = false;
+        }
+
+        if empty {
+            out.new_line();
+            out.write("pass");
+            out.new_line();
+        }
+        out.pop_tab();
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "# Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if self.config.no_includes {
+            return;
+        }
+
+        // The types that the C header gets from its standard includes.
+        out.new_line_if_not_start();
+        out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+        out.new_line();
+        out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+        out.new_line();
+        out.write("from libc.stddef cimport ptrdiff_t, wchar_t");
+        out.new_line();
+        out.write("cdef extern from *:");
+        out.push_tab();
+        out.new_line();
+        out.write("ctypedef bint bool");
+        out.new_line();
+        out.write("ctypedef struct va_list:");
+        out.push_tab();
+        out.new_line();
+        out.write("pass");
+        out.pop_tab();
+        out.pop_tab();
+        out.new_line();
+    }
+
+    /// Writes the keyword introducing an aggregate, which depends on whether
+    /// the header declares it as a typedef or with a tag.
+    fn write_aggregate_keyword<F: Write>(&self, keyword: &'static str, out: &mut SourceWriter<F>) {
+        if self.config.style.generate_typedef() {
+            write!(out, "ctypedef {} ", keyword);
+        } else {
+            write!(out, "{} ", keyword);
+        }
+    }
+
+    fn write_fields<F: Write>(
+        &self,
+        fields: &[(String, Type, Documentation)],
+        out: &mut SourceWriter<F>,
+    ) {
+        out.push_tab();
+        if fields.is_empty() {
+            out.new_line();
+            out.write("pass");
+        }
+        for (name, ty, documentation) in fields {
+            out.new_line();
+            write_documentation(self.config, documentation, out);
+            cdecl::write_field(out, ty, name);
+        }
+        out.pop_tab();
+        out.new_line();
+    }
+
+    fn write_struct<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        write_cfg(&s.cfg, out);
+        write_documentation(self.config, &s.documentation, out);
+
+        if s.is_transparent {
+            out.write("ctypedef ");
+            cdecl::write_field(out, &s.fields[0].1, s.export_name());
+            out.new_line();
+        } else {
+            self.write_aggregate_keyword("struct", out);
+            write!(out, "{}:", s.export_name());
+            self.write_fields(&s.fields, out);
+        }
+
+        for constant in &s.associated_constants {
+            self.write_constant(constant, Some(s), out);
+        }
+    }
+
+    fn write_union<F: Write>(&self, u: &Union, out: &mut SourceWriter<F>) {
+        write_cfg(&u.cfg, out);
+        write_documentation(self.config, &u.documentation, out);
+        self.write_aggregate_keyword("union", out);
+        write!(out, "{}:", u.export_name());
+        self.write_fields(&u.fields, out);
+    }
+
+    fn write_opaque<F: Write>(&self, o: &OpaqueItem, out: &mut SourceWriter<F>) {
+        write_cfg(&o.cfg, out);
+        write_documentation(self.config, &o.documentation, out);
+        self.write_aggregate_keyword("struct", out);
+        write!(out, "{}:", o.export_name());
+        self.write_fields(&[], out);
+    }
+
+    fn write_typedef<F: Write>(&self, t: &Typedef, out: &mut SourceWriter<F>) {
+        write_cfg(&t.cfg, out);
+        write_documentation(self.config, &t.documentation, out);
+        out.write("ctypedef ");
+        cdecl::write_field(out, &t.aliased, t.export_name());
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        write_cfg(&e.cfg, out);
+        write_documentation(self.config, &e.documentation, out);
+
+        let size = e.repr.ty.map(|ty| match ty {
+            ReprType::USize => "uintptr_t",
+            ReprType::U32 => "uint32_t",
+            ReprType::U16 => "uint16_t",
+            ReprType::U8 => "uint8_t",
+            ReprType::ISize => "intptr_t",
+            ReprType::I32 => "int32_t",
+            ReprType::I16 => "int16_t",
+            ReprType::I8 => "int8_t",
+        });
+
+        let enum_name = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        // Sized enums are a typedef of their integer type in the header, so
+        // the variants are declared in an anonymous enum.
+        if size.is_some() {
+            out.write("enum:");
+        } else {
+            self.write_aggregate_keyword("enum", out);
+            write!(out, "{}:", enum_name);
+        }
+        out.push_tab();
+        if e.variants.is_empty() {
+            out.new_line();
+            out.write("pass");
+        }
+        for variant in &e.variants {
+            out.new_line();
+            write_documentation(self.config, &variant.documentation, out);
+            write!(out, "{}", variant.export_name);
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            out.new_line();
+            out.write("Sentinel");
+        }
+        out.pop_tab();
+        out.new_line();
+
+        if let Some(size) = size {
+            write!(out, "ctypedef {} {}", size, enum_name);
+            out.new_line();
+        }
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                out.new_line();
+                self.write_struct(body, out);
+            }
+        }
+
+        // Cython can't declare anonymous unions, but members of the anonymous
+        // union of a separately tagged enum can be accessed as if they were
+        // declared directly in the struct.
+        let separate_tag = e.repr.style == ReprStyle::C;
+        out.new_line();
+        self.write_aggregate_keyword(if separate_tag { "struct" } else { "union" }, out);
+        write!(out, "{}:", e.export_name());
+        out.push_tab();
+        out.new_line();
+        write!(out, "{} tag", enum_name);
+        for (name, body) in e.variants.iter().filter_map(|v| v.body.as_ref()) {
+            out.new_line();
+            write!(out, "{} {}", body.export_name(), name);
+        }
+        out.pop_tab();
+        out.new_line();
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return;
+        }
+
+        let name = constant
+            .global_name(self.config, associated_to)
+            .into_owned();
+
+        write_cfg(&constant.cfg, out);
+        write_documentation(self.config, &constant.documentation, out);
+        // The header defines constants as macros, which Cython accepts being
+        // declared as constant variables.
+        if let Type::ConstPtr(..) = constant.ty {
+            // Nothing.
+        } else {
+            out.write("const ");
+        }
+        cdecl::write_field(out, &constant.ty, &name);
+        out.new_line();
+    }
+
+    fn write_static<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        write_cfg(&global.cfg, out);
+        write_documentation(self.config, &global.documentation, out);
+        if let Type::ConstPtr(..) = global.ty {
+        } else if !global.mutable {
+            out.write("const ");
+        }
+        cdecl::write_field(out, &global.ty, global.export_name());
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        write_cfg(&function.cfg, out);
+        write_documentation(self.config, &function.documentation, out);
+        cdecl::write_func(out, function, false, false);
+        out.new_line();
+    }
+}
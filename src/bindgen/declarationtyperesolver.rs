@@ -12,7 +12,7 @@ pub struct DeclarationTypeResolver {
     unions: HashSet<Path>,
 }
 
-#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
 pub enum DeclarationType {
     Struct,
     Enum,
@@ -3,14 +3,21 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 
-use bindgen::ir::{ItemContainer, Path};
+use bindgen::config::{Config, ForwardDeclarations, Language, SortKey};
+use bindgen::ir::{ItemContainer, Path, Type};
 
 /// A dependency list is used for gathering what order to output the types.
 pub struct Dependencies {
     pub order: Vec<ItemContainer>,
     pub items: HashSet<Path>,
+    /// Whether the dependencies of the dependencies are gathered too, which
+    /// ordering the types needs.
+    pub transitive: bool,
+    /// The types that are used but that no item declares.
+    pub unknown: Vec<Path>,
 }
 
 impl Dependencies {
@@ -18,6 +25,16 @@ impl Dependencies {
         Dependencies {
             order: Vec::new(),
             items: HashSet::new(),
+            transitive: true,
+            unknown: Vec::new(),
+        }
+    }
+
+    /// Returns a list that only gathers the items used directly.
+    pub fn direct() -> Dependencies {
+        Dependencies {
+            transitive: false,
+            ..Dependencies::new()
         }
     }
 
@@ -44,4 +61,211 @@ impl Dependencies {
 
         self.order.sort_by(ordering);
     }
+
+    /// Sorts the items so that they're declared before they're used, with
+    /// ties broken by their sections and then `sort_by`, forward declaring the
+    /// structs and unions that pointers refer to before they're defined, or
+    /// all of them. Without forward declarations, pointers don't need what
+    /// they refer to first, unless the items are grouped into sections.
+    pub fn sort_by_declarations(
+        &mut self,
+        config: &Config,
+        mode: Option<ForwardDeclarations>,
+        sort_by: SortKey,
+    ) {
+        let mut items = mem::replace(&mut self.order, Vec::new());
+
+        let mut indices: HashMap<Path, Vec<usize>> = HashMap::new();
+        for (i, item) in items.iter().enumerate() {
+            indices
+                .entry(item.deref().path().clone())
+                .or_insert_with(Vec::new)
+                .push(i);
+        }
+
+        // A struct pointing to itself only needs to be declared first in C,
+        // when it's only named by its typedef.
+        let self_reference = config.language == Language::C && config.style.generate_typedef();
+        let references: Vec<Vec<(usize, bool)>> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let mut references = Vec::new();
+                for (path, pointer) in item_references(item) {
+                    for &j in indices.get(&path).map_or(&[][..], |x| &x[..]) {
+                        if j != i || (pointer && self_reference) {
+                            references.push((j, pointer));
+                        }
+                    }
+                }
+                references
+            })
+            .collect();
+
+        // Untagged enums and opaque items come first in their sections, like
+        // with `sort`. Items without a source order, like the standard types,
+        // come last in it.
+        let sections: Vec<(usize, String)> = items
+            .iter()
+            .map(|item| config.sections.rank(&item.section(config)))
+            .collect();
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_by_key(|&i| {
+            let first = match items[i] {
+                ItemContainer::Enum(ref x) => x.tag.is_none(),
+                ItemContainer::OpaqueItem(..) => true,
+                _ => false,
+            };
+            let rank = match sort_by {
+                SortKey::Name => 0,
+                SortKey::SourceOrder => items[i]
+                    .deref()
+                    .annotations()
+                    .source_order
+                    .unwrap_or(usize::max_value()),
+                SortKey::Dependency => i,
+            };
+            (
+                sections[i].clone(),
+                !first,
+                rank,
+                items[i].deref().path().clone(),
+            )
+        });
+
+        let mut declared: Vec<bool> = items
+            .iter()
+            .map(|item| match mode {
+                Some(ForwardDeclarations::All) => can_forward_declare(item),
+                Some(ForwardDeclarations::Minimal) => false,
+                None => !config.has_sections(),
+            })
+            .collect();
+        let mut written = vec![false; items.len()];
+        let mut sorted = Vec::with_capacity(items.len());
+        while sorted.len() < items.len() {
+            let ready = order.iter().cloned().find(|&i| {
+                !written[i]
+                    && references[i]
+                        .iter()
+                        .all(|&(j, pointer)| written[j] || (pointer && declared[j]))
+            });
+            if let Some(i) = ready {
+                written[i] = true;
+                sorted.push(i);
+                continue;
+            }
+
+            // The items left are in cycles, which forward declaring the first
+            // struct or union that they point to breaks.
+            let pointed: HashSet<usize> = order
+                .iter()
+                .filter(|&&i| !written[i])
+                .flat_map(|&i| references[i].iter())
+                .filter(|&&(_, pointer)| pointer)
+                .map(|&(j, _)| j)
+                .collect();
+            let candidate = order.iter().cloned().find(|&j| {
+                !written[j]
+                    && !declared[j]
+                    && pointed.contains(&j)
+                    && can_forward_declare(&items[j])
+            });
+            match candidate {
+                Some(j) => declared[j] = true,
+                None => {
+                    // Cycles of values can't be broken, so the items are
+                    // written in order.
+                    let i = order.iter().cloned().find(|&i| !written[i]).unwrap();
+                    written[i] = true;
+                    sorted.push(i);
+                }
+            }
+        }
+
+        for (i, item) in items.iter_mut().enumerate() {
+            if declared[i] && mode.is_some() {
+                match *item {
+                    ItemContainer::Struct(ref mut x) => x.forward_declared = true,
+                    ItemContainer::Union(ref mut x) => x.forward_declared = true,
+                    _ => unreachable!(),
+                }
+            }
+        }
+        let mut items: Vec<Option<ItemContainer>> = items.into_iter().map(Some).collect();
+        self.order = sorted
+            .into_iter()
+            .map(|i| items[i].take().unwrap())
+            .collect();
+    }
+}
+
+/// Whether a struct or union can be declared before it's defined.
+fn can_forward_declare(item: &ItemContainer) -> bool {
+    match *item {
+        ItemContainer::Struct(ref x) => !x.is_transparent && !x.is_generic(),
+        ItemContainer::Union(ref x) => !x.is_generic(),
+        _ => false,
+    }
+}
+
+/// Returns the paths of the items that an item refers to, and whether it's
+/// only through pointers, which only need them to be declared.
+fn item_references(item: &ItemContainer) -> Vec<(Path, bool)> {
+    let mut out = Vec::new();
+    match *item {
+        ItemContainer::Struct(ref x) => {
+            for &(_, ref ty, _) in &x.fields {
+                type_references(ty, false, &mut out);
+            }
+        }
+        ItemContainer::Union(ref x) => {
+            for &(_, ref ty, _) in &x.fields {
+                type_references(ty, false, &mut out);
+            }
+        }
+        ItemContainer::Enum(ref x) => {
+            for variant in &x.variants {
+                if let Some((_, ref body)) = variant.body {
+                    for &(_, ref ty, _) in &body.fields {
+                        type_references(ty, false, &mut out);
+                    }
+                }
+            }
+        }
+        ItemContainer::Typedef(ref x) => type_references(&x.aliased, false, &mut out),
+        ItemContainer::OpaqueItem(..) | ItemContainer::Constant(..) | ItemContainer::Static(..) => {
+        }
+    }
+    out
+}
+
+fn type_references(ty: &Type, pointer: bool, out: &mut Vec<(Path, bool)>) {
+    match *ty {
+        Type::ConstPtr(ref ty, _)
+        | Type::Ptr(ref ty, _)
+        | Type::Ref(ref ty)
+        | Type::MutRef(ref ty) => type_references(ty, true, out),
+        Type::Path(ref generic) => {
+            out.push((generic.path().clone(), pointer));
+            for ty in generic.generics() {
+                type_references(ty, pointer, out);
+            }
+        }
+        Type::Primitive(..) => {}
+        Type::Array(ref ty, _) | Type::Atomic(ref ty) => type_references(ty, pointer, out),
+        // The types of function pointers only need to be declared.
+        Type::FuncPtr(ref ret, ref args, _, _, _) => {
+            type_references(ret, true, out);
+            for &(_, ref ty) in args {
+                type_references(ty, true, out);
+            }
+        }
+        Type::ConstSlice(ref ty) | Type::Slice(ref ty) => type_references(ty, true, out),
+        Type::Tuple(ref tys) => {
+            for ty in tys {
+                type_references(ty, pointer, out);
+            }
+        }
+    }
 }
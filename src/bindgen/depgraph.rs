@@ -0,0 +1,167 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use serde_json;
+
+/// A format to write the dependency graph of the bindings in.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DependencyGraphFormat {
+    Json,
+    Dot,
+}
+
+impl FromStr for DependencyGraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DependencyGraphFormat, Self::Err> {
+        match s {
+            "json" | "Json" | "JSON" => Ok(DependencyGraphFormat::Json),
+            "dot" | "Dot" | "DOT" => Ok(DependencyGraphFormat::Dot),
+            _ => Err(format!("Unrecognized DependencyGraphFormat: '{}'.", s)),
+        }
+    }
+}
+
+/// An item of the bindings, and the items it uses directly.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyNode {
+    pub name: String,
+    pub kind: &'static str,
+    /// The crate the item is declared in, or `None` for items that cbindgen
+    /// generates, such as monomorphs of the standard types.
+    #[serde(rename = "crate")]
+    pub crate_name: Option<String>,
+    pub dependencies: BTreeSet<String>,
+}
+
+/// The items of the bindings and the items each of them uses, by their
+/// exported names.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    nodes: BTreeMap<String, DependencyNode>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> DependencyGraph {
+        DependencyGraph::default()
+    }
+
+    /// Adds an item, merging it with the other definitions of the same name,
+    /// as items with several `#[cfg]`ed definitions have.
+    pub fn add<I: IntoIterator<Item = String>>(
+        &mut self,
+        name: &str,
+        kind: &'static str,
+        crate_name: Option<&String>,
+        dependencies: I,
+    ) {
+        let node = self
+            .nodes
+            .entry(name.to_owned())
+            .or_insert_with(|| DependencyNode {
+                name: name.to_owned(),
+                kind,
+                crate_name: crate_name.cloned(),
+                dependencies: BTreeSet::new(),
+            });
+        node.dependencies
+            .extend(dependencies.into_iter().filter(|x| x != name));
+    }
+
+    pub fn nodes(&self) -> Vec<&DependencyNode> {
+        self.nodes.values().collect()
+    }
+
+    pub fn write<F: Write>(&self, format: DependencyGraphFormat, out: F) -> io::Result<()> {
+        match format {
+            DependencyGraphFormat::Json => self.write_json(out),
+            DependencyGraphFormat::Dot => self.write_dot(out),
+        }
+    }
+
+    fn write_json<F: Write>(&self, mut out: F) -> io::Result<()> {
+        #[derive(Serialize)]
+        struct Graph<'a> {
+            nodes: Vec<&'a DependencyNode>,
+        }
+
+        serde_json::to_writer_pretty(
+            &mut out,
+            &Graph {
+                nodes: self.nodes(),
+            },
+        )?;
+        writeln!(out)
+    }
+
+    fn write_dot<F: Write>(&self, mut out: F) -> io::Result<()> {
+        writeln!(out, "digraph dependencies {{")?;
+        for node in self.nodes.values() {
+            let label = match node.crate_name {
+                Some(ref crate_name) => format!("{}\\n{} in {}", node.name, node.kind, crate_name),
+                None => format!("{}\\n{}", node.name, node.kind),
+            };
+            writeln!(out, "  \"{}\" [label=\"{}\"];", node.name, label)?;
+        }
+        for node in self.nodes.values() {
+            for dependency in &node.dependencies {
+                writeln!(out, "  \"{}\" -> \"{}\";", node.name, dependency)?;
+            }
+        }
+        writeln!(out, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        let crate_name = "mylib".to_owned();
+        graph.add("Foo", "struct", Some(&crate_name), vec!["Bar".to_owned()]);
+        graph.add("Bar", "enum", Some(&crate_name), vec!["Bar".to_owned()]);
+        graph.add("Foo", "struct", Some(&crate_name), vec!["Baz".to_owned()]);
+        graph.add("Baz", "typedef", None, vec![]);
+        graph
+    }
+
+    fn write(format: DependencyGraphFormat) -> String {
+        let mut out = Vec::new();
+        graph().write(format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn writes_json() {
+        let json = write(DependencyGraphFormat::Json);
+        let expected = r#"{"nodes": [
+            {"name": "Bar", "kind": "enum", "crate": "mylib", "dependencies": []},
+            {"name": "Baz", "kind": "typedef", "crate": null, "dependencies": []},
+            {"name": "Foo", "kind": "struct", "crate": "mylib", "dependencies": ["Bar", "Baz"]}
+        ]}"#;
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::from_str::<serde_json::Value>(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn writes_dot() {
+        assert_eq!(
+            write(DependencyGraphFormat::Dot),
+            "digraph dependencies {\n  \
+             \"Bar\" [label=\"Bar\\nenum in mylib\"];\n  \
+             \"Baz\" [label=\"Baz\\ntypedef\"];\n  \
+             \"Foo\" [label=\"Foo\\nstruct in mylib\"];\n  \
+             \"Foo\" -> \"Bar\";\n  \
+             \"Foo\" -> \"Baz\";\n\
+             }\n"
+        );
+    }
+}
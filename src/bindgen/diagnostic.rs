@@ -0,0 +1,308 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Diagnostics about items that can't be written as they are, with a code,
+//! the lint that sets their severity, and where the item is declared.
+//!
+//! Diagnostics are carried with the items through parsing and generating the
+//! bindings, which report them at the end. They're logged, or written to
+//! stderr as newline-delimited JSON for tools annotating the sources.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use proc_macro2::Span;
+use serde::{Serialize, Serializer};
+use serde_json;
+
+use bindgen::config::{DiagnosticsConfig, MessageFormat};
+
+/// The kinds of diagnostics, whose severity can be configured.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Lint {
+    /// An item is skipped because it has a type that can't be written.
+    UnsupportedType,
+    /// An item is skipped for another reason, like not being `pub`.
+    SkippedItem,
+    /// A type is used that no item declares.
+    UnknownType,
+    /// An identifier collides with a keyword or macro and isn't renamed.
+    KeywordCollision,
+    /// A `repr(Rust)` type, like a tuple, is lowered into a C type whose
+    /// layout Rust doesn't guarantee.
+    UnstableLayout,
+    /// An item is skipped because its zero-sized fields raise its alignment,
+    /// which C can't express without them. Denied by default.
+    ZeroSizedAlignment,
+}
+
+impl Lint {
+    /// The name configurations refer to the lint by.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnsupportedType => "unsupported-type",
+            Lint::SkippedItem => "skipped-item",
+            Lint::UnknownType => "unknown-type",
+            Lint::KeywordCollision => "keyword-collision",
+            Lint::UnstableLayout => "unstable-layout",
+            Lint::ZeroSizedAlignment => "zero-sized-alignment",
+        }
+    }
+
+    /// The code of the diagnostics of the lint.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lint::UnsupportedType => "CB0001",
+            Lint::SkippedItem => "CB0002",
+            Lint::UnknownType => "CB0003",
+            Lint::KeywordCollision => "CB0004",
+            Lint::UnstableLayout => "CB0005",
+            Lint::ZeroSizedAlignment => "CB0006",
+        }
+    }
+
+    /// The severity of the lint unless it's configured.
+    pub fn default_severity(self) -> Severity {
+        match self {
+            Lint::ZeroSizedAlignment => Severity::Deny,
+            _ => Severity::Warn,
+        }
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Lint, Self::Err> {
+        match s {
+            "unsupported-type" => Ok(Lint::UnsupportedType),
+            "skipped-item" => Ok(Lint::SkippedItem),
+            "unknown-type" => Ok(Lint::UnknownType),
+            "keyword-collision" => Ok(Lint::KeywordCollision),
+            "unstable-layout" => Ok(Lint::UnstableLayout),
+            "zero-sized-alignment" => Ok(Lint::ZeroSizedAlignment),
+            _ => Err(format!("Unrecognized lint: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(Lint);
+
+impl Serialize for Lint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// How a diagnostic is reported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Severity {
+    /// It isn't reported.
+    Allow,
+    /// It's logged as a warning.
+    Warn,
+    /// It's logged as an error, and generating the bindings fails.
+    Deny,
+}
+
+impl Severity {
+    fn level(self) -> &'static str {
+        match self {
+            Severity::Allow => "allow",
+            Severity::Warn => "warning",
+            Severity::Deny => "error",
+        }
+    }
+}
+
+/// Where an item is declared.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Location {
+    file: PathBuf,
+    line: usize,
+    column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+/// A diagnostic as a line of JSON.
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    code: &'static str,
+    lint: &'static str,
+    level: &'static str,
+    message: &'a str,
+    item: &'a str,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+/// A diagnostic about the item at the path `item`, like `crate::Foo`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostic {
+    lint: Lint,
+    item: String,
+    message: String,
+    /// The line and column the item is declared at, in the file it's parsed
+    /// from.
+    #[serde(default)]
+    span: Option<(usize, usize)>,
+    #[serde(default)]
+    file: Option<PathBuf>,
+}
+
+impl Diagnostic {
+    pub fn new(lint: Lint, item: &str, message: String) -> Diagnostic {
+        Diagnostic {
+            lint,
+            item: item.to_owned(),
+            message,
+            span: None,
+            file: None,
+        }
+    }
+
+    /// Returns the diagnostic of an item declared at `span`, in the file
+    /// it's parsed from.
+    pub fn at(self, span: Span) -> Diagnostic {
+        let start = span.start();
+        Diagnostic {
+            span: Some((start.line, start.column + 1)),
+            ..self
+        }
+    }
+
+    /// Records `file` as the file the item is parsed from.
+    pub fn set_file(&mut self, file: &Path) {
+        self.file = Some(file.to_path_buf());
+    }
+
+    fn location(&self) -> Option<Location> {
+        match (&self.file, self.span) {
+            (&Some(ref file), Some((line, column))) => Some(Location {
+                file: file.clone(),
+                line,
+                column,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Logs `diagnostics` with the severity `config` gives them, once each,
+/// returning how many were denied.
+pub fn report(config: &DiagnosticsConfig, diagnostics: &[Diagnostic]) -> usize {
+    let mut reported = HashSet::new();
+    let mut denied = 0;
+    for diagnostic in diagnostics {
+        let severity = config.severity(diagnostic.lint);
+        if severity == Severity::Allow
+            || !reported.insert((diagnostic.lint.code(), &diagnostic.message))
+        {
+            continue;
+        }
+        if severity == Severity::Deny {
+            denied += 1;
+        }
+        let location = diagnostic.location();
+        let code = diagnostic.lint.code();
+        let name = diagnostic.lint.name();
+        match config.message_format {
+            MessageFormat::Human => {
+                let location = location.map(|x| format!(" {}:", x)).unwrap_or_default();
+                if severity == Severity::Deny {
+                    error!("[{} {}]{} {}", code, name, location, diagnostic.message);
+                } else {
+                    warn!("[{} {}]{} {}", code, name, location, diagnostic.message);
+                }
+            }
+            MessageFormat::Json => {
+                let json = JsonDiagnostic {
+                    code,
+                    lint: name,
+                    level: severity.level(),
+                    message: &diagnostic.message,
+                    item: &diagnostic.item,
+                    file: location.as_ref().map(|x| x.file.display().to_string()),
+                    line: location.as_ref().map(|x| x.line),
+                    column: location.as_ref().map(|x| x.column),
+                };
+                let line = serde_json::to_string(&json).unwrap();
+                writeln!(io::stderr(), "{}", line).unwrap();
+            }
+        }
+    }
+    denied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn;
+
+    #[test]
+    fn locates_parsed_items() {
+        let item: syn::ItemStruct = syn::parse_str("#[repr(C)]\n  pub struct Foo {}").unwrap();
+        let mut diagnostic = Diagnostic::new(Lint::SkippedItem, "crate::Foo", "Skip".to_owned())
+            .at(item.ident.span());
+        assert_eq!(diagnostic.location(), None);
+        diagnostic.set_file(Path::new("b.rs"));
+        assert_eq!(diagnostic.location().unwrap().to_string(), "b.rs:2:14");
+    }
+
+    #[test]
+    fn reports_denied_diagnostics_once() {
+        let config = DiagnosticsConfig {
+            deny: vec![Lint::UnsupportedType],
+            allow: vec![Lint::SkippedItem],
+            warnings_as_errors: false,
+            message_format: MessageFormat::Human,
+        };
+        let diagnostic = |lint, message: &str| Diagnostic::new(lint, "Foo", message.to_owned());
+        let diagnostics = vec![
+            diagnostic(Lint::UnsupportedType, "Skip Foo"),
+            diagnostic(Lint::UnsupportedType, "Skip Foo"),
+            diagnostic(Lint::UnsupportedType, "Skip Bar"),
+            diagnostic(Lint::SkippedItem, "Skip Baz"),
+            diagnostic(Lint::UnknownType, "Can't find Qux"),
+        ];
+        assert_eq!(report(&config, &diagnostics), 2);
+    }
+
+    #[test]
+    fn configures_severities() {
+        let config = DiagnosticsConfig {
+            deny: vec![Lint::UnsupportedType],
+            allow: vec![Lint::SkippedItem],
+            warnings_as_errors: false,
+            message_format: MessageFormat::Human,
+        };
+        assert_eq!(config.severity(Lint::UnsupportedType), Severity::Deny);
+        assert_eq!(config.severity(Lint::SkippedItem), Severity::Allow);
+        assert_eq!(config.severity(Lint::UnknownType), Severity::Warn);
+        let strict = DiagnosticsConfig {
+            warnings_as_errors: true,
+            ..config
+        };
+        assert_eq!(strict.severity(Lint::UnknownType), Severity::Deny);
+        assert_eq!(strict.severity(Lint::SkippedItem), Severity::Allow);
+    }
+
+    #[test]
+    fn denies_zero_sized_alignment_by_default() {
+        let mut config = DiagnosticsConfig::default();
+        assert_eq!(config.severity(Lint::ZeroSizedAlignment), Severity::Deny);
+        config.allow.push(Lint::ZeroSizedAlignment);
+        assert_eq!(config.severity(Lint::ZeroSizedAlignment), Severity::Allow);
+    }
+}
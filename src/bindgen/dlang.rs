@@ -0,0 +1,710 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a D module of `extern (C)` declarations.
+//!
+//! D has the same layout rules as C, so every item is written just like in
+//! the C header: structs and unions as D structs and unions, enums with their
+//! underlying type as base type, typedefs as `alias` declarations and globals
+//! as `extern __gshared` variables. Declarations in a D module can refer to
+//! each other in any order, so nothing has to be forward declared.
+
+use std::io::Write;
+
+use bindgen::config::Config;
+use bindgen::ir::{
+    CallingConvention, Cfg, Constant, Documentation, Enum, Function, Item, ItemContainer, Literal,
+    OpaqueItem, PrimitiveType, ReprAlign, ReprStyle, ReprType, Static, Struct, Type, Typedef,
+    Union,
+};
+use bindgen::python::is_exported;
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Taken from `https://dlang.org/spec/lex.html#keywords`.
+const D_KEYWORDS: &[&str] = &[
+    "abstract",
+    "alias",
+    "align",
+    "asm",
+    "assert",
+    "auto",
+    "body",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "cast",
+    "catch",
+    "cdouble",
+    "cent",
+    "cfloat",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "creal",
+    "dchar",
+    "debug",
+    "default",
+    "delegate",
+    "delete",
+    "deprecated",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "export",
+    "extern",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "foreach",
+    "foreach_reverse",
+    "function",
+    "goto",
+    "idouble",
+    "if",
+    "ifloat",
+    "immutable",
+    "import",
+    "in",
+    "inout",
+    "int",
+    "interface",
+    "invariant",
+    "ireal",
+    "is",
+    "lazy",
+    "long",
+    "macro",
+    "mixin",
+    "module",
+    "new",
+    "nothrow",
+    "null",
+    "out",
+    "override",
+    "package",
+    "pragma",
+    "private",
+    "protected",
+    "public",
+    "pure",
+    "real",
+    "ref",
+    "return",
+    "scope",
+    "shared",
+    "short",
+    "static",
+    "struct",
+    "super",
+    "switch",
+    "synchronized",
+    "template",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeid",
+    "typeof",
+    "ubyte",
+    "ucent",
+    "uint",
+    "ulong",
+    "union",
+    "unittest",
+    "ushort",
+    "version",
+    "void",
+    "wchar",
+    "while",
+    "with",
+];
+
+fn escape(name: &str) -> String {
+    if D_KEYWORDS.contains(&name) || name.starts_with("__") {
+        format!("{}_", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+fn primitive_type(primitive: &PrimitiveType) -> &'static str {
+    match *primitive {
+        PrimitiveType::Void => "void",
+        PrimitiveType::Bool => "bool",
+        PrimitiveType::Char => "char",
+        PrimitiveType::WChar => "wchar_t",
+        PrimitiveType::SChar => "byte",
+        PrimitiveType::UChar => "ubyte",
+        PrimitiveType::Short => "short",
+        PrimitiveType::Int => "int",
+        PrimitiveType::Long => "c_long",
+        PrimitiveType::LongLong => "long",
+        PrimitiveType::UShort => "ushort",
+        PrimitiveType::UInt => "uint",
+        PrimitiveType::ULong => "c_ulong",
+        PrimitiveType::ULongLong => "ulong",
+        // `size_t` and `ptrdiff_t` are built in, and have the size of a
+        // pointer like `uintptr_t` and `intptr_t`.
+        PrimitiveType::USize => "size_t",
+        PrimitiveType::UInt8 => "ubyte",
+        PrimitiveType::UInt16 => "ushort",
+        PrimitiveType::UInt32 => "uint",
+        PrimitiveType::UInt64 => "ulong",
+        PrimitiveType::ISize => "ptrdiff_t",
+        PrimitiveType::Int8 => "byte",
+        PrimitiveType::Int16 => "short",
+        PrimitiveType::Int32 => "int",
+        PrimitiveType::Int64 => "long",
+        PrimitiveType::Int128 | PrimitiveType::UInt128 => {
+            unreachable!("128-bit integers should have been lowered to structs")
+        }
+        PrimitiveType::Float => "float",
+        PrimitiveType::Double => "double",
+        PrimitiveType::SizeT => "size_t",
+        PrimitiveType::SSizeT => "ptrdiff_t",
+        PrimitiveType::PtrDiffT => "ptrdiff_t",
+        PrimitiveType::VaList => "va_list",
+    }
+}
+
+fn repr_type(repr: ReprType) -> &'static str {
+    match repr {
+        ReprType::U8 => "ubyte",
+        ReprType::U16 => "ushort",
+        ReprType::U32 => "uint",
+        ReprType::USize => "size_t",
+        ReprType::I8 => "byte",
+        ReprType::I16 => "short",
+        ReprType::I32 => "int",
+        ReprType::ISize => "ptrdiff_t",
+    }
+}
+
+/// Returns the linkage attribute of declarations with `calling_convention`,
+/// if it isn't the `extern (C):` of the module. D has no `fastcall`.
+fn linkage(calling_convention: CallingConvention) -> Option<&'static str> {
+    match calling_convention {
+        CallingConvention::C => None,
+        CallingConvention::Stdcall => Some("extern (Windows)"),
+        CallingConvention::System => Some("extern (System)"),
+        CallingConvention::Fastcall => {
+            warn!("D has no fastcall calling convention, writing it as extern (C).");
+            None
+        }
+    }
+}
+
+/// Returns the D type of `ty`.
+fn d_type(ty: &Type) -> String {
+    match *ty {
+        Type::ConstPtr(ref pointee, _) | Type::Ref(ref pointee) => match **pointee {
+            // A function pointer is already a pointer.
+            Type::FuncPtr(..) => d_type(pointee),
+            _ => format!("const({})*", d_type(pointee)),
+        },
+        Type::Ptr(ref pointee, _) | Type::MutRef(ref pointee) => match **pointee {
+            Type::FuncPtr(..) => d_type(pointee),
+            _ => format!("{}*", d_type(pointee)),
+        },
+        Type::Path(ref generic) => generic.export_name().to_owned(),
+        Type::Primitive(ref primitive) => primitive_type(primitive).to_owned(),
+        Type::Array(ref ty, ref len) => format!("{}[{}]", d_type(ty), len.as_str()),
+        Type::FuncPtr(ref ret, ref args, _, calling_convention, variadic) => {
+            let mut args: Vec<_> = args.iter().map(|(_, ty)| d_type(ty)).collect();
+            if variadic {
+                args.push("...".to_owned());
+            }
+            let function = format!("{} function({})", d_type(ret), args.join(", "));
+            match linkage(calling_convention) {
+                Some(linkage) => format!("{} {}", linkage, function),
+                None => function,
+            }
+        }
+        Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+            unreachable!("{:?} should have been lowered to a struct", ty)
+        }
+    }
+}
+
+/// Converts a literal in C syntax, as stored in the IR, to D.
+fn literal(bindings: &Bindings, value: &Literal) -> String {
+    match *value {
+        Literal::Expr(ref v) => expr_literal(v),
+        Literal::Path(ref name) => name.clone(),
+        Literal::Cast { .. } => unreachable!("Casts are evaluated when generating the bindings."),
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => {
+            let operand = |value: &Literal| match *value {
+                Literal::BinOp { .. } => format!("({})", literal(bindings, value)),
+                _ => literal(bindings, value),
+            };
+            format!("{} {} {}", operand(left), op, operand(right))
+        }
+        Literal::Struct {
+            ref path,
+            ref fields,
+            ..
+        } => {
+            if bindings.struct_is_transparent(path) {
+                return literal(bindings, &fields[0].1);
+            }
+            // A static initializer, which names the fields.
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|(key, lit)| format!("{}: {}", escape(key), literal(bindings, lit)))
+                .collect();
+            format!("{{ {} }}", fields.join(", "))
+        }
+    }
+}
+
+fn expr_literal(v: &str) -> String {
+    if v.starts_with("u8\"") && v.ends_with('"') {
+        let mut result = String::from("\"");
+        for c in v[3..v.len() - 1].chars() {
+            match c {
+                '"' | '\\' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                _ if (c as u32) < 0x20 => result.push_str(&format!("\\x{:02x}", c as u32)),
+                _ => result.push(c),
+            }
+        }
+        result.push('"');
+        return result;
+    }
+
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        return format!("0x{}", &v[4..v.len() - 1]);
+    }
+
+    if v.starts_with('\'') && v.ends_with('\'') {
+        // `char::escape_default` output, which D reads as is but for unicode
+        // escapes, which don't fit in a `char`.
+        if let Some(start) = v.find("\\u{") {
+            return format!("0x{}", &v[start + 3..v.len() - 2]);
+        }
+        return v.to_owned();
+    }
+
+    match v {
+        "NAN" => return "double.nan".to_owned(),
+        "INFINITY" => return "double.infinity".to_owned(),
+        "-INFINITY" => return "-double.infinity".to_owned(),
+        _ => {}
+    }
+
+    // Decimal literals are `long` at most, unless they're unsigned, so the
+    // smallest `long` can't be negated from one.
+    if v.parse::<i64>() == Ok(i64::min_value()) {
+        return "long.min".to_owned();
+    }
+    match v.parse::<u64>() {
+        Ok(i) if i > i64::max_value() as u64 => format!("{}UL", v),
+        _ => v.to_owned(),
+    }
+}
+
+/// Writes a D module with `extern (C)` declarations for `bindings`.
+pub(crate) struct DWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+}
+
+impl<'a> DWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        DWriter {
+            bindings,
+            config: &bindings.config,
+        }
+    }
+
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        out.new_line_if_not_start();
+        out.write("extern (C):");
+        out.new_line();
+
+        for constant in &self.bindings.constants {
+            self.write_constant(constant, None, out);
+        }
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => self.write_struct(x, out),
+                ItemContainer::Union(ref x) => self.write_union(x, out),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque(x, out),
+                ItemContainer::Typedef(ref x) => self.write_typedef(x, out),
+            }
+        }
+
+        for global in &self.bindings.globals {
+            self.write_static(global, out);
+        }
+
+        for function in &self.bindings.functions {
+            self.write_function(function, out);
+        }
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if let Some(ref module) = self.config.d.module {
+            out.new_line_if_not_start();
+            write!(out, "module {};", module);
+            out.new_line();
+        }
+
+        out.new_line_if_not_start();
+        out.write("import core.stdc.config : c_long, c_ulong;");
+        out.new_line();
+        out.write("import core.stdc.stdarg : va_list;");
+        out.new_line();
+        out.write("import core.stdc.stddef : wchar_t;");
+        out.new_line();
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        for line in &documentation.doc_comment {
+            out.write("///");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+    }
+
+    /// The `#[cfg]` of items could be mapped to `version` blocks, but their
+    /// conditions are only recorded in a comment, like in the other
+    /// languages without a preprocessor.
+    fn write_cfg<F: Write>(&self, cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+        if let Some(ref cfg) = *cfg {
+            write!(out, "// cfg({})", cfg);
+            out.new_line();
+        }
+    }
+
+    /// Writes the fields of a struct or union, which are bitfields if `widths`
+    /// is given, as needs `-preview=bitfields`.
+    fn write_fields<F: Write>(
+        &self,
+        fields: &[(String, Type, Documentation)],
+        widths: Option<&[u32]>,
+        out: &mut SourceWriter<F>,
+    ) {
+        for (i, (name, ty, documentation)) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_documentation(documentation, out);
+            match widths {
+                Some(widths) => write!(out, "{} {} : {};", d_type(ty), escape(name), widths[i]),
+                None => write!(out, "{} {};", d_type(ty), escape(name)),
+            }
+        }
+    }
+
+    fn write_struct<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&s.cfg, out);
+        self.write_documentation(&s.documentation, out);
+
+        if s.is_transparent {
+            write!(
+                out,
+                "alias {} = {};",
+                s.export_name(),
+                d_type(&s.fields[0].1)
+            );
+        } else {
+            if let Some(ReprAlign::Align(n)) = s.alignment {
+                write!(out, "align({}) ", n);
+            }
+            write!(out, "struct {}", s.export_name());
+            out.open_brace();
+            if let Some(ReprAlign::Packed(n)) = s.alignment {
+                write!(out, "align({}):", n);
+                out.new_line();
+            }
+            self.write_fields(&s.fields, s.bitfield_widths.as_ref().map(|x| &x[..]), out);
+            out.close_brace(false);
+        }
+        out.new_line();
+
+        for constant in &s.associated_constants {
+            self.write_constant(constant, Some(s), out);
+        }
+    }
+
+    fn write_union<F: Write>(&self, u: &Union, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&u.cfg, out);
+        self.write_documentation(&u.documentation, out);
+        if let Some(ReprAlign::Align(n)) = u.alignment {
+            write!(out, "align({}) ", n);
+        }
+        write!(out, "union {}", u.export_name());
+        out.open_brace();
+        if let Some(ReprAlign::Packed(n)) = u.alignment {
+            write!(out, "align({}):", n);
+            out.new_line();
+        }
+        self.write_fields(&u.fields, None, out);
+        out.close_brace(false);
+        out.new_line();
+    }
+
+    fn write_opaque<F: Write>(&self, o: &OpaqueItem, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&o.cfg, out);
+        self.write_documentation(&o.documentation, out);
+        write!(out, "struct {};", o.export_name());
+        out.new_line();
+    }
+
+    fn write_typedef<F: Write>(&self, t: &Typedef, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&t.cfg, out);
+        self.write_documentation(&t.documentation, out);
+        write!(out, "alias {} = {};", t.export_name(), d_type(&t.aliased));
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&e.cfg, out);
+        self.write_documentation(&e.documentation, out);
+
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        write!(out, "enum {}", tag);
+        if let Some(ty) = e.repr.ty {
+            write!(out, " : {}", repr_type(ty));
+        }
+        out.open_brace();
+        let format = self.config.enumeration.discriminant_format(&e.annotations);
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_documentation(&variant.documentation, out);
+            write!(out, "{}", escape(&variant.export_name));
+            if let Some(discriminant) = variant.discriminant {
+                write!(out, " = {}", format.format(discriminant));
+            }
+            out.write(",");
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            out.new_line();
+            out.write("Sentinel,");
+        }
+        out.close_brace(false);
+        out.new_line();
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                self.write_struct(body, out);
+            }
+        }
+
+        // Like in C, the variants are in a union after the tag, or the tag is
+        // the first field of every variant.
+        let variants: Vec<_> = e
+            .variants
+            .iter()
+            .filter_map(|variant| variant.body.as_ref())
+            .map(|(name, body)| (name.clone(), body.export_name().to_owned()))
+            .collect();
+        out.new_line();
+        if e.repr.style == ReprStyle::C {
+            write!(out, "struct {}", e.export_name());
+            out.open_brace();
+            write!(out, "{} tag;", tag);
+            out.new_line();
+            out.write("union");
+            out.open_brace();
+        } else {
+            write!(out, "union {}", e.export_name());
+            out.open_brace();
+            write!(out, "{} tag;", tag);
+            out.new_line();
+        }
+        for (i, (name, body)) in variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "{} {};", body, escape(name));
+        }
+        if e.repr.style == ReprStyle::C {
+            out.close_brace(false);
+        }
+        out.close_brace(false);
+        out.new_line();
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return;
+        }
+
+        let name = match constant.associated_to {
+            None => constant.export_name().to_owned(),
+            Some(ref path) => {
+                let associated_name = match associated_to {
+                    Some(s) => s.export_name().to_owned(),
+                    None => {
+                        let mut name = path.name().to_owned();
+                        self.config.export.rename(&mut name);
+                        name
+                    }
+                };
+                format!("{}_{}", associated_name, constant.export_name())
+            }
+        };
+
+        out.new_line();
+        self.write_cfg(&constant.cfg, out);
+        self.write_documentation(&constant.documentation, out);
+        // Struct literals are static initializers, which only initialize
+        // variables.
+        let storage = match constant.value {
+            Literal::Struct { ref path, .. } if !self.bindings.struct_is_transparent(path) => {
+                "immutable"
+            }
+            _ => "enum",
+        };
+        write!(
+            out,
+            "{} {} {} = {};",
+            storage,
+            d_type(&constant.ty),
+            name,
+            literal(self.bindings, &constant.value)
+        );
+        out.new_line();
+    }
+
+    fn write_static<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&global.cfg, out);
+        self.write_documentation(&global.documentation, out);
+        if global.mutable {
+            write!(
+                out,
+                "extern __gshared {} {};",
+                d_type(&global.ty),
+                global.export_name()
+            );
+        } else {
+            write!(
+                out,
+                "extern __gshared const({}) {};",
+                d_type(&global.ty),
+                global.export_name()
+            );
+        }
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        let mut args: Vec<_> = function
+            .args
+            .iter()
+            .map(|(name, ty)| format!("{} {}", d_type(ty), escape(name)))
+            .collect();
+        if function.variadic {
+            args.push("...".to_owned());
+        }
+        if let Some(linkage) = linkage(function.calling_convention) {
+            write!(out, "{} ", linkage);
+        }
+        write!(
+            out,
+            "{} {}({});",
+            if function.no_return {
+                "noreturn".to_owned()
+            } else {
+                d_type(&function.ret)
+            },
+            function.path().name(),
+            args.join(", ")
+        );
+        out.new_line();
+    }
+}
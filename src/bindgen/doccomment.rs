@@ -0,0 +1,261 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use bindgen::config::DocumentationStyle;
+
+/// The sections of a Rust doc comment that have a counterpart in the commands
+/// of C documentation tools.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Section {
+    Text,
+    Safety,
+    Arguments,
+    Returns,
+}
+
+impl Section {
+    fn from_heading(line: &str) -> Option<Section> {
+        if !line.starts_with('#') {
+            return None;
+        }
+        let title = line.trim_start_matches('#');
+        if !title.starts_with(' ') {
+            return None;
+        }
+        Some(match title.trim().to_lowercase().as_ref() {
+            "safety" => Section::Safety,
+            "arguments" | "parameters" => Section::Arguments,
+            "returns" => Section::Returns,
+            _ => Section::Text,
+        })
+    }
+}
+
+/// Rewrites the lines of a Rust doc comment for `style`: `# Safety` and
+/// `# Returns` sections become `warning` and `return` commands, the items of
+/// `# Arguments` lists become `param` commands, and intra-doc links become
+/// references. Styles without commands leave the lines as they are.
+pub fn rewrite(lines: &[String], style: DocumentationStyle) -> Vec<String> {
+    let command = match style {
+        DocumentationStyle::Javadoc => '@',
+        DocumentationStyle::Doxygen | DocumentationStyle::Qt => '\\',
+        _ => return lines.to_vec(),
+    };
+
+    let mut out = Vec::new();
+    let mut section = Section::Text;
+    let mut first_in_section = false;
+    for line in lines {
+        if let Some(next) = Section::from_heading(line) {
+            section = next;
+            first_in_section = true;
+            if section == Section::Text {
+                out.push(line.clone());
+            }
+            continue;
+        }
+
+        let line = rewrite_links(line, style);
+        match section {
+            Section::Text => out.push(line),
+            Section::Safety | Section::Returns if first_in_section => {
+                let name = if section == Section::Safety {
+                    "warning"
+                } else {
+                    "return"
+                };
+                out.push(format!("{}{} {}", command, name, line.trim()));
+            }
+            Section::Safety | Section::Returns => out.push(line),
+            Section::Arguments => match parse_argument(&line) {
+                Some((name, description)) => {
+                    out.push(format!("{}param {} {}", command, name, description));
+                }
+                None => out.push(line),
+            },
+        }
+        first_in_section = false;
+    }
+    out
+}
+
+/// Parses an item of an `# Arguments` list, such as ``* `len` - The length``.
+fn parse_argument(line: &str) -> Option<(&str, &str)> {
+    // Leading `*`s have already been stripped with the comment markers.
+    let line = line.trim_start();
+    let line = if line.starts_with("- ") || line.starts_with("+ ") || line.starts_with("* ") {
+        line[2..].trim_start()
+    } else {
+        line
+    };
+
+    let (name, rest) = if line.starts_with('`') {
+        let end = line[1..].find('`')? + 1;
+        (&line[1..end], &line[end + 1..])
+    } else {
+        let end = line
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(line.len());
+        (&line[..end], &line[end..])
+    };
+    if !is_identifier(name) {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let description = if rest.starts_with('-') || rest.starts_with(':') {
+        rest[1..].trim_start()
+    } else if rest.starts_with('\u{2014}') {
+        rest['\u{2014}'.len_utf8()..].trim_start()
+    } else {
+        return None;
+    };
+    Some((name, description))
+}
+
+/// Rewrites the intra-doc links of `line`, such as ``[`Foo`]``,
+/// `[text][Foo]` and `[text](Foo::bar)`. Links to URLs are kept.
+fn rewrite_links(line: &str, style: DocumentationStyle) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('[') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = match rest.find(']') {
+            Some(end) => end,
+            None => break,
+        };
+        let text = &rest[1..end];
+        let after = &rest[end + 1..];
+
+        let (target, label, len) = if after.starts_with('(') || after.starts_with('[') {
+            let close = if after.starts_with('(') { ')' } else { ']' };
+            match after.find(close) {
+                Some(close) => (&after[1..close], Some(text), end + 1 + close + 1),
+                None => (text, None, end + 1),
+            }
+        } else {
+            (text, None, end + 1)
+        };
+
+        match link_target(target) {
+            Some(target) => {
+                let label = label.filter(|label| label.trim_matches('`') != target);
+                out.push_str(&reference(target, label, style));
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns the name an intra-doc link refers to in the bindings, or `None`
+/// if it isn't a link to a Rust item.
+fn link_target(target: &str) -> Option<&str> {
+    let target = target.trim_matches('`');
+    // Drop disambiguators, such as in `struct@Foo`.
+    let target = target.rsplit('@').next().unwrap();
+    let path = if target.ends_with("()") {
+        &target[..target.len() - 2]
+    } else {
+        target
+    };
+
+    if path.is_empty() || !path.split("::").all(is_identifier) {
+        return None;
+    }
+    let start = path.rfind("::").map_or(0, |i| i + 2);
+    Some(&target[start..])
+}
+
+fn reference(target: &str, label: Option<&str>, style: DocumentationStyle) -> String {
+    match (style, label) {
+        (DocumentationStyle::Javadoc, Some(label)) => format!("{{@link {} {}}}", target, label),
+        (DocumentationStyle::Javadoc, None) => format!("{{@link {}}}", target),
+        (_, Some(label)) => format!("\\ref {} \"{}\"", target, label),
+        (_, None) => format!("\\ref {}", target),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite_str(lines: &[&str], style: DocumentationStyle) -> Vec<String> {
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        rewrite(&lines, style)
+    }
+
+    #[test]
+    fn sections() {
+        let lines = [
+            "Copies `len` bytes.",
+            "# Arguments",
+            " `dst` - The destination.",
+            "- `len`: The number of bytes.",
+            "# Returns",
+            "The number of bytes copied.",
+            "# Safety",
+            "`dst` must be valid for `len` bytes.",
+            "# Examples",
+            "See the tests.",
+        ];
+        assert_eq!(
+            rewrite_str(&lines, DocumentationStyle::Javadoc),
+            [
+                "Copies `len` bytes.",
+                "@param dst The destination.",
+                "@param len The number of bytes.",
+                "@return The number of bytes copied.",
+                "@warning `dst` must be valid for `len` bytes.",
+                "# Examples",
+                "See the tests.",
+            ]
+        );
+        assert_eq!(
+            rewrite_str(&lines[..3], DocumentationStyle::Qt),
+            ["Copies `len` bytes.", "\\param dst The destination."]
+        );
+        assert_eq!(rewrite_str(&lines, DocumentationStyle::Doxy), lines);
+    }
+
+    #[test]
+    fn links() {
+        let style = DocumentationStyle::Doxygen;
+        assert_eq!(
+            rewrite_links("See [`Foo`] and [`crate::bar()`].", style),
+            "See \\ref Foo and \\ref bar()."
+        );
+        assert_eq!(
+            rewrite_links("A [buffer](Buffer) or [one][struct@Other].", style),
+            "A \\ref Buffer \"buffer\" or \\ref Other \"one\"."
+        );
+        assert_eq!(
+            rewrite_links("The [docs](https://docs.rs), [1, 2] and a[", style),
+            "The [docs](https://docs.rs), [1, 2] and a["
+        );
+        assert_eq!(
+            rewrite_links(
+                "[`Foo`](Foo) and [foo](self::foo)",
+                DocumentationStyle::Javadoc
+            ),
+            "{@link Foo} and {@link foo}"
+        );
+    }
+}
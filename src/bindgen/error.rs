@@ -24,6 +24,12 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    TargetCfgs(String, String),
+    InjectionFile(String, String),
+    Template(String),
+    KeywordCollisions(Vec<String>),
+    UnguardedTargets(Vec<String>),
+    Diagnostics(usize),
 }
 
 impl fmt::Display for Error {
@@ -70,6 +76,38 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            &Error::TargetCfgs(ref target, ref error) => write!(
+                f,
+                "Couldn't get the cfgs of target `{}` from rustc: {}",
+                target, error
+            ),
+            &Error::InjectionFile(ref path, ref error) => {
+                write!(f, "Couldn't read injected file {:?}: {}", path, error)
+            }
+            &Error::Template(ref error) => write!(f, "{}", error),
+            &Error::Diagnostics(count) => write!(
+                f,
+                "{} denied diagnostic{} reported.",
+                count,
+                if count == 1 { " was" } else { "s were" }
+            ),
+            &Error::KeywordCollisions(ref collisions) => {
+                write!(f, "Identifiers collide with C or C++ keywords or macros:")?;
+                for collision in collisions {
+                    write!(f, "\n  {}", collision)?;
+                }
+                Ok(())
+            }
+            &Error::UnguardedTargets(ref targets) => {
+                write!(
+                    f,
+                    "Items differ between targets with no `[defines]` entry to guard them with:"
+                )?;
+                for target in targets {
+                    write!(f, "\n  \"target = {}\"", target)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -82,6 +120,12 @@ impl error::Error for Error {
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::TargetCfgs(..) => None,
+            Error::InjectionFile(..) => None,
+            Error::Template(..) => None,
+            Error::KeywordCollisions(..) => None,
+            Error::UnguardedTargets(..) => None,
+            Error::Diagnostics(..) => None,
         }
     }
 }
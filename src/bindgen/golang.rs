@@ -0,0 +1,572 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a Go file using cgo to include the C header.
+//!
+//! The items of the header are aliased to Go names, so that their layout
+//! always comes from the header, and every function gets a Go wrapper. The
+//! wrappers take Go types for the fixed width integers and floats, and
+//! convert them, and pointers to them, to the types cgo gives the C ones.
+//! Platform dependent types, like `C.int` and `C.size_t`, are kept as is.
+
+use std::collections::HashSet;
+use std::io::Write;
+
+use bindgen::config::Config;
+use bindgen::ir::{
+    Cfg, Constant, Documentation, Enum, Function, Item, ItemContainer, PrimitiveType, ReprStyle,
+    Static, Struct, Type,
+};
+use bindgen::python::is_exported;
+use bindgen::rename::{IdentifierType, RenameRule};
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Taken from `https://go.dev/ref/spec#Keywords`.
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+fn escape(name: &str) -> String {
+    if GO_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Returns the Go type of a fixed width primitive, or `None` if the wrappers
+/// use the type cgo gives the C one.
+fn go_primitive(primitive: &PrimitiveType) -> Option<&'static str> {
+    match *primitive {
+        PrimitiveType::Bool => Some("bool"),
+        PrimitiveType::UInt8 => Some("uint8"),
+        PrimitiveType::UInt16 => Some("uint16"),
+        PrimitiveType::UInt32 => Some("uint32"),
+        PrimitiveType::UInt64 => Some("uint64"),
+        PrimitiveType::Int8 => Some("int8"),
+        PrimitiveType::Int16 => Some("int16"),
+        PrimitiveType::Int32 => Some("int32"),
+        PrimitiveType::Int64 => Some("int64"),
+        PrimitiveType::USize => Some("uintptr"),
+        PrimitiveType::ISize => Some("int"),
+        PrimitiveType::Float => Some("float32"),
+        PrimitiveType::Double => Some("float64"),
+        _ => None,
+    }
+}
+
+/// Returns the type cgo gives a C primitive.
+fn cgo_primitive(primitive: &PrimitiveType) -> String {
+    match *primitive {
+        PrimitiveType::SChar => "C.schar".to_owned(),
+        PrimitiveType::UChar => "C.uchar".to_owned(),
+        PrimitiveType::UShort => "C.ushort".to_owned(),
+        PrimitiveType::UInt => "C.uint".to_owned(),
+        PrimitiveType::ULong => "C.ulong".to_owned(),
+        PrimitiveType::LongLong => "C.longlong".to_owned(),
+        PrimitiveType::ULongLong => "C.ulonglong".to_owned(),
+        // cgo has no names for the native 128-bit integers.
+        PrimitiveType::Int128 | PrimitiveType::UInt128 => "[16]byte".to_owned(),
+        _ => format!("C.{}", primitive.to_repr_c()),
+    }
+}
+
+/// Returns the Go name of an item, which is the C one unless it would shadow
+/// the `C` pseudo-package.
+fn type_name(name: &str) -> String {
+    if name == "C" {
+        "C.C".to_owned()
+    } else {
+        name.to_owned()
+    }
+}
+
+fn is_pointer(ty: &Type) -> bool {
+    match *ty {
+        Type::ConstPtr(..) | Type::Ptr(..) | Type::Ref(..) | Type::MutRef(..) => true,
+        _ => false,
+    }
+}
+
+/// Writes a Go file with cgo aliases and wrappers for `bindings`.
+pub(crate) struct GoWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    /// The names declared by the aliases and constants, which wrappers can't
+    /// be named after.
+    declarations: HashSet<String>,
+}
+
+impl<'a> GoWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        let mut declarations = HashSet::new();
+        for item in &bindings.items {
+            declarations.insert(item.deref().export_name().to_owned());
+            if let ItemContainer::Enum(ref e) = *item {
+                if let Some(ref tag) = e.tag {
+                    declarations.insert(tag.clone());
+                }
+                for variant in &e.variants {
+                    declarations.insert(variant.export_name.clone());
+                }
+            }
+        }
+        declarations.extend(
+            bindings
+                .constants
+                .iter()
+                .map(|x| x.export_name().to_owned()),
+        );
+
+        GoWriter {
+            bindings,
+            config: &bindings.config,
+            declarations,
+        }
+    }
+
+    /// Returns the type of `ty` in the signature of a wrapper.
+    fn go_type(&self, ty: &Type) -> String {
+        match *ty {
+            Type::ConstPtr(ref pointee, _)
+            | Type::Ptr(ref pointee, _)
+            | Type::Ref(ref pointee)
+            | Type::MutRef(ref pointee) => match **pointee {
+                Type::Primitive(PrimitiveType::Void) => "unsafe.Pointer".to_owned(),
+                _ => format!("*{}", self.go_type(pointee)),
+            },
+            Type::Primitive(ref primitive) => match go_primitive(primitive) {
+                Some(go) => go.to_owned(),
+                None => cgo_primitive(primitive),
+            },
+            _ => self.cgo_type(ty),
+        }
+    }
+
+    /// Returns the type cgo gives `ty`.
+    fn cgo_type(&self, ty: &Type) -> String {
+        match *ty {
+            Type::ConstPtr(ref pointee, _)
+            | Type::Ptr(ref pointee, _)
+            | Type::Ref(ref pointee)
+            | Type::MutRef(ref pointee) => match **pointee {
+                Type::Primitive(PrimitiveType::Void) => "unsafe.Pointer".to_owned(),
+                _ => format!("*{}", self.cgo_type(pointee)),
+            },
+            // The aliases are the same types as the ones of cgo.
+            Type::Path(ref generic) => type_name(generic.export_name()),
+            Type::Primitive(ref primitive) => cgo_primitive(primitive),
+            Type::Array(ref ty, ref len) => format!("[{}]{}", len.as_str(), self.cgo_type(ty)),
+            // cgo has no function types.
+            Type::FuncPtr(..) => "*[0]byte".to_owned(),
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    /// Returns the type cgo gives an item, which depends on whether the
+    /// header declares it as a typedef or with a tag.
+    fn cgo_item(&self, keyword: &str, name: &str) -> String {
+        if self.config.style.generate_typedef() {
+            format!("C.{}", name)
+        } else {
+            format!("C.{}_{}", keyword, name)
+        }
+    }
+
+    /// Whether the wrappers convert pointers, which needs `unsafe`.
+    fn uses_unsafe(&self) -> bool {
+        let converts = |ty: &Type| {
+            let go = self.go_type(ty);
+            go.contains("unsafe.") || (is_pointer(ty) && go != self.cgo_type(ty))
+        };
+        self.bindings
+            .functions
+            .iter()
+            .filter(|f| !f.variadic)
+            .any(|f| converts(&f.ret) || f.args.iter().any(|(_, ty)| converts(ty)))
+            || self.bindings.globals.iter().any(|g| converts(&g.ty))
+    }
+
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => {
+                    let ty = if x.is_transparent {
+                        format!("C.{}", x.export_name())
+                    } else {
+                        self.cgo_item("struct", x.export_name())
+                    };
+                    self.write_alias(x.export_name(), &ty, &x.cfg, &x.documentation, out);
+                    for constant in &x.associated_constants {
+                        self.write_constant(constant, Some(x), out);
+                    }
+                }
+                ItemContainer::Union(ref x) => {
+                    let ty = self.cgo_item("union", x.export_name());
+                    self.write_alias(x.export_name(), &ty, &x.cfg, &x.documentation, out);
+                }
+                ItemContainer::OpaqueItem(ref x) => {
+                    let ty = self.cgo_item("struct", x.export_name());
+                    self.write_alias(x.export_name(), &ty, &x.cfg, &x.documentation, out);
+                }
+                ItemContainer::Typedef(ref x) => {
+                    let ty = format!("C.{}", x.export_name());
+                    self.write_alias(x.export_name(), &ty, &x.cfg, &x.documentation, out);
+                }
+            }
+        }
+
+        for constant in &self.bindings.constants {
+            self.write_constant(constant, None, out);
+        }
+
+        for global in &self.bindings.globals {
+            self.write_global(global, out);
+        }
+
+        for function in &self.bindings.functions {
+            self.write_function(function, out);
+        }
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        let go = &self.config.go;
+        out.new_line_if_not_start();
+        write!(out, "package {}", go.package);
+        out.new_line();
+
+        // The preamble has to be right before the import of "C".
+        out.new_line();
+        if !go.cflags.is_empty() {
+            write!(out, "// #cgo CFLAGS: {}", go.cflags.join(" "));
+            out.new_line();
+        }
+        if !go.ldflags.is_empty() {
+            write!(out, "// #cgo LDFLAGS: {}", go.ldflags.join(" "));
+            out.new_line();
+        }
+        match go.header {
+            Some(ref header) => write!(out, "// #include \"{}\"", header),
+            None => write!(out, "// #include \"{}.h\"", go.package),
+        }
+        out.new_line();
+        out.write("import \"C\"");
+        out.new_line();
+
+        if self.uses_unsafe() {
+            out.new_line();
+            out.write("import \"unsafe\"");
+            out.new_line();
+        }
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        for line in &documentation.doc_comment {
+            out.write("//");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+    }
+
+    /// Go has no preprocessor, so conditional items are always written and
+    /// their condition is only recorded in a comment.
+    fn write_cfg<F: Write>(&self, cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+        if let Some(ref cfg) = *cfg {
+            write!(out, "// cfg({})", cfg);
+            out.new_line();
+        }
+    }
+
+    fn write_alias<F: Write>(
+        &self,
+        name: &str,
+        ty: &str,
+        cfg: &Option<Cfg>,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if name == "C" {
+            warn!("Skipping the Go alias of C, since it would shadow cgo.");
+            return;
+        }
+        out.new_line();
+        self.write_cfg(cfg, out);
+        self.write_documentation(documentation, out);
+        write!(out, "type {} = {}", name, ty);
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        // Enums with a `#[repr(prim)]` are typedefs of their integer type.
+        let ty = if e.repr.ty.is_some() {
+            format!("C.{}", tag)
+        } else {
+            self.cgo_item("enum", tag)
+        };
+        self.write_alias(tag, &ty, &e.cfg, &e.documentation, out);
+
+        out.new_line();
+        out.write("const (");
+        out.push_tab();
+        for variant in &e.variants {
+            out.new_line();
+            self.write_documentation(&variant.documentation, out);
+            write!(
+                out,
+                "{} {} = C.{}",
+                variant.export_name,
+                type_name(tag),
+                variant.export_name
+            );
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            out.new_line();
+            write!(out, "Sentinel {} = C.Sentinel", type_name(tag));
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write(")");
+        out.new_line();
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                let ty = self.cgo_item("struct", body.export_name());
+                self.write_alias(body.export_name(), &ty, &body.cfg, &body.documentation, out);
+            }
+        }
+
+        let keyword = if e.repr.style == ReprStyle::C {
+            "struct"
+        } else {
+            "union"
+        };
+        let ty = self.cgo_item(keyword, e.export_name());
+        self.write_alias(e.export_name(), &ty, &e.cfg, &Documentation::none(), out);
+    }
+
+    /// Writes the constants of primitive types, which cgo can read from the
+    /// macros of the header. Booleans are skipped, since `true` is 1 in C.
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        // String constants are written as Go strings, which have the same
+        // escapes as the C ones.
+        let is_string = constant.value.is_string();
+        match constant.ty {
+            Type::Primitive(PrimitiveType::Bool) => return,
+            Type::Primitive(..) => {}
+            _ if is_string => {}
+            _ => return,
+        }
+
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        // Go has no constants for non-finite floats.
+        if !constant.value.is_valid(self.bindings) || constant.value.uses_math() {
+            return;
+        }
+
+        let name = constant
+            .global_name(self.config, associated_to)
+            .into_owned();
+
+        out.new_line();
+        self.write_cfg(&constant.cfg, out);
+        self.write_documentation(&constant.documentation, out);
+        if is_string {
+            write!(out, "const {} = {}", name, constant.value);
+        } else {
+            write!(out, "const {} = C.{}", name, name);
+        }
+        out.new_line();
+    }
+
+    /// Returns the conversion of `value` from `from` to `to`, or `value`
+    /// itself if they're the same type.
+    fn convert(&self, value: &str, ty: &Type, from: &str, to: &str) -> String {
+        if from == to {
+            value.to_owned()
+        } else if is_pointer(ty) {
+            format!("({})(unsafe.Pointer({}))", to, value)
+        } else {
+            format!("{}({})", to, value)
+        }
+    }
+
+    /// Writes a getter for a global, since cgo can't alias variables.
+    fn write_global<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        let c_name = global.export_name();
+        let name = RenameRule::PascalCase
+            .apply_to_snake_case(&c_name.to_lowercase(), IdentifierType::Function);
+        if self.declarations.contains(&name) {
+            warn!(
+                "Skipping the Go getter of {}, since {} is already declared.",
+                c_name, name
+            );
+            return;
+        }
+
+        let go = self.go_type(&global.ty);
+        let cgo = self.cgo_type(&global.ty);
+        out.new_line();
+        self.write_cfg(&global.cfg, out);
+        self.write_documentation(&global.documentation, out);
+        write!(out, "func {}() {} {{", name, go);
+        out.push_tab();
+        out.new_line();
+        let value = format!("C.{}", c_name);
+        write!(
+            out,
+            "return {}",
+            self.convert(&value, &global.ty, &cgo, &go)
+        );
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        let c_name = function.path().name();
+        if function.variadic {
+            warn!(
+                "Skipping the Go wrapper of {}, since cgo can't call variadic functions.",
+                c_name
+            );
+            return;
+        }
+        let name = RenameRule::PascalCase.apply_to_snake_case(c_name, IdentifierType::Function);
+        if self.declarations.contains(&name) {
+            warn!(
+                "Skipping the Go wrapper of {}, since {} is already declared.",
+                c_name, name
+            );
+            return;
+        }
+
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for (arg, ty) in &function.args {
+            let arg = escape(arg);
+            let go = self.go_type(ty);
+            args.push(self.convert(&arg, ty, &go, &self.cgo_type(ty)));
+            params.push(format!("{} {}", arg, go));
+        }
+        let call = format!("C.{}({})", c_name, args.join(", "));
+
+        out.new_line();
+        self.write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        write!(out, "func {}({})", name, params.join(", "));
+        if function.ret == Type::Primitive(PrimitiveType::Void) {
+            out.write(" {");
+            out.push_tab();
+            out.new_line();
+            write!(out, "{}", call);
+        } else {
+            let go = self.go_type(&function.ret);
+            let cgo = self.cgo_type(&function.ret);
+            write!(out, " {} {{", go);
+            out.push_tab();
+            out.new_line();
+            write!(
+                out,
+                "return {}",
+                self.convert(&call, &function.ret, &cgo, &go)
+            );
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+}
@@ -7,6 +7,7 @@ use std::str::FromStr;
 
 use syn;
 
+use bindgen::ir::Type;
 use bindgen::utilities::SynAttributeHelpers;
 
 // A system for specifying properties on items. Annotations are
@@ -23,7 +24,7 @@ use bindgen::utilities::SynAttributeHelpers;
 //  * cbindgen:function-postfix=WR_DESTRUCTOR_SAFE
 
 /// A value specified by an annotation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AnnotationValue {
     List(Vec<String>),
     Atom(Option<String>),
@@ -31,10 +32,38 @@ pub enum AnnotationValue {
 }
 
 /// A set of annotations specified by a document comment.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnnotationSet {
     annotations: HashMap<String, AnnotationValue>,
     pub must_use: bool,
+    /// The note of the `#[deprecated]` attribute of the item, which is empty
+    /// when it has none.
+    pub deprecated: Option<String>,
+    /// The top-level module of the binding crate the item is declared in, or
+    /// `None` for the crate root.
+    pub module: Option<String>,
+    /// The crate the item is declared in, or `None` for items that aren't
+    /// declared in a crate, such as the standard types.
+    pub crate_name: Option<String>,
+    /// The names given to the fields of the item with `cbindgen:rename`, by
+    /// their Rust names.
+    pub field_renames: HashMap<String, String>,
+    /// The locks guarding the fields of the item with `cbindgen:guarded-by`,
+    /// by their Rust names.
+    pub field_guards: HashMap<String, String>,
+    /// The names of the typedefs the function pointers of the fields of the
+    /// item are hoisted into, given with `cbindgen:callback-name`, by the
+    /// Rust names of the fields.
+    #[serde(default)]
+    pub field_callback_names: HashMap<String, String>,
+    /// The default values of the fields of the item given with
+    /// `cbindgen:default`, by their Rust names.
+    #[serde(default)]
+    pub field_defaults: HashMap<String, String>,
+    /// The position of the item in the order the items were declared in the
+    /// sources, once they're all parsed.
+    #[serde(default)]
+    pub source_order: Option<usize>,
 }
 
 impl AnnotationSet {
@@ -42,11 +71,19 @@ impl AnnotationSet {
         AnnotationSet {
             annotations: HashMap::new(),
             must_use: false,
+            deprecated: None,
+            module: None,
+            crate_name: None,
+            field_renames: HashMap::new(),
+            field_guards: HashMap::new(),
+            field_callback_names: HashMap::new(),
+            field_defaults: HashMap::new(),
+            source_order: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.annotations.is_empty() && !self.must_use
+        self.annotations.is_empty() && !self.must_use && self.deprecated.is_none()
     }
 
     pub fn load(attrs: &[syn::Attribute]) -> Result<AnnotationSet, String> {
@@ -56,7 +93,8 @@ impl AnnotationSet {
             .filter(|x| !x.is_empty() && x.starts_with("cbindgen:"))
             .collect();
 
-        let must_use = attrs.has_attr_word("must_use");
+        let must_use = attrs.has_attr_named("must_use");
+        let deprecated = attrs.deprecated_note();
 
         let mut annotations = HashMap::new();
 
@@ -70,8 +108,13 @@ impl AnnotationSet {
             // Remove the "cbingen:" prefix
             let annotation = &line[9..];
 
-            // Split the annotation in two
-            let parts: Vec<&str> = annotation.split("=").map(|x| x.trim()).collect();
+            // Split the annotation in two. The body of an inline function is
+            // code, which can contain `=` itself.
+            let parts: Vec<&str> = if annotation.starts_with("inline-body") {
+                annotation.splitn(2, "=").map(|x| x.trim()).collect()
+            } else {
+                annotation.split("=").map(|x| x.trim()).collect()
+            };
 
             if parts.len() > 2 {
                 return Err(format!("Couldn't parse {}.", line));
@@ -110,9 +153,58 @@ impl AnnotationSet {
         Ok(AnnotationSet {
             annotations,
             must_use,
+            deprecated,
+            module: None,
+            crate_name: None,
+            field_renames: HashMap::new(),
+            field_guards: HashMap::new(),
+            field_callback_names: HashMap::new(),
+            field_defaults: HashMap::new(),
+            source_order: None,
         })
     }
 
+    /// Loads the `cbindgen:rename`, `cbindgen:guarded-by` and
+    /// `cbindgen:callback-name` annotations of the fields of the item. Tuple fields are named by their index among the
+    /// fields that aren't zero sized, as in tuple structs.
+    pub fn load_field_annotations<'a, I>(&mut self, fields: I) -> Result<(), String>
+    where
+        I: IntoIterator<Item = &'a syn::Field>,
+    {
+        let mut index = 0;
+        for field in fields {
+            if Type::load(&field.ty)?.is_none() {
+                continue;
+            }
+            let name = match field.ident {
+                Some(ref ident) => ident.to_string(),
+                None => index.to_string(),
+            };
+            index += 1;
+
+            let annotations = AnnotationSet::load(&field.attrs)?;
+            if let Some(Some(rename)) = annotations.atom("rename") {
+                self.field_renames.insert(name.clone(), rename);
+            }
+            if let Some(Some(lock)) = annotations.atom("guarded-by") {
+                self.field_guards.insert(name.clone(), lock);
+            }
+            if let Some(Some(callback)) = annotations.atom("callback-name") {
+                self.field_callback_names.insert(name.clone(), callback);
+            }
+            match annotations.annotations.get("default") {
+                Some(&AnnotationValue::Atom(Some(ref value))) => {
+                    self.field_defaults.insert(name, value.clone());
+                }
+                Some(&AnnotationValue::Bool(value)) => {
+                    self.field_defaults.insert(name, value.to_string());
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     pub fn list(&self, name: &str) -> Option<Vec<String>> {
         match self.annotations.get(name) {
             Some(&AnnotationValue::List(ref x)) => Some(x.clone()),
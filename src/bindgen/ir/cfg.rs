@@ -2,8 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::{HashMap, HashSet};
+use std::env;
 use std::fmt;
 use std::io::Write;
+use std::process::Command;
 
 use syn;
 
@@ -46,7 +49,7 @@ impl<'a> DefineKey<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Cfg {
     Boolean(String),
     Named(String, String),
@@ -103,6 +106,34 @@ impl Cfg {
         }
     }
 
+    /// Adds the triples of the `target = "triple"` cfgs in `self` that
+    /// `defines` has no entry for to `unguarded`.
+    pub fn unguarded_targets(
+        &self,
+        defines: &HashMap<String, String>,
+        unguarded: &mut Vec<String>,
+    ) {
+        match self {
+            Cfg::Boolean(..) => {}
+            Cfg::Named(name, value) => {
+                if name == "target"
+                    && !unguarded.contains(value)
+                    && !defines
+                        .keys()
+                        .any(|key| DefineKey::load(key) == DefineKey::Named(name, value))
+                {
+                    unguarded.push(value.clone());
+                }
+            }
+            Cfg::Any(cfgs) | Cfg::All(cfgs) => {
+                for cfg in cfgs {
+                    cfg.unguarded_targets(defines, unguarded);
+                }
+            }
+            Cfg::Not(cfg) => cfg.unguarded_targets(defines, unguarded),
+        }
+    }
+
     pub fn load(attrs: &[syn::Attribute]) -> Option<Cfg> {
         let mut configs = Vec::new();
 
@@ -215,6 +246,247 @@ impl Cfg {
     }
 }
 
+/// The value of a cfg given the known cfgs, which is either known or a
+/// simplified cfg of the unknown ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CfgValue {
+    Known(bool),
+    Unknown(Cfg),
+}
+
+/// The cfgs that are known when generating bindings for a target, which are
+/// resolved instead of being written as preprocessor conditions.
+#[derive(Debug, Clone, Default)]
+pub struct KnownCfgs {
+    /// The target triple the cfgs are of.
+    target: String,
+    /// The known boolean cfgs and whether they're set.
+    booleans: HashMap<String, bool>,
+    /// The names whose values are all known, like `target_os`, with their
+    /// values.
+    named: HashMap<String, HashSet<String>>,
+    /// The binding crate and its enabled features.
+    features: Option<(String, HashSet<String>)>,
+}
+
+impl KnownCfgs {
+    /// Asks rustc for the cfgs of `target`.
+    pub fn for_target(target: &str) -> Result<KnownCfgs, String> {
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        let output = Command::new(rustc)
+            .args(&["--print", "cfg", "--target", target])
+            .output()
+            .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+        }
+        let mut known = KnownCfgs::parse(&String::from_utf8_lossy(&output.stdout));
+        known.target = target.to_owned();
+        Ok(known)
+    }
+
+    /// Parses the output of `rustc --print cfg`, with a cfg per line like
+    /// `unix` or `target_os="linux"`. The cfgs that depend on the profile
+    /// rather than the target are left unknown.
+    pub fn parse(cfgs: &str) -> KnownCfgs {
+        let mut known = KnownCfgs::default();
+        for family in &["unix", "windows"] {
+            known.booleans.insert(family.to_string(), false);
+        }
+        for line in cfgs.lines().map(|x| x.trim()) {
+            match line.find('=') {
+                Some(i) => {
+                    let value = line[i + 1..].trim_matches('"').to_owned();
+                    known
+                        .named
+                        .entry(line[..i].to_owned())
+                        .or_insert_with(HashSet::new)
+                        .insert(value);
+                }
+                None if line.is_empty() || line == "debug_assertions" => {}
+                None => {
+                    known.booleans.insert(line.to_owned(), true);
+                }
+            }
+        }
+        known
+    }
+
+    /// Returns the value of a named cfg like `target_os`, if it has a single
+    /// known one.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        let values = self.named.get(name)?;
+        if values.len() == 1 {
+            values.iter().next().map(|x| x.as_str())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the define of the compilers for the OS of the target, if it
+    /// has a common one.
+    fn builtin_guard(&self) -> Option<&'static str> {
+        match self.value("target_os") {
+            Some("windows") => Some("_WIN32"),
+            Some("linux") => Some("__linux__"),
+            Some("macos") | Some("ios") => Some("__APPLE__"),
+            _ => None,
+        }
+    }
+
+    /// Adds a `target = "triple"` entry to `defines` for each of `targets`
+    /// that has none, guarding it with the define of its OS, unless another of
+    /// `targets` shares it and it can't tell them apart.
+    pub fn add_builtin_guards(targets: &[KnownCfgs], defines: &mut HashMap<String, String>) {
+        for target in targets {
+            if defines
+                .keys()
+                .any(|key| DefineKey::load(key) == DefineKey::Named("target", &target.target))
+            {
+                continue;
+            }
+            let guard = match target.builtin_guard() {
+                Some(guard) => guard,
+                None => continue,
+            };
+            if targets
+                .iter()
+                .filter(|x| x.builtin_guard() == Some(guard))
+                .count()
+                == 1
+            {
+                defines.insert(format!("target = {}", target.target), guard.to_owned());
+            }
+        }
+    }
+
+    /// Sets the enabled features of the binding crate, which only the items
+    /// of that crate are resolved with.
+    pub fn set_features(&mut self, crate_name: &str, features: HashSet<String>) {
+        self.features = Some((crate_name.to_owned(), features));
+    }
+
+    /// Resolves `cfg` for an item of `crate_name` on all of `targets`. An item
+    /// that's only compiled for some of them is conditioned on those, with a
+    /// `target = "triple"` cfg per target, which `[defines]` maps to the
+    /// preprocessor conditions of the targets.
+    pub fn resolve_targets(
+        targets: &[KnownCfgs],
+        cfg: Option<&Cfg>,
+        crate_name: Option<&str>,
+    ) -> CfgValue {
+        let values: Vec<_> = targets
+            .iter()
+            .map(|target| match cfg {
+                Some(cfg) => target.resolve(cfg.clone(), crate_name),
+                None => CfgValue::Known(true),
+            })
+            .collect();
+
+        if let Some(first) = values.first() {
+            if values.iter().all(|x| x == first) {
+                return first.clone();
+            }
+        }
+
+        // The targets the item is compiled for, with the conditions left.
+        let mut present = Vec::new();
+        for (target, value) in targets.iter().zip(values) {
+            let target_cfg = Cfg::Named("target".to_owned(), target.target.clone());
+            match value {
+                CfgValue::Known(false) => {}
+                CfgValue::Known(true) => present.push((target_cfg, None)),
+                CfgValue::Unknown(cfg) => present.push((target_cfg, Some(cfg))),
+            }
+        }
+
+        fn any(mut cfgs: Vec<Cfg>) -> Cfg {
+            if cfgs.len() == 1 {
+                cfgs.pop().unwrap()
+            } else {
+                Cfg::Any(cfgs)
+            }
+        }
+
+        if present.is_empty() {
+            return CfgValue::Known(false);
+        }
+        if present.iter().all(|x| x.1 == present[0].1) {
+            let rest = present[0].1.clone();
+            let targets = any(present.into_iter().map(|x| x.0).collect());
+            return CfgValue::Unknown(match rest {
+                Some(rest) => Cfg::All(vec![targets, rest]),
+                None => targets,
+            });
+        }
+        CfgValue::Unknown(any(present
+            .into_iter()
+            .map(|(target, rest)| match rest {
+                Some(rest) => Cfg::All(vec![target, rest]),
+                None => target,
+            })
+            .collect()))
+    }
+
+    /// Resolves `cfg` for an item of `crate_name`.
+    pub fn resolve(&self, cfg: Cfg, crate_name: Option<&str>) -> CfgValue {
+        match cfg {
+            Cfg::Boolean(name) => match self.booleans.get(&name) {
+                Some(&value) => CfgValue::Known(value),
+                None => CfgValue::Unknown(Cfg::Boolean(name)),
+            },
+            Cfg::Named(name, value) => {
+                let values = match self.features {
+                    Some((ref binding_crate, ref features))
+                        if name == "feature" && crate_name == Some(binding_crate) =>
+                    {
+                        Some(features)
+                    }
+                    _ => self.named.get(&name),
+                };
+                match values {
+                    Some(values) => CfgValue::Known(values.contains(&value)),
+                    None => CfgValue::Unknown(Cfg::Named(name, value)),
+                }
+            }
+            Cfg::Any(children) => {
+                let mut unknown = Vec::new();
+                for child in children {
+                    match self.resolve(child, crate_name) {
+                        CfgValue::Known(true) => return CfgValue::Known(true),
+                        CfgValue::Known(false) => {}
+                        CfgValue::Unknown(child) => unknown.push(child),
+                    }
+                }
+                match unknown.len() {
+                    0 => CfgValue::Known(false),
+                    1 => CfgValue::Unknown(unknown.pop().unwrap()),
+                    _ => CfgValue::Unknown(Cfg::Any(unknown)),
+                }
+            }
+            Cfg::All(children) => {
+                let mut unknown = Vec::new();
+                for child in children {
+                    match self.resolve(child, crate_name) {
+                        CfgValue::Known(false) => return CfgValue::Known(false),
+                        CfgValue::Known(true) => {}
+                        CfgValue::Unknown(child) => unknown.push(child),
+                    }
+                }
+                match unknown.len() {
+                    0 => CfgValue::Known(true),
+                    1 => CfgValue::Unknown(unknown.pop().unwrap()),
+                    _ => CfgValue::Unknown(Cfg::All(unknown)),
+                }
+            }
+            Cfg::Not(child) => match self.resolve(*child, crate_name) {
+                CfgValue::Known(value) => CfgValue::Known(!value),
+                CfgValue::Unknown(child) => CfgValue::Unknown(Cfg::Not(Box::new(child))),
+            },
+        }
+    }
+}
+
 pub trait ToCondition: Sized {
     type Output;
 
@@ -271,6 +543,20 @@ impl ToCondition for Cfg {
                 });
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
+                } else if let (Some(pattern), "feature") =
+                    (config.feature_define.as_ref(), cfg_name.as_str())
+                {
+                    let feature: String = cfg_value
+                        .chars()
+                        .map(|c| {
+                            if c.is_ascii_alphanumeric() {
+                                c.to_ascii_uppercase()
+                            } else {
+                                '_'
+                            }
+                        })
+                        .collect();
+                    Some(Condition::Define(pattern.replace("{}", &feature)))
                 } else {
                     warn!(
                         "Missing `[defines]` entry for `{}` in cbindgen config.",
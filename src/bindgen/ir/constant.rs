@@ -7,13 +7,15 @@ use std::fmt;
 use std::io::Write;
 use std::mem;
 
+use serde::de::{Deserialize, Deserializer, Error as DeError};
 use syn;
 
-use bindgen::config::{Config, Language};
+use bindgen::config::{Config, ConstantStyle, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
+    cast_integer, integer_widths, AnnotationSet, Cfg, ConditionWrite, ConstEvaluator,
+    Documentation, GenericParams, Item, ItemContainer, ItemMap, Path, PrimitiveType, StrongTypedef,
     Struct, ToCondition, Type,
 };
 use bindgen::library::Library;
@@ -21,12 +23,21 @@ use bindgen::writer::{Source, SourceWriter};
 use bindgen::Bindings;
 use syn::UnOp;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Literal {
     Expr(String),
+    /// A reference to another constant.
+    Path(String),
+    /// A cast of an expression referring to other constants, which is
+    /// evaluated when generating the bindings.
+    Cast {
+        ty: PrimitiveType,
+        value: Box<Literal>,
+    },
     BinOp {
         left: Box<Literal>,
-        op: &'static str,
+        #[serde(deserialize_with = "deserialize_bin_op")]
+        op: BinOp,
         right: Box<Literal>,
     },
     Struct {
@@ -36,10 +47,26 @@ pub enum Literal {
     },
 }
 
+/// A binary operator of a constant expression, one of `BIN_OPS`. It isn't
+/// spelled `&'static str` in `Literal`, which serde would try to borrow.
+pub type BinOp = &'static str;
+
+/// The binary operators that constant expressions support.
+const BIN_OPS: &'static [&'static str] = &["+", "-", "*", "/", "%", "<<", ">>", "&", "|", "^"];
+
+fn deserialize_bin_op<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BinOp, D::Error> {
+    let op = String::deserialize(deserializer)?;
+    match BIN_OPS.iter().find(|x| **x == op) {
+        Some(op) => Ok(op),
+        None => Err(D::Error::custom(format!("Unsupported binary op {}", op))),
+    }
+}
+
 impl Literal {
     fn replace_self_with(&mut self, self_ty: &Path) {
         match *self {
-            Literal::BinOp { .. } | Literal::Expr(..) => {}
+            Literal::Cast { ref mut value, .. } => value.replace_self_with(self_ty),
+            Literal::BinOp { .. } | Literal::Expr(..) | Literal::Path(..) => {}
             Literal::Struct {
                 ref mut path,
                 ref mut export_name,
@@ -55,9 +82,39 @@ impl Literal {
         }
     }
 
-    fn is_valid(&self, bindings: &Bindings) -> bool {
+    /// Whether the literal uses the `NAN` or `INFINITY` macros of `<math.h>`.
+    pub fn uses_math(&self) -> bool {
+        match *self {
+            Literal::Expr(ref v) => v == "NAN" || v.ends_with("INFINITY"),
+            Literal::Path(..) => false,
+            Literal::Cast { ref value, .. } => value.uses_math(),
+            Literal::BinOp {
+                ref left,
+                ref right,
+                ..
+            } => left.uses_math() || right.uses_math(),
+            Literal::Struct { ref fields, .. } => {
+                fields.iter().any(|&(_, ref lit)| lit.uses_math())
+            }
+        }
+    }
+
+    /// Whether the literal is a string.
+    pub fn is_string(&self) -> bool {
+        match *self {
+            Literal::Expr(ref v) => string_literal_contents(v).is_some(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_valid(&self, bindings: &Bindings) -> bool {
         match *self {
             Literal::Expr(..) => true,
+            Literal::Path(ref name) => bindings
+                .constants
+                .iter()
+                .any(|c| c.associated_to.is_none() && c.export_name() == name),
+            Literal::Cast { ref value, .. } => value.is_valid(bindings),
             Literal::BinOp {
                 ref left,
                 ref right,
@@ -68,15 +125,139 @@ impl Literal {
     }
 }
 
+/// Returns the `<math.h>` macro of a path like `f32::NAN` or
+/// `std::f64::INFINITY`.
+fn non_finite_float(path: &syn::Path) -> Option<&'static str> {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let (ty, name) = match segments.len() {
+        2 => (&segments[0], &segments[1]),
+        3 if segments[0] == "std" || segments[0] == "core" => (&segments[1], &segments[2]),
+        _ => return None,
+    };
+    if ty != "f32" && ty != "f64" {
+        return None;
+    }
+    match name.as_str() {
+        "NAN" => Some("NAN"),
+        "INFINITY" => Some("INFINITY"),
+        "NEG_INFINITY" => Some("-INFINITY"),
+        _ => None,
+    }
+}
+
+/// Returns the unescaped contents of a string literal expression, which is
+/// stored as `u8"..."`.
+fn string_literal_contents(v: &str) -> Option<&str> {
+    if v.len() >= 4 && v.starts_with("u8\"") && v.ends_with('"') {
+        Some(&v[3..v.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// Writes a C string literal, with the bytes that aren't printable ASCII as
+/// octal escapes, which unlike hex escapes can't run into the next character.
+fn c_string_literal(contents: &str) -> String {
+    let mut result = String::from("\"");
+    for byte in contents.bytes() {
+        match byte {
+            b'"' | b'\\' => {
+                result.push('\\');
+                result.push(byte as char);
+            }
+            b'\n' => result.push_str("\\n"),
+            b'\t' => result.push_str("\\t"),
+            b'\r' => result.push_str("\\r"),
+            0x20..=0x7e => result.push(byte as char),
+            _ => result.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Writes a character literal, stored as the `char::escape_default` output
+/// in quotes up to `'\u{ff}'`, and as `L'\uXXXX'` after. Escapes that C
+/// doesn't have are written as octal for ASCII, and as wide characters
+/// otherwise.
+fn c_char_literal(v: &str) -> Option<String> {
+    let code = char_code(v)?;
+    Some(match code {
+        0..=0x7f => format!("'\\{:03o}'", code),
+        0x80..=0xff => format!("L'\\x{:X}'", code),
+        0x100..=0xffff => format!("L'\\u{:04X}'", code),
+        _ => format!("L'\\U{:08X}'", code),
+    })
+}
+
+/// Returns the code point of a character literal written with an escape.
+fn char_code(v: &str) -> Option<u32> {
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        u32::from_str_radix(&v[4..v.len() - 1], 16).ok()
+    } else if v.starts_with("'\\u{") && v.ends_with("}'") {
+        u32::from_str_radix(&v[4..v.len() - 2], 16).ok()
+    } else {
+        None
+    }
+}
+
+/// Returns `value` rounded toward zero and saturated to an integer of `bits`
+/// bits, with NaN as zero.
+fn saturate_float(value: f64, bits: u32, signed: bool) -> Option<i128> {
+    if bits == 128 {
+        return None;
+    }
+    let (min, max): (i128, i128) = if signed {
+        (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    } else {
+        (0, (1 << bits) - 1)
+    };
+    let value = value.trunc();
+    Some(if value.is_nan() {
+        0
+    } else if value <= min as f64 {
+        min
+    } else if value >= max as f64 {
+        max
+    } else {
+        value as i128
+    })
+}
+
+/// Writes an expression literal in C.
+fn c_expr<'a>(v: &'a str) -> Cow<'a, str> {
+    if let Some(contents) = string_literal_contents(v) {
+        return Cow::Owned(c_string_literal(contents));
+    }
+    if let Some(c) = c_char_literal(v) {
+        return Cow::Owned(c);
+    }
+    Cow::Borrowed(v)
+}
+
 impl fmt::Display for Literal {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Literal::Expr(v) => write!(f, "{}", v),
+            Literal::Expr(v) => write!(f, "{}", c_expr(v)),
+            Literal::Path(v) => write!(f, "{}", v),
+            Literal::Cast { ty, value } => {
+                write!(f, "({}){}", ty.to_repr_c(), value.parenthesized())
+            }
             Literal::BinOp {
                 ref left,
                 op,
                 ref right,
-            } => write!(f, "{} {} {}", left, op, right),
+            } => write!(
+                f,
+                "{} {} {}",
+                left.parenthesized(),
+                op,
+                right.parenthesized()
+            ),
             Literal::Struct {
                 path: _,
                 export_name,
@@ -116,10 +297,127 @@ impl Literal {
                 left.rename_for_config(config);
                 right.rename_for_config(config);
             }
+            Literal::Cast { ref mut value, .. } => value.rename_for_config(config),
+            Literal::Path(ref mut name) => config.export.rename_constant(name),
             Literal::Expr(_) => {}
         }
     }
 
+    /// Returns the literal as an operand of a binary operator, which has to
+    /// be parenthesized if it's an operation itself.
+    pub fn parenthesized(&self) -> String {
+        match *self {
+            Literal::BinOp { .. } => format!("({})", self),
+            Literal::Expr(ref v) if v.starts_with("-(") => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Whether the literal has casts that are evaluated later.
+    fn has_casts(&self) -> bool {
+        match *self {
+            Literal::Expr(..) | Literal::Path(..) => false,
+            Literal::Cast { .. } => true,
+            Literal::BinOp {
+                ref left,
+                ref right,
+                ..
+            } => left.has_casts() || right.has_casts(),
+            Literal::Struct { ref fields, .. } => {
+                fields.iter().any(|&(_, ref lit)| lit.has_casts())
+            }
+        }
+    }
+
+    /// Returns the literal negated, parenthesizing it unless it's a single
+    /// number or name.
+    fn negated(self) -> Result<Literal, String> {
+        Ok(match self {
+            Literal::Expr(ref v) if v.starts_with('-') && v[1..].parse::<f64>().is_ok() => {
+                Literal::Expr(v[1..].to_owned())
+            }
+            Literal::Expr(ref v) if v.starts_with('-') => Literal::Expr(format!("-({})", v)),
+            Literal::Expr(v) | Literal::Path(v) => Literal::Expr(format!("-{}", v)),
+            ref other if other.has_casts() => {
+                return Err(format!("Unsupported negation of {}", other));
+            }
+            other => {
+                // C would evaluate the operation in `int`.
+                let constants = ConstEvaluator::new(&ItemMap::new());
+                match constants.eval(&other).ok().and_then(|x| x.checked_neg()) {
+                    Some(value) if !constants.fits_int(&other) => Literal::Expr(value.to_string()),
+                    _ => Literal::Expr(format!("-({})", other)),
+                }
+            }
+        })
+    }
+
+    /// Returns the cast of the literal to the primitive type `ty`. Casts of
+    /// numbers are evaluated like Rust does, truncating, converting the sign
+    /// of or saturating them, while integer casts of other constants are
+    /// evaluated once the constants are known. Casts of addresses to
+    /// pointers keep them.
+    fn cast(self, ty: &syn::Type) -> Result<Literal, String> {
+        let unsupported = || format!("Unsupported cast of {} to {}", self, quote! { #ty });
+        let ty = match Type::load(ty)? {
+            Some(Type::Primitive(ty)) => ty,
+            Some(Type::ConstPtr(..)) | Some(Type::Ptr(..)) => {
+                return match ConstEvaluator::new(&ItemMap::new()).eval(&self) {
+                    Ok(_) => Ok(self),
+                    Err(_) => Err(unsupported()),
+                };
+            }
+            _ => return Err(unsupported()),
+        };
+        let float = match self {
+            Literal::Expr(ref v) if v.parse::<i128>().is_err() => v.parse::<f64>().ok(),
+            _ => None,
+        };
+        let result = match ty {
+            PrimitiveType::Float | PrimitiveType::Double => {
+                let value = match float {
+                    Some(float) => float,
+                    None => match ConstEvaluator::new(&ItemMap::new()).eval(&self) {
+                        Ok(value) => value as f64,
+                        Err(_) => return Err(unsupported()),
+                    },
+                };
+                if ty == PrimitiveType::Float {
+                    format!("{:?}", value as f32)
+                } else {
+                    format!("{:?}", value)
+                }
+            }
+            _ => match (float, integer_widths(&ty)) {
+                (_, None) => return Err(unsupported()),
+                (Some(float), Some((widths, signed))) => {
+                    let result = saturate_float(float, widths[0], signed);
+                    // Platform-dependent types must get the same value on
+                    // every target.
+                    if widths
+                        .iter()
+                        .any(|&bits| saturate_float(float, bits, signed) != result)
+                    {
+                        return Err(unsupported());
+                    }
+                    result.ok_or_else(unsupported)?.to_string()
+                }
+                (None, Some(_)) => match ConstEvaluator::new(&ItemMap::new()).eval(&self) {
+                    Ok(value) => cast_integer(value, &ty)
+                        .map_err(|_| unsupported())?
+                        .to_string(),
+                    Err(_) => {
+                        return Ok(Literal::Cast {
+                            ty,
+                            value: Box::new(self),
+                        })
+                    }
+                },
+            },
+        };
+        Ok(Literal::Expr(result))
+    }
+
     pub fn load(expr: &syn::Expr) -> Result<Literal, String> {
         match *expr {
             syn::Expr::Binary(ref bin_expr) => {
@@ -133,6 +431,9 @@ impl Literal {
                     syn::BinOp::Rem(..) => "%",
                     syn::BinOp::Shl(..) => "<<",
                     syn::BinOp::Shr(..) => ">>",
+                    syn::BinOp::BitAnd(..) => "&",
+                    syn::BinOp::BitOr(..) => "|",
+                    syn::BinOp::BitXor(..) => "^",
                     _ => return Err(format!("Unsupported binary op {:?}", bin_expr.op)),
                 };
                 Ok(Literal::BinOp {
@@ -182,7 +483,7 @@ impl Literal {
             syn::Expr::Lit(syn::ExprLit {
                 lit: syn::Lit::Float(ref value),
                 ..
-            }) => Ok(Literal::Expr(format!("{}", value.value()))),
+            }) => Ok(Literal::Expr(format!("{:?}", value.value()))),
             syn::Expr::Lit(syn::ExprLit {
                 lit: syn::Lit::Bool(ref value),
                 ..
@@ -215,18 +516,38 @@ impl Literal {
                 ref op,
                 ref expr,
             }) => match *op {
-                UnOp::Neg(_) => {
-                    let val = Self::load(expr)?;
-                    Ok(Literal::Expr(format!("-{}", val)))
-                }
+                UnOp::Neg(_) => Self::load(expr)?.negated(),
                 _ => Err(format!("Unsupported Unary expression. {:?}", *op)),
             },
+            syn::Expr::Path(syn::ExprPath {
+                qself: None,
+                ref path,
+                ..
+            }) if path.leading_colon.is_none() && path.segments.len() == 1 => {
+                let segment = &path.segments[0];
+                if !segment.arguments.is_empty() {
+                    return Err("Unsupported generic constant reference.".to_owned());
+                }
+                Ok(Literal::Path(segment.ident.to_string()))
+            }
+            syn::Expr::Path(syn::ExprPath {
+                qself: None,
+                ref path,
+                ..
+            }) if non_finite_float(path).is_some() => {
+                Ok(Literal::Expr(non_finite_float(path).unwrap().to_owned()))
+            }
+            // Parentheses are written back where they're needed.
+            syn::Expr::Paren(syn::ExprParen { ref expr, .. }) => Self::load(expr),
+            syn::Expr::Cast(syn::ExprCast {
+                ref expr, ref ty, ..
+            }) => Self::load(expr)?.cast(ty),
             _ => Err(format!("Unsupported literal expression. {:?}", *expr)),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Constant {
     pub path: Path,
     pub export_name: String,
@@ -265,6 +586,17 @@ impl Constant {
             }
         };
 
+        // `&str` constants are written as C strings.
+        if ty.is_str_ref() {
+            match *expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(..),
+                    ..
+                }) => ty = Type::ConstPtr(Box::new(Type::Primitive(PrimitiveType::Char)), false),
+                _ => return Err("Only string literals can be `&str` constants".to_owned()),
+            }
+        }
+
         if !can_handle(&ty, expr) {
             return Err("Unhandled const definition".to_owned());
         }
@@ -327,6 +659,10 @@ impl Item for Constant {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -341,7 +677,7 @@ impl Item for Constant {
 
     fn rename_for_config(&mut self, config: &Config) {
         if self.associated_to.is_none() {
-            config.export.rename(&mut self.export_name);
+            config.export.rename_constant(&mut self.export_name);
         }
         self.value.rename_for_config(config);
         self.ty.rename_for_config(config, &GenericParams::default()); // FIXME: should probably propagate something here
@@ -367,13 +703,41 @@ impl Constant {
 
         if let Type::ConstPtr(..) = self.ty {
             out.write("static ");
+            self.ty.write(config, out);
+            out.write(" const");
         } else {
             out.write("static const ");
+            self.ty.write(config, out);
         }
-        self.ty.write(config, out);
         write!(out, " {};", self.export_name())
     }
 
+    /// The name of the constant outside of the body of the type it's
+    /// associated to, if any, like `Foo_BAR`.
+    pub fn global_name<'a>(
+        &'a self,
+        config: &Config,
+        associated_to_struct: Option<&Struct>,
+    ) -> Cow<'a, str> {
+        let path = match self.associated_to {
+            Some(ref path) => path,
+            None => return Cow::Borrowed(self.export_name()),
+        };
+        let associated_name = match associated_to_struct {
+            Some(s) => s.export_name().to_owned(),
+            None => {
+                let mut name = path.name().to_owned();
+                config.export.rename(&mut name);
+                name
+            }
+        };
+        Cow::Owned(
+            config
+                .constant
+                .associated_name(&associated_name, self.export_name()),
+        )
+    }
+
     pub fn write<F: Write>(
         &self,
         config: &Config,
@@ -407,19 +771,8 @@ impl Constant {
                 associated_to_struct.unwrap().export_name(),
                 self.export_name(),
             ))
-        } else if self.associated_to.is_none() {
-            Cow::Borrowed(self.export_name())
         } else {
-            let associated_name = match associated_to_struct {
-                Some(s) => Cow::Borrowed(s.export_name()),
-                None => {
-                    let mut name = self.associated_to.as_ref().unwrap().name().to_owned();
-                    config.export.rename(&mut name);
-                    Cow::Owned(name)
-                }
-            };
-
-            Cow::Owned(format!("{}_{}", associated_name, self.export_name()))
+            self.global_name(config, associated_to_struct)
         };
 
         let value = match self.value {
@@ -430,18 +783,133 @@ impl Constant {
             } if out.bindings().struct_is_transparent(path) => &fields[0].1,
             _ => &self.value,
         };
+        // Without a suffix, a float literal is a `double`, and an integer
+        // that doesn't fit in an `int` gets the first type it fits in, or is
+        // unsigned with a warning if it doesn't fit in a `long long`. The
+        // smallest `long long` is the negation of a literal that doesn't fit.
+        let suffixed;
+        let value = match (value, &self.ty) {
+            (&Literal::Expr(ref v), &Type::Primitive(PrimitiveType::Float))
+                if v.parse::<f64>().is_ok() && v.contains(|c| c == '.' || c == 'e') =>
+            {
+                suffixed = Literal::Expr(format!("{}f", v));
+                &suffixed
+            }
+            (&Literal::Expr(ref v), &Type::Primitive(_))
+                if v.parse::<i64>() == Ok(i64::min_value()) =>
+            {
+                suffixed = Literal::Expr(format!("({}LL - 1)", i64::min_value() + 1));
+                &suffixed
+            }
+            (&Literal::Expr(ref v), &Type::Primitive(ref ty)) => {
+                match (v.parse::<i128>(), integer_widths(ty)) {
+                    (Ok(n), Some((_, signed)))
+                        if n < i128::from(i32::min_value()) || n > i128::from(i32::max_value()) =>
+                    {
+                        let suffix = match (signed, n) {
+                            (true, _) => "LL",
+                            (false, n) if n <= i128::from(u32::max_value()) => "U",
+                            (false, _) => "ULL",
+                        };
+                        suffixed = Literal::Expr(format!("{}{}", v, suffix));
+                        &suffixed
+                    }
+                    _ => value,
+                }
+            }
+            _ => value,
+        };
+        // `wchar_t` is only 16 bits on Windows, so the characters after ASCII
+        // are `char32_t` literals, or code points before C11 and C++11, unless
+        // `types.char` maps `char` to another type.
+        let mut char_ty = None;
+        let char32;
+        let value = match (value, &self.ty) {
+            (&Literal::Expr(ref v), &Type::Primitive(PrimitiveType::WChar)) => match char_code(v) {
+                Some(code) if code > 0x7f => {
+                    let version = match config.language {
+                        Language::Cxx => LanguageVersion::Cxx11,
+                        _ => LanguageVersion::C11,
+                    };
+                    let unicode = config.has_version(version);
+                    if config.types.rust_char.is_none() {
+                        char_ty = Some(match config.language {
+                            Language::Cxx if unicode => "char32_t",
+                            _ => "uint32_t",
+                        });
+                    }
+                    char32 = match code {
+                        _ if !unicode => Literal::Expr(format!("0x{:X}", code)),
+                        0..=0xffff => Literal::Expr(format!("U'\\u{:04X}'", code)),
+                        _ => Literal::Expr(format!("U'\\U{:08X}'", code)),
+                    };
+                    &char32
+                }
+                _ => value,
+            },
+            _ => value,
+        };
+        // Strong typedefs don't convert implicitly from the values they wrap.
+        let wrapped;
+        let value = match self.ty {
+            Type::Path(ref generic_path) if config.language == Language::Cxx => {
+                match out.bindings().strong_typedef(generic_path.path()) {
+                    Some(StrongTypedef::EnumClass) => {
+                        wrapped = Literal::Expr(format!(
+                            "static_cast<{}>({})",
+                            generic_path.export_name(),
+                            value
+                        ));
+                        &wrapped
+                    }
+                    Some(StrongTypedef::Struct) => {
+                        wrapped =
+                            Literal::Expr(format!("{}{{ {} }}", generic_path.export_name(), value));
+                        &wrapped
+                    }
+                    None => value,
+                }
+            }
+            _ => value,
+        };
+
+        let static_const = match config.constant.associated_style {
+            Some(style) if self.associated_to.is_some() && !in_body => {
+                style == ConstantStyle::StaticConst
+            }
+            _ => config.constant.allow_static_const && config.language == Language::Cxx,
+        };
 
-        if config.constant.allow_static_const && config.language == Language::Cxx {
-            out.write(if in_body { "inline " } else { "static " });
+        if static_const {
+            if let Some(prefix) = config.constant.prefix(&self.annotations) {
+                write!(out, "{} ", prefix);
+            }
+            // Exported variables can't have internal linkage.
+            if in_body || out.bindings().module_interface {
+                out.write("inline ");
+            } else {
+                out.write("static ");
+            }
             if let Type::ConstPtr(..) = self.ty {
-                // Nothing.
+                // The pointer itself is constant too.
+                self.ty.write(config, out);
+                out.write(" const");
             } else {
                 out.write("const ");
+                match char_ty {
+                    Some(ty) => out.write(ty),
+                    None => self.ty.write(config, out),
+                }
             }
-            self.ty.write(config, out);
-            write!(out, " {} = {};", name, value)
+            write!(out, " {}", name);
+            if let Some(postfix) = config.constant.postfix(&self.annotations) {
+                write!(out, " {}", postfix);
+            }
+            write!(out, " = {};", value)
         } else {
-            write!(out, "#define {} {}", name, value)
+            // Operations are parenthesized, so the macro expands to a single
+            // operand.
+            write!(out, "#define {} {}", name, value.parenthesized())
         }
         condition.write_after(config, out);
     }
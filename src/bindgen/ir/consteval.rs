@@ -0,0 +1,378 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use bindgen::ir::{Constant, Item, ItemMap, Literal, PrimitiveType};
+
+/// The deepest chain of constants an expression can refer through, which
+/// stops cycles between constants.
+const MAX_DEPTH: usize = 64;
+
+/// Evaluates integer constant expressions, such as array lengths, which can
+/// refer to the constants of the crate.
+pub struct ConstEvaluator {
+    /// The value of each constant, or `None` when it has several `#[cfg]`ed
+    /// definitions that don't agree.
+    constants: HashMap<String, Option<Literal>>,
+}
+
+impl ConstEvaluator {
+    pub fn new(constants: &ItemMap<Constant>) -> Self {
+        let mut values = HashMap::new();
+        constants.for_all_items(|constant| {
+            let value = values
+                .entry(constant.path().name().to_owned())
+                .or_insert_with(|| Some(constant.value.clone()));
+            if value.as_ref() != Some(&constant.value) {
+                *value = None;
+            }
+        });
+        ConstEvaluator { constants: values }
+    }
+
+    pub fn eval(&self, value: &Literal) -> Result<i128, String> {
+        self.eval_at(value, 0)
+    }
+
+    fn eval_at(&self, value: &Literal, depth: usize) -> Result<i128, String> {
+        match *value {
+            Literal::Expr(ref v) => match v.as_ref() {
+                "true" => Ok(1),
+                "false" => Ok(0),
+                // A negated constant.
+                _ if v.starts_with('-') && is_identifier(&v[1..]) => {
+                    Ok(-self.lookup(&v[1..], depth)?)
+                }
+                _ => v
+                    .parse()
+                    .map_err(|_| format!("{} isn't an integer constant.", v)),
+            },
+            Literal::Path(ref name) => self.lookup(name, depth),
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let left = self.eval_at(left, depth)?;
+                let right = self.eval_at(right, depth)?;
+                let result = match op {
+                    "+" => left.checked_add(right),
+                    "-" => left.checked_sub(right),
+                    "*" => left.checked_mul(right),
+                    "/" => left.checked_div(right),
+                    "%" => left.checked_rem(right),
+                    "<<" => shift_amount(right).and_then(|right| left.checked_shl(right)),
+                    ">>" => shift_amount(right).and_then(|right| left.checked_shr(right)),
+                    "&" => Some(left & right),
+                    "|" => Some(left | right),
+                    "^" => Some(left ^ right),
+                    _ => None,
+                };
+                result.ok_or_else(|| format!("Cannot evaluate {} {} {}.", left, op, right))
+            }
+            Literal::Cast { ref ty, ref value } => cast_integer(self.eval_at(value, depth)?, ty),
+            Literal::Struct { .. } => Err("Cannot evaluate a struct constant.".to_owned()),
+        }
+    }
+
+    /// Whether C evaluates `value` like Rust does, which it does when every
+    /// operation stays in the range of `int`, the type of integer literals
+    /// without a suffix. Values that can't be evaluated are assumed to.
+    pub fn fits_int(&self, value: &Literal) -> bool {
+        let in_range = |value: &Literal| match self.eval(value) {
+            Ok(v) => v >= i128::from(i32::min_value()) && v <= i128::from(i32::max_value()),
+            Err(_) => true,
+        };
+        match *value {
+            Literal::BinOp {
+                ref left,
+                ref right,
+                ..
+            } => self.fits_int(left) && self.fits_int(right) && in_range(value),
+            Literal::Cast { ref value, .. } => self.fits_int(value),
+            _ => in_range(value),
+        }
+    }
+
+    fn lookup(&self, name: &str, depth: usize) -> Result<i128, String> {
+        if depth == MAX_DEPTH {
+            return Err(format!("Constant {} refers to itself.", name));
+        }
+        match self.constants.get(name) {
+            Some(&Some(ref value)) => self.eval_at(value, depth + 1),
+            Some(&None) => Err(format!("Constant {} has conflicting definitions.", name)),
+            None => Err(format!("Cannot find constant {}.", name)),
+        }
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn shift_amount(value: i128) -> Option<u32> {
+    if value < 0 || value >= 128 {
+        return None;
+    }
+    Some(value as u32)
+}
+
+/// The widths the integer type `ty` has on the supported targets, and whether
+/// it's signed.
+pub fn integer_widths(ty: &PrimitiveType) -> Option<(&'static [u32], bool)> {
+    match *ty {
+        PrimitiveType::Int8 | PrimitiveType::SChar => Some((&[8], true)),
+        PrimitiveType::UInt8 | PrimitiveType::UChar => Some((&[8], false)),
+        PrimitiveType::Int16 | PrimitiveType::Short => Some((&[16], true)),
+        PrimitiveType::UInt16 | PrimitiveType::UShort => Some((&[16], false)),
+        PrimitiveType::Int32 | PrimitiveType::Int => Some((&[32], true)),
+        PrimitiveType::UInt32 | PrimitiveType::UInt => Some((&[32], false)),
+        PrimitiveType::Int64 | PrimitiveType::LongLong => Some((&[64], true)),
+        PrimitiveType::UInt64 | PrimitiveType::ULongLong => Some((&[64], false)),
+        PrimitiveType::Int128 => Some((&[128], true)),
+        PrimitiveType::UInt128 => Some((&[128], false)),
+        PrimitiveType::ISize
+        | PrimitiveType::Long
+        | PrimitiveType::SSizeT
+        | PrimitiveType::PtrDiffT => Some((&[32, 64], true)),
+        PrimitiveType::USize | PrimitiveType::ULong | PrimitiveType::SizeT => {
+            Some((&[32, 64], false))
+        }
+        _ => None,
+    }
+}
+
+/// Casts `value` to the integer type `ty` like `as` does, truncating it or
+/// converting its sign. Casts to platform-dependent types have to give the
+/// same value on every target.
+pub fn cast_integer(value: i128, ty: &PrimitiveType) -> Result<i128, String> {
+    let wrap = |bits: u32, signed: bool| {
+        if bits == 128 {
+            return if signed || value >= 0 {
+                Some(value)
+            } else {
+                None
+            };
+        }
+        let unsigned = value & ((1 << bits) - 1);
+        if signed && unsigned >> (bits - 1) == 1 {
+            Some(unsigned - (1 << bits))
+        } else {
+            Some(unsigned)
+        }
+    };
+    let error = || format!("Cannot cast {} to {}.", value, ty.to_repr_rust());
+    let (widths, signed) = integer_widths(ty).ok_or_else(error)?;
+    let result = wrap(widths[0], signed).ok_or_else(error)?;
+    if widths
+        .iter()
+        .any(|&bits| wrap(bits, signed) != Some(result))
+    {
+        return Err(error());
+    }
+    Ok(result)
+}
+
+/// Orders `constants` so that the constants each one refers to are defined
+/// before it, as languages other than C need them to be.
+pub fn sort_by_references(constants: Vec<Constant>) -> Vec<Constant> {
+    fn visit(
+        index: usize,
+        constants: &[Constant],
+        visited: &mut HashSet<usize>,
+        order: &mut Vec<usize>,
+    ) {
+        if !visited.insert(index) {
+            return;
+        }
+        let mut references = Vec::new();
+        constants[index].value.add_references(&mut references);
+        for name in references {
+            for (i, constant) in constants.iter().enumerate() {
+                if constant.associated_to.is_none() && constant.export_name() == name {
+                    visit(i, constants, visited, order);
+                }
+            }
+        }
+        order.push(index);
+    }
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for index in 0..constants.len() {
+        visit(index, &constants, &mut visited, &mut order);
+    }
+
+    let mut constants: Vec<_> = constants.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .map(|index| constants[index].take().unwrap())
+        .collect()
+}
+
+impl Literal {
+    /// Returns the literal with its casts of other constants evaluated by
+    /// `constants`. Casts that keep the value are left out instead, so that
+    /// the constants are still referred to.
+    pub fn evaluate_casts(&self, constants: &ConstEvaluator) -> Result<Literal, String> {
+        Ok(match *self {
+            Literal::Expr(..) | Literal::Path(..) => self.clone(),
+            Literal::Cast { ref ty, ref value } => {
+                let uncast = constants.eval(value)?;
+                match cast_integer(uncast, ty)? {
+                    result if result == uncast => value.evaluate_casts(constants)?,
+                    result => Literal::Expr(result.to_string()),
+                }
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => Literal::BinOp {
+                left: Box::new(left.evaluate_casts(constants)?),
+                op,
+                right: Box::new(right.evaluate_casts(constants)?),
+            },
+            Literal::Struct {
+                ref path,
+                ref export_name,
+                ref fields,
+            } => Literal::Struct {
+                path: path.clone(),
+                export_name: export_name.clone(),
+                fields: fields
+                    .iter()
+                    .map(|&(ref name, ref value)| {
+                        Ok((name.clone(), value.evaluate_casts(constants)?))
+                    })
+                    .collect::<Result<_, String>>()?,
+            },
+        })
+    }
+
+    /// Adds the names of the constants this literal refers to.
+    fn add_references<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match *self {
+            // Negated constants and operations are written out already.
+            Literal::Expr(ref v) if v.starts_with('-') => out.extend(
+                v.split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .filter(|x| is_identifier(x)),
+            ),
+            Literal::Expr(..) => {}
+            Literal::Path(ref name) => out.push(name),
+            Literal::Cast { ref value, .. } => value.add_references(out),
+            Literal::BinOp {
+                ref left,
+                ref right,
+                ..
+            } => {
+                left.add_references(out);
+                right.add_references(out);
+            }
+            Literal::Struct { ref fields, .. } => {
+                for &(_, ref value) in fields {
+                    value.add_references(out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn;
+
+    use bindgen::ir::{AnnotationSet, Documentation, Path, PrimitiveType, Type};
+
+    fn constant(name: &str, value: &str) -> Constant {
+        let expr: syn::Expr = syn::parse_str(value).unwrap();
+        Constant::new(
+            Path::new(name),
+            Type::Primitive(PrimitiveType::USize),
+            Literal::load(&expr).unwrap(),
+            None,
+            AnnotationSet::new(),
+            Documentation::none(),
+            None,
+        )
+    }
+
+    fn eval(constants: &[(&str, &str)], expr: &str) -> Result<i128, String> {
+        let mut map = ItemMap::new();
+        for &(name, value) in constants {
+            map.try_insert(constant(name, value));
+        }
+        let expr: syn::Expr = syn::parse_str(expr).unwrap();
+        ConstEvaluator::new(&map).eval(&Literal::load(&expr).unwrap())
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval(&[], "(1 + 2) * 3 - 4 / 3 % 2"), Ok(8));
+        assert_eq!(eval(&[], "1 << 4 | 1 << 2 & 0x7 ^ 1"), Ok(21));
+        assert_eq!(eval(&[], "-3 + 4 as usize"), Ok(1));
+        assert!(eval(&[], "1 / 0").is_err());
+        assert!(eval(&[], "1 << 200").is_err());
+    }
+
+    #[test]
+    fn references() {
+        let constants = [
+            ("HEADER_LEN", "8"),
+            ("FLAG_A", "1 << 0"),
+            ("FLAG_B", "1 << 1"),
+            ("FLAGS", "FLAG_A | FLAG_B"),
+            ("LOOP", "LOOP + 1"),
+        ];
+        assert_eq!(eval(&constants, "HEADER_LEN + 4"), Ok(12));
+        assert_eq!(eval(&constants, "FLAGS * (HEADER_LEN - 6)"), Ok(6));
+        assert!(eval(&constants, "LOOP").is_err());
+        assert!(eval(&constants, "MISSING").is_err());
+    }
+
+    #[test]
+    fn casts() {
+        let constants = [("LEN", "300"), ("MINUS_ONE", "-1")];
+        assert_eq!(eval(&constants, "LEN as u8"), Ok(44));
+        assert_eq!(eval(&constants, "LEN as u8 as usize"), Ok(44));
+        assert_eq!(eval(&constants, "MINUS_ONE as u32"), Ok(4294967295));
+        assert_eq!(eval(&constants, "(LEN + 128) as i8"), Ok(-84));
+        assert_eq!(eval(&constants, "MINUS_ONE as isize"), Ok(-1));
+        assert!(eval(&constants, "MINUS_ONE as usize").is_err());
+        assert!(eval(&constants, "MINUS_ONE as u128").is_err());
+    }
+
+    #[test]
+    fn fits_int() {
+        let mut map = ItemMap::new();
+        map.try_insert(constant("BIG", "1 << 40"));
+        let constants = ConstEvaluator::new(&map);
+        let fits =
+            |expr| constants.fits_int(&Literal::load(&syn::parse_str(expr).unwrap()).unwrap());
+        assert!(fits("1 << 30 | 1"));
+        assert!(!fits("1 << 40"));
+        assert!(!fits("(1 << 40) >> 20"));
+        assert!(!fits("BIG >> 20"));
+        assert!(fits("UNKNOWN << 40"));
+    }
+
+    #[test]
+    fn order() {
+        let constants = vec![
+            constant("A", "B + C"),
+            constant("B", "C"),
+            constant("C", "-(D + 1)"),
+            constant("D", "1"),
+        ];
+        let names: Vec<_> = sort_by_references(constants)
+            .iter()
+            .map(|c| c.export_name().to_owned())
+            .collect();
+        assert_eq!(names, ["D", "C", "B", "A"]);
+    }
+}
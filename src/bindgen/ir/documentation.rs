@@ -7,10 +7,11 @@ use std::io::Write;
 use syn;
 
 use bindgen::config::{Config, DocumentationStyle, Language};
+use bindgen::doccomment;
 use bindgen::utilities::SynAttributeHelpers;
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Documentation {
     pub doc_comment: Vec<String>,
 }
@@ -55,18 +56,28 @@ impl Source for Documentation {
                 out.new_line();
             }
 
-            DocumentationStyle::Doxy => {
+            DocumentationStyle::Doxy
+            | DocumentationStyle::Doxygen
+            | DocumentationStyle::Javadoc => {
                 out.write("/**");
                 out.new_line();
             }
 
+            DocumentationStyle::Qt => {
+                out.write("/*!");
+                out.new_line();
+            }
+
             _ => (),
         }
 
-        for line in &self.doc_comment {
+        for line in &doccomment::rewrite(&self.doc_comment, style) {
             match style {
                 DocumentationStyle::C => out.write(""),
-                DocumentationStyle::Doxy => out.write(" *"),
+                DocumentationStyle::Doxy
+                | DocumentationStyle::Doxygen
+                | DocumentationStyle::Javadoc
+                | DocumentationStyle::Qt => out.write(" *"),
                 DocumentationStyle::C99 => out.write("//"),
                 DocumentationStyle::Cxx => out.write("///"),
                 DocumentationStyle::Auto => unreachable!(), // Auto case should always be covered
@@ -80,12 +91,11 @@ impl Source for Documentation {
         }
 
         match style {
-            DocumentationStyle::C => {
-                out.write(" */");
-                out.new_line();
-            }
-
-            DocumentationStyle::Doxy => {
+            DocumentationStyle::C
+            | DocumentationStyle::Doxy
+            | DocumentationStyle::Doxygen
+            | DocumentationStyle::Javadoc
+            | DocumentationStyle::Qt => {
                 out.write(" */");
                 out.new_line();
             }
@@ -6,21 +6,23 @@ use std::io::Write;
 
 use syn;
 
-use bindgen::config::{Config, Language};
+use bindgen::config::{Config, DiscriminantFormat, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, GenericPath, Item,
-    ItemContainer, Path, Repr, ReprStyle, ReprType, Struct, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, ConstEvaluator, Documentation, ErasedTypes,
+    GenericDefaults, GenericParams, GenericPath, Item, ItemContainer, Path, Repr, ReprStyle,
+    ReprType, Struct, ToCondition, Type,
 };
 use bindgen::library::Library;
 use bindgen::mangle;
 use bindgen::monomorph::Monomorphs;
 use bindgen::rename::{IdentifierType, RenameRule};
+use bindgen::reserved;
 use bindgen::utilities::find_first_some;
 use bindgen::writer::{ListType, Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumVariant {
     pub name: String,
     pub export_name: String,
@@ -94,6 +96,9 @@ impl EnumVariant {
             Ok(res)
         }
 
+        let mut annotations = AnnotationSet::load(&variant.attrs)?;
+        annotations.load_field_annotations(&variant.fields)?;
+
         let body = match variant.fields {
             syn::Fields::Unit => None,
             syn::Fields::Named(ref fields) => {
@@ -107,7 +112,7 @@ impl EnumVariant {
                     false,
                     false,
                     Cfg::append(mod_cfg, Cfg::load(&variant.attrs)),
-                    AnnotationSet::load(&variant.attrs)?,
+                    annotations.clone(),
                     Documentation::none(),
                 ))
             }
@@ -122,7 +127,7 @@ impl EnumVariant {
                     false,
                     true,
                     Cfg::append(mod_cfg, Cfg::load(&variant.attrs)),
-                    AnnotationSet::load(&variant.attrs)?,
+                    annotations.clone(),
                     Documentation::none(),
                 ))
             }
@@ -196,18 +201,30 @@ impl EnumVariant {
     }
 }
 
-impl Source for EnumVariant {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+impl EnumVariant {
+    /// Writes the variant with its discriminant, if it has one, in `format`.
+    fn write_with_format<F: Write>(
+        &self,
+        config: &Config,
+        format: DiscriminantFormat,
+        out: &mut SourceWriter<F>,
+    ) {
         self.documentation.write(config, out);
         write!(out, "{}", self.export_name);
         if let Some(discriminant) = self.discriminant {
-            write!(out, " = {}", discriminant);
+            write!(out, " = {}", format.format(discriminant));
         }
+    }
+}
+
+impl Source for EnumVariant {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        self.write_with_format(config, config.enumeration.discriminant_format, out);
         out.write(",");
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enum {
     pub path: Path,
     pub export_name: String,
@@ -221,6 +238,16 @@ pub struct Enum {
 }
 
 impl Enum {
+    /// Returns the attribute marking this enum as `#[deprecated]`.
+    fn deprecated(&self, config: &Config) -> Option<String> {
+        let note = self.annotations.deprecated.as_ref()?;
+        config.deprecated(
+            note,
+            &config.enumeration.deprecated,
+            &config.enumeration.deprecated_with_note,
+        )
+    }
+
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         if self.generic_params.len() > 0 {
             return;
@@ -244,17 +271,140 @@ impl Enum {
         })
     }
 
+    /// Whether the config derives an X-macro or a `to_string` function for
+    /// this enum, which only fieldless enums get.
+    pub(crate) fn has_companions(&self, config: &Config) -> bool {
+        self.tag.is_none()
+            && (config.enumeration.derive_for_each_macro(&self.annotations)
+                || config.enumeration.derive_to_string(&self.annotations))
+    }
+
+    /// Writes the X-macro and `to_string` function of a fieldless enum.
+    pub(crate) fn write_companions<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+
+        let for_each = config.enumeration.derive_for_each_macro(&self.annotations);
+        if for_each {
+            write!(
+                out,
+                "#define {}_FOR_EACH(V)",
+                self.export_name().to_uppercase()
+            );
+            for variant in &self.variants {
+                out.write(" \\");
+                out.new_line();
+                write!(out, "  V({})", variant.export_name);
+            }
+            out.new_line();
+        }
+
+        if config.enumeration.derive_to_string(&self.annotations) {
+            if for_each {
+                out.new_line();
+            }
+
+            let (prefix, name, null) = if config.language == Language::Cxx {
+                let null = if config.has_version(LanguageVersion::Cxx11) {
+                    "nullptr"
+                } else {
+                    "NULL"
+                };
+                ("inline ", self.export_name().to_owned(), null)
+            } else if self.repr.ty.is_none() && !config.style.generate_typedef() {
+                (
+                    "static inline ",
+                    format!("enum {}", self.export_name()),
+                    "NULL",
+                )
+            } else {
+                ("static inline ", self.export_name().to_owned(), "NULL")
+            };
+            write!(
+                out,
+                "{}const char *{}_to_string({} value)",
+                prefix,
+                self.export_name(),
+                name
+            );
+            out.open_brace();
+            out.write("switch (value)");
+            out.open_brace();
+            for (i, variant) in self.variants.iter().enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                write!(
+                    out,
+                    "case {}: return \"{}\";",
+                    Self::variant_path(config, self.export_name(), &variant.export_name),
+                    variant.export_name
+                );
+            }
+            out.close_brace(false);
+            out.new_line();
+            write!(out, "return {};", null);
+            out.close_brace(false);
+            out.new_line();
+        }
+
+        condition.write_after(config, out);
+    }
+
+    /// The expression naming a variant, which needs to be qualified by the
+    /// enum name only when it's an `enum class`.
+    pub(crate) fn variant_path(config: &Config, enum_name: &str, variant_name: &str) -> String {
+        if config.language == Language::Cxx && config.has_version(LanguageVersion::Cxx11) {
+            format!("{}::{}", enum_name, variant_name)
+        } else {
+            variant_name.to_owned()
+        }
+    }
+
     pub fn mangle_paths(&mut self, monomorphs: &Monomorphs) {
         for variant in &mut self.variants {
             variant.mangle_paths(monomorphs);
         }
     }
 
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        for variant in &mut self.variants {
+            if let Some((_, ref mut body)) = variant.body {
+                body.fill_generic_defaults(defaults);
+            }
+        }
+    }
+
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        for variant in &mut self.variants {
+            if let Some((_, ref mut body)) = variant.body {
+                body.erase_transparent(erased);
+            }
+        }
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        for variant in &mut self.variants {
+            if let Some((_, ref mut body)) = variant.body {
+                body.lower_types(config, constants, out)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn load(item: &syn::ItemEnum, mod_cfg: Option<&Cfg>) -> Result<Enum, String> {
         let repr = Repr::load(&item.attrs)?;
         if repr == Repr::RUST {
             return Err("Enum not marked with a valid repr(prim) or repr(C).".to_owned());
         }
+        if repr.align.is_some() {
+            return Err("Enum is marked #[repr(packed)] or #[repr(align)].".to_owned());
+        }
 
         let generic_params = GenericParams::new(&item.generics);
 
@@ -336,6 +486,10 @@ impl Item for Enum {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -353,7 +507,8 @@ impl Item for Enum {
             resolver.add_struct(&self.path);
         } else if self.tag.is_some() && self.repr.style != ReprStyle::C {
             resolver.add_union(&self.path);
-        } else if self.repr.style == ReprStyle::C {
+        } else if self.repr.style == ReprStyle::C && self.repr.ty.is_none() {
+            // Enums with a `#[repr(prim)]` are typedefs of their integer type.
             resolver.add_enum(&self.path);
         }
     }
@@ -367,7 +522,7 @@ impl Item for Enum {
     fn rename_for_config(&mut self, config: &Config) {
         config.export.rename(&mut self.export_name);
 
-        if config.language == Language::C && self.tag.is_some() {
+        if config.language != Language::Cxx && self.tag.is_some() {
             // it makes sense to always prefix Tag with type name in C
             let new_tag = format!("{}_Tag", self.export_name);
             if self.repr.style == ReprStyle::Rust {
@@ -426,6 +581,18 @@ impl Item for Enum {
                 })
                 .collect();
         }
+
+        for variant in &mut self.variants {
+            config
+                .export
+                .rename_rules
+                .enum_variants
+                .apply(&mut variant.export_name);
+            config.keywords.escape(&mut variant.export_name);
+            if config.objc_compat() {
+                reserved::escape_objc(&mut variant.export_name);
+            }
+        }
     }
 
     fn instantiate_monomorph(
@@ -514,11 +681,19 @@ impl Source for Enum {
             out.write(if separate_tag { "struct" } else { "union" });
 
             if self.annotations.must_use {
-                if let Some(ref anno) = config.structure.must_use {
+                if let Some(anno) = config.type_must_use(&config.structure.must_use) {
                     write!(out, " {}", anno)
                 }
             }
 
+            if let Some(anno) = self.deprecated(config) {
+                write!(out, " {}", anno)
+            }
+
+            if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+                write!(out, " {}", attribute);
+            }
+
             write!(out, " {}", self.export_name());
             out.open_brace();
         }
@@ -537,6 +712,18 @@ impl Source for Enum {
 
             out.write("enum");
 
+            if !is_tagged {
+                // Sized enums are used through their typedef.
+                if size.is_none() {
+                    if let Some(anno) = self.deprecated(config) {
+                        write!(out, " {}", anno)
+                    }
+                }
+                if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+                    write!(out, " {}", attribute);
+                }
+            }
+
             if !size.is_none() || config.style.generate_tag() {
                 write!(out, " {}", enum_name);
             }
@@ -552,13 +739,36 @@ impl Source for Enum {
                     out.new_line();
                 }
             }
+        } else if !config.has_version(LanguageVersion::Cxx11) {
+            // C++03 has no scoped enums nor fixed underlying types, so sized
+            // enums are emitted as an anonymous enum plus a typedef.
+            out.write("enum");
+
+            if !is_tagged {
+                if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+                    write!(out, " {}", attribute);
+                }
+            }
+
+            if size.is_none() {
+                write!(out, " {}", enum_name);
+            }
         } else {
             out.write("enum class");
 
             if self.annotations.must_use {
-                if let Some(ref anno) = config.enumeration.must_use {
+                if let Some(anno) = config.type_must_use(&config.enumeration.must_use) {
+                    write!(out, " {}", anno)
+                }
+            }
+
+            if !is_tagged {
+                if let Some(anno) = self.deprecated(config) {
                     write!(out, " {}", anno)
                 }
+                if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+                    write!(out, " {}", attribute);
+                }
             }
 
             write!(out, " {}", enum_name);
@@ -567,11 +777,21 @@ impl Source for Enum {
             }
         }
         out.open_brace();
+        let format = config.enumeration.discriminant_format(&self.annotations);
+        // C99 and C++03 don't allow a comma after the last enumerator.
+        let trailing_comma = config.enumeration.add_sentinel(&self.annotations)
+            || match config.language {
+                Language::Cxx => config.has_version(LanguageVersion::Cxx11),
+                _ => config.has_version(LanguageVersion::C11),
+            };
         for (i, variant) in self.variants.iter().enumerate() {
             if i != 0 {
                 out.new_line()
             }
-            variant.write(config, out);
+            variant.write_with_format(config, format, out);
+            if i + 1 < self.variants.len() || trailing_comma {
+                out.write(",");
+            }
         }
         if config.enumeration.add_sentinel(&self.annotations) {
             out.new_line();
@@ -586,6 +806,13 @@ impl Source for Enum {
             out.close_brace(true);
         }
 
+        if config.language == Language::Cxx && !config.has_version(LanguageVersion::Cxx11) {
+            if let Some(prim) = size {
+                out.new_line();
+                write!(out, "typedef {} {};", prim, enum_name);
+            }
+        }
+
         if config.language == Language::C {
             if let Some(prim) = size {
                 if config.cpp_compat {
@@ -594,7 +821,13 @@ impl Source for Enum {
                 }
 
                 out.new_line();
-                write!(out, "typedef {} {};", prim, enum_name);
+                write!(out, "typedef {} {}", prim, enum_name);
+                if !is_tagged {
+                    if let Some(anno) = self.deprecated(config) {
+                        write!(out, " {}", anno)
+                    }
+                }
+                out.write(";");
 
                 if config.cpp_compat {
                     out.new_line_if_not_start();
@@ -627,6 +860,14 @@ impl Source for Enum {
 
                 out.write(if separate_tag { "struct" } else { "union" });
 
+                if let Some(anno) = self.deprecated(config) {
+                    write!(out, " {}", anno)
+                }
+
+                if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+                    write!(out, " {}", attribute);
+                }
+
                 if config.style.generate_tag() {
                     write!(out, " {}", self.export_name());
                 }
@@ -635,8 +876,11 @@ impl Source for Enum {
             }
 
             // C++ allows accessing only common initial sequence of union
-            // branches so we need to wrap tag into an anonymous struct
-            let wrap_tag = config.language == Language::Cxx && !separate_tag;
+            // branches so we need to wrap tag into an anonymous struct, which
+            // C++03 compilers only accept as an extension.
+            let wrap_tag = config.language == Language::Cxx
+                && !separate_tag
+                && config.has_version(LanguageVersion::Cxx11);
 
             if wrap_tag {
                 out.write("struct");
@@ -677,7 +921,13 @@ impl Source for Enum {
             }
 
             if separate_tag {
-                out.close_brace(true);
+                // C99 has no anonymous members, so the union is named there.
+                if config.language == Language::C && !config.has_version(LanguageVersion::C11) {
+                    out.close_brace(false);
+                    out.write(" body;");
+                } else {
+                    out.close_brace(true);
+                }
             }
 
             let skip_fields = if separate_tag { 0 } else { 1 };
@@ -685,7 +935,16 @@ impl Source for Enum {
             // Emit convenience methods
             let derive_helper_methods = config.enumeration.derive_helper_methods(&self.annotations);
             if config.language == Language::Cxx && derive_helper_methods {
-                for variant in &self.variants {
+                // C++03 enumerators aren't scoped, so they'd clash with the
+                // constructors.
+                let derive_constructors = config.has_version(LanguageVersion::Cxx11);
+                if !derive_constructors {
+                    warn!(
+                        "Skip the constructors of {}, which C++03 can't tell apart from its variants.",
+                        self.export_name()
+                    );
+                }
+                for variant in self.variants.iter().filter(|_| derive_constructors) {
                     out.new_line();
                     out.new_line();
 
@@ -750,7 +1009,11 @@ impl Source for Enum {
                     }
 
                     out.new_line();
-                    write!(out, "result.tag = {}::{};", enum_name, variant.export_name);
+                    write!(
+                        out,
+                        "result.tag = {};",
+                        Self::variant_path(config, enum_name, &variant.export_name)
+                    );
                     out.new_line();
                     write!(out, "return result;");
                     out.close_brace(false);
@@ -763,7 +1026,11 @@ impl Source for Enum {
                     // FIXME: create a config for method case
                     write!(out, "bool Is{}() const", variant.export_name);
                     out.open_brace();
-                    write!(out, "return tag == {}::{};", enum_name, variant.export_name);
+                    write!(
+                        out,
+                        "return tag == {};",
+                        Self::variant_path(config, enum_name, &variant.export_name)
+                    );
                     out.close_brace(false);
                 }
             }
@@ -866,9 +1133,12 @@ impl Source for Enum {
                     if let Some((ref variant_name, _)) = variant.body {
                         write!(
                             out,
-                            "case {}::{}: return {} == {}.{};",
-                            self.tag.as_ref().unwrap(),
-                            variant.export_name,
+                            "case {}: return {} == {}.{};",
+                            Self::variant_path(
+                                config,
+                                self.tag.as_ref().unwrap(),
+                                &variant.export_name
+                            ),
                             variant_name,
                             other,
                             variant_name
@@ -909,9 +1179,12 @@ impl Source for Enum {
                     if let Some((ref variant_name, ref item)) = variant.body {
                         write!(
                             out,
-                            "case {}::{}: {}.~{}(); break;",
-                            self.tag.as_ref().unwrap(),
-                            variant.export_name,
+                            "case {}: {}.~{}(); break;",
+                            Self::variant_path(
+                                config,
+                                self.tag.as_ref().unwrap(),
+                                &variant.export_name
+                            ),
                             variant_name,
                             item.export_name(),
                         );
@@ -928,6 +1201,16 @@ impl Source for Enum {
                     .enumeration
                     .derive_tagged_enum_copy_constructor(&self.annotations)
             {
+                // Declaring the copy-constructor removes the implicit default
+                // constructor, which the helper methods use for the result.
+                out.new_line();
+                out.new_line();
+                out.write("private:");
+                out.new_line();
+                write!(out, "{}() {{}}", self.export_name);
+                out.new_line();
+                out.write("public:");
+
                 out.new_line();
                 out.new_line();
                 write!(
@@ -944,9 +1227,12 @@ impl Source for Enum {
                     if let Some((ref variant_name, ref item)) = variant.body {
                         write!(
                             out,
-                            "case {}::{}: ::new (&{}) ({})({}.{}); break;",
-                            self.tag.as_ref().unwrap(),
-                            variant.export_name,
+                            "case {}: ::new (&{}) ({})({}.{}); break;",
+                            Self::variant_path(
+                                config,
+                                self.tag.as_ref().unwrap(),
+                                &variant.export_name
+                            ),
                             variant_name,
                             item.export_name(),
                             other,
@@ -7,11 +7,12 @@ use std::io::Write;
 use syn;
 
 use bindgen::cdecl;
-use bindgen::config::{Config, Language, Layout};
+use bindgen::config::{Config, Language, Layout, ResultStyle};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, Path, PrimitiveType, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, ConstEvaluator, Documentation, ErasedTypes,
+    GenericDefaults, GenericPath, Path, PrimitiveType, Struct, ToCondition, Type,
 };
 use bindgen::library::Library;
 use bindgen::monomorph::Monomorphs;
@@ -20,12 +21,84 @@ use bindgen::reserved;
 use bindgen::utilities::{find_first_some, IterHelpers};
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+/// The calling convention of a function or function pointer, given by its
+/// `extern` ABI.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallingConvention {
+    C,
+    Stdcall,
+    Fastcall,
+    /// `stdcall` on 32-bit Windows and `C` everywhere else.
+    System,
+}
+
+impl CallingConvention {
+    /// Returns the calling convention of `extern "abi"`, if bindings can be
+    /// generated for it. The `-unwind` variants, like `extern "C-unwind"`,
+    /// share the calling convention of the ABI they extend.
+    pub fn from_abi(abi: &str) -> Option<CallingConvention> {
+        match abi.trim_end_matches("-unwind") {
+            "C" => Some(CallingConvention::C),
+            "stdcall" => Some(CallingConvention::Stdcall),
+            "fastcall" => Some(CallingConvention::Fastcall),
+            "system" => Some(CallingConvention::System),
+            _ => None,
+        }
+    }
+
+    pub fn is_c(&self) -> bool {
+        *self == CallingConvention::C
+    }
+
+    /// Returns the name of the macro the C and C++ bindings define for the
+    /// calling convention.
+    pub fn macro_name(self) -> Option<&'static str> {
+        match self {
+            CallingConvention::C => None,
+            CallingConvention::Stdcall => Some("CBINDGEN_STDCALL"),
+            CallingConvention::Fastcall => Some("CBINDGEN_FASTCALL"),
+            CallingConvention::System => Some("CBINDGEN_SYSTEM"),
+        }
+    }
+
+    /// Returns what's written before the declarator of a function with the
+    /// calling convention in C, C++ and Cython. Cython has no `system`
+    /// convention, which is written as `C`.
+    pub fn keyword(self, language: Language) -> Option<&'static str> {
+        match language {
+            Language::Cython => match self {
+                CallingConvention::Stdcall => Some("__stdcall"),
+                CallingConvention::Fastcall => Some("__fastcall"),
+                CallingConvention::C | CallingConvention::System => None,
+            },
+            _ => self.macro_name(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Function {
     pub path: Path,
     pub ret: Type,
     pub args: Vec<(String, Type)>,
+    /// Whether the function takes a variable number of arguments after
+    /// `args`, like `printf`.
+    pub variadic: bool,
+    /// Whether the function returns `!`, which never returns.
+    pub no_return: bool,
+    pub calling_convention: CallingConvention,
+    /// Whether panics may unwind out of the function, which is declared with
+    /// an `-unwind` ABI like `extern "C-unwind"`.
+    pub unwind: bool,
     pub extern_decl: bool,
+    /// The type of the `impl` block the function is a method of, if it takes
+    /// `self`, which its first argument points to.
+    pub impl_path: Option<Path>,
+    /// The body of the `static inline` function written for an `#[inline]`
+    /// function, given with `inline-body` or `fn.inline_bodies`.
+    #[serde(default)]
+    pub inline_body: Option<String>,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
@@ -35,12 +108,35 @@ impl Function {
     pub fn load(
         path: Path,
         decl: &syn::FnDecl,
+        calling_convention: CallingConvention,
+        unwind: bool,
         extern_decl: bool,
         attrs: &[syn::Attribute],
         mod_cfg: Option<&Cfg>,
+        impl_path: Option<&Path>,
     ) -> Result<Function, String> {
-        let args = decl.inputs.iter().try_skip_map(|x| x.as_ident_and_type())?;
-        let ret = match decl.output {
+        let has_self = match decl.inputs.first().map(|x| x.into_value()) {
+            Some(&syn::FnArg::SelfRef(..)) => true,
+            _ => false,
+        };
+        let mut args = decl.inputs.iter().try_skip_map(|x| match *x {
+            syn::FnArg::SelfRef(ref arg) => {
+                let self_ty = match impl_path {
+                    Some(impl_path) => Type::Path(GenericPath::new(impl_path.clone(), vec![])),
+                    None => return Err("`self` outside of an impl block.".to_owned()),
+                };
+                let ty = match arg.mutability {
+                    Some(_) => Type::Ptr(Box::new(self_ty), false),
+                    None => Type::ConstPtr(Box::new(self_ty), false),
+                };
+                Ok(Some(("self".to_owned(), ty)))
+            }
+            syn::FnArg::SelfValue(..) => Err("Methods taking `self` by value are not \
+                                              supported."
+                .to_owned()),
+            _ => x.as_ident_and_type(),
+        })?;
+        let mut ret = match decl.output {
             syn::ReturnType::Default => Type::Primitive(PrimitiveType::Void),
             syn::ReturnType::Type(_, ref ty) => {
                 if let Some(x) = Type::load(ty)? {
@@ -51,11 +147,30 @@ impl Function {
             }
         };
 
+        if let Some(impl_path) = impl_path {
+            ret.replace_self_with(impl_path);
+            for &mut (_, ref mut ty) in &mut args {
+                ty.replace_self_with(impl_path);
+            }
+        }
+
         Ok(Function {
             path,
             ret,
             args,
+            variadic: decl.variadic.is_some(),
+            no_return: match decl.output {
+                syn::ReturnType::Type(_, ref ty) => match **ty {
+                    syn::Type::Never(_) => true,
+                    _ => false,
+                },
+                syn::ReturnType::Default => false,
+            },
+            calling_convention,
+            unwind,
             extern_decl,
+            impl_path: if has_self { impl_path.cloned() } else { None },
+            inline_body: None,
             cfg: Cfg::append(mod_cfg, Cfg::load(attrs)),
             annotations: AnnotationSet::load(attrs)?,
             documentation: Documentation::load(attrs),
@@ -66,11 +181,218 @@ impl Function {
         &self.path
     }
 
-    pub fn simplify_standard_types(&mut self) {
-        self.ret.simplify_standard_types();
+    /// Whether the C++ prototype of the function is `noexcept`, which is the
+    /// case with `cpp_noexcept` for functions panics can't unwind out of.
+    fn is_noexcept(&self, config: &Config) -> bool {
+        config.language == Language::Cxx && config.function.cpp_noexcept && !self.unwind
+    }
+
+    /// Returns the name of the C++ member function calling this method, which
+    /// is the name of the method without the snake case name of its type as a
+    /// prefix.
+    pub fn member(&self) -> Option<String> {
+        let impl_path = self.impl_path.as_ref()?;
+        let prefix = format!(
+            "{}_",
+            RenameRule::SnakeCase.apply_to_pascal_case(impl_path.name(), IdentifierType::Function)
+        );
+        let name = self.path.name();
+        if name.starts_with(&prefix) && name.len() > prefix.len() {
+            Some(name[prefix.len()..].to_owned())
+        } else {
+            Some(name.to_owned())
+        }
+    }
+
+    /// Returns the C++ member function `name` of `class` calling this
+    /// function with `this` as its first argument, which is `const` unless
+    /// that's a mutable pointer. With `by_reference`, the pointers it takes
+    /// and returns are references instead.
+    fn as_member(&self, class: Option<&str>, name: &str, by_reference: bool) -> (Function, bool) {
+        let is_const = match self.args.first() {
+            Some(&(_, Type::Ptr(..))) => false,
+            _ => true,
+        };
+
+        let to_reference = |ty: &Type| match *ty {
+            Type::ConstPtr(ref ty, _) if by_reference => Type::Ref(ty.clone()),
+            Type::Ptr(ref ty, _) if by_reference => Type::MutRef(ty.clone()),
+            _ => ty.clone(),
+        };
+        let mut member = self.clone();
+        member.path = match class {
+            Some(class) => Path::new(format!("{}::{}", class, name)),
+            None => Path::new(name),
+        };
+        member.ret = to_reference(&self.ret);
+        member.calling_convention = CallingConvention::C;
+        member.args = self
+            .args
+            .iter()
+            .skip(1)
+            .map(|&(ref name, ref ty)| (name.clone(), to_reference(ty)))
+            .collect();
+        (member, is_const)
+    }
+
+    /// Writes the declaration of the C++ member function `name` calling this
+    /// function. See `as_member`.
+    pub fn write_member_declaration<F: Write>(
+        &self,
+        name: &str,
+        by_reference: bool,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let (decl, is_const) = self.as_member(None, name, by_reference);
+
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+
+        self.documentation.write(config, out);
+        cdecl::write_func(out, &decl, false, false);
+        if is_const {
+            out.write(" const");
+        }
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    /// Writes the inline definition of the C++ member function `name` of
+    /// `class` calling this function. See `as_member`.
+    pub fn write_member_definition<F: Write>(
+        &self,
+        class: &str,
+        name: &str,
+        by_reference: bool,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let (def, is_const) = self.as_member(Some(class), name, by_reference);
+
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+
+        out.write("inline ");
+        cdecl::write_func(out, &def, false, false);
+        if is_const {
+            out.write(" const");
+        }
+        out.open_brace();
+
+        let is_ref = |ty: &Type| match *ty {
+            Type::Ref(..) | Type::MutRef(..) => true,
+            _ => false,
+        };
+        let mut args = match self.args.first() {
+            Some(&(_, Type::ConstPtr(..))) | Some(&(_, Type::Ptr(..))) => vec!["this".to_owned()],
+            _ => vec!["*this".to_owned()],
+        };
+        for &(ref name, ref ty) in &def.args {
+            if is_ref(ty) {
+                args.push(format!("&{}", name));
+            } else {
+                args.push(name.clone());
+            }
+        }
+        if is_ref(&def.ret) {
+            out.write("return *");
+        } else if self.ret != Type::Primitive(PrimitiveType::Void) {
+            out.write("return ");
+        }
+        write!(out, "{}({});", self.path.name(), args.join(", "));
+        out.close_brace(false);
+
+        condition.write_after(config, out);
+    }
+
+    /// Writes the `static inline` definition of an `#[inline]` function, with
+    /// its body written verbatim.
+    pub fn write_inline_definition<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let body = match self.inline_body {
+            Some(ref body) => body,
+            None => return,
+        };
+        let void_prototype = config.language == Language::C;
+
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+
+        self.documentation.write(config, out);
+        out.write("static inline ");
+        cdecl::write_func(out, self, false, void_prototype);
+        out.open_brace();
+        for (i, line) in body.trim().lines().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "{}", line.trim_end());
+        }
+        out.close_brace(false);
+
+        condition.write_after(config, out);
+    }
+
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        for &mut (ref name, ref mut ty) in &mut self.args {
+            if let Some(meaning) = ty.standard_type_note(config) {
+                self.documentation
+                    .doc_comment
+                    .push(format!("`{}`: {}", name, meaning));
+            }
+            ty.simplify_standard_types(config);
+        }
+        if let Some(meaning) = self.ret.standard_type_note(config) {
+            self.documentation
+                .doc_comment
+                .push(format!("Return value: {}", meaning));
+        }
+        self.ret.simplify_standard_types(config);
+    }
+
+    /// Rewrites a returned `Result<T, E>` as `fn.results` asks. `Result<(), E>`
+    /// has lost its `()` by then, so returns a `VoidResult<E>` as a tagged
+    /// union. Returns whether it was lowered.
+    pub fn lower_result(&mut self, config: &Config) -> bool {
+        let (ok, err) = match self.ret {
+            Type::Path(ref path) if path.name() == "Result" => match *path.generics() {
+                [ref ok, ref err] => (Some(ok.clone()), err.clone()),
+                [ref err] => (None, err.clone()),
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        match config.function.results {
+            ResultStyle::None => return false,
+            ResultStyle::TaggedUnion => {
+                if ok.is_none() {
+                    self.ret = Type::Path(GenericPath::new(Path::new("VoidResult"), vec![err]));
+                }
+            }
+            ResultStyle::OutParam => {
+                self.ret = err;
+                if let Some(ok) = ok {
+                    self.args
+                        .push(("out".to_owned(), Type::Ptr(Box::new(ok), false)));
+                }
+            }
+        }
+        true
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        self.ret.lower_types(config, constants, out)?;
         for &mut (_, ref mut ty) in &mut self.args {
-            ty.simplify_standard_types();
+            ty.lower_types(config, constants, out)?;
         }
+        Ok(())
     }
 
     pub fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -94,6 +416,20 @@ impl Function {
         }
     }
 
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        self.ret.fill_generic_defaults(defaults);
+        for &mut (_, ref mut ty) in &mut self.args {
+            ty.fill_generic_defaults(defaults);
+        }
+    }
+
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        self.ret.erase_transparent(erased);
+        for &mut (_, ref mut ty) in &mut self.args {
+            ty.erase_transparent(erased);
+        }
+    }
+
     pub fn resolve_declaration_types(&mut self, resolver: &DeclarationTypeResolver) {
         self.ret.resolve_declaration_types(resolver);
         for &mut (_, ref mut ty) in &mut self.args {
@@ -130,11 +466,52 @@ impl Function {
 
         // Escape C/C++ reserved keywords used in argument names
         for args in &mut self.args {
-            reserved::escape(&mut args.0);
+            config.keywords.escape(&mut args.0);
+            if config.objc_compat() {
+                reserved::escape_objc(&mut args.0);
+            }
         }
     }
 }
 
+impl Function {
+    /// Returns the locks given by the `requires-lock` annotation, which the
+    /// callers must hold.
+    pub(crate) fn required_locks(&self) -> Option<String> {
+        match self.annotations.list("requires-lock") {
+            Some(locks) => Some(locks.join(", ")),
+            None => self.annotations.atom("requires-lock")?,
+        }
+    }
+
+    /// Returns the attribute marking this function as `#[deprecated]`.
+    fn deprecated(&self, config: &Config) -> Option<String> {
+        let note = self.annotations.deprecated.as_ref()?;
+        config.deprecated(
+            note,
+            &config.function.deprecated,
+            &config.function.deprecated_with_note,
+        )
+    }
+
+    /// Returns the name Swift should import this function as, either from the
+    /// `swift-name` annotation or derived from `[swift] rename_functions`.
+    fn swift_name(&self, config: &Config) -> Option<String> {
+        if let Some(name) = self.annotations.atom("swift-name") {
+            return name;
+        }
+        let rule = config.swift.rename_functions.as_ref()?;
+        let mut swift_name = rule.apply_to_snake_case(self.path.name(), IdentifierType::Function);
+        swift_name.push('(');
+        for (name, _) in &self.args {
+            swift_name.push_str(&rule.apply_to_snake_case(name, IdentifierType::FunctionArg));
+            swift_name.push(':');
+        }
+        swift_name.push(')');
+        Some(swift_name)
+    }
+}
+
 impl Source for Function {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         fn write_1<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
@@ -147,25 +524,46 @@ impl Source for Function {
 
             func.documentation.write(config, out);
 
+            if func.no_return {
+                if let Some(anno) = config.function_no_return() {
+                    write!(out, "{} ", anno);
+                }
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
+                if let Some(ref dll_export) = config.export.dll_export_macro {
+                    write!(out, "{} ", dll_export);
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{} ", prefix);
                 }
                 if func.annotations.must_use {
-                    if let Some(ref anno) = config.function.must_use {
+                    if let Some(anno) = config.function_must_use() {
                         write!(out, "{} ", anno);
                     }
                 }
+                if let Some(anno) = func.deprecated(config) {
+                    write!(out, "{} ", anno);
+                }
             }
             cdecl::write_func(out, &func, false, void_prototype);
+            if func.is_noexcept(config) {
+                out.write(" noexcept");
+            }
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
                     out.write(" ");
                     write!(out, "{}", postfix);
                 }
             }
+            if let Some(locks) = func.required_locks() {
+                write!(out, " {}({})", config.thread_safety.requires, locks);
+            }
+            if let Some(swift_name) = func.swift_name(config) {
+                out.write(" ");
+                write!(out, "{}", config.swift.name_attribute(&swift_name));
+            }
             out.write(";");
 
             condition.write_after(config, out);
@@ -182,27 +580,51 @@ impl Source for Function {
 
             func.documentation.write(config, out);
 
+            if func.no_return {
+                if let Some(anno) = config.function_no_return() {
+                    write!(out, "{}", anno);
+                    out.new_line();
+                }
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
+                if let Some(ref dll_export) = config.export.dll_export_macro {
+                    write!(out, "{} ", dll_export);
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{}", prefix);
                     out.new_line();
                 }
                 if func.annotations.must_use {
-                    if let Some(ref anno) = config.function.must_use {
+                    if let Some(anno) = config.function_must_use() {
                         write!(out, "{}", anno);
                         out.new_line();
                     }
                 }
+                if let Some(anno) = func.deprecated(config) {
+                    write!(out, "{}", anno);
+                    out.new_line();
+                }
             }
             cdecl::write_func(out, &func, true, void_prototype);
+            if func.is_noexcept(config) {
+                out.write(" noexcept");
+            }
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
                     out.new_line();
                     write!(out, "{}", postfix);
                 }
             }
+            if let Some(locks) = func.required_locks() {
+                out.new_line();
+                write!(out, "{}({})", config.thread_safety.requires, locks);
+            }
+            if let Some(swift_name) = func.swift_name(config) {
+                out.new_line();
+                write!(out, "{}", config.swift.name_attribute(&swift_name));
+            }
             out.write(";");
 
             condition.write_after(config, out);
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::Write;
 use std::ops::Deref;
 
@@ -9,23 +10,60 @@ use bindgen::ir::{Path, Type};
 use bindgen::utilities::IterHelpers;
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Default, Debug, Clone)]
-pub struct GenericParams(pub Vec<Path>);
+/// The type parameters of a generic item, with the default types of the ones
+/// that have one.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GenericParams(pub Vec<Path>, #[serde(default)] pub Vec<Option<Type>>);
+
+/// The type parameters of the generic items with default types, by the paths
+/// of the items.
+pub type GenericDefaults = HashMap<Path, GenericParams>;
+
+/// The transparent structs to replace with the types they wrap, by their
+/// paths, with their type parameters.
+pub type ErasedTypes = HashMap<Path, (GenericParams, Type)>;
 
 impl GenericParams {
     pub fn new(generics: &syn::Generics) -> Self {
-        GenericParams(
-            generics
-                .params
-                .iter()
-                .filter_map(|x| match x {
-                    &syn::GenericParam::Type(syn::TypeParam { ref ident, .. }) => {
-                        Some(Path::new(ident.to_string()))
-                    }
-                    _ => None,
-                })
-                .collect(),
-        )
+        let (params, defaults) = generics
+            .params
+            .iter()
+            .filter_map(|x| match x {
+                &syn::GenericParam::Type(syn::TypeParam {
+                    ref ident,
+                    ref default,
+                    ..
+                }) => {
+                    let default = default
+                        .as_ref()
+                        .and_then(|ty| Type::load(ty).ok())
+                        .and_then(|ty| ty);
+                    Some((Path::new(ident.to_string()), default))
+                }
+                _ => None,
+            })
+            .unzip();
+        GenericParams(params, defaults)
+    }
+
+    pub fn has_defaults(&self) -> bool {
+        self.1.iter().any(|x| x.is_some())
+    }
+
+    /// Completes `generics` with the defaults of the parameters they leave
+    /// out, returning false if one of those has no default.
+    fn fill_defaults(&self, generics: &mut Vec<Type>) -> bool {
+        for i in generics.len()..self.0.len() {
+            let default = match self.1.get(i) {
+                Some(&Some(ref default)) => default,
+                _ => return false,
+            };
+            // Defaults can refer to the parameters before them.
+            let mappings: Vec<_> = self.0.iter().zip(generics.iter()).collect();
+            let value = default.specialize(&mappings);
+            generics.push(value);
+        }
+        true
     }
 }
 
@@ -55,7 +93,7 @@ impl Source for GenericParams {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct GenericPath {
     path: Path,
     export_name: String,
@@ -91,6 +129,10 @@ impl GenericPath {
         &self.generics
     }
 
+    pub fn generics_mut(&mut self) -> &mut [Type] {
+        &mut self.generics
+    }
+
     pub fn ctype(&self) -> Option<&DeclarationType> {
         self.ctype.as_ref()
     }
@@ -116,6 +158,22 @@ impl GenericPath {
         self.ctype = resolver.type_for(&self.path);
     }
 
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        for generic in &mut self.generics {
+            generic.fill_generic_defaults(defaults);
+        }
+        if let Some(params) = defaults.get(&self.path) {
+            let given = self.generics.len();
+            let mut generics = self.generics.clone();
+            if given < params.len() && params.fill_defaults(&mut generics) {
+                for generic in &mut generics[given..] {
+                    generic.fill_generic_defaults(defaults);
+                }
+                self.generics = generics;
+            }
+        }
+    }
+
     pub fn load(path: &syn::Path) -> Result<Self, String> {
         assert!(
             path.segments.len() > 0,
@@ -9,11 +9,14 @@ use syn;
 use bindgen::config::Config;
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
-use bindgen::ir::{AnnotationSet, Cfg, Documentation, Item, ItemContainer, Path, Type};
+use bindgen::ir::{
+    AnnotationSet, Cfg, ConstEvaluator, Documentation, ErasedTypes, GenericDefaults, Item,
+    ItemContainer, Path, Struct, Type,
+};
 use bindgen::library::Library;
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Static {
     pub path: Path,
     pub export_name: String,
@@ -62,8 +65,28 @@ impl Static {
         }
     }
 
-    pub fn simplify_standard_types(&mut self) {
-        self.ty.simplify_standard_types();
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        if let Some(meaning) = self.ty.standard_type_note(config) {
+            self.documentation.doc_comment.push(meaning.to_owned());
+        }
+        self.ty.simplify_standard_types(config);
+    }
+
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        self.ty.fill_generic_defaults(defaults);
+    }
+
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        self.ty.erase_transparent(erased);
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        self.ty.lower_types(config, constants, out)
     }
 }
 
@@ -80,6 +103,10 @@ impl Item for Static {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -108,6 +135,12 @@ impl Item for Static {
 impl Source for Static {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         out.write("extern ");
+        if let Some(ref dll_export) = config.export.dll_export_macro {
+            write!(out, "{} ", dll_export);
+        }
+        if let Some(prefix) = config.global.prefix(&self.annotations) {
+            write!(out, "{} ", prefix);
+        }
         if let Type::ConstPtr(..) = self.ty {
         } else {
             if !self.mutable {
@@ -115,6 +148,10 @@ impl Source for Static {
             }
         }
         self.ty.write(config, out);
-        write!(out, " {};", self.export_name());
+        write!(out, " {}", self.export_name());
+        if let Some(postfix) = config.global.postfix(&self.annotations) {
+            write!(out, " {}", postfix);
+        }
+        out.write(";");
     }
 }
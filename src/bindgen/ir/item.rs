@@ -24,6 +24,7 @@ pub trait Item {
         self.name()
     }
     fn cfg(&self) -> Option<&Cfg>;
+    fn cfg_mut(&mut self) -> &mut Option<Cfg>;
     fn annotations(&self) -> &AnnotationSet;
     fn annotations_mut(&mut self) -> &mut AnnotationSet;
 
@@ -65,15 +66,36 @@ impl ItemContainer {
             &ItemContainer::Typedef(ref x) => x,
         }
     }
+
+    /// The standard section the item is grouped into.
+    pub fn standard_section(&self) -> &'static str {
+        match *self {
+            ItemContainer::Constant(..) => "Constants",
+            ItemContainer::Static(..) => "Globals",
+            ItemContainer::Enum(..) => "Enums",
+            ItemContainer::Struct(..)
+            | ItemContainer::Union(..)
+            | ItemContainer::OpaqueItem(..) => "Structs",
+            ItemContainer::Typedef(ref x) => match x.aliased {
+                Type::FuncPtr(..) => "Callbacks",
+                _ => "Typedefs",
+            },
+        }
+    }
+
+    /// The section the item is grouped into, if sections are enabled.
+    pub fn section(&self, config: &Config) -> Option<String> {
+        config.section_of(self.deref().annotations(), self.standard_section())
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ItemValue<T: Item> {
     Cfg(Vec<T>),
     Single(T),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemMap<T: Item> {
     data: BTreeMap<Path, ItemValue<T>>,
 }
@@ -146,9 +168,9 @@ impl<T: Item + Clone> ItemMap<T> {
         })
     }
 
-    pub fn filter<F>(&mut self, callback: F)
+    pub fn filter<F>(&mut self, mut callback: F)
     where
-        F: Fn(&T) -> bool,
+        F: FnMut(&T) -> bool,
     {
         let data = mem::replace(&mut self.data, BTreeMap::new());
 
@@ -0,0 +1,467 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use bindgen::ir::{
+    ArrayLength, Enum, ItemContainer, KnownCfgs, OpaqueItem, Path, PrimitiveType, ReprAlign,
+    ReprStyle, ReprType, Struct, Type, Union,
+};
+
+/// The data model to compute layouts for. Only the sizes and alignments that
+/// are the same across the common ABIs of a data model are known.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DataModel {
+    /// 32-bit pointers. The alignment of 64-bit scalars differs between ABIs.
+    Bits32,
+    /// 64-bit pointers. The size of `long` and `wchar_t` differs between ABIs.
+    Bits64,
+}
+
+/// The sizes and alignments of the C types that differ between targets, in
+/// bytes, or `None` where they aren't known.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DataLayout {
+    /// The size and alignment of pointers.
+    pub pointer: usize,
+    /// The alignment of 64-bit scalars in structs.
+    pub align64: Option<usize>,
+    /// The size and alignment of `long`.
+    pub long: Option<usize>,
+    /// The size and alignment of `wchar_t`.
+    pub wchar: Option<usize>,
+}
+
+impl DataLayout {
+    /// Returns the data layout of the target `cfgs` are of, or `None` if it
+    /// isn't known.
+    pub fn for_target(cfgs: &KnownCfgs) -> Option<DataLayout> {
+        let pointer = match cfgs.value("target_pointer_width")? {
+            "32" => 4,
+            "64" => 8,
+            _ => return None,
+        };
+        let arch = cfgs.value("target_arch")?;
+        let windows = cfgs.value("target_os") == Some("windows");
+        // The System V ABI of x86 is the one that aligns 64-bit scalars to 4
+        // bytes in structs.
+        let align64 = if arch == "x86" && !windows { 4 } else { 8 };
+        Some(DataLayout {
+            pointer,
+            align64: Some(align64),
+            long: Some(if windows { 4 } else { pointer }),
+            wchar: Some(if windows { 2 } else { 4 }),
+        })
+    }
+}
+
+impl From<DataModel> for DataLayout {
+    fn from(model: DataModel) -> DataLayout {
+        match model {
+            DataModel::Bits32 => DataLayout {
+                pointer: 4,
+                align64: None,
+                long: Some(4),
+                wchar: None,
+            },
+            DataModel::Bits64 => DataLayout {
+                pointer: 8,
+                align64: Some(8),
+                long: None,
+                wchar: None,
+            },
+        }
+    }
+}
+
+/// The size and alignment of a type, in bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub size: usize,
+    pub align: usize,
+}
+
+impl TypeLayout {
+    fn scalar(size: usize) -> Self {
+        TypeLayout { size, align: size }
+    }
+}
+
+/// The layout of a struct, and the offset of each of its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructLayout {
+    pub layout: TypeLayout,
+    pub offsets: Vec<usize>,
+}
+
+/// The layouts of a type in each data model, or for the target the bindings
+/// are generated for, where they're known.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Layouts<T> {
+    pub bits32: Option<T>,
+    pub bits64: Option<T>,
+    /// The layout for the target, which is the only one computed when the
+    /// data layout of the target is known.
+    pub target: Option<T>,
+}
+
+pub type TypeLayouts = Layouts<TypeLayout>;
+pub type StructLayouts = Layouts<StructLayout>;
+
+impl<T: PartialEq> Layouts<T> {
+    pub fn is_known(&self) -> bool {
+        self.bits32.is_some() || self.bits64.is_some() || self.target.is_some()
+    }
+
+    /// Returns the known layouts with the preprocessor condition selecting
+    /// each of them, which is `None` for a layout that applies everywhere.
+    pub fn conditions(&self) -> Vec<(Option<&'static str>, &T)> {
+        if let Some(ref target) = self.target {
+            return vec![(None, target)];
+        }
+        match (&self.bits32, &self.bits64) {
+            (&Some(ref bits32), &Some(ref bits64)) if bits32 == bits64 => vec![(None, bits64)],
+            (bits32, bits64) => {
+                let mut out = Vec::new();
+                if let Some(ref bits64) = *bits64 {
+                    out.push((Some("UINTPTR_MAX == UINT64_MAX"), bits64));
+                }
+                if let Some(ref bits32) = *bits32 {
+                    out.push((Some("UINTPTR_MAX == UINT32_MAX"), bits32));
+                }
+                out
+            }
+        }
+    }
+}
+
+/// The engines computing the layouts of a library: one for the target when
+/// its data layout is known, or one per data model.
+pub struct LayoutEngines<'a> {
+    bits32: Option<LayoutEngine<'a>>,
+    bits64: Option<LayoutEngine<'a>>,
+    target: Option<LayoutEngine<'a>>,
+}
+
+impl<'a> LayoutEngines<'a> {
+    pub fn new(items: &'a [ItemContainer], target: Option<DataLayout>) -> Self {
+        match target {
+            Some(layout) => LayoutEngines {
+                bits32: None,
+                bits64: None,
+                target: Some(LayoutEngine::new(items, layout)),
+            },
+            None => LayoutEngines {
+                bits32: Some(LayoutEngine::new(items, DataModel::Bits32)),
+                bits64: Some(LayoutEngine::new(items, DataModel::Bits64)),
+                target: None,
+            },
+        }
+    }
+
+    /// Computes a layout with each engine.
+    pub fn layouts<T, F>(&self, f: F) -> Layouts<T>
+    where
+        F: Fn(&LayoutEngine<'a>) -> Option<T>,
+    {
+        Layouts {
+            bits32: self.bits32.as_ref().and_then(|x| f(x)),
+            bits64: self.bits64.as_ref().and_then(|x| f(x)),
+            target: self.target.as_ref().and_then(|x| f(x)),
+        }
+    }
+}
+
+/// Computes the layouts of the structs among `items` that are known for the
+/// target or in some data model.
+pub fn struct_layouts(
+    items: &[ItemContainer],
+    target: Option<DataLayout>,
+) -> HashMap<Path, StructLayouts> {
+    let engines = LayoutEngines::new(items, target);
+
+    let mut out = HashMap::new();
+    for item in items {
+        if let ItemContainer::Struct(ref s) = *item {
+            let layouts = engines.layouts(|engine| engine.struct_layout(s));
+            if layouts.is_known() {
+                out.insert(s.path.clone(), layouts);
+            }
+        }
+    }
+    out
+}
+
+/// Computes the C layout of the items of a library, following the rules
+/// `#[repr(C)]` shares with C.
+pub struct LayoutEngine<'a> {
+    items: HashMap<&'a Path, &'a ItemContainer>,
+    layout: DataLayout,
+}
+
+impl<'a> LayoutEngine<'a> {
+    pub fn new<L: Into<DataLayout>>(items: &'a [ItemContainer], layout: L) -> Self {
+        let mut map = HashMap::new();
+        let mut duplicates = Vec::new();
+        for item in items {
+            let path = item.deref().path();
+            if map.insert(path, item).is_some() {
+                duplicates.push(path);
+            }
+        }
+        // Items with several `#[cfg]`ed definitions have no single layout.
+        for path in duplicates {
+            map.remove(path);
+        }
+        LayoutEngine {
+            items: map,
+            layout: layout.into(),
+        }
+    }
+
+    fn pointer(&self) -> TypeLayout {
+        TypeLayout::scalar(self.layout.pointer)
+    }
+
+    fn primitive(&self, primitive: &PrimitiveType) -> Option<TypeLayout> {
+        Some(match *primitive {
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar
+            | PrimitiveType::UInt8
+            | PrimitiveType::Int8 => TypeLayout::scalar(1),
+            PrimitiveType::Short
+            | PrimitiveType::UShort
+            | PrimitiveType::UInt16
+            | PrimitiveType::Int16 => TypeLayout::scalar(2),
+            PrimitiveType::Int
+            | PrimitiveType::UInt
+            | PrimitiveType::UInt32
+            | PrimitiveType::Int32
+            | PrimitiveType::Float => TypeLayout::scalar(4),
+            PrimitiveType::LongLong
+            | PrimitiveType::ULongLong
+            | PrimitiveType::UInt64
+            | PrimitiveType::Int64
+            | PrimitiveType::Double => TypeLayout {
+                size: 8,
+                align: self.layout.align64?,
+            },
+            PrimitiveType::Long | PrimitiveType::ULong => TypeLayout::scalar(self.layout.long?),
+            // `__int128` is only supported on 64-bit targets.
+            PrimitiveType::Int128 | PrimitiveType::UInt128 if self.layout.pointer == 8 => {
+                TypeLayout::scalar(16)
+            }
+            PrimitiveType::Int128 | PrimitiveType::UInt128 => return None,
+            PrimitiveType::WChar => TypeLayout::scalar(self.layout.wchar?),
+            PrimitiveType::USize
+            | PrimitiveType::ISize
+            | PrimitiveType::SizeT
+            | PrimitiveType::SSizeT
+            | PrimitiveType::PtrDiffT => self.pointer(),
+            PrimitiveType::Void | PrimitiveType::VaList => return None,
+        })
+    }
+
+    /// Returns the layout of `ty`, or `None` if it isn't known.
+    pub fn type_layout(&self, ty: &Type) -> Option<TypeLayout> {
+        match *ty {
+            Type::ConstPtr(..)
+            | Type::Ptr(..)
+            | Type::Ref(..)
+            | Type::MutRef(..)
+            | Type::FuncPtr(..) => Some(self.pointer()),
+            Type::Primitive(ref primitive) => self.primitive(primitive),
+            // Atomics have the layout of their value on the common ABIs.
+            Type::Atomic(ref ty) => self.type_layout(ty),
+            Type::Array(ref ty, ArrayLength::Value(ref len)) => {
+                let layout = self.type_layout(ty)?;
+                let len = len.parse::<usize>().ok()?;
+                Some(TypeLayout {
+                    size: layout.size * len,
+                    align: layout.align,
+                })
+            }
+            Type::Array(_, ArrayLength::Name(_)) | Type::Array(_, ArrayLength::Expr(_)) => None,
+            Type::Path(ref generic) => {
+                if !generic.generics().is_empty() {
+                    return None;
+                }
+                self.item_layout(self.items.get(generic.path())?)
+            }
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) => None,
+        }
+    }
+
+    /// Returns the layout of the type `item` declares, or `None` if it isn't
+    /// known.
+    pub fn item_layout(&self, item: &ItemContainer) -> Option<TypeLayout> {
+        match *item {
+            ItemContainer::Struct(ref s) => self.struct_layout(s).map(|x| x.layout),
+            ItemContainer::Union(ref u) => self.union_layout(u),
+            ItemContainer::Enum(ref e) => self.enum_layout(e),
+            ItemContainer::Typedef(ref t) if !t.is_generic() => self.type_layout(&t.aliased),
+            _ => None,
+        }
+    }
+
+    /// Returns the layout of an opaque item, or `None` if it isn't known. The
+    /// `cbindgen:size` and `cbindgen:align` annotations take precedence over
+    /// the layout of its fields, which Rust reorders by alignment so that
+    /// they need no padding.
+    pub fn opaque_layout(&self, o: &OpaqueItem) -> Option<TypeLayout> {
+        if o.generic_params.len() > 0 {
+            return None;
+        }
+        let annotation = |name| {
+            o.annotations
+                .atom(name)
+                .and_then(|x| x)
+                .and_then(|x| x.parse::<usize>().ok())
+        };
+        let (size, align) = (annotation("size"), annotation("align"));
+        if let (Some(size), Some(align)) = (size, align) {
+            return Some(TypeLayout { size, align });
+        }
+
+        let mut fields_size = 0;
+        let mut fields_align = 1;
+        for ty in o.fields.as_ref()? {
+            let layout = self.rust_type_layout(ty)?;
+            fields_size += layout.size;
+            fields_align = fields_align.max(layout.align);
+        }
+        let align = align.unwrap_or(fields_align);
+        let size = size.unwrap_or_else(|| round_up(fields_size, align));
+        if size == 0 {
+            return None;
+        }
+        Some(TypeLayout { size, align })
+    }
+
+    /// Returns the layout of a field of a Rust struct, where the smart
+    /// pointers are pointers and other opaque items have their Rust layout.
+    fn rust_type_layout(&self, ty: &Type) -> Option<TypeLayout> {
+        if let Type::Path(ref generic) = *ty {
+            match generic.name() {
+                "Box" | "Rc" | "Arc" if generic.generics().len() == 1 => {
+                    return Some(self.pointer());
+                }
+                _ => {}
+            }
+            if let Some(&&ItemContainer::OpaqueItem(ref o)) = self.items.get(generic.path()) {
+                return self.opaque_layout(o);
+            }
+        }
+        self.type_layout(ty)
+    }
+
+    /// Returns the layout of `s`, or `None` if it isn't known.
+    pub fn struct_layout(&self, s: &Struct) -> Option<StructLayout> {
+        // Empty structs have a size of zero in Rust but not in C++.
+        if s.is_generic() || s.bitfield_widths.is_some() || s.fields.is_empty() {
+            return None;
+        }
+
+        // Packing caps the alignment of the fields, and `align(N)` raises the
+        // alignment of the struct.
+        let (max_field_align, mut align) = match s.alignment {
+            Some(ReprAlign::Packed(n)) => (n as usize, 1),
+            Some(ReprAlign::Align(n)) => (usize::max_value(), n as usize),
+            None => (usize::max_value(), 1),
+        };
+        let mut offsets = Vec::new();
+        let mut size = 0;
+        for &(_, ref ty, _) in &s.fields {
+            let layout = self.type_layout(ty)?;
+            let field_align = layout.align.min(max_field_align);
+            size = round_up(size, field_align);
+            offsets.push(size);
+            size += layout.size;
+            align = align.max(field_align);
+        }
+
+        Some(StructLayout {
+            layout: TypeLayout {
+                size: round_up(size, align),
+                align,
+            },
+            offsets,
+        })
+    }
+
+    pub fn union_layout(&self, u: &Union) -> Option<TypeLayout> {
+        if u.is_generic() {
+            return None;
+        }
+
+        // Like for structs, packing caps the alignment of the fields, and
+        // `align(N)` raises the alignment of the union.
+        let (max_field_align, mut align) = match u.alignment {
+            Some(ReprAlign::Packed(n)) => (n as usize, 1),
+            Some(ReprAlign::Align(n)) => (usize::max_value(), n as usize),
+            None => (usize::max_value(), 1),
+        };
+        let mut size = 0;
+        for &(_, ref ty, _) in &u.fields {
+            let layout = self.type_layout(ty)?;
+            size = size.max(layout.size);
+            align = align.max(layout.align.min(max_field_align));
+        }
+        Some(TypeLayout {
+            size: round_up(size, align),
+            align,
+        })
+    }
+
+    fn enum_layout(&self, e: &Enum) -> Option<TypeLayout> {
+        if e.tag.is_none() {
+            return self.tag_layout(e);
+        }
+
+        // A union of the bodies, which start with the tag unless it's
+        // separate.
+        let mut size = 0;
+        let mut align = 1;
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                let layout = self.struct_layout(body)?.layout;
+                size = size.max(layout.size);
+                align = align.max(layout.align);
+            }
+        }
+        let bodies = TypeLayout {
+            size: round_up(size, align),
+            align,
+        };
+        if e.repr.style != ReprStyle::C {
+            return Some(bodies);
+        }
+
+        let tag = self.tag_layout(e)?;
+        let align = tag.align.max(bodies.align);
+        Some(TypeLayout {
+            size: round_up(round_up(tag.size, bodies.align) + bodies.size, align),
+            align,
+        })
+    }
+
+    /// Returns the layout of a fieldless enum, or of the tag of a tagged
+    /// enum, or `None` if it isn't known.
+    pub fn tag_layout(&self, e: &Enum) -> Option<TypeLayout> {
+        match e.repr.ty {
+            Some(ReprType::U8) | Some(ReprType::I8) => Some(TypeLayout::scalar(1)),
+            Some(ReprType::U16) | Some(ReprType::I16) => Some(TypeLayout::scalar(2)),
+            Some(ReprType::U32) | Some(ReprType::I32) => Some(TypeLayout::scalar(4)),
+            Some(ReprType::USize) | Some(ReprType::ISize) => Some(self.pointer()),
+            None if e.repr.style == ReprStyle::C => Some(TypeLayout::scalar(4)),
+            None => None,
+        }
+    }
+}
+
+fn round_up(value: usize, align: usize) -> usize {
+    (value + align - 1) / align * align
+}
@@ -5,12 +5,14 @@
 pub mod annotation;
 pub mod cfg;
 pub mod constant;
+pub mod consteval;
 pub mod documentation;
 pub mod enumeration;
 pub mod function;
 pub mod generic_path;
 pub mod global;
 pub mod item;
+pub mod layout;
 pub mod opaque;
 pub mod path;
 pub mod repr;
@@ -22,6 +24,7 @@ pub mod union;
 pub use self::annotation::{AnnotationSet, AnnotationValue};
 pub use self::cfg::*;
 pub use self::constant::*;
+pub use self::consteval::*;
 pub use self::documentation::Documentation;
 pub use self::enumeration::*;
 pub use self::function::*;
@@ -6,19 +6,20 @@ use std::io::Write;
 
 use syn;
 
-use bindgen::config::{Config, Language};
+use bindgen::config::{Config, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
+use bindgen::ir::layout::{TypeLayout, TypeLayouts};
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Documentation, Function, GenericParams, Item,
+    ItemContainer, Path, Repr, ReprAlign, ToCondition, Type,
 };
 use bindgen::library::Library;
 use bindgen::mangle;
 use bindgen::monomorph::Monomorphs;
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpaqueItem {
     pub path: Path,
     pub export_name: String,
@@ -26,6 +27,20 @@ pub struct OpaqueItem {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    /// The methods to generate C++ member functions for, with
+    /// `derive_methods`.
+    pub methods: Vec<Function>,
+    /// The functions to generate C++ operators for, with their names, with
+    /// `derive_operators`.
+    pub operators: Vec<(String, Function)>,
+    /// The types of the fields of the Rust struct it was declared as, if they
+    /// could be loaded, to compute its layout from.
+    #[serde(default)]
+    pub fields: Option<Vec<Type>>,
+    /// The layout of the storage to define it with, so that it can be
+    /// allocated by callers, with `layout.opaque_storage`.
+    #[serde(skip)]
+    pub storage: Option<TypeLayouts>,
 }
 
 impl OpaqueItem {
@@ -59,6 +74,104 @@ impl OpaqueItem {
             cfg,
             annotations,
             documentation,
+            methods: vec![],
+            operators: vec![],
+            fields: None,
+            storage: None,
+        }
+    }
+
+    /// Loads the types of the fields of a struct with the default Rust
+    /// representation, or `None` if it has another one or they can't all be
+    /// loaded.
+    pub fn load_fields(attrs: &[syn::Attribute], fields: &syn::Fields) -> Option<Vec<Type>> {
+        match Repr::load(attrs) {
+            Ok(Repr {
+                ty: None,
+                align: None,
+                ..
+            }) => {}
+            _ => return None,
+        }
+        let mut out = Vec::new();
+        for field in fields.iter() {
+            if let Some(ty) = Type::load(&field.ty).ok()? {
+                out.push(ty);
+            }
+        }
+        Some(out)
+    }
+
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        if let Some(ref mut fields) = self.fields {
+            for ty in fields {
+                ty.simplify_standard_types(config);
+            }
+        }
+    }
+
+    /// Writes the definition of the storage of the item, with `layout` or
+    /// only declared without one.
+    fn write_storage<F: Write>(
+        &self,
+        layout: Option<TypeLayout>,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let layout = match layout {
+            Some(layout) => layout,
+            None => {
+                if config.language == Language::C && config.style.generate_typedef() {
+                    write!(
+                        out,
+                        "typedef struct {} {};",
+                        self.export_name(),
+                        self.export_name()
+                    );
+                } else {
+                    write!(out, "struct {};", self.export_name());
+                }
+                return;
+            }
+        };
+
+        let typedef = config.language == Language::C && config.style.generate_typedef();
+        if typedef {
+            out.write("typedef ");
+        }
+        out.write("struct");
+        if layout.align > 1 {
+            if let Some(attribute) = config
+                .layout
+                .attribute(ReprAlign::Align(layout.align as u64))
+            {
+                write!(out, " {}", attribute);
+            }
+        }
+        if config.language == Language::Cxx || config.style.generate_tag() {
+            write!(out, " {}", self.export_name());
+        }
+        out.open_brace();
+        write!(out, "uint8_t _private[{}];", layout.size);
+        if config.language == Language::Cxx {
+            for method in &self.methods {
+                out.new_line();
+                if let Some(member) = method.member() {
+                    out.new_line();
+                    method.write_member_declaration(&member, false, config, out);
+                }
+            }
+            for &(ref operator, ref function) in &self.operators {
+                out.new_line();
+                out.new_line();
+                function.write_member_declaration(operator, true, config, out);
+            }
+        }
+        if typedef {
+            out.close_brace(false);
+            write!(out, " {};", self.export_name());
+        } else {
+            out.close_brace(true);
         }
     }
 }
@@ -76,6 +189,10 @@ impl Item for OpaqueItem {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -139,7 +256,70 @@ impl Source for OpaqueItem {
 
         self.generic_params.write(config, out);
 
-        if config.style.generate_typedef() && config.language == Language::C {
+        if let Some(ref storage) = self.storage {
+            let conditions = storage.conditions();
+            match conditions[..] {
+                [(None, layout)] => self.write_storage(Some(*layout), config, out),
+                _ => {
+                    let mut directive = "#if";
+                    for &(condition, layout) in &conditions {
+                        write!(out, "{} {}", directive, condition.unwrap());
+                        out.new_line();
+                        self.write_storage(Some(*layout), config, out);
+                        out.new_line();
+                        directive = "#elif";
+                    }
+                    out.write("#else");
+                    out.new_line();
+                    self.write_storage(None, config, out);
+                    out.new_line();
+                    out.write("#endif");
+                }
+            }
+        } else if config.language == Language::Cxx
+            && (!self.methods.is_empty() || !self.operators.is_empty())
+        {
+            // The members make it a complete type, so keep it from being
+            // created, copied or destroyed outside of the functions.
+            // Before C++11, that's by declaring them private without
+            // defining them.
+            let name = self.export_name();
+            let special_members = [
+                format!("{}()", name),
+                format!("{}(const {}&)", name, name),
+                format!("{}& operator=(const {}&)", name, name),
+                format!("~{}()", name),
+            ];
+            let cxx11 = config.has_version(LanguageVersion::Cxx11);
+            write!(out, "struct {}", name);
+            out.open_brace();
+            if cxx11 {
+                for member in &special_members {
+                    write!(out, "{} = delete;", member);
+                    out.new_line();
+                }
+            }
+            for method in &self.methods {
+                out.new_line();
+                if let Some(member) = method.member() {
+                    method.write_member_declaration(&member, false, config, out);
+                }
+            }
+            for &(ref operator, ref function) in &self.operators {
+                out.new_line();
+                function.write_member_declaration(operator, true, config, out);
+            }
+            if !cxx11 {
+                out.new_line();
+                out.new_line();
+                out.write("private:");
+                for member in &special_members {
+                    out.new_line();
+                    write!(out, "{};", member);
+                }
+            }
+            out.close_brace(true);
+        } else if config.style.generate_typedef() && config.language == Language::C {
             write!(
                 out,
                 "typedef struct {} {};",
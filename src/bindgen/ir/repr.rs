@@ -2,9 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::str::FromStr;
+
 use syn;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReprStyle {
     Rust,
     C,
@@ -17,7 +19,7 @@ impl Default for ReprStyle {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReprType {
     U8,
     U16,
@@ -29,26 +31,55 @@ pub enum ReprType {
     ISize,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+impl FromStr for ReprType {
+    type Err = String;
+
+    /// Parses the name of a Rust integer type, or of the C type it is
+    /// written as.
+    fn from_str(s: &str) -> Result<ReprType, Self::Err> {
+        match s {
+            "u8" | "uint8_t" => Ok(ReprType::U8),
+            "u16" | "uint16_t" => Ok(ReprType::U16),
+            "u32" | "uint32_t" => Ok(ReprType::U32),
+            "usize" | "uintptr_t" => Ok(ReprType::USize),
+            "i8" | "int8_t" => Ok(ReprType::I8),
+            "i16" | "int16_t" => Ok(ReprType::I16),
+            "i32" | "int32_t" => Ok(ReprType::I32),
+            "isize" | "intptr_t" => Ok(ReprType::ISize),
+            _ => Err(format!("Unrecognized enum underlying type: '{}'.", s)),
+        }
+    }
+}
+
+/// A change to the alignment of a type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReprAlign {
+    /// `#[repr(packed(N))]`, which lowers the alignment of the fields to `N`
+    /// bytes, and `#[repr(packed)]`, where `N` is 1.
+    Packed(u64),
+    /// `#[repr(align(N))]`, which raises the alignment of the type to `N`
+    /// bytes.
+    Align(u64),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Repr {
     pub style: ReprStyle,
     pub ty: Option<ReprType>,
+    pub align: Option<ReprAlign>,
 }
 
 impl Repr {
-    pub const C: Self = Repr {
-        style: ReprStyle::C,
-        ty: None,
-    };
-
     pub const TRANSPARENT: Self = Repr {
         style: ReprStyle::Transparent,
         ty: None,
+        align: None,
     };
 
     pub const RUST: Self = Repr {
         style: ReprStyle::Rust,
         ty: None,
+        align: None,
     };
 
     pub fn load(attrs: &[syn::Attribute]) -> Result<Repr, String> {
@@ -64,14 +95,27 @@ impl Repr {
                 }
                 None
             })
-            .flat_map(|nested| nested)
-            .filter_map(|meta| match meta {
-                syn::NestedMeta::Meta(syn::Meta::Word(ident)) => Some(ident.to_string()),
-                _ => None,
-            });
+            .flat_map(|nested| nested);
 
         let mut repr = Repr::default();
-        for id in ids {
+        for meta in ids {
+            let id = match meta {
+                syn::NestedMeta::Meta(syn::Meta::Word(ident)) => ident.to_string(),
+                syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { ident, nested, .. })) => {
+                    let value = match nested.into_iter().next() {
+                        Some(syn::NestedMeta::Literal(syn::Lit::Int(ref value))) => value.value(),
+                        _ => return Err(format!("Unsupported #[repr({}(...))].", ident)),
+                    };
+                    let align = match ident.to_string().as_ref() {
+                        "packed" => ReprAlign::Packed(value),
+                        "align" => ReprAlign::Align(value),
+                        _ => return Err(format!("Unsupported #[repr({}(...))].", ident)),
+                    };
+                    repr.set_align(align)?;
+                    continue;
+                }
+                _ => continue,
+            };
             let new_ty = match id.as_ref() {
                 "u8" => ReprType::U8,
                 "u16" => ReprType::U16,
@@ -89,6 +133,10 @@ impl Repr {
                     repr.style = ReprStyle::Transparent;
                     continue;
                 }
+                "packed" => {
+                    repr.set_align(ReprAlign::Packed(1))?;
+                    continue;
+                }
                 _ => {
                     return Err(format!("Unsupported #[repr({})].", id));
                 }
@@ -103,4 +151,21 @@ impl Repr {
         }
         Ok(repr)
     }
+
+    fn set_align(&mut self, align: ReprAlign) -> Result<(), String> {
+        let value = match align {
+            ReprAlign::Packed(value) | ReprAlign::Align(value) => value,
+        };
+        if !value.is_power_of_two() {
+            return Err(format!("Alignment {} isn't a power of two.", value));
+        }
+        if let Some(old_align) = self.align {
+            return Err(format!(
+                "Conflicting #[repr(...)] alignments {:?} and {:?}.",
+                old_align, align
+            ));
+        }
+        self.align = Some(align);
+        Ok(())
+    }
 }
@@ -6,22 +6,24 @@ use std::io::Write;
 
 use syn;
 
-use bindgen::config::{Config, Language};
+use bindgen::cdecl;
+use bindgen::config::{Config, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, GenericParams, Item,
-    ItemContainer, Path, Repr, ToCondition, Type, Typedef,
+    AnnotationSet, Cfg, ConditionWrite, ConstEvaluator, Constant, Documentation, ErasedTypes,
+    Function, GenericDefaults, GenericParams, Item, ItemContainer, Path, PrimitiveType, Repr,
+    ReprAlign, ReprStyle, ToCondition, Type, Typedef,
 };
 use bindgen::library::Library;
 use bindgen::mangle;
 use bindgen::monomorph::Monomorphs;
 use bindgen::rename::{IdentifierType, RenameRule};
 use bindgen::reserved;
-use bindgen::utilities::{find_first_some, IterHelpers};
+use bindgen::utilities::find_first_some;
 use bindgen::writer::{ListType, Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Struct {
     pub path: Path,
     pub export_name: String,
@@ -34,10 +36,38 @@ pub struct Struct {
     pub is_enum_variant_body: bool,
     pub is_transparent: bool,
     pub tuple_struct: bool,
+    /// The width of each field in bits, if the fields are bitfields.
+    pub bitfield_widths: Option<Vec<u32>>,
+    /// The lock guarding each field, if some fields have the `guarded-by`
+    /// annotation.
+    pub guarded_by: Option<Vec<Option<String>>>,
+    /// The default value of each field, if some fields have the `default`
+    /// annotation.
+    #[serde(default)]
+    pub field_defaults: Option<Vec<Option<String>>>,
+    /// Whether the struct is `#[repr(packed)]` or `#[repr(align(N))]`.
+    pub alignment: Option<ReprAlign>,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
     pub associated_constants: Vec<Constant>,
+    /// The methods to generate C++ member functions for, with
+    /// `derive_methods`.
+    pub methods: Vec<Function>,
+    /// The functions to generate C++ operators for, with their names, with
+    /// `derive_operators`.
+    pub operators: Vec<(String, Function)>,
+    /// Whether the struct is forward declared, so that it's defined with its
+    /// tag.
+    #[serde(default)]
+    pub forward_declared: bool,
+    /// The structs and unions written as anonymous members in place of the
+    /// fields of the same name, which have them as their types.
+    #[serde(skip)]
+    pub anonymous_members: Vec<(String, ItemContainer)>,
+    /// The names of the zero-sized fields that were elided.
+    #[serde(default)]
+    pub elided_fields: Vec<String>,
 }
 
 impl Struct {
@@ -51,30 +81,43 @@ impl Struct {
     }
 
     pub fn load(item: &syn::ItemStruct, mod_cfg: Option<&Cfg>) -> Result<Self, String> {
-        let is_transparent = match Repr::load(&item.attrs)? {
-            Repr::C => false,
+        let repr = Repr::load(&item.attrs)?;
+        let is_transparent = match repr {
+            Repr {
+                style: ReprStyle::C,
+                ty: None,
+                ..
+            } => false,
             Repr::TRANSPARENT => true,
             _ => {
                 return Err("Struct is not marked #[repr(C)] or #[repr(transparent)].".to_owned());
             }
         };
 
+        // Fields of zero-sized types like `PhantomData` aren't loaded.
+        let mut elided_fields = Vec::new();
         let (fields, tuple_struct) = match &item.fields {
             &syn::Fields::Unit => (Vec::new(), false),
             &syn::Fields::Named(ref fields) => {
-                let out = fields
-                    .named
-                    .iter()
-                    .try_skip_map(|x| x.as_ident_and_type())?;
+                let mut out = Vec::new();
+                for field in fields.named.iter() {
+                    match field.as_ident_and_type()? {
+                        Some(x) => out.push(x),
+                        None => elided_fields.push(field.ident.as_ref().unwrap().to_string()),
+                    }
+                }
                 (out, false)
             }
             &syn::Fields::Unnamed(ref fields) => {
                 let mut out = Vec::new();
                 let mut current = 0;
-                for field in fields.unnamed.iter() {
+                // The elided fields are listed by their Rust index.
+                for (i, field) in fields.unnamed.iter().enumerate() {
                     if let Some(x) = Type::load(&field.ty)? {
                         out.push((format!("{}", current), x, Documentation::load(&field.attrs)));
                         current += 1;
+                    } else {
+                        elided_fields.push(format!("{}", i));
                     }
                 }
                 (out, true)
@@ -83,8 +126,23 @@ impl Struct {
 
         let is_tagged = false;
         let is_enum_variant_body = false;
+        let mut annotations = AnnotationSet::load(&item.attrs)?;
+        annotations.load_field_annotations(&item.fields)?;
+        let bitfield_widths = load_bitfield_widths(&annotations, &fields)?;
+        let guarded_by = if annotations.field_guards.is_empty() {
+            None
+        } else {
+            let guards = &annotations.field_guards;
+            Some(fields.iter().map(|x| guards.get(&x.0).cloned()).collect())
+        };
+        let field_defaults = if annotations.field_defaults.is_empty() {
+            None
+        } else {
+            let defaults = &annotations.field_defaults;
+            Some(fields.iter().map(|x| defaults.get(&x.0).cloned()).collect())
+        };
 
-        Ok(Struct::new(
+        let mut s = Struct::new(
             Path::new(item.ident.to_string()),
             GenericParams::new(&item.generics),
             fields,
@@ -93,9 +151,15 @@ impl Struct {
             is_transparent,
             tuple_struct,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-            AnnotationSet::load(&item.attrs)?,
+            annotations,
             Documentation::load(&item.attrs),
-        ))
+        );
+        s.bitfield_widths = bitfield_widths;
+        s.guarded_by = guarded_by;
+        s.field_defaults = field_defaults;
+        s.alignment = repr.align;
+        s.elided_fields = elided_fields;
+        Ok(s)
     }
 
     pub fn new(
@@ -120,23 +184,210 @@ impl Struct {
             is_enum_variant_body,
             is_transparent,
             tuple_struct,
+            bitfield_widths: None,
+            guarded_by: None,
+            field_defaults: None,
+            alignment: None,
             cfg,
             annotations,
             documentation,
             associated_constants: vec![],
+            methods: vec![],
+            operators: vec![],
+            forward_declared: false,
+            anonymous_members: vec![],
+            elided_fields: vec![],
         }
     }
 
-    pub fn simplify_standard_types(&mut self) {
+    /// Removes the zero-sized fields at `indices`, in increasing order.
+    pub fn elide_fields(&mut self, indices: &[usize]) {
+        // Tuple fields are numbered without the elided ones, which are listed
+        // by their Rust index.
+        let names: Vec<_> = if self.tuple_struct {
+            let elided = &self.elided_fields;
+            let rust_indices: Vec<_> = (0..)
+                .map(|x: usize| x.to_string())
+                .filter(|x| !elided.contains(x))
+                .take(self.fields.len())
+                .collect();
+            indices.iter().map(|&i| rust_indices[i].clone()).collect()
+        } else {
+            indices.iter().map(|&i| self.fields[i].0.clone()).collect()
+        };
+        self.elided_fields.extend(names);
+        for &i in indices.iter().rev() {
+            self.fields.remove(i);
+            if let Some(ref mut widths) = self.bitfield_widths {
+                widths.remove(i);
+            }
+            if let Some(ref mut guards) = self.guarded_by {
+                guards.remove(i);
+            }
+            if let Some(ref mut defaults) = self.field_defaults {
+                defaults.remove(i);
+            }
+        }
+        if self.tuple_struct {
+            for (i, field) in self.fields.iter_mut().enumerate() {
+                field.0 = format!("{}", i);
+            }
+        }
+    }
+
+    /// Creates the struct a tuple with the given element types is lowered
+    /// into, with fields named `_0`, `_1`, ...
+    pub fn tuple(elems: &[Type]) -> Self {
+        Struct::new(
+            mangle::mangle_path(&Path::new("Tuple"), elems),
+            GenericParams::default(),
+            elems
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| (format!("{}", i), ty.clone(), Documentation::none()))
+                .collect(),
+            false,
+            false,
+            false,
+            true,
+            None,
+            AnnotationSet::new(),
+            Documentation::none(),
+        )
+    }
+
+    /// Creates the view struct a slice with the given element type is lowered
+    /// into, with a `ptr` to the elements and their `len`.
+    pub fn slice_view(elem: &Type, is_mut: bool, config: &Config) -> Self {
+        let (name, ptr) = if is_mut {
+            (
+                &config.slice.mut_name,
+                Type::Ptr(Box::new(elem.clone()), true),
+            )
+        } else {
+            (
+                &config.slice.name,
+                Type::ConstPtr(Box::new(elem.clone()), true),
+            )
+        };
+        Struct::new(
+            mangle::mangle_path(&Path::new(name.clone()), &[elem.clone()]),
+            GenericParams::default(),
+            vec![
+                ("ptr".to_owned(), ptr, Documentation::none()),
+                (
+                    "len".to_owned(),
+                    Type::Primitive(PrimitiveType::USize),
+                    Documentation::none(),
+                ),
+            ],
+            false,
+            false,
+            false,
+            false,
+            None,
+            AnnotationSet::new(),
+            Documentation::none(),
+        )
+    }
+
+    /// Creates the struct a 128-bit integer is lowered into, with its `lo`
+    /// and `hi` 64 bits in the order of little-endian targets.
+    pub fn int128(signed: bool, config: &Config) -> Self {
+        let (name, hi) = if signed {
+            (&config.int128.name, PrimitiveType::Int64)
+        } else {
+            (&config.int128.unsigned_name, PrimitiveType::UInt64)
+        };
+        Struct::new(
+            Path::new(name.clone()),
+            GenericParams::default(),
+            vec![
+                (
+                    "lo".to_owned(),
+                    Type::Primitive(PrimitiveType::UInt64),
+                    Documentation::none(),
+                ),
+                ("hi".to_owned(), Type::Primitive(hi), Documentation::none()),
+            ],
+            false,
+            false,
+            false,
+            false,
+            None,
+            AnnotationSet::new(),
+            Documentation::none(),
+        )
+    }
+
+    /// Writes the forward declaration of the struct, which is then defined with
+    /// its tag.
+    pub fn write_forward_declaration<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+        if config.language == Language::C && config.style.generate_typedef() {
+            write!(
+                out,
+                "typedef struct {} {};",
+                self.export_name(),
+                self.export_name()
+            );
+        } else {
+            write!(out, "struct {};", self.export_name());
+        }
+        condition.write_after(config, out);
+    }
+
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        for &mut (_, ref mut ty, ref mut documentation) in &mut self.fields {
+            if let Some(meaning) = ty.standard_type_note(config) {
+                documentation.doc_comment.push(meaning.to_owned());
+            }
+            ty.simplify_standard_types(config);
+        }
+    }
+
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.fill_generic_defaults(defaults);
+        }
+    }
+
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
         for &mut (_, ref mut ty, _) in &mut self.fields {
-            ty.simplify_standard_types();
+            ty.erase_transparent(erased);
         }
+        for constant in &mut self.associated_constants {
+            constant.ty.erase_transparent(erased);
+        }
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.lower_types(config, constants, out)?;
+        }
+        Ok(())
     }
 
     pub fn is_generic(&self) -> bool {
         self.generic_params.len() > 0
     }
 
+    /// Returns the field holding the bits of a struct generated by
+    /// `bitflags!` or annotated with `bitflags`, which must be its only field.
+    pub fn bitflags_field(&self, config: &Config) -> Option<&str> {
+        config.structure.bitflags(&self.annotations)?;
+        match self.fields.as_slice() {
+            [(ref name, _, _)] => Some(name),
+            _ => None,
+        }
+    }
+
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         // Generic structs can instantiate monomorphs only once they've been
         // instantiated. See `instantiate_monomorph` for more details.
@@ -157,7 +408,7 @@ impl Struct {
 
     pub fn specialize(&self, generic_values: &[Type], mappings: &[(&Path, &Type)]) -> Self {
         let mangled_path = mangle::mangle_path(&self.path, generic_values);
-        Struct::new(
+        let mut specialized = Struct::new(
             mangled_path,
             GenericParams::default(),
             self.fields
@@ -171,7 +422,13 @@ impl Struct {
             self.cfg.clone(),
             self.annotations.clone(),
             self.documentation.clone(),
-        )
+        );
+        specialized.bitfield_widths = self.bitfield_widths.clone();
+        specialized.guarded_by = self.guarded_by.clone();
+        specialized.field_defaults = self.field_defaults.clone();
+        specialized.alignment = self.alignment;
+        specialized.elided_fields = self.elided_fields.clone();
+        specialized
     }
 }
 
@@ -188,6 +445,10 @@ impl Item for Struct {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -214,7 +475,7 @@ impl Item for Struct {
 
     fn rename_for_config(&mut self, config: &Config) {
         // Rename the name of the struct
-        if !self.is_tagged || config.language == Language::C {
+        if !self.is_tagged || config.language != Language::Cxx {
             config.export.rename(&mut self.export_name);
         }
 
@@ -238,6 +499,12 @@ impl Item for Struct {
         // If any field is a reserved keyword, then postfix it with an
         // underscore.
 
+        let renames: Vec<_> = self
+            .fields
+            .iter()
+            .map(|x| self.annotations.field_renames.get(&x.0).cloned())
+            .collect();
+
         // Scope for mutable borrow of fields
         {
             let mut names = self.fields.iter_mut().map(|field| &mut field.0);
@@ -270,7 +537,17 @@ impl Item for Struct {
         }
 
         for field in &mut self.fields {
-            reserved::escape(&mut field.0);
+            config.keywords.escape(&mut field.0);
+            if config.objc_compat() {
+                reserved::escape_objc(&mut field.0);
+            }
+        }
+
+        // Fields renamed with `cbindgen:rename` keep their names as given.
+        for (field, rename) in self.fields.iter_mut().zip(renames) {
+            if let Some(rename) = rename {
+                field.0 = rename;
+            }
         }
 
         for c in self.associated_constants.iter_mut() {
@@ -329,6 +606,98 @@ impl Item for Struct {
     }
 }
 
+/// Loads the widths given by the `bitfield-widths` annotation, which can only
+/// be applied to integer fields.
+fn load_bitfield_widths(
+    annotations: &AnnotationSet,
+    fields: &[(String, Type, Documentation)],
+) -> Result<Option<Vec<u32>>, String> {
+    let widths = match annotations.list("bitfield-widths") {
+        Some(widths) => widths,
+        None => match annotations.atom("bitfield-widths") {
+            Some(Some(widths)) => widths.split(',').map(|x| x.trim().to_owned()).collect(),
+            _ => return Ok(None),
+        },
+    };
+
+    if widths.len() != fields.len() {
+        return Err(format!(
+            "Struct has {} fields but {} bitfield widths.",
+            fields.len(),
+            widths.len()
+        ));
+    }
+
+    let mut out = Vec::new();
+    for (width, &(ref name, ref ty, _)) in widths.iter().zip(fields) {
+        let max_width = match *ty {
+            Type::Primitive(ref primitive) => primitive.max_bitfield_width(),
+            _ => None,
+        };
+        let max_width = match max_width {
+            Some(max_width) => max_width,
+            None => return Err(format!("Bitfield {} is not an integer.", name)),
+        };
+        match width.parse::<u32>() {
+            Ok(width) if width > 0 && width <= max_width => out.push(width),
+            _ => {
+                return Err(format!(
+                    "Bitfield {} has invalid width {} (expected 1 to {}).",
+                    name, width, max_width
+                ));
+            }
+        }
+    }
+    Ok(Some(out))
+}
+
+/// Writes the fields of a struct or union, with the structs and unions of
+/// `anonymous` written as anonymous members in place of their fields.
+pub(crate) fn write_fields<F: Write>(
+    fields: &[(String, Type, Documentation)],
+    anonymous: &[(String, ItemContainer)],
+    config: &Config,
+    out: &mut SourceWriter<F>,
+) {
+    if anonymous.is_empty() {
+        if config.documentation {
+            out.write_vertical_source_list(fields, ListType::Cap(";"));
+        } else {
+            let vec: Vec<_> = fields
+                .iter()
+                .map(|&(ref name, ref ty, _)| (name.clone(), ty.clone()))
+                .collect();
+            out.write_vertical_source_list(&vec[..], ListType::Cap(";"));
+        }
+        return;
+    }
+
+    for (i, &(ref name, ref ty, ref documentation)) in fields.iter().enumerate() {
+        if i != 0 {
+            out.new_line();
+        }
+        if config.documentation {
+            documentation.write(config, out);
+        }
+        match anonymous.iter().find(|x| x.0 == *name) {
+            Some(&(_, ItemContainer::Struct(ref s))) => {
+                out.write("struct");
+                out.open_brace();
+                write_fields(&s.fields, &s.anonymous_members, config, out);
+                out.close_brace(false);
+            }
+            Some(&(_, ItemContainer::Union(ref u))) => {
+                out.write("union");
+                out.open_brace();
+                write_fields(&u.fields, &u.anonymous_members, config, out);
+                out.close_brace(false);
+            }
+            _ => cdecl::write_field(out, ty, name),
+        }
+        out.write(";");
+    }
+}
+
 impl Source for Struct {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if self.is_transparent {
@@ -352,6 +721,14 @@ impl Source for Struct {
         let condition = (&self.cfg).to_condition(config);
         condition.write_before(config, out);
 
+        let pack_pragma = self
+            .alignment
+            .and_then(|align| config.layout.pack_pragma(align));
+        if let Some(n) = pack_pragma {
+            write!(out, "#pragma pack(push, {})", n);
+            out.new_line();
+        }
+
         self.documentation.write(config, out);
 
         if !self.is_enum_variant_body {
@@ -365,33 +742,83 @@ impl Source for Struct {
         //   typedef struct {
         // C with Both as style:
         //   typedef struct Name {
-        if config.language == Language::C && config.style.generate_typedef() {
+        let typedef = config.language == Language::C
+            && config.style.generate_typedef()
+            && !self.forward_declared;
+        if typedef {
             out.write("typedef ");
         }
 
         out.write("struct");
 
         if self.annotations.must_use {
-            if let Some(ref anno) = config.structure.must_use {
+            if let Some(anno) = config.type_must_use(&config.structure.must_use) {
+                write!(out, " {}", anno)
+            }
+        }
+
+        if let Some(ref note) = self.annotations.deprecated {
+            let structure = &config.structure;
+            if let Some(anno) =
+                config.deprecated(note, &structure.deprecated, &structure.deprecated_with_note)
+            {
                 write!(out, " {}", anno)
             }
         }
 
-        if config.language == Language::Cxx || config.style.generate_tag() {
+        if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+            write!(out, " {}", attribute);
+        }
+
+        if let Some(attribute) = self
+            .alignment
+            .and_then(|align| config.layout.attribute(align))
+        {
+            write!(out, " {}", attribute);
+        }
+
+        if config.language == Language::Cxx || config.style.generate_tag() || self.forward_declared
+        {
             write!(out, " {}", self.export_name());
         }
 
         out.open_brace();
 
-        if config.documentation {
-            out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
-        } else {
+        // C++11 has default member initializers, other languages get a
+        // function returning the default value instead.
+        let field_defaults =
+            if config.language == Language::Cxx && config.has_version(LanguageVersion::Cxx11) {
+                self.field_defaults.as_ref()
+            } else {
+                None
+            };
+        if self.bitfield_widths.is_some() || self.guarded_by.is_some() || field_defaults.is_some() {
             let vec: Vec<_> = self
                 .fields
                 .iter()
-                .map(|&(ref name, ref ty, _)| (name.clone(), ty.clone()))
+                .enumerate()
+                .map(|(i, &(ref name, ref ty, ref documentation))| {
+                    let documentation = if config.documentation {
+                        documentation.clone()
+                    } else {
+                        Documentation::none()
+                    };
+                    let mut name = name.clone();
+                    if let Some(Some(ref lock)) = self.guarded_by.as_ref().map(|x| &x[i]) {
+                        name = format!("{} {}({})", name, config.thread_safety.guarded_by, lock);
+                    }
+                    if let Some(ref widths) = self.bitfield_widths {
+                        name = format!("{} : {}", name, widths[i]);
+                    }
+                    if let Some(Some(ref value)) = field_defaults.map(|x| &x[i]) {
+                        name = format!("{} = {}", name, value);
+                    }
+                    (name, ty.clone(), documentation)
+                })
                 .collect();
             out.write_vertical_source_list(&vec[..], ListType::Cap(";"));
+        } else {
+            write_fields(&self.fields, &self.anonymous_members, config, out);
         }
 
         if config.language == Language::Cxx {
@@ -443,11 +870,7 @@ impl Source for Struct {
                 String::from("other")
             };
 
-            if self
-                .annotations
-                .bool("internal-derive-bitflags")
-                .unwrap_or(false)
-            {
+            if let Some(bits) = self.bitflags_field(config) {
                 if !wrote_start_newline {
                     wrote_start_newline = true;
                     out.new_line();
@@ -455,7 +878,7 @@ impl Source for Struct {
                 out.new_line();
                 write!(out, "explicit operator bool() const");
                 out.open_brace();
-                write!(out, "return !!bits;");
+                write!(out, "return !!{};", bits);
                 out.close_brace(false);
 
                 out.new_line();
@@ -469,8 +892,8 @@ impl Source for Struct {
                 out.open_brace();
                 write!(
                     out,
-                    "return {{static_cast<decltype(bits)>(this->bits | {}.bits)}};",
-                    other
+                    "return {{static_cast<decltype({0})>(this->{0} | {1}.{0})}};",
+                    bits, other
                 );
                 out.close_brace(false);
 
@@ -499,8 +922,8 @@ impl Source for Struct {
                 out.open_brace();
                 write!(
                     out,
-                    "return {{static_cast<decltype(bits)>(this->bits & {}.bits)}};",
-                    other
+                    "return {{static_cast<decltype({0})>(this->{0} & {1}.{0})}};",
+                    bits, other
                 );
                 out.close_brace(false);
 
@@ -522,6 +945,12 @@ impl Source for Struct {
             let skip_fields = if self.is_tagged { 1 } else { 0 };
 
             let mut emit_op = |op, conjuc| {
+                // The operators of the traits it implements take precedence.
+                let name = format!("operator{}", op);
+                if self.operators.iter().any(|x| x.0 == name) {
+                    return;
+                }
+
                 if !wrote_start_newline {
                     wrote_start_newline = true;
                     out.new_line();
@@ -581,6 +1010,22 @@ impl Source for Struct {
             }
         }
 
+        if config.language == Language::Cxx {
+            if !self.methods.is_empty() || !self.operators.is_empty() {
+                out.new_line();
+            }
+            for method in &self.methods {
+                out.new_line();
+                if let Some(member) = method.member() {
+                    method.write_member_declaration(&member, false, config, out);
+                }
+            }
+            for &(ref operator, ref function) in &self.operators {
+                out.new_line();
+                function.write_member_declaration(operator, true, config, out);
+            }
+        }
+
         if let Some(body) = config.export.extra_body(&self.path) {
             out.write_raw_block(body);
         }
@@ -595,13 +1040,18 @@ impl Source for Struct {
             }
         }
 
-        if config.language == Language::C && config.style.generate_typedef() {
+        if typedef {
             out.close_brace(false);
             write!(out, " {};", self.export_name());
         } else {
             out.close_brace(true);
         }
 
+        if pack_pragma.is_some() {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         for constant in &self.associated_constants {
             out.new_line();
             constant.write(config, out, Some(self));
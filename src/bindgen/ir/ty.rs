@@ -4,6 +4,7 @@
 
 use std::fmt;
 use std::io::Write;
+use std::mem;
 
 use syn;
 
@@ -11,13 +12,16 @@ use bindgen::cdecl;
 use bindgen::config::Config;
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
-use bindgen::ir::{Documentation, GenericParams, GenericPath, Path};
+use bindgen::ir::{
+    CallingConvention, ConstEvaluator, Documentation, ErasedTypes, GenericDefaults, GenericParams,
+    GenericPath, Literal, Path, Struct,
+};
 use bindgen::library::Library;
 use bindgen::monomorph::Monomorphs;
-use bindgen::utilities::IterHelpers;
+use bindgen::utilities::{IterHelpers, SynAbiHelpers};
 use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PrimitiveType {
     Void,
     Bool,
@@ -43,6 +47,8 @@ pub enum PrimitiveType {
     Int16,
     Int32,
     Int64,
+    Int128,
+    UInt128,
     Float,
     Double,
     SizeT,
@@ -51,6 +57,52 @@ pub enum PrimitiveType {
     VaList,
 }
 
+/// The standard non-zero integer types, and the integers they're laid out as.
+/// `Option`s of them use zero for `None`, so they're laid out as the same
+/// integers.
+const NON_ZERO_TYPES: &[(&str, PrimitiveType)] = &[
+    ("NonZeroU8", PrimitiveType::UInt8),
+    ("NonZeroU16", PrimitiveType::UInt16),
+    ("NonZeroU32", PrimitiveType::UInt32),
+    ("NonZeroU64", PrimitiveType::UInt64),
+    ("NonZeroUsize", PrimitiveType::USize),
+    ("NonZeroI8", PrimitiveType::Int8),
+    ("NonZeroI16", PrimitiveType::Int16),
+    ("NonZeroI32", PrimitiveType::Int32),
+    ("NonZeroI64", PrimitiveType::Int64),
+    ("NonZeroIsize", PrimitiveType::ISize),
+];
+
+fn non_zero_primitive(name: &str) -> Option<PrimitiveType> {
+    NON_ZERO_TYPES
+        .iter()
+        .find(|&&(non_zero, _)| non_zero == name)
+        .map(|&(_, ref prim)| prim.clone())
+}
+
+/// The standard atomic types other than `AtomicPtr`, and the types of their
+/// values.
+const ATOMIC_TYPES: &[(&str, PrimitiveType)] = &[
+    ("AtomicBool", PrimitiveType::Bool),
+    ("AtomicU8", PrimitiveType::UInt8),
+    ("AtomicU16", PrimitiveType::UInt16),
+    ("AtomicU32", PrimitiveType::UInt32),
+    ("AtomicU64", PrimitiveType::UInt64),
+    ("AtomicUsize", PrimitiveType::USize),
+    ("AtomicI8", PrimitiveType::Int8),
+    ("AtomicI16", PrimitiveType::Int16),
+    ("AtomicI32", PrimitiveType::Int32),
+    ("AtomicI64", PrimitiveType::Int64),
+    ("AtomicIsize", PrimitiveType::ISize),
+];
+
+fn atomic_primitive(name: &str) -> Option<PrimitiveType> {
+    ATOMIC_TYPES
+        .iter()
+        .find(|&&(atomic, _)| atomic == name)
+        .map(|&(_, ref prim)| prim.clone())
+}
+
 impl PrimitiveType {
     pub fn maybe(path: &str) -> Option<PrimitiveType> {
         match path {
@@ -80,6 +132,8 @@ impl PrimitiveType {
             "i16" | "int16_t" => Some(PrimitiveType::Int16),
             "i32" | "int32_t" => Some(PrimitiveType::Int32),
             "i64" | "int64_t" => Some(PrimitiveType::Int64),
+            "i128" => Some(PrimitiveType::Int128),
+            "u128" => Some(PrimitiveType::UInt128),
             "f32" => Some(PrimitiveType::Float),
             "f64" => Some(PrimitiveType::Double),
             "size_t" => Some(PrimitiveType::SizeT),
@@ -116,6 +170,8 @@ impl PrimitiveType {
             &PrimitiveType::Int16 => "i16",
             &PrimitiveType::Int32 => "i32",
             &PrimitiveType::Int64 => "i64",
+            &PrimitiveType::Int128 => "i128",
+            &PrimitiveType::UInt128 => "u128",
             &PrimitiveType::Float => "f32",
             &PrimitiveType::Double => "f64",
             &PrimitiveType::SizeT => "size_t",
@@ -151,6 +207,8 @@ impl PrimitiveType {
             &PrimitiveType::Int16 => "int16_t",
             &PrimitiveType::Int32 => "int32_t",
             &PrimitiveType::Int64 => "int64_t",
+            &PrimitiveType::Int128 => "__int128",
+            &PrimitiveType::UInt128 => "unsigned __int128",
             &PrimitiveType::Float => "float",
             &PrimitiveType::Double => "double",
             &PrimitiveType::SizeT => "size_t",
@@ -160,6 +218,49 @@ impl PrimitiveType {
         }
     }
 
+    /// The widest bitfield this type can hold, or `None` if it can't be used
+    /// for bitfields. Platform-dependent types are allowed their widest size.
+    pub fn max_bitfield_width(&self) -> Option<u32> {
+        match self {
+            &PrimitiveType::Bool => Some(1),
+            &PrimitiveType::Char
+            | &PrimitiveType::SChar
+            | &PrimitiveType::UChar
+            | &PrimitiveType::UInt8
+            | &PrimitiveType::Int8 => Some(8),
+            &PrimitiveType::Short
+            | &PrimitiveType::UShort
+            | &PrimitiveType::UInt16
+            | &PrimitiveType::Int16 => Some(16),
+            &PrimitiveType::Int
+            | &PrimitiveType::UInt
+            | &PrimitiveType::UInt32
+            | &PrimitiveType::Int32 => Some(32),
+            &PrimitiveType::Long
+            | &PrimitiveType::LongLong
+            | &PrimitiveType::ULong
+            | &PrimitiveType::ULongLong
+            | &PrimitiveType::USize
+            | &PrimitiveType::UInt64
+            | &PrimitiveType::ISize
+            | &PrimitiveType::Int64
+            | &PrimitiveType::SizeT
+            | &PrimitiveType::SSizeT
+            | &PrimitiveType::PtrDiffT => Some(64),
+            &PrimitiveType::Int128 | &PrimitiveType::UInt128 => Some(128),
+            &PrimitiveType::Void
+            | &PrimitiveType::WChar
+            | &PrimitiveType::Float
+            | &PrimitiveType::Double
+            | &PrimitiveType::VaList => None,
+        }
+    }
+
+    /// Whether this is an integer type, which can underlie an enum.
+    pub fn is_integer(&self) -> bool {
+        *self != PrimitiveType::Bool && self.max_bitfield_width().is_some()
+    }
+
     fn can_cmp_order(&self) -> bool {
         match self {
             &PrimitiveType::Bool => false,
@@ -179,42 +280,73 @@ impl fmt::Display for PrimitiveType {
 }
 
 // The `U` part of `[T; U]`
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ArrayLength {
     Name(String),
     Value(String),
+    /// An expression, which is evaluated when lowering types.
+    Expr(Literal),
 }
 
 impl ArrayLength {
     pub fn as_str(&self) -> &str {
         match self {
             ArrayLength::Name(ref string) | ArrayLength::Value(ref string) => string,
+            ArrayLength::Expr(..) => {
+                unreachable!("Array lengths are evaluated when lowering types.")
+            }
         }
     }
 
     fn rename_for_config(&mut self, config: &Config) {
         if let ArrayLength::Name(ref mut name) = self {
-            config.export.rename(name);
+            config.export.rename_constant(name);
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Type {
-    ConstPtr(Box<Type>),
-    Ptr(Box<Type>),
+    /// A pointer to constant data, and whether the pointer may be null.
+    ConstPtr(Box<Type>, bool),
+    /// A pointer to mutable data, and whether the pointer may be null.
+    Ptr(Box<Type>, bool),
     Ref(Box<Type>),
     MutRef(Box<Type>),
     Path(GenericPath),
     Primitive(PrimitiveType),
     Array(Box<Type>, ArrayLength),
-    FuncPtr(Box<Type>, Vec<(Option<String>, Type)>),
+    /// A function pointer, whether the pointer may be null, the calling
+    /// convention of the function, and whether it's variadic.
+    FuncPtr(
+        Box<Type>,
+        Vec<(Option<String>, Type)>,
+        bool,
+        CallingConvention,
+        bool,
+    ),
+    /// A slice of constant data, which is lowered into a generated view struct
+    /// before writing.
+    ConstSlice(Box<Type>),
+    /// A slice of mutable data, which is lowered into a generated view struct
+    /// before writing.
+    Slice(Box<Type>),
+    /// A tuple, which is lowered into a generated `Tuple_*` struct before
+    /// writing.
+    Tuple(Vec<Type>),
+    /// An atomic value, like `AtomicU32`, which is lowered into the type of
+    /// the value where the bindings can't use atomics.
+    Atomic(Box<Type>),
 }
 
 impl Type {
     pub fn load(ty: &syn::Type) -> Result<Option<Type>, String> {
         let converted = match ty {
             &syn::Type::Reference(ref reference) => {
+                if let syn::Type::Slice(ref slice) = *reference.elem {
+                    return Type::load_slice(&slice.elem, reference.mutability.is_some());
+                }
+
                 let converted = Type::load(&reference.elem)?;
 
                 let converted = match converted {
@@ -227,11 +359,15 @@ impl Type {
                 };
 
                 match reference.mutability {
-                    Some(_) => Type::Ptr(Box::new(converted)),
-                    None => Type::ConstPtr(Box::new(converted)),
+                    Some(_) => Type::Ptr(Box::new(converted), false),
+                    None => Type::ConstPtr(Box::new(converted), false),
                 }
             }
             &syn::Type::Ptr(ref pointer) => {
+                if let syn::Type::Slice(ref slice) = *pointer.elem {
+                    return Type::load_slice(&slice.elem, pointer.mutability.is_some());
+                }
+
                 let converted = Type::load(&pointer.elem)?;
 
                 let converted = match converted {
@@ -244,8 +380,8 @@ impl Type {
                 };
 
                 match pointer.mutability {
-                    Some(_) => Type::Ptr(Box::new(converted)),
-                    None => Type::ConstPtr(Box::new(converted)),
+                    Some(_) => Type::Ptr(Box::new(converted), true),
+                    None => Type::ConstPtr(Box::new(converted), true),
                 }
             }
             &syn::Type::Path(ref path) => {
@@ -299,6 +435,19 @@ impl Type {
                 // panic!("panic -> value: {:?}", len);
                 Type::Array(Box::new(converted), len)
             }
+            &syn::Type::Array(syn::TypeArray {
+                ref elem, ref len, ..
+            }) => {
+                let converted = Type::load(elem)?;
+
+                let converted = match converted {
+                    Some(converted) => converted,
+                    None => return Err("Cannot have an array of zero sized types.".to_owned()),
+                };
+
+                let len = ArrayLength::Expr(Literal::load(len)?);
+                Type::Array(Box::new(converted), len)
+            }
             &syn::Type::BareFn(ref function) => {
                 let mut wildcard_counter = 0;
                 let args = function.inputs.iter().try_skip_map(|x| {
@@ -332,24 +481,66 @@ impl Type {
                     }
                 };
 
-                Type::FuncPtr(Box::new(ret), args)
+                // Function pointers with other ABIs are treated as `extern "C"`.
+                let calling_convention = function
+                    .abi
+                    .calling_convention()
+                    .unwrap_or(CallingConvention::C);
+                Type::FuncPtr(
+                    Box::new(ret),
+                    args,
+                    false,
+                    calling_convention,
+                    function.variadic.is_some(),
+                )
             }
             &syn::Type::Tuple(ref tuple) => {
                 if tuple.elems.len() == 0 {
                     return Ok(None);
                 }
-                return Err("Tuples are not supported types.".to_owned());
+                let elems = tuple.elems.iter().try_skip_map(|x| Type::load(x))?;
+                if elems.is_empty() {
+                    return Ok(None);
+                }
+                Type::Tuple(elems)
             }
+            // `!` has no values, so it's as zero sized as `()`.
+            &syn::Type::Never(_) => return Ok(None),
             _ => return Err(format!("Unsupported type: {:?}", ty)),
         };
 
         return Ok(Some(converted));
     }
 
+    fn load_slice(elem: &syn::Type, is_mut: bool) -> Result<Option<Type>, String> {
+        let converted = match Type::load(elem)? {
+            Some(converted) => converted,
+            None => return Err("Cannot have a slice of zero sized types.".to_owned()),
+        };
+
+        if is_mut {
+            Ok(Some(Type::Slice(Box::new(converted))))
+        } else {
+            Ok(Some(Type::ConstSlice(Box::new(converted))))
+        }
+    }
+
+    /// Whether the type is a `&str`, which has no C equivalent other than as
+    /// the type of a string constant.
+    pub fn is_str_ref(&self) -> bool {
+        match *self {
+            Type::ConstPtr(ref x, _) => match **x {
+                Type::Path(ref path) => path.name() == "str" && path.generics().is_empty(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
     pub fn is_primitive_or_ptr_primitive(&self) -> bool {
         match self {
             &Type::Primitive(..) => true,
-            &Type::ConstPtr(ref x) => match x.as_ref() {
+            &Type::ConstPtr(ref x, _) => match x.as_ref() {
                 &Type::Primitive(..) => true,
                 _ => false,
             },
@@ -366,31 +557,155 @@ impl Type {
         }
     }
 
-    fn simplified_type(&self) -> Option<Self> {
+    fn make_nullable(&mut self) {
+        match *self {
+            Type::ConstPtr(_, ref mut is_nullable)
+            | Type::Ptr(_, ref mut is_nullable)
+            | Type::FuncPtr(_, _, ref mut is_nullable, _, _) => *is_nullable = true,
+            _ => {}
+        }
+    }
+
+    fn simplified_type(&self, config: &Config) -> Option<Self> {
         let path = match *self {
             Type::Path(ref p) => p,
             _ => return None,
         };
 
+        if let Some(prim) = non_zero_primitive(path.name()) {
+            return Some(Type::Primitive(prim));
+        }
+        if let Some(prim) = atomic_primitive(path.name()) {
+            return Some(Type::Atomic(Box::new(Type::Primitive(prim))));
+        }
+
         if path.generics().len() != 1 {
             return None;
         }
 
         let mut generic = path.generics()[0].clone();
-        generic.simplify_standard_types();
+        generic.simplify_standard_types(config);
+
+        if config.export.is_transparent_wrapper(path.name()) {
+            return Some(generic);
+        }
+        if config.export.is_pointer_wrapper(path.name()) {
+            return Some(Type::Ptr(Box::new(generic), false));
+        }
 
         match path.name() {
             // FIXME(#223): This is not quite correct.
-            "Option" if generic.is_repr_ptr() => Some(generic),
-            "NonNull" => Some(Type::Ptr(Box::new(generic))),
+            "Option" if generic.is_repr_ptr() => {
+                generic.make_nullable();
+                Some(generic)
+            }
+            // `None` is zero, which the non-zero integers never are.
+            "Option" if path.generics()[0].is_non_zero() => Some(generic),
+            "Box" if config.export.lower_box => Some(Type::Ptr(Box::new(generic), false)),
+            "AtomicPtr" => Some(Type::Atomic(Box::new(Type::Ptr(Box::new(generic), true)))),
+            _ => None,
+        }
+    }
+
+    /// Whether this is one of the standard non-zero integer types.
+    fn is_non_zero(&self) -> bool {
+        match *self {
+            Type::Path(ref path) => non_zero_primitive(path.name()).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Describes what the standard type this is simplified into meant, for
+    /// the documentation of the field, argument or item it's the type of.
+    pub fn standard_type_note(&self, config: &Config) -> Option<String> {
+        if let Some(meaning) = self.zero_meaning() {
+            return Some(meaning.to_owned());
+        }
+        if config.export.lower_box {
+            if let Some(ownership) = self.box_ownership() {
+                return Some(ownership.to_owned());
+            }
+        }
+        match *self {
+            Type::Path(ref path)
+                if config.export.wrapper_comments
+                    && path.generics().len() == 1
+                    && (config.export.is_transparent_wrapper(path.name())
+                        || config.export.is_pointer_wrapper(path.name())) =>
+            {
+                Some(format!("Wrapped in `{}`.", path.name()))
+            }
             _ => None,
         }
     }
 
-    pub fn simplify_standard_types(&mut self) {
-        if let Some(ty) = self.simplified_type() {
+    /// Describes the ownership of the pointers that `Box`es and `Option`s of
+    /// them are simplified into.
+    fn box_ownership(&self) -> Option<&'static str> {
+        let path = match *self {
+            Type::Path(ref path) if path.generics().len() == 1 => path,
+            _ => return None,
+        };
+        match path.name() {
+            "Box" => Some("Owned `Box`."),
+            "Option" if path.generics()[0].box_ownership() == Some("Owned `Box`.") => {
+                Some("Owned `Box`, or null for `None`.")
+            }
+            _ => None,
+        }
+    }
+
+    /// Describes what zero means for the integers that non-zero integers and
+    /// `Option`s of them are simplified into.
+    fn zero_meaning(&self) -> Option<&'static str> {
+        if self.is_non_zero() {
+            return Some("Never `0`.");
+        }
+        match *self {
+            Type::Path(ref path)
+                if path.name() == "Option"
+                    && path.generics().len() == 1
+                    && path.generics()[0].is_non_zero() =>
+            {
+                Some("`0` means `None`.")
+            }
+            _ => None,
+        }
+    }
+
+    /// Replaces the standard types that have a C representation with it,
+    /// including in the types this one is made of.
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        if let Some(ty) = self.simplified_type(config) {
             *self = ty;
         }
+        match *self {
+            Type::ConstPtr(ref mut ty, _)
+            | Type::Ptr(ref mut ty, _)
+            | Type::Ref(ref mut ty)
+            | Type::MutRef(ref mut ty)
+            | Type::Array(ref mut ty, _)
+            | Type::ConstSlice(ref mut ty)
+            | Type::Slice(ref mut ty)
+            | Type::Atomic(ref mut ty) => ty.simplify_standard_types(config),
+            Type::Path(ref mut generic_path) => {
+                for generic in generic_path.generics_mut() {
+                    generic.simplify_standard_types(config);
+                }
+            }
+            Type::Primitive(_) => {}
+            Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
+                ret.simplify_standard_types(config);
+                for (_, ref mut arg) in args {
+                    arg.simplify_standard_types(config);
+                }
+            }
+            Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.simplify_standard_types(config);
+                }
+            }
+        }
     }
 
     pub fn replace_self_with(&mut self, self_ty: &Path) {
@@ -398,18 +713,26 @@ impl Type {
             Type::Array(ref mut ty, ..)
             | Type::MutRef(ref mut ty)
             | Type::Ref(ref mut ty)
-            | Type::Ptr(ref mut ty)
-            | Type::ConstPtr(ref mut ty) => ty.replace_self_with(self_ty),
+            | Type::Ptr(ref mut ty, _)
+            | Type::ConstPtr(ref mut ty, _)
+            | Type::ConstSlice(ref mut ty)
+            | Type::Slice(ref mut ty)
+            | Type::Atomic(ref mut ty) => ty.replace_self_with(self_ty),
             Type::Path(ref mut generic_path) => {
                 generic_path.replace_self_with(self_ty);
             }
             Type::Primitive(..) => {}
-            Type::FuncPtr(ref mut ret, ref mut args) => {
+            Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
                 ret.replace_self_with(self_ty);
                 for arg in args {
                     arg.1.replace_self_with(self_ty);
                 }
             }
+            Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.replace_self_with(self_ty);
+                }
+            }
         }
     }
 
@@ -417,10 +740,11 @@ impl Type {
         let mut current = self;
         loop {
             match *current {
-                Type::ConstPtr(ref ty) => current = ty,
-                Type::Ptr(ref ty) => current = ty,
+                Type::ConstPtr(ref ty, _) => current = ty,
+                Type::Ptr(ref ty, _) => current = ty,
                 Type::Ref(ref ty) => current = ty,
                 Type::MutRef(ref ty) => current = ty,
+                Type::Atomic(ref ty) => current = ty,
                 Type::Path(ref generic) => {
                     return Some(generic.path().clone());
                 }
@@ -433,14 +757,44 @@ impl Type {
                 Type::FuncPtr(..) => {
                     return None;
                 }
+                Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) => {
+                    return None;
+                }
             };
         }
     }
 
+    /// Whether the type refers to the item at `path`, directly or through
+    /// pointers, arrays, function pointers or generic arguments.
+    pub fn mentions(&self, path: &Path) -> bool {
+        match *self {
+            Type::ConstPtr(ref ty, _)
+            | Type::Ptr(ref ty, _)
+            | Type::Ref(ref ty)
+            | Type::MutRef(ref ty)
+            | Type::Array(ref ty, _)
+            | Type::ConstSlice(ref ty)
+            | Type::Slice(ref ty)
+            | Type::Atomic(ref ty) => ty.mentions(path),
+            Type::Path(ref generic) => {
+                generic.path() == path || generic.generics().iter().any(|x| x.mentions(path))
+            }
+            Type::Primitive(..) => false,
+            Type::FuncPtr(ref ret, ref args, ..) => {
+                ret.mentions(path) || args.iter().any(|x| x.1.mentions(path))
+            }
+            Type::Tuple(ref elems) => elems.iter().any(|x| x.mentions(path)),
+        }
+    }
+
     pub fn specialize(&self, mappings: &[(&Path, &Type)]) -> Type {
         match self {
-            &Type::ConstPtr(ref ty) => Type::ConstPtr(Box::new(ty.specialize(mappings))),
-            &Type::Ptr(ref ty) => Type::Ptr(Box::new(ty.specialize(mappings))),
+            &Type::ConstPtr(ref ty, is_nullable) => {
+                Type::ConstPtr(Box::new(ty.specialize(mappings)), is_nullable)
+            }
+            &Type::Ptr(ref ty, is_nullable) => {
+                Type::Ptr(Box::new(ty.specialize(mappings)), is_nullable)
+            }
             &Type::Ref(ref ty) => Type::Ref(Box::new(ty.specialize(mappings))),
             &Type::MutRef(ref ty) => Type::MutRef(Box::new(ty.specialize(mappings))),
             &Type::Path(ref generic_path) => {
@@ -464,13 +818,24 @@ impl Type {
             &Type::Array(ref ty, ref constant) => {
                 Type::Array(Box::new(ty.specialize(mappings)), constant.clone())
             }
-            &Type::FuncPtr(ref ret, ref args) => Type::FuncPtr(
-                Box::new(ret.specialize(mappings)),
-                args.iter()
-                    .cloned()
-                    .map(|(name, ty)| (name, ty.specialize(mappings)))
-                    .collect(),
-            ),
+            &Type::FuncPtr(ref ret, ref args, is_nullable, calling_convention, variadic) => {
+                Type::FuncPtr(
+                    Box::new(ret.specialize(mappings)),
+                    args.iter()
+                        .cloned()
+                        .map(|(name, ty)| (name, ty.specialize(mappings)))
+                        .collect(),
+                    is_nullable,
+                    calling_convention,
+                    variadic,
+                )
+            }
+            &Type::ConstSlice(ref ty) => Type::ConstSlice(Box::new(ty.specialize(mappings))),
+            &Type::Slice(ref ty) => Type::Slice(Box::new(ty.specialize(mappings))),
+            &Type::Atomic(ref ty) => Type::Atomic(Box::new(ty.specialize(mappings))),
+            &Type::Tuple(ref elems) => {
+                Type::Tuple(elems.iter().map(|x| x.specialize(mappings)).collect())
+            }
         }
     }
 
@@ -481,10 +846,10 @@ impl Type {
         out: &mut Dependencies,
     ) {
         match self {
-            &Type::ConstPtr(ref ty) => {
+            &Type::ConstPtr(ref ty, _) => {
                 ty.add_dependencies_ignoring_generics(generic_params, library, out);
             }
-            &Type::Ptr(ref ty) => {
+            &Type::Ptr(ref ty, _) => {
                 ty.add_dependencies_ignoring_generics(generic_params, library, out);
             }
             &Type::Ref(ref ty) | &Type::MutRef(ref ty) => {
@@ -500,19 +865,17 @@ impl Type {
                         if !out.items.contains(path) {
                             out.items.insert(path.clone());
 
-                            for item in &items {
-                                item.deref().add_dependencies(library, out);
+                            if out.transitive {
+                                for item in &items {
+                                    item.deref().add_dependencies(library, out);
+                                }
                             }
                             for item in items {
                                 out.order.push(item);
                             }
                         }
-                    } else {
-                        warn!(
-                            "Can't find {}. This usually means that this type was incompatible or \
-                             not found.",
-                            path
-                        );
+                    } else if !out.unknown.contains(path) {
+                        out.unknown.push(path.clone());
                     }
                 }
             }
@@ -520,12 +883,20 @@ impl Type {
             &Type::Array(ref ty, _) => {
                 ty.add_dependencies_ignoring_generics(generic_params, library, out);
             }
-            &Type::FuncPtr(ref ret, ref args) => {
+            &Type::FuncPtr(ref ret, ref args, _, _, _) => {
                 ret.add_dependencies_ignoring_generics(generic_params, library, out);
                 for (_, ref arg) in args {
                     arg.add_dependencies_ignoring_generics(generic_params, library, out);
                 }
             }
+            &Type::ConstSlice(ref ty) | &Type::Slice(ref ty) | &Type::Atomic(ref ty) => {
+                ty.add_dependencies_ignoring_generics(generic_params, library, out);
+            }
+            &Type::Tuple(ref elems) => {
+                for elem in elems {
+                    elem.add_dependencies_ignoring_generics(generic_params, library, out);
+                }
+            }
         }
     }
 
@@ -535,10 +906,10 @@ impl Type {
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         match self {
-            &Type::ConstPtr(ref ty) => {
+            &Type::ConstPtr(ref ty, _) => {
                 ty.add_monomorphs(library, out);
             }
-            &Type::Ptr(ref ty) => {
+            &Type::Ptr(ref ty, _) => {
                 ty.add_monomorphs(library, out);
             }
             &Type::Ref(ref ty) | &Type::MutRef(ref ty) => {
@@ -560,21 +931,29 @@ impl Type {
             &Type::Array(ref ty, _) => {
                 ty.add_monomorphs(library, out);
             }
-            &Type::FuncPtr(ref ret, ref args) => {
+            &Type::FuncPtr(ref ret, ref args, _, _, _) => {
                 ret.add_monomorphs(library, out);
                 for (_, ref arg) in args {
                     arg.add_monomorphs(library, out);
                 }
             }
+            &Type::ConstSlice(ref ty) | &Type::Slice(ref ty) | &Type::Atomic(ref ty) => {
+                ty.add_monomorphs(library, out);
+            }
+            &Type::Tuple(ref elems) => {
+                for elem in elems {
+                    elem.add_monomorphs(library, out);
+                }
+            }
         }
     }
 
     pub fn rename_for_config(&mut self, config: &Config, generic_params: &GenericParams) {
         match self {
-            &mut Type::ConstPtr(ref mut ty) => {
+            &mut Type::ConstPtr(ref mut ty, _) => {
                 ty.rename_for_config(config, generic_params);
             }
-            &mut Type::Ptr(ref mut ty) => {
+            &mut Type::Ptr(ref mut ty, _) => {
                 ty.rename_for_config(config, generic_params);
             }
             &mut Type::Ref(ref mut ty) | &mut Type::MutRef(ref mut ty) => {
@@ -588,21 +967,31 @@ impl Type {
                 ty.rename_for_config(config, generic_params);
                 len.rename_for_config(config);
             }
-            &mut Type::FuncPtr(ref mut ret, ref mut args) => {
+            &mut Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
                 ret.rename_for_config(config, generic_params);
                 for (_, arg) in args {
                     arg.rename_for_config(config, generic_params);
                 }
             }
+            &mut Type::ConstSlice(ref mut ty)
+            | &mut Type::Slice(ref mut ty)
+            | &mut Type::Atomic(ref mut ty) => {
+                ty.rename_for_config(config, generic_params);
+            }
+            &mut Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.rename_for_config(config, generic_params);
+                }
+            }
         }
     }
 
     pub fn resolve_declaration_types(&mut self, resolver: &DeclarationTypeResolver) {
         match self {
-            &mut Type::ConstPtr(ref mut ty) => {
+            &mut Type::ConstPtr(ref mut ty, _) => {
                 ty.resolve_declaration_types(resolver);
             }
-            &mut Type::Ptr(ref mut ty) => {
+            &mut Type::Ptr(ref mut ty, _) => {
                 ty.resolve_declaration_types(resolver);
             }
             &mut Type::Ref(ref mut ty) | &mut Type::MutRef(ref mut ty) => {
@@ -615,21 +1004,116 @@ impl Type {
             &mut Type::Array(ref mut ty, _) => {
                 ty.resolve_declaration_types(resolver);
             }
-            &mut Type::FuncPtr(ref mut ret, ref mut args) => {
+            &mut Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
                 ret.resolve_declaration_types(resolver);
                 for (_, ref mut arg) in args {
                     arg.resolve_declaration_types(resolver);
                 }
             }
+            &mut Type::ConstSlice(ref mut ty)
+            | &mut Type::Slice(ref mut ty)
+            | &mut Type::Atomic(ref mut ty) => {
+                ty.resolve_declaration_types(resolver);
+            }
+            &mut Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.resolve_declaration_types(resolver);
+                }
+            }
+        }
+    }
+
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        match self {
+            &mut Type::ConstPtr(ref mut ty, _) => {
+                ty.fill_generic_defaults(defaults);
+            }
+            &mut Type::Ptr(ref mut ty, _) => {
+                ty.fill_generic_defaults(defaults);
+            }
+            &mut Type::Ref(ref mut ty) | &mut Type::MutRef(ref mut ty) => {
+                ty.fill_generic_defaults(defaults);
+            }
+            &mut Type::Path(ref mut generic_path) => {
+                generic_path.fill_generic_defaults(defaults);
+            }
+            &mut Type::Primitive(_) => {}
+            &mut Type::Array(ref mut ty, _) => {
+                ty.fill_generic_defaults(defaults);
+            }
+            &mut Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
+                ret.fill_generic_defaults(defaults);
+                for (_, ref mut arg) in args {
+                    arg.fill_generic_defaults(defaults);
+                }
+            }
+            &mut Type::ConstSlice(ref mut ty)
+            | &mut Type::Slice(ref mut ty)
+            | &mut Type::Atomic(ref mut ty) => {
+                ty.fill_generic_defaults(defaults);
+            }
+            &mut Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.fill_generic_defaults(defaults);
+                }
+            }
+        }
+    }
+
+    /// Replaces the paths to the structs of `erased` with the types they wrap.
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        let replacement = match *self {
+            Type::ConstPtr(ref mut ty, _)
+            | Type::Ptr(ref mut ty, _)
+            | Type::Ref(ref mut ty)
+            | Type::MutRef(ref mut ty)
+            | Type::Array(ref mut ty, _)
+            | Type::ConstSlice(ref mut ty)
+            | Type::Slice(ref mut ty)
+            | Type::Atomic(ref mut ty) => {
+                ty.erase_transparent(erased);
+                None
+            }
+            Type::Path(ref mut generic_path) => {
+                for generic in generic_path.generics_mut() {
+                    generic.erase_transparent(erased);
+                }
+                erased
+                    .get(generic_path.path())
+                    .map(|&(ref params, ref ty)| {
+                        let mappings: Vec<_> =
+                            params.0.iter().zip(generic_path.generics()).collect();
+                        let mut ty = ty.specialize(&mappings);
+                        ty.erase_transparent(erased);
+                        ty
+                    })
+            }
+            Type::Primitive(_) => None,
+            Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
+                ret.erase_transparent(erased);
+                for (_, ref mut arg) in args {
+                    arg.erase_transparent(erased);
+                }
+                None
+            }
+            Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.erase_transparent(erased);
+                }
+                None
+            }
+        };
+        if let Some(ty) = replacement {
+            *self = ty;
         }
     }
 
     pub fn mangle_paths(&mut self, monomorphs: &Monomorphs) {
         match self {
-            &mut Type::ConstPtr(ref mut ty) => {
+            &mut Type::ConstPtr(ref mut ty, _) => {
                 ty.mangle_paths(monomorphs);
             }
-            &mut Type::Ptr(ref mut ty) => {
+            &mut Type::Ptr(ref mut ty, _) => {
                 ty.mangle_paths(monomorphs);
             }
             &mut Type::Ref(ref mut ty) | &mut Type::MutRef(ref mut ty) => {
@@ -654,15 +1138,107 @@ impl Type {
             &mut Type::Array(ref mut ty, _) => {
                 ty.mangle_paths(monomorphs);
             }
-            &mut Type::FuncPtr(ref mut ret, ref mut args) => {
+            &mut Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
                 ret.mangle_paths(monomorphs);
                 for (_, ref mut arg) in args {
                     arg.mangle_paths(monomorphs);
                 }
             }
+            &mut Type::ConstSlice(ref mut ty)
+            | &mut Type::Slice(ref mut ty)
+            | &mut Type::Atomic(ref mut ty) => {
+                ty.mangle_paths(monomorphs);
+            }
+            &mut Type::Tuple(ref mut elems) => {
+                for elem in elems {
+                    elem.mangle_paths(monomorphs);
+                }
+            }
         }
     }
 
+    /// Replaces any tuples and slices in this type with paths to the structs
+    /// they're lowered into, which are pushed to `out`, and atomics the
+    /// bindings can't use with the types of their values. Fails if `config`
+    /// doesn't enable lowering them.
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        let lowered = match *self {
+            Type::ConstPtr(ref mut ty, _)
+            | Type::Ptr(ref mut ty, _)
+            | Type::Ref(ref mut ty)
+            | Type::MutRef(ref mut ty) => return ty.lower_types(config, constants, out),
+            Type::Array(ref mut ty, ref mut len) => {
+                let value = match *len {
+                    ArrayLength::Expr(ref expr) => constants.eval(expr)?,
+                    _ => return ty.lower_types(config, constants, out),
+                };
+                if value < 0 {
+                    return Err(format!("Array length {} is negative.", value));
+                }
+                *len = ArrayLength::Value(value.to_string());
+                return ty.lower_types(config, constants, out);
+            }
+            Type::Path(ref mut generic_path) => {
+                for generic in generic_path.generics_mut() {
+                    generic.lower_types(config, constants, out)?;
+                }
+                return Ok(());
+            }
+            Type::Primitive(PrimitiveType::Int128) if config.int128.lowers(config.language) => {
+                Struct::int128(true, config)
+            }
+            Type::Primitive(PrimitiveType::UInt128) if config.int128.lowers(config.language) => {
+                Struct::int128(false, config)
+            }
+            Type::Primitive(_) => return Ok(()),
+            Type::Atomic(ref mut ty) if config.has_atomics() => {
+                return ty.lower_types(config, constants, out)
+            }
+            Type::Atomic(ref mut ty) => {
+                ty.lower_types(config, constants, out)?;
+                let value = mem::replace(&mut **ty, Type::Primitive(PrimitiveType::Void));
+                *self = value;
+                return Ok(());
+            }
+            Type::FuncPtr(ref mut ret, ref mut args, _, _, _) => {
+                ret.lower_types(config, constants, out)?;
+                for (_, ref mut arg) in args {
+                    arg.lower_types(config, constants, out)?;
+                }
+                return Ok(());
+            }
+            Type::ConstSlice(..) | Type::Slice(..) if !config.slice.views => {
+                return Err("Slices are not supported types.".to_owned());
+            }
+            Type::ConstSlice(ref mut ty) => {
+                ty.lower_types(config, constants, out)?;
+                Struct::slice_view(ty, false, config)
+            }
+            Type::Slice(ref mut ty) => {
+                ty.lower_types(config, constants, out)?;
+                Struct::slice_view(ty, true, config)
+            }
+            Type::Tuple(ref mut elems) => {
+                if !config.structure.tuples {
+                    return Err("Tuples are not supported types.".to_owned());
+                }
+                for elem in elems.iter_mut() {
+                    elem.lower_types(config, constants, out)?;
+                }
+                Struct::tuple(elems)
+            }
+        };
+
+        *self = Type::Path(GenericPath::new(lowered.path.clone(), vec![]));
+        out.push(lowered);
+        Ok(())
+    }
+
     pub fn can_cmp_order(&self) -> bool {
         match self {
             &Type::ConstPtr(..) => true,
@@ -672,6 +1248,8 @@ impl Type {
             &Type::Primitive(ref p) => p.can_cmp_order(),
             &Type::Array(..) => false,
             &Type::FuncPtr(..) => false,
+            &Type::ConstSlice(..) | &Type::Slice(..) | &Type::Atomic(..) => false,
+            &Type::Tuple(..) => false,
         }
     }
 
@@ -684,6 +1262,8 @@ impl Type {
             &Type::Primitive(ref p) => p.can_cmp_eq(),
             &Type::Array(..) => false,
             &Type::FuncPtr(..) => true,
+            &Type::ConstSlice(..) | &Type::Slice(..) | &Type::Atomic(..) => false,
+            &Type::Tuple(..) => false,
         }
     }
 }
@@ -7,20 +7,48 @@ use std::io::Write;
 
 use syn;
 
-use bindgen::config::{Config, Language};
+use bindgen::config::{Config, Language, LanguageVersion};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, ConstEvaluator, Documentation, ErasedTypes,
+    GenericDefaults, GenericParams, Item, ItemContainer, Path, Struct, ToCondition, Type,
 };
 use bindgen::library::Library;
 use bindgen::mangle;
 use bindgen::monomorph::Monomorphs;
 use bindgen::writer::{Source, SourceWriter};
 
+/// How a typedef with the `strong-typedef` annotation is written in C++, as a
+/// distinct type that doesn't mix with the type it aliases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StrongTypedef {
+    /// An `enum class` without variants, for integers.
+    EnumClass,
+    /// A struct with a `value` field and comparison operators.
+    Struct,
+}
+
+impl StrongTypedef {
+    /// How a typedef of `aliased` is written, if its annotations make it a
+    /// strong typedef. Generic typedefs can't be.
+    pub fn of(
+        annotations: &AnnotationSet,
+        generic_params: &GenericParams,
+        aliased: &Type,
+    ) -> Option<StrongTypedef> {
+        if !annotations.bool("strong-typedef").unwrap_or(false) || !generic_params.is_empty() {
+            return None;
+        }
+        match *aliased {
+            Type::Primitive(ref p) if p.is_integer() => Some(StrongTypedef::EnumClass),
+            _ => Some(StrongTypedef::Struct),
+        }
+    }
+}
+
 /// A type alias that is represented as a C typedef
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Typedef {
     pub path: Path,
     pub export_name: String,
@@ -68,8 +96,28 @@ impl Typedef {
         }
     }
 
-    pub fn simplify_standard_types(&mut self) {
-        self.aliased.simplify_standard_types();
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        if let Some(meaning) = self.aliased.standard_type_note(config) {
+            self.documentation.doc_comment.push(meaning.to_owned());
+        }
+        self.aliased.simplify_standard_types(config);
+    }
+
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
+        self.aliased.fill_generic_defaults(defaults);
+    }
+
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        self.aliased.erase_transparent(erased);
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        self.aliased.lower_types(config, constants, out)
     }
 
     pub fn transfer_annotations(&mut self, out: &mut HashMap<Path, AnnotationSet>) {
@@ -88,7 +136,9 @@ impl Typedef {
                 }
 
                 out.insert(alias_path, self.annotations.clone());
+                let module = self.annotations.module.take();
                 self.annotations = AnnotationSet::new();
+                self.annotations.module = module;
             }
             None => {}
         }
@@ -126,6 +176,10 @@ impl Item for Typedef {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -203,15 +257,78 @@ impl Source for Typedef {
 
         self.generic_params.write(config, out);
 
-        if config.language == Language::C {
+        let strong = StrongTypedef::of(&self.annotations, &self.generic_params, &self.aliased);
+        match strong {
+            Some(StrongTypedef::EnumClass) if config.language == Language::Cxx => {
+                write!(out, "enum class {} : ", self.export_name());
+                self.aliased.write(config, out);
+                out.write(" {};");
+                condition.write_after(config, out);
+                return;
+            }
+            Some(StrongTypedef::Struct) if config.language == Language::Cxx => {
+                self.write_strong_struct(config, out);
+                condition.write_after(config, out);
+                return;
+            }
+            _ => {}
+        }
+
+        // Alias declarations came with C++11, which generic ones need.
+        let cxx11 = config.has_version(LanguageVersion::Cxx11);
+        if config.language == Language::C || (!cxx11 && self.generic_params.is_empty()) {
             out.write("typedef ");
             (self.export_name().to_owned(), self.aliased.clone()).write(config, out);
         } else {
+            if !cxx11 {
+                warn!(
+                    "Generic typedef {} needs C++11, writing it anyway.",
+                    self.export_name()
+                );
+            }
             write!(out, "using {} = ", self.export_name());
             self.aliased.write(config, out);
         }
         out.write(";");
+        if strong.is_some() {
+            out.write(" /* strong typedef */");
+        }
 
         condition.write_after(config, out);
     }
 }
+
+impl Typedef {
+    /// Writes a strong typedef as a struct wrapping the aliased type, which
+    /// compares like it.
+    fn write_strong_struct<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        write!(out, "struct {}", self.export_name());
+        out.open_brace();
+        ("value".to_owned(), self.aliased.clone()).write(config, out);
+        out.write(";");
+
+        let mut operators: Vec<&str> = Vec::new();
+        if self.aliased.can_cmp_eq() {
+            operators.extend(&["==", "!="]);
+        }
+        if self.aliased.can_cmp_order() {
+            operators.extend(&["<", "<=", ">", ">="]);
+        }
+        if !operators.is_empty() {
+            out.new_line();
+        }
+        for op in operators {
+            out.new_line();
+            write!(
+                out,
+                "bool operator{}(const {}& other) const",
+                op,
+                self.export_name()
+            );
+            out.open_brace();
+            write!(out, "return value {} other.value;", op);
+            out.close_brace(false);
+        }
+        out.close_brace(true);
+    }
+}
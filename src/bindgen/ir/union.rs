@@ -9,54 +9,81 @@ use syn;
 use bindgen::config::{Config, Language};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
+use bindgen::ir::structure::write_fields;
 use bindgen::ir::SynFieldHelpers;
 use bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Repr, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, ConstEvaluator, Documentation, ErasedTypes,
+    GenericDefaults, GenericParams, Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle, Struct,
+    ToCondition, Type,
 };
 use bindgen::library::Library;
 use bindgen::mangle;
 use bindgen::monomorph::Monomorphs;
 use bindgen::rename::{IdentifierType, RenameRule};
-use bindgen::utilities::{find_first_some, IterHelpers};
-use bindgen::writer::{ListType, Source, SourceWriter};
+use bindgen::reserved;
+use bindgen::utilities::find_first_some;
+use bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Union {
     pub path: Path,
     pub export_name: String,
     pub generic_params: GenericParams,
     pub fields: Vec<(String, Type, Documentation)>,
     pub tuple_union: bool,
+    /// Whether the union is `#[repr(packed)]` or `#[repr(align(N))]`.
+    pub alignment: Option<ReprAlign>,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    /// Whether the union is forward declared, so that it's defined with its
+    /// tag.
+    #[serde(default)]
+    pub forward_declared: bool,
+    /// The structs and unions written as anonymous members in place of the
+    /// fields of the same name, which have them as their types.
+    #[serde(skip)]
+    pub anonymous_members: Vec<(String, ItemContainer)>,
+    /// The names of the zero-sized fields that were elided.
+    #[serde(default)]
+    pub elided_fields: Vec<String>,
 }
 
 impl Union {
     pub fn load(item: &syn::ItemUnion, mod_cfg: Option<&Cfg>) -> Result<Union, String> {
-        if Repr::load(&item.attrs)? != Repr::C {
+        let repr = Repr::load(&item.attrs)?;
+        if repr.style != ReprStyle::C || repr.ty.is_some() {
             return Err("Union is not marked #[repr(C)].".to_owned());
         }
 
+        // Fields of zero-sized types like `PhantomData` aren't loaded.
+        let mut elided_fields = Vec::new();
         let (fields, tuple_union) = {
-            let out = item
-                .fields
-                .named
-                .iter()
-                .try_skip_map(|x| x.as_ident_and_type())?;
+            let mut out = Vec::new();
+            for field in item.fields.named.iter() {
+                match field.as_ident_and_type()? {
+                    Some(x) => out.push(x),
+                    None => elided_fields.push(field.ident.as_ref().unwrap().to_string()),
+                }
+            }
             (out, false)
         };
 
-        Ok(Union::new(
+        let mut annotations = AnnotationSet::load(&item.attrs)?;
+        annotations.load_field_annotations(&item.fields.named)?;
+
+        let mut u = Union::new(
             Path::new(item.ident.to_string()),
             GenericParams::new(&item.generics),
             fields,
             tuple_union,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-            AnnotationSet::load(&item.attrs)?,
+            annotations,
             Documentation::load(&item.attrs),
-        ))
+        );
+        u.alignment = repr.align;
+        u.elided_fields = elided_fields;
+        Ok(u)
     }
 
     pub fn new(
@@ -75,18 +102,75 @@ impl Union {
             generic_params,
             fields,
             tuple_union,
+            alignment: None,
             cfg,
             annotations,
             documentation,
+            forward_declared: false,
+            anonymous_members: vec![],
+            elided_fields: vec![],
+        }
+    }
+
+    /// Removes the zero-sized fields at `indices`, in increasing order.
+    pub fn elide_fields(&mut self, indices: &[usize]) {
+        let start = self.elided_fields.len();
+        for &i in indices.iter().rev() {
+            let (name, _, _) = self.fields.remove(i);
+            self.elided_fields.insert(start, name);
         }
     }
 
-    pub fn simplify_standard_types(&mut self) {
+    /// Writes the forward declaration of the union, which is then defined with
+    /// its tag.
+    pub fn write_forward_declaration<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = (&self.cfg).to_condition(config);
+        condition.write_before(config, out);
+        if config.language == Language::C && config.style.generate_typedef() {
+            write!(
+                out,
+                "typedef union {} {};",
+                self.export_name, self.export_name
+            );
+        } else {
+            write!(out, "union {};", self.export_name);
+        }
+        condition.write_after(config, out);
+    }
+
+    pub fn simplify_standard_types(&mut self, config: &Config) {
+        for &mut (_, ref mut ty, ref mut documentation) in &mut self.fields {
+            if let Some(meaning) = ty.standard_type_note(config) {
+                documentation.doc_comment.push(meaning.to_owned());
+            }
+            ty.simplify_standard_types(config);
+        }
+    }
+
+    pub fn fill_generic_defaults(&mut self, defaults: &GenericDefaults) {
         for &mut (_, ref mut ty, _) in &mut self.fields {
-            ty.simplify_standard_types();
+            ty.fill_generic_defaults(defaults);
         }
     }
 
+    pub fn erase_transparent(&mut self, erased: &ErasedTypes) {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.erase_transparent(erased);
+        }
+    }
+
+    pub fn lower_types(
+        &mut self,
+        config: &Config,
+        constants: &ConstEvaluator,
+        out: &mut Vec<Struct>,
+    ) -> Result<(), String> {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.lower_types(config, constants, out)?;
+        }
+        Ok(())
+    }
+
     pub fn is_generic(&self) -> bool {
         self.generic_params.len() > 0
     }
@@ -123,6 +207,10 @@ impl Item for Union {
         self.cfg.as_ref()
     }
 
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        &mut self.cfg
+    }
+
     fn annotations(&self) -> &AnnotationSet {
         &self.annotations
     }
@@ -151,6 +239,12 @@ impl Item for Union {
             ty.rename_for_config(config, &self.generic_params);
         }
 
+        let renames: Vec<_> = self
+            .fields
+            .iter()
+            .map(|x| self.annotations.field_renames.get(&x.0).cloned())
+            .collect();
+
         let rules = [
             self.annotations.parse_atom::<RenameRule>("rename-all"),
             config.structure.rename_fields,
@@ -187,6 +281,20 @@ impl Item for Union {
                 name.insert(0, '_');
             }
         }
+
+        for &mut (ref mut name, ..) in &mut self.fields {
+            config.keywords.escape(name);
+            if config.objc_compat() {
+                reserved::escape_objc(name);
+            }
+        }
+
+        // Fields renamed with `cbindgen:rename` keep their names as given.
+        for (field, rename) in self.fields.iter_mut().zip(renames) {
+            if let Some(rename) = rename {
+                field.0 = rename;
+            }
+        }
     }
 
     fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -221,7 +329,7 @@ impl Item for Union {
             .collect::<Vec<_>>();
 
         let mangled_path = mangle::mangle_path(&self.path, generic_values);
-        let monomorph = Union::new(
+        let mut monomorph = Union::new(
             mangled_path,
             GenericParams::default(),
             self.fields
@@ -233,6 +341,8 @@ impl Item for Union {
             self.annotations.clone(),
             self.documentation.clone(),
         );
+        monomorph.alignment = self.alignment;
+        monomorph.elided_fields = self.elided_fields.clone();
 
         // Instantiate any monomorphs for any generic paths we may have just created.
         monomorph.add_monomorphs(library, out);
@@ -246,6 +356,14 @@ impl Source for Union {
         let condition = (&self.cfg).to_condition(config);
         condition.write_before(config, out);
 
+        let pack_pragma = self
+            .alignment
+            .and_then(|align| config.layout.pack_pragma(align));
+        if let Some(n) = pack_pragma {
+            write!(out, "#pragma pack(push, {})", n);
+            out.new_line();
+        }
+
         self.documentation.write(config, out);
 
         self.generic_params.write(config, out);
@@ -257,40 +375,66 @@ impl Source for Union {
         //   typedef union {
         // C with Both as style:
         //   typedef union Name {
-        if config.language == Language::C && config.style.generate_typedef() {
+        let typedef = config.language == Language::C
+            && config.style.generate_typedef()
+            && !self.forward_declared;
+        if typedef {
             out.write("typedef ");
         }
 
         out.write("union");
 
-        if config.language == Language::Cxx || config.style.generate_tag() {
+        if self.annotations.must_use {
+            if let Some(anno) = config.type_must_use(&config.structure.must_use) {
+                write!(out, " {}", anno)
+            }
+        }
+
+        if let Some(ref note) = self.annotations.deprecated {
+            let structure = &config.structure;
+            if let Some(anno) =
+                config.deprecated(note, &structure.deprecated, &structure.deprecated_with_note)
+            {
+                write!(out, " {}", anno)
+            }
+        }
+
+        if let Some(attribute) = config.swift.type_name_attribute(&self.annotations) {
+            write!(out, " {}", attribute);
+        }
+
+        if let Some(attribute) = self
+            .alignment
+            .and_then(|align| config.layout.attribute(align))
+        {
+            write!(out, " {}", attribute);
+        }
+
+        if config.language == Language::Cxx || config.style.generate_tag() || self.forward_declared
+        {
             write!(out, " {}", self.export_name);
         }
 
         out.open_brace();
 
-        if config.documentation {
-            out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
-        } else {
-            let vec: Vec<_> = self
-                .fields
-                .iter()
-                .map(|&(ref name, ref ty, _)| (name.clone(), ty.clone()))
-                .collect();
-            out.write_vertical_source_list(&vec[..], ListType::Cap(";"));
-        }
+        write_fields(&self.fields, &self.anonymous_members, config, out);
 
         if let Some(body) = config.export.extra_body(&self.path) {
             out.write_raw_block(body);
         }
 
-        if config.language == Language::C && config.style.generate_typedef() {
+        if typedef {
             out.close_brace(false);
             write!(out, " {};", self.export_name);
         } else {
             out.close_brace(true);
         }
 
+        if pack_pragma.is_some() {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         condition.write_after(config, out);
     }
 }
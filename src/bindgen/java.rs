@@ -0,0 +1,1070 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as Java source, either as a JNA `Library` interface or as
+//! a class of JNI `native` methods.
+//!
+//! With JNA, structs, unions and tagged enums are written as `Structure` and
+//! `Union` classes nested in the interface, and function pointer typedefs as
+//! `Callback` interfaces. JNI can only pass primitives, so its bindings only
+//! have the constants and the functions it can express, with pointers passed
+//! as `long`. Enums are written as interfaces holding their variants in both.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use bindgen::config::{Config, DocumentationStyle, JavaInterface};
+use bindgen::doccomment;
+use bindgen::ir::{
+    ArrayLength, CallingConvention, Cfg, Constant, Documentation, Enum, Function, GenericPath,
+    Item, ItemContainer, Literal, Path, PrimitiveType, ReprStyle, ReprType, Static, Struct, Type,
+    Typedef, Union,
+};
+use bindgen::python::is_exported;
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Taken from `https://docs.oracle.com/javase/specs/jls/se17/html/jls-3.html#jls-3.9`,
+/// along with the literals and `_`.
+const JAVA_KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+fn escape(name: &str) -> String {
+    if JAVA_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Where a type is used, which decides how it's passed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Position {
+    Field,
+    Argument,
+    Return,
+}
+
+/// What the name of an item refers to in the bindings.
+#[derive(Debug, Copy, Clone)]
+enum Named<'a> {
+    /// A struct, union or tagged enum, which is written as a class.
+    Class,
+    /// An enum, which is passed as its integer type.
+    Integer(Option<ReprType>),
+    /// An opaque item, which can only be used through pointers.
+    Opaque,
+    /// A function pointer typedef, which is written as a `Callback`.
+    Callback,
+    /// A typedef or a transparent struct, which is written as the type it wraps.
+    Alias(&'a Type),
+}
+
+/// Writes a Java class or interface with the declarations of `bindings`.
+pub(crate) struct JavaWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    names: HashMap<&'a str, Named<'a>>,
+    /// Whether the `SizeT` and `SSizeT` classes JNA lacks have to be written.
+    uses_size_t: Cell<bool>,
+    uses_ssize_t: Cell<bool>,
+    /// The names declared so far, since items with several `#[cfg]`ed
+    /// definitions can only be declared once.
+    declared: RefCell<HashSet<String>>,
+}
+
+impl<'a> JavaWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        let mut names = HashMap::new();
+        for item in &bindings.items {
+            let named = match *item {
+                ItemContainer::Struct(ref s) if s.is_transparent => Named::Alias(&s.fields[0].1),
+                // JNA has no bitfields, nor packed or aligned structs.
+                ItemContainer::Struct(ref s)
+                    if s.bitfield_widths.is_some() || s.alignment.is_some() =>
+                {
+                    Named::Opaque
+                }
+                ItemContainer::Union(ref u) if u.alignment.is_some() => Named::Opaque,
+                ItemContainer::Struct(..) | ItemContainer::Union(..) => Named::Class,
+                ItemContainer::Enum(ref e) => match e.tag {
+                    Some(ref tag) => {
+                        names.insert(tag.as_str(), Named::Integer(e.repr.ty));
+                        Named::Class
+                    }
+                    None => Named::Integer(e.repr.ty),
+                },
+                ItemContainer::OpaqueItem(..) => Named::Opaque,
+                ItemContainer::Typedef(ref t) => match t.aliased {
+                    Type::FuncPtr(..) => Named::Callback,
+                    ref aliased => Named::Alias(aliased),
+                },
+                ItemContainer::Constant(..) | ItemContainer::Static(..) => continue,
+            };
+            names.insert(item.deref().export_name(), named);
+        }
+
+        JavaWriter {
+            bindings,
+            config: &bindings.config,
+            names,
+            uses_size_t: Cell::new(false),
+            uses_ssize_t: Cell::new(false),
+            declared: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Returns whether `name` can be declared, which it can only be once.
+    fn declare(&self, name: &str) -> bool {
+        if !self.declared.borrow_mut().insert(name.to_owned()) {
+            warn!(
+                "Skip {} - (Java can only declare one of its definitions).",
+                name
+            );
+            return false;
+        }
+        true
+    }
+
+    fn is_jni(&self) -> bool {
+        self.config.java.interface == JavaInterface::Jni
+    }
+
+    /// The modifiers of constants, which are implicit in interfaces.
+    fn constant_modifiers(&self) -> &'static str {
+        if self.is_jni() {
+            "public static final "
+        } else {
+            ""
+        }
+    }
+
+    fn pointer_type(&self) -> &'static str {
+        if self.is_jni() {
+            "long"
+        } else {
+            "Pointer"
+        }
+    }
+
+    fn primitive_type(&self, primitive: &PrimitiveType) -> &'static str {
+        let jni = self.is_jni();
+        match *primitive {
+            PrimitiveType::Void => "void",
+            // JNA passes `boolean` as an `int`, but `jboolean` is a byte.
+            PrimitiveType::Bool if jni => "boolean",
+            PrimitiveType::Bool => "byte",
+            PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar
+            | PrimitiveType::UInt8
+            | PrimitiveType::Int8 => "byte",
+            PrimitiveType::WChar => "char",
+            PrimitiveType::Short
+            | PrimitiveType::UShort
+            | PrimitiveType::UInt16
+            | PrimitiveType::Int16 => "short",
+            PrimitiveType::Int
+            | PrimitiveType::UInt
+            | PrimitiveType::UInt32
+            | PrimitiveType::Int32 => "int",
+            PrimitiveType::Long | PrimitiveType::ULong if jni => "long",
+            PrimitiveType::Long | PrimitiveType::ULong => "NativeLong",
+            PrimitiveType::LongLong
+            | PrimitiveType::ULongLong
+            | PrimitiveType::UInt64
+            | PrimitiveType::Int64 => "long",
+            PrimitiveType::Int128 | PrimitiveType::UInt128 => {
+                unreachable!("128-bit integers should have been lowered to structs")
+            }
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::USize | PrimitiveType::SizeT if jni => "long",
+            PrimitiveType::USize | PrimitiveType::SizeT => {
+                self.uses_size_t.set(true);
+                "SizeT"
+            }
+            PrimitiveType::ISize | PrimitiveType::SSizeT | PrimitiveType::PtrDiffT if jni => "long",
+            PrimitiveType::ISize | PrimitiveType::SSizeT | PrimitiveType::PtrDiffT => {
+                self.uses_ssize_t.set(true);
+                "SSizeT"
+            }
+            PrimitiveType::VaList => self.pointer_type(),
+        }
+    }
+
+    fn repr_type(&self, repr: Option<ReprType>) -> &'static str {
+        match repr {
+            None | Some(ReprType::U32) | Some(ReprType::I32) => "int",
+            Some(ReprType::U8) | Some(ReprType::I8) => "byte",
+            Some(ReprType::U16) | Some(ReprType::I16) => "short",
+            Some(ReprType::USize) => self.primitive_type(&PrimitiveType::USize),
+            Some(ReprType::ISize) => self.primitive_type(&PrimitiveType::ISize),
+        }
+    }
+
+    /// Returns the name of the class `ty` is written as, if any.
+    fn class_name<'b>(&'b self, ty: &'b Type) -> Option<&'b str> {
+        match *ty {
+            Type::Path(ref generic) => match self.names.get(generic.export_name()) {
+                Some(&Named::Class) => Some(generic.export_name()),
+                Some(&Named::Alias(aliased)) => self.class_name(aliased),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the Java type of `ty`, or `None` if it can't be passed.
+    fn java_type(&self, ty: &Type, position: Position) -> Option<String> {
+        match *ty {
+            Type::ConstPtr(ref pointee, _)
+            | Type::Ptr(ref pointee, _)
+            | Type::Ref(ref pointee)
+            | Type::MutRef(ref pointee) => {
+                if self.is_jni() {
+                    return Some(self.pointer_type().to_owned());
+                }
+                let is_const = match *ty {
+                    Type::ConstPtr(..) | Type::Ref(..) => true,
+                    _ => false,
+                };
+                Some(match **pointee {
+                    Type::Primitive(PrimitiveType::Char)
+                        if is_const && position == Position::Argument =>
+                    {
+                        "String".to_owned()
+                    }
+                    Type::Primitive(PrimitiveType::WChar)
+                        if is_const && position == Position::Argument =>
+                    {
+                        "WString".to_owned()
+                    }
+                    _ => match self.class_name(pointee) {
+                        Some(name) => format!("{}.ByReference", name),
+                        None => self.pointer_type().to_owned(),
+                    },
+                })
+            }
+            Type::Path(ref generic) => {
+                let name = generic.export_name();
+                match self.names.get(name) {
+                    Some(&Named::Class) | Some(&Named::Callback) if self.is_jni() => None,
+                    Some(&Named::Class) if position == Position::Field => Some(name.to_owned()),
+                    Some(&Named::Class) => Some(format!("{}.ByValue", name)),
+                    Some(&Named::Callback) => Some(name.to_owned()),
+                    Some(&Named::Integer(repr)) => Some(self.repr_type(repr).to_owned()),
+                    Some(&Named::Opaque) => Some(self.pointer_type().to_owned()),
+                    Some(&Named::Alias(aliased)) => self.java_type(aliased, position),
+                    None if self.is_jni() => None,
+                    None => Some(name.to_owned()),
+                }
+            }
+            Type::Primitive(ref primitive) => Some(self.primitive_type(primitive).to_owned()),
+            // Arrays decay to pointers outside of structs.
+            Type::Array(ref elem, _) if position == Position::Field => self
+                .java_type(elem, position)
+                .map(|elem| format!("{}[]", elem)),
+            Type::Array(..) | Type::FuncPtr(..) => Some(self.pointer_type().to_owned()),
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    /// Returns the initializer of an array field, which JNA needs to know the
+    /// length of the array.
+    fn array_initializer(&self, ty: &Type) -> Option<String> {
+        let (elem, len) = match *ty {
+            // Constants may be `long`s, which can't be array lengths.
+            Type::Array(ref elem, ArrayLength::Name(ref name)) => (elem, format!("(int) {}", name)),
+            Type::Array(ref elem, ref len) => (elem, len.as_str().to_owned()),
+            _ => return None,
+        };
+        if let Some(name) = self.class_name(elem) {
+            return Some(format!("({}[]) new {}().toArray({})", name, name, len));
+        }
+        let elem = self.java_type(elem, Position::Field)?;
+        // Multidimensional arrays write their lengths from the outermost one.
+        Some(match elem.find('[') {
+            Some(i) => format!("new {}[{}]{}", &elem[..i], len, &elem[i..]),
+            None => format!("new {}[{}]", elem, len),
+        })
+    }
+
+    /// Returns the Java type of a constant of type `ty`, if it can have one.
+    fn constant_type(&self, ty: &Type) -> Option<&'static str> {
+        match *ty {
+            Type::Primitive(PrimitiveType::Bool) => Some("boolean"),
+            // A Rust `char` can be outside of the range of a Java one.
+            Type::Primitive(PrimitiveType::WChar) => Some("int"),
+            Type::Primitive(PrimitiveType::Void) | Type::Primitive(PrimitiveType::VaList) => None,
+            Type::Primitive(PrimitiveType::Long)
+            | Type::Primitive(PrimitiveType::ULong)
+            | Type::Primitive(PrimitiveType::USize)
+            | Type::Primitive(PrimitiveType::ISize)
+            | Type::Primitive(PrimitiveType::SizeT)
+            | Type::Primitive(PrimitiveType::SSizeT)
+            | Type::Primitive(PrimitiveType::PtrDiffT) => Some("long"),
+            Type::Primitive(ref primitive) => Some(self.primitive_type(primitive)),
+            Type::ConstPtr(ref pointee, _) => match **pointee {
+                Type::Primitive(PrimitiveType::Char) => Some("String"),
+                _ => None,
+            },
+            Type::Path(ref generic) => match self.names.get(generic.export_name()) {
+                Some(&Named::Integer(Some(ReprType::USize)))
+                | Some(&Named::Integer(Some(ReprType::ISize))) => Some("long"),
+                Some(&Named::Integer(repr)) => Some(self.repr_type(repr)),
+                Some(&Named::Alias(aliased)) => self.constant_type(aliased),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Converts a literal in C syntax, as stored in the IR, to a Java
+    /// constant expression of type `ty`.
+    fn literal(&self, value: &Literal, ty: &str) -> Option<String> {
+        match *value {
+            Literal::Expr(ref v) => expr_literal(v, ty),
+            Literal::Path(ref name) => Some(name.clone()),
+            Literal::Cast { .. } => {
+                unreachable!("Casts are evaluated when generating the bindings.")
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => Some(format!(
+                "{} {} {}",
+                self.operand(left, ty)?,
+                op,
+                self.operand(right, ty)?
+            )),
+            Literal::Struct {
+                ref path,
+                ref fields,
+                ..
+            } => {
+                if self.bindings.struct_is_transparent(path) {
+                    self.literal(&fields[0].1, ty)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn operand(&self, value: &Literal, ty: &str) -> Option<String> {
+        let operand = self.literal(value, ty)?;
+        match *value {
+            Literal::BinOp { .. } => Some(format!("({})", operand)),
+            _ => Some(operand),
+        }
+    }
+
+    /// Returns the interface the JNA library extends, which gives all its
+    /// functions one calling convention.
+    fn library_interface(&self) -> &'static str {
+        let conventions: HashSet<_> = self
+            .bindings
+            .functions
+            .iter()
+            .map(|x| x.calling_convention)
+            .collect();
+        if conventions.contains(&CallingConvention::Fastcall) {
+            warn!("JNA has no fastcall calling convention, writing it as C.");
+        }
+        let stdcall = |x: &CallingConvention| match *x {
+            CallingConvention::Stdcall | CallingConvention::System => true,
+            _ => false,
+        };
+        if !conventions.is_empty() && conventions.iter().all(stdcall) {
+            "com.sun.jna.win32.StdCallLibrary"
+        } else {
+            if conventions.iter().any(stdcall) {
+                warn!("JNA libraries have one calling convention, writing stdcall functions as C.");
+            }
+            "Library"
+        }
+    }
+
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        out.new_line_if_not_start();
+        let name = &self.config.java.class_name;
+        if self.is_jni() {
+            write!(out, "public final class {} {{", name);
+        } else {
+            write!(
+                out,
+                "public interface {} extends {} {{",
+                name,
+                self.library_interface()
+            );
+        }
+        // Every member starts with a new line, and ends with one.
+        out.push_tab();
+
+        match self.config.java.library {
+            Some(ref library) if self.is_jni() => {
+                out.new_line();
+                out.write("static {");
+                out.push_tab();
+                out.new_line();
+                write!(out, "System.loadLibrary(\"{}\");", library);
+                out.pop_tab();
+                out.new_line();
+                out.write("}");
+                out.new_line();
+            }
+            Some(ref library) => {
+                // `Native` is qualified in case an item shadows it.
+                out.new_line();
+                write!(
+                    out,
+                    "{0} INSTANCE = com.sun.jna.Native.load(\"{1}\", {0}.class);",
+                    name, library
+                );
+                out.new_line();
+            }
+            None => {}
+        }
+        if self.is_jni() {
+            out.new_line();
+            write!(out, "private {}() {{}}", name);
+            out.new_line();
+        }
+
+        for constant in &self.bindings.constants {
+            if constant.ty.is_primitive_or_ptr_primitive() {
+                self.write_constant(constant, None, out);
+            }
+        }
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => self.write_struct(x, out),
+                ItemContainer::Union(ref x) => self.write_union(x, out),
+                ItemContainer::OpaqueItem(..) => {}
+                ItemContainer::Typedef(ref x) => self.write_typedef(x, out),
+            }
+        }
+
+        for constant in &self.bindings.constants {
+            if !constant.ty.is_primitive_or_ptr_primitive() {
+                self.write_constant(constant, None, out);
+            }
+        }
+
+        for global in &self.bindings.globals {
+            self.write_static(global, out);
+        }
+
+        for function in &self.bindings.functions {
+            self.write_function(function, out);
+        }
+
+        if self.uses_size_t.get() {
+            self.write_integer_type("SizeT", true, out);
+        }
+        if self.uses_ssize_t.get() {
+            self.write_integer_type("SSizeT", false, out);
+        }
+
+        out.pop_tab();
+        out.write("}");
+        out.new_line();
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if let Some(ref package) = self.config.java.package {
+            out.new_line_if_not_start();
+            write!(out, "package {};", package);
+            out.new_line();
+        }
+
+        if !self.is_jni() {
+            out.new_line_if_not_start();
+            out.write("import com.sun.jna.*;");
+            out.new_line();
+        }
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        out.write("/**");
+        out.new_line();
+        for line in doccomment::rewrite(&documentation.doc_comment, DocumentationStyle::Javadoc) {
+            out.write(" *");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+        out.write(" */");
+        out.new_line();
+    }
+
+    /// Java has no preprocessor, so conditional items are always written and
+    /// their condition is only recorded in a comment.
+    fn write_cfg<F: Write>(&self, cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+        if let Some(ref cfg) = *cfg {
+            write!(out, "// cfg({})", cfg);
+            out.new_line();
+        }
+    }
+
+    /// Writes a `Structure` or `Union` class with `fields`, and its `ByReference`
+    /// and `ByValue` subclasses. `nested` is written before the fields.
+    fn write_class<F, N>(
+        &self,
+        name: &str,
+        base: &str,
+        fields: &[(String, Type, Documentation)],
+        nested: N,
+        out: &mut SourceWriter<F>,
+    ) where
+        F: Write,
+        N: Fn(&mut SourceWriter<F>),
+    {
+        if base == "Structure" {
+            let names: Vec<_> = fields
+                .iter()
+                .map(|(name, _, _)| format!("\"{}\"", escape(name)))
+                .collect();
+            write!(out, "@Structure.FieldOrder({{{}}})", names.join(", "));
+            out.new_line();
+        }
+        write!(out, "class {} extends {} {{", name, base);
+        out.push_tab();
+        out.new_line();
+
+        nested(out);
+
+        for (field, ty, documentation) in fields {
+            self.write_documentation(documentation, out);
+            let java_type = self.java_type(ty, Position::Field).unwrap();
+            write!(out, "public {} {}", java_type, escape(field));
+            if let Some(initializer) = self.array_initializer(ty) {
+                write!(out, " = {}", initializer);
+            }
+            out.write(";");
+            out.new_line();
+        }
+
+        out.new_line();
+        write!(
+            out,
+            "public static class ByReference extends {} implements Structure.ByReference {{}}",
+            name
+        );
+        out.new_line();
+        out.new_line();
+        write!(
+            out,
+            "public static class ByValue extends {} implements Structure.ByValue {{}}",
+            name
+        );
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+
+    fn write_struct<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        if !s.is_transparent && !self.is_jni() {
+            if s.bitfield_widths.is_some() {
+                warn!(
+                    "Skip {} - (JNA doesn't support bitfields, use it through pointers).",
+                    s.export_name()
+                );
+            } else if s.alignment.is_some() {
+                warn!(
+                    "Skip {} - (JNA doesn't support packed or aligned structs, use it through pointers).",
+                    s.export_name()
+                );
+            } else if self.declare(s.export_name()) {
+                out.new_line();
+                self.write_cfg(&s.cfg, out);
+                self.write_documentation(&s.documentation, out);
+                self.write_class(s.export_name(), "Structure", &s.fields, |_| {}, out);
+            }
+        }
+
+        for constant in &s.associated_constants {
+            self.write_constant(constant, Some(s), out);
+        }
+    }
+
+    fn write_union<F: Write>(&self, u: &Union, out: &mut SourceWriter<F>) {
+        if self.is_jni() {
+            return;
+        }
+        if u.alignment.is_some() {
+            warn!(
+                "Skip {} - (JNA doesn't support packed or aligned unions, use it through pointers).",
+                u.export_name()
+            );
+            return;
+        }
+        if !self.declare(u.export_name()) {
+            return;
+        }
+        out.new_line();
+        self.write_cfg(&u.cfg, out);
+        self.write_documentation(&u.documentation, out);
+        self.write_class(u.export_name(), "Union", &u.fields, |_| {}, out);
+    }
+
+    fn write_typedef<F: Write>(&self, t: &Typedef, out: &mut SourceWriter<F>) {
+        let (ret, args, calling_convention) = match t.aliased {
+            Type::FuncPtr(ref ret, ref args, _, calling_convention, _) if !self.is_jni() => {
+                (ret, args, calling_convention)
+            }
+            // Other typedefs are written as the type they alias.
+            _ => return,
+        };
+        if !self.declare(t.export_name()) {
+            return;
+        }
+
+        out.new_line();
+        self.write_cfg(&t.cfg, out);
+        self.write_documentation(&t.documentation, out);
+        let callback = match calling_convention {
+            CallingConvention::Stdcall | CallingConvention::System => {
+                "com.sun.jna.win32.StdCallLibrary.StdCallCallback"
+            }
+            _ => "Callback",
+        };
+        write!(out, "interface {} extends {} {{", t.export_name(), callback);
+        out.push_tab();
+        out.new_line();
+        let args: Vec<_> = args
+            .iter()
+            .enumerate()
+            .map(|(i, (name, ty))| {
+                (
+                    name.clone().unwrap_or_else(|| format!("arg{}", i)),
+                    ty.clone(),
+                )
+            })
+            .collect();
+        // Java can't implement C variadic functions, only call them.
+        self.write_signature("invoke", ret, &args, false, out);
+        out.pop_tab();
+        out.write("}");
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+        if !self.declare(tag) || (tag != e.export_name() && !self.declare(e.export_name())) {
+            return;
+        }
+        let ty = match e.repr.ty {
+            Some(ReprType::USize) | Some(ReprType::ISize) => "long",
+            repr => self.repr_type(repr),
+        };
+
+        out.new_line();
+        self.write_cfg(&e.cfg, out);
+        self.write_documentation(&e.documentation, out);
+        if self.is_jni() {
+            out.write("public ");
+        }
+        write!(out, "interface {} {{", tag);
+        out.push_tab();
+        let mut next_value = 0;
+        for variant in &e.variants {
+            let value = variant.discriminant.unwrap_or(next_value);
+            out.new_line();
+            self.write_documentation(&variant.documentation, out);
+            write!(out, "{} {} = {};", ty, variant.export_name, value);
+            next_value = value + 1;
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            out.new_line();
+            write!(out, "{} Sentinel = {};", ty, next_value);
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        if e.tag.is_none() || self.is_jni() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                self.write_struct(body, out);
+            }
+        }
+
+        let tag_field = ("tag".to_owned(), path_type(tag), Documentation::none());
+        let variant_fields: Vec<_> = e
+            .variants
+            .iter()
+            .filter_map(|variant| variant.body.as_ref())
+            .map(|(name, body)| {
+                (
+                    name.clone(),
+                    path_type(body.export_name()),
+                    Documentation::none(),
+                )
+            })
+            .collect();
+
+        out.new_line();
+        if e.repr.style == ReprStyle::C {
+            let fields = vec![
+                tag_field,
+                (
+                    "variants".to_owned(),
+                    path_type("Variants"),
+                    Documentation::none(),
+                ),
+            ];
+            self.write_class(
+                e.export_name(),
+                "Structure",
+                &fields,
+                |out| {
+                    out.write("public static ");
+                    self.write_class("Variants", "Union", &variant_fields, |_| {}, out);
+                    out.new_line();
+                },
+                out,
+            );
+        } else {
+            let mut fields = vec![tag_field];
+            fields.extend(variant_fields);
+            self.write_class(e.export_name(), "Union", &fields, |_| {}, out);
+        }
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return;
+        }
+
+        let ty = match self.constant_type(&constant.ty) {
+            Some(ty) => ty,
+            None => return,
+        };
+        let value = match self.literal(&constant.value, ty) {
+            Some(value) => value,
+            None => return,
+        };
+
+        let name = constant
+            .global_name(self.config, associated_to)
+            .into_owned();
+
+        if !self.declare(&name) {
+            return;
+        }
+
+        out.new_line();
+        self.write_cfg(&constant.cfg, out);
+        self.write_documentation(&constant.documentation, out);
+        write!(
+            out,
+            "{}{} {} = {};",
+            self.constant_modifiers(),
+            ty,
+            name,
+            value
+        );
+        out.new_line();
+    }
+
+    fn write_static<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        let library = match self.config.java.library {
+            Some(ref library) if !self.is_jni() => library,
+            _ => return,
+        };
+        if !self.declare(global.export_name()) {
+            return;
+        }
+
+        out.new_line();
+        self.write_cfg(&global.cfg, out);
+        self.write_documentation(&global.documentation, out);
+        write!(
+            out,
+            "Pointer {0} = NativeLibrary.getInstance(\"{1}\").getGlobalVariableAddress(\"{0}\");",
+            global.export_name(),
+            library
+        );
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        let name = function.path().name();
+        let expressible = self.java_type(&function.ret, Position::Return).is_some()
+            && function
+                .args
+                .iter()
+                .all(|(_, ty)| self.java_type(ty, Position::Argument).is_some());
+        if !expressible || (self.is_jni() && function.variadic) {
+            warn!("Skip {} - (not expressible with JNI).", name);
+            return;
+        }
+        if !self.declare(name) {
+            return;
+        }
+
+        out.new_line();
+        self.write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        if self.is_jni() {
+            out.write("public static native ");
+        }
+        self.write_signature(name, &function.ret, &function.args, function.variadic, out);
+    }
+
+    fn write_signature<F: Write>(
+        &self,
+        name: &str,
+        ret: &Type,
+        args: &[(String, Type)],
+        variadic: bool,
+        out: &mut SourceWriter<F>,
+    ) {
+        let mut args: Vec<_> = args
+            .iter()
+            .map(|(name, ty)| {
+                format!(
+                    "{} {}",
+                    self.java_type(ty, Position::Argument).unwrap(),
+                    escape(name)
+                )
+            })
+            .collect();
+        if variadic {
+            // JNA passes the varargs of variadic functions as C would.
+            args.push("Object... varargs".to_owned());
+        }
+        write!(
+            out,
+            "{} {}({});",
+            self.java_type(ret, Position::Return).unwrap(),
+            name,
+            args.join(", ")
+        );
+        out.new_line();
+    }
+
+    /// Writes an `IntegerType` with the size of `size_t`, which JNA lacks.
+    fn write_integer_type<F: Write>(&self, name: &str, unsigned: bool, out: &mut SourceWriter<F>) {
+        out.new_line();
+        write!(out, "class {} extends IntegerType {{", name);
+        out.push_tab();
+        out.new_line();
+        write!(out, "public {}() {{", name);
+        out.push_tab();
+        out.new_line();
+        out.write("this(0);");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+        out.new_line();
+        write!(out, "public {}(long value) {{", name);
+        out.push_tab();
+        out.new_line();
+        write!(
+            out,
+            "super(com.sun.jna.Native.SIZE_T_SIZE, value, {});",
+            unsigned
+        );
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+}
+
+fn expr_literal(v: &str, ty: &str) -> Option<String> {
+    match v {
+        "true" | "false" if ty == "boolean" => return Some(v.to_owned()),
+        "true" => return Some("1".to_owned()),
+        "false" => return Some("0".to_owned()),
+        _ => {}
+    }
+
+    if v.starts_with("u8\"") && v.ends_with('"') {
+        let mut result = String::from("\"");
+        for c in v[3..v.len() - 1].chars() {
+            match c {
+                '"' | '\\' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                ' '..='~' => result.push(c),
+                _ => {
+                    let mut units = [0; 2];
+                    for unit in c.encode_utf16(&mut units) {
+                        result.push_str(&format!("\\u{:04x}", unit));
+                    }
+                }
+            }
+        }
+        result.push('"');
+        return Some(result);
+    }
+
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        return Some(format!("0x{}", &v[4..v.len() - 1]));
+    }
+
+    if v.starts_with('\'') && v.ends_with('\'') {
+        // `char::escape_default` output, which only differs from Java in how
+        // it writes unicode escapes.
+        if let Some(start) = v.find("\\u{") {
+            return Some(format!("0x{}", &v[start + 3..v.len() - 2]));
+        }
+        return Some(v.to_owned());
+    }
+
+    let class = if ty == "float" { "Float" } else { "Double" };
+    match v {
+        "NAN" => return Some(format!("{}.NaN", class)),
+        "INFINITY" => return Some(format!("{}.POSITIVE_INFINITY", class)),
+        "-INFINITY" => return Some(format!("{}.NEGATIVE_INFINITY", class)),
+        _ => {}
+    }
+
+    let integer = v.parse::<i128>().ok();
+    Some(match (ty, integer) {
+        ("float", _) if v.parse::<f64>().is_ok() => format!("{}f", v),
+        ("double", Some(_)) => format!("{}.0", v),
+        ("long", Some(i)) if i > i128::from(i64::max_value()) => format!("0x{:X}L", i),
+        ("long", Some(_)) => format!("{}L", v),
+        ("int", Some(i)) if i > i128::from(i32::max_value()) => format!("0x{:X}", i),
+        ("short", Some(i)) if i > i128::from(i16::max_value()) => format!("(short) {}", v),
+        ("byte", Some(i)) if i > i128::from(i8::max_value()) => format!("(byte) {}", v),
+        _ => v.to_owned(),
+    })
+}
+
+fn path_type(name: &str) -> Type {
+    Type::Path(GenericPath::new(Path::new(name), vec![]))
+}
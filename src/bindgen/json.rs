@@ -0,0 +1,557 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a JSON description of the C ABI of every item.
+//!
+//! Names are the ones of the C header, types are described structurally with
+//! the C names of the primitives, and sizes, alignments and field offsets are
+//! given for 32 and 64 bit targets where they are the same across the common
+//! ABIs, and `null` otherwise. Constant values are C expressions.
+
+use std::io::{self, Write};
+
+use serde_json::{self, Value};
+
+use bindgen::ir::layout::{LayoutEngine, LayoutEngines, Layouts, TypeLayout};
+use bindgen::ir::{
+    CallingConvention, Cfg, Constant, Documentation, Enum, Function, Item, ItemContainer,
+    ReprAlign, ReprStyle, ReprType, Static, Struct, Type,
+};
+use bindgen::python::is_exported;
+use bindgen::Bindings;
+
+#[derive(Serialize)]
+struct Abi<'a> {
+    constants: Vec<JsonConstant<'a>>,
+    types: Vec<JsonItem<'a>>,
+    globals: Vec<JsonGlobal<'a>>,
+    functions: Vec<JsonFunction<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum JsonType<'a> {
+    Primitive {
+        name: &'static str,
+    },
+    Named {
+        name: &'a str,
+    },
+    Pointer {
+        pointee: Box<JsonType<'a>>,
+        #[serde(rename = "const")]
+        is_const: bool,
+        nullable: bool,
+    },
+    Array {
+        element: Box<JsonType<'a>>,
+        length: &'a str,
+    },
+    Function {
+        ret: Box<JsonType<'a>>,
+        args: Vec<JsonType<'a>>,
+        nullable: bool,
+        #[serde(skip_serializing_if = "CallingConvention::is_c")]
+        calling_convention: CallingConvention,
+        #[serde(skip_serializing_if = "is_false")]
+        variadic: bool,
+    },
+}
+
+impl<'a> JsonType<'a> {
+    fn new(ty: &'a Type) -> Self {
+        let pointer = |pointee: &'a Type, is_const, nullable| JsonType::Pointer {
+            pointee: Box::new(JsonType::new(pointee)),
+            is_const,
+            nullable,
+        };
+        match *ty {
+            Type::ConstPtr(ref pointee, nullable) => pointer(pointee, true, nullable),
+            Type::Ptr(ref pointee, nullable) => pointer(pointee, false, nullable),
+            Type::Ref(ref pointee) => pointer(pointee, true, false),
+            Type::MutRef(ref pointee) => pointer(pointee, false, false),
+            Type::Path(ref generic) => JsonType::Named {
+                name: generic.export_name(),
+            },
+            Type::Primitive(ref primitive) => JsonType::Primitive {
+                name: primitive.to_repr_c(),
+            },
+            Type::Array(ref ty, ref len) => JsonType::Array {
+                element: Box::new(JsonType::new(ty)),
+                length: len.as_str(),
+            },
+            Type::FuncPtr(ref ret, ref args, nullable, calling_convention, variadic) => {
+                JsonType::Function {
+                    ret: Box::new(JsonType::new(ret)),
+                    args: args.iter().map(|(_, ty)| JsonType::new(ty)).collect(),
+                    nullable,
+                    calling_convention,
+                    variadic,
+                }
+            }
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+}
+
+/// The layout of a type in each data model, or for the target the bindings
+/// are generated for, where it's known.
+#[derive(Serialize)]
+struct JsonLayouts {
+    bits32: Option<JsonLayout>,
+    bits64: Option<JsonLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<JsonLayout>,
+}
+
+impl From<Layouts<JsonLayout>> for JsonLayouts {
+    fn from(layouts: Layouts<JsonLayout>) -> Self {
+        JsonLayouts {
+            bits32: layouts.bits32,
+            bits64: layouts.bits64,
+            target: layouts.target,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonLayout {
+    size: usize,
+    align: usize,
+    /// The offsets of the fields of a struct.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offsets: Option<Vec<usize>>,
+}
+
+impl JsonLayout {
+    fn new(layout: TypeLayout, offsets: Option<Vec<usize>>) -> Self {
+        JsonLayout {
+            size: layout.size,
+            align: layout.align,
+            offsets,
+        }
+    }
+}
+
+/// The things every item has.
+#[derive(Serialize)]
+struct Common {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cfg: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    documentation: Vec<String>,
+}
+
+impl Common {
+    fn new(cfg: &Option<Cfg>, documentation: &Documentation) -> Self {
+        Common {
+            cfg: cfg.as_ref().map(|cfg| cfg.to_string()),
+            documentation: documentation.doc_comment.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonField<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: JsonType<'a>,
+    /// The width of a bitfield.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bits: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct JsonVariant<'a> {
+    name: &'a str,
+    /// The discriminant of the variant, which is also the value of the
+    /// implicit ones.
+    discriminant: i64,
+    /// The struct of the fields of a variant of a tagged enum.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum JsonItem<'a> {
+    Struct {
+        name: &'a str,
+        fields: Vec<JsonField<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        packed: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        align: Option<u64>,
+        layout: JsonLayouts,
+        #[serde(flatten)]
+        common: Common,
+    },
+    Union {
+        name: &'a str,
+        fields: Vec<JsonField<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        packed: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        align: Option<u64>,
+        layout: JsonLayouts,
+        #[serde(flatten)]
+        common: Common,
+    },
+    /// A fieldless enum, or the tag of a tagged enum.
+    Enum {
+        name: &'a str,
+        #[serde(rename = "type")]
+        ty: Option<&'static str>,
+        variants: Vec<JsonVariant<'a>>,
+        layout: JsonLayouts,
+        #[serde(flatten)]
+        common: Common,
+    },
+    /// A tagged enum, which is a struct of the tag and a union of the bodies
+    /// if it's `#[repr(C)]`, or a union of bodies starting with the tag
+    /// otherwise.
+    TaggedEnum {
+        name: &'a str,
+        tag: &'a str,
+        separate_tag: bool,
+        layout: JsonLayouts,
+        #[serde(flatten)]
+        common: Common,
+    },
+    Opaque {
+        name: &'a str,
+        #[serde(flatten)]
+        common: Common,
+    },
+    Typedef {
+        name: &'a str,
+        aliased: JsonType<'a>,
+        layout: JsonLayouts,
+        #[serde(flatten)]
+        common: Common,
+    },
+}
+
+#[derive(Serialize)]
+struct JsonConstant<'a> {
+    name: String,
+    #[serde(rename = "type")]
+    ty: JsonType<'a>,
+    value: String,
+    #[serde(flatten)]
+    common: Common,
+}
+
+#[derive(Serialize)]
+struct JsonGlobal<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: JsonType<'a>,
+    mutable: bool,
+    #[serde(flatten)]
+    common: Common,
+}
+
+#[derive(Serialize)]
+struct JsonArg<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: JsonType<'a>,
+}
+
+#[derive(Serialize)]
+struct JsonFunction<'a> {
+    name: &'a str,
+    args: Vec<JsonArg<'a>>,
+    ret: JsonType<'a>,
+    #[serde(skip_serializing_if = "is_false")]
+    variadic: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    no_return: bool,
+    #[serde(skip_serializing_if = "CallingConvention::is_c")]
+    calling_convention: CallingConvention,
+    #[serde(flatten)]
+    common: Common,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+fn repr_type(repr: ReprType) -> &'static str {
+    match repr {
+        ReprType::U8 => "uint8_t",
+        ReprType::U16 => "uint16_t",
+        ReprType::U32 => "uint32_t",
+        ReprType::USize => "uintptr_t",
+        ReprType::I8 => "int8_t",
+        ReprType::I16 => "int16_t",
+        ReprType::I32 => "int32_t",
+        ReprType::ISize => "intptr_t",
+    }
+}
+
+/// Writes a JSON description of the ABI of `bindings`.
+pub(crate) struct JsonWriter<'a> {
+    bindings: &'a Bindings,
+    engines: LayoutEngines<'a>,
+}
+
+impl<'a> JsonWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        JsonWriter {
+            bindings,
+            engines: LayoutEngines::new(&bindings.items, bindings.data_layout),
+        }
+    }
+
+    pub fn write<F: Write>(&self, mut out: F) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut out, &self.abi())?;
+        writeln!(out)
+    }
+
+    /// Returns the description as a JSON value, like it's written.
+    pub fn value(&self) -> Value {
+        serde_json::to_value(&self.abi()).unwrap()
+    }
+
+    fn abi(&self) -> Abi<'a> {
+        let mut types = Vec::new();
+        let mut constants: Vec<_> = self
+            .bindings
+            .constants
+            .iter()
+            .filter_map(|x| self.constant(x, None))
+            .collect();
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.push_enum(item, x, &mut types),
+                ItemContainer::Struct(ref x) => {
+                    types.push(self.structure(x));
+                    for constant in &x.associated_constants {
+                        constants.extend(self.constant(constant, Some(x)));
+                    }
+                }
+                ItemContainer::Union(ref x) => types.push(JsonItem::Union {
+                    name: x.export_name(),
+                    fields: x.fields.iter().map(|x| self.field(x, None)).collect(),
+                    packed: match x.alignment {
+                        Some(ReprAlign::Packed(n)) => Some(n),
+                        _ => None,
+                    },
+                    align: match x.alignment {
+                        Some(ReprAlign::Align(n)) => Some(n),
+                        _ => None,
+                    },
+                    layout: self.layouts(item),
+                    common: Common::new(&x.cfg, &x.documentation),
+                }),
+                ItemContainer::OpaqueItem(ref x) => types.push(JsonItem::Opaque {
+                    name: x.export_name(),
+                    common: Common::new(&x.cfg, &x.documentation),
+                }),
+                ItemContainer::Typedef(ref x) => types.push(JsonItem::Typedef {
+                    name: x.export_name(),
+                    aliased: JsonType::new(&x.aliased),
+                    layout: self.layouts(item),
+                    common: Common::new(&x.cfg, &x.documentation),
+                }),
+            }
+        }
+
+        Abi {
+            constants,
+            types,
+            globals: self
+                .bindings
+                .globals
+                .iter()
+                .map(|x| self.global(x))
+                .collect(),
+            functions: self
+                .bindings
+                .functions
+                .iter()
+                .map(|x| self.function(x))
+                .collect(),
+        }
+    }
+
+    fn layouts(&self, item: &ItemContainer) -> JsonLayouts {
+        self.engines
+            .layouts(|engine| engine.item_layout(item).map(|x| JsonLayout::new(x, None)))
+            .into()
+    }
+
+    fn field(
+        &self,
+        &(ref name, ref ty, _): &'a (String, Type, Documentation),
+        bits: Option<u32>,
+    ) -> JsonField<'a> {
+        JsonField {
+            name,
+            ty: JsonType::new(ty),
+            bits,
+        }
+    }
+
+    fn structure(&self, s: &'a Struct) -> JsonItem<'a> {
+        let fields = s
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let bits = s.bitfield_widths.as_ref().map(|widths| widths[i]);
+                self.field(x, bits)
+            })
+            .collect();
+        let layout = |engine: &LayoutEngine| {
+            engine
+                .struct_layout(s)
+                .map(|x| JsonLayout::new(x.layout, Some(x.offsets)))
+        };
+        JsonItem::Struct {
+            name: s.export_name(),
+            fields,
+            packed: match s.alignment {
+                Some(ReprAlign::Packed(n)) => Some(n),
+                _ => None,
+            },
+            align: match s.alignment {
+                Some(ReprAlign::Align(n)) => Some(n),
+                _ => None,
+            },
+            layout: self.engines.layouts(layout).into(),
+            common: Common::new(&s.cfg, &s.documentation),
+        }
+    }
+
+    /// Pushes a fieldless enum, or the tag, bodies and union of a tagged
+    /// enum.
+    fn push_enum(&self, item: &ItemContainer, e: &'a Enum, types: &mut Vec<JsonItem<'a>>) {
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        let mut discriminant = -1;
+        let variants = e
+            .variants
+            .iter()
+            .map(|variant| {
+                discriminant = variant.discriminant.unwrap_or(discriminant + 1);
+                JsonVariant {
+                    name: &variant.export_name,
+                    discriminant,
+                    body: variant
+                        .body
+                        .as_ref()
+                        .map(|&(_, ref body)| body.export_name()),
+                }
+            })
+            .collect();
+
+        let layout = |engine: &LayoutEngine| engine.tag_layout(e).map(|x| JsonLayout::new(x, None));
+        types.push(JsonItem::Enum {
+            name: tag,
+            ty: e.repr.ty.map(repr_type),
+            variants,
+            layout: self.engines.layouts(layout).into(),
+            common: Common::new(&e.cfg, &e.documentation),
+        });
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                types.push(self.structure(body));
+            }
+        }
+
+        types.push(JsonItem::TaggedEnum {
+            name: e.export_name(),
+            tag,
+            separate_tag: e.repr.style == ReprStyle::C,
+            layout: self.layouts(item),
+            common: Common::new(&e.cfg, &Documentation::none()),
+        });
+    }
+
+    fn constant(
+        &self,
+        constant: &'a Constant,
+        associated_to: Option<&Struct>,
+    ) -> Option<JsonConstant<'a>> {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return None;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return None;
+        }
+
+        let name = match constant.associated_to {
+            None => constant.export_name().to_owned(),
+            Some(ref path) => {
+                let associated_name = match associated_to {
+                    Some(s) => s.export_name().to_owned(),
+                    None => {
+                        let mut name = path.name().to_owned();
+                        self.bindings.config.export.rename(&mut name);
+                        name
+                    }
+                };
+                format!("{}_{}", associated_name, constant.export_name())
+            }
+        };
+
+        Some(JsonConstant {
+            name,
+            ty: JsonType::new(&constant.ty),
+            value: constant.value.to_string(),
+            common: Common::new(&constant.cfg, &constant.documentation),
+        })
+    }
+
+    fn global(&self, global: &'a Static) -> JsonGlobal<'a> {
+        JsonGlobal {
+            name: global.export_name(),
+            ty: JsonType::new(&global.ty),
+            mutable: global.mutable,
+            common: Common::new(&global.cfg, &global.documentation),
+        }
+    }
+
+    fn function(&self, function: &'a Function) -> JsonFunction<'a> {
+        JsonFunction {
+            name: function.path().name(),
+            args: function
+                .args
+                .iter()
+                .map(|(name, ty)| JsonArg {
+                    name,
+                    ty: JsonType::new(ty),
+                })
+                .collect(),
+            ret: JsonType::new(&function.ret),
+            variadic: function.variadic,
+            no_return: function.no_return,
+            calling_convention: function.calling_convention,
+            common: Common::new(&function.cfg, &function.documentation),
+        }
+    }
+}
@@ -2,17 +2,36 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
 use std::mem;
 
+use syn;
+
 use bindgen::bindings::Bindings;
-use bindgen::config::{Config, Language};
+use bindgen::config::{
+    BitflagsStyle, Config, FieldOffsetStyle, KeywordCollisions, Language, LanguageVersion,
+    ResultStyle, SortKey, TransparentStyle,
+};
 use bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use bindgen::dependencies::Dependencies;
+use bindgen::depgraph::DependencyGraph;
+use bindgen::diagnostic::{Diagnostic, Lint};
 use bindgen::error::Error;
-use bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use bindgen::ir::layout::{DataLayout, LayoutEngine, LayoutEngines};
+use bindgen::ir::{consteval, layout};
+use bindgen::ir::{
+    AnnotationSet, ArrayLength, CallingConvention, Cfg, CfgValue, ConstEvaluator, Constant,
+    Documentation, Enum, Function, GenericPath, Item, ItemContainer, ItemMap, KnownCfgs, Literal,
+    PrimitiveType, ReprAlign,
+};
+use bindgen::ir::{
+    ErasedTypes, GenericDefaults, GenericParams, OpaqueItem, Path, Static, Struct, Type, Typedef,
+    Union,
+};
 use bindgen::monomorph::Monomorphs;
+use bindgen::rename::{IdentifierType, RenameRule};
+use bindgen::reserved;
 use bindgen::ItemType;
 
 #[derive(Debug, Clone)]
@@ -26,8 +45,50 @@ pub struct Library {
     opaque_items: ItemMap<OpaqueItem>,
     typedefs: ItemMap<Typedef>,
     functions: Vec<Function>,
+    /// The `#[inline]` functions, which are exported with the other functions
+    /// once they're given bodies.
+    inline_functions: Vec<Function>,
+    trait_impls: BTreeMap<Path, BTreeSet<String>>,
+    source_order: Vec<Path>,
+    targets: Vec<KnownCfgs>,
+    /// The diagnostics about the items, which the bindings carry on.
+    diagnostics: Vec<Diagnostic>,
 }
 
+/// The traits that C++ operators can be generated for, with the method of
+/// each operator and its name.
+const OPERATORS: &[(&str, &str, &str)] = &[
+    ("Add", "add", "+"),
+    ("Sub", "sub", "-"),
+    ("Mul", "mul", "*"),
+    ("Div", "div", "/"),
+    ("Rem", "rem", "%"),
+    ("BitAnd", "bitand", "&"),
+    ("BitOr", "bitor", "|"),
+    ("BitXor", "bitxor", "^"),
+    ("Shl", "shl", "<<"),
+    ("Shr", "shr", ">>"),
+    ("Neg", "neg", "-"),
+    ("AddAssign", "add_assign", "+="),
+    ("SubAssign", "sub_assign", "-="),
+    ("MulAssign", "mul_assign", "*="),
+    ("DivAssign", "div_assign", "/="),
+    ("RemAssign", "rem_assign", "%="),
+    ("BitAndAssign", "bitand_assign", "&="),
+    ("BitOrAssign", "bitor_assign", "|="),
+    ("BitXorAssign", "bitxor_assign", "^="),
+    ("ShlAssign", "shl_assign", "<<="),
+    ("ShrAssign", "shr_assign", ">>="),
+    ("PartialEq", "eq", "=="),
+    ("PartialEq", "ne", "!="),
+    ("PartialOrd", "lt", "<"),
+    ("PartialOrd", "le", "<="),
+    ("PartialOrd", "gt", ">"),
+    ("PartialOrd", "ge", ">="),
+    ("Index", "index", "[]"),
+    ("IndexMut", "index_mut", "[]"),
+];
+
 impl Library {
     pub fn new(
         config: Config,
@@ -39,6 +100,11 @@ impl Library {
         opaque_items: ItemMap<OpaqueItem>,
         typedefs: ItemMap<Typedef>,
         functions: Vec<Function>,
+        inline_functions: Vec<Function>,
+        trait_impls: BTreeMap<Path, BTreeSet<String>>,
+        source_order: Vec<Path>,
+        targets: Vec<KnownCfgs>,
+        diagnostics: Vec<Diagnostic>,
     ) -> Library {
         Library {
             config: config,
@@ -50,21 +116,78 @@ impl Library {
             opaque_items: opaque_items,
             typedefs: typedefs,
             functions: functions,
+            inline_functions: inline_functions,
+            trait_impls: trait_impls,
+            source_order: source_order,
+            targets: targets,
+            diagnostics: diagnostics,
         }
     }
 
     pub fn generate(mut self) -> Result<Bindings, Error> {
+        if let Err((file, error)) = self.config.inject.read_files() {
+            return Err(Error::InjectionFile(
+                file.display().to_string(),
+                error.to_string(),
+            ));
+        }
+        self.give_inline_bodies();
+        self.resolve_cfgs()?;
         self.remove_excluded();
-        self.functions.sort_by(|x, y| x.path.cmp(&y.path));
+        self.evaluate_constants();
+        // Functions are already in the order they were declared in.
+        if self.config.function_sort_by() != SortKey::SourceOrder {
+            self.functions.sort_by(|x, y| x.path.cmp(&y.path));
+        }
         self.transfer_annotations();
+        self.assign_source_order();
+        self.propagate_must_use();
+        self.force_enum_underlying_types();
+        self.fill_generic_defaults();
+        self.apply_transparent_styles();
+        self.apply_bitflags_styles();
+        self.make_discriminants_explicit();
         self.simplify_standard_types();
+        self.lower_results();
 
+        // Tuples and slices are lowered once generics have been instantiated,
+        // so that their element types are concrete. Items that can't be
+        // lowered are skipped before they're instantiated, though.
+        self.skip_unlowerable_items();
+
+        let mut instantiations = self.load_instantiations();
+        if self.config.language != Language::Cxx {
+            self.instantiate_monomorphs(&mut instantiations);
+        }
+        self.lower_types();
+        self.elide_zero_sized_fields();
+        self.hoist_callbacks();
         if self.config.language == Language::C {
-            self.instantiate_monomorphs();
             self.resolve_declaration_types();
         }
 
         self.rename_items();
+        if self.config.language == Language::C || self.config.language == Language::Cxx {
+            let collisions = self.keyword_collisions();
+            if !collisions.is_empty() {
+                if self.config.keywords.collisions == KeywordCollisions::Error {
+                    let collisions = collisions.into_iter().map(|x| x.1).collect();
+                    return Err(Error::KeywordCollisions(collisions));
+                }
+                for (item, collision) in collisions {
+                    self.diagnostics.push(Diagnostic::new(
+                        Lint::KeywordCollision,
+                        &item,
+                        format!(
+                            "{} collides with a keyword or macro, but can't be renamed.",
+                            collision
+                        ),
+                    ));
+                }
+            }
+            self.add_error_names();
+            self.layout_opaque_items();
+        }
 
         let mut dependencies = Dependencies::new();
 
@@ -92,26 +215,146 @@ impl Library {
                 }
             }
         }
+        for ty in &instantiations {
+            ty.add_dependencies(&self, &mut dependencies);
+        }
+        for path in &dependencies.unknown {
+            self.diagnostics.push(Diagnostic::new(
+                Lint::UnknownType,
+                path.name(),
+                format!(
+                    "Can't find {}. This usually means that this type was \
+                     incompatible or not found.",
+                    path
+                ),
+            ));
+        }
 
         dependencies.sort();
+        let forward_declarations =
+            if self.config.language == Language::C || self.config.language == Language::Cxx {
+                self.config.forward_declarations
+            } else {
+                None
+            };
+        let type_sort_by = self.config.type_sort_by();
+        if forward_declarations.is_some()
+            || type_sort_by.map_or(false, |x| x != SortKey::Dependency)
+            || self.config.has_sections()
+        {
+            let default_sort_by = if forward_declarations.is_some() {
+                SortKey::Name
+            } else {
+                SortKey::Dependency
+            };
+            dependencies.sort_by_declarations(
+                &self.config,
+                forward_declarations,
+                type_sort_by.unwrap_or(default_sort_by),
+            );
+        }
 
-        let items = dependencies.order;
+        let mut items = dependencies.order;
+        if self.config.language == Language::C || self.config.language == Language::Cxx {
+            self.include_external_types(&mut items);
+            self.inline_anonymous_types(&mut items);
+        }
+        let constant_sort_by = self.config.constant_sort_by();
         let constants = if self.config.export.should_generate(ItemType::Constants) {
-            self.constants.to_vec()
+            let mut constants = self.constants.to_vec();
+            sort_by_source_order(&mut constants, constant_sort_by);
+            sort_by_section(&mut constants, &self.config, "Constants");
+            consteval::sort_by_references(constants)
         } else {
             vec![]
         };
 
         let globals = if self.config.export.should_generate(ItemType::Globals) {
-            self.globals.to_vec()
+            let mut globals = self.globals.to_vec();
+            sort_by_source_order(&mut globals, constant_sort_by);
+            sort_by_section(&mut globals, &self.config, "Globals");
+            globals
         } else {
             vec![]
         };
         let functions = if self.config.export.should_generate(ItemType::Functions) {
-            mem::replace(&mut self.functions, vec![])
+            let mut functions = mem::replace(&mut self.functions, vec![]);
+            if self.config.has_sections() {
+                let config = &self.config;
+                functions.sort_by_key(|x| {
+                    config
+                        .sections
+                        .rank(&config.section_of(&x.annotations, "Functions"))
+                });
+            }
+            functions
         } else {
             vec![]
         };
+        // The `static inline` functions are written next to the types they
+        // use, in C and C++ only.
+        let (inline_functions, functions): (Vec<_>, Vec<_>) =
+            functions.into_iter().partition(|x| x.inline_body.is_some());
+        let inline_functions =
+            if self.config.language == Language::C || self.config.language == Language::Cxx {
+                inline_functions
+            } else {
+                vec![]
+            };
+
+        if self.config.language == Language::Cxx {
+            self.attach_methods(&mut items, &functions);
+        }
+
+        let module_dependencies = if self.config.header_per_module {
+            self.header_dependencies(&items, &constants, &globals, &functions, "crate", |_, x| {
+                x.module.clone()
+            })
+        } else {
+            BTreeMap::new()
+        };
+
+        let output_dependencies =
+            if self.config.language == Language::C || self.config.language == Language::Cxx {
+                let config = &self.config;
+                self.header_dependencies(
+                    &items,
+                    &constants,
+                    &globals,
+                    &functions,
+                    "main",
+                    |name, x| config.output_of(name, x),
+                )
+            } else {
+                BTreeMap::new()
+            };
+        // The main header includes the headers it depends on before its own
+        // items, unless it includes the secondary ones after them.
+        let dependencies = output_dependencies.get(&None);
+        if let (true, Some(dependencies)) = (self.config.output.include_in_main, dependencies) {
+            for dependency in dependencies {
+                warn!(
+                    "The main header uses items of `{}`, which is only included after it.",
+                    dependency.as_ref().unwrap()
+                );
+            }
+        }
+
+        let dependency_graph = self.dependency_graph(&items, &constants, &globals, &functions);
+
+        let data_layout = self.data_layout();
+        let computes_offsets = items.iter().any(|item| match *item {
+            ItemContainer::Struct(ref x) => {
+                self.config.structure.field_offsets(&x.annotations)
+                    == Some(FieldOffsetStyle::Computed)
+            }
+            _ => false,
+        });
+        let struct_layouts = if self.config.layout_asserts || computes_offsets {
+            layout::struct_layouts(&items, data_layout)
+        } else {
+            HashMap::new()
+        };
 
         Ok(Bindings::new(
             self.config,
@@ -120,9 +363,239 @@ impl Library {
             globals,
             items,
             functions,
+            inline_functions,
+            module_dependencies,
+            output_dependencies,
+            struct_layouts,
+            data_layout,
+            dependency_graph,
+            self.diagnostics,
         ))
     }
 
+    /// Gives the structs and opaque items that derive methods or operators the
+    /// functions that are their methods and operators.
+    fn attach_methods(&self, items: &mut [ItemContainer], functions: &[Function]) {
+        let methods_of = |path: &Path| -> Vec<Function> {
+            functions
+                .iter()
+                .filter(|x| x.impl_path.as_ref() == Some(path))
+                .cloned()
+                .collect()
+        };
+        for item in items {
+            match *item {
+                ItemContainer::Struct(ref mut x) if !x.is_transparent => {
+                    if self.config.structure.derive_methods(&x.annotations) {
+                        x.methods = methods_of(&x.path);
+                    }
+                    if self.config.structure.derive_operators(&x.annotations) {
+                        x.operators = self.operators_of(&x.path, x.export_name(), functions);
+                    }
+                }
+                ItemContainer::OpaqueItem(ref mut x) => {
+                    if self.config.structure.derive_methods(&x.annotations) {
+                        x.methods = methods_of(&x.path);
+                    }
+                    if self.config.structure.derive_operators(&x.annotations) {
+                        x.operators = self.operators_of(&x.path, x.export_name(), functions);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Finds the functions calling the operator traits that a type implements,
+    /// which are named after the type and the method of the trait and take the
+    /// type as their first argument, by value or by pointer.
+    fn operators_of(
+        &self,
+        path: &Path,
+        export_name: &str,
+        functions: &[Function],
+    ) -> Vec<(String, Function)> {
+        let traits = match self.trait_impls.get(path) {
+            Some(traits) => traits,
+            None => return vec![],
+        };
+        let prefix =
+            RenameRule::SnakeCase.apply_to_pascal_case(path.name(), IdentifierType::Function);
+        let takes_self = |function: &Function| {
+            let ty = match function.args.first() {
+                Some(&(_, Type::ConstPtr(ref ty, _))) | Some(&(_, Type::Ptr(ref ty, _))) => &**ty,
+                Some(&(_, ref ty)) => ty,
+                None => return false,
+            };
+            match *ty {
+                Type::Path(ref generic) => generic.export_name() == export_name,
+                _ => false,
+            }
+        };
+
+        let mut operators = vec![];
+        for &(trait_name, method, operator) in OPERATORS {
+            if !traits.contains(trait_name) {
+                continue;
+            }
+            let name = format!("{}_{}", prefix, method);
+            if let Some(function) = functions
+                .iter()
+                .find(|x| x.path.name() == name && takes_self(x))
+            {
+                operators.push((format!("operator{}", operator), function.clone()));
+            }
+        }
+        operators
+    }
+
+    /// Finds the other headers that the items of each header depend on, which
+    /// it needs to include. `header` gives the header of an item from its name
+    /// and annotations, `None` being the `root` one.
+    fn header_dependencies<F>(
+        &self,
+        items: &[ItemContainer],
+        constants: &[Constant],
+        globals: &[Static],
+        functions: &[Function],
+        root: &str,
+        header: F,
+    ) -> BTreeMap<Option<String>, BTreeSet<Option<String>>>
+    where
+        F: Fn(&str, &AnnotationSet) -> Option<String>,
+    {
+        let mut result = BTreeMap::new();
+        {
+            let mut add = |module: Option<String>, dependencies: Dependencies| {
+                let modules = result.entry(module.clone()).or_insert_with(BTreeSet::new);
+                for item in &dependencies.order {
+                    let item = item.deref();
+                    let dependency = header(item.path().name(), item.annotations());
+                    if dependency != module {
+                        modules.insert(dependency);
+                    }
+                }
+            };
+
+            for item in items {
+                let mut dependencies = Dependencies::new();
+                item.deref().add_dependencies(self, &mut dependencies);
+                let item = item.deref();
+                add(header(item.path().name(), item.annotations()), dependencies);
+            }
+            for constant in constants {
+                let mut dependencies = Dependencies::new();
+                constant.add_dependencies(self, &mut dependencies);
+                add(
+                    header(constant.path.name(), &constant.annotations),
+                    dependencies,
+                );
+            }
+            for global in globals {
+                let mut dependencies = Dependencies::new();
+                global.add_dependencies(self, &mut dependencies);
+                add(
+                    header(global.path.name(), &global.annotations),
+                    dependencies,
+                );
+            }
+            for function in functions {
+                let mut dependencies = Dependencies::new();
+                function.add_dependencies(self, &mut dependencies);
+                add(
+                    header(function.path.name(), &function.annotations),
+                    dependencies,
+                );
+            }
+        }
+
+        for (module, dependencies) in &result {
+            for dependency in dependencies {
+                if module < dependency
+                    && result.get(dependency).map_or(false, |x| x.contains(module))
+                {
+                    warn!(
+                        "The headers of `{}` and `{}` depend on each other.",
+                        module.as_ref().map_or(root, |x| x),
+                        dependency.as_ref().map_or(root, |x| x)
+                    );
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Finds the items that each item of the bindings uses directly.
+    fn dependency_graph(
+        &self,
+        items: &[ItemContainer],
+        constants: &[Constant],
+        globals: &[Static],
+        functions: &[Function],
+    ) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        {
+            let mut add = |name: &str,
+                           kind: &'static str,
+                           annotations: &AnnotationSet,
+                           dependencies: Dependencies| {
+                let names = dependencies
+                    .order
+                    .iter()
+                    .map(|x| x.deref().export_name().to_owned());
+                graph.add(name, kind, annotations.crate_name.as_ref(), names);
+            };
+
+            for item in items {
+                let mut dependencies = Dependencies::direct();
+                item.deref().add_dependencies(self, &mut dependencies);
+                let kind = match *item {
+                    ItemContainer::Constant(..) => "constant",
+                    ItemContainer::Static(..) => "static",
+                    ItemContainer::OpaqueItem(..) => "opaque",
+                    ItemContainer::Struct(..) => "struct",
+                    ItemContainer::Union(..) => "union",
+                    ItemContainer::Enum(..) => "enum",
+                    ItemContainer::Typedef(..) => "typedef",
+                };
+                let item = item.deref();
+                add(item.export_name(), kind, item.annotations(), dependencies);
+            }
+            for constant in constants {
+                let mut dependencies = Dependencies::direct();
+                constant.add_dependencies(self, &mut dependencies);
+                add(
+                    constant.export_name(),
+                    "constant",
+                    &constant.annotations,
+                    dependencies,
+                );
+            }
+            for global in globals {
+                let mut dependencies = Dependencies::direct();
+                global.add_dependencies(self, &mut dependencies);
+                add(
+                    global.export_name(),
+                    "static",
+                    &global.annotations,
+                    dependencies,
+                );
+            }
+            for function in functions {
+                let mut dependencies = Dependencies::direct();
+                function.add_dependencies(self, &mut dependencies);
+                add(
+                    function.path().name(),
+                    "function",
+                    &function.annotations,
+                    dependencies,
+                );
+            }
+        }
+        graph
+    }
+
     pub fn get_items(&self, p: &Path) -> Option<Vec<ItemContainer>> {
         macro_rules! find {
             ($field:ident, $kind:ident) => {
@@ -143,11 +616,186 @@ impl Library {
         None
     }
 
+    /// Declares `const char *my_error_name(MyError value)`, returning the name
+    /// of a variant, for the fieldless enums with the `error-enum` annotation.
+    /// With `enum.define_error_names` it's defined as a `static inline`
+    /// function after the enum.
+    fn add_error_names(&mut self) {
+        let config = &self.config;
+        let mut functions = Vec::new();
+        self.enums.for_all_items(|x| {
+            if !x.annotations.bool("error-enum").unwrap_or(false) {
+                return;
+            }
+            if x.tag.is_some() || !x.generic_params.is_empty() {
+                warn!(
+                    "Skip the error name function of {} - (only fieldless enums have one).",
+                    x.export_name()
+                );
+                return;
+            }
+
+            let name = RenameRule::SnakeCase
+                .apply_to_pascal_case(x.export_name(), IdentifierType::Function);
+            let mut annotations = AnnotationSet::new();
+            annotations.module = x.annotations.module.clone();
+            annotations.crate_name = x.annotations.crate_name.clone();
+            let mut function = Function {
+                path: Path::new(format!("{}_name", name)),
+                ret: Type::ConstPtr(Box::new(Type::Primitive(PrimitiveType::Char)), false),
+                args: vec![(
+                    "value".to_owned(),
+                    Type::Path(GenericPath::new(x.path.clone(), vec![])),
+                )],
+                variadic: false,
+                no_return: false,
+                calling_convention: CallingConvention::C,
+                unwind: false,
+                extern_decl: false,
+                impl_path: None,
+                inline_body: None,
+                cfg: x.cfg.clone(),
+                annotations,
+                documentation: Documentation::none(),
+            };
+            function.rename_for_config(config);
+            if config.language == Language::C && !config.style.generate_typedef() {
+                let mut resolver = DeclarationTypeResolver::new();
+                x.collect_declaration_types(&mut resolver);
+                function.resolve_declaration_types(&resolver);
+            }
+
+            if config.enumeration.define_error_names(&x.annotations) {
+                let null = if config.language == Language::Cxx
+                    && config.has_version(LanguageVersion::Cxx11)
+                {
+                    "nullptr"
+                } else {
+                    "NULL"
+                };
+                let mut body = vec!["switch (value) {".to_owned()];
+                for variant in &x.variants {
+                    body.push(format!(
+                        "  case {}: return \"{}\";",
+                        Enum::variant_path(config, x.export_name(), &variant.export_name),
+                        variant.export_name
+                    ));
+                }
+                body.push("}".to_owned());
+                body.push(format!("return {};", null));
+                function.inline_body = Some(body.join("\n"));
+            }
+            functions.push(function);
+        });
+        self.functions.extend(functions);
+    }
+
+    /// Exports the `#[inline]` functions given bodies with the `inline-body`
+    /// annotation or `fn.inline_bodies`, as `static inline` functions.
+    fn give_inline_bodies(&mut self) {
+        for mut function in mem::replace(&mut self.inline_functions, vec![]) {
+            let body = match function.annotations.atom("inline-body") {
+                Some(body) => body,
+                None => self
+                    .config
+                    .function
+                    .inline_bodies
+                    .get(function.path.name())
+                    .cloned(),
+            };
+            match body {
+                Some(body) => {
+                    function.inline_body = Some(body);
+                    self.functions.push(function);
+                }
+                None => info!(
+                    "Skip inline fn {} - (no `inline-body` given).",
+                    function.path.name()
+                ),
+            }
+        }
+    }
+
+    /// Resolves the cfgs of the items with the known cfgs of the targets,
+    /// removing the items that aren't compiled for any of them and
+    /// simplifying the cfgs of the others to the conditions left to the
+    /// preprocessor.
+    fn resolve_cfgs(&mut self) -> Result<(), Error> {
+        if self.targets.is_empty() {
+            return Ok(());
+        }
+        let targets = &self.targets;
+        KnownCfgs::add_builtin_guards(targets, &mut self.config.defines);
+
+        fn resolve<T: Item>(targets: &[KnownCfgs], item: &T) -> CfgValue {
+            let crate_name = item.annotations().crate_name.as_ref().map(|s| s.as_str());
+            KnownCfgs::resolve_targets(targets, item.cfg(), crate_name)
+        }
+
+        fn resolve_items<T: Item + Clone>(targets: &[KnownCfgs], items: &mut ItemMap<T>) {
+            items.filter(|x| resolve(targets, x) == CfgValue::Known(false));
+            items.for_all_items_mut(|x| {
+                *x.cfg_mut() = match resolve(targets, x) {
+                    CfgValue::Known(..) => None,
+                    CfgValue::Unknown(cfg) => Some(cfg),
+                };
+            });
+        }
+
+        resolve_items(targets, &mut self.constants);
+        resolve_items(targets, &mut self.globals);
+        resolve_items(targets, &mut self.enums);
+        resolve_items(targets, &mut self.structs);
+        resolve_items(targets, &mut self.unions);
+        resolve_items(targets, &mut self.opaque_items);
+        resolve_items(targets, &mut self.typedefs);
+
+        let mut functions = Vec::new();
+        for mut function in mem::replace(&mut self.functions, vec![]) {
+            let crate_name = function.annotations.crate_name.as_ref().map(|s| s.as_str());
+            match KnownCfgs::resolve_targets(targets, function.cfg.as_ref(), crate_name) {
+                CfgValue::Known(false) => continue,
+                CfgValue::Known(true) => function.cfg = None,
+                CfgValue::Unknown(cfg) => function.cfg = Some(cfg),
+            }
+            functions.push(function);
+        }
+        self.functions = functions;
+
+        // An item written without the condition of its targets would be
+        // declared for all of them.
+        let defines = &self.config.defines;
+        let mut unguarded = Vec::new();
+        {
+            let mut check = |cfg: Option<&Cfg>| {
+                if let Some(cfg) = cfg {
+                    cfg.unguarded_targets(defines, &mut unguarded);
+                }
+            };
+            self.constants.for_all_items(|x| check(x.cfg()));
+            self.globals.for_all_items(|x| check(x.cfg()));
+            self.enums.for_all_items(|x| check(x.cfg()));
+            self.structs.for_all_items(|x| check(x.cfg()));
+            self.unions.for_all_items(|x| check(x.cfg()));
+            self.opaque_items.for_all_items(|x| check(x.cfg()));
+            self.typedefs.for_all_items(|x| check(x.cfg()));
+            for function in &self.functions {
+                check(function.cfg.as_ref());
+            }
+        }
+        if !unguarded.is_empty() {
+            return Err(Error::UnguardedTargets(unguarded));
+        }
+        Ok(())
+    }
+
     fn remove_excluded(&mut self) {
         let config = &self.config;
         // FIXME: interpret `config.export.exclude` as `Path`s.
-        self.functions
-            .retain(|x| !config.export.exclude.iter().any(|y| y == x.path().name()));
+        self.functions.retain(|x| {
+            !config.export.exclude.iter().any(|y| y == x.path().name())
+                && config.function.selects(x.path().name())
+        });
         self.enums
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
         self.structs
@@ -158,12 +806,298 @@ impl Library {
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
         self.typedefs
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
-        self.globals
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+        self.globals.filter(|x| {
+            config.export.exclude.iter().any(|y| y == x.path().name())
+                || !config.function.selects(x.path().name())
+        });
         self.constants
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
     }
 
+    /// Evaluates the casts of other constants, which languages write
+    /// differently, skipping the constants whose casts can't be evaluated.
+    /// Integer operations that would overflow an `int` in C are replaced by
+    /// their values.
+    fn evaluate_constants(&mut self) {
+        let constants = ConstEvaluator::new(&self.constants);
+        let diagnostics = &mut self.diagnostics;
+        let mut evaluate = |x: &mut Constant| match x.value.evaluate_casts(&constants) {
+            Ok(value) => {
+                let is_integer = match x.ty {
+                    Type::Primitive(ref ty) => consteval::integer_widths(ty).is_some(),
+                    _ => false,
+                };
+                x.value = match value {
+                    Literal::BinOp { .. } if is_integer && !constants.fits_int(&value) => {
+                        match constants.eval(&value) {
+                            Ok(result) => Literal::Expr(result.to_string()),
+                            Err(_) => value,
+                        }
+                    }
+                    value => value,
+                };
+                true
+            }
+            Err(msg) => {
+                let message = format!("Skip {} - ({})", x.path, msg);
+                diagnostics.push(Diagnostic::new(
+                    Lint::UnsupportedType,
+                    x.path.name(),
+                    message,
+                ));
+                false
+            }
+        };
+
+        let mut skipped = Vec::new();
+        self.constants.for_all_items_mut(|x| {
+            if !evaluate(x) {
+                skipped.push(x.path.clone());
+            }
+        });
+        self.constants.filter(|x| skipped.contains(&x.path));
+        self.structs.for_all_items_mut(|x| {
+            let associated_constants = mem::replace(&mut x.associated_constants, Vec::new());
+            for mut constant in associated_constants {
+                if evaluate(&mut constant) {
+                    x.associated_constants.push(constant);
+                }
+            }
+        });
+    }
+
+    /// Removes the types that `[export.external]` maps to existing headers,
+    /// which are included instead.
+    fn include_external_types(&mut self, items: &mut Vec<ItemContainer>) {
+        let mut headers: Vec<String> = Vec::new();
+        {
+            let export = &self.config.export;
+            items.retain(|item| {
+                let item = item.deref();
+                let crate_name = item.annotations().crate_name.as_ref().map(|s| s.as_str());
+                match export.external_header(crate_name, item.path().name()) {
+                    Some(header) => {
+                        if !headers.iter().any(|x| x == header) {
+                            headers.push(header.to_owned());
+                        }
+                        false
+                    }
+                    None => true,
+                }
+            });
+        }
+        for header in headers {
+            if !self.config.includes.contains(&header) {
+                self.config.includes.push(header);
+            }
+        }
+    }
+
+    /// Moves the structs and unions with the `anonymous` annotation into the
+    /// one struct or union that has them as the type of a field, which writes
+    /// them as anonymous members in place of it. C99 has no anonymous members,
+    /// so they stay named there, like those used anywhere else.
+    fn inline_anonymous_types(&self, items: &mut Vec<ItemContainer>) {
+        let config = &self.config;
+        if config.language == Language::C && !config.has_version(LanguageVersion::C11) {
+            return;
+        }
+
+        let candidates: Vec<Path> = items
+            .iter()
+            .filter(|item| item.deref().annotations().bool("anonymous") == Some(true))
+            .map(|item| item.deref().path().clone())
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+
+        // Every type of the bindings, with the struct or union it's the type
+        // of a field of, if it is.
+        let mut types: Vec<(&Type, Option<(&Path, &str)>)> = Vec::new();
+        for item in items.iter() {
+            match *item {
+                ItemContainer::Struct(ref x) => {
+                    let can_inline = can_have_anonymous_members(x, config);
+                    for &(ref name, ref ty, _) in &x.fields {
+                        types.push((ty, Some((&x.path, name.as_str())).filter(|_| can_inline)));
+                    }
+                    types.extend(x.associated_constants.iter().map(|x| (&x.ty, None)));
+                }
+                ItemContainer::Union(ref x) => {
+                    for &(ref name, ref ty, _) in &x.fields {
+                        types.push((ty, Some((&x.path, name.as_str()))));
+                    }
+                }
+                ItemContainer::Enum(ref x) => {
+                    for variant in &x.variants {
+                        if let Some((_, ref body)) = variant.body {
+                            types.extend(body.fields.iter().map(|x| (&x.1, None)));
+                        }
+                    }
+                }
+                ItemContainer::Typedef(ref x) => types.push((&x.aliased, None)),
+                _ => {}
+            }
+        }
+        let constants = self.constants.to_vec();
+        let globals = self.globals.to_vec();
+        types.extend(constants.iter().map(|x| (&x.ty, None)));
+        types.extend(globals.iter().map(|x| (&x.ty, None)));
+        for function in &self.functions {
+            types.push((&function.ret, None));
+            types.extend(function.args.iter().map(|x| (&x.1, None)));
+        }
+
+        // The field each candidate is the type of, if it's its only use.
+        let mut members: Vec<(Path, Path, String)> = Vec::new();
+        for path in candidates {
+            let uses: Vec<_> = types.iter().filter(|x| x.0.mentions(&path)).collect();
+            let member = match uses[..] {
+                [&(&Type::Path(ref generic), Some((parent, name)))]
+                    if generic.path() == &path && generic.generics().is_empty() =>
+                {
+                    Some((parent.clone(), name.to_owned()))
+                }
+                _ => None,
+            };
+            let item = items.iter().find(|x| x.deref().path() == &path).unwrap();
+            match member {
+                Some((parent, name)) if can_be_anonymous(item) => {
+                    members.push((path, parent, name))
+                }
+                _ => warn!(
+                    "Writing {} as a named type, since it can only be an anonymous member of \
+                     the one struct or union that has it as the type of a field.",
+                    path
+                ),
+            }
+        }
+
+        // Members are moved before the members they're a member of.
+        while !members.is_empty() {
+            let i = members
+                .iter()
+                .position(|x| !members.iter().any(|y| y.1 == x.0))
+                .unwrap();
+            let (path, parent, name) = members.remove(i);
+            let j = items
+                .iter()
+                .position(|x| x.deref().path() == &path)
+                .unwrap();
+            let member = items.remove(j);
+            match items.iter_mut().find(|x| x.deref().path() == &parent) {
+                Some(&mut ItemContainer::Struct(ref mut x)) => {
+                    x.anonymous_members.push((name, member))
+                }
+                Some(&mut ItemContainer::Union(ref mut x)) => {
+                    x.anonymous_members.push((name, member))
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Marks the functions returning `#[must_use]` types as `#[must_use]`,
+    /// like Rust warns about their unused results.
+    fn propagate_must_use(&mut self) {
+        let mut functions = mem::replace(&mut self.functions, vec![]);
+        for function in &mut functions {
+            if let Type::Path(ref generic) = function.ret {
+                let must_use = self.get_items(generic.path()).map_or(false, |items| {
+                    items.iter().any(|x| x.deref().annotations().must_use)
+                });
+                function.annotations.must_use |= must_use;
+            }
+        }
+        self.functions = functions;
+    }
+
+    /// Sets the underlying type of the enums with an `enum-underlying`
+    /// annotation, and of those without a `#[repr(prim)]` when there's a
+    /// default one, as if they had that `#[repr(prim)]`.
+    fn force_enum_underlying_types(&mut self) {
+        let default = self.config.enumeration.underlying_type.clone();
+        self.enums.for_all_items_mut(|x| {
+            let ty = match x.annotations.atom("enum-underlying") {
+                Some(Some(ty)) => ty,
+                _ => match default {
+                    Some(ref ty) if x.repr.ty.is_none() => ty.clone(),
+                    _ => return,
+                },
+            };
+            match ty.parse() {
+                Ok(ty) => x.repr.ty = Some(ty),
+                Err(msg) => warn!("Ignoring the underlying type of {}: {}", x.path, msg),
+            }
+        });
+    }
+
+    /// The data layout of the target the bindings are generated for, if
+    /// there's a single one and it's known.
+    fn data_layout(&self) -> Option<DataLayout> {
+        if self.targets.len() == 1 {
+            DataLayout::for_target(&self.targets[0])
+        } else {
+            None
+        }
+    }
+
+    /// Gives the opaque items whose layout is known the storage to define
+    /// them with, with `layout.opaque_storage` or the `cbindgen:size`
+    /// annotation.
+    fn layout_opaque_items(&mut self) {
+        let mut items = Vec::new();
+        self.structs.for_all_items(|x| items.push(x.container()));
+        self.unions.for_all_items(|x| items.push(x.container()));
+        self.enums.for_all_items(|x| items.push(x.container()));
+        self.typedefs.for_all_items(|x| items.push(x.container()));
+        self.opaque_items
+            .for_all_items(|x| items.push(x.container()));
+        let engines = LayoutEngines::new(&items, self.data_layout());
+
+        let opaque_storage = self.config.layout.opaque_storage;
+        self.opaque_items.for_all_items_mut(|x| {
+            let annotated = x.annotations.atom("size").is_some();
+            if !opaque_storage && !annotated {
+                return;
+            }
+            let storage = engines.layouts(|engine| engine.opaque_layout(x));
+            if storage.is_known() {
+                x.storage = Some(storage);
+            } else if annotated {
+                warn!(
+                    "Can't define the storage of {}, whose layout isn't known.",
+                    x.path
+                );
+            }
+        });
+    }
+
+    /// Records the position of each item in the order the items were
+    /// declared in, for `SortKey::SourceOrder`.
+    fn assign_source_order(&mut self) {
+        let mut positions = HashMap::new();
+        for (i, path) in self.source_order.iter().enumerate() {
+            positions.entry(path.clone()).or_insert(i);
+        }
+
+        fn assign<T: Item + Clone>(positions: &HashMap<Path, usize>, items: &mut ItemMap<T>) {
+            items.for_all_items_mut(|x| {
+                let position = positions.get(x.path()).cloned();
+                x.annotations_mut().source_order = position;
+            });
+        }
+
+        assign(&positions, &mut self.constants);
+        assign(&positions, &mut self.globals);
+        assign(&positions, &mut self.enums);
+        assign(&positions, &mut self.structs);
+        assign(&positions, &mut self.unions);
+        assign(&positions, &mut self.opaque_items);
+        assign(&positions, &mut self.typedefs);
+    }
+
     fn transfer_annotations(&mut self) {
         let mut annotations = HashMap::new();
 
@@ -177,7 +1111,9 @@ impl Library {
 
             self.enums.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
+                    let module = x.annotations_mut().module.take();
                     *x.annotations_mut() = annotations.clone();
+                    x.annotations_mut().module = module;
                     transferred = true;
                 } else {
                     warn!(
@@ -192,7 +1128,9 @@ impl Library {
             }
             self.structs.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
+                    let module = x.annotations_mut().module.take();
                     *x.annotations_mut() = annotations.clone();
+                    x.annotations_mut().module = module;
                     transferred = true;
                 } else {
                     warn!(
@@ -207,7 +1145,9 @@ impl Library {
             }
             self.unions.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
+                    let module = x.annotations_mut().module.take();
                     *x.annotations_mut() = annotations.clone();
+                    x.annotations_mut().module = module;
                     transferred = true;
                 } else {
                     warn!(
@@ -222,7 +1162,9 @@ impl Library {
             }
             self.opaque_items.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
+                    let module = x.annotations_mut().module.take();
                     *x.annotations_mut() = annotations.clone();
+                    x.annotations_mut().module = module;
                     transferred = true;
                 } else {
                     warn!(
@@ -237,7 +1179,9 @@ impl Library {
             }
             self.typedefs.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
+                    let module = x.annotations_mut().module.take();
                     *x.annotations_mut() = annotations.clone();
+                    x.annotations_mut().module = module;
                     transferred = true;
                 } else {
                     warn!(
@@ -254,6 +1198,46 @@ impl Library {
     }
 
     fn rename_items(&mut self) {
+        let c_like = self.config.language == Language::C || self.config.language == Language::Cxx;
+        if c_like && self.config.keywords.collisions == KeywordCollisions::Rename {
+            // Types and constants are renamed like with `export.rename`, which
+            // renames their uses too.
+            let mut names = Vec::new();
+            self.structs
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            self.unions
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            self.enums
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            self.typedefs
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            self.opaque_items
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            self.constants
+                .for_all_items(|x| names.push(x.path.name().to_owned()));
+            for name in names {
+                if self.config.keywords.is_reserved(&name) {
+                    self.config
+                        .export
+                        .rename
+                        .entry(name.clone())
+                        .or_insert_with(|| format!("{}_", name));
+                }
+            }
+        }
+
+        if self.config.objc_compat() {
+            // Types and constants are renamed like with `export.rename`,
+            // which renames their uses too.
+            for &name in reserved::OBJC_RESERVED_IDENTIFIERS {
+                self.config
+                    .export
+                    .rename
+                    .entry(name.to_owned())
+                    .or_insert_with(|| format!("{}_", name));
+            }
+        }
+
         let config = &self.config;
 
         self.globals
@@ -287,6 +1271,81 @@ impl Library {
         for item in &mut self.functions {
             item.rename_for_config(&self.config);
         }
+
+        if self.config.objc_compat() {
+            let mut symbols: Vec<_> = self
+                .functions
+                .iter()
+                .map(|x| x.path.name().to_owned())
+                .collect();
+            self.globals
+                .for_all_items(|x| symbols.push(x.export_name().to_owned()));
+            for symbol in symbols.iter().filter(|x| reserved::is_objc_reserved(x)) {
+                self.diagnostics.push(Diagnostic::new(
+                    Lint::KeywordCollision,
+                    symbol,
+                    format!(
+                        "{} clashes with an Objective-C identifier, but it's a symbol so it can't be renamed.",
+                        symbol
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// Describes the identifiers of the bindings that collide with a keyword
+    /// or macro, which are left after renaming if collisions are renamed,
+    /// along with the Rust name of the item declaring them.
+    fn keyword_collisions(&self) -> Vec<(String, String)> {
+        let keywords = &self.config.keywords;
+        let mut collisions = Vec::new();
+        let mut check = |item: &Path, name: &str, what: String| {
+            if keywords.is_reserved(name) {
+                collisions.push((item.name().to_owned(), format!("`{}`, {}", name, what)));
+            }
+        };
+
+        self.structs.for_all_items(|x| {
+            check(&x.path, x.export_name(), "a struct".to_owned());
+            for field in &x.fields {
+                let what = format!("a field of `{}`", x.export_name());
+                check(&x.path, &field.0, what);
+            }
+        });
+        self.unions.for_all_items(|x| {
+            check(&x.path, x.export_name(), "a union".to_owned());
+            for field in &x.fields {
+                let what = format!("a field of `{}`", x.export_name());
+                check(&x.path, &field.0, what);
+            }
+        });
+        self.enums.for_all_items(|x| {
+            check(&x.path, x.export_name(), "an enum".to_owned());
+            for variant in &x.variants {
+                let what = format!("a variant of `{}`", x.export_name());
+                check(&x.path, &variant.export_name, what);
+            }
+        });
+        self.typedefs
+            .for_all_items(|x| check(&x.path, x.export_name(), "a typedef".to_owned()));
+        self.opaque_items
+            .for_all_items(|x| check(&x.path, x.export_name(), "an opaque type".to_owned()));
+        self.constants
+            .for_all_items(|x| check(&x.path, x.export_name(), "a constant".to_owned()));
+        self.globals
+            .for_all_items(|x| check(&x.path, x.export_name(), "a global".to_owned()));
+        for function in &self.functions {
+            check(
+                &function.path,
+                function.path.name(),
+                "a function".to_owned(),
+            );
+            for arg in &function.args {
+                let what = format!("an argument of `{}`", function.path.name());
+                check(&function.path, &arg.0, what);
+            }
+        }
+        collisions
     }
 
     fn resolve_declaration_types(&mut self) {
@@ -332,25 +1391,616 @@ impl Library {
         }
     }
 
+    /// Completes the paths to generic items that leave out parameters with
+    /// default types, like `Foo` for `Foo<T = u32>`, with those defaults.
+    fn fill_generic_defaults(&mut self) {
+        let mut defaults = GenericDefaults::new();
+        {
+            let mut add = |path: &Path, params: &GenericParams| {
+                if params.has_defaults() {
+                    defaults.insert(path.clone(), params.clone());
+                }
+            };
+            self.structs
+                .for_all_items(|x| add(x.path(), &x.generic_params));
+            self.unions
+                .for_all_items(|x| add(x.path(), &x.generic_params));
+            self.enums
+                .for_all_items(|x| add(x.path(), &x.generic_params));
+            self.opaque_items
+                .for_all_items(|x| add(x.path(), &x.generic_params));
+            self.typedefs
+                .for_all_items(|x| add(x.path(), &x.generic_params));
+        }
+        if defaults.is_empty() {
+            return;
+        }
+
+        self.structs
+            .for_all_items_mut(|x| x.fill_generic_defaults(&defaults));
+        self.unions
+            .for_all_items_mut(|x| x.fill_generic_defaults(&defaults));
+        self.enums
+            .for_all_items_mut(|x| x.fill_generic_defaults(&defaults));
+        self.typedefs
+            .for_all_items_mut(|x| x.fill_generic_defaults(&defaults));
+        self.globals
+            .for_all_items_mut(|x| x.fill_generic_defaults(&defaults));
+        for x in &mut self.functions {
+            x.fill_generic_defaults(&defaults);
+        }
+    }
+
+    /// Makes the bitflags structs written as integers typedefs of their
+    /// integer type, like transparent structs, so that their flags are
+    /// integer constants.
+    fn apply_bitflags_styles(&mut self) {
+        let config = &self.config;
+        self.structs.for_all_items_mut(|x| {
+            if config.structure.bitflags(&x.annotations) != Some(BitflagsStyle::Integer) {
+                return;
+            }
+            if x.bitflags_field(config).is_none() {
+                warn!(
+                    "Can't write {} as an integer, which doesn't have a single field.",
+                    x.path
+                );
+                return;
+            }
+            x.is_transparent = true;
+        });
+    }
+
+    /// Gives the variants of the enums with `enum.explicit_discriminants` the
+    /// discriminants they have implicitly, one more than the previous one.
+    fn make_discriminants_explicit(&mut self) {
+        let config = &self.config;
+        self.enums.for_all_items_mut(|x| {
+            if !config.enumeration.explicit_discriminants(&x.annotations) {
+                return;
+            }
+            let mut next = 0;
+            for variant in &mut x.variants {
+                let discriminant = variant.discriminant.unwrap_or(next);
+                variant.discriminant = Some(discriminant);
+                next = discriminant + 1;
+            }
+        });
+    }
+
+    /// Writes the `#[repr(transparent)]` structs in the style of their
+    /// `transparent` annotation or of `[struct] transparent`, replacing the
+    /// erased ones with the types they wrap and making the ones written as
+    /// structs non-transparent.
+    fn apply_transparent_styles(&mut self) {
+        let default = self.config.structure.transparent;
+        let mut erased = ErasedTypes::new();
+        self.structs.for_all_items_mut(|x| {
+            if !x.is_transparent {
+                return;
+            }
+            let style = match x.annotations.atom("transparent") {
+                Some(Some(style)) => style.parse().unwrap_or_else(|msg| {
+                    warn!("Ignoring the transparent style of {}: {}", x.path, msg);
+                    default
+                }),
+                _ => default,
+            };
+            match style {
+                TransparentStyle::Typedef => {}
+                TransparentStyle::Struct => x.is_transparent = false,
+                TransparentStyle::Erase => match x.fields.first() {
+                    Some(&(_, ref ty, _)) if ty.get_root_path().as_ref() != Some(&x.path) => {
+                        erased.insert(x.path.clone(), (x.generic_params.clone(), ty.clone()));
+                    }
+                    _ => warn!("Can't erase {}, which doesn't wrap another type.", x.path),
+                },
+            }
+        });
+        if erased.is_empty() {
+            return;
+        }
+
+        self.structs
+            .for_all_items_mut(|x| x.erase_transparent(&erased));
+        self.unions
+            .for_all_items_mut(|x| x.erase_transparent(&erased));
+        self.enums
+            .for_all_items_mut(|x| x.erase_transparent(&erased));
+        self.typedefs
+            .for_all_items_mut(|x| x.erase_transparent(&erased));
+        self.globals
+            .for_all_items_mut(|x| x.erase_transparent(&erased));
+        self.constants
+            .for_all_items_mut(|x| x.ty.erase_transparent(&erased));
+        for x in &mut self.functions {
+            x.erase_transparent(&erased);
+        }
+    }
+
     fn simplify_standard_types(&mut self) {
+        let config = &self.config;
         self.structs.for_all_items_mut(|x| {
-            x.simplify_standard_types();
+            x.simplify_standard_types(config);
         });
         self.unions.for_all_items_mut(|x| {
-            x.simplify_standard_types();
+            x.simplify_standard_types(config);
         });
         self.globals.for_all_items_mut(|x| {
-            x.simplify_standard_types();
+            x.simplify_standard_types(config);
         });
         self.typedefs.for_all_items_mut(|x| {
-            x.simplify_standard_types();
+            x.simplify_standard_types(config);
+        });
+        self.opaque_items.for_all_items_mut(|x| {
+            x.simplify_standard_types(config);
         });
         for x in &mut self.functions {
-            x.simplify_standard_types();
+            x.simplify_standard_types(config);
+        }
+    }
+
+    /// Lowers the `Result`s returned by functions as `fn.results` asks, adding
+    /// the enums they're lowered into if need be.
+    fn lower_results(&mut self) {
+        if self.config.function.results == ResultStyle::None {
+            return;
+        }
+        for x in &mut self.functions {
+            if x.lower_result(&self.config) {
+                self.diagnostics.push(Diagnostic::new(
+                    Lint::UnstableLayout,
+                    x.path.name(),
+                    format!(
+                        "{} returns a lowered `Result`, whose layout Rust doesn't guarantee.",
+                        x.path
+                    ),
+                ));
+            }
+        }
+
+        if self.config.function.results == ResultStyle::TaggedUnion {
+            let results = [
+                "#[repr(C)] pub enum Result<T, E> { Ok(T), Err(E) }",
+                "#[repr(C)] pub enum VoidResult<E> { Ok, Err(E) }",
+            ];
+            let result_path = Path::new("Result");
+            self.opaque_items.filter(|x| *x.path() == result_path);
+            for result in &results {
+                let item = syn::parse_str::<syn::ItemEnum>(result).unwrap();
+                self.enums.try_insert(Enum::load(&item, None).unwrap());
+            }
+        }
+    }
+
+    /// Skips the items using tuples or slices that the config doesn't enable
+    /// lowering.
+    fn skip_unlowerable_items(&mut self) {
+        let config = &self.config;
+        let constants = &ConstEvaluator::new(&self.constants);
+        let diagnostics = &mut self.diagnostics;
+        let mut unlowerable = |path: &Path, result: Result<(), String>| match result {
+            Ok(()) => false,
+            Err(msg) => {
+                let message = format!("Skip {} - ({})", path, msg);
+                diagnostics.push(Diagnostic::new(Lint::UnsupportedType, path.name(), message));
+                true
+            }
+        };
+
+        self.structs.filter(|x| {
+            unlowerable(
+                x.path(),
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+        self.unions.filter(|x| {
+            unlowerable(
+                x.path(),
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+        self.enums.filter(|x| {
+            unlowerable(
+                x.path(),
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+        self.globals.filter(|x| {
+            unlowerable(
+                x.path(),
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+        self.typedefs.filter(|x| {
+            unlowerable(
+                x.path(),
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+        self.functions.retain(|x| {
+            !unlowerable(
+                &x.path,
+                x.clone().lower_types(config, constants, &mut vec![]),
+            )
+        });
+    }
+
+    /// Replaces tuples and slices with the structs generated for them.
+    fn lower_types(&mut self) {
+        let config = &self.config;
+        let constants = &ConstEvaluator::new(&self.constants);
+        let diagnostics = &mut self.diagnostics;
+        let mut lowered = Vec::new();
+        let mut skipped = Vec::new();
+        {
+            let mut lower = |path: &Path, result: Result<(), String>| {
+                if let Err(msg) = result {
+                    let message = format!("Skip {} - ({})", path, msg);
+                    diagnostics.push(Diagnostic::new(Lint::UnsupportedType, path.name(), message));
+                    skipped.push(path.clone());
+                }
+            };
+
+            self.structs.for_all_items_mut(|x| {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(x.path(), result);
+            });
+            self.unions.for_all_items_mut(|x| {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(x.path(), result);
+            });
+            self.enums.for_all_items_mut(|x| {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(x.path(), result);
+            });
+            self.globals.for_all_items_mut(|x| {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(x.path(), result);
+            });
+            self.typedefs.for_all_items_mut(|x| {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(x.path(), result);
+            });
+            for x in &mut self.functions {
+                let result = x.lower_types(config, constants, &mut lowered);
+                lower(&x.path, result);
+            }
+        }
+
+        if config.int128.lowers(config.language) {
+            self.constants.filter(|x| match x.ty {
+                Type::Primitive(PrimitiveType::Int128)
+                | Type::Primitive(PrimitiveType::UInt128) => {
+                    diagnostics.push(Diagnostic::new(
+                        Lint::UnsupportedType,
+                        x.path().name(),
+                        format!(
+                            "Skip {} - (Constants of 128-bit integers lowered to structs aren't supported.)",
+                            x.path()
+                        ),
+                    ));
+                    true
+                }
+                _ => false,
+            });
+        }
+
+        self.structs.filter(|x| skipped.contains(x.path()));
+        self.unions.filter(|x| skipped.contains(x.path()));
+        self.enums.filter(|x| skipped.contains(x.path()));
+        self.globals.filter(|x| skipped.contains(x.path()));
+        self.typedefs.filter(|x| skipped.contains(x.path()));
+        self.functions.retain(|x| !skipped.contains(&x.path));
+
+        for x in lowered {
+            if x.path.name().starts_with("Tuple_") {
+                diagnostics.push(Diagnostic::new(
+                    Lint::UnstableLayout,
+                    x.path.name(),
+                    format!(
+                        "{} lowers a tuple, whose layout Rust doesn't guarantee.",
+                        x.path
+                    ),
+                ));
+            }
+            self.structs.try_insert(x);
         }
     }
 
-    fn instantiate_monomorphs(&mut self) {
+    /// Elides the zero-sized fields of structs and unions, zero-length arrays
+    /// and fieldless structs, and notes them in their documentation along with
+    /// the fields of zero-sized types like `PhantomData`, which aren't loaded.
+    /// Items whose alignment the elided fields would have raised are skipped.
+    fn elide_zero_sized_fields(&mut self) {
+        // The alignment of the zero-sized structs and unions, which have no
+        // fields or only zero-sized ones.
+        let mut zero_sized = HashMap::new();
+        let repr_align = |alignment: &Option<ReprAlign>| match *alignment {
+            Some(ReprAlign::Align(n)) => n as usize,
+            _ => 1,
+        };
+        loop {
+            let mut found = Vec::new();
+            self.structs.for_all_items(|x| {
+                if !x.is_generic()
+                    && !zero_sized.contains_key(&x.path)
+                    && x.fields.iter().all(|f| is_zero_sized(&f.1, &zero_sized))
+                {
+                    found.push((x.path.clone(), repr_align(&x.alignment)));
+                }
+            });
+            self.unions.for_all_items(|x| {
+                if !x.is_generic()
+                    && !zero_sized.contains_key(&x.path)
+                    && x.fields.iter().all(|f| is_zero_sized(&f.1, &zero_sized))
+                {
+                    found.push((x.path.clone(), repr_align(&x.alignment)));
+                }
+            });
+            if found.is_empty() {
+                break;
+            }
+            zero_sized.extend(found);
+        }
+
+        let mut items = Vec::new();
+        self.structs.for_all_items(|x| items.push(x.container()));
+        self.unions.for_all_items(|x| items.push(x.container()));
+        self.enums.for_all_items(|x| items.push(x.container()));
+        self.typedefs.for_all_items(|x| items.push(x.container()));
+        self.opaque_items
+            .for_all_items(|x| items.push(x.container()));
+        let engines = LayoutEngines::new(&items, self.data_layout());
+
+        // The alignment the zero-sized fields of an item need, if it's known
+        // to be more than the alignment of its other fields gives it.
+        let raised_align = |engine: &LayoutEngine,
+                            fields: &[(String, Type, Documentation)],
+                            align: Option<usize>|
+         -> Option<usize> {
+            let needed = fields
+                .iter()
+                .filter_map(|f| zero_sized_align(engine, &f.1, &zero_sized))
+                .max()?;
+            if needed > align? {
+                Some(needed)
+            } else {
+                None
+            }
+        };
+
+        let mut skipped = Vec::new();
+        self.structs.for_all_items_mut(|x| {
+            let elided: Vec<_> = (0..x.fields.len())
+                .filter(|&i| is_zero_sized(&x.fields[i].1, &zero_sized))
+                .collect();
+            if elided.is_empty() {
+                return;
+            }
+            let mut rest = x.clone();
+            rest.elide_fields(&elided);
+            let raised = engines.layouts(|engine| {
+                let align = if rest.fields.is_empty() {
+                    Some(repr_align(&rest.alignment))
+                } else {
+                    engine.struct_layout(&rest).map(|l| l.layout.align)
+                };
+                raised_align(engine, &x.fields, align)
+            });
+            if raised.is_known() {
+                skipped.push(x.path.clone());
+            } else {
+                *x = rest;
+            }
+        });
+        self.unions.for_all_items_mut(|x| {
+            let elided: Vec<_> = (0..x.fields.len())
+                .filter(|&i| is_zero_sized(&x.fields[i].1, &zero_sized))
+                .collect();
+            if elided.is_empty() {
+                return;
+            }
+            let mut rest = x.clone();
+            rest.elide_fields(&elided);
+            let raised = engines.layouts(|engine| {
+                let align = if rest.fields.is_empty() {
+                    Some(repr_align(&rest.alignment))
+                } else {
+                    engine.union_layout(&rest).map(|l| l.align)
+                };
+                raised_align(engine, &x.fields, align)
+            });
+            if raised.is_known() {
+                skipped.push(x.path.clone());
+            } else {
+                *x = rest;
+            }
+        });
+        drop(engines);
+        for path in &skipped {
+            self.diagnostics.push(Diagnostic::new(
+                Lint::ZeroSizedAlignment,
+                path.name(),
+                format!(
+                    "Skip {} - (Its zero-sized fields raise its alignment.)",
+                    path
+                ),
+            ));
+        }
+        self.structs.filter(|x| skipped.contains(&x.path));
+        self.unions.filter(|x| skipped.contains(&x.path));
+
+        let note = |elided: &[String], documentation: &mut Documentation| {
+            if !elided.is_empty() {
+                let names: Vec<_> = elided.iter().map(|x| format!("`{}`", x)).collect();
+                documentation
+                    .doc_comment
+                    .push(format!("Zero-sized fields elided: {}.", names.join(", ")));
+            }
+        };
+        self.structs
+            .for_all_items_mut(|x| note(&x.elided_fields, &mut x.documentation));
+        self.unions
+            .for_all_items_mut(|x| note(&x.elided_fields, &mut x.documentation));
+    }
+
+    /// Hoists the function pointer types of function arguments, return values
+    /// and struct and union fields into typedefs with `hoist_callbacks`. They
+    /// are named with their `callback-name` annotation, or after the function
+    /// and argument or the type and field they're used by, like
+    /// `SetLoggerLogCallback` or `LoggerOnLogCallback`.
+    fn hoist_callbacks(&mut self) {
+        if !self.config.export.hoist_callbacks {
+            return;
+        }
+
+        let mut taken = HashSet::new();
+        self.enums.for_all_items(|x| {
+            taken.insert(x.path.clone());
+        });
+        self.structs.for_all_items(|x| {
+            taken.insert(x.path.clone());
+        });
+        self.unions.for_all_items(|x| {
+            taken.insert(x.path.clone());
+        });
+        self.opaque_items.for_all_items(|x| {
+            taken.insert(x.path.clone());
+        });
+        self.typedefs.for_all_items(|x| {
+            taken.insert(x.path.clone());
+        });
+
+        let mut typedefs = Vec::new();
+        {
+            let mut hoist = |ty: &mut Type, name: String, cfg: Option<&Cfg>, of: &AnnotationSet| {
+                let path = Path::new(name);
+                if !taken.insert(path.clone()) {
+                    warn!(
+                        "Skip hoisting a callback into {} - (an item has that name).",
+                        path
+                    );
+                    return;
+                }
+                let mut annotations = AnnotationSet::new();
+                annotations.module = of.module.clone();
+                annotations.crate_name = of.crate_name.clone();
+                annotations.source_order = of.source_order;
+                let aliased = mem::replace(ty, Type::Path(GenericPath::new(path.clone(), vec![])));
+                typedefs.push(Typedef::new(
+                    path,
+                    GenericParams::default(),
+                    aliased,
+                    cfg.cloned(),
+                    annotations,
+                    Documentation::none(),
+                ));
+            };
+            let pascal_case =
+                |name: &str| RenameRule::PascalCase.apply_to_snake_case(name, IdentifierType::Type);
+
+            self.structs.for_all_items_mut(|x| {
+                if x.is_generic() {
+                    return;
+                }
+                for &mut (ref name, ref mut ty, _) in &mut x.fields {
+                    if let Type::FuncPtr(..) = *ty {
+                        let callback = match x.annotations.field_callback_names.get(name) {
+                            Some(callback) => callback.clone(),
+                            None => format!("{}{}Callback", x.path.name(), pascal_case(name)),
+                        };
+                        hoist(ty, callback, x.cfg.as_ref(), &x.annotations);
+                    }
+                }
+            });
+            self.unions.for_all_items_mut(|x| {
+                if x.is_generic() {
+                    return;
+                }
+                for &mut (ref name, ref mut ty, _) in &mut x.fields {
+                    if let Type::FuncPtr(..) = *ty {
+                        let callback = match x.annotations.field_callback_names.get(name) {
+                            Some(callback) => callback.clone(),
+                            None => format!("{}{}Callback", x.path.name(), pascal_case(name)),
+                        };
+                        hoist(ty, callback, x.cfg.as_ref(), &x.annotations);
+                    }
+                }
+            });
+            for x in &mut self.functions {
+                // The names given to the function's callbacks, in order.
+                let mut given = match x.annotations.list("callback-name") {
+                    Some(names) => names,
+                    None => x
+                        .annotations
+                        .atom("callback-name")
+                        .and_then(|x| x)
+                        .into_iter()
+                        .collect(),
+                }
+                .into_iter();
+                let function = pascal_case(x.path.name());
+                for &mut (ref name, ref mut ty) in &mut x.args {
+                    if let Type::FuncPtr(..) = *ty {
+                        let callback = given.next().unwrap_or_else(|| {
+                            format!("{}{}Callback", function, pascal_case(name))
+                        });
+                        hoist(ty, callback, x.cfg.as_ref(), &x.annotations);
+                    }
+                }
+                if let Type::FuncPtr(..) = x.ret {
+                    let callback = given
+                        .next()
+                        .unwrap_or_else(|| format!("{}Callback", function));
+                    hoist(&mut x.ret, callback, x.cfg.as_ref(), &x.annotations);
+                }
+            }
+        }
+
+        for typedef in typedefs {
+            self.typedefs.try_insert(typedef);
+        }
+    }
+
+    /// Loads the generic types listed in `[export.instantiations]`.
+    fn load_instantiations(&self) -> Vec<Type> {
+        let mut instantiations = Vec::new();
+        for name in &self.config.export.instantiations.types {
+            let ty = syn::parse_str::<syn::Type>(name)
+                .map_err(|e| e.to_string())
+                .and_then(|ty| Type::load(&ty));
+            match ty {
+                Ok(Some(mut ty)) => {
+                    ty.simplify_standard_types(&self.config);
+                    instantiations.push(ty);
+                }
+                Ok(None) => {}
+                Err(msg) => warn!("Can't instantiate {} ({}).", name, msg),
+            }
+        }
+        instantiations
+    }
+
+    fn write_instantiations_report(&self, monomorphs: &Monomorphs) {
+        let path = match self.config.export.instantiations.report {
+            Some(ref path) => path,
+            None => return,
+        };
+
+        let mut lines: Vec<_> = monomorphs
+            .replacements()
+            .iter()
+            .map(|(generic, path)| format!("{} -> {}\n", generic.path(), path))
+            .collect();
+        lines.sort();
+
+        if let Err(e) = fs::write(path, lines.concat()) {
+            warn!("Can't write instantiations report to {} ({}).", path, e);
+        }
+    }
+
+    fn instantiate_monomorphs(&mut self, instantiations: &mut [Type]) {
         // Collect a list of monomorphs
         let mut monomorphs = Monomorphs::default();
 
@@ -369,6 +2019,11 @@ impl Library {
         for x in &self.functions {
             x.add_monomorphs(self, &mut monomorphs);
         }
+        for x in instantiations.iter() {
+            x.add_monomorphs(self, &mut monomorphs);
+        }
+
+        self.write_instantiations_report(&monomorphs);
 
         // Insert the monomorphs into self
         for monomorph in monomorphs.drain_structs() {
@@ -406,5 +2061,96 @@ impl Library {
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+        for x in instantiations.iter_mut() {
+            x.mangle_paths(&monomorphs);
+        }
+    }
+}
+
+/// Sorts items that are in name order into the order they were declared in,
+/// for `SortKey::SourceOrder`.
+fn sort_by_source_order<T: Item>(items: &mut Vec<T>, sort_by: SortKey) {
+    if sort_by == SortKey::SourceOrder {
+        items.sort_by_key(|x| x.annotations().source_order.unwrap_or(usize::max_value()));
+    }
+}
+
+/// Groups the items into their sections, keeping their order in each.
+fn sort_by_section<T: Item>(items: &mut Vec<T>, config: &Config, standard: &'static str) {
+    if config.has_sections() {
+        items.sort_by_key(|x| {
+            config
+                .sections
+                .rank(&config.section_of(x.annotations(), standard))
+        });
+    }
+}
+
+/// Whether the struct or union can be written as an anonymous member, which
+/// has nothing but fields.
+fn can_be_anonymous(item: &ItemContainer) -> bool {
+    match *item {
+        ItemContainer::Struct(ref x) => {
+            !x.is_transparent
+                && !x.is_generic()
+                && x.cfg.is_none()
+                && x.bitfield_widths.is_none()
+                && x.guarded_by.is_none()
+                && x.field_defaults.is_none()
+                && x.alignment.is_none()
+                && x.associated_constants.is_empty()
+        }
+        ItemContainer::Union(ref x) => !x.is_generic() && x.cfg.is_none() && x.alignment.is_none(),
+        _ => false,
+    }
+}
+
+/// Whether the struct can have anonymous members, which the C++ members
+/// derived from its fields would have to name.
+fn can_have_anonymous_members(s: &Struct, config: &Config) -> bool {
+    let structure = &config.structure;
+    let annotations = &s.annotations;
+    !s.is_transparent
+        && s.bitfield_widths.is_none()
+        && s.guarded_by.is_none()
+        && s.field_defaults.is_none()
+        && (config.language != Language::Cxx
+            || !(structure.derive_constructor(annotations)
+                || structure.derive_eq(annotations)
+                || structure.derive_neq(annotations)
+                || structure.derive_lt(annotations)
+                || structure.derive_lte(annotations)
+                || structure.derive_gt(annotations)
+                || structure.derive_gte(annotations)))
+}
+
+/// Whether `ty` is zero-sized, being a zero-length array or one of the
+/// `zero_sized` structs and unions.
+fn is_zero_sized(ty: &Type, zero_sized: &HashMap<Path, usize>) -> bool {
+    match *ty {
+        Type::Array(_, ArrayLength::Value(ref len)) if len == "0" => true,
+        Type::Array(ref ty, _) => is_zero_sized(ty, zero_sized),
+        Type::Path(ref generic) => {
+            generic.generics().is_empty() && zero_sized.contains_key(generic.path())
+        }
+        _ => false,
+    }
+}
+
+/// The alignment the zero-sized type `ty` needs, if it's known.
+fn zero_sized_align(
+    engine: &LayoutEngine,
+    ty: &Type,
+    zero_sized: &HashMap<Path, usize>,
+) -> Option<usize> {
+    match *ty {
+        Type::Array(ref elem, ArrayLength::Value(ref len)) if len == "0" => {
+            engine.type_layout(elem).map(|x| x.align)
+        }
+        Type::Array(ref elem, _) => zero_sized_align(engine, elem, zero_sized),
+        Type::Path(ref generic) if generic.generics().is_empty() => {
+            zero_sized.get(generic.path()).cloned()
+        }
+        _ => None,
     }
 }
@@ -0,0 +1,474 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A limited expander for `macro_rules!` macros, for the common case of
+//! macros stamping out `#[repr(C)]` types and `extern "C"` functions. It
+//! substitutes fragments and repetitions, but doesn't follow hygiene or
+//! invoke other crates' macros.
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
+use syn;
+
+use bindgen::ir::Cfg;
+
+/// A `macro_rules!` macro defined in a crate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroDefinition {
+    pub crate_name: String,
+    pub name: String,
+    /// The tokens of the rules.
+    pub rules: String,
+}
+
+/// An invocation of a macro that isn't built in, in item position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroCall {
+    pub crate_name: String,
+    pub name: String,
+    /// The tokens of the input.
+    pub tokens: String,
+    pub cfg: Option<Cfg>,
+    pub module: Option<String>,
+}
+
+/// The ways a repetition can repeat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Repeat {
+    ZeroOrMore,
+    OneOrMore,
+    ZeroOrOne,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Token(TokenTree),
+    Group(Delimiter, Vec<Matcher>),
+    Fragment(String, String),
+    Repetition(Vec<Matcher>, Option<TokenTree>, Repeat),
+}
+
+#[derive(Debug, Clone)]
+enum Binding {
+    Tokens(Vec<TokenTree>, String),
+    Repeated(Vec<Binding>),
+}
+
+type Bindings = HashMap<String, Binding>;
+
+/// The rules of a macro, as matchers and the bodies to transcribe.
+#[derive(Debug)]
+pub struct MacroRules {
+    rules: Vec<(Vec<Matcher>, Vec<TokenTree>)>,
+}
+
+impl MacroRules {
+    pub fn parse(rules: &str) -> Result<MacroRules, String> {
+        let tokens: TokenStream = rules.parse().map_err(|e| format!("{:?}", e))?;
+        let tokens: Vec<_> = tokens.into_iter().collect();
+
+        let mut rules = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            let matcher = match tokens[i] {
+                TokenTree::Group(ref group) => parse_matcher(&to_vec(group.stream()))?,
+                _ => return Err("expected the matcher of a rule".to_owned()),
+            };
+            if !is_punct(tokens.get(i + 1), '=') || !is_punct(tokens.get(i + 2), '>') {
+                return Err("expected `=>` after the matcher of a rule".to_owned());
+            }
+            let body = match tokens.get(i + 3) {
+                Some(&TokenTree::Group(ref group)) => to_vec(group.stream()),
+                _ => return Err("expected the body of a rule".to_owned()),
+            };
+            rules.push((matcher, body));
+            i += 4;
+            if is_punct(tokens.get(i), ';') {
+                i += 1;
+            }
+        }
+        Ok(MacroRules { rules })
+    }
+
+    /// Expands an invocation with the first rule matching `input`.
+    pub fn expand(&self, input: &str) -> Result<TokenStream, String> {
+        let input: TokenStream = input.parse().map_err(|e| format!("{:?}", e))?;
+        let input = to_vec(input);
+        for &(ref matcher, ref body) in &self.rules {
+            let bindings = match_seq(matcher, &input, Bindings::new(), &|rest, bindings| {
+                if rest.is_empty() {
+                    Some(bindings)
+                } else {
+                    None
+                }
+            });
+            if let Some(bindings) = bindings {
+                return transcribe(body, &bindings);
+            }
+        }
+        Err("no rules matched the invocation".to_owned())
+    }
+}
+
+fn to_vec(tokens: TokenStream) -> Vec<TokenTree> {
+    tokens.into_iter().collect()
+}
+
+fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+    match token {
+        Some(&TokenTree::Punct(ref punct)) => punct.as_char() == c,
+        _ => false,
+    }
+}
+
+fn same_token(a: &TokenTree, b: &TokenTree) -> bool {
+    match (a, b) {
+        (&TokenTree::Punct(ref a), &TokenTree::Punct(ref b)) => a.as_char() == b.as_char(),
+        (&TokenTree::Group(..), _) | (_, &TokenTree::Group(..)) => false,
+        _ => a.to_string() == b.to_string(),
+    }
+}
+
+fn parse_matcher(tokens: &[TokenTree]) -> Result<Vec<Matcher>, String> {
+    let mut matchers = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i] {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => match tokens.get(i + 1) {
+                Some(&TokenTree::Ident(ref name)) => {
+                    let kind = match tokens.get(i + 3) {
+                        Some(&TokenTree::Ident(ref kind)) if is_punct(tokens.get(i + 2), ':') => {
+                            kind.to_string()
+                        }
+                        _ => return Err(format!("expected a fragment type for `${}`", name)),
+                    };
+                    matchers.push(Matcher::Fragment(name.to_string(), kind));
+                    i += 4;
+                }
+                Some(&TokenTree::Group(ref group))
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let inner = parse_matcher(&to_vec(group.stream()))?;
+                    let (separator, repeat, len) = parse_repeat(&tokens[i + 2..])?;
+                    matchers.push(Matcher::Repetition(inner, separator, repeat));
+                    i += 2 + len;
+                }
+                _ => return Err("expected a fragment or a repetition after `$`".to_owned()),
+            },
+            TokenTree::Group(ref group) => {
+                let inner = parse_matcher(&to_vec(group.stream()))?;
+                matchers.push(Matcher::Group(group.delimiter(), inner));
+                i += 1;
+            }
+            ref token => {
+                matchers.push(Matcher::Token(token.clone()));
+                i += 1;
+            }
+        }
+    }
+    Ok(matchers)
+}
+
+/// Parses the separator and the operator after a `$(...)` repetition,
+/// returning them with their number of tokens.
+fn parse_repeat(tokens: &[TokenTree]) -> Result<(Option<TokenTree>, Repeat, usize), String> {
+    fn repeat(token: Option<&TokenTree>) -> Option<Repeat> {
+        match token {
+            Some(&TokenTree::Punct(ref punct)) => match punct.as_char() {
+                '*' => Some(Repeat::ZeroOrMore),
+                '+' => Some(Repeat::OneOrMore),
+                '?' => Some(Repeat::ZeroOrOne),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    if let Some(op) = repeat(tokens.get(0)) {
+        return Ok((None, op, 1));
+    }
+    match repeat(tokens.get(1)) {
+        Some(op) => Ok((Some(tokens[0].clone()), op, 2)),
+        None => Err("expected `*`, `+` or `?` after a repetition".to_owned()),
+    }
+}
+
+/// Whether `tokens` are a whole fragment of type `kind`.
+fn is_fragment(kind: &str, tokens: &[TokenTree]) -> bool {
+    fn parses<T: syn::parse::Parse>(tokens: &[TokenTree]) -> bool {
+        syn::parse2::<T>(TokenStream::from_iter(tokens.iter().cloned())).is_ok()
+    }
+
+    match kind {
+        "vis" => tokens.is_empty() || parses::<syn::Visibility>(tokens),
+        _ if tokens.is_empty() => false,
+        "ident" => match tokens {
+            [TokenTree::Ident(..)] => true,
+            _ => false,
+        },
+        "tt" => tokens.len() == 1,
+        "literal" => match tokens {
+            [TokenTree::Literal(..)] => true,
+            [TokenTree::Punct(ref minus), TokenTree::Literal(..)] => minus.as_char() == '-',
+            _ => false,
+        },
+        "lifetime" => parses::<syn::Lifetime>(tokens),
+        "block" => parses::<syn::Block>(tokens),
+        "ty" => parses::<syn::Type>(tokens),
+        "path" => parses::<syn::Path>(tokens),
+        "expr" => parses::<syn::Expr>(tokens),
+        "item" => parses::<syn::Item>(tokens),
+        "stmt" => parses::<syn::Stmt>(tokens),
+        // Other fragments, like `meta` or `pat`, match whatever the rest of
+        // the rule leaves to them.
+        _ => true,
+    }
+}
+
+/// Matches `tokens` with `matchers`, calling `rest` with the tokens after the
+/// match, and backtracking when it fails.
+fn match_seq(
+    matchers: &[Matcher],
+    tokens: &[TokenTree],
+    bindings: Bindings,
+    rest: &dyn Fn(&[TokenTree], Bindings) -> Option<Bindings>,
+) -> Option<Bindings> {
+    let (matcher, matchers) = match matchers.split_first() {
+        Some(split) => split,
+        None => return rest(tokens, bindings),
+    };
+
+    match *matcher {
+        Matcher::Token(ref expected) => match tokens.split_first() {
+            Some((token, tokens)) if same_token(expected, token) => {
+                match_seq(matchers, tokens, bindings, rest)
+            }
+            _ => None,
+        },
+        Matcher::Group(delimiter, ref inner) => match tokens.split_first() {
+            Some((&TokenTree::Group(ref group), tokens)) if group.delimiter() == delimiter => {
+                let bindings = match_seq(inner, &to_vec(group.stream()), bindings, &|rest, b| {
+                    if rest.is_empty() {
+                        Some(b)
+                    } else {
+                        None
+                    }
+                })?;
+                match_seq(matchers, tokens, bindings, rest)
+            }
+            _ => None,
+        },
+        Matcher::Fragment(ref name, ref kind) => {
+            // Try the longest fragment first, like the greedy fragments of
+            // rustc do.
+            for len in (0..tokens.len() + 1).rev() {
+                if !is_fragment(kind, &tokens[..len]) {
+                    continue;
+                }
+                let mut bindings = bindings.clone();
+                let binding = Binding::Tokens(tokens[..len].to_vec(), kind.clone());
+                bindings.insert(name.clone(), binding);
+                if let Some(bindings) = match_seq(matchers, &tokens[len..], bindings, rest) {
+                    return Some(bindings);
+                }
+            }
+            None
+        }
+        Matcher::Repetition(ref inner, ref separator, repeat) => match_repetition(
+            (inner, separator, repeat),
+            matchers,
+            tokens,
+            Vec::new(),
+            bindings,
+            rest,
+        ),
+    }
+}
+
+/// Matches the iterations of a repetition after the ones in `iterations`,
+/// then the matchers after it.
+fn match_repetition(
+    repetition: (&[Matcher], &Option<TokenTree>, Repeat),
+    matchers: &[Matcher],
+    tokens: &[TokenTree],
+    iterations: Vec<Bindings>,
+    bindings: Bindings,
+    rest: &dyn Fn(&[TokenTree], Bindings) -> Option<Bindings>,
+) -> Option<Bindings> {
+    let (inner, separator, repeat) = repetition;
+
+    if repeat != Repeat::ZeroOrOne || iterations.is_empty() {
+        let next = match *separator {
+            Some(ref separator) if !iterations.is_empty() => match tokens.split_first() {
+                Some((token, tokens)) if same_token(separator, token) => Some(tokens),
+                _ => None,
+            },
+            _ => Some(tokens),
+        };
+        if let Some(next) = next {
+            let matched = match_seq(inner, next, Bindings::new(), &|after, iteration| {
+                // An iteration must consume tokens, or it would repeat forever.
+                if after.len() == next.len() {
+                    return None;
+                }
+                let mut iterations = iterations.clone();
+                iterations.push(iteration);
+                match_repetition(
+                    repetition,
+                    matchers,
+                    after,
+                    iterations,
+                    bindings.clone(),
+                    rest,
+                )
+            });
+            if matched.is_some() {
+                return matched;
+            }
+        }
+    }
+
+    if repeat == Repeat::OneOrMore && iterations.is_empty() {
+        return None;
+    }
+    let mut bindings = bindings;
+    let mut names = Vec::new();
+    fragment_names(inner, &mut names);
+    for name in names {
+        let repeated = iterations
+            .iter()
+            .filter_map(|iteration| iteration.get(&name).cloned())
+            .collect();
+        bindings.insert(name, Binding::Repeated(repeated));
+    }
+    match_seq(matchers, tokens, bindings, rest)
+}
+
+fn fragment_names(matchers: &[Matcher], names: &mut Vec<String>) {
+    for matcher in matchers {
+        match *matcher {
+            Matcher::Token(..) => {}
+            Matcher::Group(_, ref inner) | Matcher::Repetition(ref inner, ..) => {
+                fragment_names(inner, names)
+            }
+            Matcher::Fragment(ref name, _) => names.push(name.clone()),
+        }
+    }
+}
+
+/// Writes `body` with the fragments of `bindings` substituted.
+fn transcribe(body: &[TokenTree], bindings: &Bindings) -> Result<TokenStream, String> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < body.len() {
+        match body[i] {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => match body.get(i + 1) {
+                Some(&TokenTree::Ident(ref name)) if name == "crate" => {
+                    out.push(TokenTree::Ident(Ident::new("crate", name.span())));
+                    i += 2;
+                }
+                Some(&TokenTree::Ident(ref name)) => {
+                    match bindings.get(&name.to_string()) {
+                        Some(&Binding::Tokens(ref tokens, ref kind)) => {
+                            out.extend(substitute(tokens, kind))
+                        }
+                        Some(&Binding::Repeated(..)) => {
+                            return Err(format!("`${}` is repeated, but not in a repetition", name))
+                        }
+                        None => return Err(format!("`${}` isn't a fragment of the rule", name)),
+                    }
+                    i += 2;
+                }
+                Some(&TokenTree::Group(ref group))
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let (separator, _, len) = parse_repeat(&body[i + 2..])?;
+                    let inner = to_vec(group.stream());
+                    for (index, bindings) in iterations(&inner, bindings)?.iter().enumerate() {
+                        if index != 0 {
+                            out.extend(separator.clone());
+                        }
+                        out.extend(transcribe(&inner, bindings)?);
+                    }
+                    i += 2 + len;
+                }
+                _ => {
+                    out.push(body[i].clone());
+                    i += 1;
+                }
+            },
+            TokenTree::Group(ref group) => {
+                let inner = transcribe(&to_vec(group.stream()), bindings)?;
+                let mut new_group = Group::new(group.delimiter(), inner);
+                new_group.set_span(group.span());
+                out.push(TokenTree::Group(new_group));
+                i += 1;
+            }
+            ref token => {
+                out.push(token.clone());
+                i += 1;
+            }
+        }
+    }
+    Ok(TokenStream::from_iter(out))
+}
+
+/// The tokens of a fragment when substituted. Expressions are parenthesized
+/// like rustc keeps them together.
+fn substitute(tokens: &[TokenTree], kind: &str) -> Vec<TokenTree> {
+    if kind == "expr" && tokens.len() > 1 {
+        let stream = TokenStream::from_iter(tokens.iter().cloned());
+        return vec![TokenTree::Group(Group::new(Delimiter::Parenthesis, stream))];
+    }
+    tokens.to_vec()
+}
+
+/// The bindings of each iteration of the repetition of `body`, which repeats
+/// as many times as the repeated fragments it uses.
+fn iterations(body: &[TokenTree], bindings: &Bindings) -> Result<Vec<Bindings>, String> {
+    let mut used = Vec::new();
+    used_names(body, &mut used);
+
+    let mut count = None;
+    for name in &used {
+        if let Some(&Binding::Repeated(ref repeated)) = bindings.get(name) {
+            match count {
+                Some(count) if count != repeated.len() => {
+                    return Err(format!("`${}` repeats a different number of times", name))
+                }
+                _ => count = Some(repeated.len()),
+            }
+        }
+    }
+    let count = match count {
+        Some(count) => count,
+        None => return Err("a repetition doesn't use any repeated fragment".to_owned()),
+    };
+
+    Ok((0..count)
+        .map(|index| {
+            let mut iteration = bindings.clone();
+            for name in &used {
+                if let Some(&Binding::Repeated(ref repeated)) = bindings.get(name) {
+                    iteration.insert(name.clone(), repeated[index].clone());
+                }
+            }
+            iteration
+        })
+        .collect())
+}
+
+fn used_names(body: &[TokenTree], names: &mut Vec<String>) {
+    for (i, token) in body.iter().enumerate() {
+        match *token {
+            TokenTree::Ident(ref name) if i > 0 && is_punct(body.get(i - 1), '$') => {
+                names.push(name.to_string())
+            }
+            TokenTree::Group(ref group) => used_names(&to_vec(group.stream()), names),
+            _ => {}
+        }
+    }
+}
@@ -43,12 +43,49 @@ fn internal_mangle_name(name: &str, generic_values: &[Type], last_in_parent: boo
             Type::Primitive(ref primitive) => {
                 mangled.push_str(primitive.to_repr_rust());
             }
-            Type::MutRef(..)
-            | Type::Ref(..)
-            | Type::ConstPtr(..)
-            | Type::Ptr(..)
-            | Type::Array(..)
-            | Type::FuncPtr(..) => {
+            Type::ConstPtr(ref ty, _) => {
+                mangled.push_str(&internal_mangle_name(
+                    "ConstPtr",
+                    &[ty.as_ref().clone()],
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::Ptr(ref ty, _) => {
+                mangled.push_str(&internal_mangle_name(
+                    "Ptr",
+                    &[ty.as_ref().clone()],
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::ConstSlice(ref ty) => {
+                mangled.push_str(&internal_mangle_name(
+                    "Slice",
+                    &[ty.as_ref().clone()],
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::Slice(ref ty) => {
+                mangled.push_str(&internal_mangle_name(
+                    "MutSlice",
+                    &[ty.as_ref().clone()],
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::Atomic(ref ty) => {
+                mangled.push_str(&internal_mangle_name(
+                    "Atomic",
+                    &[ty.as_ref().clone()],
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::Tuple(ref elems) => {
+                mangled.push_str(&internal_mangle_name(
+                    "Tuple",
+                    elems,
+                    last_in_parent && is_last,
+                ));
+            }
+            Type::MutRef(..) | Type::Ref(..) | Type::Array(..) | Type::FuncPtr(..) => {
                 panic!("Unable to mangle generic parameter {:?} for '{}'", ty, name);
             }
         }
@@ -124,4 +161,22 @@ fn generics() {
         ),
         Path::new("Foo_Bar_T_____Bar_E")
     );
+
+    // Tuple<f32, *const Bar> => Tuple_f32__ConstPtr_Bar
+    assert_eq!(
+        mangle_path(
+            &Path::new("Tuple"),
+            &[float(), Type::ConstPtr(Box::new(path("Bar")), true)]
+        ),
+        Path::new("Tuple_f32__ConstPtr_Bar")
+    );
+
+    // Foo<(f32, Bar)> => Foo_Tuple_f32__Bar
+    assert_eq!(
+        mangle_path(
+            &Path::new("Foo"),
+            &[Type::Tuple(vec![float(), path("Bar")])]
+        ),
+        Path::new("Foo_Tuple_f32__Bar")
+    );
 }
@@ -36,29 +36,55 @@ macro_rules! deserialize_enum_str {
     };
 }
 
+mod abidiff;
 mod bindings;
 mod bitflags;
 mod builder;
+mod cache;
 mod cargo;
 mod cdecl;
 mod config;
+mod cython;
 mod declarationtyperesolver;
 mod dependencies;
+mod depgraph;
+mod diagnostic;
+mod dlang;
+mod doccomment;
 mod error;
+mod golang;
 mod ir;
+mod java;
+mod json;
 mod library;
+mod macro_rules;
 mod mangle;
 mod monomorph;
+mod node;
 mod parser;
+mod python;
 mod rename;
 mod reserved;
+mod symbols;
+mod template;
 mod utilities;
+mod verify;
+mod wasm;
 mod writer;
+mod zig;
 
 #[allow(unused)]
 pub(crate) use self::cargo::*;
 
+#[allow(unused_imports)]
+pub use self::abidiff::AbiChange;
 pub use self::bindings::Bindings;
 pub use self::builder::Builder;
+#[allow(unused_imports)]
+pub use self::cargo::cargo_expand::{Error as ExpandError, ExpandRequest, Expander};
 pub use self::config::*;
+pub use self::depgraph::DependencyGraphFormat;
 pub use self::error::Error;
+#[allow(unused_imports)]
+pub use self::symbols::Symbol;
+pub use self::symbols::SymbolListFormat;
@@ -82,6 +82,10 @@ impl Monomorphs {
         self.typedefs.push(monomorph);
     }
 
+    pub fn replacements(&self) -> &HashMap<GenericPath, Path> {
+        &self.replacements
+    }
+
     pub fn mangle_path(&self, path: &GenericPath) -> Option<&Path> {
         self.replacements.get(path)
     }
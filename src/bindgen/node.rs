@@ -0,0 +1,1199 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a Node.js module built on top of `ffi-napi`, along with
+//! the TypeScript declarations of the module.
+//!
+//! Structs and unions are `ref-struct-di` / `ref-union-di` types. They are all
+//! declared up-front and get their fields defined afterwards, which is what
+//! allows them to reference themselves (or items declared later) through
+//! pointers. Typedefs are resolved where they're used for the same reason.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use bindgen::config::{Config, DocumentationStyle};
+use bindgen::doccomment;
+use bindgen::ir::{
+    ArrayLength, Cfg, Constant, Documentation, Enum, Function, GenericPath, Item, ItemContainer,
+    Literal, Path, PrimitiveType, ReprStyle, ReprType, Static, Struct, Type, Typedef,
+};
+use bindgen::python::is_exported;
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Where a type is used, which decides how it's passed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Position {
+    Field,
+    Argument,
+    Return,
+}
+
+/// What the name of an item refers to in the bindings.
+#[derive(Debug, Copy, Clone)]
+enum Named<'a> {
+    /// A struct, union or tagged enum, which is written as a `ref` type.
+    Class,
+    /// An enum, which is passed as its integer type.
+    Integer(Option<ReprType>),
+    /// An opaque item, which can only be used through pointers.
+    Opaque,
+    /// A function pointer typedef, which is passed as a pointer.
+    Callback,
+    /// A typedef or a transparent struct, which is written as the type it wraps.
+    Alias(&'a Type),
+}
+
+/// The kind of value of a constant, which decides how its literal is written.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Kind {
+    Integer,
+    /// A 64-bit integer, which may have to be a `BigInt`.
+    Integer64,
+    Float,
+    Other,
+}
+
+/// A `ref` struct or union type, with its fields.
+struct Class<'a> {
+    name: String,
+    is_union: bool,
+    fields: Vec<(String, Type)>,
+    cfg: &'a Option<Cfg>,
+    documentation: &'a Documentation,
+}
+
+/// Writes the `ffi-napi` module and the TypeScript declarations of
+/// `bindings`.
+pub(crate) struct NodeWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    names: HashMap<&'a str, Named<'a>>,
+    /// Whether the `ssize_t` type `ref` lacks has to be written.
+    uses_ssize_t: Cell<bool>,
+    /// The names declared so far, since items with several `#[cfg]`ed
+    /// definitions can only be declared once.
+    declared: RefCell<HashSet<String>>,
+}
+
+impl<'a> NodeWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        let mut names = HashMap::new();
+        for item in &bindings.items {
+            let named = match *item {
+                ItemContainer::Struct(ref s) if s.is_transparent => Named::Alias(&s.fields[0].1),
+                // `ref-struct-di` has no bitfields, nor packed or aligned structs.
+                ItemContainer::Struct(ref s)
+                    if s.bitfield_widths.is_some() || s.alignment.is_some() =>
+                {
+                    Named::Opaque
+                }
+                ItemContainer::Union(ref u) if u.alignment.is_some() => Named::Opaque,
+                ItemContainer::Struct(..) | ItemContainer::Union(..) => Named::Class,
+                ItemContainer::Enum(ref e) => match e.tag {
+                    Some(ref tag) => {
+                        names.insert(tag.as_str(), Named::Integer(e.repr.ty));
+                        for variant in &e.variants {
+                            if let Some((_, ref body)) = variant.body {
+                                names.insert(body.export_name(), Named::Class);
+                            }
+                        }
+                        Named::Class
+                    }
+                    None => Named::Integer(e.repr.ty),
+                },
+                ItemContainer::OpaqueItem(..) => Named::Opaque,
+                ItemContainer::Typedef(ref t) => match t.aliased {
+                    Type::FuncPtr(..) => Named::Callback,
+                    ref aliased => Named::Alias(aliased),
+                },
+                ItemContainer::Constant(..) | ItemContainer::Static(..) => continue,
+            };
+            names.insert(item.deref().export_name(), named);
+        }
+
+        NodeWriter {
+            bindings,
+            config: &bindings.config,
+            names,
+            uses_ssize_t: Cell::new(false),
+            declared: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Whether `function` can be declared, which `ffi-napi` libraries can't
+    /// do for variadic functions.
+    fn declare_function(&self, function: &Function) -> bool {
+        if function.variadic {
+            warn!(
+                "Skip {} - (variadic functions can't be declared by ffi.Library).",
+                function.path().name()
+            );
+            return false;
+        }
+        self.declare(function.path().name())
+    }
+
+    /// Returns whether `name` can be declared, which it can only be once.
+    fn declare(&self, name: &str) -> bool {
+        if !self.declared.borrow_mut().insert(name.to_owned()) {
+            warn!(
+                "Skip {} - (JavaScript can only declare one of its definitions).",
+                name
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Returns the structs, unions and tagged enums of the bindings, in the
+    /// order their fields have to be defined in.
+    fn classes(&self) -> Vec<Class<'a>> {
+        let mut classes = Vec::new();
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+            match *item {
+                ItemContainer::Struct(ref s) => {
+                    if let Some(&Named::Class) = self.names.get(s.export_name()) {
+                        classes.push(struct_class(s));
+                    }
+                }
+                ItemContainer::Union(ref u) => {
+                    if let Some(&Named::Class) = self.names.get(u.export_name()) {
+                        classes.push(Class {
+                            name: u.export_name().to_owned(),
+                            is_union: true,
+                            fields: fields(&u.fields),
+                            cfg: &u.cfg,
+                            documentation: &u.documentation,
+                        });
+                    }
+                }
+                ItemContainer::Enum(ref e) if e.tag.is_some() => self.enum_classes(e, &mut classes),
+                _ => {}
+            }
+        }
+        classes
+    }
+
+    fn enum_classes(&self, e: &'a Enum, classes: &mut Vec<Class<'a>>) {
+        let tag = e.tag.as_ref().unwrap();
+        let mut variant_fields = vec![];
+        for variant in &e.variants {
+            if let Some((ref name, ref body)) = variant.body {
+                classes.push(struct_class(body));
+                variant_fields.push((name.clone(), path_type(body.export_name())));
+            }
+        }
+
+        let mut fields = vec![("tag".to_owned(), path_type(tag))];
+        if e.repr.style == ReprStyle::C {
+            let variants = format!("_{}_Variants", e.export_name());
+            fields.push(("variants".to_owned(), path_type(&variants)));
+            classes.push(Class {
+                name: variants,
+                is_union: true,
+                fields: variant_fields,
+                cfg: &e.cfg,
+                documentation: &NO_DOCUMENTATION,
+            });
+        } else {
+            fields.extend(variant_fields);
+        }
+        classes.push(Class {
+            name: e.export_name().to_owned(),
+            is_union: e.repr.style != ReprStyle::C,
+            fields,
+            cfg: &e.cfg,
+            documentation: &e.documentation,
+        });
+    }
+
+    fn primitive_type(&self, primitive: &PrimitiveType) -> &'static str {
+        match *primitive {
+            PrimitiveType::Void => "'void'",
+            PrimitiveType::Bool => "'bool'",
+            PrimitiveType::Char | PrimitiveType::SChar => "'char'",
+            PrimitiveType::UChar => "'uchar'",
+            // Rust's `char` is a 32-bit code point.
+            PrimitiveType::WChar => "'uint32'",
+            PrimitiveType::Short => "'short'",
+            PrimitiveType::UShort => "'ushort'",
+            PrimitiveType::Int => "'int'",
+            PrimitiveType::UInt => "'uint'",
+            PrimitiveType::Long => "'long'",
+            PrimitiveType::ULong => "'ulong'",
+            PrimitiveType::LongLong => "'longlong'",
+            PrimitiveType::ULongLong => "'ulonglong'",
+            PrimitiveType::UInt8 => "'uint8'",
+            PrimitiveType::UInt16 => "'uint16'",
+            PrimitiveType::UInt32 => "'uint32'",
+            PrimitiveType::UInt64 => "'uint64'",
+            PrimitiveType::Int8 => "'int8'",
+            PrimitiveType::Int16 => "'int16'",
+            PrimitiveType::Int32 => "'int32'",
+            PrimitiveType::Int64 => "'int64'",
+            PrimitiveType::Int128 | PrimitiveType::UInt128 => {
+                unreachable!("128-bit integers should have been lowered to structs")
+            }
+            PrimitiveType::Float => "'float'",
+            PrimitiveType::Double => "'double'",
+            PrimitiveType::USize | PrimitiveType::SizeT => "'size_t'",
+            PrimitiveType::ISize | PrimitiveType::SSizeT | PrimitiveType::PtrDiffT => {
+                self.uses_ssize_t.set(true);
+                "ssize_t"
+            }
+            PrimitiveType::VaList => "'pointer'",
+        }
+    }
+
+    fn repr_type(&self, repr: Option<ReprType>) -> &'static str {
+        match repr {
+            None => "'int'",
+            Some(ReprType::U8) => "'uint8'",
+            Some(ReprType::U16) => "'uint16'",
+            Some(ReprType::U32) => "'uint32'",
+            Some(ReprType::USize) => self.primitive_type(&PrimitiveType::USize),
+            Some(ReprType::I8) => "'int8'",
+            Some(ReprType::I16) => "'int16'",
+            Some(ReprType::I32) => "'int32'",
+            Some(ReprType::ISize) => self.primitive_type(&PrimitiveType::ISize),
+        }
+    }
+
+    /// Returns whether `ty` can only be used through pointers, which are then
+    /// written as plain pointers.
+    fn is_opaque(&self, ty: &Type) -> bool {
+        match *ty {
+            Type::Primitive(PrimitiveType::Void) => true,
+            Type::Path(ref generic) => match self.names.get(generic.export_name()) {
+                Some(&Named::Opaque) | Some(&Named::Callback) => true,
+                Some(&Named::Alias(aliased)) => self.is_opaque(aliased),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns the `ref` type of `ty`.
+    fn ref_type(&self, ty: &Type, position: Position) -> String {
+        match *ty {
+            Type::ConstPtr(ref pointee, _)
+            | Type::Ptr(ref pointee, _)
+            | Type::Ref(ref pointee)
+            | Type::MutRef(ref pointee) => {
+                let is_const = match *ty {
+                    Type::ConstPtr(..) | Type::Ref(..) => true,
+                    _ => false,
+                };
+                match **pointee {
+                    Type::Primitive(PrimitiveType::Char)
+                        if is_const && position == Position::Argument =>
+                    {
+                        "'string'".to_owned()
+                    }
+                    ref pointee if self.is_opaque(pointee) => "'pointer'".to_owned(),
+                    ref pointee => {
+                        format!("ref.refType({})", self.ref_type(pointee, Position::Field))
+                    }
+                }
+            }
+            Type::Path(ref generic) => {
+                let name = generic.export_name();
+                match self.names.get(name) {
+                    Some(&Named::Integer(repr)) => self.repr_type(repr).to_owned(),
+                    Some(&Named::Opaque) => "'void'".to_owned(),
+                    Some(&Named::Callback) => "'pointer'".to_owned(),
+                    Some(&Named::Alias(aliased)) => self.ref_type(aliased, position),
+                    Some(&Named::Class) | None => name.to_owned(),
+                }
+            }
+            Type::Primitive(ref primitive) => self.primitive_type(primitive).to_owned(),
+            Type::Array(ref elem, ArrayLength::Name(ref name))
+                if position == Position::Field && self.is_bigint(name) =>
+            {
+                format!(
+                    "ArrayType({}, Number({}))",
+                    self.ref_type(elem, position),
+                    name
+                )
+            }
+            Type::Array(ref elem, ref len) if position == Position::Field => format!(
+                "ArrayType({}, {})",
+                self.ref_type(elem, position),
+                len.as_str()
+            ),
+            // Arrays decay to pointers outside of structs.
+            Type::Array(ref elem, _) => {
+                format!("ref.refType({})", self.ref_type(elem, Position::Field))
+            }
+            Type::FuncPtr(..) => "'pointer'".to_owned(),
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    /// Returns the TypeScript type of the values of `ty`.
+    fn ts_type(&self, ty: &Type, position: Position) -> String {
+        match *ty {
+            Type::ConstPtr(ref pointee, _)
+            | Type::Ptr(ref pointee, _)
+            | Type::Ref(ref pointee)
+            | Type::MutRef(ref pointee) => {
+                let is_const = match *ty {
+                    Type::ConstPtr(..) | Type::Ref(..) => true,
+                    _ => false,
+                };
+                match (&**pointee, position) {
+                    (&Type::Primitive(PrimitiveType::Char), Position::Argument) if is_const => {
+                        "string | null".to_owned()
+                    }
+                    (_, Position::Argument) => "Buffer | null".to_owned(),
+                    _ => "Buffer".to_owned(),
+                }
+            }
+            Type::Path(ref generic) => {
+                let name = generic.export_name();
+                match self.names.get(name) {
+                    Some(&Named::Integer(repr)) => match repr {
+                        Some(ReprType::USize) | Some(ReprType::ISize) => "number | string",
+                        _ => "number",
+                    }
+                    .to_owned(),
+                    Some(&Named::Opaque) => "never".to_owned(),
+                    Some(&Named::Callback) => "Buffer".to_owned(),
+                    Some(&Named::Alias(aliased)) => self.ts_type(aliased, position),
+                    Some(&Named::Class) | None => name.to_owned(),
+                }
+            }
+            Type::Primitive(ref primitive) => ts_primitive_type(primitive).to_owned(),
+            Type::Array(ref elem, _) if position == Position::Field => {
+                format!("ArrayLike<{}>", self.ts_type(elem, position))
+            }
+            Type::Array(..) | Type::FuncPtr(..) if position == Position::Argument => {
+                "Buffer | null".to_owned()
+            }
+            Type::Array(..) | Type::FuncPtr(..) => "Buffer".to_owned(),
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    /// Returns the JavaScript value of a constant, and its TypeScript type.
+    fn constant_value(&self, constant: &Constant) -> Option<(String, String)> {
+        let (ty, kind) = self.constant_type(&constant.ty)?;
+        let bigint = kind == Kind::Integer64 && self.needs_bigint(&constant.value);
+        let value = self.literal(&constant.value, kind, bigint)?;
+        let ty = if bigint { "bigint".to_owned() } else { ty };
+        Some((value, ty))
+    }
+
+    /// Returns the TypeScript type of a constant of type `ty`, and what kind
+    /// of value it is.
+    fn constant_type(&self, ty: &Type) -> Option<(String, Kind)> {
+        match *ty {
+            Type::Primitive(PrimitiveType::Void) | Type::Primitive(PrimitiveType::VaList) => None,
+            Type::Primitive(PrimitiveType::Bool) => Some(("boolean".to_owned(), Kind::Other)),
+            Type::Primitive(PrimitiveType::Float) | Type::Primitive(PrimitiveType::Double) => {
+                Some(("number".to_owned(), Kind::Float))
+            }
+            Type::Primitive(ref primitive) => {
+                let kind = if ts_primitive_type(primitive) == "number" {
+                    Kind::Integer
+                } else {
+                    Kind::Integer64
+                };
+                Some(("number".to_owned(), kind))
+            }
+            Type::ConstPtr(ref pointee, _) => match **pointee {
+                Type::Primitive(PrimitiveType::Char) => Some(("string".to_owned(), Kind::Other)),
+                _ => None,
+            },
+            Type::Path(ref generic) => match self.names.get(generic.export_name()) {
+                Some(&Named::Integer(Some(ReprType::USize)))
+                | Some(&Named::Integer(Some(ReprType::ISize))) => {
+                    Some(("number".to_owned(), Kind::Integer64))
+                }
+                Some(&Named::Integer(_)) => Some(("number".to_owned(), Kind::Integer)),
+                Some(&Named::Alias(aliased)) => self.constant_type(aliased),
+                Some(&Named::Class) => Some((generic.export_name().to_owned(), Kind::Other)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Converts a literal in C syntax, as stored in the IR, to JavaScript.
+    fn literal(&self, value: &Literal, kind: Kind, bigint: bool) -> Option<String> {
+        match *value {
+            Literal::Expr(ref v) => expr_literal(v, bigint),
+            // Numbers and `BigInt`s can't be mixed in operations.
+            Literal::Path(ref name) if bigint => Some(format!("BigInt({})", name)),
+            Literal::Path(ref name) if self.is_bigint(name) => Some(format!("Number({})", name)),
+            Literal::Path(ref name) => Some(name.clone()),
+            Literal::Cast { .. } => {
+                unreachable!("Casts are evaluated when generating the bindings.")
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let operand = |value: &Literal| match *value {
+                    Literal::BinOp { .. } => {
+                        Some(format!("({})", self.literal(value, kind, bigint)?))
+                    }
+                    _ => self.literal(value, kind, bigint),
+                };
+                let left = operand(left)?;
+                let right = operand(right)?;
+                // Integers divide like in C, but numbers don't.
+                let is_integer = kind == Kind::Integer || kind == Kind::Integer64;
+                if op == "/" && is_integer && !bigint {
+                    Some(format!("Math.trunc({} / {})", left, right))
+                } else {
+                    Some(format!("{} {} {}", left, op, right))
+                }
+            }
+            Literal::Struct {
+                ref path,
+                ref export_name,
+                ref fields,
+            } => {
+                if self.bindings.struct_is_transparent(path) {
+                    return self.literal(&fields[0].1, kind, bigint);
+                }
+                let mut values = vec![];
+                for (key, lit) in fields {
+                    let (_, kind) = self
+                        .struct_field_type(export_name, key)
+                        .and_then(|ty| self.constant_type(ty))?;
+                    let bigint = kind == Kind::Integer64 && self.needs_bigint(lit);
+                    values.push(format!("{}: {}", key, self.literal(lit, kind, bigint)?));
+                }
+                Some(format!("{}({{ {} }})", export_name, values.join(", ")))
+            }
+        }
+    }
+
+    /// Returns whether the constant `name` is a `BigInt`.
+    fn is_bigint(&self, name: &str) -> bool {
+        self.is_bigint_at(name, 0)
+    }
+
+    fn is_bigint_at(&self, name: &str, depth: usize) -> bool {
+        // Constants that refer to themselves aren't `BigInt`s.
+        depth < 64
+            && self
+                .bindings
+                .constants
+                .iter()
+                .filter(|c| c.associated_to.is_none() && c.export_name() == name)
+                .any(|c| match self.constant_type(&c.ty) {
+                    Some((_, Kind::Integer64)) => self.needs_bigint_at(&c.value, depth + 1),
+                    _ => false,
+                })
+    }
+
+    /// Returns whether a 64-bit integer `value` has to be a `BigInt`, which it
+    /// does when a number can't hold it, when it uses bitwise operators, as
+    /// those truncate numbers to 32 bits, or when it refers to a `BigInt`.
+    fn needs_bigint(&self, value: &Literal) -> bool {
+        self.needs_bigint_at(value, 0)
+    }
+
+    fn needs_bigint_at(&self, value: &Literal, depth: usize) -> bool {
+        const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+        match *value {
+            Literal::Expr(ref v) => v
+                .parse::<i128>()
+                .map_or(false, |v| v.abs() > MAX_SAFE_INTEGER),
+            Literal::Path(ref name) => self.is_bigint_at(name, depth),
+            Literal::Cast { .. } => {
+                unreachable!("Casts are evaluated when generating the bindings.")
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                ["<<", ">>", "&", "|", "^"].contains(&op)
+                    || self.needs_bigint_at(left, depth)
+                    || self.needs_bigint_at(right, depth)
+            }
+            Literal::Struct { .. } => false,
+        }
+    }
+
+    fn struct_field_type(&self, name: &str, field: &str) -> Option<&'a Type> {
+        self.bindings.items.iter().find_map(|item| match *item {
+            ItemContainer::Struct(ref s) if s.export_name() == name => s
+                .fields
+                .iter()
+                .find(|&&(ref key, _, _)| key == field)
+                .map(|&(_, ref ty, _)| ty),
+            _ => None,
+        })
+    }
+
+    fn constant_name(&self, constant: &Constant, associated_to: Option<&Struct>) -> String {
+        constant
+            .global_name(self.config, associated_to)
+            .into_owned()
+    }
+
+    /// Returns the constants of the bindings, with the struct they're
+    /// associated to, in the order they have to be written in.
+    fn constants(&self) -> Vec<(&'a Constant, Option<&'a Struct>)> {
+        let mut constants = vec![];
+        for constant in &self.bindings.constants {
+            if constant.ty.is_primitive_or_ptr_primitive() {
+                constants.push((constant, None));
+            }
+        }
+        for item in &self.bindings.items {
+            if let ItemContainer::Struct(ref s) = *item {
+                if is_exported(item) && !s.is_generic() {
+                    for constant in &s.associated_constants {
+                        constants.push((constant, Some(s)));
+                    }
+                }
+            }
+        }
+        for constant in &self.bindings.constants {
+            if !constant.ty.is_primitive_or_ptr_primitive() {
+                constants.push((constant, None));
+            }
+        }
+        constants
+            .into_iter()
+            .filter(|&(constant, _)| constant.value.is_valid(self.bindings))
+            .collect()
+    }
+
+    fn enums(&self) -> Vec<&'a Enum> {
+        self.bindings
+            .items
+            .iter()
+            .filter(|item| is_exported(item))
+            .filter_map(|item| match *item {
+                ItemContainer::Enum(ref e) => Some(e),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn typedefs(&self) -> Vec<&'a Typedef> {
+        self.bindings
+            .items
+            .iter()
+            .filter(|item| is_exported(item))
+            .filter_map(|item| match *item {
+                ItemContainer::Typedef(ref t) => Some(t),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn write_preamble<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_trailer<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    /// Writes the JavaScript module.
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_preamble(out);
+
+        out.new_line_if_not_start();
+        out.write("'use strict';");
+        out.new_line();
+        out.new_line();
+        out.write("const ffi = require('ffi-napi');");
+        out.new_line();
+        out.write("const ref = require('ref-napi');");
+        out.new_line();
+        out.write("const ArrayType = require('ref-array-di')(ref);");
+        out.new_line();
+        out.write("const StructType = require('ref-struct-di')(ref);");
+        out.new_line();
+        out.write("const UnionType = require('ref-union-di')(ref);");
+        out.new_line();
+
+        let classes = self.classes();
+        let mut exported = vec![];
+
+        // Whether `ssize_t` has to be declared is only known once every type
+        // is written, so they're written to a buffer first.
+        let mut module = vec![];
+        {
+            let mut body = SourceWriter::new(&mut module, self.bindings);
+            // Array lengths can refer to constants, and struct constants need
+            // the fields of their struct, so constants are written around the
+            // types.
+            let (simple, structs): (Vec<_>, Vec<_>) =
+                self.constants()
+                    .into_iter()
+                    .partition(|&(constant, associated_to)| {
+                        associated_to.is_none() && constant.ty.is_primitive_or_ptr_primitive()
+                    });
+            for (constant, associated_to) in simple {
+                self.write_constant(constant, associated_to, &mut exported, &mut body);
+            }
+            for class in &classes {
+                if !self.declare(&class.name) {
+                    continue;
+                }
+                body.new_line_if_not_start();
+                self.write_class_fields(class, &mut body);
+            }
+            for enumeration in self.enums() {
+                if self.declare(enumeration.tag.as_ref().unwrap_or(&enumeration.export_name)) {
+                    body.new_line_if_not_start();
+                    self.write_enum(enumeration, &mut body);
+                }
+            }
+            for typedef in self.typedefs() {
+                if self.declare(typedef.export_name()) {
+                    body.new_line_if_not_start();
+                    write_cfg(&typedef.cfg, &mut body);
+                    write!(
+                        body,
+                        "const {} = {};",
+                        typedef.export_name(),
+                        self.ref_type(&typedef.aliased, Position::Field)
+                    );
+                    body.new_line();
+                }
+            }
+            for (constant, associated_to) in structs {
+                self.write_constant(constant, associated_to, &mut exported, &mut body);
+            }
+            body.new_line_if_not_start();
+            self.write_load(&mut body);
+        }
+
+        if self.uses_ssize_t.get() {
+            out.new_line();
+            out.write("const ssize_t = ref.sizeof.size_t === 8 ? 'int64' : 'int32';");
+            out.new_line();
+        }
+
+        if !classes.is_empty() {
+            out.new_line();
+            out.write("// Declared ahead of their fields, so pointers can refer to any of them.");
+            out.new_line();
+            let mut written = HashSet::new();
+            for class in &classes {
+                if written.insert(&class.name) {
+                    let base = if class.is_union {
+                        "UnionType"
+                    } else {
+                        "StructType"
+                    };
+                    write!(out, "const {} = {}();", class.name, base);
+                    out.new_line();
+                }
+            }
+        }
+
+        out.write_raw_block(::std::str::from_utf8(&module).unwrap().trim_end());
+        out.new_line();
+
+        out.new_line();
+        out.write("module.exports = {");
+        out.push_tab();
+        let mut names: Vec<String> = classes
+            .iter()
+            .filter(|class| !class.name.starts_with('_'))
+            .map(|class| class.name.clone())
+            .collect();
+        for enumeration in self.enums() {
+            names.push(
+                enumeration
+                    .tag
+                    .clone()
+                    .unwrap_or_else(|| enumeration.export_name.clone()),
+            );
+        }
+        names.extend(self.typedefs().iter().map(|t| t.export_name().to_owned()));
+        names.extend(exported);
+        names.push("load".to_owned());
+        let mut written = HashSet::new();
+        for name in names {
+            if written.insert(name.clone()) {
+                out.new_line();
+                write!(out, "{},", name);
+            }
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("};");
+        out.new_line();
+
+        self.write_trailer(out);
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        exported: &mut Vec<String>,
+        out: &mut SourceWriter<F>,
+    ) {
+        let name = self.constant_name(constant, associated_to);
+        if let Some((value, _)) = self.constant_value(constant) {
+            if self.declare(&name) {
+                out.new_line_if_not_start();
+                write_cfg(&constant.cfg, out);
+                write!(out, "const {} = {};", name, value);
+                out.new_line();
+                exported.push(name);
+            }
+        }
+    }
+
+    fn write_class_fields<F: Write>(&self, class: &Class, out: &mut SourceWriter<F>) {
+        write_cfg(class.cfg, out);
+        for (name, ty) in &class.fields {
+            write!(
+                out,
+                "{}.defineProperty('{}', {});",
+                class.name,
+                name,
+                self.ref_type(ty, Position::Field)
+            );
+            out.new_line();
+        }
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        write_cfg(&e.cfg, out);
+        let name = e.tag.as_ref().unwrap_or(&e.export_name);
+        write!(out, "const {} = Object.freeze({{", name);
+        out.push_tab();
+        for (variant, value) in variant_values(e) {
+            out.new_line();
+            write!(out, "{}: {},", variant, value);
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("});");
+        out.new_line();
+    }
+
+    /// Functions and globals can only be declared once the library is loaded,
+    /// so they're declared on the library object by a `load` function.
+    fn write_load<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.write("function load(path) {");
+        out.push_tab();
+        out.new_line();
+
+        let functions: Vec<&Function> = self
+            .bindings
+            .functions
+            .iter()
+            .filter(|f| self.declare_function(f))
+            .collect();
+        if functions.is_empty() {
+            out.write("const lib = ffi.Library(path, {});");
+        } else {
+            out.write("const lib = ffi.Library(path, {");
+            out.push_tab();
+            for function in functions {
+                out.new_line();
+                write_cfg(&function.cfg, out);
+                let args: Vec<_> = function
+                    .args
+                    .iter()
+                    .map(|(_, ty)| self.ref_type(ty, Position::Argument))
+                    .collect();
+                write!(
+                    out,
+                    "{}: [{}, [{}]],",
+                    function.path().name(),
+                    self.ref_type(&function.ret, Position::Return),
+                    args.join(", ")
+                );
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("});");
+        }
+
+        let globals: Vec<&Static> = self
+            .bindings
+            .globals
+            .iter()
+            .filter(|global| self.declare(global.export_name()))
+            .collect();
+        if !globals.is_empty() {
+            out.new_line();
+            out.write("const dylib = new ffi.DynamicLibrary(path);");
+            for global in globals {
+                out.new_line();
+                write_cfg(&global.cfg, out);
+                write!(out, "lib.{0} = dylib.get('{0}');", global.export_name());
+            }
+        }
+
+        out.new_line();
+        out.write("return lib;");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+
+    /// Writes the TypeScript declarations of the module.
+    pub fn write_declarations<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_preamble(out);
+
+        out.new_line_if_not_start();
+        out.write("/// <reference types=\"node\" />");
+        out.new_line();
+        out.new_line();
+        out.write("export type RefType = string | object;");
+        out.new_line();
+        out.new_line();
+        out.write("export interface StructType<T> {");
+        out.push_tab();
+        out.new_line();
+        out.write("new (fields?: Partial<T>): T;");
+        out.new_line();
+        out.write("(fields?: Partial<T>): T;");
+        out.new_line();
+        out.write("readonly size: number;");
+        out.new_line();
+        out.write("readonly alignment: number;");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        for (constant, associated_to) in self.constants() {
+            let name = self.constant_name(constant, associated_to);
+            if let Some((_, ty)) = self.constant_value(constant) {
+                if self.declare(&name) {
+                    out.new_line();
+                    write_cfg(&constant.cfg, out);
+                    self.write_documentation(&constant.documentation, out);
+                    write!(out, "export declare const {}: {};", name, ty);
+                    out.new_line();
+                }
+            }
+        }
+
+        for e in self.enums() {
+            let name = e.tag.as_ref().unwrap_or(&e.export_name);
+            if !self.declare(name) {
+                continue;
+            }
+            out.new_line();
+            write_cfg(&e.cfg, out);
+            if e.tag.is_none() {
+                self.write_documentation(&e.documentation, out);
+            }
+            write!(out, "export declare const {}: {{", name);
+            out.push_tab();
+            let documentation: HashMap<_, _> = e
+                .variants
+                .iter()
+                .map(|v| (v.export_name.as_str(), &v.documentation))
+                .collect();
+            for (variant, value) in variant_values(e) {
+                out.new_line();
+                if let Some(documentation) = documentation.get(variant.as_str()) {
+                    self.write_documentation(documentation, out);
+                }
+                write!(out, "readonly {}: {};", variant, value);
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("};");
+            out.new_line();
+        }
+
+        let classes = self.classes();
+        for class in &classes {
+            if class.name.starts_with('_') || !self.declare(&class.name) {
+                continue;
+            }
+            out.new_line();
+            write_cfg(class.cfg, out);
+            self.write_documentation(class.documentation, out);
+            write!(out, "export interface {} {{", class.name);
+            out.push_tab();
+            for (name, ty) in &class.fields {
+                out.new_line();
+                match *ty {
+                    // The variants of a tagged enum are flattened into it.
+                    Type::Path(ref generic) if generic.export_name().starts_with('_') => {
+                        write!(out, "{}: {{", name);
+                        out.push_tab();
+                        let variants = classes.iter().find(|c| c.name == generic.export_name());
+                        for &(ref name, ref ty) in variants.iter().flat_map(|c| &c.fields) {
+                            out.new_line();
+                            write!(out, "{}: {};", name, self.ts_type(ty, Position::Field));
+                        }
+                        out.pop_tab();
+                        out.new_line();
+                        out.write("};");
+                    }
+                    _ => write!(out, "{}: {};", name, self.ts_type(ty, Position::Field)),
+                }
+            }
+            out.new_line();
+            out.write("ref(): Buffer;");
+            out.pop_tab();
+            out.new_line();
+            out.write("}");
+            out.new_line();
+            write!(
+                out,
+                "export declare const {0}: StructType<{0}>;",
+                class.name
+            );
+            out.new_line();
+        }
+
+        for t in self.typedefs() {
+            if !self.declare(t.export_name()) {
+                continue;
+            }
+            out.new_line();
+            write_cfg(&t.cfg, out);
+            self.write_documentation(&t.documentation, out);
+            write!(
+                out,
+                "export type {} = {};",
+                t.export_name(),
+                self.ts_type(&t.aliased, Position::Field)
+            );
+            out.new_line();
+            write!(out, "export declare const {}: RefType;", t.export_name());
+            out.new_line();
+        }
+
+        out.new_line();
+        out.write("export interface Library {");
+        out.push_tab();
+        let mut first = true;
+        for global in &self.bindings.globals {
+            if !self.declare(global.export_name()) {
+                continue;
+            }
+            if !first {
+                out.new_line();
+            }
+            first = false;
+            out.new_line();
+            write_cfg(&global.cfg, out);
+            self.write_documentation(&global.documentation, out);
+            write!(out, "{}: Buffer;", global.export_name());
+        }
+        for function in &self.bindings.functions {
+            if !self.declare_function(function) {
+                continue;
+            }
+            if !first {
+                out.new_line();
+            }
+            first = false;
+            out.new_line();
+            write_cfg(&function.cfg, out);
+            self.write_documentation(&function.documentation, out);
+            let args: Vec<_> = function
+                .args
+                .iter()
+                .map(|(name, ty)| format!("{}: {}", name, self.ts_type(ty, Position::Argument)))
+                .collect();
+            write!(
+                out,
+                "{}({}): {};",
+                function.path().name(),
+                args.join(", "),
+                self.ts_type(&function.ret, Position::Return)
+            );
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        out.new_line();
+        out.write("export declare function load(path: string): Library;");
+        out.new_line();
+
+        self.write_trailer(out);
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        out.write("/**");
+        out.new_line();
+        for line in doccomment::rewrite(&documentation.doc_comment, DocumentationStyle::Javadoc) {
+            out.write(" *");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+        out.write(" */");
+        out.new_line();
+    }
+}
+
+static NO_DOCUMENTATION: Documentation = Documentation {
+    doc_comment: Vec::new(),
+};
+
+fn struct_class(s: &Struct) -> Class<'_> {
+    Class {
+        name: s.export_name().to_owned(),
+        is_union: false,
+        fields: fields(&s.fields),
+        cfg: &s.cfg,
+        documentation: &s.documentation,
+    }
+}
+
+fn fields(fields: &[(String, Type, Documentation)]) -> Vec<(String, Type)> {
+    fields
+        .iter()
+        .map(|&(ref name, ref ty, _)| (name.clone(), ty.clone()))
+        .collect()
+}
+
+fn variant_values(e: &Enum) -> Vec<(String, i64)> {
+    let mut values = vec![];
+    let mut next_value = 0;
+    for variant in &e.variants {
+        let value = variant.discriminant.unwrap_or(next_value);
+        values.push((variant.export_name.clone(), value));
+        next_value = value + 1;
+    }
+    values
+}
+
+fn ts_primitive_type(primitive: &PrimitiveType) -> &'static str {
+    match *primitive {
+        PrimitiveType::Void => "void",
+        PrimitiveType::Bool => "boolean",
+        // `ref` returns 64-bit integers as strings when a number can't hold
+        // them.
+        PrimitiveType::Long
+        | PrimitiveType::ULong
+        | PrimitiveType::LongLong
+        | PrimitiveType::ULongLong
+        | PrimitiveType::UInt64
+        | PrimitiveType::Int64
+        | PrimitiveType::USize
+        | PrimitiveType::ISize
+        | PrimitiveType::SizeT
+        | PrimitiveType::SSizeT
+        | PrimitiveType::PtrDiffT => "number | string",
+        PrimitiveType::VaList => "Buffer",
+        _ => "number",
+    }
+}
+
+fn expr_literal(v: &str, bigint: bool) -> Option<String> {
+    match v {
+        "NAN" => return Some("NaN".to_owned()),
+        "INFINITY" => return Some("Infinity".to_owned()),
+        "-INFINITY" => return Some("-Infinity".to_owned()),
+        _ => {}
+    }
+
+    if v.starts_with("u8\"") && v.ends_with('"') {
+        let mut result = String::from("'");
+        for c in v[3..v.len() - 1].chars() {
+            match c {
+                '\'' | '\\' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                ' '..='~' => result.push(c),
+                _ => result.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            }
+        }
+        result.push('\'');
+        return Some(result);
+    }
+
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        return Some(format!("0x{}", &v[4..v.len() - 1]));
+    }
+
+    if v.starts_with('\'') && v.ends_with('\'') {
+        // `char::escape_default` output, as a code point.
+        let c = &v[1..v.len() - 1];
+        let code = match c {
+            "\\t" => 9,
+            "\\n" => 10,
+            "\\r" => 13,
+            _ if c.starts_with("\\u{") => u32::from_str_radix(&c[3..c.len() - 1], 16).ok()?,
+            _ if c.starts_with('\\') => c[1..].chars().next()? as u32,
+            _ => c.chars().next()? as u32,
+        };
+        return Some(code.to_string());
+    }
+
+    if bigint && v.parse::<i128>().is_ok() {
+        return Some(format!("{}n", v));
+    }
+
+    Some(v.to_owned())
+}
+
+/// JavaScript has no preprocessor, so conditional items are always written
+/// and their condition is only recorded in a comment.
+fn write_cfg<F: Write>(cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+    if let Some(ref cfg) = *cfg {
+        write!(out, "// cfg({})", cfg);
+        out.new_line();
+    }
+}
+
+fn path_type(name: &str) -> Type {
+    Type::Path(GenericPath::new(Path::new(name), vec![]))
+}
@@ -2,22 +2,28 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::{HashMap, HashSet};
-use std::fs::File;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs::{self, File};
 use std::io::Read;
+use std::mem;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 use syn;
 
 use bindgen::bitflags;
+use bindgen::cache::ParseCache;
 use bindgen::cargo::{Cargo, PackageRef};
 use bindgen::config::MacroExpansionConfig;
+use bindgen::diagnostic::{Diagnostic, Lint};
 use bindgen::error::Error;
 use bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericParams, ItemMap,
-    OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
+    AnnotationSet, CallingConvention, Cfg, Constant, Documentation, Enum, Function, GenericParams,
+    Item, ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
 };
-use bindgen::utilities::{SynAbiHelpers, SynItemHelpers};
+use bindgen::macro_rules::{MacroCall, MacroDefinition, MacroRules};
+use bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemHelpers};
 
 const STD_CRATES: &'static [&'static str] = &[
     "std",
@@ -30,16 +36,21 @@ const STD_CRATES: &'static [&'static str] = &[
 
 type ParseResult = Result<Parse, Error>;
 
+/// How many times the items expanded from macros are expanded again.
+const MAX_MACRO_EXPANSION_DEPTH: usize = 64;
+
 /// Parses a single rust source file, not following `mod` or `extern crate`.
 pub fn parse_src(
     src_file: &FilePath,
     macro_expansion_config: &MacroExpansionConfig,
+    cache_dir: &Option<String>,
 ) -> ParseResult {
     let mod_name = src_file.file_stem().unwrap().to_str().unwrap();
 
     let mut context = Parser {
         binding_crate_name: mod_name.to_owned(),
-        macro_expansion_config,
+        members: Vec::new(),
+        macro_expansion_config: macro_expansion_config.clone(),
         lib: None,
         parse_deps: true,
         include: None,
@@ -50,8 +61,9 @@ pub fn parse_src(
         expand_features: None,
         parsed_crates: HashSet::new(),
         cache_src: HashMap::new(),
-        cache_expanded_crate: HashMap::new(),
+        cache: cache_dir.as_ref().map(ParseCache::new),
         cfg_stack: Vec::new(),
+        module: None,
         out: Parse::new(),
     };
 
@@ -61,10 +73,14 @@ pub fn parse_src(
     };
 
     context.parse_mod(&pkg_ref, src_file)?;
+    context
+        .out
+        .expand_macro_rules(macro_expansion_config, &context.binding_crate_name, &[]);
     Ok(context.out)
 }
 
-/// Recursively parses a rust library starting at the root crate's directory.
+/// Recursively parses a rust library starting at the root crate's directory,
+/// and the `members` of its workspace.
 ///
 /// Inside a crate, `mod` and `extern crate` declarations are followed
 /// and parsed. To find an external crate, the parser uses the `cargo metadata`
@@ -79,10 +95,14 @@ pub(crate) fn parse_lib(
     expand_all_features: bool,
     expand_default_features: bool,
     expand_features: &Option<Vec<String>>,
+    cache_dir: &Option<String>,
+    jobs: usize,
+    members: &[String],
 ) -> ParseResult {
     let mut context = Parser {
         binding_crate_name: lib.binding_crate_name().to_owned(),
-        macro_expansion_config,
+        members: members.to_owned(),
+        macro_expansion_config: macro_expansion_config.clone(),
         lib: Some(lib),
         parse_deps: parse_deps,
         include: include.clone(),
@@ -93,20 +113,38 @@ pub(crate) fn parse_lib(
         expand_features: expand_features.clone(),
         parsed_crates: HashSet::new(),
         cache_src: HashMap::new(),
-        cache_expanded_crate: HashMap::new(),
+        cache: cache_dir.as_ref().map(ParseCache::new),
         cfg_stack: Vec::new(),
+        module: None,
         out: Parse::new(),
     };
 
-    let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
-    context.parse_crate(&binding_crate)?;
+    let mut roots = vec![context.lib.as_ref().unwrap().binding_crate_ref()];
+    for member in members {
+        match context.lib.as_ref().unwrap().find_pkg_ref(member) {
+            Some(pkg) => roots.push(pkg),
+            None => warn!("Can't find the workspace member `{}`.", member),
+        }
+    }
+    let mut crates = Vec::new();
+    for root in &roots {
+        if !context.parsed_crates.contains(&root.name) {
+            context.find_crates(root, &mut crates);
+        }
+    }
+    context.parse_crates(crates, jobs)?;
+    context
+        .out
+        .expand_macro_rules(macro_expansion_config, &context.binding_crate_name, members);
     Ok(context.out)
 }
 
 #[derive(Debug, Clone)]
-struct Parser<'a> {
+struct Parser {
     binding_crate_name: String,
-    macro_expansion_config: &'a MacroExpansionConfig,
+    /// The workspace members, which export their items like the binding crate.
+    members: Vec<String>,
+    macro_expansion_config: MacroExpansionConfig,
     lib: Option<Cargo>,
     parse_deps: bool,
 
@@ -118,15 +156,29 @@ struct Parser<'a> {
     expand_features: Option<Vec<String>>,
 
     parsed_crates: HashSet<String>,
-    cache_src: HashMap<FilePathBuf, Vec<syn::Item>>,
-    cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
+    /// The modules loaded from each file, in each context.
+    cache_src: HashMap<(FilePathBuf, String), ParsedMod>,
+    /// The modules parsed by previous runs.
+    cache: Option<ParseCache>,
 
     cfg_stack: Vec<Cfg>,
+    /// The top-level module of the binding crate being parsed, if any.
+    module: Option<String>,
 
     out: Parse,
 }
 
-impl<'a> Parser<'a> {
+impl Parser {
+    /// The crate whose functions, constants and statics `pkg` exports, which
+    /// is `pkg` itself for workspace members.
+    fn exporting_crate_name<'a>(&'a self, pkg: &'a PackageRef) -> &'a str {
+        if self.members.contains(&pkg.name) {
+            &pkg.name
+        } else {
+            &self.binding_crate_name
+        }
+    }
+
     fn should_parse_dependency(&self, pkg_name: &String) -> bool {
         if self.parsed_crates.contains(pkg_name) {
             return false;
@@ -152,11 +204,12 @@ impl<'a> Parser<'a> {
         return !STD_CRATES.contains(&pkg_name.as_ref()) && !self.exclude.contains(&pkg_name);
     }
 
-    fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
+    /// Finds the crates to parse and the `#[cfg]`s they're depended on
+    /// under, with the dependencies of each crate before it.
+    fn find_crates(&mut self, pkg: &PackageRef, out: &mut Vec<(PackageRef, Vec<Cfg>)>) {
         assert!(self.lib.is_some());
         self.parsed_crates.insert(pkg.name.clone());
 
-        // Before we do anything with this crate, we must first parse all of its dependencies.
         // This is guaranteed to terminate because the crate-graph is acyclic (and even if it
         // wasn't, we've already marked the crate as parsed in the line above).
         for (dep_pkg, cfg) in self.lib.as_ref().unwrap().dependencies(&pkg) {
@@ -168,13 +221,67 @@ impl<'a> Parser<'a> {
                 self.cfg_stack.push(cfg.clone());
             }
 
-            self.parse_crate(&dep_pkg)?;
+            self.find_crates(&dep_pkg, out);
 
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
         }
 
+        out.push((pkg.clone(), self.cfg_stack.clone()));
+    }
+
+    /// Parses `crates` on up to `jobs` threads, adding their items in the
+    /// order of `crates` so that the result doesn't depend on scheduling.
+    fn parse_crates(
+        &mut self,
+        crates: Vec<(PackageRef, Vec<Cfg>)>,
+        jobs: usize,
+    ) -> Result<(), Error> {
+        let count = crates.len();
+        let queue = Arc::new(Mutex::new(crates.into_iter().enumerate()));
+        let (sender, receiver) = mpsc::channel();
+
+        let workers: Vec<_> = (0..jobs.max(1).min(count))
+            .map(|_| {
+                let queue = queue.clone();
+                let sender = sender.clone();
+                let mut parser = self.clone();
+                thread::spawn(move || loop {
+                    let next = queue.lock().unwrap().next();
+                    let (index, (pkg, cfg_stack)) = match next {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    parser.cfg_stack = cfg_stack;
+                    let result = parser
+                        .parse_crate(&pkg)
+                        .map(|_| mem::replace(&mut parser.out, Parse::new()));
+                    if sender.send((index, result)).is_err() {
+                        break;
+                    }
+                })
+            })
+            .collect();
+        drop(sender);
+
+        let mut results: Vec<_> = (0..count).map(|_| None).collect();
+        for (index, result) in receiver {
+            results[index] = Some(result);
+        }
+        for worker in workers {
+            worker.join().expect("A thread parsing crates panicked.");
+        }
+
+        for result in results {
+            self.out.extend_with(&result.unwrap()?);
+        }
+        Ok(())
+    }
+
+    fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
+        assert!(self.lib.is_some());
+
         // Check if we should use cargo expand for this crate
         if self.expand.contains(&pkg.name) {
             return self.parse_expand_crate(pkg);
@@ -199,41 +306,36 @@ impl<'a> Parser<'a> {
     fn parse_expand_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
         assert!(self.lib.is_some());
 
-        let mod_parsed = {
-            if !self.cache_expanded_crate.contains_key(&pkg.name) {
-                let s = self
-                    .lib
-                    .as_ref()
-                    .unwrap()
-                    .expand_crate(
-                        pkg,
-                        self.expand_all_features,
-                        self.expand_default_features,
-                        &self.expand_features,
-                    )
-                    .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
-                    crate_name: pkg.name.clone(),
-                    src_path: "".to_owned(),
-                    error: x,
-                })?;
-                self.cache_expanded_crate.insert(pkg.name.clone(), i.items);
-            }
-
-            self.cache_expanded_crate.get(&pkg.name).unwrap().clone()
-        };
+        let s = self
+            .lib
+            .as_ref()
+            .unwrap()
+            .expand_crate(
+                pkg,
+                self.expand_all_features,
+                self.expand_default_features,
+                &self.expand_features,
+            )
+            .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
+        let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
+            crate_name: pkg.name.clone(),
+            src_path: "".to_owned(),
+            error: x,
+        })?;
+
+        // `cargo expand` reads the crate's sources itself, so depend on all
+        // of them.
+        let crate_src = self.lib.as_ref().unwrap().find_crate_src(pkg);
+        if let Some(src_dir) = crate_src.as_ref().and_then(|x| x.parent()) {
+            add_rust_files(src_dir, &mut self.out.source_files);
+        }
 
-        self.process_expanded_mod(pkg, &mod_parsed)
+        self.process_expanded_mod(pkg, &i.items)
     }
 
     fn process_expanded_mod(&mut self, pkg: &PackageRef, items: &[syn::Item]) -> Result<(), Error> {
-        self.out.load_syn_crate_mod(
-            &self.macro_expansion_config,
-            &self.binding_crate_name,
-            &pkg.name,
-            Cfg::join(&self.cfg_stack).as_ref(),
-            items,
-        );
+        let parse = self.load_items(pkg, items);
+        self.out.extend_with(&parse);
 
         for item in items {
             if item.has_test_attr() {
@@ -246,12 +348,18 @@ impl<'a> Parser<'a> {
                         self.cfg_stack.push(cfg.clone());
                     }
 
+                    let entered_module = self.enter_module(pkg, &item.ident.to_string());
+
                     if let Some((_, ref inline_items)) = item.content {
                         self.process_expanded_mod(pkg, inline_items)?;
                     } else {
                         unreachable!();
                     }
 
+                    if entered_module {
+                        self.module = None;
+                    }
+
                     if cfg.is_some() {
                         self.cfg_stack.pop();
                     }
@@ -264,51 +372,74 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_mod(&mut self, pkg: &PackageRef, mod_path: &FilePath) -> Result<(), Error> {
-        let mod_parsed = {
-            let owned_mod_path = mod_path.to_path_buf();
+        let mod_parsed = self.load_mod(pkg, mod_path)?;
+        let mod_dir = mod_path.parent().unwrap();
 
-            if !self.cache_src.contains_key(&owned_mod_path) {
-                let mut s = String::new();
-                let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
-                    crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_str().unwrap().to_owned(),
-                })?;
-                f.read_to_string(&mut s)
-                    .map_err(|_| Error::ParseCannotOpenFile {
-                        crate_name: pkg.name.clone(),
-                        src_path: mod_path.to_str().unwrap().to_owned(),
-                    })?;
+        self.process_mod(pkg, mod_dir, &mod_parsed)
+    }
+
+    /// Loads the module at `mod_path` in the current context, from the caches
+    /// if it was already parsed.
+    fn load_mod(&mut self, pkg: &PackageRef, mod_path: &FilePath) -> Result<ParsedMod, Error> {
+        let context = format!(
+            "{:?}",
+            (
+                self.exporting_crate_name(pkg),
+                &pkg.name,
+                &self.cfg_stack,
+                &self.module,
+                &self.macro_expansion_config,
+            )
+        );
+        let key = (mod_path.to_path_buf(), context);
+        if let Some(mod_parsed) = self.cache_src.get(&key) {
+            return Ok(mod_parsed.clone());
+        }
 
+        let mut s = String::new();
+        let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
+            crate_name: pkg.name.clone(),
+            src_path: mod_path.to_str().unwrap().to_owned(),
+        })?;
+        f.read_to_string(&mut s)
+            .map_err(|_| Error::ParseCannotOpenFile {
+                crate_name: pkg.name.clone(),
+                src_path: mod_path.to_str().unwrap().to_owned(),
+            })?;
+        self.out.source_files.push(key.0.clone());
+
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(mod_path, &key.1, &s));
+        let mod_parsed = match cached {
+            Some(mod_parsed) => mod_parsed,
+            None => {
                 let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
-                    src_path: owned_mod_path.to_string_lossy().into(),
+                    src_path: mod_path.to_string_lossy().into(),
                     error: x,
                 })?;
-
-                self.cache_src.insert(owned_mod_path.clone(), i.items);
+                let mut mod_parsed = self.build_mod(pkg, &i.items);
+                mod_parsed.set_file(mod_path);
+                if let Some(ref cache) = self.cache {
+                    cache.insert(mod_path, &key.1, &s, &mod_parsed);
+                }
+                mod_parsed
             }
-
-            self.cache_src.get(&owned_mod_path).unwrap().clone()
         };
 
-        let mod_dir = mod_path.parent().unwrap();
-
-        self.process_mod(pkg, mod_dir, &mod_parsed)
+        self.cache_src.insert(key, mod_parsed.clone());
+        Ok(mod_parsed)
     }
 
-    fn process_mod(
-        &mut self,
-        pkg: &PackageRef,
-        mod_dir: &FilePath,
-        items: &[syn::Item],
-    ) -> Result<(), Error> {
-        self.out.load_syn_crate_mod(
-            &self.macro_expansion_config,
-            &self.binding_crate_name,
-            &pkg.name,
-            Cfg::join(&self.cfg_stack).as_ref(),
-            items,
-        );
+    /// Loads the items of a module and of its inline modules, and finds the
+    /// modules it declares in other files.
+    fn build_mod(&mut self, pkg: &PackageRef, items: &[syn::Item]) -> ParsedMod {
+        let mut mod_parsed = ParsedMod {
+            items: self.load_items(pkg, items),
+            mods: Vec::new(),
+        };
 
         for item in items {
             if item.has_test_attr() {
@@ -316,68 +447,188 @@ impl<'a> Parser<'a> {
             }
             match *item {
                 syn::Item::Mod(ref item) => {
-                    let next_mod_name = item.ident.to_string();
+                    let name = item.ident.to_string();
 
                     let cfg = Cfg::load(&item.attrs);
                     if let &Some(ref cfg) = &cfg {
                         self.cfg_stack.push(cfg.clone());
                     }
 
-                    if let Some((_, ref inline_items)) = item.content {
-                        self.process_mod(pkg, &mod_dir.join(&next_mod_name), inline_items)?;
+                    let entered_module = self.enter_module(pkg, &name);
+
+                    let content = if let Some((_, ref inline_items)) = item.content {
+                        ModContent::Inline(self.build_mod(pkg, inline_items))
                     } else {
-                        let next_mod_path1 = mod_dir.join(next_mod_name.clone() + ".rs");
-                        let next_mod_path2 = mod_dir.join(next_mod_name.clone()).join("mod.rs");
-
-                        if next_mod_path1.exists() {
-                            self.parse_mod(pkg, next_mod_path1.as_path())?;
-                        } else if next_mod_path2.exists() {
-                            self.parse_mod(pkg, next_mod_path2.as_path())?;
-                        } else {
-                            // Last chance to find a module path
-                            let mut path_attr_found = false;
-                            for attr in &item.attrs {
-                                match attr.interpret_meta() {
-                                    Some(syn::Meta::NameValue(syn::MetaNameValue {
-                                        ident,
-                                        lit,
-                                        ..
-                                    })) => match lit {
-                                        syn::Lit::Str(ref path) if ident == "path" => {
-                                            path_attr_found = true;
-                                            self.parse_mod(pkg, &mod_dir.join(path.value()))?;
-                                            break;
-                                        }
-                                        _ => (),
-                                    },
-                                    _ => (),
-                                }
-                            }
-
-                            // This should be an error, but it's common enough to
-                            // just elicit a warning
-                            if !path_attr_found {
-                                warn!(
-                                    "Parsing crate `{}`: can't find mod {}`.",
-                                    pkg.name, next_mod_name
-                                );
-                            }
+                        ModContent::File {
+                            path_attr: find_path_attr(&item.attrs),
                         }
+                    };
+
+                    if entered_module {
+                        self.module = None;
                     }
 
                     if cfg.is_some() {
                         self.cfg_stack.pop();
                     }
+
+                    mod_parsed.mods.push(ModDecl { name, cfg, content });
                 }
                 _ => {}
             }
         }
 
+        mod_parsed
+    }
+
+    fn process_mod(
+        &mut self,
+        pkg: &PackageRef,
+        mod_dir: &FilePath,
+        mod_parsed: &ParsedMod,
+    ) -> Result<(), Error> {
+        self.out.extend_with(&mod_parsed.items);
+
+        for decl in &mod_parsed.mods {
+            if let Some(ref cfg) = decl.cfg {
+                self.cfg_stack.push(cfg.clone());
+            }
+
+            let entered_module = self.enter_module(pkg, &decl.name);
+
+            match decl.content {
+                ModContent::Inline(ref inline_mod) => {
+                    self.process_mod(pkg, &mod_dir.join(&decl.name), inline_mod)?;
+                }
+                ModContent::File { ref path_attr } => {
+                    let next_mod_path1 = mod_dir.join(decl.name.clone() + ".rs");
+                    let next_mod_path2 = mod_dir.join(&decl.name).join("mod.rs");
+
+                    if next_mod_path1.exists() {
+                        self.parse_mod(pkg, next_mod_path1.as_path())?;
+                    } else if next_mod_path2.exists() {
+                        self.parse_mod(pkg, next_mod_path2.as_path())?;
+                    } else if let Some(ref path) = *path_attr {
+                        // Last chance to find a module path
+                        self.parse_mod(pkg, &mod_dir.join(path))?;
+                    } else {
+                        // This should be an error, but it's common enough to
+                        // just elicit a warning
+                        warn!(
+                            "Parsing crate `{}`: can't find mod {}`.",
+                            pkg.name, decl.name
+                        );
+                    }
+                }
+            }
+
+            if entered_module {
+                self.module = None;
+            }
+
+            if decl.cfg.is_some() {
+                self.cfg_stack.pop();
+            }
+        }
+
         Ok(())
     }
+
+    fn load_items(&self, pkg: &PackageRef, items: &[syn::Item]) -> Parse {
+        let mut parse = Parse::new();
+        parse.load_syn_crate_mod(
+            &self.macro_expansion_config,
+            self.exporting_crate_name(pkg),
+            &pkg.name,
+            Cfg::join(&self.cfg_stack).as_ref(),
+            items,
+        );
+        parse.set_origin(&pkg.name, self.module.as_ref().map(|x| x.as_str()));
+        parse
+    }
+
+    /// Records `name` as the current module if it is a top-level module of
+    /// the binding crate, returning whether it was.
+    fn enter_module(&mut self, pkg: &PackageRef, name: &str) -> bool {
+        if self.module.is_some() || pkg.name != self.binding_crate_name {
+            return false;
+        }
+        self.module = Some(name.to_owned());
+        true
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Finds the `#[path = "..."]` attribute of a `mod` declaration.
+fn find_path_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        match attr.interpret_meta() {
+            Some(syn::Meta::NameValue(syn::MetaNameValue { ident, lit, .. })) => match lit {
+                syn::Lit::Str(ref path) if ident == "path" => return Some(path.value()),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+    None
+}
+
+/// The items of a module, and the modules it declares.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ParsedMod {
+    items: Parse,
+    mods: Vec<ModDecl>,
+}
+
+/// A `mod` declaration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ModDecl {
+    name: String,
+    cfg: Option<Cfg>,
+    content: ModContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ModContent {
+    Inline(ParsedMod),
+    /// A module in another file, which is either next to the module, or at
+    /// the path of its `#[path]` attribute.
+    File {
+        path_attr: Option<String>,
+    },
+}
+
+impl ParsedMod {
+    /// Records `file` as the file the diagnostics of the module and of its
+    /// inline modules are about.
+    fn set_file(&mut self, file: &FilePath) {
+        for diagnostic in &mut self.items.diagnostics {
+            diagnostic.set_file(file);
+        }
+        for decl in &mut self.mods {
+            if let ModContent::Inline(ref mut mod_parsed) = decl.content {
+                mod_parsed.set_file(file);
+            }
+        }
+    }
+}
+
+/// Adds the `.rs` files in `dir` and its subdirectories.
+fn add_rust_files(dir: &FilePath, out: &mut Vec<FilePathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(|x| x.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            add_rust_files(&path, out);
+        } else if path.extension().map_or(false, |x| x == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parse {
     pub constants: ItemMap<Constant>,
     pub globals: ItemMap<Static>,
@@ -387,6 +638,29 @@ pub struct Parse {
     pub opaque_items: ItemMap<OpaqueItem>,
     pub typedefs: ItemMap<Typedef>,
     pub functions: Vec<Function>,
+    /// The `#[inline]` functions, which can be written as `static inline`
+    /// functions with the bodies given to them.
+    #[serde(default)]
+    pub inline_functions: Vec<Function>,
+    /// The traits that each type implements or derives, by their names.
+    #[serde(default)]
+    pub trait_impls: BTreeMap<Path, BTreeSet<String>>,
+    /// The source files that were read.
+    pub source_files: Vec<FilePathBuf>,
+    /// The `macro_rules!` macros that were defined.
+    #[serde(default)]
+    pub macro_definitions: Vec<MacroDefinition>,
+    /// The invocations of the macros that aren't built in, which are expanded
+    /// once the macros of their crate are all known.
+    #[serde(default)]
+    pub macro_calls: Vec<MacroCall>,
+    /// The paths of the items other than functions in the order they were
+    /// declared in.
+    #[serde(default)]
+    pub source_order: Vec<Path>,
+    /// The diagnostics about the items that were skipped.
+    #[serde(default)]
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 impl Parse {
@@ -400,6 +674,13 @@ impl Parse {
             opaque_items: ItemMap::new(),
             typedefs: ItemMap::new(),
             functions: Vec::new(),
+            inline_functions: Vec::new(),
+            trait_impls: BTreeMap::new(),
+            source_files: Vec::new(),
+            macro_definitions: Vec::new(),
+            macro_calls: Vec::new(),
+            source_order: Vec::new(),
+            diagnostics: Vec::new(),
         }
     }
 
@@ -409,7 +690,7 @@ impl Parse {
             let generic_params: Vec<_> = generic_params.into_iter().map(|s| Path::new(s)).collect();
             self.opaque_items.try_insert(OpaqueItem::new(
                 path,
-                GenericParams(generic_params),
+                GenericParams(generic_params, Vec::new()),
                 None,
                 AnnotationSet::new(),
                 Documentation::none(),
@@ -441,6 +722,126 @@ impl Parse {
         self.opaque_items.extend_with(&other.opaque_items);
         self.typedefs.extend_with(&other.typedefs);
         self.functions.extend_from_slice(&other.functions);
+        self.inline_functions
+            .extend_from_slice(&other.inline_functions);
+        for (path, traits) in &other.trait_impls {
+            self.trait_impls
+                .entry(path.clone())
+                .or_insert_with(BTreeSet::new)
+                .extend(traits.iter().cloned());
+        }
+        self.source_files.extend_from_slice(&other.source_files);
+        self.macro_definitions
+            .extend_from_slice(&other.macro_definitions);
+        self.macro_calls.extend_from_slice(&other.macro_calls);
+        self.source_order.extend_from_slice(&other.source_order);
+        self.diagnostics.extend_from_slice(&other.diagnostics);
+    }
+
+    /// Reports that the item `ident` of the crate or path `prefix` is skipped
+    /// because of `reason`.
+    fn skip_item(&mut self, lint: Lint, prefix: &str, ident: &syn::Ident, reason: &str) {
+        let path = format!("{}::{}", prefix, ident);
+        let message = format!("Skip {} - ({}).", path, reason);
+        self.diagnostics
+            .push(Diagnostic::new(lint, &path, message).at(ident.span()));
+    }
+
+    /// Records the crate the items were declared in, and the top-level
+    /// `module` of the binding crate if any.
+    pub fn set_origin(&mut self, crate_name: &str, module: Option<&str>) {
+        fn set(annotations: &mut AnnotationSet, crate_name: &str, module: Option<&str>) {
+            annotations.crate_name = Some(crate_name.to_owned());
+            annotations.module = module.map(|x| x.to_owned());
+        }
+
+        self.constants
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.globals
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.enums
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.structs
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.unions
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.opaque_items
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        self.typedefs
+            .for_all_items_mut(|x| set(x.annotations_mut(), crate_name, module));
+        for function in self.functions.iter_mut().chain(&mut self.inline_functions) {
+            set(&mut function.annotations, crate_name, module);
+        }
+        for call in &mut self.macro_calls {
+            call.module = module.map(|x| x.to_owned());
+        }
+    }
+
+    /// Expands the invocations of `macro_rules!` macros with the macros of
+    /// their crates, loading the items they expand to.
+    pub fn expand_macro_rules(
+        &mut self,
+        macro_expansion_config: &MacroExpansionConfig,
+        binding_crate_name: &str,
+        members: &[String],
+    ) {
+        // Expansions can invoke macros again, but not forever.
+        for _ in 0..MAX_MACRO_EXPANSION_DEPTH {
+            let calls = mem::replace(&mut self.macro_calls, Vec::new());
+            if calls.is_empty() {
+                return;
+            }
+
+            for call in calls {
+                let definition = self
+                    .macro_definitions
+                    .iter()
+                    .rev()
+                    .find(|x| x.crate_name == call.crate_name && x.name == call.name);
+                let definition = match definition {
+                    Some(definition) => definition,
+                    // Macros of other crates can't be expanded.
+                    None => continue,
+                };
+
+                let items = MacroRules::parse(&definition.rules)
+                    .and_then(|rules| rules.expand(&call.tokens))
+                    .and_then(|tokens| syn::parse2::<syn::File>(tokens).map_err(|e| e.to_string()));
+                let items = match items {
+                    Ok(file) => file.items,
+                    Err(e) => {
+                        warn!(
+                            "Skip {}::{}! - (couldn't expand it: {}).",
+                            call.crate_name, call.name, e
+                        );
+                        continue;
+                    }
+                };
+
+                let exporting_crate_name = if members.contains(&call.crate_name) {
+                    &call.crate_name
+                } else {
+                    binding_crate_name
+                };
+                let mut parse = Parse::new();
+                parse.load_syn_crate_mod(
+                    macro_expansion_config,
+                    exporting_crate_name,
+                    &call.crate_name,
+                    call.cfg.as_ref(),
+                    &items,
+                );
+                parse.set_origin(&call.crate_name, call.module.as_ref().map(|x| x.as_str()));
+                self.extend_with(&parse);
+            }
+        }
+
+        for call in mem::replace(&mut self.macro_calls, Vec::new()) {
+            warn!(
+                "Skip {}::{}! - (expanding it recursed too deeply).",
+                call.crate_name, call.name
+            );
+        }
     }
 
     pub fn load_syn_crate_mod(
@@ -452,6 +853,7 @@ impl Parse {
         items: &[syn::Item],
     ) {
         let mut impls_with_assoc_consts = Vec::new();
+        let mut impls_with_methods = Vec::new();
 
         for item in items {
             if item.has_test_attr() {
@@ -490,6 +892,16 @@ impl Parse {
                     if has_assoc_const {
                         impls_with_assoc_consts.push(item_impl);
                     }
+                    let has_method = item_impl.items.iter().any(|item| match item {
+                        syn::ImplItem::Method(_) => true,
+                        _ => false,
+                    });
+                    if has_method {
+                        impls_with_methods.push(item_impl);
+                    }
+                    if let Some((_, ref trait_path, _)) = item_impl.trait_ {
+                        self.load_syn_trait_impl(&item_impl.self_ty, trait_path);
+                    }
                 }
                 syn::Item::Macro(ref item) => {
                     self.load_builtin_macro(macro_expansion_config, crate_name, mod_cfg, item)
@@ -501,6 +913,98 @@ impl Parse {
         for item_impl in impls_with_assoc_consts {
             self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, item_impl)
         }
+        for item_impl in impls_with_methods {
+            self.load_syn_methods(binding_crate_name, crate_name, mod_cfg, item_impl)
+        }
+    }
+
+    /// Loads the `#[no_mangle] extern "C"` methods of an `impl` block, which
+    /// are exported as functions taking `self` as their first argument. The
+    /// methods of trait impls, like the ones some proc-macros generate, are
+    /// loaded too, and `#[export_name = "name"]` gives a method its symbol.
+    fn load_syn_methods(
+        &mut self,
+        binding_crate_name: &str,
+        crate_name: &str,
+        mod_cfg: Option<&Cfg>,
+        item_impl: &syn::ItemImpl,
+    ) {
+        if crate_name != binding_crate_name {
+            return;
+        }
+
+        let methods: Vec<_> = item_impl
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Method(ref method)
+                    if method.attrs.has_attr_word("no_mangle")
+                        || method.attrs.export_name().is_some() =>
+                {
+                    let calling_convention = method.sig.abi.calling_convention()?;
+                    Some((method, calling_convention))
+                }
+                _ => None,
+            })
+            .collect();
+        if methods.is_empty() {
+            return;
+        }
+
+        let impl_path = match Type::load(&item_impl.self_ty) {
+            Ok(Some(Type::Path(ref path))) if path.generics().is_empty() => path.path().clone(),
+            _ => {
+                for (method, _) in methods {
+                    self.skip_item(
+                        Lint::SkippedItem,
+                        crate_name,
+                        &method.sig.ident,
+                        "methods are only supported on non-generic types",
+                    );
+                }
+                return;
+            }
+        };
+
+        for (method, calling_convention) in methods {
+            // The methods of trait impls are as visible as the trait.
+            if let syn::Visibility::Public(_) = method.vis {
+            } else if item_impl.trait_.is_none() {
+                self.skip_item(
+                    Lint::SkippedItem,
+                    crate_name,
+                    &method.sig.ident,
+                    "not `pub`",
+                );
+                continue;
+            }
+
+            let name = method
+                .attrs
+                .export_name()
+                .unwrap_or_else(|| method.sig.ident.to_string());
+            let path = Path::new(name);
+            match Function::load(
+                path,
+                &method.sig.decl,
+                calling_convention,
+                method.sig.abi.is_unwind(),
+                false,
+                &method.attrs,
+                mod_cfg,
+                Some(&impl_path),
+            ) {
+                Ok(func) => {
+                    info!("Take {}::{}::{}.", crate_name, impl_path, &method.sig.ident);
+
+                    self.functions.push(func);
+                }
+                Err(msg) => {
+                    let prefix = format!("{}::{}", crate_name, impl_path);
+                    self.skip_item(Lint::UnsupportedType, &prefix, &method.sig.ident, &msg);
+                }
+            }
+        }
     }
 
     fn load_syn_assoc_consts_from_impl(
@@ -529,10 +1033,13 @@ impl Parse {
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemForeignMod,
     ) {
-        if !item.abi.is_c() {
-            info!("Skip {} - (extern block must be extern C).", crate_name);
-            return;
-        }
+        let calling_convention = match item.abi.calling_convention() {
+            Some(calling_convention) => calling_convention,
+            None => {
+                info!("Skip {} - (extern block must be extern C).", crate_name);
+                return;
+            }
+        };
 
         for foreign_item in &item.items {
             match *foreign_item {
@@ -545,16 +1052,27 @@ impl Parse {
                         return;
                     }
                     let path = Path::new(function.ident.to_string());
-                    match Function::load(path, &function.decl, true, &function.attrs, mod_cfg) {
+                    match Function::load(
+                        path,
+                        &function.decl,
+                        calling_convention,
+                        item.abi.is_unwind(),
+                        true,
+                        &function.attrs,
+                        mod_cfg,
+                        None,
+                    ) {
                         Ok(func) => {
                             info!("Take {}::{}.", crate_name, &function.ident);
 
                             self.functions.push(func);
                         }
                         Err(msg) => {
-                            error!(
-                                "Cannot use fn {}::{} ({}).",
-                                crate_name, &function.ident, msg
+                            self.skip_item(
+                                Lint::UnsupportedType,
+                                crate_name,
+                                &function.ident,
+                                &msg,
                             );
                         }
                     }
@@ -581,16 +1099,48 @@ impl Parse {
         }
 
         if let syn::Visibility::Public(_) = item.vis {
-            if item.is_no_mangle() && (item.abi.is_omitted() || item.abi.is_c()) {
+            let calling_convention = item.abi.calling_convention();
+            if let (true, Some(calling_convention)) = (item.is_no_mangle(), calling_convention) {
                 let path = Path::new(item.ident.to_string());
-                match Function::load(path, &item.decl, false, &item.attrs, mod_cfg) {
+                match Function::load(
+                    path,
+                    &item.decl,
+                    calling_convention,
+                    item.abi.is_unwind(),
+                    false,
+                    &item.attrs,
+                    mod_cfg,
+                    None,
+                ) {
                     Ok(func) => {
                         info!("Take {}::{}.", crate_name, &item.ident);
 
                         self.functions.push(func);
                     }
                     Err(msg) => {
-                        error!("Cannot use fn {}::{} ({}).", crate_name, &item.ident, msg);
+                        self.skip_item(Lint::UnsupportedType, crate_name, &item.ident, &msg);
+                    }
+                }
+                return;
+            }
+            if item.abi.is_none()
+                && item.decl.generics.params.is_empty()
+                && item.attrs.has_attr_named("inline")
+            {
+                let path = Path::new(item.ident.to_string());
+                match Function::load(
+                    path,
+                    &item.decl,
+                    CallingConvention::C,
+                    false,
+                    false,
+                    &item.attrs,
+                    mod_cfg,
+                    None,
+                ) {
+                    Ok(func) => self.inline_functions.push(func),
+                    Err(msg) => {
+                        info!("Skip inline fn {}::{} ({}).", crate_name, &item.ident, msg);
                     }
                 }
                 return;
@@ -600,18 +1150,22 @@ impl Parse {
         // TODO
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            self.skip_item(Lint::SkippedItem, crate_name, &item.ident, "not `pub`");
         }
-        if (item.abi.is_omitted() || item.abi.is_c()) && !item.is_no_mangle() {
-            warn!(
-                "Skip {}::{} - (`extern` but not `no_mangle`).",
-                crate_name, &item.ident
+        if item.abi.calling_convention().is_some() && !item.is_no_mangle() {
+            self.skip_item(
+                Lint::SkippedItem,
+                crate_name,
+                &item.ident,
+                "`extern` but not `no_mangle`",
             );
         }
-        if item.abi.is_some() && !(item.abi.is_omitted() || item.abi.is_c()) {
-            warn!(
-                "Skip {}::{} - (non `extern \"C\"`).",
-                crate_name, &item.ident
+        if item.abi.is_some() && item.abi.calling_convention().is_none() {
+            self.skip_item(
+                Lint::SkippedItem,
+                crate_name,
+                &item.ident,
+                "unsupported calling convention",
             );
         }
     }
@@ -642,7 +1196,7 @@ impl Parse {
         for item in items.into_iter() {
             if let syn::Visibility::Public(_) = item.vis {
             } else {
-                warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+                self.skip_item(Lint::SkippedItem, crate_name, &item.ident, "not `pub`");
                 return;
             }
 
@@ -657,6 +1211,7 @@ impl Parse {
             ) {
                 Ok(constant) => {
                     info!("Take {}::{}::{}.", crate_name, impl_path, &item.ident);
+                    self.source_order.push(constant.path.clone());
                     let mut any = false;
                     self.structs.for_items_mut(&impl_path, |item| {
                         any = true;
@@ -672,7 +1227,7 @@ impl Parse {
                     }
                 }
                 Err(msg) => {
-                    warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    self.skip_item(Lint::UnsupportedType, crate_name, &item.ident, &msg);
                 }
             }
         }
@@ -696,7 +1251,7 @@ impl Parse {
 
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            self.skip_item(Lint::SkippedItem, crate_name, &item.ident, "not `pub`");
             return;
         }
 
@@ -706,12 +1261,13 @@ impl Parse {
                 info!("Take {}::{}.", crate_name, &item.ident);
 
                 let full_name = constant.path.clone();
+                self.source_order.push(full_name.clone());
                 if !self.constants.try_insert(constant) {
                     error!("Conflicting name for constant {}", full_name);
                 }
             }
             Err(msg) => {
-                warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                self.skip_item(Lint::UnsupportedType, crate_name, &item.ident, &msg);
             }
         }
     }
@@ -733,32 +1289,82 @@ impl Parse {
         }
 
         if let syn::Visibility::Public(_) = item.vis {
-            if item.is_no_mangle() {
-                match Static::load(item, mod_cfg) {
-                    Ok(constant) => {
-                        info!("Take {}::{}.", crate_name, &item.ident);
+        } else {
+            self.skip_item(Lint::SkippedItem, crate_name, &item.ident, "not `pub`");
+            return;
+        }
+        if !item.is_no_mangle() {
+            self.skip_item(
+                Lint::SkippedItem,
+                crate_name,
+                &item.ident,
+                "not `no_mangle`",
+            );
+            return;
+        }
+        // Each thread has its own instance of a thread-local, which an
+        // `extern` declaration can't refer to.
+        if item.has_attr_word("thread_local") {
+            self.skip_item(
+                Lint::SkippedItem,
+                crate_name,
+                &item.ident,
+                "thread-local statics can't be declared `extern`",
+            );
+            return;
+        }
 
-                        self.globals.try_insert(constant);
-                    }
-                    Err(msg) => {
-                        warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
-                    }
-                }
+        match Static::load(item, mod_cfg) {
+            Ok(constant) => {
+                info!("Take {}::{}.", crate_name, &item.ident);
+
+                self.source_order.push(constant.path.clone());
+                self.globals.try_insert(constant);
+            }
+            Err(msg) => {
+                self.skip_item(Lint::UnsupportedType, crate_name, &item.ident, &msg);
             }
         }
+    }
 
-        // TODO
-        if let syn::Visibility::Public(_) = item.vis {
-        } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+    /// Records that a type implements a trait, for the C++ operators of its
+    /// methods.
+    fn load_syn_trait_impl(&mut self, self_ty: &syn::Type, trait_path: &syn::Path) {
+        let path = match Type::load(self_ty) {
+            Ok(Some(Type::Path(ref path))) if path.generics().is_empty() => path.path().clone(),
+            _ => return,
+        };
+        if let Some(segment) = trait_path.segments.last() {
+            self.trait_impls
+                .entry(path)
+                .or_insert_with(BTreeSet::new)
+                .insert(segment.value().ident.to_string());
         }
-        if !item.is_no_mangle() {
-            warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+    }
+
+    /// Records the traits a type derives.
+    fn load_syn_derives(&mut self, ident: &syn::Ident, attrs: &[syn::Attribute]) {
+        for attr in attrs {
+            if let Some(syn::Meta::List(ref list)) = attr.interpret_meta() {
+                if list.ident != "derive" {
+                    continue;
+                }
+                for nested in &list.nested {
+                    if let syn::NestedMeta::Meta(syn::Meta::Word(ref trait_ident)) = *nested {
+                        self.trait_impls
+                            .entry(Path::new(ident.to_string()))
+                            .or_insert_with(BTreeSet::new)
+                            .insert(trait_ident.to_string());
+                    }
+                }
+            }
         }
     }
 
     /// Loads a `struct` declaration
     fn load_syn_struct(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemStruct) {
+        self.load_syn_derives(&item.ident, &item.attrs);
+        self.source_order.push(Path::new(item.ident.to_string()));
         match Struct::load(item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
@@ -767,15 +1373,17 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
                 let path = Path::new(item.ident.to_string());
-                self.opaque_items.try_insert(
-                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
-                );
+                let mut opaque =
+                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap();
+                opaque.fields = OpaqueItem::load_fields(&item.attrs, &item.fields);
+                self.opaque_items.try_insert(opaque);
             }
         }
     }
 
     /// Loads a `union` declaration
     fn load_syn_union(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemUnion) {
+        self.source_order.push(Path::new(item.ident.to_string()));
         match Union::load(item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
@@ -802,6 +1410,7 @@ impl Parse {
             return;
         }
 
+        self.source_order.push(Path::new(item.ident.to_string()));
         match Enum::load(item, mod_cfg) {
             Ok(en) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
@@ -819,6 +1428,7 @@ impl Parse {
 
     /// Loads a `type` declaration
     fn load_syn_ty(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemType) {
+        self.source_order.push(Path::new(item.ident.to_string()));
         match Typedef::load(item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
@@ -847,7 +1457,31 @@ impl Parse {
             None => return,
         };
 
-        if name != "bitflags" || !macro_expansion_config.bitflags {
+        if name == "macro_rules" {
+            if let (true, Some(ref ident)) = (macro_expansion_config.macro_rules, &item.ident) {
+                self.macro_definitions.push(MacroDefinition {
+                    crate_name: crate_name.to_owned(),
+                    name: ident.to_string(),
+                    rules: item.mac.tts.to_string(),
+                });
+            }
+            return;
+        }
+
+        if name != "bitflags" {
+            if macro_expansion_config.macro_rules {
+                self.macro_calls.push(MacroCall {
+                    crate_name: crate_name.to_owned(),
+                    name: name,
+                    tokens: item.mac.tts.to_string(),
+                    cfg: Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+                    module: None,
+                });
+            }
+            return;
+        }
+
+        if !macro_expansion_config.bitflags {
             return;
         }
 
@@ -860,7 +1494,9 @@ impl Parse {
         };
 
         let (struct_, impl_) = bitflags.expand();
-        self.load_syn_struct(crate_name, mod_cfg, &struct_);
+        if let Some(ref struct_) = struct_ {
+            self.load_syn_struct(crate_name, mod_cfg, struct_);
+        }
         // We know that the expansion will only reference `struct_`, so it's
         // fine to just do it here instead of deferring it like we do with the
         // other calls to this function.
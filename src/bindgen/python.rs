@@ -0,0 +1,830 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a Python module built on top of `ctypes`.
+//!
+//! The layout of every item mirrors the one of the C header, so the module
+//! can be used against the same shared library. Structs and unions are
+//! declared as empty `ctypes.Structure` / `ctypes.Union` subclasses first and
+//! get their `_fields_` assigned afterwards, which is what allows them to
+//! reference themselves (or items declared later) through pointers.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use bindgen::config::Config;
+use bindgen::ir::{
+    CallingConvention, Cfg, Constant, Documentation, Enum, Function, GenericPath, Item,
+    ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, ReprAlign, ReprStyle, ReprType,
+    Static, Struct, Type, Typedef, Union,
+};
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// The `ctypes` base class of an item that is written as a class.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ClassKind {
+    Structure,
+    Union,
+}
+
+impl ClassKind {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            ClassKind::Structure => "ctypes.Structure",
+            ClassKind::Union => "ctypes.Union",
+        }
+    }
+}
+
+/// Returns the class kind of an item if it is written as a Python class.
+fn class_kind(item: &ItemContainer) -> Option<ClassKind> {
+    match *item {
+        ItemContainer::Struct(ref s) if !s.is_transparent => Some(ClassKind::Structure),
+        ItemContainer::Union(..) => Some(ClassKind::Union),
+        ItemContainer::OpaqueItem(..) => Some(ClassKind::Structure),
+        ItemContainer::Enum(ref e) if e.tag.is_some() => {
+            if e.repr.style == ReprStyle::C {
+                Some(ClassKind::Structure)
+            } else {
+                Some(ClassKind::Union)
+            }
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn is_exported(item: &ItemContainer) -> bool {
+    !item
+        .deref()
+        .annotations()
+        .bool("no-export")
+        .unwrap_or(false)
+}
+
+fn primitive_ctype(primitive: &PrimitiveType) -> &'static str {
+    match *primitive {
+        PrimitiveType::Void => "None",
+        PrimitiveType::Bool => "ctypes.c_bool",
+        PrimitiveType::Char => "ctypes.c_char",
+        PrimitiveType::WChar => "ctypes.c_wchar",
+        PrimitiveType::SChar => "ctypes.c_byte",
+        PrimitiveType::UChar => "ctypes.c_ubyte",
+        PrimitiveType::Short => "ctypes.c_short",
+        PrimitiveType::Int => "ctypes.c_int",
+        PrimitiveType::Long => "ctypes.c_long",
+        PrimitiveType::LongLong => "ctypes.c_longlong",
+        PrimitiveType::UShort => "ctypes.c_ushort",
+        PrimitiveType::UInt => "ctypes.c_uint",
+        PrimitiveType::ULong => "ctypes.c_ulong",
+        PrimitiveType::ULongLong => "ctypes.c_ulonglong",
+        // ctypes has no intptr_t / uintptr_t, but they match size_t and
+        // ssize_t on every platform ctypes supports.
+        PrimitiveType::USize => "ctypes.c_size_t",
+        PrimitiveType::UInt8 => "ctypes.c_uint8",
+        PrimitiveType::UInt16 => "ctypes.c_uint16",
+        PrimitiveType::UInt32 => "ctypes.c_uint32",
+        PrimitiveType::UInt64 => "ctypes.c_uint64",
+        PrimitiveType::ISize => "ctypes.c_ssize_t",
+        PrimitiveType::Int8 => "ctypes.c_int8",
+        PrimitiveType::Int16 => "ctypes.c_int16",
+        PrimitiveType::Int32 => "ctypes.c_int32",
+        PrimitiveType::Int64 => "ctypes.c_int64",
+        PrimitiveType::Int128 | PrimitiveType::UInt128 => {
+            unreachable!("128-bit integers should have been lowered to structs")
+        }
+        PrimitiveType::Float => "ctypes.c_float",
+        PrimitiveType::Double => "ctypes.c_double",
+        PrimitiveType::SizeT => "ctypes.c_size_t",
+        PrimitiveType::SSizeT => "ctypes.c_ssize_t",
+        PrimitiveType::PtrDiffT => "ctypes.c_ssize_t",
+        PrimitiveType::VaList => "ctypes.c_void_p",
+    }
+}
+
+fn repr_ctype(repr: Option<ReprType>) -> &'static str {
+    match repr {
+        None => "ctypes.c_int",
+        Some(ReprType::U8) => "ctypes.c_uint8",
+        Some(ReprType::U16) => "ctypes.c_uint16",
+        Some(ReprType::U32) => "ctypes.c_uint32",
+        Some(ReprType::USize) => "ctypes.c_size_t",
+        Some(ReprType::I8) => "ctypes.c_int8",
+        Some(ReprType::I16) => "ctypes.c_int16",
+        Some(ReprType::I32) => "ctypes.c_int32",
+        Some(ReprType::ISize) => "ctypes.c_ssize_t",
+    }
+}
+
+/// Returns the `ctypes` expression describing `ty`.
+fn ctype(ty: &Type) -> String {
+    match *ty {
+        Type::ConstPtr(ref pointee, _) => match **pointee {
+            Type::Primitive(PrimitiveType::Void) => "ctypes.c_void_p".to_owned(),
+            Type::Primitive(PrimitiveType::Char) => "ctypes.c_char_p".to_owned(),
+            Type::Primitive(PrimitiveType::WChar) => "ctypes.c_wchar_p".to_owned(),
+            _ => format!("ctypes.POINTER({})", ctype(pointee)),
+        },
+        Type::Ptr(ref pointee, _) | Type::Ref(ref pointee) | Type::MutRef(ref pointee) => {
+            match **pointee {
+                Type::Primitive(PrimitiveType::Void) => "ctypes.c_void_p".to_owned(),
+                _ => format!("ctypes.POINTER({})", ctype(pointee)),
+            }
+        }
+        Type::Path(ref generic) => generic.export_name().to_owned(),
+        Type::Primitive(ref primitive) => primitive_ctype(primitive).to_owned(),
+        Type::Array(ref ty, ref len) => format!("({} * {})", ctype(ty), len.as_str()),
+        Type::FuncPtr(ref ret, ref args, _, calling_convention, _) => {
+            let mut params = vec![ctype(ret)];
+            params.extend(args.iter().map(|(_, ty)| ctype(ty)));
+            format!(
+                "{}({})",
+                function_type(calling_convention),
+                params.join(", ")
+            )
+        }
+        Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+            unreachable!("{:?} should have been lowered to a struct", ty)
+        }
+    }
+}
+
+/// Returns the `ctypes` factory of function types with `calling_convention`.
+fn function_type(calling_convention: CallingConvention) -> &'static str {
+    match calling_convention {
+        CallingConvention::C => "ctypes.CFUNCTYPE",
+        CallingConvention::Stdcall | CallingConvention::System => "WINFUNCTYPE",
+        CallingConvention::Fastcall => {
+            warn!("ctypes has no fastcall calling convention, writing it as C.");
+            "ctypes.CFUNCTYPE"
+        }
+    }
+}
+
+/// Whether functions with `calling_convention` are called like
+/// `ctypes.WinDLL`'s.
+fn is_stdcall(calling_convention: CallingConvention) -> bool {
+    match calling_convention {
+        CallingConvention::Stdcall | CallingConvention::System => true,
+        _ => false,
+    }
+}
+
+/// Collects the names of all the items `ty` refers to.
+fn referenced_names<'a>(ty: &'a Type, out: &mut Vec<&'a str>) {
+    match *ty {
+        Type::ConstPtr(ref ty, _)
+        | Type::Ptr(ref ty, _)
+        | Type::Ref(ref ty)
+        | Type::MutRef(ref ty)
+        | Type::Array(ref ty, _)
+        | Type::ConstSlice(ref ty)
+        | Type::Slice(ref ty)
+        | Type::Atomic(ref ty) => referenced_names(ty, out),
+        Type::Path(ref generic) => out.push(generic.export_name()),
+        Type::Primitive(..) => {}
+        Type::FuncPtr(ref ret, ref args, _, _, _) => {
+            referenced_names(ret, out);
+            for (_, ty) in args {
+                referenced_names(ty, out);
+            }
+        }
+        Type::Tuple(ref elems) => {
+            for ty in elems {
+                referenced_names(ty, out);
+            }
+        }
+    }
+}
+
+fn item_referenced_names(item: &ItemContainer) -> Vec<&str> {
+    let mut names = Vec::new();
+    match *item {
+        ItemContainer::Struct(ref s) => {
+            for (_, ty, _) in &s.fields {
+                referenced_names(ty, &mut names);
+            }
+        }
+        ItemContainer::Union(ref u) => {
+            for (_, ty, _) in &u.fields {
+                referenced_names(ty, &mut names);
+            }
+        }
+        ItemContainer::Enum(ref e) => {
+            for variant in &e.variants {
+                if let Some((_, ref body)) = variant.body {
+                    for (_, ty, _) in &body.fields {
+                        referenced_names(ty, &mut names);
+                    }
+                }
+            }
+        }
+        ItemContainer::Typedef(ref t) => referenced_names(&t.aliased, &mut names),
+        _ => {}
+    }
+    names
+}
+
+/// Converts a literal in C syntax, as stored in the IR, to Python.
+fn literal(bindings: &Bindings, value: &Literal, floating: bool) -> String {
+    match *value {
+        Literal::Expr(ref v) => expr_literal(v),
+        Literal::Path(ref name) => name.clone(),
+        Literal::Cast { .. } => unreachable!("Casts are evaluated when generating the bindings."),
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => {
+            let op = if op == "/" && !floating { "//" } else { op };
+            let operand = |value: &Literal| match *value {
+                Literal::BinOp { .. } => format!("({})", literal(bindings, value, floating)),
+                _ => literal(bindings, value, floating),
+            };
+            format!("{} {} {}", operand(left), op, operand(right))
+        }
+        Literal::Struct {
+            ref path,
+            ref export_name,
+            ref fields,
+        } => {
+            if bindings.struct_is_transparent(path) {
+                return literal(bindings, &fields[0].1, floating);
+            }
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|(key, lit)| format!("{}={}", key, literal(bindings, lit, floating)))
+                .collect();
+            format!("{}({})", export_name, fields.join(", "))
+        }
+    }
+}
+
+fn expr_literal(v: &str) -> String {
+    match v {
+        "true" => return "True".to_owned(),
+        "false" => return "False".to_owned(),
+        "NAN" => return "float(\"nan\")".to_owned(),
+        "INFINITY" => return "float(\"inf\")".to_owned(),
+        "-INFINITY" => return "float(\"-inf\")".to_owned(),
+        _ => {}
+    }
+
+    if v.starts_with("u8\"") && v.ends_with('"') {
+        // A string, written as a bytes literal so it can be used as a
+        // `c_char_p`.
+        let mut result = String::from("b\"");
+        for byte in v[3..v.len() - 1].bytes() {
+            match byte {
+                b'"' | b'\\' => {
+                    result.push('\\');
+                    result.push(byte as char);
+                }
+                0x20..=0x7e => result.push(byte as char),
+                _ => result.push_str(&format!("\\x{:02x}", byte)),
+            }
+        }
+        result.push('"');
+        return result;
+    }
+
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        return format!("chr(0x{})", &v[4..v.len() - 1]);
+    }
+
+    if v.starts_with('\'') && v.ends_with('\'') {
+        // `char::escape_default` output, which only differs from Python in
+        // how it writes unicode escapes.
+        if let Some(start) = v.find("\\u{") {
+            let code = &v[start + 3..v.len() - 2];
+            return format!("chr(0x{})", code);
+        }
+        return v.to_owned();
+    }
+
+    v.to_owned()
+}
+
+fn is_floating(ty: &Type) -> bool {
+    match *ty {
+        Type::Primitive(PrimitiveType::Float) | Type::Primitive(PrimitiveType::Double) => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn write_documentation<F: Write>(
+    config: &Config,
+    documentation: &Documentation,
+    out: &mut SourceWriter<F>,
+) {
+    if documentation.doc_comment.is_empty() || !config.documentation {
+        return;
+    }
+
+    for line in &documentation.doc_comment {
+        out.write("#");
+        if !line.is_empty() {
+            out.write(" ");
+        }
+        write!(out, "{}", line);
+        out.new_line();
+    }
+}
+
+/// Python has no preprocessor, so conditional items are always written and
+/// their condition is only recorded in a comment.
+pub(crate) fn write_cfg<F: Write>(cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+    if let Some(ref cfg) = *cfg {
+        write!(out, "# cfg({})", cfg);
+        out.new_line();
+    }
+}
+
+/// Writes a Python module with `ctypes` declarations for `bindings`.
+pub(crate) struct PythonWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    /// The classes that are declared ahead of the items, because they're
+    /// referenced before their definition.
+    forward_declared: HashSet<&'a str>,
+}
+
+impl<'a> PythonWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        PythonWriter {
+            bindings,
+            config: &bindings.config,
+            forward_declared: HashSet::new(),
+        }
+    }
+
+    pub fn write<F: Write>(mut self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        for constant in &self.bindings.constants {
+            if constant.ty.is_primitive_or_ptr_primitive() {
+                out.new_line_if_not_start();
+                self.write_constant(constant, None, out);
+            }
+        }
+
+        self.write_forward_declarations(out);
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            if let ItemContainer::OpaqueItem(ref x) = *item {
+                if self.forward_declared.contains(x.export_name()) {
+                    continue;
+                }
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => self.write_struct(x, out),
+                ItemContainer::Union(ref x) => self.write_union(x, out),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque(x, out),
+                ItemContainer::Typedef(ref x) => self.write_typedef(x, out),
+            }
+        }
+
+        for constant in &self.bindings.constants {
+            if !constant.ty.is_primitive_or_ptr_primitive() {
+                out.new_line_if_not_start();
+                self.write_constant(constant, None, out);
+            }
+        }
+
+        if !self.bindings.functions.is_empty() || !self.bindings.globals.is_empty() {
+            out.new_line_if_not_start();
+            self.write_load(out);
+        }
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "# Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        out.new_line_if_not_start();
+        out.write("import ctypes");
+        out.new_line();
+
+        let conventions = self.bindings.calling_conventions();
+        if conventions.contains(&CallingConvention::Stdcall)
+            || conventions.contains(&CallingConvention::System)
+        {
+            out.new_line();
+            out.write("# Only Windows has `stdcall` functions.");
+            out.new_line();
+            out.write("WINFUNCTYPE = getattr(ctypes, \"WINFUNCTYPE\", ctypes.CFUNCTYPE)");
+            out.new_line();
+        }
+    }
+
+    /// Finds the classes that are referenced before they are defined, and
+    /// declares them up-front.
+    fn write_forward_declarations<F: Write>(&mut self, out: &mut SourceWriter<F>) {
+        let bindings = self.bindings;
+        let classes: HashMap<&str, ClassKind> = bindings
+            .items
+            .iter()
+            .filter(|item| is_exported(item))
+            .filter_map(|item| class_kind(item).map(|kind| (item.deref().export_name(), kind)))
+            .collect();
+
+        let mut declared = HashSet::new();
+        let mut forward = Vec::new();
+        for item in &bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+            let name = item.deref().export_name();
+            // Tagged enums write their variant bodies before their own class,
+            // so they can't refer to themselves without a forward declaration.
+            let is_enum = match *item {
+                ItemContainer::Enum(..) => true,
+                _ => false,
+            };
+            for referenced in item_referenced_names(item) {
+                if (referenced == name && !is_enum) || declared.contains(referenced) {
+                    continue;
+                }
+                if let Some(&kind) = classes.get(referenced) {
+                    declared.insert(referenced);
+                    forward.push((referenced, kind));
+                }
+            }
+            declared.insert(name);
+        }
+
+        for &(name, kind) in &forward {
+            out.new_line_if_not_start();
+            // Opaque items have nothing but their declaration, so they're
+            // entirely written here.
+            for item in &self.bindings.items {
+                if let ItemContainer::OpaqueItem(ref x) = *item {
+                    if x.export_name() == name {
+                        write_cfg(&x.cfg, out);
+                        write_documentation(self.config, &x.documentation, out);
+                    }
+                }
+            }
+            self.write_class(name, kind, out);
+        }
+
+        self.forward_declared = forward.into_iter().map(|(name, _)| name).collect();
+    }
+
+    fn write_class<F: Write>(&self, name: &str, kind: ClassKind, out: &mut SourceWriter<F>) {
+        write!(out, "class {}({}):", name, kind.as_str());
+        out.push_tab();
+        out.new_line();
+        out.write("pass");
+        out.pop_tab();
+        out.new_line();
+    }
+
+    /// Writes the class for `name`, unless it was forward declared, followed by
+    /// the assignment of its `_fields_`, which are bitfields if `widths` is
+    /// given.
+    fn write_class_with_fields<F: Write>(
+        &self,
+        name: &str,
+        kind: ClassKind,
+        fields: &[(String, Type, Documentation)],
+        widths: Option<&[u32]>,
+        anonymous: Option<&str>,
+        alignment: Option<ReprAlign>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if !self.forward_declared.contains(name) {
+            self.write_class(name, kind, out);
+            out.new_line();
+        }
+
+        if let Some(anonymous) = anonymous {
+            write!(out, "{}._anonymous_ = (\"{}\",)", name, anonymous);
+            out.new_line();
+        }
+
+        // Both have to be set before `_fields_`. Only Python 3.13 and later
+        // support `_align_`.
+        match alignment {
+            Some(ReprAlign::Packed(n)) => {
+                write!(out, "{}._pack_ = {}", name, n);
+                out.new_line();
+            }
+            Some(ReprAlign::Align(n)) => {
+                write!(out, "{}._align_ = {}", name, n);
+                out.new_line();
+            }
+            None => {}
+        }
+
+        if fields.is_empty() {
+            write!(out, "{}._fields_ = []", name);
+            out.new_line();
+            return;
+        }
+
+        write!(out, "{}._fields_ = [", name);
+        out.push_tab();
+        for (i, (field, ty, documentation)) in fields.iter().enumerate() {
+            out.new_line();
+            write_documentation(self.config, documentation, out);
+            match widths {
+                Some(widths) => write!(out, "(\"{}\", {}, {}),", field, ctype(ty), widths[i]),
+                None => write!(out, "(\"{}\", {}),", field, ctype(ty)),
+            }
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("]");
+        out.new_line();
+    }
+
+    fn write_struct<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        write_cfg(&s.cfg, out);
+        write_documentation(self.config, &s.documentation, out);
+
+        if s.is_transparent {
+            write!(out, "{} = {}", s.export_name(), ctype(&s.fields[0].1));
+            out.new_line();
+        } else {
+            self.write_class_with_fields(
+                s.export_name(),
+                ClassKind::Structure,
+                &s.fields,
+                s.bitfield_widths.as_ref().map(|x| &x[..]),
+                None,
+                s.alignment,
+                out,
+            );
+        }
+
+        for constant in &s.associated_constants {
+            self.write_constant(constant, Some(s), out);
+        }
+    }
+
+    fn write_union<F: Write>(&self, u: &Union, out: &mut SourceWriter<F>) {
+        write_cfg(&u.cfg, out);
+        write_documentation(self.config, &u.documentation, out);
+        self.write_class_with_fields(
+            u.export_name(),
+            ClassKind::Union,
+            &u.fields,
+            None,
+            None,
+            u.alignment,
+            out,
+        );
+    }
+
+    fn write_opaque<F: Write>(&self, o: &OpaqueItem, out: &mut SourceWriter<F>) {
+        write_cfg(&o.cfg, out);
+        write_documentation(self.config, &o.documentation, out);
+        self.write_class(o.export_name(), ClassKind::Structure, out);
+    }
+
+    fn write_typedef<F: Write>(&self, t: &Typedef, out: &mut SourceWriter<F>) {
+        write_cfg(&t.cfg, out);
+        write_documentation(self.config, &t.documentation, out);
+        write!(out, "{} = {}", t.export_name(), ctype(&t.aliased));
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        write_cfg(&e.cfg, out);
+        write_documentation(self.config, &e.documentation, out);
+
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        // The enum itself is an alias of its underlying integer type, and each
+        // variant is a module-level constant, just like in C.
+        write!(out, "{} = {}", tag, repr_ctype(e.repr.ty));
+        out.new_line();
+
+        let mut next_value = 0;
+        for variant in &e.variants {
+            let value = variant.discriminant.unwrap_or(next_value);
+            write_documentation(self.config, &variant.documentation, out);
+            write!(out, "{} = {}", variant.export_name, value);
+            out.new_line();
+            next_value = value + 1;
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            write!(out, "{}_Sentinel = {}", e.export_name(), next_value);
+            out.new_line();
+        }
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                out.new_line();
+                self.write_struct(body, out);
+            }
+        }
+
+        let mut fields = vec![];
+        let anonymous = if e.repr.style == ReprStyle::C {
+            let variants = format!("_{}_Variants", e.export_name());
+            let variant_fields: Vec<_> = e
+                .variants
+                .iter()
+                .filter_map(|variant| variant.body.as_ref())
+                .map(|(name, body)| {
+                    (
+                        name.clone(),
+                        path_type(body.export_name()),
+                        Documentation::none(),
+                    )
+                })
+                .collect();
+            out.new_line();
+            self.write_class_with_fields(
+                &variants,
+                ClassKind::Union,
+                &variant_fields,
+                None,
+                None,
+                None,
+                out,
+            );
+
+            fields.push(("tag".to_owned(), path_type(tag), Documentation::none()));
+            fields.push((
+                "_variants".to_owned(),
+                path_type(&variants),
+                Documentation::none(),
+            ));
+            Some("_variants")
+        } else {
+            fields.push(("tag".to_owned(), path_type(tag), Documentation::none()));
+            for (name, body) in e.variants.iter().filter_map(|v| v.body.as_ref()) {
+                fields.push((
+                    name.clone(),
+                    path_type(body.export_name()),
+                    Documentation::none(),
+                ));
+            }
+            None
+        };
+
+        out.new_line();
+        let kind = if e.repr.style == ReprStyle::C {
+            ClassKind::Structure
+        } else {
+            ClassKind::Union
+        };
+        self.write_class_with_fields(e.export_name(), kind, &fields, None, anonymous, None, out);
+    }
+
+    fn constant_name(&self, constant: &Constant, associated_to: Option<&Struct>) -> String {
+        match constant.associated_to {
+            None => constant.export_name().to_owned(),
+            Some(ref path) => {
+                let associated_name = match associated_to {
+                    Some(s) => s.export_name().to_owned(),
+                    None => {
+                        let mut name = path.name().to_owned();
+                        self.config.export.rename(&mut name);
+                        name
+                    }
+                };
+                format!("{}_{}", associated_name, constant.export_name())
+            }
+        }
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return;
+        }
+
+        write_cfg(&constant.cfg, out);
+        write_documentation(self.config, &constant.documentation, out);
+        write!(
+            out,
+            "{} = {}",
+            self.constant_name(constant, associated_to),
+            literal(self.bindings, &constant.value, is_floating(&constant.ty))
+        );
+        out.new_line();
+    }
+
+    /// Functions and globals can only be declared once the library is loaded,
+    /// so they're declared on the library object by a `load` function.
+    fn write_load<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.write("def load(path):");
+        out.push_tab();
+        out.new_line();
+        out.write("lib = ctypes.CDLL(path)");
+        out.new_line();
+        if self
+            .bindings
+            .functions
+            .iter()
+            .any(|x| is_stdcall(x.calling_convention))
+        {
+            // `ctypes.CDLL` calls its functions with the C calling convention,
+            // and `ctypes.WinDLL` with stdcall, which only Windows has.
+            out.write("windll = getattr(ctypes, \"WinDLL\", ctypes.CDLL)(path)");
+            out.new_line();
+        }
+
+        for global in &self.bindings.globals {
+            out.new_line();
+            self.write_static(global, out);
+        }
+
+        for function in &self.bindings.functions {
+            out.new_line();
+            self.write_function(function, out);
+        }
+
+        out.new_line();
+        out.write("return lib");
+        out.pop_tab();
+        out.new_line();
+    }
+
+    fn write_static<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        write_cfg(&global.cfg, out);
+        write_documentation(self.config, &global.documentation, out);
+        write!(
+            out,
+            "lib.{0} = {1}.in_dll(lib, \"{0}\")",
+            global.export_name(),
+            ctype(&global.ty)
+        );
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        write_cfg(&function.cfg, out);
+        write_documentation(self.config, &function.documentation, out);
+        let name = function.path().name();
+        let args: Vec<_> = function.args.iter().map(|(_, ty)| ctype(ty)).collect();
+        if is_stdcall(function.calling_convention) {
+            write!(out, "lib.{0} = windll.{0}", name);
+            out.new_line();
+        } else if function.calling_convention == CallingConvention::Fastcall {
+            warn!("ctypes has no fastcall calling convention, writing it as C.");
+        }
+        write!(out, "lib.{}.argtypes = [{}]", name, args.join(", "));
+        out.new_line();
+        write!(out, "lib.{}.restype = {}", name, ctype(&function.ret));
+        out.new_line();
+    }
+}
+
+fn path_type(name: &str) -> Type {
+    Type::Path(GenericPath::new(Path::new(name), vec![]))
+}
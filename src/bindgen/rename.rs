@@ -12,7 +12,9 @@ pub enum IdentifierType<'a> {
     StructMember,
     EnumVariant(&'a Enum),
     FunctionArg,
+    Function,
     Enum,
+    Type,
 }
 
 impl<'a> IdentifierType<'a> {
@@ -21,7 +23,9 @@ impl<'a> IdentifierType<'a> {
             IdentifierType::StructMember => "m",
             IdentifierType::EnumVariant(..) => "",
             IdentifierType::FunctionArg => "a",
+            IdentifierType::Function => "",
             IdentifierType::Enum => "",
+            IdentifierType::Type => "",
         }
     }
 }
@@ -81,8 +81,52 @@ const RESERVED_KEYWORDS: &[&'static str] = &[
     "while",
 ];
 
-pub fn escape(rust_identifier: &mut String) {
-    if let Ok(_) = (RESERVED_KEYWORDS).binary_search(&rust_identifier.as_ref()) {
-        rust_identifier.push('_');
+/// The identifiers that Objective-C predefines, which headers imported from it
+/// can't declare, sorted for binary search.
+pub const OBJC_RESERVED_IDENTIFIERS: &[&'static str] = &[
+    "BOOL",
+    "Class",
+    "IMP",
+    "NO",
+    "Nil",
+    "SEL",
+    "YES",
+    "id",
+    "instancetype",
+    "nil",
+];
+
+/// Macros of the standard headers and `windows.h` that are likely to collide
+/// with identifiers, sorted.
+const RESERVED_MACROS: &[&'static str] = &[
+    "EOF",
+    "NULL",
+    "assert",
+    "errno",
+    "far",
+    "interface",
+    "max",
+    "min",
+    "near",
+    "offsetof",
+    "small",
+    "stderr",
+    "stdin",
+    "stdout",
+];
+
+/// Whether `identifier` is a C or C++ keyword or one of the common macros.
+pub fn is_reserved(identifier: &str) -> bool {
+    RESERVED_KEYWORDS.binary_search(&identifier).is_ok()
+        || RESERVED_MACROS.binary_search(&identifier).is_ok()
+}
+
+pub fn is_objc_reserved(identifier: &str) -> bool {
+    OBJC_RESERVED_IDENTIFIERS.binary_search(&identifier).is_ok()
+}
+
+pub fn escape_objc(identifier: &mut String) {
+    if is_objc_reserved(identifier) {
+        identifier.push('_');
     }
 }
@@ -0,0 +1,137 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+/// A format to write the list of symbols the bindings declare in, for the
+/// linker to export exactly those from a shared library.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SymbolListFormat {
+    /// A Windows module-definition (`.def`) file.
+    Def,
+    /// A GNU ld version script.
+    VersionScript,
+    /// A macOS exported symbols list, as given to `-exported_symbols_list`.
+    ExportedSymbols,
+}
+
+impl SymbolListFormat {
+    /// Returns the format conventionally used for files with the extension of
+    /// `path`: `.def`, `.map`, `.ver` or `.lds`, and `.exp` or `.exports`.
+    pub fn from_path(path: &Path) -> Option<SymbolListFormat> {
+        match path.extension().and_then(|x| x.to_str()) {
+            Some("def") => Some(SymbolListFormat::Def),
+            Some("map") | Some("ver") | Some("lds") => Some(SymbolListFormat::VersionScript),
+            Some("exp") | Some("exports") => Some(SymbolListFormat::ExportedSymbols),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for SymbolListFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SymbolListFormat, Self::Err> {
+        match s {
+            "def" | "Def" | "DEF" => Ok(SymbolListFormat::Def),
+            "version-script" | "VersionScript" => Ok(SymbolListFormat::VersionScript),
+            "exported-symbols" | "ExportedSymbols" => Ok(SymbolListFormat::ExportedSymbols),
+            _ => Err(format!("Unrecognized SymbolListFormat: '{}'.", s)),
+        }
+    }
+}
+
+/// A symbol the bindings declare.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Symbol {
+    pub name: String,
+    /// Whether the symbol is a global rather than a function.
+    pub data: bool,
+}
+
+pub fn write_symbol_list<F: Write>(
+    symbols: &[Symbol],
+    format: SymbolListFormat,
+    mut out: F,
+) -> io::Result<()> {
+    match format {
+        SymbolListFormat::Def => {
+            writeln!(out, "EXPORTS")?;
+            for symbol in symbols {
+                if symbol.data {
+                    writeln!(out, "    {} DATA", symbol.name)?;
+                } else {
+                    writeln!(out, "    {}", symbol.name)?;
+                }
+            }
+        }
+        SymbolListFormat::VersionScript => {
+            writeln!(out, "{{")?;
+            writeln!(out, "  global:")?;
+            for symbol in symbols {
+                writeln!(out, "    {};", symbol.name)?;
+            }
+            writeln!(out, "  local:")?;
+            writeln!(out, "    *;")?;
+            writeln!(out, "}};")?;
+        }
+        SymbolListFormat::ExportedSymbols => {
+            for symbol in symbols {
+                writeln!(out, "_{}", symbol.name)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(format: SymbolListFormat) -> String {
+        let symbols = vec![
+            Symbol {
+                name: "counter".to_owned(),
+                data: true,
+            },
+            Symbol {
+                name: "root".to_owned(),
+                data: false,
+            },
+        ];
+        let mut out = Vec::new();
+        write_symbol_list(&symbols, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn writes_symbol_lists() {
+        assert_eq!(
+            write(SymbolListFormat::Def),
+            "EXPORTS\n    counter DATA\n    root\n"
+        );
+        assert_eq!(
+            write(SymbolListFormat::VersionScript),
+            "{\n  global:\n    counter;\n    root;\n  local:\n    *;\n};\n"
+        );
+        assert_eq!(
+            write(SymbolListFormat::ExportedSymbols),
+            "_counter\n_root\n"
+        );
+    }
+
+    #[test]
+    fn guesses_formats_from_extensions() {
+        let format = |path| SymbolListFormat::from_path(Path::new(path));
+        assert_eq!(format("mylib.def"), Some(SymbolListFormat::Def));
+        assert_eq!(format("mylib.map"), Some(SymbolListFormat::VersionScript));
+        assert_eq!(
+            format("mylib.exports"),
+            Some(SymbolListFormat::ExportedSymbols)
+        );
+        assert_eq!(format("mylib.txt"), None);
+    }
+}
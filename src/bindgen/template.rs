@@ -0,0 +1,166 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Renders bindings with a user-supplied Handlebars template instead of the
+//! built-in writer of their language.
+//!
+//! Templates get the JSON description of the bindings that `--lang json`
+//! writes, with the `constants`, `types`, `globals` and `functions` at the top
+//! level, along with the `language`, the `header`, `trailer`,
+//! `include_guard`, `autogen_warning` and `namespaces` of the config, and the
+//! output of the built-in writer as `default`. Values aren't escaped. The
+//! `c_decl` helper declares a described type in C, with an optional name, and
+//! the `c_function` helper declares a described function.
+
+use std::path::Path;
+
+use handlebars::{
+    no_escape, Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderError,
+};
+use serde_json::{Map, Value};
+
+use bindgen::Bindings;
+
+/// Renders `template` with the description of `bindings`.
+pub fn render(bindings: &Bindings, template: &Path, default: String) -> Result<String, String> {
+    let config = &bindings.config;
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(config.template.strict);
+    handlebars.register_escape_fn(no_escape);
+    handlebars.register_helper("c_decl", Box::new(c_decl_helper));
+    handlebars.register_helper("c_function", Box::new(c_function_helper));
+    handlebars
+        .register_template_file("bindings", template)
+        .map_err(|e| format!("Couldn't load template {:?}: {}", template, e))?;
+
+    let mut data = match bindings.abi_description() {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+    let string = |x: &Option<String>| x.clone().map_or(Value::Null, Value::String);
+    data.insert(
+        "language".to_owned(),
+        Value::String(config.language.as_str().to_owned()),
+    );
+    data.insert("header".to_owned(), string(&config.header));
+    data.insert("trailer".to_owned(), string(&config.trailer));
+    data.insert("include_guard".to_owned(), string(&config.include_guard));
+    data.insert(
+        "autogen_warning".to_owned(),
+        string(&config.autogen_warning),
+    );
+    let namespaces = config
+        .namespace
+        .iter()
+        .chain(config.namespaces.iter().flat_map(|x| x.iter()))
+        .map(|x| Value::String(x.clone()))
+        .collect();
+    data.insert("namespaces".to_owned(), Value::Array(namespaces));
+    data.insert("default".to_owned(), Value::String(default));
+
+    handlebars
+        .render("bindings", &data)
+        .map_err(|e| format!("Couldn't render template {:?}: {}", template, e))
+}
+
+fn c_decl_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let ty = h
+        .param(0)
+        .ok_or_else(|| RenderError::new("c_decl needs a type"))?
+        .value();
+    let name = h.param(1).and_then(|x| x.value().as_str()).unwrap_or("");
+    out.write(&c_decl(ty, name))?;
+    Ok(())
+}
+
+fn c_function_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let function = h
+        .param(0)
+        .ok_or_else(|| RenderError::new("c_function needs a function"))?
+        .value();
+    let name = function["name"].as_str().unwrap_or("");
+    let mut args: Vec<String> = function["args"]
+        .as_array()
+        .map_or(&[][..], |x| &x[..])
+        .iter()
+        .map(|arg| c_decl(&arg["type"], arg["name"].as_str().unwrap_or("")))
+        .collect();
+    if function["variadic"].as_bool().unwrap_or(false) {
+        args.push("...".to_owned());
+    } else if args.is_empty() {
+        args.push("void".to_owned());
+    }
+    out.write(&c_decl(
+        &function["ret"],
+        &format!("{}({})", name, args.join(", ")),
+    ))?;
+    Ok(())
+}
+
+/// Declares `declarator` with the described type in C.
+fn c_decl(ty: &Value, declarator: &str) -> String {
+    let with = |name: &str| {
+        if declarator.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{} {}", name, declarator)
+        }
+    };
+    // Array and function declarators bind tighter than pointers.
+    let grouped = || {
+        if declarator.starts_with('*') {
+            format!("({})", declarator)
+        } else {
+            declarator.to_owned()
+        }
+    };
+    match ty["kind"].as_str().unwrap_or("") {
+        "primitive" | "named" => with(ty["name"].as_str().unwrap_or("")),
+        "pointer" => {
+            let pointee = &ty["pointee"];
+            let is_const = ty["const"].as_bool().unwrap_or(false);
+            match pointee["kind"].as_str().unwrap_or("") {
+                "primitive" | "named" if is_const => {
+                    format!("const {}", c_decl(pointee, &format!("*{}", declarator)))
+                }
+                "pointer" if is_const => c_decl(pointee, &format!("const *{}", declarator)),
+                _ => c_decl(pointee, &format!("*{}", declarator)),
+            }
+        }
+        "array" => c_decl(
+            &ty["element"],
+            &format!("{}[{}]", grouped(), ty["length"].as_str().unwrap_or("")),
+        ),
+        "function" => {
+            let mut args: Vec<String> = ty["args"]
+                .as_array()
+                .map_or(&[][..], |x| &x[..])
+                .iter()
+                .map(|x| c_decl(x, ""))
+                .collect();
+            if ty["variadic"].as_bool().unwrap_or(false) {
+                args.push("...".to_owned());
+            } else if args.is_empty() {
+                args.push("void".to_owned());
+            }
+            c_decl(
+                &ty["ret"],
+                &format!("(*{})({})", declarator, args.join(", ")),
+            )
+        }
+        _ => with("void"),
+    }
+}
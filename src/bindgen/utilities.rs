@@ -4,6 +4,8 @@
 
 use syn;
 
+use bindgen::ir::CallingConvention;
+
 pub trait IterHelpers: Iterator {
     fn try_skip_map<F, T, E>(&mut self, f: F) -> Result<Vec<T>, E>
     where
@@ -163,38 +165,34 @@ impl SynItemHelpers for syn::ItemVerbatim {
 
 /// Helper function for accessing Abi information
 pub trait SynAbiHelpers {
-    fn is_c(&self) -> bool;
-    fn is_omitted(&self) -> bool;
+    /// Returns the calling convention of an `extern` ABI bindings can be
+    /// generated for, where an omitted ABI is `extern "C"`.
+    fn calling_convention(&self) -> Option<CallingConvention>;
+    /// Whether panics may unwind through the ABI, like `extern "C-unwind"`.
+    fn is_unwind(&self) -> bool;
 }
 
 impl SynAbiHelpers for Option<syn::Abi> {
-    fn is_c(&self) -> bool {
-        if let &Some(ref abi) = self {
-            if let Some(ref lit_string) = abi.name {
-                return lit_string.value() == String::from("C");
-            }
-        }
-        false
+    fn calling_convention(&self) -> Option<CallingConvention> {
+        self.as_ref().and_then(|abi| abi.calling_convention())
     }
-    fn is_omitted(&self) -> bool {
-        if let &Some(ref abi) = self {
-            abi.name.is_none()
-        } else {
-            false
-        }
+    fn is_unwind(&self) -> bool {
+        self.as_ref().map_or(false, |abi| abi.is_unwind())
     }
 }
 
 impl SynAbiHelpers for syn::Abi {
-    fn is_c(&self) -> bool {
-        if let Some(ref lit_string) = self.name {
-            lit_string.value() == String::from("C")
-        } else {
-            false
+    fn calling_convention(&self) -> Option<CallingConvention> {
+        match self.name {
+            Some(ref lit_string) => CallingConvention::from_abi(&lit_string.value()),
+            None => Some(CallingConvention::C),
         }
     }
-    fn is_omitted(&self) -> bool {
-        self.name.is_none()
+    fn is_unwind(&self) -> bool {
+        match self.name {
+            Some(ref lit_string) => lit_string.value().ends_with("-unwind"),
+            None => false,
+        }
     }
 }
 
@@ -203,6 +201,15 @@ pub trait SynAttributeHelpers {
     fn has_attr_word(&self, name: &str) -> bool;
     fn has_attr_list(&self, name: &str, args: &[&str]) -> bool;
     fn has_attr_name_value(&self, name: &str, value: &str) -> bool;
+    /// Searches for attributes named `name` of any form, like `#[must_use]`
+    /// and `#[must_use = "reason"]`.
+    fn has_attr_named(&self, name: &str) -> bool;
+    /// Returns the note of a `#[deprecated]` attribute, given as
+    /// `#[deprecated = "note"]` or `#[deprecated(note = "note")]`, which is
+    /// empty for a bare `#[deprecated]`.
+    fn deprecated_note(&self) -> Option<String>;
+    /// Returns the symbol name of an `#[export_name = "name"]` attribute.
+    fn export_name(&self) -> Option<String>;
 }
 
 impl SynAttributeHelpers for [syn::Attribute] {
@@ -251,6 +258,50 @@ impl SynAttributeHelpers for [syn::Attribute] {
         })
     }
 
+    fn has_attr_named(&self, name: &str) -> bool {
+        self.iter()
+            .filter_map(|x| x.interpret_meta())
+            .any(|attr| attr.name() == name)
+    }
+
+    fn deprecated_note(&self) -> Option<String> {
+        let attr = self
+            .iter()
+            .filter_map(|x| x.interpret_meta())
+            .find(|x| x.name() == "deprecated")?;
+        let note = match attr {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Some(lit.value()),
+            syn::Meta::List(syn::MetaList { nested, .. }) => {
+                nested.iter().find_map(|nested_meta| match *nested_meta {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        ref ident,
+                        lit: syn::Lit::Str(ref lit),
+                        ..
+                    })) if ident == "note" => Some(lit.value()),
+                    _ => None,
+                })
+            }
+            _ => None,
+        };
+        Some(note.unwrap_or_default())
+    }
+
+    fn export_name(&self) -> Option<String> {
+        self.iter()
+            .filter_map(|x| x.interpret_meta())
+            .find_map(|attr| match attr {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    ident,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) if ident == "export_name" => Some(lit.value()),
+                _ => None,
+            })
+    }
+
     fn get_comment_lines(&self) -> Vec<String> {
         let mut comment_lines = Vec::new();
 
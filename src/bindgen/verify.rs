@@ -0,0 +1,199 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Compares bindings by their declarations rather than their bytes, so that
+//! whitespace and comments don't make them differ.
+//!
+//! Sources are split into tokens, without comments, and the tokens are
+//! grouped into declarations, which end at a `;`, a `{`, a `}` that doesn't
+//! precede a name or `;`, or at the end of a preprocessor directive. Python
+//! and Cython, where indentation matters, are compared by their lines without
+//! comments and trailing whitespace instead.
+
+use bindgen::config::Language;
+
+/// Returns the first declaration of `old` that differs in `new`, and the one
+/// it differs from, if they don't have the same declarations.
+pub fn first_difference(language: Language, old: &str, new: &str) -> Option<(String, String)> {
+    let (old, new) = match language {
+        Language::Python | Language::Cython => (lines(old), lines(new)),
+        _ => {
+            let directives = language == Language::C || language == Language::Cxx;
+            (declarations(old, directives), declarations(new, directives))
+        }
+    };
+    let end = || "(end of file)".to_owned();
+    for i in 0..old.len().max(new.len()) {
+        match (old.get(i), new.get(i)) {
+            (Some(x), Some(y)) if x == y => {}
+            (x, y) => {
+                return Some((
+                    x.cloned().unwrap_or_else(end),
+                    y.cloned().unwrap_or_else(end),
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// The lines of Python or Cython source, without comments, blank lines and
+/// trailing whitespace.
+fn lines(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .map(|line| {
+            let mut quote = None;
+            let mut end = line.len();
+            for (i, c) in line.char_indices() {
+                match (quote, c) {
+                    (None, '#') => {
+                        end = i;
+                        break;
+                    }
+                    (None, '"') | (None, '\'') => quote = Some(c),
+                    (Some(q), _) if q == c => quote = None,
+                    _ => {}
+                }
+            }
+            line[..end].trim_end().to_owned()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// The declarations of brace-delimited source, as their tokens joined by
+/// spaces. Lines starting with `#` are preprocessor directives if
+/// `directives` is set.
+fn declarations(source: &str, directives: bool) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = Vec::new();
+    let mut tokens: Vec<String> = Vec::new();
+    let mut line_start = true;
+    let mut directive = false;
+    let mut i = 0;
+
+    let mut finish = |tokens: &mut Vec<String>| {
+        if !tokens.is_empty() {
+            out.push(tokens.join(" "));
+            tokens.clear();
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            if directive && (i == 0 || chars[i - 1] != '\\') {
+                directive = false;
+                finish(&mut tokens);
+            }
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() || (c == '\\' && chars.get(i + 1) == Some(&'\n')) {
+            i += 1;
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+
+        if line_start && c == '#' && directives {
+            finish(&mut tokens);
+            directive = true;
+        }
+        line_start = false;
+
+        let start = i;
+        if c == '"' || c == '\'' || c == '`' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+        } else if c.is_alphanumeric() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+        let end = i.min(chars.len());
+        tokens.push(chars[start..end].iter().collect());
+
+        if directive {
+            continue;
+        }
+        match c {
+            ';' | '{' => finish(&mut tokens),
+            '}' => match chars[i..].iter().find(|x| !x.is_whitespace()) {
+                Some(&x) if x == ';' || x.is_alphabetic() || x == '_' => {}
+                _ => finish(&mut tokens),
+            },
+            _ => {}
+        }
+    }
+    finish(&mut tokens);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_whitespace_and_comments() {
+        let old = "/* Generated with cbindgen:0.8.6 */\n\
+                   typedef struct {\n  uint8_t a;\n} Foo;\n\n\
+                   void root(Foo x); // root\n";
+        let new = "/* Generated with cbindgen:0.8.7 */\n\
+                   typedef struct\n{\n  uint8_t a;\n}\nFoo;\n\n\
+                   /* The root. */\nvoid root(Foo x);\n";
+        assert_eq!(first_difference(Language::C, old, new), None);
+    }
+
+    #[test]
+    fn reports_changed_declarations() {
+        let old = "#define FOO 1\n#define BAR 2\nvoid root(uint8_t x);\n";
+        let new = "#define FOO 1\n#define BAR 3\nvoid root(uint8_t x);\n";
+        assert_eq!(
+            first_difference(Language::C, old, new),
+            Some(("# define BAR 2".to_owned(), "# define BAR 3".to_owned()))
+        );
+
+        let new = "#define FOO 1\n#define BAR 2\n";
+        assert_eq!(
+            first_difference(Language::C, old, new),
+            Some((
+                "void root ( uint8_t x ) ;".to_owned(),
+                "(end of file)".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn keeps_python_indentation() {
+        let old = "class Foo(Structure):\n    pass\n";
+        let new = "# Foo\nclass Foo(Structure):  # struct\n\n    pass\n";
+        assert_eq!(first_difference(Language::Python, old, new), None);
+
+        let new = "class Foo(Structure):\n  pass\n";
+        assert!(first_difference(Language::Python, old, new).is_some());
+    }
+}
@@ -0,0 +1,659 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a JavaScript module describing the WebAssembly exports
+//! and imports of a crate built for `wasm32`, along with its TypeScript
+//! declarations.
+//!
+//! The signatures are lowered to WebAssembly value types following the basic
+//! C ABI of `wasm32`: integers of up to 32 bits, `usize` and pointers are
+//! `i32`, 128-bit integers are split into two `i64`s, and structs are passed
+//! through a pointer to a copy, unless they wrap a single scalar, which is
+//! passed as is. Structs and 128-bit integers are returned through a pointer
+//! the caller passes as the first parameter.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use bindgen::config::{Config, DocumentationStyle};
+use bindgen::doccomment;
+use bindgen::ir::{Cfg, Documentation, Function, Item, ItemContainer, PrimitiveType, Type};
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// A WebAssembly value type.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ValueType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl ValueType {
+    fn as_str(self) -> &'static str {
+        match self {
+            ValueType::I32 => "i32",
+            ValueType::I64 => "i64",
+            ValueType::F32 => "f32",
+            ValueType::F64 => "f64",
+        }
+    }
+
+    /// The TypeScript type of values of this type, `i64` being a `BigInt`.
+    fn ts_type(self) -> &'static str {
+        match self {
+            ValueType::I64 => "bigint",
+            _ => "number",
+        }
+    }
+}
+
+/// How a type is passed to and returned from functions.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Lowered {
+    Void,
+    Value(ValueType),
+    /// A 128-bit integer, passed as its low and high `i64`s.
+    Int128,
+    /// A struct, union or tagged enum, passed through a pointer.
+    Aggregate,
+}
+
+/// A function signature, lowered to WebAssembly value types.
+struct Signature {
+    /// The names and types of the parameters.
+    params: Vec<(String, ValueType)>,
+    result: Option<ValueType>,
+    /// Whether a struct is returned through a pointer passed as the first
+    /// parameter.
+    sret: bool,
+    /// The parameter indices of the pointer and length of each slice, along
+    /// with the typed array viewing it.
+    slices: Vec<(usize, usize, &'static str)>,
+}
+
+/// Writes the JavaScript module and TypeScript declarations describing the
+/// WebAssembly exports and imports of `bindings`.
+pub(crate) struct WasmWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    items: HashMap<&'a str, &'a ItemContainer>,
+}
+
+impl<'a> WasmWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        let mut items = HashMap::new();
+        for item in &bindings.items {
+            items.insert(item.deref().export_name(), item);
+            if let ItemContainer::Enum(ref e) = *item {
+                if let Some(ref tag) = e.tag {
+                    items.insert(tag.as_str(), item);
+                }
+            }
+        }
+        WasmWriter {
+            bindings,
+            config: &bindings.config,
+            items,
+        }
+    }
+
+    fn primitive_type(primitive: &PrimitiveType) -> Lowered {
+        match *primitive {
+            PrimitiveType::Void => Lowered::Void,
+            PrimitiveType::LongLong
+            | PrimitiveType::ULongLong
+            | PrimitiveType::UInt64
+            | PrimitiveType::Int64 => Lowered::Value(ValueType::I64),
+            PrimitiveType::Int128 | PrimitiveType::UInt128 => Lowered::Int128,
+            PrimitiveType::Float => Lowered::Value(ValueType::F32),
+            PrimitiveType::Double => Lowered::Value(ValueType::F64),
+            // `long`, `size_t` and `va_list` are all 32 bits wide on wasm32.
+            _ => Lowered::Value(ValueType::I32),
+        }
+    }
+
+    /// Returns how `ty` is passed.
+    fn lower(&self, ty: &Type) -> Lowered {
+        match *ty {
+            Type::Primitive(ref primitive) => Self::primitive_type(primitive),
+            Type::ConstPtr(..)
+            | Type::Ptr(..)
+            | Type::Ref(..)
+            | Type::MutRef(..)
+            | Type::Array(..)
+            | Type::FuncPtr(..) => Lowered::Value(ValueType::I32),
+            Type::Path(ref generic) => match self.items.get(generic.export_name()) {
+                // The tagged enums themselves are structs or unions.
+                Some(&&ItemContainer::Enum(ref e))
+                    if e.tag.is_some() && e.export_name() == generic.export_name() =>
+                {
+                    Lowered::Aggregate
+                }
+                // Every repr of enums, and their tags, is 32 bits wide at most.
+                Some(&&ItemContainer::Enum(..)) => Lowered::Value(ValueType::I32),
+                // A struct wrapping a single scalar is passed as the scalar.
+                Some(&&ItemContainer::Struct(ref s)) if s.fields.len() == 1 => {
+                    match self.lower(&s.fields[0].1) {
+                        Lowered::Void | Lowered::Aggregate => Lowered::Aggregate,
+                        scalar => scalar,
+                    }
+                }
+                Some(&&ItemContainer::Union(ref u)) if u.fields.len() == 1 => {
+                    match self.lower(&u.fields[0].1) {
+                        Lowered::Void | Lowered::Aggregate => Lowered::Aggregate,
+                        scalar => scalar,
+                    }
+                }
+                Some(&&ItemContainer::Typedef(ref t)) => self.lower(&t.aliased),
+                _ => Lowered::Aggregate,
+            },
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    /// Returns the typed array viewing the elements `ty` points to, if it's a
+    /// pointer to numbers.
+    fn typed_array(&self, ty: &Type) -> Option<&'static str> {
+        let pointee = match *ty {
+            Type::ConstPtr(ref pointee, _) | Type::Ptr(ref pointee, _) => pointee,
+            _ => return None,
+        };
+        let primitive = match **pointee {
+            Type::Primitive(ref primitive) => primitive,
+            _ => return None,
+        };
+        Some(match *primitive {
+            PrimitiveType::Void
+            | PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::UChar
+            | PrimitiveType::UInt8 => "Uint8Array",
+            PrimitiveType::SChar | PrimitiveType::Int8 => "Int8Array",
+            PrimitiveType::UShort | PrimitiveType::UInt16 => "Uint16Array",
+            PrimitiveType::Short | PrimitiveType::Int16 => "Int16Array",
+            PrimitiveType::WChar
+            | PrimitiveType::UInt
+            | PrimitiveType::ULong
+            | PrimitiveType::UInt32
+            | PrimitiveType::USize
+            | PrimitiveType::SizeT => "Uint32Array",
+            PrimitiveType::Int
+            | PrimitiveType::Long
+            | PrimitiveType::Int32
+            | PrimitiveType::ISize
+            | PrimitiveType::SSizeT
+            | PrimitiveType::PtrDiffT => "Int32Array",
+            PrimitiveType::ULongLong | PrimitiveType::UInt64 => "BigUint64Array",
+            PrimitiveType::LongLong | PrimitiveType::Int64 => "BigInt64Array",
+            PrimitiveType::Float => "Float32Array",
+            PrimitiveType::Double => "Float64Array",
+            // There are no typed arrays of 128-bit integers.
+            PrimitiveType::Int128 | PrimitiveType::UInt128 | PrimitiveType::VaList => return None,
+        })
+    }
+
+    fn signature(&self, function: &Function) -> Signature {
+        let mut params = vec![];
+        let (result, sret) = match self.lower(&function.ret) {
+            Lowered::Void => (None, false),
+            Lowered::Value(value) => (Some(value), false),
+            Lowered::Int128 | Lowered::Aggregate => {
+                params.push(("ret".to_owned(), ValueType::I32));
+                (None, true)
+            }
+        };
+
+        let mut slices = vec![];
+        for (i, &(ref name, ref ty)) in function.args.iter().enumerate() {
+            let value = match self.lower(ty) {
+                Lowered::Value(value) => value,
+                Lowered::Int128 => {
+                    params.push((format!("{}_lo", name), ValueType::I64));
+                    params.push((format!("{}_hi", name), ValueType::I64));
+                    continue;
+                }
+                Lowered::Void | Lowered::Aggregate => ValueType::I32,
+            };
+            // A pointer followed by a `usize` is taken to be a slice.
+            let is_len = match function.args.get(i + 1) {
+                Some(&(_, Type::Primitive(PrimitiveType::USize)))
+                | Some(&(_, Type::Primitive(PrimitiveType::SizeT))) => true,
+                _ => false,
+            };
+            if let Some(array) = self.typed_array(ty).filter(|_| is_len) {
+                slices.push((params.len(), params.len() + 1, array));
+            }
+            params.push((name.clone(), value));
+        }
+        // Variadic arguments are passed through a pointer to a buffer.
+        if function.variadic {
+            params.push(("varargs".to_owned(), ValueType::I32));
+        }
+
+        Signature {
+            params,
+            result,
+            sret,
+            slices,
+        }
+    }
+
+    /// Returns the functions of the bindings, only keeping the first
+    /// definition of the functions with several `#[cfg]`ed ones.
+    fn functions(&self, extern_decl: bool) -> Vec<&'a Function> {
+        let mut declared = HashSet::new();
+        self.bindings
+            .functions
+            .iter()
+            .filter(|f| f.extern_decl == extern_decl)
+            .filter(|f| {
+                if declared.insert(f.path().name()) {
+                    return true;
+                }
+                warn!(
+                    "Skip {} - (JavaScript can only declare one of its definitions).",
+                    f.path().name()
+                );
+                false
+            })
+            .collect()
+    }
+
+    fn globals(&self) -> Vec<&'a str> {
+        let mut declared = HashSet::new();
+        self.bindings
+            .globals
+            .iter()
+            .map(|x| x.export_name())
+            .filter(|x| declared.insert(*x))
+            .collect()
+    }
+
+    fn write_preamble<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_trailer<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_signature<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        let signature = self.signature(function);
+        let params: Vec<_> = signature
+            .params
+            .iter()
+            .map(|&(_, value)| format!("\"{}\"", value.as_str()))
+            .collect();
+        write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        write!(out, "{}: {{", function.path().name());
+        out.push_tab();
+        out.new_line();
+        write!(out, "params: [{}],", params.join(", "));
+        out.new_line();
+        match signature.result {
+            Some(value) => write!(out, "results: [\"{}\"],", value.as_str()),
+            None => out.write("results: [],"),
+        }
+        if !signature.slices.is_empty() {
+            let slices: Vec<_> = signature
+                .slices
+                .iter()
+                .map(|&(ptr, len, array)| {
+                    format!("{{ ptr: {}, len: {}, type: {} }}", ptr, len, array)
+                })
+                .collect();
+            out.new_line();
+            write!(out, "slices: [{}],", slices.join(", "));
+        }
+        if signature.sret {
+            out.new_line();
+            out.write("sret: true,");
+        }
+        if function.variadic {
+            out.new_line();
+            out.write("variadic: true,");
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("},");
+    }
+
+    /// Writes the JavaScript module.
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_preamble(out);
+
+        out.new_line_if_not_start();
+        out.write("/** The signatures of the functions the module exports. */");
+        out.new_line();
+        let exports = self.functions(false);
+        if exports.is_empty() {
+            out.write("export const functions = {};");
+        } else {
+            out.write("export const functions = {");
+            out.push_tab();
+            for function in exports {
+                out.new_line();
+                self.write_signature(function, out);
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("};");
+        }
+        out.new_line();
+
+        out.new_line();
+        out.write("/** The signatures of the functions the module imports. */");
+        out.new_line();
+        let imports = self.functions(true);
+        if imports.is_empty() {
+            out.write("export const imports = {};");
+        } else {
+            out.write("export const imports = {");
+            out.push_tab();
+            out.new_line();
+            write!(out, "\"{}\": {{", self.config.wasm.import_module);
+            out.push_tab();
+            for function in imports {
+                out.new_line();
+                self.write_signature(function, out);
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("},");
+            out.pop_tab();
+            out.new_line();
+            out.write("};");
+        }
+        out.new_line();
+
+        out.new_line();
+        out.write("/** The statics the module exports, as globals holding their address. */");
+        out.new_line();
+        let globals: Vec<_> = self
+            .globals()
+            .iter()
+            .map(|x| format!("\"{}\"", x))
+            .collect();
+        write!(out, "export const globals = [{}];", globals.join(", "));
+        out.new_line();
+
+        out.new_line();
+        out.write("/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */");
+        out.new_line();
+        out.write("export function view(memory, type, ptr, len) {");
+        out.push_tab();
+        out.new_line();
+        out.write("return new type(memory.buffer, ptr, len);");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        out.new_line();
+        out.write("/**");
+        out.new_line();
+        out.write(" * Returns views of the slices that `args`, the arguments of a call to a");
+        out.new_line();
+        out.write(" * function with `signature`, point to in `memory`.");
+        out.new_line();
+        out.write(" */");
+        out.new_line();
+        out.write("export function slices(memory, signature, args) {");
+        out.push_tab();
+        out.new_line();
+        out.write("return (signature.slices || []).map((slice) =>");
+        out.push_tab();
+        out.new_line();
+        out.write("view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),");
+        out.pop_tab();
+        out.new_line();
+        out.write(");");
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        self.write_trailer(out);
+    }
+
+    fn write_ts_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        let signature = self.signature(function);
+        let params: Vec<_> = signature
+            .params
+            .iter()
+            .map(|&(ref name, value)| format!("{}: {}", name, value.ts_type()))
+            .collect();
+        write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        write!(
+            out,
+            "{}({}): {};",
+            function.path().name(),
+            params.join(", "),
+            signature.result.map_or("void", |x| x.ts_type())
+        );
+    }
+
+    /// Writes the TypeScript declarations of the module.
+    pub fn write_declarations<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_preamble(out);
+
+        let preamble: &[&str] = &[
+            "export type ValueType = \"i32\" | \"i64\" | \"f32\" | \"f64\";",
+            "",
+            "export type TypedArray =",
+            "  | Int8Array",
+            "  | Uint8Array",
+            "  | Int16Array",
+            "  | Uint16Array",
+            "  | Int32Array",
+            "  | Uint32Array",
+            "  | BigInt64Array",
+            "  | BigUint64Array",
+            "  | Float32Array",
+            "  | Float64Array;",
+            "",
+            "export interface TypedArrayConstructor<T extends TypedArray = TypedArray> {",
+            "  new (buffer: ArrayBufferLike, byteOffset: number, length: number): T;",
+            "}",
+            "",
+            "/** The parameters holding the pointer and length of a slice. */",
+            "export interface Slice {",
+            "  readonly ptr: number;",
+            "  readonly len: number;",
+            "  readonly type: TypedArrayConstructor;",
+            "}",
+            "",
+            "export interface Signature {",
+            "  readonly params: readonly ValueType[];",
+            "  readonly results: readonly ValueType[];",
+            "  readonly slices?: readonly Slice[];",
+            "  /** Whether the result is written through a pointer passed first. */",
+            "  readonly sret?: boolean;",
+            "  /** Whether the variadic arguments are passed through a pointer last. */",
+            "  readonly variadic?: boolean;",
+            "}",
+        ];
+        out.new_line_if_not_start();
+        for line in preamble {
+            if !line.is_empty() {
+                out.write(line);
+            }
+            out.new_line();
+        }
+
+        let exports = self.functions(false);
+        let imports = self.functions(true);
+
+        out.new_line();
+        out.write("export declare const functions: {");
+        out.push_tab();
+        for function in &exports {
+            out.new_line();
+            write_cfg(&function.cfg, out);
+            write!(out, "readonly {}: Signature;", function.path().name());
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("};");
+        out.new_line();
+
+        out.new_line();
+        out.write("export declare const imports: {");
+        out.push_tab();
+        if !imports.is_empty() {
+            out.new_line();
+            write!(out, "readonly \"{}\": {{", self.config.wasm.import_module);
+            out.push_tab();
+            for function in &imports {
+                out.new_line();
+                write_cfg(&function.cfg, out);
+                write!(out, "readonly {}: Signature;", function.path().name());
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("};");
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("};");
+        out.new_line();
+
+        out.new_line();
+        out.write("export declare const globals: readonly string[];");
+        out.new_line();
+
+        out.new_line();
+        out.write("/** The exports of an instance of the module. */");
+        out.new_line();
+        out.write("export interface Exports {");
+        out.push_tab();
+        out.new_line();
+        out.write("readonly memory: WebAssembly.Memory;");
+        for global in self.globals() {
+            out.new_line();
+            write!(out, "readonly {}: WebAssembly.Global;", global);
+        }
+        for function in &exports {
+            out.new_line();
+            self.write_ts_function(function, out);
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        out.new_line();
+        out.write("/** The imports an instance of the module needs. */");
+        out.new_line();
+        out.write("export interface Imports {");
+        out.push_tab();
+        if !imports.is_empty() {
+            out.new_line();
+            write!(out, "readonly \"{}\": {{", self.config.wasm.import_module);
+            out.push_tab();
+            for function in &imports {
+                out.new_line();
+                self.write_ts_function(function, out);
+            }
+            out.pop_tab();
+            out.new_line();
+            out.write("};");
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+
+        out.new_line();
+        out.write("export declare function view<T extends TypedArray>(");
+        out.push_tab();
+        out.new_line();
+        out.write("memory: WebAssembly.Memory,");
+        out.new_line();
+        out.write("type: TypedArrayConstructor<T>,");
+        out.new_line();
+        out.write("ptr: number,");
+        out.new_line();
+        out.write("len: number,");
+        out.pop_tab();
+        out.new_line();
+        out.write("): T;");
+        out.new_line();
+
+        out.new_line();
+        out.write("export declare function slices(");
+        out.push_tab();
+        out.new_line();
+        out.write("memory: WebAssembly.Memory,");
+        out.new_line();
+        out.write("signature: Signature,");
+        out.new_line();
+        out.write("args: readonly (number | bigint)[],");
+        out.pop_tab();
+        out.new_line();
+        out.write("): TypedArray[];");
+        out.new_line();
+
+        self.write_trailer(out);
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        out.write("/**");
+        out.new_line();
+        for line in doccomment::rewrite(&documentation.doc_comment, DocumentationStyle::Javadoc) {
+            out.write(" *");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+        out.write(" */");
+        out.new_line();
+    }
+}
+
+/// JavaScript has no preprocessor, so conditional items are always written
+/// and their condition is only recorded in a comment.
+fn write_cfg<F: Write>(cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+    if let Some(ref cfg) = *cfg {
+        write!(out, "// cfg({})", cfg);
+        out.new_line();
+    }
+}
@@ -0,0 +1,757 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Writes bindings as a Zig file of `extern` declarations.
+//!
+//! Structs and unions are written as `extern struct` and `extern union`, which
+//! have the layout of C, opaque items as `opaque {}` and enums with an explicit
+//! tag type. Zig has no anonymous fields, so the union of the variants of a
+//! `#[repr(C)]` tagged enum is named `body`. Nullable raw pointers are written
+//! as C pointers, like `zig translate-c` does, which can point to arrays, and
+//! references as single-item pointers.
+
+use std::collections::HashSet;
+use std::io::Write;
+
+use bindgen::config::Config;
+use bindgen::ir::{
+    ArrayLength, CallingConvention, Cfg, Constant, Documentation, Enum, Function, Item,
+    ItemContainer, Literal, OpaqueItem, PrimitiveType, ReprAlign, ReprStyle, ReprType, Static,
+    Struct, Type, Typedef, Union,
+};
+use bindgen::python::is_exported;
+use bindgen::writer::SourceWriter;
+use bindgen::Bindings;
+
+/// Taken from `https://ziglang.org/documentation/master/#Keyword-Reference`,
+/// along with the primitive types and values, which can't be shadowed either.
+const ZIG_KEYWORDS: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyframe",
+    "anyopaque",
+    "anytype",
+    "asm",
+    "async",
+    "await",
+    "bool",
+    "break",
+    "callconv",
+    "catch",
+    "comptime",
+    "comptime_float",
+    "comptime_int",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "f16",
+    "f32",
+    "f64",
+    "f80",
+    "f128",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "isize",
+    "linksection",
+    "noalias",
+    "noinline",
+    "noreturn",
+    "nosuspend",
+    "null",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "true",
+    "try",
+    "type",
+    "undefined",
+    "union",
+    "unreachable",
+    "usingnamespace",
+    "usize",
+    "var",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Whether `name` is the name of an integer type, like `u8` or `i32`.
+fn is_integer_type(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some('u') | Some('i') => !name[1..].is_empty() && chars.all(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+fn escape(name: &str) -> String {
+    if ZIG_KEYWORDS.contains(&name) || name.starts_with("c_") || is_integer_type(name) {
+        format!("@\"{}\"", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+fn primitive_type(primitive: &PrimitiveType) -> &'static str {
+    match *primitive {
+        PrimitiveType::Void => "void",
+        PrimitiveType::Bool => "bool",
+        PrimitiveType::Char => "c_char",
+        // A Rust `char`.
+        PrimitiveType::WChar => "u32",
+        PrimitiveType::SChar => "i8",
+        PrimitiveType::UChar => "u8",
+        PrimitiveType::Short => "c_short",
+        PrimitiveType::Int => "c_int",
+        PrimitiveType::Long => "c_long",
+        PrimitiveType::LongLong => "c_longlong",
+        PrimitiveType::UShort => "c_ushort",
+        PrimitiveType::UInt => "c_uint",
+        PrimitiveType::ULong => "c_ulong",
+        PrimitiveType::ULongLong => "c_ulonglong",
+        PrimitiveType::USize => "usize",
+        PrimitiveType::UInt8 => "u8",
+        PrimitiveType::UInt16 => "u16",
+        PrimitiveType::UInt32 => "u32",
+        PrimitiveType::UInt64 => "u64",
+        PrimitiveType::ISize => "isize",
+        PrimitiveType::Int8 => "i8",
+        PrimitiveType::Int16 => "i16",
+        PrimitiveType::Int32 => "i32",
+        PrimitiveType::Int64 => "i64",
+        PrimitiveType::Int128 => "i128",
+        PrimitiveType::UInt128 => "u128",
+        PrimitiveType::Float => "f32",
+        PrimitiveType::Double => "f64",
+        PrimitiveType::SizeT => "usize",
+        PrimitiveType::SSizeT => "isize",
+        PrimitiveType::PtrDiffT => "isize",
+        PrimitiveType::VaList => "std.builtin.VaList",
+    }
+}
+
+/// Returns the Zig calling convention of `calling_convention`.
+fn callconv(calling_convention: CallingConvention) -> &'static str {
+    match calling_convention {
+        CallingConvention::C => ".C",
+        CallingConvention::Stdcall => ".Stdcall",
+        CallingConvention::Fastcall => ".Fastcall",
+        CallingConvention::System => "std.os.windows.WINAPI",
+    }
+}
+
+fn repr_type(repr: Option<ReprType>) -> &'static str {
+    match repr {
+        None => "c_int",
+        Some(ReprType::U8) => "u8",
+        Some(ReprType::U16) => "u16",
+        Some(ReprType::U32) => "u32",
+        Some(ReprType::USize) => "usize",
+        Some(ReprType::I8) => "i8",
+        Some(ReprType::I16) => "i16",
+        Some(ReprType::I32) => "i32",
+        Some(ReprType::ISize) => "isize",
+    }
+}
+
+fn is_signed(ty: &Type) -> bool {
+    match *ty {
+        Type::Primitive(PrimitiveType::SChar)
+        | Type::Primitive(PrimitiveType::Short)
+        | Type::Primitive(PrimitiveType::Int)
+        | Type::Primitive(PrimitiveType::Long)
+        | Type::Primitive(PrimitiveType::LongLong)
+        | Type::Primitive(PrimitiveType::ISize)
+        | Type::Primitive(PrimitiveType::Int8)
+        | Type::Primitive(PrimitiveType::Int16)
+        | Type::Primitive(PrimitiveType::Int32)
+        | Type::Primitive(PrimitiveType::Int64)
+        | Type::Primitive(PrimitiveType::Int128)
+        | Type::Primitive(PrimitiveType::SSizeT)
+        | Type::Primitive(PrimitiveType::PtrDiffT) => true,
+        _ => false,
+    }
+}
+
+fn is_floating(ty: &Type) -> bool {
+    match *ty {
+        Type::Primitive(PrimitiveType::Float) | Type::Primitive(PrimitiveType::Double) => true,
+        _ => false,
+    }
+}
+
+/// Converts a literal in C syntax, as stored in the IR, to Zig.
+fn literal(bindings: &Bindings, value: &Literal, floating: bool) -> String {
+    match *value {
+        Literal::Expr(ref v) => expr_literal(v),
+        Literal::Path(ref name) => name.clone(),
+        Literal::Cast { .. } => unreachable!("Casts are evaluated when generating the bindings."),
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => {
+            let left = literal(bindings, left, floating);
+            let right = literal(bindings, right, floating);
+            // Integer division has to say how it rounds.
+            match op {
+                "/" if !floating => format!("@divTrunc({}, {})", left, right),
+                "%" if !floating => format!("@rem({}, {})", left, right),
+                _ => format!("({} {} {})", left, op, right),
+            }
+        }
+        Literal::Struct {
+            ref path,
+            ref export_name,
+            ref fields,
+        } => {
+            if bindings.struct_is_transparent(path) {
+                return literal(bindings, &fields[0].1, floating);
+            }
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|(key, lit)| format!(".{} = {}", escape(key), literal(bindings, lit, false)))
+                .collect();
+            format!("{}{{ {} }}", export_name, fields.join(", "))
+        }
+    }
+}
+
+fn expr_literal(v: &str) -> String {
+    if v.starts_with("u8\"") && v.ends_with('"') {
+        let mut result = String::from("\"");
+        for c in v[3..v.len() - 1].chars() {
+            match c {
+                '"' | '\\' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                _ if (c as u32) < 0x20 => result.push_str(&format!("\\x{:02x}", c as u32)),
+                _ => result.push(c),
+            }
+        }
+        result.push('"');
+        return result;
+    }
+
+    if v.starts_with("L'\\u") && v.ends_with('\'') {
+        return format!("0x{}", &v[4..v.len() - 1]);
+    }
+
+    // Character literals are written by `char::escape_default`, which has
+    // the syntax of Zig.
+    v.to_owned()
+}
+
+/// Writes a Zig file with `extern` declarations for `bindings`.
+pub(crate) struct ZigWriter<'a> {
+    bindings: &'a Bindings,
+    config: &'a Config,
+    /// The opaque items, which can only be pointed to by single-item
+    /// pointers.
+    opaque_items: HashSet<&'a str>,
+    /// The names declared at the top level, which arguments can't shadow.
+    declarations: HashSet<String>,
+}
+
+impl<'a> ZigWriter<'a> {
+    pub fn new(bindings: &'a Bindings) -> Self {
+        let opaque_items = bindings
+            .items
+            .iter()
+            .filter_map(|x| match *x {
+                ItemContainer::OpaqueItem(ref x) => Some(x.export_name()),
+                _ => None,
+            })
+            .collect();
+
+        let mut declarations: HashSet<String> = bindings
+            .items
+            .iter()
+            .map(|x| x.deref().export_name().to_owned())
+            .collect();
+        declarations.extend(
+            bindings
+                .constants
+                .iter()
+                .map(|x| x.export_name().to_owned()),
+        );
+        declarations.extend(bindings.globals.iter().map(|x| x.export_name().to_owned()));
+        declarations.extend(
+            bindings
+                .functions
+                .iter()
+                .map(|x| x.path().name().to_owned()),
+        );
+        declarations.insert("std".to_owned());
+
+        ZigWriter {
+            bindings,
+            config: &bindings.config,
+            opaque_items,
+            declarations,
+        }
+    }
+
+    /// Returns the Zig type of `ty`.
+    fn zig_type(&self, ty: &Type) -> String {
+        let pointer = |pointee: &Type, nullable: bool, constness: &str| match *pointee {
+            Type::Primitive(PrimitiveType::Void) => {
+                format!("{}*{}anyopaque", if nullable { "?" } else { "" }, constness)
+            }
+            Type::Path(ref generic) if self.opaque_items.contains(generic.export_name()) => {
+                format!(
+                    "{}*{}{}",
+                    if nullable { "?" } else { "" },
+                    constness,
+                    generic.export_name()
+                )
+            }
+            // A function pointer is already a pointer.
+            Type::FuncPtr(..) => self.zig_type(pointee),
+            _ if nullable => format!("[*c]{}{}", constness, self.zig_type(pointee)),
+            _ => format!("*{}{}", constness, self.zig_type(pointee)),
+        };
+
+        match *ty {
+            Type::ConstPtr(ref pointee, nullable) => pointer(pointee, nullable, "const "),
+            Type::Ptr(ref pointee, nullable) => pointer(pointee, nullable, ""),
+            Type::Ref(ref pointee) => pointer(pointee, false, "const "),
+            Type::MutRef(ref pointee) => pointer(pointee, false, ""),
+            Type::Path(ref generic) => generic.export_name().to_owned(),
+            Type::Primitive(ref primitive) => primitive_type(primitive).to_owned(),
+            Type::Array(ref ty, ref len) => match *len {
+                // Constants may be signed, but lengths are `usize`.
+                ArrayLength::Name(ref name) => format!("[@intCast({})]{}", name, self.zig_type(ty)),
+                _ => format!("[{}]{}", len.as_str(), self.zig_type(ty)),
+            },
+            Type::FuncPtr(ref ret, ref args, nullable, calling_convention, variadic) => {
+                let mut args: Vec<_> = args.iter().map(|(_, ty)| self.zig_type(ty)).collect();
+                if variadic {
+                    args.push("...".to_owned());
+                }
+                format!(
+                    "{}*const fn ({}) callconv({}) {}",
+                    if nullable { "?" } else { "" },
+                    args.join(", "),
+                    callconv(calling_convention),
+                    self.zig_type(ret)
+                )
+            }
+            Type::ConstSlice(..) | Type::Slice(..) | Type::Tuple(..) | Type::Atomic(..) => {
+                unreachable!("{:?} should have been lowered to a struct", ty)
+            }
+        }
+    }
+
+    pub fn write<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers(out);
+
+        for constant in &self.bindings.constants {
+            self.write_constant(constant, None, out);
+        }
+
+        for item in &self.bindings.items {
+            if !is_exported(item) {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(x, out),
+                ItemContainer::Struct(ref x) => self.write_struct(x, out),
+                ItemContainer::Union(ref x) => self.write_union(x, out),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque(x, out),
+                ItemContainer::Typedef(ref x) => self.write_typedef(x, out),
+            }
+        }
+
+        for global in &self.bindings.globals {
+            self.write_static(global, out);
+        }
+
+        for function in &self.bindings.functions {
+            self.write_function(function, out);
+        }
+
+        if let Some(ref f) = self.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                ::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        out.new_line_if_not_start();
+        out.write("const std = @import(\"std\");");
+        out.new_line();
+    }
+
+    fn write_documentation<F: Write>(
+        &self,
+        documentation: &Documentation,
+        out: &mut SourceWriter<F>,
+    ) {
+        if documentation.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        for line in &documentation.doc_comment {
+            out.write("///");
+            if !line.is_empty() {
+                write!(out, " {}", line);
+            }
+            out.new_line();
+        }
+    }
+
+    /// Zig has no preprocessor, so conditional items are always written and
+    /// their condition is only recorded in a comment.
+    fn write_cfg<F: Write>(&self, cfg: &Option<Cfg>, out: &mut SourceWriter<F>) {
+        if let Some(ref cfg) = *cfg {
+            write!(out, "// cfg({})", cfg);
+            out.new_line();
+        }
+    }
+
+    /// Writes the fields of an `extern struct` or `extern union`, with the
+    /// alignment of `#[repr(packed)]` and `#[repr(align(N))]` structs, which
+    /// Zig only has for fields.
+    fn write_fields<F: Write>(
+        &self,
+        fields: &[(String, Type, Documentation)],
+        alignment: Option<ReprAlign>,
+        out: &mut SourceWriter<F>,
+    ) {
+        for (i, (name, ty, documentation)) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_documentation(documentation, out);
+            let ty = self.zig_type(ty);
+            write!(out, "{}: {}", escape(name), ty);
+            match alignment {
+                Some(ReprAlign::Packed(1)) => out.write(" align(1)"),
+                Some(ReprAlign::Packed(n)) => write!(out, " align(@min({}, @alignOf({})))", n, ty),
+                // The alignment of the struct is the one of its most aligned
+                // field.
+                Some(ReprAlign::Align(n)) if i == 0 => {
+                    write!(out, " align(@max({}, @alignOf({})))", n, ty)
+                }
+                _ => {}
+            }
+            out.write(",");
+        }
+    }
+
+    /// Writes the fields of a struct of bitfields as a `packed struct` of the
+    /// integer type their widths add up to, which has the layout of C
+    /// bitfields on little-endian targets. Other widths would need padding,
+    /// so those structs are opaque.
+    fn write_bitfields<F: Write>(&self, s: &Struct, widths: &[u32], out: &mut SourceWriter<F>) {
+        let bits: u32 = widths.iter().sum();
+        if ![8, 16, 32, 64].contains(&bits) {
+            warn!(
+                "Writing {} as opaque, since its bitfields take {} bits.",
+                s.export_name(),
+                bits
+            );
+            write!(out, "pub const {} = opaque {{}};", s.export_name());
+            return;
+        }
+        write!(
+            out,
+            "pub const {} = packed struct(u{})",
+            s.export_name(),
+            bits
+        );
+        out.open_brace();
+        for (i, ((name, ty, documentation), width)) in s.fields.iter().zip(widths).enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_documentation(documentation, out);
+            let ty = match *ty {
+                Type::Primitive(PrimitiveType::Bool) if *width == 1 => "bool".to_owned(),
+                _ if is_signed(ty) => format!("i{}", width),
+                _ => format!("u{}", width),
+            };
+            write!(out, "{}: {},", escape(name), ty);
+        }
+        out.close_brace(true);
+    }
+
+    fn write_struct<F: Write>(&self, s: &Struct, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&s.cfg, out);
+        self.write_documentation(&s.documentation, out);
+
+        if s.is_transparent {
+            write!(
+                out,
+                "pub const {} = {};",
+                s.export_name(),
+                self.zig_type(&s.fields[0].1)
+            );
+        } else if let Some(ref widths) = s.bitfield_widths {
+            self.write_bitfields(s, widths, out);
+        } else {
+            write!(out, "pub const {} = extern struct", s.export_name());
+            out.open_brace();
+            self.write_fields(&s.fields, s.alignment, out);
+            out.close_brace(true);
+        }
+        out.new_line();
+
+        for constant in &s.associated_constants {
+            self.write_constant(constant, Some(s), out);
+        }
+    }
+
+    fn write_union<F: Write>(&self, u: &Union, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&u.cfg, out);
+        self.write_documentation(&u.documentation, out);
+        write!(out, "pub const {} = extern union", u.export_name());
+        out.open_brace();
+        self.write_fields(&u.fields, u.alignment, out);
+        out.close_brace(true);
+        out.new_line();
+    }
+
+    fn write_opaque<F: Write>(&self, o: &OpaqueItem, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&o.cfg, out);
+        self.write_documentation(&o.documentation, out);
+        write!(out, "pub const {} = opaque {{}};", o.export_name());
+        out.new_line();
+    }
+
+    fn write_typedef<F: Write>(&self, t: &Typedef, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&t.cfg, out);
+        self.write_documentation(&t.documentation, out);
+        write!(
+            out,
+            "pub const {} = {};",
+            t.export_name(),
+            self.zig_type(&t.aliased)
+        );
+        out.new_line();
+    }
+
+    fn write_enum<F: Write>(&self, e: &Enum, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&e.cfg, out);
+        self.write_documentation(&e.documentation, out);
+
+        let tag = match e.tag {
+            Some(ref tag) => tag.as_str(),
+            None => e.export_name(),
+        };
+
+        write!(out, "pub const {} = enum({})", tag, repr_type(e.repr.ty));
+        out.open_brace();
+        let format = self.config.enumeration.discriminant_format(&e.annotations);
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_documentation(&variant.documentation, out);
+            write!(out, "{}", escape(&variant.export_name));
+            if let Some(discriminant) = variant.discriminant {
+                write!(out, " = {}", format.format(discriminant));
+            }
+            out.write(",");
+        }
+        if self.config.enumeration.add_sentinel(&e.annotations) {
+            out.new_line();
+            out.write("Sentinel,");
+        }
+        out.close_brace(true);
+        out.new_line();
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let Some((_, ref body)) = variant.body {
+                self.write_struct(body, out);
+            }
+        }
+
+        // Like in C, the variants are in a union after the tag, or the tag is
+        // the first field of every variant.
+        out.new_line();
+        if e.repr.style == ReprStyle::C {
+            write!(out, "pub const {} = extern struct", e.export_name());
+            out.open_brace();
+            write!(out, "tag: {},", tag);
+            out.new_line();
+            out.write("body: extern union");
+            out.open_brace();
+        } else {
+            write!(out, "pub const {} = extern union", e.export_name());
+            out.open_brace();
+            write!(out, "tag: {},", tag);
+            out.new_line();
+        }
+        let variants = e
+            .variants
+            .iter()
+            .filter_map(|variant| variant.body.as_ref());
+        for (i, (name, body)) in variants.enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "{}: {},", escape(name), body.export_name());
+        }
+        if e.repr.style == ReprStyle::C {
+            out.close_brace(false);
+            out.write(",");
+        }
+        out.close_brace(true);
+        out.new_line();
+    }
+
+    fn write_constant<F: Write>(
+        &self,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if let Some(s) = associated_to {
+            if s.is_generic() {
+                return;
+            }
+        }
+
+        if !constant.value.is_valid(self.bindings) {
+            return;
+        }
+
+        let name = constant
+            .global_name(self.config, associated_to)
+            .into_owned();
+
+        // String literals only coerce to many-item pointers.
+        let ty = if constant.value.is_string() {
+            "[*:0]const u8".to_owned()
+        } else {
+            self.zig_type(&constant.ty)
+        };
+        let value = match constant.value {
+            Literal::Expr(ref v) if v == "NAN" => format!("std.math.nan({})", ty),
+            Literal::Expr(ref v) if v == "INFINITY" => format!("std.math.inf({})", ty),
+            Literal::Expr(ref v) if v == "-INFINITY" => format!("-std.math.inf({})", ty),
+            _ => literal(self.bindings, &constant.value, is_floating(&constant.ty)),
+        };
+
+        out.new_line();
+        self.write_cfg(&constant.cfg, out);
+        self.write_documentation(&constant.documentation, out);
+        write!(out, "pub const {}: {} = {};", name, ty, value);
+        out.new_line();
+    }
+
+    fn write_static<F: Write>(&self, global: &Static, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&global.cfg, out);
+        self.write_documentation(&global.documentation, out);
+        write!(
+            out,
+            "pub extern {} {}: {};",
+            if global.mutable { "var" } else { "const" },
+            global.export_name(),
+            self.zig_type(&global.ty)
+        );
+        out.new_line();
+    }
+
+    fn write_function<F: Write>(&self, function: &Function, out: &mut SourceWriter<F>) {
+        out.new_line();
+        self.write_cfg(&function.cfg, out);
+        self.write_documentation(&function.documentation, out);
+        let mut args: Vec<_> = function
+            .args
+            .iter()
+            .map(|(name, ty)| {
+                // Parameters can't shadow declarations.
+                let name = if self.declarations.contains(name) {
+                    format!("{}_", name)
+                } else {
+                    escape(name)
+                };
+                format!("{}: {}", name, self.zig_type(ty))
+            })
+            .collect();
+        if function.variadic {
+            args.push("...".to_owned());
+        }
+        // Extern functions are `callconv(.C)` unless given another one.
+        let callconv = if function.calling_convention.is_c() {
+            String::new()
+        } else {
+            format!(" callconv({})", callconv(function.calling_convention))
+        };
+        write!(
+            out,
+            "pub extern fn {}({}){} {};",
+            function.path().name(),
+            args.join(", "),
+            callconv,
+            if function.no_return {
+                "noreturn".to_owned()
+            } else {
+                self.zig_type(&function.ret)
+            }
+        );
+        out.new_line();
+    }
+}
@@ -4,12 +4,14 @@
 
 #[macro_use]
 extern crate log;
+extern crate handlebars;
 extern crate proc_macro2;
 #[macro_use]
 extern crate serde;
 extern crate serde_json;
 #[macro_use]
 extern crate quote;
+extern crate regex;
 #[macro_use]
 extern crate syn;
 extern crate toml;
@@ -3,10 +3,12 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::env;
+use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
 extern crate clap;
+extern crate handlebars;
 #[macro_use]
 extern crate log;
 extern crate proc_macro2;
@@ -15,16 +17,21 @@ extern crate serde;
 extern crate serde_json;
 #[macro_use]
 extern crate quote;
+extern crate regex;
 #[macro_use]
 extern crate syn;
 extern crate toml;
 
-use clap::{App, Arg, ArgMatches};
+use clap::{App, Arg, ArgMatches, SubCommand};
 
 mod bindgen;
 mod logging;
+mod watch;
 
-use bindgen::{Bindings, Builder, Cargo, Config, Error, Language, Style};
+use bindgen::{
+    Bindings, Builder, Cargo, Config, DependencyGraphFormat, Error, Language, Style,
+    SymbolListFormat,
+};
 
 fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
     // We allow specifying a language to override the config default. This is
@@ -35,6 +42,25 @@ fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
             "c++" => Language::Cxx,
             "C" => Language::C,
             "c" => Language::C,
+            "Python" => Language::Python,
+            "python" => Language::Python,
+            "Cython" => Language::Cython,
+            "cython" => Language::Cython,
+            "Java" => Language::Java,
+            "java" => Language::Java,
+            "Node" => Language::Node,
+            "node" => Language::Node,
+            "D" => Language::D,
+            "d" => Language::D,
+            "Zig" => Language::Zig,
+            "zig" => Language::Zig,
+            "Go" => Language::Go,
+            "go" => Language::Go,
+            "JSON" => Language::Json,
+            "Json" => Language::Json,
+            "json" => Language::Json,
+            "Wasm" => Language::Wasm,
+            "wasm" => Language::Wasm,
             _ => {
                 error!("Unknown language specified.");
                 return;
@@ -46,6 +72,21 @@ fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
         config.cpp_compat = true;
     }
 
+    if matches.is_present("warnings-as-errors") {
+        config.diagnostics.warnings_as_errors = true;
+    }
+
+    if let Some(format) = matches.value_of("message-format") {
+        config.diagnostics.message_format = format.parse().unwrap();
+    }
+
+    for glob in matches.values_of("only").into_iter().flatten() {
+        config.function.include.push(glob.to_owned());
+    }
+    for glob in matches.values_of("exclude").into_iter().flatten() {
+        config.function.exclude.push(glob.to_owned());
+    }
+
     if let Some(style) = matches.value_of("style") {
         config.style = match style {
             "Both" => Style::Both,
@@ -64,6 +105,52 @@ fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
     if matches.is_present("d") {
         config.parse.parse_deps = true;
     }
+
+    if let Some(features) = matches.values_of("features") {
+        let features = features
+            .flat_map(|x| x.split(|c| c == ',' || c == ' '))
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_owned());
+        config
+            .parse
+            .expand
+            .features
+            .get_or_insert_with(Vec::new)
+            .extend(features);
+        config.parse.resolve_features = true;
+    }
+    if matches.is_present("no-default-features") {
+        config.parse.expand.default_features = false;
+        config.parse.resolve_features = true;
+    }
+    if matches.is_present("all-features") {
+        config.parse.expand.all_features = true;
+        config.parse.resolve_features = true;
+    }
+
+    if let Some(targets) = matches.values_of("target") {
+        let mut targets: Vec<String> = targets.map(|x| x.to_owned()).collect();
+        if targets.len() == 1 {
+            config.target = targets.pop();
+            config.targets.clear();
+        } else {
+            config.targets = targets;
+        }
+    }
+
+    // `diff` compares the description `--lang json` writes.
+    if matches.is_present("OLD") {
+        config.language = Language::Json;
+    }
+}
+
+/// Whether the manifest in `crate_dir` is the one of a workspace without a
+/// package.
+fn is_virtual_manifest(crate_dir: &Path) -> bool {
+    fs::read_to_string(crate_dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|x| x.parse::<toml::Value>().ok())
+        .map_or(false, |x| x.get("package").is_none())
 }
 
 fn load_bindings<'a>(input: &Path, matches: &ArgMatches<'a>) -> Result<Bindings, Error> {
@@ -83,19 +170,38 @@ fn load_bindings<'a>(input: &Path, matches: &ArgMatches<'a>) -> Result<Bindings,
             .generate();
     }
 
+    // The root of a workspace without a package has its own config, and the
+    // first of its `[workspace]` members is the binding crate unless one is
+    // given.
+    let root_config = if is_virtual_manifest(input) {
+        Some(match matches.value_of("config") {
+            Some(c) => Config::from_file(c).unwrap(),
+            None => Config::from_root_or_default(input),
+        })
+    } else {
+        None
+    };
+    let binding_crate = matches.value_of("crate").map(|x| x.to_owned()).or_else(|| {
+        root_config
+            .as_ref()
+            .and_then(|x| x.workspace.members.first())
+            .map(|x| x.name.clone())
+    });
+
     // We have to load a whole crate, so we use cargo to gather metadata
     let lib = Cargo::load(
         input,
         matches.value_of("lockfile"),
-        matches.value_of("crate"),
+        binding_crate.as_ref().map(|x| x.as_str()),
         true,
         matches.is_present("clean"),
     )?;
 
     // Load any config specified or search in the binding crate directory
-    let mut config = match matches.value_of("config") {
-        Some(c) => Config::from_file(c).unwrap(),
-        None => {
+    let mut config = match (root_config, matches.value_of("config")) {
+        (Some(config), _) => config,
+        (None, Some(c)) => Config::from_file(c).unwrap(),
+        (None, None) => {
             let binding_crate_dir = lib.find_crate_dir(&lib.binding_crate_ref());
 
             if let Some(binding_crate_dir) = binding_crate_dir {
@@ -128,7 +234,13 @@ fn main() {
         .arg(
             Arg::with_name("verify")
                 .long("verify")
-                .help("Generate bindings and compare it to the existing bindings file and error if they are different"),
+                .help("Generate bindings and compare their declarations to the existing bindings file and error if they are different"),
+        )
+        .arg(
+            Arg::with_name("verify-strict")
+                .long("verify-strict")
+                .conflicts_with("verify")
+                .help("Like --verify, but error if the bytes of the files are different, like with whitespace or comments"),
         )
         .arg(
             Arg::with_name("config")
@@ -143,13 +255,27 @@ fn main() {
                 .long("lang")
                 .value_name("LANGUAGE")
                 .help("Specify the language to output bindings in")
-                .possible_values(&["c++", "C++", "c", "C"]),
+                .possible_values(&["c++", "C++", "c", "C", "python", "Python", "cython", "Cython", "java", "Java",
+                    "node", "Node", "d", "D", "zig", "Zig", "go", "Go", "json", "JSON", "wasm", "Wasm",
+                ]),
         )
         .arg(
             Arg::with_name("cpp-compat")
                 .long("cpp-compat")
                 .help("Whether to add C++ compatibility to generated C bindings")
         )
+        .arg(
+            Arg::with_name("warnings-as-errors")
+                .long("warnings-as-errors")
+                .help("Fail if any diagnostic is reported, unless its lint is allowed in the config")
+        )
+        .arg(
+            Arg::with_name("message-format")
+                .long("message-format")
+                .value_name("FORMAT")
+                .possible_values(&["human", "json"])
+                .help("How to write diagnostics: as log messages, or as JSON lines on stderr")
+        )
         .arg(
             Arg::with_name("style")
                 .short("s")
@@ -164,6 +290,19 @@ fn main() {
                 .long("parse-dependencies")
                 .help("Whether to parse dependencies when generating bindings"),
         )
+        .arg(
+            Arg::with_name("target")
+                .long("target")
+                .value_name("TRIPLE")
+                .help(
+                    "Resolve #[cfg]'s for the given target triple, like \
+                    `x86_64-pc-windows-msvc`, instead of writing them all as \
+                    preprocessor conditions. Given several times, generates a \
+                    single header for all of the targets")
+                .multiple(true)
+                .number_of_values(1)
+                .required(false),
+        )
         .arg(
             Arg::with_name("clean")
                 .long("clean")
@@ -210,9 +349,159 @@ fn main() {
                     projects that use workspaces.")
                 .required(false),
         )
+        .arg(
+            Arg::with_name("watch")
+                .long("watch")
+                .help(
+                    "Keep running and regenerate the output whenever the config or \
+                    a source file changes")
+                .requires("out")
+                .conflicts_with_all(&["verify", "verify-strict"]),
+        )
+        .arg(
+            Arg::with_name("depfile")
+                .long("depfile")
+                .value_name("PATH")
+                .help(
+                    "Also write a Makefile rule making the output depend on every \
+                    source file and config read, for build systems to rerun \
+                    cbindgen only when they change")
+                .requires("out"),
+        )
+        .arg(
+            Arg::with_name("kotlin-def")
+                .long("kotlin-def")
+                .value_name("PATH")
+                .help(
+                    "Also write a Kotlin/Native cinterop definition file listing \
+                    the headers and the fieldless enums as strict enums")
+                .requires("out"),
+        )
+        .arg(
+            Arg::with_name("dep-graph")
+                .long("dep-graph")
+                .value_name("PATH")
+                .help(
+                    "Also write the items of the bindings, the crates they come \
+                    from and the items each of them uses to a file")
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("dep-graph-format")
+                .long("dep-graph-format")
+                .value_name("FORMAT")
+                .help("The format to write the dependency graph in, JSON by default")
+                .possible_values(&["json", "dot"])
+                .requires("dep-graph"),
+        )
+        .arg(
+            Arg::with_name("features")
+                .long("features")
+                .value_name("FEATURES")
+                .help(
+                    "Resolve the #[cfg(feature)]s of the binding crate with these \
+                    features enabled, separated by commas or spaces")
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("no-default-features")
+                .long("no-default-features")
+                .help(
+                    "Resolve the #[cfg(feature)]s of the binding crate without its \
+                    default features enabled"),
+        )
+        .arg(
+            Arg::with_name("all-features")
+                .long("all-features")
+                .help(
+                    "Resolve the #[cfg(feature)]s of the binding crate with all of \
+                    its features enabled"),
+        )
+        .arg(
+            Arg::with_name("only")
+                .long("only")
+                .value_name("GLOB")
+                .help(
+                    "Only export the functions and globals matching a glob, like \
+                    `audio_*`, and the types they use. It can be given several times")
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("exclude")
+                .long("exclude")
+                .value_name("GLOB")
+                .help(
+                    "Don't export the functions and globals matching a glob, like \
+                    `*_internal`, nor the types only they use. It can be given \
+                    several times")
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("symbol-list")
+                .long("symbol-list")
+                .value_name("PATH")
+                .help(
+                    "Also write the functions and globals the bindings declare as a \
+                    list of symbols for the linker to export: a Windows .def file, \
+                    a GNU ld version script (.map, .ver or .lds) or a macOS \
+                    exported symbols list (.exp or .exports), by the extension")
+                .multiple(true)
+                .number_of_values(1)
+                .validator(|x| match SymbolListFormat::from_path(Path::new(&x)) {
+                    Some(_) => Ok(()),
+                    None => Err(format!("Unrecognized symbol list extension: '{}'.", x)),
+                }),
+        )
+        .subcommand(
+            SubCommand::with_name("diff")
+                .about(
+                    "Compare the ABI of a crate to one written with `--lang json`, \
+                    exiting with 2 if it has breaking changes")
+                .arg(
+                    Arg::with_name("OLD")
+                        .help("The JSON description of the old ABI")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("INPUT")
+                        .help("A crate directory or source file to compare")
+                        .required(false)
+                        .index(2),
+                )
+                .arg(
+                    Arg::with_name("config")
+                        .short("c")
+                        .long("config")
+                        .value_name("PATH")
+                        .help("Specify path to a `cbindgen.toml` config to use"),
+                )
+                .arg(
+                    Arg::with_name("d")
+                        .short("d")
+                        .long("parse-dependencies")
+                        .help("Whether to parse dependencies when generating bindings"),
+                )
+                .arg(
+                    Arg::with_name("crate")
+                        .long("crate")
+                        .value_name("CRATE_NAME")
+                        .help("The specific crate to compare, in a workspace"),
+                )
+                .arg(
+                    Arg::with_name("lockfile")
+                        .long("lockfile")
+                        .value_name("PATH")
+                        .help("Specify the path to the Cargo.lock file explicitly"),
+                ),
+        )
         .get_matches();
 
-    if !matches.is_present("out") && matches.is_present("verify") {
+    let verify = matches.is_present("verify") || matches.is_present("verify-strict");
+    if !matches.is_present("out") && verify {
         error!(
             "Cannot verify bindings against `stdout`, please specify a file to compare against."
         );
@@ -226,6 +515,10 @@ fn main() {
         _ => logging::TraceLogger::init().unwrap(),
     }
 
+    if let Some(matches) = matches.subcommand_matches("diff") {
+        diff(matches);
+    }
+
     // Find the input directory
     let input = match matches.value_of("INPUT") {
         Some(input) => PathBuf::from(input),
@@ -241,17 +534,133 @@ fn main() {
         }
     };
 
+    if verify {
+        let file = matches.value_of("out").unwrap();
+        if let Err(msg) = bindings.verify(file, matches.is_present("verify-strict")) {
+            error!("{}", msg);
+            std::process::exit(2);
+        }
+        return;
+    }
+
+    write_bindings(&bindings, &matches);
+
+    if matches.is_present("watch") {
+        watch(bindings, &input, &matches);
+    }
+}
+
+/// Prints the changes of the ABI of a crate, exiting with 2 if any of them is
+/// breaking.
+fn diff<'a>(matches: &ArgMatches<'a>) -> ! {
+    let input = match matches.value_of("INPUT") {
+        Some(input) => PathBuf::from(input),
+        None => env::current_dir().unwrap(),
+    };
+
+    let bindings = match load_bindings(&input, matches) {
+        Ok(bindings) => bindings,
+        Err(msg) => {
+            error!("{}", msg);
+            error!("Couldn't generate bindings for {}.", input.display());
+            std::process::exit(1);
+        }
+    };
+
+    let changes = match bindings.abi_changes(matches.value_of("OLD").unwrap()) {
+        Ok(changes) => changes,
+        Err(msg) => {
+            error!("{}", msg);
+            std::process::exit(1);
+        }
+    };
+    for change in &changes {
+        println!("{}", change);
+    }
+
+    let breaking = changes.iter().filter(|x| x.breaking).count();
+    if breaking == 0 {
+        println!("No breaking changes.");
+        std::process::exit(0);
+    }
+    println!("{} breaking changes.", breaking);
+    std::process::exit(2);
+}
+
+/// Writes the bindings and the other requested outputs, returning whether the
+/// bindings changed.
+fn write_bindings<'a>(bindings: &Bindings, matches: &ArgMatches<'a>) -> bool {
+    if let Some(file) = matches.value_of("dep-graph") {
+        let format = match matches.value_of("dep-graph-format") {
+            Some("dot") => DependencyGraphFormat::Dot,
+            _ => DependencyGraphFormat::Json,
+        };
+        bindings.write_dependency_graph(file, format);
+    }
+
+    for file in matches.values_of("symbol-list").into_iter().flatten() {
+        let format = SymbolListFormat::from_path(Path::new(file)).unwrap();
+        bindings.write_symbol_list(file, format);
+    }
+
     // Write the bindings file
     match matches.value_of("out") {
         Some(file) => {
             let changed = bindings.write_to_file(file);
-
-            if matches.is_present("verify") && changed {
-                std::process::exit(2);
+            if let Some(depfile) = matches.value_of("depfile") {
+                bindings.write_depfile(depfile, file);
             }
+            if let Some(def) = matches.value_of("kotlin-def") {
+                bindings.write_kotlin_def(def, file);
+            }
+            changed
         }
         _ => {
+            if bindings.config.header_per_module {
+                warn!("header_per_module needs an output file, writing a single header.");
+            }
             bindings.write(io::stdout());
+            true
+        }
+    }
+}
+
+/// Regenerates the bindings whenever their inputs change, printing the lines
+/// of the output that changed.
+fn watch<'a>(mut bindings: Bindings, input: &Path, matches: &ArgMatches<'a>) -> ! {
+    let file = matches.value_of("out").unwrap();
+    println!("Watching {} for changes.", input.display());
+    loop {
+        watch::Watcher::new(&bindings).wait();
+
+        bindings = match load_bindings(input, matches) {
+            Ok(bindings) => bindings,
+            Err(msg) => {
+                error!("{}", msg);
+                error!("Couldn't generate bindings for {}.", input.display());
+                continue;
+            }
+        };
+
+        let old = fs::read_to_string(file).unwrap_or_default();
+        if !write_bindings(&bindings, matches) {
+            println!("{} is up to date.", file);
+            continue;
+        }
+        let new = fs::read_to_string(file).unwrap_or_default();
+
+        let (added, removed) = watch::changed_lines(&old, &new);
+        println!(
+            "Updated {}: {} lines added, {} lines removed.",
+            file,
+            added.len(),
+            removed.len()
+        );
+        for line in added {
+            println!("+ {}", line);
+        }
+        for line in removed {
+            println!("- {}", line);
         }
     }
 }
@@ -0,0 +1,88 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use bindgen::Bindings;
+
+/// How often the inputs are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches the inputs of bindings for changes by polling their modification
+/// times, which works the same on every platform.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(bindings: &Bindings) -> Watcher {
+        let files = bindings
+            .input_files()
+            .into_iter()
+            .map(|path| (path.to_owned(), modified(path)))
+            .collect();
+        Watcher { files }
+    }
+
+    /// Blocks until one of the inputs is modified or removed.
+    pub fn wait(&self) {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if self
+                .files
+                .iter()
+                .any(|&(ref path, time)| modified(path) != time)
+            {
+                return;
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|x| x.modified()).ok()
+}
+
+/// Returns the lines that were added to and removed from `old` to get `new`,
+/// ignoring blank lines and lines that only moved.
+pub fn changed_lines<'a>(old: &'a str, new: &'a str) -> (Vec<&'a str>, Vec<&'a str>) {
+    fn difference<'a>(lines: &'a str, other: &'a str) -> Vec<&'a str> {
+        let mut counts = HashMap::new();
+        for line in other.lines() {
+            *counts.entry(line).or_insert(0) += 1;
+        }
+        lines
+            .lines()
+            .filter(|line| match counts.get_mut(line) {
+                Some(ref mut count) if **count > 0 => {
+                    **count -= 1;
+                    false
+                }
+                _ => !line.trim().is_empty(),
+            })
+            .collect()
+    }
+
+    (difference(new, old), difference(old, new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_lines() {
+        let old = "void a(void);\n\nvoid b(void);\nvoid c(void);\nvoid c(void);\n";
+        let new = "void b(void);\nvoid a(void);\nvoid c(void);\n\n\nvoid d(void);\n";
+        assert_eq!(
+            changed_lines(old, new),
+            (vec!["void d(void);"], vec!["void c(void);"])
+        );
+        assert_eq!(changed_lines(old, old), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,66 @@
+export type ValueType = "i32" | "i64" | "f32" | "f64";
+
+export type TypedArray =
+  | Int8Array
+  | Uint8Array
+  | Int16Array
+  | Uint16Array
+  | Int32Array
+  | Uint32Array
+  | BigInt64Array
+  | BigUint64Array
+  | Float32Array
+  | Float64Array;
+
+export interface TypedArrayConstructor<T extends TypedArray = TypedArray> {
+  new (buffer: ArrayBufferLike, byteOffset: number, length: number): T;
+}
+
+/** The parameters holding the pointer and length of a slice. */
+export interface Slice {
+  readonly ptr: number;
+  readonly len: number;
+  readonly type: TypedArrayConstructor;
+}
+
+export interface Signature {
+  readonly params: readonly ValueType[];
+  readonly results: readonly ValueType[];
+  readonly slices?: readonly Slice[];
+  /** Whether the result is written through a pointer passed first. */
+  readonly sret?: boolean;
+  /** Whether the variadic arguments are passed through a pointer last. */
+  readonly variadic?: boolean;
+}
+
+export declare const functions: {
+  readonly root: Signature;
+};
+
+export declare const imports: {
+};
+
+export declare const globals: readonly string[];
+
+/** The exports of an instance of the module. */
+export interface Exports {
+  readonly memory: WebAssembly.Memory;
+  root(x: number, y: number, z: number, w: number): void;
+}
+
+/** The imports an instance of the module needs. */
+export interface Imports {
+}
+
+export declare function view<T extends TypedArray>(
+  memory: WebAssembly.Memory,
+  type: TypedArrayConstructor<T>,
+  ptr: number,
+  len: number,
+): T;
+
+export declare function slices(
+  memory: WebAssembly.Memory,
+  signature: Signature,
+  args: readonly (number | bigint)[],
+): TypedArray[];
@@ -0,0 +1,56 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Status: {
+  readonly Ok: 0;
+  readonly Err: 1;
+};
+
+export interface Dep {
+  a: number;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Dep: StructType<Dep>;
+
+export interface Foo_i32 {
+  a: number;
+  b: number;
+  c: Dep;
+  ref(): Buffer;
+}
+export declare const Foo_i32: StructType<Foo_i32>;
+
+export interface Foo_f64 {
+  a: number;
+  b: number;
+  c: Dep;
+  ref(): Buffer;
+}
+export declare const Foo_f64: StructType<Foo_f64>;
+
+export type IntFoo = Foo_i32;
+export declare const IntFoo: RefType;
+
+export type DoubleFoo = Foo_f64;
+export declare const DoubleFoo: RefType;
+
+export type Unit = number;
+export declare const Unit: RefType;
+
+export type SpecialStatus = number;
+export declare const SpecialStatus: RefType;
+
+export interface Library {
+  root(x: Foo_i32, y: Foo_f64, z: number, w: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,29 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Status = C.Status
+
+const (
+  Ok Status = C.Ok
+  Err Status = C.Err
+)
+
+type Dep = C.Dep
+
+type Foo_i32 = C.Foo_i32
+
+type IntFoo = C.IntFoo
+
+type Foo_f64 = C.Foo_f64
+
+type DoubleFoo = C.DoubleFoo
+
+type Unit = C.Unit
+
+type SpecialStatus = C.SpecialStatus
+
+func Root(x IntFoo, y DoubleFoo, z Unit, w SpecialStatus) {
+  C.root(x, y, z, w)
+}
@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Status {
+    int Ok = 0;
+    int Err = 1;
+  }
+
+  @Structure.FieldOrder({"a", "b"})
+  class Dep extends Structure {
+    public int a;
+    public float b;
+
+    public static class ByReference extends Dep implements Structure.ByReference {}
+
+    public static class ByValue extends Dep implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a", "b", "c"})
+  class Foo_i32 extends Structure {
+    public int a;
+    public int b;
+    public Dep c;
+
+    public static class ByReference extends Foo_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a", "b", "c"})
+  class Foo_f64 extends Structure {
+    public double a;
+    public double b;
+    public Dep c;
+
+    public static class ByReference extends Foo_f64 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_f64 implements Structure.ByValue {}
+  }
+
+  void root(Foo_i32.ByValue x, Foo_f64.ByValue y, int z, int w);
+}
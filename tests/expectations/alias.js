@@ -0,0 +1,55 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Dep = StructType();
+const Foo_i32 = StructType();
+const Foo_f64 = StructType();
+
+Dep.defineProperty('a', 'int32');
+Dep.defineProperty('b', 'float');
+
+Foo_i32.defineProperty('a', 'int32');
+Foo_i32.defineProperty('b', 'int32');
+Foo_i32.defineProperty('c', Dep);
+
+Foo_f64.defineProperty('a', 'double');
+Foo_f64.defineProperty('b', 'double');
+Foo_f64.defineProperty('c', Dep);
+
+const Status = Object.freeze({
+  Ok: 0,
+  Err: 1,
+});
+
+const IntFoo = Foo_i32;
+
+const DoubleFoo = Foo_f64;
+
+const Unit = 'int32';
+
+const SpecialStatus = 'uint32';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo_i32, Foo_f64, 'int32', 'uint32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Dep,
+  Foo_i32,
+  Foo_f64,
+  Status,
+  IntFoo,
+  DoubleFoo,
+  Unit,
+  SpecialStatus,
+  load,
+};
@@ -0,0 +1,47 @@
+import ctypes
+
+Status = ctypes.c_uint32
+Ok = 0
+Err = 1
+
+class Dep(ctypes.Structure):
+  pass
+
+Dep._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_float),
+]
+
+class Foo_i32(ctypes.Structure):
+  pass
+
+Foo_i32._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_int32),
+  ("c", Dep),
+]
+
+IntFoo = Foo_i32
+
+class Foo_f64(ctypes.Structure):
+  pass
+
+Foo_f64._fields_ = [
+  ("a", ctypes.c_double),
+  ("b", ctypes.c_double),
+  ("c", Dep),
+]
+
+DoubleFoo = Foo_f64
+
+Unit = ctypes.c_int32
+
+SpecialStatus = Status
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IntFoo, DoubleFoo, Unit, SpecialStatus]
+  lib.root.restype = None
+
+  return lib
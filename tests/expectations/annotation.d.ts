@@ -0,0 +1,92 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const C: {
+  readonly X: 2;
+  readonly Y: 3;
+};
+
+export declare const F_Tag: {
+  readonly Foo: 0;
+  readonly Bar: 1;
+  readonly Baz: 2;
+};
+
+export declare const H_Tag: {
+  readonly Hello: 0;
+  readonly There: 1;
+  readonly Everyone: 2;
+};
+
+export interface A {
+  m0: number;
+  ref(): Buffer;
+}
+export declare const A: StructType<A>;
+
+export interface B {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const B: StructType<B>;
+
+export interface Foo_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Foo_Body: StructType<Foo_Body>;
+
+export interface Bar_Body {
+  tag: number;
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Bar_Body: StructType<Bar_Body>;
+
+export interface F {
+  tag: number;
+  foo: Foo_Body;
+  bar: Bar_Body;
+  ref(): Buffer;
+}
+export declare const F: StructType<F>;
+
+export interface Hello_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Hello_Body: StructType<Hello_Body>;
+
+export interface There_Body {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const There_Body: StructType<There_Body>;
+
+export interface H {
+  tag: number;
+  variants: {
+    hello: Hello_Body;
+    there: There_Body;
+  };
+  ref(): Buffer;
+}
+export declare const H: StructType<H>;
+
+export interface Library {
+  root(x: A, y: B, z: number, f: F, h: H): void;
+}
+
+export declare function load(path: string): Library;
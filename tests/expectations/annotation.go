@@ -0,0 +1,45 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const (
+  X C.C = C.X
+  Y C.C = C.Y
+)
+
+type A = C.A
+
+type B = C.B
+
+type F_Tag = C.F_Tag
+
+const (
+  Foo F_Tag = C.Foo
+  Bar F_Tag = C.Bar
+  Baz F_Tag = C.Baz
+)
+
+type Foo_Body = C.Foo_Body
+
+type Bar_Body = C.Bar_Body
+
+type F = C.F
+
+type H_Tag = C.H_Tag
+
+const (
+  Hello H_Tag = C.Hello
+  There H_Tag = C.There
+  Everyone H_Tag = C.Everyone
+)
+
+type Hello_Body = C.Hello_Body
+
+type There_Body = C.There_Body
+
+type H = C.H
+
+func Root(x A, y B, z C.C, f F, h H) {
+  C.root(x, y, z, f, h)
+}
@@ -0,0 +1,110 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface C {
+    int X = 2;
+    int Y = 3;
+  }
+
+  @Structure.FieldOrder({"m0"})
+  class A extends Structure {
+    public int m0;
+
+    public static class ByReference extends A implements Structure.ByReference {}
+
+    public static class ByValue extends A implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class B extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends B implements Structure.ByReference {}
+
+    public static class ByValue extends B implements Structure.ByValue {}
+  }
+
+  interface F_Tag {
+    byte Foo = 0;
+    byte Bar = 1;
+    byte Baz = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Foo_Body extends Structure {
+    public byte tag;
+    public short _0;
+
+    public static class ByReference extends Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "x", "y"})
+  class Bar_Body extends Structure {
+    public byte tag;
+    public byte x;
+    public short y;
+
+    public static class ByReference extends Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Bar_Body implements Structure.ByValue {}
+  }
+
+  class F extends Union {
+    public byte tag;
+    public Foo_Body foo;
+    public Bar_Body bar;
+
+    public static class ByReference extends F implements Structure.ByReference {}
+
+    public static class ByValue extends F implements Structure.ByValue {}
+  }
+
+  interface H_Tag {
+    byte Hello = 0;
+    byte There = 1;
+    byte Everyone = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Hello_Body extends Structure {
+    public short _0;
+
+    public static class ByReference extends Hello_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Hello_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class There_Body extends Structure {
+    public byte x;
+    public short y;
+
+    public static class ByReference extends There_Body implements Structure.ByReference {}
+
+    public static class ByValue extends There_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class H extends Structure {
+    public static class Variants extends Union {
+      public Hello_Body hello;
+      public There_Body there;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends H implements Structure.ByReference {}
+
+    public static class ByValue extends H implements Structure.ByValue {}
+  }
+
+  void root(A.ByValue x, B.ByValue y, int z, F.ByValue f, H.ByValue h);
+}
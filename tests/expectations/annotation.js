@@ -0,0 +1,84 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const A = StructType();
+const B = StructType();
+const Foo_Body = StructType();
+const Bar_Body = StructType();
+const F = UnionType();
+const Hello_Body = StructType();
+const There_Body = StructType();
+const _H_Variants = UnionType();
+const H = StructType();
+
+A.defineProperty('m0', 'int32');
+
+B.defineProperty('x', 'int32');
+B.defineProperty('y', 'float');
+
+Foo_Body.defineProperty('tag', 'uint8');
+Foo_Body.defineProperty('_0', 'int16');
+
+Bar_Body.defineProperty('tag', 'uint8');
+Bar_Body.defineProperty('x', 'uint8');
+Bar_Body.defineProperty('y', 'int16');
+
+F.defineProperty('tag', 'uint8');
+F.defineProperty('foo', Foo_Body);
+F.defineProperty('bar', Bar_Body);
+
+Hello_Body.defineProperty('_0', 'int16');
+
+There_Body.defineProperty('x', 'uint8');
+There_Body.defineProperty('y', 'int16');
+
+_H_Variants.defineProperty('hello', Hello_Body);
+_H_Variants.defineProperty('there', There_Body);
+
+H.defineProperty('tag', 'uint8');
+H.defineProperty('variants', _H_Variants);
+
+const C = Object.freeze({
+  X: 2,
+  Y: 3,
+});
+
+const F_Tag = Object.freeze({
+  Foo: 0,
+  Bar: 1,
+  Baz: 2,
+});
+
+const H_Tag = Object.freeze({
+  Hello: 0,
+  There: 1,
+  Everyone: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [A, B, 'uint32', F, H]],
+  });
+  return lib;
+}
+
+module.exports = {
+  A,
+  B,
+  Foo_Body,
+  Bar_Body,
+  F,
+  Hello_Body,
+  There_Body,
+  H,
+  C,
+  F_Tag,
+  H_Tag,
+  load,
+};
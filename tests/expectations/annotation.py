@@ -0,0 +1,96 @@
+import ctypes
+
+C = ctypes.c_uint32
+X = 2
+Y = 3
+
+class A(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("m0", ctypes.c_int32),
+]
+
+class B(ctypes.Structure):
+  pass
+
+B._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+F_Tag = ctypes.c_uint8
+Foo = 0
+Bar = 1
+Baz = 2
+
+class Foo_Body(ctypes.Structure):
+  pass
+
+Foo_Body._fields_ = [
+  ("tag", F_Tag),
+  ("_0", ctypes.c_int16),
+]
+
+class Bar_Body(ctypes.Structure):
+  pass
+
+Bar_Body._fields_ = [
+  ("tag", F_Tag),
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class F(ctypes.Union):
+  pass
+
+F._fields_ = [
+  ("tag", F_Tag),
+  ("foo", Foo_Body),
+  ("bar", Bar_Body),
+]
+
+H_Tag = ctypes.c_uint8
+Hello = 0
+There = 1
+Everyone = 2
+
+class Hello_Body(ctypes.Structure):
+  pass
+
+Hello_Body._fields_ = [
+  ("_0", ctypes.c_int16),
+]
+
+class There_Body(ctypes.Structure):
+  pass
+
+There_Body._fields_ = [
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class _H_Variants(ctypes.Union):
+  pass
+
+_H_Variants._fields_ = [
+  ("hello", Hello_Body),
+  ("there", There_Body),
+]
+
+class H(ctypes.Structure):
+  pass
+
+H._anonymous_ = ("_variants",)
+H._fields_ = [
+  ("tag", H_Tag),
+  ("_variants", _H_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, C, F, H]
+  lib.root.restype = None
+
+  return lib
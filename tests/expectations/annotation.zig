@@ -0,0 +1,63 @@
+const std = @import("std");
+
+pub const C = enum(u32) {
+  X = 2,
+  Y,
+};
+
+pub const A = extern struct {
+  m0: i32,
+};
+
+pub const B = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub const F_Tag = enum(u8) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Foo_Body = extern struct {
+  tag: F_Tag,
+  _0: i16,
+};
+
+pub const Bar_Body = extern struct {
+  tag: F_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const F = extern union {
+  tag: F_Tag,
+  foo: Foo_Body,
+  bar: Bar_Body,
+};
+
+pub const H_Tag = enum(u8) {
+  Hello,
+  There,
+  Everyone,
+};
+
+pub const Hello_Body = extern struct {
+  _0: i16,
+};
+
+pub const There_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const H = extern struct {
+  tag: H_Tag,
+  body: extern union {
+    hello: Hello_Body,
+    there: There_Body,
+  },
+};
+
+pub extern fn root(x: A, y: B, z: C, f: F, h: H) void;
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t kind;
+  /**
+   * The value of the variant.
+   */
+  union {
+    int64_t integer;
+    double real;
+    struct {
+      float x;
+      float y;
+    };
+  };
+} Variant;
+
+/**
+ * Used by both `root` and `Holder`, so it stays named.
+ */
+typedef union {
+  int32_t a;
+  uint32_t b;
+} Shared;
+
+typedef struct {
+  Shared shared;
+} Holder;
+
+void root(Variant variant, Holder holder, Shared shared);
@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Variant {
+  uint8_t kind;
+  /// The value of the variant.
+  union {
+    int64_t integer;
+    double real;
+    struct {
+      float x;
+      float y;
+    };
+  };
+};
+
+/// Used by both `root` and `Holder`, so it stays named.
+union Shared {
+  int32_t a;
+  uint32_t b;
+};
+
+struct Holder {
+  Shared shared;
+};
+
+extern "C" {
+
+void root(Variant variant, Holder holder, Shared shared);
+
+} // extern "C"
@@ -0,0 +1,54 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Value {
+  integer: number | string;
+  real: number;
+  point: Point;
+  ref(): Buffer;
+}
+export declare const Value: StructType<Value>;
+
+export interface Variant {
+  kind: number;
+  value: Value;
+  ref(): Buffer;
+}
+export declare const Variant: StructType<Variant>;
+
+/**
+ * Used by both `root` and `Holder`, so it stays named.
+ */
+export interface Shared {
+  a: number;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Shared: StructType<Shared>;
+
+export interface Holder {
+  shared: Shared;
+  ref(): Buffer;
+}
+export declare const Holder: StructType<Holder>;
+
+export interface Library {
+  root(variant: Variant, holder: Holder, shared: Shared): void;
+}
+
+export declare function load(path: string): Library;
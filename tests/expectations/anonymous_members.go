@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+type Value = C.Value
+
+type Variant = C.Variant
+
+// Used by both `root` and `Holder`, so it stays named.
+type Shared = C.Shared
+
+type Holder = C.Holder
+
+func Root(variant Variant, holder Holder, shared Shared) {
+  C.root(variant, holder, shared)
+}
@@ -0,0 +1,59 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  class Value extends Union {
+    public long integer;
+    public double real;
+    public Point point;
+
+    public static class ByReference extends Value implements Structure.ByReference {}
+
+    public static class ByValue extends Value implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"kind", "value"})
+  class Variant extends Structure {
+    public byte kind;
+    /**
+     * The value of the variant.
+     */
+    public Value value;
+
+    public static class ByReference extends Variant implements Structure.ByReference {}
+
+    public static class ByValue extends Variant implements Structure.ByValue {}
+  }
+
+  /**
+   * Used by both `root` and `Holder`, so it stays named.
+   */
+  class Shared extends Union {
+    public int a;
+    public int b;
+
+    public static class ByReference extends Shared implements Structure.ByReference {}
+
+    public static class ByValue extends Shared implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"shared"})
+  class Holder extends Structure {
+    public Shared shared;
+
+    public static class ByReference extends Holder implements Structure.ByReference {}
+
+    public static class ByValue extends Holder implements Structure.ByValue {}
+  }
+
+  void root(Variant.ByValue variant, Holder.ByValue holder, Shared.ByValue shared);
+}
@@ -0,0 +1,45 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+const Value = UnionType();
+const Variant = StructType();
+const Shared = UnionType();
+const Holder = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+Value.defineProperty('integer', 'int64');
+Value.defineProperty('real', 'double');
+Value.defineProperty('point', Point);
+
+Variant.defineProperty('kind', 'uint8');
+Variant.defineProperty('value', Value);
+
+Shared.defineProperty('a', 'int32');
+Shared.defineProperty('b', 'uint32');
+
+Holder.defineProperty('shared', Shared);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Variant, Holder, Shared]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Value,
+  Variant,
+  Shared,
+  Holder,
+  load,
+};
@@ -0,0 +1,32 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Point:
+    float x
+    float y
+
+  ctypedef union Value:
+    int64_t integer
+    double real
+    Point point
+
+  ctypedef struct Variant:
+    uint8_t kind
+    # The value of the variant.
+    Value value
+
+  # Used by both `root` and `Holder`, so it stays named.
+  ctypedef union Shared:
+    int32_t a
+    uint32_t b
+
+  ctypedef struct Holder:
+    Shared shared
+
+  void root(Variant variant, Holder holder, Shared shared)
@@ -0,0 +1,51 @@
+import ctypes
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class Value(ctypes.Union):
+  pass
+
+Value._fields_ = [
+  ("integer", ctypes.c_int64),
+  ("real", ctypes.c_double),
+  ("point", Point),
+]
+
+class Variant(ctypes.Structure):
+  pass
+
+Variant._fields_ = [
+  ("kind", ctypes.c_uint8),
+  # The value of the variant.
+  ("value", Value),
+]
+
+# Used by both `root` and `Holder`, so it stays named.
+class Shared(ctypes.Union):
+  pass
+
+Shared._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_uint32),
+]
+
+class Holder(ctypes.Structure):
+  pass
+
+Holder._fields_ = [
+  ("shared", Shared),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Variant, Holder, Shared]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,30 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Value = extern union {
+  integer: i64,
+  real: f64,
+  point: Point,
+};
+
+pub const Variant = extern struct {
+  kind: u8,
+  /// The value of the variant.
+  value: Value,
+};
+
+/// Used by both `root` and `Holder`, so it stays named.
+pub const Shared = extern union {
+  a: i32,
+  b: u32,
+};
+
+pub const Holder = extern struct {
+  shared: Shared,
+};
+
+pub extern fn root(variant: Variant, holder: Holder, shared: Shared) void;
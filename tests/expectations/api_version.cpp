@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#define MYLIB_API_VERSION_LATEST 0x0103
+#ifndef MYLIB_API_VERSION
+#  define MYLIB_API_VERSION MYLIB_API_VERSION_LATEST
+#endif
+
+static const uint32_t BUFFER_SIZE = 256;
+
+#if MYLIB_API_VERSION >= 0x0102
+static const uint32_t MAX_STREAMS = 16;
+#endif
+
+struct Handle {
+  uint32_t id;
+};
+
+#if MYLIB_API_VERSION >= 0x0102
+struct Stream {
+  Handle handle;
+  uint64_t position;
+};
+#endif
+
+extern "C" {
+
+Handle open(uint32_t id);
+
+#if MYLIB_API_VERSION >= 0x0102
+Stream open_stream(Handle handle);
+#endif
+
+#if MYLIB_API_VERSION >= 0x0103
+void seek(Stream *stream, uint64_t position);
+#endif
+
+} // extern "C"
@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const BUFFER_SIZE: number;
+
+export declare const MAX_STREAMS: number;
+
+export interface Handle {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface Stream {
+  handle: Handle;
+  position: number | string;
+  ref(): Buffer;
+}
+export declare const Stream: StructType<Stream>;
+
+export interface Library {
+  open(id: number): Handle;
+
+  open_stream(handle: Handle): Stream;
+
+  seek(stream: Buffer | null, position: number | string): void;
+}
+
+export declare function load(path: string): Library;
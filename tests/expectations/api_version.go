@@ -0,0 +1,24 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+type Stream = C.Stream
+
+const BUFFER_SIZE = C.BUFFER_SIZE
+
+const MAX_STREAMS = C.MAX_STREAMS
+
+func Open(id uint32) Handle {
+  return C.open(C.uint32_t(id))
+}
+
+func OpenStream(handle Handle) Stream {
+  return C.open_stream(handle)
+}
+
+func Seek(stream *Stream, position uint64) {
+  C.seek(stream, C.uint64_t(position))
+}
@@ -0,0 +1,32 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int BUFFER_SIZE = 256;
+
+  int MAX_STREAMS = 16;
+
+  @Structure.FieldOrder({"id"})
+  class Handle extends Structure {
+    public int id;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"handle", "position"})
+  class Stream extends Structure {
+    public Handle handle;
+    public long position;
+
+    public static class ByReference extends Stream implements Structure.ByReference {}
+
+    public static class ByValue extends Stream implements Structure.ByValue {}
+  }
+
+  Handle.ByValue open(int id);
+
+  Stream.ByValue open_stream(Handle.ByValue handle);
+
+  void seek(Stream.ByReference stream, long position);
+}
@@ -0,0 +1,37 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const Stream = StructType();
+
+const BUFFER_SIZE = 256;
+
+const MAX_STREAMS = 16;
+
+Handle.defineProperty('id', 'uint32');
+
+Stream.defineProperty('handle', Handle);
+Stream.defineProperty('position', 'uint64');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    open: [Handle, ['uint32']],
+    open_stream: [Stream, [Handle]],
+    seek: ['void', [ref.refType(Stream), 'uint64']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Stream,
+  BUFFER_SIZE,
+  MAX_STREAMS,
+  load,
+};
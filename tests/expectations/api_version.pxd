@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t BUFFER_SIZE
+
+  const uint32_t MAX_STREAMS
+
+  ctypedef struct Handle:
+    uint32_t id
+
+  ctypedef struct Stream:
+    Handle handle
+    uint64_t position
+
+  Handle open(uint32_t id)
+
+  Stream open_stream(Handle handle)
+
+  void seek(Stream *stream, uint64_t position)
@@ -0,0 +1,34 @@
+import ctypes
+
+BUFFER_SIZE = 256
+
+MAX_STREAMS = 16
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+class Stream(ctypes.Structure):
+  pass
+
+Stream._fields_ = [
+  ("handle", Handle),
+  ("position", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.open.argtypes = [ctypes.c_uint32]
+  lib.open.restype = Handle
+
+  lib.open_stream.argtypes = [Handle]
+  lib.open_stream.restype = Stream
+
+  lib.seek.argtypes = [ctypes.POINTER(Stream), ctypes.c_uint64]
+  lib.seek.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const BUFFER_SIZE: u32 = 256;
+
+pub const MAX_STREAMS: u32 = 16;
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+pub const Stream = extern struct {
+  handle: Handle,
+  position: u64,
+};
+
+pub extern fn open(id: u32) Handle;
+
+pub extern fn open_stream(handle: Handle) Stream;
+
+pub extern fn seek(stream: [*c]Stream, position: u64) void;
@@ -0,0 +1,35 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Foo_Tag: {
+  readonly A: 0;
+};
+
+export interface A_Body {
+  _0: ArrayLike<number>;
+  ref(): Buffer;
+}
+export declare const A_Body: StructType<A_Body>;
+
+export interface Foo {
+  tag: number;
+  variants: {
+    a: A_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  root(a: Foo): void;
+}
+
+export declare function load(path: string): Library;
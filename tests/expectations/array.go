@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo_Tag = C.Foo_Tag
+
+const (
+  A Foo_Tag = C.A
+)
+
+type A_Body = C.A_Body
+
+type Foo = C.Foo
+
+func Root(a Foo) {
+  C.root(a)
+}
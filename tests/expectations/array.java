@@ -0,0 +1,36 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Foo_Tag {
+    int A = 0;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class A_Body extends Structure {
+    public float[] _0 = new float[20];
+
+    public static class ByReference extends A_Body implements Structure.ByReference {}
+
+    public static class ByValue extends A_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Foo extends Structure {
+    public static class Variants extends Union {
+      public A_Body a;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void root(Foo.ByValue a);
+}
@@ -0,0 +1,37 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const A_Body = StructType();
+const _Foo_Variants = UnionType();
+const Foo = StructType();
+
+A_Body.defineProperty('_0', ArrayType('float', 20));
+
+_Foo_Variants.defineProperty('a', A_Body);
+
+Foo.defineProperty('tag', 'int');
+Foo.defineProperty('variants', _Foo_Variants);
+
+const Foo_Tag = Object.freeze({
+  A: 0,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  A_Body,
+  Foo,
+  Foo_Tag,
+  load,
+};
@@ -0,0 +1,35 @@
+import ctypes
+
+Foo_Tag = ctypes.c_int
+A = 0
+
+class A_Body(ctypes.Structure):
+  pass
+
+A_Body._fields_ = [
+  ("_0", (ctypes.c_float * 20)),
+]
+
+class _Foo_Variants(ctypes.Union):
+  pass
+
+_Foo_Variants._fields_ = [
+  ("a", A_Body),
+]
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._anonymous_ = ("_variants",)
+Foo._fields_ = [
+  ("tag", Foo_Tag),
+  ("_variants", _Foo_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
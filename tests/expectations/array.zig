@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const Foo_Tag = enum(c_int) {
+  A,
+};
+
+pub const A_Body = extern struct {
+  _0: [20]f32,
+};
+
+pub const Foo = extern struct {
+  tag: Foo_Tag,
+  body: extern union {
+    a: A_Body,
+  },
+};
+
+pub extern fn root(a: Foo) void;
@@ -0,0 +1,106 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const H_Tag: {
+  readonly H_Foo: 0;
+  readonly H_Bar: 1;
+  readonly H_Baz: 2;
+};
+
+export declare const J_Tag: {
+  readonly J_Foo: 0;
+  readonly J_Bar: 1;
+  readonly J_Baz: 2;
+};
+
+export declare const K_Tag: {
+  readonly K_Foo: 0;
+  readonly K_Bar: 1;
+  readonly K_Baz: 2;
+};
+
+export interface H_Foo_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const H_Foo_Body: StructType<H_Foo_Body>;
+
+export interface H_Bar_Body {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const H_Bar_Body: StructType<H_Bar_Body>;
+
+export interface H {
+  tag: number;
+  variants: {
+    foo: H_Foo_Body;
+    bar: H_Bar_Body;
+  };
+  ref(): Buffer;
+}
+export declare const H: StructType<H>;
+
+export interface J_Foo_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const J_Foo_Body: StructType<J_Foo_Body>;
+
+export interface J_Bar_Body {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const J_Bar_Body: StructType<J_Bar_Body>;
+
+export interface J {
+  tag: number;
+  variants: {
+    foo: J_Foo_Body;
+    bar: J_Bar_Body;
+  };
+  ref(): Buffer;
+}
+export declare const J: StructType<J>;
+
+export interface K_Foo_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const K_Foo_Body: StructType<K_Foo_Body>;
+
+export interface K_Bar_Body {
+  tag: number;
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const K_Bar_Body: StructType<K_Bar_Body>;
+
+export interface K {
+  tag: number;
+  foo: K_Foo_Body;
+  bar: K_Bar_Body;
+  ref(): Buffer;
+}
+export declare const K: StructType<K>;
+
+export interface Library {
+  foo(h: H, i: never, j: J, k: K): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,55 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type I = C.I
+
+type H_Tag = C.H_Tag
+
+const (
+  H_Foo H_Tag = C.H_Foo
+  H_Bar H_Tag = C.H_Bar
+  H_Baz H_Tag = C.H_Baz
+)
+
+type H_Foo_Body = C.H_Foo_Body
+
+type H_Bar_Body = C.H_Bar_Body
+
+type H = C.H
+
+type J_Tag = C.J_Tag
+
+const (
+  J_Foo J_Tag = C.J_Foo
+  J_Bar J_Tag = C.J_Bar
+  J_Baz J_Tag = C.J_Baz
+)
+
+type J_Foo_Body = C.J_Foo_Body
+
+type J_Bar_Body = C.J_Bar_Body
+
+type J = C.J
+
+type K_Tag = C.K_Tag
+
+const (
+  K_Foo K_Tag = C.K_Foo
+  K_Bar K_Tag = C.K_Bar
+  K_Baz K_Tag = C.K_Baz
+)
+
+type K_Foo_Body = C.K_Foo_Body
+
+type K_Bar_Body = C.K_Bar_Body
+
+type K = C.K
+
+func Foo(h H, i I, j J, k K) {
+  C.foo(h, i, j, k)
+}
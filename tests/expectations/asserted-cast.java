@@ -0,0 +1,133 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface H_Tag {
+    byte H_Foo = 0;
+    byte H_Bar = 1;
+    byte H_Baz = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class H_Foo_Body extends Structure {
+    public short _0;
+
+    public static class ByReference extends H_Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends H_Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class H_Bar_Body extends Structure {
+    public byte x;
+    public short y;
+
+    public static class ByReference extends H_Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends H_Bar_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class H extends Structure {
+    public static class Variants extends Union {
+      public H_Foo_Body foo;
+      public H_Bar_Body bar;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends H implements Structure.ByReference {}
+
+    public static class ByValue extends H implements Structure.ByValue {}
+  }
+
+  interface J_Tag {
+    byte J_Foo = 0;
+    byte J_Bar = 1;
+    byte J_Baz = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class J_Foo_Body extends Structure {
+    public short _0;
+
+    public static class ByReference extends J_Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends J_Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class J_Bar_Body extends Structure {
+    public byte x;
+    public short y;
+
+    public static class ByReference extends J_Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends J_Bar_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class J extends Structure {
+    public static class Variants extends Union {
+      public J_Foo_Body foo;
+      public J_Bar_Body bar;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends J implements Structure.ByReference {}
+
+    public static class ByValue extends J implements Structure.ByValue {}
+  }
+
+  interface K_Tag {
+    byte K_Foo = 0;
+    byte K_Bar = 1;
+    byte K_Baz = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class K_Foo_Body extends Structure {
+    public byte tag;
+    public short _0;
+
+    public static class ByReference extends K_Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends K_Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "x", "y"})
+  class K_Bar_Body extends Structure {
+    public byte tag;
+    public byte x;
+    public short y;
+
+    public static class ByReference extends K_Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends K_Bar_Body implements Structure.ByValue {}
+  }
+
+  class K extends Union {
+    public byte tag;
+    public K_Foo_Body foo;
+    public K_Bar_Body bar;
+
+    public static class ByReference extends K implements Structure.ByReference {}
+
+    public static class ByValue extends K implements Structure.ByValue {}
+  }
+
+  void foo(H.ByValue h, Pointer i, J.ByValue j, K.ByValue k);
+}
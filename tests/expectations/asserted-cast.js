@@ -0,0 +1,97 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const H_Foo_Body = StructType();
+const H_Bar_Body = StructType();
+const _H_Variants = UnionType();
+const H = StructType();
+const J_Foo_Body = StructType();
+const J_Bar_Body = StructType();
+const _J_Variants = UnionType();
+const J = StructType();
+const K_Foo_Body = StructType();
+const K_Bar_Body = StructType();
+const K = UnionType();
+
+H_Foo_Body.defineProperty('_0', 'int16');
+
+H_Bar_Body.defineProperty('x', 'uint8');
+H_Bar_Body.defineProperty('y', 'int16');
+
+_H_Variants.defineProperty('foo', H_Foo_Body);
+_H_Variants.defineProperty('bar', H_Bar_Body);
+
+H.defineProperty('tag', 'uint8');
+H.defineProperty('variants', _H_Variants);
+
+J_Foo_Body.defineProperty('_0', 'int16');
+
+J_Bar_Body.defineProperty('x', 'uint8');
+J_Bar_Body.defineProperty('y', 'int16');
+
+_J_Variants.defineProperty('foo', J_Foo_Body);
+_J_Variants.defineProperty('bar', J_Bar_Body);
+
+J.defineProperty('tag', 'uint8');
+J.defineProperty('variants', _J_Variants);
+
+K_Foo_Body.defineProperty('tag', 'uint8');
+K_Foo_Body.defineProperty('_0', 'int16');
+
+K_Bar_Body.defineProperty('tag', 'uint8');
+K_Bar_Body.defineProperty('x', 'uint8');
+K_Bar_Body.defineProperty('y', 'int16');
+
+K.defineProperty('tag', 'uint8');
+K.defineProperty('foo', K_Foo_Body);
+K.defineProperty('bar', K_Bar_Body);
+
+const H_Tag = Object.freeze({
+  H_Foo: 0,
+  H_Bar: 1,
+  H_Baz: 2,
+});
+
+const J_Tag = Object.freeze({
+  J_Foo: 0,
+  J_Bar: 1,
+  J_Baz: 2,
+});
+
+const K_Tag = Object.freeze({
+  K_Foo: 0,
+  K_Bar: 1,
+  K_Baz: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    foo: ['void', [H, 'void', J, K]],
+  });
+  return lib;
+}
+
+module.exports = {
+  H_Foo_Body,
+  H_Bar_Body,
+  H,
+  J_Foo_Body,
+  J_Bar_Body,
+  J,
+  K_Foo_Body,
+  K_Bar_Body,
+  K,
+  H_Tag,
+  J_Tag,
+  K_Tag,
+  load,
+};
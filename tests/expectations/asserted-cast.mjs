@@ -0,0 +1,31 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+/** The signatures of the functions the module exports. */
+export const functions = {
+  foo: {
+    params: ["i32", "i32", "i32", "i32"],
+    results: [],
+  },
+};
+
+/** The signatures of the functions the module imports. */
+export const imports = {};
+
+/** The statics the module exports, as globals holding their address. */
+export const globals = [];
+
+/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */
+export function view(memory, type, ptr, len) {
+  return new type(memory.buffer, ptr, len);
+}
+
+/**
+ * Returns views of the slices that `args`, the arguments of a call to a
+ * function with `signature`, point to in `memory`.
+ */
+export function slices(memory, signature, args) {
+  return (signature.slices || []).map((slice) =>
+    view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),
+  );
+}
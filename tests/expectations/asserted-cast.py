@@ -0,0 +1,120 @@
+#define MY_ASSERT(...) do { } while (0)
+
+
+import ctypes
+
+class I(ctypes.Structure):
+  pass
+
+H_Tag = ctypes.c_uint8
+H_Foo = 0
+H_Bar = 1
+H_Baz = 2
+
+class H_Foo_Body(ctypes.Structure):
+  pass
+
+H_Foo_Body._fields_ = [
+  ("_0", ctypes.c_int16),
+]
+
+class H_Bar_Body(ctypes.Structure):
+  pass
+
+H_Bar_Body._fields_ = [
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class _H_Variants(ctypes.Union):
+  pass
+
+_H_Variants._fields_ = [
+  ("foo", H_Foo_Body),
+  ("bar", H_Bar_Body),
+]
+
+class H(ctypes.Structure):
+  pass
+
+H._anonymous_ = ("_variants",)
+H._fields_ = [
+  ("tag", H_Tag),
+  ("_variants", _H_Variants),
+]
+
+J_Tag = ctypes.c_uint8
+J_Foo = 0
+J_Bar = 1
+J_Baz = 2
+
+class J_Foo_Body(ctypes.Structure):
+  pass
+
+J_Foo_Body._fields_ = [
+  ("_0", ctypes.c_int16),
+]
+
+class J_Bar_Body(ctypes.Structure):
+  pass
+
+J_Bar_Body._fields_ = [
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class _J_Variants(ctypes.Union):
+  pass
+
+_J_Variants._fields_ = [
+  ("foo", J_Foo_Body),
+  ("bar", J_Bar_Body),
+]
+
+class J(ctypes.Structure):
+  pass
+
+J._anonymous_ = ("_variants",)
+J._fields_ = [
+  ("tag", J_Tag),
+  ("_variants", _J_Variants),
+]
+
+K_Tag = ctypes.c_uint8
+K_Foo = 0
+K_Bar = 1
+K_Baz = 2
+
+class K_Foo_Body(ctypes.Structure):
+  pass
+
+K_Foo_Body._fields_ = [
+  ("tag", K_Tag),
+  ("_0", ctypes.c_int16),
+]
+
+class K_Bar_Body(ctypes.Structure):
+  pass
+
+K_Bar_Body._fields_ = [
+  ("tag", K_Tag),
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class K(ctypes.Union):
+  pass
+
+K._fields_ = [
+  ("tag", K_Tag),
+  ("foo", K_Foo_Body),
+  ("bar", K_Bar_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [H, I, J, K]
+  lib.foo.restype = None
+
+  return lib
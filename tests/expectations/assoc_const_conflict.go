@@ -0,0 +1,6 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const Foo_FOO = C.Foo_FOO
@@ -0,0 +1,5 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int Foo_FOO = 42;
+}
@@ -0,0 +1,3 @@
+import ctypes
+
+Foo_FOO = 42
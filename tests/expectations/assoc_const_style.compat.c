@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+
+} Foo;
+static const int32_t FOO_GA = 10;
+static const char* const FOO_BU = "hello world";
+static const float FOO_ZO = 3.14f;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Foo x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Foo {
+
+};
+static const int32_t FOO_GA = 10;
+static const char* const FOO_BU = "hello world";
+static const float FOO_ZO = 3.14f;
+
+extern "C" {
+
+void root(Foo x);
+
+} // extern "C"
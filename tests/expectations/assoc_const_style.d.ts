@@ -0,0 +1,27 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const FOO_GA: number;
+
+export declare const FOO_BU: string;
+
+export declare const FOO_ZO: number;
+
+export interface Foo {
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  root(x: Foo): void;
+}
+
+export declare function load(path: string): Library;
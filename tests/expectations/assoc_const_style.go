@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+const FOO_GA = C.FOO_GA
+
+const FOO_BU = "hello world"
+
+const FOO_ZO = C.FOO_ZO
+
+func Root(x Foo) {
+  C.root(x)
+}
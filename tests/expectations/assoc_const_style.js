@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+
+const FOO_GA = 10;
+
+const FOO_BU = 'hello world';
+
+const FOO_ZO = 3.14;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  FOO_GA,
+  FOO_BU,
+  FOO_ZO,
+  load,
+};
@@ -0,0 +1,17 @@
+import ctypes
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+Foo_GA = 10
+Foo_BU = b"hello world"
+Foo_ZO = 3.14
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
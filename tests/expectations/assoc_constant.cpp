@@ -7,7 +7,8 @@ struct Foo {
 
 };
 static const int32_t Foo_GA = 10;
-static const float Foo_ZO = 3.14;
+static const char* const Foo_BU = "hello world";
+static const float Foo_ZO = 3.14f;
 
 extern "C" {
 
@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+const Foo_GA = C.Foo_GA
+
+const Foo_BU = "hello world"
+
+const Foo_ZO = C.Foo_ZO
+
+func Root(x Foo) {
+  C.root(x)
+}
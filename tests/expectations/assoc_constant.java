@@ -0,0 +1,19 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({})
+  class Foo extends Structure {
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  int Foo_GA = 10;
+
+  String Foo_BU = "hello world";
+
+  float Foo_ZO = 3.14f;
+
+  void root(Foo.ByValue x);
+}
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Foo = extern struct {
+
+};
+
+pub const Foo_GA: i32 = 10;
+
+pub const Foo_BU: [*:0]const u8 = "hello world";
+
+pub const Foo_ZO: f32 = 3.14;
+
+pub extern fn root(x: Foo) void;
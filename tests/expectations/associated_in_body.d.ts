@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * 'auto'
+ */
+export declare const StyleAlignFlags_AUTO: StyleAlignFlags;
+
+/**
+ * 'normal'
+ */
+export declare const StyleAlignFlags_NORMAL: StyleAlignFlags;
+
+/**
+ * 'start'
+ */
+export declare const StyleAlignFlags_START: StyleAlignFlags;
+
+/**
+ * 'end'
+ */
+export declare const StyleAlignFlags_END: StyleAlignFlags;
+
+/**
+ * 'flex-start'
+ */
+export declare const StyleAlignFlags_FLEX_START: StyleAlignFlags;
+
+/**
+ * Constants shared by multiple CSS Box Alignment properties
+ * These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+ */
+export interface StyleAlignFlags {
+  bits: number;
+  ref(): Buffer;
+}
+export declare const StyleAlignFlags: StructType<StyleAlignFlags>;
+
+export interface Library {
+  root(flags: StyleAlignFlags): void;
+}
+
+export declare function load(path: string): Library;
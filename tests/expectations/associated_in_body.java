@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * Constants shared by multiple CSS Box Alignment properties
+   * These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+   */
+  @Structure.FieldOrder({"bits"})
+  class StyleAlignFlags extends Structure {
+    public byte bits;
+
+    public static class ByReference extends StyleAlignFlags implements Structure.ByReference {}
+
+    public static class ByValue extends StyleAlignFlags implements Structure.ByValue {}
+  }
+
+  void root(StyleAlignFlags.ByValue flags);
+}
@@ -0,0 +1,39 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const StyleAlignFlags = StructType();
+
+StyleAlignFlags.defineProperty('bits', 'uint8');
+
+const StyleAlignFlags_AUTO = StyleAlignFlags({ bits: 0 });
+
+const StyleAlignFlags_NORMAL = StyleAlignFlags({ bits: 1 });
+
+const StyleAlignFlags_START = StyleAlignFlags({ bits: 1 << 1 });
+
+const StyleAlignFlags_END = StyleAlignFlags({ bits: 1 << 2 });
+
+const StyleAlignFlags_FLEX_START = StyleAlignFlags({ bits: 1 << 3 });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [StyleAlignFlags]],
+  });
+  return lib;
+}
+
+module.exports = {
+  StyleAlignFlags,
+  StyleAlignFlags_AUTO,
+  StyleAlignFlags_NORMAL,
+  StyleAlignFlags_START,
+  StyleAlignFlags_END,
+  StyleAlignFlags_FLEX_START,
+  load,
+};
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # Constants shared by multiple CSS Box Alignment properties
+  # These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+  ctypedef struct StyleAlignFlags:
+    uint8_t bits
+  # 'auto'
+  const StyleAlignFlags StyleAlignFlags_AUTO
+  # 'normal'
+  const StyleAlignFlags StyleAlignFlags_NORMAL
+  # 'start'
+  const StyleAlignFlags StyleAlignFlags_START
+  # 'end'
+  const StyleAlignFlags StyleAlignFlags_END
+  # 'flex-start'
+  const StyleAlignFlags StyleAlignFlags_FLEX_START
+
+  void root(StyleAlignFlags flags)
@@ -0,0 +1,28 @@
+import ctypes
+
+# Constants shared by multiple CSS Box Alignment properties
+# These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+class StyleAlignFlags(ctypes.Structure):
+  pass
+
+StyleAlignFlags._fields_ = [
+  ("bits", ctypes.c_uint8),
+]
+# 'auto'
+StyleAlignFlags_AUTO = StyleAlignFlags(bits=0)
+# 'normal'
+StyleAlignFlags_NORMAL = StyleAlignFlags(bits=1)
+# 'start'
+StyleAlignFlags_START = StyleAlignFlags(bits=1 << 1)
+# 'end'
+StyleAlignFlags_END = StyleAlignFlags(bits=1 << 2)
+# 'flex-start'
+StyleAlignFlags_FLEX_START = StyleAlignFlags(bits=1 << 3)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [StyleAlignFlags]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,24 @@
+const std = @import("std");
+
+/// Constants shared by multiple CSS Box Alignment properties
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub const StyleAlignFlags = extern struct {
+  bits: u8,
+};
+
+/// 'auto'
+pub const StyleAlignFlags_AUTO: StyleAlignFlags = StyleAlignFlags{ .bits = 0 };
+
+/// 'normal'
+pub const StyleAlignFlags_NORMAL: StyleAlignFlags = StyleAlignFlags{ .bits = 1 };
+
+/// 'start'
+pub const StyleAlignFlags_START: StyleAlignFlags = StyleAlignFlags{ .bits = (1 << 1) };
+
+/// 'end'
+pub const StyleAlignFlags_END: StyleAlignFlags = StyleAlignFlags{ .bits = (1 << 2) };
+
+/// 'flex-start'
+pub const StyleAlignFlags_FLEX_START: StyleAlignFlags = StyleAlignFlags{ .bits = (1 << 3) };
+
+pub extern fn root(flags: StyleAlignFlags) void;
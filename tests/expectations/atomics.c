@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t value;
+} Node;
+
+typedef struct {
+  _Atomic uintptr_t head;
+  _Atomic uintptr_t tail;
+  _Atomic uint32_t len;
+  _Atomic bool closed;
+  Node *_Atomic first;
+} Queue;
+
+uint32_t queue_len(const Queue *queue, const _Atomic uint32_t *hint);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t value;
+} Node;
+
+typedef struct {
+  uintptr_t head;
+  uintptr_t tail;
+  uint32_t len;
+  bool closed;
+  Node *first;
+} Queue;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t queue_len(const Queue *queue, const uint32_t *hint);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <atomic>
+
+struct Node {
+  uint32_t value;
+};
+
+struct Queue {
+  std::atomic<uintptr_t> head;
+  std::atomic<uintptr_t> tail;
+  std::atomic<uint32_t> len;
+  std::atomic<bool> closed;
+  std::atomic<Node*> first;
+};
+
+extern "C" {
+
+uint32_t queue_len(const Queue *queue, const std::atomic<uint32_t> *hint);
+
+} // extern "C"
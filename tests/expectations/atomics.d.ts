@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Node {
+  value: number;
+  ref(): Buffer;
+}
+export declare const Node: StructType<Node>;
+
+export interface Queue {
+  head: number | string;
+  tail: number | string;
+  len: number;
+  closed: boolean;
+  first: Buffer;
+  ref(): Buffer;
+}
+export declare const Queue: StructType<Queue>;
+
+export interface Library {
+  queue_len(queue: Buffer | null, hint: Buffer | null): number;
+}
+
+export declare function load(path: string): Library;
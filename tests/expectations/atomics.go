@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Node = C.Node
+
+type Queue = C.Queue
+
+func QueueLen(queue *Queue, hint *uint32) uint32 {
+  return uint32(C.queue_len(queue, (*C.uint32_t)(unsafe.Pointer(hint))))
+}
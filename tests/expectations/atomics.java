@@ -0,0 +1,37 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"value"})
+  class Node extends Structure {
+    public int value;
+
+    public static class ByReference extends Node implements Structure.ByReference {}
+
+    public static class ByValue extends Node implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"head", "tail", "len", "closed", "first"})
+  class Queue extends Structure {
+    public SizeT head;
+    public SizeT tail;
+    public int len;
+    public byte closed;
+    public Node.ByReference first;
+
+    public static class ByReference extends Queue implements Structure.ByReference {}
+
+    public static class ByValue extends Queue implements Structure.ByValue {}
+  }
+
+  int queue_len(Queue.ByReference queue, Pointer hint);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
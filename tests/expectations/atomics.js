@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Node = StructType();
+const Queue = StructType();
+
+Node.defineProperty('value', 'uint32');
+
+Queue.defineProperty('head', 'size_t');
+Queue.defineProperty('tail', 'size_t');
+Queue.defineProperty('len', 'uint32');
+Queue.defineProperty('closed', 'bool');
+Queue.defineProperty('first', ref.refType(Node));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    queue_len: ['uint32', [ref.refType(Queue), ref.refType('uint32')]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Node,
+  Queue,
+  load,
+};
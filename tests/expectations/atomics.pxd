@@ -0,0 +1,20 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Node:
+    uint32_t value
+
+  ctypedef struct Queue:
+    uintptr_t head
+    uintptr_t tail
+    uint32_t len
+    bool closed
+    Node *first
+
+  uint32_t queue_len(const Queue *queue, const uint32_t *hint)
@@ -0,0 +1,27 @@
+import ctypes
+
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("value", ctypes.c_uint32),
+]
+
+class Queue(ctypes.Structure):
+  pass
+
+Queue._fields_ = [
+  ("head", ctypes.c_size_t),
+  ("tail", ctypes.c_size_t),
+  ("len", ctypes.c_uint32),
+  ("closed", ctypes.c_bool),
+  ("first", ctypes.POINTER(Node)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.queue_len.argtypes = [ctypes.POINTER(Queue), ctypes.POINTER(ctypes.c_uint32)]
+  lib.queue_len.restype = ctypes.c_uint32
+
+  return lib
@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Node = extern struct {
+  value: u32,
+};
+
+pub const Queue = extern struct {
+  head: usize,
+  tail: usize,
+  len: u32,
+  closed: bool,
+  first: [*c]Node,
+};
+
+pub extern fn queue_len(queue: [*c]const Queue, hint: [*c]const u32) u32;
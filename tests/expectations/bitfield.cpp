@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct TooWide;
+
+struct Status {
+  bool ready : 1;
+  uint8_t code : 7;
+};
+
+/// A control register.
+struct Control {
+  /// The operating mode.
+  uint32_t mode : 3;
+  uint32_t prescaler : 5;
+  uint32_t reload : 24;
+};
+
+extern "C" {
+
+Status configure(Control control);
+
+void validate(const TooWide *value);
+
+} // extern "C"
@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type TooWide = C.TooWide
+
+type Status = C.Status
+
+// A control register.
+type Control = C.Control
+
+func Configure(control Control) Status {
+  return C.configure(control)
+}
+
+func Validate(value *TooWide) {
+  C.validate(value)
+}
@@ -0,0 +1,7 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  Pointer configure(Pointer control);
+
+  void validate(Pointer value);
+}
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct TooWide:
+    pass
+
+  ctypedef struct Status:
+    bool ready
+    uint8_t code
+
+  # A control register.
+  ctypedef struct Control:
+    # The operating mode.
+    uint32_t mode
+    uint32_t prescaler
+    uint32_t reload
+
+  Status configure(Control control)
+
+  void validate(const TooWide *value)
@@ -0,0 +1,34 @@
+import ctypes
+
+class TooWide(ctypes.Structure):
+  pass
+
+class Status(ctypes.Structure):
+  pass
+
+Status._fields_ = [
+  ("ready", ctypes.c_bool, 1),
+  ("code", ctypes.c_uint8, 7),
+]
+
+# A control register.
+class Control(ctypes.Structure):
+  pass
+
+Control._fields_ = [
+  # The operating mode.
+  ("mode", ctypes.c_uint32, 3),
+  ("prescaler", ctypes.c_uint32, 5),
+  ("reload", ctypes.c_uint32, 24),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.configure.argtypes = [Control]
+  lib.configure.restype = Status
+
+  lib.validate.argtypes = [ctypes.POINTER(TooWide)]
+  lib.validate.restype = None
+
+  return lib
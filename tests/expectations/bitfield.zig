@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const TooWide = opaque {};
+
+pub const Status = packed struct(u8) {
+  ready: bool,
+  code: u7,
+};
+
+/// A control register.
+pub const Control = packed struct(u32) {
+  /// The operating mode.
+  mode: u3,
+  prescaler: u5,
+  reload: u24,
+};
+
+pub extern fn configure(control: Control) Status;
+
+pub extern fn validate(value: ?*const TooWide) void;
@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * 'auto'
+ */
+export declare const AlignFlags_AUTO: AlignFlags;
+
+/**
+ * 'normal'
+ */
+export declare const AlignFlags_NORMAL: AlignFlags;
+
+/**
+ * 'start'
+ */
+export declare const AlignFlags_START: AlignFlags;
+
+/**
+ * 'end'
+ */
+export declare const AlignFlags_END: AlignFlags;
+
+/**
+ * 'flex-start'
+ */
+export declare const AlignFlags_FLEX_START: AlignFlags;
+
+/**
+ * Constants shared by multiple CSS Box Alignment properties
+ * These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+ */
+export interface AlignFlags {
+  bits: number;
+  ref(): Buffer;
+}
+export declare const AlignFlags: StructType<AlignFlags>;
+
+export interface Library {
+  root(flags: AlignFlags): void;
+}
+
+export declare function load(path: string): Library;
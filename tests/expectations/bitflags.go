@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// Constants shared by multiple CSS Box Alignment properties
+// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+type AlignFlags = C.AlignFlags
+
+func Root(flags AlignFlags) {
+  C.root(flags)
+}
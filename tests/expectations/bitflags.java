@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * Constants shared by multiple CSS Box Alignment properties
+   * These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+   */
+  @Structure.FieldOrder({"bits"})
+  class AlignFlags extends Structure {
+    public byte bits;
+
+    public static class ByReference extends AlignFlags implements Structure.ByReference {}
+
+    public static class ByValue extends AlignFlags implements Structure.ByValue {}
+  }
+
+  void root(AlignFlags.ByValue flags);
+}
@@ -0,0 +1,39 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const AlignFlags = StructType();
+
+AlignFlags.defineProperty('bits', 'uint8');
+
+const AlignFlags_AUTO = AlignFlags({ bits: 0 });
+
+const AlignFlags_NORMAL = AlignFlags({ bits: 1 });
+
+const AlignFlags_START = AlignFlags({ bits: 1 << 1 });
+
+const AlignFlags_END = AlignFlags({ bits: 1 << 2 });
+
+const AlignFlags_FLEX_START = AlignFlags({ bits: 1 << 3 });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [AlignFlags]],
+  });
+  return lib;
+}
+
+module.exports = {
+  AlignFlags,
+  AlignFlags_AUTO,
+  AlignFlags_NORMAL,
+  AlignFlags_START,
+  AlignFlags_END,
+  AlignFlags_FLEX_START,
+  load,
+};
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # Constants shared by multiple CSS Box Alignment properties
+  # These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+  ctypedef struct AlignFlags:
+    uint8_t bits
+  # 'auto'
+  const AlignFlags AlignFlags_AUTO
+  # 'normal'
+  const AlignFlags AlignFlags_NORMAL
+  # 'start'
+  const AlignFlags AlignFlags_START
+  # 'end'
+  const AlignFlags AlignFlags_END
+  # 'flex-start'
+  const AlignFlags AlignFlags_FLEX_START
+
+  void root(AlignFlags flags)
@@ -0,0 +1,28 @@
+import ctypes
+
+# Constants shared by multiple CSS Box Alignment properties
+# These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+class AlignFlags(ctypes.Structure):
+  pass
+
+AlignFlags._fields_ = [
+  ("bits", ctypes.c_uint8),
+]
+# 'auto'
+AlignFlags_AUTO = AlignFlags(bits=0)
+# 'normal'
+AlignFlags_NORMAL = AlignFlags(bits=1)
+# 'start'
+AlignFlags_START = AlignFlags(bits=1 << 1)
+# 'end'
+AlignFlags_END = AlignFlags(bits=1 << 2)
+# 'flex-start'
+AlignFlags_FLEX_START = AlignFlags(bits=1 << 3)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [AlignFlags]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,24 @@
+const std = @import("std");
+
+/// Constants shared by multiple CSS Box Alignment properties
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub const AlignFlags = extern struct {
+  bits: u8,
+};
+
+/// 'auto'
+pub const AlignFlags_AUTO: AlignFlags = AlignFlags{ .bits = 0 };
+
+/// 'normal'
+pub const AlignFlags_NORMAL: AlignFlags = AlignFlags{ .bits = 1 };
+
+/// 'start'
+pub const AlignFlags_START: AlignFlags = AlignFlags{ .bits = (1 << 1) };
+
+/// 'end'
+pub const AlignFlags_END: AlignFlags = AlignFlags{ .bits = (1 << 2) };
+
+/// 'flex-start'
+pub const AlignFlags_FLEX_START: AlignFlags = AlignFlags{ .bits = (1 << 3) };
+
+pub extern fn root(flags: AlignFlags) void;
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t _0;
+} Permissions;
+#define Permissions_READ (Permissions){ ._0 = 1 << 0 }
+#define Permissions_WRITE (Permissions){ ._0 = 1 << 1 }
+#define Permissions_EXECUTE (Permissions){ ._0 = 1 << 2 }
+
+typedef uint8_t Modifiers;
+#define Modifiers_SHIFT 1
+#define Modifiers_CONTROL (1 << 1)
+#define Modifiers_ALT (1 << 2)
+
+typedef uint16_t Buttons;
+#define Buttons_LEFT 1
+#define Buttons_RIGHT (1 << 1)
+
+void root(Permissions permissions, Modifiers modifiers, Buttons buttons);
@@ -0,0 +1,44 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Permissions {
+  uint32_t _0;
+
+  explicit operator bool() const {
+    return !!_0;
+  }
+  Permissions operator|(const Permissions& other) const {
+    return {static_cast<decltype(_0)>(this->_0 | other._0)};
+  }
+  Permissions& operator|=(const Permissions& other) {
+    *this = (*this | other);
+    return *this;
+  }
+  Permissions operator&(const Permissions& other) const {
+    return {static_cast<decltype(_0)>(this->_0 & other._0)};
+  }
+  Permissions& operator&=(const Permissions& other) {
+    *this = (*this & other);
+    return *this;
+  }
+};
+static const Permissions Permissions_READ = (Permissions){ ._0 = 1 << 0 };
+static const Permissions Permissions_WRITE = (Permissions){ ._0 = 1 << 1 };
+static const Permissions Permissions_EXECUTE = (Permissions){ ._0 = 1 << 2 };
+
+using Modifiers = uint8_t;
+static const Modifiers Modifiers_SHIFT = 1;
+static const Modifiers Modifiers_CONTROL = 1 << 1;
+static const Modifiers Modifiers_ALT = 1 << 2;
+
+using Buttons = uint16_t;
+static const Buttons Buttons_LEFT = 1;
+static const Buttons Buttons_RIGHT = 1 << 1;
+
+extern "C" {
+
+void root(Permissions permissions, Modifiers modifiers, Buttons buttons);
+
+} // extern "C"
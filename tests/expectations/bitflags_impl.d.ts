@@ -0,0 +1,38 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Permissions_READ: Permissions;
+
+export declare const Permissions_WRITE: Permissions;
+
+export declare const Permissions_EXECUTE: Permissions;
+
+export declare const Modifiers_SHIFT: number;
+
+export declare const Modifiers_CONTROL: number;
+
+export declare const Modifiers_ALT: number;
+
+export declare const Buttons_LEFT: number;
+
+export declare const Buttons_RIGHT: number;
+
+export interface Permissions {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Permissions: StructType<Permissions>;
+
+export interface Library {
+  root(permissions: Permissions, modifiers: number, buttons: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Permissions = C.Permissions
+
+type Modifiers = C.Modifiers
+
+type Buttons = C.Buttons
+
+func Root(permissions Permissions, modifiers Modifiers, buttons Buttons) {
+  C.root(permissions, modifiers, buttons)
+}
@@ -0,0 +1,24 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"_0"})
+  class Permissions extends Structure {
+    public int _0;
+
+    public static class ByReference extends Permissions implements Structure.ByReference {}
+
+    public static class ByValue extends Permissions implements Structure.ByValue {}
+  }
+
+  byte Modifiers_SHIFT = 1;
+
+  byte Modifiers_CONTROL = 1 << 1;
+
+  byte Modifiers_ALT = 1 << 2;
+
+  short Buttons_LEFT = 1;
+
+  short Buttons_RIGHT = 1 << 1;
+
+  void root(Permissions.ByValue permissions, byte modifiers, short buttons);
+}
@@ -0,0 +1,48 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Permissions = StructType();
+
+Permissions.defineProperty('_0', 'uint32');
+
+const Permissions_READ = Permissions({ _0: 1 << 0 });
+
+const Permissions_WRITE = Permissions({ _0: 1 << 1 });
+
+const Permissions_EXECUTE = Permissions({ _0: 1 << 2 });
+
+const Modifiers_SHIFT = 1;
+
+const Modifiers_CONTROL = 1 << 1;
+
+const Modifiers_ALT = 1 << 2;
+
+const Buttons_LEFT = 1;
+
+const Buttons_RIGHT = 1 << 1;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Permissions, 'uint8', 'uint16']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Permissions,
+  Permissions_READ,
+  Permissions_WRITE,
+  Permissions_EXECUTE,
+  Modifiers_SHIFT,
+  Modifiers_CONTROL,
+  Modifiers_ALT,
+  Buttons_LEFT,
+  Buttons_RIGHT,
+  load,
+};
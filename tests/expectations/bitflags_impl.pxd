@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Permissions:
+    uint32_t _0
+  const Permissions Permissions_READ
+  const Permissions Permissions_WRITE
+  const Permissions Permissions_EXECUTE
+
+  ctypedef uint8_t Modifiers
+  const Modifiers Modifiers_SHIFT
+  const Modifiers Modifiers_CONTROL
+  const Modifiers Modifiers_ALT
+
+  ctypedef uint16_t Buttons
+  const Buttons Buttons_LEFT
+  const Buttons Buttons_RIGHT
+
+  void root(Permissions permissions, Modifiers modifiers, Buttons buttons)
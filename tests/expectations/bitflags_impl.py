@@ -0,0 +1,28 @@
+import ctypes
+
+class Permissions(ctypes.Structure):
+  pass
+
+Permissions._fields_ = [
+  ("_0", ctypes.c_uint32),
+]
+Permissions_READ = Permissions(_0=1 << 0)
+Permissions_WRITE = Permissions(_0=1 << 1)
+Permissions_EXECUTE = Permissions(_0=1 << 2)
+
+Modifiers = ctypes.c_uint8
+Modifiers_SHIFT = 1
+Modifiers_CONTROL = 1 << 1
+Modifiers_ALT = 1 << 2
+
+Buttons = ctypes.c_uint16
+Buttons_LEFT = 1
+Buttons_RIGHT = 1 << 1
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Permissions, Modifiers, Buttons]
+  lib.root.restype = None
+
+  return lib
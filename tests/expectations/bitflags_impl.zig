@@ -0,0 +1,27 @@
+const std = @import("std");
+
+pub const Permissions = extern struct {
+  _0: u32,
+};
+
+pub const Permissions_READ: Permissions = Permissions{ ._0 = (1 << 0) };
+
+pub const Permissions_WRITE: Permissions = Permissions{ ._0 = (1 << 1) };
+
+pub const Permissions_EXECUTE: Permissions = Permissions{ ._0 = (1 << 2) };
+
+pub const Modifiers = u8;
+
+pub const Modifiers_SHIFT: Modifiers = 1;
+
+pub const Modifiers_CONTROL: Modifiers = (1 << 1);
+
+pub const Modifiers_ALT: Modifiers = (1 << 2);
+
+pub const Buttons = u16;
+
+pub const Buttons_LEFT: Buttons = 1;
+
+pub const Buttons_RIGHT: Buttons = (1 << 1);
+
+pub extern fn root(permissions: Permissions, modifiers: Modifiers, buttons: Buttons) void;
@@ -0,0 +1,63 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MyCLikeEnum: {
+  readonly Foo1: 0;
+  readonly Bar1: 1;
+  readonly Baz1: 2;
+};
+
+export declare const MyFancyEnum_Tag: {
+  readonly Foo: 0;
+  readonly Bar: 1;
+  readonly Baz: 2;
+};
+
+export interface MyFancyStruct {
+  i: number;
+  ref(): Buffer;
+}
+export declare const MyFancyStruct: StructType<MyFancyStruct>;
+
+export interface Bar_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Bar_Body: StructType<Bar_Body>;
+
+export interface Baz_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Baz_Body: StructType<Baz_Body>;
+
+export interface MyFancyEnum {
+  tag: number;
+  variants: {
+    bar: Bar_Body;
+    baz: Baz_Body;
+  };
+  ref(): Buffer;
+}
+export declare const MyFancyEnum: StructType<MyFancyEnum>;
+
+export interface MyUnion {
+  f: number;
+  u: number;
+  ref(): Buffer;
+}
+export declare const MyUnion: StructType<MyUnion>;
+
+export interface Library {
+  root(s: MyFancyStruct, e: MyFancyEnum, c: number, u: MyUnion): void;
+}
+
+export declare function load(path: string): Library;
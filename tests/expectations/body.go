@@ -0,0 +1,34 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type MyCLikeEnum = C.MyCLikeEnum
+
+const (
+  Foo1 MyCLikeEnum = C.Foo1
+  Bar1 MyCLikeEnum = C.Bar1
+  Baz1 MyCLikeEnum = C.Baz1
+)
+
+type MyFancyStruct = C.MyFancyStruct
+
+type MyFancyEnum_Tag = C.MyFancyEnum_Tag
+
+const (
+  Foo MyFancyEnum_Tag = C.Foo
+  Bar MyFancyEnum_Tag = C.Bar
+  Baz MyFancyEnum_Tag = C.Baz
+)
+
+type Bar_Body = C.Bar_Body
+
+type Baz_Body = C.Baz_Body
+
+type MyFancyEnum = C.MyFancyEnum
+
+type MyUnion = C.MyUnion
+
+func Root(s MyFancyStruct, e MyFancyEnum, c MyCLikeEnum, u MyUnion) {
+  C.root(s, e, c, u)
+}
@@ -0,0 +1,72 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface MyCLikeEnum {
+    int Foo1 = 0;
+    int Bar1 = 1;
+    int Baz1 = 2;
+  }
+
+  @Structure.FieldOrder({"i"})
+  class MyFancyStruct extends Structure {
+    public int i;
+
+    public static class ByReference extends MyFancyStruct implements Structure.ByReference {}
+
+    public static class ByValue extends MyFancyStruct implements Structure.ByValue {}
+  }
+
+  interface MyFancyEnum_Tag {
+    int Foo = 0;
+    int Bar = 1;
+    int Baz = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Bar_Body extends Structure {
+    public int _0;
+
+    public static class ByReference extends Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Bar_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Baz_Body extends Structure {
+    public int _0;
+
+    public static class ByReference extends Baz_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Baz_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class MyFancyEnum extends Structure {
+    public static class Variants extends Union {
+      public Bar_Body bar;
+      public Baz_Body baz;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends MyFancyEnum implements Structure.ByReference {}
+
+    public static class ByValue extends MyFancyEnum implements Structure.ByValue {}
+  }
+
+  class MyUnion extends Union {
+    public float f;
+    public int u;
+
+    public static class ByReference extends MyUnion implements Structure.ByReference {}
+
+    public static class ByValue extends MyUnion implements Structure.ByValue {}
+  }
+
+  void root(MyFancyStruct.ByValue s, MyFancyEnum.ByValue e, int c, MyUnion.ByValue u);
+}
@@ -0,0 +1,60 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const MyFancyStruct = StructType();
+const Bar_Body = StructType();
+const Baz_Body = StructType();
+const _MyFancyEnum_Variants = UnionType();
+const MyFancyEnum = StructType();
+const MyUnion = UnionType();
+
+MyFancyStruct.defineProperty('i', 'int32');
+
+Bar_Body.defineProperty('_0', 'int32');
+
+Baz_Body.defineProperty('_0', 'int32');
+
+_MyFancyEnum_Variants.defineProperty('bar', Bar_Body);
+_MyFancyEnum_Variants.defineProperty('baz', Baz_Body);
+
+MyFancyEnum.defineProperty('tag', 'int');
+MyFancyEnum.defineProperty('variants', _MyFancyEnum_Variants);
+
+MyUnion.defineProperty('f', 'float');
+MyUnion.defineProperty('u', 'uint32');
+
+const MyCLikeEnum = Object.freeze({
+  Foo1: 0,
+  Bar1: 1,
+  Baz1: 2,
+});
+
+const MyFancyEnum_Tag = Object.freeze({
+  Foo: 0,
+  Bar: 1,
+  Baz: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [MyFancyStruct, MyFancyEnum, 'int', MyUnion]],
+  });
+  return lib;
+}
+
+module.exports = {
+  MyFancyStruct,
+  Bar_Body,
+  Baz_Body,
+  MyFancyEnum,
+  MyUnion,
+  MyCLikeEnum,
+  MyFancyEnum_Tag,
+  load,
+};
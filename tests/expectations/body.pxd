@@ -0,0 +1,38 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum MyCLikeEnum:
+    Foo1
+    Bar1
+    Baz1
+
+  ctypedef struct MyFancyStruct:
+    int32_t i
+
+  ctypedef enum MyFancyEnum_Tag:
+    Foo
+    Bar
+    Baz
+
+  ctypedef struct Bar_Body:
+    int32_t _0
+
+  ctypedef struct Baz_Body:
+    int32_t _0
+
+  ctypedef struct MyFancyEnum:
+    MyFancyEnum_Tag tag
+    Bar_Body bar
+    Baz_Body baz
+
+  ctypedef union MyUnion:
+    float f
+    uint32_t u
+
+  void root(MyFancyStruct s, MyFancyEnum e, MyCLikeEnum c, MyUnion u)
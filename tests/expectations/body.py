@@ -0,0 +1,65 @@
+import ctypes
+
+MyCLikeEnum = ctypes.c_int
+Foo1 = 0
+Bar1 = 1
+Baz1 = 2
+
+class MyFancyStruct(ctypes.Structure):
+  pass
+
+MyFancyStruct._fields_ = [
+  ("i", ctypes.c_int32),
+]
+
+MyFancyEnum_Tag = ctypes.c_int
+Foo = 0
+Bar = 1
+Baz = 2
+
+class Bar_Body(ctypes.Structure):
+  pass
+
+Bar_Body._fields_ = [
+  ("_0", ctypes.c_int32),
+]
+
+class Baz_Body(ctypes.Structure):
+  pass
+
+Baz_Body._fields_ = [
+  ("_0", ctypes.c_int32),
+]
+
+class _MyFancyEnum_Variants(ctypes.Union):
+  pass
+
+_MyFancyEnum_Variants._fields_ = [
+  ("bar", Bar_Body),
+  ("baz", Baz_Body),
+]
+
+class MyFancyEnum(ctypes.Structure):
+  pass
+
+MyFancyEnum._anonymous_ = ("_variants",)
+MyFancyEnum._fields_ = [
+  ("tag", MyFancyEnum_Tag),
+  ("_variants", _MyFancyEnum_Variants),
+]
+
+class MyUnion(ctypes.Union):
+  pass
+
+MyUnion._fields_ = [
+  ("f", ctypes.c_float),
+  ("u", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [MyFancyStruct, MyFancyEnum, MyCLikeEnum, MyUnion]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,40 @@
+const std = @import("std");
+
+pub const MyCLikeEnum = enum(c_int) {
+  Foo1,
+  Bar1,
+  Baz1,
+};
+
+pub const MyFancyStruct = extern struct {
+  i: i32,
+};
+
+pub const MyFancyEnum_Tag = enum(c_int) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Bar_Body = extern struct {
+  _0: i32,
+};
+
+pub const Baz_Body = extern struct {
+  _0: i32,
+};
+
+pub const MyFancyEnum = extern struct {
+  tag: MyFancyEnum_Tag,
+  body: extern union {
+    bar: Bar_Body,
+    baz: Baz_Body,
+  },
+};
+
+pub const MyUnion = extern union {
+  f: f32,
+  u: u32,
+};
+
+pub extern fn root(s: MyFancyStruct, e: MyFancyEnum, c: MyCLikeEnum, u: MyUnion) void;
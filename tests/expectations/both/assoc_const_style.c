@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Foo {
+
+} Foo;
+static const int32_t FOO_GA = 10;
+static const char* const FOO_BU = "hello world";
+static const float FOO_ZO = 3.14f;
+
+void root(Foo x);
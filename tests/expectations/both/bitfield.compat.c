@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct TooWide TooWide;
+
+typedef struct Status {
+  bool ready : 1;
+  uint8_t code : 7;
+} Status;
+
+/**
+ * A control register.
+ */
+typedef struct Control {
+  /**
+   * The operating mode.
+   */
+  uint32_t mode : 3;
+  uint32_t prescaler : 5;
+  uint32_t reload : 24;
+} Control;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Status configure(Control control);
+
+void validate(const TooWide *value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
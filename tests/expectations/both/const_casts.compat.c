@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define FLOAT 5.0f
+
+#define LEN 44
+
+#define NARROW 69
+
+#define MASKED ((1 << 8) | NARROW)
+
+#define NEG 5
+
+#define NEG_SUM (-(1 + 2))
+
+#define ROUNDED 3
+
+#define SATURATED 0
+
+#define SIGNED -56
+
+#define TRUNCATED 255
+
+#define UNSIGNED 4294967295U
+
+#define WIDE 74565
+
+typedef struct Buffer {
+  uint8_t data[LEN];
+  uint8_t tail[4];
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Buffer b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
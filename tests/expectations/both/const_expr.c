@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define FLAG_A (1 << 0)
+
+#define FLAG_B (1 << 1)
+
+#define FLAG_AB (FLAG_A | FLAG_B)
+
+#define BIG ((1 << 20) | FLAG_AB)
+
+#define HEADER_LEN 4
+
+#define MASK ((FLAG_AB & 255) ^ FLAG_B)
+
+#define SHIFTED 1099511627776LL
+
+#define NEG_AFTER_SHIFTED (-(SHIFTED + 1))
+
+#define NEG_SHIFTED -1099511627776LL
+
+#define PAYLOAD_LEN ((HEADER_LEN * 2) + 8)
+
+#define TOTAL_LEN ((HEADER_LEN + PAYLOAD_LEN) * 2)
+
+typedef struct Packet {
+  uint8_t header[8];
+  uint8_t payload[PAYLOAD_LEN];
+  uint16_t checksum[4];
+} Packet;
+
+void root(Packet p, const uint32_t (*frames)[10]);
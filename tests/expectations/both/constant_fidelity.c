@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <math.h>
+
+#define EMOJI U'\U0001F600'
+
+#define EURO U'\u20AC'
+
+#define E_ACUTE U'\u00E9'
+
+#define HALF 0.5f
+
+#define LARGE 1e300
+
+#define MAX_I64 9223372036854775807LL
+
+#define MAX_U64 18446744073709551615ULL
+
+#define MIN_I64 (-9223372036854775807LL - 1)
+
+#define NEG_INF -INFINITY
+
+#define NOT_A_NUMBER NAN
+
+#define NUL '\000'
+
+#define ONE 1.0f
+
+#define POS_INF INFINITY
+
+#define QUOTE '\''
+
+#define TAB '\t'
+
+void root(void);
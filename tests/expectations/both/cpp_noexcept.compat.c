@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_STDCALL
+#  if defined(_MSC_VER)
+#    define CBINDGEN_STDCALL __stdcall
+#  elif defined(__i386__)
+#    define CBINDGEN_STDCALL __attribute__((stdcall))
+#  else
+#    define CBINDGEN_STDCALL
+#  endif
+#endif
+
+#ifndef CBINDGEN_SYSTEM
+#  if defined(_WIN32)
+#    define CBINDGEN_SYSTEM CBINDGEN_STDCALL
+#  else
+#    define CBINDGEN_SYSTEM
+#  endif
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern void callback(uint32_t a);
+
+extern void callback_may_unwind(uint32_t a);
+
+uint32_t may_unwind(uint32_t a);
+
+void CBINDGEN_SYSTEM may_unwind_system(void);
+
+uint32_t no_unwind(uint32_t a);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
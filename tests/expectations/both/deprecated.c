@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum OldFlags {
+  A,
+  B,
+};
+typedef uint8_t OldFlags __attribute__((deprecated("Use Flags instead")));
+
+typedef enum __attribute__((deprecated("Use \"Mode\" instead"))) OldMode {
+  Fast,
+  Slow,
+} OldMode;
+
+typedef struct __attribute__((deprecated)) OldPoint {
+  int32_t x;
+  int32_t y;
+} OldPoint;
+
+enum OldShape_Tag {
+  Circle,
+  Square,
+};
+typedef uint8_t OldShape_Tag;
+
+typedef struct Circle_Body {
+  OldShape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef struct Square_Body {
+  OldShape_Tag tag;
+  float _0;
+} Square_Body;
+
+typedef union __attribute__((deprecated)) OldShape {
+  OldShape_Tag tag;
+  Circle_Body circle;
+  Square_Body square;
+} OldShape;
+
+__attribute__((deprecated("Use new_point instead"))) int32_t old_point(void);
+
+__attribute__((deprecated))
+void old_root(const OldPoint *a,
+              OldMode b,
+              OldFlags c,
+              const OldShape *d);
@@ -0,0 +1,75 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 18446744073709551615ULL
+
+/**
+ * The direction of a move.
+ */
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+typedef bool (*Callback)(uint32_t version, char *data);
+
+typedef struct Options {
+  /**
+   * Fields named after D keywords are escaped.
+   */
+  uint32_t module;
+  long version;
+  Callback callback;
+  const char *name;
+  uint8_t grid[3][2];
+} Options;
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Moved,
+  Renamed,
+  Closed,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+typedef struct Moved_Body {
+  Direction _0;
+} Moved_Body;
+
+typedef struct Renamed_Body {
+  const char *name;
+} Renamed_Body;
+
+typedef struct Event {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+    Renamed_Body renamed;
+  };
+} Event;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+void process(const Options *options, Event event, int64_t *ref_, uint8_t *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Copies the contents of a {@link Buffer} into `dst`, see {@link buffer_len()}.
+ * @param buffer The {@link Buffer buffer} to copy from.
+ * @param dst The destination, which is
+ * at least {@link buffer_len} bytes long.
+ * @return The number of bytes copied.
+ * @warning `dst` must be valid for writes.
+ * # Examples
+ * See the [docs](https://docs.rs).
+ */
+uintptr_t buffer_copy(const Buffer *buffer, uint8_t *dst);
+
+/**
+ * Returns the length of `buffer`.
+ */
+uintptr_t buffer_len(const Buffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,89 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green = 4,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+typedef enum Direction {
+  North,
+  South,
+} Direction;
+
+typedef enum Level {
+  Low,
+  High,
+} Level;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Point,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Circle_Body {
+  Shape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef union Shape {
+  Shape_Tag tag;
+  Circle_Body circle;
+} Shape;
+
+#define COLOR_FOR_EACH(V) \
+  V(Red) \
+  V(Green) \
+  V(Blue)
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+#define DIRECTION_FOR_EACH(V) \
+  V(North) \
+  V(South)
+
+static inline const char *Direction_to_string(Direction value) {
+  switch (value) {
+    case North: return "North";
+    case South: return "South";
+  }
+  return NULL;
+}
+
+#define LEVEL_FOR_EACH(V) \
+  V(Low) \
+  V(High)
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Color a, Direction b, Level c, Shape d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
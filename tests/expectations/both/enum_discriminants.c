@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Level {
+  Low = -1,
+  Medium,
+  High = 10,
+};
+typedef int8_t Level;
+
+enum Offset {
+  Back = -0x10,
+  Here,
+};
+typedef int8_t Offset;
+
+enum Status {
+  Ok = 0x0,
+  Pending = 0x1,
+  Failed = 0x10,
+  Aborted = 0x11,
+  Fatal = 0xFF,
+};
+typedef uint32_t Status;
+
+void root(Status status, Level level, Offset offset);
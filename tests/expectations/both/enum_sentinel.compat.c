@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Direction {
+  North,
+  South,
+
+  Sentinel /* this must be last for serialization purposes. */
+} Direction;
+
+enum Shade
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Light = 2,
+  Dark,
+};
+#ifndef __cplusplus
+typedef uint8_t Shade;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Direction direction, Shade shade);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
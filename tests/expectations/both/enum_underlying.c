@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Gets the default underlying type.
+ */
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+typedef uint16_t Color;
+
+enum Direction {
+  North,
+  East,
+  South,
+  West,
+};
+typedef uint8_t Direction;
+
+/**
+ * Keeps its `#[repr(u32)]`.
+ */
+enum Flavor {
+  Sweet,
+  Sour,
+};
+typedef uint32_t Flavor;
+
+enum Sign {
+  Negative = -1,
+  Zero = 0,
+  Positive = 1,
+};
+typedef int8_t Sign;
+
+void root(Color color, Direction direction, Sign sign, Flavor flavor);
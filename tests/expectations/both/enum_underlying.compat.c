@@ -0,0 +1,72 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Gets the default underlying type.
+ */
+enum Color
+#ifdef __cplusplus
+  : uint16_t
+#endif // __cplusplus
+ {
+  Red,
+  Green,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint16_t Color;
+#endif // __cplusplus
+
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  North,
+  East,
+  South,
+  West,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+/**
+ * Keeps its `#[repr(u32)]`.
+ */
+enum Flavor
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  Sweet,
+  Sour,
+};
+#ifndef __cplusplus
+typedef uint32_t Flavor;
+#endif // __cplusplus
+
+enum Sign
+#ifdef __cplusplus
+  : int8_t
+#endif // __cplusplus
+ {
+  Negative = -1,
+  Zero = 0,
+  Positive = 1,
+};
+#ifndef __cplusplus
+typedef int8_t Sign;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Color color, Direction direction, Sign sign, Flavor flavor);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
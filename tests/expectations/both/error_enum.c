@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum IoError {
+  NotFound,
+  PermissionDenied,
+  TimedOut = 10,
+} IoError;
+
+enum ParseError {
+  UnexpectedEof,
+  InvalidToken,
+};
+typedef uint8_t ParseError;
+
+static inline const char *parse_error_name(ParseError value) {
+  switch (value) {
+    case UnexpectedEof: return "UnexpectedEof";
+    case InvalidToken: return "InvalidToken";
+  }
+  return NULL;
+}
+
+void root(IoError io, ParseError parse);
+
+const char *io_error_name(IoError value);
@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum IoError {
+  NotFound,
+  PermissionDenied,
+  TimedOut = 10,
+} IoError;
+
+enum ParseError
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  UnexpectedEof,
+  InvalidToken,
+};
+#ifndef __cplusplus
+typedef uint8_t ParseError;
+#endif // __cplusplus
+
+static inline const char *parse_error_name(ParseError value) {
+  switch (value) {
+    case UnexpectedEof: return "UnexpectedEof";
+    case InvalidToken: return "InvalidToken";
+  }
+  return NULL;
+}
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(IoError io, ParseError parse);
+
+const char *io_error_name(IoError value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -3,10 +3,16 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Zero-sized fields elided: `1`.
+ */
 typedef struct TypedLength_f32__UnknownUnit {
   float _0;
 } TypedLength_f32__UnknownUnit;
 
+/**
+ * Zero-sized fields elided: `1`.
+ */
 typedef struct TypedLength_f32__LayoutUnit {
   float _0;
 } TypedLength_f32__LayoutUnit;
@@ -15,6 +21,9 @@ typedef TypedLength_f32__UnknownUnit Length_f32;
 
 typedef TypedLength_f32__LayoutUnit LayoutLength;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedSideOffsets2D_f32__UnknownUnit {
   float top;
   float right;
@@ -22,6 +31,9 @@ typedef struct TypedSideOffsets2D_f32__UnknownUnit {
   float left;
 } TypedSideOffsets2D_f32__UnknownUnit;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedSideOffsets2D_f32__LayoutUnit {
   float top;
   float right;
@@ -33,11 +45,17 @@ typedef TypedSideOffsets2D_f32__UnknownUnit SideOffsets2D_f32;
 
 typedef TypedSideOffsets2D_f32__LayoutUnit LayoutSideOffsets2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedSize2D_f32__UnknownUnit {
   float width;
   float height;
 } TypedSize2D_f32__UnknownUnit;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedSize2D_f32__LayoutUnit {
   float width;
   float height;
@@ -47,11 +65,17 @@ typedef TypedSize2D_f32__UnknownUnit Size2D_f32;
 
 typedef TypedSize2D_f32__LayoutUnit LayoutSize2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedPoint2D_f32__UnknownUnit {
   float x;
   float y;
 } TypedPoint2D_f32__UnknownUnit;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedPoint2D_f32__LayoutUnit {
   float x;
   float y;
@@ -61,11 +85,17 @@ typedef TypedPoint2D_f32__UnknownUnit Point2D_f32;
 
 typedef TypedPoint2D_f32__LayoutUnit LayoutPoint2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedRect_f32__UnknownUnit {
   TypedPoint2D_f32__UnknownUnit origin;
   TypedSize2D_f32__UnknownUnit size;
 } TypedRect_f32__UnknownUnit;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedRect_f32__LayoutUnit {
   TypedPoint2D_f32__LayoutUnit origin;
   TypedSize2D_f32__LayoutUnit size;
@@ -75,6 +105,9 @@ typedef TypedRect_f32__UnknownUnit Rect_f32;
 
 typedef TypedRect_f32__LayoutUnit LayoutRect;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
   float m11;
   float m12;
@@ -84,6 +117,9 @@ typedef struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
   float m32;
 } TypedTransform2D_f32__UnknownUnit__LayoutUnit;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 typedef struct TypedTransform2D_f32__LayoutUnit__UnknownUnit {
   float m11;
   float m12;
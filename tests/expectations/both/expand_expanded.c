@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Surface {
+  uint32_t id;
+} Surface;
+
+typedef struct Window {
+  uint32_t id;
+} Window;
+
+Surface surface_new(uint32_t id);
+
+Window window_new(uint32_t id);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Surface {
+  uint32_t id;
+} Surface;
+
+typedef struct Window {
+  uint32_t id;
+} Window;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Surface surface_new(uint32_t id);
+
+Window window_new(uint32_t id);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
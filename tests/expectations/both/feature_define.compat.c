@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(MYLIB_FEATURE_LEGACY)
+#define LEGACY_VERSION 2
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+typedef struct Speaker {
+  uint8_t volume;
+} Speaker;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(MYLIB_SERDE)
+void load(const uint8_t *data, uintptr_t len);
+#endif
+
+#if (defined(MYLIB_FEATURE_LEGACY) && defined(MYLIB_SERDE))
+void load_legacy(const uint8_t *data, uintptr_t len);
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+void play(Speaker speaker);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
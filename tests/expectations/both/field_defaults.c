@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Options {
+  int32_t level;
+  bool verbose;
+  float ratio;
+  const uint8_t *name;
+} Options;
+
+static inline Options options_default(void) {
+  Options value = {0};
+  value.level = 3;
+  value.verbose = true;
+  value.ratio = 0.5;
+  return value;
+}
+
+typedef struct Plain {
+  int32_t x;
+} Plain;
+
+void root(Options options, Plain plain);
@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Child Child;
+typedef struct Node Node;
+
+typedef struct Handle Handle;
+
+typedef struct Data {
+  uint8_t value;
+} Data;
+
+typedef struct Parent {
+  const Child *first_child;
+  uint32_t id;
+} Parent;
+
+struct Child {
+  const Parent *parent;
+  Child *next_sibling;
+  Data data;
+};
+
+struct Node {
+  Node *next;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void read(Data data);
+
+void walk(Parent parent, Node node, const Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
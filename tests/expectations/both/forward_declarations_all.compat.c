@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Data Data;
+typedef struct Child Child;
+typedef struct Node Node;
+typedef struct Parent Parent;
+
+typedef struct Handle Handle;
+
+struct Data {
+  uint8_t value;
+};
+
+struct Child {
+  const Parent *parent;
+  Child *next_sibling;
+  Data data;
+};
+
+struct Node {
+  Node *next;
+};
+
+struct Parent {
+  const Child *first_child;
+  uint32_t id;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void read(Data data);
+
+void walk(Parent parent, Node node, const Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
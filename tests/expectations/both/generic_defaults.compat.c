@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Foo_u32 {
+  uint32_t a;
+} Foo_u32;
+
+typedef struct Foo_u8 {
+  uint8_t a;
+} Foo_u8;
+
+typedef struct Pair_i16__i16 {
+  int16_t key;
+  int16_t value;
+} Pair_i16__i16;
+
+typedef struct Pair_Foo_u32_____Foo_u32 {
+  Foo_u32 key;
+  Foo_u32 value;
+} Pair_Foo_u32_____Foo_u32;
+
+typedef Pair_Foo_u32_____Foo_u32 Bar_Foo_u32;
+
+typedef struct Pair_u8__u8 {
+  uint8_t key;
+  uint8_t value;
+} Pair_u8__u8;
+
+typedef struct Holder {
+  Foo_u32 foo;
+  Pair_u8__u8 pair;
+} Holder;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Foo_u32 a, Foo_u8 b, Pair_i16__i16 c, Bar_Foo_u32 d, Holder e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
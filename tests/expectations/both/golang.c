@@ -0,0 +1,60 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED true
+
+#define LIMIT 1024
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+};
+typedef uint8_t Direction;
+
+typedef enum Mode {
+  Fast,
+  Safe,
+} Mode;
+
+typedef struct Handle Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+typedef struct Options {
+  uint32_t size;
+  Callback callback;
+  const char *name;
+} Options;
+
+enum Event_Tag {
+  Moved,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct Moved_Body {
+  Direction _0;
+} Moved_Body;
+
+typedef struct Event {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+  };
+} Event;
+
+void *mylib_data(Handle *handle, int range);
+
+/**
+ * Opens a handle.
+ */
+Handle *mylib_open(const Options *options, Mode mode);
+
+int64_t mylib_read(Handle *handle, uint8_t *buf, uintptr_t len);
+
+bool mylib_send(Handle *handle, Event event, float scale);
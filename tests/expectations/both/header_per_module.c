@@ -0,0 +1,18 @@
+#ifndef HEADER_PER_MODULE_H
+#define HEADER_PER_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "header_per_module_geometry.c"
+#include "header_per_module_units.c"
+
+typedef struct Scene {
+  Rect bounds;
+  uint32_t count;
+} Scene;
+
+Rect scene_bounds(const Scene *scene);
+
+#endif /* HEADER_PER_MODULE_H */
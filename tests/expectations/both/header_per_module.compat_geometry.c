@@ -0,0 +1,30 @@
+#ifndef HEADER_PER_MODULE_H_GEOMETRY
+#define HEADER_PER_MODULE_H_GEOMETRY
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "header_per_module.compat_units.c"
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Rect {
+  Point origin;
+  Size size;
+} Rect;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float rect_area(const Rect *rect);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* HEADER_PER_MODULE_H_GEOMETRY */
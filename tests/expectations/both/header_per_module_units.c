@@ -0,0 +1,16 @@
+#ifndef HEADER_PER_MODULE_H_UNITS
+#define HEADER_PER_MODULE_H_UNITS
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Size {
+  float width;
+  float height;
+} Size;
+
+#define ZERO_SIZE (Size){ .width = 0.0, .height = 0.0 }
+
+#endif /* HEADER_PER_MODULE_H_UNITS */
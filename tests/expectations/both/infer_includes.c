@@ -0,0 +1,13 @@
+#include <stdbool.h>
+#include <stdint.h>
+#include <math.h>
+
+#define SCALE INFINITY
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+  bool visible;
+} Point;
+
+double area(const Point *p, uintptr_t len);
@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+static inline int32_t multiply(int32_t a, int32_t b) {
+  return a * b;
+}
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+static inline int32_t point_dot(const Point *a, const Point *b) {
+  int32_t x = a->x * b->x;
+  int32_t y = a->y * b->y;
+  return x + y;
+}
+
+/**
+ * Returns the horizontal coordinate of the point.
+ */
+static inline int32_t point_x(const Point *point) {
+  return point->x;
+}
+
+typedef struct Rect {
+  Point origin;
+  Point size;
+} Rect;
+
+static inline bool rect_is_empty(const Rect *rect) {
+  return rect->size.x == 0 || rect->size.y == 0;
+}
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t area(Rect rect);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
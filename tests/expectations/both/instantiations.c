@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Vec2_u32 {
+  uint32_t x;
+  uint32_t y;
+} Vec2_u32;
+
+typedef struct Vec2_f32 {
+  float x;
+  float y;
+} Vec2_f32;
+
+typedef struct Vec2_i32 {
+  int32_t x;
+  int32_t y;
+} Vec2_i32;
+
+typedef struct Vec2_f64 {
+  double x;
+  double y;
+} Vec2_f64;
+
+typedef struct Pair_u8__Vec2_f64 {
+  uint8_t first;
+  Vec2_f64 second;
+} Pair_u8__Vec2_f64;
+
+uint32_t length(Vec2_u32 v);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define KEY_BITS 128
+
+typedef struct Key {
+  unsigned __int128 id;
+  __int128 offset;
+} Key;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__int128 key_hash(const Key *key, unsigned __int128 seed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct I128 {
+  uint64_t lo;
+  int64_t hi;
+} I128;
+
+typedef struct U128 {
+  uint64_t lo;
+  uint64_t hi;
+} U128;
+
+typedef struct Key {
+  U128 id;
+  I128 offset;
+} Key;
+
+I128 key_hash(const Key *key, U128 seed);
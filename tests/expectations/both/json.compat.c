@@ -0,0 +1,75 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT (1 << 10)
+
+/**
+ * The direction of a move.
+ */
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down = 4,
+  Left,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+typedef bool (*Callback)(uintptr_t len);
+
+typedef struct Options {
+  uint32_t size;
+  double scale;
+  const char *name;
+  Callback callback;
+  uint8_t grid[3];
+} Options;
+
+typedef struct __attribute__((packed)) Header {
+  uint8_t tag;
+  uint32_t length;
+} Header;
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Moved,
+  Closed,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+typedef struct Moved_Body {
+  Direction _0;
+} Moved_Body;
+
+typedef struct Event {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+  };
+} Event;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+/**
+ * Processes an event.
+ */
+int32_t process(const Options *options, Header header, Event event);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define small_ 1
+
+typedef enum Mode {
+  near_,
+  stdin_,
+} Mode;
+
+typedef struct far_ {
+  int32_t delete_;
+  int32_t min_;
+} far_;
+
+typedef union Value {
+  int32_t class_;
+  float max_;
+} Value;
+
+void keyword_collisions(far_ new_, Value errno_, Mode mode);
@@ -0,0 +1,132 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+#ifndef CBINDGEN_NORETURN
+#  if defined(__cplusplus)
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#    define CBINDGEN_NORETURN _Noreturn
+#  elif defined(__GNUC__)
+#    define CBINDGEN_NORETURN __attribute__((noreturn))
+#  elif defined(_MSC_VER)
+#    define CBINDGEN_NORETURN __declspec(noreturn)
+#  else
+#    define CBINDGEN_NORETURN
+#  endif
+#endif
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue,
+} Color;
+
+enum Level {
+  Low,
+  High,
+};
+typedef uint8_t Level;
+
+typedef struct Engine Engine;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  float _0;
+} Circle_Body;
+
+typedef struct Rect_Body {
+  float width;
+  float height;
+} Rect_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+} Shape;
+
+typedef struct Pair_i32 {
+  int32_t first;
+  int32_t second;
+} Pair_i32;
+
+typedef struct Pair_Pair_i32 {
+  Pair_i32 first;
+  Pair_i32 second;
+} Pair_Pair_i32;
+
+typedef Pair_Pair_i32 Pairs;
+
+enum Token_Tag {
+  Number,
+  Eof,
+};
+typedef uint8_t Token_Tag;
+
+typedef struct Number_Body {
+  Token_Tag tag;
+  int32_t _0;
+} Number_Body;
+
+typedef union Token {
+  Token_Tag tag;
+  Number_Body number;
+} Token;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(Pair_i32) == 8, "unexpected size of Pair_i32");
+CBINDGEN_STATIC_ASSERT(offsetof(Pair_i32, first) == 0, "unexpected offset of Pair_i32::first");
+CBINDGEN_STATIC_ASSERT(offsetof(Pair_i32, second) == 4, "unexpected offset of Pair_i32::second");
+
+CBINDGEN_STATIC_ASSERT(sizeof(Pair_Pair_i32) == 16, "unexpected size of Pair_Pair_i32");
+CBINDGEN_STATIC_ASSERT(offsetof(Pair_Pair_i32, first) == 0, "unexpected offset of Pair_Pair_i32::first");
+CBINDGEN_STATIC_ASSERT(offsetof(Pair_Pair_i32, second) == 8, "unexpected offset of Pair_Pair_i32::second");
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+static inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Low: return "Low";
+    case High: return "High";
+  }
+  return NULL;
+}
+
+float area(Shape shape, Pairs pairs, Token token);
+
+Engine *engine_new(void);
+
+CBINDGEN_NORETURN void fatal(void);
+
+void old_api(Color color, Level level);
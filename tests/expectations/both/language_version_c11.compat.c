@@ -0,0 +1,66 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+#ifndef CBINDGEN_NORETURN
+#  if defined(__cplusplus)
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#    define CBINDGEN_NORETURN _Noreturn
+#  elif defined(__GNUC__)
+#    define CBINDGEN_NORETURN __attribute__((noreturn))
+#  elif defined(_MSC_VER)
+#    define CBINDGEN_NORETURN __declspec(noreturn)
+#  else
+#    define CBINDGEN_NORETURN
+#  endif
+#endif
+
+enum Level
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Low,
+  High,
+};
+#ifndef __cplusplus
+typedef uint8_t Level;
+#endif // __cplusplus
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(Point) == 8, "unexpected size of Point");
+CBINDGEN_STATIC_ASSERT(offsetof(Point, x) == 0, "unexpected offset of Point::x");
+CBINDGEN_STATIC_ASSERT(offsetof(Point, y) == 4, "unexpected offset of Point::y");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(Point point, Level level);
+
+CBINDGEN_NORETURN void fatal(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,67 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue
+} Color;
+
+enum Level {
+  Low,
+  High
+};
+typedef uint8_t Level;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  float _0;
+} Circle_Body;
+
+typedef struct Rect_Body {
+  float width;
+  float height;
+} Rect_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  } body;
+} Shape;
+
+enum Token_Tag {
+  Number,
+  Eof
+};
+typedef uint8_t Token_Tag;
+
+typedef struct Number_Body {
+  Token_Tag tag;
+  int32_t _0;
+} Number_Body;
+
+typedef union Token {
+  Token_Tag tag;
+  Number_Body number;
+} Token;
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+float area(Shape shape, Token token);
+
+void draw(Point point, Color color, Level level);
+
+__attribute__((noreturn)) void fatal(void);
@@ -0,0 +1,109 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_NORETURN
+#  if defined(__cplusplus)
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#    define CBINDGEN_NORETURN _Noreturn
+#  elif defined(__GNUC__)
+#    define CBINDGEN_NORETURN __attribute__((noreturn))
+#  elif defined(_MSC_VER)
+#    define CBINDGEN_NORETURN __declspec(noreturn)
+#  else
+#    define CBINDGEN_NORETURN
+#  endif
+#endif
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue
+} Color;
+
+enum Level
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Low,
+  High
+};
+#ifndef __cplusplus
+typedef uint8_t Level;
+#endif // __cplusplus
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Rect,
+  Empty
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Circle_Body {
+  float _0;
+} Circle_Body;
+
+typedef struct Rect_Body {
+  float width;
+  float height;
+} Rect_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  } body;
+} Shape;
+
+enum Token_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Number,
+  Eof
+};
+#ifndef __cplusplus
+typedef uint8_t Token_Tag;
+#endif // __cplusplus
+
+typedef struct Number_Body {
+  Token_Tag tag;
+  int32_t _0;
+} Number_Body;
+
+typedef union Token {
+  Token_Tag tag;
+  Number_Body number;
+} Token;
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float area(Shape shape, Token token);
+
+void draw(Point point, Color color, Level level);
+
+CBINDGEN_NORETURN void fatal(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
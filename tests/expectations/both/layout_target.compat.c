@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+typedef struct __attribute__((aligned(8))) Reader {
+  uint8_t _private[16];
+} Reader;
+
+typedef struct Sample {
+  long count;
+  wchar_t symbol;
+  const uint8_t *data;
+} Sample;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(Sample) == 24, "unexpected size of Sample");
+CBINDGEN_STATIC_ASSERT(offsetof(Sample, count) == 0, "unexpected offset of Sample::count");
+CBINDGEN_STATIC_ASSERT(offsetof(Sample, symbol) == 8, "unexpected offset of Sample::symbol");
+CBINDGEN_STATIC_ASSERT(offsetof(Sample, data) == 16, "unexpected offset of Sample::data");
+
+CBINDGEN_STATIC_ASSERT(sizeof(Reader) == 16, "unexpected size of Reader");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void reader_init(Reader *reader);
+
+void sample(Sample sample);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
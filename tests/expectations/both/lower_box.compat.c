@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Node {
+  int32_t value;
+} Node;
+
+typedef struct Tree {
+  /**
+   * Owned `Box`.
+   */
+  Node *root;
+  /**
+   * Owned `Box`, or null for `None`.
+   */
+  Node *left;
+} Tree;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * `tree`: Owned `Box`, or null for `None`.
+ */
+void tree_free(Tree *tree);
+
+/**
+ * `root`: Owned `Box`.
+ * Return value: Owned `Box`.
+ */
+Tree *tree_new(Node *root);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define DEFAULT_WIDTH 640
+
+#define MAX_WINDOWS 16
+
+#if defined(WITH_SURFACES)
+typedef struct Overlay {
+  uint32_t id;
+} Overlay;
+#endif
+
+typedef struct Surface {
+  uint32_t id;
+} Surface;
+
+typedef struct Window {
+  uint32_t id;
+} Window;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(WITH_SURFACES)
+Overlay overlay_new(uint32_t id);
+#endif
+
+Surface surface_new(uint32_t id);
+
+Window window_new(uint32_t id);
+
+float window_scale(const Window *this_);
+
+/**
+ * The width of the window.
+ */
+uint32_t window_width(const Window *this_);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counter Counter;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void counter_add(Counter *self, uint32_t amount);
+
+/**
+ * Returns the current count.
+ */
+uint32_t counter_get(const Counter *self);
+
+Counter *counter_new(void);
+
+float point_length(const Point *self);
+
+Point point_midpoint(const Point *self, const Point *other);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+enum Shape {
+  Line,
+  Polygon,
+};
+typedef uint8_t Shape;
+
+typedef struct Canvas Canvas;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+extern Shape DEFAULT_SHAPE;
+
+void canvas_add(Canvas *canvas, Point point, Shape shape);
+
+Canvas *canvas_new(void);
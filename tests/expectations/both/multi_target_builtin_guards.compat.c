@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(_WIN32)
+typedef struct Handle {
+  uint8_t *raw;
+} Handle;
+#endif
+
+#if (defined(__linux__) || defined(__APPLE__))
+typedef struct Handle {
+  int32_t fd;
+} Handle;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(__APPLE__)
+void apple_only(void);
+#endif
+
+void open_handle(Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
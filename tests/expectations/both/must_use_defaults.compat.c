@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Ok,
+  Failed,
+};
+#ifndef __cplusplus
+typedef uint8_t Status;
+#endif // __cplusplus
+
+typedef struct Handle {
+  uint32_t id;
+} Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__attribute__((warn_unused_result)) Status close(Handle handle);
+
+__attribute__((warn_unused_result)) uint32_t count(void);
+
+__attribute__((warn_unused_result)) Handle open(void);
+
+void reset(Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -3,9 +3,11 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+#define BAR "hello world"
+
 #define FOO 10
 
-#define ZOM 3.14
+#define ZOM 3.14f
 
 typedef struct Foo {
   int32_t x[FOO];
@@ -0,0 +1,43 @@
+#if !defined(__cplusplus) && !defined(__bool_true_false_are_defined)
+#define bool _Bool
+#endif
+
+#if !defined(INT8_MAX)
+typedef __INT64_TYPE__ int64_t;
+typedef __UINT8_TYPE__ uint8_t;
+typedef __UINTPTR_TYPE__ uintptr_t;
+#endif
+
+#if !defined(NAN)
+#define NAN __builtin_nanf("")
+#endif
+
+#define MISSING NAN
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Read,
+  Write,
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+  bool owned;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void fill(Buffer *buffer, Mode mode, int64_t value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle {
+  /**
+   * The index of the handle.
+   * Never `0`.
+   */
+  uint32_t index;
+  /**
+   * The generation of the handle, if it was ever reused.
+   * `0` means `None`.
+   */
+  uint32_t generation;
+  uint8_t *data;
+  void (*callback)(uintptr_t);
+  int64_t stamps[2];
+} Handle;
+
+/**
+ * `0` means `None`.
+ */
+typedef uint32_t MaybeId;
+
+extern const uint32_t LAST_ID;
+
+/**
+ * `key`: `0` means `None`.
+ * Return value: `0` means `None`.
+ */
+uint32_t find(const Handle *handle, uintptr_t key);
+
+void lookup(MaybeId id, Handle **out);
@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if !defined(__clang__)
+#  define _Nonnull
+#  define _Nullable
+#endif
+#ifndef NS_ASSUME_NONNULL_BEGIN
+#  if defined(__clang__)
+#    define NS_ASSUME_NONNULL_BEGIN _Pragma("clang assume_nonnull begin")
+#    define NS_ASSUME_NONNULL_END _Pragma("clang assume_nonnull end")
+#  else
+#    define NS_ASSUME_NONNULL_BEGIN
+#    define NS_ASSUME_NONNULL_END
+#  endif
+#endif
+
+NS_ASSUME_NONNULL_BEGIN
+
+#define Nil_ 0
+
+enum BOOL_
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  NO_,
+  YES_,
+};
+#ifndef __cplusplus
+typedef uint8_t BOOL_;
+#endif // __cplusplus
+
+typedef struct Class_ {
+  uint64_t size;
+} Class_;
+
+typedef struct Object {
+  uint64_t id_;
+  const Class_ *_Nullable isa;
+  const Class_ *_Nullable nil_;
+} Object;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+BOOL_ object_is_valid(const Object *_Nonnull object, BOOL_ *_Nullable id_);
+
+Object *_Nullable object_new(const Class_ *_Nonnull class_,
+                             void (*_Nullable SEL_)(Object*_Nullable));
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+NS_ASSUME_NONNULL_END
@@ -0,0 +1,76 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct __attribute__((aligned(16))) Context {
+  uint8_t _private[64];
+} Context;
+
+/**
+ * The size of a pointer differs between data models.
+ */
+#if UINTPTR_MAX == UINT64_MAX
+typedef struct __attribute__((aligned(8))) Handle {
+  uint8_t _private[16];
+} Handle;
+#elif UINTPTR_MAX == UINT32_MAX
+typedef struct __attribute__((aligned(4))) Handle {
+  uint8_t _private[8];
+} Handle;
+#else
+typedef struct Handle Handle;
+#endif
+
+/**
+ * Sorted by alignment, the fields need no padding.
+ */
+#if UINTPTR_MAX == UINT64_MAX
+typedef struct __attribute__((aligned(8))) Parser {
+  uint8_t _private[16];
+} Parser;
+#else
+typedef struct Parser Parser;
+#endif
+
+typedef struct Unknown Unknown;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(Context) == 64, "unexpected size of Context");
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Handle) == 16, "unexpected size of Handle");
+#elif UINTPTR_MAX == UINT32_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Handle) == 8, "unexpected size of Handle");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Parser) == 16, "unexpected size of Parser");
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void context_init(Context *context);
+
+void handle_init(Handle *handle);
+
+void parser_init(Parser *parser);
+
+Unknown *unknown_new(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,18 @@
+#ifndef OUTPUT_FILES_H
+#define OUTPUT_FILES_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle {
+  uint32_t id;
+} Handle;
+
+Handle handle_new(void);
+
+#include "output_files_internal.c"
+#include "output_files_testing.c"
+
+#endif /* OUTPUT_FILES_H */
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Foo {
+  int32_t a;
+} Foo;
+
+typedef struct Handles {
+  /**
+   * Wrapped in `Pin`.
+   */
+  Foo *pinned;
+  /**
+   * Wrapped in `NonNull`.
+   */
+  Foo *non_null;
+  /**
+   * Wrapped in `Unique`.
+   */
+  Foo *unique;
+  Foo *maybe;
+} Handles;
+
+/**
+ * `foo`: Wrapped in `Pin`.
+ * `handles`: Wrapped in `Pin`.
+ */
+void poll(Foo *foo, const Handles *handles);
+
+/**
+ * `foo`: Wrapped in `Unique`.
+ * Return value: Wrapped in `NonNull`.
+ */
+Foo *take(Foo *foo);
@@ -0,0 +1,58 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Object {
+  bool is_new;
+  uint32_t template_id;
+  uint32_t referenceCount;
+} Object;
+
+typedef struct Pair {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+typedef union Value {
+  int64_t as_int;
+  double real;
+} Value;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Circle_Body {
+  float r;
+} Circle_Body;
+
+typedef struct Square_Body {
+  float _0;
+} Square_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Object object, Pair pair, Value value, Shape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
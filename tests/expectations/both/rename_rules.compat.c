@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MY_MAX_POINTS 8
+
+enum my_Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  SHAPE_Circle,
+  SHAPE_Square,
+};
+#ifndef __cplusplus
+typedef uint8_t my_Shape;
+#endif // __cplusplus
+
+typedef struct my_Point {
+  int32_t x;
+  int32_t y;
+} my_Point;
+
+typedef struct Rectangle {
+  my_Point origin;
+  my_Point size;
+} Rectangle;
+
+typedef struct C_Kept {
+  int32_t x;
+} C_Kept;
+
+typedef struct my_Polygon {
+  my_Point points[MY_MAX_POINTS];
+} my_Polygon;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Rectangle a, C_Kept b, my_Shape c, my_Polygon d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct __attribute__((packed)) PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+} PackedHeader;
+
+#pragma pack(push, 2)
+typedef struct PackedPair {
+  uint8_t first;
+  uint64_t second;
+} PackedPair;
+#pragma pack(pop)
+
+typedef struct __attribute__((aligned(8))) AlignedId {
+  uint16_t id;
+} AlignedId;
+
+typedef struct __attribute__((aligned(16))) AlignedBlock {
+  uint8_t data[4];
+  PackedHeader header;
+} AlignedBlock;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(PackedHeader a, PackedPair b, AlignedId c, const AlignedBlock *d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
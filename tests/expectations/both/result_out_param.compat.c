@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Error {
+  NotFound,
+  Invalid,
+} Error;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Error parse_count(const uint8_t *text, uint32_t *out);
+
+Error parse_point(const uint8_t *text, Point *out);
+
+Error validate(const uint8_t *text);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,86 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Error {
+  NotFound,
+  Invalid,
+} Error;
+
+typedef enum Result_u32__Error_Tag {
+  Ok_u32__Error,
+  Err_u32__Error,
+} Result_u32__Error_Tag;
+
+typedef struct Ok_Body_u32__Error {
+  uint32_t _0;
+} Ok_Body_u32__Error;
+
+typedef struct Err_Body_u32__Error {
+  Error _0;
+} Err_Body_u32__Error;
+
+typedef struct Result_u32__Error {
+  Result_u32__Error_Tag tag;
+  union {
+    Ok_Body_u32__Error ok;
+    Err_Body_u32__Error err;
+  };
+} Result_u32__Error;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef enum Result_Point__Error_Tag {
+  Ok_Point__Error,
+  Err_Point__Error,
+} Result_Point__Error_Tag;
+
+typedef struct Ok_Body_Point__Error {
+  Point _0;
+} Ok_Body_Point__Error;
+
+typedef struct Err_Body_Point__Error {
+  Error _0;
+} Err_Body_Point__Error;
+
+typedef struct Result_Point__Error {
+  Result_Point__Error_Tag tag;
+  union {
+    Ok_Body_Point__Error ok;
+    Err_Body_Point__Error err;
+  };
+} Result_Point__Error;
+
+typedef enum VoidResult_Error_Tag {
+  Ok_Error,
+  Err_Error,
+} VoidResult_Error_Tag;
+
+typedef struct Err_Body_Error {
+  Error _0;
+} Err_Body_Error;
+
+typedef struct VoidResult_Error {
+  VoidResult_Error_Tag tag;
+  union {
+    Err_Body_Error err;
+  };
+} VoidResult_Error;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Result_u32__Error parse_count(const uint8_t *text);
+
+Result_Point__Error parse_point(const uint8_t *text);
+
+VoidResult_Error validate(const uint8_t *text);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* ---------------- Audio ---------------- */
+
+#define SAMPLE_RATE 48000
+
+/* ---------------- Constants ---------------- */
+
+#define VOLUME_MAX 100
+
+/* ---------------- Audio ---------------- */
+
+typedef enum Channel {
+  Left,
+  Right,
+} Channel;
+
+typedef void (*OnSamples)(const float *samples, uintptr_t len);
+
+typedef struct Stream {
+  Channel channel;
+  OnSamples on_samples;
+} Stream;
+
+/* ---------------- Enums ---------------- */
+
+typedef enum Mode {
+  Fast,
+  Slow,
+} Mode;
+
+/* ---------------- Typedefs ---------------- */
+
+typedef uint64_t Id;
+
+/* ---------------- Callbacks ---------------- */
+
+typedef void (*OnEvent)(uint32_t code);
+
+/* ---------------- Structs ---------------- */
+
+typedef struct Settings {
+  Mode mode;
+  Id id;
+  OnEvent on_event;
+} Settings;
+
+/* ---------------- Globals ---------------- */
+
+extern uint32_t LAST_ERROR;
+
+/* ---------------- Audio ---------------- */
+
+void open_stream(Stream *stream);
+
+/* ---------------- Functions ---------------- */
+
+void init(Settings settings);
+
+void shutdown(void);
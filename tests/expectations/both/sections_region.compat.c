@@ -0,0 +1,91 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#pragma region Constants
+
+#define VOLUME_MAX 100
+
+#pragma endregion
+
+#pragma region Audio
+
+#define SAMPLE_RATE 48000
+
+#pragma endregion
+
+#pragma region Enums
+
+typedef enum Mode {
+  Fast,
+  Slow,
+} Mode;
+
+#pragma endregion
+
+#pragma region Typedefs
+
+typedef uint64_t Id;
+
+#pragma endregion
+
+#pragma region Callbacks
+
+typedef void (*OnEvent)(uint32_t code);
+
+#pragma endregion
+
+#pragma region Structs
+
+typedef struct Settings {
+  Mode mode;
+  Id id;
+  OnEvent on_event;
+} Settings;
+
+#pragma endregion
+
+#pragma region Audio
+
+typedef enum Channel {
+  Left,
+  Right,
+} Channel;
+
+typedef void (*OnSamples)(const float *samples, uintptr_t len);
+
+typedef struct Stream {
+  Channel channel;
+  OnSamples on_samples;
+} Stream;
+
+#pragma endregion
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#pragma region Globals
+
+extern uint32_t LAST_ERROR;
+
+#pragma endregion
+
+#pragma region Functions
+
+void init(Settings settings);
+
+void shutdown(void);
+
+#pragma endregion
+
+#pragma region Audio
+
+void open_stream(Stream *stream);
+
+#pragma endregion
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
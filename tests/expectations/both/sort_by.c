@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ZETA 1
+
+#define ALPHA (ZETA + 1)
+
+#define MIDDLE 3
+
+typedef struct Zebra {
+  int32_t a;
+} Zebra;
+
+typedef struct Yak {
+  Zebra zebra;
+} Yak;
+
+typedef struct Aardvark {
+  float b;
+} Aardvark;
+
+extern uint32_t AGE;
+
+extern uint32_t WEIGHT;
+
+void zoo(Aardvark aardvark, Yak yak);
+
+void barn(const Zebra *zebra);
+
+void apiary(void);
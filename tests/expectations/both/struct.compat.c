@@ -10,6 +10,9 @@ typedef struct Normal {
   float y;
 } Normal;
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 typedef struct NormalWithZST {
   int32_t x;
   float y;
@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle {
+  uint32_t id;
+} Handle;
+
+enum Shape_Tag {
+  Empty,
+  Circle,
+  Rect,
+  Owned,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  float _0;
+} Circle_Body;
+
+typedef struct Rect_Body {
+  float width;
+  float height;
+} Rect_Body;
+
+typedef struct Owned_Body {
+  Handle _0;
+} Owned_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+    Owned_Body owned;
+  };
+} Shape;
+
+void root(const Shape *shape);
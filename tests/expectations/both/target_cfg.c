@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define WORD_BITS 64
+
+typedef struct Handle {
+  uint8_t *raw;
+} Handle;
+
+#if defined(WITH_LOGGING)
+typedef struct Logger {
+  uint8_t level;
+} Logger;
+#endif
+
+void open_handle(Handle *handle);
+
+#if defined(WITH_LOGGING)
+void set_logger(const Logger *logger);
+#endif
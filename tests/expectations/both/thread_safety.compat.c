@@ -0,0 +1,47 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_GUARDED_BY
+#  if defined(__clang__)
+#    define CBINDGEN_GUARDED_BY(x) __attribute__((guarded_by(x)))
+#  else
+#    define CBINDGEN_GUARDED_BY(x)
+#  endif
+#endif
+
+#ifndef CBINDGEN_REQUIRES
+#  if defined(__clang__)
+#    define CBINDGEN_REQUIRES(...) __attribute__((requires_capability(__VA_ARGS__)))
+#  else
+#    define CBINDGEN_REQUIRES(...)
+#  endif
+#endif
+
+typedef struct Mutex {
+  uint8_t *handle;
+} Mutex;
+
+typedef struct Counter {
+  Mutex lock;
+  uint64_t value CBINDGEN_GUARDED_BY(lock);
+  /**
+   * The number of updates.
+   */
+  uint32_t updates CBINDGEN_GUARDED_BY(lock);
+} Counter;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void counter_increment(Counter *counter) CBINDGEN_REQUIRES(counter->lock);
+
+void counter_merge(Counter *a, const Counter *b) CBINDGEN_REQUIRES(a->lock, b->lock);
+
+Counter counter_new(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counter {
+  uint32_t value;
+} Counter;
+
+Counter counter_new(void);
+
+void counter_reset(Counter *self);
+
+uint32_t counter_value(const Counter *self);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counter {
+  uint32_t value;
+} Counter;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Counter counter_new(void);
+
+void counter_reset(Counter *self);
+
+uint32_t counter_value(const Counter *self);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
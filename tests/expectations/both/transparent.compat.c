@@ -15,8 +15,14 @@ typedef DummyStruct TransparentComplexWrappingStructure;
 
 typedef uint32_t TransparentPrimitiveWrappingStructure;
 
+/**
+ * Zero-sized fields elided: `marker`.
+ */
 typedef DummyStruct TransparentComplexWrapper_i32;
 
+/**
+ * Zero-sized fields elided: `marker`.
+ */
 typedef uint32_t TransparentPrimitiveWrapper_i32;
 
 typedef uint32_t TransparentPrimitiveWithAssociatedConstants;
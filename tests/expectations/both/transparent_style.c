@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A handle kept distinct from other integers.
+ */
+typedef struct Handle {
+  uint64_t _0;
+} Handle;
+
+/**
+ * An identifier written as a typedef.
+ */
+typedef uint32_t Id;
+
+typedef struct Body {
+  Id id;
+  float height;
+  double weight;
+  const Handle *parent;
+} Body;
+
+float measure(Handle handle, Body *body);
+
+Handle open(Id id, float length);
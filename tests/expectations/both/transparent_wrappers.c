@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Slot {
+  /**
+   * Wrapped in `Cell`.
+   */
+  bool ready;
+  /**
+   * Wrapped in `MaybeUninit`.
+   */
+  uint64_t value;
+  uint8_t buffer[16];
+  /**
+   * Wrapped in `UnsafeCell`.
+   */
+  uint32_t state;
+  /**
+   * Wrapped in `ManuallyDrop`.
+   */
+  uint8_t *owner;
+} Slot;
+
+bool slot_take(Slot *slot, uint64_t *out);
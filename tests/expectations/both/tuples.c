@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Tuple_i32__i32 {
+  int32_t _0;
+  int32_t _1;
+} Tuple_i32__i32;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Tuple_Point__Point {
+  Point _0;
+  Point _1;
+} Tuple_Point__Point;
+
+typedef struct Segment {
+  Tuple_Point__Point ends;
+  float weight;
+} Segment;
+
+typedef struct Tuple_Segment__Segment {
+  Segment _0;
+  Segment _1;
+} Tuple_Segment__Segment;
+
+typedef struct Tuple_ConstPtr_u8_____usize {
+  const uint8_t *_0;
+  uintptr_t _1;
+} Tuple_ConstPtr_u8_____usize;
+
+typedef struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+} Tuple_u8__u16;
+
+typedef struct Wrapper_Tuple_u8__u16 {
+  Tuple_u8__u16 value;
+} Wrapper_Tuple_u8__u16;
+
+Tuple_i32__i32 div_mod(int32_t a, int32_t b);
+
+Tuple_Segment__Segment split(Segment s, Tuple_ConstPtr_u8_____usize bytes);
+
+void wrapped(Wrapper_Tuple_u8__u16 w);
@@ -10,6 +10,9 @@ typedef union Normal {
   float y;
 } Normal;
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 typedef union NormalWithZST {
   int32_t x;
   float y;
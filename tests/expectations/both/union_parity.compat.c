@@ -0,0 +1,63 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A union of two integers that doesn't pad `wide`.
+ */
+typedef union PACKED Packed {
+  /**
+   * The narrow view.
+   */
+  uint8_t narrow;
+  /**
+   * The wide view.
+   */
+  uint32_t wide;
+} Packed;
+
+typedef union ALIGNED(16) Aligned {
+  uint8_t bytes[4];
+  uint32_t word;
+} Aligned;
+
+typedef union Generic_f64 {
+  double asValue;
+  uint64_t asBits;
+} Generic_f64;
+
+typedef union Generic_u32 {
+  uint32_t asValue;
+  uint64_t asBits;
+} Generic_u32;
+
+typedef union __attribute__((deprecated("use Aligned"))) Old {
+  int32_t a;
+  float b;
+} Old;
+
+typedef union MUST_USE_UNION Checked {
+  int32_t a;
+  float b;
+} Checked;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Packed a, Aligned b, Generic_f64 c, Generic_u32 d, Old e, Checked f);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
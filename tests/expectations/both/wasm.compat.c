@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Filter
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  Nearest,
+  Linear,
+};
+#ifndef __cplusplus
+typedef uint32_t Filter;
+#endif // __cplusplus
+
+typedef struct Rgba {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+  uint8_t a;
+} Rgba;
+
+/**
+ * A single `f64`, passed as is.
+ */
+typedef struct Meters {
+  double _0;
+} Meters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint64_t FRAME_COUNT;
+
+Rgba average_color(void);
+
+uint32_t checksum(const uint8_t *data, uintptr_t len);
+
+Meters distance(Meters from, Meters to);
+
+/**
+ * Fills the `len` pixels at `pixels` with `color`.
+ */
+void fill(Rgba *pixels, uintptr_t len, Rgba color);
+
+extern void host_log(const uint8_t *message, uintptr_t len);
+
+extern double host_now(void);
+
+double sum_samples(const float *samples, uintptr_t count, Filter filter);
+
+int64_t timestamp(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
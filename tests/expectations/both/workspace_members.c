@@ -0,0 +1,18 @@
+#include "workspace_members_core.c"
+
+#ifndef WORKSPACE_MEMBERS_H
+#define WORKSPACE_MEMBERS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Player {
+  Format format;
+  Sample last;
+} Player;
+
+void play(Player *player, const Sample *samples, uintptr_t len);
+
+#endif /* WORKSPACE_MEMBERS_H */
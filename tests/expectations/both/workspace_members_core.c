@@ -0,0 +1,21 @@
+#ifndef WORKSPACE_MEMBERS_H_CORE
+#define WORKSPACE_MEMBERS_H_CORE
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Format {
+  Pcm,
+  Float,
+} Format;
+
+typedef struct Sample {
+  float left;
+  float right;
+} Sample;
+
+uint32_t core_version(void);
+
+#endif /* WORKSPACE_MEMBERS_H_CORE */
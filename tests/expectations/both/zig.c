@@ -0,0 +1,69 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define COUNT 3
+
+#define HALF (7 / 2)
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+};
+typedef uint8_t Direction;
+
+typedef struct Handle Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+typedef struct Options {
+  /**
+   * Fields named after Zig keywords are escaped.
+   */
+  uint32_t error;
+  int align;
+  Callback callback;
+  const char *name;
+  Handle *handle;
+  uint8_t grid[COUNT][2];
+} Options;
+
+typedef struct __attribute__((packed)) Packed {
+  uint8_t tag;
+  uint32_t value;
+} Packed;
+
+enum Event_Tag {
+  Moved,
+  Renamed,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct Moved_Body {
+  Direction _0;
+} Moved_Body;
+
+typedef struct Renamed_Body {
+  const char *name;
+} Renamed_Body;
+
+typedef struct Event {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+    Renamed_Body renamed;
+  };
+} Event;
+
+extern uint32_t COUNTER;
+
+void process(const Options *options,
+             Packed packed,
+             Event event,
+             const Handle *handle,
+             uint8_t *out);
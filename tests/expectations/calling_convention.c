@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_STDCALL
+#  if defined(_MSC_VER)
+#    define CBINDGEN_STDCALL __stdcall
+#  elif defined(__i386__)
+#    define CBINDGEN_STDCALL __attribute__((stdcall))
+#  else
+#    define CBINDGEN_STDCALL
+#  endif
+#endif
+
+#ifndef CBINDGEN_FASTCALL
+#  if defined(_MSC_VER)
+#    define CBINDGEN_FASTCALL __fastcall
+#  elif defined(__i386__)
+#    define CBINDGEN_FASTCALL __attribute__((fastcall))
+#  else
+#    define CBINDGEN_FASTCALL
+#  endif
+#endif
+
+#ifndef CBINDGEN_SYSTEM
+#  if defined(_WIN32)
+#    define CBINDGEN_SYSTEM CBINDGEN_STDCALL
+#  else
+#    define CBINDGEN_SYSTEM
+#  endif
+#endif
+
+typedef void (CBINDGEN_FASTCALL *FastcallCallback)(int32_t, int32_t);
+
+typedef int32_t (CBINDGEN_STDCALL *StdcallCallback)(int32_t);
+
+typedef struct {
+  bool (CBINDGEN_SYSTEM *system)(const uint8_t*);
+  void (*c)();
+} Callbacks;
+
+void c_fn(void (CBINDGEN_STDCALL *callback)(void));
+
+uint8_t *CBINDGEN_FASTCALL fastcall_fn(FastcallCallback callback);
+
+extern void CBINDGEN_STDCALL imported(uint32_t x);
+
+int32_t CBINDGEN_STDCALL stdcall_fn(int32_t a, StdcallCallback callback);
+
+void CBINDGEN_SYSTEM system_fn(Callbacks callbacks);
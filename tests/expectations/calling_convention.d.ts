@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Callbacks {
+  system: Buffer;
+  c: Buffer;
+  ref(): Buffer;
+}
+export declare const Callbacks: StructType<Callbacks>;
+
+export type FastcallCallback = Buffer;
+export declare const FastcallCallback: RefType;
+
+export type StdcallCallback = Buffer;
+export declare const StdcallCallback: RefType;
+
+export interface Library {
+  c_fn(callback: Buffer | null): void;
+
+  fastcall_fn(callback: Buffer): Buffer;
+
+  imported(x: number): void;
+
+  stdcall_fn(a: number, callback: Buffer): number;
+
+  system_fn(callbacks: Callbacks): void;
+}
+
+export declare function load(path: string): Library;
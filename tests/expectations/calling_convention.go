@@ -0,0 +1,32 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type FastcallCallback = C.FastcallCallback
+
+type StdcallCallback = C.StdcallCallback
+
+type Callbacks = C.Callbacks
+
+func CFn(callback *[0]byte) {
+  C.c_fn(callback)
+}
+
+func FastcallFn(callback FastcallCallback) *uint8 {
+  return (*uint8)(unsafe.Pointer(C.fastcall_fn(callback)))
+}
+
+func Imported(x uint32) {
+  C.imported(C.uint32_t(x))
+}
+
+func StdcallFn(a int32, callback StdcallCallback) int32 {
+  return int32(C.stdcall_fn(C.int32_t(a), callback))
+}
+
+func SystemFn(callbacks Callbacks) {
+  C.system_fn(callbacks)
+}
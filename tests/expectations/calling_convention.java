@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface FastcallCallback extends Callback {
+    void invoke(int arg0, int arg1);
+  }
+
+  interface StdcallCallback extends com.sun.jna.win32.StdCallLibrary.StdCallCallback {
+    int invoke(int arg0);
+  }
+
+  @Structure.FieldOrder({"system", "c"})
+  class Callbacks extends Structure {
+    public Pointer system;
+    public Pointer c;
+
+    public static class ByReference extends Callbacks implements Structure.ByReference {}
+
+    public static class ByValue extends Callbacks implements Structure.ByValue {}
+  }
+
+  void c_fn(Pointer callback);
+
+  Pointer fastcall_fn(FastcallCallback callback);
+
+  void imported(int x);
+
+  int stdcall_fn(int a, StdcallCallback callback);
+
+  void system_fn(Callbacks.ByValue callbacks);
+}
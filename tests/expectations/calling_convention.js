@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Callbacks = StructType();
+
+Callbacks.defineProperty('system', 'pointer');
+Callbacks.defineProperty('c', 'pointer');
+
+const FastcallCallback = 'pointer';
+
+const StdcallCallback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    c_fn: ['void', ['pointer']],
+    fastcall_fn: [ref.refType('uint8'), ['pointer']],
+    imported: ['void', ['uint32']],
+    stdcall_fn: ['int32', ['int32', 'pointer']],
+    system_fn: ['void', [Callbacks]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Callbacks,
+  FastcallCallback,
+  StdcallCallback,
+  load,
+};
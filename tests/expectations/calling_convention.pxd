@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef void (__fastcall *FastcallCallback)(int32_t, int32_t)
+
+  ctypedef int32_t (__stdcall *StdcallCallback)(int32_t)
+
+  ctypedef struct Callbacks:
+    bool (*system)(const uint8_t*)
+    void (*c)()
+
+  void c_fn(void (__stdcall *callback)())
+
+  uint8_t *__fastcall fastcall_fn(FastcallCallback callback)
+
+  void __stdcall imported(uint32_t x)
+
+  int32_t __stdcall stdcall_fn(int32_t a, StdcallCallback callback)
+
+  void system_fn(Callbacks callbacks)
@@ -0,0 +1,40 @@
+import ctypes
+
+# Only Windows has `stdcall` functions.
+WINFUNCTYPE = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)
+
+FastcallCallback = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32)
+
+StdcallCallback = WINFUNCTYPE(ctypes.c_int32, ctypes.c_int32)
+
+class Callbacks(ctypes.Structure):
+  pass
+
+Callbacks._fields_ = [
+  ("system", WINFUNCTYPE(ctypes.c_bool, ctypes.POINTER(ctypes.c_uint8))),
+  ("c", ctypes.CFUNCTYPE(None)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+  windll = getattr(ctypes, "WinDLL", ctypes.CDLL)(path)
+
+  lib.c_fn.argtypes = [WINFUNCTYPE(None)]
+  lib.c_fn.restype = None
+
+  lib.fastcall_fn.argtypes = [FastcallCallback]
+  lib.fastcall_fn.restype = ctypes.POINTER(ctypes.c_uint8)
+
+  lib.imported = windll.imported
+  lib.imported.argtypes = [ctypes.c_uint32]
+  lib.imported.restype = None
+
+  lib.stdcall_fn = windll.stdcall_fn
+  lib.stdcall_fn.argtypes = [ctypes.c_int32, StdcallCallback]
+  lib.stdcall_fn.restype = ctypes.c_int32
+
+  lib.system_fn = windll.system_fn
+  lib.system_fn.argtypes = [Callbacks]
+  lib.system_fn.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const FastcallCallback = ?*const fn (i32, i32) callconv(.Fastcall) void;
+
+pub const StdcallCallback = *const fn (i32) callconv(.Stdcall) i32;
+
+pub const Callbacks = extern struct {
+  system: *const fn ([*c]const u8) callconv(std.os.windows.WINAPI) bool,
+  c: *const fn () callconv(.C) void,
+};
+
+pub extern fn c_fn(callback: *const fn () callconv(.Stdcall) void) void;
+
+pub extern fn fastcall_fn(callback: FastcallCallback) callconv(.Fastcall) [*c]u8;
+
+pub extern fn imported(x: u32) callconv(.Stdcall) void;
+
+pub extern fn stdcall_fn(a: i32, callback: StdcallCallback) callconv(.Stdcall) i32;
+
+pub extern fn system_fn(callbacks: Callbacks) callconv(std.os.windows.WINAPI) void;
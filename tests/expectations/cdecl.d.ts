@@ -0,0 +1,63 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export type A = Buffer;
+export declare const A: RefType;
+
+export type B = Buffer;
+export declare const B: RefType;
+
+export type C = Buffer;
+export declare const C: RefType;
+
+export type D = Buffer;
+export declare const D: RefType;
+
+export type E = Buffer;
+export declare const E: RefType;
+
+export type F = Buffer;
+export declare const F: RefType;
+
+export type G = Buffer;
+export declare const G: RefType;
+
+export type H = Buffer;
+export declare const H: RefType;
+
+export type I = Buffer;
+export declare const I: RefType;
+
+export type J = Buffer;
+export declare const J: RefType;
+
+export type K = ArrayLike<number>;
+export declare const K: RefType;
+
+export type L = ArrayLike<Buffer>;
+export declare const L: RefType;
+
+export type M = ArrayLike<Buffer>;
+export declare const M: RefType;
+
+export type N = ArrayLike<Buffer>;
+export declare const N: RefType;
+
+export type P = Buffer;
+export declare const P: RefType;
+
+export interface Library {
+  O(): Buffer;
+
+  root(a: Buffer, b: Buffer, c: Buffer, d: Buffer, e: Buffer, f: Buffer | null, g: Buffer | null, h: Buffer | null, i: Buffer | null, j: Buffer | null, k: Buffer | null, l: Buffer | null, m: Buffer | null, n: Buffer | null, p: Buffer): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,40 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+type B = C.B
+
+type D = C.D
+
+type E = C.E
+
+type F = C.F
+
+type G = C.G
+
+type H = C.H
+
+type I = C.I
+
+type J = C.J
+
+type K = C.K
+
+type L = C.L
+
+type M = C.M
+
+type N = C.N
+
+type P = C.P
+
+func O() *[0]byte {
+  return C.O()
+}
+
+func Root(a A, b B, c C.C, d D, e E, f F, g G, h H, i I, j J, k K, l L, m M, n N, p P) {
+  C.root(a, b, c, d, e, f, g, h, i, j, k, l, m, n, p)
+}
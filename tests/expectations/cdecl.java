@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface A extends Callback {
+    void invoke();
+  }
+
+  interface B extends Callback {
+    void invoke();
+  }
+
+  interface C extends Callback {
+    byte invoke(int arg0, int arg1);
+  }
+
+  interface D extends Callback {
+    Pointer invoke(int arg0);
+  }
+
+  interface E extends Callback {
+    Pointer invoke();
+  }
+
+  interface P extends Callback {
+    void invoke(int named1st, byte arg1, byte named3rd, int __);
+  }
+
+  Pointer O();
+
+  void root(A a, B b, C c, D d, E e, Pointer f, Pointer g, Pointer h, Pointer i, Pointer j, Pointer k, Pointer l, Pointer m, Pointer n, P p);
+}
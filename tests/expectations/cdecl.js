@@ -0,0 +1,64 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const A = 'pointer';
+
+const B = 'pointer';
+
+const C = 'pointer';
+
+const D = 'pointer';
+
+const E = 'pointer';
+
+const F = ref.refType('int32');
+
+const G = ref.refType(ref.refType('int32'));
+
+const H = ref.refType(ref.refType('int32'));
+
+const I = ref.refType(ArrayType('int32', 16));
+
+const J = ref.refType('pointer');
+
+const K = ArrayType('int32', 16);
+
+const L = ArrayType(ref.refType('int32'), 16);
+
+const M = ArrayType('pointer', 16);
+
+const N = ArrayType('pointer', 16);
+
+const P = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    O: ['pointer', []],
+    root: ['void', ['pointer', 'pointer', 'pointer', 'pointer', 'pointer', ref.refType('int32'), ref.refType(ref.refType('int32')), ref.refType(ref.refType('int32')), ref.refType(ArrayType('int32', 16)), ref.refType('pointer'), ref.refType('int32'), ref.refType(ref.refType('int32')), ref.refType('pointer'), ref.refType('pointer'), 'pointer']],
+  });
+  return lib;
+}
+
+module.exports = {
+  A,
+  B,
+  C,
+  D,
+  E,
+  F,
+  G,
+  H,
+  I,
+  J,
+  K,
+  L,
+  M,
+  N,
+  P,
+  load,
+};
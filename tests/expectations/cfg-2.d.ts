@@ -0,0 +1,39 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(windows)
+export declare const DEFAULT_X: number;
+
+// cfg(any(windows, unix))
+export interface Foo {
+  x: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+// cfg(windows)
+export interface Bar {
+  y: Foo;
+  ref(): Buffer;
+}
+export declare const Bar: StructType<Bar>;
+
+export interface Root {
+  w: Bar;
+  ref(): Buffer;
+}
+export declare const Root: StructType<Root>;
+
+export interface Library {
+  root(a: Root): void;
+}
+
+export declare function load(path: string): Library;
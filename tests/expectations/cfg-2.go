@@ -0,0 +1,21 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(any(windows, unix))
+type Foo = C.Foo
+
+// cfg(windows)
+type Bar = C.Bar
+
+// cfg(unix)
+type Bar = C.Bar
+
+type Root = C.Root
+
+// cfg(windows)
+const DEFAULT_X = C.DEFAULT_X
+
+// cfg(unix)
+const DEFAULT_X = C.DEFAULT_X
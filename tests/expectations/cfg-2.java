@@ -0,0 +1,37 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(windows)
+  int DEFAULT_X = 8;
+
+  // cfg(any(windows, unix))
+  @Structure.FieldOrder({"x"})
+  class Foo extends Structure {
+    public int x;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  // cfg(windows)
+  @Structure.FieldOrder({"y"})
+  class Bar extends Structure {
+    public Foo y;
+
+    public static class ByReference extends Bar implements Structure.ByReference {}
+
+    public static class ByValue extends Bar implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"w"})
+  class Root extends Structure {
+    public Bar w;
+
+    public static class ByReference extends Root implements Structure.ByReference {}
+
+    public static class ByValue extends Root implements Structure.ByValue {}
+  }
+
+  void root(Root.ByValue a);
+}
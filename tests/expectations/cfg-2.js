@@ -0,0 +1,38 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+const Bar = StructType();
+const Root = StructType();
+
+// cfg(windows)
+const DEFAULT_X = 8;
+
+// cfg(any(windows, unix))
+Foo.defineProperty('x', 'int32');
+
+// cfg(windows)
+Bar.defineProperty('y', Foo);
+
+Root.defineProperty('w', Bar);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Root]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  Bar,
+  Root,
+  DEFAULT_X,
+  load,
+};
@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(windows)
+  const int32_t DEFAULT_X
+
+  # cfg(unix)
+  const int32_t DEFAULT_X
+
+  # cfg(any(windows, unix))
+  ctypedef struct Foo:
+    int32_t x
+
+  # cfg(windows)
+  ctypedef struct Bar:
+    Foo y
+
+  # cfg(unix)
+  ctypedef struct Bar:
+    Foo z
+
+  ctypedef struct Root:
+    Bar w
+
+  void root(Root a)
@@ -0,0 +1,46 @@
+import ctypes
+
+# cfg(windows)
+DEFAULT_X = 8
+
+# cfg(unix)
+DEFAULT_X = 42
+
+# cfg(any(windows, unix))
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+# cfg(windows)
+class Bar(ctypes.Structure):
+  pass
+
+Bar._fields_ = [
+  ("y", Foo),
+]
+
+# cfg(unix)
+class Bar(ctypes.Structure):
+  pass
+
+Bar._fields_ = [
+  ("z", Foo),
+]
+
+class Root(ctypes.Structure):
+  pass
+
+Root._fields_ = [
+  ("w", Bar),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Root]
+  lib.root.restype = None
+
+  return lib
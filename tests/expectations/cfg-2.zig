@@ -0,0 +1,28 @@
+const std = @import("std");
+
+// cfg(windows)
+pub const DEFAULT_X: i32 = 8;
+
+// cfg(unix)
+pub const DEFAULT_X: i32 = 42;
+
+// cfg(any(windows, unix))
+pub const Foo = extern struct {
+  x: i32,
+};
+
+// cfg(windows)
+pub const Bar = extern struct {
+  y: Foo,
+};
+
+// cfg(unix)
+pub const Bar = extern struct {
+  z: Foo,
+};
+
+pub const Root = extern struct {
+  w: Bar,
+};
+
+pub extern fn root(a: Root) void;
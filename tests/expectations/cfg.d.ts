@@ -0,0 +1,49 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(any(windows, target_pointer_width = "32"))
+export declare const BarType: {
+  readonly A: 0;
+  readonly B: 1;
+  readonly C: 2;
+};
+
+// cfg(all(unix, x11))
+export declare const FooType: {
+  readonly A: 0;
+  readonly B: 1;
+  readonly C: 2;
+};
+
+// cfg(all(unix, x11))
+export interface FooHandle {
+  ty: number;
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const FooHandle: StructType<FooHandle>;
+
+// cfg(any(windows, target_pointer_width = "32"))
+export interface BarHandle {
+  ty: number;
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const BarHandle: StructType<BarHandle>;
+
+export interface Library {
+  // cfg(all(unix, x11))
+  root(a: FooHandle): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,38 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(any(windows, target_pointer_width = "32"))
+type BarType = C.BarType
+
+const (
+  A BarType = C.A
+  B BarType = C.B
+  C BarType = C.C
+)
+
+// cfg(all(unix, x11))
+type FooType = C.FooType
+
+const (
+  A FooType = C.A
+  B FooType = C.B
+  C FooType = C.C
+)
+
+// cfg(all(unix, x11))
+type FooHandle = C.FooHandle
+
+// cfg(any(windows, target_pointer_width = "32"))
+type BarHandle = C.BarHandle
+
+// cfg(all(unix, x11))
+func Root(a FooHandle) {
+  C.root(a)
+}
+
+// cfg(any(windows, target_pointer_width = "32"))
+func Root(a BarHandle) {
+  C.root(a)
+}
@@ -0,0 +1,44 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(any(windows, target_pointer_width = "32"))
+  interface BarType {
+    int A = 0;
+    int B = 1;
+    int C = 2;
+  }
+
+  // cfg(all(unix, x11))
+  interface FooType {
+    int A = 0;
+    int B = 1;
+    int C = 2;
+  }
+
+  // cfg(all(unix, x11))
+  @Structure.FieldOrder({"ty", "x", "y"})
+  class FooHandle extends Structure {
+    public int ty;
+    public int x;
+    public float y;
+
+    public static class ByReference extends FooHandle implements Structure.ByReference {}
+
+    public static class ByValue extends FooHandle implements Structure.ByValue {}
+  }
+
+  // cfg(any(windows, target_pointer_width = "32"))
+  @Structure.FieldOrder({"ty", "x", "y"})
+  class BarHandle extends Structure {
+    public int ty;
+    public int x;
+    public float y;
+
+    public static class ByReference extends BarHandle implements Structure.ByReference {}
+
+    public static class ByValue extends BarHandle implements Structure.ByValue {}
+  }
+
+  // cfg(all(unix, x11))
+  void root(FooHandle.ByValue a);
+}
@@ -0,0 +1,51 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const FooHandle = StructType();
+const BarHandle = StructType();
+
+// cfg(all(unix, x11))
+FooHandle.defineProperty('ty', 'uint32');
+FooHandle.defineProperty('x', 'int32');
+FooHandle.defineProperty('y', 'float');
+
+// cfg(any(windows, target_pointer_width = "32"))
+BarHandle.defineProperty('ty', 'uint32');
+BarHandle.defineProperty('x', 'int32');
+BarHandle.defineProperty('y', 'float');
+
+// cfg(any(windows, target_pointer_width = "32"))
+const BarType = Object.freeze({
+  A: 0,
+  B: 1,
+  C: 2,
+});
+
+// cfg(all(unix, x11))
+const FooType = Object.freeze({
+  A: 0,
+  B: 1,
+  C: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    // cfg(all(unix, x11))
+    root: ['void', [FooHandle]],
+  });
+  return lib;
+}
+
+module.exports = {
+  FooHandle,
+  BarHandle,
+  BarType,
+  FooType,
+  load,
+};
@@ -0,0 +1,40 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(any(windows, target_pointer_width = "32"))
+  enum:
+    A
+    B
+    C
+  ctypedef uint32_t BarType
+
+  # cfg(all(unix, x11))
+  enum:
+    A
+    B
+    C
+  ctypedef uint32_t FooType
+
+  # cfg(all(unix, x11))
+  ctypedef struct FooHandle:
+    FooType ty
+    int32_t x
+    float y
+
+  # cfg(any(windows, target_pointer_width = "32"))
+  ctypedef struct BarHandle:
+    BarType ty
+    int32_t x
+    float y
+
+  # cfg(all(unix, x11))
+  void root(FooHandle a)
+
+  # cfg(any(windows, target_pointer_width = "32"))
+  void root(BarHandle a)
@@ -0,0 +1,46 @@
+import ctypes
+
+# cfg(any(windows, target_pointer_width = "32"))
+BarType = ctypes.c_uint32
+A = 0
+B = 1
+C = 2
+
+# cfg(all(unix, x11))
+FooType = ctypes.c_uint32
+A = 0
+B = 1
+C = 2
+
+# cfg(all(unix, x11))
+class FooHandle(ctypes.Structure):
+  pass
+
+FooHandle._fields_ = [
+  ("ty", FooType),
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+# cfg(any(windows, target_pointer_width = "32"))
+class BarHandle(ctypes.Structure):
+  pass
+
+BarHandle._fields_ = [
+  ("ty", BarType),
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # cfg(all(unix, x11))
+  lib.root.argtypes = [FooHandle]
+  lib.root.restype = None
+
+  # cfg(any(windows, target_pointer_width = "32"))
+  lib.root.argtypes = [BarHandle]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,35 @@
+const std = @import("std");
+
+// cfg(any(windows, target_pointer_width = "32"))
+pub const BarType = enum(u32) {
+  A,
+  B,
+  C,
+};
+
+// cfg(all(unix, x11))
+pub const FooType = enum(u32) {
+  A,
+  B,
+  C,
+};
+
+// cfg(all(unix, x11))
+pub const FooHandle = extern struct {
+  ty: FooType,
+  x: i32,
+  y: f32,
+};
+
+// cfg(any(windows, target_pointer_width = "32"))
+pub const BarHandle = extern struct {
+  ty: BarType,
+  x: i32,
+  y: f32,
+};
+
+// cfg(all(unix, x11))
+pub extern fn root(a: FooHandle) void;
+
+// cfg(any(windows, target_pointer_width = "32"))
+pub extern fn root(a: BarHandle) void;
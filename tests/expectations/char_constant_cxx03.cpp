@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <stdint.h>
+#include <cstdlib>
+#include <new>
+
+static const uint32_t EMOJI = 0x1F600;
+
+static const uint32_t E_ACUTE = 0xE9;
+
+static const wchar_t TAB = '\t';
+
+extern "C" {
+
+void root();
+
+} // extern "C"
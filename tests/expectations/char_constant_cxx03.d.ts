@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const EMOJI: number;
+
+export declare const E_ACUTE: number;
+
+export declare const TAB: number;
+
+export interface Library {
+  root(): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const EMOJI = C.EMOJI
+
+const E_ACUTE = C.E_ACUTE
+
+const TAB = C.TAB
+
+func Root() {
+  C.root()
+}
@@ -0,0 +1,11 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int EMOJI = 0x1F600;
+
+  int E_ACUTE = 0xe9;
+
+  int TAB = '\t';
+
+  void root();
+}
@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const EMOJI = 0x1F600;
+
+const E_ACUTE = 233;
+
+const TAB = 9;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  EMOJI,
+  E_ACUTE,
+  TAB,
+  load,
+};
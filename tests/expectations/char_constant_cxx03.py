@@ -0,0 +1,15 @@
+import ctypes
+
+EMOJI = chr(0x1F600)
+
+E_ACUTE = chr(0xe9)
+
+TAB = '\t'
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const EMOJI: u32 = 0x1F600;
+
+pub const E_ACUTE: u32 = '\u{e9}';
+
+pub const TAB: u32 = '\t';
+
+pub extern fn root() void;
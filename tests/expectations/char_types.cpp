@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+typedef uint32_t rune_t;
+typedef const char *utf8_str;
+
+struct Label {
+  utf8_str text;
+  char *buffer;
+  char initial;
+  rune_t symbol;
+};
+
+extern "C" {
+
+void label_names(const utf8_str *names, uintptr_t count);
+
+Label label_new(utf8_str text, rune_t symbol);
+
+} // extern "C"
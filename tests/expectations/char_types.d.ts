@@ -0,0 +1,27 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Label {
+  text: Buffer;
+  buffer: Buffer;
+  initial: number;
+  symbol: number;
+  ref(): Buffer;
+}
+export declare const Label: StructType<Label>;
+
+export interface Library {
+  label_names(names: Buffer | null, count: number | string): void;
+
+  label_new(text: string | null, symbol: number): Label;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Label = C.Label
+
+func LabelNames(names **C.char, count uintptr) {
+  C.label_names(names, C.uintptr_t(count))
+}
+
+func LabelNew(text *C.char, symbol C.wchar_t) Label {
+  return C.label_new(text, symbol)
+}
@@ -0,0 +1,29 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"text", "buffer", "initial", "symbol"})
+  class Label extends Structure {
+    public Pointer text;
+    public Pointer buffer;
+    public byte initial;
+    public char symbol;
+
+    public static class ByReference extends Label implements Structure.ByReference {}
+
+    public static class ByValue extends Label implements Structure.ByValue {}
+  }
+
+  void label_names(Pointer names, SizeT count);
+
+  Label.ByValue label_new(String text, char symbol);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
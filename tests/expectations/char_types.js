@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Label = StructType();
+
+Label.defineProperty('text', ref.refType('char'));
+Label.defineProperty('buffer', ref.refType('char'));
+Label.defineProperty('initial', 'char');
+Label.defineProperty('symbol', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    label_names: ['void', [ref.refType(ref.refType('char')), 'size_t']],
+    label_new: [Label, ['string', 'uint32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Label,
+  load,
+};
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Label:
+    utf8_str text
+    char *buffer
+    char initial
+    rune_t symbol
+
+  void label_names(const utf8_str *names, uintptr_t count)
+
+  Label label_new(utf8_str text, rune_t symbol)
@@ -0,0 +1,22 @@
+import ctypes
+
+class Label(ctypes.Structure):
+  pass
+
+Label._fields_ = [
+  ("text", ctypes.c_char_p),
+  ("buffer", ctypes.POINTER(ctypes.c_char)),
+  ("initial", ctypes.c_char),
+  ("symbol", ctypes.c_wchar),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.label_names.argtypes = [ctypes.POINTER(ctypes.c_char_p), ctypes.c_size_t]
+  lib.label_names.restype = None
+
+  lib.label_new.argtypes = [ctypes.c_char_p, ctypes.c_wchar]
+  lib.label_new.restype = Label
+
+  return lib
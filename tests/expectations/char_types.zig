@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const Label = extern struct {
+  text: [*c]const c_char,
+  buffer: [*c]c_char,
+  initial: c_char,
+  symbol: u32,
+};
+
+pub extern fn label_names(names: [*c]const [*c]const c_char, count: usize) void;
+
+pub extern fn label_new(text: [*c]const c_char, symbol: u32) Label;
@@ -0,0 +1,39 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const float FLOAT = 5.0f;
+
+static const uintptr_t LEN = 44;
+
+static const uint8_t NARROW = 69;
+
+static const uint32_t MASKED = (1 << 8) | NARROW;
+
+static const int32_t NEG = 5;
+
+static const int32_t NEG_SUM = -(1 + 2);
+
+static const int32_t ROUNDED = 3;
+
+static const uint8_t SATURATED = 0;
+
+static const int8_t SIGNED = -56;
+
+static const uint8_t TRUNCATED = 255;
+
+static const uint32_t UNSIGNED = 4294967295U;
+
+static const uint32_t WIDE = 74565;
+
+struct Buffer {
+  uint8_t data[LEN];
+  uint8_t tail[4];
+};
+
+extern "C" {
+
+void root(Buffer b);
+
+} // extern "C"
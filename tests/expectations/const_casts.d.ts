@@ -0,0 +1,47 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const FLOAT: number;
+
+export declare const LEN: number;
+
+export declare const NARROW: number;
+
+export declare const MASKED: number;
+
+export declare const NEG: number;
+
+export declare const NEG_SUM: number;
+
+export declare const ROUNDED: number;
+
+export declare const SATURATED: number;
+
+export declare const SIGNED: number;
+
+export declare const TRUNCATED: number;
+
+export declare const UNSIGNED: number;
+
+export declare const WIDE: number;
+
+export interface Buffer {
+  data: ArrayLike<number>;
+  tail: ArrayLike<number>;
+  ref(): Buffer;
+}
+export declare const Buffer: StructType<Buffer>;
+
+export interface Library {
+  root(b: Buffer): void;
+}
+
+export declare function load(path: string): Library;
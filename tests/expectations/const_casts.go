@@ -0,0 +1,34 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Buffer = C.Buffer
+
+const FLOAT = C.FLOAT
+
+const LEN = C.LEN
+
+const NARROW = C.NARROW
+
+const MASKED = C.MASKED
+
+const NEG = C.NEG
+
+const NEG_SUM = C.NEG_SUM
+
+const ROUNDED = C.ROUNDED
+
+const SATURATED = C.SATURATED
+
+const SIGNED = C.SIGNED
+
+const TRUNCATED = C.TRUNCATED
+
+const UNSIGNED = C.UNSIGNED
+
+const WIDE = C.WIDE
+
+func Root(b Buffer) {
+  C.root(b)
+}
@@ -0,0 +1,39 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  float FLOAT = 5.0f;
+
+  long LEN = 44L;
+
+  byte NARROW = 69;
+
+  int MASKED = (1 << 8) | NARROW;
+
+  int NEG = 5;
+
+  int NEG_SUM = -(1 + 2);
+
+  int ROUNDED = 3;
+
+  byte SATURATED = 0;
+
+  byte SIGNED = -56;
+
+  byte TRUNCATED = (byte) 255;
+
+  int UNSIGNED = 0xFFFFFFFF;
+
+  int WIDE = 74565;
+
+  @Structure.FieldOrder({"data", "tail"})
+  class Buffer extends Structure {
+    public byte[] data = new byte[(int) LEN];
+    public byte[] tail = new byte[4];
+
+    public static class ByReference extends Buffer implements Structure.ByReference {}
+
+    public static class ByValue extends Buffer implements Structure.ByValue {}
+  }
+
+  void root(Buffer.ByValue b);
+}
@@ -0,0 +1,61 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Buffer = StructType();
+
+const FLOAT = 5.0;
+
+const LEN = 44;
+
+const NARROW = 69;
+
+const MASKED = (1 << 8) | NARROW;
+
+const NEG = 5;
+
+const NEG_SUM = -(1 + 2);
+
+const ROUNDED = 3;
+
+const SATURATED = 0;
+
+const SIGNED = -56;
+
+const TRUNCATED = 255;
+
+const UNSIGNED = 4294967295;
+
+const WIDE = 74565;
+
+Buffer.defineProperty('data', ArrayType('uint8', LEN));
+Buffer.defineProperty('tail', ArrayType('uint8', 4));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Buffer]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Buffer,
+  FLOAT,
+  LEN,
+  NARROW,
+  MASKED,
+  NEG,
+  NEG_SUM,
+  ROUNDED,
+  SATURATED,
+  SIGNED,
+  TRUNCATED,
+  UNSIGNED,
+  WIDE,
+  load,
+};
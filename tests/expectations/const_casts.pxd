@@ -0,0 +1,38 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const float FLOAT
+
+  const uintptr_t LEN
+
+  const uint8_t NARROW
+
+  const uint32_t MASKED
+
+  const int32_t NEG
+
+  const int32_t NEG_SUM
+
+  const int32_t ROUNDED
+
+  const uint8_t SATURATED
+
+  const int8_t SIGNED
+
+  const uint8_t TRUNCATED
+
+  const uint32_t UNSIGNED
+
+  const uint32_t WIDE
+
+  ctypedef struct Buffer:
+    uint8_t data[LEN]
+    uint8_t tail[4]
+
+  void root(Buffer b)
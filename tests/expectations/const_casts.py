@@ -0,0 +1,41 @@
+import ctypes
+
+FLOAT = 5.0
+
+LEN = 44
+
+NARROW = 69
+
+MASKED = (1 << 8) | NARROW
+
+NEG = 5
+
+NEG_SUM = -(1 + 2)
+
+ROUNDED = 3
+
+SATURATED = 0
+
+SIGNED = -56
+
+TRUNCATED = 255
+
+UNSIGNED = 4294967295
+
+WIDE = 74565
+
+class Buffer(ctypes.Structure):
+  pass
+
+Buffer._fields_ = [
+  ("data", (ctypes.c_uint8 * LEN)),
+  ("tail", (ctypes.c_uint8 * 4)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Buffer]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,32 @@
+const std = @import("std");
+
+pub const FLOAT: f32 = 5.0;
+
+pub const LEN: usize = 44;
+
+pub const NARROW: u8 = 69;
+
+pub const MASKED: u32 = ((1 << 8) | NARROW);
+
+pub const NEG: i32 = 5;
+
+pub const NEG_SUM: i32 = -(1 + 2);
+
+pub const ROUNDED: i32 = 3;
+
+pub const SATURATED: u8 = 0;
+
+pub const SIGNED: i8 = -56;
+
+pub const TRUNCATED: u8 = 255;
+
+pub const UNSIGNED: u32 = 4294967295;
+
+pub const WIDE: u32 = 74565;
+
+pub const Buffer = extern struct {
+  data: [@intCast(LEN)]u8,
+  tail: [4]u8,
+};
+
+pub extern fn root(b: Buffer) void;
@@ -0,0 +1,17 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Foo_FOO: number;
+
+export interface Library {
+}
+
+export declare function load(path: string): Library;
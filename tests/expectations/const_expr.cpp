@@ -0,0 +1,38 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint32_t FLAG_A = 1 << 0;
+
+static const uint32_t FLAG_B = 1 << 1;
+
+static const uint32_t FLAG_AB = FLAG_A | FLAG_B;
+
+static const uint64_t BIG = (1 << 20) | FLAG_AB;
+
+static const uintptr_t HEADER_LEN = 4;
+
+static const uint32_t MASK = (FLAG_AB & 255) ^ FLAG_B;
+
+static const int64_t SHIFTED = 1099511627776LL;
+
+static const int64_t NEG_AFTER_SHIFTED = -(SHIFTED + 1);
+
+static const int64_t NEG_SHIFTED = -1099511627776LL;
+
+static const uintptr_t PAYLOAD_LEN = (HEADER_LEN * 2) + 8;
+
+static const uintptr_t TOTAL_LEN = (HEADER_LEN + PAYLOAD_LEN) * 2;
+
+struct Packet {
+  uint8_t header[8];
+  uint8_t payload[PAYLOAD_LEN];
+  uint16_t checksum[4];
+};
+
+extern "C" {
+
+void root(Packet p, const uint32_t (*frames)[10]);
+
+} // extern "C"
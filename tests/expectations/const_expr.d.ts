@@ -0,0 +1,46 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const FLAG_A: number;
+
+export declare const FLAG_B: number;
+
+export declare const FLAG_AB: number;
+
+export declare const BIG: bigint;
+
+export declare const HEADER_LEN: number;
+
+export declare const MASK: number;
+
+export declare const SHIFTED: number;
+
+export declare const NEG_AFTER_SHIFTED: number;
+
+export declare const NEG_SHIFTED: number;
+
+export declare const PAYLOAD_LEN: number;
+
+export declare const TOTAL_LEN: number;
+
+export interface Packet {
+  header: ArrayLike<number>;
+  payload: ArrayLike<number>;
+  checksum: ArrayLike<number>;
+  ref(): Buffer;
+}
+export declare const Packet: StructType<Packet>;
+
+export interface Library {
+  root(p: Packet, frames: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
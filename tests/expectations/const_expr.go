@@ -0,0 +1,32 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Packet = C.Packet
+
+const FLAG_A = C.FLAG_A
+
+const FLAG_B = C.FLAG_B
+
+const FLAG_AB = C.FLAG_AB
+
+const BIG = C.BIG
+
+const HEADER_LEN = C.HEADER_LEN
+
+const MASK = C.MASK
+
+const SHIFTED = C.SHIFTED
+
+const NEG_AFTER_SHIFTED = C.NEG_AFTER_SHIFTED
+
+const NEG_SHIFTED = C.NEG_SHIFTED
+
+const PAYLOAD_LEN = C.PAYLOAD_LEN
+
+const TOTAL_LEN = C.TOTAL_LEN
+
+func Root(p Packet, frames *[10]C.uint32_t) {
+  C.root(p, frames)
+}
@@ -0,0 +1,38 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int FLAG_A = 1 << 0;
+
+  int FLAG_B = 1 << 1;
+
+  int FLAG_AB = FLAG_A | FLAG_B;
+
+  long BIG = (1L << 20L) | FLAG_AB;
+
+  long HEADER_LEN = 4L;
+
+  int MASK = (FLAG_AB & 255) ^ FLAG_B;
+
+  long SHIFTED = 1099511627776L;
+
+  long NEG_AFTER_SHIFTED = -(SHIFTED + 1);
+
+  long NEG_SHIFTED = -1099511627776L;
+
+  long PAYLOAD_LEN = (HEADER_LEN * 2L) + 8L;
+
+  long TOTAL_LEN = (HEADER_LEN + PAYLOAD_LEN) * 2L;
+
+  @Structure.FieldOrder({"header", "payload", "checksum"})
+  class Packet extends Structure {
+    public byte[] header = new byte[8];
+    public byte[] payload = new byte[(int) PAYLOAD_LEN];
+    public short[] checksum = new short[4];
+
+    public static class ByReference extends Packet implements Structure.ByReference {}
+
+    public static class ByValue extends Packet implements Structure.ByValue {}
+  }
+
+  void root(Packet.ByValue p, Pointer frames);
+}
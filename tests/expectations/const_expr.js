@@ -0,0 +1,59 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Packet = StructType();
+
+const FLAG_A = 1 << 0;
+
+const FLAG_B = 1 << 1;
+
+const FLAG_AB = FLAG_A | FLAG_B;
+
+const BIG = (1n << 20n) | BigInt(FLAG_AB);
+
+const HEADER_LEN = 4;
+
+const MASK = (FLAG_AB & 255) ^ FLAG_B;
+
+const SHIFTED = 1099511627776;
+
+const NEG_AFTER_SHIFTED = -(SHIFTED + 1);
+
+const NEG_SHIFTED = -1099511627776;
+
+const PAYLOAD_LEN = (HEADER_LEN * 2) + 8;
+
+const TOTAL_LEN = (HEADER_LEN + PAYLOAD_LEN) * 2;
+
+Packet.defineProperty('header', ArrayType('uint8', 8));
+Packet.defineProperty('payload', ArrayType('uint8', PAYLOAD_LEN));
+Packet.defineProperty('checksum', ArrayType('uint16', 4));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Packet, ref.refType(ArrayType('uint32', 10))]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Packet,
+  FLAG_A,
+  FLAG_B,
+  FLAG_AB,
+  BIG,
+  HEADER_LEN,
+  MASK,
+  SHIFTED,
+  NEG_AFTER_SHIFTED,
+  NEG_SHIFTED,
+  PAYLOAD_LEN,
+  TOTAL_LEN,
+  load,
+};
@@ -0,0 +1,37 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t FLAG_A
+
+  const uint32_t FLAG_B
+
+  const uint32_t FLAG_AB
+
+  const uint64_t BIG
+
+  const uintptr_t HEADER_LEN
+
+  const uint32_t MASK
+
+  const int64_t SHIFTED
+
+  const int64_t NEG_AFTER_SHIFTED
+
+  const int64_t NEG_SHIFTED
+
+  const uintptr_t PAYLOAD_LEN
+
+  const uintptr_t TOTAL_LEN
+
+  ctypedef struct Packet:
+    uint8_t header[8]
+    uint8_t payload[PAYLOAD_LEN]
+    uint16_t checksum[4]
+
+  void root(Packet p, const uint32_t (*frames)[10])
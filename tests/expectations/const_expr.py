@@ -0,0 +1,40 @@
+import ctypes
+
+FLAG_A = 1 << 0
+
+FLAG_B = 1 << 1
+
+FLAG_AB = FLAG_A | FLAG_B
+
+BIG = (1 << 20) | FLAG_AB
+
+HEADER_LEN = 4
+
+MASK = (FLAG_AB & 255) ^ FLAG_B
+
+SHIFTED = 1099511627776
+
+NEG_AFTER_SHIFTED = -(SHIFTED + 1)
+
+NEG_SHIFTED = -1099511627776
+
+PAYLOAD_LEN = (HEADER_LEN * 2) + 8
+
+TOTAL_LEN = (HEADER_LEN + PAYLOAD_LEN) * 2
+
+class Packet(ctypes.Structure):
+  pass
+
+Packet._fields_ = [
+  ("header", (ctypes.c_uint8 * 8)),
+  ("payload", (ctypes.c_uint8 * PAYLOAD_LEN)),
+  ("checksum", (ctypes.c_uint16 * 4)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Packet, ctypes.POINTER((ctypes.c_uint32 * 10))]
+  lib.root.restype = None
+
+  return lib
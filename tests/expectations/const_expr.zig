@@ -0,0 +1,31 @@
+const std = @import("std");
+
+pub const FLAG_A: u32 = (1 << 0);
+
+pub const FLAG_B: u32 = (1 << 1);
+
+pub const FLAG_AB: u32 = (FLAG_A | FLAG_B);
+
+pub const BIG: u64 = ((1 << 20) | FLAG_AB);
+
+pub const HEADER_LEN: usize = 4;
+
+pub const MASK: u32 = ((FLAG_AB & 255) ^ FLAG_B);
+
+pub const SHIFTED: i64 = 1099511627776;
+
+pub const NEG_AFTER_SHIFTED: i64 = -(SHIFTED + 1);
+
+pub const NEG_SHIFTED: i64 = -1099511627776;
+
+pub const PAYLOAD_LEN: usize = ((HEADER_LEN * 2) + 8);
+
+pub const TOTAL_LEN: usize = ((HEADER_LEN + PAYLOAD_LEN) * 2);
+
+pub const Packet = extern struct {
+  header: [8]u8,
+  payload: [@intCast(PAYLOAD_LEN)]u8,
+  checksum: [4]u16,
+};
+
+pub extern fn root(p: Packet, frames: *const [10]u32) void;
@@ -0,0 +1,6 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Transparent = C.Transparent
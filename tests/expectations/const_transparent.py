@@ -0,0 +1,5 @@
+import ctypes
+
+Transparent = ctypes.c_uint8
+
+FOO = 0
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub const FOO: Transparent = 0;
+
+pub const Transparent = u8;
@@ -3,11 +3,13 @@
 #include <cstdlib>
 #include <new>
 
+static const char* const BAR = "hello world";
+
 static const wchar_t DELIMITER = ':';
 
 static const int32_t FOO = 10;
 
-static const wchar_t HEART = L'\u2764';
+static const char32_t HEART = U'\u2764';
 
 static const wchar_t LEFTCURLY = '{';
 
@@ -21,7 +23,7 @@ static const wchar_t QUOTE = '\'';
 
 static const wchar_t TAB = '\t';
 
-static const float ZOM = 3.14;
+static const float ZOM = 3.14f;
 
 struct Foo {
   int32_t x[FOO];
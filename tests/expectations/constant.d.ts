@@ -0,0 +1,44 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const BAR: string;
+
+export declare const DELIMITER: number;
+
+export declare const FOO: number;
+
+export declare const HEART: number;
+
+export declare const LEFTCURLY: number;
+
+export declare const NEG_ONE: number;
+
+export declare const NEWLINE: number;
+
+export declare const POS_ONE: number;
+
+export declare const QUOTE: number;
+
+export declare const TAB: number;
+
+export declare const ZOM: number;
+
+export interface Foo {
+  x: ArrayLike<number>;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  root(x: Foo): void;
+}
+
+export declare function load(path: string): Library;
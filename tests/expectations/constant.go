@@ -0,0 +1,32 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+const BAR = "hello world"
+
+const DELIMITER = C.DELIMITER
+
+const FOO = C.FOO
+
+const HEART = C.HEART
+
+const LEFTCURLY = C.LEFTCURLY
+
+const NEG_ONE = C.NEG_ONE
+
+const NEWLINE = C.NEWLINE
+
+const POS_ONE = C.POS_ONE
+
+const QUOTE = C.QUOTE
+
+const TAB = C.TAB
+
+const ZOM = C.ZOM
+
+func Root(x Foo) {
+  C.root(x)
+}
@@ -0,0 +1,36 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  String BAR = "hello world";
+
+  int DELIMITER = ':';
+
+  int FOO = 10;
+
+  int HEART = 0x2764;
+
+  int LEFTCURLY = '{';
+
+  byte NEG_ONE = -1;
+
+  int NEWLINE = '\n';
+
+  byte POS_ONE = 1;
+
+  int QUOTE = '\'';
+
+  int TAB = '\t';
+
+  float ZOM = 3.14f;
+
+  @Structure.FieldOrder({"x"})
+  class Foo extends Structure {
+    public int[] x = new int[(int) FOO];
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void root(Foo.ByValue x);
+}
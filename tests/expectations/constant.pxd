@@ -0,0 +1,35 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const char *BAR
+
+  const wchar_t DELIMITER
+
+  const int32_t FOO
+
+  const wchar_t HEART
+
+  const wchar_t LEFTCURLY
+
+  const int8_t NEG_ONE
+
+  const wchar_t NEWLINE
+
+  const int8_t POS_ONE
+
+  const wchar_t QUOTE
+
+  const wchar_t TAB
+
+  const float ZOM
+
+  ctypedef struct Foo:
+    int32_t x[FOO]
+
+  void root(Foo x)
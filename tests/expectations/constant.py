@@ -0,0 +1,38 @@
+import ctypes
+
+BAR = b"hello world"
+
+DELIMITER = ':'
+
+FOO = 10
+
+HEART = chr(0x2764)
+
+LEFTCURLY = '{'
+
+NEG_ONE = -1
+
+NEWLINE = '\n'
+
+POS_ONE = 1
+
+QUOTE = '\''
+
+TAB = '\t'
+
+ZOM = 3.14
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", (ctypes.c_int32 * FOO)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const BAR: [*:0]const u8 = "hello world";
+
+pub const DELIMITER: u32 = ':';
+
+pub const FOO: i32 = 10;
+
+pub const HEART: u32 = 0x2764;
+
+pub const LEFTCURLY: u32 = '{';
+
+pub const NEG_ONE: i8 = -1;
+
+pub const NEWLINE: u32 = '\n';
+
+pub const POS_ONE: i8 = 1;
+
+pub const QUOTE: u32 = '\'';
+
+pub const TAB: u32 = '\t';
+
+pub const ZOM: f32 = 3.14;
+
+pub const Foo = extern struct {
+  x: [@intCast(FOO)]i32,
+};
+
+pub extern fn root(x: Foo) void;
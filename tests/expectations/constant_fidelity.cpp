@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cmath>
+
+static const char32_t EMOJI = U'\U0001F600';
+
+static const char32_t EURO = U'\u20AC';
+
+static const char32_t E_ACUTE = U'\u00E9';
+
+static const float HALF = 0.5f;
+
+static const double LARGE = 1e300;
+
+static const int64_t MAX_I64 = 9223372036854775807LL;
+
+static const uint64_t MAX_U64 = 18446744073709551615ULL;
+
+static const int64_t MIN_I64 = (-9223372036854775807LL - 1);
+
+static const float NEG_INF = -INFINITY;
+
+static const float NOT_A_NUMBER = NAN;
+
+static const wchar_t NUL = '\000';
+
+static const float ONE = 1.0f;
+
+static const double POS_INF = INFINITY;
+
+static const wchar_t QUOTE = '\'';
+
+static const wchar_t TAB = '\t';
+
+extern "C" {
+
+void root();
+
+} // extern "C"
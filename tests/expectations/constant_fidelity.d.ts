@@ -0,0 +1,46 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const EMOJI: number;
+
+export declare const EURO: number;
+
+export declare const E_ACUTE: number;
+
+export declare const HALF: number;
+
+export declare const LARGE: number;
+
+export declare const MAX_I64: bigint;
+
+export declare const MAX_U64: bigint;
+
+export declare const MIN_I64: bigint;
+
+export declare const NEG_INF: number;
+
+export declare const NOT_A_NUMBER: number;
+
+export declare const NUL: number;
+
+export declare const ONE: number;
+
+export declare const POS_INF: number;
+
+export declare const QUOTE: number;
+
+export declare const TAB: number;
+
+export interface Library {
+  root(): void;
+}
+
+export declare function load(path: string): Library;
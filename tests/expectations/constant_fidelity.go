@@ -0,0 +1,32 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const EMOJI = C.EMOJI
+
+const EURO = C.EURO
+
+const E_ACUTE = C.E_ACUTE
+
+const HALF = C.HALF
+
+const LARGE = C.LARGE
+
+const MAX_I64 = C.MAX_I64
+
+const MAX_U64 = C.MAX_U64
+
+const MIN_I64 = C.MIN_I64
+
+const NUL = C.NUL
+
+const ONE = C.ONE
+
+const QUOTE = C.QUOTE
+
+const TAB = C.TAB
+
+func Root() {
+  C.root()
+}
@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int EMOJI = 0x1F600;
+
+  int EURO = 0x20AC;
+
+  int E_ACUTE = 0xe9;
+
+  float HALF = 0.5f;
+
+  double LARGE = 1e300;
+
+  long MAX_I64 = 9223372036854775807L;
+
+  long MAX_U64 = 0xFFFFFFFFFFFFFFFFL;
+
+  long MIN_I64 = -9223372036854775808L;
+
+  float NEG_INF = Float.NEGATIVE_INFINITY;
+
+  float NOT_A_NUMBER = Float.NaN;
+
+  int NUL = 0x0;
+
+  float ONE = 1.0f;
+
+  double POS_INF = Double.POSITIVE_INFINITY;
+
+  int QUOTE = '\'';
+
+  int TAB = '\t';
+
+  void root();
+}
@@ -0,0 +1,63 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const EMOJI = 0x1F600;
+
+const EURO = 0x20AC;
+
+const E_ACUTE = 233;
+
+const HALF = 0.5;
+
+const LARGE = 1e300;
+
+const MAX_I64 = 9223372036854775807n;
+
+const MAX_U64 = 18446744073709551615n;
+
+const MIN_I64 = -9223372036854775808n;
+
+const NEG_INF = -Infinity;
+
+const NOT_A_NUMBER = NaN;
+
+const NUL = 0;
+
+const ONE = 1.0;
+
+const POS_INF = Infinity;
+
+const QUOTE = 39;
+
+const TAB = 9;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  EMOJI,
+  EURO,
+  E_ACUTE,
+  HALF,
+  LARGE,
+  MAX_I64,
+  MAX_U64,
+  MIN_I64,
+  NEG_INF,
+  NOT_A_NUMBER,
+  NUL,
+  ONE,
+  POS_INF,
+  QUOTE,
+  TAB,
+  load,
+};
@@ -0,0 +1,40 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const wchar_t EMOJI
+
+  const wchar_t EURO
+
+  const wchar_t E_ACUTE
+
+  const float HALF
+
+  const double LARGE
+
+  const int64_t MAX_I64
+
+  const uint64_t MAX_U64
+
+  const int64_t MIN_I64
+
+  const float NEG_INF
+
+  const float NOT_A_NUMBER
+
+  const wchar_t NUL
+
+  const float ONE
+
+  const double POS_INF
+
+  const wchar_t QUOTE
+
+  const wchar_t TAB
+
+  void root()
@@ -0,0 +1,39 @@
+import ctypes
+
+EMOJI = chr(0x1F600)
+
+EURO = chr(0x20AC)
+
+E_ACUTE = chr(0xe9)
+
+HALF = 0.5
+
+LARGE = 1e300
+
+MAX_I64 = 9223372036854775807
+
+MAX_U64 = 18446744073709551615
+
+MIN_I64 = -9223372036854775808
+
+NEG_INF = float("-inf")
+
+NOT_A_NUMBER = float("nan")
+
+NUL = chr(0x0)
+
+ONE = 1.0
+
+POS_INF = float("inf")
+
+QUOTE = '\''
+
+TAB = '\t'
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const EMOJI: u32 = 0x1F600;
+
+pub const EURO: u32 = 0x20AC;
+
+pub const E_ACUTE: u32 = '\u{e9}';
+
+pub const HALF: f32 = 0.5;
+
+pub const LARGE: f64 = 1e300;
+
+pub const MAX_I64: i64 = 9223372036854775807;
+
+pub const MAX_U64: u64 = 18446744073709551615;
+
+pub const MIN_I64: i64 = -9223372036854775808;
+
+pub const NEG_INF: f32 = -std.math.inf(f32);
+
+pub const NOT_A_NUMBER: f32 = std.math.nan(f32);
+
+pub const NUL: u32 = '\u{0}';
+
+pub const ONE: f32 = 1.0;
+
+pub const POS_INF: f64 = std.math.inf(f64);
+
+pub const QUOTE: u32 = '\'';
+
+pub const TAB: u32 = '\t';
+
+pub extern fn root() void;
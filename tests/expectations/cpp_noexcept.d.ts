@@ -0,0 +1,24 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Library {
+  callback(a: number): void;
+
+  callback_may_unwind(a: number): void;
+
+  may_unwind(a: number): number;
+
+  may_unwind_system(): void;
+
+  no_unwind(a: number): number;
+}
+
+export declare function load(path: string): Library;
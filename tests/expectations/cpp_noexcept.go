@@ -0,0 +1,24 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+func Callback(a uint32) {
+  C.callback(C.uint32_t(a))
+}
+
+func CallbackMayUnwind(a uint32) {
+  C.callback_may_unwind(C.uint32_t(a))
+}
+
+func MayUnwind(a uint32) uint32 {
+  return uint32(C.may_unwind(C.uint32_t(a)))
+}
+
+func MayUnwindSystem() {
+  C.may_unwind_system()
+}
+
+func NoUnwind(a uint32) uint32 {
+  return uint32(C.no_unwind(C.uint32_t(a)))
+}
@@ -0,0 +1,13 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  void callback(int a);
+
+  void callback_may_unwind(int a);
+
+  int may_unwind(int a);
+
+  void may_unwind_system();
+
+  int no_unwind(int a);
+}
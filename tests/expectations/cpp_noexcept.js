@@ -0,0 +1,22 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    callback: ['void', ['uint32']],
+    callback_may_unwind: ['void', ['uint32']],
+    may_unwind: ['uint32', ['uint32']],
+    may_unwind_system: ['void', []],
+    no_unwind: ['uint32', ['uint32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  load,
+};
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  void callback(uint32_t a)
+
+  void callback_may_unwind(uint32_t a)
+
+  uint32_t may_unwind(uint32_t a)
+
+  void may_unwind_system()
+
+  uint32_t no_unwind(uint32_t a)
@@ -0,0 +1,26 @@
+import ctypes
+
+# Only Windows has `stdcall` functions.
+WINFUNCTYPE = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+  windll = getattr(ctypes, "WinDLL", ctypes.CDLL)(path)
+
+  lib.callback.argtypes = [ctypes.c_uint32]
+  lib.callback.restype = None
+
+  lib.callback_may_unwind.argtypes = [ctypes.c_uint32]
+  lib.callback_may_unwind.restype = None
+
+  lib.may_unwind.argtypes = [ctypes.c_uint32]
+  lib.may_unwind.restype = ctypes.c_uint32
+
+  lib.may_unwind_system = windll.may_unwind_system
+  lib.may_unwind_system.argtypes = []
+  lib.may_unwind_system.restype = None
+
+  lib.no_unwind.argtypes = [ctypes.c_uint32]
+  lib.no_unwind.restype = ctypes.c_uint32
+
+  return lib
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub extern fn callback(a: u32) void;
+
+pub extern fn callback_may_unwind(a: u32) void;
+
+pub extern fn may_unwind(a: u32) u32;
+
+pub extern fn may_unwind_system() callconv(std.os.windows.WINAPI) void;
+
+pub extern fn no_unwind(a: u32) u32;
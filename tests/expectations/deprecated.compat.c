@@ -0,0 +1,70 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum OldFlags
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t OldFlags __attribute__((deprecated("Use Flags instead")));
+#endif // __cplusplus
+
+typedef enum __attribute__((deprecated("Use \"Mode\" instead"))) {
+  Fast,
+  Slow,
+} OldMode;
+
+typedef struct __attribute__((deprecated)) {
+  int32_t x;
+  int32_t y;
+} OldPoint;
+
+enum OldShape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+typedef uint8_t OldShape_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  OldShape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef struct {
+  OldShape_Tag tag;
+  float _0;
+} Square_Body;
+
+typedef union __attribute__((deprecated)) {
+  OldShape_Tag tag;
+  Circle_Body circle;
+  Square_Body square;
+} OldShape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__attribute__((deprecated("Use new_point instead"))) int32_t old_point(void);
+
+__attribute__((deprecated))
+void old_root(const OldPoint *a,
+              OldMode b,
+              OldFlags c,
+              const OldShape *d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
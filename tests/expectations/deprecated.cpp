@@ -0,0 +1,50 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class [[deprecated("Use Flags instead")]] OldFlags : uint8_t {
+  A,
+  B,
+};
+
+enum class [[deprecated("Use \"Mode\" instead")]] OldMode {
+  Fast,
+  Slow,
+};
+
+struct [[deprecated]] OldPoint {
+  int32_t x;
+  int32_t y;
+};
+
+union [[deprecated]] OldShape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square,
+  };
+
+  struct Circle_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct Square_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Circle_Body circle;
+  Square_Body square;
+};
+
+extern "C" {
+
+[[deprecated("Use new_point instead")]] int32_t old_point();
+
+[[deprecated]] void old_root(const OldPoint *a, OldMode b, OldFlags c, const OldShape *d);
+
+} // extern "C"
@@ -0,0 +1,62 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const OldFlags: {
+  readonly A: 0;
+  readonly B: 1;
+};
+
+export declare const OldMode: {
+  readonly Fast: 0;
+  readonly Slow: 1;
+};
+
+export declare const OldShape_Tag: {
+  readonly Circle: 0;
+  readonly Square: 1;
+};
+
+export interface OldPoint {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const OldPoint: StructType<OldPoint>;
+
+export interface Circle_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Circle_Body: StructType<Circle_Body>;
+
+export interface Square_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Square_Body: StructType<Square_Body>;
+
+export interface OldShape {
+  tag: number;
+  circle: Circle_Body;
+  square: Square_Body;
+  ref(): Buffer;
+}
+export declare const OldShape: StructType<OldShape>;
+
+export interface Library {
+  old_point(): number;
+
+  old_root(a: Buffer | null, b: number, c: number, d: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
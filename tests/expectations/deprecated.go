@@ -0,0 +1,37 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type OldFlags = C.OldFlags
+
+const (
+  A OldFlags = C.A
+  B OldFlags = C.B
+)
+
+type OldMode = C.OldMode
+
+const (
+  Fast OldMode = C.Fast
+  Slow OldMode = C.Slow
+)
+
+type OldPoint = C.OldPoint
+
+type OldShape_Tag = C.OldShape_Tag
+
+const (
+  Circle OldShape_Tag = C.Circle
+  Square OldShape_Tag = C.Square
+)
+
+type Circle_Body = C.Circle_Body
+
+type Square_Body = C.Square_Body
+
+type OldShape = C.OldShape
+
+func OldRoot(a *OldPoint, b OldMode, c OldFlags, d *OldShape) {
+  C.old_root(a, b, c, d)
+}
@@ -0,0 +1,62 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface OldFlags {
+    byte A = 0;
+    byte B = 1;
+  }
+
+  interface OldMode {
+    int Fast = 0;
+    int Slow = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class OldPoint extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends OldPoint implements Structure.ByReference {}
+
+    public static class ByValue extends OldPoint implements Structure.ByValue {}
+  }
+
+  interface OldShape_Tag {
+    byte Circle = 0;
+    byte Square = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Circle_Body extends Structure {
+    public byte tag;
+    public float _0;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Square_Body extends Structure {
+    public byte tag;
+    public float _0;
+
+    public static class ByReference extends Square_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Square_Body implements Structure.ByValue {}
+  }
+
+  class OldShape extends Union {
+    public byte tag;
+    public Circle_Body circle;
+    public Square_Body square;
+
+    public static class ByReference extends OldShape implements Structure.ByReference {}
+
+    public static class ByValue extends OldShape implements Structure.ByValue {}
+  }
+
+  int old_point();
+
+  void old_root(OldPoint.ByReference a, int b, byte c, OldShape.ByReference d);
+}
@@ -0,0 +1,60 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const OldPoint = StructType();
+const Circle_Body = StructType();
+const Square_Body = StructType();
+const OldShape = UnionType();
+
+OldPoint.defineProperty('x', 'int32');
+OldPoint.defineProperty('y', 'int32');
+
+Circle_Body.defineProperty('tag', 'uint8');
+Circle_Body.defineProperty('_0', 'float');
+
+Square_Body.defineProperty('tag', 'uint8');
+Square_Body.defineProperty('_0', 'float');
+
+OldShape.defineProperty('tag', 'uint8');
+OldShape.defineProperty('circle', Circle_Body);
+OldShape.defineProperty('square', Square_Body);
+
+const OldFlags = Object.freeze({
+  A: 0,
+  B: 1,
+});
+
+const OldMode = Object.freeze({
+  Fast: 0,
+  Slow: 1,
+});
+
+const OldShape_Tag = Object.freeze({
+  Circle: 0,
+  Square: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    old_point: ['int32', []],
+    old_root: ['void', [ref.refType(OldPoint), 'int', 'uint8', ref.refType(OldShape)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  OldPoint,
+  Circle_Body,
+  Square_Body,
+  OldShape,
+  OldFlags,
+  OldMode,
+  OldShape_Tag,
+  load,
+};
@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    A
+    B
+  ctypedef uint8_t OldFlags
+
+  ctypedef enum OldMode:
+    Fast
+    Slow
+
+  ctypedef struct OldPoint:
+    int32_t x
+    int32_t y
+
+  enum:
+    Circle
+    Square
+  ctypedef uint8_t OldShape_Tag
+
+  ctypedef struct Circle_Body:
+    OldShape_Tag tag
+    float _0
+
+  ctypedef struct Square_Body:
+    OldShape_Tag tag
+    float _0
+
+  ctypedef union OldShape:
+    OldShape_Tag tag
+    Circle_Body circle
+    Square_Body square
+
+  int32_t old_point()
+
+  void old_root(const OldPoint *a, OldMode b, OldFlags c, const OldShape *d)
@@ -0,0 +1,57 @@
+import ctypes
+
+OldFlags = ctypes.c_uint8
+A = 0
+B = 1
+
+OldMode = ctypes.c_int
+Fast = 0
+Slow = 1
+
+class OldPoint(ctypes.Structure):
+  pass
+
+OldPoint._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+OldShape_Tag = ctypes.c_uint8
+Circle = 0
+Square = 1
+
+class Circle_Body(ctypes.Structure):
+  pass
+
+Circle_Body._fields_ = [
+  ("tag", OldShape_Tag),
+  ("_0", ctypes.c_float),
+]
+
+class Square_Body(ctypes.Structure):
+  pass
+
+Square_Body._fields_ = [
+  ("tag", OldShape_Tag),
+  ("_0", ctypes.c_float),
+]
+
+class OldShape(ctypes.Union):
+  pass
+
+OldShape._fields_ = [
+  ("tag", OldShape_Tag),
+  ("circle", Circle_Body),
+  ("square", Square_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.old_point.argtypes = []
+  lib.old_point.restype = ctypes.c_int32
+
+  lib.old_root.argtypes = [ctypes.POINTER(OldPoint), OldMode, OldFlags, ctypes.POINTER(OldShape)]
+  lib.old_root.restype = None
+
+  return lib
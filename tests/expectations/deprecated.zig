@@ -0,0 +1,41 @@
+const std = @import("std");
+
+pub const OldFlags = enum(u8) {
+  A,
+  B,
+};
+
+pub const OldMode = enum(c_int) {
+  Fast,
+  Slow,
+};
+
+pub const OldPoint = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const OldShape_Tag = enum(u8) {
+  Circle,
+  Square,
+};
+
+pub const Circle_Body = extern struct {
+  tag: OldShape_Tag,
+  _0: f32,
+};
+
+pub const Square_Body = extern struct {
+  tag: OldShape_Tag,
+  _0: f32,
+};
+
+pub const OldShape = extern union {
+  tag: OldShape_Tag,
+  circle: Circle_Body,
+  square: Square_Body,
+};
+
+pub extern fn old_point() i32;
+
+pub extern fn old_root(a: [*c]const OldPoint, b: OldMode, c: OldFlags, d: [*c]const OldShape) void;
@@ -0,0 +1,62 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Bar_Tag: {
+  readonly Baz: 0;
+  readonly Bazz: 1;
+  readonly FooNamed: 2;
+  readonly FooParen: 3;
+};
+
+export interface Foo {
+  a: boolean;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Bazz_Body {
+  tag: number;
+  named: Foo;
+  ref(): Buffer;
+}
+export declare const Bazz_Body: StructType<Bazz_Body>;
+
+export interface FooNamed_Body {
+  tag: number;
+  different: number;
+  fields: number;
+  ref(): Buffer;
+}
+export declare const FooNamed_Body: StructType<FooNamed_Body>;
+
+export interface FooParen_Body {
+  tag: number;
+  _0: number;
+  _1: Foo;
+  ref(): Buffer;
+}
+export declare const FooParen_Body: StructType<FooParen_Body>;
+
+export interface Bar {
+  tag: number;
+  bazz: Bazz_Body;
+  foo_named: FooNamed_Body;
+  foo_paren: FooParen_Body;
+  ref(): Buffer;
+}
+export declare const Bar: StructType<Bar>;
+
+export interface Library {
+  root(aBar: Bar): Foo;
+}
+
+export declare function load(path: string): Library;
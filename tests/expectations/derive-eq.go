@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+type Bar_Tag = C.Bar_Tag
+
+const (
+  Baz Bar_Tag = C.Baz
+  Bazz Bar_Tag = C.Bazz
+  FooNamed Bar_Tag = C.FooNamed
+  FooParen Bar_Tag = C.FooParen
+)
+
+type Bazz_Body = C.Bazz_Body
+
+type FooNamed_Body = C.FooNamed_Body
+
+type FooParen_Body = C.FooParen_Body
+
+type Bar = C.Bar
+
+func Root(aBar Bar) Foo {
+  return C.root(aBar)
+}
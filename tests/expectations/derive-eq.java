@@ -0,0 +1,65 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a", "b"})
+  class Foo extends Structure {
+    public byte a;
+    public int b;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  interface Bar_Tag {
+    byte Baz = 0;
+    byte Bazz = 1;
+    byte FooNamed = 2;
+    byte FooParen = 3;
+  }
+
+  @Structure.FieldOrder({"tag", "named"})
+  class Bazz_Body extends Structure {
+    public byte tag;
+    public Foo named;
+
+    public static class ByReference extends Bazz_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Bazz_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "different", "fields"})
+  class FooNamed_Body extends Structure {
+    public byte tag;
+    public int different;
+    public int fields;
+
+    public static class ByReference extends FooNamed_Body implements Structure.ByReference {}
+
+    public static class ByValue extends FooNamed_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0", "_1"})
+  class FooParen_Body extends Structure {
+    public byte tag;
+    public int _0;
+    public Foo _1;
+
+    public static class ByReference extends FooParen_Body implements Structure.ByReference {}
+
+    public static class ByValue extends FooParen_Body implements Structure.ByValue {}
+  }
+
+  class Bar extends Union {
+    public byte tag;
+    public Bazz_Body bazz;
+    public FooNamed_Body foo_named;
+    public FooParen_Body foo_paren;
+
+    public static class ByReference extends Bar implements Structure.ByReference {}
+
+    public static class ByValue extends Bar implements Structure.ByValue {}
+  }
+
+  Foo.ByValue root(Bar.ByValue aBar);
+}
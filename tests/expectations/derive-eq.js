@@ -0,0 +1,57 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+const Bazz_Body = StructType();
+const FooNamed_Body = StructType();
+const FooParen_Body = StructType();
+const Bar = UnionType();
+
+Foo.defineProperty('a', 'bool');
+Foo.defineProperty('b', 'int32');
+
+Bazz_Body.defineProperty('tag', 'uint8');
+Bazz_Body.defineProperty('named', Foo);
+
+FooNamed_Body.defineProperty('tag', 'uint8');
+FooNamed_Body.defineProperty('different', 'int32');
+FooNamed_Body.defineProperty('fields', 'uint32');
+
+FooParen_Body.defineProperty('tag', 'uint8');
+FooParen_Body.defineProperty('_0', 'int32');
+FooParen_Body.defineProperty('_1', Foo);
+
+Bar.defineProperty('tag', 'uint8');
+Bar.defineProperty('bazz', Bazz_Body);
+Bar.defineProperty('foo_named', FooNamed_Body);
+Bar.defineProperty('foo_paren', FooParen_Body);
+
+const Bar_Tag = Object.freeze({
+  Baz: 0,
+  Bazz: 1,
+  FooNamed: 2,
+  FooParen: 3,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: [Foo, [Bar]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  Bazz_Body,
+  FooNamed_Body,
+  FooParen_Body,
+  Bar,
+  Bar_Tag,
+  load,
+};
@@ -0,0 +1,59 @@
+import ctypes
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("a", ctypes.c_bool),
+  ("b", ctypes.c_int32),
+]
+
+Bar_Tag = ctypes.c_uint8
+Baz = 0
+Bazz = 1
+FooNamed = 2
+FooParen = 3
+
+class Bazz_Body(ctypes.Structure):
+  pass
+
+Bazz_Body._fields_ = [
+  ("tag", Bar_Tag),
+  ("named", Foo),
+]
+
+class FooNamed_Body(ctypes.Structure):
+  pass
+
+FooNamed_Body._fields_ = [
+  ("tag", Bar_Tag),
+  ("different", ctypes.c_int32),
+  ("fields", ctypes.c_uint32),
+]
+
+class FooParen_Body(ctypes.Structure):
+  pass
+
+FooParen_Body._fields_ = [
+  ("tag", Bar_Tag),
+  ("_0", ctypes.c_int32),
+  ("_1", Foo),
+]
+
+class Bar(ctypes.Union):
+  pass
+
+Bar._fields_ = [
+  ("tag", Bar_Tag),
+  ("bazz", Bazz_Body),
+  ("foo_named", FooNamed_Body),
+  ("foo_paren", FooParen_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = Foo
+
+  return lib
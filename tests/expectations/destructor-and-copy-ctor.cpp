@@ -75,6 +75,10 @@ struct Foo {
     }
   }
 
+  private:
+  Foo() {}
+  public:
+
   Foo(const Foo& other)
    : tag(other.tag) {
     switch (tag) {
@@ -146,6 +150,10 @@ union Baz {
     }
   }
 
+  private:
+  Baz() {}
+  public:
+
   Baz(const Baz& other)
    : tag(other.tag) {
     switch (tag) {
@@ -182,6 +190,10 @@ union Taz {
     }
   }
 
+  private:
+  Taz() {}
+  public:
+
   Taz(const Taz& other)
    : tag(other.tag) {
     switch (tag) {
@@ -0,0 +1,206 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const FillRule: {
+  readonly A: 0;
+  readonly B: 1;
+};
+
+export declare const Foo_u32_Tag: {
+  readonly Bar_u32: 0;
+  readonly Polygon1_u32: 1;
+  readonly Slice1_u32: 2;
+  readonly Slice2_u32: 3;
+  readonly Slice3_u32: 4;
+  readonly Slice4_u32: 5;
+};
+
+export declare const Baz_i32_Tag: {
+  readonly Bar2_i32: 0;
+  readonly Polygon21_i32: 1;
+  readonly Slice21_i32: 2;
+  readonly Slice22_i32: 3;
+  readonly Slice23_i32: 4;
+  readonly Slice24_i32: 5;
+};
+
+export declare const Taz_Tag: {
+  readonly Bar3: 0;
+  readonly Taz1: 1;
+};
+
+export declare const Tazz_Tag: {
+  readonly Bar4: 0;
+  readonly Taz2: 1;
+};
+
+/**
+ * This will have a destructor manually implemented via variant_body, and
+ * similarly a Drop impl in Rust.
+ */
+export interface OwnedSlice_u32 {
+  len: number | string;
+  ptr: Buffer;
+  ref(): Buffer;
+}
+export declare const OwnedSlice_u32: StructType<OwnedSlice_u32>;
+
+export interface Polygon_u32 {
+  fill: number;
+  coordinates: OwnedSlice_u32;
+  ref(): Buffer;
+}
+export declare const Polygon_u32: StructType<Polygon_u32>;
+
+/**
+ * This will have a destructor manually implemented via variant_body, and
+ * similarly a Drop impl in Rust.
+ */
+export interface OwnedSlice_i32 {
+  len: number | string;
+  ptr: Buffer;
+  ref(): Buffer;
+}
+export declare const OwnedSlice_i32: StructType<OwnedSlice_i32>;
+
+export interface Polygon1_Body_u32 {
+  _0: Polygon_u32;
+  ref(): Buffer;
+}
+export declare const Polygon1_Body_u32: StructType<Polygon1_Body_u32>;
+
+export interface Slice1_Body_u32 {
+  _0: OwnedSlice_u32;
+  ref(): Buffer;
+}
+export declare const Slice1_Body_u32: StructType<Slice1_Body_u32>;
+
+export interface Slice2_Body_u32 {
+  _0: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice2_Body_u32: StructType<Slice2_Body_u32>;
+
+export interface Slice3_Body_u32 {
+  fill: number;
+  coords: OwnedSlice_u32;
+  ref(): Buffer;
+}
+export declare const Slice3_Body_u32: StructType<Slice3_Body_u32>;
+
+export interface Slice4_Body_u32 {
+  fill: number;
+  coords: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice4_Body_u32: StructType<Slice4_Body_u32>;
+
+export interface Foo_u32 {
+  tag: number;
+  variants: {
+    polygon1: Polygon1_Body_u32;
+    slice1: Slice1_Body_u32;
+    slice2: Slice2_Body_u32;
+    slice3: Slice3_Body_u32;
+    slice4: Slice4_Body_u32;
+  };
+  ref(): Buffer;
+}
+export declare const Foo_u32: StructType<Foo_u32>;
+
+export interface Polygon_i32 {
+  fill: number;
+  coordinates: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Polygon_i32: StructType<Polygon_i32>;
+
+export interface Polygon21_Body_i32 {
+  tag: number;
+  _0: Polygon_i32;
+  ref(): Buffer;
+}
+export declare const Polygon21_Body_i32: StructType<Polygon21_Body_i32>;
+
+export interface Slice21_Body_i32 {
+  tag: number;
+  _0: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice21_Body_i32: StructType<Slice21_Body_i32>;
+
+export interface Slice22_Body_i32 {
+  tag: number;
+  _0: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice22_Body_i32: StructType<Slice22_Body_i32>;
+
+export interface Slice23_Body_i32 {
+  tag: number;
+  fill: number;
+  coords: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice23_Body_i32: StructType<Slice23_Body_i32>;
+
+export interface Slice24_Body_i32 {
+  tag: number;
+  fill: number;
+  coords: OwnedSlice_i32;
+  ref(): Buffer;
+}
+export declare const Slice24_Body_i32: StructType<Slice24_Body_i32>;
+
+export interface Baz_i32 {
+  tag: number;
+  polygon21: Polygon21_Body_i32;
+  slice21: Slice21_Body_i32;
+  slice22: Slice22_Body_i32;
+  slice23: Slice23_Body_i32;
+  slice24: Slice24_Body_i32;
+  ref(): Buffer;
+}
+export declare const Baz_i32: StructType<Baz_i32>;
+
+export interface Taz1_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Taz1_Body: StructType<Taz1_Body>;
+
+export interface Taz {
+  tag: number;
+  taz1: Taz1_Body;
+  ref(): Buffer;
+}
+export declare const Taz: StructType<Taz>;
+
+export interface Taz2_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Taz2_Body: StructType<Taz2_Body>;
+
+export interface Tazz {
+  tag: number;
+  taz2: Taz2_Body;
+  ref(): Buffer;
+}
+export declare const Tazz: StructType<Tazz>;
+
+export interface Library {
+  root(a: Buffer | null, b: Buffer | null, c: Buffer | null, d: Tazz): void;
+}
+
+export declare function load(path: string): Library;
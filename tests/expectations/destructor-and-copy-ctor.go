@@ -0,0 +1,95 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type FillRule = C.FillRule
+
+const (
+  A FillRule = C.A
+  B FillRule = C.B
+)
+
+// This will have a destructor manually implemented via variant_body, and
+// similarly a Drop impl in Rust.
+type OwnedSlice_u32 = C.OwnedSlice_u32
+
+type Polygon_u32 = C.Polygon_u32
+
+// This will have a destructor manually implemented via variant_body, and
+// similarly a Drop impl in Rust.
+type OwnedSlice_i32 = C.OwnedSlice_i32
+
+type Foo_u32_Tag = C.Foo_u32_Tag
+
+const (
+  Bar_u32 Foo_u32_Tag = C.Bar_u32
+  Polygon1_u32 Foo_u32_Tag = C.Polygon1_u32
+  Slice1_u32 Foo_u32_Tag = C.Slice1_u32
+  Slice2_u32 Foo_u32_Tag = C.Slice2_u32
+  Slice3_u32 Foo_u32_Tag = C.Slice3_u32
+  Slice4_u32 Foo_u32_Tag = C.Slice4_u32
+)
+
+type Polygon1_Body_u32 = C.Polygon1_Body_u32
+
+type Slice1_Body_u32 = C.Slice1_Body_u32
+
+type Slice2_Body_u32 = C.Slice2_Body_u32
+
+type Slice3_Body_u32 = C.Slice3_Body_u32
+
+type Slice4_Body_u32 = C.Slice4_Body_u32
+
+type Foo_u32 = C.Foo_u32
+
+type Polygon_i32 = C.Polygon_i32
+
+type Baz_i32_Tag = C.Baz_i32_Tag
+
+const (
+  Bar2_i32 Baz_i32_Tag = C.Bar2_i32
+  Polygon21_i32 Baz_i32_Tag = C.Polygon21_i32
+  Slice21_i32 Baz_i32_Tag = C.Slice21_i32
+  Slice22_i32 Baz_i32_Tag = C.Slice22_i32
+  Slice23_i32 Baz_i32_Tag = C.Slice23_i32
+  Slice24_i32 Baz_i32_Tag = C.Slice24_i32
+)
+
+type Polygon21_Body_i32 = C.Polygon21_Body_i32
+
+type Slice21_Body_i32 = C.Slice21_Body_i32
+
+type Slice22_Body_i32 = C.Slice22_Body_i32
+
+type Slice23_Body_i32 = C.Slice23_Body_i32
+
+type Slice24_Body_i32 = C.Slice24_Body_i32
+
+type Baz_i32 = C.Baz_i32
+
+type Taz_Tag = C.Taz_Tag
+
+const (
+  Bar3 Taz_Tag = C.Bar3
+  Taz1 Taz_Tag = C.Taz1
+)
+
+type Taz1_Body = C.Taz1_Body
+
+type Taz = C.Taz
+
+type Tazz_Tag = C.Tazz_Tag
+
+const (
+  Bar4 Tazz_Tag = C.Bar4
+  Taz2 Tazz_Tag = C.Taz2
+)
+
+type Taz2_Body = C.Taz2_Body
+
+type Tazz = C.Tazz
+
+func Root(a *Foo_u32, b *Baz_i32, c *Taz, d Tazz) {
+  C.root(a, b, c, d)
+}
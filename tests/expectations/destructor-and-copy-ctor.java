@@ -0,0 +1,268 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface FillRule {
+    byte A = 0;
+    byte B = 1;
+  }
+
+  /**
+   * This will have a destructor manually implemented via variant_body, and
+   * similarly a Drop impl in Rust.
+   */
+  @Structure.FieldOrder({"len", "ptr"})
+  class OwnedSlice_u32 extends Structure {
+    public SizeT len;
+    public Pointer ptr;
+
+    public static class ByReference extends OwnedSlice_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends OwnedSlice_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"fill", "coordinates"})
+  class Polygon_u32 extends Structure {
+    public byte fill;
+    public OwnedSlice_u32 coordinates;
+
+    public static class ByReference extends Polygon_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Polygon_u32 implements Structure.ByValue {}
+  }
+
+  /**
+   * This will have a destructor manually implemented via variant_body, and
+   * similarly a Drop impl in Rust.
+   */
+  @Structure.FieldOrder({"len", "ptr"})
+  class OwnedSlice_i32 extends Structure {
+    public SizeT len;
+    public Pointer ptr;
+
+    public static class ByReference extends OwnedSlice_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends OwnedSlice_i32 implements Structure.ByValue {}
+  }
+
+  interface Foo_u32_Tag {
+    byte Bar_u32 = 0;
+    byte Polygon1_u32 = 1;
+    byte Slice1_u32 = 2;
+    byte Slice2_u32 = 3;
+    byte Slice3_u32 = 4;
+    byte Slice4_u32 = 5;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Polygon1_Body_u32 extends Structure {
+    public Polygon_u32 _0;
+
+    public static class ByReference extends Polygon1_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Polygon1_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Slice1_Body_u32 extends Structure {
+    public OwnedSlice_u32 _0;
+
+    public static class ByReference extends Slice1_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice1_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Slice2_Body_u32 extends Structure {
+    public OwnedSlice_i32 _0;
+
+    public static class ByReference extends Slice2_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice2_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"fill", "coords"})
+  class Slice3_Body_u32 extends Structure {
+    public byte fill;
+    public OwnedSlice_u32 coords;
+
+    public static class ByReference extends Slice3_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice3_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"fill", "coords"})
+  class Slice4_Body_u32 extends Structure {
+    public byte fill;
+    public OwnedSlice_i32 coords;
+
+    public static class ByReference extends Slice4_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice4_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Foo_u32 extends Structure {
+    public static class Variants extends Union {
+      public Polygon1_Body_u32 polygon1;
+      public Slice1_Body_u32 slice1;
+      public Slice2_Body_u32 slice2;
+      public Slice3_Body_u32 slice3;
+      public Slice4_Body_u32 slice4;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Foo_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"fill", "coordinates"})
+  class Polygon_i32 extends Structure {
+    public byte fill;
+    public OwnedSlice_i32 coordinates;
+
+    public static class ByReference extends Polygon_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Polygon_i32 implements Structure.ByValue {}
+  }
+
+  interface Baz_i32_Tag {
+    byte Bar2_i32 = 0;
+    byte Polygon21_i32 = 1;
+    byte Slice21_i32 = 2;
+    byte Slice22_i32 = 3;
+    byte Slice23_i32 = 4;
+    byte Slice24_i32 = 5;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Polygon21_Body_i32 extends Structure {
+    public byte tag;
+    public Polygon_i32 _0;
+
+    public static class ByReference extends Polygon21_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Polygon21_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Slice21_Body_i32 extends Structure {
+    public byte tag;
+    public OwnedSlice_i32 _0;
+
+    public static class ByReference extends Slice21_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice21_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Slice22_Body_i32 extends Structure {
+    public byte tag;
+    public OwnedSlice_i32 _0;
+
+    public static class ByReference extends Slice22_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice22_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "fill", "coords"})
+  class Slice23_Body_i32 extends Structure {
+    public byte tag;
+    public byte fill;
+    public OwnedSlice_i32 coords;
+
+    public static class ByReference extends Slice23_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice23_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "fill", "coords"})
+  class Slice24_Body_i32 extends Structure {
+    public byte tag;
+    public byte fill;
+    public OwnedSlice_i32 coords;
+
+    public static class ByReference extends Slice24_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice24_Body_i32 implements Structure.ByValue {}
+  }
+
+  class Baz_i32 extends Union {
+    public byte tag;
+    public Polygon21_Body_i32 polygon21;
+    public Slice21_Body_i32 slice21;
+    public Slice22_Body_i32 slice22;
+    public Slice23_Body_i32 slice23;
+    public Slice24_Body_i32 slice24;
+
+    public static class ByReference extends Baz_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Baz_i32 implements Structure.ByValue {}
+  }
+
+  interface Taz_Tag {
+    byte Bar3 = 0;
+    byte Taz1 = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Taz1_Body extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends Taz1_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Taz1_Body implements Structure.ByValue {}
+  }
+
+  class Taz extends Union {
+    public byte tag;
+    public Taz1_Body taz1;
+
+    public static class ByReference extends Taz implements Structure.ByReference {}
+
+    public static class ByValue extends Taz implements Structure.ByValue {}
+  }
+
+  interface Tazz_Tag {
+    byte Bar4 = 0;
+    byte Taz2 = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Taz2_Body extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends Taz2_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Taz2_Body implements Structure.ByValue {}
+  }
+
+  class Tazz extends Union {
+    public byte tag;
+    public Taz2_Body taz2;
+
+    public static class ByReference extends Tazz implements Structure.ByReference {}
+
+    public static class ByValue extends Tazz implements Structure.ByValue {}
+  }
+
+  void root(Foo_u32.ByReference a, Baz_i32.ByReference b, Taz.ByReference c, Tazz.ByValue d);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,168 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const OwnedSlice_u32 = StructType();
+const Polygon_u32 = StructType();
+const OwnedSlice_i32 = StructType();
+const Polygon1_Body_u32 = StructType();
+const Slice1_Body_u32 = StructType();
+const Slice2_Body_u32 = StructType();
+const Slice3_Body_u32 = StructType();
+const Slice4_Body_u32 = StructType();
+const _Foo_u32_Variants = UnionType();
+const Foo_u32 = StructType();
+const Polygon_i32 = StructType();
+const Polygon21_Body_i32 = StructType();
+const Slice21_Body_i32 = StructType();
+const Slice22_Body_i32 = StructType();
+const Slice23_Body_i32 = StructType();
+const Slice24_Body_i32 = StructType();
+const Baz_i32 = UnionType();
+const Taz1_Body = StructType();
+const Taz = UnionType();
+const Taz2_Body = StructType();
+const Tazz = UnionType();
+
+OwnedSlice_u32.defineProperty('len', 'size_t');
+OwnedSlice_u32.defineProperty('ptr', ref.refType('uint32'));
+
+Polygon_u32.defineProperty('fill', 'uint8');
+Polygon_u32.defineProperty('coordinates', OwnedSlice_u32);
+
+OwnedSlice_i32.defineProperty('len', 'size_t');
+OwnedSlice_i32.defineProperty('ptr', ref.refType('int32'));
+
+Polygon1_Body_u32.defineProperty('_0', Polygon_u32);
+
+Slice1_Body_u32.defineProperty('_0', OwnedSlice_u32);
+
+Slice2_Body_u32.defineProperty('_0', OwnedSlice_i32);
+
+Slice3_Body_u32.defineProperty('fill', 'uint8');
+Slice3_Body_u32.defineProperty('coords', OwnedSlice_u32);
+
+Slice4_Body_u32.defineProperty('fill', 'uint8');
+Slice4_Body_u32.defineProperty('coords', OwnedSlice_i32);
+
+_Foo_u32_Variants.defineProperty('polygon1', Polygon1_Body_u32);
+_Foo_u32_Variants.defineProperty('slice1', Slice1_Body_u32);
+_Foo_u32_Variants.defineProperty('slice2', Slice2_Body_u32);
+_Foo_u32_Variants.defineProperty('slice3', Slice3_Body_u32);
+_Foo_u32_Variants.defineProperty('slice4', Slice4_Body_u32);
+
+Foo_u32.defineProperty('tag', 'uint8');
+Foo_u32.defineProperty('variants', _Foo_u32_Variants);
+
+Polygon_i32.defineProperty('fill', 'uint8');
+Polygon_i32.defineProperty('coordinates', OwnedSlice_i32);
+
+Polygon21_Body_i32.defineProperty('tag', 'uint8');
+Polygon21_Body_i32.defineProperty('_0', Polygon_i32);
+
+Slice21_Body_i32.defineProperty('tag', 'uint8');
+Slice21_Body_i32.defineProperty('_0', OwnedSlice_i32);
+
+Slice22_Body_i32.defineProperty('tag', 'uint8');
+Slice22_Body_i32.defineProperty('_0', OwnedSlice_i32);
+
+Slice23_Body_i32.defineProperty('tag', 'uint8');
+Slice23_Body_i32.defineProperty('fill', 'uint8');
+Slice23_Body_i32.defineProperty('coords', OwnedSlice_i32);
+
+Slice24_Body_i32.defineProperty('tag', 'uint8');
+Slice24_Body_i32.defineProperty('fill', 'uint8');
+Slice24_Body_i32.defineProperty('coords', OwnedSlice_i32);
+
+Baz_i32.defineProperty('tag', 'uint8');
+Baz_i32.defineProperty('polygon21', Polygon21_Body_i32);
+Baz_i32.defineProperty('slice21', Slice21_Body_i32);
+Baz_i32.defineProperty('slice22', Slice22_Body_i32);
+Baz_i32.defineProperty('slice23', Slice23_Body_i32);
+Baz_i32.defineProperty('slice24', Slice24_Body_i32);
+
+Taz1_Body.defineProperty('tag', 'uint8');
+Taz1_Body.defineProperty('_0', 'int32');
+
+Taz.defineProperty('tag', 'uint8');
+Taz.defineProperty('taz1', Taz1_Body);
+
+Taz2_Body.defineProperty('tag', 'uint8');
+Taz2_Body.defineProperty('_0', 'int32');
+
+Tazz.defineProperty('tag', 'uint8');
+Tazz.defineProperty('taz2', Taz2_Body);
+
+const FillRule = Object.freeze({
+  A: 0,
+  B: 1,
+});
+
+const Foo_u32_Tag = Object.freeze({
+  Bar_u32: 0,
+  Polygon1_u32: 1,
+  Slice1_u32: 2,
+  Slice2_u32: 3,
+  Slice3_u32: 4,
+  Slice4_u32: 5,
+});
+
+const Baz_i32_Tag = Object.freeze({
+  Bar2_i32: 0,
+  Polygon21_i32: 1,
+  Slice21_i32: 2,
+  Slice22_i32: 3,
+  Slice23_i32: 4,
+  Slice24_i32: 5,
+});
+
+const Taz_Tag = Object.freeze({
+  Bar3: 0,
+  Taz1: 1,
+});
+
+const Tazz_Tag = Object.freeze({
+  Bar4: 0,
+  Taz2: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [ref.refType(Foo_u32), ref.refType(Baz_i32), ref.refType(Taz), Tazz]],
+  });
+  return lib;
+}
+
+module.exports = {
+  OwnedSlice_u32,
+  Polygon_u32,
+  OwnedSlice_i32,
+  Polygon1_Body_u32,
+  Slice1_Body_u32,
+  Slice2_Body_u32,
+  Slice3_Body_u32,
+  Slice4_Body_u32,
+  Foo_u32,
+  Polygon_i32,
+  Polygon21_Body_i32,
+  Slice21_Body_i32,
+  Slice22_Body_i32,
+  Slice23_Body_i32,
+  Slice24_Body_i32,
+  Baz_i32,
+  Taz1_Body,
+  Taz,
+  Taz2_Body,
+  Tazz,
+  FillRule,
+  Foo_u32_Tag,
+  Baz_i32_Tag,
+  Taz_Tag,
+  Tazz_Tag,
+  load,
+};
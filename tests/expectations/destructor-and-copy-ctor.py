@@ -0,0 +1,216 @@
+import ctypes
+
+FillRule = ctypes.c_uint8
+A = 0
+B = 1
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+class OwnedSlice_u32(ctypes.Structure):
+  pass
+
+OwnedSlice_u32._fields_ = [
+  ("len", ctypes.c_size_t),
+  ("ptr", ctypes.POINTER(ctypes.c_uint32)),
+]
+
+class Polygon_u32(ctypes.Structure):
+  pass
+
+Polygon_u32._fields_ = [
+  ("fill", FillRule),
+  ("coordinates", OwnedSlice_u32),
+]
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+class OwnedSlice_i32(ctypes.Structure):
+  pass
+
+OwnedSlice_i32._fields_ = [
+  ("len", ctypes.c_size_t),
+  ("ptr", ctypes.POINTER(ctypes.c_int32)),
+]
+
+Foo_u32_Tag = ctypes.c_uint8
+Bar_u32 = 0
+Polygon1_u32 = 1
+Slice1_u32 = 2
+Slice2_u32 = 3
+Slice3_u32 = 4
+Slice4_u32 = 5
+
+class Polygon1_Body_u32(ctypes.Structure):
+  pass
+
+Polygon1_Body_u32._fields_ = [
+  ("_0", Polygon_u32),
+]
+
+class Slice1_Body_u32(ctypes.Structure):
+  pass
+
+Slice1_Body_u32._fields_ = [
+  ("_0", OwnedSlice_u32),
+]
+
+class Slice2_Body_u32(ctypes.Structure):
+  pass
+
+Slice2_Body_u32._fields_ = [
+  ("_0", OwnedSlice_i32),
+]
+
+class Slice3_Body_u32(ctypes.Structure):
+  pass
+
+Slice3_Body_u32._fields_ = [
+  ("fill", FillRule),
+  ("coords", OwnedSlice_u32),
+]
+
+class Slice4_Body_u32(ctypes.Structure):
+  pass
+
+Slice4_Body_u32._fields_ = [
+  ("fill", FillRule),
+  ("coords", OwnedSlice_i32),
+]
+
+class _Foo_u32_Variants(ctypes.Union):
+  pass
+
+_Foo_u32_Variants._fields_ = [
+  ("polygon1", Polygon1_Body_u32),
+  ("slice1", Slice1_Body_u32),
+  ("slice2", Slice2_Body_u32),
+  ("slice3", Slice3_Body_u32),
+  ("slice4", Slice4_Body_u32),
+]
+
+class Foo_u32(ctypes.Structure):
+  pass
+
+Foo_u32._anonymous_ = ("_variants",)
+Foo_u32._fields_ = [
+  ("tag", Foo_u32_Tag),
+  ("_variants", _Foo_u32_Variants),
+]
+
+class Polygon_i32(ctypes.Structure):
+  pass
+
+Polygon_i32._fields_ = [
+  ("fill", FillRule),
+  ("coordinates", OwnedSlice_i32),
+]
+
+Baz_i32_Tag = ctypes.c_uint8
+Bar2_i32 = 0
+Polygon21_i32 = 1
+Slice21_i32 = 2
+Slice22_i32 = 3
+Slice23_i32 = 4
+Slice24_i32 = 5
+
+class Polygon21_Body_i32(ctypes.Structure):
+  pass
+
+Polygon21_Body_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("_0", Polygon_i32),
+]
+
+class Slice21_Body_i32(ctypes.Structure):
+  pass
+
+Slice21_Body_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("_0", OwnedSlice_i32),
+]
+
+class Slice22_Body_i32(ctypes.Structure):
+  pass
+
+Slice22_Body_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("_0", OwnedSlice_i32),
+]
+
+class Slice23_Body_i32(ctypes.Structure):
+  pass
+
+Slice23_Body_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("fill", FillRule),
+  ("coords", OwnedSlice_i32),
+]
+
+class Slice24_Body_i32(ctypes.Structure):
+  pass
+
+Slice24_Body_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("fill", FillRule),
+  ("coords", OwnedSlice_i32),
+]
+
+class Baz_i32(ctypes.Union):
+  pass
+
+Baz_i32._fields_ = [
+  ("tag", Baz_i32_Tag),
+  ("polygon21", Polygon21_Body_i32),
+  ("slice21", Slice21_Body_i32),
+  ("slice22", Slice22_Body_i32),
+  ("slice23", Slice23_Body_i32),
+  ("slice24", Slice24_Body_i32),
+]
+
+Taz_Tag = ctypes.c_uint8
+Bar3 = 0
+Taz1 = 1
+
+class Taz1_Body(ctypes.Structure):
+  pass
+
+Taz1_Body._fields_ = [
+  ("tag", Taz_Tag),
+  ("_0", ctypes.c_int32),
+]
+
+class Taz(ctypes.Union):
+  pass
+
+Taz._fields_ = [
+  ("tag", Taz_Tag),
+  ("taz1", Taz1_Body),
+]
+
+Tazz_Tag = ctypes.c_uint8
+Bar4 = 0
+Taz2 = 1
+
+class Taz2_Body(ctypes.Structure):
+  pass
+
+Taz2_Body._fields_ = [
+  ("tag", Tazz_Tag),
+  ("_0", ctypes.c_int32),
+]
+
+class Tazz(ctypes.Union):
+  pass
+
+Tazz._fields_ = [
+  ("tag", Tazz_Tag),
+  ("taz2", Taz2_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo_u32), ctypes.POINTER(Baz_i32), ctypes.POINTER(Taz), Tazz]
+  lib.root.restype = None
+
+  return lib
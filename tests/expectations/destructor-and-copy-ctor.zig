@@ -0,0 +1,149 @@
+const std = @import("std");
+
+pub const FillRule = enum(u8) {
+  A,
+  B,
+};
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+pub const OwnedSlice_u32 = extern struct {
+  len: usize,
+  ptr: *u32,
+};
+
+pub const Polygon_u32 = extern struct {
+  fill: FillRule,
+  coordinates: OwnedSlice_u32,
+};
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+pub const OwnedSlice_i32 = extern struct {
+  len: usize,
+  ptr: *i32,
+};
+
+pub const Foo_u32_Tag = enum(u8) {
+  Bar_u32,
+  Polygon1_u32,
+  Slice1_u32,
+  Slice2_u32,
+  Slice3_u32,
+  Slice4_u32,
+};
+
+pub const Polygon1_Body_u32 = extern struct {
+  _0: Polygon_u32,
+};
+
+pub const Slice1_Body_u32 = extern struct {
+  _0: OwnedSlice_u32,
+};
+
+pub const Slice2_Body_u32 = extern struct {
+  _0: OwnedSlice_i32,
+};
+
+pub const Slice3_Body_u32 = extern struct {
+  fill: FillRule,
+  coords: OwnedSlice_u32,
+};
+
+pub const Slice4_Body_u32 = extern struct {
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Foo_u32 = extern struct {
+  tag: Foo_u32_Tag,
+  body: extern union {
+    polygon1: Polygon1_Body_u32,
+    slice1: Slice1_Body_u32,
+    slice2: Slice2_Body_u32,
+    slice3: Slice3_Body_u32,
+    slice4: Slice4_Body_u32,
+  },
+};
+
+pub const Polygon_i32 = extern struct {
+  fill: FillRule,
+  coordinates: OwnedSlice_i32,
+};
+
+pub const Baz_i32_Tag = enum(u8) {
+  Bar2_i32,
+  Polygon21_i32,
+  Slice21_i32,
+  Slice22_i32,
+  Slice23_i32,
+  Slice24_i32,
+};
+
+pub const Polygon21_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  _0: Polygon_i32,
+};
+
+pub const Slice21_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  _0: OwnedSlice_i32,
+};
+
+pub const Slice22_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  _0: OwnedSlice_i32,
+};
+
+pub const Slice23_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Slice24_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Baz_i32 = extern union {
+  tag: Baz_i32_Tag,
+  polygon21: Polygon21_Body_i32,
+  slice21: Slice21_Body_i32,
+  slice22: Slice22_Body_i32,
+  slice23: Slice23_Body_i32,
+  slice24: Slice24_Body_i32,
+};
+
+pub const Taz_Tag = enum(u8) {
+  Bar3,
+  Taz1,
+};
+
+pub const Taz1_Body = extern struct {
+  tag: Taz_Tag,
+  _0: i32,
+};
+
+pub const Taz = extern union {
+  tag: Taz_Tag,
+  taz1: Taz1_Body,
+};
+
+pub const Tazz_Tag = enum(u8) {
+  Bar4,
+  Taz2,
+};
+
+pub const Taz2_Body = extern struct {
+  tag: Tazz_Tag,
+  _0: i32,
+};
+
+pub const Tazz = extern union {
+  tag: Tazz_Tag,
+  taz2: Taz2_Body,
+};
+
+pub extern fn root(a: *const Foo_u32, b: *const Baz_i32, c: *const Taz, d: Tazz) void;
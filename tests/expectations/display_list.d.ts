@@ -0,0 +1,64 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const DisplayItem_Tag: {
+  readonly Fill: 0;
+  readonly Image: 1;
+  readonly ClearScreen: 2;
+};
+
+export interface Rect {
+  x: number;
+  y: number;
+  w: number;
+  h: number;
+  ref(): Buffer;
+}
+export declare const Rect: StructType<Rect>;
+
+export interface Color {
+  r: number;
+  g: number;
+  b: number;
+  a: number;
+  ref(): Buffer;
+}
+export declare const Color: StructType<Color>;
+
+export interface Fill_Body {
+  tag: number;
+  _0: Rect;
+  _1: Color;
+  ref(): Buffer;
+}
+export declare const Fill_Body: StructType<Fill_Body>;
+
+export interface Image_Body {
+  tag: number;
+  id: number;
+  bounds: Rect;
+  ref(): Buffer;
+}
+export declare const Image_Body: StructType<Image_Body>;
+
+export interface DisplayItem {
+  tag: number;
+  fill: Fill_Body;
+  image: Image_Body;
+  ref(): Buffer;
+}
+export declare const DisplayItem: StructType<DisplayItem>;
+
+export interface Library {
+  push_item(item: DisplayItem): boolean;
+}
+
+export declare function load(path: string): Library;
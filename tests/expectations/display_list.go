@@ -0,0 +1,26 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Rect = C.Rect
+
+type Color = C.Color
+
+type DisplayItem_Tag = C.DisplayItem_Tag
+
+const (
+  Fill DisplayItem_Tag = C.Fill
+  Image DisplayItem_Tag = C.Image
+  ClearScreen DisplayItem_Tag = C.ClearScreen
+)
+
+type Fill_Body = C.Fill_Body
+
+type Image_Body = C.Image_Body
+
+type DisplayItem = C.DisplayItem
+
+func PushItem(item DisplayItem) bool {
+  return bool(C.push_item(item))
+}
@@ -0,0 +1,67 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y", "w", "h"})
+  class Rect extends Structure {
+    public float x;
+    public float y;
+    public float w;
+    public float h;
+
+    public static class ByReference extends Rect implements Structure.ByReference {}
+
+    public static class ByValue extends Rect implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"r", "g", "b", "a"})
+  class Color extends Structure {
+    public byte r;
+    public byte g;
+    public byte b;
+    public byte a;
+
+    public static class ByReference extends Color implements Structure.ByReference {}
+
+    public static class ByValue extends Color implements Structure.ByValue {}
+  }
+
+  interface DisplayItem_Tag {
+    byte Fill = 0;
+    byte Image = 1;
+    byte ClearScreen = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0", "_1"})
+  class Fill_Body extends Structure {
+    public byte tag;
+    public Rect _0;
+    public Color _1;
+
+    public static class ByReference extends Fill_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Fill_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "id", "bounds"})
+  class Image_Body extends Structure {
+    public byte tag;
+    public int id;
+    public Rect bounds;
+
+    public static class ByReference extends Image_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Image_Body implements Structure.ByValue {}
+  }
+
+  class DisplayItem extends Union {
+    public byte tag;
+    public Fill_Body fill;
+    public Image_Body image;
+
+    public static class ByReference extends DisplayItem implements Structure.ByReference {}
+
+    public static class ByValue extends DisplayItem implements Structure.ByValue {}
+  }
+
+  byte push_item(DisplayItem.ByValue item);
+}
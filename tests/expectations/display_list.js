@@ -0,0 +1,59 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Rect = StructType();
+const Color = StructType();
+const Fill_Body = StructType();
+const Image_Body = StructType();
+const DisplayItem = UnionType();
+
+Rect.defineProperty('x', 'float');
+Rect.defineProperty('y', 'float');
+Rect.defineProperty('w', 'float');
+Rect.defineProperty('h', 'float');
+
+Color.defineProperty('r', 'uint8');
+Color.defineProperty('g', 'uint8');
+Color.defineProperty('b', 'uint8');
+Color.defineProperty('a', 'uint8');
+
+Fill_Body.defineProperty('tag', 'uint8');
+Fill_Body.defineProperty('_0', Rect);
+Fill_Body.defineProperty('_1', Color);
+
+Image_Body.defineProperty('tag', 'uint8');
+Image_Body.defineProperty('id', 'uint32');
+Image_Body.defineProperty('bounds', Rect);
+
+DisplayItem.defineProperty('tag', 'uint8');
+DisplayItem.defineProperty('fill', Fill_Body);
+DisplayItem.defineProperty('image', Image_Body);
+
+const DisplayItem_Tag = Object.freeze({
+  Fill: 0,
+  Image: 1,
+  ClearScreen: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    push_item: ['bool', [DisplayItem]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Rect,
+  Color,
+  Fill_Body,
+  Image_Body,
+  DisplayItem,
+  DisplayItem_Tag,
+  load,
+};
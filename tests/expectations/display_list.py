@@ -0,0 +1,61 @@
+import ctypes
+
+class Rect(ctypes.Structure):
+  pass
+
+Rect._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+  ("w", ctypes.c_float),
+  ("h", ctypes.c_float),
+]
+
+class Color(ctypes.Structure):
+  pass
+
+Color._fields_ = [
+  ("r", ctypes.c_uint8),
+  ("g", ctypes.c_uint8),
+  ("b", ctypes.c_uint8),
+  ("a", ctypes.c_uint8),
+]
+
+DisplayItem_Tag = ctypes.c_uint8
+Fill = 0
+Image = 1
+ClearScreen = 2
+
+class Fill_Body(ctypes.Structure):
+  pass
+
+Fill_Body._fields_ = [
+  ("tag", DisplayItem_Tag),
+  ("_0", Rect),
+  ("_1", Color),
+]
+
+class Image_Body(ctypes.Structure):
+  pass
+
+Image_Body._fields_ = [
+  ("tag", DisplayItem_Tag),
+  ("id", ctypes.c_uint32),
+  ("bounds", Rect),
+]
+
+class DisplayItem(ctypes.Union):
+  pass
+
+DisplayItem._fields_ = [
+  ("tag", DisplayItem_Tag),
+  ("fill", Fill_Body),
+  ("image", Image_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.push_item.argtypes = [DisplayItem]
+  lib.push_item.restype = ctypes.c_bool
+
+  return lib
@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 18446744073709551615ULL
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+};
+typedef uint8_t Direction;
+
+typedef bool (*Callback)(uint32_t version, char *data);
+
+typedef struct {
+  /**
+   * Fields named after D keywords are escaped.
+   */
+  uint32_t module;
+  long version;
+  Callback callback;
+  const char *name;
+  uint8_t grid[3][2];
+} Options;
+
+enum Event_Tag {
+  Moved,
+  Renamed,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct {
+  Direction _0;
+} Moved_Body;
+
+typedef struct {
+  const char *name;
+} Renamed_Body;
+
+typedef struct {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+    Renamed_Body renamed;
+  };
+} Event;
+
+extern uint32_t COUNTER;
+
+void process(const Options *options, Event event, int64_t *ref_, uint8_t *out);
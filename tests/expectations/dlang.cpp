@@ -0,0 +1,53 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint64_t LIMIT = 18446744073709551615ULL;
+
+/// The direction of a move.
+enum class Direction : uint8_t {
+  Up,
+  Down = 4,
+};
+
+using Callback = bool(*)(uint32_t version, char *data);
+
+struct Options {
+  /// Fields named after D keywords are escaped.
+  uint32_t module;
+  long version;
+  Callback callback;
+  const char *name;
+  uint8_t grid[3][2];
+};
+
+struct Event {
+  enum class Tag : uint8_t {
+    Moved,
+    Renamed,
+    Closed,
+  };
+
+  struct Moved_Body {
+    Direction _0;
+  };
+
+  struct Renamed_Body {
+    const char *name;
+  };
+
+  Tag tag;
+  union {
+    Moved_Body moved;
+    Renamed_Body renamed;
+  };
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+void process(const Options *options, Event event, int64_t *ref_, uint8_t *out);
+
+} // extern "C"
@@ -0,0 +1,42 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+// The direction of a move.
+type Direction = C.Direction
+
+const (
+  Up Direction = C.Up
+  Down Direction = C.Down
+)
+
+type Callback = C.Callback
+
+type Options = C.Options
+
+type Event_Tag = C.Event_Tag
+
+const (
+  Moved Event_Tag = C.Moved
+  Renamed Event_Tag = C.Renamed
+  Closed Event_Tag = C.Closed
+)
+
+type Moved_Body = C.Moved_Body
+
+type Renamed_Body = C.Renamed_Body
+
+type Event = C.Event
+
+const LIMIT = C.LIMIT
+
+func Counter() uint32 {
+  return uint32(C.COUNTER)
+}
+
+func Process(options *Options, event Event, ref_ *int64, out *uint8) {
+  C.process(options, event, (*C.int64_t)(unsafe.Pointer(ref_)), (*C.uint8_t)(unsafe.Pointer(out)))
+}
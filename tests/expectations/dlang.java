@@ -0,0 +1,78 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  long LIMIT = 0xFFFFFFFFFFFFFFFFL;
+
+  /**
+   * The direction of a move.
+   */
+  interface Direction {
+    byte Up = 0;
+    byte Down = 4;
+  }
+
+  interface Callback extends Callback {
+    byte invoke(int version, Pointer data);
+  }
+
+  @Structure.FieldOrder({"module", "version", "callback", "name", "grid"})
+  class Options extends Structure {
+    /**
+     * Fields named after D keywords are escaped.
+     */
+    public int module;
+    public NativeLong version;
+    public Callback callback;
+    public Pointer name;
+    public byte[][] grid = new byte[3][];
+
+    public static class ByReference extends Options implements Structure.ByReference {}
+
+    public static class ByValue extends Options implements Structure.ByValue {}
+  }
+
+  interface Event_Tag {
+    byte Moved = 0;
+    byte Renamed = 1;
+    byte Closed = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Moved_Body extends Structure {
+    public byte _0;
+
+    public static class ByReference extends Moved_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Moved_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"name"})
+  class Renamed_Body extends Structure {
+    public Pointer name;
+
+    public static class ByReference extends Renamed_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Renamed_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Event extends Structure {
+    public static class Variants extends Union {
+      public Moved_Body moved;
+      public Renamed_Body renamed;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  void process(Options.ByReference options, Event.ByValue event, Pointer ref_, Pointer out);
+}
@@ -0,0 +1,66 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Options = StructType();
+const Moved_Body = StructType();
+const Renamed_Body = StructType();
+const _Event_Variants = UnionType();
+const Event = StructType();
+
+const LIMIT = 18446744073709551615n;
+
+Options.defineProperty('module', 'uint32');
+Options.defineProperty('version', 'long');
+Options.defineProperty('callback', 'pointer');
+Options.defineProperty('name', ref.refType('char'));
+Options.defineProperty('grid', ArrayType(ArrayType('uint8', 2), 3));
+
+Moved_Body.defineProperty('_0', 'uint8');
+
+Renamed_Body.defineProperty('name', ref.refType('char'));
+
+_Event_Variants.defineProperty('moved', Moved_Body);
+_Event_Variants.defineProperty('renamed', Renamed_Body);
+
+Event.defineProperty('tag', 'uint8');
+Event.defineProperty('variants', _Event_Variants);
+
+const Direction = Object.freeze({
+  Up: 0,
+  Down: 4,
+});
+
+const Event_Tag = Object.freeze({
+  Moved: 0,
+  Renamed: 1,
+  Closed: 2,
+});
+
+const Callback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    process: ['void', [ref.refType(Options), Event, ref.refType('int64'), ref.refType('uint8')]],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.COUNTER = dylib.get('COUNTER');
+  return lib;
+}
+
+module.exports = {
+  Options,
+  Moved_Body,
+  Renamed_Body,
+  Event,
+  Direction,
+  Event_Tag,
+  Callback,
+  LIMIT,
+  load,
+};
@@ -0,0 +1,47 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint64_t LIMIT
+
+  # The direction of a move.
+  enum:
+    Up
+    Down
+  ctypedef uint8_t Direction
+
+  ctypedef bool (*Callback)(uint32_t version, char *data)
+
+  ctypedef struct Options:
+    # Fields named after D keywords are escaped.
+    uint32_t module
+    long version
+    Callback callback
+    const char *name
+    uint8_t grid[3][2]
+
+  enum:
+    Moved
+    Renamed
+    Closed
+  ctypedef uint8_t Event_Tag
+
+  ctypedef struct Moved_Body:
+    Direction _0
+
+  ctypedef struct Renamed_Body:
+    const char *name
+
+  ctypedef struct Event:
+    Event_Tag tag
+    Moved_Body moved
+    Renamed_Body renamed
+
+  uint32_t COUNTER
+
+  void process(const Options *options, Event event, int64_t *ref_, uint8_t *out)
@@ -0,0 +1,68 @@
+import ctypes
+
+LIMIT = 18446744073709551615
+
+# The direction of a move.
+Direction = ctypes.c_uint8
+Up = 0
+Down = 4
+
+Callback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_uint32, ctypes.POINTER(ctypes.c_char))
+
+class Options(ctypes.Structure):
+  pass
+
+Options._fields_ = [
+  # Fields named after D keywords are escaped.
+  ("module", ctypes.c_uint32),
+  ("version", ctypes.c_long),
+  ("callback", Callback),
+  ("name", ctypes.c_char_p),
+  ("grid", ((ctypes.c_uint8 * 2) * 3)),
+]
+
+Event_Tag = ctypes.c_uint8
+Moved = 0
+Renamed = 1
+Closed = 2
+
+class Moved_Body(ctypes.Structure):
+  pass
+
+Moved_Body._fields_ = [
+  ("_0", Direction),
+]
+
+class Renamed_Body(ctypes.Structure):
+  pass
+
+Renamed_Body._fields_ = [
+  ("name", ctypes.c_char_p),
+]
+
+class _Event_Variants(ctypes.Union):
+  pass
+
+_Event_Variants._fields_ = [
+  ("moved", Moved_Body),
+  ("renamed", Renamed_Body),
+]
+
+class Event(ctypes.Structure):
+  pass
+
+Event._anonymous_ = ("_variants",)
+Event._fields_ = [
+  ("tag", Event_Tag),
+  ("_variants", _Event_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  lib.process.argtypes = [ctypes.POINTER(Options), Event, ctypes.POINTER(ctypes.c_int64), ctypes.POINTER(ctypes.c_uint8)]
+  lib.process.restype = None
+
+  return lib
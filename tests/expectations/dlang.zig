@@ -0,0 +1,46 @@
+const std = @import("std");
+
+pub const LIMIT: u64 = 18446744073709551615;
+
+/// The direction of a move.
+pub const Direction = enum(u8) {
+  Up,
+  Down = 4,
+};
+
+pub const Callback = *const fn (u32, [*c]c_char) callconv(.C) bool;
+
+pub const Options = extern struct {
+  /// Fields named after D keywords are escaped.
+  module: u32,
+  version: c_long,
+  callback: Callback,
+  name: [*c]const c_char,
+  grid: [3][2]u8,
+};
+
+pub const Event_Tag = enum(u8) {
+  Moved,
+  Renamed,
+  Closed,
+};
+
+pub const Moved_Body = extern struct {
+  _0: Direction,
+};
+
+pub const Renamed_Body = extern struct {
+  name: [*c]const c_char,
+};
+
+pub const Event = extern struct {
+  tag: Event_Tag,
+  body: extern union {
+    moved: Moved_Body,
+    renamed: Renamed_Body,
+  },
+};
+
+pub extern var COUNTER: u32;
+
+pub extern fn process(options: [*c]const Options, event: Event, ref_: [*c]i64, out: [*c]u8) void;
@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#ifndef MYLIB_API
+#  if defined(MYLIB_STATIC)
+#    define MYLIB_API
+#  elif defined(_WIN32) || defined(__CYGWIN__)
+#    if defined(MYLIB_BUILD)
+#      define MYLIB_API __declspec(dllexport)
+#    else
+#      define MYLIB_API __declspec(dllimport)
+#    endif
+#  elif defined(__GNUC__) && __GNUC__ >= 4
+#    define MYLIB_API __attribute__((visibility("default")))
+#  else
+#    define MYLIB_API
+#  endif
+#endif
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+extern MYLIB_API Point ORIGIN;
+
+MYLIB_API float point_length(Point p);
+
+MYLIB_API void point_translate(Point *p, float dx, float dy, bool scale_first, float scale_factor);
+
+} // extern "C"
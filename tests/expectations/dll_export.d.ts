@@ -0,0 +1,27 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  ORIGIN: Buffer;
+
+  point_length(p: Point): number;
+
+  point_translate(p: Buffer | null, dx: number, dy: number, scale_first: boolean, scale_factor: number): void;
+}
+
+export declare function load(path: string): Library;
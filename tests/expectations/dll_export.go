@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+func Origin() Point {
+  return C.ORIGIN
+}
+
+func PointLength(p Point) float32 {
+  return float32(C.point_length(p))
+}
+
+func PointTranslate(p *Point, dx float32, dy float32, scale_first bool, scale_factor float32) {
+  C.point_translate(p, C.float(dx), C.float(dy), C.bool(scale_first), C.float(scale_factor))
+}
@@ -0,0 +1,17 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  float point_length(Point.ByValue p);
+
+  void point_translate(Point.ByReference p, float dx, float dy, byte scale_first, float scale_factor);
+}
@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    point_length: ['float', [Point]],
+    point_translate: ['void', [ref.refType(Point), 'float', 'float', 'bool', 'float']],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.ORIGIN = dylib.get('ORIGIN');
+  return lib;
+}
+
+module.exports = {
+  Point,
+  load,
+};
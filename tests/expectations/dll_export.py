@@ -0,0 +1,22 @@
+import ctypes
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.ORIGIN = Point.in_dll(lib, "ORIGIN")
+
+  lib.point_length.argtypes = [Point]
+  lib.point_length.restype = ctypes.c_float
+
+  lib.point_translate.argtypes = [ctypes.POINTER(Point), ctypes.c_float, ctypes.c_float, ctypes.c_bool, ctypes.c_float]
+  lib.point_translate.restype = None
+
+  return lib
@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern var ORIGIN: Point;
+
+pub extern fn point_length(p: Point) f32;
+
+pub extern fn point_translate(p: [*c]Point, dx: f32, dy: f32, scale_first: bool, scale_factor: f32) void;
@@ -0,0 +1,9 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// The root of all evil.
+func Root() {
+  C.root()
+}
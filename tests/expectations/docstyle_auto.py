@@ -0,0 +1,10 @@
+import ctypes
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # The root of all evil.
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
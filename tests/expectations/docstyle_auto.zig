@@ -0,0 +1,4 @@
+const std = @import("std");
+
+/// The root of all evil.
+pub extern fn root() void;
@@ -0,0 +1,8 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * The root of all evil.
+   */
+  void root();
+}
@@ -0,0 +1,38 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Buffer {
+  data: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Buffer: StructType<Buffer>;
+
+export interface Library {
+  /**
+   * Copies the contents of a {@link Buffer} into `dst`, see {@link buffer_len()}.
+   * @param buffer The {@link Buffer buffer} to copy from.
+   * @param dst The destination, which is
+   * at least {@link buffer_len} bytes long.
+   * @return The number of bytes copied.
+   * @warning `dst` must be valid for writes.
+   * # Examples
+   * See the [docs](https://docs.rs).
+   */
+  buffer_copy(buffer: Buffer | null, dst: Buffer | null): number | string;
+
+  /**
+   * Returns the length of `buffer`.
+   */
+  buffer_len(buffer: Buffer | null): number | string;
+}
+
+export declare function load(path: string): Library;
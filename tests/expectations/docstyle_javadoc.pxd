@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Buffer:
+    uint8_t *data
+    uintptr_t len
+
+  # Copies the contents of a [`Buffer`] into `dst`, see [`crate::buffer_len()`].
+  # # Arguments
+  #  `buffer` - The [buffer](Buffer) to copy from.
+  #  `dst` - The destination, which is
+  # at least [`buffer_len`] bytes long.
+  # # Returns
+  # The number of bytes copied.
+  # # Safety
+  # `dst` must be valid for writes.
+  # # Examples
+  # See the [docs](https://docs.rs).
+  uintptr_t buffer_copy(const Buffer *buffer, uint8_t *dst)
+
+  # Returns the length of `buffer`.
+  uintptr_t buffer_len(const Buffer *buffer)
@@ -0,0 +1,32 @@
+import ctypes
+
+class Buffer(ctypes.Structure):
+  pass
+
+Buffer._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # Copies the contents of a [`Buffer`] into `dst`, see [`crate::buffer_len()`].
+  # # Arguments
+  #  `buffer` - The [buffer](Buffer) to copy from.
+  #  `dst` - The destination, which is
+  # at least [`buffer_len`] bytes long.
+  # # Returns
+  # The number of bytes copied.
+  # # Safety
+  # `dst` must be valid for writes.
+  # # Examples
+  # See the [docs](https://docs.rs).
+  lib.buffer_copy.argtypes = [ctypes.POINTER(Buffer), ctypes.POINTER(ctypes.c_uint8)]
+  lib.buffer_copy.restype = ctypes.c_size_t
+
+  # Returns the length of `buffer`.
+  lib.buffer_len.argtypes = [ctypes.POINTER(Buffer)]
+  lib.buffer_len.restype = ctypes.c_size_t
+
+  return lib
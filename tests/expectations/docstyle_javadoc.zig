@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const Buffer = extern struct {
+  data: [*c]u8,
+  len: usize,
+};
+
+/// Copies the contents of a [`Buffer`] into `dst`, see [`crate::buffer_len()`].
+/// # Arguments
+///  `buffer` - The [buffer](Buffer) to copy from.
+///  `dst` - The destination, which is
+/// at least [`buffer_len`] bytes long.
+/// # Returns
+/// The number of bytes copied.
+/// # Safety
+/// `dst` must be valid for writes.
+/// # Examples
+/// See the [docs](https://docs.rs).
+pub extern fn buffer_copy(buffer: *const Buffer, dst: [*c]u8) usize;
+
+/// Returns the length of `buffer`.
+pub extern fn buffer_len(buffer: *const Buffer) usize;
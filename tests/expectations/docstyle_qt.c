@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+/*!
+ * Copies the contents of a \ref Buffer into `dst`, see \ref buffer_len().
+ * \param buffer The \ref Buffer "buffer" to copy from.
+ * \param dst The destination, which is
+ * at least \ref buffer_len bytes long.
+ * \return The number of bytes copied.
+ * \warning `dst` must be valid for writes.
+ * # Examples
+ * See the [docs](https://docs.rs).
+ */
+uintptr_t buffer_copy(const Buffer *buffer, uint8_t *dst);
+
+/*!
+ * Returns the length of `buffer`.
+ */
+uintptr_t buffer_len(const Buffer *buffer);
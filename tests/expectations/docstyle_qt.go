@@ -0,0 +1,28 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Buffer = C.Buffer
+
+// Copies the contents of a [`Buffer`] into `dst`, see [`crate::buffer_len()`].
+// # Arguments
+//  `buffer` - The [buffer](Buffer) to copy from.
+//  `dst` - The destination, which is
+// at least [`buffer_len`] bytes long.
+// # Returns
+// The number of bytes copied.
+// # Safety
+// `dst` must be valid for writes.
+// # Examples
+// See the [docs](https://docs.rs).
+func BufferCopy(buffer *Buffer, dst *uint8) uintptr {
+  return uintptr(C.buffer_copy(buffer, (*C.uint8_t)(unsafe.Pointer(dst))))
+}
+
+// Returns the length of `buffer`.
+func BufferLen(buffer *Buffer) uintptr {
+  return uintptr(C.buffer_len(buffer))
+}
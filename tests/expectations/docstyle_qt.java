@@ -0,0 +1,40 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"data", "len"})
+  class Buffer extends Structure {
+    public Pointer data;
+    public SizeT len;
+
+    public static class ByReference extends Buffer implements Structure.ByReference {}
+
+    public static class ByValue extends Buffer implements Structure.ByValue {}
+  }
+
+  /**
+   * Copies the contents of a {@link Buffer} into `dst`, see {@link buffer_len()}.
+   * @param buffer The {@link Buffer buffer} to copy from.
+   * @param dst The destination, which is
+   * at least {@link buffer_len} bytes long.
+   * @return The number of bytes copied.
+   * @warning `dst` must be valid for writes.
+   * # Examples
+   * See the [docs](https://docs.rs).
+   */
+  SizeT buffer_copy(Buffer.ByReference buffer, Pointer dst);
+
+  /**
+   * Returns the length of `buffer`.
+   */
+  SizeT buffer_len(Buffer.ByReference buffer);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,26 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Buffer = StructType();
+
+Buffer.defineProperty('data', ref.refType('uint8'));
+Buffer.defineProperty('len', 'size_t');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    buffer_copy: ['size_t', [ref.refType(Buffer), ref.refType('uint8')]],
+    buffer_len: ['size_t', [ref.refType(Buffer)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Buffer,
+  load,
+};
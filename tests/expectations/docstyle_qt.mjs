@@ -0,0 +1,45 @@
+/** The signatures of the functions the module exports. */
+export const functions = {
+  /**
+   * Copies the contents of a {@link Buffer} into `dst`, see {@link buffer_len()}.
+   * @param buffer The {@link Buffer buffer} to copy from.
+   * @param dst The destination, which is
+   * at least {@link buffer_len} bytes long.
+   * @return The number of bytes copied.
+   * @warning `dst` must be valid for writes.
+   * # Examples
+   * See the [docs](https://docs.rs).
+   */
+  buffer_copy: {
+    params: ["i32", "i32"],
+    results: ["i32"],
+  },
+  /**
+   * Returns the length of `buffer`.
+   */
+  buffer_len: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+};
+
+/** The signatures of the functions the module imports. */
+export const imports = {};
+
+/** The statics the module exports, as globals holding their address. */
+export const globals = [];
+
+/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */
+export function view(memory, type, ptr, len) {
+  return new type(memory.buffer, ptr, len);
+}
+
+/**
+ * Returns views of the slices that `args`, the arguments of a call to a
+ * function with `signature`, point to in `memory`.
+ */
+export function slices(memory, signature, args) {
+  return (signature.slices || []).map((slice) =>
+    view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),
+  );
+}
@@ -0,0 +1,151 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const A: {
+  readonly a1: 0;
+  readonly a2: 2;
+  readonly a3: 3;
+  readonly a4: 5;
+};
+
+export declare const B: {
+  readonly b1: 0;
+  readonly b2: 2;
+  readonly b3: 3;
+  readonly b4: 5;
+};
+
+export declare const C: {
+  readonly c1: 0;
+  readonly c2: 2;
+  readonly c3: 3;
+  readonly c4: 5;
+};
+
+export declare const D: {
+  readonly d1: 0;
+  readonly d2: 2;
+  readonly d3: 3;
+  readonly d4: 5;
+};
+
+export declare const E: {
+  readonly e1: 0;
+  readonly e2: 2;
+  readonly e3: 3;
+  readonly e4: 5;
+};
+
+export declare const K: {
+  readonly k1: 0;
+  readonly k2: 1;
+  readonly k3: 2;
+  readonly k4: 3;
+};
+
+export declare const L: {
+  readonly l1: -1;
+  readonly l2: 0;
+  readonly l3: 1;
+};
+
+export declare const F_Tag: {
+  readonly Foo: 0;
+  readonly Bar: 1;
+  readonly Baz: 2;
+};
+
+export declare const G_Tag: {
+  readonly G_Foo: 0;
+  readonly G_Bar: 1;
+  readonly G_Baz: 2;
+};
+
+export declare const H_Tag: {
+  readonly H_Foo: 0;
+  readonly H_Bar: 1;
+  readonly H_Baz: 2;
+};
+
+export interface Foo_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Foo_Body: StructType<Foo_Body>;
+
+export interface Bar_Body {
+  tag: number;
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Bar_Body: StructType<Bar_Body>;
+
+export interface F {
+  tag: number;
+  foo: Foo_Body;
+  bar: Bar_Body;
+  ref(): Buffer;
+}
+export declare const F: StructType<F>;
+
+export interface G_Foo_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const G_Foo_Body: StructType<G_Foo_Body>;
+
+export interface G_Bar_Body {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const G_Bar_Body: StructType<G_Bar_Body>;
+
+export interface G {
+  tag: number;
+  variants: {
+    foo: G_Foo_Body;
+    bar: G_Bar_Body;
+  };
+  ref(): Buffer;
+}
+export declare const G: StructType<G>;
+
+export interface H_Foo_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const H_Foo_Body: StructType<H_Foo_Body>;
+
+export interface H_Bar_Body {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const H_Bar_Body: StructType<H_Bar_Body>;
+
+export interface H {
+  tag: number;
+  variants: {
+    foo: H_Foo_Body;
+    bar: H_Bar_Body;
+  };
+  ref(): Buffer;
+}
+export declare const H: StructType<H>;
+
+export interface Library {
+  root(o: Buffer | null, a: number, b: number, c: number, d: number | string, e: number | string, f: F, g: G, h: H, i: never, j: never, k: number, l: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,116 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+const (
+  a1 A = C.a1
+  a2 A = C.a2
+  a3 A = C.a3
+  a4 A = C.a4
+)
+
+type B = C.B
+
+const (
+  b1 B = C.b1
+  b2 B = C.b2
+  b3 B = C.b3
+  b4 B = C.b4
+)
+
+const (
+  c1 C.C = C.c1
+  c2 C.C = C.c2
+  c3 C.C = C.c3
+  c4 C.C = C.c4
+)
+
+type D = C.D
+
+const (
+  d1 D = C.d1
+  d2 D = C.d2
+  d3 D = C.d3
+  d4 D = C.d4
+)
+
+type E = C.E
+
+const (
+  e1 E = C.e1
+  e2 E = C.e2
+  e3 E = C.e3
+  e4 E = C.e4
+)
+
+type K = C.K
+
+const (
+  k1 K = C.k1
+  k2 K = C.k2
+  k3 K = C.k3
+  k4 K = C.k4
+)
+
+type L = C.L
+
+const (
+  l1 L = C.l1
+  l2 L = C.l2
+  l3 L = C.l3
+)
+
+type I = C.I
+
+type J = C.J
+
+type Opaque = C.Opaque
+
+type F_Tag = C.F_Tag
+
+const (
+  Foo F_Tag = C.Foo
+  Bar F_Tag = C.Bar
+  Baz F_Tag = C.Baz
+)
+
+type Foo_Body = C.Foo_Body
+
+type Bar_Body = C.Bar_Body
+
+type F = C.F
+
+type G_Tag = C.G_Tag
+
+const (
+  G_Foo G_Tag = C.G_Foo
+  G_Bar G_Tag = C.G_Bar
+  G_Baz G_Tag = C.G_Baz
+)
+
+type G_Foo_Body = C.G_Foo_Body
+
+type G_Bar_Body = C.G_Bar_Body
+
+type G = C.G
+
+type H_Tag = C.H_Tag
+
+const (
+  H_Foo H_Tag = C.H_Foo
+  H_Bar H_Tag = C.H_Bar
+  H_Baz H_Tag = C.H_Baz
+)
+
+type H_Foo_Body = C.H_Foo_Body
+
+type H_Bar_Body = C.H_Bar_Body
+
+type H = C.H
+
+func Root(o *Opaque, a A, b B, c C.C, d D, e E, f F, g G, h H, i I, j J, k K, l L) {
+  C.root(o, a, b, c, d, e, f, g, h, i, j, k, l)
+}
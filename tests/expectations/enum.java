@@ -0,0 +1,198 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface A {
+    int a1 = 0;
+    int a2 = 2;
+    int a3 = 3;
+    int a4 = 5;
+  }
+
+  interface B {
+    short b1 = 0;
+    short b2 = 2;
+    short b3 = 3;
+    short b4 = 5;
+  }
+
+  interface C {
+    byte c1 = 0;
+    byte c2 = 2;
+    byte c3 = 3;
+    byte c4 = 5;
+  }
+
+  interface D {
+    long d1 = 0;
+    long d2 = 2;
+    long d3 = 3;
+    long d4 = 5;
+  }
+
+  interface E {
+    long e1 = 0;
+    long e2 = 2;
+    long e3 = 3;
+    long e4 = 5;
+  }
+
+  interface K {
+    int k1 = 0;
+    int k2 = 1;
+    int k3 = 2;
+    int k4 = 3;
+  }
+
+  interface L {
+    byte l1 = -1;
+    byte l2 = 0;
+    byte l3 = 1;
+  }
+
+  interface F_Tag {
+    byte Foo = 0;
+    byte Bar = 1;
+    byte Baz = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Foo_Body extends Structure {
+    public byte tag;
+    public short _0;
+
+    public static class ByReference extends Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "x", "y"})
+  class Bar_Body extends Structure {
+    public byte tag;
+    public byte x;
+    public short y;
+
+    public static class ByReference extends Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Bar_Body implements Structure.ByValue {}
+  }
+
+  class F extends Union {
+    public byte tag;
+    public Foo_Body foo;
+    public Bar_Body bar;
+
+    public static class ByReference extends F implements Structure.ByReference {}
+
+    public static class ByValue extends F implements Structure.ByValue {}
+  }
+
+  interface G_Tag {
+    int G_Foo = 0;
+    int G_Bar = 1;
+    int G_Baz = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class G_Foo_Body extends Structure {
+    public short _0;
+
+    public static class ByReference extends G_Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends G_Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class G_Bar_Body extends Structure {
+    public byte x;
+    public short y;
+
+    public static class ByReference extends G_Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends G_Bar_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class G extends Structure {
+    public static class Variants extends Union {
+      public G_Foo_Body foo;
+      public G_Bar_Body bar;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends G implements Structure.ByReference {}
+
+    public static class ByValue extends G implements Structure.ByValue {}
+  }
+
+  interface H_Tag {
+    byte H_Foo = 0;
+    byte H_Bar = 1;
+    byte H_Baz = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class H_Foo_Body extends Structure {
+    public short _0;
+
+    public static class ByReference extends H_Foo_Body implements Structure.ByReference {}
+
+    public static class ByValue extends H_Foo_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class H_Bar_Body extends Structure {
+    public byte x;
+    public short y;
+
+    public static class ByReference extends H_Bar_Body implements Structure.ByReference {}
+
+    public static class ByValue extends H_Bar_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class H extends Structure {
+    public static class Variants extends Union {
+      public H_Foo_Body foo;
+      public H_Bar_Body bar;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends H implements Structure.ByReference {}
+
+    public static class ByValue extends H implements Structure.ByValue {}
+  }
+
+  void root(Pointer o, int a, short b, byte c, SizeT d, SSizeT e, F.ByValue f, G.ByValue g, H.ByValue h, Pointer i, Pointer j, int k, byte l);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+
+  class SSizeT extends IntegerType {
+    public SSizeT() {
+      this(0);
+    }
+
+    public SSizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, false);
+    }
+  }
+}
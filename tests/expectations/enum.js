@@ -0,0 +1,151 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const ssize_t = ref.sizeof.size_t === 8 ? 'int64' : 'int32';
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo_Body = StructType();
+const Bar_Body = StructType();
+const F = UnionType();
+const G_Foo_Body = StructType();
+const G_Bar_Body = StructType();
+const _G_Variants = UnionType();
+const G = StructType();
+const H_Foo_Body = StructType();
+const H_Bar_Body = StructType();
+const _H_Variants = UnionType();
+const H = StructType();
+
+Foo_Body.defineProperty('tag', 'uint8');
+Foo_Body.defineProperty('_0', 'int16');
+
+Bar_Body.defineProperty('tag', 'uint8');
+Bar_Body.defineProperty('x', 'uint8');
+Bar_Body.defineProperty('y', 'int16');
+
+F.defineProperty('tag', 'uint8');
+F.defineProperty('foo', Foo_Body);
+F.defineProperty('bar', Bar_Body);
+
+G_Foo_Body.defineProperty('_0', 'int16');
+
+G_Bar_Body.defineProperty('x', 'uint8');
+G_Bar_Body.defineProperty('y', 'int16');
+
+_G_Variants.defineProperty('foo', G_Foo_Body);
+_G_Variants.defineProperty('bar', G_Bar_Body);
+
+G.defineProperty('tag', 'int');
+G.defineProperty('variants', _G_Variants);
+
+H_Foo_Body.defineProperty('_0', 'int16');
+
+H_Bar_Body.defineProperty('x', 'uint8');
+H_Bar_Body.defineProperty('y', 'int16');
+
+_H_Variants.defineProperty('foo', H_Foo_Body);
+_H_Variants.defineProperty('bar', H_Bar_Body);
+
+H.defineProperty('tag', 'uint8');
+H.defineProperty('variants', _H_Variants);
+
+const A = Object.freeze({
+  a1: 0,
+  a2: 2,
+  a3: 3,
+  a4: 5,
+});
+
+const B = Object.freeze({
+  b1: 0,
+  b2: 2,
+  b3: 3,
+  b4: 5,
+});
+
+const C = Object.freeze({
+  c1: 0,
+  c2: 2,
+  c3: 3,
+  c4: 5,
+});
+
+const D = Object.freeze({
+  d1: 0,
+  d2: 2,
+  d3: 3,
+  d4: 5,
+});
+
+const E = Object.freeze({
+  e1: 0,
+  e2: 2,
+  e3: 3,
+  e4: 5,
+});
+
+const K = Object.freeze({
+  k1: 0,
+  k2: 1,
+  k3: 2,
+  k4: 3,
+});
+
+const L = Object.freeze({
+  l1: -1,
+  l2: 0,
+  l3: 1,
+});
+
+const F_Tag = Object.freeze({
+  Foo: 0,
+  Bar: 1,
+  Baz: 2,
+});
+
+const G_Tag = Object.freeze({
+  G_Foo: 0,
+  G_Bar: 1,
+  G_Baz: 2,
+});
+
+const H_Tag = Object.freeze({
+  H_Foo: 0,
+  H_Bar: 1,
+  H_Baz: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', 'uint32', 'uint16', 'uint8', 'size_t', ssize_t, F, G, H, 'void', 'void', 'int', 'int8']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo_Body,
+  Bar_Body,
+  F,
+  G_Foo_Body,
+  G_Bar_Body,
+  G,
+  H_Foo_Body,
+  H_Bar_Body,
+  H,
+  A,
+  B,
+  C,
+  D,
+  E,
+  K,
+  L,
+  F_Tag,
+  G_Tag,
+  H_Tag,
+  load,
+};
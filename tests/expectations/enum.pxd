@@ -0,0 +1,121 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    a1
+    a2
+    a3
+    a4
+  ctypedef uint32_t A
+
+  enum:
+    b1
+    b2
+    b3
+    b4
+  ctypedef uint16_t B
+
+  enum:
+    c1
+    c2
+    c3
+    c4
+  ctypedef uint8_t C
+
+  enum:
+    d1
+    d2
+    d3
+    d4
+  ctypedef uintptr_t D
+
+  enum:
+    e1
+    e2
+    e3
+    e4
+  ctypedef intptr_t E
+
+  ctypedef enum K:
+    k1
+    k2
+    k3
+    k4
+
+  enum:
+    l1
+    l2
+    l3
+  ctypedef int8_t L
+
+  ctypedef struct I:
+    pass
+
+  ctypedef struct J:
+    pass
+
+  ctypedef struct Opaque:
+    pass
+
+  enum:
+    Foo
+    Bar
+    Baz
+  ctypedef uint8_t F_Tag
+
+  ctypedef struct Foo_Body:
+    F_Tag tag
+    int16_t _0
+
+  ctypedef struct Bar_Body:
+    F_Tag tag
+    uint8_t x
+    int16_t y
+
+  ctypedef union F:
+    F_Tag tag
+    Foo_Body foo
+    Bar_Body bar
+
+  ctypedef enum G_Tag:
+    G_Foo
+    G_Bar
+    G_Baz
+
+  ctypedef struct G_Foo_Body:
+    int16_t _0
+
+  ctypedef struct G_Bar_Body:
+    uint8_t x
+    int16_t y
+
+  ctypedef struct G:
+    G_Tag tag
+    G_Foo_Body foo
+    G_Bar_Body bar
+
+  enum:
+    H_Foo
+    H_Bar
+    H_Baz
+  ctypedef uint8_t H_Tag
+
+  ctypedef struct H_Foo_Body:
+    int16_t _0
+
+  ctypedef struct H_Bar_Body:
+    uint8_t x
+    int16_t y
+
+  ctypedef struct H:
+    H_Tag tag
+    H_Foo_Body foo
+    H_Bar_Body bar
+
+  void root(Opaque *o, A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l)
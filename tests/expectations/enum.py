@@ -0,0 +1,164 @@
+import ctypes
+
+A = ctypes.c_uint32
+a1 = 0
+a2 = 2
+a3 = 3
+a4 = 5
+
+B = ctypes.c_uint16
+b1 = 0
+b2 = 2
+b3 = 3
+b4 = 5
+
+C = ctypes.c_uint8
+c1 = 0
+c2 = 2
+c3 = 3
+c4 = 5
+
+D = ctypes.c_size_t
+d1 = 0
+d2 = 2
+d3 = 3
+d4 = 5
+
+E = ctypes.c_ssize_t
+e1 = 0
+e2 = 2
+e3 = 3
+e4 = 5
+
+K = ctypes.c_int
+k1 = 0
+k2 = 1
+k3 = 2
+k4 = 3
+
+L = ctypes.c_int8
+l1 = -1
+l2 = 0
+l3 = 1
+
+class I(ctypes.Structure):
+  pass
+
+class J(ctypes.Structure):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+F_Tag = ctypes.c_uint8
+Foo = 0
+Bar = 1
+Baz = 2
+
+class Foo_Body(ctypes.Structure):
+  pass
+
+Foo_Body._fields_ = [
+  ("tag", F_Tag),
+  ("_0", ctypes.c_int16),
+]
+
+class Bar_Body(ctypes.Structure):
+  pass
+
+Bar_Body._fields_ = [
+  ("tag", F_Tag),
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class F(ctypes.Union):
+  pass
+
+F._fields_ = [
+  ("tag", F_Tag),
+  ("foo", Foo_Body),
+  ("bar", Bar_Body),
+]
+
+G_Tag = ctypes.c_int
+G_Foo = 0
+G_Bar = 1
+G_Baz = 2
+
+class G_Foo_Body(ctypes.Structure):
+  pass
+
+G_Foo_Body._fields_ = [
+  ("_0", ctypes.c_int16),
+]
+
+class G_Bar_Body(ctypes.Structure):
+  pass
+
+G_Bar_Body._fields_ = [
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class _G_Variants(ctypes.Union):
+  pass
+
+_G_Variants._fields_ = [
+  ("foo", G_Foo_Body),
+  ("bar", G_Bar_Body),
+]
+
+class G(ctypes.Structure):
+  pass
+
+G._anonymous_ = ("_variants",)
+G._fields_ = [
+  ("tag", G_Tag),
+  ("_variants", _G_Variants),
+]
+
+H_Tag = ctypes.c_uint8
+H_Foo = 0
+H_Bar = 1
+H_Baz = 2
+
+class H_Foo_Body(ctypes.Structure):
+  pass
+
+H_Foo_Body._fields_ = [
+  ("_0", ctypes.c_int16),
+]
+
+class H_Bar_Body(ctypes.Structure):
+  pass
+
+H_Bar_Body._fields_ = [
+  ("x", ctypes.c_uint8),
+  ("y", ctypes.c_int16),
+]
+
+class _H_Variants(ctypes.Union):
+  pass
+
+_H_Variants._fields_ = [
+  ("foo", H_Foo_Body),
+  ("bar", H_Bar_Body),
+]
+
+class H(ctypes.Structure):
+  pass
+
+H._anonymous_ = ("_variants",)
+H._fields_ = [
+  ("tag", H_Tag),
+  ("_variants", _H_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), A, B, C, D, E, F, G, H, I, J, K, L]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,126 @@
+const std = @import("std");
+
+pub const A = enum(u32) {
+  a1 = 0,
+  a2 = 2,
+  a3,
+  a4 = 5,
+};
+
+pub const B = enum(u16) {
+  b1 = 0,
+  b2 = 2,
+  b3,
+  b4 = 5,
+};
+
+pub const C = enum(u8) {
+  c1 = 0,
+  c2 = 2,
+  c3,
+  c4 = 5,
+};
+
+pub const D = enum(usize) {
+  d1 = 0,
+  d2 = 2,
+  d3,
+  d4 = 5,
+};
+
+pub const E = enum(isize) {
+  e1 = 0,
+  e2 = 2,
+  e3,
+  e4 = 5,
+};
+
+pub const K = enum(c_int) {
+  k1,
+  k2,
+  k3,
+  k4,
+};
+
+pub const L = enum(i8) {
+  l1 = -1,
+  l2 = 0,
+  l3 = 1,
+};
+
+pub const I = opaque {};
+
+pub const J = opaque {};
+
+pub const Opaque = opaque {};
+
+pub const F_Tag = enum(u8) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Foo_Body = extern struct {
+  tag: F_Tag,
+  _0: i16,
+};
+
+pub const Bar_Body = extern struct {
+  tag: F_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const F = extern union {
+  tag: F_Tag,
+  foo: Foo_Body,
+  bar: Bar_Body,
+};
+
+pub const G_Tag = enum(c_int) {
+  G_Foo,
+  G_Bar,
+  G_Baz,
+};
+
+pub const G_Foo_Body = extern struct {
+  _0: i16,
+};
+
+pub const G_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const G = extern struct {
+  tag: G_Tag,
+  body: extern union {
+    foo: G_Foo_Body,
+    bar: G_Bar_Body,
+  },
+};
+
+pub const H_Tag = enum(u8) {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+};
+
+pub const H_Foo_Body = extern struct {
+  _0: i16,
+};
+
+pub const H_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const H = extern struct {
+  tag: H_Tag,
+  body: extern union {
+    foo: H_Foo_Body,
+    bar: H_Bar_Body,
+  },
+};
+
+pub extern fn root(o: ?*Opaque, a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L) void;
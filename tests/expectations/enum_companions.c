@@ -0,0 +1,69 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green = 4,
+  Blue,
+};
+typedef uint8_t Color;
+
+typedef enum {
+  North,
+  South,
+} Direction;
+
+typedef enum {
+  Low,
+  High,
+} Level;
+
+enum Shape_Tag {
+  Circle,
+  Point,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  Shape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef union {
+  Shape_Tag tag;
+  Circle_Body circle;
+} Shape;
+
+#define COLOR_FOR_EACH(V) \
+  V(Red) \
+  V(Green) \
+  V(Blue)
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+#define DIRECTION_FOR_EACH(V) \
+  V(North) \
+  V(South)
+
+static inline const char *Direction_to_string(Direction value) {
+  switch (value) {
+    case North: return "North";
+    case South: return "South";
+  }
+  return NULL;
+}
+
+#define LEVEL_FOR_EACH(V) \
+  V(Low) \
+  V(High)
+
+void root(Color a, Direction b, Level c, Shape d);
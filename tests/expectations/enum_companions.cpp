@@ -0,0 +1,73 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Color : uint8_t {
+  Red,
+  Green = 4,
+  Blue,
+};
+
+enum class Direction {
+  North,
+  South,
+};
+
+enum class Level {
+  Low,
+  High,
+};
+
+union Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Point,
+  };
+
+  struct Circle_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Circle_Body circle;
+};
+
+#define COLOR_FOR_EACH(V) \
+  V(Red) \
+  V(Green) \
+  V(Blue)
+
+inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Color::Red: return "Red";
+    case Color::Green: return "Green";
+    case Color::Blue: return "Blue";
+  }
+  return nullptr;
+}
+
+#define DIRECTION_FOR_EACH(V) \
+  V(North) \
+  V(South)
+
+inline const char *Direction_to_string(Direction value) {
+  switch (value) {
+    case Direction::North: return "North";
+    case Direction::South: return "South";
+  }
+  return nullptr;
+}
+
+#define LEVEL_FOR_EACH(V) \
+  V(Low) \
+  V(High)
+
+extern "C" {
+
+void root(Color a, Direction b, Level c, Shape d);
+
+} // extern "C"
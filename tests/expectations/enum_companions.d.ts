@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Color: {
+  readonly Red: 0;
+  readonly Green: 4;
+  readonly Blue: 5;
+};
+
+export declare const Direction: {
+  readonly North: 0;
+  readonly South: 1;
+};
+
+export declare const Level: {
+  readonly Low: 0;
+  readonly High: 1;
+};
+
+export declare const Shape_Tag: {
+  readonly Circle: 0;
+  readonly Point: 1;
+};
+
+export interface Circle_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Circle_Body: StructType<Circle_Body>;
+
+export interface Shape {
+  tag: number;
+  circle: Circle_Body;
+  ref(): Buffer;
+}
+export declare const Shape: StructType<Shape>;
+
+export interface Library {
+  root(a: number, b: number, c: number, d: Shape): void;
+}
+
+export declare function load(path: string): Library;
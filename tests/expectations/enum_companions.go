@@ -0,0 +1,41 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Color = C.Color
+
+const (
+  Red Color = C.Red
+  Green Color = C.Green
+  Blue Color = C.Blue
+)
+
+type Direction = C.Direction
+
+const (
+  North Direction = C.North
+  South Direction = C.South
+)
+
+type Level = C.Level
+
+const (
+  Low Level = C.Low
+  High Level = C.High
+)
+
+type Shape_Tag = C.Shape_Tag
+
+const (
+  Circle Shape_Tag = C.Circle
+  Point Shape_Tag = C.Point
+)
+
+type Circle_Body = C.Circle_Body
+
+type Shape = C.Shape
+
+func Root(a Color, b Direction, c Level, d Shape) {
+  C.root(a, b, c, d)
+}
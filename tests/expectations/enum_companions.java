@@ -0,0 +1,45 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Color {
+    byte Red = 0;
+    byte Green = 4;
+    byte Blue = 5;
+  }
+
+  interface Direction {
+    int North = 0;
+    int South = 1;
+  }
+
+  interface Level {
+    int Low = 0;
+    int High = 1;
+  }
+
+  interface Shape_Tag {
+    byte Circle = 0;
+    byte Point = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Circle_Body extends Structure {
+    public byte tag;
+    public float _0;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  class Shape extends Union {
+    public byte tag;
+    public Circle_Body circle;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  void root(byte a, int b, int c, Shape.ByValue d);
+}
@@ -0,0 +1,55 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Circle_Body = StructType();
+const Shape = UnionType();
+
+Circle_Body.defineProperty('tag', 'uint8');
+Circle_Body.defineProperty('_0', 'float');
+
+Shape.defineProperty('tag', 'uint8');
+Shape.defineProperty('circle', Circle_Body);
+
+const Color = Object.freeze({
+  Red: 0,
+  Green: 4,
+  Blue: 5,
+});
+
+const Direction = Object.freeze({
+  North: 0,
+  South: 1,
+});
+
+const Level = Object.freeze({
+  Low: 0,
+  High: 1,
+});
+
+const Shape_Tag = Object.freeze({
+  Circle: 0,
+  Point: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['uint8', 'int', 'int', Shape]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Circle_Body,
+  Shape,
+  Color,
+  Direction,
+  Level,
+  Shape_Tag,
+  load,
+};
@@ -0,0 +1,37 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    Red
+    Green
+    Blue
+  ctypedef uint8_t Color
+
+  ctypedef enum Direction:
+    North
+    South
+
+  ctypedef enum Level:
+    Low
+    High
+
+  enum:
+    Circle
+    Point
+  ctypedef uint8_t Shape_Tag
+
+  ctypedef struct Circle_Body:
+    Shape_Tag tag
+    float _0
+
+  ctypedef union Shape:
+    Shape_Tag tag
+    Circle_Body circle
+
+  void root(Color a, Direction b, Level c, Shape d)
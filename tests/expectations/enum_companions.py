@@ -0,0 +1,42 @@
+import ctypes
+
+Color = ctypes.c_uint8
+Red = 0
+Green = 4
+Blue = 5
+
+Direction = ctypes.c_int
+North = 0
+South = 1
+
+Level = ctypes.c_int
+Low = 0
+High = 1
+
+Shape_Tag = ctypes.c_uint8
+Circle = 0
+Point = 1
+
+class Circle_Body(ctypes.Structure):
+  pass
+
+Circle_Body._fields_ = [
+  ("tag", Shape_Tag),
+  ("_0", ctypes.c_float),
+]
+
+class Shape(ctypes.Union):
+  pass
+
+Shape._fields_ = [
+  ("tag", Shape_Tag),
+  ("circle", Circle_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Color, Direction, Level, Shape]
+  lib.root.restype = None
+
+  return lib
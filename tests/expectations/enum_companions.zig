@@ -0,0 +1,34 @@
+const std = @import("std");
+
+pub const Color = enum(u8) {
+  Red,
+  Green = 4,
+  Blue,
+};
+
+pub const Direction = enum(c_int) {
+  North,
+  South,
+};
+
+pub const Level = enum(c_int) {
+  Low,
+  High,
+};
+
+pub const Shape_Tag = enum(u8) {
+  Circle,
+  Point,
+};
+
+pub const Circle_Body = extern struct {
+  tag: Shape_Tag,
+  _0: f32,
+};
+
+pub const Shape = extern union {
+  tag: Shape_Tag,
+  circle: Circle_Body,
+};
+
+pub extern fn root(a: Color, b: Direction, c: Level, d: Shape) void;
@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Level : int8_t {
+  Low = -1,
+  Medium,
+  High = 10,
+};
+
+enum class Offset : int8_t {
+  Back = -0x10,
+  Here,
+};
+
+enum class Status : uint32_t {
+  Ok = 0x0,
+  Pending = 0x1,
+  Failed = 0x10,
+  Aborted = 0x11,
+  Fatal = 0xFF,
+};
+
+extern "C" {
+
+void root(Status status, Level level, Offset offset);
+
+} // extern "C"
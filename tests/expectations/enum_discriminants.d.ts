@@ -0,0 +1,35 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Level: {
+  readonly Low: -1;
+  readonly Medium: 0;
+  readonly High: 10;
+};
+
+export declare const Offset: {
+  readonly Back: -16;
+  readonly Here: -15;
+};
+
+export declare const Status: {
+  readonly Ok: 0;
+  readonly Pending: 1;
+  readonly Failed: 16;
+  readonly Aborted: 17;
+  readonly Fatal: 255;
+};
+
+export interface Library {
+  root(status: number, level: number, offset: number): void;
+}
+
+export declare function load(path: string): Library;
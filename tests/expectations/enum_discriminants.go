@@ -0,0 +1,33 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Level = C.Level
+
+const (
+  Low Level = C.Low
+  Medium Level = C.Medium
+  High Level = C.High
+)
+
+type Offset = C.Offset
+
+const (
+  Back Offset = C.Back
+  Here Offset = C.Here
+)
+
+type Status = C.Status
+
+const (
+  Ok Status = C.Ok
+  Pending Status = C.Pending
+  Failed Status = C.Failed
+  Aborted Status = C.Aborted
+  Fatal Status = C.Fatal
+)
+
+func Root(status Status, level Level, offset Offset) {
+  C.root(status, level, offset)
+}
@@ -0,0 +1,24 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Level {
+    byte Low = -1;
+    byte Medium = 0;
+    byte High = 10;
+  }
+
+  interface Offset {
+    byte Back = -16;
+    byte Here = -15;
+  }
+
+  interface Status {
+    int Ok = 0;
+    int Pending = 1;
+    int Failed = 16;
+    int Aborted = 17;
+    int Fatal = 255;
+  }
+
+  void root(int status, byte level, byte offset);
+}
@@ -0,0 +1,40 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const Level = Object.freeze({
+  Low: -1,
+  Medium: 0,
+  High: 10,
+});
+
+const Offset = Object.freeze({
+  Back: -16,
+  Here: -15,
+});
+
+const Status = Object.freeze({
+  Ok: 0,
+  Pending: 1,
+  Failed: 16,
+  Aborted: 17,
+  Fatal: 255,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['uint32', 'int8', 'int8']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Level,
+  Offset,
+  Status,
+  load,
+};
@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    Low
+    Medium
+    High
+  ctypedef int8_t Level
+
+  enum:
+    Back
+    Here
+  ctypedef int8_t Offset
+
+  enum:
+    Ok
+    Pending
+    Failed
+    Aborted
+    Fatal
+  ctypedef uint32_t Status
+
+  void root(Status status, Level level, Offset offset)
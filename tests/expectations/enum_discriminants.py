@@ -0,0 +1,25 @@
+import ctypes
+
+Level = ctypes.c_int8
+Low = -1
+Medium = 0
+High = 10
+
+Offset = ctypes.c_int8
+Back = -16
+Here = -15
+
+Status = ctypes.c_uint32
+Ok = 0
+Pending = 1
+Failed = 16
+Aborted = 17
+Fatal = 255
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Status, Level, Offset]
+  lib.root.restype = None
+
+  return lib
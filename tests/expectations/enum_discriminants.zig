@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const Level = enum(i8) {
+  Low = -1,
+  Medium,
+  High = 10,
+};
+
+pub const Offset = enum(i8) {
+  Back = -0x10,
+  Here,
+};
+
+pub const Status = enum(u32) {
+  Ok = 0x0,
+  Pending = 0x1,
+  Failed = 0x10,
+  Aborted = 0x11,
+  Fatal = 0xFF,
+};
+
+pub extern fn root(status: Status, level: Level, offset: Offset) void;
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  North,
+  South,
+
+  Sentinel /* this must be last for serialization purposes. */
+} Direction;
+
+enum Shade {
+  Light = 2,
+  Dark,
+};
+typedef uint8_t Shade;
+
+void root(Direction direction, Shade shade);
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Direction {
+  North,
+  South,
+
+  Sentinel /* this must be last for serialization purposes. */
+};
+
+enum class Shade : uint8_t {
+  Light = 2,
+  Dark,
+};
+
+extern "C" {
+
+void root(Direction direction, Shade shade);
+
+} // extern "C"
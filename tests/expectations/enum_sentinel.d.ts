@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Direction: {
+  readonly North: 0;
+  readonly South: 1;
+};
+
+export declare const Shade: {
+  readonly Light: 2;
+  readonly Dark: 3;
+};
+
+export interface Library {
+  root(direction: number, shade: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,23 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Direction = C.Direction
+
+const (
+  North Direction = C.North
+  South Direction = C.South
+  Sentinel Direction = C.Sentinel
+)
+
+type Shade = C.Shade
+
+const (
+  Light Shade = C.Light
+  Dark Shade = C.Dark
+)
+
+func Root(direction Direction, shade Shade) {
+  C.root(direction, shade)
+}
@@ -0,0 +1,16 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Direction {
+    int North = 0;
+    int South = 1;
+    int Sentinel = 2;
+  }
+
+  interface Shade {
+    byte Light = 2;
+    byte Dark = 3;
+  }
+
+  void root(int direction, byte shade);
+}
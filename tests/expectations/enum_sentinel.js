@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const Direction = Object.freeze({
+  North: 0,
+  South: 1,
+});
+
+const Shade = Object.freeze({
+  Light: 2,
+  Dark: 3,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['int', 'uint8']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Direction,
+  Shade,
+  load,
+};
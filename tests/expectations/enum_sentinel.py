@@ -0,0 +1,18 @@
+import ctypes
+
+Direction = ctypes.c_int
+North = 0
+South = 1
+Direction_Sentinel = 2
+
+Shade = ctypes.c_uint8
+Light = 2
+Dark = 3
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Direction, Shade]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const Direction = enum(c_int) {
+  North,
+  South,
+  Sentinel,
+};
+
+pub const Shade = enum(u8) {
+  Light = 2,
+  Dark,
+};
+
+pub extern fn root(direction: Direction, shade: Shade) void;
@@ -0,0 +1,36 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/// Gets the default underlying type.
+enum class Color : uint16_t {
+  Red,
+  Green,
+  Blue,
+};
+
+enum class Direction : uint8_t {
+  North,
+  East,
+  South,
+  West,
+};
+
+/// Keeps its `#[repr(u32)]`.
+enum class Flavor : uint32_t {
+  Sweet,
+  Sour,
+};
+
+enum class Sign : int8_t {
+  Negative = -1,
+  Zero = 0,
+  Positive = 1,
+};
+
+extern "C" {
+
+void root(Color color, Direction direction, Sign sign, Flavor flavor);
+
+} // extern "C"
@@ -0,0 +1,46 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * Gets the default underlying type.
+ */
+export declare const Color: {
+  readonly Red: 0;
+  readonly Green: 1;
+  readonly Blue: 2;
+};
+
+export declare const Direction: {
+  readonly North: 0;
+  readonly East: 1;
+  readonly South: 2;
+  readonly West: 3;
+};
+
+/**
+ * Keeps its `#[repr(u32)]`.
+ */
+export declare const Flavor: {
+  readonly Sweet: 0;
+  readonly Sour: 1;
+};
+
+export declare const Sign: {
+  readonly Negative: -1;
+  readonly Zero: 0;
+  readonly Positive: 1;
+};
+
+export interface Library {
+  root(color: number, direction: number, sign: number, flavor: number): void;
+}
+
+export declare function load(path: string): Library;
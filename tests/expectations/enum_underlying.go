@@ -0,0 +1,42 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// Gets the default underlying type.
+type Color = C.Color
+
+const (
+  Red Color = C.Red
+  Green Color = C.Green
+  Blue Color = C.Blue
+)
+
+type Direction = C.Direction
+
+const (
+  North Direction = C.North
+  East Direction = C.East
+  South Direction = C.South
+  West Direction = C.West
+)
+
+// Keeps its `#[repr(u32)]`.
+type Flavor = C.Flavor
+
+const (
+  Sweet Flavor = C.Sweet
+  Sour Flavor = C.Sour
+)
+
+type Sign = C.Sign
+
+const (
+  Negative Sign = C.Negative
+  Zero Sign = C.Zero
+  Positive Sign = C.Positive
+)
+
+func Root(color Color, direction Direction, sign Sign, flavor Flavor) {
+  C.root(color, direction, sign, flavor)
+}
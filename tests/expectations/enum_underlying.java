@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * Gets the default underlying type.
+   */
+  interface Color {
+    short Red = 0;
+    short Green = 1;
+    short Blue = 2;
+  }
+
+  interface Direction {
+    byte North = 0;
+    byte East = 1;
+    byte South = 2;
+    byte West = 3;
+  }
+
+  /**
+   * Keeps its `#[repr(u32)]`.
+   */
+  interface Flavor {
+    int Sweet = 0;
+    int Sour = 1;
+  }
+
+  interface Sign {
+    byte Negative = -1;
+    byte Zero = 0;
+    byte Positive = 1;
+  }
+
+  void root(short color, byte direction, byte sign, int flavor);
+}
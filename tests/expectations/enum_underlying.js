@@ -0,0 +1,46 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const Color = Object.freeze({
+  Red: 0,
+  Green: 1,
+  Blue: 2,
+});
+
+const Direction = Object.freeze({
+  North: 0,
+  East: 1,
+  South: 2,
+  West: 3,
+});
+
+const Flavor = Object.freeze({
+  Sweet: 0,
+  Sour: 1,
+});
+
+const Sign = Object.freeze({
+  Negative: -1,
+  Zero: 0,
+  Positive: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['uint16', 'uint8', 'int8', 'uint32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Color,
+  Direction,
+  Flavor,
+  Sign,
+  load,
+};
@@ -0,0 +1,36 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # Gets the default underlying type.
+  enum:
+    Red
+    Green
+    Blue
+  ctypedef uint16_t Color
+
+  enum:
+    North
+    East
+    South
+    West
+  ctypedef uint8_t Direction
+
+  # Keeps its `#[repr(u32)]`.
+  enum:
+    Sweet
+    Sour
+  ctypedef uint32_t Flavor
+
+  enum:
+    Negative
+    Zero
+    Positive
+  ctypedef int8_t Sign
+
+  void root(Color color, Direction direction, Sign sign, Flavor flavor)
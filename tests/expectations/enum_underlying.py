@@ -0,0 +1,31 @@
+import ctypes
+
+# Gets the default underlying type.
+Color = ctypes.c_uint16
+Red = 0
+Green = 1
+Blue = 2
+
+Direction = ctypes.c_uint8
+North = 0
+East = 1
+South = 2
+West = 3
+
+# Keeps its `#[repr(u32)]`.
+Flavor = ctypes.c_uint32
+Sweet = 0
+Sour = 1
+
+Sign = ctypes.c_int8
+Negative = -1
+Zero = 0
+Positive = 1
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Color, Direction, Sign, Flavor]
+  lib.root.restype = None
+
+  return lib
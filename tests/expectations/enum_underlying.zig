@@ -0,0 +1,29 @@
+const std = @import("std");
+
+/// Gets the default underlying type.
+pub const Color = enum(u16) {
+  Red,
+  Green,
+  Blue,
+};
+
+pub const Direction = enum(u8) {
+  North,
+  East,
+  South,
+  West,
+};
+
+/// Keeps its `#[repr(u32)]`.
+pub const Flavor = enum(u32) {
+  Sweet,
+  Sour,
+};
+
+pub const Sign = enum(i8) {
+  Negative = -1,
+  Zero = 0,
+  Positive = 1,
+};
+
+pub extern fn root(color: Color, direction: Direction, sign: Sign, flavor: Flavor) void;
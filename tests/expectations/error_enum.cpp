@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class IoError {
+  NotFound,
+  PermissionDenied,
+  TimedOut = 10,
+};
+
+enum class ParseError : uint8_t {
+  UnexpectedEof,
+  InvalidToken,
+};
+
+static inline const char *parse_error_name(ParseError value) {
+  switch (value) {
+    case ParseError::UnexpectedEof: return "UnexpectedEof";
+    case ParseError::InvalidToken: return "InvalidToken";
+  }
+  return nullptr;
+}
+
+extern "C" {
+
+void root(IoError io, ParseError parse);
+
+const char *io_error_name(IoError value);
+
+} // extern "C"
@@ -0,0 +1,27 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const IoError: {
+  readonly NotFound: 0;
+  readonly PermissionDenied: 1;
+  readonly TimedOut: 10;
+};
+
+export declare const ParseError: {
+  readonly UnexpectedEof: 0;
+  readonly InvalidToken: 1;
+};
+
+export interface Library {
+  root(io: number, parse: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,23 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type IoError = C.IoError
+
+const (
+  NotFound IoError = C.NotFound
+  PermissionDenied IoError = C.PermissionDenied
+  TimedOut IoError = C.TimedOut
+)
+
+type ParseError = C.ParseError
+
+const (
+  UnexpectedEof ParseError = C.UnexpectedEof
+  InvalidToken ParseError = C.InvalidToken
+)
+
+func Root(io IoError, parse ParseError) {
+  C.root(io, parse)
+}
@@ -0,0 +1,16 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface IoError {
+    int NotFound = 0;
+    int PermissionDenied = 1;
+    int TimedOut = 10;
+  }
+
+  interface ParseError {
+    byte UnexpectedEof = 0;
+    byte InvalidToken = 1;
+  }
+
+  void root(int io, byte parse);
+}
@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const IoError = Object.freeze({
+  NotFound: 0,
+  PermissionDenied: 1,
+  TimedOut: 10,
+});
+
+const ParseError = Object.freeze({
+  UnexpectedEof: 0,
+  InvalidToken: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['int', 'uint8']],
+  });
+  return lib;
+}
+
+module.exports = {
+  IoError,
+  ParseError,
+  load,
+};
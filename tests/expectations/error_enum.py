@@ -0,0 +1,18 @@
+import ctypes
+
+IoError = ctypes.c_int
+NotFound = 0
+PermissionDenied = 1
+TimedOut = 10
+
+ParseError = ctypes.c_uint8
+UnexpectedEof = 0
+InvalidToken = 1
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IoError, ParseError]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const IoError = enum(c_int) {
+  NotFound,
+  PermissionDenied,
+  TimedOut = 10,
+};
+
+pub const ParseError = enum(u8) {
+  UnexpectedEof,
+  InvalidToken,
+};
+
+pub extern fn root(io: IoError, parse: ParseError) void;
@@ -7,6 +7,7 @@ struct LayoutUnit;
 
 struct UnknownUnit;
 
+/// Zero-sized fields elided: `1`.
 template<typename T, typename Unit>
 struct TypedLength {
   T _0;
@@ -17,6 +18,7 @@ using Length = TypedLength<T, UnknownUnit>;
 
 using LayoutLength = TypedLength<float, LayoutUnit>;
 
+/// Zero-sized fields elided: `_phantom`.
 template<typename T, typename U>
 struct TypedSideOffsets2D {
   T top;
@@ -30,6 +32,7 @@ using SideOffsets2D = TypedSideOffsets2D<T, UnknownUnit>;
 
 using LayoutSideOffsets2D = TypedSideOffsets2D<float, LayoutUnit>;
 
+/// Zero-sized fields elided: `_phantom`.
 template<typename T, typename U>
 struct TypedSize2D {
   T width;
@@ -41,6 +44,7 @@ using Size2D = TypedSize2D<T, UnknownUnit>;
 
 using LayoutSize2D = TypedSize2D<float, LayoutUnit>;
 
+/// Zero-sized fields elided: `_phantom`.
 template<typename T, typename U>
 struct TypedPoint2D {
   T x;
@@ -52,6 +56,7 @@ using Point2D = TypedPoint2D<T, UnknownUnit>;
 
 using LayoutPoint2D = TypedPoint2D<float, LayoutUnit>;
 
+/// Zero-sized fields elided: `_phantom`.
 template<typename T, typename U>
 struct TypedRect {
   TypedPoint2D<T, U> origin;
@@ -63,6 +68,7 @@ using Rect = TypedRect<T, UnknownUnit>;
 
 using LayoutRect = TypedRect<float, LayoutUnit>;
 
+/// Zero-sized fields elided: `_phantom`.
 template<typename T, typename Src, typename Dst>
 struct TypedTransform2D {
   T m11;
@@ -0,0 +1,176 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * Zero-sized fields elided: `1`.
+ */
+export interface TypedLength_f32__UnknownUnit {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const TypedLength_f32__UnknownUnit: StructType<TypedLength_f32__UnknownUnit>;
+
+/**
+ * Zero-sized fields elided: `1`.
+ */
+export interface TypedLength_f32__LayoutUnit {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const TypedLength_f32__LayoutUnit: StructType<TypedLength_f32__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedSideOffsets2D_f32__UnknownUnit {
+  top: number;
+  right: number;
+  bottom: number;
+  left: number;
+  ref(): Buffer;
+}
+export declare const TypedSideOffsets2D_f32__UnknownUnit: StructType<TypedSideOffsets2D_f32__UnknownUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedSideOffsets2D_f32__LayoutUnit {
+  top: number;
+  right: number;
+  bottom: number;
+  left: number;
+  ref(): Buffer;
+}
+export declare const TypedSideOffsets2D_f32__LayoutUnit: StructType<TypedSideOffsets2D_f32__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedSize2D_f32__UnknownUnit {
+  width: number;
+  height: number;
+  ref(): Buffer;
+}
+export declare const TypedSize2D_f32__UnknownUnit: StructType<TypedSize2D_f32__UnknownUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedSize2D_f32__LayoutUnit {
+  width: number;
+  height: number;
+  ref(): Buffer;
+}
+export declare const TypedSize2D_f32__LayoutUnit: StructType<TypedSize2D_f32__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedPoint2D_f32__UnknownUnit {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const TypedPoint2D_f32__UnknownUnit: StructType<TypedPoint2D_f32__UnknownUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedPoint2D_f32__LayoutUnit {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const TypedPoint2D_f32__LayoutUnit: StructType<TypedPoint2D_f32__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedRect_f32__UnknownUnit {
+  origin: TypedPoint2D_f32__UnknownUnit;
+  size: TypedSize2D_f32__UnknownUnit;
+  ref(): Buffer;
+}
+export declare const TypedRect_f32__UnknownUnit: StructType<TypedRect_f32__UnknownUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedRect_f32__LayoutUnit {
+  origin: TypedPoint2D_f32__LayoutUnit;
+  size: TypedSize2D_f32__LayoutUnit;
+  ref(): Buffer;
+}
+export declare const TypedRect_f32__LayoutUnit: StructType<TypedRect_f32__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedTransform2D_f32__UnknownUnit__LayoutUnit {
+  m11: number;
+  m12: number;
+  m21: number;
+  m22: number;
+  m31: number;
+  m32: number;
+  ref(): Buffer;
+}
+export declare const TypedTransform2D_f32__UnknownUnit__LayoutUnit: StructType<TypedTransform2D_f32__UnknownUnit__LayoutUnit>;
+
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
+export interface TypedTransform2D_f32__LayoutUnit__UnknownUnit {
+  m11: number;
+  m12: number;
+  m21: number;
+  m22: number;
+  m31: number;
+  m32: number;
+  ref(): Buffer;
+}
+export declare const TypedTransform2D_f32__LayoutUnit__UnknownUnit: StructType<TypedTransform2D_f32__LayoutUnit__UnknownUnit>;
+
+export type Length_f32 = TypedLength_f32__UnknownUnit;
+export declare const Length_f32: RefType;
+
+export type LayoutLength = TypedLength_f32__LayoutUnit;
+export declare const LayoutLength: RefType;
+
+export type SideOffsets2D_f32 = TypedSideOffsets2D_f32__UnknownUnit;
+export declare const SideOffsets2D_f32: RefType;
+
+export type LayoutSideOffsets2D = TypedSideOffsets2D_f32__LayoutUnit;
+export declare const LayoutSideOffsets2D: RefType;
+
+export type Size2D_f32 = TypedSize2D_f32__UnknownUnit;
+export declare const Size2D_f32: RefType;
+
+export type LayoutSize2D = TypedSize2D_f32__LayoutUnit;
+export declare const LayoutSize2D: RefType;
+
+export type Point2D_f32 = TypedPoint2D_f32__UnknownUnit;
+export declare const Point2D_f32: RefType;
+
+export type LayoutPoint2D = TypedPoint2D_f32__LayoutUnit;
+export declare const LayoutPoint2D: RefType;
+
+export type Rect_f32 = TypedRect_f32__UnknownUnit;
+export declare const Rect_f32: RefType;
+
+export type LayoutRect = TypedRect_f32__LayoutUnit;
+export declare const LayoutRect: RefType;
+
+export interface Library {
+  root(length_a: TypedLength_f32__UnknownUnit, length_b: TypedLength_f32__LayoutUnit, length_c: TypedLength_f32__UnknownUnit, length_d: TypedLength_f32__LayoutUnit, side_offsets_a: TypedSideOffsets2D_f32__UnknownUnit, side_offsets_b: TypedSideOffsets2D_f32__LayoutUnit, side_offsets_c: TypedSideOffsets2D_f32__UnknownUnit, side_offsets_d: TypedSideOffsets2D_f32__LayoutUnit, size_a: TypedSize2D_f32__UnknownUnit, size_b: TypedSize2D_f32__LayoutUnit, size_c: TypedSize2D_f32__UnknownUnit, size_d: TypedSize2D_f32__LayoutUnit, point_a: TypedPoint2D_f32__UnknownUnit, point_b: TypedPoint2D_f32__LayoutUnit, point_c: TypedPoint2D_f32__UnknownUnit, point_d: TypedPoint2D_f32__LayoutUnit, rect_a: TypedRect_f32__UnknownUnit, rect_b: TypedRect_f32__LayoutUnit, rect_c: TypedRect_f32__UnknownUnit, rect_d: TypedRect_f32__LayoutUnit, transform_a: TypedTransform2D_f32__UnknownUnit__LayoutUnit, transform_b: TypedTransform2D_f32__LayoutUnit__UnknownUnit): void;
+}
+
+export declare function load(path: string): Library;
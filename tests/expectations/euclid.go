@@ -0,0 +1,64 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// Zero-sized fields elided: `1`.
+type TypedLength_f32__UnknownUnit = C.TypedLength_f32__UnknownUnit
+
+// Zero-sized fields elided: `1`.
+type TypedLength_f32__LayoutUnit = C.TypedLength_f32__LayoutUnit
+
+type Length_f32 = C.Length_f32
+
+type LayoutLength = C.LayoutLength
+
+// Zero-sized fields elided: `_phantom`.
+type TypedSideOffsets2D_f32__UnknownUnit = C.TypedSideOffsets2D_f32__UnknownUnit
+
+// Zero-sized fields elided: `_phantom`.
+type TypedSideOffsets2D_f32__LayoutUnit = C.TypedSideOffsets2D_f32__LayoutUnit
+
+type SideOffsets2D_f32 = C.SideOffsets2D_f32
+
+type LayoutSideOffsets2D = C.LayoutSideOffsets2D
+
+// Zero-sized fields elided: `_phantom`.
+type TypedSize2D_f32__UnknownUnit = C.TypedSize2D_f32__UnknownUnit
+
+// Zero-sized fields elided: `_phantom`.
+type TypedSize2D_f32__LayoutUnit = C.TypedSize2D_f32__LayoutUnit
+
+type Size2D_f32 = C.Size2D_f32
+
+type LayoutSize2D = C.LayoutSize2D
+
+// Zero-sized fields elided: `_phantom`.
+type TypedPoint2D_f32__UnknownUnit = C.TypedPoint2D_f32__UnknownUnit
+
+// Zero-sized fields elided: `_phantom`.
+type TypedPoint2D_f32__LayoutUnit = C.TypedPoint2D_f32__LayoutUnit
+
+type Point2D_f32 = C.Point2D_f32
+
+type LayoutPoint2D = C.LayoutPoint2D
+
+// Zero-sized fields elided: `_phantom`.
+type TypedRect_f32__UnknownUnit = C.TypedRect_f32__UnknownUnit
+
+// Zero-sized fields elided: `_phantom`.
+type TypedRect_f32__LayoutUnit = C.TypedRect_f32__LayoutUnit
+
+type Rect_f32 = C.Rect_f32
+
+type LayoutRect = C.LayoutRect
+
+// Zero-sized fields elided: `_phantom`.
+type TypedTransform2D_f32__UnknownUnit__LayoutUnit = C.TypedTransform2D_f32__UnknownUnit__LayoutUnit
+
+// Zero-sized fields elided: `_phantom`.
+type TypedTransform2D_f32__LayoutUnit__UnknownUnit = C.TypedTransform2D_f32__LayoutUnit__UnknownUnit
+
+func Root(length_a TypedLength_f32__UnknownUnit, length_b TypedLength_f32__LayoutUnit, length_c Length_f32, length_d LayoutLength, side_offsets_a TypedSideOffsets2D_f32__UnknownUnit, side_offsets_b TypedSideOffsets2D_f32__LayoutUnit, side_offsets_c SideOffsets2D_f32, side_offsets_d LayoutSideOffsets2D, size_a TypedSize2D_f32__UnknownUnit, size_b TypedSize2D_f32__LayoutUnit, size_c Size2D_f32, size_d LayoutSize2D, point_a TypedPoint2D_f32__UnknownUnit, point_b TypedPoint2D_f32__LayoutUnit, point_c Point2D_f32, point_d LayoutPoint2D, rect_a TypedRect_f32__UnknownUnit, rect_b TypedRect_f32__LayoutUnit, rect_c Rect_f32, rect_d LayoutRect, transform_a TypedTransform2D_f32__UnknownUnit__LayoutUnit, transform_b TypedTransform2D_f32__LayoutUnit__UnknownUnit) {
+  C.root(length_a, length_b, length_c, length_d, side_offsets_a, side_offsets_b, side_offsets_c, side_offsets_d, size_a, size_b, size_c, size_d, point_a, point_b, point_c, point_d, rect_a, rect_b, rect_c, rect_d, transform_a, transform_b)
+}
@@ -0,0 +1,171 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * Zero-sized fields elided: `1`.
+   */
+  @Structure.FieldOrder({"_0"})
+  class TypedLength_f32__UnknownUnit extends Structure {
+    public float _0;
+
+    public static class ByReference extends TypedLength_f32__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedLength_f32__UnknownUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `1`.
+   */
+  @Structure.FieldOrder({"_0"})
+  class TypedLength_f32__LayoutUnit extends Structure {
+    public float _0;
+
+    public static class ByReference extends TypedLength_f32__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedLength_f32__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"top", "right", "bottom", "left"})
+  class TypedSideOffsets2D_f32__UnknownUnit extends Structure {
+    public float top;
+    public float right;
+    public float bottom;
+    public float left;
+
+    public static class ByReference extends TypedSideOffsets2D_f32__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedSideOffsets2D_f32__UnknownUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"top", "right", "bottom", "left"})
+  class TypedSideOffsets2D_f32__LayoutUnit extends Structure {
+    public float top;
+    public float right;
+    public float bottom;
+    public float left;
+
+    public static class ByReference extends TypedSideOffsets2D_f32__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedSideOffsets2D_f32__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"width", "height"})
+  class TypedSize2D_f32__UnknownUnit extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends TypedSize2D_f32__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedSize2D_f32__UnknownUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"width", "height"})
+  class TypedSize2D_f32__LayoutUnit extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends TypedSize2D_f32__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedSize2D_f32__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"x", "y"})
+  class TypedPoint2D_f32__UnknownUnit extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends TypedPoint2D_f32__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedPoint2D_f32__UnknownUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"x", "y"})
+  class TypedPoint2D_f32__LayoutUnit extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends TypedPoint2D_f32__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedPoint2D_f32__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"origin", "size"})
+  class TypedRect_f32__UnknownUnit extends Structure {
+    public TypedPoint2D_f32__UnknownUnit origin;
+    public TypedSize2D_f32__UnknownUnit size;
+
+    public static class ByReference extends TypedRect_f32__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedRect_f32__UnknownUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"origin", "size"})
+  class TypedRect_f32__LayoutUnit extends Structure {
+    public TypedPoint2D_f32__LayoutUnit origin;
+    public TypedSize2D_f32__LayoutUnit size;
+
+    public static class ByReference extends TypedRect_f32__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedRect_f32__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"m11", "m12", "m21", "m22", "m31", "m32"})
+  class TypedTransform2D_f32__UnknownUnit__LayoutUnit extends Structure {
+    public float m11;
+    public float m12;
+    public float m21;
+    public float m22;
+    public float m31;
+    public float m32;
+
+    public static class ByReference extends TypedTransform2D_f32__UnknownUnit__LayoutUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedTransform2D_f32__UnknownUnit__LayoutUnit implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `_phantom`.
+   */
+  @Structure.FieldOrder({"m11", "m12", "m21", "m22", "m31", "m32"})
+  class TypedTransform2D_f32__LayoutUnit__UnknownUnit extends Structure {
+    public float m11;
+    public float m12;
+    public float m21;
+    public float m22;
+    public float m31;
+    public float m32;
+
+    public static class ByReference extends TypedTransform2D_f32__LayoutUnit__UnknownUnit implements Structure.ByReference {}
+
+    public static class ByValue extends TypedTransform2D_f32__LayoutUnit__UnknownUnit implements Structure.ByValue {}
+  }
+
+  void root(TypedLength_f32__UnknownUnit.ByValue length_a, TypedLength_f32__LayoutUnit.ByValue length_b, TypedLength_f32__UnknownUnit.ByValue length_c, TypedLength_f32__LayoutUnit.ByValue length_d, TypedSideOffsets2D_f32__UnknownUnit.ByValue side_offsets_a, TypedSideOffsets2D_f32__LayoutUnit.ByValue side_offsets_b, TypedSideOffsets2D_f32__UnknownUnit.ByValue side_offsets_c, TypedSideOffsets2D_f32__LayoutUnit.ByValue side_offsets_d, TypedSize2D_f32__UnknownUnit.ByValue size_a, TypedSize2D_f32__LayoutUnit.ByValue size_b, TypedSize2D_f32__UnknownUnit.ByValue size_c, TypedSize2D_f32__LayoutUnit.ByValue size_d, TypedPoint2D_f32__UnknownUnit.ByValue point_a, TypedPoint2D_f32__LayoutUnit.ByValue point_b, TypedPoint2D_f32__UnknownUnit.ByValue point_c, TypedPoint2D_f32__LayoutUnit.ByValue point_d, TypedRect_f32__UnknownUnit.ByValue rect_a, TypedRect_f32__LayoutUnit.ByValue rect_b, TypedRect_f32__UnknownUnit.ByValue rect_c, TypedRect_f32__LayoutUnit.ByValue rect_d, TypedTransform2D_f32__UnknownUnit__LayoutUnit.ByValue transform_a, TypedTransform2D_f32__LayoutUnit__UnknownUnit.ByValue transform_b);
+}
@@ -0,0 +1,120 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const TypedLength_f32__UnknownUnit = StructType();
+const TypedLength_f32__LayoutUnit = StructType();
+const TypedSideOffsets2D_f32__UnknownUnit = StructType();
+const TypedSideOffsets2D_f32__LayoutUnit = StructType();
+const TypedSize2D_f32__UnknownUnit = StructType();
+const TypedSize2D_f32__LayoutUnit = StructType();
+const TypedPoint2D_f32__UnknownUnit = StructType();
+const TypedPoint2D_f32__LayoutUnit = StructType();
+const TypedRect_f32__UnknownUnit = StructType();
+const TypedRect_f32__LayoutUnit = StructType();
+const TypedTransform2D_f32__UnknownUnit__LayoutUnit = StructType();
+const TypedTransform2D_f32__LayoutUnit__UnknownUnit = StructType();
+
+TypedLength_f32__UnknownUnit.defineProperty('_0', 'float');
+
+TypedLength_f32__LayoutUnit.defineProperty('_0', 'float');
+
+TypedSideOffsets2D_f32__UnknownUnit.defineProperty('top', 'float');
+TypedSideOffsets2D_f32__UnknownUnit.defineProperty('right', 'float');
+TypedSideOffsets2D_f32__UnknownUnit.defineProperty('bottom', 'float');
+TypedSideOffsets2D_f32__UnknownUnit.defineProperty('left', 'float');
+
+TypedSideOffsets2D_f32__LayoutUnit.defineProperty('top', 'float');
+TypedSideOffsets2D_f32__LayoutUnit.defineProperty('right', 'float');
+TypedSideOffsets2D_f32__LayoutUnit.defineProperty('bottom', 'float');
+TypedSideOffsets2D_f32__LayoutUnit.defineProperty('left', 'float');
+
+TypedSize2D_f32__UnknownUnit.defineProperty('width', 'float');
+TypedSize2D_f32__UnknownUnit.defineProperty('height', 'float');
+
+TypedSize2D_f32__LayoutUnit.defineProperty('width', 'float');
+TypedSize2D_f32__LayoutUnit.defineProperty('height', 'float');
+
+TypedPoint2D_f32__UnknownUnit.defineProperty('x', 'float');
+TypedPoint2D_f32__UnknownUnit.defineProperty('y', 'float');
+
+TypedPoint2D_f32__LayoutUnit.defineProperty('x', 'float');
+TypedPoint2D_f32__LayoutUnit.defineProperty('y', 'float');
+
+TypedRect_f32__UnknownUnit.defineProperty('origin', TypedPoint2D_f32__UnknownUnit);
+TypedRect_f32__UnknownUnit.defineProperty('size', TypedSize2D_f32__UnknownUnit);
+
+TypedRect_f32__LayoutUnit.defineProperty('origin', TypedPoint2D_f32__LayoutUnit);
+TypedRect_f32__LayoutUnit.defineProperty('size', TypedSize2D_f32__LayoutUnit);
+
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m11', 'float');
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m12', 'float');
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m21', 'float');
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m22', 'float');
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m31', 'float');
+TypedTransform2D_f32__UnknownUnit__LayoutUnit.defineProperty('m32', 'float');
+
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m11', 'float');
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m12', 'float');
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m21', 'float');
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m22', 'float');
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m31', 'float');
+TypedTransform2D_f32__LayoutUnit__UnknownUnit.defineProperty('m32', 'float');
+
+const Length_f32 = TypedLength_f32__UnknownUnit;
+
+const LayoutLength = TypedLength_f32__LayoutUnit;
+
+const SideOffsets2D_f32 = TypedSideOffsets2D_f32__UnknownUnit;
+
+const LayoutSideOffsets2D = TypedSideOffsets2D_f32__LayoutUnit;
+
+const Size2D_f32 = TypedSize2D_f32__UnknownUnit;
+
+const LayoutSize2D = TypedSize2D_f32__LayoutUnit;
+
+const Point2D_f32 = TypedPoint2D_f32__UnknownUnit;
+
+const LayoutPoint2D = TypedPoint2D_f32__LayoutUnit;
+
+const Rect_f32 = TypedRect_f32__UnknownUnit;
+
+const LayoutRect = TypedRect_f32__LayoutUnit;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [TypedLength_f32__UnknownUnit, TypedLength_f32__LayoutUnit, TypedLength_f32__UnknownUnit, TypedLength_f32__LayoutUnit, TypedSideOffsets2D_f32__UnknownUnit, TypedSideOffsets2D_f32__LayoutUnit, TypedSideOffsets2D_f32__UnknownUnit, TypedSideOffsets2D_f32__LayoutUnit, TypedSize2D_f32__UnknownUnit, TypedSize2D_f32__LayoutUnit, TypedSize2D_f32__UnknownUnit, TypedSize2D_f32__LayoutUnit, TypedPoint2D_f32__UnknownUnit, TypedPoint2D_f32__LayoutUnit, TypedPoint2D_f32__UnknownUnit, TypedPoint2D_f32__LayoutUnit, TypedRect_f32__UnknownUnit, TypedRect_f32__LayoutUnit, TypedRect_f32__UnknownUnit, TypedRect_f32__LayoutUnit, TypedTransform2D_f32__UnknownUnit__LayoutUnit, TypedTransform2D_f32__LayoutUnit__UnknownUnit]],
+  });
+  return lib;
+}
+
+module.exports = {
+  TypedLength_f32__UnknownUnit,
+  TypedLength_f32__LayoutUnit,
+  TypedSideOffsets2D_f32__UnknownUnit,
+  TypedSideOffsets2D_f32__LayoutUnit,
+  TypedSize2D_f32__UnknownUnit,
+  TypedSize2D_f32__LayoutUnit,
+  TypedPoint2D_f32__UnknownUnit,
+  TypedPoint2D_f32__LayoutUnit,
+  TypedRect_f32__UnknownUnit,
+  TypedRect_f32__LayoutUnit,
+  TypedTransform2D_f32__UnknownUnit__LayoutUnit,
+  TypedTransform2D_f32__LayoutUnit__UnknownUnit,
+  Length_f32,
+  LayoutLength,
+  SideOffsets2D_f32,
+  LayoutSideOffsets2D,
+  Size2D_f32,
+  LayoutSize2D,
+  Point2D_f32,
+  LayoutPoint2D,
+  Rect_f32,
+  LayoutRect,
+  load,
+};
@@ -0,0 +1,147 @@
+import ctypes
+
+# Zero-sized fields elided: `1`.
+class TypedLength_f32__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedLength_f32__UnknownUnit._fields_ = [
+  ("_0", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `1`.
+class TypedLength_f32__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedLength_f32__LayoutUnit._fields_ = [
+  ("_0", ctypes.c_float),
+]
+
+Length_f32 = TypedLength_f32__UnknownUnit
+
+LayoutLength = TypedLength_f32__LayoutUnit
+
+# Zero-sized fields elided: `_phantom`.
+class TypedSideOffsets2D_f32__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedSideOffsets2D_f32__UnknownUnit._fields_ = [
+  ("top", ctypes.c_float),
+  ("right", ctypes.c_float),
+  ("bottom", ctypes.c_float),
+  ("left", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `_phantom`.
+class TypedSideOffsets2D_f32__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedSideOffsets2D_f32__LayoutUnit._fields_ = [
+  ("top", ctypes.c_float),
+  ("right", ctypes.c_float),
+  ("bottom", ctypes.c_float),
+  ("left", ctypes.c_float),
+]
+
+SideOffsets2D_f32 = TypedSideOffsets2D_f32__UnknownUnit
+
+LayoutSideOffsets2D = TypedSideOffsets2D_f32__LayoutUnit
+
+# Zero-sized fields elided: `_phantom`.
+class TypedSize2D_f32__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedSize2D_f32__UnknownUnit._fields_ = [
+  ("width", ctypes.c_float),
+  ("height", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `_phantom`.
+class TypedSize2D_f32__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedSize2D_f32__LayoutUnit._fields_ = [
+  ("width", ctypes.c_float),
+  ("height", ctypes.c_float),
+]
+
+Size2D_f32 = TypedSize2D_f32__UnknownUnit
+
+LayoutSize2D = TypedSize2D_f32__LayoutUnit
+
+# Zero-sized fields elided: `_phantom`.
+class TypedPoint2D_f32__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedPoint2D_f32__UnknownUnit._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `_phantom`.
+class TypedPoint2D_f32__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedPoint2D_f32__LayoutUnit._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+Point2D_f32 = TypedPoint2D_f32__UnknownUnit
+
+LayoutPoint2D = TypedPoint2D_f32__LayoutUnit
+
+# Zero-sized fields elided: `_phantom`.
+class TypedRect_f32__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedRect_f32__UnknownUnit._fields_ = [
+  ("origin", TypedPoint2D_f32__UnknownUnit),
+  ("size", TypedSize2D_f32__UnknownUnit),
+]
+
+# Zero-sized fields elided: `_phantom`.
+class TypedRect_f32__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedRect_f32__LayoutUnit._fields_ = [
+  ("origin", TypedPoint2D_f32__LayoutUnit),
+  ("size", TypedSize2D_f32__LayoutUnit),
+]
+
+Rect_f32 = TypedRect_f32__UnknownUnit
+
+LayoutRect = TypedRect_f32__LayoutUnit
+
+# Zero-sized fields elided: `_phantom`.
+class TypedTransform2D_f32__UnknownUnit__LayoutUnit(ctypes.Structure):
+  pass
+
+TypedTransform2D_f32__UnknownUnit__LayoutUnit._fields_ = [
+  ("m11", ctypes.c_float),
+  ("m12", ctypes.c_float),
+  ("m21", ctypes.c_float),
+  ("m22", ctypes.c_float),
+  ("m31", ctypes.c_float),
+  ("m32", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `_phantom`.
+class TypedTransform2D_f32__LayoutUnit__UnknownUnit(ctypes.Structure):
+  pass
+
+TypedTransform2D_f32__LayoutUnit__UnknownUnit._fields_ = [
+  ("m11", ctypes.c_float),
+  ("m12", ctypes.c_float),
+  ("m21", ctypes.c_float),
+  ("m22", ctypes.c_float),
+  ("m31", ctypes.c_float),
+  ("m32", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [TypedLength_f32__UnknownUnit, TypedLength_f32__LayoutUnit, Length_f32, LayoutLength, TypedSideOffsets2D_f32__UnknownUnit, TypedSideOffsets2D_f32__LayoutUnit, SideOffsets2D_f32, LayoutSideOffsets2D, TypedSize2D_f32__UnknownUnit, TypedSize2D_f32__LayoutUnit, Size2D_f32, LayoutSize2D, TypedPoint2D_f32__UnknownUnit, TypedPoint2D_f32__LayoutUnit, Point2D_f32, LayoutPoint2D, TypedRect_f32__UnknownUnit, TypedRect_f32__LayoutUnit, Rect_f32, LayoutRect, TypedTransform2D_f32__UnknownUnit__LayoutUnit, TypedTransform2D_f32__LayoutUnit__UnknownUnit]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Surface = C.Surface
+
+type Window = C.Window
+
+func SurfaceNew(id uint32) Surface {
+  return C.surface_new(C.uint32_t(id))
+}
+
+func WindowNew(id uint32) Window {
+  return C.window_new(C.uint32_t(id))
+}
@@ -0,0 +1,25 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"id"})
+  class Surface extends Structure {
+    public int id;
+
+    public static class ByReference extends Surface implements Structure.ByReference {}
+
+    public static class ByValue extends Surface implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id"})
+  class Window extends Structure {
+    public int id;
+
+    public static class ByReference extends Window implements Structure.ByReference {}
+
+    public static class ByValue extends Window implements Structure.ByValue {}
+  }
+
+  Surface.ByValue surface_new(int id);
+
+  Window.ByValue window_new(int id);
+}
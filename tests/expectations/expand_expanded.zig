@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Surface = extern struct {
+  id: u32,
+};
+
+pub const Window = extern struct {
+  id: u32,
+};
+
+pub extern fn surface_new(id: u32) Surface;
+
+pub extern fn window_new(id: u32) Window;
@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Surface {
+  uint32_t id;
+};
+
+struct Window {
+  uint32_t id;
+};
+
+extern "C" {
+
+Surface surface_new(uint32_t id);
+
+Window window_new(uint32_t id);
+
+} // extern "C"
@@ -0,0 +1,30 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Surface {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Surface: StructType<Surface>;
+
+export interface Window {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Window: StructType<Window>;
+
+export interface Library {
+  surface_new(id: number): Surface;
+
+  window_new(id: number): Window;
+}
+
+export declare function load(path: string): Library;
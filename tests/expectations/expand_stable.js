@@ -0,0 +1,29 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Surface = StructType();
+const Window = StructType();
+
+Surface.defineProperty('id', 'uint32');
+
+Window.defineProperty('id', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    surface_new: [Surface, ['uint32']],
+    window_new: [Window, ['uint32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Surface,
+  Window,
+  load,
+};
@@ -0,0 +1,26 @@
+import ctypes
+
+class Surface(ctypes.Structure):
+  pass
+
+Surface._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+class Window(ctypes.Structure):
+  pass
+
+Window._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.surface_new.argtypes = [ctypes.c_uint32]
+  lib.surface_new.restype = Surface
+
+  lib.window_new.argtypes = [ctypes.c_uint32]
+  lib.window_new.restype = Window
+
+  return lib
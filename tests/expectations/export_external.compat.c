@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "time.h"
+#include "signal.h"
+
+typedef struct {
+  time_t created;
+  sig_atomic_t flags;
+} Record;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void write_record(const Record *record, sig_atomic_t flags);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include "time.h"
+#include "signal.h"
+
+struct Record {
+  time_t created;
+  sig_atomic_t flags;
+};
+
+extern "C" {
+
+void write_record(const Record *record, sig_atomic_t flags);
+
+} // extern "C"
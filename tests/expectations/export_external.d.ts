@@ -0,0 +1,29 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Record {
+  created: number | string;
+  flags: number;
+  ref(): Buffer;
+}
+export declare const Record: StructType<Record>;
+
+export type time_t = number | string;
+export declare const time_t: RefType;
+
+export type sig_atomic_t = number;
+export declare const sig_atomic_t: RefType;
+
+export interface Library {
+  write_record(record: Buffer | null, flags: number): void;
+}
+
+export declare function load(path: string): Library;
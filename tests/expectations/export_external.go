@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type time_t = C.time_t
+
+type sig_atomic_t = C.sig_atomic_t
+
+type Record = C.Record
+
+func WriteRecord(record *Record, flags sig_atomic_t) {
+  C.write_record(record, flags)
+}
@@ -0,0 +1,15 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"created", "flags"})
+  class Record extends Structure {
+    public long created;
+    public int flags;
+
+    public static class ByReference extends Record implements Structure.ByReference {}
+
+    public static class ByValue extends Record implements Structure.ByValue {}
+  }
+
+  void write_record(Record.ByReference record, int flags);
+}
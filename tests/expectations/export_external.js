@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Record = StructType();
+
+Record.defineProperty('created', 'int64');
+Record.defineProperty('flags', 'int32');
+
+const time_t = 'int64';
+
+const sig_atomic_t = 'int32';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    write_record: ['void', [ref.refType(Record), 'int32']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Record,
+  time_t,
+  sig_atomic_t,
+  load,
+};
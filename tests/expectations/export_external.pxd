@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef int64_t time_t
+
+  ctypedef int32_t sig_atomic_t
+
+  ctypedef struct Record:
+    time_t created
+    sig_atomic_t flags
+
+  void write_record(const Record *record, sig_atomic_t flags)
@@ -0,0 +1,21 @@
+import ctypes
+
+time_t = ctypes.c_int64
+
+sig_atomic_t = ctypes.c_int32
+
+class Record(ctypes.Structure):
+  pass
+
+Record._fields_ = [
+  ("created", time_t),
+  ("flags", sig_atomic_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.write_record.argtypes = [ctypes.POINTER(Record), sig_atomic_t]
+  lib.write_record.restype = None
+
+  return lib
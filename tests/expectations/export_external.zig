@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const time_t = i64;
+
+pub const sig_atomic_t = i32;
+
+pub const Record = extern struct {
+  created: time_t,
+  flags: sig_atomic_t,
+};
+
+pub extern fn write_record(record: [*c]const Record, flags: sig_atomic_t) void;
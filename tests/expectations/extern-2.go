@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+func First() {
+  C.first()
+}
+
+func Second() {
+  C.second()
+}
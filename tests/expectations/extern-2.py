@@ -0,0 +1,12 @@
+import ctypes
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.first.argtypes = []
+  lib.first.restype = None
+
+  lib.second.argtypes = []
+  lib.second.restype = None
+
+  return lib
@@ -0,0 +1,25 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Normal {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Normal: StructType<Normal>;
+
+export interface Library {
+  bar(a: Normal): void;
+
+  foo(): number;
+}
+
+export declare function load(path: string): Library;
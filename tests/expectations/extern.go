@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Normal = C.Normal
+
+func Bar(a Normal) {
+  C.bar(a)
+}
+
+func Foo() int32 {
+  return int32(C.foo())
+}
@@ -0,0 +1,17 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Normal extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends Normal implements Structure.ByReference {}
+
+    public static class ByValue extends Normal implements Structure.ByValue {}
+  }
+
+  void bar(Normal.ByValue a);
+
+  int foo();
+}
@@ -0,0 +1,26 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Normal = StructType();
+
+Normal.defineProperty('x', 'int32');
+Normal.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    bar: ['void', [Normal]],
+    foo: ['int32', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  Normal,
+  load,
+};
@@ -0,0 +1,20 @@
+import ctypes
+
+class Normal(ctypes.Structure):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.bar.argtypes = [Normal]
+  lib.bar.restype = None
+
+  lib.foo.argtypes = []
+  lib.foo.restype = ctypes.c_int32
+
+  return lib
@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface ExtType {
+  data: number;
+  ref(): Buffer;
+}
+export declare const ExtType: StructType<ExtType>;
+
+export interface Library {
+  consume_ext(_ext: ExtType): void;
+}
+
+export declare function load(path: string): Library;
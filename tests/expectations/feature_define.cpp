@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#if defined(MYLIB_FEATURE_LEGACY)
+static const uint32_t LEGACY_VERSION = 2;
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+struct Speaker {
+  uint8_t volume;
+};
+#endif
+
+extern "C" {
+
+#if defined(MYLIB_SERDE)
+void load(const uint8_t *data, uintptr_t len);
+#endif
+
+#if (defined(MYLIB_FEATURE_LEGACY) && defined(MYLIB_SERDE))
+void load_legacy(const uint8_t *data, uintptr_t len);
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+void play(Speaker speaker);
+#endif
+
+} // extern "C"
@@ -0,0 +1,33 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(feature = "legacy")
+export declare const LEGACY_VERSION: number;
+
+// cfg(feature = "audio-out")
+export interface Speaker {
+  volume: number;
+  ref(): Buffer;
+}
+export declare const Speaker: StructType<Speaker>;
+
+export interface Library {
+  // cfg(feature = "serde")
+  load(data: Buffer | null, len: number | string): void;
+
+  // cfg(all(feature = "legacy", feature = "serde"))
+  load_legacy(data: Buffer | null, len: number | string): void;
+
+  // cfg(feature = "audio-out")
+  play(speaker: Speaker): void;
+}
+
+export declare function load(path: string): Library;
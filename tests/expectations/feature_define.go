@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+// cfg(feature = "audio-out")
+type Speaker = C.Speaker
+
+// cfg(feature = "legacy")
+const LEGACY_VERSION = C.LEGACY_VERSION
+
+// cfg(feature = "serde")
+func Load(data *uint8, len uintptr) {
+  C.load((*C.uint8_t)(unsafe.Pointer(data)), C.uintptr_t(len))
+}
+
+// cfg(all(feature = "legacy", feature = "serde"))
+func LoadLegacy(data *uint8, len uintptr) {
+  C.load_legacy((*C.uint8_t)(unsafe.Pointer(data)), C.uintptr_t(len))
+}
+
+// cfg(feature = "audio-out")
+func Play(speaker Speaker) {
+  C.play(speaker)
+}
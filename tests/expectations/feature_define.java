@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(feature = "legacy")
+  int LEGACY_VERSION = 2;
+
+  // cfg(feature = "audio-out")
+  @Structure.FieldOrder({"volume"})
+  class Speaker extends Structure {
+    public byte volume;
+
+    public static class ByReference extends Speaker implements Structure.ByReference {}
+
+    public static class ByValue extends Speaker implements Structure.ByValue {}
+  }
+
+  // cfg(feature = "serde")
+  void load(Pointer data, SizeT len);
+
+  // cfg(all(feature = "legacy", feature = "serde"))
+  void load_legacy(Pointer data, SizeT len);
+
+  // cfg(feature = "audio-out")
+  void play(Speaker.ByValue speaker);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,34 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Speaker = StructType();
+
+// cfg(feature = "legacy")
+const LEGACY_VERSION = 2;
+
+// cfg(feature = "audio-out")
+Speaker.defineProperty('volume', 'uint8');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    // cfg(feature = "serde")
+    load: ['void', [ref.refType('uint8'), 'size_t']],
+    // cfg(all(feature = "legacy", feature = "serde"))
+    load_legacy: ['void', [ref.refType('uint8'), 'size_t']],
+    // cfg(feature = "audio-out")
+    play: ['void', [Speaker]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Speaker,
+  LEGACY_VERSION,
+  load,
+};
@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(feature = "legacy")
+  const uint32_t LEGACY_VERSION
+
+  # cfg(feature = "audio-out")
+  ctypedef struct Speaker:
+    uint8_t volume
+
+  # cfg(feature = "serde")
+  void load(const uint8_t *data, uintptr_t len)
+
+  # cfg(all(feature = "legacy", feature = "serde"))
+  void load_legacy(const uint8_t *data, uintptr_t len)
+
+  # cfg(feature = "audio-out")
+  void play(Speaker speaker)
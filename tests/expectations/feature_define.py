@@ -0,0 +1,29 @@
+import ctypes
+
+# cfg(feature = "legacy")
+LEGACY_VERSION = 2
+
+# cfg(feature = "audio-out")
+class Speaker(ctypes.Structure):
+  pass
+
+Speaker._fields_ = [
+  ("volume", ctypes.c_uint8),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # cfg(feature = "serde")
+  lib.load.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.load.restype = None
+
+  # cfg(all(feature = "legacy", feature = "serde"))
+  lib.load_legacy.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.load_legacy.restype = None
+
+  # cfg(feature = "audio-out")
+  lib.play.argtypes = [Speaker]
+  lib.play.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+const std = @import("std");
+
+// cfg(feature = "legacy")
+pub const LEGACY_VERSION: u32 = 2;
+
+// cfg(feature = "audio-out")
+pub const Speaker = extern struct {
+  volume: u8,
+};
+
+// cfg(feature = "serde")
+pub extern fn load(data: [*c]const u8, len: usize) void;
+
+// cfg(all(feature = "legacy", feature = "serde"))
+pub extern fn load_legacy(data: [*c]const u8, len: usize) void;
+
+// cfg(feature = "audio-out")
+pub extern fn play(speaker: Speaker) void;
@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Options {
+  int32_t level = 3;
+  bool verbose = true;
+  float ratio = 0.5;
+  const uint8_t *name;
+};
+
+struct Plain {
+  int32_t x;
+};
+
+extern "C" {
+
+void root(Options options, Plain plain);
+
+} // extern "C"
@@ -0,0 +1,31 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Options {
+  level: number;
+  verbose: boolean;
+  ratio: number;
+  name: Buffer;
+  ref(): Buffer;
+}
+export declare const Options: StructType<Options>;
+
+export interface Plain {
+  x: number;
+  ref(): Buffer;
+}
+export declare const Plain: StructType<Plain>;
+
+export interface Library {
+  root(options: Options, plain: Plain): void;
+}
+
+export declare function load(path: string): Library;
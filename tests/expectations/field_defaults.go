@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Options = C.Options
+
+type Plain = C.Plain
+
+func Root(options Options, plain Plain) {
+  C.root(options, plain)
+}
@@ -0,0 +1,26 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"level", "verbose", "ratio", "name"})
+  class Options extends Structure {
+    public int level;
+    public byte verbose;
+    public float ratio;
+    public Pointer name;
+
+    public static class ByReference extends Options implements Structure.ByReference {}
+
+    public static class ByValue extends Options implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x"})
+  class Plain extends Structure {
+    public int x;
+
+    public static class ByReference extends Plain implements Structure.ByReference {}
+
+    public static class ByValue extends Plain implements Structure.ByValue {}
+  }
+
+  void root(Options.ByValue options, Plain.ByValue plain);
+}
@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Options = StructType();
+const Plain = StructType();
+
+Options.defineProperty('level', 'int32');
+Options.defineProperty('verbose', 'bool');
+Options.defineProperty('ratio', 'float');
+Options.defineProperty('name', ref.refType('uint8'));
+
+Plain.defineProperty('x', 'int32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Options, Plain]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Options,
+  Plain,
+  load,
+};
@@ -0,0 +1,26 @@
+import ctypes
+
+class Options(ctypes.Structure):
+  pass
+
+Options._fields_ = [
+  ("level", ctypes.c_int32),
+  ("verbose", ctypes.c_bool),
+  ("ratio", ctypes.c_float),
+  ("name", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+class Plain(ctypes.Structure):
+  pass
+
+Plain._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Options, Plain]
+  lib.root.restype = None
+
+  return lib
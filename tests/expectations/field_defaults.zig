@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const Options = extern struct {
+  level: i32,
+  verbose: bool,
+  ratio: f32,
+  name: [*c]const u8,
+};
+
+pub const Plain = extern struct {
+  x: i32,
+};
+
+pub extern fn root(options: Options, plain: Plain) void;
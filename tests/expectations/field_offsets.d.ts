@@ -0,0 +1,44 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Header {
+  magic: number;
+  version: number;
+  flags: number;
+  length: number | string;
+  ref(): Buffer;
+}
+export declare const Header: StructType<Header>;
+
+export interface Record {
+  id: number;
+  value: number;
+  count: number;
+  ref(): Buffer;
+}
+export declare const Record: StructType<Record>;
+
+/**
+ * Pointers differ in size between data models.
+ */
+export interface Node {
+  tag: number;
+  data: Buffer;
+  weight: number;
+  ref(): Buffer;
+}
+export declare const Node: StructType<Node>;
+
+export interface Library {
+  root(header: Header, record: Record, node: Node): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,15 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Header = C.Header
+
+type Record = C.Record
+
+// Pointers differ in size between data models.
+type Node = C.Node
+
+func Root(header Header, record Record, node Node) {
+  C.root(header, record, node)
+}
@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"magic", "version", "flags", "length"})
+  class Header extends Structure {
+    public int magic;
+    public short version;
+    public byte flags;
+    public long length;
+
+    public static class ByReference extends Header implements Structure.ByReference {}
+
+    public static class ByValue extends Header implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id", "value", "count"})
+  class Record extends Structure {
+    public byte id;
+    public double value;
+    public int count;
+
+    public static class ByReference extends Record implements Structure.ByReference {}
+
+    public static class ByValue extends Record implements Structure.ByValue {}
+  }
+
+  /**
+   * Pointers differ in size between data models.
+   */
+  @Structure.FieldOrder({"tag", "data", "weight"})
+  class Node extends Structure {
+    public byte tag;
+    public Pointer data;
+    public int weight;
+
+    public static class ByReference extends Node implements Structure.ByReference {}
+
+    public static class ByValue extends Node implements Structure.ByValue {}
+  }
+
+  void root(Header.ByValue header, Record.ByValue record, Node.ByValue node);
+}
@@ -0,0 +1,39 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Header = StructType();
+const Record = StructType();
+const Node = StructType();
+
+Header.defineProperty('magic', 'uint32');
+Header.defineProperty('version', 'uint16');
+Header.defineProperty('flags', 'uint8');
+Header.defineProperty('length', 'uint64');
+
+Record.defineProperty('id', 'uint8');
+Record.defineProperty('value', 'double');
+Record.defineProperty('count', 'int32');
+
+Node.defineProperty('tag', 'uint8');
+Node.defineProperty('data', ref.refType('uint8'));
+Node.defineProperty('weight', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Header, Record, Node]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Header,
+  Record,
+  Node,
+  load,
+};
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Header:
+    uint32_t magic
+    uint16_t version
+    uint8_t flags
+    uint64_t length
+
+  ctypedef struct Record:
+    uint8_t id
+    double value
+    int32_t count
+
+  # Pointers differ in size between data models.
+  ctypedef struct Node:
+    uint8_t tag
+    const uint8_t *data
+    uint32_t weight
+
+  void root(Header header, Record record, Node node)
@@ -0,0 +1,38 @@
+import ctypes
+
+class Header(ctypes.Structure):
+  pass
+
+Header._fields_ = [
+  ("magic", ctypes.c_uint32),
+  ("version", ctypes.c_uint16),
+  ("flags", ctypes.c_uint8),
+  ("length", ctypes.c_uint64),
+]
+
+class Record(ctypes.Structure):
+  pass
+
+Record._fields_ = [
+  ("id", ctypes.c_uint8),
+  ("value", ctypes.c_double),
+  ("count", ctypes.c_int32),
+]
+
+# Pointers differ in size between data models.
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("weight", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Header, Record, Node]
+  lib.root.restype = None
+
+  return lib
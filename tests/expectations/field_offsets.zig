@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const Header = extern struct {
+  magic: u32,
+  version: u16,
+  flags: u8,
+  length: u64,
+};
+
+pub const Record = extern struct {
+  id: u8,
+  value: f64,
+  count: i32,
+};
+
+/// Pointers differ in size between data models.
+pub const Node = extern struct {
+  tag: u8,
+  data: [*c]const u8,
+  weight: u32,
+};
+
+pub extern fn root(header: Header, record: Record, node: Node) void;
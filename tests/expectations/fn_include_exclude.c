@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float *samples;
+  uintptr_t len;
+} AudioBuffer;
+
+extern float audio_volume;
+
+void audio_play(const AudioBuffer *buffer);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float *samples;
+  uintptr_t len;
+} AudioBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern float audio_volume;
+
+void audio_play(const AudioBuffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct AudioBuffer {
+  float *samples;
+  uintptr_t len;
+};
+
+extern "C" {
+
+extern float audio_volume;
+
+void audio_play(const AudioBuffer *buffer);
+
+} // extern "C"
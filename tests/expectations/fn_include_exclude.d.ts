@@ -0,0 +1,25 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface AudioBuffer {
+  samples: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const AudioBuffer: StructType<AudioBuffer>;
+
+export interface Library {
+  audio_volume: Buffer;
+
+  audio_play(buffer: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type AudioBuffer = C.AudioBuffer
+
+func AudioVolume() float32 {
+  return float32(C.audio_volume)
+}
+
+func AudioPlay(buffer *AudioBuffer) {
+  C.audio_play(buffer)
+}
@@ -0,0 +1,25 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"samples", "len"})
+  class AudioBuffer extends Structure {
+    public Pointer samples;
+    public SizeT len;
+
+    public static class ByReference extends AudioBuffer implements Structure.ByReference {}
+
+    public static class ByValue extends AudioBuffer implements Structure.ByValue {}
+  }
+
+  void audio_play(AudioBuffer.ByReference buffer);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
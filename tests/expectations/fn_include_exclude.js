@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const AudioBuffer = StructType();
+
+AudioBuffer.defineProperty('samples', ref.refType('float'));
+AudioBuffer.defineProperty('len', 'size_t');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    audio_play: ['void', [ref.refType(AudioBuffer)]],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.audio_volume = dylib.get('audio_volume');
+  return lib;
+}
+
+module.exports = {
+  AudioBuffer,
+  load,
+};
@@ -0,0 +1,19 @@
+import ctypes
+
+class AudioBuffer(ctypes.Structure):
+  pass
+
+AudioBuffer._fields_ = [
+  ("samples", ctypes.POINTER(ctypes.c_float)),
+  ("len", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.audio_volume = ctypes.c_float.in_dll(lib, "audio_volume")
+
+  lib.audio_play.argtypes = [ctypes.POINTER(AudioBuffer)]
+  lib.audio_play.restype = None
+
+  return lib
@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const AudioBuffer = extern struct {
+  samples: [*c]f32,
+  len: usize,
+};
+
+pub extern var audio_volume: f32;
+
+pub extern fn audio_play(buffer: [*c]const AudioBuffer) void;
@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Fns {
+  noArgs: Buffer;
+  anonymousArg: Buffer;
+  returnsNumber: Buffer;
+  namedArgs: Buffer;
+  namedArgsWildcards: Buffer;
+  ref(): Buffer;
+}
+export declare const Fns: StructType<Fns>;
+
+export interface Library {
+  root(_fns: Fns): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Fns = C.Fns
+
+func Root(_fns Fns) {
+  C.root(_fns)
+}
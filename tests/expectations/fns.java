@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"noArgs", "anonymousArg", "returnsNumber", "namedArgs", "namedArgsWildcards"})
+  class Fns extends Structure {
+    public Pointer noArgs;
+    public Pointer anonymousArg;
+    public Pointer returnsNumber;
+    public Pointer namedArgs;
+    public Pointer namedArgsWildcards;
+
+    public static class ByReference extends Fns implements Structure.ByReference {}
+
+    public static class ByValue extends Fns implements Structure.ByValue {}
+  }
+
+  void root(Fns.ByValue _fns);
+}
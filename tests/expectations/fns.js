@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Fns = StructType();
+
+Fns.defineProperty('noArgs', 'pointer');
+Fns.defineProperty('anonymousArg', 'pointer');
+Fns.defineProperty('returnsNumber', 'pointer');
+Fns.defineProperty('namedArgs', 'pointer');
+Fns.defineProperty('namedArgsWildcards', 'pointer');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Fns]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Fns,
+  load,
+};
@@ -0,0 +1,20 @@
+import ctypes
+
+class Fns(ctypes.Structure):
+  pass
+
+Fns._fields_ = [
+  ("noArgs", ctypes.CFUNCTYPE(None)),
+  ("anonymousArg", ctypes.CFUNCTYPE(None, ctypes.c_int32)),
+  ("returnsNumber", ctypes.CFUNCTYPE(ctypes.c_int32)),
+  ("namedArgs", ctypes.CFUNCTYPE(ctypes.c_int8, ctypes.c_int32, ctypes.c_int16)),
+  ("namedArgsWildcards", ctypes.CFUNCTYPE(ctypes.c_int8, ctypes.c_int32, ctypes.c_int16, ctypes.c_int64)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Fns]
+  lib.root.restype = None
+
+  return lib
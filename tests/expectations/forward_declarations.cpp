@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Child;
+
+struct Handle;
+
+struct Data {
+  uint8_t value;
+};
+
+struct Node {
+  Node *next;
+};
+
+struct Parent {
+  const Child *first_child;
+  uint32_t id;
+};
+
+struct Child {
+  const Parent *parent;
+  Child *next_sibling;
+  Data data;
+};
+
+extern "C" {
+
+void read(Data data);
+
+void walk(Parent parent, Node node, const Handle *handle);
+
+} // extern "C"
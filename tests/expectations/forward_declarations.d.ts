@@ -0,0 +1,45 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Data {
+  value: number;
+  ref(): Buffer;
+}
+export declare const Data: StructType<Data>;
+
+export interface Child {
+  parent: Buffer;
+  next_sibling: Buffer;
+  data: Data;
+  ref(): Buffer;
+}
+export declare const Child: StructType<Child>;
+
+export interface Parent {
+  first_child: Buffer;
+  id: number;
+  ref(): Buffer;
+}
+export declare const Parent: StructType<Parent>;
+
+export interface Node {
+  next: Buffer;
+  ref(): Buffer;
+}
+export declare const Node: StructType<Node>;
+
+export interface Library {
+  read(data: Data): void;
+
+  walk(parent: Parent, node: Node, handle: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
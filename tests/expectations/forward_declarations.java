@@ -0,0 +1,46 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"value"})
+  class Data extends Structure {
+    public byte value;
+
+    public static class ByReference extends Data implements Structure.ByReference {}
+
+    public static class ByValue extends Data implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"parent", "next_sibling", "data"})
+  class Child extends Structure {
+    public Parent.ByReference parent;
+    public Child.ByReference next_sibling;
+    public Data data;
+
+    public static class ByReference extends Child implements Structure.ByReference {}
+
+    public static class ByValue extends Child implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"first_child", "id"})
+  class Parent extends Structure {
+    public Child.ByReference first_child;
+    public int id;
+
+    public static class ByReference extends Parent implements Structure.ByReference {}
+
+    public static class ByValue extends Parent implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"next"})
+  class Node extends Structure {
+    public Node.ByReference next;
+
+    public static class ByReference extends Node implements Structure.ByReference {}
+
+    public static class ByValue extends Node implements Structure.ByValue {}
+  }
+
+  void read(Data.ByValue data);
+
+  void walk(Parent.ByValue parent, Node.ByValue node, Pointer handle);
+}
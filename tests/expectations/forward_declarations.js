@@ -0,0 +1,40 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Data = StructType();
+const Child = StructType();
+const Parent = StructType();
+const Node = StructType();
+
+Data.defineProperty('value', 'uint8');
+
+Child.defineProperty('parent', ref.refType(Parent));
+Child.defineProperty('next_sibling', ref.refType(Child));
+Child.defineProperty('data', Data);
+
+Parent.defineProperty('first_child', ref.refType(Child));
+Parent.defineProperty('id', 'uint32');
+
+Node.defineProperty('next', ref.refType(Node));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    read: ['void', [Data]],
+    walk: ['void', [Parent, Node, 'pointer']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Data,
+  Child,
+  Parent,
+  Node,
+  load,
+};
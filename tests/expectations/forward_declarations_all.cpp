@@ -0,0 +1,38 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Data;
+struct Child;
+struct Node;
+struct Parent;
+
+struct Handle;
+
+struct Data {
+  uint8_t value;
+};
+
+struct Child {
+  const Parent *parent;
+  Child *next_sibling;
+  Data data;
+};
+
+struct Node {
+  Node *next;
+};
+
+struct Parent {
+  const Child *first_child;
+  uint32_t id;
+};
+
+extern "C" {
+
+void read(Data data);
+
+void walk(Parent parent, Node node, const Handle *handle);
+
+} // extern "C"
@@ -0,0 +1,22 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+type Data = C.Data
+
+type Child = C.Child
+
+type Parent = C.Parent
+
+type Node = C.Node
+
+func Read(data Data) {
+  C.read(data)
+}
+
+func Walk(parent Parent, node Node, handle *Handle) {
+  C.walk(parent, node, handle)
+}
@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Handle:
+    pass
+
+  ctypedef struct Data:
+    uint8_t value
+
+  ctypedef struct Child:
+    const Parent *parent
+    Child *next_sibling
+    Data data
+
+  ctypedef struct Parent:
+    const Child *first_child
+    uint32_t id
+
+  ctypedef struct Node:
+    Node *next
+
+  void read(Data data)
+
+  void walk(Parent parent, Node node, const Handle *handle)
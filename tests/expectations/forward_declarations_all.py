@@ -0,0 +1,46 @@
+import ctypes
+
+class Parent(ctypes.Structure):
+  pass
+
+class Handle(ctypes.Structure):
+  pass
+
+class Data(ctypes.Structure):
+  pass
+
+Data._fields_ = [
+  ("value", ctypes.c_uint8),
+]
+
+class Child(ctypes.Structure):
+  pass
+
+Child._fields_ = [
+  ("parent", ctypes.POINTER(Parent)),
+  ("next_sibling", ctypes.POINTER(Child)),
+  ("data", Data),
+]
+
+Parent._fields_ = [
+  ("first_child", ctypes.POINTER(Child)),
+  ("id", ctypes.c_uint32),
+]
+
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("next", ctypes.POINTER(Node)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.read.argtypes = [Data]
+  lib.read.restype = None
+
+  lib.walk.argtypes = [Parent, Node, ctypes.POINTER(Handle)]
+  lib.walk.restype = None
+
+  return lib
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const Handle = opaque {};
+
+pub const Data = extern struct {
+  value: u8,
+};
+
+pub const Child = extern struct {
+  parent: [*c]const Parent,
+  next_sibling: [*c]Child,
+  data: Data,
+};
+
+pub const Parent = extern struct {
+  first_child: [*c]const Child,
+  id: u32,
+};
+
+pub const Node = extern struct {
+  next: [*c]Node,
+};
+
+pub extern fn read(data: Data) void;
+
+pub extern fn walk(parent: Parent, node: Node, handle: ?*const Handle) void;
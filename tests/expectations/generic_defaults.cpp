@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+template<typename T>
+struct Foo {
+  T a;
+};
+
+template<typename K, typename V>
+struct Pair {
+  K key;
+  V value;
+};
+
+template<typename T>
+using Bar = Pair<T, T>;
+
+struct Holder {
+  Foo<uint32_t> foo;
+  Pair<uint8_t, uint8_t> pair;
+};
+
+extern "C" {
+
+void root(Foo<uint32_t> a,
+          Foo<uint8_t> b,
+          Pair<int16_t, int16_t> c,
+          Bar<Foo<uint32_t>> d,
+          Holder e);
+
+} // extern "C"
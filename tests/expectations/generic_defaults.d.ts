@@ -0,0 +1,59 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo_u32 {
+  a: number;
+  ref(): Buffer;
+}
+export declare const Foo_u32: StructType<Foo_u32>;
+
+export interface Foo_u8 {
+  a: number;
+  ref(): Buffer;
+}
+export declare const Foo_u8: StructType<Foo_u8>;
+
+export interface Pair_i16__i16 {
+  key: number;
+  value: number;
+  ref(): Buffer;
+}
+export declare const Pair_i16__i16: StructType<Pair_i16__i16>;
+
+export interface Pair_Foo_u32_____Foo_u32 {
+  key: Foo_u32;
+  value: Foo_u32;
+  ref(): Buffer;
+}
+export declare const Pair_Foo_u32_____Foo_u32: StructType<Pair_Foo_u32_____Foo_u32>;
+
+export interface Pair_u8__u8 {
+  key: number;
+  value: number;
+  ref(): Buffer;
+}
+export declare const Pair_u8__u8: StructType<Pair_u8__u8>;
+
+export interface Holder {
+  foo: Foo_u32;
+  pair: Pair_u8__u8;
+  ref(): Buffer;
+}
+export declare const Holder: StructType<Holder>;
+
+export type Bar_Foo_u32 = Pair_Foo_u32_____Foo_u32;
+export declare const Bar_Foo_u32: RefType;
+
+export interface Library {
+  root(a: Foo_u32, b: Foo_u8, c: Pair_i16__i16, d: Pair_Foo_u32_____Foo_u32, e: Holder): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,22 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo_u32 = C.Foo_u32
+
+type Foo_u8 = C.Foo_u8
+
+type Pair_i16__i16 = C.Pair_i16__i16
+
+type Pair_Foo_u32_____Foo_u32 = C.Pair_Foo_u32_____Foo_u32
+
+type Bar_Foo_u32 = C.Bar_Foo_u32
+
+type Pair_u8__u8 = C.Pair_u8__u8
+
+type Holder = C.Holder
+
+func Root(a Foo_u32, b Foo_u8, c Pair_i16__i16, d Bar_Foo_u32, e Holder) {
+  C.root(a, b, c, d, e)
+}
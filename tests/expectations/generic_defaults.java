@@ -0,0 +1,63 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a"})
+  class Foo_u32 extends Structure {
+    public int a;
+
+    public static class ByReference extends Foo_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a"})
+  class Foo_u8 extends Structure {
+    public byte a;
+
+    public static class ByReference extends Foo_u8 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_u8 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"key", "value"})
+  class Pair_i16__i16 extends Structure {
+    public short key;
+    public short value;
+
+    public static class ByReference extends Pair_i16__i16 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_i16__i16 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"key", "value"})
+  class Pair_Foo_u32_____Foo_u32 extends Structure {
+    public Foo_u32 key;
+    public Foo_u32 value;
+
+    public static class ByReference extends Pair_Foo_u32_____Foo_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_Foo_u32_____Foo_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"key", "value"})
+  class Pair_u8__u8 extends Structure {
+    public byte key;
+    public byte value;
+
+    public static class ByReference extends Pair_u8__u8 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_u8__u8 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"foo", "pair"})
+  class Holder extends Structure {
+    public Foo_u32 foo;
+    public Pair_u8__u8 pair;
+
+    public static class ByReference extends Holder implements Structure.ByReference {}
+
+    public static class ByValue extends Holder implements Structure.ByValue {}
+  }
+
+  void root(Foo_u32.ByValue a, Foo_u8.ByValue b, Pair_i16__i16.ByValue c, Pair_Foo_u32_____Foo_u32.ByValue d, Holder.ByValue e);
+}
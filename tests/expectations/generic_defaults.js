@@ -0,0 +1,51 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo_u32 = StructType();
+const Foo_u8 = StructType();
+const Pair_i16__i16 = StructType();
+const Pair_Foo_u32_____Foo_u32 = StructType();
+const Pair_u8__u8 = StructType();
+const Holder = StructType();
+
+Foo_u32.defineProperty('a', 'uint32');
+
+Foo_u8.defineProperty('a', 'uint8');
+
+Pair_i16__i16.defineProperty('key', 'int16');
+Pair_i16__i16.defineProperty('value', 'int16');
+
+Pair_Foo_u32_____Foo_u32.defineProperty('key', Foo_u32);
+Pair_Foo_u32_____Foo_u32.defineProperty('value', Foo_u32);
+
+Pair_u8__u8.defineProperty('key', 'uint8');
+Pair_u8__u8.defineProperty('value', 'uint8');
+
+Holder.defineProperty('foo', Foo_u32);
+Holder.defineProperty('pair', Pair_u8__u8);
+
+const Bar_Foo_u32 = Pair_Foo_u32_____Foo_u32;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo_u32, Foo_u8, Pair_i16__i16, Pair_Foo_u32_____Foo_u32, Holder]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo_u32,
+  Foo_u8,
+  Pair_i16__i16,
+  Pair_Foo_u32_____Foo_u32,
+  Pair_u8__u8,
+  Holder,
+  Bar_Foo_u32,
+  load,
+};
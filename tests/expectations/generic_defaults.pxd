@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Foo_u32:
+    uint32_t a
+
+  ctypedef struct Foo_u8:
+    uint8_t a
+
+  ctypedef struct Pair_i16__i16:
+    int16_t key
+    int16_t value
+
+  ctypedef struct Pair_Foo_u32_____Foo_u32:
+    Foo_u32 key
+    Foo_u32 value
+
+  ctypedef Pair_Foo_u32_____Foo_u32 Bar_Foo_u32
+
+  ctypedef struct Pair_u8__u8:
+    uint8_t key
+    uint8_t value
+
+  ctypedef struct Holder:
+    Foo_u32 foo
+    Pair_u8__u8 pair
+
+  void root(Foo_u32 a, Foo_u8 b, Pair_i16__i16 c, Bar_Foo_u32 d, Holder e)
@@ -0,0 +1,57 @@
+import ctypes
+
+class Foo_u32(ctypes.Structure):
+  pass
+
+Foo_u32._fields_ = [
+  ("a", ctypes.c_uint32),
+]
+
+class Foo_u8(ctypes.Structure):
+  pass
+
+Foo_u8._fields_ = [
+  ("a", ctypes.c_uint8),
+]
+
+class Pair_i16__i16(ctypes.Structure):
+  pass
+
+Pair_i16__i16._fields_ = [
+  ("key", ctypes.c_int16),
+  ("value", ctypes.c_int16),
+]
+
+class Pair_Foo_u32_____Foo_u32(ctypes.Structure):
+  pass
+
+Pair_Foo_u32_____Foo_u32._fields_ = [
+  ("key", Foo_u32),
+  ("value", Foo_u32),
+]
+
+Bar_Foo_u32 = Pair_Foo_u32_____Foo_u32
+
+class Pair_u8__u8(ctypes.Structure):
+  pass
+
+Pair_u8__u8._fields_ = [
+  ("key", ctypes.c_uint8),
+  ("value", ctypes.c_uint8),
+]
+
+class Holder(ctypes.Structure):
+  pass
+
+Holder._fields_ = [
+  ("foo", Foo_u32),
+  ("pair", Pair_u8__u8),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo_u32, Foo_u8, Pair_i16__i16, Bar_Foo_u32, Holder]
+  lib.root.restype = None
+
+  return lib
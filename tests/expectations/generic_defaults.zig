@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const Foo_u32 = extern struct {
+  a: u32,
+};
+
+pub const Foo_u8 = extern struct {
+  a: u8,
+};
+
+pub const Pair_i16__i16 = extern struct {
+  key: i16,
+  value: i16,
+};
+
+pub const Pair_Foo_u32_____Foo_u32 = extern struct {
+  key: Foo_u32,
+  value: Foo_u32,
+};
+
+pub const Bar_Foo_u32 = Pair_Foo_u32_____Foo_u32;
+
+pub const Pair_u8__u8 = extern struct {
+  key: u8,
+  value: u8,
+};
+
+pub const Holder = extern struct {
+  foo: Foo_u32,
+  pair: Pair_u8__u8,
+};
+
+pub extern fn root(a: Foo_u32, b: Foo_u8, c: Pair_i16__i16, d: Bar_Foo_u32, e: Holder) void;
@@ -0,0 +1,80 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED true
+
+#define LIMIT 1024
+
+/**
+ * The direction of a move.
+ */
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+typedef enum {
+  Fast,
+  Safe,
+} Mode;
+
+typedef struct Handle Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+typedef struct {
+  uint32_t size;
+  Callback callback;
+  const char *name;
+} Options;
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Moved,
+  Closed,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Direction _0;
+} Moved_Body;
+
+typedef struct {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+  };
+} Event;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void *mylib_data(Handle *handle, int range);
+
+/**
+ * Opens a handle.
+ */
+Handle *mylib_open(const Options *options, Mode mode);
+
+int64_t mylib_read(Handle *handle, uint8_t *buf, uintptr_t len);
+
+bool mylib_send(Handle *handle, Event event, float scale);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
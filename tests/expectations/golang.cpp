@@ -0,0 +1,58 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const bool ENABLED = true;
+
+static const uint64_t LIMIT = 1024;
+
+/// The direction of a move.
+enum class Direction : uint8_t {
+  Up,
+  Down = 4,
+};
+
+enum class Mode {
+  Fast,
+  Safe,
+};
+
+struct Handle;
+
+using Callback = bool(*)(uintptr_t len, char *data);
+
+struct Options {
+  uint32_t size;
+  Callback callback;
+  const char *name;
+};
+
+struct Event {
+  enum class Tag : uint8_t {
+    Moved,
+    Closed,
+  };
+
+  struct Moved_Body {
+    Direction _0;
+  };
+
+  Tag tag;
+  union {
+    Moved_Body moved;
+  };
+};
+
+extern "C" {
+
+void *mylib_data(Handle *handle, int range);
+
+/// Opens a handle.
+Handle *mylib_open(const Options *options, Mode mode);
+
+int64_t mylib_read(Handle *handle, uint8_t *buf, uintptr_t len);
+
+bool mylib_send(Handle *handle, Event event, float scale);
+
+} // extern "C"
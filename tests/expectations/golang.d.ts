@@ -0,0 +1,73 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const ENABLED: boolean;
+
+export declare const LIMIT: number;
+
+/**
+ * The direction of a move.
+ */
+export declare const Direction: {
+  readonly Up: 0;
+  readonly Down: 4;
+};
+
+export declare const Mode: {
+  readonly Fast: 0;
+  readonly Safe: 1;
+};
+
+export declare const Event_Tag: {
+  readonly Moved: 0;
+  readonly Closed: 1;
+};
+
+export interface Options {
+  size: number;
+  callback: Buffer;
+  name: Buffer;
+  ref(): Buffer;
+}
+export declare const Options: StructType<Options>;
+
+export interface Moved_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Moved_Body: StructType<Moved_Body>;
+
+export interface Event {
+  tag: number;
+  variants: {
+    moved: Moved_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Event: StructType<Event>;
+
+export type Callback = Buffer;
+export declare const Callback: RefType;
+
+export interface Library {
+  mylib_data(handle: Buffer | null, range: number): Buffer;
+
+  /**
+   * Opens a handle.
+   */
+  mylib_open(options: Buffer | null, mode: number): Buffer;
+
+  mylib_read(handle: Buffer | null, buf: Buffer | null, len: number | string): number | string;
+
+  mylib_send(handle: Buffer | null, event: Event, scale: number): boolean;
+}
+
+export declare function load(path: string): Library;
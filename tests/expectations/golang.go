@@ -0,0 +1,58 @@
+package mylib
+
+// #cgo LDFLAGS: -lmylib
+// #include "mylib.h"
+import "C"
+
+import "unsafe"
+
+// The direction of a move.
+type Direction = C.Direction
+
+const (
+  Up Direction = C.Up
+  Down Direction = C.Down
+)
+
+type Mode = C.Mode
+
+const (
+  Fast Mode = C.Fast
+  Safe Mode = C.Safe
+)
+
+type Handle = C.Handle
+
+type Callback = C.Callback
+
+type Options = C.Options
+
+type Event_Tag = C.Event_Tag
+
+const (
+  Moved Event_Tag = C.Moved
+  Closed Event_Tag = C.Closed
+)
+
+type Moved_Body = C.Moved_Body
+
+type Event = C.Event
+
+const LIMIT = C.LIMIT
+
+func MylibData(handle *Handle, range_ C.int) unsafe.Pointer {
+  return C.mylib_data(handle, range_)
+}
+
+// Opens a handle.
+func MylibOpen(options *Options, mode Mode) *Handle {
+  return C.mylib_open(options, mode)
+}
+
+func MylibRead(handle *Handle, buf *uint8, len uintptr) int64 {
+  return int64(C.mylib_read(handle, (*C.uint8_t)(unsafe.Pointer(buf)), C.uintptr_t(len)))
+}
+
+func MylibSend(handle *Handle, event Event, scale float32) bool {
+  return bool(C.mylib_send(handle, event, C.float(scale)))
+}
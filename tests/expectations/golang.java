@@ -0,0 +1,88 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  boolean ENABLED = true;
+
+  long LIMIT = 1024L;
+
+  /**
+   * The direction of a move.
+   */
+  interface Direction {
+    byte Up = 0;
+    byte Down = 4;
+  }
+
+  interface Mode {
+    int Fast = 0;
+    int Safe = 1;
+  }
+
+  interface Callback extends Callback {
+    byte invoke(SizeT len, Pointer data);
+  }
+
+  @Structure.FieldOrder({"size", "callback", "name"})
+  class Options extends Structure {
+    public int size;
+    public Callback callback;
+    public Pointer name;
+
+    public static class ByReference extends Options implements Structure.ByReference {}
+
+    public static class ByValue extends Options implements Structure.ByValue {}
+  }
+
+  interface Event_Tag {
+    byte Moved = 0;
+    byte Closed = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Moved_Body extends Structure {
+    public byte _0;
+
+    public static class ByReference extends Moved_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Moved_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Event extends Structure {
+    public static class Variants extends Union {
+      public Moved_Body moved;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  Pointer mylib_data(Pointer handle, int range);
+
+  /**
+   * Opens a handle.
+   */
+  Pointer mylib_open(Options.ByReference options, int mode);
+
+  long mylib_read(Pointer handle, Pointer buf, SizeT len);
+
+  byte mylib_send(Pointer handle, Event.ByValue event, float scale);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,68 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Options = StructType();
+const Moved_Body = StructType();
+const _Event_Variants = UnionType();
+const Event = StructType();
+
+const ENABLED = true;
+
+const LIMIT = 1024;
+
+Options.defineProperty('size', 'uint32');
+Options.defineProperty('callback', 'pointer');
+Options.defineProperty('name', ref.refType('char'));
+
+Moved_Body.defineProperty('_0', 'uint8');
+
+_Event_Variants.defineProperty('moved', Moved_Body);
+
+Event.defineProperty('tag', 'uint8');
+Event.defineProperty('variants', _Event_Variants);
+
+const Direction = Object.freeze({
+  Up: 0,
+  Down: 4,
+});
+
+const Mode = Object.freeze({
+  Fast: 0,
+  Safe: 1,
+});
+
+const Event_Tag = Object.freeze({
+  Moved: 0,
+  Closed: 1,
+});
+
+const Callback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    mylib_data: ['pointer', ['pointer', 'int']],
+    mylib_open: ['pointer', [ref.refType(Options), 'int']],
+    mylib_read: ['int64', ['pointer', ref.refType('uint8'), 'size_t']],
+    mylib_send: ['bool', ['pointer', Event, 'float']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Options,
+  Moved_Body,
+  Event,
+  Direction,
+  Mode,
+  Event_Tag,
+  Callback,
+  ENABLED,
+  LIMIT,
+  load,
+};
@@ -0,0 +1,53 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const bool ENABLED
+
+  const uint64_t LIMIT
+
+  # The direction of a move.
+  enum:
+    Up
+    Down
+  ctypedef uint8_t Direction
+
+  ctypedef enum Mode:
+    Fast
+    Safe
+
+  ctypedef struct Handle:
+    pass
+
+  ctypedef bool (*Callback)(uintptr_t len, char *data)
+
+  ctypedef struct Options:
+    uint32_t size
+    Callback callback
+    const char *name
+
+  enum:
+    Moved
+    Closed
+  ctypedef uint8_t Event_Tag
+
+  ctypedef struct Moved_Body:
+    Direction _0
+
+  ctypedef struct Event:
+    Event_Tag tag
+    Moved_Body moved
+
+  void *mylib_data(Handle *handle, int range)
+
+  # Opens a handle.
+  Handle *mylib_open(const Options *options, Mode mode)
+
+  int64_t mylib_read(Handle *handle, uint8_t *buf, uintptr_t len)
+
+  bool mylib_send(Handle *handle, Event event, float scale)
@@ -0,0 +1,73 @@
+import ctypes
+
+ENABLED = True
+
+LIMIT = 1024
+
+# The direction of a move.
+Direction = ctypes.c_uint8
+Up = 0
+Down = 4
+
+Mode = ctypes.c_int
+Fast = 0
+Safe = 1
+
+class Handle(ctypes.Structure):
+  pass
+
+Callback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_size_t, ctypes.POINTER(ctypes.c_char))
+
+class Options(ctypes.Structure):
+  pass
+
+Options._fields_ = [
+  ("size", ctypes.c_uint32),
+  ("callback", Callback),
+  ("name", ctypes.c_char_p),
+]
+
+Event_Tag = ctypes.c_uint8
+Moved = 0
+Closed = 1
+
+class Moved_Body(ctypes.Structure):
+  pass
+
+Moved_Body._fields_ = [
+  ("_0", Direction),
+]
+
+class _Event_Variants(ctypes.Union):
+  pass
+
+_Event_Variants._fields_ = [
+  ("moved", Moved_Body),
+]
+
+class Event(ctypes.Structure):
+  pass
+
+Event._anonymous_ = ("_variants",)
+Event._fields_ = [
+  ("tag", Event_Tag),
+  ("_variants", _Event_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.mylib_data.argtypes = [ctypes.POINTER(Handle), ctypes.c_int]
+  lib.mylib_data.restype = ctypes.c_void_p
+
+  # Opens a handle.
+  lib.mylib_open.argtypes = [ctypes.POINTER(Options), Mode]
+  lib.mylib_open.restype = ctypes.POINTER(Handle)
+
+  lib.mylib_read.argtypes = [ctypes.POINTER(Handle), ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.mylib_read.restype = ctypes.c_int64
+
+  lib.mylib_send.argtypes = [ctypes.POINTER(Handle), Event, ctypes.c_float]
+  lib.mylib_send.restype = ctypes.c_bool
+
+  return lib
@@ -0,0 +1,51 @@
+const std = @import("std");
+
+pub const ENABLED: bool = true;
+
+pub const LIMIT: u64 = 1024;
+
+/// The direction of a move.
+pub const Direction = enum(u8) {
+  Up,
+  Down = 4,
+};
+
+pub const Mode = enum(c_int) {
+  Fast,
+  Safe,
+};
+
+pub const Handle = opaque {};
+
+pub const Callback = *const fn (usize, [*c]c_char) callconv(.C) bool;
+
+pub const Options = extern struct {
+  size: u32,
+  callback: Callback,
+  name: [*c]const c_char,
+};
+
+pub const Event_Tag = enum(u8) {
+  Moved,
+  Closed,
+};
+
+pub const Moved_Body = extern struct {
+  _0: Direction,
+};
+
+pub const Event = extern struct {
+  tag: Event_Tag,
+  body: extern union {
+    moved: Moved_Body,
+  },
+};
+
+pub extern fn mylib_data(handle: ?*Handle, range: c_int) ?*anyopaque;
+
+/// Opens a handle.
+pub extern fn mylib_open(options: [*c]const Options, mode: Mode) ?*Handle;
+
+pub extern fn mylib_read(handle: ?*Handle, buf: [*c]u8, len: usize) i64;
+
+pub extern fn mylib_send(handle: *Handle, event: Event, scale: f32) bool;
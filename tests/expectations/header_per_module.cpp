@@ -0,0 +1,22 @@
+#ifndef HEADER_PER_MODULE_H
+#define HEADER_PER_MODULE_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include "header_per_module_geometry.cpp"
+#include "header_per_module_units.cpp"
+
+struct Scene {
+  Rect bounds;
+  uint32_t count;
+};
+
+extern "C" {
+
+Rect scene_bounds(const Scene *scene);
+
+} // extern "C"
+
+#endif // HEADER_PER_MODULE_H
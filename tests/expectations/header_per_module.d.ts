@@ -0,0 +1,48 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const ZERO_SIZE: Size;
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Size {
+  width: number;
+  height: number;
+  ref(): Buffer;
+}
+export declare const Size: StructType<Size>;
+
+export interface Rect {
+  origin: Point;
+  size: Size;
+  ref(): Buffer;
+}
+export declare const Rect: StructType<Rect>;
+
+export interface Scene {
+  bounds: Rect;
+  count: number;
+  ref(): Buffer;
+}
+export declare const Scene: StructType<Scene>;
+
+export interface Library {
+  rect_area(rect: Buffer | null): number;
+
+  scene_bounds(scene: Buffer | null): Rect;
+}
+
+export declare function load(path: string): Library;
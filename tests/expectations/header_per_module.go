@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+type Size = C.Size
+
+type Rect = C.Rect
+
+type Scene = C.Scene
+
+func RectArea(rect *Rect) float32 {
+  return float32(C.rect_area(rect))
+}
+
+func SceneBounds(scene *Scene) Rect {
+  return C.scene_bounds(scene)
+}
@@ -0,0 +1,47 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"width", "height"})
+  class Size extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends Size implements Structure.ByReference {}
+
+    public static class ByValue extends Size implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"origin", "size"})
+  class Rect extends Structure {
+    public Point origin;
+    public Size size;
+
+    public static class ByReference extends Rect implements Structure.ByReference {}
+
+    public static class ByValue extends Rect implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"bounds", "count"})
+  class Scene extends Structure {
+    public Rect bounds;
+    public int count;
+
+    public static class ByReference extends Scene implements Structure.ByReference {}
+
+    public static class ByValue extends Scene implements Structure.ByValue {}
+  }
+
+  float rect_area(Rect.ByReference rect);
+
+  Rect.ByValue scene_bounds(Scene.ByReference scene);
+}
@@ -0,0 +1,44 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+const Size = StructType();
+const Rect = StructType();
+const Scene = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+Size.defineProperty('width', 'float');
+Size.defineProperty('height', 'float');
+
+Rect.defineProperty('origin', Point);
+Rect.defineProperty('size', Size);
+
+Scene.defineProperty('bounds', Rect);
+Scene.defineProperty('count', 'uint32');
+
+const ZERO_SIZE = Size({ width: 0.0, height: 0.0 });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    rect_area: ['float', [ref.refType(Rect)]],
+    scene_bounds: [Rect, [ref.refType(Scene)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Size,
+  Rect,
+  Scene,
+  ZERO_SIZE,
+  load,
+};
@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Point:
+    float x
+    float y
+
+  ctypedef struct Size:
+    float width
+    float height
+
+  ctypedef struct Rect:
+    Point origin
+    Size size
+
+  ctypedef struct Scene:
+    Rect bounds
+    uint32_t count
+
+  const Size ZERO_SIZE
+
+  float rect_area(const Rect *rect)
+
+  Rect scene_bounds(const Scene *scene)
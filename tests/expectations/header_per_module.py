@@ -0,0 +1,46 @@
+import ctypes
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class Size(ctypes.Structure):
+  pass
+
+Size._fields_ = [
+  ("width", ctypes.c_float),
+  ("height", ctypes.c_float),
+]
+
+class Rect(ctypes.Structure):
+  pass
+
+Rect._fields_ = [
+  ("origin", Point),
+  ("size", Size),
+]
+
+class Scene(ctypes.Structure):
+  pass
+
+Scene._fields_ = [
+  ("bounds", Rect),
+  ("count", ctypes.c_uint32),
+]
+
+ZERO_SIZE = Size(width=0.0, height=0.0)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.rect_area.argtypes = [ctypes.POINTER(Rect)]
+  lib.rect_area.restype = ctypes.c_float
+
+  lib.scene_bounds.argtypes = [ctypes.POINTER(Scene)]
+  lib.scene_bounds.restype = Rect
+
+  return lib
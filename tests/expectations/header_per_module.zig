@@ -0,0 +1,27 @@
+const std = @import("std");
+
+pub const ZERO_SIZE: Size = Size{ .width = 0.0, .height = 0.0 };
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Size = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const Rect = extern struct {
+  origin: Point,
+  size: Size,
+};
+
+pub const Scene = extern struct {
+  bounds: Rect,
+  count: u32,
+};
+
+pub extern fn rect_area(rect: *const Rect) f32;
+
+pub extern fn scene_bounds(scene: *const Scene) Rect;
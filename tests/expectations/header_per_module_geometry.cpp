@@ -0,0 +1,26 @@
+#ifndef HEADER_PER_MODULE_H_GEOMETRY
+#define HEADER_PER_MODULE_H_GEOMETRY
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include "header_per_module_units.cpp"
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Rect {
+  Point origin;
+  Size size;
+};
+
+extern "C" {
+
+float rect_area(const Rect *rect);
+
+} // extern "C"
+
+#endif // HEADER_PER_MODULE_H_GEOMETRY
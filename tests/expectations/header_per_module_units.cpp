@@ -0,0 +1,16 @@
+#ifndef HEADER_PER_MODULE_H_UNITS
+#define HEADER_PER_MODULE_H_UNITS
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Size {
+  float width;
+  float height;
+};
+
+static const Size ZERO_SIZE = (Size){ .width = 0.0, .height = 0.0 };
+
+#endif // HEADER_PER_MODULE_H_UNITS
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef void (*MyLib_Previous)(uint32_t code);
+
+typedef void (*MyLib_ErrorHandler)(uint32_t code);
+
+typedef bool (*MyLib_SetFilterFilterCallback)(int32_t level);
+
+typedef void (*MyLib_LoggerOnFlushCallback)();
+
+typedef void (*MyLib_LogCallback)(int32_t level, const uint8_t *message);
+
+typedef struct {
+  int32_t level;
+  MyLib_LoggerOnFlushCallback on_flush;
+  MyLib_LogCallback on_log;
+} MyLib_Logger;
+
+MyLib_Previous set_error_handler(MyLib_ErrorHandler handler);
+
+void set_filter(MyLib_SetFilterFilterCallback filter, int32_t threshold);
+
+void set_logger(MyLib_Logger logger);
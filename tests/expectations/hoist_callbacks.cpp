@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+using MyLib_Previous = void(*)(uint32_t code);
+
+using MyLib_ErrorHandler = void(*)(uint32_t code);
+
+using MyLib_SetFilterFilterCallback = bool(*)(int32_t level);
+
+using MyLib_LoggerOnFlushCallback = void(*)();
+
+using MyLib_LogCallback = void(*)(int32_t level, const uint8_t *message);
+
+struct MyLib_Logger {
+  int32_t level;
+  MyLib_LoggerOnFlushCallback on_flush;
+  MyLib_LogCallback on_log;
+};
+
+extern "C" {
+
+MyLib_Previous set_error_handler(MyLib_ErrorHandler handler);
+
+void set_filter(MyLib_SetFilterFilterCallback filter, int32_t threshold);
+
+void set_logger(MyLib_Logger logger);
+
+} // extern "C"
@@ -0,0 +1,43 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface MyLib_Logger {
+  level: number;
+  on_flush: Buffer;
+  on_log: Buffer;
+  ref(): Buffer;
+}
+export declare const MyLib_Logger: StructType<MyLib_Logger>;
+
+export type MyLib_Previous = Buffer;
+export declare const MyLib_Previous: RefType;
+
+export type MyLib_ErrorHandler = Buffer;
+export declare const MyLib_ErrorHandler: RefType;
+
+export type MyLib_SetFilterFilterCallback = Buffer;
+export declare const MyLib_SetFilterFilterCallback: RefType;
+
+export type MyLib_LoggerOnFlushCallback = Buffer;
+export declare const MyLib_LoggerOnFlushCallback: RefType;
+
+export type MyLib_LogCallback = Buffer;
+export declare const MyLib_LogCallback: RefType;
+
+export interface Library {
+  set_error_handler(handler: Buffer): Buffer;
+
+  set_filter(filter: Buffer, threshold: number): void;
+
+  set_logger(logger: MyLib_Logger): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,28 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type MyLib_Previous = C.MyLib_Previous
+
+type MyLib_ErrorHandler = C.MyLib_ErrorHandler
+
+type MyLib_SetFilterFilterCallback = C.MyLib_SetFilterFilterCallback
+
+type MyLib_LoggerOnFlushCallback = C.MyLib_LoggerOnFlushCallback
+
+type MyLib_LogCallback = C.MyLib_LogCallback
+
+type MyLib_Logger = C.MyLib_Logger
+
+func SetErrorHandler(handler MyLib_ErrorHandler) MyLib_Previous {
+  return C.set_error_handler(handler)
+}
+
+func SetFilter(filter MyLib_SetFilterFilterCallback, threshold int32) {
+  C.set_filter(filter, C.int32_t(threshold))
+}
+
+func SetLogger(logger MyLib_Logger) {
+  C.set_logger(logger)
+}
@@ -0,0 +1,40 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface MyLib_Previous extends Callback {
+    void invoke(int code);
+  }
+
+  interface MyLib_ErrorHandler extends Callback {
+    void invoke(int code);
+  }
+
+  interface MyLib_SetFilterFilterCallback extends Callback {
+    byte invoke(int level);
+  }
+
+  interface MyLib_LoggerOnFlushCallback extends Callback {
+    void invoke();
+  }
+
+  interface MyLib_LogCallback extends Callback {
+    void invoke(int level, Pointer message);
+  }
+
+  @Structure.FieldOrder({"level", "on_flush", "on_log"})
+  class MyLib_Logger extends Structure {
+    public int level;
+    public MyLib_LoggerOnFlushCallback on_flush;
+    public MyLib_LogCallback on_log;
+
+    public static class ByReference extends MyLib_Logger implements Structure.ByReference {}
+
+    public static class ByValue extends MyLib_Logger implements Structure.ByValue {}
+  }
+
+  MyLib_Previous set_error_handler(MyLib_ErrorHandler handler);
+
+  void set_filter(MyLib_SetFilterFilterCallback filter, int threshold);
+
+  void set_logger(MyLib_Logger.ByValue logger);
+}
@@ -0,0 +1,43 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const MyLib_Logger = StructType();
+
+MyLib_Logger.defineProperty('level', 'int32');
+MyLib_Logger.defineProperty('on_flush', 'pointer');
+MyLib_Logger.defineProperty('on_log', 'pointer');
+
+const MyLib_Previous = 'pointer';
+
+const MyLib_ErrorHandler = 'pointer';
+
+const MyLib_SetFilterFilterCallback = 'pointer';
+
+const MyLib_LoggerOnFlushCallback = 'pointer';
+
+const MyLib_LogCallback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    set_error_handler: ['pointer', ['pointer']],
+    set_filter: ['void', ['pointer', 'int32']],
+    set_logger: ['void', [MyLib_Logger]],
+  });
+  return lib;
+}
+
+module.exports = {
+  MyLib_Logger,
+  MyLib_Previous,
+  MyLib_ErrorHandler,
+  MyLib_SetFilterFilterCallback,
+  MyLib_LoggerOnFlushCallback,
+  MyLib_LogCallback,
+  load,
+};
@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef void (*MyLib_Previous)(uint32_t code)
+
+  ctypedef void (*MyLib_ErrorHandler)(uint32_t code)
+
+  ctypedef bool (*MyLib_SetFilterFilterCallback)(int32_t level)
+
+  ctypedef void (*MyLib_LoggerOnFlushCallback)()
+
+  ctypedef void (*MyLib_LogCallback)(int32_t level, const uint8_t *message)
+
+  ctypedef struct MyLib_Logger:
+    int32_t level
+    MyLib_LoggerOnFlushCallback on_flush
+    MyLib_LogCallback on_log
+
+  MyLib_Previous set_error_handler(MyLib_ErrorHandler handler)
+
+  void set_filter(MyLib_SetFilterFilterCallback filter, int32_t threshold)
+
+  void set_logger(MyLib_Logger logger)
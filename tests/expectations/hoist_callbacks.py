@@ -0,0 +1,34 @@
+import ctypes
+
+MyLib_Previous = ctypes.CFUNCTYPE(None, ctypes.c_uint32)
+
+MyLib_ErrorHandler = ctypes.CFUNCTYPE(None, ctypes.c_uint32)
+
+MyLib_SetFilterFilterCallback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_int32)
+
+MyLib_LoggerOnFlushCallback = ctypes.CFUNCTYPE(None)
+
+MyLib_LogCallback = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.POINTER(ctypes.c_uint8))
+
+class MyLib_Logger(ctypes.Structure):
+  pass
+
+MyLib_Logger._fields_ = [
+  ("level", ctypes.c_int32),
+  ("on_flush", MyLib_LoggerOnFlushCallback),
+  ("on_log", MyLib_LogCallback),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.set_error_handler.argtypes = [MyLib_ErrorHandler]
+  lib.set_error_handler.restype = MyLib_Previous
+
+  lib.set_filter.argtypes = [MyLib_SetFilterFilterCallback, ctypes.c_int32]
+  lib.set_filter.restype = None
+
+  lib.set_logger.argtypes = [MyLib_Logger]
+  lib.set_logger.restype = None
+
+  return lib
@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const MyLib_Previous = ?*const fn (u32) callconv(.C) void;
+
+pub const MyLib_ErrorHandler = *const fn (u32) callconv(.C) void;
+
+pub const MyLib_SetFilterFilterCallback = *const fn (i32) callconv(.C) bool;
+
+pub const MyLib_LoggerOnFlushCallback = *const fn () callconv(.C) void;
+
+pub const MyLib_LogCallback = ?*const fn (i32, [*c]const u8) callconv(.C) void;
+
+pub const MyLib_Logger = extern struct {
+  level: i32,
+  on_flush: MyLib_LoggerOnFlushCallback,
+  on_log: MyLib_LogCallback,
+};
+
+pub extern fn set_error_handler(handler: MyLib_ErrorHandler) MyLib_Previous;
+
+pub extern fn set_filter(filter: MyLib_SetFilterFilterCallback, threshold: i32) void;
+
+pub extern fn set_logger(logger: MyLib_Logger) void;
@@ -0,0 +1,28 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface A {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const A: StructType<A>;
+
+export interface B {
+  data: A;
+  ref(): Buffer;
+}
+export declare const B: StructType<B>;
+
+export interface Library {
+}
+
+export declare function load(path: string): Library;
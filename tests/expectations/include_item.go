@@ -0,0 +1,8 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+type B = C.B
@@ -0,0 +1,22 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class A extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends A implements Structure.ByReference {}
+
+    public static class ByValue extends A implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"data"})
+  class B extends Structure {
+    public A data;
+
+    public static class ByReference extends B implements Structure.ByReference {}
+
+    public static class ByValue extends B implements Structure.ByValue {}
+  }
+}
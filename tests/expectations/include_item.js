@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const A = StructType();
+const B = StructType();
+
+A.defineProperty('x', 'int32');
+A.defineProperty('y', 'float');
+
+B.defineProperty('data', A);
+
+function load(path) {
+  const lib = ffi.Library(path, {});
+  return lib;
+}
+
+module.exports = {
+  A,
+  B,
+  load,
+};
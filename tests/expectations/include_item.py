@@ -0,0 +1,16 @@
+import ctypes
+
+class A(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+class B(ctypes.Structure):
+  pass
+
+B._fields_ = [
+  ("data", A),
+]
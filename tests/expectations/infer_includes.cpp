@@ -0,0 +1,16 @@
+#include <cstdint>
+#include <cmath>
+
+static const float SCALE = INFINITY;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+  bool visible;
+};
+
+extern "C" {
+
+double area(const Point *p, uintptr_t len);
+
+} // extern "C"
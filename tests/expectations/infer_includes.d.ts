@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const SCALE: number;
+
+export interface Point {
+  x: number;
+  y: number;
+  visible: boolean;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  area(p: Buffer | null, len: number | string): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+func Area(p *Point, len uintptr) float64 {
+  return float64(C.area(p, C.uintptr_t(len)))
+}
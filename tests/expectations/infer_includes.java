@@ -0,0 +1,28 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  float SCALE = Float.POSITIVE_INFINITY;
+
+  @Structure.FieldOrder({"x", "y", "visible"})
+  class Point extends Structure {
+    public int x;
+    public int y;
+    public byte visible;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  double area(Point.ByReference p, SizeT len);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
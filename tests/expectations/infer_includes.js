@@ -0,0 +1,29 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+const SCALE = Infinity;
+
+Point.defineProperty('x', 'int32');
+Point.defineProperty('y', 'int32');
+Point.defineProperty('visible', 'bool');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    area: ['double', [ref.refType(Point), 'size_t']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  SCALE,
+  load,
+};
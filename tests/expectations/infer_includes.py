@@ -0,0 +1,20 @@
+import ctypes
+
+SCALE = float("inf")
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+  ("visible", ctypes.c_bool),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.area.argtypes = [ctypes.POINTER(Point), ctypes.c_size_t]
+  lib.area.restype = ctypes.c_double
+
+  return lib
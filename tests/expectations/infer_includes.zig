@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const SCALE: f32 = std.math.inf(f32);
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+  visible: bool,
+};
+
+pub extern fn area(p: [*c]const Point, len: usize) f64;
@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#define HEADER_MAGIC 0x4844
+
+/* Constants */
+
+static const uint32_t VERSION = 3;
+
+enum class Mode {
+  Fast,
+  Slow,
+};
+
+#define MODE_DEFAULT Fast
+
+#pragma pack(push, 1)
+
+struct Header {
+  Mode mode;
+  uint32_t size;
+};
+
+#pragma pack(pop)
+
+/* End of types */
+
+extern "C" {
+
+/* Functions */
+
+bool read_header(Header *header);
+
+bool write_header(const Header *header);
+
+#define write_header_v1 write_header
+
+} // extern "C"
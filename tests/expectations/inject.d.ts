@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const VERSION: number;
+
+export declare const Mode: {
+  readonly Fast: 0;
+  readonly Slow: 1;
+};
+
+export interface Header {
+  mode: number;
+  size: number;
+  ref(): Buffer;
+}
+export declare const Header: StructType<Header>;
+
+export interface Library {
+  read_header(header: Buffer | null): boolean;
+
+  write_header(header: Buffer | null): boolean;
+}
+
+export declare function load(path: string): Library;
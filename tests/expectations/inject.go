@@ -0,0 +1,23 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Mode = C.Mode
+
+const (
+  Fast Mode = C.Fast
+  Slow Mode = C.Slow
+)
+
+type Header = C.Header
+
+const VERSION = C.VERSION
+
+func ReadHeader(header *Header) bool {
+  return bool(C.read_header(header))
+}
+
+func WriteHeader(header *Header) bool {
+  return bool(C.write_header(header))
+}
@@ -0,0 +1,24 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int VERSION = 3;
+
+  interface Mode {
+    int Fast = 0;
+    int Slow = 1;
+  }
+
+  @Structure.FieldOrder({"mode", "size"})
+  class Header extends Structure {
+    public int mode;
+    public int size;
+
+    public static class ByReference extends Header implements Structure.ByReference {}
+
+    public static class ByValue extends Header implements Structure.ByValue {}
+  }
+
+  byte read_header(Header.ByReference header);
+
+  byte write_header(Header.ByReference header);
+}
@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Header = StructType();
+
+const VERSION = 3;
+
+Header.defineProperty('mode', 'int');
+Header.defineProperty('size', 'uint32');
+
+const Mode = Object.freeze({
+  Fast: 0,
+  Slow: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    read_header: ['bool', [ref.refType(Header)]],
+    write_header: ['bool', [ref.refType(Header)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Header,
+  Mode,
+  VERSION,
+  load,
+};
@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t VERSION
+
+  ctypedef enum Mode:
+    Fast
+    Slow
+
+  ctypedef struct Header:
+    Mode mode
+    uint32_t size
+
+  bool read_header(Header *header)
+
+  bool write_header(const Header *header)
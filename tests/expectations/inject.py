@@ -0,0 +1,26 @@
+import ctypes
+
+VERSION = 3
+
+Mode = ctypes.c_int
+Fast = 0
+Slow = 1
+
+class Header(ctypes.Structure):
+  pass
+
+Header._fields_ = [
+  ("mode", Mode),
+  ("size", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.read_header.argtypes = [ctypes.POINTER(Header)]
+  lib.read_header.restype = ctypes.c_bool
+
+  lib.write_header.argtypes = [ctypes.POINTER(Header)]
+  lib.write_header.restype = ctypes.c_bool
+
+  return lib
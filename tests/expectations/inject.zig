@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const VERSION: u32 = 3;
+
+pub const Mode = enum(c_int) {
+  Fast,
+  Slow,
+};
+
+pub const Header = extern struct {
+  mode: Mode,
+  size: u32,
+};
+
+pub extern fn read_header(header: [*c]Header) bool;
+
+pub extern fn write_header(header: [*c]const Header) bool;
@@ -0,0 +1,30 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Rect {
+  origin: Point;
+  size: Point;
+  ref(): Buffer;
+}
+export declare const Rect: StructType<Rect>;
+
+export interface Library {
+  area(rect: Rect): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+type Rect = C.Rect
+
+func Area(rect Rect) int32 {
+  return int32(C.area(rect))
+}
@@ -0,0 +1,25 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"origin", "size"})
+  class Rect extends Structure {
+    public Point origin;
+    public Point size;
+
+    public static class ByReference extends Rect implements Structure.ByReference {}
+
+    public static class ByValue extends Rect implements Structure.ByValue {}
+  }
+
+  int area(Rect.ByValue rect);
+}
@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+const Rect = StructType();
+
+Point.defineProperty('x', 'int32');
+Point.defineProperty('y', 'int32');
+
+Rect.defineProperty('origin', Point);
+Rect.defineProperty('size', Point);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    area: ['int32', [Rect]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Rect,
+  load,
+};
@@ -0,0 +1,25 @@
+import ctypes
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+class Rect(ctypes.Structure):
+  pass
+
+Rect._fields_ = [
+  ("origin", Point),
+  ("size", Point),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.area.argtypes = [Rect]
+  lib.area.restype = ctypes.c_int32
+
+  return lib
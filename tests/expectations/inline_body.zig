@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Rect = extern struct {
+  origin: Point,
+  size: Point,
+};
+
+pub extern fn area(rect: Rect) i32;
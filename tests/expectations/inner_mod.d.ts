@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo {
+  x: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  root(a: Foo): void;
+}
+
+export declare function load(path: string): Library;
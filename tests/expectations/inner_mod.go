@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+func Root(a Foo) {
+  C.root(a)
+}
@@ -0,0 +1,14 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x"})
+  class Foo extends Structure {
+    public float x;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void root(Foo.ByValue a);
+}
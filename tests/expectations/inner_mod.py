@@ -0,0 +1,16 @@
+import ctypes
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t x;
+  uint32_t y;
+} Vec2_u32;
+
+typedef struct {
+  float x;
+  float y;
+} Vec2_f32;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Vec2_i32;
+
+typedef struct {
+  double x;
+  double y;
+} Vec2_f64;
+
+typedef struct {
+  uint8_t first;
+  Vec2_f64 second;
+} Pair_u8__Vec2_f64;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t length(Vec2_u32 v);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+template<typename T>
+struct Vec2 {
+  T x;
+  T y;
+};
+
+template<typename A, typename B>
+struct Pair {
+  A first;
+  B second;
+};
+
+extern "C" {
+
+uint32_t length(Vec2<uint32_t> v);
+
+} // extern "C"
@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Vec2_u32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Vec2_u32: StructType<Vec2_u32>;
+
+export interface Vec2_f32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Vec2_f32: StructType<Vec2_f32>;
+
+export interface Vec2_i32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Vec2_i32: StructType<Vec2_i32>;
+
+export interface Vec2_f64 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Vec2_f64: StructType<Vec2_f64>;
+
+export interface Pair_u8__Vec2_f64 {
+  first: number;
+  second: Vec2_f64;
+  ref(): Buffer;
+}
+export declare const Pair_u8__Vec2_f64: StructType<Pair_u8__Vec2_f64>;
+
+export interface Library {
+  length(v: Vec2_u32): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Vec2_u32 = C.Vec2_u32
+
+type Vec2_f32 = C.Vec2_f32
+
+type Vec2_i32 = C.Vec2_i32
+
+type Vec2_f64 = C.Vec2_f64
+
+type Pair_u8__Vec2_f64 = C.Pair_u8__Vec2_f64
+
+func Length(v Vec2_u32) uint32 {
+  return uint32(C.length(v))
+}
@@ -0,0 +1,55 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Vec2_u32 extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Vec2_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Vec2_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Vec2_f32 extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Vec2_f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Vec2_f32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Vec2_i32 extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Vec2_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Vec2_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Vec2_f64 extends Structure {
+    public double x;
+    public double y;
+
+    public static class ByReference extends Vec2_f64 implements Structure.ByReference {}
+
+    public static class ByValue extends Vec2_f64 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"first", "second"})
+  class Pair_u8__Vec2_f64 extends Structure {
+    public byte first;
+    public Vec2_f64 second;
+
+    public static class ByReference extends Pair_u8__Vec2_f64 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_u8__Vec2_f64 implements Structure.ByValue {}
+  }
+
+  int length(Vec2_u32.ByValue v);
+}
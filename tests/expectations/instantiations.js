@@ -0,0 +1,45 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Vec2_u32 = StructType();
+const Vec2_f32 = StructType();
+const Vec2_i32 = StructType();
+const Vec2_f64 = StructType();
+const Pair_u8__Vec2_f64 = StructType();
+
+Vec2_u32.defineProperty('x', 'uint32');
+Vec2_u32.defineProperty('y', 'uint32');
+
+Vec2_f32.defineProperty('x', 'float');
+Vec2_f32.defineProperty('y', 'float');
+
+Vec2_i32.defineProperty('x', 'int32');
+Vec2_i32.defineProperty('y', 'int32');
+
+Vec2_f64.defineProperty('x', 'double');
+Vec2_f64.defineProperty('y', 'double');
+
+Pair_u8__Vec2_f64.defineProperty('first', 'uint8');
+Pair_u8__Vec2_f64.defineProperty('second', Vec2_f64);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    length: ['uint32', [Vec2_u32]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Vec2_u32,
+  Vec2_f32,
+  Vec2_i32,
+  Vec2_f64,
+  Pair_u8__Vec2_f64,
+  load,
+};
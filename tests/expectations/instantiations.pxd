@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Vec2_u32:
+    uint32_t x
+    uint32_t y
+
+  ctypedef struct Vec2_f32:
+    float x
+    float y
+
+  ctypedef struct Vec2_i32:
+    int32_t x
+    int32_t y
+
+  ctypedef struct Vec2_f64:
+    double x
+    double y
+
+  ctypedef struct Pair_u8__Vec2_f64:
+    uint8_t first
+    Vec2_f64 second
+
+  uint32_t length(Vec2_u32 v)
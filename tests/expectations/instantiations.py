@@ -0,0 +1,49 @@
+import ctypes
+
+class Vec2_u32(ctypes.Structure):
+  pass
+
+Vec2_u32._fields_ = [
+  ("x", ctypes.c_uint32),
+  ("y", ctypes.c_uint32),
+]
+
+class Vec2_f32(ctypes.Structure):
+  pass
+
+Vec2_f32._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class Vec2_i32(ctypes.Structure):
+  pass
+
+Vec2_i32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+class Vec2_f64(ctypes.Structure):
+  pass
+
+Vec2_f64._fields_ = [
+  ("x", ctypes.c_double),
+  ("y", ctypes.c_double),
+]
+
+class Pair_u8__Vec2_f64(ctypes.Structure):
+  pass
+
+Pair_u8__Vec2_f64._fields_ = [
+  ("first", ctypes.c_uint8),
+  ("second", Vec2_f64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.length.argtypes = [Vec2_u32]
+  lib.length.restype = ctypes.c_uint32
+
+  return lib
@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const Vec2_u32 = extern struct {
+  x: u32,
+  y: u32,
+};
+
+pub const Vec2_f32 = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Vec2_i32 = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Vec2_f64 = extern struct {
+  x: f64,
+  y: f64,
+};
+
+pub const Pair_u8__Vec2_f64 = extern struct {
+  first: u8,
+  second: Vec2_f64,
+};
+
+pub extern fn length(v: Vec2_u32) u32;
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const unsigned __int128 KEY_BITS = 128;
+
+struct Key {
+  unsigned __int128 id;
+  __int128 offset;
+};
+
+extern "C" {
+
+__int128 key_hash(const Key *key, unsigned __int128 seed);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Int128 {
+  lo: number | string;
+  hi: number | string;
+  ref(): Buffer;
+}
+export declare const Int128: StructType<Int128>;
+
+export interface UInt128 {
+  lo: number | string;
+  hi: number | string;
+  ref(): Buffer;
+}
+export declare const UInt128: StructType<UInt128>;
+
+export interface Key {
+  id: UInt128;
+  offset: Int128;
+  ref(): Buffer;
+}
+export declare const Key: StructType<Key>;
+
+export interface Library {
+  key_hash(key: Buffer | null, seed: UInt128): Int128;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Key = C.Key
+
+const KEY_BITS = C.KEY_BITS
+
+func KeyHash(key *Key, seed [16]byte) [16]byte {
+  return C.key_hash(key, seed)
+}
@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"lo", "hi"})
+  class Int128 extends Structure {
+    public long lo;
+    public long hi;
+
+    public static class ByReference extends Int128 implements Structure.ByReference {}
+
+    public static class ByValue extends Int128 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"lo", "hi"})
+  class UInt128 extends Structure {
+    public long lo;
+    public long hi;
+
+    public static class ByReference extends UInt128 implements Structure.ByReference {}
+
+    public static class ByValue extends UInt128 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id", "offset"})
+  class Key extends Structure {
+    public UInt128 id;
+    public Int128 offset;
+
+    public static class ByReference extends Key implements Structure.ByReference {}
+
+    public static class ByValue extends Key implements Structure.ByValue {}
+  }
+
+  Int128.ByValue key_hash(Key.ByReference key, UInt128.ByValue seed);
+}
@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Int128 = StructType();
+const UInt128 = StructType();
+const Key = StructType();
+
+Int128.defineProperty('lo', 'uint64');
+Int128.defineProperty('hi', 'int64');
+
+UInt128.defineProperty('lo', 'uint64');
+UInt128.defineProperty('hi', 'uint64');
+
+Key.defineProperty('id', UInt128);
+Key.defineProperty('offset', Int128);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    key_hash: [Int128, [ref.refType(Key), UInt128]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Int128,
+  UInt128,
+  Key,
+  load,
+};
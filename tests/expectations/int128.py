@@ -0,0 +1,33 @@
+import ctypes
+
+class Int128(ctypes.Structure):
+  pass
+
+Int128._fields_ = [
+  ("lo", ctypes.c_uint64),
+  ("hi", ctypes.c_int64),
+]
+
+class UInt128(ctypes.Structure):
+  pass
+
+UInt128._fields_ = [
+  ("lo", ctypes.c_uint64),
+  ("hi", ctypes.c_uint64),
+]
+
+class Key(ctypes.Structure):
+  pass
+
+Key._fields_ = [
+  ("id", UInt128),
+  ("offset", Int128),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.key_hash.argtypes = [ctypes.POINTER(Key), UInt128]
+  lib.key_hash.restype = Int128
+
+  return lib
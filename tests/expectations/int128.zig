@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const KEY_BITS: u128 = 128;
+
+pub const Key = extern struct {
+  id: u128,
+  offset: i128,
+};
+
+pub extern fn key_hash(key: [*c]const Key, seed: u128) i128;
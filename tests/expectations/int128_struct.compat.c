@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint64_t lo;
+  int64_t hi;
+} I128;
+
+typedef struct {
+  uint64_t lo;
+  uint64_t hi;
+} U128;
+
+typedef struct {
+  U128 id;
+  I128 offset;
+} Key;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+I128 key_hash(const Key *key, U128 seed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct I128 {
+  uint64_t lo;
+  int64_t hi;
+};
+
+struct U128 {
+  uint64_t lo;
+  uint64_t hi;
+};
+
+struct Key {
+  U128 id;
+  I128 offset;
+};
+
+extern "C" {
+
+I128 key_hash(const Key *key, U128 seed);
+
+} // extern "C"
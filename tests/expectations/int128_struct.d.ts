@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface I128 {
+  lo: number | string;
+  hi: number | string;
+  ref(): Buffer;
+}
+export declare const I128: StructType<I128>;
+
+export interface U128 {
+  lo: number | string;
+  hi: number | string;
+  ref(): Buffer;
+}
+export declare const U128: StructType<U128>;
+
+export interface Key {
+  id: U128;
+  offset: I128;
+  ref(): Buffer;
+}
+export declare const Key: StructType<Key>;
+
+export interface Library {
+  key_hash(key: Buffer | null, seed: U128): I128;
+}
+
+export declare function load(path: string): Library;
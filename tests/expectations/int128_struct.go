@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type I128 = C.I128
+
+type U128 = C.U128
+
+type Key = C.Key
+
+func KeyHash(key *Key, seed U128) I128 {
+  return C.key_hash(key, seed)
+}
@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"lo", "hi"})
+  class I128 extends Structure {
+    public long lo;
+    public long hi;
+
+    public static class ByReference extends I128 implements Structure.ByReference {}
+
+    public static class ByValue extends I128 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"lo", "hi"})
+  class U128 extends Structure {
+    public long lo;
+    public long hi;
+
+    public static class ByReference extends U128 implements Structure.ByReference {}
+
+    public static class ByValue extends U128 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id", "offset"})
+  class Key extends Structure {
+    public U128 id;
+    public I128 offset;
+
+    public static class ByReference extends Key implements Structure.ByReference {}
+
+    public static class ByValue extends Key implements Structure.ByValue {}
+  }
+
+  I128.ByValue key_hash(Key.ByReference key, U128.ByValue seed);
+}
@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const I128 = StructType();
+const U128 = StructType();
+const Key = StructType();
+
+I128.defineProperty('lo', 'uint64');
+I128.defineProperty('hi', 'int64');
+
+U128.defineProperty('lo', 'uint64');
+U128.defineProperty('hi', 'uint64');
+
+Key.defineProperty('id', U128);
+Key.defineProperty('offset', I128);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    key_hash: [I128, [ref.refType(Key), U128]],
+  });
+  return lib;
+}
+
+module.exports = {
+  I128,
+  U128,
+  Key,
+  load,
+};
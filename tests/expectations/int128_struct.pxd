@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct I128:
+    uint64_t lo
+    int64_t hi
+
+  ctypedef struct U128:
+    uint64_t lo
+    uint64_t hi
+
+  ctypedef struct Key:
+    U128 id
+    I128 offset
+
+  I128 key_hash(const Key *key, U128 seed)
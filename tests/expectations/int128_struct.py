@@ -0,0 +1,33 @@
+import ctypes
+
+class I128(ctypes.Structure):
+  pass
+
+I128._fields_ = [
+  ("lo", ctypes.c_uint64),
+  ("hi", ctypes.c_int64),
+]
+
+class U128(ctypes.Structure):
+  pass
+
+U128._fields_ = [
+  ("lo", ctypes.c_uint64),
+  ("hi", ctypes.c_uint64),
+]
+
+class Key(ctypes.Structure):
+  pass
+
+Key._fields_ = [
+  ("id", U128),
+  ("offset", I128),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.key_hash.argtypes = [ctypes.POINTER(Key), U128]
+  lib.key_hash.restype = I128
+
+  return lib
@@ -0,0 +1,30 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 10
+
+#define NAME 0
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern MYLIB_VAR const int32_t COUNT MYLIB_VAR_ATTR;
+
+extern MYLIB_VAR uint32_t STATE MYLIB_VAR_ATTR;
+
+MYLIB_API int32_t root(int32_t x) MYLIB_NOEXCEPT;
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,26 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+MYLIB_CONST static const int32_t LIMIT MYLIB_CONST_ATTR = 10;
+
+MYLIB_CONST_PTR static const uint8_t* const NAME MYLIB_CONST_ATTR = 0;
+
+extern "C" {
+
+extern MYLIB_VAR const int32_t COUNT MYLIB_VAR_ATTR;
+
+extern MYLIB_VAR uint32_t STATE MYLIB_VAR_ATTR;
+
+MYLIB_API int32_t root(int32_t x) MYLIB_NOEXCEPT;
+
+} // extern "C"
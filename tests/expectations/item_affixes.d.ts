@@ -0,0 +1,30 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const LIMIT: number;
+
+export interface Library {
+  COUNT: Buffer;
+
+  STATE: Buffer;
+
+  root(x: number): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,26 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const LIMIT = C.LIMIT
+
+func Count() int32 {
+  return int32(C.COUNT)
+}
+
+func State() uint32 {
+  return uint32(C.STATE)
+}
+
+func Root(x int32) int32 {
+  return int32(C.root(C.int32_t(x)))
+}
@@ -0,0 +1,15 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int LIMIT = 10;
+
+  int root(int x);
+}
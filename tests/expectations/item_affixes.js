@@ -0,0 +1,32 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const LIMIT = 10;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['int32', ['int32']],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.COUNT = dylib.get('COUNT');
+  lib.STATE = dylib.get('STATE');
+  return lib;
+}
+
+module.exports = {
+  LIMIT,
+  load,
+};
@@ -0,0 +1,25 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+import ctypes
+
+LIMIT = 10
+
+NAME = 0
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNT = ctypes.c_int32.in_dll(lib, "COUNT")
+
+  lib.STATE = ctypes.c_uint32.in_dll(lib, "STATE")
+
+  lib.root.argtypes = [ctypes.c_int32]
+  lib.root.restype = ctypes.c_int32
+
+  return lib
@@ -0,0 +1,19 @@
+#define MYLIB_API
+#define MYLIB_NOEXCEPT
+#define MYLIB_VAR
+#define MYLIB_VAR_ATTR
+#define MYLIB_CONST
+#define MYLIB_CONST_PTR
+#define MYLIB_CONST_ATTR
+
+const std = @import("std");
+
+pub const LIMIT: i32 = 10;
+
+pub const NAME: [*c]const u8 = 0;
+
+pub extern const COUNT: i32;
+
+pub extern var STATE: u32;
+
+pub extern fn root(x: i32) i32;
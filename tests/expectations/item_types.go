@@ -0,0 +1,11 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type OnlyThisShouldBeGenerated = C.OnlyThisShouldBeGenerated
+
+const (
+  Foo OnlyThisShouldBeGenerated = C.Foo
+  Bar OnlyThisShouldBeGenerated = C.Bar
+)
@@ -0,0 +1,8 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface OnlyThisShouldBeGenerated {
+    byte Foo = 0;
+    byte Bar = 1;
+  }
+}
@@ -0,0 +1,22 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const OnlyThisShouldBeGenerated = Object.freeze({
+  Foo: 0,
+  Bar: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {});
+  return lib;
+}
+
+module.exports = {
+  OnlyThisShouldBeGenerated,
+  load,
+};
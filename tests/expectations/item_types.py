@@ -0,0 +1,5 @@
+import ctypes
+
+OnlyThisShouldBeGenerated = ctypes.c_uint8
+Foo = 0
+Bar = 1
@@ -0,0 +1,11 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type StyleOnlyThisShouldBeGenerated = C.StyleOnlyThisShouldBeGenerated
+
+const (
+  Foo StyleOnlyThisShouldBeGenerated = C.Foo
+  Bar StyleOnlyThisShouldBeGenerated = C.Bar
+)
@@ -0,0 +1,72 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const wchar_t LETTER = 'j';
+
+static const uint64_t MASK = 18446744073709551615ULL;
+
+static const uint32_t MAX_POINTS = 8;
+
+static const float SCALE = 1.5f;
+
+enum class Color : uint8_t {
+  Red,
+  Green = 5,
+  Blue,
+};
+
+/// A point.
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Shape {
+  Point points[8];
+  double weights[4];
+  Color color;
+  uintptr_t len;
+  Point *origin;
+  bool package;
+};
+
+using Visitor = bool(*)(const Point *point, uint8_t *user_data);
+
+struct Value {
+  enum class Tag {
+    Number,
+    Pair,
+    Empty,
+  };
+
+  struct Number_Body {
+    int32_t _0;
+  };
+
+  struct Pair_Body {
+    Point _0;
+    Point _1;
+  };
+
+  Tag tag;
+  union {
+    Number_Body number;
+    Pair_Body pair;
+  };
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+/// Draws `shape`.
+/// # Arguments
+///  `shape` - The [`Shape`] to draw.
+///  `label` - A label to draw next to it.
+intptr_t draw(const Shape *shape, const char *label);
+
+Point visit(Shape shape, Visitor visitor, Value value);
+
+} // extern "C"
@@ -0,0 +1,95 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const LETTER: number;
+
+export declare const MASK: bigint;
+
+/**
+ * The largest number of points.
+ */
+export declare const MAX_POINTS: number;
+
+export declare const SCALE: number;
+
+export declare const Color: {
+  readonly Red: 0;
+  readonly Green: 5;
+  readonly Blue: 6;
+};
+
+export declare const Value_Tag: {
+  readonly Number: 0;
+  readonly Pair: 1;
+  readonly Empty: 2;
+};
+
+/**
+ * A point.
+ */
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Shape {
+  points: ArrayLike<Point>;
+  weights: ArrayLike<number>;
+  color: number;
+  len: number | string;
+  origin: Buffer;
+  package: boolean;
+  ref(): Buffer;
+}
+export declare const Shape: StructType<Shape>;
+
+export interface Number_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Number_Body: StructType<Number_Body>;
+
+export interface Pair_Body {
+  _0: Point;
+  _1: Point;
+  ref(): Buffer;
+}
+export declare const Pair_Body: StructType<Pair_Body>;
+
+export interface Value {
+  tag: number;
+  variants: {
+    number: Number_Body;
+    pair: Pair_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Value: StructType<Value>;
+
+export type Visitor = Buffer;
+export declare const Visitor: RefType;
+
+export interface Library {
+  COUNTER: Buffer;
+
+  /**
+   * Draws `shape`.
+   * @param shape The {@link Shape} to draw.
+   * @param label A label to draw next to it.
+   */
+  draw(shape: Buffer | null, label: string | null): number | string;
+
+  visit(shape: Shape, visitor: Buffer, value: Value): Point;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,58 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Color = C.Color
+
+const (
+  Red Color = C.Red
+  Green Color = C.Green
+  Blue Color = C.Blue
+)
+
+// A point.
+type Point = C.Point
+
+type Shape = C.Shape
+
+type Visitor = C.Visitor
+
+type Value_Tag = C.Value_Tag
+
+const (
+  Number Value_Tag = C.Number
+  Pair Value_Tag = C.Pair
+  Empty Value_Tag = C.Empty
+)
+
+type Number_Body = C.Number_Body
+
+type Pair_Body = C.Pair_Body
+
+type Value = C.Value
+
+const LETTER = C.LETTER
+
+const MASK = C.MASK
+
+// The largest number of points.
+const MAX_POINTS = C.MAX_POINTS
+
+const SCALE = C.SCALE
+
+func Counter() uint32 {
+  return uint32(C.COUNTER)
+}
+
+// Draws `shape`.
+// # Arguments
+//  `shape` - The [`Shape`] to draw.
+//  `label` - A label to draw next to it.
+func Draw(shape *Shape, label *C.char) int {
+  return int(C.draw(shape, label))
+}
+
+func Visit(shape Shape, visitor Visitor, value Value) Point {
+  return C.visit(shape, visitor, value)
+}
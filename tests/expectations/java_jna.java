@@ -0,0 +1,130 @@
+package org.example.demo;
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  Bindings INSTANCE = com.sun.jna.Native.load("demo", Bindings.class);
+
+  int LETTER = 'j';
+
+  long MASK = 0xFFFFFFFFFFFFFFFFL;
+
+  /**
+   * The largest number of points.
+   */
+  int MAX_POINTS = 8;
+
+  float SCALE = 1.5f;
+
+  interface Color {
+    byte Red = 0;
+    byte Green = 5;
+    byte Blue = 6;
+  }
+
+  /**
+   * A point.
+   */
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"points", "weights", "color", "len", "origin", "package_"})
+  class Shape extends Structure {
+    public Point[] points = (Point[]) new Point().toArray(8);
+    public double[] weights = new double[4];
+    public byte color;
+    public SizeT len;
+    public Point.ByReference origin;
+    public byte package_;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  interface Visitor extends Callback {
+    byte invoke(Point.ByReference point, Pointer user_data);
+  }
+
+  interface Value_Tag {
+    int Number = 0;
+    int Pair = 1;
+    int Empty = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Number_Body extends Structure {
+    public int _0;
+
+    public static class ByReference extends Number_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Number_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0", "_1"})
+  class Pair_Body extends Structure {
+    public Point _0;
+    public Point _1;
+
+    public static class ByReference extends Pair_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Value extends Structure {
+    public static class Variants extends Union {
+      public Number_Body number;
+      public Pair_Body pair;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends Value implements Structure.ByReference {}
+
+    public static class ByValue extends Value implements Structure.ByValue {}
+  }
+
+  Pointer COUNTER = NativeLibrary.getInstance("demo").getGlobalVariableAddress("COUNTER");
+
+  /**
+   * Draws `shape`.
+   * @param shape The {@link Shape} to draw.
+   * @param label A label to draw next to it.
+   */
+  SSizeT draw(Shape.ByReference shape, String label);
+
+  Point.ByValue visit(Shape.ByValue shape, Visitor visitor, Value.ByValue value);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+
+  class SSizeT extends IntegerType {
+    public SSizeT() {
+      this(0);
+    }
+
+    public SSizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, false);
+    }
+  }
+}
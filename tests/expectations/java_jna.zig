@@ -0,0 +1,66 @@
+const std = @import("std");
+
+pub const LETTER: u32 = 'j';
+
+pub const MASK: u64 = 18446744073709551615;
+
+/// The largest number of points.
+pub const MAX_POINTS: u32 = 8;
+
+pub const SCALE: f32 = 1.5;
+
+pub const Color = enum(u8) {
+  Red,
+  Green = 5,
+  Blue,
+};
+
+/// A point.
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Shape = extern struct {
+  points: [8]Point,
+  weights: [4]f64,
+  color: Color,
+  len: usize,
+  origin: [*c]Point,
+  package: bool,
+};
+
+pub const Visitor = *const fn ([*c]const Point, [*c]u8) callconv(.C) bool;
+
+pub const Value_Tag = enum(c_int) {
+  Number,
+  Pair,
+  Empty,
+};
+
+pub const Number_Body = extern struct {
+  _0: i32,
+};
+
+pub const Pair_Body = extern struct {
+  _0: Point,
+  _1: Point,
+};
+
+pub const Value = extern struct {
+  tag: Value_Tag,
+  body: extern union {
+    number: Number_Body,
+    pair: Pair_Body,
+  },
+};
+
+pub extern var COUNTER: u32;
+
+/// Draws `shape`.
+/// # Arguments
+///  `shape` - The [`Shape`] to draw.
+///  `label` - A label to draw next to it.
+pub extern fn draw(shape: *const Shape, label: [*c]const c_char) isize;
+
+pub extern fn visit(shape: Shape, visitor: Visitor, value: Value) Point;
@@ -0,0 +1,89 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LETTER 'j'
+
+#define MASK 18446744073709551615ULL
+
+#define MAX_POINTS 8
+
+#define SCALE 1.5f
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green = 5,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+/**
+ * A point.
+ */
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+typedef enum {
+  Number,
+  Pair,
+  Empty,
+} Value_Tag;
+
+typedef struct {
+  int32_t _0;
+} Number_Body;
+
+typedef struct {
+  Point _0;
+  Point _1;
+} Pair_Body;
+
+typedef struct {
+  Value_Tag tag;
+  union {
+    Number_Body number;
+    Pair_Body pair;
+  };
+} Value;
+
+typedef bool (*Visitor)(const Point *point, uint8_t *user_data);
+
+typedef struct {
+  Point points[8];
+  double weights[4];
+  Color color;
+  uintptr_t len;
+  Point *origin;
+  bool package;
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+Color color(Value value);
+
+uint64_t count(Visitor visitor, const Shape *shape);
+
+/**
+ * Draws `shape`.
+ * # Arguments
+ *  `shape` - The [`Shape`] to draw.
+ *  `label` - A label to draw next to it.
+ */
+intptr_t draw(const Shape *shape, const char *label);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,74 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const wchar_t LETTER = 'j';
+
+static const uint64_t MASK = 18446744073709551615ULL;
+
+static const uint32_t MAX_POINTS = 8;
+
+static const float SCALE = 1.5f;
+
+enum class Color : uint8_t {
+  Red,
+  Green = 5,
+  Blue,
+};
+
+/// A point.
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Value {
+  enum class Tag {
+    Number,
+    Pair,
+    Empty,
+  };
+
+  struct Number_Body {
+    int32_t _0;
+  };
+
+  struct Pair_Body {
+    Point _0;
+    Point _1;
+  };
+
+  Tag tag;
+  union {
+    Number_Body number;
+    Pair_Body pair;
+  };
+};
+
+using Visitor = bool(*)(const Point *point, uint8_t *user_data);
+
+struct Shape {
+  Point points[8];
+  double weights[4];
+  Color color;
+  uintptr_t len;
+  Point *origin;
+  bool package;
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+Color color(Value value);
+
+uint64_t count(Visitor visitor, const Shape *shape);
+
+/// Draws `shape`.
+/// # Arguments
+///  `shape` - The [`Shape`] to draw.
+///  `label` - A label to draw next to it.
+intptr_t draw(const Shape *shape, const char *label);
+
+} // extern "C"
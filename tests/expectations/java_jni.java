@@ -0,0 +1,39 @@
+package org.example.demo;
+
+public final class Bindings {
+  static {
+    System.loadLibrary("demo");
+  }
+
+  private Bindings() {}
+
+  public static final int LETTER = 'j';
+
+  public static final long MASK = 0xFFFFFFFFFFFFFFFFL;
+
+  /**
+   * The largest number of points.
+   */
+  public static final int MAX_POINTS = 8;
+
+  public static final float SCALE = 1.5f;
+
+  public interface Color {
+    byte Red = 0;
+    byte Green = 5;
+    byte Blue = 6;
+  }
+
+  public interface Value_Tag {
+    int Number = 0;
+    int Pair = 1;
+    int Empty = 2;
+  }
+
+  /**
+   * Draws `shape`.
+   * @param shape The {@link Shape} to draw.
+   * @param label A label to draw next to it.
+   */
+  public static native long draw(long shape, long label);
+}
@@ -0,0 +1,87 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const ssize_t = ref.sizeof.size_t === 8 ? 'int64' : 'int32';
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+const Number_Body = StructType();
+const Pair_Body = StructType();
+const _Value_Variants = UnionType();
+const Value = StructType();
+const Shape = StructType();
+
+const LETTER = 106;
+
+const MASK = 18446744073709551615n;
+
+const MAX_POINTS = 8;
+
+const SCALE = 1.5;
+
+Point.defineProperty('x', 'int32');
+Point.defineProperty('y', 'int32');
+
+Number_Body.defineProperty('_0', 'int32');
+
+Pair_Body.defineProperty('_0', Point);
+Pair_Body.defineProperty('_1', Point);
+
+_Value_Variants.defineProperty('number', Number_Body);
+_Value_Variants.defineProperty('pair', Pair_Body);
+
+Value.defineProperty('tag', 'int');
+Value.defineProperty('variants', _Value_Variants);
+
+Shape.defineProperty('points', ArrayType(Point, 8));
+Shape.defineProperty('weights', ArrayType('double', 4));
+Shape.defineProperty('color', 'uint8');
+Shape.defineProperty('len', 'size_t');
+Shape.defineProperty('origin', ref.refType(Point));
+Shape.defineProperty('package', 'bool');
+
+const Color = Object.freeze({
+  Red: 0,
+  Green: 5,
+  Blue: 6,
+});
+
+const Value_Tag = Object.freeze({
+  Number: 0,
+  Pair: 1,
+  Empty: 2,
+});
+
+const Visitor = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    color: ['uint8', [Value]],
+    count: ['uint64', ['pointer', ref.refType(Shape)]],
+    draw: [ssize_t, [ref.refType(Shape), 'string']],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.COUNTER = dylib.get('COUNTER');
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Number_Body,
+  Pair_Body,
+  Value,
+  Shape,
+  Color,
+  Value_Tag,
+  Visitor,
+  LETTER,
+  MASK,
+  MAX_POINTS,
+  SCALE,
+  load,
+};
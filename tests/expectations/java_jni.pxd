@@ -0,0 +1,67 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const wchar_t LETTER
+
+  const uint64_t MASK
+
+  # The largest number of points.
+  const uint32_t MAX_POINTS
+
+  const float SCALE
+
+  enum:
+    Red
+    Green
+    Blue
+  ctypedef uint8_t Color
+
+  # A point.
+  ctypedef struct Point:
+    int32_t x
+    int32_t y
+
+  ctypedef enum Value_Tag:
+    Number
+    Pair
+    Empty
+
+  ctypedef struct Number_Body:
+    int32_t _0
+
+  ctypedef struct Pair_Body:
+    Point _0
+    Point _1
+
+  ctypedef struct Value:
+    Value_Tag tag
+    Number_Body number
+    Pair_Body pair
+
+  ctypedef bool (*Visitor)(const Point *point, uint8_t *user_data)
+
+  ctypedef struct Shape:
+    Point points[8]
+    double weights[4]
+    Color color
+    uintptr_t len
+    Point *origin
+    bool package
+
+  uint32_t COUNTER
+
+  Color color(Value value)
+
+  uint64_t count(Visitor visitor, const Shape *shape)
+
+  # Draws `shape`.
+  # # Arguments
+  #  `shape` - The [`Shape`] to draw.
+  #  `label` - A label to draw next to it.
+  intptr_t draw(const Shape *shape, const char *label)
@@ -0,0 +1,95 @@
+import ctypes
+
+LETTER = 'j'
+
+MASK = 18446744073709551615
+
+# The largest number of points.
+MAX_POINTS = 8
+
+SCALE = 1.5
+
+Color = ctypes.c_uint8
+Red = 0
+Green = 5
+Blue = 6
+
+# A point.
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+Value_Tag = ctypes.c_int
+Number = 0
+Pair = 1
+Empty = 2
+
+class Number_Body(ctypes.Structure):
+  pass
+
+Number_Body._fields_ = [
+  ("_0", ctypes.c_int32),
+]
+
+class Pair_Body(ctypes.Structure):
+  pass
+
+Pair_Body._fields_ = [
+  ("_0", Point),
+  ("_1", Point),
+]
+
+class _Value_Variants(ctypes.Union):
+  pass
+
+_Value_Variants._fields_ = [
+  ("number", Number_Body),
+  ("pair", Pair_Body),
+]
+
+class Value(ctypes.Structure):
+  pass
+
+Value._anonymous_ = ("_variants",)
+Value._fields_ = [
+  ("tag", Value_Tag),
+  ("_variants", _Value_Variants),
+]
+
+Visitor = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.POINTER(Point), ctypes.POINTER(ctypes.c_uint8))
+
+class Shape(ctypes.Structure):
+  pass
+
+Shape._fields_ = [
+  ("points", (Point * 8)),
+  ("weights", (ctypes.c_double * 4)),
+  ("color", Color),
+  ("len", ctypes.c_size_t),
+  ("origin", ctypes.POINTER(Point)),
+  ("package", ctypes.c_bool),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  lib.color.argtypes = [Value]
+  lib.color.restype = Color
+
+  lib.count.argtypes = [Visitor, ctypes.POINTER(Shape)]
+  lib.count.restype = ctypes.c_uint64
+
+  # Draws `shape`.
+  # # Arguments
+  #  `shape` - The [`Shape`] to draw.
+  #  `label` - A label to draw next to it.
+  lib.draw.argtypes = [ctypes.POINTER(Shape), ctypes.c_char_p]
+  lib.draw.restype = ctypes.c_ssize_t
+
+  return lib
@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT (1 << 10)
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+  Left,
+};
+typedef uint8_t Direction;
+
+typedef bool (*Callback)(uintptr_t len);
+
+typedef struct {
+  uint32_t size;
+  double scale;
+  const char *name;
+  Callback callback;
+  uint8_t grid[3];
+} Options;
+
+typedef struct __attribute__((packed)) {
+  uint8_t tag;
+  uint32_t length;
+} Header;
+
+enum Event_Tag {
+  Moved,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct {
+  Direction _0;
+} Moved_Body;
+
+typedef struct {
+  Event_Tag tag;
+  union {
+    Moved_Body moved;
+  };
+} Event;
+
+extern uint32_t COUNTER;
+
+/**
+ * Processes an event.
+ */
+int32_t process(const Options *options, Header header, Event event);
@@ -0,0 +1,53 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint32_t LIMIT = 1 << 10;
+
+/// The direction of a move.
+enum class Direction : uint8_t {
+  Up,
+  Down = 4,
+  Left,
+};
+
+using Callback = bool(*)(uintptr_t len);
+
+struct Options {
+  uint32_t size;
+  double scale;
+  const char *name;
+  Callback callback;
+  uint8_t grid[3];
+};
+
+struct __attribute__((packed)) Header {
+  uint8_t tag;
+  uint32_t length;
+};
+
+struct Event {
+  enum class Tag : uint8_t {
+    Moved,
+    Closed,
+  };
+
+  struct Moved_Body {
+    Direction _0;
+  };
+
+  Tag tag;
+  union {
+    Moved_Body moved;
+  };
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+/// Processes an event.
+int32_t process(const Options *options, Header header, Event event);
+
+} // extern "C"
@@ -0,0 +1,68 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * The largest size.
+ */
+export declare const LIMIT: number;
+
+/**
+ * The direction of a move.
+ */
+export declare const Direction: {
+  readonly Up: 0;
+  readonly Down: 4;
+  readonly Left: 5;
+};
+
+export declare const Event_Tag: {
+  readonly Moved: 0;
+  readonly Closed: 1;
+};
+
+export interface Options {
+  size: number;
+  scale: number;
+  name: Buffer;
+  callback: Buffer;
+  grid: ArrayLike<number>;
+  ref(): Buffer;
+}
+export declare const Options: StructType<Options>;
+
+export interface Moved_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Moved_Body: StructType<Moved_Body>;
+
+export interface Event {
+  tag: number;
+  variants: {
+    moved: Moved_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Event: StructType<Event>;
+
+export type Callback = Buffer;
+export declare const Callback: RefType;
+
+export interface Library {
+  COUNTER: Buffer;
+
+  /**
+   * Processes an event.
+   */
+  process(options: Buffer | null, header: never, event: Event): number;
+}
+
+export declare function load(path: string): Library;
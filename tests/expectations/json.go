@@ -0,0 +1,42 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// The direction of a move.
+type Direction = C.Direction
+
+const (
+  Up Direction = C.Up
+  Down Direction = C.Down
+  Left Direction = C.Left
+)
+
+type Callback = C.Callback
+
+type Options = C.Options
+
+type Header = C.Header
+
+type Event_Tag = C.Event_Tag
+
+const (
+  Moved Event_Tag = C.Moved
+  Closed Event_Tag = C.Closed
+)
+
+type Moved_Body = C.Moved_Body
+
+type Event = C.Event
+
+// The largest size.
+const LIMIT = C.LIMIT
+
+func Counter() uint32 {
+  return uint32(C.COUNTER)
+}
+
+// Processes an event.
+func Process(options *Options, header Header, event Event) int32 {
+  return int32(C.process(options, header, event))
+}
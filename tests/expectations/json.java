@@ -0,0 +1,81 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * The largest size.
+   */
+  int LIMIT = 1 << 10;
+
+  /**
+   * The direction of a move.
+   */
+  interface Direction {
+    byte Up = 0;
+    byte Down = 4;
+    byte Left = 5;
+  }
+
+  interface Callback extends Callback {
+    byte invoke(SizeT len);
+  }
+
+  @Structure.FieldOrder({"size", "scale", "name", "callback", "grid"})
+  class Options extends Structure {
+    public int size;
+    public double scale;
+    public Pointer name;
+    public Callback callback;
+    public byte[] grid = new byte[3];
+
+    public static class ByReference extends Options implements Structure.ByReference {}
+
+    public static class ByValue extends Options implements Structure.ByValue {}
+  }
+
+  interface Event_Tag {
+    byte Moved = 0;
+    byte Closed = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Moved_Body extends Structure {
+    public byte _0;
+
+    public static class ByReference extends Moved_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Moved_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Event extends Structure {
+    public static class Variants extends Union {
+      public Moved_Body moved;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  /**
+   * Processes an event.
+   */
+  int process(Options.ByReference options, Pointer header, Event.ByValue event);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
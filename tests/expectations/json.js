@@ -0,0 +1,61 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Options = StructType();
+const Moved_Body = StructType();
+const _Event_Variants = UnionType();
+const Event = StructType();
+
+const LIMIT = 1 << 10;
+
+Options.defineProperty('size', 'uint32');
+Options.defineProperty('scale', 'double');
+Options.defineProperty('name', ref.refType('char'));
+Options.defineProperty('callback', 'pointer');
+Options.defineProperty('grid', ArrayType('uint8', 3));
+
+Moved_Body.defineProperty('_0', 'uint8');
+
+_Event_Variants.defineProperty('moved', Moved_Body);
+
+Event.defineProperty('tag', 'uint8');
+Event.defineProperty('variants', _Event_Variants);
+
+const Direction = Object.freeze({
+  Up: 0,
+  Down: 4,
+  Left: 5,
+});
+
+const Event_Tag = Object.freeze({
+  Moved: 0,
+  Closed: 1,
+});
+
+const Callback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    process: ['int32', [ref.refType(Options), 'void', Event]],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.COUNTER = dylib.get('COUNTER');
+  return lib;
+}
+
+module.exports = {
+  Options,
+  Moved_Body,
+  Event,
+  Direction,
+  Event_Tag,
+  Callback,
+  LIMIT,
+  load,
+};
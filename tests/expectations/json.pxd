@@ -0,0 +1,48 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # The largest size.
+  const uint32_t LIMIT
+
+  # The direction of a move.
+  enum:
+    Up
+    Down
+    Left
+  ctypedef uint8_t Direction
+
+  ctypedef bool (*Callback)(uintptr_t len)
+
+  ctypedef struct Options:
+    uint32_t size
+    double scale
+    const char *name
+    Callback callback
+    uint8_t grid[3]
+
+  ctypedef struct Header:
+    uint8_t tag
+    uint32_t length
+
+  enum:
+    Moved
+    Closed
+  ctypedef uint8_t Event_Tag
+
+  ctypedef struct Moved_Body:
+    Direction _0
+
+  ctypedef struct Event:
+    Event_Tag tag
+    Moved_Body moved
+
+  uint32_t COUNTER
+
+  # Processes an event.
+  int32_t process(const Options *options, Header header, Event event)
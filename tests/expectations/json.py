@@ -0,0 +1,70 @@
+import ctypes
+
+# The largest size.
+LIMIT = 1 << 10
+
+# The direction of a move.
+Direction = ctypes.c_uint8
+Up = 0
+Down = 4
+Left = 5
+
+Callback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_size_t)
+
+class Options(ctypes.Structure):
+  pass
+
+Options._fields_ = [
+  ("size", ctypes.c_uint32),
+  ("scale", ctypes.c_double),
+  ("name", ctypes.c_char_p),
+  ("callback", Callback),
+  ("grid", (ctypes.c_uint8 * 3)),
+]
+
+class Header(ctypes.Structure):
+  pass
+
+Header._pack_ = 1
+Header._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("length", ctypes.c_uint32),
+]
+
+Event_Tag = ctypes.c_uint8
+Moved = 0
+Closed = 1
+
+class Moved_Body(ctypes.Structure):
+  pass
+
+Moved_Body._fields_ = [
+  ("_0", Direction),
+]
+
+class _Event_Variants(ctypes.Union):
+  pass
+
+_Event_Variants._fields_ = [
+  ("moved", Moved_Body),
+]
+
+class Event(ctypes.Structure):
+  pass
+
+Event._anonymous_ = ("_variants",)
+Event._fields_ = [
+  ("tag", Event_Tag),
+  ("_variants", _Event_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  # Processes an event.
+  lib.process.argtypes = [ctypes.POINTER(Options), Header, Event]
+  lib.process.restype = ctypes.c_int32
+
+  return lib
@@ -0,0 +1,47 @@
+const std = @import("std");
+
+/// The largest size.
+pub const LIMIT: u32 = (1 << 10);
+
+/// The direction of a move.
+pub const Direction = enum(u8) {
+  Up,
+  Down = 4,
+  Left,
+};
+
+pub const Callback = ?*const fn (usize) callconv(.C) bool;
+
+pub const Options = extern struct {
+  size: u32,
+  scale: f64,
+  name: [*c]const c_char,
+  callback: Callback,
+  grid: [3]u8,
+};
+
+pub const Header = extern struct {
+  tag: u8 align(1),
+  length: u32 align(1),
+};
+
+pub const Event_Tag = enum(u8) {
+  Moved,
+  Closed,
+};
+
+pub const Moved_Body = extern struct {
+  _0: Direction,
+};
+
+pub const Event = extern struct {
+  tag: Event_Tag,
+  body: extern union {
+    moved: Moved_Body,
+  },
+};
+
+pub extern var COUNTER: u32;
+
+/// Processes an event.
+pub extern fn process(options: *const Options, header: Header, event: Event) i32;
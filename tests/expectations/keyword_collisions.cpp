@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint8_t small_ = 1;
+
+enum class Mode {
+  near_,
+  stdin_,
+};
+
+struct far_ {
+  int32_t delete_;
+  int32_t min_;
+};
+
+union Value {
+  int32_t class_;
+  float max_;
+};
+
+extern "C" {
+
+void keyword_collisions(far_ new_, Value errno_, Mode mode);
+
+} // extern "C"
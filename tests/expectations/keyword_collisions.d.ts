@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const small: number;
+
+export declare const Mode: {
+  readonly near_: 0;
+  readonly stdin_: 1;
+};
+
+export interface far {
+  delete_: number;
+  min_: number;
+  ref(): Buffer;
+}
+export declare const far: StructType<far>;
+
+export interface Value {
+  class_: number;
+  max_: number;
+  ref(): Buffer;
+}
+export declare const Value: StructType<Value>;
+
+export interface Library {
+  keyword_collisions(new_: far, errno_: Value, mode: number): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,21 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Mode = C.Mode
+
+const (
+  near_ Mode = C.near_
+  stdin_ Mode = C.stdin_
+)
+
+type far = C.far
+
+type Value = C.Value
+
+const small = C.small
+
+func KeywordCollisions(new_ far, errno_ Value, mode Mode) {
+  C.keyword_collisions(new_, errno_, mode)
+}
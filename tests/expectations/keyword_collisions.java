@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  byte small = 1;
+
+  interface Mode {
+    int near_ = 0;
+    int stdin_ = 1;
+  }
+
+  @Structure.FieldOrder({"delete_", "min_"})
+  class far extends Structure {
+    public int delete_;
+    public int min_;
+
+    public static class ByReference extends far implements Structure.ByReference {}
+
+    public static class ByValue extends far implements Structure.ByValue {}
+  }
+
+  class Value extends Union {
+    public int class_;
+    public float max_;
+
+    public static class ByReference extends Value implements Structure.ByReference {}
+
+    public static class ByValue extends Value implements Structure.ByValue {}
+  }
+
+  void keyword_collisions(far.ByValue new_, Value.ByValue errno_, int mode);
+}
@@ -0,0 +1,39 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const far = StructType();
+const Value = UnionType();
+
+const small = 1;
+
+far.defineProperty('delete_', 'int32');
+far.defineProperty('min_', 'int32');
+
+Value.defineProperty('class_', 'int32');
+Value.defineProperty('max_', 'float');
+
+const Mode = Object.freeze({
+  near_: 0,
+  stdin_: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    keyword_collisions: ['void', [far, Value, 'int']],
+  });
+  return lib;
+}
+
+module.exports = {
+  far,
+  Value,
+  Mode,
+  small,
+  load,
+};
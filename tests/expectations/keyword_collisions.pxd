@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint8_t small
+
+  ctypedef enum Mode:
+    near_
+    stdin_
+
+  ctypedef struct far:
+    int32_t delete_
+    int32_t min_
+
+  ctypedef union Value:
+    int32_t class_
+    float max_
+
+  void keyword_collisions(far new_, Value errno_, Mode mode)
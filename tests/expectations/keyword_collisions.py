@@ -0,0 +1,31 @@
+import ctypes
+
+small = 1
+
+Mode = ctypes.c_int
+near_ = 0
+stdin_ = 1
+
+class far(ctypes.Structure):
+  pass
+
+far._fields_ = [
+  ("delete_", ctypes.c_int32),
+  ("min_", ctypes.c_int32),
+]
+
+class Value(ctypes.Union):
+  pass
+
+Value._fields_ = [
+  ("class_", ctypes.c_int32),
+  ("max_", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.keyword_collisions.argtypes = [far, Value, Mode]
+  lib.keyword_collisions.restype = None
+
+  return lib
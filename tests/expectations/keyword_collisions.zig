@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const small: u8 = 1;
+
+pub const Mode = enum(c_int) {
+  near_,
+  stdin_,
+};
+
+pub const far = extern struct {
+  delete_: i32,
+  min_: i32,
+};
+
+pub const Value = extern union {
+  class_: i32,
+  max_: f32,
+};
+
+pub extern fn keyword_collisions(new_: far, errno_: Value, mode: Mode) void;
@@ -0,0 +1,116 @@
+#include <cstdarg>
+#include <stdint.h>
+#include <cstdlib>
+#include <new>
+#include <cstddef>
+
+enum Color {
+  Red,
+  Green,
+  Blue
+};
+
+enum {
+  Low,
+  High
+};
+typedef uint8_t Level;
+
+struct Engine;
+
+struct Shape {
+  enum {
+    Circle,
+    Rect,
+    Empty
+  };
+  typedef uint8_t Tag;
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Rect_Body {
+    float width;
+    float height;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+
+  bool IsCircle() const {
+    return tag == Circle;
+  }
+
+  bool IsRect() const {
+    return tag == Rect;
+  }
+
+  bool IsEmpty() const {
+    return tag == Empty;
+  }
+};
+
+template<typename T>
+struct Pair {
+  T first;
+  T second;
+};
+
+typedef Pair<Pair<int32_t> > Pairs;
+
+union Token {
+  enum {
+    Number,
+    Eof
+  };
+  typedef uint8_t Tag;
+
+  struct Number_Body {
+    Tag tag;
+    int32_t _0;
+  };
+
+  Tag tag;
+  Number_Body number;
+
+  bool IsNumber() const {
+    return tag == Number;
+  }
+
+  bool IsEof() const {
+    return tag == Eof;
+  }
+};
+
+inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Low: return "Low";
+    case High: return "High";
+  }
+  return NULL;
+}
+
+extern "C" {
+
+float area(Shape shape, Pairs pairs, Token token);
+
+Engine *engine_new();
+
+__attribute__((noreturn)) void fatal();
+
+void old_api(Color color, Level level);
+
+} // extern "C"
@@ -0,0 +1,68 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Color = C.Color
+
+const (
+  Red Color = C.Red
+  Green Color = C.Green
+  Blue Color = C.Blue
+)
+
+type Level = C.Level
+
+const (
+  Low Level = C.Low
+  High Level = C.High
+)
+
+type Engine = C.Engine
+
+type Shape_Tag = C.Shape_Tag
+
+const (
+  Circle Shape_Tag = C.Circle
+  Rect Shape_Tag = C.Rect
+  Empty Shape_Tag = C.Empty
+)
+
+type Circle_Body = C.Circle_Body
+
+type Rect_Body = C.Rect_Body
+
+type Shape = C.Shape
+
+type Pair_i32 = C.Pair_i32
+
+type Pair_Pair_i32 = C.Pair_Pair_i32
+
+type Pairs = C.Pairs
+
+type Token_Tag = C.Token_Tag
+
+const (
+  Number Token_Tag = C.Number
+  Eof Token_Tag = C.Eof
+)
+
+type Number_Body = C.Number_Body
+
+type Token = C.Token
+
+func Area(shape Shape, pairs Pairs, token Token) float32 {
+  return float32(C.area(shape, pairs, token))
+}
+
+func EngineNew() *Engine {
+  return C.engine_new()
+}
+
+func Fatal() {
+  C.fatal()
+}
+
+func OldApi(color Color, level Level) {
+  C.old_api(color, level)
+}
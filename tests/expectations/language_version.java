@@ -0,0 +1,110 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Color {
+    int Red = 0;
+    int Green = 1;
+    int Blue = 2;
+  }
+
+  interface Level {
+    byte Low = 0;
+    byte High = 1;
+  }
+
+  interface Shape_Tag {
+    byte Circle = 0;
+    byte Rect = 1;
+    byte Empty = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Circle_Body extends Structure {
+    public float _0;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"width", "height"})
+  class Rect_Body extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends Rect_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Rect_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Shape extends Structure {
+    public static class Variants extends Union {
+      public Circle_Body circle;
+      public Rect_Body rect;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"first", "second"})
+  class Pair_i32 extends Structure {
+    public int first;
+    public int second;
+
+    public static class ByReference extends Pair_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"first", "second"})
+  class Pair_Pair_i32 extends Structure {
+    public Pair_i32 first;
+    public Pair_i32 second;
+
+    public static class ByReference extends Pair_Pair_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Pair_Pair_i32 implements Structure.ByValue {}
+  }
+
+  interface Token_Tag {
+    byte Number = 0;
+    byte Eof = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Number_Body extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends Number_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Number_Body implements Structure.ByValue {}
+  }
+
+  class Token extends Union {
+    public byte tag;
+    public Number_Body number;
+
+    public static class ByReference extends Token implements Structure.ByReference {}
+
+    public static class ByValue extends Token implements Structure.ByValue {}
+  }
+
+  float area(Shape.ByValue shape, Pair_Pair_i32.ByValue pairs, Token.ByValue token);
+
+  Pointer engine_new();
+
+  void fatal();
+
+  void old_api(int color, byte level);
+}
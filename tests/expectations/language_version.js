@@ -0,0 +1,90 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Circle_Body = StructType();
+const Rect_Body = StructType();
+const _Shape_Variants = UnionType();
+const Shape = StructType();
+const Pair_i32 = StructType();
+const Pair_Pair_i32 = StructType();
+const Number_Body = StructType();
+const Token = UnionType();
+
+Circle_Body.defineProperty('_0', 'float');
+
+Rect_Body.defineProperty('width', 'float');
+Rect_Body.defineProperty('height', 'float');
+
+_Shape_Variants.defineProperty('circle', Circle_Body);
+_Shape_Variants.defineProperty('rect', Rect_Body);
+
+Shape.defineProperty('tag', 'uint8');
+Shape.defineProperty('variants', _Shape_Variants);
+
+Pair_i32.defineProperty('first', 'int32');
+Pair_i32.defineProperty('second', 'int32');
+
+Pair_Pair_i32.defineProperty('first', Pair_i32);
+Pair_Pair_i32.defineProperty('second', Pair_i32);
+
+Number_Body.defineProperty('tag', 'uint8');
+Number_Body.defineProperty('_0', 'int32');
+
+Token.defineProperty('tag', 'uint8');
+Token.defineProperty('number', Number_Body);
+
+const Color = Object.freeze({
+  Red: 0,
+  Green: 1,
+  Blue: 2,
+});
+
+const Level = Object.freeze({
+  Low: 0,
+  High: 1,
+});
+
+const Shape_Tag = Object.freeze({
+  Circle: 0,
+  Rect: 1,
+  Empty: 2,
+});
+
+const Token_Tag = Object.freeze({
+  Number: 0,
+  Eof: 1,
+});
+
+const Pairs = Pair_Pair_i32;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    area: ['float', [Shape, Pair_Pair_i32, Token]],
+    engine_new: ['pointer', []],
+    fatal: ['void', []],
+    old_api: ['void', ['int', 'uint8']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Circle_Body,
+  Rect_Body,
+  Shape,
+  Pair_i32,
+  Pair_Pair_i32,
+  Number_Body,
+  Token,
+  Color,
+  Level,
+  Shape_Tag,
+  Token_Tag,
+  Pairs,
+  load,
+};
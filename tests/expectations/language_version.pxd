@@ -0,0 +1,70 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum Color:
+    Red
+    Green
+    Blue
+
+  enum:
+    Low
+    High
+  ctypedef uint8_t Level
+
+  ctypedef struct Engine:
+    pass
+
+  enum:
+    Circle
+    Rect
+    Empty
+  ctypedef uint8_t Shape_Tag
+
+  ctypedef struct Circle_Body:
+    float _0
+
+  ctypedef struct Rect_Body:
+    float width
+    float height
+
+  ctypedef struct Shape:
+    Shape_Tag tag
+    Circle_Body circle
+    Rect_Body rect
+
+  ctypedef struct Pair_i32:
+    int32_t first
+    int32_t second
+
+  ctypedef struct Pair_Pair_i32:
+    Pair_i32 first
+    Pair_i32 second
+
+  ctypedef Pair_Pair_i32 Pairs
+
+  enum:
+    Number
+    Eof
+  ctypedef uint8_t Token_Tag
+
+  ctypedef struct Number_Body:
+    Token_Tag tag
+    int32_t _0
+
+  ctypedef union Token:
+    Token_Tag tag
+    Number_Body number
+
+  float area(Shape shape, Pairs pairs, Token token)
+
+  Engine *engine_new()
+
+  void fatal()
+
+  void old_api(Color color, Level level)
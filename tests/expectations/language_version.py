@@ -0,0 +1,105 @@
+import ctypes
+
+Color = ctypes.c_int
+Red = 0
+Green = 1
+Blue = 2
+
+Level = ctypes.c_uint8
+Low = 0
+High = 1
+
+class Engine(ctypes.Structure):
+  pass
+
+Shape_Tag = ctypes.c_uint8
+Circle = 0
+Rect = 1
+Empty = 2
+
+class Circle_Body(ctypes.Structure):
+  pass
+
+Circle_Body._fields_ = [
+  ("_0", ctypes.c_float),
+]
+
+class Rect_Body(ctypes.Structure):
+  pass
+
+Rect_Body._fields_ = [
+  ("width", ctypes.c_float),
+  ("height", ctypes.c_float),
+]
+
+class _Shape_Variants(ctypes.Union):
+  pass
+
+_Shape_Variants._fields_ = [
+  ("circle", Circle_Body),
+  ("rect", Rect_Body),
+]
+
+class Shape(ctypes.Structure):
+  pass
+
+Shape._anonymous_ = ("_variants",)
+Shape._fields_ = [
+  ("tag", Shape_Tag),
+  ("_variants", _Shape_Variants),
+]
+
+class Pair_i32(ctypes.Structure):
+  pass
+
+Pair_i32._fields_ = [
+  ("first", ctypes.c_int32),
+  ("second", ctypes.c_int32),
+]
+
+class Pair_Pair_i32(ctypes.Structure):
+  pass
+
+Pair_Pair_i32._fields_ = [
+  ("first", Pair_i32),
+  ("second", Pair_i32),
+]
+
+Pairs = Pair_Pair_i32
+
+Token_Tag = ctypes.c_uint8
+Number = 0
+Eof = 1
+
+class Number_Body(ctypes.Structure):
+  pass
+
+Number_Body._fields_ = [
+  ("tag", Token_Tag),
+  ("_0", ctypes.c_int32),
+]
+
+class Token(ctypes.Union):
+  pass
+
+Token._fields_ = [
+  ("tag", Token_Tag),
+  ("number", Number_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.area.argtypes = [Shape, Pairs, Token]
+  lib.area.restype = ctypes.c_float
+
+  lib.engine_new.argtypes = []
+  lib.engine_new.restype = ctypes.POINTER(Engine)
+
+  lib.fatal.argtypes = []
+  lib.fatal.restype = None
+
+  lib.old_api.argtypes = [Color, Level]
+  lib.old_api.restype = None
+
+  return lib
@@ -0,0 +1,72 @@
+const std = @import("std");
+
+pub const Color = enum(c_int) {
+  Red,
+  Green,
+  Blue,
+};
+
+pub const Level = enum(u8) {
+  Low,
+  High,
+};
+
+pub const Engine = opaque {};
+
+pub const Shape_Tag = enum(u8) {
+  Circle,
+  Rect,
+  Empty,
+};
+
+pub const Circle_Body = extern struct {
+  _0: f32,
+};
+
+pub const Rect_Body = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const Shape = extern struct {
+  tag: Shape_Tag,
+  body: extern union {
+    circle: Circle_Body,
+    rect: Rect_Body,
+  },
+};
+
+pub const Pair_i32 = extern struct {
+  first: i32,
+  second: i32,
+};
+
+pub const Pair_Pair_i32 = extern struct {
+  first: Pair_i32,
+  second: Pair_i32,
+};
+
+pub const Pairs = Pair_Pair_i32;
+
+pub const Token_Tag = enum(u8) {
+  Number,
+  Eof,
+};
+
+pub const Number_Body = extern struct {
+  tag: Token_Tag,
+  _0: i32,
+};
+
+pub const Token = extern union {
+  tag: Token_Tag,
+  number: Number_Body,
+};
+
+pub extern fn area(shape: Shape, pairs: Pairs, token: Token) f32;
+
+pub extern fn engine_new() ?*Engine;
+
+pub extern fn fatal() noreturn;
+
+pub extern fn old_api(color: Color, level: Level) void;
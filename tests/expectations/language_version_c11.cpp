@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cstddef>
+
+enum class Level : uint8_t {
+  Low,
+  High,
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+static_assert(sizeof(Point) == 8, "unexpected size of Point");
+static_assert(offsetof(Point, x) == 0, "unexpected offset of Point::x");
+static_assert(offsetof(Point, y) == 4, "unexpected offset of Point::y");
+
+extern "C" {
+
+void draw(Point point, Level level);
+
+[[noreturn]] void fatal();
+
+} // extern "C"
@@ -0,0 +1,30 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Level: {
+  readonly Low: 0;
+  readonly High: 1;
+};
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  draw(point: Point, level: number): void;
+
+  fatal(): void;
+}
+
+export declare function load(path: string): Library;
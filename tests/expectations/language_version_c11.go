@@ -0,0 +1,21 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Level = C.Level
+
+const (
+  Low Level = C.Low
+  High Level = C.High
+)
+
+type Point = C.Point
+
+func Draw(point Point, level Level) {
+  C.draw(point, level)
+}
+
+func Fatal() {
+  C.fatal()
+}
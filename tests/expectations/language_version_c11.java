@@ -0,0 +1,22 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Level {
+    byte Low = 0;
+    byte High = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  void draw(Point.ByValue point, byte level);
+
+  void fatal();
+}
@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+Point.defineProperty('x', 'int32');
+Point.defineProperty('y', 'int32');
+
+const Level = Object.freeze({
+  Low: 0,
+  High: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    draw: ['void', [Point, 'uint8']],
+    fatal: ['void', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Level,
+  load,
+};
@@ -0,0 +1,24 @@
+import ctypes
+
+Level = ctypes.c_uint8
+Low = 0
+High = 1
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.draw.argtypes = [Point, Level]
+  lib.draw.restype = None
+
+  lib.fatal.argtypes = []
+  lib.fatal.restype = None
+
+  return lib
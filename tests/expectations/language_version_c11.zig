@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Level = enum(u8) {
+  Low,
+  High,
+};
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub extern fn draw(point: Point, level: Level) void;
+
+pub extern fn fatal() noreturn;
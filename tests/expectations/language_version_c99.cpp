@@ -0,0 +1,70 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Color {
+  Red,
+  Green,
+  Blue,
+};
+
+enum class Level : uint8_t {
+  Low,
+  High,
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Rect,
+    Empty,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Rect_Body {
+    float width;
+    float height;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+};
+
+union Token {
+  enum class Tag : uint8_t {
+    Number,
+    Eof,
+  };
+
+  struct Number_Body {
+    Tag tag;
+    int32_t _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Number_Body number;
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+float area(Shape shape, Token token);
+
+void draw(Point point, Color color, Level level);
+
+[[noreturn]] void fatal();
+
+} // extern "C"
@@ -0,0 +1,86 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Color: {
+  readonly Red: 0;
+  readonly Green: 1;
+  readonly Blue: 2;
+};
+
+export declare const Level: {
+  readonly Low: 0;
+  readonly High: 1;
+};
+
+export declare const Shape_Tag: {
+  readonly Circle: 0;
+  readonly Rect: 1;
+  readonly Empty: 2;
+};
+
+export declare const Token_Tag: {
+  readonly Number: 0;
+  readonly Eof: 1;
+};
+
+export interface Circle_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Circle_Body: StructType<Circle_Body>;
+
+export interface Rect_Body {
+  width: number;
+  height: number;
+  ref(): Buffer;
+}
+export declare const Rect_Body: StructType<Rect_Body>;
+
+export interface Shape {
+  tag: number;
+  variants: {
+    circle: Circle_Body;
+    rect: Rect_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Shape: StructType<Shape>;
+
+export interface Number_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Number_Body: StructType<Number_Body>;
+
+export interface Token {
+  tag: number;
+  number: Number_Body;
+  ref(): Buffer;
+}
+export declare const Token: StructType<Token>;
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  area(shape: Shape, token: Token): number;
+
+  draw(point: Point, color: number, level: number): void;
+
+  fatal(): void;
+}
+
+export declare function load(path: string): Library;
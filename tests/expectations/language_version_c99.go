@@ -0,0 +1,58 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Color = C.Color
+
+const (
+  Red Color = C.Red
+  Green Color = C.Green
+  Blue Color = C.Blue
+)
+
+type Level = C.Level
+
+const (
+  Low Level = C.Low
+  High Level = C.High
+)
+
+type Shape_Tag = C.Shape_Tag
+
+const (
+  Circle Shape_Tag = C.Circle
+  Rect Shape_Tag = C.Rect
+  Empty Shape_Tag = C.Empty
+)
+
+type Circle_Body = C.Circle_Body
+
+type Rect_Body = C.Rect_Body
+
+type Shape = C.Shape
+
+type Token_Tag = C.Token_Tag
+
+const (
+  Number Token_Tag = C.Number
+  Eof Token_Tag = C.Eof
+)
+
+type Number_Body = C.Number_Body
+
+type Token = C.Token
+
+type Point = C.Point
+
+func Area(shape Shape, token Token) float32 {
+  return float32(C.area(shape, token))
+}
+
+func Draw(point Point, color Color, level Level) {
+  C.draw(point, color, level)
+}
+
+func Fatal() {
+  C.fatal()
+}
@@ -0,0 +1,98 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Color {
+    int Red = 0;
+    int Green = 1;
+    int Blue = 2;
+  }
+
+  interface Level {
+    byte Low = 0;
+    byte High = 1;
+  }
+
+  interface Shape_Tag {
+    byte Circle = 0;
+    byte Rect = 1;
+    byte Empty = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Circle_Body extends Structure {
+    public float _0;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"width", "height"})
+  class Rect_Body extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends Rect_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Rect_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Shape extends Structure {
+    public static class Variants extends Union {
+      public Circle_Body circle;
+      public Rect_Body rect;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  interface Token_Tag {
+    byte Number = 0;
+    byte Eof = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Number_Body extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends Number_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Number_Body implements Structure.ByValue {}
+  }
+
+  class Token extends Union {
+    public byte tag;
+    public Number_Body number;
+
+    public static class ByReference extends Token implements Structure.ByReference {}
+
+    public static class ByValue extends Token implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  float area(Shape.ByValue shape, Token.ByValue token);
+
+  void draw(Point.ByValue point, int color, byte level);
+
+  void fatal();
+}
@@ -0,0 +1,59 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum Color:
+    Red
+    Green
+    Blue
+
+  enum:
+    Low
+    High
+  ctypedef uint8_t Level
+
+  enum:
+    Circle
+    Rect
+    Empty
+  ctypedef uint8_t Shape_Tag
+
+  ctypedef struct Circle_Body:
+    float _0
+
+  ctypedef struct Rect_Body:
+    float width
+    float height
+
+  ctypedef struct Shape:
+    Shape_Tag tag
+    Circle_Body circle
+    Rect_Body rect
+
+  enum:
+    Number
+    Eof
+  ctypedef uint8_t Token_Tag
+
+  ctypedef struct Number_Body:
+    Token_Tag tag
+    int32_t _0
+
+  ctypedef union Token:
+    Token_Tag tag
+    Number_Body number
+
+  ctypedef struct Point:
+    int32_t x
+    int32_t y
+
+  float area(Shape shape, Token token)
+
+  void draw(Point point, Color color, Level level)
+
+  void fatal()
@@ -0,0 +1,93 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+enum Kind {
+  A,
+  B,
+};
+typedef uint8_t Kind;
+
+typedef struct {
+  uint8_t tag;
+  uint32_t len;
+  uint16_t flags;
+} Header;
+
+typedef struct {
+  Header header;
+  uint8_t payload[5];
+  uint16_t checksum;
+} Packet;
+
+typedef struct {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+typedef struct {
+  int64_t seconds;
+  bool valid;
+} Timestamp;
+
+typedef struct {
+  Kind kind;
+  Timestamp at;
+} Event;
+
+typedef struct {
+  long value;
+} Native;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(Header) == 12, "unexpected size of Header");
+CBINDGEN_STATIC_ASSERT(offsetof(Header, tag) == 0, "unexpected offset of Header::tag");
+CBINDGEN_STATIC_ASSERT(offsetof(Header, len) == 4, "unexpected offset of Header::len");
+CBINDGEN_STATIC_ASSERT(offsetof(Header, flags) == 8, "unexpected offset of Header::flags");
+
+CBINDGEN_STATIC_ASSERT(sizeof(Packet) == 20, "unexpected size of Packet");
+CBINDGEN_STATIC_ASSERT(offsetof(Packet, header) == 0, "unexpected offset of Packet::header");
+CBINDGEN_STATIC_ASSERT(offsetof(Packet, payload) == 12, "unexpected offset of Packet::payload");
+CBINDGEN_STATIC_ASSERT(offsetof(Packet, checksum) == 18, "unexpected offset of Packet::checksum");
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Buffer) == 16, "unexpected size of Buffer");
+CBINDGEN_STATIC_ASSERT(offsetof(Buffer, data) == 0, "unexpected offset of Buffer::data");
+CBINDGEN_STATIC_ASSERT(offsetof(Buffer, len) == 8, "unexpected offset of Buffer::len");
+#elif UINTPTR_MAX == UINT32_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Buffer) == 8, "unexpected size of Buffer");
+CBINDGEN_STATIC_ASSERT(offsetof(Buffer, data) == 0, "unexpected offset of Buffer::data");
+CBINDGEN_STATIC_ASSERT(offsetof(Buffer, len) == 4, "unexpected offset of Buffer::len");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Timestamp) == 16, "unexpected size of Timestamp");
+CBINDGEN_STATIC_ASSERT(offsetof(Timestamp, seconds) == 0, "unexpected offset of Timestamp::seconds");
+CBINDGEN_STATIC_ASSERT(offsetof(Timestamp, valid) == 8, "unexpected offset of Timestamp::valid");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Event) == 24, "unexpected size of Event");
+CBINDGEN_STATIC_ASSERT(offsetof(Event, kind) == 0, "unexpected offset of Event::kind");
+CBINDGEN_STATIC_ASSERT(offsetof(Event, at) == 8, "unexpected offset of Event::at");
+#endif
+
+#if UINTPTR_MAX == UINT32_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(Native) == 4, "unexpected size of Native");
+CBINDGEN_STATIC_ASSERT(offsetof(Native, value) == 0, "unexpected offset of Native::value");
+#endif
+
+void send(Packet packet, Buffer buffer, Event event, Native native);
@@ -0,0 +1,84 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cstddef>
+
+enum class Kind : uint8_t {
+  A,
+  B,
+};
+
+struct Header {
+  uint8_t tag;
+  uint32_t len;
+  uint16_t flags;
+};
+
+struct Packet {
+  Header header;
+  uint8_t payload[5];
+  uint16_t checksum;
+};
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+struct Timestamp {
+  int64_t seconds;
+  bool valid;
+};
+
+struct Event {
+  Kind kind;
+  Timestamp at;
+};
+
+struct Native {
+  long value;
+};
+
+static_assert(sizeof(Header) == 12, "unexpected size of Header");
+static_assert(offsetof(Header, tag) == 0, "unexpected offset of Header::tag");
+static_assert(offsetof(Header, len) == 4, "unexpected offset of Header::len");
+static_assert(offsetof(Header, flags) == 8, "unexpected offset of Header::flags");
+
+static_assert(sizeof(Packet) == 20, "unexpected size of Packet");
+static_assert(offsetof(Packet, header) == 0, "unexpected offset of Packet::header");
+static_assert(offsetof(Packet, payload) == 12, "unexpected offset of Packet::payload");
+static_assert(offsetof(Packet, checksum) == 18, "unexpected offset of Packet::checksum");
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Buffer) == 16, "unexpected size of Buffer");
+static_assert(offsetof(Buffer, data) == 0, "unexpected offset of Buffer::data");
+static_assert(offsetof(Buffer, len) == 8, "unexpected offset of Buffer::len");
+#elif UINTPTR_MAX == UINT32_MAX
+static_assert(sizeof(Buffer) == 8, "unexpected size of Buffer");
+static_assert(offsetof(Buffer, data) == 0, "unexpected offset of Buffer::data");
+static_assert(offsetof(Buffer, len) == 4, "unexpected offset of Buffer::len");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Timestamp) == 16, "unexpected size of Timestamp");
+static_assert(offsetof(Timestamp, seconds) == 0, "unexpected offset of Timestamp::seconds");
+static_assert(offsetof(Timestamp, valid) == 8, "unexpected offset of Timestamp::valid");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Event) == 24, "unexpected size of Event");
+static_assert(offsetof(Event, kind) == 0, "unexpected offset of Event::kind");
+static_assert(offsetof(Event, at) == 8, "unexpected offset of Event::at");
+#endif
+
+#if UINTPTR_MAX == UINT32_MAX
+static_assert(sizeof(Native) == 4, "unexpected size of Native");
+static_assert(offsetof(Native, value) == 0, "unexpected offset of Native::value");
+#endif
+
+extern "C" {
+
+void send(Packet packet, Buffer buffer, Event event, Native native);
+
+} // extern "C"
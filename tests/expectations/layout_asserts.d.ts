@@ -0,0 +1,64 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Kind: {
+  readonly A: 0;
+  readonly B: 1;
+};
+
+export interface Header {
+  tag: number;
+  len: number;
+  flags: number;
+  ref(): Buffer;
+}
+export declare const Header: StructType<Header>;
+
+export interface Packet {
+  header: Header;
+  payload: ArrayLike<number>;
+  checksum: number;
+  ref(): Buffer;
+}
+export declare const Packet: StructType<Packet>;
+
+export interface Buffer {
+  data: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Buffer: StructType<Buffer>;
+
+export interface Timestamp {
+  seconds: number | string;
+  valid: boolean;
+  ref(): Buffer;
+}
+export declare const Timestamp: StructType<Timestamp>;
+
+export interface Event {
+  kind: number;
+  at: Timestamp;
+  ref(): Buffer;
+}
+export declare const Event: StructType<Event>;
+
+export interface Native {
+  value: number | string;
+  ref(): Buffer;
+}
+export declare const Native: StructType<Native>;
+
+export interface Library {
+  send(packet: Packet, buffer: Buffer, event: Event, native: Native): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Kind = C.Kind
+
+const (
+  A Kind = C.A
+  B Kind = C.B
+)
+
+type Header = C.Header
+
+type Packet = C.Packet
+
+type Buffer = C.Buffer
+
+type Timestamp = C.Timestamp
+
+type Event = C.Event
+
+type Native = C.Native
+
+func Send(packet Packet, buffer Buffer, event Event, native Native) {
+  C.send(packet, buffer, event, native)
+}
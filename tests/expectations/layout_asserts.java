@@ -0,0 +1,81 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Kind {
+    byte A = 0;
+    byte B = 1;
+  }
+
+  @Structure.FieldOrder({"tag", "len", "flags"})
+  class Header extends Structure {
+    public byte tag;
+    public int len;
+    public short flags;
+
+    public static class ByReference extends Header implements Structure.ByReference {}
+
+    public static class ByValue extends Header implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"header", "payload", "checksum"})
+  class Packet extends Structure {
+    public Header header;
+    public byte[] payload = new byte[5];
+    public short checksum;
+
+    public static class ByReference extends Packet implements Structure.ByReference {}
+
+    public static class ByValue extends Packet implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"data", "len"})
+  class Buffer extends Structure {
+    public Pointer data;
+    public SizeT len;
+
+    public static class ByReference extends Buffer implements Structure.ByReference {}
+
+    public static class ByValue extends Buffer implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"seconds", "valid"})
+  class Timestamp extends Structure {
+    public long seconds;
+    public byte valid;
+
+    public static class ByReference extends Timestamp implements Structure.ByReference {}
+
+    public static class ByValue extends Timestamp implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"kind", "at"})
+  class Event extends Structure {
+    public byte kind;
+    public Timestamp at;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"value"})
+  class Native extends Structure {
+    public NativeLong value;
+
+    public static class ByReference extends Native implements Structure.ByReference {}
+
+    public static class ByValue extends Native implements Structure.ByValue {}
+  }
+
+  void send(Packet.ByValue packet, Buffer.ByValue buffer, Event.ByValue event, Native.ByValue native_);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
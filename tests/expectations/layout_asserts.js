@@ -0,0 +1,57 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Header = StructType();
+const Packet = StructType();
+const Buffer = StructType();
+const Timestamp = StructType();
+const Event = StructType();
+const Native = StructType();
+
+Header.defineProperty('tag', 'uint8');
+Header.defineProperty('len', 'uint32');
+Header.defineProperty('flags', 'uint16');
+
+Packet.defineProperty('header', Header);
+Packet.defineProperty('payload', ArrayType('uint8', 5));
+Packet.defineProperty('checksum', 'uint16');
+
+Buffer.defineProperty('data', ref.refType('uint8'));
+Buffer.defineProperty('len', 'size_t');
+
+Timestamp.defineProperty('seconds', 'int64');
+Timestamp.defineProperty('valid', 'bool');
+
+Event.defineProperty('kind', 'uint8');
+Event.defineProperty('at', Timestamp);
+
+Native.defineProperty('value', 'long');
+
+const Kind = Object.freeze({
+  A: 0,
+  B: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    send: ['void', [Packet, Buffer, Event, Native]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Header,
+  Packet,
+  Buffer,
+  Timestamp,
+  Event,
+  Native,
+  Kind,
+  load,
+};
@@ -0,0 +1,40 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    A
+    B
+  ctypedef uint8_t Kind
+
+  ctypedef struct Header:
+    uint8_t tag
+    uint32_t len
+    uint16_t flags
+
+  ctypedef struct Packet:
+    Header header
+    uint8_t payload[5]
+    uint16_t checksum
+
+  ctypedef struct Buffer:
+    uint8_t *data
+    uintptr_t len
+
+  ctypedef struct Timestamp:
+    int64_t seconds
+    bool valid
+
+  ctypedef struct Event:
+    Kind kind
+    Timestamp at
+
+  ctypedef struct Native:
+    long value
+
+  void send(Packet packet, Buffer buffer, Event event, Native native)
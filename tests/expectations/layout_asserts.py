@@ -0,0 +1,62 @@
+import ctypes
+
+Kind = ctypes.c_uint8
+A = 0
+B = 1
+
+class Header(ctypes.Structure):
+  pass
+
+Header._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("len", ctypes.c_uint32),
+  ("flags", ctypes.c_uint16),
+]
+
+class Packet(ctypes.Structure):
+  pass
+
+Packet._fields_ = [
+  ("header", Header),
+  ("payload", (ctypes.c_uint8 * 5)),
+  ("checksum", ctypes.c_uint16),
+]
+
+class Buffer(ctypes.Structure):
+  pass
+
+Buffer._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+class Timestamp(ctypes.Structure):
+  pass
+
+Timestamp._fields_ = [
+  ("seconds", ctypes.c_int64),
+  ("valid", ctypes.c_bool),
+]
+
+class Event(ctypes.Structure):
+  pass
+
+Event._fields_ = [
+  ("kind", Kind),
+  ("at", Timestamp),
+]
+
+class Native(ctypes.Structure):
+  pass
+
+Native._fields_ = [
+  ("value", ctypes.c_long),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.send.argtypes = [Packet, Buffer, Event, Native]
+  lib.send.restype = None
+
+  return lib
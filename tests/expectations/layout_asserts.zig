@@ -0,0 +1,39 @@
+const std = @import("std");
+
+pub const Kind = enum(u8) {
+  A,
+  B,
+};
+
+pub const Header = extern struct {
+  tag: u8,
+  len: u32,
+  flags: u16,
+};
+
+pub const Packet = extern struct {
+  header: Header,
+  payload: [5]u8,
+  checksum: u16,
+};
+
+pub const Buffer = extern struct {
+  data: [*c]u8,
+  len: usize,
+};
+
+pub const Timestamp = extern struct {
+  seconds: i64,
+  valid: bool,
+};
+
+pub const Event = extern struct {
+  kind: Kind,
+  at: Timestamp,
+};
+
+pub const Native = extern struct {
+  value: c_long,
+};
+
+pub extern fn send(packet: Packet, buffer: Buffer, event: Event, native: Native) void;
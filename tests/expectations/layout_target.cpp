@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cstddef>
+
+struct __attribute__((aligned(8))) Reader {
+  uint8_t _private[16];
+};
+
+struct Sample {
+  long count;
+  wchar_t symbol;
+  const uint8_t *data;
+};
+
+static_assert(sizeof(Sample) == 24, "unexpected size of Sample");
+static_assert(offsetof(Sample, count) == 0, "unexpected offset of Sample::count");
+static_assert(offsetof(Sample, symbol) == 8, "unexpected offset of Sample::symbol");
+static_assert(offsetof(Sample, data) == 16, "unexpected offset of Sample::data");
+
+static_assert(sizeof(Reader) == 16, "unexpected size of Reader");
+
+extern "C" {
+
+void reader_init(Reader *reader);
+
+void sample(Sample sample);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Sample {
+  count: number | string;
+  symbol: number;
+  data: Buffer;
+  ref(): Buffer;
+}
+export declare const Sample: StructType<Sample>;
+
+export interface Library {
+  reader_init(reader: Buffer | null): void;
+
+  sample(sample: Sample): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Reader = C.Reader
+
+type Sample = C.Sample
+
+func ReaderInit(reader *Reader) {
+  C.reader_init(reader)
+}
@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"count", "symbol", "data"})
+  class Sample extends Structure {
+    public NativeLong count;
+    public char symbol;
+    public Pointer data;
+
+    public static class ByReference extends Sample implements Structure.ByReference {}
+
+    public static class ByValue extends Sample implements Structure.ByValue {}
+  }
+
+  void reader_init(Pointer reader);
+
+  void sample(Sample.ByValue sample);
+}
@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Sample = StructType();
+
+Sample.defineProperty('count', 'long');
+Sample.defineProperty('symbol', 'uint32');
+Sample.defineProperty('data', ref.refType('uint8'));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    reader_init: ['void', ['pointer']],
+    sample: ['void', [Sample]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Sample,
+  load,
+};
@@ -0,0 +1,20 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Reader:
+    pass
+
+  ctypedef struct Sample:
+    long count
+    wchar_t symbol
+    const uint8_t *data
+
+  void reader_init(Reader *reader)
+
+  void sample(Sample sample)
@@ -0,0 +1,24 @@
+import ctypes
+
+class Reader(ctypes.Structure):
+  pass
+
+class Sample(ctypes.Structure):
+  pass
+
+Sample._fields_ = [
+  ("count", ctypes.c_long),
+  ("symbol", ctypes.c_wchar),
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.reader_init.argtypes = [ctypes.POINTER(Reader)]
+  lib.reader_init.restype = None
+
+  lib.sample.argtypes = [Sample]
+  lib.sample.restype = None
+
+  return lib
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Reader = opaque {};
+
+pub const Sample = extern struct {
+  count: c_long,
+  symbol: u32,
+  data: [*c]const u8,
+};
+
+pub extern fn reader_init(reader: ?*Reader) void;
+
+pub extern fn sample(sample_: Sample) void;
@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface A {
+  data: Buffer;
+  ref(): Buffer;
+}
+export declare const A: StructType<A>;
+
+export interface Library {
+  root(_a: A): void;
+}
+
+export declare function load(path: string): Library;
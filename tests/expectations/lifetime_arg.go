@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+func Root(_a A) {
+  C.root(_a)
+}
@@ -0,0 +1,14 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"data"})
+  class A extends Structure {
+    public Pointer data;
+
+    public static class ByReference extends A implements Structure.ByReference {}
+
+    public static class ByValue extends A implements Structure.ByValue {}
+  }
+
+  void root(A.ByValue _a);
+}
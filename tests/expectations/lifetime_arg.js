@@ -0,0 +1,24 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const A = StructType();
+
+A.defineProperty('data', ref.refType('int32'));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [A]],
+  });
+  return lib;
+}
+
+module.exports = {
+  A,
+  load,
+};
@@ -0,0 +1,16 @@
+import ctypes
+
+class A(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  data: *const i32,
+};
+
+pub extern fn root(_a: A) void;
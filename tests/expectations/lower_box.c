@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t value;
+} Node;
+
+typedef struct {
+  /**
+   * Owned `Box`.
+   */
+  Node *root;
+  /**
+   * Owned `Box`, or null for `None`.
+   */
+  Node *left;
+} Tree;
+
+/**
+ * `tree`: Owned `Box`, or null for `None`.
+ */
+void tree_free(Tree *tree);
+
+/**
+ * `root`: Owned `Box`.
+ * Return value: Owned `Box`.
+ */
+Tree *tree_new(Node *root);
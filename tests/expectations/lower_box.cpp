@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Node {
+  int32_t value;
+};
+
+struct Tree {
+  /// Owned `Box`.
+  Node *root;
+  /// Owned `Box`, or null for `None`.
+  Node *left;
+};
+
+extern "C" {
+
+/// `tree`: Owned `Box`, or null for `None`.
+void tree_free(Tree *tree);
+
+/// `root`: Owned `Box`.
+/// Return value: Owned `Box`.
+Tree *tree_new(Node *root);
+
+} // extern "C"
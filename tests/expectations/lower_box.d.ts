@@ -0,0 +1,38 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Node {
+  value: number;
+  ref(): Buffer;
+}
+export declare const Node: StructType<Node>;
+
+export interface Tree {
+  root: Buffer;
+  left: Buffer;
+  ref(): Buffer;
+}
+export declare const Tree: StructType<Tree>;
+
+export interface Library {
+  /**
+   * `tree`: Owned `Box`, or null for `None`.
+   */
+  tree_free(tree: Buffer | null): void;
+
+  /**
+   * `root`: Owned `Box`.
+   * Return value: Owned `Box`.
+   */
+  tree_new(root: Buffer | null): Buffer;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Node = C.Node
+
+type Tree = C.Tree
+
+// `tree`: Owned `Box`, or null for `None`.
+func TreeFree(tree *Tree) {
+  C.tree_free(tree)
+}
+
+// `root`: Owned `Box`.
+// Return value: Owned `Box`.
+func TreeNew(root *Node) *Tree {
+  return C.tree_new(root)
+}
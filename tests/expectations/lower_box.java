@@ -0,0 +1,39 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"value"})
+  class Node extends Structure {
+    public int value;
+
+    public static class ByReference extends Node implements Structure.ByReference {}
+
+    public static class ByValue extends Node implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"root", "left"})
+  class Tree extends Structure {
+    /**
+     * Owned `Box`.
+     */
+    public Node.ByReference root;
+    /**
+     * Owned `Box`, or null for `None`.
+     */
+    public Node.ByReference left;
+
+    public static class ByReference extends Tree implements Structure.ByReference {}
+
+    public static class ByValue extends Tree implements Structure.ByValue {}
+  }
+
+  /**
+   * `tree`: Owned `Box`, or null for `None`.
+   */
+  void tree_free(Tree.ByReference tree);
+
+  /**
+   * `root`: Owned `Box`.
+   * Return value: Owned `Box`.
+   */
+  Tree.ByReference tree_new(Node.ByReference root);
+}
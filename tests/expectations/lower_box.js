@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Node = StructType();
+const Tree = StructType();
+
+Node.defineProperty('value', 'int32');
+
+Tree.defineProperty('root', ref.refType(Node));
+Tree.defineProperty('left', ref.refType(Node));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    tree_free: ['void', [ref.refType(Tree)]],
+    tree_new: [ref.refType(Tree), [ref.refType(Node)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Node,
+  Tree,
+  load,
+};
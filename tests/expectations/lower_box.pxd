@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Node:
+    int32_t value
+
+  ctypedef struct Tree:
+    # Owned `Box`.
+    Node *root
+    # Owned `Box`, or null for `None`.
+    Node *left
+
+  # `tree`: Owned `Box`, or null for `None`.
+  void tree_free(Tree *tree)
+
+  # `root`: Owned `Box`.
+  # Return value: Owned `Box`.
+  Tree *tree_new(Node *root)
@@ -0,0 +1,32 @@
+import ctypes
+
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("value", ctypes.c_int32),
+]
+
+class Tree(ctypes.Structure):
+  pass
+
+Tree._fields_ = [
+  # Owned `Box`.
+  ("root", ctypes.POINTER(Node)),
+  # Owned `Box`, or null for `None`.
+  ("left", ctypes.POINTER(Node)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # `tree`: Owned `Box`, or null for `None`.
+  lib.tree_free.argtypes = [ctypes.POINTER(Tree)]
+  lib.tree_free.restype = None
+
+  # `root`: Owned `Box`.
+  # Return value: Owned `Box`.
+  lib.tree_new.argtypes = [ctypes.POINTER(Node)]
+  lib.tree_new.restype = ctypes.POINTER(Tree)
+
+  return lib
@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const Node = extern struct {
+  value: i32,
+};
+
+pub const Tree = extern struct {
+  /// Owned `Box`.
+  root: *Node,
+  /// Owned `Box`, or null for `None`.
+  left: [*c]Node,
+};
+
+/// `tree`: Owned `Box`, or null for `None`.
+pub extern fn tree_free(tree: [*c]Tree) void;
+
+/// `root`: Owned `Box`.
+/// Return value: Owned `Box`.
+pub extern fn tree_new(root: *Node) *Tree;
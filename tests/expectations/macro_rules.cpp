@@ -0,0 +1,39 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const int32_t DEFAULT_WIDTH = 640;
+
+static const int32_t MAX_WINDOWS = 16;
+
+#if defined(WITH_SURFACES)
+struct Overlay {
+  uint32_t id;
+};
+#endif
+
+struct Surface {
+  uint32_t id;
+};
+
+struct Window {
+  uint32_t id;
+};
+
+extern "C" {
+
+#if defined(WITH_SURFACES)
+Overlay overlay_new(uint32_t id);
+#endif
+
+Surface surface_new(uint32_t id);
+
+Window window_new(uint32_t id);
+
+float window_scale(const Window *this_);
+
+/// The width of the window.
+uint32_t window_width(const Window *this_);
+
+} // extern "C"
@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const DEFAULT_WIDTH: number;
+
+export declare const MAX_WINDOWS: number;
+
+// cfg(feature = "surfaces")
+export interface Overlay {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Overlay: StructType<Overlay>;
+
+export interface Surface {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Surface: StructType<Surface>;
+
+export interface Window {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Window: StructType<Window>;
+
+export interface Library {
+  // cfg(feature = "surfaces")
+  overlay_new(id: number): Overlay;
+
+  surface_new(id: number): Surface;
+
+  window_new(id: number): Window;
+
+  window_scale(this_: Buffer | null): number;
+
+  /**
+   * The width of the window.
+   */
+  window_width(this_: Buffer | null): number;
+}
+
+export declare function load(path: string): Library;
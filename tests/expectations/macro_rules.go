@@ -0,0 +1,37 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(feature = "surfaces")
+type Overlay = C.Overlay
+
+type Surface = C.Surface
+
+type Window = C.Window
+
+const DEFAULT_WIDTH = C.DEFAULT_WIDTH
+
+const MAX_WINDOWS = C.MAX_WINDOWS
+
+// cfg(feature = "surfaces")
+func OverlayNew(id uint32) Overlay {
+  return C.overlay_new(C.uint32_t(id))
+}
+
+func SurfaceNew(id uint32) Surface {
+  return C.surface_new(C.uint32_t(id))
+}
+
+func WindowNew(id uint32) Window {
+  return C.window_new(C.uint32_t(id))
+}
+
+func WindowScale(this_ *Window) float32 {
+  return float32(C.window_scale(this_))
+}
+
+// The width of the window.
+func WindowWidth(this_ *Window) uint32 {
+  return uint32(C.window_width(this_))
+}
@@ -0,0 +1,49 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int DEFAULT_WIDTH = 640;
+
+  int MAX_WINDOWS = 16;
+
+  // cfg(feature = "surfaces")
+  @Structure.FieldOrder({"id"})
+  class Overlay extends Structure {
+    public int id;
+
+    public static class ByReference extends Overlay implements Structure.ByReference {}
+
+    public static class ByValue extends Overlay implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id"})
+  class Surface extends Structure {
+    public int id;
+
+    public static class ByReference extends Surface implements Structure.ByReference {}
+
+    public static class ByValue extends Surface implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id"})
+  class Window extends Structure {
+    public int id;
+
+    public static class ByReference extends Window implements Structure.ByReference {}
+
+    public static class ByValue extends Window implements Structure.ByValue {}
+  }
+
+  // cfg(feature = "surfaces")
+  Overlay.ByValue overlay_new(int id);
+
+  Surface.ByValue surface_new(int id);
+
+  Window.ByValue window_new(int id);
+
+  float window_scale(Window.ByReference this_);
+
+  /**
+   * The width of the window.
+   */
+  int window_width(Window.ByReference this_);
+}
@@ -0,0 +1,44 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Overlay = StructType();
+const Surface = StructType();
+const Window = StructType();
+
+const DEFAULT_WIDTH = 640;
+
+const MAX_WINDOWS = 16;
+
+// cfg(feature = "surfaces")
+Overlay.defineProperty('id', 'uint32');
+
+Surface.defineProperty('id', 'uint32');
+
+Window.defineProperty('id', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    // cfg(feature = "surfaces")
+    overlay_new: [Overlay, ['uint32']],
+    surface_new: [Surface, ['uint32']],
+    window_new: [Window, ['uint32']],
+    window_scale: ['float', [ref.refType(Window)]],
+    window_width: ['uint32', [ref.refType(Window)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Overlay,
+  Surface,
+  Window,
+  DEFAULT_WIDTH,
+  MAX_WINDOWS,
+  load,
+};
@@ -0,0 +1,48 @@
+/** The signatures of the functions the module exports. */
+export const functions = {
+  // cfg(feature = "surfaces")
+  overlay_new: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+  surface_new: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+  window_new: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+  window_scale: {
+    params: ["i32"],
+    results: ["f32"],
+  },
+  /**
+   * The width of the window.
+   */
+  window_width: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+};
+
+/** The signatures of the functions the module imports. */
+export const imports = {};
+
+/** The statics the module exports, as globals holding their address. */
+export const globals = [];
+
+/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */
+export function view(memory, type, ptr, len) {
+  return new type(memory.buffer, ptr, len);
+}
+
+/**
+ * Returns views of the slices that `args`, the arguments of a call to a
+ * function with `signature`, point to in `memory`.
+ */
+export function slices(memory, signature, args) {
+  return (signature.slices || []).map((slice) =>
+    view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),
+  );
+}
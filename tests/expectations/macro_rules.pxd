@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const int32_t DEFAULT_WIDTH
+
+  const int32_t MAX_WINDOWS
+
+  # cfg(feature = "surfaces")
+  ctypedef struct Overlay:
+    uint32_t id
+
+  ctypedef struct Surface:
+    uint32_t id
+
+  ctypedef struct Window:
+    uint32_t id
+
+  # cfg(feature = "surfaces")
+  Overlay overlay_new(uint32_t id)
+
+  Surface surface_new(uint32_t id)
+
+  Window window_new(uint32_t id)
+
+  float window_scale(const Window *this_)
+
+  # The width of the window.
+  uint32_t window_width(const Window *this_)
@@ -0,0 +1,49 @@
+import ctypes
+
+DEFAULT_WIDTH = 640
+
+MAX_WINDOWS = 16
+
+# cfg(feature = "surfaces")
+class Overlay(ctypes.Structure):
+  pass
+
+Overlay._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+class Surface(ctypes.Structure):
+  pass
+
+Surface._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+class Window(ctypes.Structure):
+  pass
+
+Window._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # cfg(feature = "surfaces")
+  lib.overlay_new.argtypes = [ctypes.c_uint32]
+  lib.overlay_new.restype = Overlay
+
+  lib.surface_new.argtypes = [ctypes.c_uint32]
+  lib.surface_new.restype = Surface
+
+  lib.window_new.argtypes = [ctypes.c_uint32]
+  lib.window_new.restype = Window
+
+  lib.window_scale.argtypes = [ctypes.POINTER(Window)]
+  lib.window_scale.restype = ctypes.c_float
+
+  # The width of the window.
+  lib.window_width.argtypes = [ctypes.POINTER(Window)]
+  lib.window_width.restype = ctypes.c_uint32
+
+  return lib
@@ -0,0 +1,30 @@
+const std = @import("std");
+
+pub const DEFAULT_WIDTH: i32 = 640;
+
+pub const MAX_WINDOWS: i32 = 16;
+
+// cfg(feature = "surfaces")
+pub const Overlay = extern struct {
+  id: u32,
+};
+
+pub const Surface = extern struct {
+  id: u32,
+};
+
+pub const Window = extern struct {
+  id: u32,
+};
+
+// cfg(feature = "surfaces")
+pub extern fn overlay_new(id: u32) Overlay;
+
+pub extern fn surface_new(id: u32) Surface;
+
+pub extern fn window_new(id: u32) Window;
+
+pub extern fn window_scale(this_: [*c]const Window) f32;
+
+/// The width of the window.
+pub extern fn window_width(this_: [*c]const Window) u32;
@@ -0,0 +1,54 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Counter {
+  Counter() = delete;
+  Counter(const Counter&) = delete;
+  Counter& operator=(const Counter&) = delete;
+  ~Counter() = delete;
+
+  void add(uint32_t amount);
+  /// Returns the current count.
+  uint32_t get() const;
+};
+
+struct Point {
+  float x;
+  float y;
+
+  float length() const;
+  Point midpoint(const Point *other) const;
+};
+
+extern "C" {
+
+void counter_add(Counter *self, uint32_t amount);
+
+/// Returns the current count.
+uint32_t counter_get(const Counter *self);
+
+Counter *counter_new();
+
+float point_length(const Point *self);
+
+Point point_midpoint(const Point *self, const Point *other);
+
+} // extern "C"
+
+inline void Counter::add(uint32_t amount) {
+  counter_add(this, amount);
+}
+
+inline uint32_t Counter::get() const {
+  return counter_get(this);
+}
+
+inline float Point::length() const {
+  return point_length(this);
+}
+
+inline Point Point::midpoint(const Point *other) const {
+  return point_midpoint(this, other);
+}
@@ -0,0 +1,34 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  counter_add(self: Buffer | null, amount: number): void;
+
+  /**
+   * Returns the current count.
+   */
+  counter_get(self: Buffer | null): number;
+
+  counter_new(): Buffer;
+
+  point_length(self: Buffer | null): number;
+
+  point_midpoint(self: Buffer | null, other: Buffer | null): Point;
+}
+
+export declare function load(path: string): Library;
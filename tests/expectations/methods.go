@@ -0,0 +1,29 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Counter = C.Counter
+
+type Point = C.Point
+
+func CounterAdd(self *Counter, amount uint32) {
+  C.counter_add(self, C.uint32_t(amount))
+}
+
+// Returns the current count.
+func CounterGet(self *Counter) uint32 {
+  return uint32(C.counter_get(self))
+}
+
+func CounterNew() *Counter {
+  return C.counter_new()
+}
+
+func PointLength(self *Point) float32 {
+  return float32(C.point_length(self))
+}
+
+func PointMidpoint(self *Point, other *Point) Point {
+  return C.point_midpoint(self, other)
+}
@@ -0,0 +1,26 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  void counter_add(Pointer self, int amount);
+
+  /**
+   * Returns the current count.
+   */
+  int counter_get(Pointer self);
+
+  Pointer counter_new();
+
+  float point_length(Point.ByReference self);
+
+  Point.ByValue point_midpoint(Point.ByReference self, Point.ByReference other);
+}
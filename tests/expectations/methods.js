@@ -0,0 +1,29 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    counter_add: ['void', ['pointer', 'uint32']],
+    counter_get: ['uint32', ['pointer']],
+    counter_new: ['pointer', []],
+    point_length: ['float', [ref.refType(Point)]],
+    point_midpoint: [Point, [ref.refType(Point), ref.refType(Point)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  load,
+};
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Counter:
+    pass
+
+  ctypedef struct Point:
+    float x
+    float y
+
+  void counter_add(Counter *self, uint32_t amount)
+
+  # Returns the current count.
+  uint32_t counter_get(const Counter *self)
+
+  Counter *counter_new()
+
+  float point_length(const Point *self)
+
+  Point point_midpoint(const Point *self, const Point *other)
@@ -0,0 +1,33 @@
+import ctypes
+
+class Counter(ctypes.Structure):
+  pass
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.counter_add.argtypes = [ctypes.POINTER(Counter), ctypes.c_uint32]
+  lib.counter_add.restype = None
+
+  # Returns the current count.
+  lib.counter_get.argtypes = [ctypes.POINTER(Counter)]
+  lib.counter_get.restype = ctypes.c_uint32
+
+  lib.counter_new.argtypes = []
+  lib.counter_new.restype = ctypes.POINTER(Counter)
+
+  lib.point_length.argtypes = [ctypes.POINTER(Point)]
+  lib.point_length.restype = ctypes.c_float
+
+  lib.point_midpoint.argtypes = [ctypes.POINTER(Point), ctypes.POINTER(Point)]
+  lib.point_midpoint.restype = Point
+
+  return lib
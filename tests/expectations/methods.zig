@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const Counter = opaque {};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn counter_add(self: *Counter, amount: u32) void;
+
+/// Returns the current count.
+pub extern fn counter_get(self: *const Counter) u32;
+
+pub extern fn counter_new() ?*Counter;
+
+pub extern fn point_length(self: *const Point) f32;
+
+pub extern fn point_midpoint(self: *const Point, other: *const Point) Point;
@@ -0,0 +1,38 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(all(all(feature = "foobar"), bar))
+export declare const BAR: number;
+
+// cfg(foo)
+export declare const FOO: number;
+
+// cfg(all(all(feature = "foobar"), bar))
+export interface Bar {
+  ref(): Buffer;
+}
+export declare const Bar: StructType<Bar>;
+
+// cfg(foo)
+export interface Foo {
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  // cfg(all(all(feature = "foobar"), bar))
+  bar(bar: Buffer | null): void;
+
+  // cfg(foo)
+  foo(foo: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(all(all(feature = "foobar"), bar))
+type Bar = C.Bar
+
+// cfg(foo)
+type Foo = C.Foo
+
+// cfg(all(all(feature = "foobar"), bar))
+const BAR = C.BAR
+
+// cfg(foo)
+const FOO = C.FOO
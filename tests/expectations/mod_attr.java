@@ -0,0 +1,33 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(all(all(feature = "foobar"), bar))
+  int BAR = 2;
+
+  // cfg(foo)
+  int FOO = 1;
+
+  // cfg(all(all(feature = "foobar"), bar))
+  @Structure.FieldOrder({})
+  class Bar extends Structure {
+
+    public static class ByReference extends Bar implements Structure.ByReference {}
+
+    public static class ByValue extends Bar implements Structure.ByValue {}
+  }
+
+  // cfg(foo)
+  @Structure.FieldOrder({})
+  class Foo extends Structure {
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  // cfg(all(all(feature = "foobar"), bar))
+  void bar(Bar.ByReference bar);
+
+  // cfg(foo)
+  void foo(Foo.ByReference foo);
+}
@@ -0,0 +1,39 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Bar = StructType();
+const Foo = StructType();
+
+// cfg(all(all(feature = "foobar"), bar))
+const BAR = 2;
+
+// cfg(foo)
+const FOO = 1;
+
+// cfg(all(all(feature = "foobar"), bar))
+
+// cfg(foo)
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    // cfg(all(all(feature = "foobar"), bar))
+    bar: ['void', [ref.refType(Bar)]],
+    // cfg(foo)
+    foo: ['void', [ref.refType(Foo)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Bar,
+  Foo,
+  BAR,
+  FOO,
+  load,
+};
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(all(all(feature = "foobar"), bar))
+  const int32_t BAR
+
+  # cfg(foo)
+  const int32_t FOO
+
+  # cfg(all(all(feature = "foobar"), bar))
+  ctypedef struct Bar:
+    pass
+
+  # cfg(foo)
+  ctypedef struct Foo:
+    pass
+
+  # cfg(all(all(feature = "foobar"), bar))
+  void bar(const Bar *bar)
+
+  # cfg(foo)
+  void foo(const Foo *foo)
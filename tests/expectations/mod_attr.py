@@ -0,0 +1,32 @@
+import ctypes
+
+# cfg(all(all(feature = "foobar"), bar))
+BAR = 2
+
+# cfg(foo)
+FOO = 1
+
+# cfg(all(all(feature = "foobar"), bar))
+class Bar(ctypes.Structure):
+  pass
+
+Bar._fields_ = []
+
+# cfg(foo)
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # cfg(all(all(feature = "foobar"), bar))
+  lib.bar.argtypes = [ctypes.POINTER(Bar)]
+  lib.bar.restype = None
+
+  # cfg(foo)
+  lib.foo.argtypes = [ctypes.POINTER(Foo)]
+  lib.foo.restype = None
+
+  return lib
@@ -0,0 +1,23 @@
+const std = @import("std");
+
+// cfg(all(all(feature = "foobar"), bar))
+pub const BAR: i32 = 2;
+
+// cfg(foo)
+pub const FOO: i32 = 1;
+
+// cfg(all(all(feature = "foobar"), bar))
+pub const Bar = extern struct {
+
+};
+
+// cfg(foo)
+pub const Foo = extern struct {
+
+};
+
+// cfg(all(all(feature = "foobar"), bar))
+pub extern fn bar(bar_: *const Bar) void;
+
+// cfg(foo)
+pub extern fn foo(foo_: *const Foo) void;
@@ -0,0 +1,24 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const EXPORT_ME_TOO: number;
+
+export interface ExportMe {
+  val: number | string;
+  ref(): Buffer;
+}
+export declare const ExportMe: StructType<ExportMe>;
+
+export interface Library {
+  export_me(val: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,8 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type ExportMe = C.ExportMe
+
+const EXPORT_ME_TOO = C.EXPORT_ME_TOO
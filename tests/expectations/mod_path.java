@@ -0,0 +1,16 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  byte EXPORT_ME_TOO = 42;
+
+  @Structure.FieldOrder({"val"})
+  class ExportMe extends Structure {
+    public long val;
+
+    public static class ByReference extends ExportMe implements Structure.ByReference {}
+
+    public static class ByValue extends ExportMe implements Structure.ByValue {}
+  }
+
+  void export_me(ExportMe.ByReference val);
+}
@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const ExportMe = StructType();
+
+const EXPORT_ME_TOO = 42;
+
+ExportMe.defineProperty('val', 'uint64');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    export_me: ['void', [ref.refType(ExportMe)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  ExportMe,
+  EXPORT_ME_TOO,
+  load,
+};
@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+enum Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Line,
+  Polygon,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape;
+#endif // __cplusplus
+
+typedef struct Canvas Canvas;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern Shape DEFAULT_SHAPE;
+
+void canvas_add(Canvas *canvas, Point point, Shape shape);
+
+Canvas *canvas_new(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,40 @@
+module;
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+export module mylib;
+
+export {
+
+namespace mylib {
+
+inline const uint32_t MAX_POINTS = 16;
+
+enum class Shape : uint8_t {
+  Line,
+  Polygon,
+};
+
+struct Canvas;
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+extern Shape DEFAULT_SHAPE;
+
+void canvas_add(Canvas *canvas, Point point, Shape shape);
+
+Canvas *canvas_new();
+
+} // extern "C"
+
+} // namespace mylib
+
+} // export
@@ -0,0 +1,34 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MAX_POINTS: number;
+
+export declare const Shape: {
+  readonly Line: 0;
+  readonly Polygon: 1;
+};
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  DEFAULT_SHAPE: Buffer;
+
+  canvas_add(canvas: Buffer | null, point: Point, shape: number): void;
+
+  canvas_new(): Buffer;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,29 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Shape = C.Shape
+
+const (
+  Line Shape = C.Line
+  Polygon Shape = C.Polygon
+)
+
+type Canvas = C.Canvas
+
+type Point = C.Point
+
+const MAX_POINTS = C.MAX_POINTS
+
+func DefaultShape() Shape {
+  return C.DEFAULT_SHAPE
+}
+
+func CanvasAdd(canvas *Canvas, point Point, shape Shape) {
+  C.canvas_add(canvas, point, shape)
+}
+
+func CanvasNew() *Canvas {
+  return C.canvas_new()
+}
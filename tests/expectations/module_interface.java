@@ -0,0 +1,24 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int MAX_POINTS = 16;
+
+  interface Shape {
+    byte Line = 0;
+    byte Polygon = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  void canvas_add(Pointer canvas, Point.ByValue point, byte shape);
+
+  Pointer canvas_new();
+}
@@ -0,0 +1,37 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+const MAX_POINTS = 16;
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+const Shape = Object.freeze({
+  Line: 0,
+  Polygon: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    canvas_add: ['void', ['pointer', Point, 'uint8']],
+    canvas_new: ['pointer', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.DEFAULT_SHAPE = dylib.get('DEFAULT_SHAPE');
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Shape,
+  MAX_POINTS,
+  load,
+};
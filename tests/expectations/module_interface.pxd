@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t MAX_POINTS
+
+  enum:
+    Line
+    Polygon
+  ctypedef uint8_t Shape
+
+  ctypedef struct Canvas:
+    pass
+
+  ctypedef struct Point:
+    float x
+    float y
+
+  Shape DEFAULT_SHAPE
+
+  void canvas_add(Canvas *canvas, Point point, Shape shape)
+
+  Canvas *canvas_new()
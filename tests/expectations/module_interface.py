@@ -0,0 +1,31 @@
+import ctypes
+
+MAX_POINTS = 16
+
+Shape = ctypes.c_uint8
+Line = 0
+Polygon = 1
+
+class Canvas(ctypes.Structure):
+  pass
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.DEFAULT_SHAPE = Shape.in_dll(lib, "DEFAULT_SHAPE")
+
+  lib.canvas_add.argtypes = [ctypes.POINTER(Canvas), Point, Shape]
+  lib.canvas_add.restype = None
+
+  lib.canvas_new.argtypes = []
+  lib.canvas_new.restype = ctypes.POINTER(Canvas)
+
+  return lib
@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const MAX_POINTS: u32 = 16;
+
+pub const Shape = enum(u8) {
+  Line,
+  Polygon,
+};
+
+pub const Canvas = opaque {};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern var DEFAULT_SHAPE: Shape;
+
+pub extern fn canvas_add(canvas: ?*Canvas, point: Point, shape: Shape) void;
+
+pub extern fn canvas_new() ?*Canvas;
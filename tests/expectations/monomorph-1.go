@@ -0,0 +1,26 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Bar_Bar_f32 = C.Bar_Bar_f32
+
+type Bar_Foo_f32 = C.Bar_Foo_f32
+
+type Bar_f32 = C.Bar_f32
+
+type Foo_i32 = C.Foo_i32
+
+type Foo_f32 = C.Foo_f32
+
+type Foo_Bar_f32 = C.Foo_Bar_f32
+
+type Tuple_Foo_f32_____f32 = C.Tuple_Foo_f32_____f32
+
+type Tuple_f32__f32 = C.Tuple_f32__f32
+
+type Indirection_f32 = C.Indirection_f32
+
+func Root(a Foo_i32, b Foo_f32, c Bar_f32, d Foo_Bar_f32, e Bar_Foo_f32, f Bar_Bar_f32, g Tuple_Foo_f32_____f32, h Indirection_f32) {
+  C.root(a, b, c, d, e, f, g, h)
+}
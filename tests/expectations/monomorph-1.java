@@ -0,0 +1,52 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"data"})
+  class Foo_i32 extends Structure {
+    public Pointer data;
+
+    public static class ByReference extends Foo_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"data"})
+  class Foo_f32 extends Structure {
+    public Pointer data;
+
+    public static class ByReference extends Foo_f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_f32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"data"})
+  class Foo_Bar_f32 extends Structure {
+    public Pointer data;
+
+    public static class ByReference extends Foo_Bar_f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_Bar_f32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a", "b"})
+  class Tuple_Foo_f32_____f32 extends Structure {
+    public Foo_f32.ByReference a;
+    public Pointer b;
+
+    public static class ByReference extends Tuple_Foo_f32_____f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_Foo_f32_____f32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a", "b"})
+  class Tuple_f32__f32 extends Structure {
+    public Pointer a;
+    public Pointer b;
+
+    public static class ByReference extends Tuple_f32__f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_f32__f32 implements Structure.ByValue {}
+  }
+
+  void root(Foo_i32.ByValue a, Foo_f32.ByValue b, Pointer c, Foo_Bar_f32.ByValue d, Pointer e, Pointer f, Tuple_Foo_f32_____f32.ByValue g, Tuple_f32__f32.ByValue h);
+}
@@ -0,0 +1,45 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo_i32 = StructType();
+const Foo_f32 = StructType();
+const Foo_Bar_f32 = StructType();
+const Tuple_Foo_f32_____f32 = StructType();
+const Tuple_f32__f32 = StructType();
+
+Foo_i32.defineProperty('data', ref.refType('int32'));
+
+Foo_f32.defineProperty('data', ref.refType('float'));
+
+Foo_Bar_f32.defineProperty('data', 'pointer');
+
+Tuple_Foo_f32_____f32.defineProperty('a', ref.refType(Foo_f32));
+Tuple_Foo_f32_____f32.defineProperty('b', ref.refType('float'));
+
+Tuple_f32__f32.defineProperty('a', ref.refType('float'));
+Tuple_f32__f32.defineProperty('b', ref.refType('float'));
+
+const Indirection_f32 = Tuple_f32__f32;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo_i32, Foo_f32, 'void', Foo_Bar_f32, 'void', 'void', Tuple_Foo_f32_____f32, Tuple_f32__f32]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo_i32,
+  Foo_f32,
+  Foo_Bar_f32,
+  Tuple_Foo_f32_____f32,
+  Tuple_f32__f32,
+  Indirection_f32,
+  load,
+};
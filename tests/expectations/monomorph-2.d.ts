@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface List_B {
+  members: Buffer;
+  count: number | string;
+  ref(): Buffer;
+}
+export declare const List_B: StructType<List_B>;
+
+export interface List_A {
+  members: Buffer;
+  count: number | string;
+  ref(): Buffer;
+}
+export declare const List_A: StructType<List_A>;
+
+export interface Library {
+  bar(b: List_B): void;
+
+  foo(a: List_A): void;
+}
+
+export declare function load(path: string): Library;
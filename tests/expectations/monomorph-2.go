@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+type B = C.B
+
+type List_B = C.List_B
+
+type List_A = C.List_A
+
+func Bar(b List_B) {
+  C.bar(b)
+}
+
+func Foo(a List_A) {
+  C.foo(a)
+}
@@ -0,0 +1,37 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"members", "count"})
+  class List_B extends Structure {
+    public Pointer members;
+    public SizeT count;
+
+    public static class ByReference extends List_B implements Structure.ByReference {}
+
+    public static class ByValue extends List_B implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"members", "count"})
+  class List_A extends Structure {
+    public Pointer members;
+    public SizeT count;
+
+    public static class ByReference extends List_A implements Structure.ByReference {}
+
+    public static class ByValue extends List_A implements Structure.ByValue {}
+  }
+
+  void bar(List_B.ByValue b);
+
+  void foo(List_A.ByValue a);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
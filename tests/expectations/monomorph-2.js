@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const List_B = StructType();
+const List_A = StructType();
+
+List_B.defineProperty('members', 'pointer');
+List_B.defineProperty('count', 'size_t');
+
+List_A.defineProperty('members', 'pointer');
+List_A.defineProperty('count', 'size_t');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    bar: ['void', [List_B]],
+    foo: ['void', [List_A]],
+  });
+  return lib;
+}
+
+module.exports = {
+  List_B,
+  List_A,
+  load,
+};
@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const A = opaque {};
+
+pub const B = opaque {};
+
+pub const List_B = extern struct {
+  members: ?*B,
+  count: usize,
+};
+
+pub const List_A = extern struct {
+  members: ?*A,
+  count: usize,
+};
+
+pub extern fn bar(b: List_B) void;
+
+pub extern fn foo(a: List_A) void;
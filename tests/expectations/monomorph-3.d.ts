@@ -0,0 +1,51 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo_i32 {
+  data: Buffer;
+  ref(): Buffer;
+}
+export declare const Foo_i32: StructType<Foo_i32>;
+
+export interface Foo_f32 {
+  data: Buffer;
+  ref(): Buffer;
+}
+export declare const Foo_f32: StructType<Foo_f32>;
+
+export interface Foo_Bar_f32 {
+  data: Buffer;
+  ref(): Buffer;
+}
+export declare const Foo_Bar_f32: StructType<Foo_Bar_f32>;
+
+export interface Tuple_Foo_f32_____f32 {
+  a: Buffer;
+  b: Buffer;
+  ref(): Buffer;
+}
+export declare const Tuple_Foo_f32_____f32: StructType<Tuple_Foo_f32_____f32>;
+
+export interface Tuple_f32__f32 {
+  a: Buffer;
+  b: Buffer;
+  ref(): Buffer;
+}
+export declare const Tuple_f32__f32: StructType<Tuple_f32__f32>;
+
+export type Indirection_f32 = Tuple_f32__f32;
+export declare const Indirection_f32: RefType;
+
+export interface Library {
+  root(a: Foo_i32, b: Foo_f32, c: never, d: Foo_Bar_f32, e: never, f: never, g: Tuple_Foo_f32_____f32, h: Tuple_f32__f32): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (defined(_WIN64) || defined(__x86_64__))
+#define WORD_BITS 64
+#endif
+
+#if defined(__i386__)
+#define WORD_BITS 32
+#endif
+
+#if defined(_WIN64)
+typedef struct {
+  uint8_t *raw;
+} Handle;
+#endif
+
+#if (defined(__x86_64__) || defined(__i386__))
+typedef struct {
+  int32_t fd;
+} Handle;
+#endif
+
+#if ((defined(__x86_64__) || defined(__i386__)) && defined(WITH_LOGGING))
+typedef struct {
+  uint8_t level;
+} Logger;
+#endif
+
+void open_handle(Handle *handle);
+
+#if ((defined(__x86_64__) || defined(__i386__)) && defined(WITH_LOGGING))
+void set_logger(const Logger *logger);
+#endif
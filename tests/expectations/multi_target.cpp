@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#if (defined(_WIN64) || defined(__x86_64__))
+static const uint32_t WORD_BITS = 64;
+#endif
+
+#if defined(__i386__)
+static const uint32_t WORD_BITS = 32;
+#endif
+
+#if defined(_WIN64)
+struct Handle {
+  uint8_t *raw;
+};
+#endif
+
+#if (defined(__x86_64__) || defined(__i386__))
+struct Handle {
+  int32_t fd;
+};
+#endif
+
+#if ((defined(__x86_64__) || defined(__i386__)) && defined(WITH_LOGGING))
+struct Logger {
+  uint8_t level;
+};
+#endif
+
+extern "C" {
+
+void open_handle(Handle *handle);
+
+#if ((defined(__x86_64__) || defined(__i386__)) && defined(WITH_LOGGING))
+void set_logger(const Logger *logger);
+#endif
+
+} // extern "C"
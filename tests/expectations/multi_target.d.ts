@@ -0,0 +1,36 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+export declare const WORD_BITS: number;
+
+// cfg(target = "x86_64-pc-windows-msvc")
+export interface Handle {
+  raw: Buffer;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+export interface Logger {
+  level: number;
+  ref(): Buffer;
+}
+export declare const Logger: StructType<Logger>;
+
+export interface Library {
+  open_handle(handle: Buffer | null): void;
+
+  // cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  set_logger(logger: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
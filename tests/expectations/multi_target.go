@@ -0,0 +1,28 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(target = "x86_64-pc-windows-msvc")
+type Handle = C.Handle
+
+// cfg(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"))
+type Handle = C.Handle
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+type Logger = C.Logger
+
+// cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+const WORD_BITS = C.WORD_BITS
+
+// cfg(target = "i686-unknown-linux-gnu")
+const WORD_BITS = C.WORD_BITS
+
+func OpenHandle(handle *Handle) {
+  C.open_handle(handle)
+}
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+func SetLogger(logger *Logger) {
+  C.set_logger(logger)
+}
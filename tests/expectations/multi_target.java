@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+  int WORD_BITS = 64;
+
+  // cfg(target = "x86_64-pc-windows-msvc")
+  @Structure.FieldOrder({"raw"})
+  class Handle extends Structure {
+    public Pointer raw;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  // cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  @Structure.FieldOrder({"level"})
+  class Logger extends Structure {
+    public byte level;
+
+    public static class ByReference extends Logger implements Structure.ByReference {}
+
+    public static class ByValue extends Logger implements Structure.ByValue {}
+  }
+
+  void open_handle(Handle.ByReference handle);
+
+  // cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  void set_logger(Logger.ByReference logger);
+}
@@ -0,0 +1,36 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const Logger = StructType();
+
+// cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+const WORD_BITS = 64;
+
+// cfg(target = "x86_64-pc-windows-msvc")
+Handle.defineProperty('raw', ref.refType('uint8'));
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+Logger.defineProperty('level', 'uint8');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    open_handle: ['void', [ref.refType(Handle)]],
+    // cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+    set_logger: ['void', [ref.refType(Logger)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Logger,
+  WORD_BITS,
+  load,
+};
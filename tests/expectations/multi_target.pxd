@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+  const uint32_t WORD_BITS
+
+  # cfg(target = "i686-unknown-linux-gnu")
+  const uint32_t WORD_BITS
+
+  # cfg(target = "x86_64-pc-windows-msvc")
+  ctypedef struct Handle:
+    uint8_t *raw
+
+  # cfg(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"))
+  ctypedef struct Handle:
+    int32_t fd
+
+  # cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  ctypedef struct Logger:
+    uint8_t level
+
+  void open_handle(Handle *handle)
+
+  # cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  void set_logger(const Logger *logger)
@@ -0,0 +1,43 @@
+import ctypes
+
+# cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+WORD_BITS = 64
+
+# cfg(target = "i686-unknown-linux-gnu")
+WORD_BITS = 32
+
+# cfg(target = "x86_64-pc-windows-msvc")
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("raw", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+# cfg(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"))
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("fd", ctypes.c_int32),
+]
+
+# cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+class Logger(ctypes.Structure):
+  pass
+
+Logger._fields_ = [
+  ("level", ctypes.c_uint8),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.open_handle.argtypes = [ctypes.POINTER(Handle)]
+  lib.open_handle.restype = None
+
+  # cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+  lib.set_logger.argtypes = [ctypes.POINTER(Logger)]
+  lib.set_logger.restype = None
+
+  return lib
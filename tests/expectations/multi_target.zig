@@ -0,0 +1,27 @@
+const std = @import("std");
+
+// cfg(any(target = "x86_64-pc-windows-msvc", target = "x86_64-unknown-linux-gnu"))
+pub const WORD_BITS: u32 = 64;
+
+// cfg(target = "i686-unknown-linux-gnu")
+pub const WORD_BITS: u32 = 32;
+
+// cfg(target = "x86_64-pc-windows-msvc")
+pub const Handle = extern struct {
+  raw: [*c]u8,
+};
+
+// cfg(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"))
+pub const Handle = extern struct {
+  fd: i32,
+};
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+pub const Logger = extern struct {
+  level: u8,
+};
+
+pub extern fn open_handle(handle: [*c]Handle) void;
+
+// cfg(all(any(target = "x86_64-unknown-linux-gnu", target = "i686-unknown-linux-gnu"), feature = "logging"))
+pub extern fn set_logger(logger: [*c]const Logger) void;
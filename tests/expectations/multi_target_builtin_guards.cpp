@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#if defined(_WIN32)
+struct Handle {
+  uint8_t *raw;
+};
+#endif
+
+#if (defined(__linux__) || defined(__APPLE__))
+struct Handle {
+  int32_t fd;
+};
+#endif
+
+extern "C" {
+
+#if defined(__APPLE__)
+void apple_only();
+#endif
+
+void open_handle(Handle *handle);
+
+} // extern "C"
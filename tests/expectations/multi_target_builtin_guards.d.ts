@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(target = "x86_64-pc-windows-msvc")
+export interface Handle {
+  raw: Buffer;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface Library {
+  // cfg(target = "x86_64-apple-darwin")
+  apple_only(): void;
+
+  open_handle(handle: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(target = "x86_64-pc-windows-msvc")
+type Handle = C.Handle
+
+// cfg(any(target = "x86_64-unknown-linux-gnu", target = "x86_64-apple-darwin"))
+type Handle = C.Handle
+
+// cfg(target = "x86_64-apple-darwin")
+func AppleOnly() {
+  C.apple_only()
+}
+
+func OpenHandle(handle *Handle) {
+  C.open_handle(handle)
+}
@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(target = "x86_64-pc-windows-msvc")
+  @Structure.FieldOrder({"raw"})
+  class Handle extends Structure {
+    public Pointer raw;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  // cfg(target = "x86_64-apple-darwin")
+  void apple_only();
+
+  void open_handle(Handle.ByReference handle);
+}
@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+
+// cfg(target = "x86_64-pc-windows-msvc")
+Handle.defineProperty('raw', ref.refType('uint8'));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    // cfg(target = "x86_64-apple-darwin")
+    apple_only: ['void', []],
+    open_handle: ['void', [ref.refType(Handle)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  load,
+};
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(target = "x86_64-pc-windows-msvc")
+  ctypedef struct Handle:
+    uint8_t *raw
+
+  # cfg(any(target = "x86_64-unknown-linux-gnu", target = "x86_64-apple-darwin"))
+  ctypedef struct Handle:
+    int32_t fd
+
+  # cfg(target = "x86_64-apple-darwin")
+  void apple_only()
+
+  void open_handle(Handle *handle)
@@ -0,0 +1,29 @@
+import ctypes
+
+# cfg(target = "x86_64-pc-windows-msvc")
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("raw", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+# cfg(any(target = "x86_64-unknown-linux-gnu", target = "x86_64-apple-darwin"))
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("fd", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # cfg(target = "x86_64-apple-darwin")
+  lib.apple_only.argtypes = []
+  lib.apple_only.restype = None
+
+  lib.open_handle.argtypes = [ctypes.POINTER(Handle)]
+  lib.open_handle.restype = None
+
+  return lib
@@ -0,0 +1,16 @@
+const std = @import("std");
+
+// cfg(target = "x86_64-pc-windows-msvc")
+pub const Handle = extern struct {
+  raw: [*c]u8,
+};
+
+// cfg(any(target = "x86_64-unknown-linux-gnu", target = "x86_64-apple-darwin"))
+pub const Handle = extern struct {
+  fd: i32,
+};
+
+// cfg(target = "x86_64-apple-darwin")
+pub extern fn apple_only() void;
+
+pub extern fn open_handle(handle: [*c]Handle) void;
@@ -0,0 +1,47 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MaybeOwnedPtr_i32_Tag: {
+  readonly Owned_i32: 0;
+  readonly None_i32: 1;
+};
+
+export interface Owned_Body_i32 {
+  _0: Buffer;
+  ref(): Buffer;
+}
+export declare const Owned_Body_i32: StructType<Owned_Body_i32>;
+
+export interface MaybeOwnedPtr_i32 {
+  tag: number;
+  variants: {
+    owned: Owned_Body_i32;
+  };
+  ref(): Buffer;
+}
+export declare const MaybeOwnedPtr_i32: StructType<MaybeOwnedPtr_i32>;
+
+export interface OwnedPtr_i32 {
+  ptr: Buffer;
+  ref(): Buffer;
+}
+export declare const OwnedPtr_i32: StructType<OwnedPtr_i32>;
+
+export interface Library {
+  maybe_consume(input: OwnedPtr_i32): MaybeOwnedPtr_i32;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,26 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type MaybeOwnedPtr_i32_Tag = C.MaybeOwnedPtr_i32_Tag
+
+const (
+  Owned_i32 MaybeOwnedPtr_i32_Tag = C.Owned_i32
+  None_i32 MaybeOwnedPtr_i32_Tag = C.None_i32
+)
+
+type Owned_Body_i32 = C.Owned_Body_i32
+
+type MaybeOwnedPtr_i32 = C.MaybeOwnedPtr_i32
+
+type OwnedPtr_i32 = C.OwnedPtr_i32
+
+func MaybeConsume(input OwnedPtr_i32) MaybeOwnedPtr_i32 {
+  return C.maybe_consume(input)
+}
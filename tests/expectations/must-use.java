@@ -0,0 +1,51 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface MaybeOwnedPtr_i32_Tag {
+    byte Owned_i32 = 0;
+    byte None_i32 = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Owned_Body_i32 extends Structure {
+    public Pointer _0;
+
+    public static class ByReference extends Owned_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Owned_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class MaybeOwnedPtr_i32 extends Structure {
+    public static class Variants extends Union {
+      public Owned_Body_i32 owned;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends MaybeOwnedPtr_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends MaybeOwnedPtr_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr"})
+  class OwnedPtr_i32 extends Structure {
+    public Pointer ptr;
+
+    public static class ByReference extends OwnedPtr_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends OwnedPtr_i32 implements Structure.ByValue {}
+  }
+
+  MaybeOwnedPtr_i32.ByValue maybe_consume(OwnedPtr_i32.ByValue input);
+}
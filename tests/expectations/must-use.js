@@ -0,0 +1,47 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Owned_Body_i32 = StructType();
+const _MaybeOwnedPtr_i32_Variants = UnionType();
+const MaybeOwnedPtr_i32 = StructType();
+const OwnedPtr_i32 = StructType();
+
+Owned_Body_i32.defineProperty('_0', ref.refType('int32'));
+
+_MaybeOwnedPtr_i32_Variants.defineProperty('owned', Owned_Body_i32);
+
+MaybeOwnedPtr_i32.defineProperty('tag', 'uint8');
+MaybeOwnedPtr_i32.defineProperty('variants', _MaybeOwnedPtr_i32_Variants);
+
+OwnedPtr_i32.defineProperty('ptr', ref.refType('int32'));
+
+const MaybeOwnedPtr_i32_Tag = Object.freeze({
+  Owned_i32: 0,
+  None_i32: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    maybe_consume: [MaybeOwnedPtr_i32, [OwnedPtr_i32]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Owned_Body_i32,
+  MaybeOwnedPtr_i32,
+  OwnedPtr_i32,
+  MaybeOwnedPtr_i32_Tag,
+  load,
+};
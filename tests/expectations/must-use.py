@@ -0,0 +1,48 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+import ctypes
+
+MaybeOwnedPtr_i32_Tag = ctypes.c_uint8
+Owned_i32 = 0
+None_i32 = 1
+
+class Owned_Body_i32(ctypes.Structure):
+  pass
+
+Owned_Body_i32._fields_ = [
+  ("_0", ctypes.POINTER(ctypes.c_int32)),
+]
+
+class _MaybeOwnedPtr_i32_Variants(ctypes.Union):
+  pass
+
+_MaybeOwnedPtr_i32_Variants._fields_ = [
+  ("owned", Owned_Body_i32),
+]
+
+class MaybeOwnedPtr_i32(ctypes.Structure):
+  pass
+
+MaybeOwnedPtr_i32._anonymous_ = ("_variants",)
+MaybeOwnedPtr_i32._fields_ = [
+  ("tag", MaybeOwnedPtr_i32_Tag),
+  ("_variants", _MaybeOwnedPtr_i32_Variants),
+]
+
+class OwnedPtr_i32(ctypes.Structure):
+  pass
+
+OwnedPtr_i32._fields_ = [
+  ("ptr", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.maybe_consume.argtypes = [OwnedPtr_i32]
+  lib.maybe_consume.restype = MaybeOwnedPtr_i32
+
+  return lib
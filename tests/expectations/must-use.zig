@@ -0,0 +1,28 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+const std = @import("std");
+
+pub const MaybeOwnedPtr_i32_Tag = enum(u8) {
+  Owned_i32,
+  None_i32,
+};
+
+pub const Owned_Body_i32 = extern struct {
+  _0: [*c]i32,
+};
+
+pub const MaybeOwnedPtr_i32 = extern struct {
+  tag: MaybeOwnedPtr_i32_Tag,
+  body: extern union {
+    owned: Owned_Body_i32,
+  },
+};
+
+pub const OwnedPtr_i32 = extern struct {
+  ptr: [*c]i32,
+};
+
+pub extern fn maybe_consume(input: OwnedPtr_i32) MaybeOwnedPtr_i32;
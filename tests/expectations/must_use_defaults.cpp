@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class [[nodiscard]] Status : uint8_t {
+  Ok,
+  Failed,
+};
+
+struct [[nodiscard]] Handle {
+  uint32_t id;
+};
+
+extern "C" {
+
+[[nodiscard]] Status close(Handle handle);
+
+[[nodiscard]] uint32_t count();
+
+[[nodiscard]] Handle open();
+
+void reset(Handle *handle);
+
+} // extern "C"
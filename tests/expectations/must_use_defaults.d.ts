@@ -0,0 +1,33 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Status: {
+  readonly Ok: 0;
+  readonly Failed: 1;
+};
+
+export interface Handle {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface Library {
+  close(handle: Handle): number;
+
+  count(): number;
+
+  open(): Handle;
+
+  reset(handle: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,29 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Status = C.Status
+
+const (
+  Ok Status = C.Ok
+  Failed Status = C.Failed
+)
+
+type Handle = C.Handle
+
+func Close(handle Handle) Status {
+  return C.close(handle)
+}
+
+func Count() uint32 {
+  return uint32(C.count())
+}
+
+func Open() Handle {
+  return C.open()
+}
+
+func Reset(handle *Handle) {
+  C.reset(handle)
+}
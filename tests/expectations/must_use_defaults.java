@@ -0,0 +1,25 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Status {
+    byte Ok = 0;
+    byte Failed = 1;
+  }
+
+  @Structure.FieldOrder({"id"})
+  class Handle extends Structure {
+    public int id;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  byte close(Handle.ByValue handle);
+
+  int count();
+
+  Handle.ByValue open();
+
+  void reset(Handle.ByReference handle);
+}
@@ -0,0 +1,33 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+
+Handle.defineProperty('id', 'uint32');
+
+const Status = Object.freeze({
+  Ok: 0,
+  Failed: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    close: ['uint8', [Handle]],
+    count: ['uint32', []],
+    open: [Handle, []],
+    reset: ['void', [ref.refType(Handle)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Status,
+  load,
+};
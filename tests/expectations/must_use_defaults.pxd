@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    Ok
+    Failed
+  ctypedef uint8_t Status
+
+  ctypedef struct Handle:
+    uint32_t id
+
+  Status close(Handle handle)
+
+  uint32_t count()
+
+  Handle open()
+
+  void reset(Handle *handle)
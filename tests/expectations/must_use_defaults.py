@@ -0,0 +1,29 @@
+import ctypes
+
+Status = ctypes.c_uint8
+Ok = 0
+Failed = 1
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.close.argtypes = [Handle]
+  lib.close.restype = Status
+
+  lib.count.argtypes = []
+  lib.count.restype = ctypes.c_uint32
+
+  lib.open.argtypes = []
+  lib.open.restype = Handle
+
+  lib.reset.argtypes = [ctypes.POINTER(Handle)]
+  lib.reset.restype = None
+
+  return lib
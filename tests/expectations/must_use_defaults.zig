@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const Status = enum(u8) {
+  Ok,
+  Failed,
+};
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+pub extern fn close(handle: Handle) Status;
+
+pub extern fn count() u32;
+
+pub extern fn open() Handle;
+
+pub extern fn reset(handle: [*c]Handle) void;
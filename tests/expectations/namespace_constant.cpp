@@ -5,9 +5,11 @@
 
 namespace constants {
 
+static const char* const BAR = "hello world";
+
 static const int32_t FOO = 10;
 
-static const float ZOM = 3.14;
+static const float ZOM = 3.14f;
 
 struct Foo {
   int32_t x[FOO];
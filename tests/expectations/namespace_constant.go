@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+const BAR = "hello world"
+
+const FOO = C.FOO
+
+const ZOM = C.ZOM
+
+func Root(x Foo) {
+  C.root(x)
+}
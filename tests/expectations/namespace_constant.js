@@ -0,0 +1,33 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+
+const BAR = 'hello world';
+
+const FOO = 10;
+
+const ZOM = 3.14;
+
+Foo.defineProperty('x', ArrayType('int32', FOO));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  BAR,
+  FOO,
+  ZOM,
+  load,
+};
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const BAR: [*:0]const u8 = "hello world";
+
+pub const FOO: i32 = 10;
+
+pub const ZOM: f32 = 3.14;
+
+pub const Foo = extern struct {
+  x: [@intCast(FOO)]i32,
+};
+
+pub extern fn root(x: Foo) void;
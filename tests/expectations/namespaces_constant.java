@@ -0,0 +1,20 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  String BAR = "hello world";
+
+  int FOO = 10;
+
+  float ZOM = 3.14f;
+
+  @Structure.FieldOrder({"x"})
+  class Foo extends Structure {
+    public int[] x = new int[(int) FOO];
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void root(Foo.ByValue x);
+}
@@ -0,0 +1,22 @@
+import ctypes
+
+BAR = b"hello world"
+
+FOO = 10
+
+ZOM = 3.14
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", (ctypes.c_int32 * FOO)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,4 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
@@ -0,0 +1,8 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+func Root() {
+  C.root()
+}
@@ -0,0 +1,9 @@
+import ctypes
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
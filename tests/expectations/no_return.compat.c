@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_NORETURN
+#  if defined(__cplusplus)
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#    define CBINDGEN_NORETURN _Noreturn
+#  elif defined(__GNUC__)
+#    define CBINDGEN_NORETURN __attribute__((noreturn))
+#  elif defined(_MSC_VER)
+#    define CBINDGEN_NORETURN __declspec(noreturn)
+#  else
+#    define CBINDGEN_NORETURN
+#  endif
+#endif
+
+typedef void (*Abort)();
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+CBINDGEN_NORETURN extern void host_abort(void);
+
+CBINDGEN_NORETURN void rust_panic(const uint8_t *message, uintptr_t len);
+
+void set_abort_handler(Abort handler);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+using Abort = void(*)();
+
+extern "C" {
+
+[[noreturn]] extern void host_abort();
+
+[[noreturn]] void rust_panic(const uint8_t *message, uintptr_t len);
+
+void set_abort_handler(Abort handler);
+
+} // extern "C"
@@ -0,0 +1,23 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export type Abort = Buffer;
+export declare const Abort: RefType;
+
+export interface Library {
+  host_abort(): void;
+
+  rust_panic(message: Buffer | null, len: number | string): void;
+
+  set_abort_handler(handler: Buffer): void;
+}
+
+export declare function load(path: string): Library;
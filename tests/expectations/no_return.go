@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Abort = C.Abort
+
+func HostAbort() {
+  C.host_abort()
+}
+
+func RustPanic(message *uint8, len uintptr) {
+  C.rust_panic((*C.uint8_t)(unsafe.Pointer(message)), C.uintptr_t(len))
+}
+
+func SetAbortHandler(handler Abort) {
+  C.set_abort_handler(handler)
+}
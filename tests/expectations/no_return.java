@@ -0,0 +1,23 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Abort extends Callback {
+    void invoke();
+  }
+
+  void host_abort();
+
+  void rust_panic(Pointer message, SizeT len);
+
+  void set_abort_handler(Abort handler);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,23 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const Abort = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    host_abort: ['void', []],
+    rust_panic: ['void', [ref.refType('uint8'), 'size_t']],
+    set_abort_handler: ['void', ['pointer']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Abort,
+  load,
+};
@@ -0,0 +1,17 @@
+import ctypes
+
+Abort = ctypes.CFUNCTYPE(None)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.host_abort.argtypes = []
+  lib.host_abort.restype = None
+
+  lib.rust_panic.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.rust_panic.restype = None
+
+  lib.set_abort_handler.argtypes = [Abort]
+  lib.set_abort_handler.restype = None
+
+  return lib
@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const Abort = *const fn () callconv(.C) void;
+
+pub extern fn host_abort() noreturn;
+
+pub extern fn rust_panic(message: [*c]const u8, len: usize) noreturn;
+
+pub extern fn set_abort_handler(handler: Abort) void;
@@ -0,0 +1,29 @@
+#if !defined(__cplusplus) && !defined(__bool_true_false_are_defined)
+#define bool _Bool
+#endif
+
+#if !defined(INT8_MAX)
+typedef __INT64_TYPE__ int64_t;
+typedef __UINT8_TYPE__ uint8_t;
+typedef __UINTPTR_TYPE__ uintptr_t;
+#endif
+
+#if !defined(NAN)
+#define NAN __builtin_nanf("")
+#endif
+
+#define MISSING NAN
+
+enum Mode {
+  Read,
+  Write,
+};
+typedef uint8_t Mode;
+
+typedef struct {
+  uint8_t *data;
+  uintptr_t len;
+  bool owned;
+} Buffer;
+
+void fill(Buffer *buffer, Mode mode, int64_t value);
@@ -0,0 +1,28 @@
+#if !defined(INT8_MAX)
+typedef __INT64_TYPE__ int64_t;
+typedef __UINT8_TYPE__ uint8_t;
+typedef __UINTPTR_TYPE__ uintptr_t;
+#endif
+
+#if !defined(NAN)
+#define NAN __builtin_nanf("")
+#endif
+
+static const double MISSING = NAN;
+
+enum class Mode : uint8_t {
+  Read,
+  Write,
+};
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+  bool owned;
+};
+
+extern "C" {
+
+void fill(Buffer *buffer, Mode mode, int64_t value);
+
+} // extern "C"
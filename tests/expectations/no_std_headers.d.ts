@@ -0,0 +1,31 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MISSING: number;
+
+export declare const Mode: {
+  readonly Read: 0;
+  readonly Write: 1;
+};
+
+export interface Buffer {
+  data: Buffer;
+  len: number | string;
+  owned: boolean;
+  ref(): Buffer;
+}
+export declare const Buffer: StructType<Buffer>;
+
+export interface Library {
+  fill(buffer: Buffer | null, mode: number, value: number | string): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,17 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Mode = C.Mode
+
+const (
+  Read Mode = C.Read
+  Write Mode = C.Write
+)
+
+type Buffer = C.Buffer
+
+func Fill(buffer *Buffer, mode Mode, value int64) {
+  C.fill(buffer, mode, C.int64_t(value))
+}
@@ -0,0 +1,33 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  double MISSING = Double.NaN;
+
+  interface Mode {
+    byte Read = 0;
+    byte Write = 1;
+  }
+
+  @Structure.FieldOrder({"data", "len", "owned"})
+  class Buffer extends Structure {
+    public Pointer data;
+    public SizeT len;
+    public byte owned;
+
+    public static class ByReference extends Buffer implements Structure.ByReference {}
+
+    public static class ByValue extends Buffer implements Structure.ByValue {}
+  }
+
+  void fill(Buffer.ByReference buffer, byte mode, long value);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Buffer = StructType();
+
+const MISSING = NaN;
+
+Buffer.defineProperty('data', ref.refType('uint8'));
+Buffer.defineProperty('len', 'size_t');
+Buffer.defineProperty('owned', 'bool');
+
+const Mode = Object.freeze({
+  Read: 0,
+  Write: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    fill: ['void', [ref.refType(Buffer), 'uint8', 'int64']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Buffer,
+  Mode,
+  MISSING,
+  load,
+};
@@ -0,0 +1,24 @@
+import ctypes
+
+MISSING = float("nan")
+
+Mode = ctypes.c_uint8
+Read = 0
+Write = 1
+
+class Buffer(ctypes.Structure):
+  pass
+
+Buffer._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+  ("owned", ctypes.c_bool),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.fill.argtypes = [ctypes.POINTER(Buffer), Mode, ctypes.c_int64]
+  lib.fill.restype = None
+
+  return lib
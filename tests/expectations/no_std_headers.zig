@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const MISSING: f64 = std.math.nan(f64);
+
+pub const Mode = enum(u8) {
+  Read,
+  Write,
+};
+
+pub const Buffer = extern struct {
+  data: [*c]u8,
+  len: usize,
+  owned: bool,
+};
+
+pub extern fn fill(buffer: [*c]Buffer, mode: Mode, value: i64) void;
@@ -0,0 +1,70 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define CAPACITY 16
+
+#define HALF (7 / 2)
+
+#define LARGE 9007199254740993ULL
+
+#define LIMIT (1 << 20)
+
+#define NAME 'n'
+
+typedef struct Opaque Opaque;
+
+typedef struct {
+  uint64_t value;
+  intptr_t delta;
+} Node;
+
+/**
+ * A list of nodes.
+ */
+typedef struct {
+  Node nodes[CAPACITY];
+  Node *head;
+  uintptr_t len;
+} List;
+#define List_EMPTY_LEN 0
+
+typedef List *Handle;
+
+enum Event_Tag {
+  Added,
+  Removed,
+  Cleared,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct {
+  Event_Tag tag;
+  uint32_t _0;
+} Added_Body;
+
+typedef struct {
+  Event_Tag tag;
+  uint32_t index;
+  uint64_t value;
+} Removed_Body;
+
+typedef union {
+  Event_Tag tag;
+  Added_Body added;
+  Removed_Body removed;
+} Event;
+
+typedef void (*Callback)(const List *list, Event event);
+
+extern uint32_t LISTS;
+
+/**
+ * Pushes `value` to `list`.
+ * # Returns
+ * Whether there was room for it.
+ */
+bool list_push(Handle list, uint64_t value);
+
+void list_watch(const List *list, const char *name, Callback callback, Opaque *opaque);
@@ -0,0 +1,71 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uintptr_t CAPACITY = 16;
+
+static const int32_t HALF = 7 / 2;
+
+static const uint64_t LARGE = 9007199254740993ULL;
+
+static const int64_t LIMIT = 1 << 20;
+
+static const wchar_t NAME = 'n';
+
+struct Opaque;
+
+struct Node {
+  uint64_t value;
+  intptr_t delta;
+};
+
+/// A list of nodes.
+struct List {
+  Node nodes[CAPACITY];
+  Node *head;
+  uintptr_t len;
+};
+static const uintptr_t List_EMPTY_LEN = 0;
+
+using Handle = List*;
+
+union Event {
+  enum class Tag : uint8_t {
+    Added,
+    Removed,
+    Cleared,
+  };
+
+  struct Added_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct Removed_Body {
+    Tag tag;
+    uint32_t index;
+    uint64_t value;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Added_Body added;
+  Removed_Body removed;
+};
+
+using Callback = void(*)(const List *list, Event event);
+
+extern "C" {
+
+extern uint32_t LISTS;
+
+/// Pushes `value` to `list`.
+/// # Returns
+/// Whether there was room for it.
+bool list_push(Handle list, uint64_t value);
+
+void list_watch(const List *list, const char *name, Callback callback, Opaque *opaque);
+
+} // extern "C"
@@ -0,0 +1,89 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * The number of nodes a list can hold.
+ */
+export declare const CAPACITY: number;
+
+export declare const HALF: number;
+
+export declare const LARGE: bigint;
+
+export declare const LIMIT: bigint;
+
+export declare const NAME: number;
+
+export declare const List_EMPTY_LEN: number;
+
+export declare const Event_Tag: {
+  readonly Added: 0;
+  readonly Removed: 1;
+  readonly Cleared: 2;
+};
+
+export interface Node {
+  value: number | string;
+  delta: number | string;
+  ref(): Buffer;
+}
+export declare const Node: StructType<Node>;
+
+/**
+ * A list of nodes.
+ */
+export interface List {
+  nodes: ArrayLike<Node>;
+  head: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const List: StructType<List>;
+
+export interface Added_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Added_Body: StructType<Added_Body>;
+
+export interface Removed_Body {
+  tag: number;
+  index: number;
+  value: number | string;
+  ref(): Buffer;
+}
+export declare const Removed_Body: StructType<Removed_Body>;
+
+export interface Event {
+  tag: number;
+  added: Added_Body;
+  removed: Removed_Body;
+  ref(): Buffer;
+}
+export declare const Event: StructType<Event>;
+
+export type Callback = Buffer;
+export declare const Callback: RefType;
+
+export interface Library {
+  LISTS: Buffer;
+
+  /**
+   * Pushes `value` to `list`.
+   * @return Whether there was room for it.
+   */
+  list_push(list: Buffer | null, value: number | string): boolean;
+
+  list_watch(list: Buffer | null, name: string | null, callback: Buffer, opaque: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
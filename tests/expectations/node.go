@@ -0,0 +1,57 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Opaque = C.Opaque
+
+type Node = C.Node
+
+// A list of nodes.
+type List = C.List
+
+const List_EMPTY_LEN = C.List_EMPTY_LEN
+
+type Handle = C.Handle
+
+type Event_Tag = C.Event_Tag
+
+const (
+  Added Event_Tag = C.Added
+  Removed Event_Tag = C.Removed
+  Cleared Event_Tag = C.Cleared
+)
+
+type Added_Body = C.Added_Body
+
+type Removed_Body = C.Removed_Body
+
+type Event = C.Event
+
+type Callback = C.Callback
+
+// The number of nodes a list can hold.
+const CAPACITY = C.CAPACITY
+
+const HALF = C.HALF
+
+const LARGE = C.LARGE
+
+const LIMIT = C.LIMIT
+
+const NAME = C.NAME
+
+func Lists() uint32 {
+  return uint32(C.LISTS)
+}
+
+// Pushes `value` to `list`.
+// # Returns
+// Whether there was room for it.
+func ListPush(list Handle, value uint64) bool {
+  return bool(C.list_push(list, C.uint64_t(value)))
+}
+
+func ListWatch(list *List, name *C.char, callback Callback, opaque *Opaque) {
+  C.list_watch(list, name, callback, opaque)
+}
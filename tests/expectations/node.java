@@ -0,0 +1,111 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * The number of nodes a list can hold.
+   */
+  long CAPACITY = 16L;
+
+  int HALF = 7 / 2;
+
+  long LARGE = 9007199254740993L;
+
+  long LIMIT = 1L << 20L;
+
+  int NAME = 'n';
+
+  @Structure.FieldOrder({"value", "delta"})
+  class Node extends Structure {
+    public long value;
+    public SSizeT delta;
+
+    public static class ByReference extends Node implements Structure.ByReference {}
+
+    public static class ByValue extends Node implements Structure.ByValue {}
+  }
+
+  /**
+   * A list of nodes.
+   */
+  @Structure.FieldOrder({"nodes", "head", "len"})
+  class List extends Structure {
+    public Node[] nodes = (Node[]) new Node().toArray((int) CAPACITY);
+    public Node.ByReference head;
+    public SizeT len;
+
+    public static class ByReference extends List implements Structure.ByReference {}
+
+    public static class ByValue extends List implements Structure.ByValue {}
+  }
+
+  long List_EMPTY_LEN = 0L;
+
+  interface Event_Tag {
+    byte Added = 0;
+    byte Removed = 1;
+    byte Cleared = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class Added_Body extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends Added_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Added_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "index", "value"})
+  class Removed_Body extends Structure {
+    public byte tag;
+    public int index;
+    public long value;
+
+    public static class ByReference extends Removed_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Removed_Body implements Structure.ByValue {}
+  }
+
+  class Event extends Union {
+    public byte tag;
+    public Added_Body added;
+    public Removed_Body removed;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  interface Callback extends Callback {
+    void invoke(List.ByReference list, Event.ByValue event);
+  }
+
+  /**
+   * Pushes `value` to `list`.
+   * @return Whether there was room for it.
+   */
+  byte list_push(List.ByReference list, long value);
+
+  void list_watch(List.ByReference list, String name, Callback callback, Pointer opaque);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+
+  class SSizeT extends IntegerType {
+    public SSizeT() {
+      this(0);
+    }
+
+    public SSizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, false);
+    }
+  }
+}
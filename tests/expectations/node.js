@@ -0,0 +1,81 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const ssize_t = ref.sizeof.size_t === 8 ? 'int64' : 'int32';
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Node = StructType();
+const List = StructType();
+const Added_Body = StructType();
+const Removed_Body = StructType();
+const Event = UnionType();
+
+const CAPACITY = 16;
+
+const HALF = Math.trunc(7 / 2);
+
+const LARGE = 9007199254740993n;
+
+const LIMIT = 1n << 20n;
+
+const NAME = 110;
+
+Node.defineProperty('value', 'uint64');
+Node.defineProperty('delta', ssize_t);
+
+List.defineProperty('nodes', ArrayType(Node, CAPACITY));
+List.defineProperty('head', ref.refType(Node));
+List.defineProperty('len', 'size_t');
+
+Added_Body.defineProperty('tag', 'uint8');
+Added_Body.defineProperty('_0', 'uint32');
+
+Removed_Body.defineProperty('tag', 'uint8');
+Removed_Body.defineProperty('index', 'uint32');
+Removed_Body.defineProperty('value', 'uint64');
+
+Event.defineProperty('tag', 'uint8');
+Event.defineProperty('added', Added_Body);
+Event.defineProperty('removed', Removed_Body);
+
+const Event_Tag = Object.freeze({
+  Added: 0,
+  Removed: 1,
+  Cleared: 2,
+});
+
+const Callback = 'pointer';
+
+const List_EMPTY_LEN = 0;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    list_push: ['bool', [ref.refType(List), 'uint64']],
+    list_watch: ['void', [ref.refType(List), 'string', 'pointer', 'pointer']],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.LISTS = dylib.get('LISTS');
+  return lib;
+}
+
+module.exports = {
+  Node,
+  List,
+  Added_Body,
+  Removed_Body,
+  Event,
+  Event_Tag,
+  Callback,
+  CAPACITY,
+  HALF,
+  LARGE,
+  LIMIT,
+  NAME,
+  List_EMPTY_LEN,
+  load,
+};
@@ -0,0 +1,66 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # The number of nodes a list can hold.
+  const uintptr_t CAPACITY
+
+  const int32_t HALF
+
+  const uint64_t LARGE
+
+  const int64_t LIMIT
+
+  const wchar_t NAME
+
+  ctypedef struct Opaque:
+    pass
+
+  ctypedef struct Node:
+    uint64_t value
+    intptr_t delta
+
+  # A list of nodes.
+  ctypedef struct List:
+    Node nodes[CAPACITY]
+    Node *head
+    uintptr_t len
+  const uintptr_t List_EMPTY_LEN
+
+  ctypedef List *Handle
+
+  enum:
+    Added
+    Removed
+    Cleared
+  ctypedef uint8_t Event_Tag
+
+  ctypedef struct Added_Body:
+    Event_Tag tag
+    uint32_t _0
+
+  ctypedef struct Removed_Body:
+    Event_Tag tag
+    uint32_t index
+    uint64_t value
+
+  ctypedef union Event:
+    Event_Tag tag
+    Added_Body added
+    Removed_Body removed
+
+  ctypedef void (*Callback)(const List *list, Event event)
+
+  uint32_t LISTS
+
+  # Pushes `value` to `list`.
+  # # Returns
+  # Whether there was room for it.
+  bool list_push(Handle list, uint64_t value)
+
+  void list_watch(const List *list, const char *name, Callback callback, Opaque *opaque)
@@ -0,0 +1,85 @@
+import ctypes
+
+# The number of nodes a list can hold.
+CAPACITY = 16
+
+HALF = 7 // 2
+
+LARGE = 9007199254740993
+
+LIMIT = 1 << 20
+
+NAME = 'n'
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("value", ctypes.c_uint64),
+  ("delta", ctypes.c_ssize_t),
+]
+
+# A list of nodes.
+class List(ctypes.Structure):
+  pass
+
+List._fields_ = [
+  ("nodes", (Node * CAPACITY)),
+  ("head", ctypes.POINTER(Node)),
+  ("len", ctypes.c_size_t),
+]
+List_EMPTY_LEN = 0
+
+Handle = ctypes.POINTER(List)
+
+Event_Tag = ctypes.c_uint8
+Added = 0
+Removed = 1
+Cleared = 2
+
+class Added_Body(ctypes.Structure):
+  pass
+
+Added_Body._fields_ = [
+  ("tag", Event_Tag),
+  ("_0", ctypes.c_uint32),
+]
+
+class Removed_Body(ctypes.Structure):
+  pass
+
+Removed_Body._fields_ = [
+  ("tag", Event_Tag),
+  ("index", ctypes.c_uint32),
+  ("value", ctypes.c_uint64),
+]
+
+class Event(ctypes.Union):
+  pass
+
+Event._fields_ = [
+  ("tag", Event_Tag),
+  ("added", Added_Body),
+  ("removed", Removed_Body),
+]
+
+Callback = ctypes.CFUNCTYPE(None, ctypes.POINTER(List), Event)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.LISTS = ctypes.c_uint32.in_dll(lib, "LISTS")
+
+  # Pushes `value` to `list`.
+  # # Returns
+  # Whether there was room for it.
+  lib.list_push.argtypes = [Handle, ctypes.c_uint64]
+  lib.list_push.restype = ctypes.c_bool
+
+  lib.list_watch.argtypes = [ctypes.POINTER(List), ctypes.c_char_p, Callback, ctypes.POINTER(Opaque)]
+  lib.list_watch.restype = None
+
+  return lib
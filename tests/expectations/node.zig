@@ -0,0 +1,64 @@
+const std = @import("std");
+
+/// The number of nodes a list can hold.
+pub const CAPACITY: usize = 16;
+
+pub const HALF: i32 = @divTrunc(7, 2);
+
+pub const LARGE: u64 = 9007199254740993;
+
+pub const LIMIT: i64 = (1 << 20);
+
+pub const NAME: u32 = 'n';
+
+pub const Opaque = opaque {};
+
+pub const Node = extern struct {
+  value: u64,
+  delta: isize,
+};
+
+/// A list of nodes.
+pub const List = extern struct {
+  nodes: [@intCast(CAPACITY)]Node,
+  head: [*c]Node,
+  len: usize,
+};
+
+pub const List_EMPTY_LEN: usize = 0;
+
+pub const Handle = [*c]List;
+
+pub const Event_Tag = enum(u8) {
+  Added,
+  Removed,
+  Cleared,
+};
+
+pub const Added_Body = extern struct {
+  tag: Event_Tag,
+  _0: u32,
+};
+
+pub const Removed_Body = extern struct {
+  tag: Event_Tag,
+  index: u32,
+  value: u64,
+};
+
+pub const Event = extern union {
+  tag: Event_Tag,
+  added: Added_Body,
+  removed: Removed_Body,
+};
+
+pub const Callback = *const fn ([*c]const List, Event) callconv(.C) void;
+
+pub extern var LISTS: u32;
+
+/// Pushes `value` to `list`.
+/// # Returns
+/// Whether there was room for it.
+pub extern fn list_push(list: Handle, value: u64) bool;
+
+pub extern fn list_watch(list: *const List, name: [*c]const c_char, callback: Callback, @"opaque": ?*Opaque) void;
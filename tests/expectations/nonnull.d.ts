@@ -0,0 +1,30 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo_u64 {
+  a: Buffer;
+  b: Buffer;
+  c: Buffer;
+  d: Buffer;
+  e: Buffer;
+  f: Buffer;
+  g: Buffer;
+  h: Buffer;
+  i: Buffer;
+  ref(): Buffer;
+}
+export declare const Foo_u64: StructType<Foo_u64>;
+
+export interface Library {
+  root(arg: Buffer | null, foo: Buffer | null, d: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Opaque = C.Opaque
+
+type Foo_u64 = C.Foo_u64
+
+func Root(arg *int32, foo *Foo_u64, d **Opaque) {
+  C.root((*C.int32_t)(unsafe.Pointer(arg)), foo, d)
+}
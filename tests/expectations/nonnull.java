@@ -0,0 +1,22 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a", "b", "c", "d", "e", "f", "g", "h", "i"})
+  class Foo_u64 extends Structure {
+    public Pointer a;
+    public Pointer b;
+    public Pointer c;
+    public Pointer d;
+    public Pointer e;
+    public Pointer f;
+    public Pointer g;
+    public Pointer h;
+    public Pointer i;
+
+    public static class ByReference extends Foo_u64 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_u64 implements Structure.ByValue {}
+  }
+
+  void root(Pointer arg, Foo_u64.ByReference foo, Pointer d);
+}
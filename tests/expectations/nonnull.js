@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo_u64 = StructType();
+
+Foo_u64.defineProperty('a', ref.refType('float'));
+Foo_u64.defineProperty('b', ref.refType('uint64'));
+Foo_u64.defineProperty('c', 'pointer');
+Foo_u64.defineProperty('d', ref.refType(ref.refType('uint64')));
+Foo_u64.defineProperty('e', ref.refType(ref.refType('float')));
+Foo_u64.defineProperty('f', ref.refType('pointer'));
+Foo_u64.defineProperty('g', ref.refType('uint64'));
+Foo_u64.defineProperty('h', ref.refType('int32'));
+Foo_u64.defineProperty('i', ref.refType(ref.refType('int32')));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [ref.refType('int32'), ref.refType(Foo_u64), ref.refType('pointer')]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo_u64,
+  load,
+};
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const Opaque = opaque {};
+
+pub const Foo_u64 = extern struct {
+  a: *f32,
+  b: *u64,
+  c: *Opaque,
+  d: **u64,
+  e: **f32,
+  f: **Opaque,
+  g: [*c]u64,
+  h: [*c]i32,
+  i: [*c]*i32,
+};
+
+pub extern fn root(arg: *i32, foo: [*c]Foo_u64, d: **Opaque) void;
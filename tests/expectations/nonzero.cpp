@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Handle {
+  /// The index of the handle.
+  /// Never `0`.
+  uint32_t index;
+  /// The generation of the handle, if it was ever reused.
+  /// `0` means `None`.
+  uint32_t generation;
+  uint8_t *data;
+  void (*callback)(uintptr_t);
+  int64_t stamps[2];
+};
+
+/// `0` means `None`.
+using MaybeId = uint32_t;
+
+extern "C" {
+
+extern const uint32_t LAST_ID;
+
+/// `key`: `0` means `None`.
+/// Return value: `0` means `None`.
+uint32_t find(const Handle *handle, uintptr_t key);
+
+void lookup(MaybeId id, Handle **out);
+
+} // extern "C"
@@ -0,0 +1,43 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Handle {
+  index: number;
+  generation: number;
+  data: Buffer;
+  callback: Buffer;
+  stamps: ArrayLike<number | string>;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+/**
+ * `0` means `None`.
+ */
+export type MaybeId = number;
+export declare const MaybeId: RefType;
+
+export interface Library {
+  /**
+   * `0` means `None`.
+   */
+  LAST_ID: Buffer;
+
+  /**
+   * `key`: `0` means `None`.
+   * Return value: `0` means `None`.
+   */
+  find(handle: Buffer | null, key: number | string): number;
+
+  lookup(id: number, out: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,24 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+// `0` means `None`.
+type MaybeId = C.MaybeId
+
+// `0` means `None`.
+func LastId() uint32 {
+  return uint32(C.LAST_ID)
+}
+
+// `key`: `0` means `None`.
+// Return value: `0` means `None`.
+func Find(handle *Handle, key uintptr) uint32 {
+  return uint32(C.find(handle, C.uintptr_t(key)))
+}
+
+func Lookup(id MaybeId, out **Handle) {
+  C.lookup(id, out)
+}
@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"index", "generation", "data", "callback", "stamps"})
+  class Handle extends Structure {
+    /**
+     * The index of the handle.
+     * Never `0`.
+     */
+    public int index;
+    /**
+     * The generation of the handle, if it was ever reused.
+     * `0` means `None`.
+     */
+    public int generation;
+    public Pointer data;
+    public Pointer callback;
+    public long[] stamps = new long[2];
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  /**
+   * `key`: `0` means `None`.
+   * Return value: `0` means `None`.
+   */
+  int find(Handle.ByReference handle, SizeT key);
+
+  void lookup(int id, Pointer out);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,34 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+
+Handle.defineProperty('index', 'uint32');
+Handle.defineProperty('generation', 'uint32');
+Handle.defineProperty('data', ref.refType('uint8'));
+Handle.defineProperty('callback', 'pointer');
+Handle.defineProperty('stamps', ArrayType('int64', 2));
+
+const MaybeId = 'uint32';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    find: ['uint32', [ref.refType(Handle), 'size_t']],
+    lookup: ['void', ['uint32', ref.refType(ref.refType(Handle))]],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.LAST_ID = dylib.get('LAST_ID');
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  MaybeId,
+  load,
+};
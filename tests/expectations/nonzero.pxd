@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Handle:
+    # The index of the handle.
+    # Never `0`.
+    uint32_t index
+    # The generation of the handle, if it was ever reused.
+    # `0` means `None`.
+    uint32_t generation
+    uint8_t *data
+    void (*callback)(uintptr_t)
+    int64_t stamps[2]
+
+  # `0` means `None`.
+  ctypedef uint32_t MaybeId
+
+  # `0` means `None`.
+  const uint32_t LAST_ID
+
+  # `key`: `0` means `None`.
+  # Return value: `0` means `None`.
+  uint32_t find(const Handle *handle, uintptr_t key)
+
+  void lookup(MaybeId id, Handle **out)
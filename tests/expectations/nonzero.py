@@ -0,0 +1,35 @@
+import ctypes
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  # The index of the handle.
+  # Never `0`.
+  ("index", ctypes.c_uint32),
+  # The generation of the handle, if it was ever reused.
+  # `0` means `None`.
+  ("generation", ctypes.c_uint32),
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("callback", ctypes.CFUNCTYPE(None, ctypes.c_size_t)),
+  ("stamps", (ctypes.c_int64 * 2)),
+]
+
+# `0` means `None`.
+MaybeId = ctypes.c_uint32
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # `0` means `None`.
+  lib.LAST_ID = ctypes.c_uint32.in_dll(lib, "LAST_ID")
+
+  # `key`: `0` means `None`.
+  # Return value: `0` means `None`.
+  lib.find.argtypes = [ctypes.POINTER(Handle), ctypes.c_size_t]
+  lib.find.restype = ctypes.c_uint32
+
+  lib.lookup.argtypes = [MaybeId, ctypes.POINTER(ctypes.POINTER(Handle))]
+  lib.lookup.restype = None
+
+  return lib
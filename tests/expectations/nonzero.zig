@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const Handle = extern struct {
+  /// The index of the handle.
+  /// Never `0`.
+  index: u32,
+  /// The generation of the handle, if it was ever reused.
+  /// `0` means `None`.
+  generation: u32,
+  data: [*c]u8,
+  callback: ?*const fn (usize) callconv(.C) void,
+  stamps: [2]i64,
+};
+
+/// `0` means `None`.
+pub const MaybeId = u32;
+
+/// `0` means `None`.
+pub extern const LAST_ID: u32;
+
+/// `key`: `0` means `None`.
+/// Return value: `0` means `None`.
+pub extern fn find(handle: [*c]const Handle, key: usize) u32;
+
+pub extern fn lookup(id: MaybeId, out: [*c][*c]Handle) void;
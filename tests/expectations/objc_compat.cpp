@@ -0,0 +1,48 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#if !defined(__clang__)
+#  define _Nonnull
+#  define _Nullable
+#endif
+#ifndef NS_ASSUME_NONNULL_BEGIN
+#  if defined(__clang__)
+#    define NS_ASSUME_NONNULL_BEGIN _Pragma("clang assume_nonnull begin")
+#    define NS_ASSUME_NONNULL_END _Pragma("clang assume_nonnull end")
+#  else
+#    define NS_ASSUME_NONNULL_BEGIN
+#    define NS_ASSUME_NONNULL_END
+#  endif
+#endif
+
+NS_ASSUME_NONNULL_BEGIN
+
+static const uint32_t Nil_ = 0;
+
+enum class BOOL_ : uint8_t {
+  NO_,
+  YES_,
+};
+
+struct Class_ {
+  uint64_t size;
+};
+
+struct Object {
+  uint64_t id_;
+  const Class_ *_Nullable isa;
+  const Class_ *_Nullable nil_;
+};
+
+extern "C" {
+
+BOOL_ object_is_valid(const Object *_Nonnull object, BOOL_ *_Nullable id_);
+
+Object *_Nullable object_new(const Class_ *_Nonnull class_,
+                             void (*_Nullable SEL_)(Object*_Nullable));
+
+} // extern "C"
+
+NS_ASSUME_NONNULL_END
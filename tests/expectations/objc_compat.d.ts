@@ -0,0 +1,39 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Nil: number;
+
+export declare const BOOL: {
+  readonly NO: 0;
+  readonly YES: 1;
+};
+
+export interface Class {
+  size: number | string;
+  ref(): Buffer;
+}
+export declare const Class: StructType<Class>;
+
+export interface Object {
+  id: number | string;
+  isa: Buffer;
+  nil: Buffer;
+  ref(): Buffer;
+}
+export declare const Object: StructType<Object>;
+
+export interface Library {
+  object_is_valid(object: Buffer | null, id: Buffer | null): number;
+
+  object_new(class_: Buffer | null, SEL: Buffer | null): Buffer;
+}
+
+export declare function load(path: string): Library;
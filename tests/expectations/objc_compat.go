@@ -0,0 +1,25 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type BOOL = C.BOOL
+
+const (
+  NO BOOL = C.NO
+  YES BOOL = C.YES
+)
+
+type Class = C.Class
+
+type Object = C.Object
+
+const Nil = C.Nil
+
+func ObjectIsValid(object *Object, id *BOOL) BOOL {
+  return C.object_is_valid(object, id)
+}
+
+func ObjectNew(class_ *Class, SEL *[0]byte) *Object {
+  return C.object_new(class_, SEL)
+}
@@ -0,0 +1,34 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int Nil = 0;
+
+  interface BOOL {
+    byte NO = 0;
+    byte YES = 1;
+  }
+
+  @Structure.FieldOrder({"size"})
+  class Class extends Structure {
+    public long size;
+
+    public static class ByReference extends Class implements Structure.ByReference {}
+
+    public static class ByValue extends Class implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id", "isa", "nil"})
+  class Object extends Structure {
+    public long id;
+    public Class.ByReference isa;
+    public Class.ByReference nil;
+
+    public static class ByReference extends Object implements Structure.ByReference {}
+
+    public static class ByValue extends Object implements Structure.ByValue {}
+  }
+
+  byte object_is_valid(Object.ByReference object, Pointer id);
+
+  Object.ByReference object_new(Class.ByReference class_, Pointer SEL);
+}
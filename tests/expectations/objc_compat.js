@@ -0,0 +1,40 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Class = StructType();
+const Object = StructType();
+
+const Nil = 0;
+
+Class.defineProperty('size', 'uint64');
+
+Object.defineProperty('id', 'uint64');
+Object.defineProperty('isa', ref.refType(Class));
+Object.defineProperty('nil', ref.refType(Class));
+
+const BOOL = Object.freeze({
+  NO: 0,
+  YES: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    object_is_valid: ['uint8', [ref.refType(Object), ref.refType('uint8')]],
+    object_new: [ref.refType(Object), [ref.refType(Class), 'pointer']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Class,
+  Object,
+  BOOL,
+  Nil,
+  load,
+};
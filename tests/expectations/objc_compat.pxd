@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t Nil
+
+  enum:
+    NO
+    YES
+  ctypedef uint8_t BOOL
+
+  ctypedef struct Class:
+    uint64_t size
+
+  ctypedef struct Object:
+    uint64_t id
+    const Class *isa
+    const Class *nil
+
+  BOOL object_is_valid(const Object *object, BOOL *id)
+
+  Object *object_new(const Class *class_, void (*SEL)(Object*))
@@ -0,0 +1,34 @@
+import ctypes
+
+Nil = 0
+
+BOOL = ctypes.c_uint8
+NO = 0
+YES = 1
+
+class Class(ctypes.Structure):
+  pass
+
+Class._fields_ = [
+  ("size", ctypes.c_uint64),
+]
+
+class Object(ctypes.Structure):
+  pass
+
+Object._fields_ = [
+  ("id", ctypes.c_uint64),
+  ("isa", ctypes.POINTER(Class)),
+  ("nil", ctypes.POINTER(Class)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.object_is_valid.argtypes = [ctypes.POINTER(Object), ctypes.POINTER(BOOL)]
+  lib.object_is_valid.restype = BOOL
+
+  lib.object_new.argtypes = [ctypes.POINTER(Class), ctypes.CFUNCTYPE(None, ctypes.POINTER(Object))]
+  lib.object_new.restype = ctypes.POINTER(Object)
+
+  return lib
@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const Nil: u32 = 0;
+
+pub const BOOL = enum(u8) {
+  NO,
+  YES,
+};
+
+pub const Class = extern struct {
+  size: u64,
+};
+
+pub const Object = extern struct {
+  id: u64,
+  isa: [*c]const Class,
+  nil: [*c]const Class,
+};
+
+pub extern fn object_is_valid(object: *const Object, id: [*c]BOOL) BOOL;
+
+pub extern fn object_new(class_: *const Class, SEL: ?*const fn ([*c]Object) callconv(.C) void) [*c]Object;
@@ -0,0 +1,56 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct __attribute__((aligned(16))) Context {
+  uint8_t _private[64];
+};
+
+/// The size of a pointer differs between data models.
+#if UINTPTR_MAX == UINT64_MAX
+struct __attribute__((aligned(8))) Handle {
+  uint8_t _private[16];
+};
+#elif UINTPTR_MAX == UINT32_MAX
+struct __attribute__((aligned(4))) Handle {
+  uint8_t _private[8];
+};
+#else
+struct Handle;
+#endif
+
+/// Sorted by alignment, the fields need no padding.
+#if UINTPTR_MAX == UINT64_MAX
+struct __attribute__((aligned(8))) Parser {
+  uint8_t _private[16];
+};
+#else
+struct Parser;
+#endif
+
+struct Unknown;
+
+static_assert(sizeof(Context) == 64, "unexpected size of Context");
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Handle) == 16, "unexpected size of Handle");
+#elif UINTPTR_MAX == UINT32_MAX
+static_assert(sizeof(Handle) == 8, "unexpected size of Handle");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Parser) == 16, "unexpected size of Parser");
+#endif
+
+extern "C" {
+
+void context_init(Context *context);
+
+void handle_init(Handle *handle);
+
+void parser_init(Parser *parser);
+
+Unknown *unknown_new();
+
+} // extern "C"
@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Library {
+  context_init(context: Buffer | null): void;
+
+  handle_init(handle: Buffer | null): void;
+
+  parser_init(parser: Buffer | null): void;
+
+  unknown_new(): Buffer;
+}
+
+export declare function load(path: string): Library;
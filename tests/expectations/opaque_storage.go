@@ -0,0 +1,30 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Context = C.Context
+
+// The size of a pointer differs between data models.
+type Handle = C.Handle
+
+// Sorted by alignment, the fields need no padding.
+type Parser = C.Parser
+
+type Unknown = C.Unknown
+
+func ContextInit(context *Context) {
+  C.context_init(context)
+}
+
+func HandleInit(handle *Handle) {
+  C.handle_init(handle)
+}
+
+func ParserInit(parser *Parser) {
+  C.parser_init(parser)
+}
+
+func UnknownNew() *Unknown {
+  return C.unknown_new()
+}
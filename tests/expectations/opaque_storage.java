@@ -0,0 +1,11 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  void context_init(Pointer context);
+
+  void handle_init(Pointer handle);
+
+  void parser_init(Pointer parser);
+
+  Pointer unknown_new();
+}
@@ -0,0 +1,21 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    context_init: ['void', ['pointer']],
+    handle_init: ['void', ['pointer']],
+    parser_init: ['void', ['pointer']],
+    unknown_new: ['pointer', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  load,
+};
@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Context:
+    pass
+
+  # The size of a pointer differs between data models.
+  ctypedef struct Handle:
+    pass
+
+  # Sorted by alignment, the fields need no padding.
+  ctypedef struct Parser:
+    pass
+
+  ctypedef struct Unknown:
+    pass
+
+  void context_init(Context *context)
+
+  void handle_init(Handle *handle)
+
+  void parser_init(Parser *parser)
+
+  Unknown *unknown_new()
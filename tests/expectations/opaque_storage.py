@@ -0,0 +1,32 @@
+import ctypes
+
+class Context(ctypes.Structure):
+  pass
+
+# The size of a pointer differs between data models.
+class Handle(ctypes.Structure):
+  pass
+
+# Sorted by alignment, the fields need no padding.
+class Parser(ctypes.Structure):
+  pass
+
+class Unknown(ctypes.Structure):
+  pass
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.context_init.argtypes = [ctypes.POINTER(Context)]
+  lib.context_init.restype = None
+
+  lib.handle_init.argtypes = [ctypes.POINTER(Handle)]
+  lib.handle_init.restype = None
+
+  lib.parser_init.argtypes = [ctypes.POINTER(Parser)]
+  lib.parser_init.restype = None
+
+  lib.unknown_new.argtypes = []
+  lib.unknown_new.restype = ctypes.POINTER(Unknown)
+
+  return lib
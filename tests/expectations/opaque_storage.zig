@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const Context = opaque {};
+
+/// The size of a pointer differs between data models.
+pub const Handle = opaque {};
+
+/// Sorted by alignment, the fields need no padding.
+pub const Parser = opaque {};
+
+pub const Unknown = opaque {};
+
+pub extern fn context_init(context: ?*Context) void;
+
+pub extern fn handle_init(handle: ?*Handle) void;
+
+pub extern fn parser_init(parser: ?*Parser) void;
+
+pub extern fn unknown_new() ?*Unknown;
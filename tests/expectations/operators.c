@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer Buffer;
+
+typedef struct {
+  float x;
+  float y;
+} Vec2;
+
+const uint8_t *buffer_index(const Buffer *buffer, uintptr_t index);
+
+Vec2 vec2_add(Vec2 a, Vec2 b);
+
+void vec2_add_assign(Vec2 *a, Vec2 b);
+
+bool vec2_eq(const Vec2 *a, const Vec2 *b);
+
+Vec2 vec2_neg(Vec2 a);
+
+Vec2 vec2_sub(Vec2 a, Vec2 b);
@@ -0,0 +1,59 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Buffer {
+  Buffer() = delete;
+  Buffer(const Buffer&) = delete;
+  Buffer& operator=(const Buffer&) = delete;
+  ~Buffer() = delete;
+
+  const uint8_t &operator[](uintptr_t index) const;
+};
+
+struct Vec2 {
+  float x;
+  float y;
+
+  Vec2 operator+(Vec2 b) const;
+  Vec2 operator-() const;
+  void operator+=(Vec2 b);
+  bool operator==(const Vec2 &b) const;
+};
+
+extern "C" {
+
+const uint8_t *buffer_index(const Buffer *buffer, uintptr_t index);
+
+Vec2 vec2_add(Vec2 a, Vec2 b);
+
+void vec2_add_assign(Vec2 *a, Vec2 b);
+
+bool vec2_eq(const Vec2 *a, const Vec2 *b);
+
+Vec2 vec2_neg(Vec2 a);
+
+Vec2 vec2_sub(Vec2 a, Vec2 b);
+
+} // extern "C"
+
+inline const uint8_t &Buffer::operator[](uintptr_t index) const {
+  return *buffer_index(this, index);
+}
+
+inline Vec2 Vec2::operator+(Vec2 b) const {
+  return vec2_add(*this, b);
+}
+
+inline Vec2 Vec2::operator-() const {
+  return vec2_neg(*this);
+}
+
+inline void Vec2::operator+=(Vec2 b) {
+  vec2_add_assign(this, b);
+}
+
+inline bool Vec2::operator==(const Vec2 &b) const {
+  return vec2_eq(this, &b);
+}
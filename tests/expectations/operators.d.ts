@@ -0,0 +1,33 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Vec2 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Vec2: StructType<Vec2>;
+
+export interface Library {
+  buffer_index(buffer: Buffer | null, index: number | string): Buffer;
+
+  vec2_add(a: Vec2, b: Vec2): Vec2;
+
+  vec2_add_assign(a: Buffer | null, b: Vec2): void;
+
+  vec2_eq(a: Buffer | null, b: Buffer | null): boolean;
+
+  vec2_neg(a: Vec2): Vec2;
+
+  vec2_sub(a: Vec2, b: Vec2): Vec2;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,34 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Buffer = C.Buffer
+
+type Vec2 = C.Vec2
+
+func BufferIndex(buffer *Buffer, index uintptr) *uint8 {
+  return (*uint8)(unsafe.Pointer(C.buffer_index(buffer, C.uintptr_t(index))))
+}
+
+func Vec2Add(a Vec2, b Vec2) Vec2 {
+  return C.vec2_add(a, b)
+}
+
+func Vec2AddAssign(a *Vec2, b Vec2) {
+  C.vec2_add_assign(a, b)
+}
+
+func Vec2Eq(a *Vec2, b *Vec2) bool {
+  return bool(C.vec2_eq(a, b))
+}
+
+func Vec2Neg(a Vec2) Vec2 {
+  return C.vec2_neg(a)
+}
+
+func Vec2Sub(a Vec2, b Vec2) Vec2 {
+  return C.vec2_sub(a, b)
+}
@@ -0,0 +1,35 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Vec2 extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Vec2 implements Structure.ByReference {}
+
+    public static class ByValue extends Vec2 implements Structure.ByValue {}
+  }
+
+  Pointer buffer_index(Pointer buffer, SizeT index);
+
+  Vec2.ByValue vec2_add(Vec2.ByValue a, Vec2.ByValue b);
+
+  void vec2_add_assign(Vec2.ByReference a, Vec2.ByValue b);
+
+  byte vec2_eq(Vec2.ByReference a, Vec2.ByReference b);
+
+  Vec2.ByValue vec2_neg(Vec2.ByValue a);
+
+  Vec2.ByValue vec2_sub(Vec2.ByValue a, Vec2.ByValue b);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Vec2 = StructType();
+
+Vec2.defineProperty('x', 'float');
+Vec2.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    buffer_index: [ref.refType('uint8'), ['pointer', 'size_t']],
+    vec2_add: [Vec2, [Vec2, Vec2]],
+    vec2_add_assign: ['void', [ref.refType(Vec2), Vec2]],
+    vec2_eq: ['bool', [ref.refType(Vec2), ref.refType(Vec2)]],
+    vec2_neg: [Vec2, [Vec2]],
+    vec2_sub: [Vec2, [Vec2, Vec2]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Vec2,
+  load,
+};
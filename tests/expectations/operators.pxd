@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Buffer:
+    pass
+
+  ctypedef struct Vec2:
+    float x
+    float y
+
+  const uint8_t *buffer_index(const Buffer *buffer, uintptr_t index)
+
+  Vec2 vec2_add(Vec2 a, Vec2 b)
+
+  void vec2_add_assign(Vec2 *a, Vec2 b)
+
+  bool vec2_eq(const Vec2 *a, const Vec2 *b)
+
+  Vec2 vec2_neg(Vec2 a)
+
+  Vec2 vec2_sub(Vec2 a, Vec2 b)
@@ -0,0 +1,35 @@
+import ctypes
+
+class Buffer(ctypes.Structure):
+  pass
+
+class Vec2(ctypes.Structure):
+  pass
+
+Vec2._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.buffer_index.argtypes = [ctypes.POINTER(Buffer), ctypes.c_size_t]
+  lib.buffer_index.restype = ctypes.POINTER(ctypes.c_uint8)
+
+  lib.vec2_add.argtypes = [Vec2, Vec2]
+  lib.vec2_add.restype = Vec2
+
+  lib.vec2_add_assign.argtypes = [ctypes.POINTER(Vec2), Vec2]
+  lib.vec2_add_assign.restype = None
+
+  lib.vec2_eq.argtypes = [ctypes.POINTER(Vec2), ctypes.POINTER(Vec2)]
+  lib.vec2_eq.restype = ctypes.c_bool
+
+  lib.vec2_neg.argtypes = [Vec2]
+  lib.vec2_neg.restype = Vec2
+
+  lib.vec2_sub.argtypes = [Vec2, Vec2]
+  lib.vec2_sub.restype = Vec2
+
+  return lib
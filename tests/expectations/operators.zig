@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const Buffer = opaque {};
+
+pub const Vec2 = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn buffer_index(buffer: ?*const Buffer, index: usize) [*c]const u8;
+
+pub extern fn vec2_add(a: Vec2, b: Vec2) Vec2;
+
+pub extern fn vec2_add_assign(a: [*c]Vec2, b: Vec2) void;
+
+pub extern fn vec2_eq(a: [*c]const Vec2, b: [*c]const Vec2) bool;
+
+pub extern fn vec2_neg(a: Vec2) Vec2;
+
+pub extern fn vec2_sub(a: Vec2, b: Vec2) Vec2;
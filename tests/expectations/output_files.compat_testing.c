@@ -0,0 +1,26 @@
+#include "output_files.compat.c"
+#include "output_files.compat_internal.c"
+
+#ifndef OUTPUT_FILES_H_TESTING
+#define OUTPUT_FILES_H_TESTING
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  InternalState state;
+} Probe;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Probe test_probe(Handle handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* OUTPUT_FILES_H_TESTING */
@@ -0,0 +1,22 @@
+#ifndef OUTPUT_FILES_H
+#define OUTPUT_FILES_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Handle {
+  uint32_t id;
+};
+
+extern "C" {
+
+Handle handle_new();
+
+} // extern "C"
+
+#include "output_files_internal.cpp"
+#include "output_files_testing.cpp"
+
+#endif // OUTPUT_FILES_H
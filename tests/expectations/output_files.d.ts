@@ -0,0 +1,39 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Handle {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface InternalState {
+  handle: Handle;
+  refs: number;
+  ref(): Buffer;
+}
+export declare const InternalState: StructType<InternalState>;
+
+export interface Probe {
+  state: InternalState;
+  ref(): Buffer;
+}
+export declare const Probe: StructType<Probe>;
+
+export interface Library {
+  handle_new(): Handle;
+
+  internal_state(handle: Handle): InternalState;
+
+  test_probe(handle: Handle): Probe;
+}
+
+export declare function load(path: string): Library;
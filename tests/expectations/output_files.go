@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+type InternalState = C.InternalState
+
+type Probe = C.Probe
+
+func HandleNew() Handle {
+  return C.handle_new()
+}
+
+func TestProbe(handle Handle) Probe {
+  return C.test_probe(handle)
+}
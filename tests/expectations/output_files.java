@@ -0,0 +1,37 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"id"})
+  class Handle extends Structure {
+    public int id;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"handle", "refs"})
+  class InternalState extends Structure {
+    public Handle handle;
+    public int refs;
+
+    public static class ByReference extends InternalState implements Structure.ByReference {}
+
+    public static class ByValue extends InternalState implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"state"})
+  class Probe extends Structure {
+    public InternalState state;
+
+    public static class ByReference extends Probe implements Structure.ByReference {}
+
+    public static class ByValue extends Probe implements Structure.ByValue {}
+  }
+
+  Handle.ByValue handle_new();
+
+  InternalState.ByValue internal_state(Handle.ByValue handle);
+
+  Probe.ByValue test_probe(Handle.ByValue handle);
+}
@@ -0,0 +1,35 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const InternalState = StructType();
+const Probe = StructType();
+
+Handle.defineProperty('id', 'uint32');
+
+InternalState.defineProperty('handle', Handle);
+InternalState.defineProperty('refs', 'uint32');
+
+Probe.defineProperty('state', InternalState);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    handle_new: [Handle, []],
+    internal_state: [InternalState, [Handle]],
+    test_probe: [Probe, [Handle]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  InternalState,
+  Probe,
+  load,
+};
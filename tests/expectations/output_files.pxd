@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Handle:
+    uint32_t id
+
+  ctypedef struct InternalState:
+    Handle handle
+    uint32_t refs
+
+  ctypedef struct Probe:
+    InternalState state
+
+  Handle handle_new()
+
+  InternalState internal_state(Handle handle)
+
+  Probe test_probe(Handle handle)
@@ -0,0 +1,37 @@
+import ctypes
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+class InternalState(ctypes.Structure):
+  pass
+
+InternalState._fields_ = [
+  ("handle", Handle),
+  ("refs", ctypes.c_uint32),
+]
+
+class Probe(ctypes.Structure):
+  pass
+
+Probe._fields_ = [
+  ("state", InternalState),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.handle_new.argtypes = []
+  lib.handle_new.restype = Handle
+
+  lib.internal_state.argtypes = [Handle]
+  lib.internal_state.restype = InternalState
+
+  lib.test_probe.argtypes = [Handle]
+  lib.test_probe.restype = Probe
+
+  return lib
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+pub const InternalState = extern struct {
+  handle: Handle,
+  refs: u32,
+};
+
+pub const Probe = extern struct {
+  state: InternalState,
+};
+
+pub extern fn handle_new() Handle;
+
+pub extern fn internal_state(handle: Handle) InternalState;
+
+pub extern fn test_probe(handle: Handle) Probe;
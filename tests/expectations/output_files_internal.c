@@ -0,0 +1,18 @@
+#include "output_files.c"
+
+#ifndef OUTPUT_FILES_H_INTERNAL
+#define OUTPUT_FILES_H_INTERNAL
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  Handle handle;
+  uint32_t refs;
+} InternalState;
+
+InternalState internal_state(Handle handle);
+
+#endif /* OUTPUT_FILES_H_INTERNAL */
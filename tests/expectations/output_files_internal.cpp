@@ -0,0 +1,22 @@
+#include "output_files.cpp"
+
+#ifndef OUTPUT_FILES_H_INTERNAL
+#define OUTPUT_FILES_H_INTERNAL
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct InternalState {
+  Handle handle;
+  uint32_t refs;
+};
+
+extern "C" {
+
+InternalState internal_state(Handle handle);
+
+} // extern "C"
+
+#endif // OUTPUT_FILES_H_INTERNAL
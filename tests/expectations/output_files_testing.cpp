@@ -0,0 +1,22 @@
+#include "output_files.cpp"
+#include "output_files_internal.cpp"
+
+#ifndef OUTPUT_FILES_H_TESTING
+#define OUTPUT_FILES_H_TESTING
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Probe {
+  InternalState state;
+};
+
+extern "C" {
+
+Probe test_probe(Handle handle);
+
+} // extern "C"
+
+#endif // OUTPUT_FILES_H_TESTING
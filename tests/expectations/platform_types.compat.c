@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+typedef struct {
+  int64_t size;
+  uint64_t blocks;
+  size_t len;
+  ptrdiff_t offset;
+} Stat;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(int64_t) == sizeof(long), "unexpected size of c_long");
+CBINDGEN_STATIC_ASSERT(sizeof(uint64_t) == sizeof(unsigned long), "unexpected size of c_ulong");
+CBINDGEN_STATIC_ASSERT(sizeof(size_t) == sizeof(uintptr_t), "unexpected size of usize");
+CBINDGEN_STATIC_ASSERT(sizeof(ptrdiff_t) == sizeof(intptr_t), "unexpected size of isize");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+size_t stat_len(const Stat *stat);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
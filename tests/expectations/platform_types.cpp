@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <stddef.h>
+
+struct Stat {
+  int64_t size;
+  uint64_t blocks;
+  size_t len;
+  ptrdiff_t offset;
+};
+
+static_assert(sizeof(int64_t) == sizeof(long), "unexpected size of c_long");
+static_assert(sizeof(uint64_t) == sizeof(unsigned long), "unexpected size of c_ulong");
+static_assert(sizeof(size_t) == sizeof(uintptr_t), "unexpected size of usize");
+static_assert(sizeof(ptrdiff_t) == sizeof(intptr_t), "unexpected size of isize");
+
+extern "C" {
+
+size_t stat_len(const Stat *stat);
+
+} // extern "C"
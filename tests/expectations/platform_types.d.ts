@@ -0,0 +1,25 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Stat {
+  size: number | string;
+  blocks: number | string;
+  len: number | string;
+  offset: number | string;
+  ref(): Buffer;
+}
+export declare const Stat: StructType<Stat>;
+
+export interface Library {
+  stat_len(stat: Buffer | null): number | string;
+}
+
+export declare function load(path: string): Library;
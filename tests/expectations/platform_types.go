@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Stat = C.Stat
+
+func StatLen(stat *Stat) uintptr {
+  return uintptr(C.stat_len(stat))
+}
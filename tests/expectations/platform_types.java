@@ -0,0 +1,37 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"size", "blocks", "len", "offset"})
+  class Stat extends Structure {
+    public NativeLong size;
+    public NativeLong blocks;
+    public SizeT len;
+    public SSizeT offset;
+
+    public static class ByReference extends Stat implements Structure.ByReference {}
+
+    public static class ByValue extends Stat implements Structure.ByValue {}
+  }
+
+  SizeT stat_len(Stat.ByReference stat);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+
+  class SSizeT extends IntegerType {
+    public SSizeT() {
+      this(0);
+    }
+
+    public SSizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, false);
+    }
+  }
+}
@@ -0,0 +1,29 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const ssize_t = ref.sizeof.size_t === 8 ? 'int64' : 'int32';
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Stat = StructType();
+
+Stat.defineProperty('size', 'long');
+Stat.defineProperty('blocks', 'ulong');
+Stat.defineProperty('len', 'size_t');
+Stat.defineProperty('offset', ssize_t);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    stat_len: ['size_t', [ref.refType(Stat)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Stat,
+  load,
+};
@@ -0,0 +1,19 @@
+import ctypes
+
+class Stat(ctypes.Structure):
+  pass
+
+Stat._fields_ = [
+  ("size", ctypes.c_long),
+  ("blocks", ctypes.c_ulong),
+  ("len", ctypes.c_size_t),
+  ("offset", ctypes.c_ssize_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.stat_len.argtypes = [ctypes.POINTER(Stat)]
+  lib.stat_len.restype = ctypes.c_size_t
+
+  return lib
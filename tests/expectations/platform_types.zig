@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const Stat = extern struct {
+  size: c_long,
+  blocks: c_ulong,
+  len: usize,
+  offset: isize,
+};
+
+pub extern fn stat_len(stat: [*c]const Stat) usize;
@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t a;
+} Foo;
+
+typedef struct {
+  /**
+   * Wrapped in `Pin`.
+   */
+  Foo *pinned;
+  /**
+   * Wrapped in `NonNull`.
+   */
+  Foo *non_null;
+  /**
+   * Wrapped in `Unique`.
+   */
+  Foo *unique;
+  Foo *maybe;
+} Handles;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * `foo`: Wrapped in `Pin`.
+ * `handles`: Wrapped in `Pin`.
+ */
+void poll(Foo *foo, const Handles *handles);
+
+/**
+ * `foo`: Wrapped in `Unique`.
+ * Return value: Wrapped in `NonNull`.
+ */
+Foo *take(Foo *foo);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
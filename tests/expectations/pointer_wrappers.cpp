@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Foo {
+  int32_t a;
+};
+
+struct Handles {
+  /// Wrapped in `Pin`.
+  Foo *pinned;
+  /// Wrapped in `NonNull`.
+  Foo *non_null;
+  /// Wrapped in `Unique`.
+  Foo *unique;
+  Foo *maybe;
+};
+
+extern "C" {
+
+/// `foo`: Wrapped in `Pin`.
+/// `handles`: Wrapped in `Pin`.
+void poll(Foo *foo, const Handles *handles);
+
+/// `foo`: Wrapped in `Unique`.
+/// Return value: Wrapped in `NonNull`.
+Foo *take(Foo *foo);
+
+} // extern "C"
@@ -0,0 +1,41 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo {
+  a: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Handles {
+  pinned: Buffer;
+  non_null: Buffer;
+  unique: Buffer;
+  maybe: Buffer;
+  ref(): Buffer;
+}
+export declare const Handles: StructType<Handles>;
+
+export interface Library {
+  /**
+   * `foo`: Wrapped in `Pin`.
+   * `handles`: Wrapped in `Pin`.
+   */
+  poll(foo: Buffer | null, handles: Buffer | null): void;
+
+  /**
+   * `foo`: Wrapped in `Unique`.
+   * Return value: Wrapped in `NonNull`.
+   */
+  take(foo: Buffer | null): Buffer;
+}
+
+export declare function load(path: string): Library;
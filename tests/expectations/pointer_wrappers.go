@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo = C.Foo
+
+type Handles = C.Handles
+
+// `foo`: Wrapped in `Pin`.
+// `handles`: Wrapped in `Pin`.
+func Poll(foo *Foo, handles *Handles) {
+  C.poll(foo, handles)
+}
+
+// `foo`: Wrapped in `Unique`.
+// Return value: Wrapped in `NonNull`.
+func Take(foo *Foo) *Foo {
+  return C.take(foo)
+}
@@ -0,0 +1,45 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a"})
+  class Foo extends Structure {
+    public int a;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"pinned", "non_null", "unique", "maybe"})
+  class Handles extends Structure {
+    /**
+     * Wrapped in `Pin`.
+     */
+    public Foo.ByReference pinned;
+    /**
+     * Wrapped in `NonNull`.
+     */
+    public Foo.ByReference non_null;
+    /**
+     * Wrapped in `Unique`.
+     */
+    public Foo.ByReference unique;
+    public Foo.ByReference maybe;
+
+    public static class ByReference extends Handles implements Structure.ByReference {}
+
+    public static class ByValue extends Handles implements Structure.ByValue {}
+  }
+
+  /**
+   * `foo`: Wrapped in `Pin`.
+   * `handles`: Wrapped in `Pin`.
+   */
+  void poll(Foo.ByReference foo, Handles.ByReference handles);
+
+  /**
+   * `foo`: Wrapped in `Unique`.
+   * Return value: Wrapped in `NonNull`.
+   */
+  Foo.ByReference take(Foo.ByReference foo);
+}
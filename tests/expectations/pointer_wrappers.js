@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+const Handles = StructType();
+
+Foo.defineProperty('a', 'int32');
+
+Handles.defineProperty('pinned', ref.refType(Foo));
+Handles.defineProperty('non_null', ref.refType(Foo));
+Handles.defineProperty('unique', ref.refType(Foo));
+Handles.defineProperty('maybe', ref.refType(Foo));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    poll: ['void', [ref.refType(Foo), ref.refType(Handles)]],
+    take: [ref.refType(Foo), [ref.refType(Foo)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  Handles,
+  load,
+};
@@ -0,0 +1,40 @@
+/** The signatures of the functions the module exports. */
+export const functions = {
+  /**
+   * `foo`: Wrapped in `Pin`.
+   * `handles`: Wrapped in `Pin`.
+   */
+  poll: {
+    params: ["i32", "i32"],
+    results: [],
+  },
+  /**
+   * `foo`: Wrapped in `Unique`.
+   * Return value: Wrapped in `NonNull`.
+   */
+  take: {
+    params: ["i32"],
+    results: ["i32"],
+  },
+};
+
+/** The signatures of the functions the module imports. */
+export const imports = {};
+
+/** The statics the module exports, as globals holding their address. */
+export const globals = [];
+
+/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */
+export function view(memory, type, ptr, len) {
+  return new type(memory.buffer, ptr, len);
+}
+
+/**
+ * Returns views of the slices that `args`, the arguments of a call to a
+ * function with `signature`, point to in `memory`.
+ */
+export function slices(memory, signature, args) {
+  return (signature.slices || []).map((slice) =>
+    view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),
+  );
+}
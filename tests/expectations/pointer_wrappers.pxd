@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Foo:
+    int32_t a
+
+  ctypedef struct Handles:
+    # Wrapped in `Pin`.
+    Foo *pinned
+    # Wrapped in `NonNull`.
+    Foo *non_null
+    # Wrapped in `Unique`.
+    Foo *unique
+    Foo *maybe
+
+  # `foo`: Wrapped in `Pin`.
+  # `handles`: Wrapped in `Pin`.
+  void poll(Foo *foo, const Handles *handles)
+
+  # `foo`: Wrapped in `Unique`.
+  # Return value: Wrapped in `NonNull`.
+  Foo *take(Foo *foo)
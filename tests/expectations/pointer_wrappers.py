@@ -0,0 +1,36 @@
+import ctypes
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("a", ctypes.c_int32),
+]
+
+class Handles(ctypes.Structure):
+  pass
+
+Handles._fields_ = [
+  # Wrapped in `Pin`.
+  ("pinned", ctypes.POINTER(Foo)),
+  # Wrapped in `NonNull`.
+  ("non_null", ctypes.POINTER(Foo)),
+  # Wrapped in `Unique`.
+  ("unique", ctypes.POINTER(Foo)),
+  ("maybe", ctypes.POINTER(Foo)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # `foo`: Wrapped in `Pin`.
+  # `handles`: Wrapped in `Pin`.
+  lib.poll.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(Handles)]
+  lib.poll.restype = None
+
+  # `foo`: Wrapped in `Unique`.
+  # Return value: Wrapped in `NonNull`.
+  lib.take.argtypes = [ctypes.POINTER(Foo)]
+  lib.take.restype = ctypes.POINTER(Foo)
+
+  return lib
@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const Foo = extern struct {
+  a: i32,
+};
+
+pub const Handles = extern struct {
+  /// Wrapped in `Pin`.
+  pinned: *Foo,
+  /// Wrapped in `NonNull`.
+  non_null: *Foo,
+  /// Wrapped in `Unique`.
+  unique: *Foo,
+  maybe: [*c]Foo,
+};
+
+/// `foo`: Wrapped in `Pin`.
+/// `handles`: Wrapped in `Pin`.
+pub extern fn poll(foo: *Foo, handles: *const Handles) void;
+
+/// `foo`: Wrapped in `Unique`.
+/// Return value: Wrapped in `NonNull`.
+pub extern fn take(foo: *Foo) *Foo;
@@ -0,0 +1,44 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const PREFIX_LEN: number;
+
+export declare const PREFIX_AbsoluteFontWeight_Tag: {
+  readonly Weight: 0;
+  readonly Normal: 1;
+  readonly Bold: 2;
+};
+
+export interface PREFIX_Weight_Body {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const PREFIX_Weight_Body: StructType<PREFIX_Weight_Body>;
+
+export interface PREFIX_AbsoluteFontWeight {
+  tag: number;
+  weight: PREFIX_Weight_Body;
+  ref(): Buffer;
+}
+export declare const PREFIX_AbsoluteFontWeight: StructType<PREFIX_AbsoluteFontWeight>;
+
+export type PREFIX_NamedLenArray = ArrayLike<number>;
+export declare const PREFIX_NamedLenArray: RefType;
+
+export type PREFIX_ValuedLenArray = ArrayLike<number>;
+export declare const PREFIX_ValuedLenArray: RefType;
+
+export interface Library {
+  root(x: Buffer | null, y: Buffer | null, z: PREFIX_AbsoluteFontWeight): void;
+}
+
+export declare function load(path: string): Library;
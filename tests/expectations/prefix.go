@@ -0,0 +1,26 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PREFIX_NamedLenArray = C.PREFIX_NamedLenArray
+
+type PREFIX_ValuedLenArray = C.PREFIX_ValuedLenArray
+
+type PREFIX_AbsoluteFontWeight_Tag = C.PREFIX_AbsoluteFontWeight_Tag
+
+const (
+  Weight PREFIX_AbsoluteFontWeight_Tag = C.Weight
+  Normal PREFIX_AbsoluteFontWeight_Tag = C.Normal
+  Bold PREFIX_AbsoluteFontWeight_Tag = C.Bold
+)
+
+type PREFIX_Weight_Body = C.PREFIX_Weight_Body
+
+type PREFIX_AbsoluteFontWeight = C.PREFIX_AbsoluteFontWeight
+
+const PREFIX_LEN = C.PREFIX_LEN
+
+func Root(x PREFIX_NamedLenArray, y PREFIX_ValuedLenArray, z PREFIX_AbsoluteFontWeight) {
+  C.root(x, y, z)
+}
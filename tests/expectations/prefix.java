@@ -0,0 +1,32 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int PREFIX_LEN = 42;
+
+  interface PREFIX_AbsoluteFontWeight_Tag {
+    byte Weight = 0;
+    byte Normal = 1;
+    byte Bold = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class PREFIX_Weight_Body extends Structure {
+    public byte tag;
+    public float _0;
+
+    public static class ByReference extends PREFIX_Weight_Body implements Structure.ByReference {}
+
+    public static class ByValue extends PREFIX_Weight_Body implements Structure.ByValue {}
+  }
+
+  class PREFIX_AbsoluteFontWeight extends Union {
+    public byte tag;
+    public PREFIX_Weight_Body weight;
+
+    public static class ByReference extends PREFIX_AbsoluteFontWeight implements Structure.ByReference {}
+
+    public static class ByValue extends PREFIX_AbsoluteFontWeight implements Structure.ByValue {}
+  }
+
+  void root(Pointer x, Pointer y, PREFIX_AbsoluteFontWeight.ByValue z);
+}
@@ -0,0 +1,46 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const PREFIX_Weight_Body = StructType();
+const PREFIX_AbsoluteFontWeight = UnionType();
+
+const PREFIX_LEN = 42;
+
+PREFIX_Weight_Body.defineProperty('tag', 'uint8');
+PREFIX_Weight_Body.defineProperty('_0', 'float');
+
+PREFIX_AbsoluteFontWeight.defineProperty('tag', 'uint8');
+PREFIX_AbsoluteFontWeight.defineProperty('weight', PREFIX_Weight_Body);
+
+const PREFIX_AbsoluteFontWeight_Tag = Object.freeze({
+  Weight: 0,
+  Normal: 1,
+  Bold: 2,
+});
+
+const PREFIX_NamedLenArray = ArrayType('int32', PREFIX_LEN);
+
+const PREFIX_ValuedLenArray = ArrayType('int32', 42);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [ref.refType('int32'), ref.refType('int32'), PREFIX_AbsoluteFontWeight]],
+  });
+  return lib;
+}
+
+module.exports = {
+  PREFIX_Weight_Body,
+  PREFIX_AbsoluteFontWeight,
+  PREFIX_AbsoluteFontWeight_Tag,
+  PREFIX_NamedLenArray,
+  PREFIX_ValuedLenArray,
+  PREFIX_LEN,
+  load,
+};
@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const int32_t PREFIX_LEN
+
+  ctypedef int32_t PREFIX_NamedLenArray[PREFIX_LEN]
+
+  ctypedef int32_t PREFIX_ValuedLenArray[42]
+
+  enum:
+    Weight
+    Normal
+    Bold
+  ctypedef uint8_t PREFIX_AbsoluteFontWeight_Tag
+
+  ctypedef struct PREFIX_Weight_Body:
+    PREFIX_AbsoluteFontWeight_Tag tag
+    float _0
+
+  ctypedef union PREFIX_AbsoluteFontWeight:
+    PREFIX_AbsoluteFontWeight_Tag tag
+    PREFIX_Weight_Body weight
+
+  void root(PREFIX_NamedLenArray x, PREFIX_ValuedLenArray y, PREFIX_AbsoluteFontWeight z)
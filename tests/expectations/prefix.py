@@ -0,0 +1,36 @@
+import ctypes
+
+PREFIX_LEN = 42
+
+PREFIX_NamedLenArray = (ctypes.c_int32 * PREFIX_LEN)
+
+PREFIX_ValuedLenArray = (ctypes.c_int32 * 42)
+
+PREFIX_AbsoluteFontWeight_Tag = ctypes.c_uint8
+Weight = 0
+Normal = 1
+Bold = 2
+
+class PREFIX_Weight_Body(ctypes.Structure):
+  pass
+
+PREFIX_Weight_Body._fields_ = [
+  ("tag", PREFIX_AbsoluteFontWeight_Tag),
+  ("_0", ctypes.c_float),
+]
+
+class PREFIX_AbsoluteFontWeight(ctypes.Union):
+  pass
+
+PREFIX_AbsoluteFontWeight._fields_ = [
+  ("tag", PREFIX_AbsoluteFontWeight_Tag),
+  ("weight", PREFIX_Weight_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIX_NamedLenArray, PREFIX_ValuedLenArray, PREFIX_AbsoluteFontWeight]
+  lib.root.restype = None
+
+  return lib
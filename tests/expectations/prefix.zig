@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const PREFIX_LEN: i32 = 42;
+
+pub const PREFIX_NamedLenArray = [@intCast(PREFIX_LEN)]i32;
+
+pub const PREFIX_ValuedLenArray = [42]i32;
+
+pub const PREFIX_AbsoluteFontWeight_Tag = enum(u8) {
+  Weight,
+  Normal,
+  Bold,
+};
+
+pub const PREFIX_Weight_Body = extern struct {
+  tag: PREFIX_AbsoluteFontWeight_Tag,
+  _0: f32,
+};
+
+pub const PREFIX_AbsoluteFontWeight = extern union {
+  tag: PREFIX_AbsoluteFontWeight_Tag,
+  weight: PREFIX_Weight_Body,
+};
+
+pub extern fn root(x: PREFIX_NamedLenArray, y: PREFIX_ValuedLenArray, z: PREFIX_AbsoluteFontWeight) void;
@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PREFIXFoo = C.PREFIXFoo
+
+func Root(x PREFIXFoo) {
+  C.root(x)
+}
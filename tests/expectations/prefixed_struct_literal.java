@@ -0,0 +1,15 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a", "b"})
+  class PREFIXFoo extends Structure {
+    public int a;
+    public int b;
+
+    public static class ByReference extends PREFIXFoo implements Structure.ByReference {}
+
+    public static class ByValue extends PREFIXFoo implements Structure.ByValue {}
+  }
+
+  void root(PREFIXFoo.ByValue x);
+}
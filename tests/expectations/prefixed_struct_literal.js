@@ -0,0 +1,31 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const PREFIXFoo = StructType();
+
+PREFIXFoo.defineProperty('a', 'int32');
+PREFIXFoo.defineProperty('b', 'uint32');
+
+const PREFIXFoo_FOO = PREFIXFoo({ a: 42, b: 47 });
+
+const PREFIXBAR = PREFIXFoo({ a: 42, b: 1337 });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [PREFIXFoo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  PREFIXFoo,
+  PREFIXFoo_FOO,
+  PREFIXBAR,
+  load,
+};
@@ -0,0 +1,20 @@
+import ctypes
+
+class PREFIXFoo(ctypes.Structure):
+  pass
+
+PREFIXFoo._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_uint32),
+]
+PREFIXFoo_FOO = PREFIXFoo(a=42, b=47)
+
+PREFIXBAR = PREFIXFoo(a=42, b=1337)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIXFoo]
+  lib.root.restype = None
+
+  return lib
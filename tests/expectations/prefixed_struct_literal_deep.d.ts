@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const PREFIXVAL: PREFIXFoo;
+
+export interface PREFIXBar {
+  a: number;
+  ref(): Buffer;
+}
+export declare const PREFIXBar: StructType<PREFIXBar>;
+
+export interface PREFIXFoo {
+  a: number;
+  b: number;
+  bar: PREFIXBar;
+  ref(): Buffer;
+}
+export declare const PREFIXFoo: StructType<PREFIXFoo>;
+
+export interface Library {
+  root(x: PREFIXFoo): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PREFIXBar = C.PREFIXBar
+
+type PREFIXFoo = C.PREFIXFoo
+
+func Root(x PREFIXFoo) {
+  C.root(x)
+}
@@ -0,0 +1,25 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a"})
+  class PREFIXBar extends Structure {
+    public int a;
+
+    public static class ByReference extends PREFIXBar implements Structure.ByReference {}
+
+    public static class ByValue extends PREFIXBar implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"a", "b", "bar"})
+  class PREFIXFoo extends Structure {
+    public int a;
+    public int b;
+    public PREFIXBar bar;
+
+    public static class ByReference extends PREFIXFoo implements Structure.ByReference {}
+
+    public static class ByValue extends PREFIXFoo implements Structure.ByValue {}
+  }
+
+  void root(PREFIXFoo.ByValue x);
+}
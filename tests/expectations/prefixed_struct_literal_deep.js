@@ -0,0 +1,33 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const PREFIXBar = StructType();
+const PREFIXFoo = StructType();
+
+PREFIXBar.defineProperty('a', 'int32');
+
+PREFIXFoo.defineProperty('a', 'int32');
+PREFIXFoo.defineProperty('b', 'uint32');
+PREFIXFoo.defineProperty('bar', PREFIXBar);
+
+const PREFIXVAL = PREFIXFoo({ a: 42, b: 1337, bar: PREFIXBar({ a: 323 }) });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [PREFIXFoo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  PREFIXBar,
+  PREFIXFoo,
+  PREFIXVAL,
+  load,
+};
@@ -0,0 +1,27 @@
+import ctypes
+
+class PREFIXBar(ctypes.Structure):
+  pass
+
+PREFIXBar._fields_ = [
+  ("a", ctypes.c_int32),
+]
+
+class PREFIXFoo(ctypes.Structure):
+  pass
+
+PREFIXFoo._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_uint32),
+  ("bar", PREFIXBar),
+]
+
+PREFIXVAL = PREFIXFoo(a=42, b=1337, bar=PREFIXBar(a=323))
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIXFoo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const PREFIXVAL: PREFIXFoo = PREFIXFoo{ .a = 42, .b = 1337, .bar = PREFIXBar{ .a = 323 } };
+
+pub const PREFIXBar = extern struct {
+  a: i32,
+};
+
+pub const PREFIXFoo = extern struct {
+  a: i32,
+  b: u32,
+  bar: PREFIXBar,
+};
+
+pub extern fn root(x: PREFIXFoo) void;
@@ -0,0 +1,46 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+// cfg(all(not(target_os = "freebsd")))
+export interface NoExternTy {
+  field: number;
+  ref(): Buffer;
+}
+export declare const NoExternTy: StructType<NoExternTy>;
+
+// cfg(not(target_os = "freebsd"))
+export interface ContainsNoExternTy {
+  field: NoExternTy;
+  ref(): Buffer;
+}
+export declare const ContainsNoExternTy: StructType<ContainsNoExternTy>;
+
+export interface RenamedTy {
+  y: number | string;
+  ref(): Buffer;
+}
+export declare const RenamedTy: StructType<RenamedTy>;
+
+export interface Foo {
+  x: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  no_extern_func(a: ContainsNoExternTy): void;
+
+  renamed_func(a: RenamedTy): void;
+
+  root(a: Foo): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,29 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// cfg(all(not(target_os = "freebsd")))
+type NoExternTy = C.NoExternTy
+
+// cfg(not(target_os = "freebsd"))
+type ContainsNoExternTy = C.ContainsNoExternTy
+
+// cfg(target_os = "freebsd")
+type ContainsNoExternTy = C.ContainsNoExternTy
+
+type RenamedTy = C.RenamedTy
+
+type Foo = C.Foo
+
+func NoExternFunc(a ContainsNoExternTy) {
+  C.no_extern_func(a)
+}
+
+func RenamedFunc(a RenamedTy) {
+  C.renamed_func(a)
+}
+
+func Root(a Foo) {
+  C.root(a)
+}
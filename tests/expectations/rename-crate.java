@@ -0,0 +1,47 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  // cfg(all(not(target_os = "freebsd")))
+  @Structure.FieldOrder({"field"})
+  class NoExternTy extends Structure {
+    public byte field;
+
+    public static class ByReference extends NoExternTy implements Structure.ByReference {}
+
+    public static class ByValue extends NoExternTy implements Structure.ByValue {}
+  }
+
+  // cfg(not(target_os = "freebsd"))
+  @Structure.FieldOrder({"field"})
+  class ContainsNoExternTy extends Structure {
+    public NoExternTy field;
+
+    public static class ByReference extends ContainsNoExternTy implements Structure.ByReference {}
+
+    public static class ByValue extends ContainsNoExternTy implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"y"})
+  class RenamedTy extends Structure {
+    public long y;
+
+    public static class ByReference extends RenamedTy implements Structure.ByReference {}
+
+    public static class ByValue extends RenamedTy implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x"})
+  class Foo extends Structure {
+    public int x;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void no_extern_func(ContainsNoExternTy.ByValue a);
+
+  void renamed_func(RenamedTy.ByValue a);
+
+  void root(Foo.ByValue a);
+}
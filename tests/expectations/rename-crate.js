@@ -0,0 +1,40 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const NoExternTy = StructType();
+const ContainsNoExternTy = StructType();
+const RenamedTy = StructType();
+const Foo = StructType();
+
+// cfg(all(not(target_os = "freebsd")))
+NoExternTy.defineProperty('field', 'uint8');
+
+// cfg(not(target_os = "freebsd"))
+ContainsNoExternTy.defineProperty('field', NoExternTy);
+
+RenamedTy.defineProperty('y', 'uint64');
+
+Foo.defineProperty('x', 'int32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    no_extern_func: ['void', [ContainsNoExternTy]],
+    renamed_func: ['void', [RenamedTy]],
+    root: ['void', [Foo]],
+  });
+  return lib;
+}
+
+module.exports = {
+  NoExternTy,
+  ContainsNoExternTy,
+  RenamedTy,
+  Foo,
+  load,
+};
@@ -0,0 +1,32 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # cfg(all(not(target_os = "freebsd")))
+  ctypedef struct NoExternTy:
+    uint8_t field
+
+  # cfg(not(target_os = "freebsd"))
+  ctypedef struct ContainsNoExternTy:
+    NoExternTy field
+
+  # cfg(target_os = "freebsd")
+  ctypedef struct ContainsNoExternTy:
+    uint64_t field
+
+  ctypedef struct RenamedTy:
+    uint64_t y
+
+  ctypedef struct Foo:
+    int32_t x
+
+  void no_extern_func(ContainsNoExternTy a)
+
+  void renamed_func(RenamedTy a)
+
+  void root(Foo a)
@@ -0,0 +1,53 @@
+import ctypes
+
+# cfg(all(not(target_os = "freebsd")))
+class NoExternTy(ctypes.Structure):
+  pass
+
+NoExternTy._fields_ = [
+  ("field", ctypes.c_uint8),
+]
+
+# cfg(not(target_os = "freebsd"))
+class ContainsNoExternTy(ctypes.Structure):
+  pass
+
+ContainsNoExternTy._fields_ = [
+  ("field", NoExternTy),
+]
+
+# cfg(target_os = "freebsd")
+class ContainsNoExternTy(ctypes.Structure):
+  pass
+
+ContainsNoExternTy._fields_ = [
+  ("field", ctypes.c_uint64),
+]
+
+class RenamedTy(ctypes.Structure):
+  pass
+
+RenamedTy._fields_ = [
+  ("y", ctypes.c_uint64),
+]
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.no_extern_func.argtypes = [ContainsNoExternTy]
+  lib.no_extern_func.restype = None
+
+  lib.renamed_func.argtypes = [RenamedTy]
+  lib.renamed_func.restype = None
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,30 @@
+const std = @import("std");
+
+// cfg(all(not(target_os = "freebsd")))
+pub const NoExternTy = extern struct {
+  field: u8,
+};
+
+// cfg(not(target_os = "freebsd"))
+pub const ContainsNoExternTy = extern struct {
+  field: NoExternTy,
+};
+
+// cfg(target_os = "freebsd")
+pub const ContainsNoExternTy = extern struct {
+  field: u64,
+};
+
+pub const RenamedTy = extern struct {
+  y: u64,
+};
+
+pub const Foo = extern struct {
+  x: i32,
+};
+
+pub extern fn no_extern_func(a: ContainsNoExternTy) void;
+
+pub extern fn renamed_func(a: RenamedTy) void;
+
+pub extern fn root(a: Foo) void;
@@ -0,0 +1,42 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const C_H: number;
+
+export declare const C_E: {
+  readonly x: 0;
+  readonly y: 1;
+};
+
+export interface C_AwesomeB {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const C_AwesomeB: StructType<C_AwesomeB>;
+
+export interface C_D {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const C_D: StructType<C_D>;
+
+export type C_F = never;
+export declare const C_F: RefType;
+
+export interface Library {
+  G: Buffer;
+
+  root(a: Buffer | null, b: C_AwesomeB, c: never, d: C_D, e: number, f: never): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,31 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type C_E = C.C_E
+
+const (
+  x C_E = C.x
+  y C_E = C.y
+)
+
+type C_A = C.C_A
+
+type C_C = C.C_C
+
+type C_AwesomeB = C.C_AwesomeB
+
+type C_D = C.C_D
+
+type C_F = C.C_F
+
+const C_H = C.C_H
+
+func G() int32 {
+  return int32(C.G)
+}
+
+func Root(a *C_A, b C_AwesomeB, c C_C, d C_D, e C_E, f C_F) {
+  C.root(a, b, c, d, e, f)
+}
@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int C_H = 10;
+
+  interface C_E {
+    byte x = 0;
+    byte y = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class C_AwesomeB extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends C_AwesomeB implements Structure.ByReference {}
+
+    public static class ByValue extends C_AwesomeB implements Structure.ByValue {}
+  }
+
+  class C_D extends Union {
+    public int x;
+    public float y;
+
+    public static class ByReference extends C_D implements Structure.ByReference {}
+
+    public static class ByValue extends C_D implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, C_AwesomeB.ByValue b, Pointer c, C_D.ByValue d, byte e, Pointer f);
+}
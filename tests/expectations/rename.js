@@ -0,0 +1,44 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const C_AwesomeB = StructType();
+const C_D = UnionType();
+
+const C_H = 10;
+
+C_AwesomeB.defineProperty('x', 'int32');
+C_AwesomeB.defineProperty('y', 'float');
+
+C_D.defineProperty('x', 'int32');
+C_D.defineProperty('y', 'float');
+
+const C_E = Object.freeze({
+  x: 0,
+  y: 1,
+});
+
+const C_F = 'void';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', C_AwesomeB, 'void', C_D, 'uint8', 'void']],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.G = dylib.get('G');
+  return lib;
+}
+
+module.exports = {
+  C_AwesomeB,
+  C_D,
+  C_E,
+  C_F,
+  C_H,
+  load,
+};
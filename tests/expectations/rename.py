@@ -0,0 +1,41 @@
+import ctypes
+
+C_H = 10
+
+C_E = ctypes.c_uint8
+x = 0
+y = 1
+
+class C_A(ctypes.Structure):
+  pass
+
+class C_C(ctypes.Structure):
+  pass
+
+class C_AwesomeB(ctypes.Structure):
+  pass
+
+C_AwesomeB._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+class C_D(ctypes.Union):
+  pass
+
+C_D._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+C_F = C_A
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.G = ctypes.c_int32.in_dll(lib, "G")
+
+  lib.root.argtypes = [ctypes.POINTER(C_A), C_AwesomeB, C_C, C_D, C_E, C_F]
+  lib.root.restype = None
+
+  return lib
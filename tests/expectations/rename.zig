@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const C_H: i32 = 10;
+
+pub const C_E = enum(u8) {
+  x = 0,
+  y = 1,
+};
+
+pub const C_A = opaque {};
+
+pub const C_C = opaque {};
+
+pub const C_AwesomeB = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub const C_D = extern union {
+  x: i32,
+  y: f32,
+};
+
+pub const C_F = C_A;
+
+pub extern const G: i32;
+
+pub extern fn root(a: ?*const C_A, b: C_AwesomeB, c: C_C, d: C_D, e: C_E, f: C_F) void;
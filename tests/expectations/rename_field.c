@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool is_new;
+  uint32_t template_id;
+  uint32_t referenceCount;
+} Object;
+
+typedef struct {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+typedef union {
+  int64_t as_int;
+  double real;
+} Value;
+
+enum Shape_Tag {
+  Circle,
+  Square,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  float r;
+} Circle_Body;
+
+typedef struct {
+  float _0;
+} Square_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+} Shape;
+
+void root(Object object, Pair pair, Value value, Shape shape);
@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Object {
+  bool is_new;
+  uint32_t template_id;
+  uint32_t referenceCount;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t _1;
+};
+
+union Value {
+  int64_t as_int;
+  double real;
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square,
+  };
+
+  struct Circle_Body {
+    float r;
+  };
+
+  struct Square_Body {
+    float _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+extern "C" {
+
+void root(Object object, Pair pair, Value value, Shape shape);
+
+} // extern "C"
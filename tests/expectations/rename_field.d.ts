@@ -0,0 +1,65 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Shape_Tag: {
+  readonly Circle: 0;
+  readonly Square: 1;
+};
+
+export interface Object {
+  is_new: boolean;
+  template_id: number;
+  referenceCount: number;
+  ref(): Buffer;
+}
+export declare const Object: StructType<Object>;
+
+export interface Pair {
+  first: number;
+  _1: number;
+  ref(): Buffer;
+}
+export declare const Pair: StructType<Pair>;
+
+export interface Value {
+  as_int: number | string;
+  real: number;
+  ref(): Buffer;
+}
+export declare const Value: StructType<Value>;
+
+export interface Circle_Body {
+  r: number;
+  ref(): Buffer;
+}
+export declare const Circle_Body: StructType<Circle_Body>;
+
+export interface Square_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Square_Body: StructType<Square_Body>;
+
+export interface Shape {
+  tag: number;
+  variants: {
+    circle: Circle_Body;
+    square: Square_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Shape: StructType<Shape>;
+
+export interface Library {
+  root(object: Object, pair: Pair, value: Value, shape: Shape): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Object = C.Object
+
+type Pair = C.Pair
+
+type Value = C.Value
+
+type Shape_Tag = C.Shape_Tag
+
+const (
+  Circle Shape_Tag = C.Circle
+  Square Shape_Tag = C.Square
+)
+
+type Circle_Body = C.Circle_Body
+
+type Square_Body = C.Square_Body
+
+type Shape = C.Shape
+
+func Root(object Object, pair Pair, value Value, shape Shape) {
+  C.root(object, pair, value, shape)
+}
@@ -0,0 +1,77 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"is_new", "template_id", "referenceCount"})
+  class Object extends Structure {
+    public byte is_new;
+    public int template_id;
+    public int referenceCount;
+
+    public static class ByReference extends Object implements Structure.ByReference {}
+
+    public static class ByValue extends Object implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"first", "_1"})
+  class Pair extends Structure {
+    public int first;
+    public int _1;
+
+    public static class ByReference extends Pair implements Structure.ByReference {}
+
+    public static class ByValue extends Pair implements Structure.ByValue {}
+  }
+
+  class Value extends Union {
+    public long as_int;
+    public double real;
+
+    public static class ByReference extends Value implements Structure.ByReference {}
+
+    public static class ByValue extends Value implements Structure.ByValue {}
+  }
+
+  interface Shape_Tag {
+    byte Circle = 0;
+    byte Square = 1;
+  }
+
+  @Structure.FieldOrder({"r"})
+  class Circle_Body extends Structure {
+    public float r;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Square_Body extends Structure {
+    public float _0;
+
+    public static class ByReference extends Square_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Square_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Shape extends Structure {
+    public static class Variants extends Union {
+      public Circle_Body circle;
+      public Square_Body square;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  void root(Object.ByValue object, Pair.ByValue pair, Value.ByValue value, Shape.ByValue shape);
+}
@@ -0,0 +1,59 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Object = StructType();
+const Pair = StructType();
+const Value = UnionType();
+const Circle_Body = StructType();
+const Square_Body = StructType();
+const _Shape_Variants = UnionType();
+const Shape = StructType();
+
+Object.defineProperty('is_new', 'bool');
+Object.defineProperty('template_id', 'uint32');
+Object.defineProperty('referenceCount', 'uint32');
+
+Pair.defineProperty('first', 'int32');
+Pair.defineProperty('_1', 'int32');
+
+Value.defineProperty('as_int', 'int64');
+Value.defineProperty('real', 'double');
+
+Circle_Body.defineProperty('r', 'float');
+
+Square_Body.defineProperty('_0', 'float');
+
+_Shape_Variants.defineProperty('circle', Circle_Body);
+_Shape_Variants.defineProperty('square', Square_Body);
+
+Shape.defineProperty('tag', 'uint8');
+Shape.defineProperty('variants', _Shape_Variants);
+
+const Shape_Tag = Object.freeze({
+  Circle: 0,
+  Square: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Object, Pair, Value, Shape]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Object,
+  Pair,
+  Value,
+  Circle_Body,
+  Square_Body,
+  Shape,
+  Shape_Tag,
+  load,
+};
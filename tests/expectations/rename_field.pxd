@@ -0,0 +1,39 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Object:
+    bool is_new
+    uint32_t template_id
+    uint32_t referenceCount
+
+  ctypedef struct Pair:
+    int32_t first
+    int32_t _1
+
+  ctypedef union Value:
+    int64_t as_int
+    double real
+
+  enum:
+    Circle
+    Square
+  ctypedef uint8_t Shape_Tag
+
+  ctypedef struct Circle_Body:
+    float r
+
+  ctypedef struct Square_Body:
+    float _0
+
+  ctypedef struct Shape:
+    Shape_Tag tag
+    Circle_Body circle
+    Square_Body square
+
+  void root(Object object, Pair pair, Value value, Shape shape)
@@ -0,0 +1,69 @@
+import ctypes
+
+class Object(ctypes.Structure):
+  pass
+
+Object._fields_ = [
+  ("is_new", ctypes.c_bool),
+  ("template_id", ctypes.c_uint32),
+  ("referenceCount", ctypes.c_uint32),
+]
+
+class Pair(ctypes.Structure):
+  pass
+
+Pair._fields_ = [
+  ("first", ctypes.c_int32),
+  ("_1", ctypes.c_int32),
+]
+
+class Value(ctypes.Union):
+  pass
+
+Value._fields_ = [
+  ("as_int", ctypes.c_int64),
+  ("real", ctypes.c_double),
+]
+
+Shape_Tag = ctypes.c_uint8
+Circle = 0
+Square = 1
+
+class Circle_Body(ctypes.Structure):
+  pass
+
+Circle_Body._fields_ = [
+  ("r", ctypes.c_float),
+]
+
+class Square_Body(ctypes.Structure):
+  pass
+
+Square_Body._fields_ = [
+  ("_0", ctypes.c_float),
+]
+
+class _Shape_Variants(ctypes.Union):
+  pass
+
+_Shape_Variants._fields_ = [
+  ("circle", Circle_Body),
+  ("square", Square_Body),
+]
+
+class Shape(ctypes.Structure):
+  pass
+
+Shape._anonymous_ = ("_variants",)
+Shape._fields_ = [
+  ("tag", Shape_Tag),
+  ("_variants", _Shape_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Object, Pair, Value, Shape]
+  lib.root.restype = None
+
+  return lib
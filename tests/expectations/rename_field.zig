@@ -0,0 +1,40 @@
+const std = @import("std");
+
+pub const Object = extern struct {
+  is_new: bool,
+  template_id: u32,
+  referenceCount: u32,
+};
+
+pub const Pair = extern struct {
+  first: i32,
+  _1: i32,
+};
+
+pub const Value = extern union {
+  as_int: i64,
+  real: f64,
+};
+
+pub const Shape_Tag = enum(u8) {
+  Circle,
+  Square,
+};
+
+pub const Circle_Body = extern struct {
+  r: f32,
+};
+
+pub const Square_Body = extern struct {
+  _0: f32,
+};
+
+pub const Shape = extern struct {
+  tag: Shape_Tag,
+  body: extern union {
+    circle: Circle_Body,
+    square: Square_Body,
+  },
+};
+
+pub extern fn root(object: Object, pair: Pair, value: Value, shape: Shape) void;
@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uintptr_t MY_MAX_POINTS = 8;
+
+enum class my_Shape : uint8_t {
+  SHAPE_Circle,
+  SHAPE_Square,
+};
+
+struct my_Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Rectangle {
+  my_Point origin;
+  my_Point size;
+};
+
+struct C_Kept {
+  int32_t x;
+};
+
+struct my_Polygon {
+  my_Point points[MY_MAX_POINTS];
+};
+
+extern "C" {
+
+void root(Rectangle a, C_Kept b, my_Shape c, my_Polygon d);
+
+} // extern "C"
@@ -0,0 +1,49 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MY_MAX_POINTS: number;
+
+export declare const my_Shape: {
+  readonly SHAPE_Circle: 0;
+  readonly SHAPE_Square: 1;
+};
+
+export interface my_Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const my_Point: StructType<my_Point>;
+
+export interface Rectangle {
+  origin: my_Point;
+  size: my_Point;
+  ref(): Buffer;
+}
+export declare const Rectangle: StructType<Rectangle>;
+
+export interface C_Kept {
+  x: number;
+  ref(): Buffer;
+}
+export declare const C_Kept: StructType<C_Kept>;
+
+export interface my_Polygon {
+  points: ArrayLike<my_Point>;
+  ref(): Buffer;
+}
+export declare const my_Polygon: StructType<my_Polygon>;
+
+export interface Library {
+  root(a: Rectangle, b: C_Kept, c: number, d: my_Polygon): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,25 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type my_Shape = C.my_Shape
+
+const (
+  SHAPE_Circle my_Shape = C.SHAPE_Circle
+  SHAPE_Square my_Shape = C.SHAPE_Square
+)
+
+type my_Point = C.my_Point
+
+type Rectangle = C.Rectangle
+
+type C_Kept = C.C_Kept
+
+type my_Polygon = C.my_Polygon
+
+const MY_MAX_POINTS = C.MY_MAX_POINTS
+
+func Root(a Rectangle, b C_Kept, c my_Shape, d my_Polygon) {
+  C.root(a, b, c, d)
+}
@@ -0,0 +1,50 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  long MY_MAX_POINTS = 8L;
+
+  interface my_Shape {
+    byte SHAPE_Circle = 0;
+    byte SHAPE_Square = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class my_Point extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends my_Point implements Structure.ByReference {}
+
+    public static class ByValue extends my_Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"origin", "size"})
+  class Rectangle extends Structure {
+    public my_Point origin;
+    public my_Point size;
+
+    public static class ByReference extends Rectangle implements Structure.ByReference {}
+
+    public static class ByValue extends Rectangle implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x"})
+  class C_Kept extends Structure {
+    public int x;
+
+    public static class ByReference extends C_Kept implements Structure.ByReference {}
+
+    public static class ByValue extends C_Kept implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"points"})
+  class my_Polygon extends Structure {
+    public my_Point[] points = (my_Point[]) new my_Point().toArray((int) MY_MAX_POINTS);
+
+    public static class ByReference extends my_Polygon implements Structure.ByReference {}
+
+    public static class ByValue extends my_Polygon implements Structure.ByValue {}
+  }
+
+  void root(Rectangle.ByValue a, C_Kept.ByValue b, byte c, my_Polygon.ByValue d);
+}
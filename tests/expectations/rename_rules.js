@@ -0,0 +1,47 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const my_Point = StructType();
+const Rectangle = StructType();
+const C_Kept = StructType();
+const my_Polygon = StructType();
+
+const MY_MAX_POINTS = 8;
+
+my_Point.defineProperty('x', 'int32');
+my_Point.defineProperty('y', 'int32');
+
+Rectangle.defineProperty('origin', my_Point);
+Rectangle.defineProperty('size', my_Point);
+
+C_Kept.defineProperty('x', 'int32');
+
+my_Polygon.defineProperty('points', ArrayType(my_Point, MY_MAX_POINTS));
+
+const my_Shape = Object.freeze({
+  SHAPE_Circle: 0,
+  SHAPE_Square: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Rectangle, C_Kept, 'uint8', my_Polygon]],
+  });
+  return lib;
+}
+
+module.exports = {
+  my_Point,
+  Rectangle,
+  C_Kept,
+  my_Polygon,
+  my_Shape,
+  MY_MAX_POINTS,
+  load,
+};
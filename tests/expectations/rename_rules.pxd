@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uintptr_t MY_MAX_POINTS
+
+  enum:
+    SHAPE_Circle
+    SHAPE_Square
+  ctypedef uint8_t my_Shape
+
+  ctypedef struct my_Point:
+    int32_t x
+    int32_t y
+
+  ctypedef struct Rectangle:
+    my_Point origin
+    my_Point size
+
+  ctypedef struct C_Kept:
+    int32_t x
+
+  ctypedef struct my_Polygon:
+    my_Point points[MY_MAX_POINTS]
+
+  void root(Rectangle a, C_Kept b, my_Shape c, my_Polygon d)
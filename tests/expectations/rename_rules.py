@@ -0,0 +1,45 @@
+import ctypes
+
+MY_MAX_POINTS = 8
+
+my_Shape = ctypes.c_uint8
+SHAPE_Circle = 0
+SHAPE_Square = 1
+
+class my_Point(ctypes.Structure):
+  pass
+
+my_Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+class Rectangle(ctypes.Structure):
+  pass
+
+Rectangle._fields_ = [
+  ("origin", my_Point),
+  ("size", my_Point),
+]
+
+class C_Kept(ctypes.Structure):
+  pass
+
+C_Kept._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+class my_Polygon(ctypes.Structure):
+  pass
+
+my_Polygon._fields_ = [
+  ("points", (my_Point * MY_MAX_POINTS)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Rectangle, C_Kept, my_Shape, my_Polygon]
+  lib.root.restype = None
+
+  return lib
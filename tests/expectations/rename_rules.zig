@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const MY_MAX_POINTS: usize = 8;
+
+pub const my_Shape = enum(u8) {
+  SHAPE_Circle,
+  SHAPE_Square,
+};
+
+pub const my_Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Rectangle = extern struct {
+  origin: my_Point,
+  size: my_Point,
+};
+
+pub const C_Kept = extern struct {
+  x: i32,
+};
+
+pub const my_Polygon = extern struct {
+  points: [@intCast(MY_MAX_POINTS)]my_Point,
+};
+
+pub extern fn root(a: Rectangle, b: C_Kept, c: my_Shape, d: my_Polygon) void;
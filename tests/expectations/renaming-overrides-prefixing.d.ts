@@ -0,0 +1,23 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface B {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const B: StructType<B>;
+
+export interface Library {
+  root(a: Buffer | null, b: B): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type StyleA = C.StyleA
+
+type B = C.B
+
+func Root(a *StyleA, b B) {
+  C.root(a, b)
+}
@@ -0,0 +1,15 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class B extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends B implements Structure.ByReference {}
+
+    public static class ByValue extends B implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, B.ByValue b);
+}
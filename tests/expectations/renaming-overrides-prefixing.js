@@ -0,0 +1,25 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const B = StructType();
+
+B.defineProperty('x', 'int32');
+B.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', B]],
+  });
+  return lib;
+}
+
+module.exports = {
+  B,
+  load,
+};
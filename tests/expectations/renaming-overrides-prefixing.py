@@ -0,0 +1,20 @@
+import ctypes
+
+class StyleA(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+B._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(StyleA), B]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct __attribute__((packed)) {
+  uint8_t tag;
+  uint32_t length;
+} PackedHeader;
+
+#pragma pack(push, 2)
+typedef struct {
+  uint8_t first;
+  uint64_t second;
+} PackedPair;
+#pragma pack(pop)
+
+typedef struct __attribute__((aligned(8))) {
+  uint16_t id;
+} AlignedId;
+
+typedef struct __attribute__((aligned(16))) {
+  uint8_t data[4];
+  PackedHeader header;
+} AlignedBlock;
+
+void root(PackedHeader a, PackedPair b, AlignedId c, const AlignedBlock *d);
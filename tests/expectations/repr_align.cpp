@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct __attribute__((packed)) PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+
+#pragma pack(push, 2)
+struct PackedPair {
+  uint8_t first;
+  uint64_t second;
+};
+#pragma pack(pop)
+
+struct __attribute__((aligned(8))) AlignedId {
+  uint16_t id;
+};
+
+struct __attribute__((aligned(16))) AlignedBlock {
+  uint8_t data[4];
+  PackedHeader header;
+};
+
+extern "C" {
+
+void root(PackedHeader a, PackedPair b, AlignedId c, const AlignedBlock *d);
+
+} // extern "C"
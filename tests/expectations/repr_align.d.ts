@@ -0,0 +1,16 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Library {
+  root(a: never, b: never, c: never, d: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
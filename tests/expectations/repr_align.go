@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PackedHeader = C.PackedHeader
+
+type PackedPair = C.PackedPair
+
+type AlignedId = C.AlignedId
+
+type AlignedBlock = C.AlignedBlock
+
+func Root(a PackedHeader, b PackedPair, c AlignedId, d *AlignedBlock) {
+  C.root(a, b, c, d)
+}
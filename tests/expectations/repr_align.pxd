@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct PackedHeader:
+    uint8_t tag
+    uint32_t length
+
+  ctypedef struct PackedPair:
+    uint8_t first
+    uint64_t second
+
+  ctypedef struct AlignedId:
+    uint16_t id
+
+  ctypedef struct AlignedBlock:
+    uint8_t data[4]
+    PackedHeader header
+
+  void root(PackedHeader a, PackedPair b, AlignedId c, const AlignedBlock *d)
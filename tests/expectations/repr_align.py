@@ -0,0 +1,44 @@
+import ctypes
+
+class PackedHeader(ctypes.Structure):
+  pass
+
+PackedHeader._pack_ = 1
+PackedHeader._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("length", ctypes.c_uint32),
+]
+
+class PackedPair(ctypes.Structure):
+  pass
+
+PackedPair._pack_ = 2
+PackedPair._fields_ = [
+  ("first", ctypes.c_uint8),
+  ("second", ctypes.c_uint64),
+]
+
+class AlignedId(ctypes.Structure):
+  pass
+
+AlignedId._align_ = 8
+AlignedId._fields_ = [
+  ("id", ctypes.c_uint16),
+]
+
+class AlignedBlock(ctypes.Structure):
+  pass
+
+AlignedBlock._align_ = 16
+AlignedBlock._fields_ = [
+  ("data", (ctypes.c_uint8 * 4)),
+  ("header", PackedHeader),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PackedHeader, PackedPair, AlignedId, ctypes.POINTER(AlignedBlock)]
+  lib.root.restype = None
+
+  return lib
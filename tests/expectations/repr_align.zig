@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const PackedHeader = extern struct {
+  tag: u8 align(1),
+  length: u32 align(1),
+};
+
+pub const PackedPair = extern struct {
+  first: u8 align(@min(2, @alignOf(u8))),
+  second: u64 align(@min(2, @alignOf(u64))),
+};
+
+pub const AlignedId = extern struct {
+  id: u16 align(@max(8, @alignOf(u16))),
+};
+
+pub const AlignedBlock = extern struct {
+  data: [4]u8 align(@max(16, @alignOf([4]u8))),
+  header: PackedHeader,
+};
+
+pub extern fn root(a: PackedHeader, b: PackedPair, c: AlignedId, d: [*c]const AlignedBlock) void;
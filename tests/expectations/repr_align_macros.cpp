@@ -0,0 +1,28 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct PACKED PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+
+struct ALIGNED(4) AlignedId {
+  uint16_t id;
+};
+
+extern "C" {
+
+void root(PackedHeader a, AlignedId b);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PackedHeader = C.PackedHeader
+
+type AlignedId = C.AlignedId
+
+func Root(a PackedHeader, b AlignedId) {
+  C.root(a, b)
+}
@@ -0,0 +1,14 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  void root(Pointer a, Pointer b);
+}
@@ -0,0 +1,35 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+import ctypes
+
+class PackedHeader(ctypes.Structure):
+  pass
+
+PackedHeader._pack_ = 1
+PackedHeader._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("length", ctypes.c_uint32),
+]
+
+class AlignedId(ctypes.Structure):
+  pass
+
+AlignedId._align_ = 4
+AlignedId._fields_ = [
+  ("id", ctypes.c_uint16),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PackedHeader, AlignedId]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,21 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+const std = @import("std");
+
+pub const PackedHeader = extern struct {
+  tag: u8 align(1),
+  length: u32 align(1),
+};
+
+pub const AlignedId = extern struct {
+  id: u16 align(@max(4, @alignOf(u16))),
+};
+
+pub extern fn root(a: PackedHeader, b: AlignedId) void;
@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+#pragma pack(push, 1)
+typedef struct {
+  uint8_t tag;
+  uint32_t length;
+} PackedHeader;
+#pragma pack(pop)
+
+#pragma pack(push, 2)
+typedef struct {
+  uint8_t first;
+  uint64_t second;
+} PackedPair;
+#pragma pack(pop)
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(PackedHeader) == 5, "unexpected size of PackedHeader");
+CBINDGEN_STATIC_ASSERT(offsetof(PackedHeader, tag) == 0, "unexpected offset of PackedHeader::tag");
+CBINDGEN_STATIC_ASSERT(offsetof(PackedHeader, length) == 1, "unexpected offset of PackedHeader::length");
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(PackedPair) == 10, "unexpected size of PackedPair");
+CBINDGEN_STATIC_ASSERT(offsetof(PackedPair, first) == 0, "unexpected offset of PackedPair::first");
+CBINDGEN_STATIC_ASSERT(offsetof(PackedPair, second) == 2, "unexpected offset of PackedPair::second");
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(PackedHeader a, PackedPair b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cstddef>
+
+#pragma pack(push, 1)
+struct PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+#pragma pack(pop)
+
+#pragma pack(push, 2)
+struct PackedPair {
+  uint8_t first;
+  uint64_t second;
+};
+#pragma pack(pop)
+
+static_assert(sizeof(PackedHeader) == 5, "unexpected size of PackedHeader");
+static_assert(offsetof(PackedHeader, tag) == 0, "unexpected offset of PackedHeader::tag");
+static_assert(offsetof(PackedHeader, length) == 1, "unexpected offset of PackedHeader::length");
+
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(PackedPair) == 10, "unexpected size of PackedPair");
+static_assert(offsetof(PackedPair, first) == 0, "unexpected offset of PackedPair::first");
+static_assert(offsetof(PackedPair, second) == 2, "unexpected offset of PackedPair::second");
+#endif
+
+extern "C" {
+
+void root(PackedHeader a, PackedPair b);
+
+} // extern "C"
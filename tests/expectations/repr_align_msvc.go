@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type PackedHeader = C.PackedHeader
+
+type PackedPair = C.PackedPair
+
+func Root(a PackedHeader, b PackedPair) {
+  C.root(a, b)
+}
@@ -0,0 +1,27 @@
+import ctypes
+
+class PackedHeader(ctypes.Structure):
+  pass
+
+PackedHeader._pack_ = 1
+PackedHeader._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("length", ctypes.c_uint32),
+]
+
+class PackedPair(ctypes.Structure):
+  pass
+
+PackedPair._pack_ = 2
+PackedPair._fields_ = [
+  ("first", ctypes.c_uint8),
+  ("second", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PackedHeader, PackedPair]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const PackedHeader = extern struct {
+  tag: u8 align(1),
+  length: u32 align(1),
+};
+
+pub const PackedPair = extern struct {
+  first: u8 align(@min(2, @alignOf(u8))),
+  second: u64 align(@min(2, @alignOf(u64))),
+};
+
+pub extern fn root(a: PackedHeader, b: PackedPair) void;
@@ -0,0 +1,50 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const C_Tag: {
+  readonly D: 0;
+};
+
+export interface A {
+  namespace_: number;
+  float_: number;
+  ref(): Buffer;
+}
+export declare const A: StructType<A>;
+
+export interface B {
+  namespace_: number;
+  float_: number;
+  ref(): Buffer;
+}
+export declare const B: StructType<B>;
+
+export interface D_Body {
+  namespace_: number;
+  float_: number;
+  ref(): Buffer;
+}
+export declare const D_Body: StructType<D_Body>;
+
+export interface C {
+  tag: number;
+  variants: {
+    d: D_Body;
+  };
+  ref(): Buffer;
+}
+export declare const C: StructType<C>;
+
+export interface Library {
+  root(a: A, b: B, c: C, namespace_: number, float_: number): void;
+}
+
+export declare function load(path: string): Library;
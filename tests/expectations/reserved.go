@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type A = C.A
+
+type B = C.B
+
+type C_Tag = C.C_Tag
+
+const (
+  D C_Tag = C.D
+)
+
+type D_Body = C.D_Body
+
+func Root(a A, b B, c C.C, namespace_ int32, float_ float32) {
+  C.root(a, b, c, C.int32_t(namespace_), C.float(float_))
+}
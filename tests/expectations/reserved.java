@@ -0,0 +1,57 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"namespace_", "float_"})
+  class A extends Structure {
+    public int namespace_;
+    public float float_;
+
+    public static class ByReference extends A implements Structure.ByReference {}
+
+    public static class ByValue extends A implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"namespace_", "float_"})
+  class B extends Structure {
+    public int namespace_;
+    public float float_;
+
+    public static class ByReference extends B implements Structure.ByReference {}
+
+    public static class ByValue extends B implements Structure.ByValue {}
+  }
+
+  interface C_Tag {
+    byte D = 0;
+  }
+
+  @Structure.FieldOrder({"namespace_", "float_"})
+  class D_Body extends Structure {
+    public int namespace_;
+    public float float_;
+
+    public static class ByReference extends D_Body implements Structure.ByReference {}
+
+    public static class ByValue extends D_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class C extends Structure {
+    public static class Variants extends Union {
+      public D_Body d;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends C implements Structure.ByReference {}
+
+    public static class ByValue extends C implements Structure.ByValue {}
+  }
+
+  void root(A.ByValue a, B.ByValue b, C.ByValue c, int namespace_, float float_);
+}
@@ -0,0 +1,48 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const A = StructType();
+const B = StructType();
+const D_Body = StructType();
+const _C_Variants = UnionType();
+const C = StructType();
+
+A.defineProperty('namespace_', 'int32');
+A.defineProperty('float_', 'float');
+
+B.defineProperty('namespace_', 'int32');
+B.defineProperty('float_', 'float');
+
+D_Body.defineProperty('namespace_', 'int32');
+D_Body.defineProperty('float_', 'float');
+
+_C_Variants.defineProperty('d', D_Body);
+
+C.defineProperty('tag', 'uint8');
+C.defineProperty('variants', _C_Variants);
+
+const C_Tag = Object.freeze({
+  D: 0,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [A, B, C, 'int32', 'float']],
+  });
+  return lib;
+}
+
+module.exports = {
+  A,
+  B,
+  D_Body,
+  C,
+  C_Tag,
+  load,
+};
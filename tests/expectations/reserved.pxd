@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct A:
+    int32_t namespace_
+    float float_
+
+  ctypedef struct B:
+    int32_t namespace_
+    float float_
+
+  enum:
+    D
+  ctypedef uint8_t C_Tag
+
+  ctypedef struct D_Body:
+    int32_t namespace_
+    float float_
+
+  ctypedef struct C:
+    C_Tag tag
+    D_Body d
+
+  void root(A a, B b, C c, int32_t namespace_, float float_)
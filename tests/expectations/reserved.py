@@ -0,0 +1,52 @@
+import ctypes
+
+class A(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("namespace_", ctypes.c_int32),
+  ("float_", ctypes.c_float),
+]
+
+class B(ctypes.Structure):
+  pass
+
+B._fields_ = [
+  ("namespace_", ctypes.c_int32),
+  ("float_", ctypes.c_float),
+]
+
+C_Tag = ctypes.c_uint8
+D = 0
+
+class D_Body(ctypes.Structure):
+  pass
+
+D_Body._fields_ = [
+  ("namespace_", ctypes.c_int32),
+  ("float_", ctypes.c_float),
+]
+
+class _C_Variants(ctypes.Union):
+  pass
+
+_C_Variants._fields_ = [
+  ("d", D_Body),
+]
+
+class C(ctypes.Structure):
+  pass
+
+C._anonymous_ = ("_variants",)
+C._fields_ = [
+  ("tag", C_Tag),
+  ("_variants", _C_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, C, ctypes.c_int32, ctypes.c_float]
+  lib.root.restype = None
+
+  return lib
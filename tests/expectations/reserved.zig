@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const B = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const C_Tag = enum(u8) {
+  D,
+};
+
+pub const D_Body = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const C = extern struct {
+  tag: C_Tag,
+  body: extern union {
+    d: D_Body,
+  },
+};
+
+pub extern fn root(a: A, b: B, c: C, namespace_: i32, float_: f32) void;
@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Error {
+  NotFound,
+  Invalid,
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+Error parse_count(const uint8_t *text, uint32_t *out);
+
+Error parse_point(const uint8_t *text, Point *out);
+
+Error validate(const uint8_t *text);
+
+} // extern "C"
@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Error: {
+  readonly NotFound: 0;
+  readonly Invalid: 1;
+};
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Library {
+  parse_count(text: Buffer | null, out: Buffer | null): number;
+
+  parse_point(text: Buffer | null, out: Buffer | null): number;
+
+  validate(text: Buffer | null): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Error = C.Error
+
+const (
+  NotFound Error = C.NotFound
+  Invalid Error = C.Invalid
+)
+
+type Point = C.Point
+
+func ParseCount(text *uint8, out *uint32) Error {
+  return C.parse_count((*C.uint8_t)(unsafe.Pointer(text)), (*C.uint32_t)(unsafe.Pointer(out)))
+}
+
+func ParsePoint(text *uint8, out *Point) Error {
+  return C.parse_point((*C.uint8_t)(unsafe.Pointer(text)), out)
+}
+
+func Validate(text *uint8) Error {
+  return C.validate((*C.uint8_t)(unsafe.Pointer(text)))
+}
@@ -0,0 +1,24 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Error {
+    int NotFound = 0;
+    int Invalid = 1;
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  int parse_count(Pointer text, Pointer out);
+
+  int parse_point(Pointer text, Point.ByReference out);
+
+  int validate(Pointer text);
+}
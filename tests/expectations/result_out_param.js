@@ -0,0 +1,33 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+const Error = Object.freeze({
+  NotFound: 0,
+  Invalid: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    parse_count: ['int', [ref.refType('uint8'), ref.refType('uint32')]],
+    parse_point: ['int', [ref.refType('uint8'), ref.refType(Point)]],
+    validate: ['int', [ref.refType('uint8')]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Error,
+  load,
+};
@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum Error:
+    NotFound
+    Invalid
+
+  ctypedef struct Point:
+    float x
+    float y
+
+  Error parse_count(const uint8_t *text, uint32_t *out)
+
+  Error parse_point(const uint8_t *text, Point *out)
+
+  Error validate(const uint8_t *text)
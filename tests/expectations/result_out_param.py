@@ -0,0 +1,27 @@
+import ctypes
+
+Error = ctypes.c_int
+NotFound = 0
+Invalid = 1
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.parse_count.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.POINTER(ctypes.c_uint32)]
+  lib.parse_count.restype = Error
+
+  lib.parse_point.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.POINTER(Point)]
+  lib.parse_point.restype = Error
+
+  lib.validate.argtypes = [ctypes.POINTER(ctypes.c_uint8)]
+  lib.validate.restype = Error
+
+  return lib
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const Error = enum(c_int) {
+  NotFound,
+  Invalid,
+};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn parse_count(text: [*c]const u8, out: *u32) Error;
+
+pub extern fn parse_point(text: [*c]const u8, out: *Point) Error;
+
+pub extern fn validate(text: [*c]const u8) Error;
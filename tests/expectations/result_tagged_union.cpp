@@ -0,0 +1,63 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Error {
+  NotFound,
+  Invalid,
+};
+
+template<typename T, typename E>
+struct Result {
+  enum class Tag {
+    Ok,
+    Err,
+  };
+
+  struct Ok_Body {
+    T _0;
+  };
+
+  struct Err_Body {
+    E _0;
+  };
+
+  Tag tag;
+  union {
+    Ok_Body ok;
+    Err_Body err;
+  };
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+template<typename E>
+struct VoidResult {
+  enum class Tag {
+    Ok,
+    Err,
+  };
+
+  struct Err_Body {
+    E _0;
+  };
+
+  Tag tag;
+  union {
+    Err_Body err;
+  };
+};
+
+extern "C" {
+
+Result<uint32_t, Error> parse_count(const uint8_t *text);
+
+Result<Point, Error> parse_point(const uint8_t *text);
+
+VoidResult<Error> validate(const uint8_t *text);
+
+} // extern "C"
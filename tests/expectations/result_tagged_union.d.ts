@@ -0,0 +1,106 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Error: {
+  readonly NotFound: 0;
+  readonly Invalid: 1;
+};
+
+export declare const Result_u32__Error_Tag: {
+  readonly Ok_u32__Error: 0;
+  readonly Err_u32__Error: 1;
+};
+
+export declare const Result_Point__Error_Tag: {
+  readonly Ok_Point__Error: 0;
+  readonly Err_Point__Error: 1;
+};
+
+export declare const VoidResult_Error_Tag: {
+  readonly Ok_Error: 0;
+  readonly Err_Error: 1;
+};
+
+export interface Ok_Body_u32__Error {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Ok_Body_u32__Error: StructType<Ok_Body_u32__Error>;
+
+export interface Err_Body_u32__Error {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Err_Body_u32__Error: StructType<Err_Body_u32__Error>;
+
+export interface Result_u32__Error {
+  tag: number;
+  variants: {
+    ok: Ok_Body_u32__Error;
+    err: Err_Body_u32__Error;
+  };
+  ref(): Buffer;
+}
+export declare const Result_u32__Error: StructType<Result_u32__Error>;
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Ok_Body_Point__Error {
+  _0: Point;
+  ref(): Buffer;
+}
+export declare const Ok_Body_Point__Error: StructType<Ok_Body_Point__Error>;
+
+export interface Err_Body_Point__Error {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Err_Body_Point__Error: StructType<Err_Body_Point__Error>;
+
+export interface Result_Point__Error {
+  tag: number;
+  variants: {
+    ok: Ok_Body_Point__Error;
+    err: Err_Body_Point__Error;
+  };
+  ref(): Buffer;
+}
+export declare const Result_Point__Error: StructType<Result_Point__Error>;
+
+export interface Err_Body_Error {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Err_Body_Error: StructType<Err_Body_Error>;
+
+export interface VoidResult_Error {
+  tag: number;
+  variants: {
+    err: Err_Body_Error;
+  };
+  ref(): Buffer;
+}
+export declare const VoidResult_Error: StructType<VoidResult_Error>;
+
+export interface Library {
+  parse_count(text: Buffer | null): Result_u32__Error;
+
+  parse_point(text: Buffer | null): Result_Point__Error;
+
+  validate(text: Buffer | null): VoidResult_Error;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,64 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Error = C.Error
+
+const (
+  NotFound Error = C.NotFound
+  Invalid Error = C.Invalid
+)
+
+type Result_u32__Error_Tag = C.Result_u32__Error_Tag
+
+const (
+  Ok_u32__Error Result_u32__Error_Tag = C.Ok_u32__Error
+  Err_u32__Error Result_u32__Error_Tag = C.Err_u32__Error
+)
+
+type Ok_Body_u32__Error = C.Ok_Body_u32__Error
+
+type Err_Body_u32__Error = C.Err_Body_u32__Error
+
+type Result_u32__Error = C.Result_u32__Error
+
+type Point = C.Point
+
+type Result_Point__Error_Tag = C.Result_Point__Error_Tag
+
+const (
+  Ok_Point__Error Result_Point__Error_Tag = C.Ok_Point__Error
+  Err_Point__Error Result_Point__Error_Tag = C.Err_Point__Error
+)
+
+type Ok_Body_Point__Error = C.Ok_Body_Point__Error
+
+type Err_Body_Point__Error = C.Err_Body_Point__Error
+
+type Result_Point__Error = C.Result_Point__Error
+
+type VoidResult_Error_Tag = C.VoidResult_Error_Tag
+
+const (
+  Ok_Error VoidResult_Error_Tag = C.Ok_Error
+  Err_Error VoidResult_Error_Tag = C.Err_Error
+)
+
+type Err_Body_Error = C.Err_Body_Error
+
+type VoidResult_Error = C.VoidResult_Error
+
+func ParseCount(text *uint8) Result_u32__Error {
+  return C.parse_count((*C.uint8_t)(unsafe.Pointer(text)))
+}
+
+func ParsePoint(text *uint8) Result_Point__Error {
+  return C.parse_point((*C.uint8_t)(unsafe.Pointer(text)))
+}
+
+func Validate(text *uint8) VoidResult_Error {
+  return C.validate((*C.uint8_t)(unsafe.Pointer(text)))
+}
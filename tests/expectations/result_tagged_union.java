@@ -0,0 +1,140 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Error {
+    int NotFound = 0;
+    int Invalid = 1;
+  }
+
+  interface Result_u32__Error_Tag {
+    int Ok_u32__Error = 0;
+    int Err_u32__Error = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Ok_Body_u32__Error extends Structure {
+    public int _0;
+
+    public static class ByReference extends Ok_Body_u32__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Ok_Body_u32__Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Err_Body_u32__Error extends Structure {
+    public int _0;
+
+    public static class ByReference extends Err_Body_u32__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Err_Body_u32__Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Result_u32__Error extends Structure {
+    public static class Variants extends Union {
+      public Ok_Body_u32__Error ok;
+      public Err_Body_u32__Error err;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends Result_u32__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Result_u32__Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  interface Result_Point__Error_Tag {
+    int Ok_Point__Error = 0;
+    int Err_Point__Error = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Ok_Body_Point__Error extends Structure {
+    public Point _0;
+
+    public static class ByReference extends Ok_Body_Point__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Ok_Body_Point__Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Err_Body_Point__Error extends Structure {
+    public int _0;
+
+    public static class ByReference extends Err_Body_Point__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Err_Body_Point__Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Result_Point__Error extends Structure {
+    public static class Variants extends Union {
+      public Ok_Body_Point__Error ok;
+      public Err_Body_Point__Error err;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends Result_Point__Error implements Structure.ByReference {}
+
+    public static class ByValue extends Result_Point__Error implements Structure.ByValue {}
+  }
+
+  interface VoidResult_Error_Tag {
+    int Ok_Error = 0;
+    int Err_Error = 1;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Err_Body_Error extends Structure {
+    public int _0;
+
+    public static class ByReference extends Err_Body_Error implements Structure.ByReference {}
+
+    public static class ByValue extends Err_Body_Error implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class VoidResult_Error extends Structure {
+    public static class Variants extends Union {
+      public Err_Body_Error err;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends VoidResult_Error implements Structure.ByReference {}
+
+    public static class ByValue extends VoidResult_Error implements Structure.ByValue {}
+  }
+
+  Result_u32__Error.ByValue parse_count(Pointer text);
+
+  Result_Point__Error.ByValue parse_point(Pointer text);
+
+  VoidResult_Error.ByValue validate(Pointer text);
+}
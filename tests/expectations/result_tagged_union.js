@@ -0,0 +1,97 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Ok_Body_u32__Error = StructType();
+const Err_Body_u32__Error = StructType();
+const _Result_u32__Error_Variants = UnionType();
+const Result_u32__Error = StructType();
+const Point = StructType();
+const Ok_Body_Point__Error = StructType();
+const Err_Body_Point__Error = StructType();
+const _Result_Point__Error_Variants = UnionType();
+const Result_Point__Error = StructType();
+const Err_Body_Error = StructType();
+const _VoidResult_Error_Variants = UnionType();
+const VoidResult_Error = StructType();
+
+Ok_Body_u32__Error.defineProperty('_0', 'uint32');
+
+Err_Body_u32__Error.defineProperty('_0', 'int');
+
+_Result_u32__Error_Variants.defineProperty('ok', Ok_Body_u32__Error);
+_Result_u32__Error_Variants.defineProperty('err', Err_Body_u32__Error);
+
+Result_u32__Error.defineProperty('tag', 'int');
+Result_u32__Error.defineProperty('variants', _Result_u32__Error_Variants);
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+Ok_Body_Point__Error.defineProperty('_0', Point);
+
+Err_Body_Point__Error.defineProperty('_0', 'int');
+
+_Result_Point__Error_Variants.defineProperty('ok', Ok_Body_Point__Error);
+_Result_Point__Error_Variants.defineProperty('err', Err_Body_Point__Error);
+
+Result_Point__Error.defineProperty('tag', 'int');
+Result_Point__Error.defineProperty('variants', _Result_Point__Error_Variants);
+
+Err_Body_Error.defineProperty('_0', 'int');
+
+_VoidResult_Error_Variants.defineProperty('err', Err_Body_Error);
+
+VoidResult_Error.defineProperty('tag', 'int');
+VoidResult_Error.defineProperty('variants', _VoidResult_Error_Variants);
+
+const Error = Object.freeze({
+  NotFound: 0,
+  Invalid: 1,
+});
+
+const Result_u32__Error_Tag = Object.freeze({
+  Ok_u32__Error: 0,
+  Err_u32__Error: 1,
+});
+
+const Result_Point__Error_Tag = Object.freeze({
+  Ok_Point__Error: 0,
+  Err_Point__Error: 1,
+});
+
+const VoidResult_Error_Tag = Object.freeze({
+  Ok_Error: 0,
+  Err_Error: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    parse_count: [Result_u32__Error, [ref.refType('uint8')]],
+    parse_point: [Result_Point__Error, [ref.refType('uint8')]],
+    validate: [VoidResult_Error, [ref.refType('uint8')]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Ok_Body_u32__Error,
+  Err_Body_u32__Error,
+  Result_u32__Error,
+  Point,
+  Ok_Body_Point__Error,
+  Err_Body_Point__Error,
+  Result_Point__Error,
+  Err_Body_Error,
+  VoidResult_Error,
+  Error,
+  Result_u32__Error_Tag,
+  Result_Point__Error_Tag,
+  VoidResult_Error_Tag,
+  load,
+};
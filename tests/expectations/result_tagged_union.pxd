@@ -0,0 +1,63 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum Error:
+    NotFound
+    Invalid
+
+  ctypedef enum Result_u32__Error_Tag:
+    Ok_u32__Error
+    Err_u32__Error
+
+  ctypedef struct Ok_Body_u32__Error:
+    uint32_t _0
+
+  ctypedef struct Err_Body_u32__Error:
+    Error _0
+
+  ctypedef struct Result_u32__Error:
+    Result_u32__Error_Tag tag
+    Ok_Body_u32__Error ok
+    Err_Body_u32__Error err
+
+  ctypedef struct Point:
+    float x
+    float y
+
+  ctypedef enum Result_Point__Error_Tag:
+    Ok_Point__Error
+    Err_Point__Error
+
+  ctypedef struct Ok_Body_Point__Error:
+    Point _0
+
+  ctypedef struct Err_Body_Point__Error:
+    Error _0
+
+  ctypedef struct Result_Point__Error:
+    Result_Point__Error_Tag tag
+    Ok_Body_Point__Error ok
+    Err_Body_Point__Error err
+
+  ctypedef enum VoidResult_Error_Tag:
+    Ok_Error
+    Err_Error
+
+  ctypedef struct Err_Body_Error:
+    Error _0
+
+  ctypedef struct VoidResult_Error:
+    VoidResult_Error_Tag tag
+    Err_Body_Error err
+
+  Result_u32__Error parse_count(const uint8_t *text)
+
+  Result_Point__Error parse_point(const uint8_t *text)
+
+  VoidResult_Error validate(const uint8_t *text)
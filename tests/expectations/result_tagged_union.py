@@ -0,0 +1,124 @@
+import ctypes
+
+Error = ctypes.c_int
+NotFound = 0
+Invalid = 1
+
+Result_u32__Error_Tag = ctypes.c_int
+Ok_u32__Error = 0
+Err_u32__Error = 1
+
+class Ok_Body_u32__Error(ctypes.Structure):
+  pass
+
+Ok_Body_u32__Error._fields_ = [
+  ("_0", ctypes.c_uint32),
+]
+
+class Err_Body_u32__Error(ctypes.Structure):
+  pass
+
+Err_Body_u32__Error._fields_ = [
+  ("_0", Error),
+]
+
+class _Result_u32__Error_Variants(ctypes.Union):
+  pass
+
+_Result_u32__Error_Variants._fields_ = [
+  ("ok", Ok_Body_u32__Error),
+  ("err", Err_Body_u32__Error),
+]
+
+class Result_u32__Error(ctypes.Structure):
+  pass
+
+Result_u32__Error._anonymous_ = ("_variants",)
+Result_u32__Error._fields_ = [
+  ("tag", Result_u32__Error_Tag),
+  ("_variants", _Result_u32__Error_Variants),
+]
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+Result_Point__Error_Tag = ctypes.c_int
+Ok_Point__Error = 0
+Err_Point__Error = 1
+
+class Ok_Body_Point__Error(ctypes.Structure):
+  pass
+
+Ok_Body_Point__Error._fields_ = [
+  ("_0", Point),
+]
+
+class Err_Body_Point__Error(ctypes.Structure):
+  pass
+
+Err_Body_Point__Error._fields_ = [
+  ("_0", Error),
+]
+
+class _Result_Point__Error_Variants(ctypes.Union):
+  pass
+
+_Result_Point__Error_Variants._fields_ = [
+  ("ok", Ok_Body_Point__Error),
+  ("err", Err_Body_Point__Error),
+]
+
+class Result_Point__Error(ctypes.Structure):
+  pass
+
+Result_Point__Error._anonymous_ = ("_variants",)
+Result_Point__Error._fields_ = [
+  ("tag", Result_Point__Error_Tag),
+  ("_variants", _Result_Point__Error_Variants),
+]
+
+VoidResult_Error_Tag = ctypes.c_int
+Ok_Error = 0
+Err_Error = 1
+
+class Err_Body_Error(ctypes.Structure):
+  pass
+
+Err_Body_Error._fields_ = [
+  ("_0", Error),
+]
+
+class _VoidResult_Error_Variants(ctypes.Union):
+  pass
+
+_VoidResult_Error_Variants._fields_ = [
+  ("err", Err_Body_Error),
+]
+
+class VoidResult_Error(ctypes.Structure):
+  pass
+
+VoidResult_Error._anonymous_ = ("_variants",)
+VoidResult_Error._fields_ = [
+  ("tag", VoidResult_Error_Tag),
+  ("_variants", _VoidResult_Error_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.parse_count.argtypes = [ctypes.POINTER(ctypes.c_uint8)]
+  lib.parse_count.restype = Result_u32__Error
+
+  lib.parse_point.argtypes = [ctypes.POINTER(ctypes.c_uint8)]
+  lib.parse_point.restype = Result_Point__Error
+
+  lib.validate.argtypes = [ctypes.POINTER(ctypes.c_uint8)]
+  lib.validate.restype = VoidResult_Error
+
+  return lib
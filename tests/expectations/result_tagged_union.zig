@@ -0,0 +1,75 @@
+const std = @import("std");
+
+pub const Error = enum(c_int) {
+  NotFound,
+  Invalid,
+};
+
+pub const Result_u32__Error_Tag = enum(c_int) {
+  Ok_u32__Error,
+  Err_u32__Error,
+};
+
+pub const Ok_Body_u32__Error = extern struct {
+  _0: u32,
+};
+
+pub const Err_Body_u32__Error = extern struct {
+  _0: Error,
+};
+
+pub const Result_u32__Error = extern struct {
+  tag: Result_u32__Error_Tag,
+  body: extern union {
+    ok: Ok_Body_u32__Error,
+    err: Err_Body_u32__Error,
+  },
+};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Result_Point__Error_Tag = enum(c_int) {
+  Ok_Point__Error,
+  Err_Point__Error,
+};
+
+pub const Ok_Body_Point__Error = extern struct {
+  _0: Point,
+};
+
+pub const Err_Body_Point__Error = extern struct {
+  _0: Error,
+};
+
+pub const Result_Point__Error = extern struct {
+  tag: Result_Point__Error_Tag,
+  body: extern union {
+    ok: Ok_Body_Point__Error,
+    err: Err_Body_Point__Error,
+  },
+};
+
+pub const VoidResult_Error_Tag = enum(c_int) {
+  Ok_Error,
+  Err_Error,
+};
+
+pub const Err_Body_Error = extern struct {
+  _0: Error,
+};
+
+pub const VoidResult_Error = extern struct {
+  tag: VoidResult_Error_Tag,
+  body: extern union {
+    err: Err_Body_Error,
+  },
+};
+
+pub extern fn parse_count(text: [*c]const u8) Result_u32__Error;
+
+pub extern fn parse_point(text: [*c]const u8) Result_Point__Error;
+
+pub extern fn validate(text: [*c]const u8) VoidResult_Error;
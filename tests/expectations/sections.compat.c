@@ -0,0 +1,71 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* ---------------- Audio ---------------- */
+
+#define SAMPLE_RATE 48000
+
+/* ---------------- Constants ---------------- */
+
+#define VOLUME_MAX 100
+
+/* ---------------- Audio ---------------- */
+
+typedef enum {
+  Left,
+  Right,
+} Channel;
+
+typedef void (*OnSamples)(const float *samples, uintptr_t len);
+
+typedef struct {
+  Channel channel;
+  OnSamples on_samples;
+} Stream;
+
+/* ---------------- Enums ---------------- */
+
+typedef enum {
+  Fast,
+  Slow,
+} Mode;
+
+/* ---------------- Typedefs ---------------- */
+
+typedef uint64_t Id;
+
+/* ---------------- Callbacks ---------------- */
+
+typedef void (*OnEvent)(uint32_t code);
+
+/* ---------------- Structs ---------------- */
+
+typedef struct {
+  Mode mode;
+  Id id;
+  OnEvent on_event;
+} Settings;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* ---------------- Globals ---------------- */
+
+extern uint32_t LAST_ERROR;
+
+/* ---------------- Audio ---------------- */
+
+void open_stream(Stream *stream);
+
+/* ---------------- Functions ---------------- */
+
+void init(Settings settings);
+
+void shutdown(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,67 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/* ---------------- Audio ---------------- */
+
+static const uint32_t SAMPLE_RATE = 48000;
+
+/* ---------------- Constants ---------------- */
+
+static const uint32_t VOLUME_MAX = 100;
+
+/* ---------------- Audio ---------------- */
+
+enum class Channel {
+  Left,
+  Right,
+};
+
+using OnSamples = void(*)(const float *samples, uintptr_t len);
+
+struct Stream {
+  Channel channel;
+  OnSamples on_samples;
+};
+
+/* ---------------- Enums ---------------- */
+
+enum class Mode {
+  Fast,
+  Slow,
+};
+
+/* ---------------- Typedefs ---------------- */
+
+using Id = uint64_t;
+
+/* ---------------- Callbacks ---------------- */
+
+using OnEvent = void(*)(uint32_t code);
+
+/* ---------------- Structs ---------------- */
+
+struct Settings {
+  Mode mode;
+  Id id;
+  OnEvent on_event;
+};
+
+extern "C" {
+
+/* ---------------- Globals ---------------- */
+
+extern uint32_t LAST_ERROR;
+
+/* ---------------- Audio ---------------- */
+
+void open_stream(Stream *stream);
+
+/* ---------------- Functions ---------------- */
+
+void init(Settings settings);
+
+void shutdown();
+
+} // extern "C"
@@ -0,0 +1,60 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const SAMPLE_RATE: number;
+
+export declare const VOLUME_MAX: number;
+
+export declare const Channel: {
+  readonly Left: 0;
+  readonly Right: 1;
+};
+
+export declare const Mode: {
+  readonly Fast: 0;
+  readonly Slow: 1;
+};
+
+export interface Settings {
+  mode: number;
+  id: number | string;
+  on_event: Buffer;
+  ref(): Buffer;
+}
+export declare const Settings: StructType<Settings>;
+
+export interface Stream {
+  channel: number;
+  on_samples: Buffer;
+  ref(): Buffer;
+}
+export declare const Stream: StructType<Stream>;
+
+export type Id = number | string;
+export declare const Id: RefType;
+
+export type OnEvent = Buffer;
+export declare const OnEvent: RefType;
+
+export type OnSamples = Buffer;
+export declare const OnSamples: RefType;
+
+export interface Library {
+  LAST_ERROR: Buffer;
+
+  init(settings: Settings): void;
+
+  open_stream(stream: Buffer | null): void;
+
+  shutdown(): void;
+}
+
+export declare function load(path: string): Library;
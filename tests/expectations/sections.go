@@ -0,0 +1,48 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Channel = C.Channel
+
+const (
+  Left Channel = C.Left
+  Right Channel = C.Right
+)
+
+type Mode = C.Mode
+
+const (
+  Fast Mode = C.Fast
+  Slow Mode = C.Slow
+)
+
+type Id = C.Id
+
+type OnEvent = C.OnEvent
+
+type Settings = C.Settings
+
+type OnSamples = C.OnSamples
+
+type Stream = C.Stream
+
+const SAMPLE_RATE = C.SAMPLE_RATE
+
+const VOLUME_MAX = C.VOLUME_MAX
+
+func LastError() uint32 {
+  return uint32(C.LAST_ERROR)
+}
+
+func Init(settings Settings) {
+  C.init(settings)
+}
+
+func OpenStream(stream *Stream) {
+  C.open_stream(stream)
+}
+
+func Shutdown() {
+  C.shutdown()
+}
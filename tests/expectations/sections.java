@@ -0,0 +1,62 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int SAMPLE_RATE = 48000;
+
+  int VOLUME_MAX = 100;
+
+  interface Channel {
+    int Left = 0;
+    int Right = 1;
+  }
+
+  interface Mode {
+    int Fast = 0;
+    int Slow = 1;
+  }
+
+  interface OnEvent extends Callback {
+    void invoke(int code);
+  }
+
+  @Structure.FieldOrder({"mode", "id", "on_event"})
+  class Settings extends Structure {
+    public int mode;
+    public long id;
+    public OnEvent on_event;
+
+    public static class ByReference extends Settings implements Structure.ByReference {}
+
+    public static class ByValue extends Settings implements Structure.ByValue {}
+  }
+
+  interface OnSamples extends Callback {
+    void invoke(Pointer samples, SizeT len);
+  }
+
+  @Structure.FieldOrder({"channel", "on_samples"})
+  class Stream extends Structure {
+    public int channel;
+    public OnSamples on_samples;
+
+    public static class ByReference extends Stream implements Structure.ByReference {}
+
+    public static class ByValue extends Stream implements Structure.ByValue {}
+  }
+
+  void init(Settings.ByValue settings);
+
+  void open_stream(Stream.ByReference stream);
+
+  void shutdown();
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
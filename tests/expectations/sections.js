@@ -0,0 +1,62 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Settings = StructType();
+const Stream = StructType();
+
+const SAMPLE_RATE = 48000;
+
+const VOLUME_MAX = 100;
+
+Settings.defineProperty('mode', 'int');
+Settings.defineProperty('id', 'uint64');
+Settings.defineProperty('on_event', 'pointer');
+
+Stream.defineProperty('channel', 'int');
+Stream.defineProperty('on_samples', 'pointer');
+
+const Channel = Object.freeze({
+  Left: 0,
+  Right: 1,
+});
+
+const Mode = Object.freeze({
+  Fast: 0,
+  Slow: 1,
+});
+
+const Id = 'uint64';
+
+const OnEvent = 'pointer';
+
+const OnSamples = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    init: ['void', [Settings]],
+    open_stream: ['void', [ref.refType(Stream)]],
+    shutdown: ['void', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.LAST_ERROR = dylib.get('LAST_ERROR');
+  return lib;
+}
+
+module.exports = {
+  Settings,
+  Stream,
+  Channel,
+  Mode,
+  Id,
+  OnEvent,
+  OnSamples,
+  SAMPLE_RATE,
+  VOLUME_MAX,
+  load,
+};
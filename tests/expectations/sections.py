@@ -0,0 +1,52 @@
+import ctypes
+
+SAMPLE_RATE = 48000
+
+VOLUME_MAX = 100
+
+Channel = ctypes.c_int
+Left = 0
+Right = 1
+
+Mode = ctypes.c_int
+Fast = 0
+Slow = 1
+
+Id = ctypes.c_uint64
+
+OnEvent = ctypes.CFUNCTYPE(None, ctypes.c_uint32)
+
+class Settings(ctypes.Structure):
+  pass
+
+Settings._fields_ = [
+  ("mode", Mode),
+  ("id", Id),
+  ("on_event", OnEvent),
+]
+
+OnSamples = ctypes.CFUNCTYPE(None, ctypes.POINTER(ctypes.c_float), ctypes.c_size_t)
+
+class Stream(ctypes.Structure):
+  pass
+
+Stream._fields_ = [
+  ("channel", Channel),
+  ("on_samples", OnSamples),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.LAST_ERROR = ctypes.c_uint32.in_dll(lib, "LAST_ERROR")
+
+  lib.init.argtypes = [Settings]
+  lib.init.restype = None
+
+  lib.open_stream.argtypes = [ctypes.POINTER(Stream)]
+  lib.open_stream.restype = None
+
+  lib.shutdown.argtypes = []
+  lib.shutdown.restype = None
+
+  return lib
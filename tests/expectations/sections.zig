@@ -0,0 +1,40 @@
+const std = @import("std");
+
+pub const SAMPLE_RATE: u32 = 48000;
+
+pub const VOLUME_MAX: u32 = 100;
+
+pub const Channel = enum(c_int) {
+  Left,
+  Right,
+};
+
+pub const Mode = enum(c_int) {
+  Fast,
+  Slow,
+};
+
+pub const Id = u64;
+
+pub const OnEvent = *const fn (u32) callconv(.C) void;
+
+pub const Settings = extern struct {
+  mode: Mode,
+  id: Id,
+  on_event: OnEvent,
+};
+
+pub const OnSamples = *const fn ([*c]const f32, usize) callconv(.C) void;
+
+pub const Stream = extern struct {
+  channel: Channel,
+  on_samples: OnSamples,
+};
+
+pub extern var LAST_ERROR: u32;
+
+pub extern fn init(settings: Settings) void;
+
+pub extern fn open_stream(stream: [*c]Stream) void;
+
+pub extern fn shutdown() void;
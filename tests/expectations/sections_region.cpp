@@ -0,0 +1,87 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#pragma region Constants
+
+static const uint32_t VOLUME_MAX = 100;
+
+#pragma endregion
+
+#pragma region Audio
+
+static const uint32_t SAMPLE_RATE = 48000;
+
+#pragma endregion
+
+#pragma region Enums
+
+enum class Mode {
+  Fast,
+  Slow,
+};
+
+#pragma endregion
+
+#pragma region Typedefs
+
+using Id = uint64_t;
+
+#pragma endregion
+
+#pragma region Callbacks
+
+using OnEvent = void(*)(uint32_t code);
+
+#pragma endregion
+
+#pragma region Structs
+
+struct Settings {
+  Mode mode;
+  Id id;
+  OnEvent on_event;
+};
+
+#pragma endregion
+
+#pragma region Audio
+
+enum class Channel {
+  Left,
+  Right,
+};
+
+using OnSamples = void(*)(const float *samples, uintptr_t len);
+
+struct Stream {
+  Channel channel;
+  OnSamples on_samples;
+};
+
+#pragma endregion
+
+extern "C" {
+
+#pragma region Globals
+
+extern uint32_t LAST_ERROR;
+
+#pragma endregion
+
+#pragma region Functions
+
+void init(Settings settings);
+
+void shutdown();
+
+#pragma endregion
+
+#pragma region Audio
+
+void open_stream(Stream *stream);
+
+#pragma endregion
+
+} // extern "C"
@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t SAMPLE_RATE
+
+  const uint32_t VOLUME_MAX
+
+  ctypedef enum Channel:
+    Left
+    Right
+
+  ctypedef enum Mode:
+    Fast
+    Slow
+
+  ctypedef uint64_t Id
+
+  ctypedef void (*OnEvent)(uint32_t code)
+
+  ctypedef struct Settings:
+    Mode mode
+    Id id
+    OnEvent on_event
+
+  ctypedef void (*OnSamples)(const float *samples, uintptr_t len)
+
+  ctypedef struct Stream:
+    Channel channel
+    OnSamples on_samples
+
+  uint32_t LAST_ERROR
+
+  void init(Settings settings)
+
+  void open_stream(Stream *stream)
+
+  void shutdown()
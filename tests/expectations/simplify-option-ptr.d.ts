@@ -0,0 +1,32 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo {
+  x: Buffer;
+  y: Buffer;
+  z: Buffer;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Bar {
+  x: Buffer;
+  y: Buffer;
+  z: Buffer;
+  ref(): Buffer;
+}
+export declare const Bar: StructType<Bar>;
+
+export interface Library {
+  root(a: Buffer | null, b: Buffer | null, c: Foo, d: Bar): void;
+}
+
+export declare function load(path: string): Library;
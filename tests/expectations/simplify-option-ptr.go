@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Opaque = C.Opaque
+
+type Foo = C.Foo
+
+type Bar = C.Bar
+
+func Root(a *Opaque, b *Opaque, c Foo, d Bar) {
+  C.root(a, b, c, d)
+}
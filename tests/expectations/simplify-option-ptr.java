@@ -0,0 +1,26 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y", "z"})
+  class Foo extends Structure {
+    public Pointer x;
+    public Pointer y;
+    public Pointer z;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  class Bar extends Union {
+    public Pointer x;
+    public Pointer y;
+    public Pointer z;
+
+    public static class ByReference extends Bar implements Structure.ByReference {}
+
+    public static class ByValue extends Bar implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, Pointer b, Foo.ByValue c, Bar.ByValue d);
+}
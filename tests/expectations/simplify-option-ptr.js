@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+const Bar = UnionType();
+
+Foo.defineProperty('x', 'pointer');
+Foo.defineProperty('y', 'pointer');
+Foo.defineProperty('z', 'pointer');
+
+Bar.defineProperty('x', 'pointer');
+Bar.defineProperty('y', 'pointer');
+Bar.defineProperty('z', 'pointer');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', 'pointer', Foo, Bar]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  Bar,
+  load,
+};
@@ -0,0 +1,30 @@
+import ctypes
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.POINTER(Opaque)),
+  ("y", ctypes.POINTER(Opaque)),
+  ("z", ctypes.CFUNCTYPE(None)),
+]
+
+class Bar(ctypes.Union):
+  pass
+
+Bar._fields_ = [
+  ("x", ctypes.POINTER(Opaque)),
+  ("y", ctypes.POINTER(Opaque)),
+  ("z", ctypes.CFUNCTYPE(None)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), ctypes.POINTER(Opaque), Foo, Bar]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const Opaque = opaque {};
+
+pub const Foo = extern struct {
+  x: ?*const Opaque,
+  y: ?*Opaque,
+  z: ?*const fn () callconv(.C) void,
+};
+
+pub const Bar = extern union {
+  x: ?*const Opaque,
+  y: ?*Opaque,
+  z: ?*const fn () callconv(.C) void,
+};
+
+pub extern fn root(a: ?*const Opaque, b: ?*Opaque, c: Foo, d: Bar) void;
@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  const Point *ptr;
+  uintptr_t len;
+} Slice_Point;
+
+typedef struct {
+  Slice_Point points;
+} Polygon;
+
+typedef struct {
+  const uint8_t *ptr;
+  uintptr_t len;
+} Slice_u8;
+
+typedef struct {
+  uint8_t *ptr;
+  uintptr_t len;
+} MutSlice_u8;
+
+typedef struct {
+  Point *ptr;
+  uintptr_t len;
+} MutSlice_Point;
+
+typedef struct {
+  const float *ptr;
+  uintptr_t len;
+} Slice_f32;
+
+float area(Polygon polygon);
+
+uint32_t checksum(Slice_u8 bytes);
+
+void fill(MutSlice_u8 out, uint8_t value);
+
+void translate(MutSlice_Point points, Slice_f32 by);
@@ -0,0 +1,50 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Slice_Point {
+  const Point *ptr;
+  uintptr_t len;
+};
+
+struct Polygon {
+  Slice_Point points;
+};
+
+struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct MutSlice_u8 {
+  uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct MutSlice_Point {
+  Point *ptr;
+  uintptr_t len;
+};
+
+struct Slice_f32 {
+  const float *ptr;
+  uintptr_t len;
+};
+
+extern "C" {
+
+float area(Polygon polygon);
+
+uint32_t checksum(Slice_u8 bytes);
+
+void fill(MutSlice_u8 out, uint8_t value);
+
+void translate(MutSlice_Point points, Slice_f32 by);
+
+} // extern "C"
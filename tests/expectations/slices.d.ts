@@ -0,0 +1,70 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Slice_Point {
+  ptr: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Slice_Point: StructType<Slice_Point>;
+
+export interface Polygon {
+  points: Slice_Point;
+  ref(): Buffer;
+}
+export declare const Polygon: StructType<Polygon>;
+
+export interface Slice_u8 {
+  ptr: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Slice_u8: StructType<Slice_u8>;
+
+export interface MutSlice_u8 {
+  ptr: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const MutSlice_u8: StructType<MutSlice_u8>;
+
+export interface MutSlice_Point {
+  ptr: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const MutSlice_Point: StructType<MutSlice_Point>;
+
+export interface Slice_f32 {
+  ptr: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Slice_f32: StructType<Slice_f32>;
+
+export interface Library {
+  area(polygon: Polygon): number;
+
+  checksum(bytes: Slice_u8): number;
+
+  fill(out: MutSlice_u8, value: number): void;
+
+  translate(points: MutSlice_Point, by: Slice_f32): void;
+}
+
+export declare function load(path: string): Library;
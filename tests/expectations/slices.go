@@ -0,0 +1,34 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Point = C.Point
+
+type Slice_Point = C.Slice_Point
+
+type Polygon = C.Polygon
+
+type Slice_u8 = C.Slice_u8
+
+type MutSlice_u8 = C.MutSlice_u8
+
+type MutSlice_Point = C.MutSlice_Point
+
+type Slice_f32 = C.Slice_f32
+
+func Area(polygon Polygon) float32 {
+  return float32(C.area(polygon))
+}
+
+func Checksum(bytes Slice_u8) uint32 {
+  return uint32(C.checksum(bytes))
+}
+
+func Fill(out MutSlice_u8, value uint8) {
+  C.fill(out, C.uint8_t(value))
+}
+
+func Translate(points MutSlice_Point, by Slice_f32) {
+  C.translate(points, by)
+}
@@ -0,0 +1,90 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr", "len"})
+  class Slice_Point extends Structure {
+    public Point.ByReference ptr;
+    public SizeT len;
+
+    public static class ByReference extends Slice_Point implements Structure.ByReference {}
+
+    public static class ByValue extends Slice_Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"points"})
+  class Polygon extends Structure {
+    public Slice_Point points;
+
+    public static class ByReference extends Polygon implements Structure.ByReference {}
+
+    public static class ByValue extends Polygon implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr", "len"})
+  class Slice_u8 extends Structure {
+    public Pointer ptr;
+    public SizeT len;
+
+    public static class ByReference extends Slice_u8 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice_u8 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr", "len"})
+  class MutSlice_u8 extends Structure {
+    public Pointer ptr;
+    public SizeT len;
+
+    public static class ByReference extends MutSlice_u8 implements Structure.ByReference {}
+
+    public static class ByValue extends MutSlice_u8 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr", "len"})
+  class MutSlice_Point extends Structure {
+    public Point.ByReference ptr;
+    public SizeT len;
+
+    public static class ByReference extends MutSlice_Point implements Structure.ByReference {}
+
+    public static class ByValue extends MutSlice_Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ptr", "len"})
+  class Slice_f32 extends Structure {
+    public Pointer ptr;
+    public SizeT len;
+
+    public static class ByReference extends Slice_f32 implements Structure.ByReference {}
+
+    public static class ByValue extends Slice_f32 implements Structure.ByValue {}
+  }
+
+  float area(Polygon.ByValue polygon);
+
+  int checksum(Slice_u8.ByValue bytes);
+
+  void fill(MutSlice_u8.ByValue out, byte value);
+
+  void translate(MutSlice_Point.ByValue points, Slice_f32.ByValue by);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,57 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Point = StructType();
+const Slice_Point = StructType();
+const Polygon = StructType();
+const Slice_u8 = StructType();
+const MutSlice_u8 = StructType();
+const MutSlice_Point = StructType();
+const Slice_f32 = StructType();
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+Slice_Point.defineProperty('ptr', ref.refType(Point));
+Slice_Point.defineProperty('len', 'size_t');
+
+Polygon.defineProperty('points', Slice_Point);
+
+Slice_u8.defineProperty('ptr', ref.refType('uint8'));
+Slice_u8.defineProperty('len', 'size_t');
+
+MutSlice_u8.defineProperty('ptr', ref.refType('uint8'));
+MutSlice_u8.defineProperty('len', 'size_t');
+
+MutSlice_Point.defineProperty('ptr', ref.refType(Point));
+MutSlice_Point.defineProperty('len', 'size_t');
+
+Slice_f32.defineProperty('ptr', ref.refType('float'));
+Slice_f32.defineProperty('len', 'size_t');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    area: ['float', [Polygon]],
+    checksum: ['uint32', [Slice_u8]],
+    fill: ['void', [MutSlice_u8, 'uint8']],
+    translate: ['void', [MutSlice_Point, Slice_f32]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Point,
+  Slice_Point,
+  Polygon,
+  Slice_u8,
+  MutSlice_u8,
+  MutSlice_Point,
+  Slice_f32,
+  load,
+};
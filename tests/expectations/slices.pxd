@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Point:
+    float x
+    float y
+
+  ctypedef struct Slice_Point:
+    const Point *ptr
+    uintptr_t len
+
+  ctypedef struct Polygon:
+    Slice_Point points
+
+  ctypedef struct Slice_u8:
+    const uint8_t *ptr
+    uintptr_t len
+
+  ctypedef struct MutSlice_u8:
+    uint8_t *ptr
+    uintptr_t len
+
+  ctypedef struct MutSlice_Point:
+    Point *ptr
+    uintptr_t len
+
+  ctypedef struct Slice_f32:
+    const float *ptr
+    uintptr_t len
+
+  float area(Polygon polygon)
+
+  uint32_t checksum(Slice_u8 bytes)
+
+  void fill(MutSlice_u8 out, uint8_t value)
+
+  void translate(MutSlice_Point points, Slice_f32 by)
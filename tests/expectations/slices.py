@@ -0,0 +1,73 @@
+import ctypes
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class Slice_Point(ctypes.Structure):
+  pass
+
+Slice_Point._fields_ = [
+  ("ptr", ctypes.POINTER(Point)),
+  ("len", ctypes.c_size_t),
+]
+
+class Polygon(ctypes.Structure):
+  pass
+
+Polygon._fields_ = [
+  ("points", Slice_Point),
+]
+
+class Slice_u8(ctypes.Structure):
+  pass
+
+Slice_u8._fields_ = [
+  ("ptr", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+class MutSlice_u8(ctypes.Structure):
+  pass
+
+MutSlice_u8._fields_ = [
+  ("ptr", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+class MutSlice_Point(ctypes.Structure):
+  pass
+
+MutSlice_Point._fields_ = [
+  ("ptr", ctypes.POINTER(Point)),
+  ("len", ctypes.c_size_t),
+]
+
+class Slice_f32(ctypes.Structure):
+  pass
+
+Slice_f32._fields_ = [
+  ("ptr", ctypes.POINTER(ctypes.c_float)),
+  ("len", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.area.argtypes = [Polygon]
+  lib.area.restype = ctypes.c_float
+
+  lib.checksum.argtypes = [Slice_u8]
+  lib.checksum.restype = ctypes.c_uint32
+
+  lib.fill.argtypes = [MutSlice_u8, ctypes.c_uint8]
+  lib.fill.restype = None
+
+  lib.translate.argtypes = [MutSlice_Point, Slice_f32]
+  lib.translate.restype = None
+
+  return lib
@@ -0,0 +1,43 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Slice_Point = extern struct {
+  ptr: [*c]const Point,
+  len: usize,
+};
+
+pub const Polygon = extern struct {
+  points: Slice_Point,
+};
+
+pub const Slice_u8 = extern struct {
+  ptr: [*c]const u8,
+  len: usize,
+};
+
+pub const MutSlice_u8 = extern struct {
+  ptr: [*c]u8,
+  len: usize,
+};
+
+pub const MutSlice_Point = extern struct {
+  ptr: [*c]Point,
+  len: usize,
+};
+
+pub const Slice_f32 = extern struct {
+  ptr: [*c]const f32,
+  len: usize,
+};
+
+pub extern fn area(polygon: Polygon) f32;
+
+pub extern fn checksum(bytes: Slice_u8) u32;
+
+pub extern fn fill(out: MutSlice_u8, value: u8) void;
+
+pub extern fn translate(points: MutSlice_Point, by: Slice_f32) void;
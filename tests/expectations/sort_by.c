@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ZETA 1
+
+#define ALPHA (ZETA + 1)
+
+#define MIDDLE 3
+
+typedef struct {
+  int32_t a;
+} Zebra;
+
+typedef struct {
+  Zebra zebra;
+} Yak;
+
+typedef struct {
+  float b;
+} Aardvark;
+
+extern uint32_t AGE;
+
+extern uint32_t WEIGHT;
+
+void zoo(Aardvark aardvark, Yak yak);
+
+void barn(const Zebra *zebra);
+
+void apiary(void);
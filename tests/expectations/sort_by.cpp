@@ -0,0 +1,36 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint8_t ZETA = 1;
+
+static const uint8_t ALPHA = ZETA + 1;
+
+static const uint8_t MIDDLE = 3;
+
+struct Zebra {
+  int32_t a;
+};
+
+struct Yak {
+  Zebra zebra;
+};
+
+struct Aardvark {
+  float b;
+};
+
+extern "C" {
+
+extern uint32_t AGE;
+
+extern uint32_t WEIGHT;
+
+void zoo(Aardvark aardvark, Yak yak);
+
+void barn(const Zebra *zebra);
+
+void apiary();
+
+} // extern "C"
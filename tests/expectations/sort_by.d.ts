@@ -0,0 +1,48 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const ZETA: number;
+
+export declare const ALPHA: number;
+
+export declare const MIDDLE: number;
+
+export interface Zebra {
+  a: number;
+  ref(): Buffer;
+}
+export declare const Zebra: StructType<Zebra>;
+
+export interface Yak {
+  zebra: Zebra;
+  ref(): Buffer;
+}
+export declare const Yak: StructType<Yak>;
+
+export interface Aardvark {
+  b: number;
+  ref(): Buffer;
+}
+export declare const Aardvark: StructType<Aardvark>;
+
+export interface Library {
+  AGE: Buffer;
+
+  WEIGHT: Buffer;
+
+  zoo(aardvark: Aardvark, yak: Yak): void;
+
+  barn(zebra: Buffer | null): void;
+
+  apiary(): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,36 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Zebra = C.Zebra
+
+type Yak = C.Yak
+
+type Aardvark = C.Aardvark
+
+const ZETA = C.ZETA
+
+const ALPHA = C.ALPHA
+
+const MIDDLE = C.MIDDLE
+
+func Age() uint32 {
+  return uint32(C.AGE)
+}
+
+func Weight() uint32 {
+  return uint32(C.WEIGHT)
+}
+
+func Zoo(aardvark Aardvark, yak Yak) {
+  C.zoo(aardvark, yak)
+}
+
+func Barn(zebra *Zebra) {
+  C.barn(zebra)
+}
+
+func Apiary() {
+  C.apiary()
+}
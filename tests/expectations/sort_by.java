@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  byte ZETA = 1;
+
+  byte ALPHA = ZETA + 1;
+
+  byte MIDDLE = 3;
+
+  @Structure.FieldOrder({"a"})
+  class Zebra extends Structure {
+    public int a;
+
+    public static class ByReference extends Zebra implements Structure.ByReference {}
+
+    public static class ByValue extends Zebra implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"zebra"})
+  class Yak extends Structure {
+    public Zebra zebra;
+
+    public static class ByReference extends Yak implements Structure.ByReference {}
+
+    public static class ByValue extends Yak implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"b"})
+  class Aardvark extends Structure {
+    public float b;
+
+    public static class ByReference extends Aardvark implements Structure.ByReference {}
+
+    public static class ByValue extends Aardvark implements Structure.ByValue {}
+  }
+
+  void zoo(Aardvark.ByValue aardvark, Yak.ByValue yak);
+
+  void barn(Zebra.ByReference zebra);
+
+  void apiary();
+}
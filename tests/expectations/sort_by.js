@@ -0,0 +1,46 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Zebra = StructType();
+const Yak = StructType();
+const Aardvark = StructType();
+
+const ZETA = 1;
+
+const ALPHA = ZETA + 1;
+
+const MIDDLE = 3;
+
+Zebra.defineProperty('a', 'int32');
+
+Yak.defineProperty('zebra', Zebra);
+
+Aardvark.defineProperty('b', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    zoo: ['void', [Aardvark, Yak]],
+    barn: ['void', [ref.refType(Zebra)]],
+    apiary: ['void', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.AGE = dylib.get('AGE');
+  lib.WEIGHT = dylib.get('WEIGHT');
+  return lib;
+}
+
+module.exports = {
+  Zebra,
+  Yak,
+  Aardvark,
+  ZETA,
+  ALPHA,
+  MIDDLE,
+  load,
+};
@@ -0,0 +1,33 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint8_t ZETA
+
+  const uint8_t ALPHA
+
+  const uint8_t MIDDLE
+
+  ctypedef struct Zebra:
+    int32_t a
+
+  ctypedef struct Yak:
+    Zebra zebra
+
+  ctypedef struct Aardvark:
+    float b
+
+  uint32_t AGE
+
+  uint32_t WEIGHT
+
+  void zoo(Aardvark aardvark, Yak yak)
+
+  void barn(const Zebra *zebra)
+
+  void apiary()
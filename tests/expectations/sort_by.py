@@ -0,0 +1,46 @@
+import ctypes
+
+ZETA = 1
+
+ALPHA = ZETA + 1
+
+MIDDLE = 3
+
+class Zebra(ctypes.Structure):
+  pass
+
+Zebra._fields_ = [
+  ("a", ctypes.c_int32),
+]
+
+class Yak(ctypes.Structure):
+  pass
+
+Yak._fields_ = [
+  ("zebra", Zebra),
+]
+
+class Aardvark(ctypes.Structure):
+  pass
+
+Aardvark._fields_ = [
+  ("b", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.AGE = ctypes.c_uint32.in_dll(lib, "AGE")
+
+  lib.WEIGHT = ctypes.c_uint32.in_dll(lib, "WEIGHT")
+
+  lib.zoo.argtypes = [Aardvark, Yak]
+  lib.zoo.restype = None
+
+  lib.barn.argtypes = [ctypes.POINTER(Zebra)]
+  lib.barn.restype = None
+
+  lib.apiary.argtypes = []
+  lib.apiary.restype = None
+
+  return lib
@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const ZETA: u8 = 1;
+
+pub const ALPHA: u8 = (ZETA + 1);
+
+pub const MIDDLE: u8 = 3;
+
+pub const Zebra = extern struct {
+  a: i32,
+};
+
+pub const Yak = extern struct {
+  zebra: Zebra,
+};
+
+pub const Aardvark = extern struct {
+  b: f32,
+};
+
+pub extern var AGE: u32;
+
+pub extern var WEIGHT: u32;
+
+pub extern fn zoo(aardvark: Aardvark, yak: Yak) void;
+
+pub extern fn barn(zebra: [*c]const Zebra) void;
+
+pub extern fn apiary() void;
@@ -0,0 +1,16 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Bar = C.Bar
+
+type Foo = C.Foo
+
+func Number() int32 {
+  return int32(C.NUMBER)
+}
+
+func Root() {
+  C.root()
+}
@@ -0,0 +1,26 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.BAR = dylib.get('BAR');
+  lib.FOO = dylib.get('FOO');
+  lib.NUMBER = dylib.get('NUMBER');
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  load,
+};
@@ -0,0 +1,23 @@
+import ctypes
+
+class Bar(ctypes.Structure):
+  pass
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.BAR = Bar.in_dll(lib, "BAR")
+
+  lib.FOO = Foo.in_dll(lib, "FOO")
+
+  lib.NUMBER = ctypes.c_int32.in_dll(lib, "NUMBER")
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
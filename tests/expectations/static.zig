@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Bar = opaque {};
+
+pub const Foo = extern struct {
+
+};
+
+pub extern const BAR: Bar;
+
+pub extern var FOO: Foo;
+
+pub extern const NUMBER: i32;
+
+pub extern fn root() void;
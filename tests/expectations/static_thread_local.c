@@ -0,0 +1,10 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+extern const uint32_t COUNTER;
+
+extern const uint8_t* NAME;
+
+void root(void);
@@ -0,0 +1,14 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+extern "C" {
+
+extern const uint32_t COUNTER;
+
+extern const uint8_t* NAME;
+
+void root();
+
+} // extern "C"
@@ -0,0 +1,23 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Library {
+  COUNTER: Buffer;
+
+  /**
+   * The name of the library.
+   */
+  NAME: Buffer;
+
+  root(): void;
+}
+
+export declare function load(path: string): Library;
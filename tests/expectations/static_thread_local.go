@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+func Counter() uint32 {
+  return uint32(C.COUNTER)
+}
+
+// The name of the library.
+func Name() *uint8 {
+  return (*uint8)(unsafe.Pointer(C.NAME))
+}
+
+func Root() {
+  C.root()
+}
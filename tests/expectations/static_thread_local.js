@@ -0,0 +1,21 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.COUNTER = dylib.get('COUNTER');
+  lib.NAME = dylib.get('NAME');
+  return lib;
+}
+
+module.exports = {
+  load,
+};
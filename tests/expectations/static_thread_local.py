@@ -0,0 +1,14 @@
+import ctypes
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  # The name of the library.
+  lib.NAME = ctypes.POINTER(ctypes.c_uint8).in_dll(lib, "NAME")
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub extern const COUNTER: u32;
+
+/// The name of the library.
+pub extern const NAME: [*c]const u8;
+
+pub extern fn root() void;
@@ -0,0 +1,14 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Option_i32 = C.Option_i32
+
+type Result_i32__String = C.Result_i32__String
+
+type Vec_String = C.Vec_String
+
+func Root(a *Vec_String, b *Option_i32, c *Result_i32__String) {
+  C.root(a, b, c)
+}
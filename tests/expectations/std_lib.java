@@ -0,0 +1,5 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  void root(Pointer a, Pointer b, Pointer c);
+}
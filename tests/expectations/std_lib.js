@@ -0,0 +1,18 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', 'pointer', 'pointer']],
+  });
+  return lib;
+}
+
+module.exports = {
+  load,
+};
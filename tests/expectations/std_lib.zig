@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const Option_i32 = opaque {};
+
+pub const Result_i32__String = opaque {};
+
+pub const Vec_String = opaque {};
+
+pub extern fn root(a: *const Vec_String, b: *const Option_i32, c: *const Result_i32__String) void;
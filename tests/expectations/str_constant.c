@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NAME "cbindgen \"test\"\n"
+
+#define UNICODE "caf\303\2511"
+
+#define VERSION "1.2.3"
+
+void root(void);
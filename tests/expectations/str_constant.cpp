@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const char* const NAME = "cbindgen \"test\"\n";
+
+static const char* const UNICODE = "caf\303\2511";
+
+static const char* const VERSION = "1.2.3";
+
+extern "C" {
+
+void root();
+
+} // extern "C"
@@ -0,0 +1,25 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const NAME: string;
+
+export declare const UNICODE: string;
+
+/**
+ * The version of the library.
+ */
+export declare const VERSION: string;
+
+export interface Library {
+  root(): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,15 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+const NAME = "cbindgen \"test\"\n"
+
+const UNICODE = "caf\303\2511"
+
+// The version of the library.
+const VERSION = "1.2.3"
+
+func Root() {
+  C.root()
+}
@@ -0,0 +1,14 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  String NAME = "cbindgen \"test\"\u000a";
+
+  String UNICODE = "caf\u00e91";
+
+  /**
+   * The version of the library.
+   */
+  String VERSION = "1.2.3";
+
+  void root();
+}
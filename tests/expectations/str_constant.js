@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const NAME = 'cbindgen "test"\u{a}';
+
+const UNICODE = 'caf\u{e9}1';
+
+const VERSION = '1.2.3';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', []],
+  });
+  return lib;
+}
+
+module.exports = {
+  NAME,
+  UNICODE,
+  VERSION,
+  load,
+};
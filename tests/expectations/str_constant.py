@@ -0,0 +1,16 @@
+import ctypes
+
+NAME = b"cbindgen \"test\"\x0a"
+
+UNICODE = b"caf\xc3\xa91"
+
+# The version of the library.
+VERSION = b"1.2.3"
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const NAME: [*:0]const u8 = "cbindgen \"test\"\n";
+
+pub const UNICODE: [*:0]const u8 = "café1";
+
+/// The version of the library.
+pub const VERSION: [*:0]const u8 = "1.2.3";
+
+pub extern fn root() void;
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A handle to a window.
+ */
+typedef uint64_t WindowHandle; /* strong typedef */
+
+/**
+ * A handle to a texture.
+ */
+typedef uint32_t TextureHandle; /* strong typedef */
+#define TextureHandle_INVALID 0
+
+typedef float Opacity; /* strong typedef */
+
+typedef uint64_t Plain;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void bind(WindowHandle window, TextureHandle texture, Opacity opacity, Plain plain);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
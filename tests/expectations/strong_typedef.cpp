@@ -0,0 +1,42 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/// A handle to a window.
+enum class WindowHandle : uint64_t {};
+
+/// A handle to a texture.
+enum class TextureHandle : uint32_t {};
+static const TextureHandle TextureHandle_INVALID = static_cast<TextureHandle>(0);
+
+struct Opacity {
+  float value;
+
+  bool operator==(const Opacity& other) const {
+    return value == other.value;
+  }
+  bool operator!=(const Opacity& other) const {
+    return value != other.value;
+  }
+  bool operator<(const Opacity& other) const {
+    return value < other.value;
+  }
+  bool operator<=(const Opacity& other) const {
+    return value <= other.value;
+  }
+  bool operator>(const Opacity& other) const {
+    return value > other.value;
+  }
+  bool operator>=(const Opacity& other) const {
+    return value >= other.value;
+  }
+};
+
+using Plain = uint64_t;
+
+extern "C" {
+
+void bind(WindowHandle window, TextureHandle texture, Opacity opacity, Plain plain);
+
+} // extern "C"
@@ -0,0 +1,30 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const TextureHandle_INVALID: number;
+
+/**
+ * A handle to a window.
+ */
+export type WindowHandle = number | string;
+export declare const WindowHandle: RefType;
+
+export type Opacity = number;
+export declare const Opacity: RefType;
+
+export type Plain = number | string;
+export declare const Plain: RefType;
+
+export interface Library {
+  bind(window: number | string, texture: number, opacity: number, plain: number | string): void;
+}
+
+export declare function load(path: string): Library;
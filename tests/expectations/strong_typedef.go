@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// A handle to a window.
+type WindowHandle = C.WindowHandle
+
+// A handle to a texture.
+type TextureHandle = C.TextureHandle
+
+type Opacity = C.Opacity
+
+type Plain = C.Plain
+
+func Bind(window WindowHandle, texture TextureHandle, opacity Opacity, plain Plain) {
+  C.bind(window, texture, opacity, plain)
+}
@@ -0,0 +1,7 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int TextureHandle_INVALID = 0;
+
+  void bind(long window, int texture, float opacity, long plain);
+}
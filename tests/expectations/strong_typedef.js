@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const WindowHandle = 'uint64';
+
+const Opacity = 'float';
+
+const Plain = 'uint64';
+
+const TextureHandle_INVALID = 0;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    bind: ['void', ['uint64', 'uint32', 'float', 'uint64']],
+  });
+  return lib;
+}
+
+module.exports = {
+  WindowHandle,
+  Opacity,
+  Plain,
+  TextureHandle_INVALID,
+  load,
+};
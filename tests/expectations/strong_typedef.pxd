@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # A handle to a window.
+  ctypedef uint64_t WindowHandle
+
+  # A handle to a texture.
+  ctypedef uint32_t TextureHandle
+  const TextureHandle TextureHandle_INVALID
+
+  ctypedef float Opacity
+
+  ctypedef uint64_t Plain
+
+  void bind(WindowHandle window, TextureHandle texture, Opacity opacity, Plain plain)
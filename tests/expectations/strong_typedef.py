@@ -0,0 +1,20 @@
+import ctypes
+
+# A handle to a window.
+WindowHandle = ctypes.c_uint64
+
+# A handle to a texture.
+TextureHandle = ctypes.c_uint32
+TextureHandle_INVALID = 0
+
+Opacity = ctypes.c_float
+
+Plain = ctypes.c_uint64
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.bind.argtypes = [WindowHandle, TextureHandle, Opacity, Plain]
+  lib.bind.restype = None
+
+  return lib
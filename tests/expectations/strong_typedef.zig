@@ -0,0 +1,15 @@
+const std = @import("std");
+
+/// A handle to a window.
+pub const WindowHandle = u64;
+
+/// A handle to a texture.
+pub const TextureHandle = u32;
+
+pub const TextureHandle_INVALID: TextureHandle = 0;
+
+pub const Opacity = f32;
+
+pub const Plain = u64;
+
+pub extern fn bind(window: WindowHandle, texture: TextureHandle, opacity: Opacity, plain: Plain) void;
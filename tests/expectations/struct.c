@@ -10,6 +10,9 @@ typedef struct {
   float y;
 } Normal;
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 typedef struct {
   int32_t x;
   float y;
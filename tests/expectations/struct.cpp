@@ -10,6 +10,7 @@ struct Normal {
   float y;
 };
 
+/// Zero-sized fields elided: `z`, `w`.
 struct NormalWithZST {
   int32_t x;
   float y;
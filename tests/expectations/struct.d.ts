@@ -0,0 +1,47 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Normal {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Normal: StructType<Normal>;
+
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
+export interface NormalWithZST {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const NormalWithZST: StructType<NormalWithZST>;
+
+export interface TupleRenamed {
+  m0: number;
+  m1: number;
+  ref(): Buffer;
+}
+export declare const TupleRenamed: StructType<TupleRenamed>;
+
+export interface TupleNamed {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const TupleNamed: StructType<TupleNamed>;
+
+export interface Library {
+  root(a: Buffer | null, b: Normal, c: NormalWithZST, d: TupleRenamed, e: TupleNamed): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,19 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Opaque = C.Opaque
+
+type Normal = C.Normal
+
+// Zero-sized fields elided: `z`, `w`.
+type NormalWithZST = C.NormalWithZST
+
+type TupleRenamed = C.TupleRenamed
+
+type TupleNamed = C.TupleNamed
+
+func Root(a *Opaque, b Normal, c NormalWithZST, d TupleRenamed, e TupleNamed) {
+  C.root(a, b, c, d, e)
+}
@@ -0,0 +1,48 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Normal extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends Normal implements Structure.ByReference {}
+
+    public static class ByValue extends Normal implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `z`, `w`.
+   */
+  @Structure.FieldOrder({"x", "y"})
+  class NormalWithZST extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends NormalWithZST implements Structure.ByReference {}
+
+    public static class ByValue extends NormalWithZST implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"m0", "m1"})
+  class TupleRenamed extends Structure {
+    public int m0;
+    public float m1;
+
+    public static class ByReference extends TupleRenamed implements Structure.ByReference {}
+
+    public static class ByValue extends TupleRenamed implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class TupleNamed extends Structure {
+    public int x;
+    public float y;
+
+    public static class ByReference extends TupleNamed implements Structure.ByReference {}
+
+    public static class ByValue extends TupleNamed implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, Normal.ByValue b, NormalWithZST.ByValue c, TupleRenamed.ByValue d, TupleNamed.ByValue e);
+}
@@ -0,0 +1,40 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Normal = StructType();
+const NormalWithZST = StructType();
+const TupleRenamed = StructType();
+const TupleNamed = StructType();
+
+Normal.defineProperty('x', 'int32');
+Normal.defineProperty('y', 'float');
+
+NormalWithZST.defineProperty('x', 'int32');
+NormalWithZST.defineProperty('y', 'float');
+
+TupleRenamed.defineProperty('m0', 'int32');
+TupleRenamed.defineProperty('m1', 'float');
+
+TupleNamed.defineProperty('x', 'int32');
+TupleNamed.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', Normal, NormalWithZST, TupleRenamed, TupleNamed]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Normal,
+  NormalWithZST,
+  TupleRenamed,
+  TupleNamed,
+  load,
+};
@@ -0,0 +1,45 @@
+import ctypes
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Normal(ctypes.Structure):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `z`, `w`.
+class NormalWithZST(ctypes.Structure):
+  pass
+
+NormalWithZST._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+class TupleRenamed(ctypes.Structure):
+  pass
+
+TupleRenamed._fields_ = [
+  ("m0", ctypes.c_int32),
+  ("m1", ctypes.c_float),
+]
+
+class TupleNamed(ctypes.Structure):
+  pass
+
+TupleNamed._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST, TupleRenamed, TupleNamed]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,31 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Foo_FOO: Foo;
+
+export declare const Foo_FOO2: Foo;
+
+export declare const Foo_FOO3: Foo;
+
+export declare const BAR: Foo;
+
+export interface Foo {
+  a: number;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Foo: StructType<Foo>;
+
+export interface Library {
+  root(x: Foo, bar: never): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Bar = C.Bar
+
+type Foo = C.Foo
+
+func Root(x Foo, bar Bar) {
+  C.root(x, bar)
+}
@@ -0,0 +1,15 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"a", "b"})
+  class Foo extends Structure {
+    public int a;
+    public int b;
+
+    public static class ByReference extends Foo implements Structure.ByReference {}
+
+    public static class ByValue extends Foo implements Structure.ByValue {}
+  }
+
+  void root(Foo.ByValue x, Pointer bar);
+}
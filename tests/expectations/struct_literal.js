@@ -0,0 +1,37 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo = StructType();
+
+Foo.defineProperty('a', 'int32');
+Foo.defineProperty('b', 'uint32');
+
+const Foo_FOO = Foo({ a: 42, b: 47 });
+
+const Foo_FOO2 = Foo({ a: 42, b: 47 });
+
+const Foo_FOO3 = Foo({ a: 42, b: 47 });
+
+const BAR = Foo({ a: 42, b: 1337 });
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo, 'void']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo,
+  Foo_FOO,
+  Foo_FOO2,
+  Foo_FOO3,
+  BAR,
+  load,
+};
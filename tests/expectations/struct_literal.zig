@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const BAR: Foo = Foo{ .a = 42, .b = 1337 };
+
+pub const Bar = opaque {};
+
+pub const Foo = extern struct {
+  a: i32,
+  b: u32,
+};
+
+pub const Foo_FOO: Foo = Foo{ .a = 42, .b = 47 };
+
+pub const Foo_FOO2: Foo = Foo{ .a = 42, .b = 47 };
+
+pub const Foo_FOO3: Foo = Foo{ .a = 42, .b = 47 };
+
+pub extern fn root(x: Foo, bar: Bar) void;
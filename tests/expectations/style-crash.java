@@ -0,0 +1,3 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {}
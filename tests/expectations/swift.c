@@ -0,0 +1,42 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum SWIFT_NAME(Shape) FfiShape {
+  Circle,
+  Square,
+};
+typedef uint8_t FfiShape;
+
+typedef struct {
+  uint8_t *_Nullable data;
+  uintptr_t len;
+} Buffer;
+
+typedef struct SWIFT_NAME(Point) {
+  float x;
+  float y;
+} FfiPoint;
+
+void buffer_clear(Buffer *_Nullable buffer) SWIFT_NAME(Buffer.clear(self:));
+
+void buffer_fill(Buffer *_Nonnull buffer,
+                 uint8_t value,
+                 void (*_Nullable callback)(const uint8_t*_Nullable))
+SWIFT_NAME(bufferFill(buffer:value:callback:));
+
+float point_distance(const FfiPoint *_Nonnull a,
+                     const FfiPoint *_Nonnull b)
+SWIFT_NAME(pointDistance(a:b:));
+
+float shape_area(FfiShape shape, float size) SWIFT_NAME(shapeArea(shape:size:));
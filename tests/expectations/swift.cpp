@@ -0,0 +1,45 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class SWIFT_NAME(Shape) FfiShape : uint8_t {
+  Circle,
+  Square,
+};
+
+struct Buffer {
+  uint8_t *_Nullable data;
+  uintptr_t len;
+};
+
+struct SWIFT_NAME(Point) FfiPoint {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+void buffer_clear(Buffer *_Nullable buffer) SWIFT_NAME(Buffer.clear(self:));
+
+void buffer_fill(Buffer *_Nonnull buffer,
+                 uint8_t value,
+                 void (*_Nullable callback)(const uint8_t*_Nullable))
+SWIFT_NAME(bufferFill(buffer:value:callback:));
+
+float point_distance(const FfiPoint *_Nonnull a,
+                     const FfiPoint *_Nonnull b)
+SWIFT_NAME(pointDistance(a:b:));
+
+float shape_area(FfiShape shape, float size) SWIFT_NAME(shapeArea(shape:size:));
+
+} // extern "C"
@@ -0,0 +1,50 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const FfiShape: {
+  readonly Circle: 0;
+  readonly Square: 1;
+};
+
+export interface Buffer {
+  data: Buffer;
+  len: number | string;
+  ref(): Buffer;
+}
+export declare const Buffer: StructType<Buffer>;
+
+export interface FfiPoint {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const FfiPoint: StructType<FfiPoint>;
+
+export interface Library {
+  buffer_clear(buffer: Buffer | null): void;
+
+  buffer_fill(buffer: Buffer | null, value: number, callback: Buffer | null): void;
+
+  point_distance(a: Buffer | null, b: Buffer | null): number;
+
+  shape_area(shape: number, size: number): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,40 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type FfiShape = C.FfiShape
+
+const (
+  Circle FfiShape = C.Circle
+  Square FfiShape = C.Square
+)
+
+type Buffer = C.Buffer
+
+type FfiPoint = C.FfiPoint
+
+func BufferClear(buffer *Buffer) {
+  C.buffer_clear(buffer)
+}
+
+func BufferFill(buffer *Buffer, value uint8, callback *[0]byte) {
+  C.buffer_fill(buffer, C.uint8_t(value), callback)
+}
+
+func PointDistance(a *FfiPoint, b *FfiPoint) float32 {
+  return float32(C.point_distance(a, b))
+}
+
+func ShapeArea(shape FfiShape, size float32) float32 {
+  return float32(C.shape_area(shape, C.float(size)))
+}
@@ -0,0 +1,55 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface FfiShape {
+    byte Circle = 0;
+    byte Square = 1;
+  }
+
+  @Structure.FieldOrder({"data", "len"})
+  class Buffer extends Structure {
+    public Pointer data;
+    public SizeT len;
+
+    public static class ByReference extends Buffer implements Structure.ByReference {}
+
+    public static class ByValue extends Buffer implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class FfiPoint extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends FfiPoint implements Structure.ByReference {}
+
+    public static class ByValue extends FfiPoint implements Structure.ByValue {}
+  }
+
+  void buffer_clear(Buffer.ByReference buffer);
+
+  void buffer_fill(Buffer.ByReference buffer, byte value, Pointer callback);
+
+  float point_distance(FfiPoint.ByReference a, FfiPoint.ByReference b);
+
+  float shape_area(byte shape, float size);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,48 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Buffer = StructType();
+const FfiPoint = StructType();
+
+Buffer.defineProperty('data', ref.refType('uint8'));
+Buffer.defineProperty('len', 'size_t');
+
+FfiPoint.defineProperty('x', 'float');
+FfiPoint.defineProperty('y', 'float');
+
+const FfiShape = Object.freeze({
+  Circle: 0,
+  Square: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    buffer_clear: ['void', [ref.refType(Buffer)]],
+    buffer_fill: ['void', [ref.refType(Buffer), 'uint8', 'pointer']],
+    point_distance: ['float', [ref.refType(FfiPoint), ref.refType(FfiPoint)]],
+    shape_area: ['float', ['uint8', 'float']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Buffer,
+  FfiPoint,
+  FfiShape,
+  load,
+};
@@ -0,0 +1,38 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    Circle
+    Square
+  ctypedef uint8_t FfiShape
+
+  ctypedef struct Buffer:
+    uint8_t *data
+    uintptr_t len
+
+  ctypedef struct FfiPoint:
+    float x
+    float y
+
+  void buffer_clear(Buffer *buffer)
+
+  void buffer_fill(Buffer *buffer, uint8_t value, void (*callback)(const uint8_t*))
+
+  float point_distance(const FfiPoint *a, const FfiPoint *b)
+
+  float shape_area(FfiShape shape, float size)
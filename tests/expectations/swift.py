@@ -0,0 +1,47 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+import ctypes
+
+FfiShape = ctypes.c_uint8
+Circle = 0
+Square = 1
+
+class Buffer(ctypes.Structure):
+  pass
+
+Buffer._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+class FfiPoint(ctypes.Structure):
+  pass
+
+FfiPoint._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.buffer_clear.argtypes = [ctypes.POINTER(Buffer)]
+  lib.buffer_clear.restype = None
+
+  lib.buffer_fill.argtypes = [ctypes.POINTER(Buffer), ctypes.c_uint8, ctypes.CFUNCTYPE(None, ctypes.POINTER(ctypes.c_uint8))]
+  lib.buffer_fill.restype = None
+
+  lib.point_distance.argtypes = [ctypes.POINTER(FfiPoint), ctypes.POINTER(FfiPoint)]
+  lib.point_distance.restype = ctypes.c_float
+
+  lib.shape_area.argtypes = [FfiShape, ctypes.c_float]
+  lib.shape_area.restype = ctypes.c_float
+
+  return lib
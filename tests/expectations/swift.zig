@@ -0,0 +1,33 @@
+#if defined(__clang__)
+#define SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+#else
+#define SWIFT_NAME(_name)
+#define _Nonnull
+#define _Nullable
+#endif
+
+
+const std = @import("std");
+
+pub const FfiShape = enum(u8) {
+  Circle,
+  Square,
+};
+
+pub const Buffer = extern struct {
+  data: [*c]u8,
+  len: usize,
+};
+
+pub const FfiPoint = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn buffer_clear(buffer: [*c]Buffer) void;
+
+pub extern fn buffer_fill(buffer: *Buffer, value: u8, callback: ?*const fn ([*c]const u8) callconv(.C) void) void;
+
+pub extern fn point_distance(a: *const FfiPoint, b: *const FfiPoint) f32;
+
+pub extern fn shape_area(shape: FfiShape, size: f32) f32;
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Variant {
+  uint8_t kind;
+  /**
+   * The value of the variant.
+   */
+  union {
+    int64_t integer;
+    double real;
+    struct {
+      float x;
+      float y;
+    };
+  };
+};
+
+/**
+ * Used by both `root` and `Holder`, so it stays named.
+ */
+union Shared {
+  int32_t a;
+  uint32_t b;
+};
+
+struct Holder {
+  union Shared shared;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Variant variant, struct Holder holder, union Shared shared);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
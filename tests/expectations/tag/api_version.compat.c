@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MYLIB_API_VERSION_LATEST 0x0103
+#ifndef MYLIB_API_VERSION
+#  define MYLIB_API_VERSION MYLIB_API_VERSION_LATEST
+#endif
+
+#define BUFFER_SIZE 256
+
+#if MYLIB_API_VERSION >= 0x0102
+#define MAX_STREAMS 16
+#endif
+
+struct Handle {
+  uint32_t id;
+};
+
+#if MYLIB_API_VERSION >= 0x0102
+struct Stream {
+  struct Handle handle;
+  uint64_t position;
+};
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Handle open(uint32_t id);
+
+#if MYLIB_API_VERSION >= 0x0102
+struct Stream open_stream(struct Handle handle);
+#endif
+
+#if MYLIB_API_VERSION >= 0x0103
+void seek(struct Stream *stream, uint64_t position);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -7,6 +7,7 @@ struct Foo {
 
 };
 #define Foo_GA 10
-#define Foo_ZO 3.14
+#define Foo_BU "hello world"
+#define Foo_ZO 3.14f
 
 void root(struct Foo x);
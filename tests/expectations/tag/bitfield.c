@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct TooWide;
+
+struct Status {
+  bool ready : 1;
+  uint8_t code : 7;
+};
+
+/**
+ * A control register.
+ */
+struct Control {
+  /**
+   * The operating mode.
+   */
+  uint32_t mode : 3;
+  uint32_t prescaler : 5;
+  uint32_t reload : 24;
+};
+
+struct Status configure(struct Control control);
+
+void validate(const struct TooWide *value);
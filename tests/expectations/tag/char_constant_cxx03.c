@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define EMOJI U'\U0001F600'
+
+#define E_ACUTE U'\u00E9'
+
+#define TAB '\t'
+
+void root(void);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint32_t rune_t;
+typedef const char *utf8_str;
+
+struct Label {
+  utf8_str text;
+  char *buffer;
+  char initial;
+  rune_t symbol;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void label_names(const utf8_str *names, uintptr_t count);
+
+struct Label label_new(utf8_str text, rune_t symbol);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -3,11 +3,13 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+#define BAR "hello world"
+
 #define DELIMITER ':'
 
 #define FOO 10
 
-#define HEART L'\u2764'
+#define HEART U'\u2764'
 
 #define LEFTCURLY '{'
 
@@ -21,7 +23,7 @@
 
 #define TAB '\t'
 
-#define ZOM 3.14
+#define ZOM 3.14f
 
 struct Foo {
   int32_t x[FOO];
@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum OldFlags {
+  A,
+  B,
+};
+typedef uint8_t OldFlags __attribute__((deprecated("Use Flags instead")));
+
+enum __attribute__((deprecated("Use \"Mode\" instead"))) OldMode {
+  Fast,
+  Slow,
+};
+
+struct __attribute__((deprecated)) OldPoint {
+  int32_t x;
+  int32_t y;
+};
+
+enum OldShape_Tag {
+  Circle,
+  Square,
+};
+typedef uint8_t OldShape_Tag;
+
+struct Circle_Body {
+  OldShape_Tag tag;
+  float _0;
+};
+
+struct Square_Body {
+  OldShape_Tag tag;
+  float _0;
+};
+
+union __attribute__((deprecated)) OldShape {
+  enum OldShape_Tag tag;
+  struct Circle_Body circle;
+  struct Square_Body square;
+};
+
+__attribute__((deprecated("Use new_point instead"))) int32_t old_point(void);
+
+__attribute__((deprecated))
+void old_root(const struct OldPoint *a,
+              enum OldMode b,
+              OldFlags c,
+              const union OldShape *d);
@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 18446744073709551615ULL
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+};
+typedef uint8_t Direction;
+
+typedef bool (*Callback)(uint32_t version, char *data);
+
+struct Options {
+  /**
+   * Fields named after D keywords are escaped.
+   */
+  uint32_t module;
+  long version;
+  Callback callback;
+  const char *name;
+  uint8_t grid[3][2];
+};
+
+enum Event_Tag {
+  Moved,
+  Renamed,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+struct Moved_Body {
+  Direction _0;
+};
+
+struct Renamed_Body {
+  const char *name;
+};
+
+struct Event {
+  enum Event_Tag tag;
+  union {
+    struct Moved_Body moved;
+    struct Renamed_Body renamed;
+  };
+};
+
+extern uint32_t COUNTER;
+
+void process(const struct Options *options, struct Event event, int64_t *ref_, uint8_t *out);
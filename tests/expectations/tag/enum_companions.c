@@ -0,0 +1,69 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green = 4,
+  Blue,
+};
+typedef uint8_t Color;
+
+enum Direction {
+  North,
+  South,
+};
+
+enum Level {
+  Low,
+  High,
+};
+
+enum Shape_Tag {
+  Circle,
+  Point,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  Shape_Tag tag;
+  float _0;
+};
+
+union Shape {
+  enum Shape_Tag tag;
+  struct Circle_Body circle;
+};
+
+#define COLOR_FOR_EACH(V) \
+  V(Red) \
+  V(Green) \
+  V(Blue)
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+#define DIRECTION_FOR_EACH(V) \
+  V(North) \
+  V(South)
+
+static inline const char *Direction_to_string(enum Direction value) {
+  switch (value) {
+    case North: return "North";
+    case South: return "South";
+  }
+  return NULL;
+}
+
+#define LEVEL_FOR_EACH(V) \
+  V(Low) \
+  V(High)
+
+void root(Color a, enum Direction b, enum Level c, union Shape d);
@@ -0,0 +1,54 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Level
+#ifdef __cplusplus
+  : int8_t
+#endif // __cplusplus
+ {
+  Low = -1,
+  Medium,
+  High = 10,
+};
+#ifndef __cplusplus
+typedef int8_t Level;
+#endif // __cplusplus
+
+enum Offset
+#ifdef __cplusplus
+  : int8_t
+#endif // __cplusplus
+ {
+  Back = -0x10,
+  Here,
+};
+#ifndef __cplusplus
+typedef int8_t Offset;
+#endif // __cplusplus
+
+enum Status
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  Ok = 0x0,
+  Pending = 0x1,
+  Failed = 0x10,
+  Aborted = 0x11,
+  Fatal = 0xFF,
+};
+#ifndef __cplusplus
+typedef uint32_t Status;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Status status, Level level, Offset offset);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
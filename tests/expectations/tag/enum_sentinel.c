@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Direction {
+  North,
+  South,
+
+  Sentinel /* this must be last for serialization purposes. */
+};
+
+enum Shade {
+  Light = 2,
+  Dark,
+};
+typedef uint8_t Shade;
+
+void root(enum Direction direction, Shade shade);
@@ -3,10 +3,16 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Zero-sized fields elided: `1`.
+ */
 struct TypedLength_f32__UnknownUnit {
   float _0;
 };
 
+/**
+ * Zero-sized fields elided: `1`.
+ */
 struct TypedLength_f32__LayoutUnit {
   float _0;
 };
@@ -15,6 +21,9 @@ typedef struct TypedLength_f32__UnknownUnit Length_f32;
 
 typedef struct TypedLength_f32__LayoutUnit LayoutLength;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedSideOffsets2D_f32__UnknownUnit {
   float top;
   float right;
@@ -22,6 +31,9 @@ struct TypedSideOffsets2D_f32__UnknownUnit {
   float left;
 };
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedSideOffsets2D_f32__LayoutUnit {
   float top;
   float right;
@@ -33,11 +45,17 @@ typedef struct TypedSideOffsets2D_f32__UnknownUnit SideOffsets2D_f32;
 
 typedef struct TypedSideOffsets2D_f32__LayoutUnit LayoutSideOffsets2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedSize2D_f32__UnknownUnit {
   float width;
   float height;
 };
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedSize2D_f32__LayoutUnit {
   float width;
   float height;
@@ -47,11 +65,17 @@ typedef struct TypedSize2D_f32__UnknownUnit Size2D_f32;
 
 typedef struct TypedSize2D_f32__LayoutUnit LayoutSize2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedPoint2D_f32__UnknownUnit {
   float x;
   float y;
 };
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedPoint2D_f32__LayoutUnit {
   float x;
   float y;
@@ -61,11 +85,17 @@ typedef struct TypedPoint2D_f32__UnknownUnit Point2D_f32;
 
 typedef struct TypedPoint2D_f32__LayoutUnit LayoutPoint2D;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedRect_f32__UnknownUnit {
   struct TypedPoint2D_f32__UnknownUnit origin;
   struct TypedSize2D_f32__UnknownUnit size;
 };
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedRect_f32__LayoutUnit {
   struct TypedPoint2D_f32__LayoutUnit origin;
   struct TypedSize2D_f32__LayoutUnit size;
@@ -75,6 +105,9 @@ typedef struct TypedRect_f32__UnknownUnit Rect_f32;
 
 typedef struct TypedRect_f32__LayoutUnit LayoutRect;
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
   float m11;
   float m12;
@@ -84,6 +117,9 @@ struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
   float m32;
 };
 
+/**
+ * Zero-sized fields elided: `_phantom`.
+ */
 struct TypedTransform2D_f32__LayoutUnit__UnknownUnit {
   float m11;
   float m12;
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Surface {
+  uint32_t id;
+};
+
+struct Window {
+  uint32_t id;
+};
+
+struct Surface surface_new(uint32_t id);
+
+struct Window window_new(uint32_t id);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Surface {
+  uint32_t id;
+};
+
+struct Window {
+  uint32_t id;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Surface surface_new(uint32_t id);
+
+struct Window window_new(uint32_t id);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "time.h"
+#include "signal.h"
+
+struct Record {
+  time_t created;
+  sig_atomic_t flags;
+};
+
+void write_record(const struct Record *record, sig_atomic_t flags);
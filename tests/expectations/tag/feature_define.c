@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(MYLIB_FEATURE_LEGACY)
+#define LEGACY_VERSION 2
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+struct Speaker {
+  uint8_t volume;
+};
+#endif
+
+#if defined(MYLIB_SERDE)
+void load(const uint8_t *data, uintptr_t len);
+#endif
+
+#if (defined(MYLIB_FEATURE_LEGACY) && defined(MYLIB_SERDE))
+void load_legacy(const uint8_t *data, uintptr_t len);
+#endif
+
+#if defined(MYLIB_FEATURE_AUDIO_OUT)
+void play(struct Speaker speaker);
+#endif
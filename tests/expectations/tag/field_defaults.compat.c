@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Options {
+  int32_t level;
+  bool verbose;
+  float ratio;
+  const uint8_t *name;
+};
+
+static inline struct Options options_default(void) {
+  struct Options value = {0};
+  value.level = 3;
+  value.verbose = true;
+  value.ratio = 0.5;
+  return value;
+}
+
+struct Plain {
+  int32_t x;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Options options, struct Plain plain);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Header {
+  uint32_t magic;
+  uint16_t version;
+  uint8_t flags;
+  uint64_t length;
+};
+
+#define HEADER_OFFSETOF_MAGIC offsetof(struct Header, magic)
+#define HEADER_OFFSETOF_VERSION offsetof(struct Header, version)
+#define HEADER_OFFSETOF_FLAGS offsetof(struct Header, flags)
+#define HEADER_OFFSETOF_LENGTH offsetof(struct Header, length)
+
+struct Record {
+  uint8_t id;
+  double value;
+  int32_t count;
+};
+
+#if UINTPTR_MAX == UINT64_MAX
+#define RECORD_OFFSETOF_ID 0
+#define RECORD_OFFSETOF_VALUE 8
+#define RECORD_OFFSETOF_COUNT 16
+#endif
+
+/**
+ * Pointers differ in size between data models.
+ */
+struct Node {
+  uint8_t tag;
+  const uint8_t *data;
+  uint32_t weight;
+};
+
+#if UINTPTR_MAX == UINT64_MAX
+#define NODE_OFFSETOF_TAG 0
+#define NODE_OFFSETOF_DATA 8
+#define NODE_OFFSETOF_WEIGHT 16
+#elif UINTPTR_MAX == UINT32_MAX
+#define NODE_OFFSETOF_TAG 0
+#define NODE_OFFSETOF_DATA 4
+#define NODE_OFFSETOF_WEIGHT 8
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Header header, struct Record record, struct Node node);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
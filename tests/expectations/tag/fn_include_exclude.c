@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct AudioBuffer {
+  float *samples;
+  uintptr_t len;
+};
+
+extern float audio_volume;
+
+void audio_play(const struct AudioBuffer *buffer);
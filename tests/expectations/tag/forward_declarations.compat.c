@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Child;
+
+struct Handle;
+
+struct Data {
+  uint8_t value;
+};
+
+struct Node {
+  struct Node *next;
+};
+
+struct Parent {
+  const struct Child *first_child;
+  uint32_t id;
+};
+
+struct Child {
+  const struct Parent *parent;
+  struct Child *next_sibling;
+  struct Data data;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void read(struct Data data);
+
+void walk(struct Parent parent, struct Node node, const struct Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
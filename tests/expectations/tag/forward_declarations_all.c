@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Data;
+struct Child;
+struct Node;
+struct Parent;
+
+struct Handle;
+
+struct Data {
+  uint8_t value;
+};
+
+struct Child {
+  const struct Parent *parent;
+  struct Child *next_sibling;
+  struct Data data;
+};
+
+struct Node {
+  struct Node *next;
+};
+
+struct Parent {
+  const struct Child *first_child;
+  uint32_t id;
+};
+
+void read(struct Data data);
+
+void walk(struct Parent parent, struct Node node, const struct Handle *handle);
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Foo_u32 {
+  uint32_t a;
+};
+
+struct Foo_u8 {
+  uint8_t a;
+};
+
+struct Pair_i16__i16 {
+  int16_t key;
+  int16_t value;
+};
+
+struct Pair_Foo_u32_____Foo_u32 {
+  struct Foo_u32 key;
+  struct Foo_u32 value;
+};
+
+typedef struct Pair_Foo_u32_____Foo_u32 Bar_Foo_u32;
+
+struct Pair_u8__u8 {
+  uint8_t key;
+  uint8_t value;
+};
+
+struct Holder {
+  struct Foo_u32 foo;
+  struct Pair_u8__u8 pair;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Foo_u32 a,
+          struct Foo_u8 b,
+          struct Pair_i16__i16 c,
+          Bar_Foo_u32 d,
+          struct Holder e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,60 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED true
+
+#define LIMIT 1024
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+};
+typedef uint8_t Direction;
+
+enum Mode {
+  Fast,
+  Safe,
+};
+
+struct Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+struct Options {
+  uint32_t size;
+  Callback callback;
+  const char *name;
+};
+
+enum Event_Tag {
+  Moved,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+struct Moved_Body {
+  Direction _0;
+};
+
+struct Event {
+  enum Event_Tag tag;
+  union {
+    struct Moved_Body moved;
+  };
+};
+
+void *mylib_data(struct Handle *handle, int range);
+
+/**
+ * Opens a handle.
+ */
+struct Handle *mylib_open(const struct Options *options, enum Mode mode);
+
+int64_t mylib_read(struct Handle *handle, uint8_t *buf, uintptr_t len);
+
+bool mylib_send(struct Handle *handle, struct Event event, float scale);
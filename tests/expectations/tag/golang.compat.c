@@ -0,0 +1,80 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED true
+
+#define LIMIT 1024
+
+/**
+ * The direction of a move.
+ */
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+enum Mode {
+  Fast,
+  Safe,
+};
+
+struct Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+struct Options {
+  uint32_t size;
+  Callback callback;
+  const char *name;
+};
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Moved,
+  Closed,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+struct Moved_Body {
+  Direction _0;
+};
+
+struct Event {
+  enum Event_Tag tag;
+  union {
+    struct Moved_Body moved;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void *mylib_data(struct Handle *handle, int range);
+
+/**
+ * Opens a handle.
+ */
+struct Handle *mylib_open(const struct Options *options, enum Mode mode);
+
+int64_t mylib_read(struct Handle *handle, uint8_t *buf, uintptr_t len);
+
+bool mylib_send(struct Handle *handle, struct Event event, float scale);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
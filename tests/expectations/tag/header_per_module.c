@@ -0,0 +1,18 @@
+#ifndef HEADER_PER_MODULE_H
+#define HEADER_PER_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "header_per_module_geometry.c"
+#include "header_per_module_units.c"
+
+struct Scene {
+  struct Rect bounds;
+  uint32_t count;
+};
+
+struct Rect scene_bounds(const struct Scene *scene);
+
+#endif /* HEADER_PER_MODULE_H */
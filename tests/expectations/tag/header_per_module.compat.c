@@ -0,0 +1,26 @@
+#ifndef HEADER_PER_MODULE_H
+#define HEADER_PER_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "header_per_module.compat_geometry.c"
+#include "header_per_module.compat_units.c"
+
+struct Scene {
+  struct Rect bounds;
+  uint32_t count;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Rect scene_bounds(const struct Scene *scene);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* HEADER_PER_MODULE_H */
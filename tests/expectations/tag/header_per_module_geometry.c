@@ -0,0 +1,22 @@
+#ifndef HEADER_PER_MODULE_H_GEOMETRY
+#define HEADER_PER_MODULE_H_GEOMETRY
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "header_per_module_units.c"
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Rect {
+  struct Point origin;
+  struct Size size;
+};
+
+float rect_area(const struct Rect *rect);
+
+#endif /* HEADER_PER_MODULE_H_GEOMETRY */
@@ -0,0 +1,13 @@
+#include <stdbool.h>
+#include <stdint.h>
+#include <math.h>
+
+#define SCALE INFINITY
+
+struct Point {
+  int32_t x;
+  int32_t y;
+  bool visible;
+};
+
+double area(const struct Point *p, uintptr_t len);
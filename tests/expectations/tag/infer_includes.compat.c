@@ -0,0 +1,21 @@
+#include <stdbool.h>
+#include <stdint.h>
+#include <math.h>
+
+#define SCALE INFINITY
+
+struct Point {
+  int32_t x;
+  int32_t y;
+  bool visible;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+double area(const struct Point *p, uintptr_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HEADER_MAGIC 0x4844
+
+/* Constants */
+
+#define VERSION 3
+
+enum Mode {
+  Fast,
+  Slow,
+};
+
+#define MODE_DEFAULT Fast
+
+#pragma pack(push, 1)
+
+struct Header {
+  enum Mode mode;
+  uint32_t size;
+};
+
+#pragma pack(pop)
+
+/* End of types */
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* Functions */
+
+bool read_header(struct Header *header);
+
+bool write_header(const struct Header *header);
+
+#define write_header_v1 write_header
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+static inline int32_t multiply(int32_t a, int32_t b) {
+  return a * b;
+}
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+static inline int32_t point_dot(const struct Point *a, const struct Point *b) {
+  int32_t x = a->x * b->x;
+  int32_t y = a->y * b->y;
+  return x + y;
+}
+
+/**
+ * Returns the horizontal coordinate of the point.
+ */
+static inline int32_t point_x(const struct Point *point) {
+  return point->x;
+}
+
+struct Rect {
+  struct Point origin;
+  struct Point size;
+};
+
+static inline bool rect_is_empty(const struct Rect *rect) {
+  return rect->size.x == 0 || rect->size.y == 0;
+}
+
+int32_t area(struct Rect rect);
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Vec2_u32 {
+  uint32_t x;
+  uint32_t y;
+};
+
+struct Vec2_f32 {
+  float x;
+  float y;
+};
+
+struct Vec2_i32 {
+  int32_t x;
+  int32_t y;
+};
+
+struct Vec2_f64 {
+  double x;
+  double y;
+};
+
+struct Pair_u8__Vec2_f64 {
+  uint8_t first;
+  struct Vec2_f64 second;
+};
+
+uint32_t length(struct Vec2_u32 v);
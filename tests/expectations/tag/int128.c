@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define KEY_BITS 128
+
+struct Key {
+  unsigned __int128 id;
+  __int128 offset;
+};
+
+__int128 key_hash(const struct Key *key, unsigned __int128 seed);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct I128 {
+  uint64_t lo;
+  int64_t hi;
+};
+
+struct U128 {
+  uint64_t lo;
+  uint64_t hi;
+};
+
+struct Key {
+  struct U128 id;
+  struct I128 offset;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct I128 key_hash(const struct Key *key, struct U128 seed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
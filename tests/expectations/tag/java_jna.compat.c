@@ -0,0 +1,87 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LETTER 'j'
+
+#define MASK 18446744073709551615ULL
+
+#define MAX_POINTS 8
+
+#define SCALE 1.5f
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green = 5,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+/**
+ * A point.
+ */
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Shape {
+  struct Point points[8];
+  double weights[4];
+  Color color;
+  uintptr_t len;
+  struct Point *origin;
+  bool package;
+};
+
+typedef bool (*Visitor)(const struct Point *point, uint8_t *user_data);
+
+enum Value_Tag {
+  Number,
+  Pair,
+  Empty,
+};
+
+struct Number_Body {
+  int32_t _0;
+};
+
+struct Pair_Body {
+  struct Point _0;
+  struct Point _1;
+};
+
+struct Value {
+  enum Value_Tag tag;
+  union {
+    struct Number_Body number;
+    struct Pair_Body pair;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+/**
+ * Draws `shape`.
+ * # Arguments
+ *  `shape` - The [`Shape`] to draw.
+ *  `label` - A label to draw next to it.
+ */
+intptr_t draw(const struct Shape *shape, const char *label);
+
+struct Point visit(struct Shape shape, Visitor visitor, struct Value value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,75 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LETTER 'j'
+
+#define MASK 18446744073709551615ULL
+
+#define MAX_POINTS 8
+
+#define SCALE 1.5f
+
+enum Color {
+  Red,
+  Green = 5,
+  Blue,
+};
+typedef uint8_t Color;
+
+/**
+ * A point.
+ */
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+enum Value_Tag {
+  Number,
+  Pair,
+  Empty,
+};
+
+struct Number_Body {
+  int32_t _0;
+};
+
+struct Pair_Body {
+  struct Point _0;
+  struct Point _1;
+};
+
+struct Value {
+  enum Value_Tag tag;
+  union {
+    struct Number_Body number;
+    struct Pair_Body pair;
+  };
+};
+
+typedef bool (*Visitor)(const struct Point *point, uint8_t *user_data);
+
+struct Shape {
+  struct Point points[8];
+  double weights[4];
+  Color color;
+  uintptr_t len;
+  struct Point *origin;
+  bool package;
+};
+
+extern uint32_t COUNTER;
+
+Color color(struct Value value);
+
+uint64_t count(Visitor visitor, const struct Shape *shape);
+
+/**
+ * Draws `shape`.
+ * # Arguments
+ *  `shape` - The [`Shape`] to draw.
+ *  `label` - A label to draw next to it.
+ */
+intptr_t draw(const struct Shape *shape, const char *label);
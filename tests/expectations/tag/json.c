@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT (1 << 10)
+
+/**
+ * The direction of a move.
+ */
+enum Direction {
+  Up,
+  Down = 4,
+  Left,
+};
+typedef uint8_t Direction;
+
+typedef bool (*Callback)(uintptr_t len);
+
+struct Options {
+  uint32_t size;
+  double scale;
+  const char *name;
+  Callback callback;
+  uint8_t grid[3];
+};
+
+struct __attribute__((packed)) Header {
+  uint8_t tag;
+  uint32_t length;
+};
+
+enum Event_Tag {
+  Moved,
+  Closed,
+};
+typedef uint8_t Event_Tag;
+
+struct Moved_Body {
+  Direction _0;
+};
+
+struct Event {
+  enum Event_Tag tag;
+  union {
+    struct Moved_Body moved;
+  };
+};
+
+extern uint32_t COUNTER;
+
+/**
+ * Processes an event.
+ */
+int32_t process(const struct Options *options, struct Header header, struct Event event);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define small_ 1
+
+enum Mode {
+  near_,
+  stdin_,
+};
+
+struct far_ {
+  int32_t delete_;
+  int32_t min_;
+};
+
+union Value {
+  int32_t class_;
+  float max_;
+};
+
+void keyword_collisions(struct far_ new_, union Value errno_, enum Mode mode);
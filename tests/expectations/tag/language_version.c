@@ -0,0 +1,132 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+#ifndef CBINDGEN_NORETURN
+#  if defined(__cplusplus)
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 202311L
+#    define CBINDGEN_NORETURN [[noreturn]]
+#  elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#    define CBINDGEN_NORETURN _Noreturn
+#  elif defined(__GNUC__)
+#    define CBINDGEN_NORETURN __attribute__((noreturn))
+#  elif defined(_MSC_VER)
+#    define CBINDGEN_NORETURN __declspec(noreturn)
+#  else
+#    define CBINDGEN_NORETURN
+#  endif
+#endif
+
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+
+enum Level {
+  Low,
+  High,
+};
+typedef uint8_t Level;
+
+struct Engine;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Rect_Body {
+  float width;
+  float height;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Rect_Body rect;
+  };
+};
+
+struct Pair_i32 {
+  int32_t first;
+  int32_t second;
+};
+
+struct Pair_Pair_i32 {
+  struct Pair_i32 first;
+  struct Pair_i32 second;
+};
+
+typedef struct Pair_Pair_i32 Pairs;
+
+enum Token_Tag {
+  Number,
+  Eof,
+};
+typedef uint8_t Token_Tag;
+
+struct Number_Body {
+  Token_Tag tag;
+  int32_t _0;
+};
+
+union Token {
+  enum Token_Tag tag;
+  struct Number_Body number;
+};
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(struct Pair_i32) == 8, "unexpected size of Pair_i32");
+CBINDGEN_STATIC_ASSERT(offsetof(struct Pair_i32, first) == 0, "unexpected offset of Pair_i32::first");
+CBINDGEN_STATIC_ASSERT(offsetof(struct Pair_i32, second) == 4, "unexpected offset of Pair_i32::second");
+
+CBINDGEN_STATIC_ASSERT(sizeof(struct Pair_Pair_i32) == 16, "unexpected size of Pair_Pair_i32");
+CBINDGEN_STATIC_ASSERT(offsetof(struct Pair_Pair_i32, first) == 0, "unexpected offset of Pair_Pair_i32::first");
+CBINDGEN_STATIC_ASSERT(offsetof(struct Pair_Pair_i32, second) == 8, "unexpected offset of Pair_Pair_i32::second");
+
+static inline const char *Color_to_string(enum Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+  }
+  return NULL;
+}
+
+static inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Low: return "Low";
+    case High: return "High";
+  }
+  return NULL;
+}
+
+float area(struct Shape shape, Pairs pairs, union Token token);
+
+struct Engine *engine_new(void);
+
+CBINDGEN_NORETURN void fatal(void);
+
+void old_api(enum Color color, Level level);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+enum Level {
+  Low,
+  High,
+};
+typedef uint8_t Level;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+_Static_assert(sizeof(struct Point) == 8, "unexpected size of Point");
+_Static_assert(offsetof(struct Point, x) == 0, "unexpected offset of Point::x");
+_Static_assert(offsetof(struct Point, y) == 4, "unexpected offset of Point::y");
+
+void draw(struct Point point, Level level);
+
+_Noreturn void fatal(void);
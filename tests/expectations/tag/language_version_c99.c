@@ -0,0 +1,67 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green,
+  Blue
+};
+
+enum Level {
+  Low,
+  High
+};
+typedef uint8_t Level;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Rect_Body {
+  float width;
+  float height;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Rect_Body rect;
+  } body;
+};
+
+enum Token_Tag {
+  Number,
+  Eof
+};
+typedef uint8_t Token_Tag;
+
+struct Number_Body {
+  Token_Tag tag;
+  int32_t _0;
+};
+
+union Token {
+  enum Token_Tag tag;
+  struct Number_Body number;
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+float area(struct Shape shape, union Token token);
+
+void draw(struct Point point, enum Color color, Level level);
+
+__attribute__((noreturn)) void fatal(void);
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node {
+  int32_t value;
+};
+
+struct Tree {
+  /**
+   * Owned `Box`.
+   */
+  struct Node *root;
+  /**
+   * Owned `Box`, or null for `None`.
+   */
+  struct Node *left;
+};
+
+/**
+ * `tree`: Owned `Box`, or null for `None`.
+ */
+void tree_free(struct Tree *tree);
+
+/**
+ * `root`: Owned `Box`.
+ * Return value: Owned `Box`.
+ */
+struct Tree *tree_new(struct Node *root);
@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define DEFAULT_WIDTH 640
+
+#define MAX_WINDOWS 16
+
+#if defined(WITH_SURFACES)
+struct Overlay {
+  uint32_t id;
+};
+#endif
+
+struct Surface {
+  uint32_t id;
+};
+
+struct Window {
+  uint32_t id;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(WITH_SURFACES)
+struct Overlay overlay_new(uint32_t id);
+#endif
+
+struct Surface surface_new(uint32_t id);
+
+struct Window window_new(uint32_t id);
+
+float window_scale(const struct Window *this_);
+
+/**
+ * The width of the window.
+ */
+uint32_t window_width(const struct Window *this_);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
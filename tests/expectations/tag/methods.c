@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Counter;
+
+struct Point {
+  float x;
+  float y;
+};
+
+void counter_add(struct Counter *self, uint32_t amount);
+
+/**
+ * Returns the current count.
+ */
+uint32_t counter_get(const struct Counter *self);
+
+struct Counter *counter_new(void);
+
+float point_length(const struct Point *self);
+
+struct Point point_midpoint(const struct Point *self, const struct Point *other);
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+enum Shape {
+  Line,
+  Polygon,
+};
+typedef uint8_t Shape;
+
+struct Canvas;
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern Shape DEFAULT_SHAPE;
+
+void canvas_add(struct Canvas *canvas, struct Point point, Shape shape);
+
+struct Canvas *canvas_new(void);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(_WIN32)
+struct Handle {
+  uint8_t *raw;
+};
+#endif
+
+#if (defined(__linux__) || defined(__APPLE__))
+struct Handle {
+  int32_t fd;
+};
+#endif
+
+#if defined(__APPLE__)
+void apple_only(void);
+#endif
+
+void open_handle(struct Handle *handle);
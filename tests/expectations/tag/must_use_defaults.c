@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status {
+  Ok,
+  Failed,
+};
+typedef uint8_t Status;
+
+struct Handle {
+  uint32_t id;
+};
+
+__attribute__((warn_unused_result)) Status close(struct Handle handle);
+
+__attribute__((warn_unused_result)) uint32_t count(void);
+
+__attribute__((warn_unused_result)) struct Handle open(void);
+
+void reset(struct Handle *handle);
@@ -0,0 +1,87 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define CAPACITY 16
+
+#define HALF (7 / 2)
+
+#define LARGE 9007199254740993ULL
+
+#define LIMIT (1 << 20)
+
+#define NAME 'n'
+
+struct Opaque;
+
+struct Node {
+  uint64_t value;
+  intptr_t delta;
+};
+
+/**
+ * A list of nodes.
+ */
+struct List {
+  struct Node nodes[CAPACITY];
+  struct Node *head;
+  uintptr_t len;
+};
+#define List_EMPTY_LEN 0
+
+typedef struct List *Handle;
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Added,
+  Removed,
+  Cleared,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+struct Added_Body {
+  Event_Tag tag;
+  uint32_t _0;
+};
+
+struct Removed_Body {
+  Event_Tag tag;
+  uint32_t index;
+  uint64_t value;
+};
+
+union Event {
+  enum Event_Tag tag;
+  struct Added_Body added;
+  struct Removed_Body removed;
+};
+
+typedef void (*Callback)(const struct List *list, union Event event);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t LISTS;
+
+/**
+ * Pushes `value` to `list`.
+ * # Returns
+ * Whether there was room for it.
+ */
+bool list_push(Handle list, uint64_t value);
+
+void list_watch(const struct List *list,
+                const char *name,
+                Callback callback,
+                struct Opaque *opaque);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,68 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct __attribute__((aligned(16))) Context {
+  uint8_t _private[64];
+};
+
+/**
+ * The size of a pointer differs between data models.
+ */
+#if UINTPTR_MAX == UINT64_MAX
+struct __attribute__((aligned(8))) Handle {
+  uint8_t _private[16];
+};
+#elif UINTPTR_MAX == UINT32_MAX
+struct __attribute__((aligned(4))) Handle {
+  uint8_t _private[8];
+};
+#else
+struct Handle;
+#endif
+
+/**
+ * Sorted by alignment, the fields need no padding.
+ */
+#if UINTPTR_MAX == UINT64_MAX
+struct __attribute__((aligned(8))) Parser {
+  uint8_t _private[16];
+};
+#else
+struct Parser;
+#endif
+
+struct Unknown;
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(struct Context) == 64, "unexpected size of Context");
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(struct Handle) == 16, "unexpected size of Handle");
+#elif UINTPTR_MAX == UINT32_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(struct Handle) == 8, "unexpected size of Handle");
+#endif
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(struct Parser) == 16, "unexpected size of Parser");
+#endif
+
+void context_init(struct Context *context);
+
+void handle_init(struct Handle *handle);
+
+void parser_init(struct Parser *parser);
+
+struct Unknown *unknown_new(void);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer;
+
+struct Vec2 {
+  float x;
+  float y;
+};
+
+const uint8_t *buffer_index(const struct Buffer *buffer, uintptr_t index);
+
+struct Vec2 vec2_add(struct Vec2 a, struct Vec2 b);
+
+void vec2_add_assign(struct Vec2 *a, struct Vec2 b);
+
+bool vec2_eq(const struct Vec2 *a, const struct Vec2 *b);
+
+struct Vec2 vec2_neg(struct Vec2 a);
+
+struct Vec2 vec2_sub(struct Vec2 a, struct Vec2 b);
@@ -0,0 +1,26 @@
+#ifndef OUTPUT_FILES_H
+#define OUTPUT_FILES_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle {
+  uint32_t id;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Handle handle_new(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#include "output_files.compat_internal.c"
+#include "output_files.compat_testing.c"
+
+#endif /* OUTPUT_FILES_H */
@@ -0,0 +1,26 @@
+#include "output_files.compat.c"
+
+#ifndef OUTPUT_FILES_H_INTERNAL
+#define OUTPUT_FILES_H_INTERNAL
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct InternalState {
+  struct Handle handle;
+  uint32_t refs;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct InternalState internal_state(struct Handle handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* OUTPUT_FILES_H_INTERNAL */
@@ -0,0 +1,18 @@
+#include "output_files.c"
+
+#ifndef OUTPUT_FILES_H_INTERNAL
+#define OUTPUT_FILES_H_INTERNAL
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct InternalState {
+  struct Handle handle;
+  uint32_t refs;
+};
+
+struct InternalState internal_state(struct Handle handle);
+
+#endif /* OUTPUT_FILES_H_INTERNAL */
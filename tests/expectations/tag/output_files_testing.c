@@ -0,0 +1,18 @@
+#include "output_files.c"
+#include "output_files_internal.c"
+
+#ifndef OUTPUT_FILES_H_TESTING
+#define OUTPUT_FILES_H_TESTING
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Probe {
+  struct InternalState state;
+};
+
+struct Probe test_probe(struct Handle handle);
+
+#endif /* OUTPUT_FILES_H_TESTING */
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Foo {
+  int32_t a;
+};
+
+struct Handles {
+  /**
+   * Wrapped in `Pin`.
+   */
+  struct Foo *pinned;
+  /**
+   * Wrapped in `NonNull`.
+   */
+  struct Foo *non_null;
+  /**
+   * Wrapped in `Unique`.
+   */
+  struct Foo *unique;
+  struct Foo *maybe;
+};
+
+/**
+ * `foo`: Wrapped in `Pin`.
+ * `handles`: Wrapped in `Pin`.
+ */
+void poll(struct Foo *foo, const struct Handles *handles);
+
+/**
+ * `foo`: Wrapped in `Unique`.
+ * Return value: Wrapped in `NonNull`.
+ */
+struct Foo *take(struct Foo *foo);
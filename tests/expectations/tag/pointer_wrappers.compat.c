@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Foo {
+  int32_t a;
+};
+
+struct Handles {
+  /**
+   * Wrapped in `Pin`.
+   */
+  struct Foo *pinned;
+  /**
+   * Wrapped in `NonNull`.
+   */
+  struct Foo *non_null;
+  /**
+   * Wrapped in `Unique`.
+   */
+  struct Foo *unique;
+  struct Foo *maybe;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * `foo`: Wrapped in `Pin`.
+ * `handles`: Wrapped in `Pin`.
+ */
+void poll(struct Foo *foo, const struct Handles *handles);
+
+/**
+ * `foo`: Wrapped in `Unique`.
+ * Return value: Wrapped in `NonNull`.
+ */
+struct Foo *take(struct Foo *foo);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
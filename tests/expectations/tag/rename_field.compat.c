@@ -0,0 +1,58 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Object {
+  bool is_new;
+  uint32_t template_id;
+  uint32_t referenceCount;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t _1;
+};
+
+union Value {
+  int64_t as_int;
+  double real;
+};
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  float r;
+};
+
+struct Square_Body {
+  float _0;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Square_Body square;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Object object, struct Pair pair, union Value value, struct Shape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MY_MAX_POINTS 8
+
+enum my_Shape {
+  SHAPE_Circle,
+  SHAPE_Square,
+};
+typedef uint8_t my_Shape;
+
+struct my_Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Rectangle {
+  struct my_Point origin;
+  struct my_Point size;
+};
+
+struct C_Kept {
+  int32_t x;
+};
+
+struct my_Polygon {
+  struct my_Point points[MY_MAX_POINTS];
+};
+
+void root(struct Rectangle a, struct C_Kept b, my_Shape c, struct my_Polygon d);
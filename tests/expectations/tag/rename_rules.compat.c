@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MY_MAX_POINTS 8
+
+enum my_Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  SHAPE_Circle,
+  SHAPE_Square,
+};
+#ifndef __cplusplus
+typedef uint8_t my_Shape;
+#endif // __cplusplus
+
+struct my_Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Rectangle {
+  struct my_Point origin;
+  struct my_Point size;
+};
+
+struct C_Kept {
+  int32_t x;
+};
+
+struct my_Polygon {
+  struct my_Point points[MY_MAX_POINTS];
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Rectangle a, struct C_Kept b, my_Shape c, struct my_Polygon d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct __attribute__((packed)) PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+
+#pragma pack(push, 2)
+struct PackedPair {
+  uint8_t first;
+  uint64_t second;
+};
+#pragma pack(pop)
+
+struct __attribute__((aligned(8))) AlignedId {
+  uint16_t id;
+};
+
+struct __attribute__((aligned(16))) AlignedBlock {
+  uint8_t data[4];
+  struct PackedHeader header;
+};
+
+void root(struct PackedHeader a,
+          struct PackedPair b,
+          struct AlignedId c,
+          const struct AlignedBlock *d);
@@ -0,0 +1,32 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct PACKED PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+
+struct ALIGNED(4) AlignedId {
+  uint16_t id;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct PackedHeader a, struct AlignedId b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stddef.h>
+
+#pragma pack(push, 1)
+struct PackedHeader {
+  uint8_t tag;
+  uint32_t length;
+};
+#pragma pack(pop)
+
+#pragma pack(push, 2)
+struct PackedPair {
+  uint8_t first;
+  uint64_t second;
+};
+#pragma pack(pop)
+
+#ifndef CBINDGEN_STATIC_ASSERT
+#if defined(__cplusplus)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define CBINDGEN_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#else
+#define CBINDGEN_STATIC_ASSERT_NAME_(line) cbindgen_static_assert_##line
+#define CBINDGEN_STATIC_ASSERT_NAME(line) CBINDGEN_STATIC_ASSERT_NAME_(line)
+#define CBINDGEN_STATIC_ASSERT(cond, msg) typedef char CBINDGEN_STATIC_ASSERT_NAME(__LINE__)[(cond) ? 1 : -1]
+#endif
+#endif
+
+CBINDGEN_STATIC_ASSERT(sizeof(struct PackedHeader) == 5, "unexpected size of PackedHeader");
+CBINDGEN_STATIC_ASSERT(offsetof(struct PackedHeader, tag) == 0, "unexpected offset of PackedHeader::tag");
+CBINDGEN_STATIC_ASSERT(offsetof(struct PackedHeader, length) == 1, "unexpected offset of PackedHeader::length");
+
+#if UINTPTR_MAX == UINT64_MAX
+CBINDGEN_STATIC_ASSERT(sizeof(struct PackedPair) == 10, "unexpected size of PackedPair");
+CBINDGEN_STATIC_ASSERT(offsetof(struct PackedPair, first) == 0, "unexpected offset of PackedPair::first");
+CBINDGEN_STATIC_ASSERT(offsetof(struct PackedPair, second) == 2, "unexpected offset of PackedPair::second");
+#endif
+
+void root(struct PackedHeader a, struct PackedPair b);
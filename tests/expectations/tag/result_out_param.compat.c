@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Error {
+  NotFound,
+  Invalid,
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+enum Error parse_count(const uint8_t *text, uint32_t *out);
+
+enum Error parse_point(const uint8_t *text, struct Point *out);
+
+enum Error validate(const uint8_t *text);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
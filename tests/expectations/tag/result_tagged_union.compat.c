@@ -0,0 +1,86 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Error {
+  NotFound,
+  Invalid,
+};
+
+enum Result_u32__Error_Tag {
+  Ok_u32__Error,
+  Err_u32__Error,
+};
+
+struct Ok_Body_u32__Error {
+  uint32_t _0;
+};
+
+struct Err_Body_u32__Error {
+  enum Error _0;
+};
+
+struct Result_u32__Error {
+  enum Result_u32__Error_Tag tag;
+  union {
+    struct Ok_Body_u32__Error ok;
+    struct Err_Body_u32__Error err;
+  };
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Result_Point__Error_Tag {
+  Ok_Point__Error,
+  Err_Point__Error,
+};
+
+struct Ok_Body_Point__Error {
+  struct Point _0;
+};
+
+struct Err_Body_Point__Error {
+  enum Error _0;
+};
+
+struct Result_Point__Error {
+  enum Result_Point__Error_Tag tag;
+  union {
+    struct Ok_Body_Point__Error ok;
+    struct Err_Body_Point__Error err;
+  };
+};
+
+enum VoidResult_Error_Tag {
+  Ok_Error,
+  Err_Error,
+};
+
+struct Err_Body_Error {
+  enum Error _0;
+};
+
+struct VoidResult_Error {
+  enum VoidResult_Error_Tag tag;
+  union {
+    struct Err_Body_Error err;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Result_u32__Error parse_count(const uint8_t *text);
+
+struct Result_Point__Error parse_point(const uint8_t *text);
+
+struct VoidResult_Error validate(const uint8_t *text);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* ---------------- Audio ---------------- */
+
+#define SAMPLE_RATE 48000
+
+/* ---------------- Constants ---------------- */
+
+#define VOLUME_MAX 100
+
+/* ---------------- Audio ---------------- */
+
+enum Channel {
+  Left,
+  Right,
+};
+
+typedef void (*OnSamples)(const float *samples, uintptr_t len);
+
+struct Stream {
+  enum Channel channel;
+  OnSamples on_samples;
+};
+
+/* ---------------- Enums ---------------- */
+
+enum Mode {
+  Fast,
+  Slow,
+};
+
+/* ---------------- Typedefs ---------------- */
+
+typedef uint64_t Id;
+
+/* ---------------- Callbacks ---------------- */
+
+typedef void (*OnEvent)(uint32_t code);
+
+/* ---------------- Structs ---------------- */
+
+struct Settings {
+  enum Mode mode;
+  Id id;
+  OnEvent on_event;
+};
+
+/* ---------------- Globals ---------------- */
+
+extern uint32_t LAST_ERROR;
+
+/* ---------------- Audio ---------------- */
+
+void open_stream(struct Stream *stream);
+
+/* ---------------- Functions ---------------- */
+
+void init(struct Settings settings);
+
+void shutdown(void);
@@ -0,0 +1,83 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#pragma region Constants
+
+#define VOLUME_MAX 100
+
+#pragma endregion
+
+#pragma region Audio
+
+#define SAMPLE_RATE 48000
+
+#pragma endregion
+
+#pragma region Enums
+
+enum Mode {
+  Fast,
+  Slow,
+};
+
+#pragma endregion
+
+#pragma region Typedefs
+
+typedef uint64_t Id;
+
+#pragma endregion
+
+#pragma region Callbacks
+
+typedef void (*OnEvent)(uint32_t code);
+
+#pragma endregion
+
+#pragma region Structs
+
+struct Settings {
+  enum Mode mode;
+  Id id;
+  OnEvent on_event;
+};
+
+#pragma endregion
+
+#pragma region Audio
+
+enum Channel {
+  Left,
+  Right,
+};
+
+typedef void (*OnSamples)(const float *samples, uintptr_t len);
+
+struct Stream {
+  enum Channel channel;
+  OnSamples on_samples;
+};
+
+#pragma endregion
+
+#pragma region Globals
+
+extern uint32_t LAST_ERROR;
+
+#pragma endregion
+
+#pragma region Functions
+
+void init(struct Settings settings);
+
+void shutdown(void);
+
+#pragma endregion
+
+#pragma region Audio
+
+void open_stream(struct Stream *stream);
+
+#pragma endregion
@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Slice_Point {
+  const struct Point *ptr;
+  uintptr_t len;
+};
+
+struct Polygon {
+  struct Slice_Point points;
+};
+
+struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct MutSlice_u8 {
+  uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct MutSlice_Point {
+  struct Point *ptr;
+  uintptr_t len;
+};
+
+struct Slice_f32 {
+  const float *ptr;
+  uintptr_t len;
+};
+
+float area(struct Polygon polygon);
+
+uint32_t checksum(struct Slice_u8 bytes);
+
+void fill(struct MutSlice_u8 out, uint8_t value);
+
+void translate(struct MutSlice_Point points, struct Slice_f32 by);
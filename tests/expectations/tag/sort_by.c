@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ZETA 1
+
+#define ALPHA (ZETA + 1)
+
+#define MIDDLE 3
+
+struct Zebra {
+  int32_t a;
+};
+
+struct Yak {
+  struct Zebra zebra;
+};
+
+struct Aardvark {
+  float b;
+};
+
+extern uint32_t AGE;
+
+extern uint32_t WEIGHT;
+
+void zoo(struct Aardvark aardvark, struct Yak yak);
+
+void barn(const struct Zebra *zebra);
+
+void apiary(void);
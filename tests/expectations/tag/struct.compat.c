@@ -10,6 +10,9 @@ struct Normal {
   float y;
 };
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 struct NormalWithZST {
   int32_t x;
   float y;
@@ -0,0 +1,54 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle {
+  uint32_t id;
+};
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Empty,
+  Circle,
+  Rect,
+  Owned,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Rect_Body {
+  float width;
+  float height;
+};
+
+struct Owned_Body {
+  struct Handle _0;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Rect_Body rect;
+    struct Owned_Body owned;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const struct Shape *shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
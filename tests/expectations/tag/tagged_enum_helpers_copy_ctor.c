@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle {
+  uint32_t id;
+};
+
+enum Shape_Tag {
+  Empty,
+  Circle,
+  Rect,
+  Owned,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Rect_Body {
+  float width;
+  float height;
+};
+
+struct Owned_Body {
+  struct Handle _0;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Rect_Body rect;
+    struct Owned_Body owned;
+  };
+};
+
+void root(const struct Shape *shape);
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define WORD_BITS 64
+
+struct Handle {
+  uint8_t *raw;
+};
+
+#if defined(WITH_LOGGING)
+struct Logger {
+  uint8_t level;
+};
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void open_handle(struct Handle *handle);
+
+#if defined(WITH_LOGGING)
+void set_logger(const struct Logger *logger);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Counter {
+  uint32_t value;
+};
+
+struct Counter counter_new(void);
+
+void counter_reset(struct Counter *self);
+
+uint32_t counter_value(const struct Counter *self);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Counter {
+  uint32_t value;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Counter counter_new(void);
+
+void counter_reset(struct Counter *self);
+
+uint32_t counter_value(const struct Counter *self);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
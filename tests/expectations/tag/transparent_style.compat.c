@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A handle kept distinct from other integers.
+ */
+struct Handle {
+  uint64_t _0;
+};
+
+/**
+ * An identifier written as a typedef.
+ */
+typedef uint32_t Id;
+
+struct Body {
+  Id id;
+  float height;
+  double weight;
+  const struct Handle *parent;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float measure(struct Handle handle, struct Body *body);
+
+struct Handle open(Id id, float length);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
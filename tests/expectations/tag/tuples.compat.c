@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Tuple_i32__i32 {
+  int32_t _0;
+  int32_t _1;
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Tuple_Point__Point {
+  struct Point _0;
+  struct Point _1;
+};
+
+struct Segment {
+  struct Tuple_Point__Point ends;
+  float weight;
+};
+
+struct Tuple_Segment__Segment {
+  struct Segment _0;
+  struct Segment _1;
+};
+
+struct Tuple_ConstPtr_u8_____usize {
+  const uint8_t *_0;
+  uintptr_t _1;
+};
+
+struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+};
+
+struct Wrapper_Tuple_u8__u16 {
+  struct Tuple_u8__u16 value;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Tuple_i32__i32 div_mod(int32_t a, int32_t b);
+
+struct Tuple_Segment__Segment split(struct Segment s, struct Tuple_ConstPtr_u8_____usize bytes);
+
+void wrapped(struct Wrapper_Tuple_u8__u16 w);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -10,6 +10,9 @@ union Normal {
   float y;
 };
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 union NormalWithZST {
   int32_t x;
   float y;
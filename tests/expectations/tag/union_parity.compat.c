@@ -0,0 +1,68 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A union of two integers that doesn't pad `wide`.
+ */
+union PACKED Packed {
+  /**
+   * The narrow view.
+   */
+  uint8_t narrow;
+  /**
+   * The wide view.
+   */
+  uint32_t wide;
+};
+
+union ALIGNED(16) Aligned {
+  uint8_t bytes[4];
+  uint32_t word;
+};
+
+union Generic_f64 {
+  double asValue;
+  uint64_t asBits;
+};
+
+union Generic_u32 {
+  uint32_t asValue;
+  uint64_t asBits;
+};
+
+union __attribute__((deprecated("use Aligned"))) Old {
+  int32_t a;
+  float b;
+};
+
+union MUST_USE_UNION Checked {
+  int32_t a;
+  float b;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(union Packed a,
+          union Aligned b,
+          union Generic_f64 c,
+          union Generic_u32 d,
+          union Old e,
+          union Checked f);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,18 @@
+#include "workspace_members_core.c"
+
+#ifndef WORKSPACE_MEMBERS_H
+#define WORKSPACE_MEMBERS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Player {
+  enum Format format;
+  struct Sample last;
+};
+
+void play(struct Player *player, const struct Sample *samples, uintptr_t len);
+
+#endif /* WORKSPACE_MEMBERS_H */
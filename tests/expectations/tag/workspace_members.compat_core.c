@@ -0,0 +1,29 @@
+#ifndef WORKSPACE_MEMBERS_H_CORE
+#define WORKSPACE_MEMBERS_H_CORE
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Format {
+  Pcm,
+  Float,
+};
+
+struct Sample {
+  float left;
+  float right;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t core_version(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* WORKSPACE_MEMBERS_H_CORE */
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+ */
+struct Fields {
+  uint64_t a;
+  uint8_t b;
+};
+
+/**
+ * Zero-sized fields elided: `1`, `2`.
+ */
+struct Tuple {
+  uint32_t _0;
+  uint32_t _1;
+};
+
+/**
+ * Zero-sized fields elided: `unit`, `pad`.
+ */
+union Either {
+  int32_t a;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Fields f, struct Tuple t, union Either e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,89 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define COUNT 3
+
+#define HALF (7 / 2)
+
+/**
+ * The direction of a move.
+ */
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+struct Handle;
+
+typedef bool (*Callback)(uintptr_t len, char *data);
+
+struct Options {
+  /**
+   * Fields named after Zig keywords are escaped.
+   */
+  uint32_t error;
+  int align;
+  Callback callback;
+  const char *name;
+  struct Handle *handle;
+  uint8_t grid[COUNT][2];
+};
+
+struct __attribute__((packed)) Packed {
+  uint8_t tag;
+  uint32_t value;
+};
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Moved,
+  Renamed,
+  Closed,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+struct Moved_Body {
+  Direction _0;
+};
+
+struct Renamed_Body {
+  const char *name;
+};
+
+struct Event {
+  enum Event_Tag tag;
+  union {
+    struct Moved_Body moved;
+    struct Renamed_Body renamed;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+void process(const struct Options *options,
+             struct Packed packed,
+             struct Event event,
+             const struct Handle *handle,
+             uint8_t *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,128 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+#include <cassert>
+
+struct Handle {
+  uint32_t id;
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Empty,
+    Circle,
+    Rect,
+    Owned,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Rect_Body {
+    float width;
+    float height;
+  };
+
+  struct Owned_Body {
+    Handle _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+    Owned_Body owned;
+  };
+
+  static Shape Empty() {
+    Shape result;
+    result.tag = Tag::Empty;
+    return result;
+  }
+
+  static Shape Circle(const float &a0) {
+    Shape result;
+    ::new (&result.circle._0) (float)(a0);
+    result.tag = Tag::Circle;
+    return result;
+  }
+
+  static Shape Rect(const float &aWidth,
+                    const float &aHeight) {
+    Shape result;
+    ::new (&result.rect.width) (float)(aWidth);
+    ::new (&result.rect.height) (float)(aHeight);
+    result.tag = Tag::Rect;
+    return result;
+  }
+
+  static Shape Owned(const Handle &a0) {
+    Shape result;
+    ::new (&result.owned._0) (Handle)(a0);
+    result.tag = Tag::Owned;
+    return result;
+  }
+
+  bool IsEmpty() const {
+    return tag == Tag::Empty;
+  }
+
+  bool IsCircle() const {
+    return tag == Tag::Circle;
+  }
+
+  bool IsRect() const {
+    return tag == Tag::Rect;
+  }
+
+  bool IsOwned() const {
+    return tag == Tag::Owned;
+  }
+
+  const float& AsCircle() const {
+    assert(IsCircle());
+    return circle._0;
+  }
+
+  float& AsCircle() {
+    assert(IsCircle());
+    return circle._0;
+  }
+
+  const Rect_Body& AsRect() const {
+    assert(IsRect());
+    return rect;
+  }
+
+  Rect_Body& AsRect() {
+    assert(IsRect());
+    return rect;
+  }
+
+  const Handle& AsOwned() const {
+    assert(IsOwned());
+    return owned._0;
+  }
+
+  Handle& AsOwned() {
+    assert(IsOwned());
+    return owned._0;
+  }
+
+  ~Shape() {
+    switch (tag) {
+      case Tag::Circle: circle.~Circle_Body(); break;
+      case Tag::Rect: rect.~Rect_Body(); break;
+      case Tag::Owned: owned.~Owned_Body(); break;
+      default: break;
+    }
+  }
+};
+
+extern "C" {
+
+void root(const Shape *shape);
+
+} // extern "C"
@@ -0,0 +1,59 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Shape_Tag: {
+  readonly Empty: 0;
+  readonly Circle: 1;
+  readonly Rect: 2;
+  readonly Owned: 3;
+};
+
+export interface Handle {
+  id: number;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface Circle_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Circle_Body: StructType<Circle_Body>;
+
+export interface Rect_Body {
+  width: number;
+  height: number;
+  ref(): Buffer;
+}
+export declare const Rect_Body: StructType<Rect_Body>;
+
+export interface Owned_Body {
+  _0: Handle;
+  ref(): Buffer;
+}
+export declare const Owned_Body: StructType<Owned_Body>;
+
+export interface Shape {
+  tag: number;
+  variants: {
+    circle: Circle_Body;
+    rect: Rect_Body;
+    owned: Owned_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Shape: StructType<Shape>;
+
+export interface Library {
+  root(shape: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,27 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+type Shape_Tag = C.Shape_Tag
+
+const (
+  Empty Shape_Tag = C.Empty
+  Circle Shape_Tag = C.Circle
+  Rect Shape_Tag = C.Rect
+  Owned Shape_Tag = C.Owned
+)
+
+type Circle_Body = C.Circle_Body
+
+type Rect_Body = C.Rect_Body
+
+type Owned_Body = C.Owned_Body
+
+type Shape = C.Shape
+
+func Root(shape *Shape) {
+  C.root(shape)
+}
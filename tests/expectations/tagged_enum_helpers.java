@@ -0,0 +1,69 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"id"})
+  class Handle extends Structure {
+    public int id;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  interface Shape_Tag {
+    byte Empty = 0;
+    byte Circle = 1;
+    byte Rect = 2;
+    byte Owned = 3;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Circle_Body extends Structure {
+    public float _0;
+
+    public static class ByReference extends Circle_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Circle_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"width", "height"})
+  class Rect_Body extends Structure {
+    public float width;
+    public float height;
+
+    public static class ByReference extends Rect_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Rect_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Owned_Body extends Structure {
+    public Handle _0;
+
+    public static class ByReference extends Owned_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Owned_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Shape extends Structure {
+    public static class Variants extends Union {
+      public Circle_Body circle;
+      public Rect_Body rect;
+      public Owned_Body owned;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Shape implements Structure.ByReference {}
+
+    public static class ByValue extends Shape implements Structure.ByValue {}
+  }
+
+  void root(Shape.ByReference shape);
+}
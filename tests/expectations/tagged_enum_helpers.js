@@ -0,0 +1,55 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const Circle_Body = StructType();
+const Rect_Body = StructType();
+const Owned_Body = StructType();
+const _Shape_Variants = UnionType();
+const Shape = StructType();
+
+Handle.defineProperty('id', 'uint32');
+
+Circle_Body.defineProperty('_0', 'float');
+
+Rect_Body.defineProperty('width', 'float');
+Rect_Body.defineProperty('height', 'float');
+
+Owned_Body.defineProperty('_0', Handle);
+
+_Shape_Variants.defineProperty('circle', Circle_Body);
+_Shape_Variants.defineProperty('rect', Rect_Body);
+_Shape_Variants.defineProperty('owned', Owned_Body);
+
+Shape.defineProperty('tag', 'uint8');
+Shape.defineProperty('variants', _Shape_Variants);
+
+const Shape_Tag = Object.freeze({
+  Empty: 0,
+  Circle: 1,
+  Rect: 2,
+  Owned: 3,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [ref.refType(Shape)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Circle_Body,
+  Rect_Body,
+  Owned_Body,
+  Shape,
+  Shape_Tag,
+  load,
+};
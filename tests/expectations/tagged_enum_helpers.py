@@ -0,0 +1,62 @@
+import ctypes
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+Shape_Tag = ctypes.c_uint8
+Empty = 0
+Circle = 1
+Rect = 2
+Owned = 3
+
+class Circle_Body(ctypes.Structure):
+  pass
+
+Circle_Body._fields_ = [
+  ("_0", ctypes.c_float),
+]
+
+class Rect_Body(ctypes.Structure):
+  pass
+
+Rect_Body._fields_ = [
+  ("width", ctypes.c_float),
+  ("height", ctypes.c_float),
+]
+
+class Owned_Body(ctypes.Structure):
+  pass
+
+Owned_Body._fields_ = [
+  ("_0", Handle),
+]
+
+class _Shape_Variants(ctypes.Union):
+  pass
+
+_Shape_Variants._fields_ = [
+  ("circle", Circle_Body),
+  ("rect", Rect_Body),
+  ("owned", Owned_Body),
+]
+
+class Shape(ctypes.Structure):
+  pass
+
+Shape._anonymous_ = ("_variants",)
+Shape._fields_ = [
+  ("tag", Shape_Tag),
+  ("_variants", _Shape_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Shape)]
+  lib.root.restype = None
+
+  return lib
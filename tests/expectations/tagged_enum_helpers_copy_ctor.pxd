@@ -0,0 +1,36 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Handle:
+    uint32_t id
+
+  enum:
+    Empty
+    Circle
+    Rect
+    Owned
+  ctypedef uint8_t Shape_Tag
+
+  ctypedef struct Circle_Body:
+    float _0
+
+  ctypedef struct Rect_Body:
+    float width
+    float height
+
+  ctypedef struct Owned_Body:
+    Handle _0
+
+  ctypedef struct Shape:
+    Shape_Tag tag
+    Circle_Body circle
+    Rect_Body rect
+    Owned_Body owned
+
+  void root(const Shape *shape)
@@ -0,0 +1,36 @@
+const std = @import("std");
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+pub const Shape_Tag = enum(u8) {
+  Empty,
+  Circle,
+  Rect,
+  Owned,
+};
+
+pub const Circle_Body = extern struct {
+  _0: f32,
+};
+
+pub const Rect_Body = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const Owned_Body = extern struct {
+  _0: Handle,
+};
+
+pub const Shape = extern struct {
+  tag: Shape_Tag,
+  body: extern union {
+    circle: Circle_Body,
+    rect: Rect_Body,
+    owned: Owned_Body,
+  },
+};
+
+pub extern fn root(shape: [*c]const Shape) void;
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define WORD_BITS 64
+
+typedef struct {
+  uint8_t *raw;
+} Handle;
+
+#if defined(WITH_LOGGING)
+typedef struct {
+  uint8_t level;
+} Logger;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void open_handle(Handle *handle);
+
+#if defined(WITH_LOGGING)
+void set_logger(const Logger *logger);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
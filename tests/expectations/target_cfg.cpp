@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint32_t WORD_BITS = 64;
+
+struct Handle {
+  uint8_t *raw;
+};
+
+#if defined(WITH_LOGGING)
+struct Logger {
+  uint8_t level;
+};
+#endif
+
+extern "C" {
+
+void open_handle(Handle *handle);
+
+#if defined(WITH_LOGGING)
+void set_logger(const Logger *logger);
+#endif
+
+} // extern "C"
@@ -0,0 +1,34 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const WORD_BITS: number;
+
+export interface Handle {
+  raw: Buffer;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+// cfg(feature = "logging")
+export interface Logger {
+  level: number;
+  ref(): Buffer;
+}
+export declare const Logger: StructType<Logger>;
+
+export interface Library {
+  open_handle(handle: Buffer | null): void;
+
+  // cfg(feature = "logging")
+  set_logger(logger: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Handle = C.Handle
+
+// cfg(feature = "logging")
+type Logger = C.Logger
+
+const WORD_BITS = C.WORD_BITS
+
+func OpenHandle(handle *Handle) {
+  C.open_handle(handle)
+}
+
+// cfg(feature = "logging")
+func SetLogger(logger *Logger) {
+  C.set_logger(logger)
+}
@@ -0,0 +1,29 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int WORD_BITS = 64;
+
+  @Structure.FieldOrder({"raw"})
+  class Handle extends Structure {
+    public Pointer raw;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  // cfg(feature = "logging")
+  @Structure.FieldOrder({"level"})
+  class Logger extends Structure {
+    public byte level;
+
+    public static class ByReference extends Logger implements Structure.ByReference {}
+
+    public static class ByValue extends Logger implements Structure.ByValue {}
+  }
+
+  void open_handle(Handle.ByReference handle);
+
+  // cfg(feature = "logging")
+  void set_logger(Logger.ByReference logger);
+}
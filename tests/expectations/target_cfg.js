@@ -0,0 +1,34 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const Logger = StructType();
+
+const WORD_BITS = 64;
+
+Handle.defineProperty('raw', ref.refType('uint8'));
+
+// cfg(feature = "logging")
+Logger.defineProperty('level', 'uint8');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    open_handle: ['void', [ref.refType(Handle)]],
+    // cfg(feature = "logging")
+    set_logger: ['void', [ref.refType(Logger)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Logger,
+  WORD_BITS,
+  load,
+};
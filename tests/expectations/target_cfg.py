@@ -0,0 +1,30 @@
+import ctypes
+
+WORD_BITS = 64
+
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("raw", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+# cfg(feature = "logging")
+class Logger(ctypes.Structure):
+  pass
+
+Logger._fields_ = [
+  ("level", ctypes.c_uint8),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.open_handle.argtypes = [ctypes.POINTER(Handle)]
+  lib.open_handle.restype = None
+
+  # cfg(feature = "logging")
+  lib.set_logger.argtypes = [ctypes.POINTER(Logger)]
+  lib.set_logger.restype = None
+
+  return lib
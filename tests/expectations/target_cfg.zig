@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const WORD_BITS: u32 = 64;
+
+pub const Handle = extern struct {
+  raw: [*c]u8,
+};
+
+// cfg(feature = "logging")
+pub const Logger = extern struct {
+  level: u8,
+};
+
+pub extern fn open_handle(handle: [*c]Handle) void;
+
+// cfg(feature = "logging")
+pub extern fn set_logger(logger: [*c]const Logger) void;
@@ -0,0 +1,26 @@
+/* Rendered from a template for C */
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define MAX_PLAYERS 4
+
+typedef enum {
+  Red = 0,
+  Blue = 1,
+} Team;
+
+typedef bool (*ScoreCallback)(Team, uint32_t);
+
+typedef struct {
+  const uint8_t *name;
+  Team team;
+  uint32_t scores[4];
+  ScoreCallback on_score;
+} Player;
+
+uint32_t player_count(void);
+
+void player_names(const Player *const *players, const uint8_t **out);
+
+Player *player_new(const uint8_t *name, Team team);
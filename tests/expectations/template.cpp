@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uint32_t MAX_PLAYERS = 4;
+
+enum class Team {
+  Red,
+  Blue,
+};
+
+using ScoreCallback = bool(*)(Team team, uint32_t score);
+
+struct Player {
+  const uint8_t *name;
+  Team team;
+  uint32_t scores[4];
+  ScoreCallback on_score;
+};
+
+extern "C" {
+
+uint32_t player_count();
+
+void player_names(const Player *const *players, const uint8_t **out);
+
+Player *player_new(const uint8_t *name, Team team);
+
+} // extern "C"
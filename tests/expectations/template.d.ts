@@ -0,0 +1,39 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const MAX_PLAYERS: number;
+
+export declare const Team: {
+  readonly Red: 0;
+  readonly Blue: 1;
+};
+
+export interface Player {
+  name: Buffer;
+  team: number;
+  scores: ArrayLike<number>;
+  on_score: Buffer;
+  ref(): Buffer;
+}
+export declare const Player: StructType<Player>;
+
+export type ScoreCallback = Buffer;
+export declare const ScoreCallback: RefType;
+
+export interface Library {
+  player_count(): number;
+
+  player_names(players: Buffer | null, out: Buffer | null): void;
+
+  player_new(name: Buffer | null, team: number): Buffer;
+}
+
+export declare function load(path: string): Library;
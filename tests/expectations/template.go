@@ -0,0 +1,31 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Team = C.Team
+
+const (
+  Red Team = C.Red
+  Blue Team = C.Blue
+)
+
+type ScoreCallback = C.ScoreCallback
+
+type Player = C.Player
+
+const MAX_PLAYERS = C.MAX_PLAYERS
+
+func PlayerCount() uint32 {
+  return uint32(C.player_count())
+}
+
+func PlayerNames(players **Player, out **uint8) {
+  C.player_names(players, (**C.uint8_t)(unsafe.Pointer(out)))
+}
+
+func PlayerNew(name *uint8, team Team) *Player {
+  return C.player_new((*C.uint8_t)(unsafe.Pointer(name)), team)
+}
@@ -0,0 +1,32 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int MAX_PLAYERS = 4;
+
+  interface Team {
+    int Red = 0;
+    int Blue = 1;
+  }
+
+  interface ScoreCallback extends Callback {
+    byte invoke(int team, int score);
+  }
+
+  @Structure.FieldOrder({"name", "team", "scores", "on_score"})
+  class Player extends Structure {
+    public Pointer name;
+    public int team;
+    public int[] scores = new int[4];
+    public ScoreCallback on_score;
+
+    public static class ByReference extends Player implements Structure.ByReference {}
+
+    public static class ByValue extends Player implements Structure.ByValue {}
+  }
+
+  int player_count();
+
+  void player_names(Pointer players, Pointer out);
+
+  Player.ByReference player_new(Pointer name, int team);
+}
@@ -0,0 +1,41 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Player = StructType();
+
+const MAX_PLAYERS = 4;
+
+Player.defineProperty('name', ref.refType('uint8'));
+Player.defineProperty('team', 'int');
+Player.defineProperty('scores', ArrayType('uint32', 4));
+Player.defineProperty('on_score', 'pointer');
+
+const Team = Object.freeze({
+  Red: 0,
+  Blue: 1,
+});
+
+const ScoreCallback = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    player_count: ['uint32', []],
+    player_names: ['void', [ref.refType(ref.refType(Player)), ref.refType(ref.refType('uint8'))]],
+    player_new: [ref.refType(Player), [ref.refType('uint8'), 'int']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Player,
+  Team,
+  ScoreCallback,
+  MAX_PLAYERS,
+  load,
+};
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uint32_t MAX_PLAYERS
+
+  ctypedef enum Team:
+    Red
+    Blue
+
+  ctypedef bool (*ScoreCallback)(Team team, uint32_t score)
+
+  ctypedef struct Player:
+    const uint8_t *name
+    Team team
+    uint32_t scores[4]
+    ScoreCallback on_score
+
+  uint32_t player_count()
+
+  void player_names(const Player *const *players, const uint8_t **out)
+
+  Player *player_new(const uint8_t *name, Team team)
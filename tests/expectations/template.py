@@ -0,0 +1,33 @@
+import ctypes
+
+MAX_PLAYERS = 4
+
+Team = ctypes.c_int
+Red = 0
+Blue = 1
+
+ScoreCallback = ctypes.CFUNCTYPE(ctypes.c_bool, Team, ctypes.c_uint32)
+
+class Player(ctypes.Structure):
+  pass
+
+Player._fields_ = [
+  ("name", ctypes.POINTER(ctypes.c_uint8)),
+  ("team", Team),
+  ("scores", (ctypes.c_uint32 * 4)),
+  ("on_score", ScoreCallback),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.player_count.argtypes = []
+  lib.player_count.restype = ctypes.c_uint32
+
+  lib.player_names.argtypes = [ctypes.POINTER(ctypes.POINTER(Player)), ctypes.POINTER(ctypes.POINTER(ctypes.c_uint8))]
+  lib.player_names.restype = None
+
+  lib.player_new.argtypes = [ctypes.POINTER(ctypes.c_uint8), Team]
+  lib.player_new.restype = ctypes.POINTER(Player)
+
+  return lib
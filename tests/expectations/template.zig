@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const MAX_PLAYERS: u32 = 4;
+
+pub const Team = enum(c_int) {
+  Red,
+  Blue,
+};
+
+pub const ScoreCallback = *const fn (Team, u32) callconv(.C) bool;
+
+pub const Player = extern struct {
+  name: [*c]const u8,
+  team: Team,
+  scores: [4]u32,
+  on_score: ScoreCallback,
+};
+
+pub extern fn player_count() u32;
+
+pub extern fn player_names(players: [*c]const [*c]const Player, out: [*c][*c]const u8) void;
+
+pub extern fn player_new(name: [*c]const u8, team: Team) [*c]Player;
@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+#ifndef CBINDGEN_GUARDED_BY
+#  if defined(__clang__)
+#    define CBINDGEN_GUARDED_BY(x) __attribute__((guarded_by(x)))
+#  else
+#    define CBINDGEN_GUARDED_BY(x)
+#  endif
+#endif
+
+#ifndef CBINDGEN_REQUIRES
+#  if defined(__clang__)
+#    define CBINDGEN_REQUIRES(...) __attribute__((requires_capability(__VA_ARGS__)))
+#  else
+#    define CBINDGEN_REQUIRES(...)
+#  endif
+#endif
+
+struct Mutex {
+  uint8_t *handle;
+};
+
+struct Counter {
+  Mutex lock;
+  uint64_t value CBINDGEN_GUARDED_BY(lock);
+  /// The number of updates.
+  uint32_t updates CBINDGEN_GUARDED_BY(lock);
+};
+
+extern "C" {
+
+void counter_increment(Counter *counter) CBINDGEN_REQUIRES(counter->lock);
+
+void counter_merge(Counter *a, const Counter *b) CBINDGEN_REQUIRES(a->lock, b->lock);
+
+Counter counter_new();
+
+} // extern "C"
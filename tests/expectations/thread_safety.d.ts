@@ -0,0 +1,34 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Mutex {
+  handle: Buffer;
+  ref(): Buffer;
+}
+export declare const Mutex: StructType<Mutex>;
+
+export interface Counter {
+  lock: Mutex;
+  value: number | string;
+  updates: number;
+  ref(): Buffer;
+}
+export declare const Counter: StructType<Counter>;
+
+export interface Library {
+  counter_increment(counter: Buffer | null): void;
+
+  counter_merge(a: Buffer | null, b: Buffer | null): void;
+
+  counter_new(): Counter;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Mutex = C.Mutex
+
+type Counter = C.Counter
+
+func CounterIncrement(counter *Counter) {
+  C.counter_increment(counter)
+}
+
+func CounterMerge(a *Counter, b *Counter) {
+  C.counter_merge(a, b)
+}
+
+func CounterNew() Counter {
+  return C.counter_new()
+}
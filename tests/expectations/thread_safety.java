@@ -0,0 +1,32 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"handle"})
+  class Mutex extends Structure {
+    public Pointer handle;
+
+    public static class ByReference extends Mutex implements Structure.ByReference {}
+
+    public static class ByValue extends Mutex implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"lock", "value", "updates"})
+  class Counter extends Structure {
+    public Mutex lock;
+    public long value;
+    /**
+     * The number of updates.
+     */
+    public int updates;
+
+    public static class ByReference extends Counter implements Structure.ByReference {}
+
+    public static class ByValue extends Counter implements Structure.ByValue {}
+  }
+
+  void counter_increment(Counter.ByReference counter);
+
+  void counter_merge(Counter.ByReference a, Counter.ByReference b);
+
+  Counter.ByValue counter_new();
+}
@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Mutex = StructType();
+const Counter = StructType();
+
+Mutex.defineProperty('handle', ref.refType('uint8'));
+
+Counter.defineProperty('lock', Mutex);
+Counter.defineProperty('value', 'uint64');
+Counter.defineProperty('updates', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    counter_increment: ['void', [ref.refType(Counter)]],
+    counter_merge: ['void', [ref.refType(Counter), ref.refType(Counter)]],
+    counter_new: [Counter, []],
+  });
+  return lib;
+}
+
+module.exports = {
+  Mutex,
+  Counter,
+  load,
+};
@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Mutex:
+    uint8_t *handle
+
+  ctypedef struct Counter:
+    Mutex lock
+    uint64_t value
+    # The number of updates.
+    uint32_t updates
+
+  void counter_increment(Counter *counter)
+
+  void counter_merge(Counter *a, const Counter *b)
+
+  Counter counter_new()
@@ -0,0 +1,32 @@
+import ctypes
+
+class Mutex(ctypes.Structure):
+  pass
+
+Mutex._fields_ = [
+  ("handle", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+class Counter(ctypes.Structure):
+  pass
+
+Counter._fields_ = [
+  ("lock", Mutex),
+  ("value", ctypes.c_uint64),
+  # The number of updates.
+  ("updates", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.counter_increment.argtypes = [ctypes.POINTER(Counter)]
+  lib.counter_increment.restype = None
+
+  lib.counter_merge.argtypes = [ctypes.POINTER(Counter), ctypes.POINTER(Counter)]
+  lib.counter_merge.restype = None
+
+  lib.counter_new.argtypes = []
+  lib.counter_new.restype = Counter
+
+  return lib
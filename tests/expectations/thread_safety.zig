@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const Mutex = extern struct {
+  handle: [*c]u8,
+};
+
+pub const Counter = extern struct {
+  lock: Mutex,
+  value: u64,
+  /// The number of updates.
+  updates: u32,
+};
+
+pub extern fn counter_increment(counter: [*c]Counter) void;
+
+pub extern fn counter_merge(a: [*c]Counter, b: [*c]const Counter) void;
+
+pub extern fn counter_new() Counter;
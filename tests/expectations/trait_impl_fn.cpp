@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Counter {
+  uint32_t value;
+};
+
+extern "C" {
+
+Counter counter_new();
+
+void counter_reset(Counter *self);
+
+uint32_t counter_value(const Counter *self);
+
+} // extern "C"
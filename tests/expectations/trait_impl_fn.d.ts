@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Counter {
+  value: number;
+  ref(): Buffer;
+}
+export declare const Counter: StructType<Counter>;
+
+export interface Library {
+  counter_new(): Counter;
+
+  counter_reset(self: Buffer | null): void;
+
+  counter_value(self: Buffer | null): number;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,18 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Counter = C.Counter
+
+func CounterNew() Counter {
+  return C.counter_new()
+}
+
+func CounterReset(self *Counter) {
+  C.counter_reset(self)
+}
+
+func CounterValue(self *Counter) uint32 {
+  return uint32(C.counter_value(self))
+}
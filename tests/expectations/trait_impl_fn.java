@@ -0,0 +1,18 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"value"})
+  class Counter extends Structure {
+    public int value;
+
+    public static class ByReference extends Counter implements Structure.ByReference {}
+
+    public static class ByValue extends Counter implements Structure.ByValue {}
+  }
+
+  Counter.ByValue counter_new();
+
+  void counter_reset(Counter.ByReference self);
+
+  int counter_value(Counter.ByReference self);
+}
@@ -0,0 +1,26 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Counter = StructType();
+
+Counter.defineProperty('value', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    counter_new: [Counter, []],
+    counter_reset: ['void', [ref.refType(Counter)]],
+    counter_value: ['uint32', [ref.refType(Counter)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Counter,
+  load,
+};
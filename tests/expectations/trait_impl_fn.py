@@ -0,0 +1,22 @@
+import ctypes
+
+class Counter(ctypes.Structure):
+  pass
+
+Counter._fields_ = [
+  ("value", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.counter_new.argtypes = []
+  lib.counter_new.restype = Counter
+
+  lib.counter_reset.argtypes = [ctypes.POINTER(Counter)]
+  lib.counter_reset.restype = None
+
+  lib.counter_value.argtypes = [ctypes.POINTER(Counter)]
+  lib.counter_value.restype = ctypes.c_uint32
+
+  return lib
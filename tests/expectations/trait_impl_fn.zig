@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const Counter = extern struct {
+  value: u32,
+};
+
+pub extern fn counter_new() Counter;
+
+pub extern fn counter_reset(self: *Counter) void;
+
+pub extern fn counter_value(self: *const Counter) u32;
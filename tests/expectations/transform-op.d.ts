@@ -0,0 +1,210 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const StyleFoo_i32_Tag: {
+  readonly Foo_i32: 0;
+  readonly Bar_i32: 1;
+  readonly Baz_i32: 2;
+  readonly Bazz_i32: 3;
+};
+
+export declare const StyleBar_i32_Tag: {
+  readonly Bar1_i32: 0;
+  readonly Bar2_i32: 1;
+  readonly Bar3_i32: 2;
+  readonly Bar4_i32: 3;
+};
+
+export declare const StyleBar_u32_Tag: {
+  readonly Bar1_u32: 0;
+  readonly Bar2_u32: 1;
+  readonly Bar3_u32: 2;
+  readonly Bar4_u32: 3;
+};
+
+export declare const StyleBaz_Tag: {
+  readonly Baz1: 0;
+  readonly Baz2: 1;
+  readonly Baz3: 2;
+};
+
+export declare const StyleTaz_Tag: {
+  readonly Taz1: 0;
+  readonly Taz2: 1;
+  readonly Taz3: 2;
+};
+
+export interface StylePoint_i32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const StylePoint_i32: StructType<StylePoint_i32>;
+
+export interface StylePoint_f32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const StylePoint_f32: StructType<StylePoint_f32>;
+
+export interface StyleFoo_Body_i32 {
+  tag: number;
+  x: number;
+  y: StylePoint_i32;
+  z: StylePoint_f32;
+  ref(): Buffer;
+}
+export declare const StyleFoo_Body_i32: StructType<StyleFoo_Body_i32>;
+
+export interface StyleBar_Body_i32 {
+  tag: number;
+  _0: number;
+  ref(): Buffer;
+}
+export declare const StyleBar_Body_i32: StructType<StyleBar_Body_i32>;
+
+export interface StyleBaz_Body_i32 {
+  tag: number;
+  _0: StylePoint_i32;
+  ref(): Buffer;
+}
+export declare const StyleBaz_Body_i32: StructType<StyleBaz_Body_i32>;
+
+export interface StyleFoo_i32 {
+  tag: number;
+  foo: StyleFoo_Body_i32;
+  bar: StyleBar_Body_i32;
+  baz: StyleBaz_Body_i32;
+  ref(): Buffer;
+}
+export declare const StyleFoo_i32: StructType<StyleFoo_i32>;
+
+export interface StyleBar1_Body_i32 {
+  x: number;
+  y: StylePoint_i32;
+  z: StylePoint_f32;
+  u: Buffer;
+  ref(): Buffer;
+}
+export declare const StyleBar1_Body_i32: StructType<StyleBar1_Body_i32>;
+
+export interface StyleBar2_Body_i32 {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const StyleBar2_Body_i32: StructType<StyleBar2_Body_i32>;
+
+export interface StyleBar3_Body_i32 {
+  _0: StylePoint_i32;
+  ref(): Buffer;
+}
+export declare const StyleBar3_Body_i32: StructType<StyleBar3_Body_i32>;
+
+export interface StyleBar_i32 {
+  tag: number;
+  variants: {
+    bar1: StyleBar1_Body_i32;
+    bar2: StyleBar2_Body_i32;
+    bar3: StyleBar3_Body_i32;
+  };
+  ref(): Buffer;
+}
+export declare const StyleBar_i32: StructType<StyleBar_i32>;
+
+export interface StylePoint_u32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const StylePoint_u32: StructType<StylePoint_u32>;
+
+export interface StyleBar1_Body_u32 {
+  x: number;
+  y: StylePoint_u32;
+  z: StylePoint_f32;
+  u: Buffer;
+  ref(): Buffer;
+}
+export declare const StyleBar1_Body_u32: StructType<StyleBar1_Body_u32>;
+
+export interface StyleBar2_Body_u32 {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const StyleBar2_Body_u32: StructType<StyleBar2_Body_u32>;
+
+export interface StyleBar3_Body_u32 {
+  _0: StylePoint_u32;
+  ref(): Buffer;
+}
+export declare const StyleBar3_Body_u32: StructType<StyleBar3_Body_u32>;
+
+export interface StyleBar_u32 {
+  tag: number;
+  variants: {
+    bar1: StyleBar1_Body_u32;
+    bar2: StyleBar2_Body_u32;
+    bar3: StyleBar3_Body_u32;
+  };
+  ref(): Buffer;
+}
+export declare const StyleBar_u32: StructType<StyleBar_u32>;
+
+export interface StyleBaz1_Body {
+  tag: number;
+  _0: StyleBar_u32;
+  ref(): Buffer;
+}
+export declare const StyleBaz1_Body: StructType<StyleBaz1_Body>;
+
+export interface StyleBaz2_Body {
+  tag: number;
+  _0: StylePoint_i32;
+  ref(): Buffer;
+}
+export declare const StyleBaz2_Body: StructType<StyleBaz2_Body>;
+
+export interface StyleBaz {
+  tag: number;
+  baz1: StyleBaz1_Body;
+  baz2: StyleBaz2_Body;
+  ref(): Buffer;
+}
+export declare const StyleBaz: StructType<StyleBaz>;
+
+export interface StyleTaz1_Body {
+  _0: StyleBar_u32;
+  ref(): Buffer;
+}
+export declare const StyleTaz1_Body: StructType<StyleTaz1_Body>;
+
+export interface StyleTaz2_Body {
+  _0: StyleBaz;
+  ref(): Buffer;
+}
+export declare const StyleTaz2_Body: StructType<StyleTaz2_Body>;
+
+export interface StyleTaz {
+  tag: number;
+  variants: {
+    taz1: StyleTaz1_Body;
+    taz2: StyleTaz2_Body;
+  };
+  ref(): Buffer;
+}
+export declare const StyleTaz: StructType<StyleTaz>;
+
+export interface Library {
+  foo(foo: Buffer | null, bar: Buffer | null, baz: Buffer | null, taz: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,93 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type StylePoint_i32 = C.StylePoint_i32
+
+type StylePoint_f32 = C.StylePoint_f32
+
+type StyleFoo_i32_Tag = C.StyleFoo_i32_Tag
+
+const (
+  Foo_i32 StyleFoo_i32_Tag = C.Foo_i32
+  Bar_i32 StyleFoo_i32_Tag = C.Bar_i32
+  Baz_i32 StyleFoo_i32_Tag = C.Baz_i32
+  Bazz_i32 StyleFoo_i32_Tag = C.Bazz_i32
+)
+
+type StyleFoo_Body_i32 = C.StyleFoo_Body_i32
+
+type StyleBar_Body_i32 = C.StyleBar_Body_i32
+
+type StyleBaz_Body_i32 = C.StyleBaz_Body_i32
+
+type StyleFoo_i32 = C.StyleFoo_i32
+
+type StyleBar_i32_Tag = C.StyleBar_i32_Tag
+
+const (
+  Bar1_i32 StyleBar_i32_Tag = C.Bar1_i32
+  Bar2_i32 StyleBar_i32_Tag = C.Bar2_i32
+  Bar3_i32 StyleBar_i32_Tag = C.Bar3_i32
+  Bar4_i32 StyleBar_i32_Tag = C.Bar4_i32
+)
+
+type StyleBar1_Body_i32 = C.StyleBar1_Body_i32
+
+type StyleBar2_Body_i32 = C.StyleBar2_Body_i32
+
+type StyleBar3_Body_i32 = C.StyleBar3_Body_i32
+
+type StyleBar_i32 = C.StyleBar_i32
+
+type StylePoint_u32 = C.StylePoint_u32
+
+type StyleBar_u32_Tag = C.StyleBar_u32_Tag
+
+const (
+  Bar1_u32 StyleBar_u32_Tag = C.Bar1_u32
+  Bar2_u32 StyleBar_u32_Tag = C.Bar2_u32
+  Bar3_u32 StyleBar_u32_Tag = C.Bar3_u32
+  Bar4_u32 StyleBar_u32_Tag = C.Bar4_u32
+)
+
+type StyleBar1_Body_u32 = C.StyleBar1_Body_u32
+
+type StyleBar2_Body_u32 = C.StyleBar2_Body_u32
+
+type StyleBar3_Body_u32 = C.StyleBar3_Body_u32
+
+type StyleBar_u32 = C.StyleBar_u32
+
+type StyleBaz_Tag = C.StyleBaz_Tag
+
+const (
+  Baz1 StyleBaz_Tag = C.Baz1
+  Baz2 StyleBaz_Tag = C.Baz2
+  Baz3 StyleBaz_Tag = C.Baz3
+)
+
+type StyleBaz1_Body = C.StyleBaz1_Body
+
+type StyleBaz2_Body = C.StyleBaz2_Body
+
+type StyleBaz = C.StyleBaz
+
+type StyleTaz_Tag = C.StyleTaz_Tag
+
+const (
+  Taz1 StyleTaz_Tag = C.Taz1
+  Taz2 StyleTaz_Tag = C.Taz2
+  Taz3 StyleTaz_Tag = C.Taz3
+)
+
+type StyleTaz1_Body = C.StyleTaz1_Body
+
+type StyleTaz2_Body = C.StyleTaz2_Body
+
+type StyleTaz = C.StyleTaz
+
+func Foo(foo *StyleFoo_i32, bar *StyleBar_i32, baz *StyleBaz, taz *StyleTaz) {
+  C.foo(foo, bar, baz, taz)
+}
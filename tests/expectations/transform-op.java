@@ -0,0 +1,278 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class StylePoint_i32 extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends StylePoint_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StylePoint_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class StylePoint_f32 extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends StylePoint_f32 implements Structure.ByReference {}
+
+    public static class ByValue extends StylePoint_f32 implements Structure.ByValue {}
+  }
+
+  interface StyleFoo_i32_Tag {
+    byte Foo_i32 = 0;
+    byte Bar_i32 = 1;
+    byte Baz_i32 = 2;
+    byte Bazz_i32 = 3;
+  }
+
+  @Structure.FieldOrder({"tag", "x", "y", "z"})
+  class StyleFoo_Body_i32 extends Structure {
+    public byte tag;
+    public int x;
+    public StylePoint_i32 y;
+    public StylePoint_f32 z;
+
+    public static class ByReference extends StyleFoo_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleFoo_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class StyleBar_Body_i32 extends Structure {
+    public byte tag;
+    public int _0;
+
+    public static class ByReference extends StyleBar_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class StyleBaz_Body_i32 extends Structure {
+    public byte tag;
+    public StylePoint_i32 _0;
+
+    public static class ByReference extends StyleBaz_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBaz_Body_i32 implements Structure.ByValue {}
+  }
+
+  class StyleFoo_i32 extends Union {
+    public byte tag;
+    public StyleFoo_Body_i32 foo;
+    public StyleBar_Body_i32 bar;
+    public StyleBaz_Body_i32 baz;
+
+    public static class ByReference extends StyleFoo_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleFoo_i32 implements Structure.ByValue {}
+  }
+
+  interface StyleBar_i32_Tag {
+    int Bar1_i32 = 0;
+    int Bar2_i32 = 1;
+    int Bar3_i32 = 2;
+    int Bar4_i32 = 3;
+  }
+
+  @Structure.FieldOrder({"x", "y", "z", "u"})
+  class StyleBar1_Body_i32 extends Structure {
+    public int x;
+    public StylePoint_i32 y;
+    public StylePoint_f32 z;
+    public Pointer u;
+
+    public static class ByReference extends StyleBar1_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar1_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleBar2_Body_i32 extends Structure {
+    public int _0;
+
+    public static class ByReference extends StyleBar2_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar2_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleBar3_Body_i32 extends Structure {
+    public StylePoint_i32 _0;
+
+    public static class ByReference extends StyleBar3_Body_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar3_Body_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class StyleBar_i32 extends Structure {
+    public static class Variants extends Union {
+      public StyleBar1_Body_i32 bar1;
+      public StyleBar2_Body_i32 bar2;
+      public StyleBar3_Body_i32 bar3;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends StyleBar_i32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar_i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class StylePoint_u32 extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends StylePoint_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends StylePoint_u32 implements Structure.ByValue {}
+  }
+
+  interface StyleBar_u32_Tag {
+    int Bar1_u32 = 0;
+    int Bar2_u32 = 1;
+    int Bar3_u32 = 2;
+    int Bar4_u32 = 3;
+  }
+
+  @Structure.FieldOrder({"x", "y", "z", "u"})
+  class StyleBar1_Body_u32 extends Structure {
+    public int x;
+    public StylePoint_u32 y;
+    public StylePoint_f32 z;
+    public Pointer u;
+
+    public static class ByReference extends StyleBar1_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar1_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleBar2_Body_u32 extends Structure {
+    public int _0;
+
+    public static class ByReference extends StyleBar2_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar2_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleBar3_Body_u32 extends Structure {
+    public StylePoint_u32 _0;
+
+    public static class ByReference extends StyleBar3_Body_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar3_Body_u32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class StyleBar_u32 extends Structure {
+    public static class Variants extends Union {
+      public StyleBar1_Body_u32 bar1;
+      public StyleBar2_Body_u32 bar2;
+      public StyleBar3_Body_u32 bar3;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public int tag;
+    public Variants variants;
+
+    public static class ByReference extends StyleBar_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBar_u32 implements Structure.ByValue {}
+  }
+
+  interface StyleBaz_Tag {
+    byte Baz1 = 0;
+    byte Baz2 = 1;
+    byte Baz3 = 2;
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class StyleBaz1_Body extends Structure {
+    public byte tag;
+    public StyleBar_u32 _0;
+
+    public static class ByReference extends StyleBaz1_Body implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBaz1_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "_0"})
+  class StyleBaz2_Body extends Structure {
+    public byte tag;
+    public StylePoint_i32 _0;
+
+    public static class ByReference extends StyleBaz2_Body implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBaz2_Body implements Structure.ByValue {}
+  }
+
+  class StyleBaz extends Union {
+    public byte tag;
+    public StyleBaz1_Body baz1;
+    public StyleBaz2_Body baz2;
+
+    public static class ByReference extends StyleBaz implements Structure.ByReference {}
+
+    public static class ByValue extends StyleBaz implements Structure.ByValue {}
+  }
+
+  interface StyleTaz_Tag {
+    byte Taz1 = 0;
+    byte Taz2 = 1;
+    byte Taz3 = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleTaz1_Body extends Structure {
+    public StyleBar_u32 _0;
+
+    public static class ByReference extends StyleTaz1_Body implements Structure.ByReference {}
+
+    public static class ByValue extends StyleTaz1_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class StyleTaz2_Body extends Structure {
+    public StyleBaz _0;
+
+    public static class ByReference extends StyleTaz2_Body implements Structure.ByReference {}
+
+    public static class ByValue extends StyleTaz2_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class StyleTaz extends Structure {
+    public static class Variants extends Union {
+      public StyleTaz1_Body taz1;
+      public StyleTaz2_Body taz2;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends StyleTaz implements Structure.ByReference {}
+
+    public static class ByValue extends StyleTaz implements Structure.ByValue {}
+  }
+
+  void foo(StyleFoo_i32.ByReference foo, StyleBar_i32.ByReference bar, StyleBaz.ByReference baz, StyleTaz.ByReference taz);
+}
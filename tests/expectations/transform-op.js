@@ -0,0 +1,180 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const StylePoint_i32 = StructType();
+const StylePoint_f32 = StructType();
+const StyleFoo_Body_i32 = StructType();
+const StyleBar_Body_i32 = StructType();
+const StyleBaz_Body_i32 = StructType();
+const StyleFoo_i32 = UnionType();
+const StyleBar1_Body_i32 = StructType();
+const StyleBar2_Body_i32 = StructType();
+const StyleBar3_Body_i32 = StructType();
+const _StyleBar_i32_Variants = UnionType();
+const StyleBar_i32 = StructType();
+const StylePoint_u32 = StructType();
+const StyleBar1_Body_u32 = StructType();
+const StyleBar2_Body_u32 = StructType();
+const StyleBar3_Body_u32 = StructType();
+const _StyleBar_u32_Variants = UnionType();
+const StyleBar_u32 = StructType();
+const StyleBaz1_Body = StructType();
+const StyleBaz2_Body = StructType();
+const StyleBaz = UnionType();
+const StyleTaz1_Body = StructType();
+const StyleTaz2_Body = StructType();
+const _StyleTaz_Variants = UnionType();
+const StyleTaz = StructType();
+
+StylePoint_i32.defineProperty('x', 'int32');
+StylePoint_i32.defineProperty('y', 'int32');
+
+StylePoint_f32.defineProperty('x', 'float');
+StylePoint_f32.defineProperty('y', 'float');
+
+StyleFoo_Body_i32.defineProperty('tag', 'uint8');
+StyleFoo_Body_i32.defineProperty('x', 'int32');
+StyleFoo_Body_i32.defineProperty('y', StylePoint_i32);
+StyleFoo_Body_i32.defineProperty('z', StylePoint_f32);
+
+StyleBar_Body_i32.defineProperty('tag', 'uint8');
+StyleBar_Body_i32.defineProperty('_0', 'int32');
+
+StyleBaz_Body_i32.defineProperty('tag', 'uint8');
+StyleBaz_Body_i32.defineProperty('_0', StylePoint_i32);
+
+StyleFoo_i32.defineProperty('tag', 'uint8');
+StyleFoo_i32.defineProperty('foo', StyleFoo_Body_i32);
+StyleFoo_i32.defineProperty('bar', StyleBar_Body_i32);
+StyleFoo_i32.defineProperty('baz', StyleBaz_Body_i32);
+
+StyleBar1_Body_i32.defineProperty('x', 'int32');
+StyleBar1_Body_i32.defineProperty('y', StylePoint_i32);
+StyleBar1_Body_i32.defineProperty('z', StylePoint_f32);
+StyleBar1_Body_i32.defineProperty('u', 'pointer');
+
+StyleBar2_Body_i32.defineProperty('_0', 'int32');
+
+StyleBar3_Body_i32.defineProperty('_0', StylePoint_i32);
+
+_StyleBar_i32_Variants.defineProperty('bar1', StyleBar1_Body_i32);
+_StyleBar_i32_Variants.defineProperty('bar2', StyleBar2_Body_i32);
+_StyleBar_i32_Variants.defineProperty('bar3', StyleBar3_Body_i32);
+
+StyleBar_i32.defineProperty('tag', 'int');
+StyleBar_i32.defineProperty('variants', _StyleBar_i32_Variants);
+
+StylePoint_u32.defineProperty('x', 'uint32');
+StylePoint_u32.defineProperty('y', 'uint32');
+
+StyleBar1_Body_u32.defineProperty('x', 'int32');
+StyleBar1_Body_u32.defineProperty('y', StylePoint_u32);
+StyleBar1_Body_u32.defineProperty('z', StylePoint_f32);
+StyleBar1_Body_u32.defineProperty('u', 'pointer');
+
+StyleBar2_Body_u32.defineProperty('_0', 'uint32');
+
+StyleBar3_Body_u32.defineProperty('_0', StylePoint_u32);
+
+_StyleBar_u32_Variants.defineProperty('bar1', StyleBar1_Body_u32);
+_StyleBar_u32_Variants.defineProperty('bar2', StyleBar2_Body_u32);
+_StyleBar_u32_Variants.defineProperty('bar3', StyleBar3_Body_u32);
+
+StyleBar_u32.defineProperty('tag', 'int');
+StyleBar_u32.defineProperty('variants', _StyleBar_u32_Variants);
+
+StyleBaz1_Body.defineProperty('tag', 'uint8');
+StyleBaz1_Body.defineProperty('_0', StyleBar_u32);
+
+StyleBaz2_Body.defineProperty('tag', 'uint8');
+StyleBaz2_Body.defineProperty('_0', StylePoint_i32);
+
+StyleBaz.defineProperty('tag', 'uint8');
+StyleBaz.defineProperty('baz1', StyleBaz1_Body);
+StyleBaz.defineProperty('baz2', StyleBaz2_Body);
+
+StyleTaz1_Body.defineProperty('_0', StyleBar_u32);
+
+StyleTaz2_Body.defineProperty('_0', StyleBaz);
+
+_StyleTaz_Variants.defineProperty('taz1', StyleTaz1_Body);
+_StyleTaz_Variants.defineProperty('taz2', StyleTaz2_Body);
+
+StyleTaz.defineProperty('tag', 'uint8');
+StyleTaz.defineProperty('variants', _StyleTaz_Variants);
+
+const StyleFoo_i32_Tag = Object.freeze({
+  Foo_i32: 0,
+  Bar_i32: 1,
+  Baz_i32: 2,
+  Bazz_i32: 3,
+});
+
+const StyleBar_i32_Tag = Object.freeze({
+  Bar1_i32: 0,
+  Bar2_i32: 1,
+  Bar3_i32: 2,
+  Bar4_i32: 3,
+});
+
+const StyleBar_u32_Tag = Object.freeze({
+  Bar1_u32: 0,
+  Bar2_u32: 1,
+  Bar3_u32: 2,
+  Bar4_u32: 3,
+});
+
+const StyleBaz_Tag = Object.freeze({
+  Baz1: 0,
+  Baz2: 1,
+  Baz3: 2,
+});
+
+const StyleTaz_Tag = Object.freeze({
+  Taz1: 0,
+  Taz2: 1,
+  Taz3: 2,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    foo: ['void', [ref.refType(StyleFoo_i32), ref.refType(StyleBar_i32), ref.refType(StyleBaz), ref.refType(StyleTaz)]],
+  });
+  return lib;
+}
+
+module.exports = {
+  StylePoint_i32,
+  StylePoint_f32,
+  StyleFoo_Body_i32,
+  StyleBar_Body_i32,
+  StyleBaz_Body_i32,
+  StyleFoo_i32,
+  StyleBar1_Body_i32,
+  StyleBar2_Body_i32,
+  StyleBar3_Body_i32,
+  StyleBar_i32,
+  StylePoint_u32,
+  StyleBar1_Body_u32,
+  StyleBar2_Body_u32,
+  StyleBar3_Body_u32,
+  StyleBar_u32,
+  StyleBaz1_Body,
+  StyleBaz2_Body,
+  StyleBaz,
+  StyleTaz1_Body,
+  StyleTaz2_Body,
+  StyleTaz,
+  StyleFoo_i32_Tag,
+  StyleBar_i32_Tag,
+  StyleBar_u32_Tag,
+  StyleBaz_Tag,
+  StyleTaz_Tag,
+  load,
+};
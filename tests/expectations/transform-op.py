@@ -0,0 +1,237 @@
+import ctypes
+
+class StylePoint_i32(ctypes.Structure):
+  pass
+
+StylePoint_i32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+class StylePoint_f32(ctypes.Structure):
+  pass
+
+StylePoint_f32._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+StyleFoo_i32_Tag = ctypes.c_uint8
+Foo_i32 = 0
+Bar_i32 = 1
+Baz_i32 = 2
+Bazz_i32 = 3
+
+class StyleFoo_Body_i32(ctypes.Structure):
+  pass
+
+StyleFoo_Body_i32._fields_ = [
+  ("tag", StyleFoo_i32_Tag),
+  ("x", ctypes.c_int32),
+  ("y", StylePoint_i32),
+  ("z", StylePoint_f32),
+]
+
+class StyleBar_Body_i32(ctypes.Structure):
+  pass
+
+StyleBar_Body_i32._fields_ = [
+  ("tag", StyleFoo_i32_Tag),
+  ("_0", ctypes.c_int32),
+]
+
+class StyleBaz_Body_i32(ctypes.Structure):
+  pass
+
+StyleBaz_Body_i32._fields_ = [
+  ("tag", StyleFoo_i32_Tag),
+  ("_0", StylePoint_i32),
+]
+
+class StyleFoo_i32(ctypes.Union):
+  pass
+
+StyleFoo_i32._fields_ = [
+  ("tag", StyleFoo_i32_Tag),
+  ("foo", StyleFoo_Body_i32),
+  ("bar", StyleBar_Body_i32),
+  ("baz", StyleBaz_Body_i32),
+]
+
+StyleBar_i32_Tag = ctypes.c_int
+Bar1_i32 = 0
+Bar2_i32 = 1
+Bar3_i32 = 2
+Bar4_i32 = 3
+
+class StyleBar1_Body_i32(ctypes.Structure):
+  pass
+
+StyleBar1_Body_i32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", StylePoint_i32),
+  ("z", StylePoint_f32),
+  ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+]
+
+class StyleBar2_Body_i32(ctypes.Structure):
+  pass
+
+StyleBar2_Body_i32._fields_ = [
+  ("_0", ctypes.c_int32),
+]
+
+class StyleBar3_Body_i32(ctypes.Structure):
+  pass
+
+StyleBar3_Body_i32._fields_ = [
+  ("_0", StylePoint_i32),
+]
+
+class _StyleBar_i32_Variants(ctypes.Union):
+  pass
+
+_StyleBar_i32_Variants._fields_ = [
+  ("bar1", StyleBar1_Body_i32),
+  ("bar2", StyleBar2_Body_i32),
+  ("bar3", StyleBar3_Body_i32),
+]
+
+class StyleBar_i32(ctypes.Structure):
+  pass
+
+StyleBar_i32._anonymous_ = ("_variants",)
+StyleBar_i32._fields_ = [
+  ("tag", StyleBar_i32_Tag),
+  ("_variants", _StyleBar_i32_Variants),
+]
+
+class StylePoint_u32(ctypes.Structure):
+  pass
+
+StylePoint_u32._fields_ = [
+  ("x", ctypes.c_uint32),
+  ("y", ctypes.c_uint32),
+]
+
+StyleBar_u32_Tag = ctypes.c_int
+Bar1_u32 = 0
+Bar2_u32 = 1
+Bar3_u32 = 2
+Bar4_u32 = 3
+
+class StyleBar1_Body_u32(ctypes.Structure):
+  pass
+
+StyleBar1_Body_u32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", StylePoint_u32),
+  ("z", StylePoint_f32),
+  ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+]
+
+class StyleBar2_Body_u32(ctypes.Structure):
+  pass
+
+StyleBar2_Body_u32._fields_ = [
+  ("_0", ctypes.c_uint32),
+]
+
+class StyleBar3_Body_u32(ctypes.Structure):
+  pass
+
+StyleBar3_Body_u32._fields_ = [
+  ("_0", StylePoint_u32),
+]
+
+class _StyleBar_u32_Variants(ctypes.Union):
+  pass
+
+_StyleBar_u32_Variants._fields_ = [
+  ("bar1", StyleBar1_Body_u32),
+  ("bar2", StyleBar2_Body_u32),
+  ("bar3", StyleBar3_Body_u32),
+]
+
+class StyleBar_u32(ctypes.Structure):
+  pass
+
+StyleBar_u32._anonymous_ = ("_variants",)
+StyleBar_u32._fields_ = [
+  ("tag", StyleBar_u32_Tag),
+  ("_variants", _StyleBar_u32_Variants),
+]
+
+StyleBaz_Tag = ctypes.c_uint8
+Baz1 = 0
+Baz2 = 1
+Baz3 = 2
+
+class StyleBaz1_Body(ctypes.Structure):
+  pass
+
+StyleBaz1_Body._fields_ = [
+  ("tag", StyleBaz_Tag),
+  ("_0", StyleBar_u32),
+]
+
+class StyleBaz2_Body(ctypes.Structure):
+  pass
+
+StyleBaz2_Body._fields_ = [
+  ("tag", StyleBaz_Tag),
+  ("_0", StylePoint_i32),
+]
+
+class StyleBaz(ctypes.Union):
+  pass
+
+StyleBaz._fields_ = [
+  ("tag", StyleBaz_Tag),
+  ("baz1", StyleBaz1_Body),
+  ("baz2", StyleBaz2_Body),
+]
+
+StyleTaz_Tag = ctypes.c_uint8
+Taz1 = 0
+Taz2 = 1
+Taz3 = 2
+
+class StyleTaz1_Body(ctypes.Structure):
+  pass
+
+StyleTaz1_Body._fields_ = [
+  ("_0", StyleBar_u32),
+]
+
+class StyleTaz2_Body(ctypes.Structure):
+  pass
+
+StyleTaz2_Body._fields_ = [
+  ("_0", StyleBaz),
+]
+
+class _StyleTaz_Variants(ctypes.Union):
+  pass
+
+_StyleTaz_Variants._fields_ = [
+  ("taz1", StyleTaz1_Body),
+  ("taz2", StyleTaz2_Body),
+]
+
+class StyleTaz(ctypes.Structure):
+  pass
+
+StyleTaz._anonymous_ = ("_variants",)
+StyleTaz._fields_ = [
+  ("tag", StyleTaz_Tag),
+  ("_variants", _StyleTaz_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [ctypes.POINTER(StyleFoo_i32), ctypes.POINTER(StyleBar_i32), ctypes.POINTER(StyleBaz), ctypes.POINTER(StyleTaz)]
+  lib.foo.restype = None
+
+  return lib
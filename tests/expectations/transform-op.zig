@@ -0,0 +1,155 @@
+const std = @import("std");
+
+pub const StylePoint_i32 = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const StylePoint_f32 = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const StyleFoo_i32_Tag = enum(u8) {
+  Foo_i32,
+  Bar_i32,
+  Baz_i32,
+  Bazz_i32,
+};
+
+pub const StyleFoo_Body_i32 = extern struct {
+  tag: StyleFoo_i32_Tag,
+  x: i32,
+  y: StylePoint_i32,
+  z: StylePoint_f32,
+};
+
+pub const StyleBar_Body_i32 = extern struct {
+  tag: StyleFoo_i32_Tag,
+  _0: i32,
+};
+
+pub const StyleBaz_Body_i32 = extern struct {
+  tag: StyleFoo_i32_Tag,
+  _0: StylePoint_i32,
+};
+
+pub const StyleFoo_i32 = extern union {
+  tag: StyleFoo_i32_Tag,
+  foo: StyleFoo_Body_i32,
+  bar: StyleBar_Body_i32,
+  baz: StyleBaz_Body_i32,
+};
+
+pub const StyleBar_i32_Tag = enum(c_int) {
+  Bar1_i32,
+  Bar2_i32,
+  Bar3_i32,
+  Bar4_i32,
+};
+
+pub const StyleBar1_Body_i32 = extern struct {
+  x: i32,
+  y: StylePoint_i32,
+  z: StylePoint_f32,
+  u: *const fn (i32) callconv(.C) i32,
+};
+
+pub const StyleBar2_Body_i32 = extern struct {
+  _0: i32,
+};
+
+pub const StyleBar3_Body_i32 = extern struct {
+  _0: StylePoint_i32,
+};
+
+pub const StyleBar_i32 = extern struct {
+  tag: StyleBar_i32_Tag,
+  body: extern union {
+    bar1: StyleBar1_Body_i32,
+    bar2: StyleBar2_Body_i32,
+    bar3: StyleBar3_Body_i32,
+  },
+};
+
+pub const StylePoint_u32 = extern struct {
+  x: u32,
+  y: u32,
+};
+
+pub const StyleBar_u32_Tag = enum(c_int) {
+  Bar1_u32,
+  Bar2_u32,
+  Bar3_u32,
+  Bar4_u32,
+};
+
+pub const StyleBar1_Body_u32 = extern struct {
+  x: i32,
+  y: StylePoint_u32,
+  z: StylePoint_f32,
+  u: *const fn (i32) callconv(.C) i32,
+};
+
+pub const StyleBar2_Body_u32 = extern struct {
+  _0: u32,
+};
+
+pub const StyleBar3_Body_u32 = extern struct {
+  _0: StylePoint_u32,
+};
+
+pub const StyleBar_u32 = extern struct {
+  tag: StyleBar_u32_Tag,
+  body: extern union {
+    bar1: StyleBar1_Body_u32,
+    bar2: StyleBar2_Body_u32,
+    bar3: StyleBar3_Body_u32,
+  },
+};
+
+pub const StyleBaz_Tag = enum(u8) {
+  Baz1,
+  Baz2,
+  Baz3,
+};
+
+pub const StyleBaz1_Body = extern struct {
+  tag: StyleBaz_Tag,
+  _0: StyleBar_u32,
+};
+
+pub const StyleBaz2_Body = extern struct {
+  tag: StyleBaz_Tag,
+  _0: StylePoint_i32,
+};
+
+pub const StyleBaz = extern union {
+  tag: StyleBaz_Tag,
+  baz1: StyleBaz1_Body,
+  baz2: StyleBaz2_Body,
+};
+
+pub const StyleTaz_Tag = enum(u8) {
+  Taz1,
+  Taz2,
+  Taz3,
+};
+
+pub const StyleTaz1_Body = extern struct {
+  _0: StyleBar_u32,
+};
+
+pub const StyleTaz2_Body = extern struct {
+  _0: StyleBaz,
+};
+
+pub const StyleTaz = extern struct {
+  tag: StyleTaz_Tag,
+  body: extern union {
+    taz1: StyleTaz1_Body,
+    taz2: StyleTaz2_Body,
+  },
+};
+
+pub extern fn foo(foo_: [*c]const StyleFoo_i32, bar: [*c]const StyleBar_i32, baz: [*c]const StyleBaz, taz: [*c]const StyleTaz) void;
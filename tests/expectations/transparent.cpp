@@ -15,9 +15,11 @@ using TransparentComplexWrappingStructure = DummyStruct;
 
 using TransparentPrimitiveWrappingStructure = uint32_t;
 
+/// Zero-sized fields elided: `marker`.
 template<typename T>
 using TransparentComplexWrapper = DummyStruct;
 
+/// Zero-sized fields elided: `marker`.
 template<typename T>
 using TransparentPrimitiveWrapper = uint32_t;
 
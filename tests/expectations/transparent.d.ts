@@ -0,0 +1,22 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const TransparentPrimitiveWithAssociatedConstants_ZERO: number;
+
+export declare const TransparentPrimitiveWithAssociatedConstants_ONE: number;
+
+export declare const EnumWithAssociatedConstantInImpl_TEN: number;
+
+export interface Library {
+  root(a: never, b: number, c: never, d: number, e: never, f: number, g: number, h: never): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,28 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type DummyStruct = C.DummyStruct
+
+type EnumWithAssociatedConstantInImpl = C.EnumWithAssociatedConstantInImpl
+
+type TransparentComplexWrappingStructTuple = C.TransparentComplexWrappingStructTuple
+
+type TransparentPrimitiveWrappingStructTuple = C.TransparentPrimitiveWrappingStructTuple
+
+type TransparentComplexWrappingStructure = C.TransparentComplexWrappingStructure
+
+type TransparentPrimitiveWrappingStructure = C.TransparentPrimitiveWrappingStructure
+
+// Zero-sized fields elided: `marker`.
+type TransparentComplexWrapper_i32 = C.TransparentComplexWrapper_i32
+
+// Zero-sized fields elided: `marker`.
+type TransparentPrimitiveWrapper_i32 = C.TransparentPrimitiveWrapper_i32
+
+type TransparentPrimitiveWithAssociatedConstants = C.TransparentPrimitiveWithAssociatedConstants
+
+func Root(a TransparentComplexWrappingStructTuple, b TransparentPrimitiveWrappingStructTuple, c TransparentComplexWrappingStructure, d TransparentPrimitiveWrappingStructure, e TransparentComplexWrapper_i32, f TransparentPrimitiveWrapper_i32, g TransparentPrimitiveWithAssociatedConstants, h EnumWithAssociatedConstantInImpl) {
+  C.root(a, b, c, d, e, f, g, h)
+}
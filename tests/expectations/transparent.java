@@ -0,0 +1,11 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  int TransparentPrimitiveWithAssociatedConstants_ZERO = 0;
+
+  int TransparentPrimitiveWithAssociatedConstants_ONE = 1;
+
+  int EnumWithAssociatedConstantInImpl_TEN = 10;
+
+  void root(Pointer a, int b, Pointer c, int d, Pointer e, int f, int g, Pointer h);
+}
@@ -0,0 +1,27 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+const TransparentPrimitiveWithAssociatedConstants_ZERO = 0;
+
+const TransparentPrimitiveWithAssociatedConstants_ONE = 1;
+
+const EnumWithAssociatedConstantInImpl_TEN = 10;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['void', 'uint32', 'void', 'uint32', 'void', 'uint32', 'uint32', 'void']],
+  });
+  return lib;
+}
+
+module.exports = {
+  TransparentPrimitiveWithAssociatedConstants_ZERO,
+  TransparentPrimitiveWithAssociatedConstants_ONE,
+  EnumWithAssociatedConstantInImpl_TEN,
+  load,
+};
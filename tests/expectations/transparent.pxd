@@ -0,0 +1,36 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct DummyStruct:
+    pass
+
+  ctypedef struct EnumWithAssociatedConstantInImpl:
+    pass
+
+  ctypedef DummyStruct TransparentComplexWrappingStructTuple
+
+  ctypedef uint32_t TransparentPrimitiveWrappingStructTuple
+
+  ctypedef DummyStruct TransparentComplexWrappingStructure
+
+  ctypedef uint32_t TransparentPrimitiveWrappingStructure
+
+  # Zero-sized fields elided: `marker`.
+  ctypedef DummyStruct TransparentComplexWrapper_i32
+
+  # Zero-sized fields elided: `marker`.
+  ctypedef uint32_t TransparentPrimitiveWrapper_i32
+
+  ctypedef uint32_t TransparentPrimitiveWithAssociatedConstants
+  const TransparentPrimitiveWithAssociatedConstants TransparentPrimitiveWithAssociatedConstants_ZERO
+  const TransparentPrimitiveWithAssociatedConstants TransparentPrimitiveWithAssociatedConstants_ONE
+
+  const TransparentPrimitiveWrappingStructure EnumWithAssociatedConstantInImpl_TEN
+
+  void root(TransparentComplexWrappingStructTuple a, TransparentPrimitiveWrappingStructTuple b, TransparentComplexWrappingStructure c, TransparentPrimitiveWrappingStructure d, TransparentComplexWrapper_i32 e, TransparentPrimitiveWrapper_i32 f, TransparentPrimitiveWithAssociatedConstants g, EnumWithAssociatedConstantInImpl h)
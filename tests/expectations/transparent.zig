@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const EnumWithAssociatedConstantInImpl_TEN: TransparentPrimitiveWrappingStructure = 10;
+
+pub const DummyStruct = opaque {};
+
+pub const EnumWithAssociatedConstantInImpl = opaque {};
+
+pub const TransparentComplexWrappingStructTuple = DummyStruct;
+
+pub const TransparentPrimitiveWrappingStructTuple = u32;
+
+pub const TransparentComplexWrappingStructure = DummyStruct;
+
+pub const TransparentPrimitiveWrappingStructure = u32;
+
+/// Zero-sized fields elided: `marker`.
+pub const TransparentComplexWrapper_i32 = DummyStruct;
+
+/// Zero-sized fields elided: `marker`.
+pub const TransparentPrimitiveWrapper_i32 = u32;
+
+pub const TransparentPrimitiveWithAssociatedConstants = u32;
+
+pub const TransparentPrimitiveWithAssociatedConstants_ZERO: TransparentPrimitiveWithAssociatedConstants = 0;
+
+pub const TransparentPrimitiveWithAssociatedConstants_ONE: TransparentPrimitiveWithAssociatedConstants = 1;
+
+pub extern fn root(a: TransparentComplexWrappingStructTuple, b: TransparentPrimitiveWrappingStructTuple, c: TransparentComplexWrappingStructure, d: TransparentPrimitiveWrappingStructure, e: TransparentComplexWrapper_i32, f: TransparentPrimitiveWrapper_i32, g: TransparentPrimitiveWithAssociatedConstants, h: EnumWithAssociatedConstantInImpl) void;
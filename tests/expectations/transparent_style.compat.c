@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A handle kept distinct from other integers.
+ */
+typedef struct {
+  uint64_t _0;
+} Handle;
+
+/**
+ * An identifier written as a typedef.
+ */
+typedef uint32_t Id;
+
+typedef struct {
+  Id id;
+  float height;
+  double weight;
+  const Handle *parent;
+} Body;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float measure(Handle handle, Body *body);
+
+Handle open(Id id, float length);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
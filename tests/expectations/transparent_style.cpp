@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/// A handle kept distinct from other integers.
+struct Handle {
+  uint64_t _0;
+};
+
+/// An identifier written as a typedef.
+using Id = uint32_t;
+
+struct Body {
+  Id id;
+  float height;
+  double weight;
+  const Handle *parent;
+};
+
+extern "C" {
+
+float measure(Handle handle, Body *body);
+
+Handle open(Id id, float length);
+
+} // extern "C"
@@ -0,0 +1,36 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * A handle kept distinct from other integers.
+ */
+export interface Handle {
+  _0: number | string;
+  ref(): Buffer;
+}
+export declare const Handle: StructType<Handle>;
+
+export interface Body {
+  id: number;
+  height: number;
+  weight: number;
+  parent: Buffer;
+  ref(): Buffer;
+}
+export declare const Body: StructType<Body>;
+
+export interface Library {
+  measure(handle: Handle, body: Buffer | null): number;
+
+  open(id: number, length: number): Handle;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,20 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// A handle kept distinct from other integers.
+type Handle = C.Handle
+
+// An identifier written as a typedef.
+type Id = C.Id
+
+type Body = C.Body
+
+func Measure(handle Handle, body *Body) float32 {
+  return float32(C.measure(handle, body))
+}
+
+func Open(id Id, length float32) Handle {
+  return C.open(id, C.float(length))
+}
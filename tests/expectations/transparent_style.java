@@ -0,0 +1,31 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * A handle kept distinct from other integers.
+   */
+  @Structure.FieldOrder({"_0"})
+  class Handle extends Structure {
+    public long _0;
+
+    public static class ByReference extends Handle implements Structure.ByReference {}
+
+    public static class ByValue extends Handle implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"id", "height", "weight", "parent"})
+  class Body extends Structure {
+    public int id;
+    public float height;
+    public double weight;
+    public Handle.ByReference parent;
+
+    public static class ByReference extends Body implements Structure.ByReference {}
+
+    public static class ByValue extends Body implements Structure.ByValue {}
+  }
+
+  float measure(Handle.ByValue handle, Body.ByReference body);
+
+  Handle.ByValue open(int id, float length);
+}
@@ -0,0 +1,32 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Handle = StructType();
+const Body = StructType();
+
+Handle.defineProperty('_0', 'uint64');
+
+Body.defineProperty('id', 'uint32');
+Body.defineProperty('height', 'float');
+Body.defineProperty('weight', 'double');
+Body.defineProperty('parent', ref.refType(Handle));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    measure: ['float', [Handle, ref.refType(Body)]],
+    open: [Handle, ['uint32', 'float']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Handle,
+  Body,
+  load,
+};
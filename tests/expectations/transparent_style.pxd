@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # A handle kept distinct from other integers.
+  ctypedef struct Handle:
+    uint64_t _0
+
+  # An identifier written as a typedef.
+  ctypedef uint32_t Id
+
+  ctypedef struct Body:
+    Id id
+    float height
+    double weight
+    const Handle *parent
+
+  float measure(Handle handle, Body *body)
+
+  Handle open(Id id, float length)
@@ -0,0 +1,33 @@
+import ctypes
+
+# A handle kept distinct from other integers.
+class Handle(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("_0", ctypes.c_uint64),
+]
+
+# An identifier written as a typedef.
+Id = ctypes.c_uint32
+
+class Body(ctypes.Structure):
+  pass
+
+Body._fields_ = [
+  ("id", Id),
+  ("height", ctypes.c_float),
+  ("weight", ctypes.c_double),
+  ("parent", ctypes.POINTER(Handle)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.measure.argtypes = [Handle, ctypes.POINTER(Body)]
+  lib.measure.restype = ctypes.c_float
+
+  lib.open.argtypes = [Id, ctypes.c_float]
+  lib.open.restype = Handle
+
+  return lib
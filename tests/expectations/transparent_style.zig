@@ -0,0 +1,20 @@
+const std = @import("std");
+
+/// A handle kept distinct from other integers.
+pub const Handle = extern struct {
+  _0: u64,
+};
+
+/// An identifier written as a typedef.
+pub const Id = u32;
+
+pub const Body = extern struct {
+  id: Id,
+  height: f32,
+  weight: f64,
+  parent: [*c]const Handle,
+};
+
+pub extern fn measure(handle: Handle, body: [*c]Body) f32;
+
+pub extern fn open(id: Id, length: f32) Handle;
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Slot {
+  /// Wrapped in `Cell`.
+  bool ready;
+  /// Wrapped in `MaybeUninit`.
+  uint64_t value;
+  uint8_t buffer[16];
+  /// Wrapped in `UnsafeCell`.
+  uint32_t state;
+  /// Wrapped in `ManuallyDrop`.
+  uint8_t *owner;
+};
+
+extern "C" {
+
+bool slot_take(Slot *slot, uint64_t *out);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Slot {
+  ready: boolean;
+  value: number | string;
+  buffer: ArrayLike<number>;
+  state: number;
+  owner: Buffer;
+  ref(): Buffer;
+}
+export declare const Slot: StructType<Slot>;
+
+export interface Library {
+  slot_take(slot: Buffer | null, out: Buffer | null): boolean;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Slot = C.Slot
+
+func SlotTake(slot *Slot, out *uint64) bool {
+  return bool(C.slot_take(slot, (*C.uint64_t)(unsafe.Pointer(out))))
+}
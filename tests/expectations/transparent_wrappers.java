@@ -0,0 +1,30 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"ready", "value", "buffer", "state", "owner"})
+  class Slot extends Structure {
+    /**
+     * Wrapped in `Cell`.
+     */
+    public byte ready;
+    /**
+     * Wrapped in `MaybeUninit`.
+     */
+    public long value;
+    public byte[] buffer = new byte[16];
+    /**
+     * Wrapped in `UnsafeCell`.
+     */
+    public int state;
+    /**
+     * Wrapped in `ManuallyDrop`.
+     */
+    public Pointer owner;
+
+    public static class ByReference extends Slot implements Structure.ByReference {}
+
+    public static class ByValue extends Slot implements Structure.ByValue {}
+  }
+
+  byte slot_take(Slot.ByReference slot, Pointer out);
+}
@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Slot = StructType();
+
+Slot.defineProperty('ready', 'bool');
+Slot.defineProperty('value', 'uint64');
+Slot.defineProperty('buffer', ArrayType('uint8', 16));
+Slot.defineProperty('state', 'uint32');
+Slot.defineProperty('owner', ref.refType('uint8'));
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    slot_take: ['bool', [ref.refType(Slot), ref.refType('uint64')]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Slot,
+  load,
+};
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Slot:
+    # Wrapped in `Cell`.
+    bool ready
+    # Wrapped in `MaybeUninit`.
+    uint64_t value
+    uint8_t buffer[16]
+    # Wrapped in `UnsafeCell`.
+    uint32_t state
+    # Wrapped in `ManuallyDrop`.
+    uint8_t *owner
+
+  bool slot_take(Slot *slot, uint64_t *out)
@@ -0,0 +1,24 @@
+import ctypes
+
+class Slot(ctypes.Structure):
+  pass
+
+Slot._fields_ = [
+  # Wrapped in `Cell`.
+  ("ready", ctypes.c_bool),
+  # Wrapped in `MaybeUninit`.
+  ("value", ctypes.c_uint64),
+  ("buffer", (ctypes.c_uint8 * 16)),
+  # Wrapped in `UnsafeCell`.
+  ("state", ctypes.c_uint32),
+  # Wrapped in `ManuallyDrop`.
+  ("owner", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.slot_take.argtypes = [ctypes.POINTER(Slot), ctypes.POINTER(ctypes.c_uint64)]
+  lib.slot_take.restype = ctypes.c_bool
+
+  return lib
@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Slot = extern struct {
+  /// Wrapped in `Cell`.
+  ready: bool,
+  /// Wrapped in `MaybeUninit`.
+  value: u64,
+  buffer: [16]u8,
+  /// Wrapped in `UnsafeCell`.
+  state: u32,
+  /// Wrapped in `ManuallyDrop`.
+  owner: [*c]u8,
+};
+
+pub extern fn slot_take(slot: [*c]Slot, out: [*c]u64) bool;
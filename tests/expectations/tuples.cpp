@@ -0,0 +1,54 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Tuple_i32__i32 {
+  int32_t _0;
+  int32_t _1;
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Tuple_Point__Point {
+  Point _0;
+  Point _1;
+};
+
+struct Segment {
+  Tuple_Point__Point ends;
+  float weight;
+};
+
+struct Tuple_Segment__Segment {
+  Segment _0;
+  Segment _1;
+};
+
+struct Tuple_ConstPtr_u8_____usize {
+  const uint8_t *_0;
+  uintptr_t _1;
+};
+
+struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+};
+
+template<typename T>
+struct Wrapper {
+  T value;
+};
+
+extern "C" {
+
+Tuple_i32__i32 div_mod(int32_t a, int32_t b);
+
+Tuple_Segment__Segment split(Segment s, Tuple_ConstPtr_u8_____usize bytes);
+
+void wrapped(Wrapper<Tuple_u8__u16> w);
+
+} // extern "C"
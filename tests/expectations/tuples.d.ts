@@ -0,0 +1,75 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Tuple_i32__i32 {
+  _0: number;
+  _1: number;
+  ref(): Buffer;
+}
+export declare const Tuple_i32__i32: StructType<Tuple_i32__i32>;
+
+export interface Point {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Point: StructType<Point>;
+
+export interface Tuple_Point__Point {
+  _0: Point;
+  _1: Point;
+  ref(): Buffer;
+}
+export declare const Tuple_Point__Point: StructType<Tuple_Point__Point>;
+
+export interface Segment {
+  ends: Tuple_Point__Point;
+  weight: number;
+  ref(): Buffer;
+}
+export declare const Segment: StructType<Segment>;
+
+export interface Tuple_Segment__Segment {
+  _0: Segment;
+  _1: Segment;
+  ref(): Buffer;
+}
+export declare const Tuple_Segment__Segment: StructType<Tuple_Segment__Segment>;
+
+export interface Tuple_ConstPtr_u8_____usize {
+  _0: Buffer;
+  _1: number | string;
+  ref(): Buffer;
+}
+export declare const Tuple_ConstPtr_u8_____usize: StructType<Tuple_ConstPtr_u8_____usize>;
+
+export interface Tuple_u8__u16 {
+  _0: number;
+  _1: number;
+  ref(): Buffer;
+}
+export declare const Tuple_u8__u16: StructType<Tuple_u8__u16>;
+
+export interface Wrapper_Tuple_u8__u16 {
+  value: Tuple_u8__u16;
+  ref(): Buffer;
+}
+export declare const Wrapper_Tuple_u8__u16: StructType<Wrapper_Tuple_u8__u16>;
+
+export interface Library {
+  div_mod(a: number, b: number): Tuple_i32__i32;
+
+  split(s: Segment, bytes: Tuple_ConstPtr_u8_____usize): Tuple_Segment__Segment;
+
+  wrapped(w: Wrapper_Tuple_u8__u16): void;
+}
+
+export declare function load(path: string): Library;
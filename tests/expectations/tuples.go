@@ -0,0 +1,32 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Tuple_i32__i32 = C.Tuple_i32__i32
+
+type Point = C.Point
+
+type Tuple_Point__Point = C.Tuple_Point__Point
+
+type Segment = C.Segment
+
+type Tuple_Segment__Segment = C.Tuple_Segment__Segment
+
+type Tuple_ConstPtr_u8_____usize = C.Tuple_ConstPtr_u8_____usize
+
+type Tuple_u8__u16 = C.Tuple_u8__u16
+
+type Wrapper_Tuple_u8__u16 = C.Wrapper_Tuple_u8__u16
+
+func DivMod(a int32, b int32) Tuple_i32__i32 {
+  return C.div_mod(C.int32_t(a), C.int32_t(b))
+}
+
+func Split(s Segment, bytes Tuple_ConstPtr_u8_____usize) Tuple_Segment__Segment {
+  return C.split(s, bytes)
+}
+
+func Wrapped(w Wrapper_Tuple_u8__u16) {
+  C.wrapped(w)
+}
@@ -0,0 +1,98 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple_i32__i32 extends Structure {
+    public int _0;
+    public int _1;
+
+    public static class ByReference extends Tuple_i32__i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_i32__i32 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"x", "y"})
+  class Point extends Structure {
+    public float x;
+    public float y;
+
+    public static class ByReference extends Point implements Structure.ByReference {}
+
+    public static class ByValue extends Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple_Point__Point extends Structure {
+    public Point _0;
+    public Point _1;
+
+    public static class ByReference extends Tuple_Point__Point implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_Point__Point implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"ends", "weight"})
+  class Segment extends Structure {
+    public Tuple_Point__Point ends;
+    public float weight;
+
+    public static class ByReference extends Segment implements Structure.ByReference {}
+
+    public static class ByValue extends Segment implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple_Segment__Segment extends Structure {
+    public Segment _0;
+    public Segment _1;
+
+    public static class ByReference extends Tuple_Segment__Segment implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_Segment__Segment implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple_ConstPtr_u8_____usize extends Structure {
+    public Pointer _0;
+    public SizeT _1;
+
+    public static class ByReference extends Tuple_ConstPtr_u8_____usize implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_ConstPtr_u8_____usize implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple_u8__u16 extends Structure {
+    public byte _0;
+    public short _1;
+
+    public static class ByReference extends Tuple_u8__u16 implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple_u8__u16 implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"value"})
+  class Wrapper_Tuple_u8__u16 extends Structure {
+    public Tuple_u8__u16 value;
+
+    public static class ByReference extends Wrapper_Tuple_u8__u16 implements Structure.ByReference {}
+
+    public static class ByValue extends Wrapper_Tuple_u8__u16 implements Structure.ByValue {}
+  }
+
+  Tuple_i32__i32.ByValue div_mod(int a, int b);
+
+  Tuple_Segment__Segment.ByValue split(Segment.ByValue s, Tuple_ConstPtr_u8_____usize.ByValue bytes);
+
+  void wrapped(Wrapper_Tuple_u8__u16.ByValue w);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
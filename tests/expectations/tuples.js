@@ -0,0 +1,61 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Tuple_i32__i32 = StructType();
+const Point = StructType();
+const Tuple_Point__Point = StructType();
+const Segment = StructType();
+const Tuple_Segment__Segment = StructType();
+const Tuple_ConstPtr_u8_____usize = StructType();
+const Tuple_u8__u16 = StructType();
+const Wrapper_Tuple_u8__u16 = StructType();
+
+Tuple_i32__i32.defineProperty('_0', 'int32');
+Tuple_i32__i32.defineProperty('_1', 'int32');
+
+Point.defineProperty('x', 'float');
+Point.defineProperty('y', 'float');
+
+Tuple_Point__Point.defineProperty('_0', Point);
+Tuple_Point__Point.defineProperty('_1', Point);
+
+Segment.defineProperty('ends', Tuple_Point__Point);
+Segment.defineProperty('weight', 'float');
+
+Tuple_Segment__Segment.defineProperty('_0', Segment);
+Tuple_Segment__Segment.defineProperty('_1', Segment);
+
+Tuple_ConstPtr_u8_____usize.defineProperty('_0', ref.refType('uint8'));
+Tuple_ConstPtr_u8_____usize.defineProperty('_1', 'size_t');
+
+Tuple_u8__u16.defineProperty('_0', 'uint8');
+Tuple_u8__u16.defineProperty('_1', 'uint16');
+
+Wrapper_Tuple_u8__u16.defineProperty('value', Tuple_u8__u16);
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    div_mod: [Tuple_i32__i32, ['int32', 'int32']],
+    split: [Tuple_Segment__Segment, [Segment, Tuple_ConstPtr_u8_____usize]],
+    wrapped: ['void', [Wrapper_Tuple_u8__u16]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Tuple_i32__i32,
+  Point,
+  Tuple_Point__Point,
+  Segment,
+  Tuple_Segment__Segment,
+  Tuple_ConstPtr_u8_____usize,
+  Tuple_u8__u16,
+  Wrapper_Tuple_u8__u16,
+  load,
+};
@@ -0,0 +1,45 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef struct Tuple_i32__i32:
+    int32_t _0
+    int32_t _1
+
+  ctypedef struct Point:
+    float x
+    float y
+
+  ctypedef struct Tuple_Point__Point:
+    Point _0
+    Point _1
+
+  ctypedef struct Segment:
+    Tuple_Point__Point ends
+    float weight
+
+  ctypedef struct Tuple_Segment__Segment:
+    Segment _0
+    Segment _1
+
+  ctypedef struct Tuple_ConstPtr_u8_____usize:
+    const uint8_t *_0
+    uintptr_t _1
+
+  ctypedef struct Tuple_u8__u16:
+    uint8_t _0
+    uint16_t _1
+
+  ctypedef struct Wrapper_Tuple_u8__u16:
+    Tuple_u8__u16 value
+
+  Tuple_i32__i32 div_mod(int32_t a, int32_t b)
+
+  Tuple_Segment__Segment split(Segment s, Tuple_ConstPtr_u8_____usize bytes)
+
+  void wrapped(Wrapper_Tuple_u8__u16 w)
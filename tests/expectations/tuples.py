@@ -0,0 +1,78 @@
+import ctypes
+
+class Tuple_i32__i32(ctypes.Structure):
+  pass
+
+Tuple_i32__i32._fields_ = [
+  ("_0", ctypes.c_int32),
+  ("_1", ctypes.c_int32),
+]
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class Tuple_Point__Point(ctypes.Structure):
+  pass
+
+Tuple_Point__Point._fields_ = [
+  ("_0", Point),
+  ("_1", Point),
+]
+
+class Segment(ctypes.Structure):
+  pass
+
+Segment._fields_ = [
+  ("ends", Tuple_Point__Point),
+  ("weight", ctypes.c_float),
+]
+
+class Tuple_Segment__Segment(ctypes.Structure):
+  pass
+
+Tuple_Segment__Segment._fields_ = [
+  ("_0", Segment),
+  ("_1", Segment),
+]
+
+class Tuple_ConstPtr_u8_____usize(ctypes.Structure):
+  pass
+
+Tuple_ConstPtr_u8_____usize._fields_ = [
+  ("_0", ctypes.POINTER(ctypes.c_uint8)),
+  ("_1", ctypes.c_size_t),
+]
+
+class Tuple_u8__u16(ctypes.Structure):
+  pass
+
+Tuple_u8__u16._fields_ = [
+  ("_0", ctypes.c_uint8),
+  ("_1", ctypes.c_uint16),
+]
+
+class Wrapper_Tuple_u8__u16(ctypes.Structure):
+  pass
+
+Wrapper_Tuple_u8__u16._fields_ = [
+  ("value", Tuple_u8__u16),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.div_mod.argtypes = [ctypes.c_int32, ctypes.c_int32]
+  lib.div_mod.restype = Tuple_i32__i32
+
+  lib.split.argtypes = [Segment, Tuple_ConstPtr_u8_____usize]
+  lib.split.restype = Tuple_Segment__Segment
+
+  lib.wrapped.argtypes = [Wrapper_Tuple_u8__u16]
+  lib.wrapped.restype = None
+
+  return lib
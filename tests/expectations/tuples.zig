@@ -0,0 +1,46 @@
+const std = @import("std");
+
+pub const Tuple_i32__i32 = extern struct {
+  _0: i32,
+  _1: i32,
+};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Tuple_Point__Point = extern struct {
+  _0: Point,
+  _1: Point,
+};
+
+pub const Segment = extern struct {
+  ends: Tuple_Point__Point,
+  weight: f32,
+};
+
+pub const Tuple_Segment__Segment = extern struct {
+  _0: Segment,
+  _1: Segment,
+};
+
+pub const Tuple_ConstPtr_u8_____usize = extern struct {
+  _0: [*c]const u8,
+  _1: usize,
+};
+
+pub const Tuple_u8__u16 = extern struct {
+  _0: u8,
+  _1: u16,
+};
+
+pub const Wrapper_Tuple_u8__u16 = extern struct {
+  value: Tuple_u8__u16,
+};
+
+pub extern fn div_mod(a: i32, b: i32) Tuple_i32__i32;
+
+pub extern fn split(s: Segment, bytes: Tuple_ConstPtr_u8_____usize) Tuple_Segment__Segment;
+
+pub extern fn wrapped(w: Wrapper_Tuple_u8__u16) void;
@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Foo_i32__i32 {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Foo_i32__i32: StructType<Foo_i32__i32>;
+
+export type IntFoo_i32 = Foo_i32__i32;
+export declare const IntFoo_i32: RefType;
+
+export interface Library {
+  root(a: Foo_i32__i32): void;
+}
+
+export declare function load(path: string): Library;
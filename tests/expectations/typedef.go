@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Foo_i32__i32 = C.Foo_i32__i32
+
+type IntFoo_i32 = C.IntFoo_i32
+
+func Root(a IntFoo_i32) {
+  C.root(a)
+}
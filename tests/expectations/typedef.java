@@ -0,0 +1,15 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"x", "y"})
+  class Foo_i32__i32 extends Structure {
+    public int x;
+    public int y;
+
+    public static class ByReference extends Foo_i32__i32 implements Structure.ByReference {}
+
+    public static class ByValue extends Foo_i32__i32 implements Structure.ByValue {}
+  }
+
+  void root(Foo_i32__i32.ByValue a);
+}
@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Foo_i32__i32 = StructType();
+
+Foo_i32__i32.defineProperty('x', 'int32');
+Foo_i32__i32.defineProperty('y', 'int32');
+
+const IntFoo_i32 = Foo_i32__i32;
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Foo_i32__i32]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Foo_i32__i32,
+  IntFoo_i32,
+  load,
+};
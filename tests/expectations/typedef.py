@@ -0,0 +1,19 @@
+import ctypes
+
+class Foo_i32__i32(ctypes.Structure):
+  pass
+
+Foo_i32__i32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+IntFoo_i32 = Foo_i32__i32
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IntFoo_i32]
+  lib.root.restype = None
+
+  return lib
@@ -10,6 +10,9 @@ typedef union {
   float y;
 } Normal;
 
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
 typedef union {
   int32_t x;
   float y;
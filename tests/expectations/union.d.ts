@@ -0,0 +1,33 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Normal {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const Normal: StructType<Normal>;
+
+/**
+ * Zero-sized fields elided: `z`, `w`.
+ */
+export interface NormalWithZST {
+  x: number;
+  y: number;
+  ref(): Buffer;
+}
+export declare const NormalWithZST: StructType<NormalWithZST>;
+
+export interface Library {
+  root(a: Buffer | null, b: Normal, c: NormalWithZST): void;
+}
+
+export declare function load(path: string): Library;
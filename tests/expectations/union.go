@@ -0,0 +1,15 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Opaque = C.Opaque
+
+type Normal = C.Normal
+
+// Zero-sized fields elided: `z`, `w`.
+type NormalWithZST = C.NormalWithZST
+
+func Root(a *Opaque, b Normal, c NormalWithZST) {
+  C.root(a, b, c)
+}
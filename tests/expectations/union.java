@@ -0,0 +1,26 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  class Normal extends Union {
+    public int x;
+    public float y;
+
+    public static class ByReference extends Normal implements Structure.ByReference {}
+
+    public static class ByValue extends Normal implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `z`, `w`.
+   */
+  class NormalWithZST extends Union {
+    public int x;
+    public float y;
+
+    public static class ByReference extends NormalWithZST implements Structure.ByReference {}
+
+    public static class ByValue extends NormalWithZST implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, Normal.ByValue b, NormalWithZST.ByValue c);
+}
@@ -0,0 +1,30 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Normal = UnionType();
+const NormalWithZST = UnionType();
+
+Normal.defineProperty('x', 'int32');
+Normal.defineProperty('y', 'float');
+
+NormalWithZST.defineProperty('x', 'int32');
+NormalWithZST.defineProperty('y', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['pointer', Normal, NormalWithZST]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Normal,
+  NormalWithZST,
+  load,
+};
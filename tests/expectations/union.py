@@ -0,0 +1,29 @@
+import ctypes
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Normal(ctypes.Union):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+# Zero-sized fields elided: `z`, `w`.
+class NormalWithZST(ctypes.Union):
+  pass
+
+NormalWithZST._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST]
+  lib.root.restype = None
+
+  return lib
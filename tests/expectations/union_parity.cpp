@@ -0,0 +1,49 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/// A union of two integers that doesn't pad `wide`.
+union PACKED Packed {
+  /// The narrow view.
+  uint8_t narrow;
+  /// The wide view.
+  uint32_t wide;
+};
+
+union ALIGNED(16) Aligned {
+  uint8_t bytes[4];
+  uint32_t word;
+};
+
+template<typename T>
+union Generic {
+  T asValue;
+  uint64_t asBits;
+};
+
+union [[deprecated("use Aligned")]] Old {
+  int32_t a;
+  float b;
+};
+
+union MUST_USE_UNION Checked {
+  int32_t a;
+  float b;
+};
+
+extern "C" {
+
+void root(Packed a, Aligned b, Generic<double> c, Generic<uint32_t> d, Old e, Checked f);
+
+} // extern "C"
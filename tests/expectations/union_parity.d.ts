@@ -0,0 +1,54 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Generic_f64 {
+  asValue: number;
+  asBits: number | string;
+  ref(): Buffer;
+}
+export declare const Generic_f64: StructType<Generic_f64>;
+
+export interface Generic_u32 {
+  asValue: number;
+  asBits: number | string;
+  ref(): Buffer;
+}
+export declare const Generic_u32: StructType<Generic_u32>;
+
+export interface Old {
+  a: number;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Old: StructType<Old>;
+
+export interface Checked {
+  a: number;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Checked: StructType<Checked>;
+
+export interface Library {
+  root(a: never, b: never, c: Generic_f64, d: Generic_u32, e: Old, f: Checked): void;
+}
+
+export declare function load(path: string): Library;
@@ -0,0 +1,31 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// A union of two integers that doesn't pad `wide`.
+type Packed = C.Packed
+
+type Aligned = C.Aligned
+
+type Generic_f64 = C.Generic_f64
+
+type Generic_u32 = C.Generic_u32
+
+type Old = C.Old
+
+type Checked = C.Checked
+
+func Root(a Packed, b Aligned, c Generic_f64, d Generic_u32, e Old, f Checked) {
+  C.root(a, b, c, d, e, f)
+}
@@ -0,0 +1,51 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  class Generic_f64 extends Union {
+    public double asValue;
+    public long asBits;
+
+    public static class ByReference extends Generic_f64 implements Structure.ByReference {}
+
+    public static class ByValue extends Generic_f64 implements Structure.ByValue {}
+  }
+
+  class Generic_u32 extends Union {
+    public int asValue;
+    public long asBits;
+
+    public static class ByReference extends Generic_u32 implements Structure.ByReference {}
+
+    public static class ByValue extends Generic_u32 implements Structure.ByValue {}
+  }
+
+  class Old extends Union {
+    public int a;
+    public float b;
+
+    public static class ByReference extends Old implements Structure.ByReference {}
+
+    public static class ByValue extends Old implements Structure.ByValue {}
+  }
+
+  class Checked extends Union {
+    public int a;
+    public float b;
+
+    public static class ByReference extends Checked implements Structure.ByReference {}
+
+    public static class ByValue extends Checked implements Structure.ByValue {}
+  }
+
+  void root(Pointer a, Pointer b, Generic_f64.ByValue c, Generic_u32.ByValue d, Old.ByValue e, Checked.ByValue f);
+}
@@ -0,0 +1,50 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Generic_f64 = UnionType();
+const Generic_u32 = UnionType();
+const Old = UnionType();
+const Checked = UnionType();
+
+Generic_f64.defineProperty('asValue', 'double');
+Generic_f64.defineProperty('asBits', 'uint64');
+
+Generic_u32.defineProperty('asValue', 'uint32');
+Generic_u32.defineProperty('asBits', 'uint64');
+
+Old.defineProperty('a', 'int32');
+Old.defineProperty('b', 'float');
+
+Checked.defineProperty('a', 'int32');
+Checked.defineProperty('b', 'float');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', ['void', 'void', Generic_f64, Generic_u32, Old, Checked]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Generic_f64,
+  Generic_u32,
+  Old,
+  Checked,
+  load,
+};
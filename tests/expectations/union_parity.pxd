@@ -0,0 +1,47 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # A union of two integers that doesn't pad `wide`.
+  ctypedef union Packed:
+    # The narrow view.
+    uint8_t narrow
+    # The wide view.
+    uint32_t wide
+
+  ctypedef union Aligned:
+    uint8_t bytes[4]
+    uint32_t word
+
+  ctypedef union Generic_f64:
+    double asValue
+    uint64_t asBits
+
+  ctypedef union Generic_u32:
+    uint32_t asValue
+    uint64_t asBits
+
+  ctypedef union Old:
+    int32_t a
+    float b
+
+  ctypedef union Checked:
+    int32_t a
+    float b
+
+  void root(Packed a, Aligned b, Generic_f64 c, Generic_u32 d, Old e, Checked f)
@@ -0,0 +1,72 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+import ctypes
+
+# A union of two integers that doesn't pad `wide`.
+class Packed(ctypes.Union):
+  pass
+
+Packed._pack_ = 1
+Packed._fields_ = [
+  # The narrow view.
+  ("narrow", ctypes.c_uint8),
+  # The wide view.
+  ("wide", ctypes.c_uint32),
+]
+
+class Aligned(ctypes.Union):
+  pass
+
+Aligned._align_ = 16
+Aligned._fields_ = [
+  ("bytes", (ctypes.c_uint8 * 4)),
+  ("word", ctypes.c_uint32),
+]
+
+class Generic_f64(ctypes.Union):
+  pass
+
+Generic_f64._fields_ = [
+  ("asValue", ctypes.c_double),
+  ("asBits", ctypes.c_uint64),
+]
+
+class Generic_u32(ctypes.Union):
+  pass
+
+Generic_u32._fields_ = [
+  ("asValue", ctypes.c_uint32),
+  ("asBits", ctypes.c_uint64),
+]
+
+class Old(ctypes.Union):
+  pass
+
+Old._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_float),
+]
+
+class Checked(ctypes.Union):
+  pass
+
+Checked._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Packed, Aligned, Generic_f64, Generic_u32, Old, Checked]
+  lib.root.restype = None
+
+  return lib
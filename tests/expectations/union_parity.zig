@@ -0,0 +1,46 @@
+#if defined(_MSC_VER)
+#define PACKED
+#define ALIGNED(n) __declspec(align(n))
+#else
+#define PACKED __attribute__((packed))
+#define ALIGNED(n) __attribute__((aligned(n)))
+#endif
+#define MUST_USE_UNION __attribute__((warn_unused))
+
+
+const std = @import("std");
+
+/// A union of two integers that doesn't pad `wide`.
+pub const Packed = extern union {
+  /// The narrow view.
+  narrow: u8 align(1),
+  /// The wide view.
+  wide: u32 align(1),
+};
+
+pub const Aligned = extern union {
+  bytes: [4]u8 align(@max(16, @alignOf([4]u8))),
+  word: u32,
+};
+
+pub const Generic_f64 = extern union {
+  asValue: f64,
+  asBits: u64,
+};
+
+pub const Generic_u32 = extern union {
+  asValue: u32,
+  asBits: u64,
+};
+
+pub const Old = extern union {
+  a: i32,
+  b: f32,
+};
+
+pub const Checked = extern union {
+  a: i32,
+  b: f32,
+};
+
+pub extern fn root(a: Packed, b: Aligned, c: Generic_f64, d: Generic_u32, e: Old, f: Checked) void;
@@ -0,0 +1,8 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+func VaListTest(ap C.va_list) int32 {
+  return int32(C.va_list_test(ap))
+}
@@ -0,0 +1,9 @@
+import ctypes
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.va_list_test.argtypes = [ctypes.c_void_p]
+  lib.va_list_test.restype = ctypes.c_int32
+
+  return lib
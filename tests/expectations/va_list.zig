@@ -0,0 +1,3 @@
+const std = @import("std");
+
+pub extern fn va_list_test(ap: std.builtin.VaList) i32;
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef void (*Logger)(int32_t level, const char *format, ...);
+
+typedef struct {
+  Logger log;
+  int32_t (*print)(const char *format, ...);
+} Sink;
+
+extern void log_message(int32_t level, const char *format, ...);
+
+void set_sink(Sink sink);
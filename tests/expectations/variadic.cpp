@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+using Logger = void(*)(int32_t level, const char *format, ...);
+
+struct Sink {
+  Logger log;
+  int32_t (*print)(const char *format, ...);
+};
+
+extern "C" {
+
+extern void log_message(int32_t level, const char *format, ...);
+
+void set_sink(Sink sink);
+
+} // extern "C"
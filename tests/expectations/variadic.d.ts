@@ -0,0 +1,26 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export interface Sink {
+  log: Buffer;
+  print: Buffer;
+  ref(): Buffer;
+}
+export declare const Sink: StructType<Sink>;
+
+export type Logger = Buffer;
+export declare const Logger: RefType;
+
+export interface Library {
+  set_sink(sink: Sink): void;
+}
+
+export declare function load(path: string): Library;
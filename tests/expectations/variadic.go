@@ -0,0 +1,12 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Logger = C.Logger
+
+type Sink = C.Sink
+
+func SetSink(sink Sink) {
+  C.set_sink(sink)
+}
@@ -0,0 +1,21 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Logger extends Callback {
+    void invoke(int level, String format);
+  }
+
+  @Structure.FieldOrder({"log", "print"})
+  class Sink extends Structure {
+    public Logger log;
+    public Pointer print;
+
+    public static class ByReference extends Sink implements Structure.ByReference {}
+
+    public static class ByValue extends Sink implements Structure.ByValue {}
+  }
+
+  void log_message(int level, String format, Object... varargs);
+
+  void set_sink(Sink.ByValue sink);
+}
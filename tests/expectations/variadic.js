@@ -0,0 +1,28 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Sink = StructType();
+
+Sink.defineProperty('log', 'pointer');
+Sink.defineProperty('print', 'pointer');
+
+const Logger = 'pointer';
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    set_sink: ['void', [Sink]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Sink,
+  Logger,
+  load,
+};
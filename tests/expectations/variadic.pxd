@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef void (*Logger)(int32_t level, const char *format, ...)
+
+  ctypedef struct Sink:
+    Logger log
+    int32_t (*print)(const char *format, ...)
+
+  void log_message(int32_t level, const char *format, ...)
+
+  void set_sink(Sink sink)
@@ -0,0 +1,22 @@
+import ctypes
+
+Logger = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_char_p)
+
+class Sink(ctypes.Structure):
+  pass
+
+Sink._fields_ = [
+  ("log", Logger),
+  ("print", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_char_p)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.log_message.argtypes = [ctypes.c_int32, ctypes.c_char_p]
+  lib.log_message.restype = None
+
+  lib.set_sink.argtypes = [Sink]
+  lib.set_sink.restype = None
+
+  return lib
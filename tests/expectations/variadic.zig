@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const Logger = *const fn (i32, [*c]const c_char, ...) callconv(.C) void;
+
+pub const Sink = extern struct {
+  log: Logger,
+  print: *const fn ([*c]const c_char, ...) callconv(.C) i32,
+};
+
+pub extern fn log_message(level: i32, format: [*c]const c_char, ...) void;
+
+pub extern fn set_sink(sink: Sink) void;
@@ -0,0 +1,44 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Filter : uint32_t {
+  Nearest,
+  Linear,
+};
+
+struct Rgba {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+  uint8_t a;
+};
+
+/// A single `f64`, passed as is.
+struct Meters {
+  double _0;
+};
+
+extern "C" {
+
+extern uint64_t FRAME_COUNT;
+
+Rgba average_color();
+
+uint32_t checksum(const uint8_t *data, uintptr_t len);
+
+Meters distance(Meters from, Meters to);
+
+/// Fills the `len` pixels at `pixels` with `color`.
+void fill(Rgba *pixels, uintptr_t len, Rgba color);
+
+extern void host_log(const uint8_t *message, uintptr_t len);
+
+extern double host_now();
+
+double sum_samples(const float *samples, uintptr_t count, Filter filter);
+
+int64_t timestamp();
+
+} // extern "C"
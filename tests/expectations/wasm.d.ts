@@ -0,0 +1,58 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Filter: {
+  readonly Nearest: 0;
+  readonly Linear: 1;
+};
+
+export interface Rgba {
+  r: number;
+  g: number;
+  b: number;
+  a: number;
+  ref(): Buffer;
+}
+export declare const Rgba: StructType<Rgba>;
+
+/**
+ * A single `f64`, passed as is.
+ */
+export interface Meters {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Meters: StructType<Meters>;
+
+export interface Library {
+  FRAME_COUNT: Buffer;
+
+  average_color(): Rgba;
+
+  checksum(data: Buffer | null, len: number | string): number;
+
+  distance(from: Meters, to: Meters): Meters;
+
+  /**
+   * Fills the `len` pixels at `pixels` with `color`.
+   */
+  fill(pixels: Buffer | null, len: number | string, color: Rgba): void;
+
+  host_log(message: Buffer | null, len: number | string): void;
+
+  host_now(): number;
+
+  sum_samples(samples: Buffer | null, count: number | string, filter: number): number;
+
+  timestamp(): number | string;
+}
+
+export declare function load(path: string): Library;
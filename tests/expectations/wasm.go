@@ -0,0 +1,55 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+type Filter = C.Filter
+
+const (
+  Nearest Filter = C.Nearest
+  Linear Filter = C.Linear
+)
+
+type Rgba = C.Rgba
+
+// A single `f64`, passed as is.
+type Meters = C.Meters
+
+func FrameCount() uint64 {
+  return uint64(C.FRAME_COUNT)
+}
+
+func AverageColor() Rgba {
+  return C.average_color()
+}
+
+func Checksum(data *uint8, len uintptr) uint32 {
+  return uint32(C.checksum((*C.uint8_t)(unsafe.Pointer(data)), C.uintptr_t(len)))
+}
+
+func Distance(from Meters, to Meters) Meters {
+  return C.distance(from, to)
+}
+
+// Fills the `len` pixels at `pixels` with `color`.
+func Fill(pixels *Rgba, len uintptr, color Rgba) {
+  C.fill(pixels, C.uintptr_t(len), color)
+}
+
+func HostLog(message *uint8, len uintptr) {
+  C.host_log((*C.uint8_t)(unsafe.Pointer(message)), C.uintptr_t(len))
+}
+
+func HostNow() float64 {
+  return float64(C.host_now())
+}
+
+func SumSamples(samples *float32, count uintptr, filter Filter) float64 {
+  return float64(C.sum_samples((*C.float)(unsafe.Pointer(samples)), C.uintptr_t(count), filter))
+}
+
+func Timestamp() int64 {
+  return int64(C.timestamp())
+}
@@ -0,0 +1,61 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Filter {
+    int Nearest = 0;
+    int Linear = 1;
+  }
+
+  @Structure.FieldOrder({"r", "g", "b", "a"})
+  class Rgba extends Structure {
+    public byte r;
+    public byte g;
+    public byte b;
+    public byte a;
+
+    public static class ByReference extends Rgba implements Structure.ByReference {}
+
+    public static class ByValue extends Rgba implements Structure.ByValue {}
+  }
+
+  /**
+   * A single `f64`, passed as is.
+   */
+  @Structure.FieldOrder({"_0"})
+  class Meters extends Structure {
+    public double _0;
+
+    public static class ByReference extends Meters implements Structure.ByReference {}
+
+    public static class ByValue extends Meters implements Structure.ByValue {}
+  }
+
+  Rgba.ByValue average_color();
+
+  int checksum(Pointer data, SizeT len);
+
+  Meters.ByValue distance(Meters.ByValue from, Meters.ByValue to);
+
+  /**
+   * Fills the `len` pixels at `pixels` with `color`.
+   */
+  void fill(Rgba.ByReference pixels, SizeT len, Rgba.ByValue color);
+
+  void host_log(Pointer message, SizeT len);
+
+  double host_now();
+
+  double sum_samples(Pointer samples, SizeT count, int filter);
+
+  long timestamp();
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,46 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Rgba = StructType();
+const Meters = StructType();
+
+Rgba.defineProperty('r', 'uint8');
+Rgba.defineProperty('g', 'uint8');
+Rgba.defineProperty('b', 'uint8');
+Rgba.defineProperty('a', 'uint8');
+
+Meters.defineProperty('_0', 'double');
+
+const Filter = Object.freeze({
+  Nearest: 0,
+  Linear: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    average_color: [Rgba, []],
+    checksum: ['uint32', [ref.refType('uint8'), 'size_t']],
+    distance: [Meters, [Meters, Meters]],
+    fill: ['void', [ref.refType(Rgba), 'size_t', Rgba]],
+    host_log: ['void', [ref.refType('uint8'), 'size_t']],
+    host_now: ['double', []],
+    sum_samples: ['double', [ref.refType('float'), 'size_t', 'uint32']],
+    timestamp: ['int64', []],
+  });
+  const dylib = new ffi.DynamicLibrary(path);
+  lib.FRAME_COUNT = dylib.get('FRAME_COUNT');
+  return lib;
+}
+
+module.exports = {
+  Rgba,
+  Meters,
+  Filter,
+  load,
+};
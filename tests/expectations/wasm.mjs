@@ -0,0 +1,66 @@
+/** The signatures of the functions the module exports. */
+export const functions = {
+  average_color: {
+    params: ["i32"],
+    results: [],
+    sret: true,
+  },
+  checksum: {
+    params: ["i32", "i32"],
+    results: ["i32"],
+    slices: [{ ptr: 0, len: 1, type: Uint8Array }],
+  },
+  distance: {
+    params: ["f64", "f64"],
+    results: ["f64"],
+  },
+  /**
+   * Fills the `len` pixels at `pixels` with `color`.
+   */
+  fill: {
+    params: ["i32", "i32", "i32"],
+    results: [],
+  },
+  sum_samples: {
+    params: ["i32", "i32", "i32"],
+    results: ["f64"],
+    slices: [{ ptr: 0, len: 1, type: Float32Array }],
+  },
+  timestamp: {
+    params: [],
+    results: ["i64"],
+  },
+};
+
+/** The signatures of the functions the module imports. */
+export const imports = {
+  "env": {
+    host_log: {
+      params: ["i32", "i32"],
+      results: [],
+      slices: [{ ptr: 0, len: 1, type: Uint8Array }],
+    },
+    host_now: {
+      params: [],
+      results: ["f64"],
+    },
+  },
+};
+
+/** The statics the module exports, as globals holding their address. */
+export const globals = ["FRAME_COUNT"];
+
+/** Returns a view of `len` elements of `type` at `ptr` in `memory`. */
+export function view(memory, type, ptr, len) {
+  return new type(memory.buffer, ptr, len);
+}
+
+/**
+ * Returns views of the slices that `args`, the arguments of a call to a
+ * function with `signature`, point to in `memory`.
+ */
+export function slices(memory, signature, args) {
+  return (signature.slices || []).map((slice) =>
+    view(memory, slice.type, args[slice.ptr], Number(args[slice.len])),
+  );
+}
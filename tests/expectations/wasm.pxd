@@ -0,0 +1,42 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  enum:
+    Nearest
+    Linear
+  ctypedef uint32_t Filter
+
+  ctypedef struct Rgba:
+    uint8_t r
+    uint8_t g
+    uint8_t b
+    uint8_t a
+
+  # A single `f64`, passed as is.
+  ctypedef struct Meters:
+    double _0
+
+  uint64_t FRAME_COUNT
+
+  Rgba average_color()
+
+  uint32_t checksum(const uint8_t *data, uintptr_t len)
+
+  Meters distance(Meters from, Meters to)
+
+  # Fills the `len` pixels at `pixels` with `color`.
+  void fill(Rgba *pixels, uintptr_t len, Rgba color)
+
+  void host_log(const uint8_t *message, uintptr_t len)
+
+  double host_now()
+
+  double sum_samples(const float *samples, uintptr_t count, Filter filter)
+
+  int64_t timestamp()
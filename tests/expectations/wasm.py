@@ -0,0 +1,55 @@
+import ctypes
+
+Filter = ctypes.c_uint32
+Nearest = 0
+Linear = 1
+
+class Rgba(ctypes.Structure):
+  pass
+
+Rgba._fields_ = [
+  ("r", ctypes.c_uint8),
+  ("g", ctypes.c_uint8),
+  ("b", ctypes.c_uint8),
+  ("a", ctypes.c_uint8),
+]
+
+# A single `f64`, passed as is.
+class Meters(ctypes.Structure):
+  pass
+
+Meters._fields_ = [
+  ("_0", ctypes.c_double),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.FRAME_COUNT = ctypes.c_uint64.in_dll(lib, "FRAME_COUNT")
+
+  lib.average_color.argtypes = []
+  lib.average_color.restype = Rgba
+
+  lib.checksum.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.checksum.restype = ctypes.c_uint32
+
+  lib.distance.argtypes = [Meters, Meters]
+  lib.distance.restype = Meters
+
+  # Fills the `len` pixels at `pixels` with `color`.
+  lib.fill.argtypes = [ctypes.POINTER(Rgba), ctypes.c_size_t, Rgba]
+  lib.fill.restype = None
+
+  lib.host_log.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.host_log.restype = None
+
+  lib.host_now.argtypes = []
+  lib.host_now.restype = ctypes.c_double
+
+  lib.sum_samples.argtypes = [ctypes.POINTER(ctypes.c_float), ctypes.c_size_t, Filter]
+  lib.sum_samples.restype = ctypes.c_double
+
+  lib.timestamp.argtypes = []
+  lib.timestamp.restype = ctypes.c_int64
+
+  return lib
@@ -0,0 +1,37 @@
+const std = @import("std");
+
+pub const Filter = enum(u32) {
+  Nearest,
+  Linear,
+};
+
+pub const Rgba = extern struct {
+  r: u8,
+  g: u8,
+  b: u8,
+  a: u8,
+};
+
+/// A single `f64`, passed as is.
+pub const Meters = extern struct {
+  _0: f64,
+};
+
+pub extern var FRAME_COUNT: u64;
+
+pub extern fn average_color() Rgba;
+
+pub extern fn checksum(data: [*c]const u8, len: usize) u32;
+
+pub extern fn distance(from: Meters, to: Meters) Meters;
+
+/// Fills the `len` pixels at `pixels` with `color`.
+pub extern fn fill(pixels: [*c]Rgba, len: usize, color: Rgba) void;
+
+pub extern fn host_log(message: [*c]const u8, len: usize) void;
+
+pub extern fn host_now() f64;
+
+pub extern fn sum_samples(samples: [*c]const f32, count: usize, filter: Filter) f64;
+
+pub extern fn timestamp() i64;
@@ -0,0 +1,10 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type ExtType = C.ExtType
+
+func ConsumeExt(_ext ExtType) {
+  C.consume_ext(_ext)
+}
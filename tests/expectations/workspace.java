@@ -0,0 +1,14 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  @Structure.FieldOrder({"data"})
+  class ExtType extends Structure {
+    public int data;
+
+    public static class ByReference extends ExtType implements Structure.ByReference {}
+
+    public static class ByValue extends ExtType implements Structure.ByValue {}
+  }
+
+  void consume_ext(ExtType.ByValue _ext);
+}
@@ -0,0 +1,24 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const ExtType = StructType();
+
+ExtType.defineProperty('data', 'uint32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    consume_ext: ['void', [ExtType]],
+  });
+  return lib;
+}
+
+module.exports = {
+  ExtType,
+  load,
+};
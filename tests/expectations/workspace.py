@@ -0,0 +1,16 @@
+import ctypes
+
+class ExtType(ctypes.Structure):
+  pass
+
+ExtType._fields_ = [
+  ("data", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.consume_ext.argtypes = [ExtType]
+  lib.consume_ext.restype = None
+
+  return lib
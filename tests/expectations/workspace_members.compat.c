@@ -0,0 +1,26 @@
+#include "workspace_members.compat_core.c"
+
+#ifndef WORKSPACE_MEMBERS_H
+#define WORKSPACE_MEMBERS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  Format format;
+  Sample last;
+} Player;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void play(Player *player, const Sample *samples, uintptr_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* WORKSPACE_MEMBERS_H */
@@ -0,0 +1,22 @@
+#include "workspace_members_core.cpp"
+
+#ifndef WORKSPACE_MEMBERS_H
+#define WORKSPACE_MEMBERS_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+struct Player {
+  Format format;
+  Sample last;
+};
+
+extern "C" {
+
+void play(Player *player, const Sample *samples, uintptr_t len);
+
+} // extern "C"
+
+#endif // WORKSPACE_MEMBERS_H
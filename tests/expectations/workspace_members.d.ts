@@ -0,0 +1,37 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const Format: {
+  readonly Pcm: 0;
+  readonly Float: 1;
+};
+
+export interface Sample {
+  left: number;
+  right: number;
+  ref(): Buffer;
+}
+export declare const Sample: StructType<Sample>;
+
+export interface Player {
+  format: number;
+  last: Sample;
+  ref(): Buffer;
+}
+export declare const Player: StructType<Player>;
+
+export interface Library {
+  core_version(): number;
+
+  play(player: Buffer | null, samples: Buffer | null, len: number | string): void;
+}
+
+export declare function load(path: string): Library;
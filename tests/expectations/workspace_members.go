@@ -0,0 +1,23 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+type Format = C.Format
+
+const (
+  Pcm Format = C.Pcm
+  Float Format = C.Float
+)
+
+type Sample = C.Sample
+
+type Player = C.Player
+
+func CoreVersion() uint32 {
+  return uint32(C.core_version())
+}
+
+func Play(player *Player, samples *Sample, len uintptr) {
+  C.play(player, samples, C.uintptr_t(len))
+}
@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  interface Format {
+    int Pcm = 0;
+    int Float = 1;
+  }
+
+  @Structure.FieldOrder({"left", "right"})
+  class Sample extends Structure {
+    public float left;
+    public float right;
+
+    public static class ByReference extends Sample implements Structure.ByReference {}
+
+    public static class ByValue extends Sample implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"format", "last"})
+  class Player extends Structure {
+    public int format;
+    public Sample last;
+
+    public static class ByReference extends Player implements Structure.ByReference {}
+
+    public static class ByValue extends Player implements Structure.ByValue {}
+  }
+
+  int core_version();
+
+  void play(Player.ByReference player, Sample.ByReference samples, SizeT len);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
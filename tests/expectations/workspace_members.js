@@ -0,0 +1,37 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Sample = StructType();
+const Player = StructType();
+
+Sample.defineProperty('left', 'float');
+Sample.defineProperty('right', 'float');
+
+Player.defineProperty('format', 'int');
+Player.defineProperty('last', Sample);
+
+const Format = Object.freeze({
+  Pcm: 0,
+  Float: 1,
+});
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    core_version: ['uint32', []],
+    play: ['void', [ref.refType(Player), ref.refType(Sample), 'size_t']],
+  });
+  return lib;
+}
+
+module.exports = {
+  Sample,
+  Player,
+  Format,
+  load,
+};
@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  ctypedef enum Format:
+    Pcm
+    Float
+
+  ctypedef struct Sample:
+    float left
+    float right
+
+  ctypedef struct Player:
+    Format format
+    Sample last
+
+  uint32_t core_version()
+
+  void play(Player *player, const Sample *samples, uintptr_t len)
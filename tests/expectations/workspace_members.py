@@ -0,0 +1,32 @@
+import ctypes
+
+Format = ctypes.c_int
+Pcm = 0
+Float = 1
+
+class Sample(ctypes.Structure):
+  pass
+
+Sample._fields_ = [
+  ("left", ctypes.c_float),
+  ("right", ctypes.c_float),
+]
+
+class Player(ctypes.Structure):
+  pass
+
+Player._fields_ = [
+  ("format", Format),
+  ("last", Sample),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.core_version.argtypes = []
+  lib.core_version.restype = ctypes.c_uint32
+
+  lib.play.argtypes = [ctypes.POINTER(Player), ctypes.POINTER(Sample), ctypes.c_size_t]
+  lib.play.restype = None
+
+  return lib
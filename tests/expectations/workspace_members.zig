@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const Format = enum(c_int) {
+  Pcm,
+  Float,
+};
+
+pub const Sample = extern struct {
+  left: f32,
+  right: f32,
+};
+
+pub const Player = extern struct {
+  format: Format,
+  last: Sample,
+};
+
+pub extern fn core_version() u32;
+
+pub extern fn play(player: [*c]Player, samples: [*c]const Sample, len: usize) void;
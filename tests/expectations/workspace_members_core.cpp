@@ -0,0 +1,25 @@
+#ifndef WORKSPACE_MEMBERS_H_CORE
+#define WORKSPACE_MEMBERS_H_CORE
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+enum class Format {
+  Pcm,
+  Float,
+};
+
+struct Sample {
+  float left;
+  float right;
+};
+
+extern "C" {
+
+uint32_t core_version();
+
+} // extern "C"
+
+#endif // WORKSPACE_MEMBERS_H_CORE
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+ */
+typedef struct {
+  uint64_t a;
+  uint8_t b;
+} Fields;
+
+/**
+ * Zero-sized fields elided: `1`, `2`.
+ */
+typedef struct {
+  uint32_t _0;
+  uint32_t _1;
+} Tuple;
+
+/**
+ * Zero-sized fields elided: `unit`, `pad`.
+ */
+typedef union {
+  int32_t a;
+} Either;
+
+void root(Fields f, Tuple t, Either e);
@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+/// Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+struct Fields {
+  uint64_t a;
+  uint8_t b;
+};
+
+/// Zero-sized fields elided: `1`, `2`.
+struct Tuple {
+  uint32_t _0;
+  uint32_t _1;
+};
+
+/// Zero-sized fields elided: `unit`, `pad`.
+union Either {
+  int32_t a;
+};
+
+extern "C" {
+
+void root(Fields f, Tuple t, Either e);
+
+} // extern "C"
@@ -0,0 +1,45 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+/**
+ * Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+ */
+export interface Fields {
+  a: number | string;
+  b: number;
+  ref(): Buffer;
+}
+export declare const Fields: StructType<Fields>;
+
+/**
+ * Zero-sized fields elided: `1`, `2`.
+ */
+export interface Tuple {
+  _0: number;
+  _1: number;
+  ref(): Buffer;
+}
+export declare const Tuple: StructType<Tuple>;
+
+/**
+ * Zero-sized fields elided: `unit`, `pad`.
+ */
+export interface Either {
+  a: number;
+  ref(): Buffer;
+}
+export declare const Either: StructType<Either>;
+
+export interface Library {
+  root(f: Fields, t: Tuple, e: Either): void;
+}
+
+export declare function load(path: string): Library;
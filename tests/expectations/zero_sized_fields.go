@@ -0,0 +1,17 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+// Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+type Fields = C.Fields
+
+// Zero-sized fields elided: `1`, `2`.
+type Tuple = C.Tuple
+
+// Zero-sized fields elided: `unit`, `pad`.
+type Either = C.Either
+
+func Root(f Fields, t Tuple, e Either) {
+  C.root(f, t, e)
+}
@@ -0,0 +1,42 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  /**
+   * Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+   */
+  @Structure.FieldOrder({"a", "b"})
+  class Fields extends Structure {
+    public long a;
+    public byte b;
+
+    public static class ByReference extends Fields implements Structure.ByReference {}
+
+    public static class ByValue extends Fields implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `1`, `2`.
+   */
+  @Structure.FieldOrder({"_0", "_1"})
+  class Tuple extends Structure {
+    public int _0;
+    public int _1;
+
+    public static class ByReference extends Tuple implements Structure.ByReference {}
+
+    public static class ByValue extends Tuple implements Structure.ByValue {}
+  }
+
+  /**
+   * Zero-sized fields elided: `unit`, `pad`.
+   */
+  class Either extends Union {
+    public int a;
+
+    public static class ByReference extends Either implements Structure.ByReference {}
+
+    public static class ByValue extends Either implements Structure.ByValue {}
+  }
+
+  void root(Fields.ByValue f, Tuple.ByValue t, Either.ByValue e);
+}
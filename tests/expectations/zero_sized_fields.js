@@ -0,0 +1,34 @@
+'use strict';
+
+const ffi = require('ffi-napi');
+const ref = require('ref-napi');
+const ArrayType = require('ref-array-di')(ref);
+const StructType = require('ref-struct-di')(ref);
+const UnionType = require('ref-union-di')(ref);
+
+// Declared ahead of their fields, so pointers can refer to any of them.
+const Fields = StructType();
+const Tuple = StructType();
+const Either = UnionType();
+
+Fields.defineProperty('a', 'uint64');
+Fields.defineProperty('b', 'uint8');
+
+Tuple.defineProperty('_0', 'uint32');
+Tuple.defineProperty('_1', 'uint32');
+
+Either.defineProperty('a', 'int32');
+
+function load(path) {
+  const lib = ffi.Library(path, {
+    root: ['void', [Fields, Tuple, Either]],
+  });
+  return lib;
+}
+
+module.exports = {
+  Fields,
+  Tuple,
+  Either,
+  load,
+};
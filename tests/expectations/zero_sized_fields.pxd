@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  # Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+  ctypedef struct Fields:
+    uint64_t a
+    uint8_t b
+
+  # Zero-sized fields elided: `1`, `2`.
+  ctypedef struct Tuple:
+    uint32_t _0
+    uint32_t _1
+
+  # Zero-sized fields elided: `unit`, `pad`.
+  ctypedef union Either:
+    int32_t a
+
+  void root(Fields f, Tuple t, Either e)
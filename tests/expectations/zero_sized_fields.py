@@ -0,0 +1,35 @@
+import ctypes
+
+# Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+class Fields(ctypes.Structure):
+  pass
+
+Fields._fields_ = [
+  ("a", ctypes.c_uint64),
+  ("b", ctypes.c_uint8),
+]
+
+# Zero-sized fields elided: `1`, `2`.
+class Tuple(ctypes.Structure):
+  pass
+
+Tuple._fields_ = [
+  ("_0", ctypes.c_uint32),
+  ("_1", ctypes.c_uint32),
+]
+
+# Zero-sized fields elided: `unit`, `pad`.
+class Either(ctypes.Union):
+  pass
+
+Either._fields_ = [
+  ("a", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Fields, Tuple, Either]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+/// Zero-sized fields elided: `marker`, `unit`, `markers`, `pad`.
+pub const Fields = extern struct {
+  a: u64,
+  b: u8,
+};
+
+/// Zero-sized fields elided: `1`, `2`.
+pub const Tuple = extern struct {
+  _0: u32,
+  _1: u32,
+};
+
+/// Zero-sized fields elided: `unit`, `pad`.
+pub const Either = extern union {
+  a: i32,
+};
+
+pub extern fn root(f: Fields, t: Tuple, e: Either) void;
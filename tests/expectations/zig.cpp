@@ -0,0 +1,67 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <new>
+
+static const uintptr_t COUNT = 3;
+
+static const int32_t HALF = 7 / 2;
+
+/// The direction of a move.
+enum class Direction : uint8_t {
+  Up,
+  Down = 4,
+};
+
+struct Handle;
+
+using Callback = bool(*)(uintptr_t len, char *data);
+
+struct Options {
+  /// Fields named after Zig keywords are escaped.
+  uint32_t error;
+  int align;
+  Callback callback;
+  const char *name;
+  Handle *handle;
+  uint8_t grid[COUNT][2];
+};
+
+struct __attribute__((packed)) Packed {
+  uint8_t tag;
+  uint32_t value;
+};
+
+struct Event {
+  enum class Tag : uint8_t {
+    Moved,
+    Renamed,
+    Closed,
+  };
+
+  struct Moved_Body {
+    Direction _0;
+  };
+
+  struct Renamed_Body {
+    const char *name;
+  };
+
+  Tag tag;
+  union {
+    Moved_Body moved;
+    Renamed_Body renamed;
+  };
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+void process(const Options *options,
+             Packed packed,
+             Event event,
+             const Handle *handle,
+             uint8_t *out);
+
+} // extern "C"
@@ -0,0 +1,72 @@
+/// <reference types="node" />
+
+export type RefType = string | object;
+
+export interface StructType<T> {
+  new (fields?: Partial<T>): T;
+  (fields?: Partial<T>): T;
+  readonly size: number;
+  readonly alignment: number;
+}
+
+export declare const COUNT: number;
+
+export declare const HALF: number;
+
+/**
+ * The direction of a move.
+ */
+export declare const Direction: {
+  readonly Up: 0;
+  readonly Down: 4;
+};
+
+export declare const Event_Tag: {
+  readonly Moved: 0;
+  readonly Renamed: 1;
+  readonly Closed: 2;
+};
+
+export interface Options {
+  error: number;
+  align: number;
+  callback: Buffer;
+  name: Buffer;
+  handle: Buffer;
+  grid: ArrayLike<ArrayLike<number>>;
+  ref(): Buffer;
+}
+export declare const Options: StructType<Options>;
+
+export interface Moved_Body {
+  _0: number;
+  ref(): Buffer;
+}
+export declare const Moved_Body: StructType<Moved_Body>;
+
+export interface Renamed_Body {
+  name: Buffer;
+  ref(): Buffer;
+}
+export declare const Renamed_Body: StructType<Renamed_Body>;
+
+export interface Event {
+  tag: number;
+  variants: {
+    moved: Moved_Body;
+    renamed: Renamed_Body;
+  };
+  ref(): Buffer;
+}
+export declare const Event: StructType<Event>;
+
+export type Callback = Buffer;
+export declare const Callback: RefType;
+
+export interface Library {
+  COUNTER: Buffer;
+
+  process(options: Buffer | null, packed: never, event: Event, handle: Buffer | null, out: Buffer | null): void;
+}
+
+export declare function load(path: string): Library;
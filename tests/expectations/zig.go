@@ -0,0 +1,48 @@
+package bindings
+
+// #include "bindings.h"
+import "C"
+
+import "unsafe"
+
+// The direction of a move.
+type Direction = C.Direction
+
+const (
+  Up Direction = C.Up
+  Down Direction = C.Down
+)
+
+type Handle = C.Handle
+
+type Callback = C.Callback
+
+type Options = C.Options
+
+type Packed = C.Packed
+
+type Event_Tag = C.Event_Tag
+
+const (
+  Moved Event_Tag = C.Moved
+  Renamed Event_Tag = C.Renamed
+  Closed Event_Tag = C.Closed
+)
+
+type Moved_Body = C.Moved_Body
+
+type Renamed_Body = C.Renamed_Body
+
+type Event = C.Event
+
+const COUNT = C.COUNT
+
+const HALF = C.HALF
+
+func Counter() uint32 {
+  return uint32(C.COUNTER)
+}
+
+func Process(options *Options, packed Packed, event Event, handle *Handle, out *uint8) {
+  C.process(options, packed, event, handle, (*C.uint8_t)(unsafe.Pointer(out)))
+}
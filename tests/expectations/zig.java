@@ -0,0 +1,91 @@
+import com.sun.jna.*;
+
+public interface Bindings extends Library {
+  long COUNT = 3L;
+
+  int HALF = 7 / 2;
+
+  /**
+   * The direction of a move.
+   */
+  interface Direction {
+    byte Up = 0;
+    byte Down = 4;
+  }
+
+  interface Callback extends Callback {
+    byte invoke(SizeT len, Pointer data);
+  }
+
+  @Structure.FieldOrder({"error", "align", "callback", "name", "handle", "grid"})
+  class Options extends Structure {
+    /**
+     * Fields named after Zig keywords are escaped.
+     */
+    public int error;
+    public int align;
+    public Callback callback;
+    public Pointer name;
+    public Pointer handle;
+    public byte[][] grid = new byte[(int) COUNT][];
+
+    public static class ByReference extends Options implements Structure.ByReference {}
+
+    public static class ByValue extends Options implements Structure.ByValue {}
+  }
+
+  interface Event_Tag {
+    byte Moved = 0;
+    byte Renamed = 1;
+    byte Closed = 2;
+  }
+
+  @Structure.FieldOrder({"_0"})
+  class Moved_Body extends Structure {
+    public byte _0;
+
+    public static class ByReference extends Moved_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Moved_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"name"})
+  class Renamed_Body extends Structure {
+    public Pointer name;
+
+    public static class ByReference extends Renamed_Body implements Structure.ByReference {}
+
+    public static class ByValue extends Renamed_Body implements Structure.ByValue {}
+  }
+
+  @Structure.FieldOrder({"tag", "variants"})
+  class Event extends Structure {
+    public static class Variants extends Union {
+      public Moved_Body moved;
+      public Renamed_Body renamed;
+
+      public static class ByReference extends Variants implements Structure.ByReference {}
+
+      public static class ByValue extends Variants implements Structure.ByValue {}
+    }
+
+    public byte tag;
+    public Variants variants;
+
+    public static class ByReference extends Event implements Structure.ByReference {}
+
+    public static class ByValue extends Event implements Structure.ByValue {}
+  }
+
+  void process(Options.ByReference options, Pointer packed, Event.ByValue event, Pointer handle, Pointer out);
+
+  class SizeT extends IntegerType {
+    public SizeT() {
+      this(0);
+    }
+
+    public SizeT(long value) {
+      super(com.sun.jna.Native.SIZE_T_SIZE, value, true);
+    }
+  }
+}
@@ -0,0 +1,57 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from libc.stddef cimport ptrdiff_t, wchar_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list:
+    pass
+
+cdef extern from *:
+  const uintptr_t COUNT
+
+  const int32_t HALF
+
+  # The direction of a move.
+  enum:
+    Up
+    Down
+  ctypedef uint8_t Direction
+
+  ctypedef struct Handle:
+    pass
+
+  ctypedef bool (*Callback)(uintptr_t len, char *data)
+
+  ctypedef struct Options:
+    # Fields named after Zig keywords are escaped.
+    uint32_t error
+    int align
+    Callback callback
+    const char *name
+    Handle *handle
+    uint8_t grid[COUNT][2]
+
+  ctypedef struct Packed:
+    uint8_t tag
+    uint32_t value
+
+  enum:
+    Moved
+    Renamed
+    Closed
+  ctypedef uint8_t Event_Tag
+
+  ctypedef struct Moved_Body:
+    Direction _0
+
+  ctypedef struct Renamed_Body:
+    const char *name
+
+  ctypedef struct Event:
+    Event_Tag tag
+    Moved_Body moved
+    Renamed_Body renamed
+
+  uint32_t COUNTER
+
+  void process(const Options *options, Packed packed, Event event, const Handle *handle, uint8_t *out)
@@ -0,0 +1,83 @@
+import ctypes
+
+COUNT = 3
+
+HALF = 7 // 2
+
+# The direction of a move.
+Direction = ctypes.c_uint8
+Up = 0
+Down = 4
+
+class Handle(ctypes.Structure):
+  pass
+
+Callback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_size_t, ctypes.POINTER(ctypes.c_char))
+
+class Options(ctypes.Structure):
+  pass
+
+Options._fields_ = [
+  # Fields named after Zig keywords are escaped.
+  ("error", ctypes.c_uint32),
+  ("align", ctypes.c_int),
+  ("callback", Callback),
+  ("name", ctypes.c_char_p),
+  ("handle", ctypes.POINTER(Handle)),
+  ("grid", ((ctypes.c_uint8 * 2) * COUNT)),
+]
+
+class Packed(ctypes.Structure):
+  pass
+
+Packed._pack_ = 1
+Packed._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("value", ctypes.c_uint32),
+]
+
+Event_Tag = ctypes.c_uint8
+Moved = 0
+Renamed = 1
+Closed = 2
+
+class Moved_Body(ctypes.Structure):
+  pass
+
+Moved_Body._fields_ = [
+  ("_0", Direction),
+]
+
+class Renamed_Body(ctypes.Structure):
+  pass
+
+Renamed_Body._fields_ = [
+  ("name", ctypes.c_char_p),
+]
+
+class _Event_Variants(ctypes.Union):
+  pass
+
+_Event_Variants._fields_ = [
+  ("moved", Moved_Body),
+  ("renamed", Renamed_Body),
+]
+
+class Event(ctypes.Structure):
+  pass
+
+Event._anonymous_ = ("_variants",)
+Event._fields_ = [
+  ("tag", Event_Tag),
+  ("_variants", _Event_Variants),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  lib.process.argtypes = [ctypes.POINTER(Options), Packed, Event, ctypes.POINTER(Handle), ctypes.POINTER(ctypes.c_uint8)]
+  lib.process.restype = None
+
+  return lib
@@ -0,0 +1,56 @@
+const std = @import("std");
+
+pub const COUNT: usize = 3;
+
+pub const HALF: i32 = @divTrunc(7, 2);
+
+/// The direction of a move.
+pub const Direction = enum(u8) {
+  Up,
+  Down = 4,
+};
+
+pub const Handle = opaque {};
+
+pub const Callback = *const fn (usize, [*c]c_char) callconv(.C) bool;
+
+pub const Options = extern struct {
+  /// Fields named after Zig keywords are escaped.
+  @"error": u32,
+  @"align": c_int,
+  callback: Callback,
+  name: [*c]const c_char,
+  handle: ?*Handle,
+  grid: [@intCast(COUNT)][2]u8,
+};
+
+pub const Packed = extern struct {
+  tag: u8 align(1),
+  value: u32 align(1),
+};
+
+pub const Event_Tag = enum(u8) {
+  Moved,
+  Renamed,
+  Closed,
+};
+
+pub const Moved_Body = extern struct {
+  _0: Direction,
+};
+
+pub const Renamed_Body = extern struct {
+  name: [*c]const c_char,
+};
+
+pub const Event = extern struct {
+  tag: Event_Tag,
+  body: extern union {
+    moved: Moved_Body,
+    renamed: Renamed_Body,
+  },
+};
+
+pub extern var COUNTER: u32;
+
+pub extern fn process(options: *const Options, @"packed": Packed, event: Event, handle: ?*const Handle, out: [*c]u8) void;
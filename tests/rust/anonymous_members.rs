@@ -0,0 +1,37 @@
+/// cbindgen:anonymous
+#[repr(C)]
+pub union Value {
+    integer: i64,
+    real: f64,
+    point: Point,
+}
+
+/// cbindgen:anonymous
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Variant {
+    kind: u8,
+    /// The value of the variant.
+    value: Value,
+}
+
+/// Used by both `root` and `Holder`, so it stays named.
+/// cbindgen:anonymous
+#[repr(C)]
+pub union Shared {
+    a: i32,
+    b: u32,
+}
+
+#[repr(C)]
+pub struct Holder {
+    shared: Shared,
+}
+
+#[no_mangle]
+pub extern "C" fn root(variant: Variant, holder: Holder, shared: Shared) {}
@@ -0,0 +1,31 @@
+pub const BUFFER_SIZE: u32 = 256;
+
+/// cbindgen:since=1.2
+pub const MAX_STREAMS: u32 = 16;
+
+#[repr(C)]
+pub struct Handle {
+    id: u32,
+}
+
+/// cbindgen:since=1.2
+#[repr(C)]
+pub struct Stream {
+    handle: Handle,
+    position: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn open(id: u32) -> Handle {
+    Handle { id }
+}
+
+/// cbindgen:since=1.2
+#[no_mangle]
+pub extern "C" fn open_stream(handle: Handle) -> Stream {
+    Stream { handle, position: 0 }
+}
+
+/// cbindgen:since=1.3
+#[no_mangle]
+pub extern "C" fn seek(stream: *mut Stream, position: u64) {}
@@ -0,0 +1,11 @@
+#[repr(C)]
+pub struct Foo {}
+
+impl Foo {
+    pub const GA: i32 = 10;
+    pub const BU: &'static str = "hello world";
+    pub const ZO: f32 = 3.14;
+}
+
+#[no_mangle]
+pub extern "C" fn root(x: Foo) { }
@@ -0,0 +1,18 @@
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicUsize};
+
+#[repr(C)]
+pub struct Node {
+    value: u32,
+}
+
+#[repr(C)]
+pub struct Queue {
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    len: AtomicU32,
+    closed: AtomicBool,
+    first: AtomicPtr<Node>,
+}
+
+#[no_mangle]
+pub extern "C" fn queue_len(queue: *const Queue, hint: *const AtomicU32) -> u32 {}
@@ -0,0 +1,30 @@
+/// A control register.
+/// cbindgen:bitfield-widths=[3, 5, 24]
+#[repr(C)]
+pub struct Control {
+    /// The operating mode.
+    mode: u32,
+    prescaler: u32,
+    reload: u32,
+}
+
+/// cbindgen:bitfield-widths=1,7
+#[repr(C)]
+pub struct Status {
+    ready: bool,
+    code: u8,
+}
+
+/// cbindgen:bitfield-widths=[40]
+#[repr(C)]
+pub struct TooWide {
+    value: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn configure(control: Control) -> Status {
+    Status { ready: true, code: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn validate(value: *const TooWide) {}
@@ -0,0 +1,35 @@
+/// cbindgen:bitflags
+#[repr(C)]
+pub struct Permissions(u32);
+
+bitflags! {
+    impl Permissions: u32 {
+        const READ = 1 << 0;
+        const WRITE = 1 << 1;
+        const EXECUTE = 1 << 2;
+    }
+}
+
+/// cbindgen:bitflags=integer
+#[repr(C)]
+pub struct Modifiers(u8);
+
+bitflags! {
+    impl Modifiers: u8 {
+        const SHIFT = 1;
+        const CONTROL = 1 << 1;
+        const ALT = 1 << 2;
+    }
+}
+
+bitflags! {
+    /// cbindgen:bitflags=integer
+    #[repr(C)]
+    pub struct Buttons: u16 {
+        const LEFT = 1;
+        const RIGHT = 1 << 1;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn root(permissions: Permissions, modifiers: Modifiers, buttons: Buttons) {}
@@ -0,0 +1,28 @@
+pub type StdcallCallback = extern "stdcall" fn(i32) -> i32;
+pub type FastcallCallback = Option<extern "fastcall" fn(i32, i32)>;
+
+#[repr(C)]
+pub struct Callbacks {
+    system: extern "system" fn(*const u8) -> bool,
+    c: extern "C" fn(),
+}
+
+#[no_mangle]
+pub extern "stdcall" fn stdcall_fn(a: i32, callback: StdcallCallback) -> i32 {
+    callback(a)
+}
+
+#[no_mangle]
+pub extern "fastcall" fn fastcall_fn(callback: FastcallCallback) -> *mut u8 {
+    std::ptr::null_mut()
+}
+
+#[no_mangle]
+pub extern "system" fn system_fn(callbacks: Callbacks) {}
+
+#[no_mangle]
+pub extern "C" fn c_fn(callback: extern "stdcall" fn()) {}
+
+extern "stdcall" {
+    fn imported(x: u32);
+}
@@ -0,0 +1,6 @@
+pub const TAB: char = '\t';
+pub const E_ACUTE: char = 'é';
+pub const EMOJI: char = '😀';
+
+#[no_mangle]
+pub extern "C" fn root() { }
@@ -0,0 +1,15 @@
+use std::os::raw::c_char;
+
+#[repr(C)]
+pub struct Label {
+    text: *const c_char,
+    buffer: *mut c_char,
+    initial: c_char,
+    symbol: char,
+}
+
+#[no_mangle]
+pub extern "C" fn label_new(text: *const c_char, symbol: char) -> Label {}
+
+#[no_mangle]
+pub extern "C" fn label_names(names: *const *const c_char, count: usize) {}
@@ -0,0 +1,22 @@
+pub const NEG: i32 = -(-5);
+pub const NEG_SUM: i32 = -(1 + 2);
+pub const TRUNCATED: u8 = 0x1FF as u8;
+pub const UNSIGNED: u32 = -1i32 as u32;
+pub const SIGNED: i8 = 200 as i8;
+pub const ROUNDED: i32 = 3.9 as i32;
+pub const SATURATED: u8 = -3.5 as u8;
+pub const FLOAT: f32 = 5 as f32;
+
+pub const LEN: usize = 300 as u8 as usize;
+pub const WIDE: u32 = 0x12345;
+pub const NARROW: u8 = WIDE as u8;
+pub const MASKED: u32 = 1 << 8 | NARROW as u32;
+
+#[repr(C)]
+pub struct Buffer {
+    data: [u8; LEN],
+    tail: [u8; (WIDE as u8 >> 4) as usize],
+}
+
+#[no_mangle]
+pub extern "C" fn root(b: Buffer) {}
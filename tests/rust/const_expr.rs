@@ -0,0 +1,23 @@
+pub const HEADER_LEN: usize = 4;
+pub const PAYLOAD_LEN: usize = HEADER_LEN * 2 + 8;
+pub const TOTAL_LEN: usize = (HEADER_LEN + PAYLOAD_LEN) * 2;
+
+pub const FLAG_A: u32 = 1 << 0;
+pub const FLAG_B: u32 = 1 << 1;
+pub const FLAG_AB: u32 = FLAG_A | FLAG_B;
+pub const MASK: u32 = FLAG_AB & 0xff ^ FLAG_B;
+pub const BIG: u64 = 1 << 20 | FLAG_AB as u64;
+
+#[repr(C)]
+pub struct Packet {
+    header: [u8; HEADER_LEN + 4],
+    payload: [u8; PAYLOAD_LEN],
+    checksum: [u16; TOTAL_LEN / 8 - 1],
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Packet, frames: &[u32; (TOTAL_LEN >> 2) as usize]) {}
+
+pub const SHIFTED: i64 = 1 << 40;
+pub const NEG_SHIFTED: i64 = -(1 << 40);
+pub const NEG_AFTER_SHIFTED: i64 = -(SHIFTED + 1);
@@ -0,0 +1,20 @@
+pub const TAB: char = '\t';
+pub const NUL: char = '\0';
+pub const QUOTE: char = '\'';
+pub const E_ACUTE: char = 'é';
+pub const EURO: char = '€';
+pub const EMOJI: char = '😀';
+
+pub const HALF: f32 = 0.5;
+pub const ONE: f32 = 1.0;
+pub const LARGE: f64 = 1e300;
+pub const NOT_A_NUMBER: f32 = f32::NAN;
+pub const POS_INF: f64 = std::f64::INFINITY;
+pub const NEG_INF: f32 = f32::NEG_INFINITY;
+
+pub const MAX_U64: u64 = 18446744073709551615;
+pub const MAX_I64: i64 = 9223372036854775807;
+pub const MIN_I64: i64 = -9223372036854775808;
+
+#[no_mangle]
+pub extern "C" fn root() { }
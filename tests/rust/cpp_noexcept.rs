@@ -0,0 +1,20 @@
+#[no_mangle]
+pub extern "C" fn no_unwind(a: u32) -> u32 {
+    a
+}
+
+#[no_mangle]
+pub extern "C-unwind" fn may_unwind(a: u32) -> u32 {
+    a
+}
+
+#[no_mangle]
+pub extern "system-unwind" fn may_unwind_system() {}
+
+extern "C-unwind" {
+    fn callback_may_unwind(a: u32);
+}
+
+extern "C" {
+    fn callback(a: u32);
+}
@@ -0,0 +1,37 @@
+#[repr(C)]
+#[deprecated]
+pub struct OldPoint {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+#[deprecated(since = "1.2.0", note = "Use \"Mode\" instead")]
+pub enum OldMode {
+    Fast,
+    Slow,
+}
+
+#[repr(u8)]
+#[deprecated = "Use Flags instead"]
+pub enum OldFlags {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[deprecated]
+pub enum OldShape {
+    Circle(f32),
+    Square(f32),
+}
+
+#[no_mangle]
+#[deprecated(note = "Use new_point instead")]
+pub extern "C" fn old_point() -> i32 {
+    0
+}
+
+#[no_mangle]
+#[deprecated]
+pub extern "C" fn old_root(a: *const OldPoint, b: OldMode, c: OldFlags, d: *const OldShape) {}
@@ -0,0 +1,35 @@
+use std::os::raw::{c_char, c_long};
+
+pub type Callback = extern "C" fn(version: u32, data: *mut c_char) -> bool;
+
+/// The direction of a move.
+#[repr(u8)]
+pub enum Direction {
+    Up,
+    Down = 4,
+}
+
+#[repr(C)]
+pub struct Options {
+    /// Fields named after D keywords are escaped.
+    module: u32,
+    version: c_long,
+    callback: Callback,
+    name: *const c_char,
+    grid: [[u8; 2]; 3],
+}
+
+#[repr(C, u8)]
+pub enum Event {
+    Moved(Direction),
+    Renamed { name: *const c_char },
+    Closed,
+}
+
+pub const LIMIT: u64 = 18446744073709551615;
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn process(options: *const Options, event: Event, ref_: *mut i64, out: *mut u8) {}
@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[no_mangle]
+pub static mut ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+
+#[no_mangle]
+pub extern "C" fn point_length(p: Point) -> f32 {
+    (p.x * p.x + p.y * p.y).sqrt()
+}
+
+#[no_mangle]
+pub extern "C" fn point_translate(p: *mut Point, dx: f32, dy: f32, scale_first: bool, scale_factor: f32) {}
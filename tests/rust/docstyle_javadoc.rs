@@ -0,0 +1,35 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+/// Copies the contents of a [`Buffer`] into `dst`, see [`crate::buffer_len()`].
+///
+/// # Arguments
+///
+/// * `buffer` - The [buffer](Buffer) to copy from.
+/// * `dst` - The destination, which is
+///   at least [`buffer_len`] bytes long.
+///
+/// # Returns
+///
+/// The number of bytes copied.
+///
+/// # Safety
+///
+/// `dst` must be valid for writes.
+///
+/// # Examples
+///
+/// See the [docs](https://docs.rs).
+#[no_mangle]
+pub unsafe extern "C" fn buffer_copy(buffer: &Buffer, dst: *mut u8) -> usize {
+    0
+}
+
+/// Returns the length of `buffer`.
+#[no_mangle]
+pub extern "C" fn buffer_len(buffer: &Buffer) -> usize {
+    0
+}
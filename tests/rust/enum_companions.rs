@@ -0,0 +1,28 @@
+#[repr(u8)]
+pub enum Color {
+    Red,
+    Green = 4,
+    Blue,
+}
+
+#[repr(C)]
+pub enum Direction {
+    North,
+    South,
+}
+
+/// cbindgen:derive-to-string=false
+#[repr(C)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle(f32),
+    Point,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Color, b: Direction, c: Level, d: Shape) {}
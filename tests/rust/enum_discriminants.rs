@@ -0,0 +1,27 @@
+#[repr(u32)]
+pub enum Status {
+    Ok,
+    Pending,
+    Failed = 16,
+    Aborted,
+    Fatal = 255,
+}
+
+/// cbindgen:discriminant-format=decimal
+/// cbindgen:explicit-discriminants=false
+#[repr(i8)]
+pub enum Level {
+    Low = -1,
+    Medium,
+    High = 10,
+}
+
+/// cbindgen:explicit-discriminants=false
+#[repr(i8)]
+pub enum Offset {
+    Back = -16,
+    Here,
+}
+
+#[no_mangle]
+pub extern "C" fn root(status: Status, level: Level, offset: Offset) {}
@@ -0,0 +1,15 @@
+/// cbindgen:add-sentinel
+#[repr(C)]
+pub enum Direction {
+    North,
+    South,
+}
+
+#[repr(u8)]
+pub enum Shade {
+    Light = 2,
+    Dark,
+}
+
+#[no_mangle]
+pub extern "C" fn root(direction: Direction, shade: Shade) {}
@@ -0,0 +1,34 @@
+/// Gets the default underlying type.
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+/// cbindgen:enum-underlying=uint8_t
+#[repr(C)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// cbindgen:enum-underlying=int8_t
+#[repr(i32)]
+pub enum Sign {
+    Negative = -1,
+    Zero = 0,
+    Positive = 1,
+}
+
+/// Keeps its `#[repr(u32)]`.
+#[repr(u32)]
+pub enum Flavor {
+    Sweet,
+    Sour,
+}
+
+#[no_mangle]
+pub extern "C" fn root(color: Color, direction: Direction, sign: Sign, flavor: Flavor) {}
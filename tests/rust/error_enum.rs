@@ -0,0 +1,18 @@
+/// cbindgen:error-enum
+#[repr(C)]
+pub enum IoError {
+    NotFound,
+    PermissionDenied,
+    TimedOut = 10,
+}
+
+/// cbindgen:error-enum
+/// cbindgen:define-error-name
+#[repr(u8)]
+pub enum ParseError {
+    UnexpectedEof,
+    InvalidToken,
+}
+
+#[no_mangle]
+pub extern "C" fn root(io: IoError, parse: ParseError) {}
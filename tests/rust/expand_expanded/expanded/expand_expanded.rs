@@ -0,0 +1,12 @@
+#[repr(C)]
+pub struct Window {
+    id: u32,
+}
+#[no_mangle]
+pub extern "C" fn window_new(id: u32) -> Window { Window { id: id } }
+#[repr(C)]
+pub struct Surface {
+    id: u32,
+}
+#[no_mangle]
+pub extern "C" fn surface_new(id: u32) -> Surface { Surface { id: id } }
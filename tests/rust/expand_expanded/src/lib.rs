@@ -0,0 +1,16 @@
+macro_rules! ffi_handle {
+    ($name:ident, $new:ident) => {
+        #[repr(C)]
+        pub struct $name {
+            id: u32,
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $new(id: u32) -> $name {
+            $name { id: id }
+        }
+    };
+}
+
+ffi_handle!(Window, window_new);
+ffi_handle!(Surface, surface_new);
@@ -0,0 +1,12 @@
+pub type time_t = i64;
+
+pub type sig_atomic_t = i32;
+
+#[repr(C)]
+pub struct Record {
+    created: time_t,
+    flags: sig_atomic_t,
+}
+
+#[no_mangle]
+pub extern "C" fn write_record(record: *const Record, flags: sig_atomic_t) {}
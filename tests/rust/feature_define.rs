@@ -0,0 +1,20 @@
+#[cfg(feature = "audio-out")]
+#[repr(C)]
+pub struct Speaker {
+    volume: u8,
+}
+
+#[cfg(feature = "legacy")]
+pub const LEGACY_VERSION: u32 = 2;
+
+#[cfg(feature = "audio-out")]
+#[no_mangle]
+pub extern "C" fn play(speaker: Speaker) {}
+
+#[cfg(all(feature = "legacy", feature = "serde"))]
+#[no_mangle]
+pub extern "C" fn load_legacy(data: *const u8, len: usize) {}
+
+#[cfg(feature = "serde")]
+#[no_mangle]
+pub extern "C" fn load(data: *const u8, len: usize) {}
@@ -0,0 +1,18 @@
+#[repr(C)]
+pub struct Options {
+    /// cbindgen:default=3
+    level: i32,
+    /// cbindgen:default=true
+    verbose: bool,
+    /// cbindgen:default=0.5
+    ratio: f32,
+    name: *const u8,
+}
+
+#[repr(C)]
+pub struct Plain {
+    x: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(options: Options, plain: Plain) {}
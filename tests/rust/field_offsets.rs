@@ -0,0 +1,28 @@
+/// cbindgen:field-offsets
+#[repr(C)]
+pub struct Header {
+    magic: u32,
+    version: u16,
+    flags: u8,
+    length: u64,
+}
+
+/// cbindgen:field-offsets=computed
+#[repr(C)]
+pub struct Record {
+    id: u8,
+    value: f64,
+    count: i32,
+}
+
+/// Pointers differ in size between data models.
+/// cbindgen:field-offsets=computed
+#[repr(C)]
+pub struct Node {
+    tag: u8,
+    data: *const u8,
+    weight: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(header: Header, record: Record, node: Node) {}
@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct AudioBuffer {
+    samples: *mut f32,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct VideoFrame {
+    pixels: *mut u8,
+    width: u32,
+    height: u32,
+}
+
+#[repr(C)]
+pub struct AudioState {
+    volume: f32,
+}
+
+#[no_mangle]
+pub static mut audio_volume: f32 = 1.0;
+
+#[no_mangle]
+pub static mut video_fps: u32 = 60;
+
+#[no_mangle]
+pub extern "C" fn audio_play(buffer: *const AudioBuffer) {}
+
+#[no_mangle]
+pub extern "C" fn audio_state_internal() -> AudioState {
+    AudioState { volume: 1.0 }
+}
+
+#[no_mangle]
+pub extern "C" fn video_show(frame: *const VideoFrame) {}
@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Parent {
+    first_child: *const Child,
+    id: u32,
+}
+
+#[repr(C)]
+pub struct Child {
+    parent: *const Parent,
+    next_sibling: *mut Child,
+    data: Data,
+}
+
+#[repr(C)]
+pub struct Data {
+    value: u8,
+}
+
+#[repr(C)]
+pub struct Node {
+    next: *mut Node,
+}
+
+pub struct Handle;
+
+#[no_mangle]
+pub extern "C" fn walk(parent: Parent, node: Node, handle: *const Handle) {}
+
+#[no_mangle]
+pub extern "C" fn read(data: Data) {}
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Foo<T = u32> {
+    a: T,
+}
+
+#[repr(C)]
+pub struct Pair<K, V = K> {
+    key: K,
+    value: V,
+}
+
+pub type Bar<T = Foo> = Pair<T>;
+
+#[repr(C)]
+pub struct Holder {
+    foo: Foo,
+    pair: Pair<u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Foo, b: Foo<u8>, c: Pair<i16>, d: Bar, e: Holder) {}
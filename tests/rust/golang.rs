@@ -0,0 +1,55 @@
+use std::os::raw::{c_char, c_int, c_void};
+
+pub type Callback = extern "C" fn(len: usize, data: *mut c_char) -> bool;
+
+/// The direction of a move.
+#[repr(u8)]
+pub enum Direction {
+    Up,
+    Down = 4,
+}
+
+#[repr(C)]
+pub enum Mode {
+    Fast,
+    Safe,
+}
+
+pub struct Handle;
+
+#[repr(C)]
+pub struct Options {
+    size: u32,
+    callback: Callback,
+    name: *const c_char,
+}
+
+#[repr(C, u8)]
+pub enum Event {
+    Moved(Direction),
+    Closed,
+}
+
+pub const LIMIT: u64 = 1024;
+pub const ENABLED: bool = true;
+
+/// Opens a handle.
+#[no_mangle]
+pub extern "C" fn mylib_open(options: *const Options, mode: Mode) -> *mut Handle {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn mylib_read(handle: *mut Handle, buf: *mut u8, len: usize) -> i64 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn mylib_data(handle: *mut Handle, range: c_int) -> *mut c_void {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn mylib_send(handle: &mut Handle, event: Event, scale: f32) -> bool {
+    true
+}
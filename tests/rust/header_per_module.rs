@@ -0,0 +1,38 @@
+mod geometry {
+    #[repr(C)]
+    pub struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    #[repr(C)]
+    pub struct Rect {
+        origin: Point,
+        size: super::units::Size,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn rect_area(rect: &Rect) -> f32 {
+        0.0
+    }
+}
+
+mod units {
+    #[repr(C)]
+    pub struct Size {
+        width: f32,
+        height: f32,
+    }
+
+    pub const ZERO_SIZE: Size = Size { width: 0.0, height: 0.0 };
+}
+
+#[repr(C)]
+pub struct Scene {
+    bounds: geometry::Rect,
+    count: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn scene_bounds(scene: &Scene) -> geometry::Rect {
+}
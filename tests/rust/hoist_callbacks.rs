@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Logger {
+    level: i32,
+    on_flush: extern "C" fn(),
+    /// cbindgen:callback-name=LogCallback
+    on_log: Option<extern "C" fn(level: i32, message: *const u8)>,
+}
+
+#[no_mangle]
+pub extern "C" fn set_logger(logger: Logger) {}
+
+#[no_mangle]
+pub extern "C" fn set_filter(filter: extern "C" fn(level: i32) -> bool, threshold: i32) {}
+
+/// cbindgen:callback-name=[ErrorHandler, Previous]
+#[no_mangle]
+pub extern "C" fn set_error_handler(
+    handler: extern "C" fn(code: u32),
+) -> Option<extern "C" fn(code: u32)> {
+    None
+}
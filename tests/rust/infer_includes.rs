@@ -0,0 +1,11 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    visible: bool,
+}
+
+pub const SCALE: f32 = std::f32::INFINITY;
+
+#[no_mangle]
+pub extern "C" fn area(p: *const Point, len: usize) -> f64 { 0.0 }
@@ -0,0 +1,26 @@
+pub const VERSION: u32 = 3;
+
+#[repr(C)]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+/// cbindgen:before=packed-begin
+/// cbindgen:after=packed-end
+#[repr(C)]
+pub struct Header {
+    mode: Mode,
+    size: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn read_header(header: *mut Header) -> bool {
+    true
+}
+
+/// cbindgen:after=legacy-alias
+#[no_mangle]
+pub extern "C" fn write_header(header: *const Header) -> bool {
+    true
+}
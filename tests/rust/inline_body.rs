@@ -0,0 +1,45 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// Returns the horizontal coordinate of the point.
+/// cbindgen:inline-body=return point->x;
+#[inline]
+pub fn point_x(point: &Point) -> i32 {
+    point.x
+}
+
+#[inline]
+pub fn point_dot(a: &Point, b: &Point) -> i32 {
+    a.x * b.x + a.y * b.y
+}
+
+/// cbindgen:inline-body=return a * b;
+#[inline(always)]
+pub fn multiply(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+#[inline]
+pub fn not_exported(a: i32) -> i32 {
+    a
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+}
+
+/// cbindgen:inline-body=return rect->size.x == 0 || rect->size.y == 0;
+#[inline]
+pub fn rect_is_empty(rect: &Rect) -> bool {
+    rect.size.x == 0 || rect.size.y == 0
+}
+
+#[no_mangle]
+pub extern "C" fn area(rect: Rect) -> i32 {
+    rect.size.x * rect.size.y
+}
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Vec2<T> {
+    x: T,
+    y: T,
+}
+
+#[repr(C)]
+pub struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+#[repr(C)]
+pub struct Unused<T> {
+    value: T,
+}
+
+#[no_mangle]
+pub extern "C" fn length(v: Vec2<u32>) -> u32 {
+    0
+}
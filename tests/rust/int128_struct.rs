@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Key {
+    id: u128,
+    offset: i128,
+}
+
+pub const KEY_BITS: u128 = 128;
+
+#[no_mangle]
+pub extern "C" fn key_hash(key: *const Key, seed: u128) -> i128 {}
@@ -0,0 +1,15 @@
+pub const LIMIT: i32 = 10;
+
+/// cbindgen:prefix=MYLIB_CONST_PTR
+pub const NAME: *const u8 = 0 as *const u8;
+
+#[no_mangle]
+pub static COUNT: i32 = 0;
+
+#[no_mangle]
+pub static mut STATE: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn root(x: i32) -> i32 {
+    x
+}
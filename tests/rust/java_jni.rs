@@ -0,0 +1,64 @@
+use std::os::raw::c_char;
+
+/// The largest number of points.
+pub const MAX_POINTS: u32 = 8;
+pub const MASK: u64 = 0xffff_ffff_ffff_ffff;
+pub const SCALE: f32 = 1.5;
+pub const LETTER: char = 'j';
+
+#[repr(u8)]
+pub enum Color {
+    Red,
+    Green = 5,
+    Blue,
+}
+
+/// A point.
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub struct Shape {
+    points: [Point; 8],
+    weights: [f64; 4],
+    color: Color,
+    len: usize,
+    origin: *mut Point,
+    package: bool,
+}
+
+#[repr(C)]
+pub enum Value {
+    Number(i32),
+    Pair(Point, Point),
+    Empty,
+}
+
+pub type Visitor = extern "C" fn(point: *const Point, user_data: *mut u8) -> bool;
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+/// Draws `shape`.
+///
+/// # Arguments
+///
+/// * `shape` - The [`Shape`] to draw.
+/// * `label` - A label to draw next to it.
+#[no_mangle]
+pub extern "C" fn draw(shape: &Shape, label: *const c_char) -> isize {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn count(visitor: Visitor, shape: *const Shape) -> u64 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn color(value: Value) -> Color {
+    Color::Red
+}
@@ -0,0 +1,44 @@
+use std::os::raw::c_char;
+
+pub type Callback = Option<extern "C" fn(len: usize) -> bool>;
+
+/// The direction of a move.
+#[repr(u8)]
+pub enum Direction {
+    Up,
+    Down = 4,
+    Left,
+}
+
+#[repr(C)]
+pub struct Options {
+    size: u32,
+    scale: f64,
+    name: *const c_char,
+    callback: Callback,
+    grid: [u8; 3],
+}
+
+#[repr(C, packed)]
+pub struct Header {
+    tag: u8,
+    length: u32,
+}
+
+#[repr(C, u8)]
+pub enum Event {
+    Moved(Direction),
+    Closed,
+}
+
+/// The largest size.
+pub const LIMIT: u32 = 1 << 10;
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+/// Processes an event.
+#[no_mangle]
+pub extern "C" fn process(options: &Options, header: Header, event: Event) -> i32 {
+    0
+}
@@ -0,0 +1,22 @@
+#[repr(C)]
+pub struct far {
+    delete: i32,
+    min: i32,
+}
+
+#[repr(C)]
+pub union Value {
+    class: i32,
+    max: f32,
+}
+
+#[repr(C)]
+pub enum Mode {
+    near,
+    stdin,
+}
+
+pub const small: u8 = 1;
+
+#[no_mangle]
+pub extern "C" fn keyword_collisions(new: far, errno: Value, mode: Mode) {}
@@ -0,0 +1,57 @@
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[repr(C, u8)]
+pub enum Shape {
+    Circle(f32),
+    Rect { width: f32, height: f32 },
+    Empty,
+}
+
+#[repr(u8)]
+pub enum Token {
+    Number(i32),
+    Eof,
+}
+
+#[repr(C)]
+pub struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+pub type Pairs = Pair<Pair<i32>>;
+
+pub struct Engine {
+    inner: Vec<u8>,
+}
+
+#[deprecated]
+#[no_mangle]
+pub extern "C" fn old_api(color: Color, level: Level) {}
+
+#[must_use]
+#[no_mangle]
+pub extern "C" fn area(shape: Shape, pairs: Pairs, token: Token) -> f32 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn engine_new() -> *mut Engine {
+    0 as *mut Engine
+}
+
+#[no_mangle]
+pub extern "C" fn fatal() -> ! {
+    loop {}
+}
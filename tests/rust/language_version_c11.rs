@@ -0,0 +1,19 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[no_mangle]
+pub extern "C" fn draw(point: Point, level: Level) {}
+
+#[no_mangle]
+pub extern "C" fn fatal() -> ! {
+    loop {}
+}
@@ -0,0 +1,44 @@
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[repr(C, u8)]
+pub enum Shape {
+    Circle(f32),
+    Rect { width: f32, height: f32 },
+    Empty,
+}
+
+#[repr(u8)]
+pub enum Token {
+    Number(i32),
+    Eof,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn draw(point: Point, color: Color, level: Level) {}
+
+#[no_mangle]
+pub extern "C" fn area(shape: Shape, token: Token) -> f32 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn fatal() -> ! {
+    loop {}
+}
@@ -0,0 +1,45 @@
+#[repr(C)]
+pub struct Header {
+    tag: u8,
+    len: u32,
+    flags: u16,
+}
+
+#[repr(C)]
+pub struct Packet {
+    header: Header,
+    payload: [u8; 5],
+    checksum: u16,
+}
+
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Timestamp {
+    seconds: i64,
+    valid: bool,
+}
+
+#[repr(u8)]
+pub enum Kind {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub struct Event {
+    kind: Kind,
+    at: Timestamp,
+}
+
+#[repr(C)]
+pub struct Native {
+    value: std::os::raw::c_long,
+}
+
+#[no_mangle]
+pub extern "C" fn send(packet: Packet, buffer: Buffer, event: Event, native: Native) {}
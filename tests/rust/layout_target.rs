@@ -0,0 +1,19 @@
+use std::os::raw::c_long;
+
+#[repr(C)]
+pub struct Sample {
+    count: c_long,
+    symbol: char,
+    data: *const u8,
+}
+
+pub struct Reader {
+    position: u64,
+    buffer: Box<u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn sample(sample: Sample) {}
+
+#[no_mangle]
+pub extern "C" fn reader_init(reader: *mut Reader) {}
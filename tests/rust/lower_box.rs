@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Node {
+    value: i32,
+}
+
+#[repr(C)]
+pub struct Tree {
+    root: Box<Node>,
+    left: Option<Box<Node>>,
+}
+
+#[no_mangle]
+pub extern "C" fn tree_new(root: Box<Node>) -> Box<Tree> {}
+
+#[no_mangle]
+pub extern "C" fn tree_free(tree: Option<Box<Tree>>) {}
@@ -0,0 +1,49 @@
+macro_rules! ffi_handle {
+    ($name:ident, $new:ident) => {
+        #[repr(C)]
+        pub struct $name {
+            id: u32,
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $new(id: u32) -> $name {
+            $name { id: id }
+        }
+    };
+}
+
+ffi_handle!(Window, window_new);
+ffi_handle!(Surface, surface_new);
+
+macro_rules! getters {
+    ($ty:ty; $($(#[$meta:meta])* $name:ident => $field:ty = $value:expr),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[no_mangle]
+            pub extern "C" fn $name(this: *const $ty) -> $field {
+                $value
+            }
+        )*
+    };
+}
+
+getters! {
+    Window;
+    /// The width of the window.
+    window_width => u32 = 640,
+    window_scale => f32 = 1.0 + 0.5,
+}
+
+macro_rules! constants {
+    ($($name:ident = $value:expr;)+) => {
+        $(pub const $name: i32 = $value;)+
+    };
+}
+
+constants! {
+    MAX_WINDOWS = 16;
+    DEFAULT_WIDTH = 640;
+}
+
+#[cfg(feature = "surfaces")]
+ffi_handle!(Overlay, overlay_new);
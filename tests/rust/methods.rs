@@ -0,0 +1,44 @@
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    /// Returns the current count.
+    #[no_mangle]
+    pub extern "C" fn counter_get(&self) -> u32 {
+        self.count
+    }
+
+    #[no_mangle]
+    pub extern "C" fn counter_add(&mut self, amount: u32) {
+        self.count += amount;
+    }
+
+    #[no_mangle]
+    pub extern "C" fn counter_new() -> *mut Counter {
+        Box::into_raw(Box::new(Counter { count: 0 }))
+    }
+}
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+impl Point {
+    #[no_mangle]
+    pub extern "C" fn point_length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn point_midpoint(&self, other: &Self) -> Self {
+        Point {
+            x: (self.x + other.x) / 2.0,
+            y: (self.y + other.y) / 2.0,
+        }
+    }
+
+    pub fn not_exported(&self) {}
+}
@@ -0,0 +1,28 @@
+pub const MAX_POINTS: u32 = 16;
+
+#[repr(u8)]
+pub enum Shape {
+    Line,
+    Polygon,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+pub struct Canvas {
+    points: Vec<Point>,
+}
+
+#[no_mangle]
+pub static mut DEFAULT_SHAPE: Shape = Shape::Line;
+
+#[no_mangle]
+pub extern "C" fn canvas_new() -> *mut Canvas {
+    0 as *mut Canvas
+}
+
+#[no_mangle]
+pub extern "C" fn canvas_add(canvas: *mut Canvas, point: Point, shape: Shape) {}
@@ -0,0 +1,34 @@
+#[cfg(windows)]
+#[repr(C)]
+pub struct Handle {
+    raw: *mut u8,
+}
+
+#[cfg(unix)]
+#[repr(C)]
+pub struct Handle {
+    fd: i32,
+}
+
+#[cfg(target_pointer_width = "64")]
+pub const WORD_BITS: u32 = 64;
+
+#[cfg(target_pointer_width = "32")]
+pub const WORD_BITS: u32 = 32;
+
+#[cfg(all(unix, feature = "logging"))]
+#[repr(C)]
+pub struct Logger {
+    level: u8,
+}
+
+#[cfg(target_os = "macos")]
+#[no_mangle]
+pub extern "C" fn apple_only() {}
+
+#[no_mangle]
+pub extern "C" fn open_handle(handle: *mut Handle) {}
+
+#[cfg(all(unix, feature = "logging"))]
+#[no_mangle]
+pub extern "C" fn set_logger(logger: *const Logger) {}
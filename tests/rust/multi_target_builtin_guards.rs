@@ -0,0 +1,18 @@
+#[cfg(windows)]
+#[repr(C)]
+pub struct Handle {
+    raw: *mut u8,
+}
+
+#[cfg(unix)]
+#[repr(C)]
+pub struct Handle {
+    fd: i32,
+}
+
+#[cfg(target_os = "macos")]
+#[no_mangle]
+pub extern "C" fn apple_only() {}
+
+#[no_mangle]
+pub extern "C" fn open_handle(handle: *mut Handle) {}
@@ -0,0 +1,31 @@
+#[repr(C)]
+#[must_use = "handles have to be closed"]
+pub struct Handle {
+    id: u32,
+}
+
+#[repr(u8)]
+#[must_use]
+pub enum Status {
+    Ok,
+    Failed,
+}
+
+#[no_mangle]
+pub extern "C" fn open() -> Handle {
+    Handle { id: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn close(handle: Handle) -> Status {
+    Status::Ok
+}
+
+#[no_mangle]
+#[must_use]
+pub extern "C" fn count() -> u32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn reset(handle: *mut Handle) {}
@@ -0,0 +1,13 @@
+pub type Abort = extern "C" fn() -> !;
+
+#[no_mangle]
+pub extern "C" fn rust_panic(message: *const u8, len: usize) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn set_abort_handler(handler: Abort) {}
+
+extern "C" {
+    fn host_abort() -> !;
+}
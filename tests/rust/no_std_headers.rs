@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+    owned: bool,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+pub const MISSING: f64 = f64::NAN;
+
+#[no_mangle]
+pub extern "C" fn fill(buffer: *mut Buffer, mode: Mode, value: i64) { }
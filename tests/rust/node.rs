@@ -0,0 +1,56 @@
+use std::os::raw::c_char;
+
+/// The number of nodes a list can hold.
+pub const CAPACITY: usize = 16;
+pub const HALF: i32 = 7 / 2;
+pub const LIMIT: i64 = 1 << 20;
+pub const LARGE: u64 = 9007199254740993;
+pub const NAME: char = 'n';
+
+#[repr(C)]
+pub struct Node {
+    value: u64,
+    delta: isize,
+}
+
+/// A list of nodes.
+#[repr(C)]
+pub struct List {
+    nodes: [Node; CAPACITY],
+    head: *mut Node,
+    len: usize,
+}
+
+impl List {
+    pub const EMPTY_LEN: usize = 0;
+}
+
+#[repr(u8)]
+pub enum Event {
+    Added(u32),
+    Removed { index: u32, value: u64 },
+    Cleared,
+}
+
+#[repr(transparent)]
+pub struct Handle(*mut List);
+
+pub type Callback = extern "C" fn(list: *const List, event: Event);
+
+pub struct Opaque;
+
+#[no_mangle]
+pub static mut LISTS: u32 = 0;
+
+/// Pushes `value` to `list`.
+///
+/// # Returns
+///
+/// Whether there was room for it.
+#[no_mangle]
+pub extern "C" fn list_push(list: Handle, value: u64) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn list_watch(list: &List, name: *const c_char, callback: Callback, opaque: *mut Opaque) {}
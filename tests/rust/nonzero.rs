@@ -0,0 +1,24 @@
+use std::num::{NonZeroU32, NonZeroI64, NonZeroUsize};
+use std::ptr::NonNull;
+
+#[repr(C)]
+pub struct Handle {
+    /// The index of the handle.
+    index: NonZeroU32,
+    /// The generation of the handle, if it was ever reused.
+    generation: Option<NonZeroU32>,
+    data: Option<NonNull<u8>>,
+    callback: Option<extern "C" fn(Option<NonZeroUsize>)>,
+    stamps: [Option<NonZeroI64>; 2],
+}
+
+pub type MaybeId = Option<NonZeroU32>;
+
+#[no_mangle]
+pub static LAST_ID: Option<NonZeroU32> = None;
+
+#[no_mangle]
+pub extern "C" fn find(handle: *const Handle, key: Option<NonZeroUsize>) -> Option<NonZeroU32> {}
+
+#[no_mangle]
+pub extern "C" fn lookup(id: MaybeId, out: *mut Option<NonNull<Handle>>) {}
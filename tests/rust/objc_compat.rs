@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Class {
+    size: u64,
+}
+
+#[repr(C)]
+pub struct Object {
+    id: u64,
+    isa: *const Class,
+    nil: Option<&'static Class>,
+}
+
+#[repr(u8)]
+pub enum BOOL {
+    NO,
+    YES,
+}
+
+pub const Nil: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn object_new(class: &Class, SEL: Option<extern "C" fn(*mut Object)>) -> *mut Object {
+    0 as *mut Object
+}
+
+#[no_mangle]
+pub extern "C" fn object_is_valid(object: &Object, id: *mut BOOL) -> BOOL {
+    BOOL::YES
+}
@@ -0,0 +1,39 @@
+use std::marker::PhantomData;
+
+/// Sorted by alignment, the fields need no padding.
+pub struct Parser {
+    state: u8,
+    position: u64,
+    depth: u16,
+    _marker: PhantomData<u32>,
+}
+
+/// The size of a pointer differs between data models.
+pub struct Handle {
+    data: Box<u32>,
+    flags: u32,
+}
+
+/// cbindgen:size=64
+/// cbindgen:align=16
+pub struct Context {
+    inner: Vec<u8>,
+}
+
+pub struct Unknown {
+    inner: Vec<u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn parser_init(parser: *mut Parser) {}
+
+#[no_mangle]
+pub extern "C" fn handle_init(handle: *mut Handle) {}
+
+#[no_mangle]
+pub extern "C" fn context_init(context: *mut Context) {}
+
+#[no_mangle]
+pub extern "C" fn unknown_new() -> *mut Unknown {
+    0 as *mut Unknown
+}
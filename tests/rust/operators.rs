@@ -0,0 +1,70 @@
+use std::ops::{Add, AddAssign, Index, Neg};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn vec2_add(a: Vec2, b: Vec2) -> Vec2 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn vec2_add_assign(a: *mut Vec2, b: Vec2) {
+    unsafe { *a += b }
+}
+
+#[no_mangle]
+pub extern "C" fn vec2_neg(a: Vec2) -> Vec2 {
+    -a
+}
+
+#[no_mangle]
+pub extern "C" fn vec2_eq(a: *const Vec2, b: *const Vec2) -> bool {
+    unsafe { *a == *b }
+}
+
+// Not an operator, since Vec2 doesn't implement `Sub`.
+#[no_mangle]
+pub extern "C" fn vec2_sub(a: Vec2, b: Vec2) -> Vec2 {
+    Vec2 { x: a.x - b.x, y: a.y - b.y }
+}
+
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+impl Index<usize> for Buffer {
+    type Output = u8;
+    fn index(&self, index: usize) -> &u8 {
+        &self.data[index]
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_index(buffer: *const Buffer, index: usize) -> *const u8 {
+    unsafe { &(*buffer)[index] }
+}
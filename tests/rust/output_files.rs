@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct Handle {
+    id: u32,
+}
+
+#[repr(C)]
+pub struct InternalState {
+    handle: Handle,
+    refs: u32,
+}
+
+/// cbindgen:output=testing
+#[repr(C)]
+pub struct Probe {
+    state: InternalState,
+}
+
+#[no_mangle]
+pub extern "C" fn handle_new() -> Handle {
+    Handle { id: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn internal_state(handle: Handle) -> InternalState {
+    InternalState { handle, refs: 1 }
+}
+
+/// cbindgen:output=testing
+#[no_mangle]
+pub extern "C" fn test_probe(handle: Handle) -> Probe {
+    Probe {
+        state: internal_state(handle),
+    }
+}
@@ -0,0 +1,12 @@
+use std::os::raw::{c_long, c_ulong};
+
+#[repr(C)]
+pub struct Stat {
+    size: c_long,
+    blocks: c_ulong,
+    len: usize,
+    offset: isize,
+}
+
+#[no_mangle]
+pub extern "C" fn stat_len(stat: *const Stat) -> usize {}
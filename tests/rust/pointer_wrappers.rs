@@ -0,0 +1,26 @@
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+#[repr(transparent)]
+pub struct Unique<T> {
+    ptr: NonNull<T>,
+}
+
+#[repr(C)]
+pub struct Foo {
+    a: i32,
+}
+
+#[repr(C)]
+pub struct Handles {
+    pinned: Pin<&'static mut Foo>,
+    non_null: NonNull<Foo>,
+    unique: Unique<Foo>,
+    maybe: Option<Unique<Foo>>,
+}
+
+#[no_mangle]
+pub extern "C" fn poll(foo: Pin<&mut Foo>, handles: Pin<&Handles>) {}
+
+#[no_mangle]
+pub extern "C" fn take(foo: Unique<Foo>) -> NonNull<Foo> {}
@@ -0,0 +1,35 @@
+/// cbindgen:rename-all=CamelCase
+#[repr(C)]
+pub struct Object {
+    /// cbindgen:rename=is_new
+    new: bool,
+    /// cbindgen:rename=template_id
+    template: u32,
+    reference_count: u32,
+}
+
+#[repr(C)]
+pub struct Pair(
+    /// cbindgen:rename=first
+    i32,
+    i32,
+);
+
+#[repr(C)]
+pub union Value {
+    /// cbindgen:rename=as_int
+    int: i64,
+    real: f64,
+}
+
+#[repr(C, u8)]
+pub enum Shape {
+    Circle {
+        /// cbindgen:rename=r
+        radius: f32,
+    },
+    Square(f32),
+}
+
+#[no_mangle]
+pub extern "C" fn root(object: Object, pair: Pair, value: Value, shape: Shape) {}
@@ -0,0 +1,32 @@
+#[repr(C)]
+struct FFIPoint {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+struct FFIRect {
+    origin: FFIPoint,
+    size: FFIPoint,
+}
+
+#[repr(C)]
+struct Kept {
+    x: i32,
+}
+
+#[repr(u8)]
+enum FFIShape {
+    ShapeCircle,
+    ShapeSquare,
+}
+
+pub const FFI_MAX_POINTS: usize = 8;
+
+#[repr(C)]
+struct FFIPolygon {
+    points: [FFIPoint; FFI_MAX_POINTS],
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: FFIRect, b: Kept, c: FFIShape, d: FFIPolygon) {}
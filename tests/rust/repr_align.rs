@@ -0,0 +1,26 @@
+#[repr(C, packed)]
+pub struct PackedHeader {
+    tag: u8,
+    length: u32,
+}
+
+#[repr(C, packed(2))]
+pub struct PackedPair {
+    first: u8,
+    second: u64,
+}
+
+#[repr(C, align(8))]
+pub struct AlignedId {
+    id: u16,
+}
+
+#[repr(C)]
+#[repr(align(16))]
+pub struct AlignedBlock {
+    data: [u8; 4],
+    header: PackedHeader,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: PackedHeader, b: PackedPair, c: AlignedId, d: *const AlignedBlock) {}
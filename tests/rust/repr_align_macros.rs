@@ -0,0 +1,13 @@
+#[repr(C, packed)]
+pub struct PackedHeader {
+    tag: u8,
+    length: u32,
+}
+
+#[repr(C, align(4))]
+pub struct AlignedId {
+    id: u16,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: PackedHeader, b: AlignedId) {}
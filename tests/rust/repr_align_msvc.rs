@@ -0,0 +1,14 @@
+#[repr(C, packed)]
+pub struct PackedHeader {
+    tag: u8,
+    length: u32,
+}
+
+#[repr(C, packed(2))]
+pub struct PackedPair {
+    first: u8,
+    second: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: PackedHeader, b: PackedPair) {}
@@ -0,0 +1,20 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub enum Error {
+    NotFound,
+    Invalid,
+}
+
+#[no_mangle]
+pub extern "C" fn parse_point(text: *const u8) -> Result<Point, Error> {}
+
+#[no_mangle]
+pub extern "C" fn parse_count(text: *const u8) -> Result<u32, Error> {}
+
+#[no_mangle]
+pub extern "C" fn validate(text: *const u8) -> Result<(), Error> {}
@@ -0,0 +1,51 @@
+pub const VOLUME_MAX: u32 = 100;
+
+/// cbindgen:section=Audio
+pub const SAMPLE_RATE: u32 = 48000;
+
+#[repr(C)]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+/// cbindgen:section=Audio
+#[repr(C)]
+pub enum Channel {
+    Left,
+    Right,
+}
+
+pub type OnEvent = extern "C" fn(code: u32);
+
+/// cbindgen:section=Audio
+pub type OnSamples = extern "C" fn(samples: *const f32, len: usize);
+
+pub type Id = u64;
+
+#[repr(C)]
+pub struct Settings {
+    mode: Mode,
+    id: Id,
+    on_event: OnEvent,
+}
+
+/// cbindgen:section=Audio
+#[repr(C)]
+pub struct Stream {
+    channel: Channel,
+    on_samples: OnSamples,
+}
+
+#[no_mangle]
+pub static mut LAST_ERROR: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn init(settings: Settings) {}
+
+/// cbindgen:section=Audio
+#[no_mangle]
+pub extern "C" fn open_stream(stream: *mut Stream) {}
+
+#[no_mangle]
+pub extern "C" fn shutdown() {}
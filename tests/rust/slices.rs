@@ -0,0 +1,26 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Polygon {
+    points: &'static [Point],
+}
+
+#[no_mangle]
+pub extern "C" fn checksum(bytes: &[u8]) -> u32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn fill(out: &mut [u8], value: u8) {}
+
+#[no_mangle]
+pub extern "C" fn translate(points: *mut [Point], by: *const [f32]) {}
+
+#[no_mangle]
+pub extern "C" fn area(polygon: Polygon) -> f32 {
+    0.0
+}
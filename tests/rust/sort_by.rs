@@ -0,0 +1,33 @@
+pub const ZETA: u8 = 1;
+pub const ALPHA: u8 = ZETA + 1;
+pub const MIDDLE: u8 = 3;
+
+#[repr(C)]
+pub struct Zebra {
+    a: i32,
+}
+
+#[repr(C)]
+pub struct Yak {
+    zebra: Zebra,
+}
+
+#[repr(C)]
+pub struct Aardvark {
+    b: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn zoo(aardvark: Aardvark, yak: Yak) {}
+
+#[no_mangle]
+pub extern "C" fn barn(zebra: *const Zebra) {}
+
+#[no_mangle]
+pub extern "C" fn apiary() {}
+
+#[no_mangle]
+pub static mut WEIGHT: u32 = 0;
+
+#[no_mangle]
+pub static mut AGE: u32 = 0;
@@ -0,0 +1,15 @@
+#![feature(thread_local)]
+
+#[no_mangle]
+pub static COUNTER: u32 = 0;
+
+#[no_mangle]
+#[thread_local]
+pub static mut PER_THREAD: u32 = 0;
+
+/// The name of the library.
+#[no_mangle]
+pub static NAME: *const u8 = 0 as *const u8;
+
+#[no_mangle]
+pub extern "C" fn root() { }
@@ -0,0 +1,7 @@
+/// The version of the library.
+pub const VERSION: &str = "1.2.3";
+pub const NAME: &'static str = "cbindgen \"test\"\n";
+pub const UNICODE: &str = "caf\u{e9}1";
+
+#[no_mangle]
+pub extern "C" fn root() { }
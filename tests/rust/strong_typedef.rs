@@ -0,0 +1,22 @@
+/// A handle to a window.
+/// cbindgen:strong-typedef
+pub type WindowHandle = u64;
+
+/// cbindgen:strong-typedef
+pub type Opacity = f32;
+
+/// A handle to a texture.
+/// cbindgen:strong-typedef
+#[repr(transparent)]
+pub struct TextureHandle {
+    id: u32,
+}
+
+impl TextureHandle {
+    pub const INVALID: TextureHandle = TextureHandle { id: 0 };
+}
+
+pub type Plain = u64;
+
+#[no_mangle]
+pub extern "C" fn bind(window: WindowHandle, texture: TextureHandle, opacity: Opacity, plain: Plain) {}
@@ -0,0 +1,40 @@
+use std::ptr::NonNull;
+
+/// cbindgen:swift-name=Point
+#[repr(C)]
+pub struct FfiPoint {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Buffer {
+    data: Option<NonNull<u8>>,
+    len: usize,
+}
+
+/// cbindgen:swift-name=Shape
+#[repr(u8)]
+pub enum FfiShape {
+    Circle,
+    Square,
+}
+
+#[no_mangle]
+pub extern "C" fn point_distance(a: &FfiPoint, b: &FfiPoint) -> f32 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_fill(buffer: NonNull<Buffer>, value: u8, callback: Option<extern "C" fn(*const u8)>) {
+}
+
+/// cbindgen:swift-name=Buffer.clear(self:)
+#[no_mangle]
+pub extern "C" fn buffer_clear(buffer: *mut Buffer) {
+}
+
+#[no_mangle]
+pub extern "C" fn shape_area(shape: FfiShape, size: f32) -> f32 {
+    0.0
+}
@@ -0,0 +1,15 @@
+#[repr(C)]
+pub struct Handle {
+    id: u32,
+}
+
+#[repr(C, u8)]
+pub enum Shape {
+    Empty,
+    Circle(f32),
+    Rect { width: f32, height: f32 },
+    Owned(Handle),
+}
+
+#[no_mangle]
+pub extern "C" fn root(shape: *const Shape) {}
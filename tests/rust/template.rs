@@ -0,0 +1,30 @@
+pub const MAX_PLAYERS: u32 = 4;
+
+#[repr(C)]
+pub enum Team {
+    Red,
+    Blue,
+}
+
+pub type ScoreCallback = extern "C" fn(team: Team, score: u32) -> bool;
+
+#[repr(C)]
+pub struct Player {
+    name: *const u8,
+    team: Team,
+    scores: [u32; 4],
+    on_score: ScoreCallback,
+}
+
+#[no_mangle]
+pub extern "C" fn player_new(name: *const u8, team: Team) -> *mut Player {
+    0 as *mut Player
+}
+
+#[no_mangle]
+pub extern "C" fn player_names(players: *const *const Player, out: *mut *const u8) {}
+
+#[no_mangle]
+pub extern "C" fn player_count() -> u32 {
+    0
+}
@@ -0,0 +1,26 @@
+#[repr(C)]
+pub struct Mutex {
+    handle: *mut u8,
+}
+
+#[repr(C)]
+pub struct Counter {
+    lock: Mutex,
+    /// cbindgen:guarded-by=lock
+    value: u64,
+    /// The number of updates.
+    ///
+    /// cbindgen:guarded-by=lock
+    updates: u32,
+}
+
+/// cbindgen:requires-lock=counter->lock
+#[no_mangle]
+pub extern "C" fn counter_increment(counter: *mut Counter) {}
+
+/// cbindgen:requires-lock=[a->lock, b->lock]
+#[no_mangle]
+pub extern "C" fn counter_merge(a: *mut Counter, b: *const Counter) {}
+
+#[no_mangle]
+pub extern "C" fn counter_new() -> Counter {}
@@ -0,0 +1,38 @@
+#[repr(C)]
+pub struct Counter {
+    value: u32,
+}
+
+pub trait Resettable {
+    extern "C" fn reset(&mut self);
+    extern "C" fn value(&self) -> u32;
+}
+
+impl Resettable for Counter {
+    #[export_name = "counter_reset"]
+    extern "C" fn reset(&mut self) {
+        self.value = 0;
+    }
+
+    #[export_name = "counter_value"]
+    extern "C" fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+pub trait Create {
+    extern "C" fn counter_new() -> Self;
+}
+
+impl Create for Counter {
+    #[no_mangle]
+    extern "C" fn counter_new() -> Self {
+        Counter { value: 0 }
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Counter { value: 0 }
+    }
+}
@@ -0,0 +1,33 @@
+/// A length, erased to its float.
+#[repr(transparent)]
+pub struct Meters(f32);
+
+/// A wrapper erased to whatever it wraps.
+#[repr(transparent)]
+pub struct Wrapper<T> {
+    inner: T,
+}
+
+/// A handle kept distinct from other integers.
+/// cbindgen:transparent=struct
+#[repr(transparent)]
+pub struct Handle(u64);
+
+/// An identifier written as a typedef.
+/// cbindgen:transparent=typedef
+#[repr(transparent)]
+pub struct Id(u32);
+
+#[repr(C)]
+pub struct Body {
+    id: Id,
+    height: Meters,
+    weight: Wrapper<f64>,
+    parent: *const Wrapper<Handle>,
+}
+
+#[no_mangle]
+pub extern "C" fn open(id: Id, length: Meters) -> Handle {}
+
+#[no_mangle]
+pub extern "C" fn measure(handle: Handle, body: *mut Body) -> Wrapper<Meters> {}
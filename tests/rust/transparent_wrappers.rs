@@ -0,0 +1,14 @@
+use std::cell::{Cell, UnsafeCell};
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+#[repr(C)]
+pub struct Slot {
+    ready: Cell<bool>,
+    value: MaybeUninit<u64>,
+    buffer: [MaybeUninit<u8>; 16],
+    state: UnsafeCell<u32>,
+    owner: ManuallyDrop<*mut u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn slot_take(slot: *mut Slot, out: *mut MaybeUninit<u64>) -> bool {}
@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Segment {
+    ends: (Point, Point),
+    weight: f32,
+}
+
+#[repr(C)]
+pub struct Wrapper<T> {
+    value: T,
+}
+
+#[no_mangle]
+pub extern "C" fn div_mod(a: i32, b: i32) -> (i32, i32) {
+    (a / b, a % b)
+}
+
+#[no_mangle]
+pub extern "C" fn split(s: Segment, bytes: (*const u8, usize)) -> (Segment, Segment) {
+    (s, s)
+}
+
+#[no_mangle]
+pub extern "C" fn wrapped(w: Wrapper<(u8, u16)>) {}
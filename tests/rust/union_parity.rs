@@ -0,0 +1,46 @@
+/// A union of two integers that doesn't pad `wide`.
+#[repr(C, packed)]
+pub union Packed {
+    /// The narrow view.
+    narrow: u8,
+    /// The wide view.
+    wide: u32,
+}
+
+#[repr(C, align(16))]
+pub union Aligned {
+    bytes: [u8; 4],
+    word: u32,
+}
+
+/// cbindgen:rename-all=CamelCase
+#[repr(C)]
+pub union Generic<T> {
+    as_value: T,
+    as_bits: u64,
+}
+
+#[deprecated(note = "use Aligned")]
+#[repr(C)]
+pub union Old {
+    a: i32,
+    b: f32,
+}
+
+#[must_use]
+#[repr(C)]
+pub union Checked {
+    a: i32,
+    b: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Packed,
+    b: Aligned,
+    c: Generic<f64>,
+    d: Generic<u32>,
+    e: Old,
+    f: Checked,
+) {
+}
@@ -0,0 +1,16 @@
+use std::os::raw::c_char;
+
+pub type Logger = unsafe extern "C" fn(level: i32, format: *const c_char, ...);
+
+#[repr(C)]
+pub struct Sink {
+    log: Logger,
+    print: unsafe extern "C" fn(format: *const c_char, ...) -> i32,
+}
+
+extern "C" {
+    pub fn log_message(level: i32, format: *const c_char, ...);
+}
+
+#[no_mangle]
+pub extern "C" fn set_sink(sink: Sink) {}
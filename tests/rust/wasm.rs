@@ -0,0 +1,54 @@
+#[repr(C)]
+pub struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+/// A single `f64`, passed as is.
+#[repr(C)]
+pub struct Meters(f64);
+
+#[repr(u32)]
+pub enum Filter {
+    Nearest,
+    Linear,
+}
+
+#[no_mangle]
+pub static mut FRAME_COUNT: u64 = 0;
+
+/// Fills the `len` pixels at `pixels` with `color`.
+#[no_mangle]
+pub extern "C" fn fill(pixels: *mut Rgba, len: usize, color: Rgba) {}
+
+#[no_mangle]
+pub extern "C" fn checksum(data: *const u8, len: usize) -> u32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn sum_samples(samples: *const f32, count: usize, filter: Filter) -> f64 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn average_color() -> Rgba {
+    Rgba { r: 0, g: 0, b: 0, a: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn distance(from: Meters, to: Meters) -> Meters {
+    Meters(0.0)
+}
+
+#[no_mangle]
+pub extern "C" fn timestamp() -> i64 {
+    0
+}
+
+extern "C" {
+    fn host_log(message: *const u8, len: usize);
+    fn host_now() -> f64;
+}
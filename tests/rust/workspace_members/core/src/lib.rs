@@ -0,0 +1,16 @@
+#[repr(C)]
+pub enum Format {
+    Pcm,
+    Float,
+}
+
+#[repr(C)]
+pub struct Sample {
+    pub left: f32,
+    pub right: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn core_version() -> u32 {
+    1
+}
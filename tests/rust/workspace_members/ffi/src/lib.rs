@@ -0,0 +1,12 @@
+extern crate workspace_core;
+
+use workspace_core::{Format, Sample};
+
+#[repr(C)]
+pub struct Player {
+    format: Format,
+    last: Sample,
+}
+
+#[no_mangle]
+pub extern "C" fn play(player: *mut Player, samples: *const Sample, len: usize) {}
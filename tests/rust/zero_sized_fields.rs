@@ -0,0 +1,41 @@
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub struct Unit;
+
+#[repr(C)]
+pub struct Markers {
+    unit: Unit,
+    marker: PhantomData<u32>,
+}
+
+#[repr(C)]
+pub struct Fields {
+    a: u64,
+    marker: PhantomData<u8>,
+    unit: Unit,
+    markers: Markers,
+    pad: [u32; 0],
+    b: u8,
+}
+
+#[repr(C)]
+pub struct Tuple(u32, PhantomData<u8>, [u16; 0], u32);
+
+#[repr(C)]
+pub union Either {
+    a: i32,
+    unit: Unit,
+    pad: [u8; 0],
+}
+
+/// Skipped, as `pad` raises its alignment to that of `u64`, which fails
+/// unless `zero-sized-alignment` is allowed.
+#[repr(C)]
+pub struct Overaligned {
+    a: u8,
+    pad: [u64; 0],
+}
+
+#[no_mangle]
+pub extern "C" fn root(f: Fields, t: Tuple, e: Either) {}
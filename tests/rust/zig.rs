@@ -0,0 +1,52 @@
+use std::os::raw::{c_char, c_int};
+
+pub type Callback = extern "C" fn(len: usize, data: *mut c_char) -> bool;
+
+/// The direction of a move.
+#[repr(u8)]
+pub enum Direction {
+    Up,
+    Down = 4,
+}
+
+pub struct Handle;
+
+#[repr(C)]
+pub struct Options {
+    /// Fields named after Zig keywords are escaped.
+    error: u32,
+    align: c_int,
+    callback: Callback,
+    name: *const c_char,
+    handle: *mut Handle,
+    grid: [[u8; 2]; COUNT],
+}
+
+#[repr(C, u8)]
+pub enum Event {
+    Moved(Direction),
+    Renamed { name: *const c_char },
+    Closed,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    tag: u8,
+    value: u32,
+}
+
+pub const COUNT: usize = 3;
+pub const HALF: i32 = 7 / 2;
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn process(
+    options: &Options,
+    packed: Packed,
+    event: Event,
+    handle: *const Handle,
+    out: *mut u8,
+) {
+}
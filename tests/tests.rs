@@ -1,6 +1,9 @@
 extern crate cbindgen;
+extern crate serde_json;
+extern crate tempfile;
 
 use cbindgen::*;
+use std::io::ErrorKind;
 use std::path::Path;
 use std::process::Command;
 use std::{env, fs, str};
@@ -24,6 +27,33 @@ fn run_cbindgen(
                 command.arg("--cpp-compat");
             }
         }
+        Language::Python => {
+            command.arg("--lang").arg("python");
+        }
+        Language::Cython => {
+            command.arg("--lang").arg("cython");
+        }
+        Language::Java => {
+            command.arg("--lang").arg("java");
+        }
+        Language::Node => {
+            command.arg("--lang").arg("node");
+        }
+        Language::D => {
+            command.arg("--lang").arg("d");
+        }
+        Language::Zig => {
+            command.arg("--lang").arg("zig");
+        }
+        Language::Go => {
+            command.arg("--lang").arg("go");
+        }
+        Language::Json => {
+            command.arg("--lang").arg("json");
+        }
+        Language::Wasm => {
+            command.arg("--lang").arg("wasm");
+        }
     }
 
     if let Some(style) = style {
@@ -38,7 +68,7 @@ fn run_cbindgen(
     command.arg("-o").arg(output);
 
     if env::var("CBINDGEN_TEST_VERIFY").is_ok() {
-        command.arg("--verify");
+        command.arg("--verify-strict");
     }
 
     let mut config = path.clone().to_path_buf();
@@ -59,10 +89,19 @@ fn run_cbindgen(
     );
 }
 
-fn compile(cbindgen_output: &Path, language: Language) {
+fn compile(cbindgen_output: &Path, language: Language, version: Option<LanguageVersion>) {
     let cc = match language {
         Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
         Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
+        Language::Python => return run_python(cbindgen_output),
+        Language::Cython => return run_cython(cbindgen_output),
+        Language::Java => return run_javac(cbindgen_output),
+        Language::Node => return run_node(cbindgen_output),
+        Language::D => return run_dmd(cbindgen_output),
+        Language::Zig => return run_zig(cbindgen_output),
+        Language::Go => return run_gofmt(cbindgen_output),
+        Language::Json => return check_json(cbindgen_output),
+        Language::Wasm => return run_node(cbindgen_output),
     };
 
     let mut object = cbindgen_output.to_path_buf();
@@ -72,9 +111,18 @@ fn compile(cbindgen_output: &Path, language: Language) {
     command.arg("-D").arg("DEFINED");
     command.arg("-c").arg(cbindgen_output);
     command.arg("-o").arg(&object);
-    if let Language::Cxx = language {
-        // enum class is a c++11 extension which makes g++ on macos 10.14 error out
-        command.arg("-std=c++11");
+    match version {
+        // The headers targeting a standard have to stick to it.
+        Some(version) if version.language() == language => {
+            command.arg(format!("-std={}", version.as_str()));
+            command.arg("-pedantic-errors");
+        }
+        _ => {
+            if let Language::Cxx = language {
+                // enum class is a c++11 extension which makes g++ on macos 10.14 error out
+                command.arg("-std=c++11");
+            }
+        }
     }
 
     println!("Running: {:?}", command);
@@ -86,6 +134,164 @@ fn compile(cbindgen_output: &Path, language: Language) {
     }
 }
 
+fn run_python(cbindgen_output: &Path) {
+    let python = env::var("PYTHON").unwrap_or_else(|_| "python3".to_owned());
+
+    // Importing the module is enough to have ctypes validate every declaration.
+    let mut command = Command::new(python);
+    command.arg(cbindgen_output);
+
+    println!("Running: {:?}", command);
+    let out = command.output().expect("failed to run python");
+    assert!(out.status.success(), "Output failed to run: {:?}", out);
+}
+
+fn run_cython(cbindgen_output: &Path) {
+    let cython = env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned());
+
+    let mut command = Command::new(cython);
+    command.arg("-3").arg(cbindgen_output);
+    command
+        .arg("-o")
+        .arg(env::temp_dir().join("cbindgen-test.c"));
+
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // Cython is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run cython: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn run_javac(cbindgen_output: &Path) {
+    // Compiling needs JNA, which is an optional dependency of the test suite.
+    let jna = match env::var("JNA_JAR") {
+        Ok(jna) => jna,
+        Err(_) => return,
+    };
+    // Some tests configure a C header, which isn't valid Java.
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if source.lines().any(|line| line.starts_with('#')) {
+        return;
+    }
+    let javac = env::var("JAVAC").unwrap_or_else(|_| "javac".to_owned());
+
+    // A public class has to be declared in a file of the same name.
+    let dir = env::temp_dir()
+        .join("cbindgen-test-java")
+        .join(cbindgen_output.file_stem().unwrap());
+    fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("Bindings.java");
+    fs::copy(cbindgen_output, &source).unwrap();
+
+    let mut command = Command::new(javac);
+    command.arg("-cp").arg(jna).arg("-d").arg(&dir).arg(&source);
+
+    println!("Running: {:?}", command);
+    let out = command.output().expect("failed to run javac");
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn run_node(cbindgen_output: &Path) {
+    // Some tests configure a C header, which isn't valid JavaScript.
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if source.lines().any(|line| line.starts_with('#')) {
+        return;
+    }
+    let node = env::var("NODE").unwrap_or_else(|_| "node".to_owned());
+
+    // Loading the module needs `ffi-napi`, so only its syntax is checked.
+    let mut command = Command::new(node);
+    command.arg("--check").arg(cbindgen_output);
+
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // Node is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run node: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn run_dmd(cbindgen_output: &Path) {
+    // Some tests configure a C header, which isn't valid D.
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if source.lines().any(|line| line.starts_with('#')) {
+        return;
+    }
+    let dmd = env::var("DMD").unwrap_or_else(|_| "dmd".to_owned());
+
+    // Bitfields are still a preview feature.
+    let mut command = Command::new(dmd);
+    command
+        .arg("-c")
+        .arg("-o-")
+        .arg("-preview=bitfields")
+        .arg(cbindgen_output);
+
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // DMD is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run dmd: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn run_zig(cbindgen_output: &Path) {
+    // Some tests configure a C header, which isn't valid Zig.
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if source.lines().any(|line| line.starts_with('#')) {
+        return;
+    }
+    let zig = env::var("ZIG").unwrap_or_else(|_| "zig".to_owned());
+
+    let mut command = Command::new(zig);
+    command.arg("ast-check").arg(cbindgen_output);
+
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // Zig is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run zig: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn run_gofmt(cbindgen_output: &Path) {
+    // Some tests configure a C header, which isn't valid Go.
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if source.lines().any(|line| line.starts_with('#')) {
+        return;
+    }
+    let gofmt = env::var("GOFMT").unwrap_or_else(|_| "gofmt".to_owned());
+
+    // Building needs the header, so the syntax is only checked.
+    let mut command = Command::new(gofmt);
+    command.arg("-e").arg("-l").arg(cbindgen_output);
+
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // Go is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run gofmt: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+fn check_json(cbindgen_output: &Path) {
+    let source = fs::read_to_string(cbindgen_output).unwrap();
+    if let Err(e) = serde_json::from_str::<serde_json::Value>(&source) {
+        panic!("Output isn't valid JSON: {}", e);
+    }
+}
+
 fn run_compile_test(
     cbindgen_path: &'static str,
     name: &'static str,
@@ -117,15 +323,31 @@ fn run_compile_test(
                 "c"
             }
         }
+        Language::Python => "py",
+        Language::Cython => "pxd",
+        Language::Java => "java",
+        Language::Node => "js",
+        Language::D => "d",
+        Language::Zig => "zig",
+        Language::Go => "go",
+        Language::Json => "json",
+        Language::Wasm => "mjs",
     };
 
     output.push(format!("{}.{}", name, ext));
 
     run_cbindgen(cbindgen_path, path, &output, language, cpp_compat, style);
-    compile(&output, language);
+    let mut config = path.to_path_buf();
+    config.set_extension("toml");
+    let version = if config.exists() {
+        Config::from_file(&config).unwrap().language_version
+    } else {
+        None
+    };
+    compile(&output, language, version);
 
     if language == Language::C && cpp_compat {
-        compile(&output, Language::Cxx)
+        compile(&output, Language::Cxx, None)
     }
 }
 
@@ -151,6 +373,78 @@ fn test_file(cbindgen_path: &'static str, name: &'static str, filename: &'static
         /* cpp_compat = */ false,
         None,
     );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Python,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Cython,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Java,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Node,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::D,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Zig,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Go,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Json,
+        /* cpp_compat = */ false,
+        None,
+    );
+    run_compile_test(
+        cbindgen_path,
+        name,
+        &test,
+        Language::Wasm,
+        /* cpp_compat = */ false,
+        None,
+    );
 }
 
 macro_rules! test_file {
@@ -162,5 +456,225 @@ macro_rules! test_file {
     };
 }
 
+fn generate_with_jobs(crate_dir: &Path, jobs: usize) -> Vec<u8> {
+    let mut config = Config::from_root_or_default(crate_dir);
+    config.parse.jobs = jobs;
+    let bindings = Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Unable to generate bindings");
+    let mut output = Vec::new();
+    bindings.write(&mut output);
+    output
+}
+
+#[test]
+fn test_parallel_parsing() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    for name in &["rename-crate", "workspace"] {
+        let path = Path::new(&crate_dir).join("tests").join("rust").join(name);
+        assert_eq!(
+            str::from_utf8(&generate_with_jobs(&path, 8)).unwrap(),
+            str::from_utf8(&generate_with_jobs(&path, 1)).unwrap(),
+            "Parsing {} on several threads changed the bindings",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_kotlin_def() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("lib.rs");
+    fs::write(
+        &src,
+        "#[repr(C)] pub enum Mode { A, B }\n\
+         #[repr(C, u8)] pub enum Shape { Circle(f32), Square }\n\
+         #[no_mangle] pub extern \"C\" fn draw(mode: Mode, shape: Shape) {}\n",
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.kotlin.package = Some("mylib".to_owned());
+    config.kotlin.linker_opts = vec!["-lmylib".to_owned()];
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_language(Language::C)
+        .with_src(&src)
+        .generate()
+        .expect("Unable to generate bindings");
+    let header = dir.path().join("mylib.h");
+    let def = dir.path().join("mylib.def");
+    bindings.write_to_file(&header);
+    assert!(bindings.write_kotlin_def(&def, &header));
+    assert_eq!(
+        fs::read_to_string(&def).unwrap(),
+        "headers = mylib.h\n\
+         headerFilter = mylib.h\n\
+         package = mylib\n\
+         linkerOpts = -lmylib\n\
+         strictEnums = Mode\n"
+    );
+    assert!(!bindings.write_kotlin_def(&def, &header));
+}
+
+#[test]
+fn test_unguarded_targets() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("lib.rs");
+    fs::write(
+        &src,
+        "#[cfg(target_pointer_width = \"64\")]\n\
+         #[no_mangle] pub extern \"C\" fn wide() {}\n",
+    )
+    .unwrap();
+
+    // Both targets are Linux, so `__linux__` can't tell them apart.
+    let mut config = Config::default();
+    config.targets = vec![
+        "x86_64-unknown-linux-gnu".to_owned(),
+        "i686-unknown-linux-gnu".to_owned(),
+    ];
+    let error = Builder::new()
+        .with_config(config)
+        .with_language(Language::C)
+        .with_src(&src)
+        .generate()
+        .err()
+        .expect("Items of unguarded targets were generated");
+    assert_eq!(
+        error.to_string(),
+        "Items differ between targets with no `[defines]` entry to guard them with:\n  \
+         \"target = x86_64-unknown-linux-gnu\""
+    );
+}
+
+#[test]
+fn test_go_int128() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src = Path::new(&crate_dir).join("tests/rust/int128.rs");
+    let dir = tempfile::tempdir().unwrap();
+    for &(language, file) in &[(Language::C, "bindings.h"), (Language::Go, "bindings.go")] {
+        Builder::new()
+            .with_language(language)
+            .with_src(&src)
+            .generate()
+            .expect("Unable to generate bindings")
+            .write_to_file(dir.path().join(file));
+    }
+
+    // The Go file has to type check against the header, which needs cgo.
+    let go = env::var("GO").unwrap_or_else(|_| "go".to_owned());
+    let mut command = Command::new(go);
+    command
+        .arg("vet")
+        .arg("bindings.go")
+        .current_dir(dir.path())
+        .env("CGO_ENABLED", "1")
+        .env("GO111MODULE", "off");
+    println!("Running: {:?}", command);
+    let out = match command.output() {
+        Ok(out) => out,
+        // Go is an optional dependency of the test suite.
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("failed to run go: {}", e),
+    };
+    assert!(out.status.success(), "Output failed to compile: {:?}", out);
+}
+
+#[test]
+fn test_wasm_int128() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("lib.rs");
+    fs::write(
+        &src,
+        "#[no_mangle] pub extern \"C\" fn mix(seed: u128, x: i32) -> i128 {}\n",
+    )
+    .unwrap();
+
+    let bindings = Builder::new()
+        .with_language(Language::Wasm)
+        .with_src(&src)
+        .generate()
+        .expect("Unable to generate bindings");
+    let mut output = Vec::new();
+    bindings.write(&mut output);
+    // The integers are split into two `i64`s, and returned through a pointer.
+    assert!(str::from_utf8(&output).unwrap().contains(
+        "  mix: {\n    \
+         params: [\"i32\", \"i64\", \"i64\", \"i32\"],\n    \
+         results: [],\n    \
+         sret: true,\n  \
+         },"
+    ));
+}
+
+#[test]
+fn test_json_message_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("lib.rs");
+    fs::write(
+        &src,
+        "#[repr(C)]\n\
+         pub struct Foo { x: i32 }\n\
+         impl Foo {\n    \
+             #[no_mangle]\n    \
+             extern \"C\" fn foo_get(&self) -> i32 { self.x }\n\
+         }\n\
+         #[no_mangle]\n\
+         pub extern \"C\" fn bar(x: Missing) {}\n",
+    )
+    .unwrap();
+
+    // The binary is next to the `deps` directory the tests are built in.
+    let mut program = env::current_exe().unwrap();
+    program.pop();
+    program.pop();
+    let output = Command::new(program.join("cbindgen"))
+        .arg("--lang")
+        .arg("c")
+        .arg("--message-format")
+        .arg("json")
+        .arg("-o")
+        .arg(dir.path().join("lib.h"))
+        .arg(&src)
+        .output()
+        .expect("failed to execute process");
+    assert!(output.status.success());
+
+    let diagnostics: Vec<serde_json::Value> = str::from_utf8(&output.stderr)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Diagnostic isn't valid JSON"))
+        .collect();
+    let src = src.to_str().unwrap();
+    assert_eq!(
+        diagnostics,
+        vec![
+            serde_json::json!({
+                "code": "CB0002",
+                "lint": "skipped-item",
+                "level": "warning",
+                "message": "Skip lib::foo_get - (not `pub`).",
+                "item": "lib::foo_get",
+                "file": src,
+                "line": 5,
+                "column": 19,
+            }),
+            serde_json::json!({
+                "code": "CB0003",
+                "lint": "unknown-type",
+                "level": "warning",
+                "message": "Can't find Missing. This usually means that this type was incompatible or not found.",
+                "item": "Missing",
+                "file": null,
+                "line": null,
+                "column": null,
+            }),
+        ]
+    );
+}
+
 // This file is generated by build.rs
 include!(concat!(env!("OUT_DIR"), "/tests.rs"));